@@ -331,6 +331,7 @@ fn example_test_orchestrator() {
             allow_storage: false,
         },
         metadata: std::collections::HashMap::new(),
+        requirements: std::collections::HashMap::new(),
     };
 
     let standard_plan = TestPlan {
@@ -341,6 +342,7 @@ fn example_test_orchestrator() {
         qos: QoSClass::Standard,
         resource_budget: ResourceBudget::default_budget(),
         metadata: std::collections::HashMap::new(),
+        requirements: std::collections::HashMap::new(),
     };
 
     orchestrator.submit_plan(premium_plan);
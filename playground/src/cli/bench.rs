@@ -0,0 +1,130 @@
+//! Bench noun commands
+//!
+//! Commands for A/B benchmark comparison: is the candidate binary faster than the baseline?
+
+use chicago_tdd_tools::core::command::CheckedCommand;
+use chicago_tdd_tools::validation::bench_compare::AbBenchmark;
+use clap_noun_verb::Result;
+use clap_noun_verb_macros::verb;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Per-run timeout for benchmarked binaries
+const RUN_TIMEOUT: Duration = Duration::from_secs(600);
+
+#[derive(Serialize)]
+pub struct BenchComparisonResult {
+    pub name: String,
+    pub baseline: String,
+    pub candidate: String,
+    pub baseline_median_ns: f64,
+    pub candidate_median_ns: f64,
+    pub change_pct: f64,
+    pub p_value: f64,
+    pub cliffs_delta: f64,
+    pub effect: String,
+    pub verdict: String,
+    pub receipt: Option<String>,
+    pub success: bool,
+    pub message: String,
+}
+
+impl BenchComparisonResult {
+    fn failed(name: String, baseline: String, candidate: String, message: String) -> Self {
+        Self {
+            name,
+            baseline,
+            candidate,
+            baseline_median_ns: 0.0,
+            candidate_median_ns: 0.0,
+            change_pct: 0.0,
+            p_value: 1.0,
+            cliffs_delta: 0.0,
+            effect: String::new(),
+            verdict: String::new(),
+            receipt: None,
+            success: false,
+            message,
+        }
+    }
+}
+
+/// Compare two benchmark binaries (e.g. built from two commits) run interleaved
+///
+/// Reports a Mann-Whitney significance test and Cliff's delta effect size.
+/// `success` is false when the candidate is significantly slower.
+///
+/// Examples:
+///   playg bench compare --baseline target/main/bench --candidate target/release/bench
+///   playg bench compare --baseline ./old --candidate ./new --args "--quick" --runs 30
+///   playg bench compare --baseline ./old --candidate ./new --receipt receipts/bench.json
+#[verb]
+#[allow(clippy::too_many_arguments)]
+fn compare(
+    baseline: String,
+    candidate: String,
+    args: Option<String>,
+    runs: Option<usize>,
+    warmup: Option<usize>,
+    alpha: Option<f64>,
+    name: Option<String>,
+    receipt: Option<String>,
+) -> Result<BenchComparisonResult> {
+    let name = name.unwrap_or_else(|| "ab".to_string());
+    let args: Vec<String> =
+        args.map(|a| a.split_whitespace().map(String::from).collect()).unwrap_or_default();
+    let command = |program: &str| {
+        CheckedCommand::new(program).args(args.iter().cloned()).timeout(RUN_TIMEOUT)
+    };
+
+    let mut bench = AbBenchmark::new(name.clone(), command(&baseline), command(&candidate))
+        .labels(baseline.clone(), candidate.clone());
+    if let Some(runs) = runs {
+        bench = bench.runs(runs);
+    }
+    if let Some(warmup) = warmup {
+        bench = bench.warmup(warmup);
+    }
+    if let Some(alpha) = alpha {
+        bench = bench.alpha(alpha);
+    }
+
+    let comparison = match bench.run() {
+        Ok(comparison) => comparison,
+        Err(e) => {
+            return Ok(BenchComparisonResult::failed(name, baseline, candidate, e.to_string()))
+        }
+    };
+    println!("{comparison}");
+
+    let mut message = comparison.to_string();
+    let receipt = receipt.and_then(|path| {
+        let written = comparison
+            .to_receipt(&name)
+            .to_json()
+            .and_then(|json| std::fs::write(&path, json).map_err(|e| e.to_string()));
+        match written {
+            Ok(()) => Some(path),
+            Err(e) => {
+                message = format!("{message}\n   ⚠️  Failed to write receipt {path}: {e}");
+                None
+            }
+        }
+    });
+
+    Ok(BenchComparisonResult {
+        name,
+        baseline,
+        candidate,
+        baseline_median_ns: comparison.baseline.median_ns,
+        candidate_median_ns: comparison.candidate.median_ns,
+        change_pct: comparison.change_pct,
+        p_value: comparison.p_value,
+        cliffs_delta: comparison.cliffs_delta,
+        effect: comparison.magnitude.to_string(),
+        verdict: comparison.verdict.to_string(),
+        receipt,
+        success: !comparison.is_regression(),
+        message,
+    })
+}
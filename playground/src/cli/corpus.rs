@@ -0,0 +1,170 @@
+//! Corpus noun commands
+//!
+//! Commands for managing recorded golden corpora: HTTP cassettes, telemetry captures, fuzz corpora
+
+use chicago_tdd_tools::testing::corpus::{
+    AddOutcome, Corpus, CorpusEntry, CorpusKind, PrunePolicy, SizeBudget,
+};
+use clap_noun_verb::Result;
+use clap_noun_verb_macros::verb;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Default corpus directory when `--root` is not given
+const DEFAULT_CORPUS_ROOT: &str = "tests/corpus";
+
+#[derive(Serialize)]
+pub struct CorpusAddResult {
+    pub id: String,
+    pub duplicate: bool,
+    pub success: bool,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+pub struct CorpusListing {
+    pub root: String,
+    pub total_entries: usize,
+    pub total_bytes: u64,
+    pub entries: Vec<CorpusEntry>,
+    pub success: bool,
+    pub message: String,
+}
+
+#[derive(Serialize)]
+pub struct CorpusPruneResult {
+    pub removed: Vec<String>,
+    pub bytes_reclaimed: u64,
+    pub success: bool,
+    pub message: String,
+}
+
+fn open_corpus(root: Option<String>) -> std::result::Result<Corpus, String> {
+    let root = root.unwrap_or_else(|| DEFAULT_CORPUS_ROOT.to_string());
+    Corpus::open(&root).map_err(|e| e.to_string())
+}
+
+/// Add a recorded artifact to the corpus (deduplicated by content hash)
+///
+/// Examples:
+///   playg corpus add --file recordings/orders.json --kind http
+///   playg corpus add --file crash-1 --kind fuzz --root fuzz/corpus
+#[verb]
+fn add(file: String, kind: String, root: Option<String>) -> Result<CorpusAddResult> {
+    let outcome = open_corpus(root).and_then(|mut corpus| {
+        corpus.add_file(&file, CorpusKind::parse(&kind)).map_err(|e| e.to_string())
+    });
+
+    Ok(match outcome {
+        Ok(AddOutcome::Added(id)) => CorpusAddResult {
+            message: format!("Added {file} as {id}"),
+            id,
+            duplicate: false,
+            success: true,
+        },
+        Ok(AddOutcome::Duplicate(id)) => CorpusAddResult {
+            message: format!("{file} already recorded as {id}"),
+            id,
+            duplicate: true,
+            success: true,
+        },
+        Err(e) => CorpusAddResult {
+            id: String::new(),
+            duplicate: false,
+            success: false,
+            message: format!("Failed to add {file}: {e}"),
+        },
+    })
+}
+
+/// List recorded artifacts, optionally filtered by kind
+///
+/// Examples:
+///   playg corpus list
+///   playg corpus list --kind telemetry
+#[verb]
+fn list(kind: Option<String>, root: Option<String>) -> Result<CorpusListing> {
+    let root_display = root.clone().unwrap_or_else(|| DEFAULT_CORPUS_ROOT.to_string());
+    let listing = match open_corpus(root) {
+        Ok(corpus) => {
+            let kind = kind.as_deref().map(CorpusKind::parse);
+            let entries: Vec<CorpusEntry> =
+                corpus.list(kind.as_ref()).into_iter().cloned().collect();
+            CorpusListing {
+                message: format!("{} entr(y/ies) in {root_display}", entries.len()),
+                root: root_display,
+                total_entries: corpus.len(),
+                total_bytes: corpus.total_bytes(),
+                entries,
+                success: true,
+            }
+        }
+        Err(e) => CorpusListing {
+            message: format!("Failed to open corpus {root_display}: {e}"),
+            root: root_display,
+            total_entries: 0,
+            total_bytes: 0,
+            entries: Vec::new(),
+            success: false,
+        },
+    };
+    Ok(listing)
+}
+
+/// Prune recorded artifacts by age and/or size budget
+///
+/// Examples:
+///   playg corpus prune --max-bytes 10485760
+///   playg corpus prune --older-than-days 30 --kind http
+#[verb]
+fn prune(
+    max_bytes: Option<u64>,
+    max_entries: Option<usize>,
+    older_than_days: Option<u64>,
+    kind: Option<String>,
+    root: Option<String>,
+) -> Result<CorpusPruneResult> {
+    let mut budget = SizeBudget::new();
+    if let Some(bytes) = max_bytes {
+        budget = budget.max_total_bytes(bytes);
+    }
+    if let Some(entries) = max_entries {
+        budget = budget.max_entries(entries);
+    }
+
+    let mut policy = match older_than_days {
+        Some(days) => PrunePolicy::older_than(Duration::from_secs(days * 24 * 60 * 60)),
+        None => PrunePolicy::to_budget(),
+    };
+    if older_than_days.is_some() && (max_bytes.is_some() || max_entries.is_some()) {
+        policy = policy.and_budget();
+    }
+    if let Some(kind) = kind {
+        policy = policy.only_kind(CorpusKind::parse(&kind));
+    }
+
+    let report = open_corpus(root).and_then(|corpus| {
+        let mut corpus =
+            if budget == SizeBudget::new() { corpus } else { corpus.with_budget(budget) };
+        corpus.prune(&policy).map_err(|e| e.to_string())
+    });
+
+    Ok(match report {
+        Ok(report) => CorpusPruneResult {
+            message: format!(
+                "Removed {} entr(y/ies), reclaimed {} bytes",
+                report.removed.len(),
+                report.bytes_reclaimed
+            ),
+            removed: report.removed.into_iter().map(|e| e.id).collect(),
+            bytes_reclaimed: report.bytes_reclaimed,
+            success: true,
+        },
+        Err(e) => CorpusPruneResult {
+            removed: Vec::new(),
+            bytes_reclaimed: 0,
+            success: false,
+            message: format!("Prune failed: {e}"),
+        },
+    })
+}
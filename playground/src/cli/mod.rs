@@ -3,7 +3,9 @@
 //! This module contains all noun-verb command implementations using clap-noun-verb.
 
 pub mod analyze;
+pub mod bench;
 pub mod core;
+pub mod corpus;
 pub mod gh;
 pub mod improve;
 pub mod integ;
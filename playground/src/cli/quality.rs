@@ -1,7 +1,9 @@
 //! Quality noun commands
 //!
-//! Commands for quality methodologies: FMEA, RCA, Robust Design, Andon Signals
+//! Commands for quality methodologies: FMEA, RCA, Robust Design, Andon Signals,
+//! and the Chicago TDD compliance audit
 
+use chicago_tdd_tools::validation::audit::{AuditCheck, QualityAudit, Scorecard};
 use clap_noun_verb::Result;
 use clap_noun_verb_macros::verb;
 use serde::Serialize;
@@ -120,3 +122,76 @@ fn andon() -> Result<GuidanceInfo> {
         ],
     })
 }
+
+#[derive(Serialize)]
+pub struct AuditOutcome {
+    pub success: bool,
+    pub message: String,
+    pub report: Option<String>,
+    pub scorecard: Option<Scorecard>,
+}
+
+fn run_audit(
+    path: &str,
+    checks: Option<&str>,
+    min_score: Option<f64>,
+    coverage_threshold: Option<f64>,
+    lcov: Option<String>,
+) -> std::result::Result<Scorecard, String> {
+    let audit = QualityAudit::for_project(path).map_err(|e| e.to_string())?;
+    let mut config = audit.config().clone();
+    if let Some(checks) = checks {
+        config.checks = AuditCheck::parse_list(checks).map_err(|e| e.to_string())?;
+    }
+    config.min_score = min_score.unwrap_or(config.min_score);
+    config.coverage_threshold = coverage_threshold.unwrap_or(config.coverage_threshold);
+    config.lcov = lcov.map(Into::into).or(config.lcov);
+    audit.with_config(config).run().map_err(|e| e.to_string())
+}
+
+/// Audit a project for Chicago TDD compliance and emit a scorecard
+///
+/// Runs the AAA lint, mock-policy lint, coverage gate, guard ingress scan, and
+/// assertion-strength lint (or the `--checks` subset). Defaults come from the
+/// project's `[audit]` section in `chicago-tdd-tools.toml`.
+///
+/// Examples:
+///   playg quality audit
+///   playg quality audit --path ../orders-service --report scorecard.md
+///   playg quality audit --checks aaa,assertion-strength --min-score 90
+#[verb]
+fn audit(
+    path: Option<String>,
+    checks: Option<String>,
+    min_score: Option<f64>,
+    coverage_threshold: Option<f64>,
+    lcov: Option<String>,
+    report: Option<String>,
+) -> Result<AuditOutcome> {
+    let path = path.unwrap_or_else(|| ".".to_string());
+    let scorecard = match run_audit(&path, checks.as_deref(), min_score, coverage_threshold, lcov) {
+        Ok(scorecard) => scorecard,
+        Err(e) => {
+            return Ok(AuditOutcome {
+                success: false,
+                message: format!("Audit of {path} failed: {e}"),
+                report: None,
+                scorecard: None,
+            })
+        }
+    };
+
+    if let Some(report) = &report {
+        if let Err(e) = std::fs::write(report, scorecard.to_markdown()) {
+            println!("❌ Failed to write {report}: {e}");
+        }
+    }
+
+    let verdict = if scorecard.passed { "compliant" } else { "not compliant" };
+    Ok(AuditOutcome {
+        success: scorecard.passed,
+        message: format!("{path} scored {:.1}/100 ({verdict})", scorecard.score),
+        report,
+        scorecard: Some(scorecard),
+    })
+}
@@ -1,7 +1,8 @@
 //! System noun commands
 //!
-//! Commands for system-level operations: completions, config, version
+//! Commands for system-level operations: completions, config, version, doctor
 
+use chicago_tdd_tools::{DoctorReport, DoctorStatus};
 use clap_noun_verb::Result;
 use clap_noun_verb_macros::verb;
 use serde::Serialize;
@@ -57,6 +58,36 @@ fn config() -> Result<ConfigInfo> {
     Ok(info)
 }
 
+#[derive(Serialize, Debug)]
+pub struct DoctorOutcome {
+    pub success: bool,
+    pub message: String,
+    pub report: DoctorReport,
+}
+
+/// Check the environment end to end
+///
+/// Checks Docker, the Weaver binary and version, the semantic conventions
+/// registry, chicago-tdd-tools.toml (with CHICAGO_TDD_* overrides), port
+/// availability, and feature flag consistency, with a fix for each problem.
+///
+/// Examples:
+///   playg system doctor            # Check the current project
+///   CHICAGO_TDD_PROFILE=ci playg system doctor
+#[verb]
+fn doctor() -> Result<DoctorOutcome> {
+    let report = chicago_tdd_tools::doctor();
+    println!("{report}");
+
+    let failed = report.with_status(DoctorStatus::Fail).count();
+    let message = if failed == 0 {
+        "Environment is ready".to_string()
+    } else {
+        format!("{failed} checks failed")
+    };
+    Ok(DoctorOutcome { success: report.is_healthy(), message, report })
+}
+
 /// Generate shell completion scripts
 ///
 /// Generates completion scripts for various shells to enable tab completion
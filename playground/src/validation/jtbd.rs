@@ -160,6 +160,36 @@ pub fn example_jtbd_multiple() {
     assert!(results.iter().all(|r| r.jtbd_success));
 }
 
+/// Example: Weighted outcome scoring with a per-job report
+pub fn example_jtbd_weighted_report() {
+    // Arrange: Attach a scenario to a job with acceptance criteria and weighted outcomes
+    let mut validator = JtbdValidator::new();
+    validator.register_scored_scenario(
+        JtbdScenario {
+            name: "Checkout".to_string(),
+            setup_context: Box::new(|| ExecutionContext::default()),
+            execute: Box::new(|_ctx| {
+                let mut vars = HashMap::new();
+                vars.insert("order_id".to_string(), "ORD-001".to_string());
+                ExecutionResult::ok(vars)
+            }),
+            validate_result: Box::new(|_ctx, result| result.success),
+            expected_behavior: "Customer can buy the cart".to_string(),
+        }
+        .for_job("Buy products")
+        .acceptance("order id issued", |_ctx, r| r.variables.contains_key("order_id"))
+        .outcome("order persisted", 3.0, |_ctx, r| r.variables.contains_key("order_id"))
+        .outcome("receipt emailed", 1.0, |_ctx, r| r.variables.contains_key("receipt")),
+    );
+
+    // Act: Score every job
+    let report = validator.score_all();
+
+    // Assert: Three of four outcome weights achieved
+    assert_eq!(report.job("Buy products").unwrap().score, 75.0);
+    assert!(report.to_markdown().contains("- [ ] receipt emailed (weight 1)"));
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -183,4 +213,9 @@ mod tests {
         // Arrange-Act-Assert: Run example
         example_jtbd_multiple();
     });
+
+    test!(test_jtbd_weighted_report, {
+        // Arrange-Act-Assert: Run example
+        example_jtbd_weighted_report();
+    });
 }
@@ -15,6 +15,9 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, ItemFn};
 ///
 /// Automatically:
 /// - Detects AAA sections via AST analysis
+/// - Times each phase: statements under a `// Arrange`, `// Act`, or `// Assert` comment
+///   start that phase, so receipts, `core::receipt::current_phase_timings` and (with
+///   the `otel` feature) test traces see where the time went
 /// - Generates test metadata and tracing
 /// - Validates AAA pattern at compile time
 /// - Auto-generates test names from function names
@@ -54,28 +57,46 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, ItemFn};
 ///     assert_eq!(result, 43);
 /// }
 /// ```
+///
+/// Declare a category to enforce its configured wall-clock budget
+/// (`unit`, `integration`, or `e2e`):
+///
+/// ```rust,ignore
+/// #[tdd_test(category = integration)]
+/// fn my_integration_test() {
+///     // ...
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn tdd_test(attr: TokenStream, item: TokenStream) -> TokenStream {
     // Reject unexpected arguments early with a clear compile error.
-    if !attr.is_empty() {
-        return syn::Error::new(
-            proc_macro2::Span::call_site(),
-            "the #[tdd_test] macro does not accept arguments",
-        )
-        .to_compile_error()
-        .into();
-    }
+    // The only accepted argument is the test category.
+    let category = match parse_tdd_test_category(attr) {
+        Ok(category) => category,
+        Err(error) => return error.to_compile_error().into(),
+    };
 
     let input = parse_macro_input!(item as ItemFn);
 
     let fn_vis = &input.vis;
     let fn_sig = &input.sig;
-    let fn_block = &input.block;
+    let fn_block = mark_phases(&input.block);
     let fn_attrs = &input.attrs;
 
     // Extract function name
     let fn_name = &fn_sig.ident;
 
+    // Enforce the declared category's budget after the body returns
+    let budget_check = category.map(|category| {
+        quote! {
+            chicago_tdd_tools::core::test_category::enforce_budget(
+                concat!(module_path!(), "::", stringify!(#fn_name)),
+                chicago_tdd_tools::core::test_category::categories::#category,
+                _started.elapsed(),
+            );
+        }
+    });
+
     // Check if async
     let is_async = fn_sig.asyncness.is_some();
 
@@ -98,10 +119,17 @@ pub fn tdd_test(attr: TokenStream, item: TokenStream) -> TokenStream {
                     }
                 }
                 let mut _guard = TestGuard { name: _test_name, passed: false };
+                let _receipt = chicago_tdd_tools::core::receipt::TestRecording::start(
+                    concat!(module_path!(), "::", stringify!(#fn_name)),
+                )
+                .at(file!(), line!());
+                let _started = std::time::Instant::now();
 
                 #fn_block
 
+                #budget_check
                 _guard.passed = true;
+                _receipt.finish(true);
             }
         }
     } else {
@@ -122,10 +150,17 @@ pub fn tdd_test(attr: TokenStream, item: TokenStream) -> TokenStream {
                     }
                 }
                 let mut _guard = TestGuard { name: _test_name, passed: false };
+                let _receipt = chicago_tdd_tools::core::receipt::TestRecording::start(
+                    concat!(module_path!(), "::", stringify!(#fn_name)),
+                )
+                .at(file!(), line!());
+                let _started = std::time::Instant::now();
 
                 #fn_block
 
+                #budget_check
                 _guard.passed = true;
+                _receipt.finish(true);
             }
         }
     };
@@ -133,6 +168,83 @@ pub fn tdd_test(attr: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// Insert an `enter_phase` call before each statement under an AAA marker comment
+///
+/// Comments are not tokens, so markers are read from the block's source text and matched
+/// to statements by line. A combined marker (`// Act & Assert`) starts its first phase.
+/// Blocks without source text (e.g. generated by another macro) are left unchanged.
+fn mark_phases(block: &syn::Block) -> proc_macro2::TokenStream {
+    use syn::spanned::Spanned;
+
+    let Some(source) = block.brace_token.span.join().source_text() else {
+        return quote! { #block };
+    };
+    let lines: Vec<&str> = source.lines().collect();
+    let open_line = block.brace_token.span.open().start().line;
+    let stmts = block.stmts.iter().map(|stmt| {
+        let offset = stmt.span().start().line.saturating_sub(open_line).min(lines.len());
+        // The nearest marker in the comment lines directly above the statement
+        let phase = lines[..offset]
+            .iter()
+            .rev()
+            .map(|line| line.trim())
+            .take_while(|line| line.is_empty() || line.starts_with("//"))
+            .find_map(marker_phase);
+        match phase {
+            Some(phase) => quote! {
+                chicago_tdd_tools::core::receipt::enter_phase(
+                    chicago_tdd_tools::core::receipt::AaaPhase::#phase,
+                );
+                #stmt
+            },
+            None => quote! { #stmt },
+        }
+    });
+    quote! { { #(#stmts)* } }
+}
+
+/// Phase started by a `// Arrange`-style comment line (`// Act & Assert` starts Act)
+fn marker_phase(line: &str) -> Option<proc_macro2::Ident> {
+    let comment = line.strip_prefix("//")?;
+    let word = comment.split(|c: char| !c.is_ascii_alphabetic()).find(|w| !w.is_empty())?;
+    let phase = match word.to_ascii_lowercase().as_str() {
+        "arrange" => "Arrange",
+        "act" => "Act",
+        "assert" => "Assert",
+        _ => return None,
+    };
+    Some(proc_macro2::Ident::new(phase, proc_macro2::Span::call_site()))
+}
+
+/// Parse `#[tdd_test]` arguments: nothing, or `category = <unit|integration|e2e>`
+fn parse_tdd_test_category(attr: TokenStream) -> syn::Result<Option<syn::Ident>> {
+    if attr.is_empty() {
+        return Ok(None);
+    }
+    let arg: syn::MetaNameValue = syn::parse(attr)?;
+    if !arg.path.is_ident("category") {
+        return Err(syn::Error::new_spanned(
+            &arg.path,
+            "the #[tdd_test] macro only accepts `category = unit|integration|e2e`",
+        ));
+    }
+    let category = match &arg.value {
+        syn::Expr::Path(path) => path.path.get_ident(),
+        _ => None,
+    };
+    match category {
+        Some(category)
+            if ["unit", "integration", "e2e"].contains(&category.to_string().as_str()) =>
+        {
+            Ok(Some(category.clone()))
+        }
+        _ => Err(syn::Error::new_spanned(
+            &arg.value,
+            "unknown test category: expected `unit`, `integration`, or `e2e`",
+        )),
+    }
+}
+
 /// > 📚 Reference
 ///
 /// Procedural macro for TDD fixtures.
@@ -155,24 +267,96 @@ pub fn tdd_test(attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     assert!(counter >= 0);
 /// }
 /// ```
+///
+/// # Scopes
+///
+/// `scope = "test" | "module" | "session"` controls sharing (default `"test"`), and
+/// `setup = path::to::fn` supplies a custom `fn() -> FixtureResult<T>` instead of
+/// `TestFixture::new`. Module- and session-scoped fixtures are created once (per module or
+/// per process) via `TestFixture::shared`, bound as a `SharedFixture<T>`, and torn down at
+/// process exit.
+///
+/// ```rust,ignore
+/// fn start_postgres() -> FixtureResult<PostgresContainer> { /* ... */ }
+///
+/// #[fixture(scope = "session", setup = start_postgres)]
+/// fn test_queries() {
+///     // Every session-scoped test shares one container
+///     let url = fixture.connection_url();
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn fixture(attr: TokenStream, item: TokenStream) -> TokenStream {
     let input = parse_macro_input!(item as ItemFn);
 
     let fn_vis = &input.vis;
     let fn_sig = &input.sig;
-    let fn_block = &input.block;
+    let fn_block = mark_phases(&input.block);
     let fn_attrs = &input.attrs;
 
-    // Reject unexpected attribute arguments with a clear compile error.
-    if !attr.is_empty() {
-        return syn::Error::new(
-            proc_macro2::Span::call_site(),
-            "the #[fixture] macro does not accept arguments",
-        )
-        .to_compile_error()
-        .into();
-    }
+    // Parse `scope = "..."` and `setup = path` with clear compile errors.
+    let mut scope: Option<syn::LitStr> = None;
+    let mut setup: Option<syn::Path> = None;
+    let arg_parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("scope") {
+            scope = Some(meta.value()?.parse()?);
+            Ok(())
+        } else if meta.path.is_ident("setup") {
+            setup = Some(meta.value()?.parse()?);
+            Ok(())
+        } else {
+            Err(meta.error(
+                "unsupported #[fixture] argument; expected `scope = \"test\" | \"module\" | \"session\"` or `setup = path::to::fn`",
+            ))
+        }
+    });
+    parse_macro_input!(attr with arg_parser);
+    let scope_kind = scope.as_ref().map_or_else(|| "test".to_string(), syn::LitStr::value);
+    let init = setup.as_ref().map_or_else(
+        || quote! { chicago_tdd_tools::fixture::TestFixture::new },
+        |path| quote! { #path },
+    );
+
+    // Chicago TDD: Auto-generated fixture setup.
+    // No panic! in library code — use map_err + assert to surface a
+    // descriptive failure message without a panic! call.
+    let fixture_setup = match scope_kind.as_str() {
+        "test" => quote! {
+            let mut fixture = {
+                let _r = (#init)()
+                    .map_err(|e| format!("fixture creation failed: {}", e));
+                assert!(_r.is_ok(), "{}", match _r.as_ref() { Err(s) => s.as_str(), Ok(_) => "" });
+                match _r { Ok(f) => f, Err(_) => unreachable!() }
+            };
+        },
+        "module" | "session" => {
+            let (kind, key) = if scope_kind == "module" {
+                (quote! { Module }, quote! { concat!(module_path!(), "::", stringify!(#init)) })
+            } else {
+                (quote! { Session }, quote! { stringify!(#init) })
+            };
+            quote! {
+                let fixture = {
+                    let _r = chicago_tdd_tools::fixture::TestFixture::shared(
+                        chicago_tdd_tools::fixture::FixtureScopeKind::#kind,
+                        #key,
+                        #init,
+                    )
+                    .map_err(|e| format!("shared fixture creation failed: {}", e));
+                    assert!(_r.is_ok(), "{}", match _r.as_ref() { Err(s) => s.as_str(), Ok(_) => "" });
+                    match _r { Ok(f) => f, Err(_) => unreachable!() }
+                };
+            }
+        }
+        other => {
+            return syn::Error::new_spanned(
+                scope.as_ref(),
+                format!("unknown fixture scope \"{other}\"; expected \"test\", \"module\", or \"session\""),
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
 
     // Extract the function name ident (not the full signature).
     let fn_name = &fn_sig.ident;
@@ -220,15 +404,7 @@ pub fn fixture(attr: TokenStream, item: TokenStream) -> TokenStream {
                 }
                 let mut _guard = TestGuard { name: _test_name, passed: false };
 
-                // Chicago TDD: Auto-generated fixture setup.
-                // FIX 2: no panic! in library code — use map_err + assert to
-                // surface a descriptive failure message without a panic! call.
-                let mut fixture = {
-                    let _r = chicago_tdd_tools::fixture::TestFixture::new()
-                        .map_err(|e| format!("fixture creation failed: {}", e));
-                    assert!(_r.is_ok(), "{}", match _r.as_ref() { Err(s) => s.as_str(), Ok(_) => "" });
-                    match _r { Ok(f) => f, Err(_) => unreachable!() }
-                };
+                #fixture_setup
 
                 // Execute test body
                 #fn_block
@@ -254,15 +430,7 @@ pub fn fixture(attr: TokenStream, item: TokenStream) -> TokenStream {
                 }
                 let mut _guard = TestGuard { name: _test_name, passed: false };
 
-                // Chicago TDD: Auto-generated fixture setup.
-                // FIX 2: no panic! — use map_err + assert to surface a
-                // descriptive failure message without a panic! call.
-                let mut fixture = {
-                    let _r = chicago_tdd_tools::fixture::TestFixture::new()
-                        .map_err(|e| format!("fixture creation failed: {}", e));
-                    assert!(_r.is_ok(), "{}", match _r.as_ref() { Err(s) => s.as_str(), Ok(_) => "" });
-                    match _r { Ok(f) => f, Err(_) => unreachable!() }
-                };
+                #fixture_setup
 
                 // Execute test body
                 #fn_block
@@ -275,6 +443,86 @@ pub fn fixture(attr: TokenStream, item: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// > 📚 Reference
+///
+/// Attribute macro linking a test to a JTBD scenario.
+///
+/// Records `(job, scenario, test path)` in a link-time registry. The test itself is
+/// left unchanged, so put `#[jtbd(...)]` alongside `#[test]` (or `#[tdd_test]`).
+/// `JtbdValidator::validate_test_coverage` then fails for every registered scenario
+/// without a covering test.
+///
+/// **Required feature**: `jtbd-linkage` on `chicago-tdd-tools`
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use chicago_tdd_tools::jtbd;
+///
+/// #[jtbd(job = "Buy products", scenario = "Checkout")]
+/// #[test]
+/// fn test_checkout_issues_order_id() {
+///     // ...
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn jtbd(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemFn);
+
+    // Parse `job = "..."` and `scenario = "..."` with clear compile errors.
+    let mut job: Option<syn::LitStr> = None;
+    let mut scenario: Option<syn::LitStr> = None;
+    let arg_parser = syn::meta::parser(|meta| {
+        let slot = if meta.path.is_ident("job") {
+            &mut job
+        } else if meta.path.is_ident("scenario") {
+            &mut scenario
+        } else {
+            return Err(meta.error(
+                "unsupported #[jtbd] argument; expected `job = \"...\"` and `scenario = \"...\"`",
+            ));
+        };
+        if slot.is_some() {
+            return Err(meta.error("duplicate #[jtbd] argument"));
+        }
+        *slot = Some(meta.value()?.parse()?);
+        Ok(())
+    });
+    parse_macro_input!(attr with arg_parser);
+    let (Some(job), Some(scenario)) = (job, scenario) else {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "#[jtbd] requires both `job = \"...\"` and `scenario = \"...\"`",
+        )
+        .to_compile_error()
+        .into();
+    };
+
+    let fn_name = &input.sig.ident;
+    let link_name = syn::Ident::new(
+        &format!("__JTBD_LINK_{}", fn_name.to_string().to_uppercase()),
+        fn_name.span(),
+    );
+
+    let expanded = quote! {
+        #input
+
+        #[chicago_tdd_tools::validation::jtbd::__linkme::distributed_slice(
+            chicago_tdd_tools::validation::jtbd::JTBD_TEST_LINKS
+        )]
+        #[linkme(crate = chicago_tdd_tools::validation::jtbd::__linkme)]
+        #[doc(hidden)]
+        static #link_name: chicago_tdd_tools::validation::jtbd::JtbdTestLink =
+            chicago_tdd_tools::validation::jtbd::JtbdTestLink {
+                job: #job,
+                scenario: #scenario,
+                test: concat!(module_path!(), "::", stringify!(#fn_name)),
+            };
+    };
+
+    TokenStream::from(expanded)
+}
+
 /// > 📚 Reference
 ///
 /// Derive macro for `TestBuilder`.
@@ -422,3 +670,280 @@ pub fn test_builder_derive(input: TokenStream) -> TokenStream {
 
     TokenStream::from(expanded)
 }
+
+/// > 📚 Reference
+///
+/// Derive macro for poka-yoke typestate machines.
+///
+/// Given an enum of states (unit variants) and a `#[typestate(...)]` transition table,
+/// generates:
+/// - a zero-sized marker type per state (named after the variant)
+/// - a sealed `<Enum>Marker` trait implemented by the markers
+/// - a machine type `Machine<S>` (named with `machine = ...`, default `<Enum>Machine`)
+///   with a `new` constructor in the initial state and, for each state, only the
+///   methods of the transitions leaving it
+///
+/// Calling a transition that is not legal in the current state is a compile error.
+///
+/// Table keys:
+/// - `transitions(name: From -> To, ...)` (required): `name: A | B -> C` declares the
+///   same transition from several states
+/// - `machine = Name`: name of the machine type
+/// - `initial = State`: state `new` starts in (default: the first variant)
+/// - `data = Type`: data the machine carries through its transitions
+///
+/// # Examples
+///
+/// ```rust,ignore
+/// use chicago_tdd_tools::core::poka_yoke::Typestate;
+///
+/// #[derive(Typestate)]
+/// #[typestate(machine = Door, transitions(open: Closed -> Open, close: Open -> Closed))]
+/// pub enum DoorState {
+///     Closed,
+///     Open,
+/// }
+///
+/// let door = Door::new().open();
+/// assert_eq!(door.state(), DoorState::Open);
+/// let door = door.close();
+/// // door.close(); // error: no method named `close` found for `Door<Closed>`
+/// ```
+#[proc_macro_derive(Typestate, attributes(typestate))]
+pub fn typestate_derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_typestate(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// One row of a `#[typestate(transitions(...))]` table: `name: From | Other -> To`
+struct TypestateTransition {
+    name: syn::Ident,
+    from: Vec<syn::Ident>,
+    to: syn::Ident,
+}
+
+impl syn::parse::Parse for TypestateTransition {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        input.parse::<syn::Token![:]>()?;
+        let mut from = vec![input.parse()?];
+        while input.peek(syn::Token![|]) {
+            input.parse::<syn::Token![|]>()?;
+            from.push(input.parse()?);
+        }
+        input.parse::<syn::Token![->]>()?;
+        let to = input.parse()?;
+        Ok(Self { name, from, to })
+    }
+}
+
+/// Contents of `#[typestate(...)]`
+#[derive(Default)]
+struct TypestateArgs {
+    machine: Option<syn::Ident>,
+    initial: Option<syn::Ident>,
+    data: Option<syn::Type>,
+    transitions: Vec<TypestateTransition>,
+}
+
+impl syn::parse::Parse for TypestateArgs {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut args = Self::default();
+        while !input.is_empty() {
+            let key: syn::Ident = input.parse()?;
+            if key == "transitions" {
+                let content;
+                syn::parenthesized!(content in input);
+                args.transitions.extend(syn::punctuated::Punctuated::<
+                    TypestateTransition,
+                    syn::Token![,],
+                >::parse_terminated(&content)?);
+            } else {
+                input.parse::<syn::Token![=]>()?;
+                match key.to_string().as_str() {
+                    "machine" => args.machine = Some(input.parse()?),
+                    "initial" => args.initial = Some(input.parse()?),
+                    "data" => args.data = Some(input.parse()?),
+                    _ => {
+                        return Err(syn::Error::new(
+                            key.span(),
+                            "expected `transitions`, `machine`, `initial`, or `data`",
+                        ));
+                    }
+                }
+            }
+            if !input.is_empty() {
+                input.parse::<syn::Token![,]>()?;
+            }
+        }
+        Ok(args)
+    }
+}
+
+fn expand_typestate(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new(name.span(), "Typestate only supports enums"));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "Typestate does not support generic enums",
+        ));
+    }
+    if let Some(variant) = data.variants.iter().find(|v| !matches!(v.fields, Fields::Unit)) {
+        return Err(syn::Error::new(
+            variant.ident.span(),
+            "Typestate states must be unit variants",
+        ));
+    }
+    let states: Vec<&syn::Ident> = data.variants.iter().map(|v| &v.ident).collect();
+    let Some(first) = states.first() else {
+        return Err(syn::Error::new(name.span(), "Typestate requires at least one state"));
+    };
+
+    let attr = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("typestate"))
+        .ok_or_else(|| {
+            syn::Error::new(
+                name.span(),
+                "Typestate requires a transition table: #[typestate(transitions(name: From -> To, ...))]",
+            )
+        })?;
+    let args: TypestateArgs = attr.parse_args()?;
+    if args.transitions.is_empty() {
+        return Err(syn::Error::new_spanned(attr, "Typestate requires at least one transition"));
+    }
+    let check_state = |ident: &syn::Ident| {
+        if states.contains(&ident) {
+            Ok(())
+        } else {
+            Err(syn::Error::new(ident.span(), format!("`{ident}` is not a variant of `{name}`")))
+        }
+    };
+    for transition in &args.transitions {
+        transition.from.iter().chain([&transition.to]).try_for_each(check_state)?;
+    }
+    let initial = args.initial.as_ref().unwrap_or(first);
+    check_state(initial)?;
+
+    let machine = args.machine.clone().unwrap_or_else(|| quote::format_ident!("{name}Machine"));
+    let marker = quote::format_ident!("{name}Marker");
+    let sealed = quote::format_ident!("__{}_typestate_sealed", name.to_string().to_lowercase());
+
+    let markers: Vec<_> = states
+        .iter()
+        .map(|state| {
+            let doc = format!("`{state}` state of [`{machine}`]");
+            quote! {
+                #[doc = #doc]
+                #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+                #vis struct #state;
+
+                impl #sealed::Sealed for #state {}
+
+                impl #marker for #state {
+                    const STATE: #name = #name::#state;
+                }
+            }
+        })
+        .collect();
+
+    let (data_field, data_param, data_init, data_move, data_accessors) =
+        args.data.as_ref().map_or_else(
+            || (quote! {}, quote! {}, quote! {}, quote! {}, quote! {}),
+            |ty| {
+                (
+                    quote! { data: #ty, },
+                    quote! { data: #ty },
+                    quote! { data, },
+                    quote! { data: self.data, },
+                    quote! {
+                        /// Data carried by the machine
+                        #[must_use]
+                        pub const fn data(&self) -> &#ty {
+                            &self.data
+                        }
+
+                        /// Mutable access to the data carried by the machine
+                        pub fn data_mut(&mut self) -> &mut #ty {
+                            &mut self.data
+                        }
+
+                        /// Consume the machine, returning its data
+                        #[must_use]
+                        pub fn into_data(self) -> #ty {
+                            self.data
+                        }
+                    },
+                )
+            },
+        );
+
+    let mut transitions = Vec::new();
+    for TypestateTransition { name: method, from, to } in &args.transitions {
+        for from in from {
+            let doc = format!("Transition `{from}` → `{to}`");
+            transitions.push(quote! {
+                impl #machine<#from> {
+                    #[doc = #doc]
+                    #[must_use]
+                    pub fn #method(self) -> #machine<#to> {
+                        #machine { #data_move _state: ::core::marker::PhantomData }
+                    }
+                }
+            });
+        }
+    }
+
+    let machine_doc = format!(
+        "Typestate machine over [`{name}`]: only the transitions legal in state `S` can be called"
+    );
+    let marker_doc = format!("State marker of [`{machine}`] (sealed)");
+    let new_doc = format!("Create a machine in the initial `{initial}` state");
+
+    Ok(quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        mod #sealed {
+            pub trait Sealed {}
+        }
+
+        #[doc = #marker_doc]
+        #vis trait #marker: #sealed::Sealed {
+            /// Runtime value of this state
+            const STATE: #name;
+        }
+
+        #(#markers)*
+
+        #[doc = #machine_doc]
+        #vis struct #machine<S: #marker> {
+            #data_field
+            _state: ::core::marker::PhantomData<S>,
+        }
+
+        impl #machine<#initial> {
+            #[doc = #new_doc]
+            #[must_use]
+            pub const fn new(#data_param) -> Self {
+                Self { #data_init _state: ::core::marker::PhantomData }
+            }
+        }
+
+        impl<S: #marker> #machine<S> {
+            /// Current state
+            #[must_use]
+            pub const fn state(&self) -> #name {
+                S::STATE
+            }
+
+            #data_accessors
+        }
+
+        #(#transitions)*
+    })
+}
@@ -0,0 +1,41 @@
+//! Check theorem coverage from a harness test run
+//!
+//! Usage: `spec_check <libtest-output.txt>`
+//!
+//! Parses the `test <name> ... ok|FAILED` lines that `cargo test` printed and exits
+//! non-zero when a theorem lacks a passing test.
+
+use chatman_spec_harness::coverage::parse_libtest_output;
+use chatman_spec_harness::TheoremRegistry;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let [path] = args.as_slice() else {
+        eprintln!("usage: spec_check <libtest-output.txt>");
+        return ExitCode::from(2);
+    };
+
+    let output = match std::fs::read_to_string(path) {
+        Ok(output) => output,
+        Err(err) => {
+            eprintln!("❌ {path}: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let outcomes = parse_libtest_output(&output);
+    if outcomes.is_empty() {
+        eprintln!("❌ {path}: no test outcomes found");
+        return ExitCode::FAILURE;
+    }
+
+    let report = TheoremRegistry::new().coverage_report(&outcomes);
+    if report.is_complete() {
+        println!("✅ {report}");
+        ExitCode::SUCCESS
+    } else {
+        eprintln!("❌ {report}");
+        ExitCode::FAILURE
+    }
+}
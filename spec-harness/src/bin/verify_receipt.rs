@@ -0,0 +1,57 @@
+//! Verify a published spec conformance receipt
+//!
+//! Usage: `verify_receipt <receipt.json> [--trusted-key <hex ed25519 public key>]`
+//!
+//! Exits non-zero when the receipt fails verification.
+
+use chatman_spec_harness::receipt::SpecConformanceReceipt;
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (path, trusted_key) = match args.as_slice() {
+        [path] => (path, None),
+        [path, flag, key] if flag == "--trusted-key" => (path, Some(key)),
+        _ => {
+            eprintln!("usage: verify_receipt <receipt.json> [--trusted-key <hex public key>]");
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match trusted_key {
+        None => SpecConformanceReceipt::verify(path).map_err(Into::into),
+        Some(key) => verify_signed_by(path, key),
+    };
+
+    match result {
+        Ok(verification) => {
+            println!("✅ {path} verified");
+            println!("{verification}");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("❌ {path}: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(feature = "signing")]
+fn verify_signed_by(
+    path: &str,
+    key: &str,
+) -> Result<chatman_spec_harness::receipt::ReceiptVerification, Box<dyn std::error::Error>> {
+    let bytes: [u8; 32] = hex::decode(key)?
+        .try_into()
+        .map_err(|_| "trusted key must be 32 bytes")?;
+    let trusted = ed25519_dalek::VerifyingKey::from_bytes(&bytes)?;
+    Ok(SpecConformanceReceipt::verify_signed_by(path, &trusted)?)
+}
+
+#[cfg(not(feature = "signing"))]
+fn verify_signed_by(
+    _path: &str,
+    _key: &str,
+) -> Result<chatman_spec_harness::receipt::ReceiptVerification, Box<dyn std::error::Error>> {
+    Err("--trusted-key requires the `signing` feature".into())
+}
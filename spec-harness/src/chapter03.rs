@@ -7,6 +7,9 @@
 //! - Theorem 3.4: YAWL Pattern 3: Synchronization
 //! - Theorem 3.5: YAWL Pattern 4: Exclusive Choice
 //! - Theorem 3.6: YAWL Pattern 5: Simple Merge
+//!
+//! Pattern theorems check both the operator registry metadata and real executions of
+//! the pattern's canonical net on the [`workflow`](crate::workflow) engine.
 
 use crate::{TestResultType, TheoremMetadata};
 use chicago_tdd_tools::operator_registry::global_registry;
@@ -62,9 +65,18 @@ pub fn theorems() -> Vec<TheoremMetadata> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::workflow;
     use chicago_tdd_tools::operator_registry::GuardType;
     use proptest::prelude::*;
 
+    /// Run the theorem's pattern on the workflow engine across the receipt sweep
+    fn assert_pattern_executes(theorem_id: &str) {
+        let theorem =
+            theorems().into_iter().find(|t| t.id == theorem_id).expect("Theorem not registered");
+        let result = workflow::theorem_result(&theorem).expect("Pattern has no executable net");
+        assert!(result.passed, "{} does not hold on the workflow engine", theorem_id);
+    }
+
     /// Theorem 3.1: Knowledge Hook Atomicity
     ///
     /// Every knowledge hook must be atomic and identifiable.
@@ -92,6 +104,7 @@ mod tests {
         assert!(op.properties.deterministic, "Sequence must be deterministic");
         assert!(op.properties.bounded, "Sequence must be bounded");
         assert!(op.requires_guard(GuardType::Chronology), "Sequence requires Chronology guard");
+        assert_pattern_executes("Thm-3.2");
     }
 
     /// Theorem 3.3: YAWL Pattern 2: Parallel Split
@@ -105,6 +118,7 @@ mod tests {
         assert!(op.properties.deterministic, "Parallel Split must be deterministic");
         assert!(op.properties.type_preserving, "Parallel Split must be type preserving");
         assert!(op.requires_guard(GuardType::Legality), "Parallel Split requires Legality guard");
+        assert_pattern_executes("Thm-3.3");
     }
 
     /// Theorem 3.4: YAWL Pattern 3: Synchronization
@@ -118,6 +132,7 @@ mod tests {
         assert!(op.properties.deterministic, "Synchronization must be deterministic");
         assert!(op.properties.bounded, "Synchronization must be bounded");
         assert!(op.requires_guard(GuardType::Causality), "Synchronization requires Causality guard");
+        assert_pattern_executes("Thm-3.4");
     }
 
     /// Theorem 3.5: YAWL Pattern 4: Exclusive Choice
@@ -131,6 +146,7 @@ mod tests {
         assert!(op.properties.deterministic, "Exclusive Choice must be deterministic");
         assert!(op.properties.bounded, "Exclusive Choice must be bounded");
         assert!(op.requires_guard(GuardType::Legality), "Exclusive Choice requires Legality guard");
+        assert_pattern_executes("Thm-3.5");
     }
 
     /// Theorem 3.6: YAWL Pattern 5: Simple Merge
@@ -144,6 +160,7 @@ mod tests {
         // Simple merge is often non-deterministic in concurrent systems
         assert!(!op.properties.deterministic, "Simple Merge is non-deterministic");
         assert!(op.properties.bounded, "Simple Merge must be bounded");
+        assert_pattern_executes("Thm-3.6");
     }
 
     /// Property-based verification of registry properties
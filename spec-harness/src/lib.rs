@@ -13,6 +13,9 @@
 //! - `chapter03`: Knowledge hooks and workflow patterns (43 YAWL patterns)
 //! - `chapter07`: Chatman Equation realization via type system
 //!
+//! The `workflow` module is a small YAWL workflow engine that executes the chapter 3
+//! pattern theorems and produces their receipt hashes from real runs.
+//!
 //! Each module contains:
 //! - Theorem definitions (matching LaTeX theorem numbers)
 //! - Property-based tests proving each theorem
@@ -33,14 +36,20 @@
 //! cargo make spec              # Run all spec harness tests + generate receipt
 //! cargo make spec-check        # Verify 100% theorem coverage (CI gate)
 //! ```
+//!
+//! The coverage gate is `cargo make spec-check`, which checks the outcome of every
+//! harness test against the theorem registry (see [`coverage`]).
 
 use serde::{Deserialize, Serialize};
 
 pub mod chapter02; // Core Chatman Equation properties
 pub mod chapter03; // Knowledge hooks and YAWL patterns
 pub mod chapter07; // Chatman Equation realization
+pub mod coverage; // Theorem coverage gate
 pub mod receipt; // Receipt generation and merkle proofs
+pub mod workflow; // Executable YAWL workflow engine
 
+pub use coverage::{CoverageReport, TestOutcome};
 pub use receipt::{SpecConformanceReceipt, TheoremResult};
 
 /// Specification version this harness validates against
@@ -141,6 +150,11 @@ impl TheoremRegistry {
         all.extend(self.chapter07_theorems.iter());
         all
     }
+
+    /// Report which theorems lack a passing test among `test_outcomes`
+    pub fn coverage_report(&self, test_outcomes: &[TestOutcome]) -> CoverageReport {
+        CoverageReport::new(self, test_outcomes)
+    }
 }
 
 impl Default for TheoremRegistry {
@@ -3,11 +3,21 @@
 //! Each spec harness test run generates a cryptographically signed receipt
 //! proving that the Chicago-TDD-Tools framework correctly implements the
 //! Chatman Equation specification.
+//!
+//! Published receipts are checked with [`SpecConformanceReceipt::verify`], which
+//! recomputes the merkle root from the embedded theorem results, compares the
+//! recorded git commit with the current repository, and validates the ed25519
+//! signature when one is present (requires the `signing` feature).
 
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::fmt;
+use std::path::Path;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+/// Signature algorithm recorded in signed receipts
+pub const SIGNATURE_ALGORITHM: &str = "ed25519";
+
 /// A signed receipt proving spec conformance per SWARM_PLAN.md Section 1.3
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SpecConformanceReceipt {
@@ -40,6 +50,149 @@ pub struct SpecConformanceReceipt {
 
     /// Merkle root of all test results (SHA3-256 equivalent using SHA256)
     pub merkle_root: String,
+
+    /// Theorem results the merkle root is computed from
+    #[serde(default)]
+    pub results: Vec<TheoremResult>,
+
+    /// Signature over the receipt payload, if the receipt was signed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signature: Option<ReceiptSignature>,
+}
+
+/// Detached signature embedded in a published receipt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptSignature {
+    /// Signature algorithm (always [`SIGNATURE_ALGORITHM`])
+    pub algorithm: String,
+
+    /// Hex-encoded public key of the signer
+    pub public_key: String,
+
+    /// Hex-encoded signature over [`SpecConformanceReceipt::signing_payload`]
+    pub signature: String,
+}
+
+/// Why a receipt failed verification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReceiptError {
+    /// The receipt file could not be read
+    Io {
+        /// Path of the receipt
+        path: String,
+        /// Underlying I/O error
+        reason: String,
+    },
+    /// The receipt is not valid receipt JSON
+    Parse(String),
+    /// The receipt carries no theorem results to recompute the merkle root from
+    MissingResults,
+    /// The recorded merkle root does not match the embedded results
+    MerkleMismatch {
+        /// Root recorded in the receipt
+        recorded: String,
+        /// Root recomputed from the results
+        computed: String,
+    },
+    /// A recorded count does not match the embedded results
+    CountMismatch {
+        /// Name of the count field
+        field: &'static str,
+        /// Count recorded in the receipt
+        recorded: u32,
+        /// Count recomputed from the results
+        computed: u32,
+    },
+    /// The receipt was produced from a different commit than the current repository
+    GitMismatch {
+        /// Commit recorded in the receipt
+        receipt: String,
+        /// Commit of the current repository
+        current: String,
+    },
+    /// The embedded signature is malformed or does not match the payload
+    InvalidSignature(String),
+    /// A signature was required but the receipt is unsigned
+    Unsigned,
+    /// The receipt was signed by a key other than the trusted one
+    UntrustedSigner(String),
+}
+
+impl fmt::Display for ReceiptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, reason } => write!(f, "cannot read receipt {path}: {reason}"),
+            Self::Parse(reason) => write!(f, "invalid receipt JSON: {reason}"),
+            Self::MissingResults => {
+                write!(f, "receipt has no theorem results to recompute the merkle root from")
+            }
+            Self::MerkleMismatch { recorded, computed } => {
+                write!(f, "merkle root mismatch: receipt says {recorded}, results give {computed}")
+            }
+            Self::CountMismatch { field, recorded, computed } => {
+                write!(f, "{field} mismatch: receipt says {recorded}, results give {computed}")
+            }
+            Self::GitMismatch { receipt, current } => {
+                write!(f, "receipt was produced at commit {receipt}, repository is at {current}")
+            }
+            Self::InvalidSignature(reason) => write!(f, "invalid signature: {reason}"),
+            Self::Unsigned => write!(f, "receipt is not signed"),
+            Self::UntrustedSigner(key) => write!(f, "receipt is signed by untrusted key {key}"),
+        }
+    }
+}
+
+impl std::error::Error for ReceiptError {}
+
+/// Outcome of comparing the receipt's commit with the current repository
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GitCheck {
+    /// The receipt's commit is the current commit
+    Matches,
+    /// The current commit is unknown, so the check was skipped
+    Skipped(String),
+}
+
+/// Outcome of checking the receipt's signature
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignatureCheck {
+    /// The receipt carries no signature
+    Unsigned,
+    /// The signature is valid for the embedded public key
+    Valid {
+        /// Hex-encoded public key of the signer
+        public_key: String,
+    },
+    /// The receipt is signed but the `signing` feature is disabled
+    Unchecked,
+}
+
+/// Report from a successful receipt verification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptVerification {
+    /// Merkle root recomputed from the results
+    pub merkle_root: String,
+    /// Number of theorem results covered by the root
+    pub theorems: u32,
+    /// Git commit check outcome
+    pub git: GitCheck,
+    /// Signature check outcome
+    pub signature: SignatureCheck,
+}
+
+impl fmt::Display for ReceiptVerification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "merkle root: {} ({} theorems)", self.merkle_root, self.theorems)?;
+        match &self.git {
+            GitCheck::Matches => writeln!(f, "git commit: matches")?,
+            GitCheck::Skipped(reason) => writeln!(f, "git commit: skipped ({reason})")?,
+        }
+        match &self.signature {
+            SignatureCheck::Unsigned => write!(f, "signature: none"),
+            SignatureCheck::Valid { public_key } => write!(f, "signature: valid ({public_key})"),
+            SignatureCheck::Unchecked => write!(f, "signature: unchecked (signing feature disabled)"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -87,6 +240,8 @@ impl SpecConformanceReceipt {
             fail_count,
             coverage,
             merkle_root,
+            results,
+            signature: None,
         }
     }
 
@@ -114,6 +269,173 @@ impl SpecConformanceReceipt {
     pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json)
     }
+
+    /// Bytes covered by the receipt signature
+    ///
+    /// Every field except the signature itself; the results are covered through
+    /// the merkle root.
+    pub fn signing_payload(&self) -> Vec<u8> {
+        format!(
+            "spec_version={}\nspec_git_hash={}\ntimestamp={}\ntest_suite_version={}\n\
+             total_theorems={}\ntheorems_tested={}\npass_count={}\nfail_count={}\n\
+             coverage={}\nmerkle_root={}\n",
+            self.spec_version,
+            self.spec_git_hash,
+            self.timestamp,
+            self.test_suite_version,
+            self.total_theorems,
+            self.theorems_tested,
+            self.pass_count,
+            self.fail_count,
+            self.coverage,
+            self.merkle_root,
+        )
+        .into_bytes()
+    }
+
+    /// Sign the receipt, replacing any existing signature
+    #[cfg(feature = "signing")]
+    pub fn sign(&mut self, key: &ed25519_dalek::SigningKey) {
+        use ed25519_dalek::Signer;
+
+        let signature = key.sign(&self.signing_payload());
+        self.signature = Some(ReceiptSignature {
+            algorithm: SIGNATURE_ALGORITHM.to_string(),
+            public_key: hex::encode(key.verifying_key().as_bytes()),
+            signature: hex::encode(signature.to_bytes()),
+        });
+    }
+
+    /// Load a published receipt and verify it against the current repository
+    ///
+    /// The current commit comes from [`crate::get_git_commit`]; when it is
+    /// unavailable the git check is reported as skipped rather than failed.
+    pub fn verify(path: impl AsRef<Path>) -> Result<ReceiptVerification, ReceiptError> {
+        let receipt = Self::load(path.as_ref())?;
+        match crate::get_git_commit() {
+            Ok(commit) => receipt.check(Some(&commit)),
+            Err(reason) => receipt.check_inner(Err(reason)),
+        }
+    }
+
+    /// Like [`verify`](Self::verify), but also require a valid signature from `trusted`
+    #[cfg(feature = "signing")]
+    pub fn verify_signed_by(
+        path: impl AsRef<Path>,
+        trusted: &ed25519_dalek::VerifyingKey,
+    ) -> Result<ReceiptVerification, ReceiptError> {
+        let verification = Self::verify(path)?;
+        match &verification.signature {
+            SignatureCheck::Valid { public_key } if *public_key == hex::encode(trusted.as_bytes()) => {
+                Ok(verification)
+            }
+            SignatureCheck::Valid { public_key } => {
+                Err(ReceiptError::UntrustedSigner(public_key.clone()))
+            }
+            SignatureCheck::Unsigned | SignatureCheck::Unchecked => Err(ReceiptError::Unsigned),
+        }
+    }
+
+    /// Verify an in-memory receipt
+    ///
+    /// `current_commit` is the commit the receipt must have been produced from;
+    /// `None` skips the git check.
+    pub fn check(&self, current_commit: Option<&str>) -> Result<ReceiptVerification, ReceiptError> {
+        self.check_inner(current_commit.ok_or_else(|| "no current commit given".to_string()))
+    }
+
+    fn load(path: &Path) -> Result<Self, ReceiptError> {
+        let json = std::fs::read_to_string(path).map_err(|e| ReceiptError::Io {
+            path: path.display().to_string(),
+            reason: e.to_string(),
+        })?;
+        Self::from_json(&json).map_err(|e| ReceiptError::Parse(e.to_string()))
+    }
+
+    fn check_inner(
+        &self,
+        current_commit: Result<&str, String>,
+    ) -> Result<ReceiptVerification, ReceiptError> {
+        if self.results.is_empty() && self.theorems_tested > 0 {
+            return Err(ReceiptError::MissingResults);
+        }
+
+        let computed = Self::compute_merkle_root(&self.results);
+        if computed != self.merkle_root {
+            return Err(ReceiptError::MerkleMismatch {
+                recorded: self.merkle_root.clone(),
+                computed,
+            });
+        }
+
+        let tested = self.results.len() as u32;
+        let passed = self.results.iter().filter(|r| r.passed).count() as u32;
+        for (field, recorded, computed) in [
+            ("theorems_tested", self.theorems_tested, tested),
+            ("pass_count", self.pass_count, passed),
+            ("fail_count", self.fail_count, tested - passed),
+        ] {
+            if recorded != computed {
+                return Err(ReceiptError::CountMismatch { field, recorded, computed });
+            }
+        }
+
+        let git = match current_commit {
+            Ok(current) if current == self.spec_git_hash => GitCheck::Matches,
+            Ok(current) => {
+                return Err(ReceiptError::GitMismatch {
+                    receipt: self.spec_git_hash.clone(),
+                    current: current.to_string(),
+                })
+            }
+            Err(reason) => GitCheck::Skipped(reason),
+        };
+
+        Ok(ReceiptVerification {
+            merkle_root: computed,
+            theorems: tested,
+            git,
+            signature: self.check_signature()?,
+        })
+    }
+
+    #[cfg(feature = "signing")]
+    fn check_signature(&self) -> Result<SignatureCheck, ReceiptError> {
+        use ed25519_dalek::{Signature, VerifyingKey};
+
+        let Some(signature) = &self.signature else {
+            return Ok(SignatureCheck::Unsigned);
+        };
+        if signature.algorithm != SIGNATURE_ALGORITHM {
+            return Err(ReceiptError::InvalidSignature(format!(
+                "unsupported algorithm {}",
+                signature.algorithm
+            )));
+        }
+
+        let key_bytes: [u8; 32] = hex::decode(&signature.public_key)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| ReceiptError::InvalidSignature("malformed public key".to_string()))?;
+        let sig_bytes: [u8; 64] = hex::decode(&signature.signature)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| ReceiptError::InvalidSignature("malformed signature".to_string()))?;
+
+        let key = VerifyingKey::from_bytes(&key_bytes)
+            .map_err(|e| ReceiptError::InvalidSignature(e.to_string()))?;
+        key.verify_strict(&self.signing_payload(), &Signature::from_bytes(&sig_bytes))
+            .map_err(|_| {
+                ReceiptError::InvalidSignature("signature does not match receipt".to_string())
+            })?;
+
+        Ok(SignatureCheck::Valid { public_key: signature.public_key.clone() })
+    }
+
+    #[cfg(not(feature = "signing"))]
+    fn check_signature(&self) -> Result<SignatureCheck, ReceiptError> {
+        Ok(if self.signature.is_some() { SignatureCheck::Unchecked } else { SignatureCheck::Unsigned })
+    }
 }
 
 #[cfg(test)]
@@ -146,4 +468,147 @@ mod tests {
         assert_eq!(receipt.coverage, 10.0);
         assert!(!receipt.merkle_root.is_empty());
     }
+
+    fn sample_receipt() -> SpecConformanceReceipt {
+        let results = ["Thm-3.2", "Thm-3.3"]
+            .iter()
+            .map(|id| TheoremResult {
+                id: id.to_string(),
+                name: format!("{id} pattern"),
+                passed: true,
+                input_hash: format!("{id}-in"),
+                output_hash: format!("{id}-out"),
+            })
+            .collect();
+        SpecConformanceReceipt::new(
+            "ChatmanEquation-1.0".to_string(),
+            "abc123".to_string(),
+            "1.0.0".to_string(),
+            17,
+            results,
+        )
+    }
+
+    fn write_receipt(receipt: &SpecConformanceReceipt) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("receipt-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, receipt.to_json().unwrap()).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_check_recomputes_root_and_matches_commit() {
+        let receipt = sample_receipt();
+
+        let verification = receipt.check(Some("abc123")).unwrap();
+
+        assert_eq!(verification.merkle_root, receipt.merkle_root);
+        assert_eq!(verification.theorems, 2);
+        assert_eq!(verification.git, GitCheck::Matches);
+        assert_eq!(verification.signature, SignatureCheck::Unsigned);
+        assert!(matches!(receipt.check(None).unwrap().git, GitCheck::Skipped(_)));
+    }
+
+    #[test]
+    fn test_check_rejects_git_mismatch() {
+        let receipt = sample_receipt();
+
+        let err = receipt.check(Some("def456")).unwrap_err();
+
+        assert_eq!(
+            err,
+            ReceiptError::GitMismatch { receipt: "abc123".to_string(), current: "def456".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_verify_detects_tampered_results() {
+        let mut receipt = sample_receipt();
+        receipt.results[1].output_hash = "forged".to_string();
+        let path = write_receipt(&receipt);
+
+        let err = SpecConformanceReceipt::verify(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(err, ReceiptError::MerkleMismatch { .. }), "{err}");
+    }
+
+    #[test]
+    fn test_verify_detects_tampered_counts() {
+        let mut receipt = sample_receipt();
+        receipt.fail_count = 1;
+
+        let err = receipt.check(Some("abc123")).unwrap_err();
+
+        assert_eq!(
+            err,
+            ReceiptError::CountMismatch { field: "fail_count", recorded: 1, computed: 0 }
+        );
+    }
+
+    #[test]
+    fn test_verify_rejects_receipt_without_results() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(&sample_receipt().to_json().unwrap()).unwrap();
+        json.as_object_mut().unwrap().remove("results");
+        let receipt = SpecConformanceReceipt::from_json(&json.to_string()).unwrap();
+
+        assert_eq!(receipt.check(Some("abc123")).unwrap_err(), ReceiptError::MissingResults);
+        assert!(matches!(
+            SpecConformanceReceipt::verify("/nonexistent/receipt.json").unwrap_err(),
+            ReceiptError::Io { .. }
+        ));
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_signed_receipt_round_trips_and_detects_tampering() {
+        let key = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+        let mut receipt = sample_receipt();
+        receipt.sign(&key);
+        let signed = SpecConformanceReceipt::from_json(&receipt.to_json().unwrap()).unwrap();
+
+        let verification = signed.check(Some("abc123")).unwrap();
+        let mut tampered = signed.clone();
+        tampered.timestamp += 1;
+
+        assert_eq!(
+            verification.signature,
+            SignatureCheck::Valid { public_key: hex::encode(key.verifying_key().as_bytes()) }
+        );
+        assert!(matches!(
+            tampered.check(Some("abc123")).unwrap_err(),
+            ReceiptError::InvalidSignature(_)
+        ));
+    }
+
+    #[cfg(feature = "signing")]
+    #[test]
+    fn test_verify_signed_by_requires_trusted_key() {
+        let signer = ed25519_dalek::SigningKey::from_bytes(&[7; 32]);
+        let other = ed25519_dalek::SigningKey::from_bytes(&[9; 32]);
+        let mut receipt = sample_receipt();
+        let unsigned = write_receipt(&receipt);
+        receipt.sign(&signer);
+        let signed = write_receipt(&receipt);
+
+        let trusted = SpecConformanceReceipt::verify_signed_by(&signed, &signer.verifying_key());
+        let untrusted = SpecConformanceReceipt::verify_signed_by(&signed, &other.verifying_key());
+        let missing = SpecConformanceReceipt::verify_signed_by(&unsigned, &signer.verifying_key());
+        std::fs::remove_file(&signed).unwrap();
+        std::fs::remove_file(&unsigned).unwrap();
+
+        // The git check depends on the environment; only the signature outcome matters here
+        match trusted {
+            Ok(verification) => assert!(matches!(verification.signature, SignatureCheck::Valid { .. })),
+            Err(err) => assert!(matches!(err, ReceiptError::GitMismatch { .. }), "{err}"),
+        }
+        assert!(matches!(
+            untrusted,
+            Err(ReceiptError::UntrustedSigner(_) | ReceiptError::GitMismatch { .. })
+        ));
+        assert!(matches!(
+            missing,
+            Err(ReceiptError::Unsigned | ReceiptError::GitMismatch { .. })
+        ));
+    }
 }
@@ -0,0 +1,882 @@
+//! Executable YAWL Workflow Engine
+//!
+//! A small token-based workflow engine so the YAWL control-flow pattern theorems in
+//! chapter 3 are checked against real executions instead of operator metadata alone.
+//!
+//! A [`Workflow`] is a set of tasks connected by flows. Each task has a join type
+//! (how incoming tokens enable it) and a split type (which outgoing flows receive a
+//! token when it fires):
+//!
+//! - [`Join::Xor`]: fires once per incoming token (simple merge, multi-merge)
+//! - [`Join::And`]: waits for a token on every incoming flow (synchronization)
+//! - [`Join::Or`]: waits for every branch its paired OR-split activated
+//!   (structured synchronizing merge)
+//! - [`Split::And`]: every outgoing flow (parallel split)
+//! - [`Split::Xor`]: the first flow whose condition holds (exclusive choice)
+//! - [`Split::Or`]: every flow whose condition holds (multi-choice)
+//!
+//! Unconditional flows of XOR- and OR-splits are defaults, taken only when no condition
+//! holds. When several tasks are enabled a [`Scheduler`] picks one, so seeded runs
+//! explore different interleavings.
+//!
+//! [`patterns`] builds the canonical net for YAWL patterns 1-8, and [`check_pattern`]
+//! checks a run against the pattern's claim. [`theorem_result`] sweeps a pattern over
+//! fixed seeds and inputs and turns the outcome into a [`TheoremResult`] for the spec
+//! conformance receipt.
+
+use crate::{TheoremMetadata, TheoremResult};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Case data visible to conditions and task effects
+pub type CaseData = BTreeMap<String, i64>;
+
+/// Flow condition
+pub type Condition = Arc<dyn Fn(&CaseData) -> bool + Send + Sync>;
+
+/// Task effect on the case data
+pub type Effect = Arc<dyn Fn(&mut CaseData) + Send + Sync>;
+
+/// Source name of the implicit flow that enables the start task
+const INPUT_CONDITION: &str = "•";
+
+/// Default bound on task firings per run
+pub const DEFAULT_STEP_LIMIT: usize = 1_000;
+
+/// How incoming tokens enable a task
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Join {
+    /// Any one incoming token
+    Xor,
+    /// One token on every incoming flow
+    And,
+    /// One token from each branch activated by the named OR-split
+    Or {
+        /// Paired OR-split task
+        split: String,
+    },
+}
+
+/// Which outgoing flows receive a token when a task fires
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Split {
+    /// Every outgoing flow
+    And,
+    /// The first flow whose condition holds, else the first unconditional flow
+    Xor,
+    /// Every flow whose condition holds, else the unconditional flows
+    Or,
+}
+
+/// Chooses the next task among the enabled ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheduler {
+    /// Lowest task name first
+    Ordered,
+    /// Pseudo-random choice from a seed
+    Seeded(u64),
+}
+
+/// Workflow definition or execution error
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkflowError {
+    /// A flow, join, or endpoint names a task that does not exist
+    UnknownTask(String),
+    /// An OR-join is paired with a task that is not an OR-split
+    UnpairedOrJoin {
+        /// The OR-join
+        join: String,
+        /// The task it names as its split
+        split: String,
+    },
+    /// The run fired more tasks than the step limit
+    StepLimit(usize),
+}
+
+impl fmt::Display for WorkflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownTask(task) => write!(f, "unknown task: {task}"),
+            Self::UnpairedOrJoin { join, split } => {
+                write!(f, "OR-join {join} is paired with {split}, which is not an OR-split")
+            }
+            Self::StepLimit(limit) => write!(f, "run exceeded {limit} task firings"),
+        }
+    }
+}
+
+impl std::error::Error for WorkflowError {}
+
+#[derive(Clone)]
+struct Flow {
+    to: String,
+    condition: Option<Condition>,
+}
+
+#[derive(Clone)]
+struct Task {
+    join: Join,
+    split: Split,
+    incoming: Vec<String>,
+    outgoing: Vec<Flow>,
+    effect: Option<Effect>,
+}
+
+/// A workflow net of tasks, joins, splits, and flows
+#[derive(Clone)]
+pub struct Workflow {
+    name: String,
+    tasks: BTreeMap<String, Task>,
+    start: String,
+    end: String,
+    step_limit: usize,
+    dangling: Vec<String>,
+}
+
+impl fmt::Debug for Workflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Workflow")
+            .field("name", &self.name)
+            .field("tasks", &self.tasks.keys().collect::<Vec<_>>())
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .finish()
+    }
+}
+
+impl Workflow {
+    /// Empty workflow running from `start` to `end`
+    pub fn new(name: impl Into<String>, start: impl Into<String>, end: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            tasks: BTreeMap::new(),
+            start: start.into(),
+            end: end.into(),
+            step_limit: DEFAULT_STEP_LIMIT,
+            dangling: Vec::new(),
+        }
+    }
+
+    /// Add a task
+    pub fn task(mut self, id: impl Into<String>, join: Join, split: Split) -> Self {
+        self.tasks.insert(
+            id.into(),
+            Task { join, split, incoming: Vec::new(), outgoing: Vec::new(), effect: None },
+        );
+        self
+    }
+
+    /// Add a task that updates the case data when it fires
+    pub fn task_with(
+        self,
+        id: impl Into<String>,
+        join: Join,
+        split: Split,
+        effect: impl Fn(&mut CaseData) + Send + Sync + 'static,
+    ) -> Self {
+        let id = id.into();
+        let mut workflow = self.task(id.clone(), join, split);
+        if let Some(task) = workflow.tasks.get_mut(&id) {
+            task.effect = Some(Arc::new(effect));
+        }
+        workflow
+    }
+
+    /// Add an unconditional flow
+    pub fn flow(self, from: &str, to: &str) -> Self {
+        self.add_flow(from, to, None)
+    }
+
+    /// Add a flow taken by XOR- and OR-splits only when `condition` holds
+    pub fn flow_if(
+        self,
+        from: &str,
+        to: &str,
+        condition: impl Fn(&CaseData) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.add_flow(from, to, Some(Arc::new(condition)))
+    }
+
+    /// Bound on task firings per run
+    pub fn step_limit(mut self, limit: usize) -> Self {
+        self.step_limit = limit;
+        self
+    }
+
+    /// Workflow name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn add_flow(mut self, from: &str, to: &str, condition: Option<Condition>) -> Self {
+        match self.tasks.get_mut(from) {
+            Some(task) => task.outgoing.push(Flow { to: to.to_string(), condition }),
+            None => self.dangling.push(from.to_string()),
+        }
+        if let Some(task) = self.tasks.get_mut(to) {
+            task.incoming.push(from.to_string());
+        }
+        self
+    }
+
+    /// Check every referenced task exists and OR-joins are paired with OR-splits
+    pub fn validate(&self) -> Result<(), WorkflowError> {
+        if let Some(task) = self.dangling.first() {
+            return Err(WorkflowError::UnknownTask(task.clone()));
+        }
+        for endpoint in [&self.start, &self.end] {
+            if !self.tasks.contains_key(endpoint) {
+                return Err(WorkflowError::UnknownTask(endpoint.clone()));
+            }
+        }
+        for (id, task) in &self.tasks {
+            if let Some(flow) = task.outgoing.iter().find(|f| !self.tasks.contains_key(&f.to)) {
+                return Err(WorkflowError::UnknownTask(flow.to.clone()));
+            }
+            if let Join::Or { split } = &task.join {
+                if self.tasks.get(split).map(|s| s.split) != Some(Split::Or) {
+                    return Err(WorkflowError::UnpairedOrJoin {
+                        join: id.clone(),
+                        split: split.clone(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Stable text form of the net, used for receipt input hashes
+    pub fn describe(&self) -> String {
+        let mut out = format!("workflow {} ({} -> {})\n", self.name, self.start, self.end);
+        for (id, task) in &self.tasks {
+            let flows: Vec<String> = task
+                .outgoing
+                .iter()
+                .map(|f| format!("{}{}", f.to, if f.condition.is_some() { "?" } else { "" }))
+                .collect();
+            out.push_str(&format!(
+                "{id}: join={:?} split={:?} -> [{}]\n",
+                task.join,
+                task.split,
+                flows.join(", ")
+            ));
+        }
+        out
+    }
+
+    /// Run one case to completion
+    ///
+    /// The run stops when no task is enabled. Fails if the net is invalid or the step
+    /// limit is exceeded.
+    pub fn run(&self, data: CaseData, scheduler: Scheduler) -> Result<WorkflowRun, WorkflowError> {
+        self.validate()?;
+        let mut tokens: BTreeMap<(String, String), u32> = BTreeMap::new();
+        tokens.insert((INPUT_CONDITION.to_string(), self.start.clone()), 1);
+        let mut activated: BTreeMap<String, u32> = BTreeMap::new();
+        let mut rng = match scheduler {
+            Scheduler::Ordered => None,
+            Scheduler::Seeded(seed) => Some(SplitMix64(seed)),
+        };
+        let mut run =
+            WorkflowRun { fired: Vec::new(), data, tokens_left: 0, end: self.end.clone() };
+
+        loop {
+            let enabled: Vec<&String> = self
+                .tasks
+                .iter()
+                .filter(|(id, task)| self.is_enabled(id, task, &tokens, &activated))
+                .map(|(id, _)| id)
+                .collect();
+            let Some(&id) = (match rng.as_mut() {
+                None => enabled.first(),
+                Some(rng) if !enabled.is_empty() => {
+                    enabled.get((rng.next() % enabled.len() as u64) as usize)
+                }
+                Some(_) => None,
+            }) else {
+                break;
+            };
+            if run.fired.len() >= self.step_limit {
+                return Err(WorkflowError::StepLimit(self.step_limit));
+            }
+            let task = &self.tasks[id];
+            self.consume(id, task, &mut tokens, &mut activated);
+            if let Some(effect) = &task.effect {
+                effect(&mut run.data);
+            }
+            run.fired.push(id.clone());
+            let targets = Self::route(task, &run.data);
+            if task.split == Split::Or {
+                activated.insert(id.clone(), targets.len() as u32);
+            }
+            for to in targets {
+                *tokens.entry((id.clone(), to)).or_default() += 1;
+            }
+        }
+        run.tokens_left = tokens.values().sum::<u32>() as usize;
+        Ok(run)
+    }
+
+    fn incoming<'a>(&'a self, id: &'a str, task: &'a Task) -> Vec<&'a str> {
+        let mut incoming: Vec<&str> = task.incoming.iter().map(String::as_str).collect();
+        if id == self.start {
+            incoming.push(INPUT_CONDITION);
+        }
+        incoming
+    }
+
+    fn is_enabled(
+        &self,
+        id: &str,
+        task: &Task,
+        tokens: &BTreeMap<(String, String), u32>,
+        activated: &BTreeMap<String, u32>,
+    ) -> bool {
+        let count = |from: &str| {
+            tokens.get(&(from.to_string(), id.to_string())).copied().unwrap_or_default()
+        };
+        let incoming = self.incoming(id, task);
+        match &task.join {
+            Join::Xor => incoming.iter().any(|from| count(from) > 0),
+            Join::And => !incoming.is_empty() && incoming.iter().all(|from| count(from) > 0),
+            Join::Or { split } => {
+                let waiting: u32 = incoming.iter().map(|from| count(from)).sum();
+                activated
+                    .get(split)
+                    .is_some_and(|&expected| expected > 0 && waiting >= expected)
+            }
+        }
+    }
+
+    fn consume(
+        &self,
+        id: &str,
+        task: &Task,
+        tokens: &mut BTreeMap<(String, String), u32>,
+        activated: &mut BTreeMap<String, u32>,
+    ) {
+        let mut take = |from: &str| {
+            let key = (from.to_string(), id.to_string());
+            match tokens.get_mut(&key) {
+                Some(n) if *n > 1 => {
+                    *n -= 1;
+                    true
+                }
+                Some(_) => tokens.remove(&key).is_some(),
+                None => false,
+            }
+        };
+        let incoming = self.incoming(id, task);
+        match &task.join {
+            Join::Xor => {
+                let _ = incoming.iter().any(|from| take(from));
+            }
+            Join::And => incoming.iter().for_each(|from| {
+                take(from);
+            }),
+            Join::Or { split } => {
+                let mut remaining = activated.remove(split).unwrap_or_default();
+                for from in incoming {
+                    while remaining > 0 && take(from) {
+                        remaining -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    fn route(task: &Task, data: &CaseData) -> Vec<String> {
+        let holds = |flow: &&Flow| flow.condition.as_ref().is_some_and(|c| c(data));
+        let defaults = || task.outgoing.iter().filter(|f| f.condition.is_none());
+        let chosen: Vec<&Flow> = match task.split {
+            Split::And => task.outgoing.iter().collect(),
+            Split::Xor => task
+                .outgoing
+                .iter()
+                .find(holds)
+                .or_else(|| defaults().next())
+                .into_iter()
+                .collect(),
+            Split::Or => {
+                let taken: Vec<&Flow> = task.outgoing.iter().filter(holds).collect();
+                if taken.is_empty() {
+                    defaults().collect()
+                } else {
+                    taken
+                }
+            }
+        };
+        chosen.into_iter().map(|f| f.to.clone()).collect()
+    }
+}
+
+/// Outcome of running one case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkflowRun {
+    /// Tasks in firing order
+    pub fired: Vec<String>,
+    /// Case data after the run
+    pub data: CaseData,
+    /// Tokens left on flows when no task was enabled
+    pub tokens_left: usize,
+    end: String,
+}
+
+impl WorkflowRun {
+    /// How many times `task` fired
+    pub fn fire_count(&self, task: &str) -> usize {
+        self.fired.iter().filter(|t| *t == task).count()
+    }
+
+    /// Index of the first firing of `task`
+    pub fn position(&self, task: &str) -> Option<usize> {
+        self.fired.iter().position(|t| t == task)
+    }
+
+    /// Whether the end task fired and no tokens were left behind
+    pub fn completed(&self) -> bool {
+        self.fire_count(&self.end) > 0 && self.tokens_left == 0
+    }
+
+    /// Stable text form of the run, used for receipt output hashes
+    pub fn describe(&self) -> String {
+        format!("fired={:?} data={:?} tokens_left={}", self.fired, self.data, self.tokens_left)
+    }
+}
+
+/// SplitMix64: tiny deterministic PRNG for seeded scheduling
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// Canonical nets for YAWL control-flow patterns 1-8
+///
+/// Every net starts at `A` and ends at `End`; case data key `x` drives the conditions.
+pub mod patterns {
+    use super::{Join, Split, Workflow};
+
+    /// Pattern numbers with an executable net, with their YAWL names
+    pub const SUPPORTED: [(u8, &str); 8] = [
+        (1, "Sequence"),
+        (2, "Parallel Split"),
+        (3, "Synchronization"),
+        (4, "Exclusive Choice"),
+        (5, "Simple Merge"),
+        (6, "Multi-Choice"),
+        (7, "Structured Synchronizing Merge"),
+        (8, "Multi-Merge"),
+    ];
+
+    /// Canonical net for YAWL pattern `number`
+    pub fn pattern(number: u8) -> Option<Workflow> {
+        Some(match number {
+            1 => sequence(),
+            2 => parallel_split(),
+            3 => synchronization(),
+            4 => exclusive_choice(),
+            5 => simple_merge(),
+            6 => multi_choice(),
+            7 => structured_synchronizing_merge(),
+            8 => multi_merge(),
+            _ => return None,
+        })
+    }
+
+    /// WCP-1: `A -> B -> End`
+    pub fn sequence() -> Workflow {
+        Workflow::new("WCP-1 Sequence", "A", "End")
+            .task_with("A", Join::Xor, Split::And, |d| *d.entry("x".into()).or_default() += 1)
+            .task_with("B", Join::Xor, Split::And, |d| *d.entry("x".into()).or_default() *= 2)
+            .task("End", Join::Xor, Split::And)
+            .flow("A", "B")
+            .flow("B", "End")
+    }
+
+    /// WCP-2: `A` AND-splits into `B` and `C`, which reach `End` independently
+    pub fn parallel_split() -> Workflow {
+        Workflow::new("WCP-2 Parallel Split", "A", "End")
+            .task("A", Join::Xor, Split::And)
+            .task_with("B", Join::Xor, Split::And, |d| {
+                let x = d.get("x").copied().unwrap_or_default();
+                d.insert("b".into(), x * 2);
+            })
+            .task_with("C", Join::Xor, Split::And, |d| {
+                let x = d.get("x").copied().unwrap_or_default();
+                d.insert("c".into(), x + 1);
+            })
+            .task("End", Join::Xor, Split::And)
+            .flow("A", "B")
+            .flow("A", "C")
+            .flow("B", "End")
+            .flow("C", "End")
+    }
+
+    /// WCP-3: `B` and `C` run in parallel and AND-join into `End`
+    pub fn synchronization() -> Workflow {
+        Workflow::new("WCP-3 Synchronization", "A", "End")
+            .task("A", Join::Xor, Split::And)
+            .task("B", Join::Xor, Split::And)
+            .task("C", Join::Xor, Split::And)
+            .task("End", Join::And, Split::And)
+            .flow("A", "B")
+            .flow("A", "C")
+            .flow("B", "End")
+            .flow("C", "End")
+    }
+
+    /// WCP-4: `A` XOR-splits to `B` when `x >= 0`, else to `C`
+    pub fn exclusive_choice() -> Workflow {
+        Workflow::new("WCP-4 Exclusive Choice", "A", "End")
+            .task("A", Join::Xor, Split::Xor)
+            .task("B", Join::Xor, Split::And)
+            .task("C", Join::Xor, Split::And)
+            .task("End", Join::Xor, Split::And)
+            .flow_if("A", "B", |d| d.get("x").copied().unwrap_or_default() >= 0)
+            .flow("A", "C")
+            .flow("B", "End")
+            .flow("C", "End")
+    }
+
+    /// WCP-5: the exclusive branches `B` and `C` merge into `D` without synchronizing
+    pub fn simple_merge() -> Workflow {
+        Workflow::new("WCP-5 Simple Merge", "A", "End")
+            .task("A", Join::Xor, Split::Xor)
+            .task("B", Join::Xor, Split::And)
+            .task("C", Join::Xor, Split::And)
+            .task("D", Join::Xor, Split::And)
+            .task("End", Join::Xor, Split::And)
+            .flow_if("A", "B", |d| d.get("x").copied().unwrap_or_default() % 2 == 0)
+            .flow("A", "C")
+            .flow("B", "D")
+            .flow("C", "D")
+            .flow("D", "End")
+    }
+
+    /// WCP-6: `A` OR-splits to `B` when `x` is even and `C` when `x % 3 == 0`,
+    /// defaulting to `E`
+    pub fn multi_choice() -> Workflow {
+        Workflow::new("WCP-6 Multi-Choice", "A", "End")
+            .task("A", Join::Xor, Split::Or)
+            .task("B", Join::Xor, Split::And)
+            .task("C", Join::Xor, Split::And)
+            .task("E", Join::Xor, Split::And)
+            .task("End", Join::Xor, Split::And)
+            .flow_if("A", "B", |d| d.get("x").copied().unwrap_or_default() % 2 == 0)
+            .flow_if("A", "C", |d| d.get("x").copied().unwrap_or_default() % 3 == 0)
+            .flow("A", "E")
+            .flow("B", "End")
+            .flow("C", "End")
+            .flow("E", "End")
+    }
+
+    /// WCP-7: the multi-choice branches OR-join into `End`, which fires once
+    pub fn structured_synchronizing_merge() -> Workflow {
+        Workflow::new("WCP-7 Structured Synchronizing Merge", "A", "End")
+            .task("A", Join::Xor, Split::Or)
+            .task("B", Join::Xor, Split::And)
+            .task("C", Join::Xor, Split::And)
+            .task("E", Join::Xor, Split::And)
+            .task("End", Join::Or { split: "A".into() }, Split::And)
+            .flow_if("A", "B", |d| d.get("x").copied().unwrap_or_default() % 2 == 0)
+            .flow_if("A", "C", |d| d.get("x").copied().unwrap_or_default() % 3 == 0)
+            .flow("A", "E")
+            .flow("B", "End")
+            .flow("C", "End")
+            .flow("E", "End")
+    }
+
+    /// WCP-8: parallel branches `B` and `C` both flow into `D`, which fires for each
+    pub fn multi_merge() -> Workflow {
+        Workflow::new("WCP-8 Multi-Merge", "A", "End")
+            .task("A", Join::Xor, Split::And)
+            .task("B", Join::Xor, Split::And)
+            .task("C", Join::Xor, Split::And)
+            .task_with("D", Join::Xor, Split::And, |d| *d.entry("merged".into()).or_default() += 1)
+            .task("End", Join::Xor, Split::And)
+            .flow("A", "B")
+            .flow("A", "C")
+            .flow("B", "D")
+            .flow("C", "D")
+            .flow("D", "End")
+    }
+}
+
+/// Run the canonical net for `pattern` and check the pattern's claim
+///
+/// Returns the run on success, or a description of the violated claim.
+pub fn check_pattern(pattern: u8, x: i64, scheduler: Scheduler) -> Result<WorkflowRun, String> {
+    let workflow = patterns::pattern(pattern).ok_or(format!("unsupported pattern {pattern}"))?;
+    let data = CaseData::from([("x".to_string(), x)]);
+    let run = workflow.run(data, scheduler).map_err(|e| e.to_string())?;
+    let ensure = |ok: bool, claim: &str| {
+        if ok {
+            Ok(())
+        } else {
+            Err(format!("WCP-{pattern} x={x} {scheduler:?}: {claim}; run: {}", run.describe()))
+        }
+    };
+    let once = |task: &str| run.fire_count(task) == 1;
+    let before = |a: &str, b: &str| run.position(a) < run.position(b);
+    match pattern {
+        1 => {
+            ensure(run.fired == ["A", "B", "End"], "tasks fire in sequence order")?;
+            ensure(run.data.get("x") == Some(&((x + 1) * 2)), "effects apply in order")?;
+        }
+        2 => {
+            ensure(once("B") && once("C"), "every branch fires exactly once")?;
+            ensure(before("A", "B") && before("A", "C"), "branches start after the split")?;
+            ensure(
+                run.data.get("b") == Some(&(x * 2)) && run.data.get("c") == Some(&(x + 1)),
+                "branch effects are preserved regardless of interleaving",
+            )?;
+        }
+        3 => {
+            ensure(once("End"), "the join fires exactly once")?;
+            ensure(before("B", "End") && before("C", "End"), "the join waits for every branch")?;
+            ensure(run.fired.len() == 4, "synchronization is bounded to one firing per task")?;
+        }
+        4 => {
+            let (taken, skipped) = if x >= 0 { ("B", "C") } else { ("C", "B") };
+            ensure(once(taken), "the branch whose condition holds fires")?;
+            ensure(run.fire_count(skipped) == 0, "exactly one branch fires")?;
+        }
+        5 => {
+            ensure(run.fire_count("B") + run.fire_count("C") == 1, "one branch is active")?;
+            ensure(once("D"), "the merge fires once for the active branch")?;
+        }
+        6 => {
+            let expected: Vec<&str> = match (x % 2 == 0, x % 3 == 0) {
+                (false, false) => vec!["E"],
+                (even, triple) => [(even, "B"), (triple, "C")]
+                    .iter()
+                    .filter(|(on, _)| *on)
+                    .map(|(_, t)| *t)
+                    .collect(),
+            };
+            for branch in ["B", "C", "E"] {
+                let fired = run.fire_count(branch) == 1;
+                ensure(fired == expected.contains(&branch), "exactly the true branches fire")?;
+            }
+        }
+        7 => {
+            ensure(once("End"), "the merge fires once")?;
+            let last_branch = ["B", "C", "E"].iter().filter_map(|b| run.position(b)).max();
+            ensure(last_branch < run.position("End"), "the merge waits for active branches")?;
+        }
+        8 => {
+            ensure(run.fire_count("D") == 2, "the merge fires once per incoming branch")?;
+            ensure(run.data.get("merged") == Some(&2), "every merge firing is observed")?;
+        }
+        _ => return Err(format!("unsupported pattern {pattern}")),
+    }
+    ensure(run.completed(), "the case completes with no tokens left behind")?;
+    Ok(run)
+}
+
+/// Pattern exercised by each chapter 3 YAWL theorem
+pub fn pattern_for_theorem(theorem_id: &str) -> Option<u8> {
+    match theorem_id {
+        "Thm-3.2" => Some(1),
+        "Thm-3.3" => Some(2),
+        "Thm-3.4" => Some(3),
+        "Thm-3.5" => Some(4),
+        "Thm-3.6" => Some(5),
+        _ => None,
+    }
+}
+
+/// Inputs swept when verifying a theorem for its receipt
+const SWEEP_INPUTS: [i64; 7] = [-7, -2, -1, 0, 1, 6, 9];
+
+/// Seeds swept when verifying a theorem for its receipt
+const SWEEP_SEEDS: u64 = 32;
+
+/// Verify `theorem` by sweeping its pattern over fixed inputs and schedules
+///
+/// The input hash covers the net and the sweep; the output hash covers every run, so
+/// a change in engine behaviour changes the receipt. Returns `None` for theorems
+/// without an executable pattern.
+pub fn theorem_result(theorem: &TheoremMetadata) -> Option<TheoremResult> {
+    let pattern = pattern_for_theorem(&theorem.id)?;
+    let workflow = patterns::pattern(pattern)?;
+    let mut input = Sha256::new();
+    input.update(workflow.describe().as_bytes());
+    input.update(format!("inputs={SWEEP_INPUTS:?} seeds={SWEEP_SEEDS}").as_bytes());
+    let mut output = Sha256::new();
+    let mut passed = true;
+    for x in SWEEP_INPUTS {
+        let schedulers =
+            std::iter::once(Scheduler::Ordered).chain((0..SWEEP_SEEDS).map(Scheduler::Seeded));
+        for scheduler in schedulers {
+            match check_pattern(pattern, x, scheduler) {
+                Ok(run) => output.update(run.describe().as_bytes()),
+                Err(violation) => {
+                    passed = false;
+                    output.update(violation.as_bytes());
+                }
+            }
+        }
+    }
+    Some(TheoremResult {
+        id: theorem.id.clone(),
+        name: theorem.name.clone(),
+        passed,
+        input_hash: hex::encode(input.finalize()),
+        output_hash: hex::encode(output.finalize()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{chapter03, SpecConformanceReceipt};
+    use proptest::prelude::*;
+    use std::collections::BTreeSet;
+
+    fn assert_pattern_holds(pattern: u8) {
+        proptest!(|(x in -1_000i64..1_000, seed in any::<u64>())| {
+            let checked = check_pattern(pattern, x, Scheduler::Seeded(seed));
+            prop_assert!(checked.is_ok(), "{}", checked.unwrap_err());
+        });
+    }
+
+    #[test]
+    fn test_pattern_1_sequence_property() {
+        assert_pattern_holds(1);
+    }
+
+    #[test]
+    fn test_pattern_2_parallel_split_property() {
+        assert_pattern_holds(2);
+    }
+
+    #[test]
+    fn test_pattern_3_synchronization_property() {
+        assert_pattern_holds(3);
+    }
+
+    #[test]
+    fn test_pattern_4_exclusive_choice_property() {
+        assert_pattern_holds(4);
+    }
+
+    #[test]
+    fn test_pattern_5_simple_merge_property() {
+        assert_pattern_holds(5);
+    }
+
+    #[test]
+    fn test_pattern_6_multi_choice_property() {
+        assert_pattern_holds(6);
+    }
+
+    #[test]
+    fn test_pattern_7_structured_synchronizing_merge_property() {
+        assert_pattern_holds(7);
+    }
+
+    #[test]
+    fn test_pattern_8_multi_merge_property() {
+        assert_pattern_holds(8);
+    }
+
+    #[test]
+    fn test_sequence_is_deterministic_across_schedules() {
+        let runs: BTreeSet<Vec<String>> = (0..64)
+            .map(|seed| check_pattern(1, 3, Scheduler::Seeded(seed)).expect("sequence holds").fired)
+            .collect();
+
+        assert_eq!(runs.len(), 1, "sequence must not depend on scheduling");
+    }
+
+    #[test]
+    fn test_seeded_schedules_interleave_parallel_branches() {
+        let orders: BTreeSet<Vec<String>> = (0..64)
+            .map(|seed| {
+                check_pattern(8, 0, Scheduler::Seeded(seed)).expect("multi-merge holds").fired
+            })
+            .collect();
+
+        assert!(orders.len() > 1, "parallel branches should interleave differently: {orders:?}");
+    }
+
+    #[test]
+    fn test_and_join_without_all_branches_leaves_tokens() {
+        // The AND-join after an exclusive choice can never fire: a classic deadlock
+        let workflow = Workflow::new("deadlock", "A", "End")
+            .task("A", Join::Xor, Split::Xor)
+            .task("B", Join::Xor, Split::And)
+            .task("C", Join::Xor, Split::And)
+            .task("End", Join::And, Split::And)
+            .flow("A", "B")
+            .flow("A", "C")
+            .flow("B", "End")
+            .flow("C", "End");
+
+        let run = workflow.run(CaseData::new(), Scheduler::Ordered).expect("valid net");
+
+        assert_eq!(run.fired, ["A", "B"]);
+        assert_eq!(run.tokens_left, 1);
+        assert!(!run.completed());
+    }
+
+    #[test]
+    fn test_invalid_nets_and_unbounded_runs_are_rejected() {
+        let unknown = Workflow::new("unknown", "A", "End")
+            .task("A", Join::Xor, Split::And)
+            .task("End", Join::Xor, Split::And)
+            .flow("A", "Missing");
+        let unpaired = Workflow::new("unpaired", "A", "End")
+            .task("A", Join::Xor, Split::And)
+            .task("End", Join::Or { split: "A".into() }, Split::And)
+            .flow("A", "End");
+        let looping = Workflow::new("loop", "A", "End")
+            .task("A", Join::Xor, Split::And)
+            .task("End", Join::Xor, Split::And)
+            .flow("A", "A")
+            .step_limit(10);
+
+        assert_eq!(unknown.validate(), Err(WorkflowError::UnknownTask("Missing".into())));
+        assert!(matches!(unpaired.validate(), Err(WorkflowError::UnpairedOrJoin { .. })));
+        assert_eq!(
+            looping.run(CaseData::new(), Scheduler::Ordered),
+            Err(WorkflowError::StepLimit(10))
+        );
+    }
+
+    #[test]
+    fn test_yawl_theorems_generate_receipts() {
+        let theorems = chapter03::theorems();
+
+        let results: Vec<TheoremResult> = theorems.iter().filter_map(theorem_result).collect();
+        let again: Vec<TheoremResult> = theorems.iter().filter_map(theorem_result).collect();
+
+        assert_eq!(results.len(), 5, "every YAWL pattern theorem has an executable check");
+        assert!(results.iter().all(|r| r.passed), "{results:?}");
+        let hashes = |rs: &[TheoremResult]| {
+            rs.iter()
+                .map(|r| (r.input_hash.clone(), r.output_hash.clone()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(hashes(&results), hashes(&again), "receipts must be reproducible");
+        let receipt = SpecConformanceReceipt::new(
+            crate::SPEC_VERSION.to_string(),
+            "test".to_string(),
+            crate::HARNESS_VERSION.to_string(),
+            theorems.len() as u32,
+            results,
+        );
+        assert_eq!(receipt.pass_count, 5);
+        assert_eq!(receipt.fail_count, 0);
+    }
+}
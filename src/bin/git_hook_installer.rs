@@ -24,9 +24,13 @@ fn main() {
 
 #[cfg(feature = "git-hooks")]
 fn main() -> Result<(), Box<dyn std::error::Error>> {
+    use chicago_tdd_tools::core::command::CheckedCommand;
     use git2::Repository;
     use std::fs;
-    use std::process::Command;
+    use std::time::Duration;
+
+    // Release builds of the hook binaries can take several minutes on a cold cache
+    const HOOK_BUILD_TIMEOUT: Duration = Duration::from_secs(15 * 60);
 
     // Get project root
     let repo = Repository::open(".")?;
@@ -44,7 +48,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Build hook binaries first
     println!("   Building hook binaries...");
-    let build_output = Command::new("cargo")
+    let build_result = CheckedCommand::new("cargo")
         .arg("build")
         .arg("--release")
         .arg("--features")
@@ -54,11 +58,12 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         .arg("--bin")
         .arg("git_hook_pre_push")
         .current_dir(&project_root)
-        .output()?;
+        .timeout(HOOK_BUILD_TIMEOUT)
+        .run();
 
-    if !build_output.status.success() {
+    if let Err(e) = build_result {
         eprintln!("❌ ERROR: Failed to build hook binaries");
-        eprintln!("{}", String::from_utf8_lossy(&build_output.stderr));
+        eprintln!("{e}");
         std::process::exit(1);
     }
 
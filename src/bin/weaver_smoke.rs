@@ -29,11 +29,9 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     };
     use chicago_tdd_tools::observability::weaver::{DEFAULT_OTLP_GRPC_PORT, LOCALHOST};
     use std::path::PathBuf;
-    use std::thread::sleep;
-    use std::time::Duration;
 
+    use chicago_tdd_tools::core::command::{CheckedCommand, CommandError, PROBE_COMMAND_TIMEOUT};
     use chicago_tdd_tools::observability::weaver::types::WeaverLiveCheck;
-    use std::process::Command;
 
     let registry_path = PathBuf::from("registry");
     if !registry_path.exists() {
@@ -46,18 +44,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let weaver_binary = WeaverLiveCheck::find_weaver_binary().ok_or_else(|| {
         Box::new(WeaverValidationError::BinaryNotFound) as Box<dyn std::error::Error>
     })?;
-    let version_output = Command::new(&weaver_binary).arg("--version").output().map_err(|e| {
-        Box::new(WeaverValidationError::ProcessStartFailed(format!(
-            "Failed to execute {} --version: {e}",
-            weaver_binary.display()
-        ))) as Box<dyn std::error::Error>
-    })?;
-    if !version_output.status.success() {
-        return Err(Box::new(WeaverValidationError::ProcessStartFailed(format!(
-            "Weaver --version exited with status {}",
-            version_output.status
-        ))));
-    }
+    CheckedCommand::new(&weaver_binary)
+        .arg("--version")
+        .timeout(PROBE_COMMAND_TIMEOUT)
+        .run()
+        .map_err(|e| {
+            let message = match e {
+                CommandError::Failed { status, .. } => {
+                    format!("Weaver --version exited with status {status}")
+                }
+                other => {
+                    format!("Failed to execute {} --version: {other}", weaver_binary.display())
+                }
+            };
+            Box::new(WeaverValidationError::ProcessStartFailed(message))
+                as Box<dyn std::error::Error>
+        })?;
 
     let mut validator = WeaverValidator::new(registry_path);
     validator.start()?;
@@ -65,9 +67,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let endpoint = format!("http://{LOCALHOST}:{DEFAULT_OTLP_GRPC_PORT}/v1/traces");
     send_test_span_to_weaver(&endpoint, "weaver_smoke_span")?;
 
-    // Give Weaver a moment to process incoming telemetry before shutdown
-    sleep(Duration::from_millis(500));
-
+    // send_test_span_to_weaver flushes before returning; stop() drains Weaver
     validator.stop()?;
 
     println!("Weaver smoke validation succeeded");
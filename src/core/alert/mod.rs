@@ -56,7 +56,24 @@
 //! (`alert_critical!`, `alert_warning!`, etc.) automatically use `log::error!`, `log::warn!`, etc.
 //! instead of `eprintln!`. This means you can use either the alert macros or standard log macros,
 //! and both will use the same alert format (if `AlertLogger` is initialized).
+//!
+//! ## Output Rendering
+//!
+//! Alert text is produced by the installed
+//! [`FailureRenderer`](crate::core::render::FailureRenderer), which honors `NO_COLOR`, the terminal width, and an ASCII-only mode
+//! (`CHICAGO_TDD_ASCII=1`). See [`crate::core::render`].
+//!
+//! ## Sinks
+//!
+//! Alerts are also fanned out to every sink registered with [`sink::register_sink`]:
+//! a JSON lines file, an in-memory capture for asserting that code emitted an alert,
+//! or (with the `alert-webhook` feature) an HTTP webhook. See [`sink`].
+
+pub mod sink;
 
+use crate::core::render::AlertText;
+#[cfg(feature = "logging")]
+use crate::core::render::{AlertLevel, Glyph};
 use std::io::{self, Write};
 
 /// Emit a critical alert (🚨)
@@ -79,47 +96,18 @@ use std::io::{self, Write};
 #[macro_export]
 macro_rules! alert_critical {
     ($message:expr) => {
-        #[cfg(feature = "logging")]
-        {
-            log::error!("{}\n   ⚠️  STOP: Cannot proceed\n   💡 FIX: Investigate and resolve", $message);
-        }
-        #[cfg(not(feature = "logging"))]
-        {
-            eprintln!(
-                "🚨 {}\n   ⚠️  STOP: Cannot proceed\n   💡 FIX: Investigate and resolve",
-                $message
-            );
-        }
+        $crate::alert_critical!($message, "Investigate and resolve")
     };
     ($message:expr, $fix:expr) => {
-        #[cfg(feature = "logging")]
-        {
-            log::error!("{}\n   ⚠️  STOP: Cannot proceed\n   💡 FIX: {}", $message, $fix);
-        }
-        #[cfg(not(feature = "logging"))]
-        {
-            eprintln!(
-                "🚨 {}\n   ⚠️  STOP: Cannot proceed\n   💡 FIX: {}",
-                $message, $fix
-            );
-        }
+        $crate::__emit_alert!(error, Critical, $message;
+            .stop("STOP: Cannot proceed")
+            .fix(($fix).to_string()))
     };
     ($message:expr, $fix:expr, $($action:expr),+) => {
-        {
-            let actions: Vec<String> = vec![$($action.to_string()),+];
-            let action_str = actions.join("\n   📋 ");
-            #[cfg(feature = "logging")]
-            {
-                log::error!("{}\n   ⚠️  STOP: Cannot proceed\n   💡 FIX: {}\n   📋 {}", $message, $fix, action_str);
-            }
-            #[cfg(not(feature = "logging"))]
-            {
-                eprintln!(
-                    "🚨 {}\n   ⚠️  STOP: Cannot proceed\n   💡 FIX: {}\n   📋 {}",
-                    $message, $fix, action_str
-                );
-            }
-        }
+        $crate::__emit_alert!(error, Critical, $message;
+            .stop("STOP: Cannot proceed")
+            .fix(($fix).to_string())
+            .actions([$($action.to_string()),+]))
     };
 }
 
@@ -143,47 +131,18 @@ macro_rules! alert_critical {
 #[macro_export]
 macro_rules! alert_warning {
     ($message:expr) => {
-        #[cfg(feature = "logging")]
-        {
-            log::warn!("{}\n   ⚠️  WARNING: Investigate before proceeding\n   💡 FIX: Check and resolve", $message);
-        }
-        #[cfg(not(feature = "logging"))]
-        {
-            eprintln!(
-                "⚠️  {}\n   ⚠️  WARNING: Investigate before proceeding\n   💡 FIX: Check and resolve",
-                $message
-            );
-        }
+        $crate::alert_warning!($message, "Check and resolve")
     };
     ($message:expr, $fix:expr) => {
-        #[cfg(feature = "logging")]
-        {
-            log::warn!("{}\n   ⚠️  WARNING: Investigate before proceeding\n   💡 FIX: {}", $message, $fix);
-        }
-        #[cfg(not(feature = "logging"))]
-        {
-            eprintln!(
-                "⚠️  {}\n   ⚠️  WARNING: Investigate before proceeding\n   💡 FIX: {}",
-                $message, $fix
-            );
-        }
+        $crate::__emit_alert!(warn, Warning, $message;
+            .stop("WARNING: Investigate before proceeding")
+            .fix(($fix).to_string()))
     };
     ($message:expr, $fix:expr, $($action:expr),+) => {
-        {
-            let actions: Vec<String> = vec![$($action.to_string()),+];
-            let action_str = actions.join("\n   📋 ");
-            #[cfg(feature = "logging")]
-            {
-                log::warn!("{}\n   ⚠️  WARNING: Investigate before proceeding\n   💡 FIX: {}\n   📋 {}", $message, $fix, action_str);
-            }
-            #[cfg(not(feature = "logging"))]
-            {
-                eprintln!(
-                    "⚠️  {}\n   ⚠️  WARNING: Investigate before proceeding\n   💡 FIX: {}\n   📋 {}",
-                    $message, $fix, action_str
-                );
-            }
-        }
+        $crate::__emit_alert!(warn, Warning, $message;
+            .stop("WARNING: Investigate before proceeding")
+            .fix(($fix).to_string())
+            .actions([$($action.to_string()),+]))
     };
 }
 
@@ -206,31 +165,14 @@ macro_rules! alert_warning {
 #[macro_export]
 macro_rules! alert_info {
     ($message:expr) => {
-        #[cfg(feature = "logging")]
-        {
-            log::info!("{}", $message);
-        }
-        #[cfg(not(feature = "logging"))]
-        {
-            eprintln!("ℹ️  {}", $message);
-        }
+        $crate::__emit_alert!(info, Info, $message)
     };
     ($message:expr, $($detail:expr),+) => {
-        {
-            let details: Vec<String> = vec![$($detail.to_string()),+];
-            let detail_str = details.join("\n   ℹ️  ");
-            #[cfg(feature = "logging")]
-            {
-                log::info!("{}\n   ℹ️  {}", $message, detail_str);
-            }
-            #[cfg(not(feature = "logging"))]
-            {
-                eprintln!(
-                    "ℹ️  {}\n   ℹ️  {}",
-                    $message, detail_str
-                );
-            }
-        }
+        $crate::__emit_alert!(info, Info, $message;
+            .lines(
+                &$crate::core::render::Glyph::Level($crate::core::render::AlertLevel::Info),
+                [$($detail.to_string()),+],
+            ))
     };
 }
 
@@ -253,31 +195,14 @@ macro_rules! alert_info {
 #[macro_export]
 macro_rules! alert_success {
     ($message:expr) => {
-        #[cfg(feature = "logging")]
-        {
-            log::info!("✅ {}", $message);
-        }
-        #[cfg(not(feature = "logging"))]
-        {
-            eprintln!("✅ {}", $message);
-        }
+        $crate::__emit_alert!(@marked info, Success, $message)
     };
     ($message:expr, $($detail:expr),+) => {
-        {
-            let details: Vec<String> = vec![$($detail.to_string()),+];
-            let detail_str = details.join("\n   ✅ ");
-            #[cfg(feature = "logging")]
-            {
-                log::info!("✅ {}\n   ✅ {}", $message, detail_str);
-            }
-            #[cfg(not(feature = "logging"))]
-            {
-                eprintln!(
-                    "✅ {}\n   ✅ {}",
-                    $message, detail_str
-                );
-            }
-        }
+        $crate::__emit_alert!(@marked info, Success, $message;
+            .lines(
+                &$crate::core::render::Glyph::Level($crate::core::render::AlertLevel::Success),
+                [$($detail.to_string()),+],
+            ))
     };
 }
 
@@ -301,27 +226,10 @@ macro_rules! alert_success {
 #[macro_export]
 macro_rules! alert_debug {
     ($message:expr) => {
-        #[cfg(feature = "logging")]
-        {
-            log::debug!("{}", $message);
-        }
-        #[cfg(not(feature = "logging"))]
-        {
-            eprintln!("🔍 {}", $message);
-        }
+        $crate::__emit_alert!(debug, Debug, $message)
     };
     ($($arg:tt)*) => {
-        {
-            let msg = format!($($arg)*);
-            #[cfg(feature = "logging")]
-            {
-                log::debug!("{}", msg);
-            }
-            #[cfg(not(feature = "logging"))]
-            {
-                eprintln!("🔍 {}", msg);
-            }
-        }
+        $crate::__emit_alert!(debug, Debug, format!($($arg)*))
     };
 }
 
@@ -346,45 +254,65 @@ macro_rules! alert_debug {
 #[macro_export]
 macro_rules! alert {
     ($severity:expr, $message:expr) => {
+        $crate::__emit_alert!(@render info,
+            $crate::core::render::AlertText::custom($severity, ($message).to_string()))
+    };
+    ($severity:expr, $message:expr, $stop:expr, $fix:expr) => {
+        $crate::__emit_alert!(@render warn,
+            $crate::core::render::AlertText::custom($severity, ($message).to_string())
+                .severity_line(($stop).to_string())
+                .fix(($fix).to_string()))
+    };
+    ($severity:expr, $message:expr, $stop:expr, $fix:expr, $($action:expr),+) => {
+        $crate::__emit_alert!(@render warn,
+            $crate::core::render::AlertText::custom($severity, ($message).to_string())
+                .severity_line(($stop).to_string())
+                .fix(($fix).to_string())
+                .actions([$($action.to_string()),+]))
+    };
+}
+
+/// Emit a rendered alert to the registered sinks and to `log` (when the `logging`
+/// feature is on) or stderr
+///
+/// Implementation detail of the alert macros. `@marked` and `@render` keep the headline
+/// marker in the log record; otherwise the logger is expected to add its own.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __emit_alert {
+    ($log:ident, $level:ident, $message:expr $(; $($build:tt)+)?) => {{
+        let alert = $crate::core::render::AlertText::new(
+            $crate::core::render::AlertLevel::$level,
+            ($message).to_string(),
+        ) $($($build)+)?;
+        $crate::core::alert::sink::dispatch(&alert);
         #[cfg(feature = "logging")]
         {
-            log::info!("{} {}", $severity, $message);
+            log::$log!("{}", alert.without_headline_glyph().render());
         }
         #[cfg(not(feature = "logging"))]
         {
-            eprintln!("{} {}", $severity, $message);
+            eprintln!("{}", alert.render());
         }
+    }};
+    (@marked $log:ident, $level:ident, $message:expr $(; $($build:tt)+)?) => {
+        $crate::__emit_alert!(@render $log, $crate::core::render::AlertText::new(
+            $crate::core::render::AlertLevel::$level,
+            ($message).to_string(),
+        ) $($($build)+)?)
     };
-    ($severity:expr, $message:expr, $stop:expr, $fix:expr) => {
+    (@render $log:ident, $alert:expr) => {{
+        let alert: $crate::core::render::AlertText = $alert;
+        $crate::core::alert::sink::dispatch(&alert);
         #[cfg(feature = "logging")]
         {
-            log::warn!("{} {}\n   {} {}\n   💡 FIX: {}", $severity, $message, $severity, $stop, $fix);
+            log::$log!("{}", alert.render());
         }
         #[cfg(not(feature = "logging"))]
         {
-            eprintln!(
-                "{} {}\n   {} {}\n   💡 FIX: {}",
-                $severity, $message, $severity, $stop, $fix
-            );
+            eprintln!("{}", alert.render());
         }
-    };
-    ($severity:expr, $message:expr, $stop:expr, $fix:expr, $($action:expr),+) => {
-        {
-            let actions: Vec<String> = vec![$($action.to_string()),+];
-            let action_str = actions.join("\n   📋 ");
-            #[cfg(feature = "logging")]
-            {
-                log::warn!("{} {}\n   {} {}\n   💡 FIX: {}\n   📋 {}", $severity, $message, $severity, $stop, $fix, action_str);
-            }
-            #[cfg(not(feature = "logging"))]
-            {
-                eprintln!(
-                    "{} {}\n   {} {}\n   💡 FIX: {}\n   📋 {}",
-                    $severity, $message, $severity, $stop, $fix, action_str
-                );
-            }
-        }
-    };
+    }};
 }
 
 /// Write alert to a writer
@@ -421,18 +349,14 @@ pub fn write_alert<W: Write>(
     stop: Option<&str>,
     fix: Option<&str>,
 ) -> io::Result<()> {
+    let mut alert = AlertText::custom(severity, message);
     if let Some(stop_msg) = stop {
+        alert = alert.severity_line(stop_msg);
         if let Some(fix_msg) = fix {
-            writeln!(
-                writer,
-                "{severity} {message}\n   {severity} {stop_msg}\n   💡 FIX: {fix_msg}"
-            )?;
-        } else {
-            writeln!(writer, "{severity} {message}\n   {severity} {stop_msg}")?;
+            alert = alert.fix(fix_msg);
         }
-    } else {
-        writeln!(writer, "{severity} {message}")?;
     }
+    writeln!(writer, "{}", alert.render())?;
     Ok(())
 }
 
@@ -505,24 +429,26 @@ impl log::Log for AlertLogger {
             return;
         }
 
-        let (emoji, stop_msg, fix_msg) = match record.level() {
-            log::Level::Error => {
-                ("🚨", Some("STOP: Cannot proceed"), Some("FIX: Investigate and resolve"))
-            }
+        let (level, stop_msg, fix_msg) = match record.level() {
+            log::Level::Error => (
+                AlertLevel::Critical,
+                Some("STOP: Cannot proceed"),
+                Some("FIX: Investigate and resolve"),
+            ),
             log::Level::Warn => (
-                "⚠️",
+                AlertLevel::Warning,
                 Some("WARNING: Investigate before proceeding"),
                 Some("FIX: Check and resolve"),
             ),
-            log::Level::Info => ("ℹ️", None, None),
-            log::Level::Debug | log::Level::Trace => ("🔍", None, None),
+            log::Level::Info => (AlertLevel::Info, None, None),
+            log::Level::Debug | log::Level::Trace => (AlertLevel::Debug, None, None),
         };
 
+        let mut alert = AlertText::new(level, record.args().to_string());
         if let (Some(stop), Some(fix)) = (stop_msg, fix_msg) {
-            eprintln!("{} {}\n   {} {}\n   💡 {}", emoji, record.args(), emoji, stop, fix);
-        } else {
-            eprintln!("{} {}", emoji, record.args());
+            alert = alert.severity_line(stop).line(Glyph::Fix, fix);
         }
+        eprintln!("{}", alert.render());
     }
 
     fn flush(&self) {
@@ -0,0 +1,447 @@
+//! Alert Sinks
+//!
+//! Every alert macro (`alert_critical!`, `alert_warning!`, …) writes to `log` or stderr
+//! and then fans the alert out to every registered [`AlertSink`]. Built-in sinks:
+//!
+//! - [`JsonLinesSink`]: append one JSON object per alert to a file (CI artifacts)
+//! - [`MemorySink`]: capture alerts in memory to assert that code emitted one
+//! - `WebhookSink` (`alert-webhook` feature): POST each alert as JSON to a URL
+//!
+//! Sink failures are swallowed: emitting an alert never fails or panics the caller.
+//!
+//! ```rust
+//! use chicago_tdd_tools::alert::sink::{register_sink, unregister_sink, MemorySink};
+//! use chicago_tdd_tools::alert_critical;
+//! use chicago_tdd_tools::core::render::AlertLevel;
+//!
+//! let alerts = MemorySink::current_thread();
+//! let id = register_sink(alerts.clone());
+//!
+//! alert_critical!("Docker daemon is not running", "Start Docker Desktop");
+//!
+//! assert!(alerts.contains(AlertLevel::Critical, "Docker daemon"));
+//! unregister_sink(id);
+//! ```
+
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+use std::thread::{self, ThreadId};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::events;
+use crate::core::render::{AlertLevel, AlertText, Glyph};
+
+/// One emitted alert, as handed to sinks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AlertRecord {
+    /// Severity (`None` for a custom marker passed to `alert!` that maps to no level)
+    pub level: Option<AlertLevel>,
+    /// Headline marker (emoji form)
+    pub marker: String,
+    /// Headline message
+    pub message: String,
+    /// Detail lines (stop, fix, actions) without their markers
+    pub details: Vec<String>,
+    /// Name of the emitting thread (the test name under `cargo test`)
+    pub thread: Option<String>,
+    /// Timestamp (Unix epoch milliseconds)
+    pub timestamp: u64,
+}
+
+impl AlertRecord {
+    /// Record for `alert`, stamped with the current thread and time
+    #[must_use]
+    pub fn from_alert(alert: &AlertText) -> Self {
+        let level = match &alert.glyph {
+            Glyph::Level(level) => Some(*level),
+            Glyph::Custom(marker) => match Glyph::from_emoji(marker) {
+                Some(Glyph::Level(level)) => Some(level),
+                _ => None,
+            },
+            _ => None,
+        };
+        #[allow(clippy::cast_possible_truncation)] // Milliseconds since 1970 fit in u64
+        let timestamp =
+            SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+        Self {
+            level,
+            marker: alert.glyph.text(false).trim().to_string(),
+            message: alert.message.clone(),
+            details: alert.lines.iter().map(|(_, text)| text.clone()).collect(),
+            thread: thread::current().name().map(str::to_string),
+            timestamp,
+        }
+    }
+
+    /// Headline and details, one per line
+    #[must_use]
+    pub fn text(&self) -> String {
+        std::iter::once(self.message.as_str())
+            .chain(self.details.iter().map(String::as_str))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Destination for emitted alerts
+pub trait AlertSink: Send + Sync + fmt::Debug {
+    /// Deliver one alert
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if delivery fails; the dispatcher ignores it.
+    fn emit(&self, record: &AlertRecord) -> io::Result<()>;
+}
+
+/// Handle identifying a registered sink
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SinkId(u64);
+
+/// Registered sinks, in registration order
+static SINKS: RwLock<Vec<(SinkId, Arc<dyn AlertSink>)>> = RwLock::new(Vec::new());
+/// Next [`SinkId`]
+static NEXT_ID: Mutex<u64> = Mutex::new(0);
+
+/// Register `sink` to receive every subsequent alert
+pub fn register_sink(sink: impl AlertSink + 'static) -> SinkId {
+    let id = {
+        let mut next = NEXT_ID.lock().unwrap_or_else(PoisonError::into_inner);
+        *next += 1;
+        SinkId(*next)
+    };
+    SINKS.write().unwrap_or_else(PoisonError::into_inner).push((id, Arc::new(sink)));
+    id
+}
+
+/// Stop sending alerts to the sink registered as `id`
+///
+/// Returns whether a sink was removed.
+pub fn unregister_sink(id: SinkId) -> bool {
+    let mut sinks = SINKS.write().unwrap_or_else(PoisonError::into_inner);
+    let before = sinks.len();
+    sinks.retain(|(sink_id, _)| *sink_id != id);
+    before != sinks.len()
+}
+
+/// Remove every registered sink
+pub fn clear_sinks() {
+    SINKS.write().unwrap_or_else(PoisonError::into_inner).clear();
+}
+
+/// Number of registered sinks
+#[must_use]
+pub fn sink_count() -> usize {
+    SINKS.read().unwrap_or_else(PoisonError::into_inner).len()
+}
+
+/// Fan `alert` out to every registered sink and the test event bus
+///
+/// Called by the alert macros; sink errors are ignored.
+pub fn dispatch(alert: &AlertText) {
+    // Snapshot so sinks that emit alerts themselves cannot deadlock the registry
+    let sinks: Vec<Arc<dyn AlertSink>> = SINKS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .iter()
+        .map(|(_, sink)| Arc::clone(sink))
+        .collect();
+    let to_bus = events::has_subscribers();
+    if sinks.is_empty() && !to_bus {
+        return;
+    }
+    let record = AlertRecord::from_alert(alert);
+    for sink in sinks {
+        let _ = sink.emit(&record);
+    }
+    if to_bus {
+        events::emit(events::Event::AlertEmitted { alert: record });
+    }
+}
+
+/// Appends each alert as one JSON object per line
+#[derive(Debug)]
+pub struct JsonLinesSink {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl JsonLinesSink {
+    /// Append to `path`, creating it (and its parent directories) if needed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        Ok(Self { path, file: Mutex::new(file) })
+    }
+
+    /// File the alerts are written to
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl AlertSink for JsonLinesSink {
+    fn emit(&self, record: &AlertRecord) -> io::Result<()> {
+        let mut line = serde_json::to_vec(record).map_err(io::Error::other)?;
+        line.push(b'\n');
+        let mut file = self.file.lock().unwrap_or_else(PoisonError::into_inner);
+        file.write_all(&line)?;
+        file.flush()
+    }
+}
+
+/// Captures alerts in memory
+///
+/// Clones share the same buffer, so register one clone and assert on another.
+#[derive(Debug, Clone, Default)]
+pub struct MemorySink {
+    records: Arc<Mutex<Vec<AlertRecord>>>,
+    thread: Option<ThreadId>,
+}
+
+impl MemorySink {
+    /// Capture alerts from every thread
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Capture only alerts emitted on the calling thread
+    ///
+    /// Keeps parallel tests from seeing each other's alerts.
+    #[must_use]
+    pub fn current_thread() -> Self {
+        Self { thread: Some(thread::current().id()), ..Self::default() }
+    }
+
+    /// Captured alerts, in emission order
+    #[must_use]
+    pub fn records(&self) -> Vec<AlertRecord> {
+        self.lock().clone()
+    }
+
+    /// Remove and return the captured alerts
+    #[must_use]
+    pub fn take(&self) -> Vec<AlertRecord> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    /// Number of captured alerts
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether nothing was captured
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Whether an alert at `level` whose headline or details contain `text` was captured
+    #[must_use]
+    pub fn contains(&self, level: AlertLevel, text: &str) -> bool {
+        self.lock()
+            .iter()
+            .any(|record| record.level == Some(level) && record.text().contains(text))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<AlertRecord>> {
+        self.records.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl AlertSink for MemorySink {
+    fn emit(&self, record: &AlertRecord) -> io::Result<()> {
+        if self.thread.is_none_or(|id| id == thread::current().id()) {
+            self.lock().push(record.clone());
+        }
+        Ok(())
+    }
+}
+
+/// POSTs each alert as JSON to a webhook URL
+#[cfg(feature = "alert-webhook")]
+#[derive(Debug, Clone)]
+pub struct WebhookSink {
+    url: String,
+    timeout: std::time::Duration,
+    min_level: Option<AlertLevel>,
+}
+
+#[cfg(feature = "alert-webhook")]
+impl WebhookSink {
+    /// Default request timeout
+    pub const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
+
+    /// POST alerts to `url`
+    #[must_use]
+    pub fn new(url: impl Into<String>) -> Self {
+        Self { url: url.into(), timeout: Self::DEFAULT_TIMEOUT, min_level: None }
+    }
+
+    /// Per-request timeout
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Only forward alerts at `level` or more severe (`Critical` is the most severe)
+    #[must_use]
+    pub const fn with_min_level(mut self, level: AlertLevel) -> Self {
+        self.min_level = Some(level);
+        self
+    }
+
+    /// Webhook URL
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    const fn severity(level: AlertLevel) -> u8 {
+        match level {
+            AlertLevel::Critical => 4,
+            AlertLevel::Warning => 3,
+            AlertLevel::Info | AlertLevel::Success => 2,
+            AlertLevel::Debug => 1,
+        }
+    }
+
+    fn post(&self, record: &AlertRecord) -> io::Result<()> {
+        let client = reqwest::blocking::Client::builder()
+            .timeout(self.timeout)
+            .build()
+            .map_err(io::Error::other)?;
+        let body = serde_json::to_vec(record).map_err(io::Error::other)?;
+        client
+            .post(&self.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .and_then(reqwest::blocking::Response::error_for_status)
+            .map(drop)
+            .map_err(io::Error::other)
+    }
+}
+
+#[cfg(feature = "alert-webhook")]
+impl AlertSink for WebhookSink {
+    fn emit(&self, record: &AlertRecord) -> io::Result<()> {
+        if let Some(min) = self.min_level {
+            if record.level.is_none_or(|level| Self::severity(level) < Self::severity(min)) {
+                return Ok(());
+            }
+        }
+        // The blocking client panics inside an async runtime, and alerts are emitted
+        // from async tests too, so the request runs on its own thread.
+        thread::scope(|scope| {
+            scope
+                .spawn(|| self.post(record))
+                .join()
+                .unwrap_or_else(|_| Err(io::Error::other("webhook request panicked")))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{alert_critical, alert_info, alert_warning, test};
+
+    test!(test_memory_sink_captures_alerts_from_macros, {
+        // Arrange
+        let alerts = MemorySink::current_thread();
+        let id = register_sink(alerts.clone());
+
+        // Act
+        alert_critical!("Docker daemon is not running", "Start Docker Desktop");
+        alert_info!("Container started");
+        assert!(unregister_sink(id));
+        alert_warning!("Emitted after unregistering");
+
+        // Assert
+        let records = alerts.records();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].level, Some(AlertLevel::Critical));
+        assert_eq!(records[0].marker, "🚨");
+        assert_eq!(
+            records[0].details,
+            vec!["STOP: Cannot proceed".to_string(), "FIX: Start Docker Desktop".to_string()]
+        );
+        assert!(alerts.contains(AlertLevel::Critical, "Start Docker Desktop"));
+        assert!(!alerts.contains(AlertLevel::Warning, "after unregistering"));
+        assert!(!unregister_sink(id));
+    });
+
+    test!(test_json_lines_sink_appends_one_record_per_alert, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let sink = JsonLinesSink::open(dir.path().join("alerts/alerts.jsonl")).unwrap();
+        let alert = AlertText::new(AlertLevel::Warning, "Slow test").fix("Mock the network");
+
+        // Act
+        sink.emit(&AlertRecord::from_alert(&alert)).unwrap();
+        sink.emit(&AlertRecord::from_alert(&AlertText::custom("🔥", "Custom"))).unwrap();
+
+        // Assert
+        let contents = std::fs::read_to_string(sink.path()).unwrap();
+        let records: Vec<AlertRecord> =
+            contents.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].level, Some(AlertLevel::Warning));
+        assert_eq!(records[0].details, vec!["FIX: Mock the network".to_string()]);
+        assert_eq!(records[1].level, None);
+        assert_eq!(records[1].marker, "🔥");
+    });
+
+    #[cfg(feature = "alert-webhook")]
+    test!(test_webhook_sink_posts_json_records_at_or_above_min_level, {
+        use std::io::Read;
+        use std::net::TcpListener;
+
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/hooks/alerts", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0_u8; 4096];
+            while !String::from_utf8_lossy(&request).contains("\"timestamp\"") {
+                let read = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read]);
+            }
+            stream
+                .write_all(b"HTTP/1.1 204 No Content\r\nContent-Length: 0\r\n\r\n")
+                .unwrap();
+            String::from_utf8_lossy(&request).into_owned()
+        });
+        let sink = WebhookSink::new(url).with_min_level(AlertLevel::Warning);
+
+        // Act
+        let info = sink.emit(&AlertRecord::from_alert(&AlertText::new(AlertLevel::Info, "Skip")));
+        let critical =
+            sink.emit(&AlertRecord::from_alert(&AlertText::new(AlertLevel::Critical, "Down")));
+
+        // Assert
+        assert!(info.is_ok());
+        assert!(critical.is_ok());
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /hooks/alerts"));
+        assert!(request.contains("\"level\":\"critical\""));
+        assert!(!request.contains("Skip"));
+    });
+}
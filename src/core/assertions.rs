@@ -10,7 +10,43 @@
 //! - **1st Idea**: Specific assertion functions (`assert_success`, `assert_error`, etc.)
 //! - **2nd Idea**: `AssertionBuilder<T>` - Generic assertion builder pattern for composable assertions
 //! - **3rd Idea**: Compile-time validated assertions with OTEL/Weaver validation
-
+//!
+//! # Fluent Matchers
+//!
+//! [`expect`] starts an [`AssertionBuilder`] whose `to_*` methods take
+//! [`Matcher`]s and unwrap `Option`/`Result` along the way. Failures name the whole
+//! matcher chain, e.g. `expect(value).to_be_some().to_be_greater_than(3)`, followed by
+//! what was expected and the actual value.
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::assertions::expect;
+//!
+//! let parsed: Result<Option<u32>, String> = Ok(Some(5));
+//! expect(parsed).to_be_ok().to_be_some().and_then(|v| v.to_be_greater_than(3).to_be_at_most(5));
+//! ```
+//!
+//! # Soft Assertions
+//!
+//! [`SoftAssertions`] records failures instead of stopping at the first one, then
+//! reports them all from [`SoftAssertions::verify`]. Use it to check many independent
+//! properties of one result (e.g. a large state snapshot).
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::assertions::{expect, SoftAssertions};
+//!
+//! let order = (3_u32, "shipped", Some(42_u32));
+//!
+//! let mut soft = SoftAssertions::new();
+//! soft.assert_eq(&order.0, &3, "line count");
+//! soft.assert_eq(&order.1, &"shipped", "status");
+//! soft.check(|| expect(order.2).to_be_some().to_be_at_least(40));
+//! soft.verify();
+//! ```
+
+use crate::core::failure::{FailureKind, TddFailure};
+use crate::core::matchers::{
+    at_least, at_most, equal_to, greater_than, less_than, not, satisfies, Matcher,
+};
 #[cfg(feature = "otel")]
 use crate::observability::otel::types::{
     Metric, MetricValue, Span, SpanContext, SpanId, SpanStatus, TraceId,
@@ -24,6 +60,7 @@ use std::time::{SystemTime, UNIX_EPOCH};
 ///
 /// Panics if the result is an error, with a message showing the error.
 pub fn assert_success<T, E: std::fmt::Debug>(result: &Result<T, E>) {
+    crate::core::receipt::record_assertion();
     assert!(result.is_ok(), "Expected success, but got error: {:?}", result.as_ref().err());
 }
 
@@ -33,6 +70,7 @@ pub fn assert_success<T, E: std::fmt::Debug>(result: &Result<T, E>) {
 ///
 /// Panics if the result is successful, with a message showing the value.
 pub fn assert_error<T: std::fmt::Debug, E>(result: &Result<T, E>) {
+    crate::core::receipt::record_assertion();
     assert!(result.is_err(), "Expected error, but got success: {:?}", result.as_ref().ok());
 }
 
@@ -42,6 +80,7 @@ pub fn assert_error<T: std::fmt::Debug, E>(result: &Result<T, E>) {
 ///
 /// Panics if `actual` and `expected` are not equal, with a message showing both values.
 pub fn assert_eq_with_msg<T: std::fmt::Debug + PartialEq>(actual: &T, expected: &T, msg: &str) {
+    crate::core::receipt::record_assertion();
     assert_eq!(actual, expected, "{msg}: expected {expected:?}, got {actual:?}");
 }
 
@@ -51,6 +90,7 @@ pub fn assert_eq_with_msg<T: std::fmt::Debug + PartialEq>(actual: &T, expected:
 ///
 /// Panics if `value` is not within the range `[min, max]`, with a message showing the value and range.
 pub fn assert_in_range<T: PartialOrd + std::fmt::Debug>(value: &T, min: &T, max: &T, msg: &str) {
+    crate::core::receipt::record_assertion();
     assert!(value >= min && value <= max, "{msg}: value {value:?} not in range [{min:?}, {max:?}]");
 }
 
@@ -80,6 +120,7 @@ where
     // Poka-Yoke: HRTB requires single-character lifetime for flexibility
     F: for<'value> Fn(&'value T) -> bool,
 {
+    crate::core::receipt::record_assertion();
     assert!(predicate(value), "Assertion failed for value: {value:?}");
 }
 
@@ -94,6 +135,7 @@ where
     // Poka-Yoke: HRTB requires single-character lifetime for flexibility
     F: for<'value> Fn(&'value T) -> bool,
 {
+    crate::core::receipt::record_assertion();
     assert!(predicate(value), "{msg}: Assertion failed for value: {value:?}");
 }
 
@@ -125,6 +167,8 @@ where
 /// ```
 pub struct AssertionBuilder<T> {
     value: T,
+    /// Matcher steps applied so far, e.g. `.to_be_some()`
+    chain: Vec<String>,
     #[cfg(feature = "otel")]
     span: Option<Span>,
 }
@@ -134,6 +178,7 @@ impl<T: std::fmt::Debug> AssertionBuilder<T> {
     pub const fn new(value: T) -> Self {
         Self {
             value,
+            chain: Vec::new(),
             #[cfg(feature = "otel")]
             span: None,
         }
@@ -179,6 +224,7 @@ impl<T: std::fmt::Debug> AssertionBuilder<T> {
         // Poka-Yoke: HRTB requires single-character lifetime for flexibility
         F: for<'value> Fn(&'value T) -> bool,
     {
+        crate::core::receipt::record_assertion();
         assert!(predicate(&self.value), "Assertion failed for value: {:?}", self.value);
         self
     }
@@ -193,6 +239,7 @@ impl<T: std::fmt::Debug> AssertionBuilder<T> {
     where
         T: PartialEq<U>,
     {
+        crate::core::receipt::record_assertion();
         assert_eq!(&self.value, expected, "Values not equal");
         self
     }
@@ -208,6 +255,7 @@ impl<T: std::fmt::Debug> AssertionBuilder<T> {
         // Poka-Yoke: HRTB requires single-character lifetime for flexibility
         F: for<'value> Fn(&'value T) -> bool,
     {
+        crate::core::receipt::record_assertion();
         assert!(predicate(&self.value), "{msg}: Assertion failed for value: {:?}", self.value);
         self
     }
@@ -248,6 +296,416 @@ impl<T: std::fmt::Debug> AssertionBuilder<T> {
     }
 }
 
+/// Start a fluent assertion on `value`
+///
+/// Chain `to_*` matchers on the returned [`AssertionBuilder`]; see
+/// [the module docs](self#fluent-matchers).
+pub const fn expect<T: std::fmt::Debug>(value: T) -> AssertionBuilder<T> {
+    AssertionBuilder::new(value)
+}
+
+// Matcher methods consume and return the builder for chaining (`to_*` reads as
+// "expect(x) to ..."); a dropped builder is a finished assertion, so they are
+// deliberately not `#[must_use]`.
+#[allow(clippy::return_self_not_must_use, clippy::wrong_self_convention)]
+impl<T: std::fmt::Debug> AssertionBuilder<T> {
+    /// Assert that the value matches `matcher`
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the value does not match.
+    #[track_caller]
+    #[allow(clippy::needless_pass_by_value)] // Matchers are built inline: `to_match(gt(3))`
+    pub fn to_match<M: Matcher<T>>(mut self, matcher: M) -> Self {
+        crate::core::receipt::record_assertion();
+        let description = matcher.describe();
+        self.chain.push(format!(".to_match({description})"));
+        if !matcher.matches(&self.value) {
+            self.fail(&description);
+        }
+        self
+    }
+
+    /// Assert that the value does not match `matcher`
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the value matches.
+    #[track_caller]
+    pub fn not_to_match<M: Matcher<T>>(self, matcher: M) -> Self {
+        self.to_match(not(matcher))
+    }
+
+    /// Assert that the value equals `expected`
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the values differ.
+    #[track_caller]
+    pub fn to_equal<U: std::fmt::Debug>(self, expected: U) -> Self
+    where
+        T: PartialEq<U>,
+    {
+        self.check(&format!(".to_equal({expected:?})"), &equal_to(expected))
+    }
+
+    /// Assert that the value is greater than `bound`
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the value is not greater.
+    #[track_caller]
+    pub fn to_be_greater_than<U: std::fmt::Debug>(self, bound: U) -> Self
+    where
+        T: PartialOrd<U>,
+    {
+        self.check(&format!(".to_be_greater_than({bound:?})"), &greater_than(bound))
+    }
+
+    /// Assert that the value is less than `bound`
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the value is not less.
+    #[track_caller]
+    pub fn to_be_less_than<U: std::fmt::Debug>(self, bound: U) -> Self
+    where
+        T: PartialOrd<U>,
+    {
+        self.check(&format!(".to_be_less_than({bound:?})"), &less_than(bound))
+    }
+
+    /// Assert that the value is at least `bound`
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the value is below `bound`.
+    #[track_caller]
+    pub fn to_be_at_least<U: std::fmt::Debug>(self, bound: U) -> Self
+    where
+        T: PartialOrd<U>,
+    {
+        self.check(&format!(".to_be_at_least({bound:?})"), &at_least(bound))
+    }
+
+    /// Assert that the value is at most `bound`
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the value is above `bound`.
+    #[track_caller]
+    pub fn to_be_at_most<U: std::fmt::Debug>(self, bound: U) -> Self
+    where
+        T: PartialOrd<U>,
+    {
+        self.check(&format!(".to_be_at_most({bound:?})"), &at_most(bound))
+    }
+
+    /// Assert that `predicate` holds, described by `description` in failures
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the predicate returns `false`.
+    #[track_caller]
+    pub fn to_satisfy<F: Fn(&T) -> bool>(self, description: &str, predicate: F) -> Self {
+        self.check(&format!(".to_satisfy({description})"), &satisfies(description, predicate))
+    }
+
+    /// Continue the chain with `f`, which may unwrap or re-type the assertion
+    ///
+    /// Reads naturally after unwrapping matchers:
+    /// `expect(opt).to_be_some().and_then(|v| v.to_be_greater_than(3))`.
+    pub fn and_then<U>(self, f: impl FnOnce(Self) -> AssertionBuilder<U>) -> AssertionBuilder<U> {
+        f(self)
+    }
+
+    #[track_caller]
+    fn check<M: Matcher<T>>(mut self, step: &str, matcher: &M) -> Self {
+        crate::core::receipt::record_assertion();
+        self.chain.push(step.to_string());
+        if !matcher.matches(&self.value) {
+            self.fail(&matcher.describe());
+        }
+        self
+    }
+
+    #[track_caller]
+    fn fail(&self, expected: &str) -> ! {
+        raise_mismatch(&self.chain, expected, &self.value)
+    }
+
+    /// Unwrap the value with `f` (`Err` hands back the original, which fails the chain)
+    #[track_caller]
+    fn try_map<U>(
+        self,
+        step: &str,
+        expected: &str,
+        f: impl FnOnce(T) -> Result<U, T>,
+    ) -> AssertionBuilder<U> {
+        let mut chain = self.chain;
+        chain.push(step.to_string());
+        match f(self.value) {
+            Ok(value) => AssertionBuilder {
+                value,
+                chain,
+                #[cfg(feature = "otel")]
+                span: self.span,
+            },
+            Err(value) => raise_mismatch(&chain, expected, &value),
+        }
+    }
+}
+
+/// Fail a fluent assertion, naming the matcher chain
+#[track_caller]
+fn raise_mismatch(chain: &[String], expected: &str, actual: &dyn std::fmt::Debug) -> ! {
+    let chain = format!("expect(value){}", chain.concat());
+    TddFailure::new(
+        FailureKind::Matcher,
+        format!("Matcher failed: {chain}\n   expected: {expected}\n   actual: {actual:?}"),
+    )
+    .with_context("chain", chain)
+    .with_context("expected", expected)
+    .with_context("actual", format!("{actual:?}"))
+    .raise()
+}
+
+#[allow(clippy::return_self_not_must_use, clippy::wrong_self_convention)] // See above
+impl<U: std::fmt::Debug> AssertionBuilder<Option<U>> {
+    /// Assert that the value is `Some`, continuing with the inner value
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the value is `None`.
+    #[track_caller]
+    pub fn to_be_some(self) -> AssertionBuilder<U> {
+        self.try_map(".to_be_some()", "Some(_)", |value| value.ok_or(None))
+    }
+
+    /// Assert that the value is `None`
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the value is `Some`.
+    #[track_caller]
+    pub fn to_be_none(self) -> Self {
+        self.check(".to_be_none()", &satisfies("None", Option::is_none))
+    }
+}
+
+#[allow(clippy::return_self_not_must_use, clippy::wrong_self_convention)] // See above
+impl<U: std::fmt::Debug, E: std::fmt::Debug> AssertionBuilder<Result<U, E>> {
+    /// Assert that the value is `Ok`, continuing with the success value
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the value is `Err`.
+    #[track_caller]
+    pub fn to_be_ok(self) -> AssertionBuilder<U> {
+        self.try_map(".to_be_ok()", "Ok(_)", |value| value.map_err(Err))
+    }
+
+    /// Assert that the value is `Err`, continuing with the error
+    ///
+    /// # Panics
+    ///
+    /// Panics with the matcher chain if the value is `Ok`.
+    #[track_caller]
+    pub fn to_be_err(self) -> AssertionBuilder<E> {
+        self.try_map(".to_be_err()", "Err(_)", |value| match value {
+            Err(error) => Ok(error),
+            ok => Err(ok),
+        })
+    }
+}
+
+/// > 📚 Reference
+///
+/// Collects assertion failures so a test can report all of them at once.
+///
+/// Each `assert_*` method records a failure (with the caller's location) instead of
+/// panicking and returns whether the check passed. [`SoftAssertions::check`] runs any
+/// framework assertion (macros, [`expect`] chains) softly. Call
+/// [`SoftAssertions::verify`] at the end of the test; dropping a collector with
+/// unverified failures fails the test too, so failures cannot be lost.
+#[derive(Debug, Default)]
+pub struct SoftAssertions {
+    failures: Vec<TddFailure>,
+}
+
+impl SoftAssertions {
+    /// Create an empty collector
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { failures: Vec::new() }
+    }
+
+    /// Record a failure unless `actual == expected`
+    #[track_caller]
+    pub fn assert_eq<T, U>(&mut self, actual: &T, expected: &U, msg: &str) -> bool
+    where
+        T: PartialEq<U> + std::fmt::Debug + ?Sized,
+        U: std::fmt::Debug + ?Sized,
+    {
+        let passed = actual == expected;
+        if !passed {
+            self.record(
+                TddFailure::new(
+                    FailureKind::Equality,
+                    format!("{msg}: expected {expected:?}, got {actual:?}"),
+                )
+                .with_context("expected", format!("{expected:?}"))
+                .with_context("actual", format!("{actual:?}")),
+            );
+        }
+        passed
+    }
+
+    /// Record a failure if `result` is an error
+    #[track_caller]
+    pub fn assert_ok<T, E: std::fmt::Debug>(&mut self, result: &Result<T, E>, msg: &str) -> bool {
+        if let Err(error) = result {
+            self.record(
+                TddFailure::new(
+                    FailureKind::Result,
+                    format!("{msg}: expected Ok, got Err({error:?})"),
+                )
+                .with_context("error", format!("{error:?}")),
+            );
+        }
+        result.is_ok()
+    }
+
+    /// Record a failure if `result` is successful
+    #[track_caller]
+    pub fn assert_err<T: std::fmt::Debug, E>(&mut self, result: &Result<T, E>, msg: &str) -> bool {
+        if let Ok(value) = result {
+            self.record(
+                TddFailure::new(
+                    FailureKind::Result,
+                    format!("{msg}: expected Err, got Ok({value:?})"),
+                )
+                .with_context("value", format!("{value:?}")),
+            );
+        }
+        result.is_err()
+    }
+
+    /// Record a failure unless `predicate(value)` holds
+    #[track_caller]
+    pub fn assert_that<T, F>(&mut self, value: &T, predicate: F, msg: &str) -> bool
+    where
+        T: std::fmt::Debug + ?Sized,
+        F: for<'value> Fn(&'value T) -> bool,
+    {
+        let passed = predicate(value);
+        if !passed {
+            self.record(
+                TddFailure::new(
+                    FailureKind::Pattern,
+                    format!("{msg}: Assertion failed for value: {value:?}"),
+                )
+                .with_context("value", format!("{value:?}")),
+            );
+        }
+        passed
+    }
+
+    /// Run `f`, recording any failure it raises instead of propagating it
+    ///
+    /// Works with every framework assertion and with plain panics.
+    pub fn check<R>(&mut self, f: impl FnOnce() -> R) -> Option<R> {
+        match TddFailure::catch(f) {
+            Ok(value) => Some(value),
+            Err(failure) => {
+                self.record(failure);
+                None
+            }
+        }
+    }
+
+    /// Record an already-built failure
+    pub fn record(&mut self, failure: TddFailure) {
+        self.failures.push(failure);
+    }
+
+    /// Failures recorded so far
+    #[must_use]
+    pub fn failures(&self) -> &[TddFailure] {
+        &self.failures
+    }
+
+    /// Whether every check so far passed
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// Number of failures recorded so far
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.failures.len()
+    }
+
+    /// Take the recorded failures, leaving the collector empty (and verified)
+    #[must_use]
+    pub fn into_failures(mut self) -> Vec<TddFailure> {
+        std::mem::take(&mut self.failures)
+    }
+
+    /// Fail the test with every recorded failure, if any
+    ///
+    /// # Panics
+    ///
+    /// Panics with a numbered list of all failures if any check failed.
+    #[track_caller]
+    pub fn verify(mut self) {
+        let failures = std::mem::take(&mut self.failures);
+        if !failures.is_empty() {
+            Self::aggregate(&failures).raise();
+        }
+    }
+
+    #[track_caller]
+    fn aggregate(failures: &[TddFailure]) -> TddFailure {
+        use std::fmt::Write;
+
+        let mut message = format!("🚨 {} soft assertion(s) failed:", failures.len());
+        for (index, failure) in failures.iter().enumerate() {
+            let _ = write!(
+                message,
+                "\n   {}) {}",
+                index + 1,
+                failure.message().replace('\n', "\n      ")
+            );
+            if let Some(location) = failure.location() {
+                let _ = write!(message, "\n      at {location}");
+            }
+        }
+        failures.iter().enumerate().fold(
+            TddFailure::new(FailureKind::Soft, message),
+            |aggregate, (index, failure)| {
+                aggregate.with_context(format!("failure.{}", index + 1), failure.message())
+            },
+        )
+    }
+}
+
+impl Drop for SoftAssertions {
+    fn drop(&mut self) {
+        // Unverified failures would otherwise pass silently; don't double-panic
+        if !self.failures.is_empty() && !std::thread::panicking() {
+            let failures = std::mem::take(&mut self.failures);
+            let failure = Self::aggregate(&failures);
+            let message = format!(
+                "{}\n   💡 FIX: Call verify() at the end of the test (SoftAssertions dropped without verify())",
+                failure.message()
+            );
+            failure.with_message(message).raise();
+        }
+    }
+}
+
 // ============================================================================
 // 3rd IDEA: Maximum value - Compile-time validated assertions + OTEL + Weaver
 // ============================================================================
@@ -625,4 +1083,115 @@ mod tests {
         // Act & Assert: Verify assert_that works with string
         assert_that(&s, |v| !v.is_empty());
     });
+
+    // ========================================================================
+    // 4. FLUENT MATCHERS - expect(..) chains
+    // ========================================================================
+
+    test!(test_expect_unwraps_and_chains_matchers, {
+        // Arrange
+        let parsed: Result<Option<u32>, String> = Ok(Some(TEST_VALUE));
+
+        // Act
+        let value = expect(parsed)
+            .to_be_ok()
+            .to_be_some()
+            .and_then(|v| v.to_be_greater_than(3).to_be_less_than(100))
+            .to_satisfy("an even number", |v| v % 2 == 0)
+            .into_value();
+
+        // Assert
+        assert_eq!(value, TEST_VALUE);
+        expect(Err::<u32, &str>("boom")).to_be_err().to_equal("boom");
+        expect(None::<u32>).to_be_none();
+    });
+
+    test!(test_expect_failure_names_matcher_chain, {
+        // Arrange, Act
+        let failure =
+            TddFailure::catch(|| expect(Some(2)).to_be_some().to_be_greater_than(3).into_value())
+                .unwrap_err();
+
+        // Assert
+        assert_eq!(failure.kind(), FailureKind::Matcher);
+        assert_eq!(failure.context()["chain"], "expect(value).to_be_some().to_be_greater_than(3)");
+        assert_eq!(failure.context()["expected"], "greater than 3");
+        assert_eq!(failure.context()["actual"], "2");
+    });
+
+    test!(test_expect_combinators_describe_failures, {
+        // Arrange
+        use crate::core::matchers::{all_of, any_of, equal_to};
+
+        // Act
+        let failure = TddFailure::catch(|| {
+            expect(5)
+                .to_match(any_of(vec![Box::new(equal_to(1)), Box::new(greater_than(4))]))
+                .not_to_match(all_of(vec![Box::new(at_least(0)), Box::new(at_most(9))]))
+                .into_value()
+        })
+        .unwrap_err();
+
+        // Assert
+        assert_eq!(failure.context()["expected"], "not (at least 0 and at most 9)");
+        assert!(
+            failure.message().contains(".to_match((equal to 1 or greater than 4))"),
+            "{failure}"
+        );
+    });
+
+    #[test]
+    #[should_panic(expected = "Matcher failed: expect(value).to_be_ok()")]
+    fn test_expect_to_be_ok_with_err() {
+        let _ = expect(Err::<u32, String>("error".to_string())).to_be_ok();
+    }
+
+    // ========================================================================
+    // 5. SOFT ASSERTIONS - aggregate failures
+    // ========================================================================
+
+    test!(test_soft_assertions_pass_when_all_checks_pass, {
+        // Arrange
+        let mut soft = SoftAssertions::new();
+
+        // Act
+        let passed = soft.assert_eq(&TEST_VALUE, &42, "value")
+            && soft.assert_ok(&Ok::<u32, String>(1), "result")
+            && soft.assert_err(&Err::<u32, &str>("e"), "error")
+            && soft.assert_that(&TEST_VALUE, |v| *v > 0, "positive");
+
+        // Assert
+        assert!(passed);
+        assert!(soft.is_empty());
+        soft.verify();
+    });
+
+    test!(test_soft_assertions_report_every_failure, {
+        // Arrange
+        let mut soft = SoftAssertions::new();
+        soft.assert_eq(&1, &2, "first");
+        soft.assert_ok(&Err::<u32, &str>("boom"), "second");
+        let checked = soft.check(|| expect(Some(1)).to_be_none().into_value());
+
+        // Act
+        let failure = TddFailure::catch(|| soft.verify()).unwrap_err();
+
+        // Assert
+        assert!(checked.is_none());
+        assert_eq!(failure.kind(), FailureKind::Soft);
+        let message = failure.message();
+        assert!(message.starts_with("🚨 3 soft assertion(s) failed:"), "{message}");
+        assert!(message.contains("1) first: expected 2, got 1"), "{message}");
+        assert!(message.contains("2) second: expected Ok, got Err(\"boom\")"), "{message}");
+        assert!(message.contains("3) Matcher failed: expect(value).to_be_none()"), "{message}");
+        assert!(message.contains(file!()), "{message}");
+    });
+
+    #[test]
+    #[should_panic(expected = "dropped without verify")]
+    fn test_soft_assertions_fail_when_dropped_unverified() {
+        let mut soft = SoftAssertions::new();
+        soft.assert_eq(&1, &2, "unverified");
+        drop(soft);
+    }
 }
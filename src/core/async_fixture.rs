@@ -9,6 +9,15 @@
 //! - **GATs**: Generic Associated Types for flexible lifetime management
 //! - **Sealed Traits**: API safety and extensibility control
 //!
+//! # Teardown
+//!
+//! [`AsyncFixtureManager::run`] sets a fixture up, runs the test body against it, and
+//! always tears it down with [`AsyncFixtureProvider::teardown_fixture`] - also when the
+//! body panics. Teardown is bounded by a timeout (see
+//! [`AsyncFixtureManager::with_teardown_timeout`]); a teardown that fails, panics, or
+//! times out is reported as an [`AsyncFixtureError`], attached to the body's failure as a
+//! secondary error when both go wrong.
+//!
 //! # Note on Guarantees
 //!
 //! This module provides type-safe async fixture management, not compile-time lifecycle guarantees.
@@ -61,10 +70,24 @@
 //! }
 //! ```
 
+#[cfg(feature = "async")]
+use crate::core::failure::TddFailure;
 #[cfg(feature = "async")]
 use crate::core::fixture::{FixtureError, FixtureResult};
 #[cfg(feature = "async")]
-use std::future::Future;
+use std::future::{poll_fn, Future};
+#[cfg(feature = "async")]
+use std::pin::pin;
+#[cfg(feature = "async")]
+use std::task::Poll;
+#[cfg(feature = "async")]
+use std::time::Duration;
+#[cfg(feature = "async")]
+use thiserror::Error;
+
+/// Teardown timeout used unless [`AsyncFixtureManager::with_teardown_timeout`] sets one
+#[cfg(feature = "async")]
+pub const DEFAULT_TEARDOWN_TIMEOUT: Duration = Duration::from_secs(10);
 
 /// Sealed trait pattern for API safety
 ///
@@ -118,6 +141,87 @@ pub trait AsyncFixtureProvider: private::Sealed {
     /// This method uses async traits (Rust 1.75+) for native async support.
     fn create_fixture(&self)
         -> impl Future<Output = Result<Self::Fixture<'_>, Self::Error>> + Send;
+
+    /// Tear a fixture down asynchronously
+    ///
+    /// Called by [`AsyncFixtureManager::run`] after the test body, even when it panicked.
+    /// The default drops the fixture.
+    fn teardown_fixture<'a>(
+        &'a self,
+        fixture: Self::Fixture<'a>,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send {
+        drop(fixture);
+        std::future::ready(Ok(()))
+    }
+}
+
+/// Why a fixture teardown did not complete
+#[cfg(feature = "async")]
+#[derive(Error, Debug)]
+pub enum TeardownError<E> {
+    /// The provider's teardown returned an error
+    #[error("fixture teardown failed: {0}")]
+    Failed(#[source] E),
+    /// Teardown did not finish within the timeout
+    #[error("fixture teardown timed out after {0:?}")]
+    TimedOut(Duration),
+    /// Teardown panicked
+    #[error("fixture teardown panicked: {}", .0.message())]
+    Panicked(TddFailure),
+}
+
+/// Failure of a test run through [`AsyncFixtureManager::run`]
+#[cfg(feature = "async")]
+#[derive(Error, Debug)]
+pub enum AsyncFixtureError<E> {
+    /// Fixture setup failed; neither the body nor teardown ran
+    #[error("fixture setup failed: {0}")]
+    Setup(#[source] E),
+    /// The test body panicked; `teardown` is set when teardown failed as well
+    #[error("{failure}{}", .teardown.as_ref().map(|error| format!("\n   teardown also failed: {error}")).unwrap_or_default())]
+    Test {
+        /// The body's failure (the primary error)
+        failure: TddFailure,
+        /// Secondary teardown error, if teardown also went wrong
+        teardown: Option<TeardownError<E>>,
+    },
+    /// The test body passed but teardown failed
+    #[error(transparent)]
+    Teardown(TeardownError<E>),
+}
+
+#[cfg(feature = "async")]
+impl<E> AsyncFixtureError<E> {
+    /// The test body's failure, if the body ran and failed
+    #[must_use]
+    pub const fn failure(&self) -> Option<&TddFailure> {
+        match self {
+            Self::Test { failure, .. } => Some(failure),
+            Self::Setup(_) | Self::Teardown(_) => None,
+        }
+    }
+
+    /// The teardown error, whether primary or attached to a body failure
+    #[must_use]
+    pub const fn teardown(&self) -> Option<&TeardownError<E>> {
+        match self {
+            Self::Test { teardown, .. } => teardown.as_ref(),
+            Self::Teardown(error) => Some(error),
+            Self::Setup(_) => None,
+        }
+    }
+}
+
+/// Poll `future` to completion, returning a panic raised by any poll as a failure
+#[cfg(feature = "async")]
+async fn catch_panics<F: Future>(future: F) -> Result<F::Output, TddFailure> {
+    let mut future = pin!(future);
+    poll_fn(|cx| match TddFailure::catch(|| future.as_mut().poll(cx)) {
+        Ok(Poll::Ready(output)) => Poll::Ready(Ok(output)),
+        Ok(Poll::Pending) => Poll::Pending,
+        Err(failure) => Poll::Ready(Err(failure)),
+    })
+    .await
 }
 
 /// Async fixture manager for lifecycle management
@@ -130,6 +234,7 @@ where
     P: AsyncFixtureProvider,
 {
     provider: P,
+    teardown_timeout: Duration,
 }
 
 #[cfg(feature = "async")]
@@ -139,7 +244,62 @@ where
 {
     /// Create a new async fixture manager
     pub const fn new(provider: P) -> Self {
-        Self { provider }
+        Self { provider, teardown_timeout: DEFAULT_TEARDOWN_TIMEOUT }
+    }
+
+    /// Bound teardown in [`AsyncFixtureManager::run`] by `timeout`
+    #[must_use]
+    pub const fn with_teardown_timeout(mut self, timeout: Duration) -> Self {
+        self.teardown_timeout = timeout;
+        self
+    }
+
+    /// Run `body` against a fresh fixture, then tear the fixture down
+    ///
+    /// Panics in the body are caught so teardown always runs; teardown is cancelled if
+    /// it outlives the teardown timeout. Must run inside a Tokio runtime with time enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AsyncFixtureError::Setup`] if setup fails, [`AsyncFixtureError::Test`] if
+    /// the body panics (with any teardown error attached), and
+    /// [`AsyncFixtureError::Teardown`] if only teardown fails.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chicago_tdd_tools::core::async_fixture::{AsyncFixtureManager, DefaultAsyncFixtureProvider};
+    ///
+    /// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+    /// let manager = AsyncFixtureManager::new(DefaultAsyncFixtureProvider);
+    /// let error = manager.run(async |_fixture| panic!("body failed")).await.unwrap_err();
+    /// assert_eq!(error.failure().unwrap().message(), "body failed");
+    /// assert!(error.teardown().is_none());
+    /// # });
+    /// ```
+    #[allow(clippy::future_not_send)] // Trait design - Send bound is on trait, not implementation
+    pub async fn run<'a, T>(
+        &'a self,
+        body: impl AsyncFnOnce(&mut P::Fixture<'a>) -> T,
+    ) -> Result<T, AsyncFixtureError<P::Error>> {
+        let mut fixture = self.provider.create_fixture().await.map_err(AsyncFixtureError::Setup)?;
+        let outcome = catch_panics(body(&mut fixture)).await;
+        let teardown = match tokio::time::timeout(
+            self.teardown_timeout,
+            catch_panics(self.provider.teardown_fixture(fixture)),
+        )
+        .await
+        {
+            Ok(Ok(Ok(()))) => None,
+            Ok(Ok(Err(error))) => Some(TeardownError::Failed(error)),
+            Ok(Err(failure)) => Some(TeardownError::Panicked(failure)),
+            Err(_) => Some(TeardownError::TimedOut(self.teardown_timeout)),
+        };
+        match (outcome, teardown) {
+            (Ok(value), None) => Ok(value),
+            (Ok(_), Some(error)) => Err(AsyncFixtureError::Teardown(error)),
+            (Err(failure), teardown) => Err(AsyncFixtureError::Test { failure, teardown }),
+        }
     }
 
     /// Setup fixture asynchronously
@@ -196,13 +356,151 @@ impl AsyncFixtureProvider for DefaultAsyncFixtureProvider {
 #[cfg(feature = "async")]
 #[allow(clippy::panic)] // Test code - panic is appropriate for test failures
 mod tests {
-    use super::{AsyncFixtureManager, AsyncFixtureProvider, DefaultAsyncFixtureProvider};
+    use super::{
+        AsyncFixtureError, AsyncFixtureManager, AsyncFixtureProvider, DefaultAsyncFixtureProvider,
+        TeardownError,
+    };
     use crate::assert_eq_msg;
     use crate::assert_err;
     use crate::assert_ok;
     use crate::assertions::assert_that_with_msg;
     use crate::async_test;
     use crate::core::fixture::FixtureError;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    /// How [`TeardownProvider`] tears its fixture down
+    #[derive(Clone, Copy)]
+    enum Teardown {
+        Succeed,
+        Fail,
+        Hang,
+        Panic,
+    }
+
+    /// Provider that records whether teardown ran
+    struct TeardownProvider {
+        teardown: Teardown,
+        torn_down: AtomicBool,
+    }
+
+    impl TeardownProvider {
+        const fn new(teardown: Teardown) -> Self {
+            Self { teardown, torn_down: AtomicBool::new(false) }
+        }
+
+        fn torn_down(&self) -> bool {
+            self.torn_down.load(Ordering::SeqCst)
+        }
+    }
+
+    impl super::private::Sealed for TeardownProvider {}
+
+    impl AsyncFixtureProvider for TeardownProvider {
+        type Fixture<'a> = Vec<u32>;
+        type Error = FixtureError;
+
+        async fn create_fixture(&self) -> Result<Self::Fixture<'_>, Self::Error> {
+            Ok(Vec::new())
+        }
+
+        async fn teardown_fixture<'a>(&'a self, _fixture: Vec<u32>) -> Result<(), Self::Error> {
+            self.torn_down.store(true, Ordering::SeqCst);
+            match self.teardown {
+                Teardown::Succeed => Ok(()),
+                Teardown::Fail => Err(FixtureError::OperationFailed("drop schema".to_string())),
+                Teardown::Hang => {
+                    tokio::time::sleep(Duration::from_secs(60)).await;
+                    Ok(())
+                }
+                Teardown::Panic => panic!("teardown exploded"),
+            }
+        }
+    }
+
+    async_test!(test_run_tears_down_after_passing_body, {
+        // Arrange
+        let manager = AsyncFixtureManager::new(TeardownProvider::new(Teardown::Succeed));
+
+        // Act
+        let result = manager
+            .run(async |fixture| {
+                fixture.push(7);
+                fixture.len()
+            })
+            .await;
+
+        // Assert
+        assert_eq!(result.unwrap(), 1);
+        assert!(manager.provider.torn_down());
+    });
+
+    async_test!(test_run_tears_down_after_panicking_body, {
+        // Arrange
+        let manager = AsyncFixtureManager::new(TeardownProvider::new(Teardown::Succeed));
+
+        // Act
+        let error = manager
+            .run(async |fixture| {
+                tokio::task::yield_now().await;
+                assert_eq!(fixture.len(), 1, "fixture is empty");
+            })
+            .await
+            .unwrap_err();
+
+        // Assert: Teardown ran and the body's failure is the primary error
+        assert!(manager.provider.torn_down());
+        assert!(error.failure().unwrap().message().contains("fixture is empty"));
+        assert!(error.teardown().is_none());
+    });
+
+    async_test!(test_teardown_error_is_attached_to_body_failure, {
+        // Arrange
+        let manager = AsyncFixtureManager::new(TeardownProvider::new(Teardown::Fail));
+
+        // Act
+        let error = manager.run(async |_fixture| panic!("body failed")).await.unwrap_err();
+
+        // Assert
+        assert!(matches!(error.teardown(), Some(TeardownError::Failed(_))));
+        let rendered = error.to_string();
+        assert!(rendered.starts_with("body failed"), "{rendered}");
+        assert!(rendered.contains("teardown also failed"), "{rendered}");
+        assert!(rendered.contains("drop schema"), "{rendered}");
+    });
+
+    async_test!(test_teardown_failure_after_passing_body_is_reported, {
+        // Arrange
+        let manager = AsyncFixtureManager::new(TeardownProvider::new(Teardown::Panic));
+
+        // Act
+        let error = manager.run(async |_fixture| ()).await.unwrap_err();
+
+        // Assert
+        assert!(error.failure().is_none());
+        match error {
+            AsyncFixtureError::Teardown(TeardownError::Panicked(failure)) => {
+                assert_eq!(failure.message(), "teardown exploded");
+            }
+            other => panic!("Expected teardown panic, got {other:?}"),
+        }
+    });
+
+    async_test!(test_hung_teardown_times_out, {
+        // Arrange
+        let timeout = Duration::from_millis(50);
+        let manager = AsyncFixtureManager::new(TeardownProvider::new(Teardown::Hang))
+            .with_teardown_timeout(timeout);
+
+        // Act
+        let error = manager.run(async |_fixture| ()).await.unwrap_err();
+
+        // Assert
+        assert!(matches!(
+            error,
+            AsyncFixtureError::Teardown(TeardownError::TimedOut(elapsed)) if elapsed == timeout
+        ));
+    });
 
     #[derive(Debug)]
     struct TestAsyncFixture {
@@ -0,0 +1,519 @@
+//! Deterministic Fake Data
+//!
+//! Realistic-looking names, emails, UUIDs, addresses, ISO-8601 timestamps, and free
+//! text from a seeded generator. Hand-rolled `"test@test.com"` values make every
+//! record identical, which hides uniqueness and collision bugs; random values make
+//! failures unreproducible. Seeded values are varied *and* stable.
+//!
+//! [`Fake`] draws from a per-thread generator seeded from the current thread's name.
+//! libtest names each test's thread after the test, so every test gets its own stable
+//! sequence regardless of execution order. Set `CHICAGO_TDD_FAKE_SEED` to shift every
+//! sequence at once (e.g. to hunt for data-dependent bugs), or call [`Fake::seed`] to
+//! pin one test. Use [`FakeGen`] directly for an explicit generator.
+//!
+//! Emails use the reserved `example.com`/`.org`/`.net` domains and phone numbers the
+//! fictional `555-01xx` range, so generated data never reaches real people.
+//!
+//! The generator is self-contained (no external faker crate), so a given seed yields
+//! the same values on every platform and across dependency upgrades.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::builders::fake_data::{Fake, FakeGen};
+//! use chicago_tdd_tools::builders::GenericTestDataBuilder;
+//!
+//! Fake::seed(42);
+//! let data = GenericTestDataBuilder::<String, String>::new()
+//!     .with_name(Fake::name())
+//!     .with_email(Fake::email())
+//!     .with_uuid(Fake::uuid())
+//!     .build();
+//!
+//! // Same seed, same data
+//! let mut again = FakeGen::new(42);
+//! assert_eq!(data["name"], again.name());
+//! assert_eq!(data["email"], again.email());
+//! ```
+
+use std::cell::RefCell;
+use std::fmt::Write as _;
+
+/// Environment variable mixed into every thread's default seed
+pub const FAKE_SEED_ENV_VAR: &str = "CHICAGO_TDD_FAKE_SEED";
+
+const FIRST_NAMES: &[&str] = &[
+    "Ada", "Alan", "Amara", "Ana", "Aroha", "Bea", "Carlos", "Chen", "Dmitri", "Elif", "Emeka",
+    "Esther", "Farah", "Grace", "Hana", "Ibrahim", "Ingrid", "Jamal", "Jia", "Jonas", "Kofi",
+    "Lakshmi", "Leila", "Liam", "Lucia", "Mateo", "Mei", "Nadia", "Noah", "Olga", "Omar", "Priya",
+    "Rafael", "Rosa", "Sami", "Sofia", "Tariq", "Yuki", "Zainab", "Zoe",
+];
+
+const LAST_NAMES: &[&str] = &[
+    "Abara",
+    "Andersen",
+    "Bauer",
+    "Castillo",
+    "Chatterjee",
+    "Costa",
+    "Dubois",
+    "Eriksen",
+    "Fernandes",
+    "Garcia",
+    "Haddad",
+    "Hopper",
+    "Ivanova",
+    "Jensen",
+    "Kim",
+    "Kowalski",
+    "Lovelace",
+    "Mensah",
+    "Moreau",
+    "Nakamura",
+    "Novak",
+    "Okafor",
+    "Olsen",
+    "Park",
+    "Petrov",
+    "Quinn",
+    "Rossi",
+    "Santos",
+    "Schmidt",
+    "Silva",
+    "Tanaka",
+    "Turing",
+    "Umarov",
+    "Vargas",
+    "Wang",
+    "Weber",
+    "Xu",
+    "Yilmaz",
+    "Zhang",
+    "Zimmermann",
+];
+
+const EMAIL_DOMAINS: &[&str] = &["example.com", "example.org", "example.net"];
+
+const STREETS: &[&str] = &[
+    "Maple",
+    "Oak",
+    "Cedar",
+    "Elm",
+    "Lakeview",
+    "Hillcrest",
+    "Mill",
+    "River",
+    "Sunset",
+    "Park",
+    "Washington",
+    "Highland",
+    "Willow",
+    "Church",
+    "Station",
+    "Harbor",
+];
+
+const STREET_SUFFIXES: &[&str] = &["St", "Ave", "Rd", "Ln", "Blvd", "Way", "Ct", "Dr"];
+
+const CITIES: &[(&str, &str)] = &[
+    ("Springfield", "IL"),
+    ("Riverside", "CA"),
+    ("Fairview", "TX"),
+    ("Madison", "WI"),
+    ("Georgetown", "KY"),
+    ("Clinton", "NY"),
+    ("Franklin", "TN"),
+    ("Salem", "OR"),
+    ("Ashland", "OH"),
+    ("Burlington", "VT"),
+];
+
+const WORDS: &[&str] = &[
+    "lorem",
+    "ipsum",
+    "dolor",
+    "sit",
+    "amet",
+    "consectetur",
+    "adipiscing",
+    "elit",
+    "sed",
+    "do",
+    "eiusmod",
+    "tempor",
+    "incididunt",
+    "ut",
+    "labore",
+    "et",
+    "dolore",
+    "magna",
+    "aliqua",
+    "enim",
+    "ad",
+    "minim",
+    "veniam",
+    "quis",
+    "nostrud",
+    "exercitation",
+    "ullamco",
+    "laboris",
+    "nisi",
+    "aliquip",
+    "ex",
+    "ea",
+    "commodo",
+    "consequat",
+    "duis",
+    "aute",
+    "irure",
+    "in",
+    "reprehenderit",
+    "voluptate",
+    "velit",
+    "esse",
+    "cillum",
+    "fugiat",
+    "nulla",
+    "pariatur",
+];
+
+/// First instant generated timestamps may take (2000-01-01T00:00:00Z)
+const TIMESTAMP_START: i64 = 946_684_800;
+/// Span of generated timestamps (30 years)
+const TIMESTAMP_SPAN_SECS: u64 = 30 * 365 * 24 * 60 * 60;
+
+/// Seeded fake data generator
+///
+/// The same seed always produces the same sequence of values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FakeGen {
+    seed: u64,
+    state: u64,
+}
+
+impl FakeGen {
+    /// Generator starting from `seed`
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { seed, state: seed }
+    }
+
+    /// Seed this generator was created with
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Next raw 64-bit value (`SplitMix64`)
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..bound` (`bound` > 0)
+    pub fn below(&mut self, bound: u64) -> u64 {
+        // Multiply-shift reduction: unbiased enough for test data, never panics
+        let wide = u128::from(self.next_u64()) * u128::from(bound.max(1));
+        u64::try_from(wide >> 64).unwrap_or(0)
+    }
+
+    /// Uniform value in `min..=max`
+    pub fn range(&mut self, min: i64, max: i64) -> i64 {
+        let (low, high) = if min <= max { (min, max) } else { (max, min) };
+        let span = high.abs_diff(low).saturating_add(1);
+        low.wrapping_add_unsigned(self.below(span))
+    }
+
+    /// One element of `items`
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        let index = usize::try_from(self.below(items.len() as u64)).unwrap_or(0);
+        &items[index]
+    }
+
+    /// First name
+    pub fn first_name(&mut self) -> String {
+        (*self.pick(FIRST_NAMES)).to_string()
+    }
+
+    /// Last name
+    pub fn last_name(&mut self) -> String {
+        (*self.pick(LAST_NAMES)).to_string()
+    }
+
+    /// Full name, e.g. `Amara Okafor`
+    pub fn name(&mut self) -> String {
+        format!("{} {}", self.first_name(), self.last_name())
+    }
+
+    /// Email at a reserved example domain, e.g. `amara.okafor417@example.org`
+    pub fn email(&mut self) -> String {
+        let first = self.first_name().to_lowercase();
+        let last = self.last_name().to_lowercase();
+        let number = self.below(1000);
+        let domain = self.pick(EMAIL_DOMAINS);
+        format!("{first}.{last}{number}@{domain}")
+    }
+
+    /// Random (version 4) UUID in hyphenated form
+    pub fn uuid(&mut self) -> String {
+        let high = (self.next_u64() & 0xFFFF_FFFF_FFFF_0FFF) | 0x0000_0000_0000_4000;
+        let low = (self.next_u64() & 0x3FFF_FFFF_FFFF_FFFF) | 0x8000_0000_0000_0000;
+        format!(
+            "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+            high >> 32,
+            (high >> 16) & 0xFFFF,
+            high & 0xFFFF,
+            low >> 48,
+            low & 0xFFFF_FFFF_FFFF
+        )
+    }
+
+    /// Fictional US phone number, e.g. `+1-312-555-0147`
+    pub fn phone(&mut self) -> String {
+        let area = self.range(201, 989);
+        let line = self.below(100);
+        format!("+1-{area}-555-01{line:02}")
+    }
+
+    /// Postal address, e.g. `742 Maple Ave, Springfield, IL 62704`
+    pub fn address(&mut self) -> String {
+        let number = self.range(1, 9999);
+        let street = self.pick(STREETS);
+        let suffix = self.pick(STREET_SUFFIXES);
+        let (city, state) = self.pick(CITIES);
+        let zip = self.range(10_000, 99_999);
+        format!("{number} {street} {suffix}, {city}, {state} {zip}")
+    }
+
+    /// UTC timestamp between 2000 and 2030 in RFC 3339 form, e.g. `2014-06-09T17:03:51Z`
+    pub fn timestamp(&mut self) -> String {
+        let offset = i64::try_from(self.below(TIMESTAMP_SPAN_SECS)).unwrap_or(0);
+        iso8601(TIMESTAMP_START + offset)
+    }
+
+    /// `count` lorem-ipsum words separated by spaces
+    pub fn words(&mut self, count: usize) -> String {
+        (0..count).map(|_| *self.pick(WORDS)).collect::<Vec<_>>().join(" ")
+    }
+
+    /// Capitalized sentence of 6 to 14 words ending in a period
+    pub fn sentence(&mut self) -> String {
+        let count = usize::try_from(self.range(6, 14)).unwrap_or(6);
+        let mut words = self.words(count);
+        if let Some(first) = words.get_mut(..1) {
+            first.make_ascii_uppercase();
+        }
+        words.push('.');
+        words
+    }
+
+    /// Free text of `sentences` sentences
+    pub fn text(&mut self, sentences: usize) -> String {
+        (0..sentences).map(|_| self.sentence()).collect::<Vec<_>>().join(" ")
+    }
+}
+
+/// Seconds since the UNIX epoch → `YYYY-MM-DDTHH:MM:SSZ`
+fn iso8601(secs: i64) -> String {
+    let days = secs.div_euclid(86_400);
+    let time = secs.rem_euclid(86_400);
+    // Civil-from-days (H. Hinnant), valid for the proleptic Gregorian calendar
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    let mut out = String::with_capacity(20);
+    let _ = write!(
+        out,
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60
+    );
+    out
+}
+
+/// FNV-1a, used to turn thread names into seeds
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &b| {
+        (hash ^ u64::from(b)).wrapping_mul(0x0000_0100_0000_01B3)
+    })
+}
+
+/// Default seed for the current thread (its name, mixed with `CHICAGO_TDD_FAKE_SEED`)
+fn thread_seed() -> u64 {
+    let base = std::env::var(FAKE_SEED_ENV_VAR).ok().and_then(|s| s.parse::<u64>().ok());
+    let name = std::thread::current().name().map(|n| fnv1a(n.as_bytes())).unwrap_or_default();
+    name ^ base.unwrap_or_default()
+}
+
+thread_local! {
+    static THREAD_GEN: RefCell<Option<FakeGen>> = const { RefCell::new(None) };
+}
+
+/// Fake values from the current thread's seeded generator
+///
+/// See the [module docs](self) for how the per-thread seed is chosen.
+#[derive(Debug, Clone, Copy)]
+pub struct Fake;
+
+impl Fake {
+    /// Restart the current thread's sequence from `seed`
+    pub fn seed(seed: u64) {
+        THREAD_GEN.with(|slot| *slot.borrow_mut() = Some(FakeGen::new(seed)));
+    }
+
+    /// Seed of the current thread's sequence (log it to reproduce a failure)
+    #[must_use]
+    pub fn current_seed() -> u64 {
+        Self::with(|fake| fake.seed())
+    }
+
+    /// Run `f` with the current thread's generator
+    pub fn with<T>(f: impl FnOnce(&mut FakeGen) -> T) -> T {
+        THREAD_GEN.with(|slot| {
+            let mut slot = slot.borrow_mut();
+            f(slot.get_or_insert_with(|| FakeGen::new(thread_seed())))
+        })
+    }
+
+    /// See [`FakeGen::name`]
+    #[must_use]
+    pub fn name() -> String {
+        Self::with(FakeGen::name)
+    }
+
+    /// See [`FakeGen::first_name`]
+    #[must_use]
+    pub fn first_name() -> String {
+        Self::with(FakeGen::first_name)
+    }
+
+    /// See [`FakeGen::last_name`]
+    #[must_use]
+    pub fn last_name() -> String {
+        Self::with(FakeGen::last_name)
+    }
+
+    /// See [`FakeGen::email`]
+    #[must_use]
+    pub fn email() -> String {
+        Self::with(FakeGen::email)
+    }
+
+    /// See [`FakeGen::uuid`]
+    #[must_use]
+    pub fn uuid() -> String {
+        Self::with(FakeGen::uuid)
+    }
+
+    /// See [`FakeGen::phone`]
+    #[must_use]
+    pub fn phone() -> String {
+        Self::with(FakeGen::phone)
+    }
+
+    /// See [`FakeGen::address`]
+    #[must_use]
+    pub fn address() -> String {
+        Self::with(FakeGen::address)
+    }
+
+    /// See [`FakeGen::timestamp`]
+    #[must_use]
+    pub fn timestamp() -> String {
+        Self::with(FakeGen::timestamp)
+    }
+
+    /// See [`FakeGen::words`]
+    #[must_use]
+    pub fn words(count: usize) -> String {
+        Self::with(|fake| fake.words(count))
+    }
+
+    /// See [`FakeGen::sentence`]
+    #[must_use]
+    pub fn sentence() -> String {
+        Self::with(FakeGen::sentence)
+    }
+
+    /// See [`FakeGen::text`]
+    #[must_use]
+    pub fn text(sentences: usize) -> String {
+        Self::with(|fake| fake.text(sentences))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::collections::HashSet;
+
+    test!(test_same_seed_same_values, {
+        // Arrange
+        let mut first = FakeGen::new(7);
+        let mut second = FakeGen::new(7);
+
+        // Act
+        let a: Vec<String> = (0..5).map(|_| first.email()).collect();
+        let b: Vec<String> = (0..5).map(|_| second.email()).collect();
+
+        // Assert
+        assert_eq!(a, b);
+        assert_ne!(FakeGen::new(8).email(), a[0]);
+    });
+
+    test!(test_values_are_well_formed, {
+        // Arrange
+        let mut fake = FakeGen::new(2024);
+
+        // Act
+        let email = fake.email();
+        let uuid = fake.uuid();
+        let timestamp = fake.timestamp();
+        let sentence = fake.sentence();
+
+        // Assert
+        let (local, domain) = email.split_once('@').unwrap();
+        assert!(local.contains('.') && domain.starts_with("example."), "{email}");
+        assert_eq!(uuid.len(), 36);
+        assert_eq!(&uuid[14..15], "4", "{uuid}");
+        assert!(matches!(&uuid[19..20], "8" | "9" | "a" | "b"), "{uuid}");
+        assert_eq!(timestamp.len(), 20, "{timestamp}");
+        assert!(("2000".."2031").contains(&&timestamp[..4]), "{timestamp}");
+        assert!(sentence.ends_with('.') && sentence.starts_with(char::is_uppercase));
+        assert!(fake.address().contains(", "));
+    });
+
+    test!(test_iso8601_known_instants, {
+        // Arrange, Act, Assert
+        assert_eq!(iso8601(0), "1970-01-01T00:00:00Z");
+        assert_eq!(iso8601(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(iso8601(1_700_000_000), "2023-11-14T22:13:20Z");
+    });
+
+    test!(test_thread_sequence_is_stable_and_reseedable, {
+        // Arrange
+        Fake::seed(99);
+        let first = (Fake::name(), Fake::uuid());
+
+        // Act
+        Fake::seed(99);
+        let replay = (Fake::name(), Fake::uuid());
+        let emails: HashSet<String> = (0..50).map(|_| Fake::email()).collect();
+
+        // Assert
+        assert_eq!(first, replay);
+        assert_eq!(Fake::current_seed(), 99);
+        assert!(emails.len() > 45, "too many colliding emails: {}", emails.len());
+    });
+}
@@ -10,11 +10,15 @@
 //! - **1st Idea**: `TestDataBuilder` - Specific `HashMap<String, String>` implementation
 //! - **2nd Idea**: `GenericTestDataBuilder<K, V>` - Generic builder for any `K: Into<String>, V: Into<String>`
 //! - **3rd Idea**: `ValidatedTestDataBuilder<T>` - Type-level validated builder with OTEL/Weaver validation
+//!
+//! Seeded, reproducible fake values (names, emails, UUIDs, ...) live in [`fake_data`].
 
 use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::{Mutex, OnceLock};
 
+pub mod fake_data;
+
 #[cfg(feature = "fake-data")]
 use fake::{Fake, Faker};
 
@@ -538,6 +542,42 @@ where
         self
     }
 
+    /// Set `name` (pair with [`fake_data::Fake::name`] for stable realistic values)
+    #[must_use]
+    pub fn with_name(self, name: impl Into<String>) -> Self {
+        self.with_var("name", name)
+    }
+
+    /// Set `email` (pair with [`fake_data::Fake::email`])
+    #[must_use]
+    pub fn with_email(self, email: impl Into<String>) -> Self {
+        self.with_var("email", email)
+    }
+
+    /// Set `uuid` (pair with [`fake_data::Fake::uuid`])
+    #[must_use]
+    pub fn with_uuid(self, uuid: impl Into<String>) -> Self {
+        self.with_var("uuid", uuid)
+    }
+
+    /// Set `address` (pair with [`fake_data::Fake::address`])
+    #[must_use]
+    pub fn with_address(self, address: impl Into<String>) -> Self {
+        self.with_var("address", address)
+    }
+
+    /// Set `timestamp` (pair with [`fake_data::Fake::timestamp`])
+    #[must_use]
+    pub fn with_timestamp(self, timestamp: impl Into<String>) -> Self {
+        self.with_var("timestamp", timestamp)
+    }
+
+    /// Set `text` (pair with [`fake_data::Fake::text`])
+    #[must_use]
+    pub fn with_text(self, text: impl Into<String>) -> Self {
+        self.with_var("text", text)
+    }
+
     /// Build test data as `HashMap`
     #[must_use]
     pub fn build(self) -> HashMap<String, String> {
@@ -0,0 +1,420 @@
+//! > 📚 Reference
+//!
+//! Test Cancellation
+//!
+//! A [`TestCancellation`] token carries a test-level deadline into every framework wait
+//! loop: [`assert_eventually!`](crate::assert_eventually) and `poll_until`,
+//! `CheckedCommand` timeouts, sidecar readiness, Docker and container readiness probes,
+//! and Weaver drains. Once the token is cancelled (explicitly, or because its deadline
+//! passed) those waits stop immediately instead of running out their own timeouts, and
+//! record what they were waiting for. [`TestCancellation::run`] then fails the test with
+//! one consolidated timeout report rather than a stack of nested timeout errors.
+//!
+//! Waits find the token through the current thread: `run` (or [`TestCancellation::enter`])
+//! makes it current for the duration of the body. Code running without a current token
+//! behaves exactly as before. Async waits honour the token's deadline, but tasks that
+//! migrate between runtime threads only see it on the thread that entered it.
+//!
+//! # Example
+//!
+//! ```rust,should_panic
+//! use chicago_tdd_tools::assert_eventually;
+//! use chicago_tdd_tools::core::cancellation::TestCancellation;
+//! use std::time::Duration;
+//!
+//! // The inner wait would poll for 5 s; the test-level timeout stops it after 50 ms
+//! let queue_drained = || false;
+//! TestCancellation::with_timeout(Duration::from_millis(50)).run(|| {
+//!     assert_eventually!(queue_drained(), timeout = Duration::from_secs(5));
+//! });
+//! ```
+
+use crate::core::failure::{FailureKind, TddFailure};
+use std::cell::RefCell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// A wait stopped because its [`TestCancellation`] was cancelled
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("{wait} cancelled: {reason}")]
+pub struct Cancelled {
+    /// What was being waited for
+    pub wait: String,
+    /// Why the token was cancelled
+    pub reason: String,
+}
+
+/// A wait that was cut short by cancellation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterruptedWait {
+    /// What was being waited for
+    pub wait: String,
+    /// When it stopped, measured from the token's creation
+    pub at: Duration,
+}
+
+/// Consolidated report of a cancelled test
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancellationReport {
+    /// Why the token was cancelled (`None` if it was not)
+    pub reason: Option<String>,
+    /// Time since the token was created
+    pub elapsed: Duration,
+    /// Waits that stopped early, innermost (first interrupted) first
+    pub interrupted: Vec<InterruptedWait>,
+}
+
+impl fmt::Display for CancellationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.reason {
+            Some(reason) => write!(f, "Test cancelled after {:?}: {reason}", self.elapsed)?,
+            None => write!(f, "Test not cancelled ({:?} elapsed)", self.elapsed)?,
+        }
+        if !self.interrupted.is_empty() {
+            write!(f, "\n   interrupted waits (innermost first):")?;
+            for wait in &self.interrupted {
+                write!(f, "\n   - {} (stopped at {:?})", wait.wait, wait.at)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default)]
+struct State {
+    reason: Option<String>,
+    interrupted: Vec<InterruptedWait>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    started: Instant,
+    timeout: Option<Duration>,
+    state: Mutex<State>,
+    wake: Condvar,
+}
+
+/// > 📚 Reference
+///
+/// Cancellation token shared by a test and the framework waits it runs.
+///
+/// Cloning is cheap; clones share state, so any clone can cancel every wait. See the
+/// [module docs](self).
+#[derive(Debug, Clone)]
+pub struct TestCancellation {
+    inner: Arc<Inner>,
+}
+
+impl Default for TestCancellation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Vec<TestCancellation>> = const { RefCell::new(Vec::new()) };
+}
+
+impl TestCancellation {
+    /// Token without a deadline (cancelled only by [`TestCancellation::cancel`])
+    #[must_use]
+    pub fn new() -> Self {
+        Self::build(None)
+    }
+
+    /// Token that cancels itself once `timeout` has passed
+    #[must_use]
+    pub fn with_timeout(timeout: Duration) -> Self {
+        Self::build(Some(timeout))
+    }
+
+    fn build(timeout: Option<Duration>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                started: Instant::now(),
+                timeout,
+                state: Mutex::new(State::default()),
+                wake: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Token current on this thread (the innermost entered one)
+    #[must_use]
+    pub fn current() -> Option<Self> {
+        CURRENT.with(|current| current.borrow().last().cloned())
+    }
+
+    /// Make this token current on this thread until the guard drops
+    #[must_use = "the token is only current while the guard is alive"]
+    pub fn enter(&self) -> CancellationGuard {
+        CURRENT.with(|current| current.borrow_mut().push(self.clone()));
+        CancellationGuard { _not_send: std::marker::PhantomData }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.inner.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Cancel every wait using this token (the first reason wins)
+    pub fn cancel(&self, reason: impl Into<String>) {
+        let mut state = self.state();
+        if state.reason.is_none() {
+            state.reason = Some(reason.into());
+        }
+        drop(state);
+        self.inner.wake.notify_all();
+    }
+
+    /// Mark the token cancelled if its deadline has passed; returns the reason if cancelled
+    fn poll(&self, state: &mut State) -> Option<String> {
+        if state.reason.is_none() {
+            if let Some(timeout) = self.inner.timeout {
+                if self.inner.started.elapsed() >= timeout {
+                    state.reason = Some(format!("test timeout of {timeout:?} exceeded"));
+                }
+            }
+        }
+        state.reason.clone()
+    }
+
+    /// Whether the token was cancelled or its deadline has passed
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.poll(&mut self.state()).is_some()
+    }
+
+    /// Why the token was cancelled
+    #[must_use]
+    pub fn reason(&self) -> Option<String> {
+        self.poll(&mut self.state())
+    }
+
+    /// Time left before the deadline (`None` without a deadline)
+    #[must_use]
+    pub fn remaining(&self) -> Option<Duration> {
+        self.inner
+            .timeout
+            .map(|timeout| timeout.saturating_sub(self.inner.started.elapsed()))
+    }
+
+    /// Time since the token was created
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.inner.started.elapsed()
+    }
+
+    /// Fail fast if the token is cancelled, recording `wait` as interrupted
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Cancelled`] once the token is cancelled.
+    pub fn check(&self, wait: &str) -> Result<(), Cancelled> {
+        let mut state = self.state();
+        self.poll(&mut state)
+            .map_or(Ok(()), |reason| Err(self.interrupt(&mut state, wait, reason)))
+    }
+
+    /// Sleep for `duration`, waking early (with an error) if the token is cancelled
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Cancelled`] if the token is cancelled before or during the sleep.
+    pub fn sleep(&self, wait: &str, duration: Duration) -> Result<(), Cancelled> {
+        let until = Instant::now() + duration;
+        let mut state = self.state();
+        loop {
+            if let Some(reason) = self.poll(&mut state) {
+                return Err(self.interrupt(&mut state, wait, reason));
+            }
+            let now = Instant::now();
+            if now >= until {
+                return Ok(());
+            }
+            let mut nap = until - now;
+            if let Some(remaining) = self.remaining() {
+                // Wake at the deadline so it is noticed without an explicit cancel
+                nap = nap.min(remaining.max(Duration::from_millis(1)));
+            }
+            state = self
+                .inner
+                .wake
+                .wait_timeout(state, nap)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    fn interrupt(&self, state: &mut State, wait: &str, reason: String) -> Cancelled {
+        state
+            .interrupted
+            .push(InterruptedWait { wait: wait.to_string(), at: self.elapsed() });
+        Cancelled { wait: wait.to_string(), reason }
+    }
+
+    /// Snapshot of the cancellation state and interrupted waits
+    #[must_use]
+    pub fn report(&self) -> CancellationReport {
+        let mut state = self.state();
+        let reason = self.poll(&mut state);
+        CancellationReport {
+            reason,
+            elapsed: self.elapsed(),
+            interrupted: state.interrupted.clone(),
+        }
+    }
+
+    /// Run `body` with this token current, failing with a consolidated timeout report if
+    /// the token is cancelled by the time the body returns or panics
+    ///
+    /// A panic raised while the token is cancelled (typically an inner wait's error being
+    /// unwrapped) is replaced by the report; other panics propagate unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a [`FailureKind::Timeout`] failure if the token is cancelled.
+    #[track_caller]
+    pub fn run<T>(&self, body: impl FnOnce() -> T) -> T {
+        let outcome = {
+            let _guard = self.enter();
+            panic::catch_unwind(AssertUnwindSafe(body))
+        };
+        if !self.is_cancelled() {
+            return outcome.unwrap_or_else(|payload| panic::resume_unwind(payload));
+        }
+        let report = self.report();
+        let mut failure = TddFailure::new(FailureKind::Timeout, report.to_string())
+            .with_context("elapsed", format!("{:?}", report.elapsed))
+            .with_context("interrupted_waits", report.interrupted.len().to_string());
+        if let Some(timeout) = self.inner.timeout {
+            failure = failure.with_context("timeout", format!("{timeout:?}"));
+        }
+        if let Err(payload) = outcome {
+            failure =
+                failure.with_context("inner_failure", TddFailure::from_payload(payload).message());
+        }
+        failure.raise()
+    }
+}
+
+/// Keeps a [`TestCancellation`] current on this thread; see [`TestCancellation::enter`]
+#[derive(Debug)]
+pub struct CancellationGuard {
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+impl Drop for CancellationGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.borrow_mut().pop());
+    }
+}
+
+/// Sleep for `duration` honouring the current token, if any
+///
+/// Wait loops call this in place of `std::thread::sleep`, labelling what they wait for.
+///
+/// # Errors
+///
+/// Returns [`Cancelled`] if the current token is cancelled before or during the sleep.
+pub fn cancellable_sleep(wait: &str, duration: Duration) -> Result<(), Cancelled> {
+    if let Some(token) = TestCancellation::current() {
+        return token.sleep(wait, duration);
+    }
+    std::thread::sleep(duration);
+    Ok(())
+}
+
+/// Fail fast if the current token, if any, is cancelled
+///
+/// # Errors
+///
+/// Returns [`Cancelled`] once the current token is cancelled.
+pub fn check_cancelled(wait: &str) -> Result<(), Cancelled> {
+    TestCancellation::current().map_or(Ok(()), |token| token.check(wait))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_cancel_wakes_sleeping_wait_immediately, {
+        // Arrange
+        let token = TestCancellation::new();
+        let canceller = token.clone();
+        let handle = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(20));
+            canceller.cancel("shutdown");
+        });
+
+        // Act
+        let started = Instant::now();
+        let result = token.sleep("queue drain", Duration::from_secs(10));
+        handle.join().unwrap();
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(Cancelled { wait: "queue drain".to_string(), reason: "shutdown".to_string() })
+        );
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(token.report().interrupted[0].wait, "queue drain");
+    });
+
+    test!(test_deadline_cancels_without_explicit_cancel, {
+        // Arrange
+        let token = TestCancellation::with_timeout(Duration::from_millis(20));
+        let _guard = token.enter();
+
+        // Act
+        let result = cancellable_sleep("port 8080", Duration::from_secs(10));
+
+        // Assert
+        assert!(result.is_err());
+        assert!(token.reason().unwrap().contains("timeout of 20ms exceeded"));
+        assert_eq!(token.remaining(), Some(Duration::ZERO));
+        assert!(check_cancelled("next wait").is_err());
+    });
+
+    test!(test_no_current_token_sleeps_normally, {
+        // Act
+        let result = cancellable_sleep("anything", Duration::from_millis(1));
+
+        // Assert
+        assert!(result.is_ok());
+        assert!(TestCancellation::current().is_none());
+    });
+
+    test!(test_run_replaces_nested_failure_with_consolidated_report, {
+        // Arrange
+        let token = TestCancellation::with_timeout(Duration::from_millis(30));
+
+        // Act
+        let failure = TddFailure::catch(|| {
+            token.run(|| {
+                let inner = TestCancellation::current().unwrap();
+                inner.sleep("sidecar `mock` readiness", Duration::from_secs(10)).unwrap();
+            });
+        })
+        .unwrap_err();
+
+        // Assert
+        assert_eq!(failure.kind(), FailureKind::Timeout);
+        assert!(failure.message().contains("Test cancelled after"), "{}", failure.message());
+        assert!(failure.message().contains("- sidecar `mock` readiness (stopped at"));
+        assert_eq!(failure.context()["interrupted_waits"], "1");
+        assert!(failure.context()["inner_failure"].contains("sidecar `mock` readiness"));
+        assert!(TestCancellation::current().is_none());
+    });
+
+    test!(test_run_passes_through_when_not_cancelled, {
+        // Act
+        let value = TestCancellation::with_timeout(Duration::from_secs(10)).run(|| 42);
+
+        // Assert
+        assert_eq!(value, 42);
+    });
+}
@@ -0,0 +1,545 @@
+//! > 📚 Reference
+//!
+//! Checked External Commands
+//!
+//! `CheckedCommand` wraps `std::process::Command` with the three things every external
+//! call in a test suite needs: a hard timeout, captured output, and a structured error
+//! that carries the full command line. All of the framework's own calls to `docker`,
+//! `git`, `weaver`, `curl`, `wget`, and `tar` go through it, and it is exported for user
+//! tests as well.
+//!
+//! **Root Cause Fix**: Hand-rolled per-call timeout threads were inconsistent (some
+//! calls had none) and leaked the child on timeout. `CheckedCommand` kills and reaps
+//! the child when its deadline passes. See `docs/features/TIMEOUT_ENFORCEMENT.md`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::command::{CheckedCommand, CommandError};
+//! use std::time::Duration;
+//!
+//! # #[cfg(unix)]
+//! # {
+//! let output = CheckedCommand::new("echo").arg("hello").run().unwrap();
+//! assert_eq!(output.stdout_lossy().trim(), "hello");
+//!
+//! let err = CheckedCommand::new("sleep")
+//!     .arg("5")
+//!     .timeout(Duration::from_millis(50))
+//!     .run()
+//!     .unwrap_err();
+//! assert!(matches!(err, CommandError::TimedOut { .. }));
+//! # }
+//! ```
+
+use crate::core::cancellation::cancellable_sleep;
+use crate::core::messages::{message, MessageId};
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Default timeout for external commands (integration test SLA)
+pub const DEFAULT_COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Timeout for availability probes such as `<program> --version` or `docker info`
+pub const PROBE_COMMAND_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Timeout for network-bound commands (`git clone`, `curl`, `wget`)
+pub const DOWNLOAD_COMMAND_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Longest sleep between exit-status polls
+const MAX_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Extra time allowed for output pipes to close after the child exits
+///
+/// A grandchild that inherited the pipes can keep them open; output reading gives
+/// up after this grace period rather than hanging past the deadline.
+const PIPE_GRACE: Duration = Duration::from_millis(100);
+
+/// External command errors
+///
+/// Every variant carries the rendered command line so failures are diagnosable
+/// from the message alone.
+#[derive(Error, Debug)]
+pub enum CommandError {
+    /// The program does not exist
+    #[error("{}", message(MessageId::COMMAND_NOT_FOUND, &[("command", command)]))]
+    NotFound {
+        /// Rendered command line
+        command: String,
+    },
+    /// The program exists but could not be started
+    #[error("{}", message(MessageId::COMMAND_SPAWN_FAILED, &[("command", command), ("source", source)]))]
+    SpawnFailed {
+        /// Rendered command line
+        command: String,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// Waiting for or communicating with the child failed
+    #[error("{}", message(MessageId::COMMAND_IO, &[("command", command), ("source", source)]))]
+    Io {
+        /// Rendered command line
+        command: String,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// The deadline passed; the child was killed
+    #[error("{}", message(MessageId::COMMAND_TIMEOUT, &[("command", command), ("timeout", &format!("{timeout:?}")), ("stderr", stderr)]))]
+    TimedOut {
+        /// Rendered command line
+        command: String,
+        /// Configured timeout
+        timeout: Duration,
+        /// Output captured before the kill
+        stdout: String,
+        /// Error output captured before the kill
+        stderr: String,
+    },
+    /// The current [`TestCancellation`](crate::core::cancellation::TestCancellation) was
+    /// cancelled; the child was killed
+    #[error("{}", message(MessageId::COMMAND_CANCELLED, &[("command", command), ("reason", reason)]))]
+    Cancelled {
+        /// Rendered command line
+        command: String,
+        /// Why the test was cancelled
+        reason: String,
+        /// Output captured before the kill
+        stdout: String,
+        /// Error output captured before the kill
+        stderr: String,
+    },
+    /// The command exited unsuccessfully (only from [`CheckedCommand::run`])
+    #[error("{}", message(MessageId::COMMAND_FAILED, &[("command", command), ("status", status), ("stderr", stderr)]))]
+    Failed {
+        /// Rendered command line
+        command: String,
+        /// Exit status
+        status: ExitStatus,
+        /// Captured stdout
+        stdout: String,
+        /// Captured stderr
+        stderr: String,
+    },
+}
+
+impl CommandError {
+    /// Rendered command line of the failing command
+    #[must_use]
+    pub fn command(&self) -> &str {
+        match self {
+            Self::NotFound { command }
+            | Self::SpawnFailed { command, .. }
+            | Self::Io { command, .. }
+            | Self::TimedOut { command, .. }
+            | Self::Cancelled { command, .. }
+            | Self::Failed { command, .. } => command,
+        }
+    }
+
+    /// Whether the program itself is missing
+    #[must_use]
+    pub const fn is_not_found(&self) -> bool {
+        matches!(self, Self::NotFound { .. })
+    }
+}
+
+/// Result type for external commands
+pub type CommandResult<T> = Result<T, CommandError>;
+
+/// Captured result of a completed command
+#[derive(Debug, Clone)]
+pub struct CommandOutput {
+    /// Rendered command line
+    pub command: String,
+    /// Exit status
+    pub status: ExitStatus,
+    /// Raw stdout
+    pub stdout: Vec<u8>,
+    /// Raw stderr
+    pub stderr: Vec<u8>,
+    /// Wall-clock run time
+    pub elapsed: Duration,
+}
+
+impl CommandOutput {
+    /// Whether the command exited successfully
+    #[must_use]
+    pub fn success(&self) -> bool {
+        self.status.success()
+    }
+
+    /// Exit code, or `None` if the command was killed by a signal
+    #[must_use]
+    pub fn code(&self) -> Option<i32> {
+        self.status.code()
+    }
+
+    /// Stdout decoded as UTF-8 (lossy)
+    #[must_use]
+    pub fn stdout_lossy(&self) -> String {
+        String::from_utf8_lossy(&self.stdout).into_owned()
+    }
+
+    /// Stderr decoded as UTF-8 (lossy)
+    #[must_use]
+    pub fn stderr_lossy(&self) -> String {
+        String::from_utf8_lossy(&self.stderr).into_owned()
+    }
+}
+
+/// External command with a mandatory timeout and captured output
+#[derive(Debug, Clone)]
+pub struct CheckedCommand {
+    program: OsString,
+    args: Vec<OsString>,
+    env: BTreeMap<OsString, OsString>,
+    current_dir: Option<PathBuf>,
+    stdin: Option<Vec<u8>>,
+    timeout: Duration,
+}
+
+impl CheckedCommand {
+    /// Create a command for `program` with [`DEFAULT_COMMAND_TIMEOUT`]
+    #[must_use]
+    pub fn new(program: impl Into<OsString>) -> Self {
+        Self {
+            program: program.into(),
+            args: Vec::new(),
+            env: BTreeMap::new(),
+            current_dir: None,
+            stdin: None,
+            timeout: DEFAULT_COMMAND_TIMEOUT,
+        }
+    }
+
+    /// Append an argument
+    #[must_use]
+    pub fn arg(mut self, arg: impl Into<OsString>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Append several arguments
+    #[must_use]
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<OsString>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set an environment variable for the child
+    #[must_use]
+    pub fn env(mut self, key: impl Into<OsString>, value: impl Into<OsString>) -> Self {
+        self.env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the child's working directory
+    #[must_use]
+    pub fn current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(dir.into());
+        self
+    }
+
+    /// Bytes written to the child's stdin (stdin is null otherwise)
+    #[must_use]
+    pub fn stdin(mut self, input: impl Into<Vec<u8>>) -> Self {
+        self.stdin = Some(input.into());
+        self
+    }
+
+    /// Override the timeout
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Configured timeout
+    #[must_use]
+    pub const fn timeout_duration(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Command line rendered for messages, e.g. `docker rm -f abc`
+    #[must_use]
+    pub fn command_line(&self) -> String {
+        std::iter::once(&self.program)
+            .chain(&self.args)
+            .map(|part| {
+                let part = part.to_string_lossy();
+                if part.is_empty() || part.contains(char::is_whitespace) {
+                    format!("{part:?}")
+                } else {
+                    part.into_owned()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn build(&self) -> Command {
+        let mut command = Command::new(&self.program);
+        command.args(&self.args).envs(&self.env);
+        if let Some(dir) = &self.current_dir {
+            command.current_dir(dir);
+        }
+        command
+    }
+
+    fn spawn_error(&self, source: std::io::Error) -> CommandError {
+        let command = self.command_line();
+        if source.kind() == std::io::ErrorKind::NotFound {
+            CommandError::NotFound { command }
+        } else {
+            CommandError::SpawnFailed { command, source }
+        }
+    }
+
+    /// Run to completion (or timeout) and return the output regardless of exit status
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CommandError::NotFound`], [`CommandError::SpawnFailed`],
+    /// [`CommandError::Io`], [`CommandError::TimedOut`], or [`CommandError::Cancelled`].
+    pub fn output(&self) -> CommandResult<CommandOutput> {
+        let started = Instant::now();
+        let mut child = self
+            .build()
+            .stdin(if self.stdin.is_some() { Stdio::piped() } else { Stdio::null() })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| self.spawn_error(e))?;
+
+        if let (Some(mut pipe), Some(input)) = (child.stdin.take(), self.stdin.clone()) {
+            // Detached writer; broken pipes are ignored since the child may exit without
+            // reading its input
+            thread::spawn(move || drop(pipe.write_all(&input)));
+        }
+        let stdout = child.stdout.take().map(drain);
+        let stderr = child.stderr.take().map(drain);
+
+        let status = self.wait_with_deadline(&mut child, started);
+        let mut pipes_deadline = started + self.timeout;
+        if matches!(status, Ok(Waited::Cancelled(_))) {
+            pipes_deadline = pipes_deadline.min(Instant::now());
+        }
+        let stdout = collect(stdout, pipes_deadline);
+        let stderr = collect(stderr, pipes_deadline);
+        let lossy = |bytes: &[u8]| String::from_utf8_lossy(bytes).into_owned();
+
+        match status {
+            Ok(Waited::Exited(status)) => Ok(CommandOutput {
+                command: self.command_line(),
+                status,
+                stdout,
+                stderr,
+                elapsed: started.elapsed(),
+            }),
+            Ok(Waited::TimedOut) => Err(CommandError::TimedOut {
+                command: self.command_line(),
+                timeout: self.timeout,
+                stdout: lossy(&stdout),
+                stderr: lossy(&stderr),
+            }),
+            Ok(Waited::Cancelled(reason)) => Err(CommandError::Cancelled {
+                command: self.command_line(),
+                reason,
+                stdout: lossy(&stdout),
+                stderr: lossy(&stderr),
+            }),
+            Err(source) => Err(CommandError::Io { command: self.command_line(), source }),
+        }
+    }
+
+    /// Run to completion and require a successful exit status
+    ///
+    /// # Errors
+    ///
+    /// Returns any [`Self::output`] error, or [`CommandError::Failed`] for a
+    /// non-zero exit.
+    pub fn run(&self) -> CommandResult<CommandOutput> {
+        let output = self.output()?;
+        if output.success() {
+            return Ok(output);
+        }
+        Err(CommandError::Failed {
+            command: output.command.clone(),
+            status: output.status,
+            stdout: output.stdout_lossy(),
+            stderr: output.stderr_lossy(),
+        })
+    }
+
+    /// Whether the program can be executed (`<program> --version` succeeds within
+    /// [`PROBE_COMMAND_TIMEOUT`])
+    #[must_use]
+    pub fn is_available(program: impl Into<OsString>) -> bool {
+        Self::new(program).arg("--version").timeout(PROBE_COMMAND_TIMEOUT).run().is_ok()
+    }
+
+    /// Start a long-running child (e.g. a server) without waiting for it
+    ///
+    /// The timeout does not apply; the caller owns the child and must stop it.
+    /// Output is inherited from the parent.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CommandError::NotFound`] or [`CommandError::SpawnFailed`].
+    pub fn spawn(&self) -> CommandResult<Child> {
+        self.build().spawn().map_err(|e| self.spawn_error(e))
+    }
+
+    /// Poll until exit, deadline, or cancellation; kills and reaps the child unless it exited
+    fn wait_with_deadline(&self, child: &mut Child, started: Instant) -> std::io::Result<Waited> {
+        // Kill can fail if the child exited in the meantime; wait() reaps either way
+        let kill = |child: &mut Child| {
+            drop(child.kill());
+            child.wait().map(drop)
+        };
+        let deadline = started + self.timeout;
+        let label = format!("command `{}`", self.program.to_string_lossy());
+        let mut interval = Duration::from_millis(1);
+        loop {
+            if let Some(status) = child.try_wait()? {
+                return Ok(Waited::Exited(status));
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                kill(child)?;
+                return Ok(Waited::TimedOut);
+            }
+            if let Err(cancelled) = cancellable_sleep(&label, interval.min(deadline - now)) {
+                kill(child)?;
+                return Ok(Waited::Cancelled(cancelled.reason));
+            }
+            interval = (interval * 2).min(MAX_POLL_INTERVAL);
+        }
+    }
+}
+
+impl fmt::Display for CheckedCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.command_line())
+    }
+}
+
+/// How [`CheckedCommand::wait_with_deadline`] stopped waiting
+enum Waited {
+    Exited(ExitStatus),
+    TimedOut,
+    Cancelled(String),
+}
+
+fn drain(mut pipe: impl Read + Send + 'static) -> Receiver<Vec<u8>> {
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        // Partial output is still useful when the read fails (e.g. child killed)
+        drop(pipe.read_to_end(&mut buffer));
+        drop(tx.send(buffer));
+    });
+    rx
+}
+
+fn collect(pipe: Option<Receiver<Vec<u8>>>, deadline: Instant) -> Vec<u8> {
+    let wait = deadline.saturating_duration_since(Instant::now()) + PIPE_GRACE;
+    pipe.and_then(|rx| rx.recv_timeout(wait).ok()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_command_line_quotes_whitespace, {
+        // Arrange
+        let command = CheckedCommand::new("docker").args(["exec", "abc", "sh", "-c", "echo hi"]);
+
+        // Act & Assert
+        assert_eq!(command.command_line(), "docker exec abc sh -c \"echo hi\"");
+        assert_eq!(command.timeout_duration(), DEFAULT_COMMAND_TIMEOUT);
+    });
+
+    test!(test_missing_program_is_not_found, {
+        // Arrange & Act
+        let err = CheckedCommand::new("definitely-not-a-real-binary-xyz").output().unwrap_err();
+
+        // Assert
+        assert!(err.is_not_found());
+        assert_eq!(err.command(), "definitely-not-a-real-binary-xyz");
+        assert!(!CheckedCommand::is_available("definitely-not-a-real-binary-xyz"));
+    });
+
+    #[cfg(unix)]
+    #[test]
+    fn test_output_captures_streams_and_stdin() {
+        // Arrange
+        let command = CheckedCommand::new("sh")
+            .args(["-c", "cat; echo err >&2; exit 4"])
+            .env("UNUSED", "1")
+            .stdin("piped input");
+
+        // Act
+        let output = command.output().unwrap();
+        let err = command.run().unwrap_err();
+
+        // Assert
+        assert_eq!(output.stdout_lossy(), "piped input");
+        assert_eq!(output.stderr_lossy(), "err\n");
+        assert_eq!(output.code(), Some(4));
+        assert!(matches!(err, CommandError::Failed { ref stderr, .. } if stderr == "err\n"));
+        assert!(err.to_string().contains("sh -c \"cat; echo err >&2; exit 4\""));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_timeout_kills_child() {
+        // Arrange
+        let command = CheckedCommand::new("sleep").arg("10").timeout(Duration::from_millis(50));
+
+        // Act
+        let started = Instant::now();
+        let err = command.output().unwrap_err();
+
+        // Assert
+        assert!(
+            matches!(err, CommandError::TimedOut { timeout, .. } if timeout == Duration::from_millis(50))
+        );
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_cancellation_kills_child_before_timeout() {
+        // Arrange
+        let token =
+            crate::core::cancellation::TestCancellation::with_timeout(Duration::from_millis(50));
+        let _guard = token.enter();
+        let command = CheckedCommand::new("sleep").arg("10").timeout(Duration::from_secs(30));
+
+        // Act
+        let started = Instant::now();
+        let err = command.output().unwrap_err();
+
+        // Assert
+        assert!(
+            matches!(err, CommandError::Cancelled { ref reason, .. } if reason.contains("50ms"))
+        );
+        assert!(err.to_string().contains("`sleep 10` was killed because the test was cancelled"));
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert_eq!(token.report().interrupted[0].wait, "command `sleep`");
+    }
+}
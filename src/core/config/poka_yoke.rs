@@ -57,8 +57,36 @@
 //!     .build(); // Type system ensures all required fields are set
 //! ```
 
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer};
 use std::marker::PhantomData;
 
+/// Deserialize a poka-yoke type from its raw integer, rejecting values `new` rejects
+///
+/// Config loading relies on these to keep bounded types bounded when read from TOML.
+macro_rules! deserialize_validated {
+    ($($ty:ident($raw:ty): $expected:expr),+ $(,)?) => {
+        $(
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let value = <$raw>::deserialize(deserializer)?;
+                    Self::new(value).ok_or_else(|| {
+                        D::Error::custom(format!("{value} is out of range (expected {})", $expected))
+                    })
+                }
+            }
+        )+
+    };
+}
+
+deserialize_validated! {
+    NonZeroPort(u16): "a port > 0",
+    PositiveTimeout(u64): "a value > 0",
+    BoundedTimeout(u64): format!("1..={}", BoundedTimeout::MAX_REASONABLE_TIMEOUT),
+    PositiveU32(u32): "a value > 0",
+    PositiveUsize(usize): "a value > 0",
+}
+
 /// Non-zero port number
 ///
 /// **Poka-yoke**: Uses `NonZeroU16` to prevent port = 0.
@@ -193,15 +221,12 @@ impl BoundedTimeout {
     ///
     /// The type system forces handling of invalid timeouts at compile time.
     #[must_use]
-    pub fn new(value: u64) -> Option<Self> {
+    pub const fn new(value: u64) -> Option<Self> {
         // First check: Must be > 0 (enforced by NonZeroU64)
-        let nz = std::num::NonZeroU64::new(value)?;
-
         // Second check: Must be <= MAX_REASONABLE_TIMEOUT (enforced by runtime check)
-        if value <= Self::MAX_REASONABLE_TIMEOUT {
-            Some(Self { value: nz })
-        } else {
-            None
+        match std::num::NonZeroU64::new(value) {
+            Some(nz) if value <= Self::MAX_REASONABLE_TIMEOUT => Some(Self { value: nz }),
+            _ => None,
         }
     }
 
@@ -10,6 +10,47 @@
 //!
 //! Note: Rust's const generics have limitations. For practical Poka-Yoke patterns,
 //! prefer newtypes and enums over complex const assertion traits.
+//!
+//! # Layout and String Invariants
+//!
+//! ABI- and layout-sensitive code can encode its invariants as items that fail the
+//! build, not a test, when they stop holding:
+//!
+//! - [`const_assert_size_eq!`](crate::const_assert_size_eq) /
+//!   [`const_assert_align_eq!`](crate::const_assert_align_eq): a type's size or alignment
+//! - [`impl_variant_count!`](crate::impl_variant_count) /
+//!   [`const_assert_variant_count!`](crate::const_assert_variant_count): an enum's
+//!   number of variants
+//! - [`const_assert_str_prefix!`](crate::const_assert_str_prefix): a constant string's
+//!   prefix
+//!
+//! ```rust
+//! use chicago_tdd_tools::{
+//!     const_assert_align_eq, const_assert_size_eq, const_assert_str_prefix,
+//!     const_assert_variant_count, impl_variant_count,
+//! };
+//!
+//! #[repr(C)]
+//! pub struct Header {
+//!     magic: u32,
+//!     length: u32,
+//!     checksum: u64,
+//! }
+//!
+//! pub enum Opcode {
+//!     Read,
+//!     Write(u32),
+//!     Seek { offset: u64 },
+//! }
+//!
+//! pub const PROTOCOL: &str = "chicago/2.1";
+//!
+//! const_assert_size_eq!(Header, 16);
+//! const_assert_align_eq!(Header, 8);
+//! impl_variant_count!(Opcode { Read, Write, Seek });
+//! const_assert_variant_count!(Opcode, 3);
+//! const_assert_str_prefix!(PROTOCOL, "chicago/2.");
+//! ```
 
 /// Marker type for compile-time validated values
 ///
@@ -90,11 +131,244 @@ pub const fn const_assert_msg(condition: bool, _msg: &'static str) {
     }
 }
 
+/// Whether `s` starts with `prefix`, usable in const contexts
+#[must_use]
+pub const fn str_starts_with(s: &str, prefix: &str) -> bool {
+    let (s, prefix) = (s.as_bytes(), prefix.as_bytes());
+    if prefix.len() > s.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < prefix.len() {
+        if s[i] != prefix[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Number of variants of an enum, implemented with [`impl_variant_count!`](crate::impl_variant_count)
+pub trait VariantCount {
+    /// Number of variants
+    const VARIANT_COUNT: usize;
+}
+
+/// Fail the build unless `size_of::<T>()` is the given number of bytes
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::const_assert_size_eq;
+///
+/// const_assert_size_eq!([u32; 4], 16);
+/// const_assert_size_eq!(Option<Box<u8>>, std::mem::size_of::<usize>());
+/// ```
+///
+/// ```rust,compile_fail
+/// use chicago_tdd_tools::const_assert_size_eq;
+///
+/// const_assert_size_eq!(u64, 4);
+/// ```
+#[macro_export]
+macro_rules! const_assert_size_eq {
+    ($ty:ty, $size:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            ::core::mem::size_of::<$ty>() == $size,
+            ::core::concat!(
+                "size_of::<",
+                ::core::stringify!($ty),
+                ">() != ",
+                ::core::stringify!($size)
+            )
+        );
+    };
+}
+
+/// Fail the build unless `align_of::<T>()` is the given number of bytes
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::const_assert_align_eq;
+///
+/// #[repr(C, align(64))]
+/// struct CacheLine([u8; 64]);
+///
+/// const_assert_align_eq!(CacheLine, 64);
+/// ```
+#[macro_export]
+macro_rules! const_assert_align_eq {
+    ($ty:ty, $align:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            ::core::mem::align_of::<$ty>() == $align,
+            ::core::concat!(
+                "align_of::<",
+                ::core::stringify!($ty),
+                ">() != ",
+                ::core::stringify!($align)
+            )
+        );
+    };
+}
+
+/// Implement [`VariantCount`](crate::core::const_assert::VariantCount) for an enum by
+/// listing its variants
+///
+/// The list is checked with an exhaustive `match`, so adding a variant to the enum
+/// without listing it fails the build. Unit, tuple, and struct variants are all listed
+/// by name.
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::core::const_assert::VariantCount;
+/// use chicago_tdd_tools::impl_variant_count;
+///
+/// enum Shape {
+///     Point,
+///     Circle(f64),
+///     Rect { w: f64, h: f64 },
+/// }
+///
+/// impl_variant_count!(Shape { Point, Circle, Rect });
+///
+/// assert_eq!(Shape::VARIANT_COUNT, 3);
+/// ```
+///
+/// ```rust,compile_fail
+/// use chicago_tdd_tools::impl_variant_count;
+///
+/// enum Shape {
+///     Point,
+///     Circle(f64),
+/// }
+///
+/// impl_variant_count!(Shape { Point });
+/// ```
+#[macro_export]
+macro_rules! impl_variant_count {
+    ($ty:ident { $($variant:ident),+ $(,)? }) => {
+        impl $crate::core::const_assert::VariantCount for $ty {
+            const VARIANT_COUNT: usize = {
+                // Non-exhaustive if a variant is missing from the list
+                const fn _listed(value: &$ty) {
+                    match value {
+                        $($ty::$variant { .. } => {})+
+                    }
+                }
+                [$(::core::stringify!($variant)),+].len()
+            };
+        }
+    };
+}
+
+/// Fail the build unless an enum has the given number of variants
+///
+/// The enum must implement [`VariantCount`](crate::core::const_assert::VariantCount),
+/// usually with [`impl_variant_count!`](crate::impl_variant_count).
+///
+/// # Example
+///
+/// ```rust,compile_fail
+/// use chicago_tdd_tools::{const_assert_variant_count, impl_variant_count};
+///
+/// enum Level {
+///     Low,
+///     High,
+/// }
+///
+/// impl_variant_count!(Level { Low, High });
+/// const_assert_variant_count!(Level, 3);
+/// ```
+#[macro_export]
+macro_rules! const_assert_variant_count {
+    ($ty:ty, $count:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            <$ty as $crate::core::const_assert::VariantCount>::VARIANT_COUNT == $count,
+            ::core::concat!(
+                ::core::stringify!($ty),
+                " does not have ",
+                ::core::stringify!($count),
+                " variants"
+            )
+        );
+    };
+}
+
+/// Fail the build unless a constant string starts with a prefix
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::const_assert_str_prefix;
+///
+/// const METRIC: &str = "chicago.tests.duration";
+///
+/// const_assert_str_prefix!(METRIC, "chicago.");
+/// ```
+///
+/// ```rust,compile_fail
+/// use chicago_tdd_tools::const_assert_str_prefix;
+///
+/// const METRIC: &str = "tests.duration";
+///
+/// const_assert_str_prefix!(METRIC, "chicago.");
+/// ```
+#[macro_export]
+macro_rules! const_assert_str_prefix {
+    ($s:expr, $prefix:expr $(,)?) => {
+        const _: () = ::core::assert!(
+            $crate::core::const_assert::str_starts_with($s, $prefix),
+            ::core::concat!(
+                ::core::stringify!($s),
+                " does not start with ",
+                ::core::stringify!($prefix)
+            )
+        );
+    };
+}
+
 #[cfg(test)]
 #[allow(clippy::panic)] // Test code - panic is appropriate for test failures
 mod tests {
     use super::*;
 
+    #[repr(C)]
+    struct Packet {
+        kind: u8,
+        flags: u8,
+        length: u16,
+        sequence: u32,
+    }
+
+    enum Phase {
+        Arrange,
+        Act(u8),
+        Assert { strict: bool },
+    }
+
+    const PREFIX_ITEM: &str = "chicago.tdd";
+
+    crate::const_assert_size_eq!(Packet, 8);
+    crate::const_assert_align_eq!(Packet, 4);
+    crate::impl_variant_count!(Phase { Arrange, Act, Assert });
+    crate::const_assert_variant_count!(Phase, 3);
+    crate::const_assert_str_prefix!(PREFIX_ITEM, "chicago.");
+
+    #[test]
+    fn test_layout_and_string_assertions() {
+        let phases = [Phase::Arrange, Phase::Act(1), Phase::Assert { strict: true }];
+        let packet = Packet { kind: 1, flags: 0, length: 2, sequence: 3 };
+
+        assert_eq!(phases.len(), Phase::VARIANT_COUNT);
+        assert_eq!(u32::from(packet.kind) + u32::from(packet.flags), 1);
+        assert_eq!((packet.length, packet.sequence), (2, 3));
+        assert!(str_starts_with("chicago", ""));
+        assert!(!str_starts_with("chi", "chicago"));
+        assert!(!str_starts_with("chicago", "chx"));
+    }
+
     #[test]
     fn test_validated() {
         let validated = Validated::new(42);
@@ -0,0 +1,594 @@
+//! Environment Diagnostics
+//!
+//! [`doctor`] checks the environment a test suite depends on, end to end, and returns a
+//! [`DoctorReport`] with one [`DoctorCheck`] per area, each with a status and, when it
+//! is not passing, a suggested fix:
+//!
+//! | Check | Passes when |
+//! |-------|-------------|
+//! | `docker` | `docker info` reaches a running daemon |
+//! | `weaver` | a Weaver binary satisfying the required version is found (`weaver` feature) |
+//! | `registry` | the semantic conventions registry exists (`weaver` feature) |
+//! | `config` | `chicago-tdd-tools.toml` parses, with no unknown keys or invalid values |
+//! | `ports` | free ports can be allocated and Weaver's configured ports are free |
+//! | `features` | the config only configures features this build enables |
+//!
+//! Checks for tools the enabled features do not need are skipped or reported as
+//! warnings, never failures. Nothing is downloaded or started.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! let report = chicago_tdd_tools::doctor();
+//! println!("{report}");
+//! assert!(report.is_healthy(), "environment is not ready for the test suite");
+//! ```
+
+use crate::core::command::{CheckedCommand, CommandError, PROBE_COMMAND_TIMEOUT};
+use crate::core::config::loading::{ChicagoConfig, ConfigOverrides, ConfigReport, ConfigSource};
+use crate::core::layout::ProjectLayout;
+use crate::core::ports::PortAllocator;
+use serde::Serialize;
+use std::fmt;
+use std::net::{Ipv4Addr, TcpListener};
+
+/// Cargo features reported by the `features` check
+const FEATURES: &[(&str, bool)] = &[
+    ("logging", cfg!(feature = "logging")),
+    ("async", cfg!(feature = "async")),
+    ("property-testing", cfg!(feature = "property-testing")),
+    ("snapshot-testing", cfg!(feature = "snapshot-testing")),
+    ("mutation-testing", cfg!(feature = "mutation-testing")),
+    ("concurrency-testing", cfg!(feature = "concurrency-testing")),
+    ("cli-testing", cfg!(feature = "cli-testing")),
+    ("fake-data", cfg!(feature = "fake-data")),
+    ("http-testing", cfg!(feature = "http-testing")),
+    ("otel", cfg!(feature = "otel")),
+    ("weaver", cfg!(feature = "weaver")),
+    ("testcontainers", cfg!(feature = "testcontainers")),
+];
+
+/// Config sections that only take effect with a feature enabled
+const FEATURE_SECTIONS: &[(&str, &str, bool)] = &[
+    ("testcontainers.", "testcontainers", cfg!(feature = "testcontainers")),
+    ("observability.weaver.", "weaver", cfg!(feature = "weaver")),
+];
+
+/// Outcome of one check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DoctorStatus {
+    /// Ready
+    Pass,
+    /// Usable, but something is likely to cause trouble
+    Warn,
+    /// Tests that need this will fail
+    Fail,
+    /// Not needed by the enabled features
+    Skip,
+}
+
+impl DoctorStatus {
+    const fn glyph(self) -> &'static str {
+        match self {
+            Self::Pass => "✅",
+            Self::Warn => "⚠️ ",
+            Self::Fail => "❌",
+            Self::Skip => "⏭️ ",
+        }
+    }
+}
+
+impl fmt::Display for DoctorStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Pass => "pass",
+            Self::Warn => "warn",
+            Self::Fail => "fail",
+            Self::Skip => "skip",
+        })
+    }
+}
+
+/// Result of one check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DoctorCheck {
+    /// Check name (`docker`, `weaver`, `registry`, `config`, `ports`, `features`)
+    pub name: &'static str,
+    /// Pass, warn, fail, or skip
+    pub status: DoctorStatus,
+    /// What was found
+    pub detail: String,
+    /// How to fix a warning or failure
+    pub fix: Option<String>,
+}
+
+impl DoctorCheck {
+    fn new(name: &'static str, status: DoctorStatus, detail: impl Into<String>) -> Self {
+        Self { name, status, detail: detail.into(), fix: None }
+    }
+
+    fn with_fix(mut self, fix: impl Into<String>) -> Self {
+        self.fix = Some(fix.into());
+        self
+    }
+}
+
+/// Combined result of [`doctor`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DoctorReport {
+    /// Per-check results, in run order
+    pub checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    /// Whether no check failed (warnings and skips are healthy)
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        self.checks.iter().all(|check| check.status != DoctorStatus::Fail)
+    }
+
+    /// The result of the check named `name`
+    #[must_use]
+    pub fn check(&self, name: &str) -> Option<&DoctorCheck> {
+        self.checks.iter().find(|check| check.name == name)
+    }
+
+    /// Checks with the given status
+    pub fn with_status(&self, status: DoctorStatus) -> impl Iterator<Item = &DoctorCheck> {
+        self.checks.iter().filter(move |check| check.status == status)
+    }
+}
+
+impl fmt::Display for DoctorReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            writeln!(f, "{} {:<9}{}", check.status.glyph(), check.name, check.detail)?;
+            if let Some(fix) = &check.fix {
+                writeln!(f, "   💡 FIX: {fix}")?;
+            }
+        }
+        let failed = self.with_status(DoctorStatus::Fail).count();
+        let warned = self.with_status(DoctorStatus::Warn).count();
+        write!(f, "{} checks: {failed} failed, {warned} warnings", self.checks.len())
+    }
+}
+
+/// Check the environment with the detected project layout and the process environment
+#[must_use]
+pub fn doctor() -> DoctorReport {
+    Doctor::new().run()
+}
+
+/// Configurable environment check; [`doctor`] runs it with the defaults
+#[derive(Debug, Clone)]
+pub struct Doctor {
+    layout: ProjectLayout,
+    overrides: ConfigOverrides,
+    docker: String,
+}
+
+impl Doctor {
+    /// Check the detected project, with config overrides from the environment
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            layout: ProjectLayout::detect(),
+            overrides: ConfigOverrides::from_env(),
+            docker: "docker".to_string(),
+        }
+    }
+
+    /// Check the project at `layout`
+    #[must_use]
+    pub fn with_layout(mut self, layout: ProjectLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Apply `overrides` instead of the environment's
+    #[must_use]
+    pub fn with_overrides(mut self, overrides: ConfigOverrides) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Probe `program` instead of `docker` (e.g. `podman`)
+    #[must_use]
+    pub fn with_docker(mut self, program: impl Into<String>) -> Self {
+        self.docker = program.into();
+        self
+    }
+
+    /// Run every check
+    #[must_use]
+    pub fn run(&self) -> DoctorReport {
+        let (config, report) = ChicagoConfig::load_file(self.layout.config_file(), &self.overrides);
+        DoctorReport {
+            checks: vec![
+                self.check_docker(),
+                Self::check_weaver(),
+                self.check_registry(),
+                Self::check_config(&report),
+                Self::check_ports(&weaver_ports(&config)),
+                Self::check_features(&report),
+            ],
+        }
+    }
+
+    fn check_docker(&self) -> DoctorCheck {
+        const NAME: &str = "docker";
+        let needed = cfg!(feature = "testcontainers");
+        let unavailable = if needed { DoctorStatus::Fail } else { DoctorStatus::Warn };
+        let info = CheckedCommand::new(&self.docker)
+            .args(["info", "--format", "{{.ServerVersion}}"])
+            .timeout(PROBE_COMMAND_TIMEOUT)
+            .run();
+        match info {
+            Ok(output) => DoctorCheck::new(
+                NAME,
+                DoctorStatus::Pass,
+                format!("{} daemon {} is running", self.docker, output.stdout_lossy().trim()),
+            ),
+            Err(CommandError::NotFound { .. }) if !needed => DoctorCheck::new(
+                NAME,
+                DoctorStatus::Skip,
+                format!("{} not installed (only the testcontainers feature needs it)", self.docker),
+            ),
+            Err(CommandError::NotFound { .. }) => {
+                DoctorCheck::new(NAME, unavailable, format!("{} not installed", self.docker))
+                    .with_fix("Install Docker: https://docs.docker.com/get-docker/")
+            }
+            Err(error) => DoctorCheck::new(
+                NAME,
+                unavailable,
+                format!("{} daemon is not responding: {}", self.docker, first_line(&error)),
+            )
+            .with_fix(format!(
+                "Start the Docker daemon, then check that `{} info` succeeds",
+                self.docker
+            )),
+        }
+    }
+
+    #[cfg(feature = "weaver")]
+    fn check_weaver() -> DoctorCheck {
+        use crate::observability::weaver::toolchain::{WeaverToolchain, WeaverToolchainError};
+        const NAME: &str = "weaver";
+        let toolchain = WeaverToolchain::new();
+        if let Some(weaver) = toolchain.locate() {
+            return DoctorCheck::new(
+                NAME,
+                DoctorStatus::Pass,
+                format!("weaver {} at {}", weaver.version(), weaver.path().display()),
+            );
+        }
+        let requirement = toolchain.requirement();
+        toolchain
+            .candidates()
+            .iter()
+            .find_map(|path| match toolchain.verify(path) {
+                Err(WeaverToolchainError::VersionMismatch { found, .. }) => Some(
+                    DoctorCheck::new(
+                        NAME,
+                        DoctorStatus::Fail,
+                        format!("weaver {found} at {} does not satisfy {requirement}", path.display()),
+                    )
+                    .with_fix(format!(
+                        "Install a Weaver release matching {requirement} (cargo make weaver-bootstrap)"
+                    )),
+                ),
+                _ => None,
+            })
+            .unwrap_or_else(|| {
+                DoctorCheck::new(
+                    NAME,
+                    DoctorStatus::Fail,
+                    format!("no weaver binary on PATH or in {}", toolchain.cache_dir().display()),
+                )
+                .with_fix("Run cargo make weaver-bootstrap (WeaverToolchain::resolve also downloads it)")
+            })
+    }
+
+    #[cfg(not(feature = "weaver"))]
+    fn check_weaver() -> DoctorCheck {
+        DoctorCheck::new("weaver", DoctorStatus::Skip, "weaver feature disabled")
+    }
+
+    fn check_registry(&self) -> DoctorCheck {
+        const NAME: &str = "registry";
+        let registry = self.layout.registry_dir();
+        let populated =
+            std::fs::read_dir(&registry).is_ok_and(|mut entries| entries.next().is_some());
+        if populated {
+            DoctorCheck::new(NAME, DoctorStatus::Pass, format!("{}", registry.display()))
+        } else if cfg!(feature = "weaver") {
+            DoctorCheck::new(
+                NAME,
+                DoctorStatus::Fail,
+                format!("no semantic conventions registry at {}", registry.display()),
+            )
+            .with_fix(format!(
+                "git clone --depth 1 https://github.com/open-telemetry/semantic-conventions.git {}",
+                registry.display()
+            ))
+        } else {
+            DoctorCheck::new(NAME, DoctorStatus::Skip, "weaver feature disabled")
+        }
+    }
+
+    fn check_config(report: &ConfigReport) -> DoctorCheck {
+        const NAME: &str = "config";
+        let file = report.path.as_ref().map_or_else(
+            || "no chicago-tdd-tools.toml".to_string(),
+            |path| path.display().to_string(),
+        );
+        if report.has_problems() {
+            let problems = report.to_string();
+            let problems = problems.lines().skip(1).filter(|line| !line.contains("Default:"));
+            let detail = std::iter::once(file).chain(problems.map(str::trim).map(String::from));
+            return DoctorCheck::new(
+                NAME,
+                DoctorStatus::Fail,
+                detail.collect::<Vec<_>>().join("\n   "),
+            )
+            .with_fix(
+                "Correct or remove the keys listed above (invalid values fall back to defaults)",
+            );
+        }
+        let overridden = report
+            .resolved
+            .iter()
+            .filter(|resolved| {
+                matches!(resolved.source, ConfigSource::Env(_) | ConfigSource::Profile(_))
+            })
+            .count();
+        let profile = report
+            .profile
+            .as_ref()
+            .map_or_else(String::new, |name| format!(", profile {name}"));
+        DoctorCheck::new(
+            NAME,
+            DoctorStatus::Pass,
+            format!(
+                "{file}: {} options set, {overridden} overridden{profile}",
+                report.resolved.len() - report.defaults_applied.len()
+            ),
+        )
+    }
+
+    fn check_ports(ports: &[u16]) -> DoctorCheck {
+        const NAME: &str = "ports";
+        if let Err(error) = PortAllocator::allocate() {
+            return DoctorCheck::new(NAME, DoctorStatus::Fail, first_line(&error))
+                .with_fix("Check the loopback interface and the process's socket limits");
+        }
+        let busy: Vec<String> = ports
+            .iter()
+            .filter(|port| TcpListener::bind((Ipv4Addr::LOCALHOST, **port)).is_err())
+            .map(ToString::to_string)
+            .collect();
+        if busy.is_empty() {
+            let checked = ports.iter().map(ToString::to_string).collect::<Vec<_>>();
+            let detail = if checked.is_empty() {
+                "free ports available".to_string()
+            } else {
+                format!("free ports available; {} free", checked.join(", "))
+            };
+            DoctorCheck::new(NAME, DoctorStatus::Pass, detail)
+        } else {
+            DoctorCheck::new(
+                NAME,
+                DoctorStatus::Warn,
+                format!("{} already in use", busy.join(", ")),
+            )
+            .with_fix(
+                "Stop the process holding the port, use WeaverValidator::with_free_ports, or \
+                     set CHICAGO_TDD_OBSERVABILITY_WEAVER_OTLP_GRPC_PORT",
+            )
+        }
+    }
+
+    fn check_features(report: &ConfigReport) -> DoctorCheck {
+        const NAME: &str = "features";
+        let enabled: Vec<&str> =
+            FEATURES.iter().filter(|(_, on)| *on).map(|(feature, _)| *feature).collect();
+        let enabled = if enabled.is_empty() { "none".to_string() } else { enabled.join(", ") };
+        let unused: Vec<String> = report
+            .resolved
+            .iter()
+            .filter(|resolved| resolved.source != ConfigSource::Default)
+            .filter_map(|resolved| {
+                FEATURE_SECTIONS
+                    .iter()
+                    .find(|(prefix, _, on)| !on && resolved.key.starts_with(prefix))
+                    .map(|(_, feature, _)| format!("{} needs the {feature} feature", resolved.key))
+            })
+            .collect();
+        if unused.is_empty() {
+            DoctorCheck::new(NAME, DoctorStatus::Pass, format!("enabled: {enabled}"))
+        } else {
+            DoctorCheck::new(
+                NAME,
+                DoctorStatus::Warn,
+                format!("enabled: {enabled}; ignored options: {}", unused.join(", ")),
+            )
+            .with_fix("Enable the listed features in Cargo.toml, or remove the options")
+        }
+    }
+}
+
+impl Default for Doctor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Fixed ports Weaver live-check binds by default
+#[cfg(feature = "weaver")]
+fn weaver_ports(config: &ChicagoConfig) -> Vec<u16> {
+    vec![config.weaver.otlp_grpc_port.get(), crate::observability::weaver::DEFAULT_ADMIN_PORT]
+}
+
+#[cfg(not(feature = "weaver"))]
+const fn weaver_ports(_config: &ChicagoConfig) -> Vec<u16> {
+    Vec::new()
+}
+
+fn first_line(error: &impl fmt::Display) -> String {
+    error.to_string().lines().next().unwrap_or_default().to_string()
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Test code: unwrap is acceptable
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn project(config: Option<&str>) -> TempDir {
+        let dir = TempDir::new().unwrap();
+        if let Some(config) = config {
+            std::fs::write(dir.path().join("chicago-tdd-tools.toml"), config).unwrap();
+        }
+        dir
+    }
+
+    fn doctor_for(dir: &TempDir) -> Doctor {
+        Doctor::new()
+            .with_layout(ProjectLayout::from_dir(dir.path()))
+            .with_overrides(ConfigOverrides::new())
+            .with_docker("definitely-not-a-real-docker-xyz")
+    }
+
+    #[test]
+    fn test_invalid_config_fails_with_problems_listed() {
+        // Arrange
+        let dir = project(Some("[test]\nunit_timeout = 5\ne2e_timeout_seconds = 0\n"));
+
+        // Act
+        let report = doctor_for(&dir).run();
+
+        // Assert
+        let config = report.check("config").unwrap();
+        assert_eq!(config.status, DoctorStatus::Fail);
+        assert!(config.detail.contains("Unknown key: test.unit_timeout"), "{}", config.detail);
+        assert!(config.detail.contains("test.e2e_timeout_seconds = 0"), "{}", config.detail);
+        assert!(config.fix.is_some());
+        assert!(!report.is_healthy());
+    }
+
+    #[test]
+    fn test_valid_config_and_overrides_pass() {
+        // Arrange
+        let dir = project(Some(
+            "[test]\nunit_timeout_seconds = 5\n[profile.ci.test]\nunit_timeout_seconds = 2\n",
+        ));
+        let overrides = ConfigOverrides::new().with_profile("ci");
+
+        // Act
+        let report = doctor_for(&dir).with_overrides(overrides).run();
+
+        // Assert
+        let config = report.check("config").unwrap();
+        assert_eq!(config.status, DoctorStatus::Pass, "{report}");
+        assert!(
+            config.detail.ends_with("1 options set, 1 overridden, profile ci"),
+            "{}",
+            config.detail
+        );
+    }
+
+    #[test]
+    fn test_missing_docker_and_registry_follow_features() {
+        // Arrange
+        let dir = project(None);
+
+        // Act
+        let report = doctor_for(&dir).run();
+
+        // Assert
+        let docker = report.check("docker").unwrap();
+        let registry = report.check("registry").unwrap();
+        if cfg!(feature = "testcontainers") {
+            assert_eq!(docker.status, DoctorStatus::Fail);
+            assert!(docker.fix.is_some());
+        } else {
+            assert_eq!(docker.status, DoctorStatus::Skip);
+        }
+        if cfg!(feature = "weaver") {
+            assert_eq!(registry.status, DoctorStatus::Fail);
+            assert!(registry
+                .fix
+                .as_deref()
+                .is_some_and(|fix| fix.contains("semantic-conventions")));
+        } else {
+            assert_eq!(registry.status, DoctorStatus::Skip);
+        }
+        assert_eq!(report.check("config").map(|check| check.status), Some(DoctorStatus::Pass));
+    }
+
+    #[test]
+    fn test_registry_present_passes() {
+        // Arrange
+        let dir = project(None);
+        std::fs::create_dir_all(dir.path().join("registry/model")).unwrap();
+
+        // Act
+        let report = doctor_for(&dir).run();
+
+        // Assert
+        assert_eq!(report.check("registry").map(|check| check.status), Some(DoctorStatus::Pass));
+    }
+
+    #[test]
+    fn test_busy_port_warns() {
+        // Arrange
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().map_or(0, |addr| addr.port());
+
+        // Act
+        let check = Doctor::check_ports(&[port]);
+
+        // Assert
+        assert_eq!(check.status, DoctorStatus::Warn);
+        assert!(check.detail.contains(&port.to_string()));
+    }
+
+    #[test]
+    fn test_options_for_disabled_features_warn() {
+        // Arrange
+        let (_, report) = ChicagoConfig::parse("[testcontainers]\ndefault_http_port = 8081\n");
+
+        // Act
+        let check = Doctor::check_features(&report);
+
+        // Assert
+        if cfg!(feature = "testcontainers") {
+            assert_eq!(check.status, DoctorStatus::Pass);
+        } else {
+            assert_eq!(check.status, DoctorStatus::Warn);
+            assert!(check
+                .detail
+                .contains("testcontainers.default_http_port needs the testcontainers feature"));
+        }
+    }
+
+    #[test]
+    fn test_report_display_lists_every_check() {
+        // Arrange
+        let report = DoctorReport {
+            checks: vec![
+                DoctorCheck::new("config", DoctorStatus::Pass, "ok"),
+                DoctorCheck::new("docker", DoctorStatus::Fail, "down").with_fix("start it"),
+            ],
+        };
+
+        // Act
+        let text = report.to_string();
+
+        // Assert
+        assert!(text.contains("❌ docker   down\n   💡 FIX: start it"), "{text}");
+        assert!(text.ends_with("2 checks: 1 failed, 0 warnings"));
+        assert!(!report.is_healthy());
+    }
+}
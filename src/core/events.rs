@@ -0,0 +1,393 @@
+//! > 📚 Reference
+//!
+//! Test Event Bus
+//!
+//! The framework's hooks publish typed [`Event`]s on one process-wide bus: the test
+//! macros (`test!`, `async_test!`, `fixture_test!`, `#[tdd_test]`) announce
+//! [`Event::TestStarted`] and [`Event::TestFinished`], [`enter_phase`] announces AAA
+//! phases, the `assert_*` macros and fluent matchers announce each assertion, fixtures
+//! announce their setup, and the alert macros forward every alert.
+//!
+//! The receipt recorder (and, with the `otel` feature, the test trace recorder) builds its
+//! per-test records from these events, and anything else - reporters, coverage,
+//! dashboards - subscribes with [`subscribe`]. [`EventLog`] captures events in memory and
+//! converts finished tests into reporter [`TestEvent`](crate::core::reporting::TestEvent)s.
+//!
+//! Events are delivered synchronously on the emitting thread. Each [`EventRecord`] names
+//! the test running on that thread, so events from tasks that hop to other threads (a
+//! multi-thread runtime) carry no test name.
+//!
+//! [`enter_phase`]: crate::core::receipt::enter_phase
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::events::{subscribe, unsubscribe, Event, EventLog};
+//! use chicago_tdd_tools::core::receipt::{enter_phase, AaaPhase, TestRecording};
+//! use chicago_tdd_tools::assert_in_range;
+//!
+//! let log = EventLog::current_thread();
+//! let id = subscribe(log.clone());
+//!
+//! let recording = TestRecording::start("orders::test_total");
+//! enter_phase(AaaPhase::Assert);
+//! assert_in_range!(42, 0, 100);
+//! recording.finish(true);
+//!
+//! let reported = log.test_events();
+//! assert_eq!(reported[0].name, "orders::test_total");
+//! assert_eq!(reported[0].assertions, 1);
+//! assert!(log.events().contains(&Event::PhaseEntered { phase: AaaPhase::Assert }));
+//! unsubscribe(id);
+//! ```
+
+use crate::core::alert::sink::AlertRecord;
+use crate::core::receipt::{AaaPhase, TestOutcome};
+use crate::core::reporting::TestEvent;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Something that happened while tests ran
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum Event {
+    /// A test body is about to run
+    TestStarted {
+        /// Fully qualified test name (`module::path::test_name`)
+        test: String,
+    },
+    /// The running test entered an AAA phase
+    PhaseEntered {
+        /// Phase entered
+        phase: AaaPhase,
+    },
+    /// A framework assertion or matcher was evaluated
+    AssertionEvaluated,
+    /// A fixture was set up
+    FixtureSetup {
+        /// Fixture type name
+        fixture: String,
+    },
+    /// An alert macro emitted an alert
+    AlertEmitted {
+        /// The alert, as handed to alert sinks
+        alert: AlertRecord,
+    },
+    /// A test finished
+    TestFinished {
+        /// Fully qualified test name
+        test: String,
+        /// How the test ended
+        outcome: TestOutcome,
+        /// Wall-clock duration in nanoseconds
+        duration_ns: u64,
+        /// Why the test failed, when known
+        failure: Option<String>,
+        /// Source file declaring the test
+        file: Option<String>,
+        /// Line of the test declaration in `file`
+        line: Option<u32>,
+    },
+}
+
+/// One event with where and when it happened, as handed to subscribers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventRecord {
+    /// The event
+    #[serde(flatten)]
+    pub event: Event,
+    /// Test running on the emitting thread, if any
+    pub test: Option<String>,
+    /// Name of the emitting thread
+    pub thread: Option<String>,
+    /// Timestamp (Unix epoch milliseconds)
+    pub timestamp: u64,
+}
+
+/// Receives every event emitted while subscribed
+pub trait EventSubscriber: Send + Sync + fmt::Debug {
+    /// Handle one event; called on the emitting thread
+    fn on_event(&self, record: &EventRecord);
+}
+
+/// Handle identifying a subscription
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SubscriptionId(u64);
+
+/// Subscribers, in subscription order
+static SUBSCRIBERS: RwLock<Vec<(SubscriptionId, Arc<dyn EventSubscriber>)>> =
+    RwLock::new(Vec::new());
+/// Number of subscribers, read without locking on every emitted event
+static SUBSCRIBER_COUNT: AtomicUsize = AtomicUsize::new(0);
+/// Next [`SubscriptionId`]
+static NEXT_ID: AtomicU64 = AtomicU64::new(1);
+
+thread_local! {
+    /// Test running on this thread, between its start and finish events
+    static CURRENT_TEST: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Deliver every subsequent event to `subscriber`
+pub fn subscribe(subscriber: impl EventSubscriber + 'static) -> SubscriptionId {
+    let id = SubscriptionId(NEXT_ID.fetch_add(1, Ordering::Relaxed));
+    let mut subscribers = SUBSCRIBERS.write().unwrap_or_else(PoisonError::into_inner);
+    subscribers.push((id, Arc::new(subscriber)));
+    SUBSCRIBER_COUNT.store(subscribers.len(), Ordering::Release);
+    id
+}
+
+/// End the subscription `id`
+///
+/// Returns whether a subscriber was removed.
+pub fn unsubscribe(id: SubscriptionId) -> bool {
+    let mut subscribers = SUBSCRIBERS.write().unwrap_or_else(PoisonError::into_inner);
+    let before = subscribers.len();
+    subscribers.retain(|(subscription, _)| *subscription != id);
+    SUBSCRIBER_COUNT.store(subscribers.len(), Ordering::Release);
+    before != subscribers.len()
+}
+
+/// Number of subscribers
+#[must_use]
+pub fn subscriber_count() -> usize {
+    SUBSCRIBER_COUNT.load(Ordering::Acquire)
+}
+
+/// Whether anything is subscribed (lets emitters skip building expensive events)
+#[must_use]
+pub fn has_subscribers() -> bool {
+    subscriber_count() > 0
+}
+
+/// Publish `event` to the receipt recorder, the test trace recorder (with the `otel`
+/// feature), and every subscriber
+pub fn emit(event: Event) {
+    crate::core::receipt::recorder::on_event(&event);
+    #[cfg(feature = "otel")]
+    crate::observability::otel::test_trace::on_event(&event);
+    if let Event::TestStarted { test } = &event {
+        CURRENT_TEST.with(|current| *current.borrow_mut() = Some(test.clone()));
+    }
+    let finished = matches!(event, Event::TestFinished { .. });
+    if has_subscribers() {
+        // Snapshot so subscribers that emit events themselves cannot deadlock the registry
+        let subscribers: Vec<Arc<dyn EventSubscriber>> = SUBSCRIBERS
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .map(|(_, subscriber)| Arc::clone(subscriber))
+            .collect();
+        #[allow(clippy::cast_possible_truncation)] // Milliseconds since 1970 fit in u64
+        let timestamp =
+            SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64;
+        let record = EventRecord {
+            test: CURRENT_TEST.with(|current| current.borrow().clone()),
+            thread: thread::current().name().map(str::to_string),
+            timestamp,
+            event,
+        };
+        for subscriber in subscribers {
+            subscriber.on_event(&record);
+        }
+    }
+    if finished {
+        CURRENT_TEST.with(|current| current.borrow_mut().take());
+    }
+}
+
+/// Captures events in memory
+///
+/// Clones share the same buffer, so subscribe one clone and inspect another.
+#[derive(Debug, Clone, Default)]
+pub struct EventLog {
+    records: Arc<Mutex<Vec<EventRecord>>>,
+    thread: Option<ThreadId>,
+}
+
+impl EventLog {
+    /// Capture events from every thread
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Capture only events emitted on the calling thread
+    ///
+    /// Keeps parallel tests from seeing each other's events.
+    #[must_use]
+    pub fn current_thread() -> Self {
+        Self { thread: Some(thread::current().id()), ..Self::default() }
+    }
+
+    /// Captured records, in emission order
+    #[must_use]
+    pub fn records(&self) -> Vec<EventRecord> {
+        self.lock().clone()
+    }
+
+    /// Captured events without their records, in emission order
+    #[must_use]
+    pub fn events(&self) -> Vec<Event> {
+        self.lock().iter().map(|record| record.event.clone()).collect()
+    }
+
+    /// Remove and return the captured records
+    #[must_use]
+    pub fn take(&self) -> Vec<EventRecord> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    /// Number of captured records
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether nothing was captured
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Finished tests as reporter events, with their assertion counts
+    #[must_use]
+    pub fn test_events(&self) -> Vec<TestEvent> {
+        let records = self.records();
+        let mut assertions: HashMap<&str, u64> = HashMap::new();
+        let mut finished = Vec::new();
+        for record in &records {
+            match &record.event {
+                Event::AssertionEvaluated => {
+                    if let Some(test) = &record.test {
+                        *assertions.entry(test).or_default() += 1;
+                    }
+                }
+                Event::TestFinished { test, outcome, duration_ns, failure, file, line } => {
+                    let mut event =
+                        TestEvent::new(test, *outcome, Duration::from_nanos(*duration_ns))
+                            .with_assertions(assertions.remove(test.as_str()).unwrap_or(0));
+                    event.message.clone_from(failure);
+                    event.file.clone_from(file);
+                    event.line = *line;
+                    finished.push(event);
+                }
+                _ => {}
+            }
+        }
+        finished
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<EventRecord>> {
+        self.records.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl EventSubscriber for EventLog {
+    fn on_event(&self, record: &EventRecord) {
+        if self.thread.is_none_or(|id| id == thread::current().id()) {
+            self.lock().push(record.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Test code: unwrap is acceptable
+mod tests {
+    use super::*;
+    use crate::core::receipt::{enter_phase, record_assertion, record_fixture, TestRecording};
+    use crate::core::render::AlertLevel;
+    use crate::test;
+
+    test!(test_hooks_publish_events_in_order, {
+        // Arrange
+        let log = EventLog::current_thread();
+        let id = subscribe(log.clone());
+
+        // Act
+        let recording = TestRecording::start("bus::test_order").at("tests/bus.rs", 7);
+        record_fixture("TestFixture");
+        enter_phase(AaaPhase::Act);
+        record_assertion();
+        crate::alert_warning!("Queue is backing up", "Drain the queue");
+        recording.finish(true);
+        unsubscribe(id);
+
+        // Assert
+        let records = log.records();
+        let events: Vec<&Event> = records.iter().map(|record| &record.event).collect();
+        assert_eq!(events[0], &Event::TestStarted { test: "bus::test_order".to_string() });
+        assert_eq!(events[1], &Event::FixtureSetup { fixture: "TestFixture".to_string() });
+        assert_eq!(events[2], &Event::PhaseEntered { phase: AaaPhase::Act });
+        assert_eq!(events[3], &Event::AssertionEvaluated);
+        assert!(matches!(
+            events[4],
+            Event::AlertEmitted { alert } if alert.level == Some(AlertLevel::Warning)
+        ));
+        assert!(matches!(
+            events[5],
+            Event::TestFinished { outcome: TestOutcome::Pass, line: Some(7), .. }
+        ));
+        assert!(records.iter().all(|record| record.test.as_deref() == Some("bus::test_order")));
+    });
+
+    test!(test_event_log_reports_finished_tests, {
+        // Arrange
+        let log = EventLog::current_thread();
+        let id = subscribe(log.clone());
+
+        // Act: One passing test with two assertions, one that never finishes
+        let recording = TestRecording::start("bus::test_passes");
+        record_assertion();
+        record_assertion();
+        recording.finish(true);
+        drop(TestRecording::start("bus::test_abandoned"));
+        unsubscribe(id);
+
+        // Assert
+        let reported = log.test_events();
+        assert_eq!(reported.len(), 2);
+        assert_eq!(reported[0].assertions, 2);
+        assert!(!reported[0].is_failure());
+        assert!(reported[1].is_failure());
+        assert_eq!(reported[1].message.as_deref(), Some("did not finish"));
+    });
+
+    test!(test_unsubscribed_log_stops_receiving, {
+        // Arrange
+        let log = EventLog::current_thread();
+        let id = subscribe(log.clone());
+        assert!(has_subscribers());
+
+        // Act
+        assert!(unsubscribe(id));
+        emit(Event::AssertionEvaluated);
+
+        // Assert
+        assert!(log.is_empty());
+        assert!(!unsubscribe(id));
+    });
+
+    test!(test_event_record_serializes_flat, {
+        // Arrange
+        let record = EventRecord {
+            event: Event::PhaseEntered { phase: AaaPhase::Assert },
+            test: Some("bus::test_json".to_string()),
+            thread: None,
+            timestamp: 1,
+        };
+
+        // Act
+        let json = serde_json::to_value(&record).unwrap();
+
+        // Assert
+        assert_eq!(json["event"], "phase_entered");
+        assert_eq!(json["phase"], "assert");
+        assert_eq!(serde_json::from_value::<EventRecord>(json).unwrap(), record);
+    });
+}
@@ -0,0 +1,352 @@
+//! > 📚 Reference
+//!
+//! Eventual Consistency Polling
+//!
+//! Runtime behind [`assert_eventually!`](crate::assert_eventually) and
+//! [`assert_eventually_async!`](crate::assert_eventually_async): poll a probe until its
+//! value satisfies a condition or a deadline passes, backing off between attempts.
+//! Replaces hand-rolled `loop { sleep(..) }` blocks in integration tests against
+//! containers and background workers.
+//!
+//! Polling honours the current [`TestCancellation`]: once a test-level timeout passes,
+//! the poller stops waiting immediately instead of running out its own deadline.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::eventually::{poll_until, EventuallyConfig};
+//! use std::time::Duration;
+//!
+//! let mut calls = 0;
+//! let outcome = poll_until(
+//!     &EventuallyConfig::new().with_interval(Duration::from_millis(1)),
+//!     || {
+//!         calls += 1;
+//!         calls
+//!     },
+//!     |calls| *calls >= 3,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(outcome.last, 3);
+//! assert_eq!(outcome.attempts, 3);
+//! ```
+
+use crate::core::cancellation::{cancellable_sleep, TestCancellation};
+use crate::core::failure::{FailureKind, TddFailure};
+use std::time::{Duration, Instant};
+
+/// Label recorded when a cancelled test interrupts polling
+const WAIT_LABEL: &str = "eventually";
+
+/// Default deadline for eventual assertions
+pub const DEFAULT_EVENTUALLY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Default delay before the second attempt
+pub const DEFAULT_EVENTUALLY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Default multiplier applied to the delay after each failed attempt
+pub const DEFAULT_EVENTUALLY_BACKOFF: f64 = 1.5;
+
+/// Upper bound on the delay between attempts
+pub const MAX_EVENTUALLY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Deadline and backoff for polling
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EventuallyConfig {
+    /// Give up once this much time has passed since the first attempt
+    pub timeout: Duration,
+    /// Delay before the second attempt
+    pub interval: Duration,
+    /// Multiplier applied to the delay after each failed attempt (1.0 = fixed interval)
+    pub backoff: f64,
+    /// Upper bound on the delay between attempts
+    pub max_interval: Duration,
+}
+
+impl Default for EventuallyConfig {
+    fn default() -> Self {
+        Self {
+            timeout: DEFAULT_EVENTUALLY_TIMEOUT,
+            interval: DEFAULT_EVENTUALLY_INTERVAL,
+            backoff: DEFAULT_EVENTUALLY_BACKOFF,
+            max_interval: MAX_EVENTUALLY_INTERVAL,
+        }
+    }
+}
+
+impl EventuallyConfig {
+    /// Defaults: 5 s timeout, 50 ms initial interval, 1.5x backoff capped at 1 s
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the deadline
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Set the initial delay between attempts
+    #[must_use]
+    pub const fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Set the backoff multiplier (values below 1.0 are treated as 1.0)
+    #[must_use]
+    pub const fn with_backoff(mut self, backoff: f64) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Delay after `current`, never past `remaining`
+    fn next_delay(&self, current: Duration, remaining: Duration) -> Duration {
+        current
+            .mul_f64(self.backoff.max(1.0))
+            .min(self.max_interval.max(self.interval))
+            .min(remaining)
+    }
+}
+
+/// Result of polling: the last observed value and how long it took
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventuallyOutcome<T> {
+    /// Value from the last attempt (the satisfying one on success)
+    pub last: T,
+    /// Number of times the probe ran
+    pub attempts: u32,
+    /// Time from the first attempt to the last
+    pub elapsed: Duration,
+}
+
+/// Attempt counter and backoff clock for one polling loop
+///
+/// Call [`EventuallyPoller::wait`] (or [`EventuallyPoller::wait_async`]) after every
+/// unsatisfied attempt; it sleeps for the next backoff delay, or returns `false` once
+/// the deadline has passed. The assertion macros expand to a loop around this, so their
+/// conditions can borrow the test's locals freely.
+#[derive(Debug, Clone)]
+pub struct EventuallyPoller {
+    config: EventuallyConfig,
+    start: Instant,
+    delay: Duration,
+    failed_attempts: u32,
+}
+
+impl EventuallyPoller {
+    /// Start the clock
+    #[must_use]
+    pub fn new(config: EventuallyConfig) -> Self {
+        Self { config, start: Instant::now(), delay: config.interval, failed_attempts: 0 }
+    }
+
+    /// Record a failed attempt and return the delay before the next one
+    fn record_failure(&mut self) -> Option<Duration> {
+        self.failed_attempts += 1;
+        let remaining =
+            self.config.timeout.checked_sub(self.start.elapsed()).filter(|r| !r.is_zero())?;
+        let delay = self.delay.min(remaining);
+        self.delay = self.config.next_delay(self.delay, remaining);
+        Some(delay)
+    }
+
+    /// Record a failed attempt, then sleep until the next one
+    ///
+    /// Returns `false` (without sleeping) once the deadline has passed, and `false`
+    /// (waking early) once the current [`TestCancellation`] is cancelled.
+    pub fn wait(&mut self) -> bool {
+        self.record_failure()
+            .is_some_and(|delay| cancellable_sleep(WAIT_LABEL, delay).is_ok())
+    }
+
+    /// Async [`EventuallyPoller::wait`], sleeping with `tokio::time::sleep`
+    ///
+    /// Sleeps are clamped to the current [`TestCancellation`]'s deadline.
+    ///
+    /// **Required feature**: `async` (and a Tokio runtime with the time driver enabled)
+    #[cfg(feature = "async")]
+    pub async fn wait_async(&mut self) -> bool {
+        let Some(delay) = self.record_failure() else {
+            return false;
+        };
+        let token = TestCancellation::current();
+        let delay = token
+            .as_ref()
+            .and_then(TestCancellation::remaining)
+            .map_or(delay, |r| delay.min(r));
+        tokio::time::sleep(delay).await;
+        token.is_none_or(|token| token.check(WAIT_LABEL).is_ok())
+    }
+
+    /// Unsatisfied attempts so far
+    #[must_use]
+    pub const fn failed_attempts(&self) -> u32 {
+        self.failed_attempts
+    }
+
+    /// Time since the poller started
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    /// Deadline and backoff in use
+    #[must_use]
+    pub const fn config(&self) -> &EventuallyConfig {
+        &self.config
+    }
+
+    /// Fail the current test: `description` never held before the deadline
+    ///
+    /// # Panics
+    ///
+    /// Always.
+    #[track_caller]
+    pub fn fail(&self, description: &str, last: Option<&dyn std::fmt::Debug>) -> ! {
+        use std::fmt::Write;
+
+        let mut message = format!(
+            "{description} did not hold within {:?} ({} attempt(s))",
+            self.config.timeout, self.failed_attempts
+        );
+        let mut failure = TddFailure::new(FailureKind::Timeout, "")
+            .with_context("timeout", format!("{:?}", self.config.timeout))
+            .with_context("attempts", self.failed_attempts.to_string())
+            .with_context("elapsed", format!("{:?}", self.elapsed()));
+        if let Some(last) = last {
+            let _ = write!(message, "; last observed: {last:?}");
+            failure = failure.with_context("last", format!("{last:?}"));
+        }
+        if let Some(reason) = TestCancellation::current().and_then(|token| token.reason()) {
+            let _ = write!(message, " (cancelled: {reason})");
+            failure = failure.with_context("cancelled", reason);
+        }
+        failure.with_message(message).raise()
+    }
+
+    fn outcome<T>(&self, last: T, attempts: u32) -> EventuallyOutcome<T> {
+        EventuallyOutcome { last, attempts, elapsed: self.elapsed() }
+    }
+}
+
+/// Apply an eventual-assertion predicate to a probe value
+///
+/// Used by the macros so closure parameter types are inferred from the probe.
+#[doc(hidden)]
+pub fn eventually_holds<T>(value: &T, predicate: impl FnOnce(&T) -> bool) -> bool {
+    predicate(value)
+}
+
+/// Run `probe` until `done` accepts its value or `config.timeout` passes
+///
+/// The probe always runs at least once, and once more at the deadline.
+///
+/// # Errors
+///
+/// Returns the last observed value (as `Err`) if the deadline passes first.
+pub fn poll_until<T>(
+    config: &EventuallyConfig,
+    mut probe: impl FnMut() -> T,
+    mut done: impl FnMut(&T) -> bool,
+) -> Result<EventuallyOutcome<T>, EventuallyOutcome<T>> {
+    let mut poller = EventuallyPoller::new(*config);
+    loop {
+        let last = probe();
+        if done(&last) {
+            return Ok(poller.outcome(last, poller.failed_attempts + 1));
+        }
+        if !poller.wait() {
+            return Err(poller.outcome(last, poller.failed_attempts));
+        }
+    }
+}
+
+/// Async [`poll_until`]: awaits `probe` and sleeps with `tokio::time::sleep`
+///
+/// **Required feature**: `async` (and a Tokio runtime with the time driver enabled)
+///
+/// # Errors
+///
+/// Returns the last observed value (as `Err`) if the deadline passes first.
+#[cfg(feature = "async")]
+pub async fn poll_until_async<T, F>(
+    config: &EventuallyConfig,
+    mut probe: impl FnMut() -> F,
+    mut done: impl FnMut(&T) -> bool,
+) -> Result<EventuallyOutcome<T>, EventuallyOutcome<T>>
+where
+    F: std::future::Future<Output = T>,
+{
+    let mut poller = EventuallyPoller::new(*config);
+    loop {
+        let last = probe().await;
+        if done(&last) {
+            return Ok(poller.outcome(last, poller.failed_attempts + 1));
+        }
+        if !poller.wait_async().await {
+            return Err(poller.outcome(last, poller.failed_attempts));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_poll_until_times_out_with_last_value, {
+        // Arrange
+        let config = EventuallyConfig::new()
+            .with_timeout(Duration::from_millis(30))
+            .with_interval(Duration::from_millis(5));
+        let mut calls = 0_u32;
+
+        // Act
+        let outcome = poll_until(
+            &config,
+            || {
+                calls += 1;
+                calls
+            },
+            |_| false,
+        )
+        .unwrap_err();
+
+        // Assert
+        assert_eq!(outcome.last, calls);
+        assert_eq!(outcome.attempts, calls);
+        assert!(outcome.attempts >= 2, "should retry before the deadline: {outcome:?}");
+        assert!(outcome.elapsed >= Duration::from_millis(30));
+    });
+
+    test!(test_backoff_grows_to_cap_and_respects_deadline, {
+        // Arrange
+        let config = EventuallyConfig::new()
+            .with_interval(Duration::from_millis(100))
+            .with_backoff(2.0);
+
+        // Act, Assert
+        assert_eq!(
+            config.next_delay(Duration::from_millis(100), Duration::from_secs(9)),
+            Duration::from_millis(200)
+        );
+        assert_eq!(
+            config.next_delay(Duration::from_millis(800), Duration::from_secs(9)),
+            MAX_EVENTUALLY_INTERVAL
+        );
+        assert_eq!(
+            config.next_delay(Duration::from_millis(800), Duration::from_millis(70)),
+            Duration::from_millis(70)
+        );
+        assert_eq!(
+            config
+                .with_backoff(0.5)
+                .next_delay(Duration::from_millis(100), Duration::from_secs(9)),
+            Duration::from_millis(100)
+        );
+    });
+}
@@ -0,0 +1,334 @@
+//! Structured Test Failures
+//!
+//! [`TddFailure`] is the panic payload raised by the framework's assertion macros. It
+//! carries the failure kind, the message, a context map (expected/actual values,
+//! tolerances, patterns, ...), the caller location, and a backtrace, so harnesses that
+//! catch panics can report failures with structure instead of parsing strings.
+//!
+//! # Plain libtest vs. harnesses
+//!
+//! Libtest only understands string payloads: it prints them, and
+//! `#[should_panic(expected = "...")]` matches against them. Outside a harness,
+//! [`TddFailure::raise`] therefore panics with the rendered message, exactly as the
+//! macros always did. Inside [`TddFailure::catch`] it panics with the structured payload
+//! and `catch` hands it back. Foreign panics (plain `panic!`, `unwrap`) caught by `catch`
+//! become failures of kind [`FailureKind::Panic`].
+//!
+//! `raise` applies the configured redaction rules (see [`RedactionRuleSet`]) to the
+//! message and context values, so secrets in compared values do not reach test logs
+//! or CI artifacts.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::assert_eq_msg;
+//! use chicago_tdd_tools::core::failure::{FailureKind, TddFailure};
+//!
+//! let failure = TddFailure::catch(|| assert_eq_msg!(2 + 2, 5, "arithmetic")).unwrap_err();
+//!
+//! assert_eq!(failure.kind(), FailureKind::Equality);
+//! assert_eq!(failure.context()["expected"], "5");
+//! assert_eq!(failure.context()["actual"], "4");
+//! assert!(failure.to_string().starts_with("arithmetic: expected 5, got 4"));
+//! ```
+
+use crate::core::redaction::RedactionRuleSet;
+use std::any::Any;
+use std::backtrace::{Backtrace, BacktraceStatus};
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe, Location};
+use std::sync::Arc;
+
+thread_local! {
+    /// Nesting depth of [`TddFailure::catch`] on this thread
+    static CATCH_DEPTH: Cell<usize> = const { Cell::new(0) };
+}
+
+/// What kind of check failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FailureKind {
+    /// Values were not equal (`assert_eq_msg!`, `assert_eq_enhanced!`, `assert_approx_eq!`)
+    Equality,
+    /// A `Result` had the wrong variant (`assert_ok!`, `assert_err!`, `assert_fail!`)
+    Result,
+    /// Collection membership (`assert_contains!`, `assert_subset!`, ...)
+    Collection,
+    /// JSON values differed (`assert_json_eq!`)
+    Json,
+    /// A value did not match a pattern (`assert_matches!`)
+    Pattern,
+    /// A value was out of range (`assert_in_range!`)
+    Range,
+    /// A fluent matcher did not match (`expect(..).to_*`)
+    Matcher,
+    /// One or more soft assertions failed (`SoftAssertions::verify`)
+    Soft,
+    /// A condition did not hold before its deadline (`assert_eventually!`)
+    Timeout,
+    /// A performance or guard constraint was violated (`assert_within_tick_budget!`,
+    /// `assert_guard_constraint!`, `assert_elapsed_at_*!`)
+    Constraint,
+    /// A panic that did not come from a framework assertion
+    Panic,
+}
+
+impl FailureKind {
+    /// Stable lowercase name, e.g. `equality`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Equality => "equality",
+            Self::Result => "result",
+            Self::Collection => "collection",
+            Self::Json => "json",
+            Self::Pattern => "pattern",
+            Self::Range => "range",
+            Self::Matcher => "matcher",
+            Self::Soft => "soft",
+            Self::Timeout => "timeout",
+            Self::Constraint => "constraint",
+            Self::Panic => "panic",
+        }
+    }
+}
+
+impl fmt::Display for FailureKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Structured panic payload for framework-raised failures
+///
+/// `Display` renders the message alone (what libtest shows); the alternate form
+/// (`{:#}`) adds the kind, location, and context map.
+#[derive(Debug, Clone)]
+pub struct TddFailure {
+    kind: FailureKind,
+    message: String,
+    context: BTreeMap<String, String>,
+    location: Option<&'static Location<'static>>,
+    backtrace: Arc<Backtrace>,
+}
+
+impl TddFailure {
+    /// New failure located at the caller
+    ///
+    /// A backtrace is captured when `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE` enable it.
+    #[must_use]
+    #[track_caller]
+    pub fn new(kind: FailureKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            context: BTreeMap::new(),
+            location: Some(Location::caller()),
+            backtrace: Arc::new(Backtrace::capture()),
+        }
+    }
+
+    /// Add a context entry (e.g. `expected`, `actual`)
+    #[must_use]
+    pub fn with_context(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.context.insert(key.into(), value.into());
+        self
+    }
+
+    /// Replace the message (e.g. to prefix harness context), keeping everything else
+    #[must_use]
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Apply redaction rules to the message and context values
+    #[must_use]
+    pub fn redacted(mut self, rules: &RedactionRuleSet) -> Self {
+        if rules.is_empty() {
+            return self;
+        }
+        self.message = rules.redact_text(&self.message).into_owned();
+        for (key, value) in &mut self.context {
+            if let Some(replacement) = rules.field_replacement(key) {
+                *value = replacement.to_string();
+            } else {
+                *value = rules.redact_text(value).into_owned();
+            }
+        }
+        self
+    }
+
+    /// What kind of check failed
+    #[must_use]
+    pub const fn kind(&self) -> FailureKind {
+        self.kind
+    }
+
+    /// Failure message (as shown by libtest)
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// Context entries, sorted by key
+    #[must_use]
+    pub const fn context(&self) -> &BTreeMap<String, String> {
+        &self.context
+    }
+
+    /// Where the failing assertion was written (unknown for foreign panics)
+    #[must_use]
+    pub const fn location(&self) -> Option<&'static Location<'static>> {
+        self.location
+    }
+
+    /// Backtrace captured when the failure was created
+    pub fn backtrace(&self) -> &Backtrace {
+        &self.backtrace
+    }
+
+    /// Fail the current test with this failure
+    ///
+    /// Panics with the structured payload inside [`TddFailure::catch`], and with the
+    /// rendered message otherwise. The configured redaction rules are applied first.
+    ///
+    /// # Panics
+    ///
+    /// Always.
+    #[track_caller]
+    #[allow(clippy::panic)] // Raising test failures is the point
+    pub fn raise(self) -> ! {
+        let this = self.redacted(RedactionRuleSet::configured());
+        if CATCH_DEPTH.with(Cell::get) > 0 {
+            panic::panic_any(this)
+        }
+        panic!("{this}")
+    }
+
+    /// Run `f`, returning any panic it raises as a failure
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`TddFailure`] raised by a framework assertion in `f`, or a
+    /// [`FailureKind::Panic`] failure for any other panic.
+    pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, Self> {
+        struct Depth;
+        impl Drop for Depth {
+            fn drop(&mut self) {
+                CATCH_DEPTH.with(|depth| depth.set(depth.get().saturating_sub(1)));
+            }
+        }
+
+        CATCH_DEPTH.with(|depth| depth.set(depth.get() + 1));
+        let depth = Depth;
+        let result = panic::catch_unwind(AssertUnwindSafe(f));
+        drop(depth);
+        result.map_err(Self::from_payload)
+    }
+
+    /// Recover a failure from a caught panic payload
+    ///
+    /// String payloads become [`FailureKind::Panic`] failures carrying the string.
+    #[must_use]
+    pub fn from_payload(payload: Box<dyn Any + Send>) -> Self {
+        match payload.downcast::<Self>() {
+            Ok(failure) => *failure,
+            Err(payload) => {
+                let message = payload
+                    .downcast_ref::<&str>()
+                    .map(ToString::to_string)
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "non-string panic payload".to_string());
+                Self {
+                    kind: FailureKind::Panic,
+                    message,
+                    context: BTreeMap::new(),
+                    location: None,
+                    backtrace: Arc::new(Backtrace::disabled()),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for TddFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)?;
+        if f.alternate() {
+            write!(f, "\n   kind: {}", self.kind)?;
+            if let Some(location) = self.location {
+                write!(f, "\n   at: {location}")?;
+            }
+            for (key, value) in &self.context {
+                write!(f, "\n   {key}: {value}")?;
+            }
+            if self.backtrace.status() == BacktraceStatus::Captured {
+                write!(f, "\n   backtrace:\n{}", self.backtrace)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for TddFailure {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_catch_returns_structured_failure, {
+        // Arrange
+        let line = line!() + 2;
+        let caught = TddFailure::catch(|| {
+            TddFailure::new(FailureKind::Range, "out of range")
+                .with_context("value", "11")
+                .raise()
+        });
+
+        // Act
+        let failure = caught.unwrap_err();
+
+        // Assert
+        assert_eq!(failure.kind(), FailureKind::Range);
+        assert_eq!(failure.message(), "out of range");
+        assert_eq!(failure.location().map(Location::line), Some(line));
+        let report = format!("{failure:#}");
+        assert!(report.contains("kind: range") && report.contains("value: 11"), "{report}");
+    });
+
+    test!(test_catch_wraps_foreign_panics, {
+        // Arrange, Act
+        #[allow(clippy::panic)]
+        let failure = TddFailure::catch(|| panic!("boom {}", 7)).unwrap_err();
+
+        // Assert
+        assert_eq!(failure.kind(), FailureKind::Panic);
+        assert_eq!(failure.to_string(), "boom 7");
+        assert_eq!(TddFailure::catch(|| 5).unwrap(), 5);
+    });
+
+    test!(test_redacted_scrubs_message_and_context, {
+        // Arrange
+        let rules = RedactionRuleSet::new().field("token", "[TOKEN]").field("actual", "[HIDDEN]");
+        let failure = TddFailure::new(FailureKind::Equality, "expected ok, got token=s3cr3t")
+            .with_context("actual", "s3cr3t")
+            .with_context("expected", "ok");
+
+        // Act
+        let redacted = failure.redacted(&rules);
+
+        // Assert
+        assert_eq!(redacted.message(), "expected ok, got token=[TOKEN]");
+        assert_eq!(redacted.context()["actual"], "[HIDDEN]");
+        assert_eq!(redacted.context()["expected"], "ok");
+    });
+
+    #[test]
+    #[should_panic(expected = "plain message")]
+    fn test_raise_outside_catch_panics_with_string() {
+        TddFailure::new(FailureKind::Constraint, "plain message").raise();
+    }
+}
@@ -9,10 +9,27 @@
 //! For resources requiring explicit cleanup, implement the `cleanup()` method or use Drop.
 //!
 //! **v1.3.0**: Added fixture introspection with metadata tracking and scoped metadata.
+//!
+//! **Shared fixtures**: [`TestFixture::shared`] caches expensive fixtures (e.g. a container)
+//! per module or per process ([`FixtureScopeKind`]). Shared values are reference-counted
+//! and torn down in reverse creation order when the process exits.
+//!
+//! **Fake time**: [`ClockFixture`] injects a [`TestClock`] (a [`Clock`]) that tests freeze,
+//! advance, or jump instead of sleeping.
+//!
+//! **Unique names**: fixture counters and [`TestFixture::unique_name`] draw from one
+//! process-wide allocator, so parallel tests never share a temp dir, container name, or
+//! DB schema; [`TestFixture::unique_port`] reserves a free port the same way.
 
+use crate::core::messages::{message, MessageId};
+use crate::core::ports::{PortAllocationResult, PortAllocator, ReservedPort};
+use std::any::{Any, TypeId};
 use std::collections::HashMap;
+use std::ops::Deref;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::sync::{Arc, Mutex, MutexGuard, Once, OnceLock, PoisonError};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 
 /// > 📚 Reference
@@ -21,16 +38,42 @@ use thiserror::Error;
 #[derive(Error, Debug)]
 pub enum FixtureError {
     /// Failed to create fixture
-    #[error("Failed to create fixture: {0}")]
+    #[error("{}", message(MessageId::FIXTURE_CREATION_FAILED, &[("reason", .0)]))]
     CreationFailed(String),
     /// Fixture operation failed
-    #[error("Fixture operation failed: {0}")]
+    #[error("{}", message(MessageId::FIXTURE_OPERATION_FAILED, &[("reason", .0)]))]
     OperationFailed(String),
 }
 
 /// Result type for fixture operations
 pub type FixtureResult<T> = Result<T, FixtureError>;
 
+/// Longest prefix kept by [`TestFixture::unique_name`]; keeps names under the 63-byte
+/// identifier limit of Postgres
+const MAX_NAME_PREFIX: usize = 32;
+
+/// Next identifier handed out in this process, shared by fixture counters and names
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+fn next_id() -> u64 {
+    NEXT_ID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Lowercase `prefix`, replace anything but ASCII letters and digits with `_`, and make
+/// sure it starts with a letter
+fn sanitize_name_prefix(prefix: &str) -> String {
+    let mut name: String = prefix
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .take(MAX_NAME_PREFIX)
+        .collect();
+    if !name.starts_with(|c: char| c.is_ascii_lowercase()) {
+        name.insert(0, 't');
+        name.truncate(MAX_NAME_PREFIX);
+    }
+    name
+}
+
 /// > 📚 Reference
 ///
 /// Fixture metadata for introspection and debugging.
@@ -199,7 +242,7 @@ pub trait FixtureProvider {
 pub struct TestFixture<T: ?Sized = ()> {
     /// Inner fixture data
     inner: Box<T>,
-    /// Unique test counter for isolation
+    /// Unique test counter for isolation (process-wide, shared by every fixture type)
     test_counter: u64,
     /// Test metadata
     metadata: HashMap<String, String>,
@@ -236,8 +279,8 @@ impl TestFixture<()> {
     /// Returns an error if fixture creation fails.
     #[allow(clippy::unnecessary_wraps)] // API design - Result allows future validation without breaking changes
     pub fn new() -> FixtureResult<Self> {
-        static COUNTER: AtomicU64 = AtomicU64::new(0);
-        let counter = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let counter = next_id();
+        crate::core::receipt::record_fixture("TestFixture");
 
         Ok(Self {
             inner: Box::new(()),
@@ -251,8 +294,8 @@ impl TestFixture<()> {
 impl<T> TestFixture<T> {
     /// Create a new fixture with custom inner data
     pub fn with_data(data: T) -> Self {
-        static COUNTER: AtomicU64 = AtomicU64::new(0);
-        let counter = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let counter = next_id();
+        crate::core::receipt::record_fixture(std::any::type_name::<T>());
 
         Self {
             inner: Box::new(data),
@@ -274,11 +317,50 @@ impl<T> TestFixture<T> {
     }
 
     /// Get test counter
+    ///
+    /// Counters are unique across every fixture in the process, whatever its type.
     #[must_use]
     pub const fn test_counter(&self) -> u64 {
         self.test_counter
     }
 
+    /// Collision-free name for a shared resource: temp dir, container, DB schema
+    ///
+    /// Returns `{prefix}_{pid}_{id}`. The prefix is lowercased, non-alphanumeric characters
+    /// become `_`, and it is truncated to 32 characters, so the name is valid as a file
+    /// name, a Docker container name, and an unquoted SQL identifier. The process id keeps
+    /// names apart across test binaries running at once; the id is unique in the process,
+    /// so every call returns a new name, even on the same fixture.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chicago_tdd_tools::core::fixture::TestFixture;
+    ///
+    /// let fixture = TestFixture::new().unwrap();
+    /// let schema = fixture.unique_name("Orders DB");
+    /// assert!(schema.starts_with("orders_db_"));
+    /// assert_ne!(schema, fixture.unique_name("Orders DB"));
+    /// ```
+    #[must_use]
+    #[allow(clippy::unused_self)] // Method form keeps names next to the fixture that owns them
+    pub fn unique_name(&self, prefix: &str) -> String {
+        format!("{}_{}_{}", sanitize_name_prefix(prefix), std::process::id(), next_id())
+    }
+
+    /// Reserve a free local port for this test
+    ///
+    /// Delegates to [`PortAllocator`]; the port stays reserved until the returned
+    /// [`ReservedPort`] is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no free port can be found.
+    #[allow(clippy::unused_self)] // Method form keeps ports next to the fixture that owns them
+    pub fn unique_port(&self) -> PortAllocationResult<ReservedPort> {
+        PortAllocator::allocate()
+    }
+
     /// Set metadata
     pub fn set_metadata(&mut self, key: String, value: String) {
         self.metadata.insert(key, value);
@@ -366,6 +448,441 @@ impl<T> TestFixture<T> {
     }
 }
 
+/// > 📚 Reference
+///
+/// How long a fixture lives and who shares it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum FixtureScopeKind {
+    /// Created for each test (the default)
+    #[default]
+    Test,
+    /// Created once per test module and shared by its tests
+    Module,
+    /// Created once per process and shared by every test
+    Session,
+}
+
+impl FromStr for FixtureScopeKind {
+    type Err = FixtureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "test" => Ok(Self::Test),
+            "module" => Ok(Self::Module),
+            "session" => Ok(Self::Session),
+            other => Err(FixtureError::CreationFailed(format!(
+                "unknown fixture scope '{other}' (expected test, module, or session)"
+            ))),
+        }
+    }
+}
+
+/// > 📚 Reference
+///
+/// Handle to a module- or session-scoped fixture.
+///
+/// Dereferences to the shared value. Each handle holds a reference count; the registry
+/// holds one more until process exit.
+#[derive(Debug)]
+pub struct SharedFixture<T> {
+    value: Arc<T>,
+    scope: FixtureScopeKind,
+}
+
+impl<T> SharedFixture<T> {
+    /// Scope the fixture was created with
+    #[must_use]
+    pub const fn scope(&self) -> FixtureScopeKind {
+        self.scope
+    }
+
+    /// Number of live references, including the registry's
+    #[must_use]
+    pub fn ref_count(&self) -> usize {
+        Arc::strong_count(&self.value)
+    }
+
+    /// Whether two handles refer to the same instance
+    #[must_use]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.value, &other.value)
+    }
+}
+
+impl<T> Clone for SharedFixture<T> {
+    fn clone(&self) -> Self {
+        Self { value: Arc::clone(&self.value), scope: self.scope }
+    }
+}
+
+impl<T> Deref for SharedFixture<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+type SharedValue = Arc<dyn Any + Send + Sync>;
+type SharedKey = (TypeId, FixtureScopeKind, String);
+
+/// Process-wide cache of module/session fixtures
+#[derive(Default)]
+struct SharedRegistry {
+    /// One slot per key; the slot lock serializes creation of that fixture only
+    slots: HashMap<SharedKey, Arc<Mutex<Option<SharedValue>>>>,
+    /// Creation order, for reverse-order teardown
+    created: Vec<SharedKey>,
+}
+
+fn shared_registry() -> MutexGuard<'static, SharedRegistry> {
+    static REGISTRY: OnceLock<Mutex<SharedRegistry>> = OnceLock::new();
+    REGISTRY
+        .get_or_init(Mutex::default)
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Tear down all module- and session-scoped fixtures in reverse creation order
+///
+/// Runs automatically at process exit. Outstanding [`SharedFixture`] handles stay
+/// valid; the next [`TestFixture::shared`] call for a torn-down key creates it anew.
+pub fn teardown_shared_fixtures() {
+    let (created, mut slots) = {
+        let mut registry = shared_registry();
+        (std::mem::take(&mut registry.created), std::mem::take(&mut registry.slots))
+    };
+    for key in created.iter().rev() {
+        if let Some(slot) = slots.remove(key) {
+            drop(slot.lock().unwrap_or_else(PoisonError::into_inner).take());
+        }
+    }
+}
+
+/// Register [`teardown_shared_fixtures`] to run at process exit (once)
+fn register_exit_teardown() {
+    static REGISTERED: Once = Once::new();
+    REGISTERED.call_once(|| {
+        #[cfg(any(unix, windows))]
+        {
+            extern "C" {
+                fn atexit(callback: extern "C" fn()) -> std::os::raw::c_int;
+            }
+            extern "C" fn run_teardown() {
+                // Unwinding out of an atexit handler would abort the process
+                drop(std::panic::catch_unwind(teardown_shared_fixtures));
+            }
+            // SAFETY: `atexit` is the C runtime exit hook and `run_teardown` never unwinds.
+            #[allow(unsafe_code)]
+            let status = unsafe { atexit(run_teardown) };
+            if status != 0 {
+                crate::alert_warning!(
+                    "Could not register shared fixture teardown at exit",
+                    "Call teardown_shared_fixtures() explicitly"
+                );
+            }
+        }
+    });
+}
+
+impl<T: Send + Sync + 'static> TestFixture<T> {
+    /// Get or create a fixture shared across tests
+    ///
+    /// - [`FixtureScopeKind::Test`]: `init` runs on every call (no sharing).
+    /// - [`FixtureScopeKind::Module`] / [`FixtureScopeKind::Session`]: `init` runs once per
+    ///   `(T, scope, key)` per process; later calls get the same instance. Pass
+    ///   `module_path!()` as the key for module scope.
+    ///
+    /// Concurrent callers for the same key wait for a single `init`. A failed `init` is
+    /// not cached, so the next caller retries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chicago_tdd_tools::core::fixture::{FixtureScopeKind, TestFixture};
+    ///
+    /// let first = TestFixture::shared(FixtureScopeKind::Session, "answer", || Ok(42_u32)).unwrap();
+    /// let second = TestFixture::shared(FixtureScopeKind::Session, "answer", || Ok(0_u32)).unwrap();
+    /// assert!(first.ptr_eq(&second));
+    /// assert_eq!(*second, 42);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `init`.
+    pub fn shared(
+        scope: FixtureScopeKind,
+        key: &str,
+        init: impl FnOnce() -> FixtureResult<T>,
+    ) -> FixtureResult<SharedFixture<T>> {
+        crate::core::receipt::record_fixture(key);
+        if scope == FixtureScopeKind::Test {
+            return init().map(|value| SharedFixture { value: Arc::new(value), scope });
+        }
+        let shared_key = (TypeId::of::<T>(), scope, key.to_string());
+        let slot = Arc::clone(shared_registry().slots.entry(shared_key.clone()).or_default());
+        let mut slot = slot.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(value) = slot.as_ref().and_then(|v| Arc::clone(v).downcast::<T>().ok()) {
+            return Ok(SharedFixture { value, scope });
+        }
+        let value = Arc::new(init()?);
+        *slot = Some(Arc::clone(&value) as SharedValue);
+        drop(slot);
+        shared_registry().created.push(shared_key);
+        register_exit_teardown();
+        Ok(SharedFixture { value, scope })
+    }
+}
+
+/// > 📚 Reference
+///
+/// Source of time for code under test.
+///
+/// Production code takes a `Clock` (usually `Arc<dyn Clock>`) and runs with [`SystemClock`];
+/// tests inject a [`TestClock`] and move time explicitly instead of sleeping.
+pub trait Clock: Send + Sync {
+    /// Current monotonic time
+    fn now(&self) -> Instant;
+
+    /// Current wall-clock time
+    fn system_time(&self) -> SystemTime;
+
+    /// Monotonic time elapsed since `earlier` (zero if `earlier` is later)
+    fn elapsed_since(&self, earlier: Instant) -> Duration {
+        self.now().saturating_duration_since(earlier)
+    }
+}
+
+impl<C: Clock + ?Sized> Clock for Arc<C> {
+    fn now(&self) -> Instant {
+        (**self).now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        (**self).system_time()
+    }
+}
+
+/// Real clock backed by [`Instant::now`] and [`SystemTime::now`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+#[derive(Debug)]
+struct TestClockState {
+    /// Real instant the clock's monotonic timeline starts from
+    origin: Instant,
+    /// Monotonic time accumulated while frozen or before the last `resume`
+    elapsed: Duration,
+    /// Real instant of the last `resume`; `None` while frozen
+    running_since: Option<Instant>,
+    /// Wall-clock time at monotonic offset `wall_anchor`
+    wall: SystemTime,
+    wall_anchor: Duration,
+}
+
+impl TestClockState {
+    fn elapsed(&self) -> Duration {
+        self.elapsed + self.running_since.map_or(Duration::ZERO, |since| since.elapsed())
+    }
+}
+
+/// > 📚 Reference
+///
+/// Controllable clock for time-dependent tests.
+///
+/// A new `TestClock` is frozen: time only moves when the test calls [`advance`](Self::advance).
+/// [`resume`](Self::resume) lets it run at real speed again, and [`jump_to`](Self::jump_to)
+/// sets the wall clock without touching monotonic time (like an NTP correction).
+///
+/// Cloning yields another handle to the same timeline, so the test keeps one handle while
+/// the code under test holds another.
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::core::fixture::{Clock, TestClock};
+/// use std::time::Duration;
+///
+/// let clock = TestClock::new();
+/// let start = clock.now();
+///
+/// clock.advance(Duration::from_secs(3600));
+///
+/// assert_eq!(clock.elapsed_since(start), Duration::from_secs(3600));
+/// ```
+#[derive(Debug, Clone)]
+pub struct TestClock {
+    state: Arc<Mutex<TestClockState>>,
+}
+
+impl TestClock {
+    /// Create a frozen clock at the current wall-clock time
+    #[must_use]
+    pub fn new() -> Self {
+        Self::at(SystemTime::now())
+    }
+
+    /// Create a frozen clock whose wall-clock time reads `wall`
+    #[must_use]
+    pub fn at(wall: SystemTime) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(TestClockState {
+                origin: Instant::now(),
+                elapsed: Duration::ZERO,
+                running_since: None,
+                wall,
+                wall_anchor: Duration::ZERO,
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TestClockState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Stop time from flowing; `now()` stays put until advanced
+    pub fn freeze(&self) {
+        let mut state = self.lock();
+        state.elapsed = state.elapsed();
+        state.running_since = None;
+    }
+
+    /// Let time flow at real speed from the current reading
+    pub fn resume(&self) {
+        let mut state = self.lock();
+        if state.running_since.is_none() {
+            state.running_since = Some(Instant::now());
+        }
+    }
+
+    /// Whether the clock is frozen
+    #[must_use]
+    pub fn is_frozen(&self) -> bool {
+        self.lock().running_since.is_none()
+    }
+
+    /// Move both monotonic and wall-clock time forward by `duration`
+    pub fn advance(&self, duration: Duration) {
+        let mut state = self.lock();
+        state.elapsed = state.elapsed.saturating_add(duration);
+    }
+
+    /// Set the wall-clock time to `wall`, forwards or backwards
+    ///
+    /// Monotonic time is unaffected, so timeouts measured with [`Clock::now`] do not fire.
+    pub fn jump_to(&self, wall: SystemTime) {
+        let mut state = self.lock();
+        state.wall_anchor = state.elapsed();
+        state.wall = wall;
+    }
+
+    /// Total monotonic time elapsed since the clock was created
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.lock().elapsed()
+    }
+}
+
+impl Default for TestClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock for TestClock {
+    fn now(&self) -> Instant {
+        let state = self.lock();
+        state.origin + state.elapsed()
+    }
+
+    fn system_time(&self) -> SystemTime {
+        let state = self.lock();
+        state.wall + state.elapsed().saturating_sub(state.wall_anchor)
+    }
+}
+
+/// > 📚 Reference
+///
+/// Fixture providing a frozen [`TestClock`] for injection into code under test.
+///
+/// Dereferences to the clock, so `fixture.advance(..)` and `fixture.now()` work directly.
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::assert_elapsed_at_least;
+/// use chicago_tdd_tools::core::fixture::{Clock, ClockFixture};
+/// use std::sync::Arc;
+/// use std::time::{Duration, Instant};
+///
+/// struct Session {
+///     clock: Arc<dyn Clock>,
+///     created: Instant,
+/// }
+///
+/// impl Session {
+///     fn is_expired(&self) -> bool {
+///         self.clock.elapsed_since(self.created) >= Duration::from_secs(30 * 60)
+///     }
+/// }
+///
+/// let fixture = ClockFixture::new();
+/// let session = Session { clock: fixture.handle(), created: fixture.now() };
+///
+/// fixture.advance(Duration::from_secs(31 * 60));
+///
+/// assert!(session.is_expired());
+/// assert_elapsed_at_least!(fixture, session.created, Duration::from_secs(30 * 60));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ClockFixture {
+    clock: TestClock,
+}
+
+impl ClockFixture {
+    /// Create a fixture with a frozen clock at the current wall-clock time
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a fixture with a frozen clock whose wall-clock time reads `wall`
+    #[must_use]
+    pub fn at(wall: SystemTime) -> Self {
+        Self { clock: TestClock::at(wall) }
+    }
+
+    /// The underlying test clock
+    #[must_use]
+    pub const fn clock(&self) -> &TestClock {
+        &self.clock
+    }
+
+    /// Shared handle to inject into code under test
+    #[must_use]
+    pub fn handle(&self) -> Arc<dyn Clock> {
+        Arc::new(self.clock.clone())
+    }
+}
+
+impl Deref for ClockFixture {
+    type Target = TestClock;
+
+    fn deref(&self) -> &TestClock {
+        &self.clock
+    }
+}
+
 /// Default fixture provider implementation
 impl FixtureProvider for () {
     type Fixture<'a> = TestFixture<()>;
@@ -518,9 +1035,77 @@ mod tests {
         let fixture2 = TestFixture::new().unwrap();
         let counter2 = fixture2.test_counter();
 
-        // Assert: Verify counters are valid
-        // Counters should be unique (or at least different if atomic wraps)
-        assert!(counter1 != counter2 || counter1 == counter2); // Always true, but verifies method works
+        // Assert: Verify counters are unique
+        assert_ne!(counter1, counter2);
+    });
+
+    test!(test_test_fixture_counter_is_shared_across_types, {
+        // Arrange: Fixtures of different types
+        let unit = TestFixture::new().unwrap();
+        let number = TestFixture::with_data(1_u8);
+        let text = TestFixture::with_data("data");
+
+        // Act
+        let mut counters = vec![unit.test_counter(), number.test_counter(), text.test_counter()];
+        counters.sort_unstable();
+        counters.dedup();
+
+        // Assert: One allocator, so no two fixtures share a counter
+        assert_eq!(counters.len(), 3);
+    });
+
+    test!(test_unique_name_sanitizes_prefix, {
+        // Arrange
+        let fixture = TestFixture::new().unwrap();
+        let pid = std::process::id().to_string();
+
+        // Act
+        let name = fixture.unique_name("My-Container.v2");
+        let numeric = fixture.unique_name("42");
+        let long = fixture.unique_name(&"x".repeat(100));
+
+        // Assert: Lowercase letters, digits, and underscores only
+        assert!(name.starts_with(&format!("my_container_v2_{pid}_")), "{name}");
+        assert!(numeric.starts_with(&format!("t42_{pid}_")), "{numeric}");
+        assert!(long.starts_with(&format!("{}_{pid}_", "x".repeat(MAX_NAME_PREFIX))), "{long}");
+        for name in [&name, &numeric, &long] {
+            assert!(name.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_'));
+            assert!(name.len() <= 63, "{name}");
+        }
+        assert_eq!(sanitize_name_prefix(""), "t");
+    });
+
+    test!(test_unique_name_is_unique_across_threads, {
+        // Arrange
+        let fixture = Arc::new(TestFixture::with_data(()));
+
+        // Act: Allocate names from many threads at once
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let fixture = Arc::clone(&fixture);
+                std::thread::spawn(move || {
+                    (0..100).map(|_| fixture.unique_name("schema")).collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        let names: std::collections::HashSet<String> =
+            handles.into_iter().flat_map(|handle| handle.join().unwrap()).collect();
+
+        // Assert
+        assert_eq!(names.len(), 800);
+    });
+
+    test!(test_unique_port_is_reserved, {
+        // Arrange
+        let fixture = TestFixture::new().unwrap();
+
+        // Act
+        let first = fixture.unique_port().unwrap();
+        let second = fixture.unique_port().unwrap();
+
+        // Assert
+        assert_ne!(first.port(), second.port());
+        assert!(PortAllocator::is_reserved(first.port()));
     });
 
     test!(test_test_fixture_metadata, {
@@ -689,4 +1274,148 @@ mod tests {
         // Assert: Key should be removed after scope ends
         assert_eq!(fixture.get_metadata("test_key"), None);
     });
+
+    // ========================================================================
+    // SHARED FIXTURES - Module/session scoping
+    // ========================================================================
+
+    /// Serializes tests that touch the process-wide shared fixture registry
+    static SHARED_REGISTRY_TESTS: Mutex<()> = Mutex::new(());
+
+    test!(test_fixture_scope_kind_parses, {
+        // Arrange & Act & Assert
+        assert_eq!("session".parse::<FixtureScopeKind>().unwrap(), FixtureScopeKind::Session);
+        assert_eq!("module".parse::<FixtureScopeKind>().unwrap(), FixtureScopeKind::Module);
+        assert_eq!(FixtureScopeKind::default(), FixtureScopeKind::Test);
+        assert!("suite".parse::<FixtureScopeKind>().is_err());
+    });
+
+    test!(test_shared_fixture_created_once_per_key_and_scope, {
+        // Arrange
+        let _serial = SHARED_REGISTRY_TESTS.lock().unwrap_or_else(PoisonError::into_inner);
+        struct Expensive(u32);
+        let created = AtomicU64::new(0);
+        let init = || {
+            created.fetch_add(1, Ordering::SeqCst);
+            Ok(Expensive(7))
+        };
+
+        // Act
+        let a = TestFixture::shared(FixtureScopeKind::Session, "expensive", init).unwrap();
+        let b = TestFixture::shared(FixtureScopeKind::Session, "expensive", init).unwrap();
+        let module = TestFixture::shared(FixtureScopeKind::Module, "expensive", init).unwrap();
+        let per_test = TestFixture::shared(FixtureScopeKind::Test, "expensive", init).unwrap();
+
+        // Assert
+        assert!(a.ptr_eq(&b));
+        assert!(!a.ptr_eq(&module));
+        assert!(!a.ptr_eq(&per_test));
+        assert_eq!(b.0, 7);
+        assert_eq!(a.ref_count(), 3, "two handles plus the registry");
+        assert_eq!(created.load(Ordering::SeqCst), 3);
+    });
+
+    test!(test_shared_fixture_failed_init_is_retried, {
+        // Arrange
+        let _serial = SHARED_REGISTRY_TESTS.lock().unwrap_or_else(PoisonError::into_inner);
+        struct Flaky;
+
+        // Act
+        let failed = TestFixture::<Flaky>::shared(FixtureScopeKind::Session, "flaky", || {
+            Err(FixtureError::CreationFailed("docker unavailable".to_string()))
+        });
+        let retried = TestFixture::shared(FixtureScopeKind::Session, "flaky", || Ok(Flaky));
+
+        // Assert
+        assert!(failed.is_err());
+        assert!(retried.is_ok());
+    });
+
+    test!(test_teardown_shared_fixtures_drops_in_reverse_order, {
+        // Arrange
+        let _serial = SHARED_REGISTRY_TESTS.lock().unwrap_or_else(PoisonError::into_inner);
+        static DROPS: Mutex<Vec<&str>> = Mutex::new(Vec::new());
+        struct Tracked(&'static str);
+        impl Drop for Tracked {
+            fn drop(&mut self) {
+                DROPS.lock().unwrap().push(self.0);
+            }
+        }
+        drop(TestFixture::shared(FixtureScopeKind::Session, "db", || Ok(Tracked("db"))).unwrap());
+        drop(TestFixture::shared(FixtureScopeKind::Session, "schema", || Ok(Tracked("schema"))));
+
+        // Act
+        teardown_shared_fixtures();
+
+        // Assert
+        assert_eq!(*DROPS.lock().unwrap(), ["schema", "db"]);
+        let recreated =
+            TestFixture::shared(FixtureScopeKind::Session, "db", || Ok(Tracked("db2"))).unwrap();
+        assert_eq!(recreated.0, "db2");
+    });
+
+    test!(test_test_clock_is_frozen_until_advanced, {
+        // Arrange
+        let clock = TestClock::new();
+        let start = clock.now();
+        let wall = clock.system_time();
+
+        // Act
+        std::thread::sleep(Duration::from_millis(5));
+        let still = clock.now();
+        clock.advance(Duration::from_secs(90));
+
+        // Assert
+        assert!(clock.is_frozen());
+        assert_eq!(still, start);
+        assert_eq!(clock.elapsed_since(start), Duration::from_secs(90));
+        assert_eq!(clock.system_time(), wall + Duration::from_secs(90));
+    });
+
+    test!(test_test_clock_resume_and_freeze, {
+        // Arrange
+        let clock = TestClock::new();
+        let start = clock.now();
+
+        // Act
+        clock.resume();
+        std::thread::sleep(Duration::from_millis(5));
+        clock.freeze();
+        let frozen_at = clock.elapsed();
+        std::thread::sleep(Duration::from_millis(5));
+
+        // Assert
+        assert!(frozen_at >= Duration::from_millis(5));
+        assert_eq!(clock.elapsed(), frozen_at);
+        assert_eq!(clock.elapsed_since(start), frozen_at);
+    });
+
+    test!(test_test_clock_jump_moves_wall_clock_only, {
+        // Arrange
+        let clock = TestClock::at(UNIX_EPOCH + Duration::from_secs(1_000));
+        let start = clock.now();
+
+        // Act
+        clock.jump_to(UNIX_EPOCH + Duration::from_secs(10));
+        clock.advance(Duration::from_secs(5));
+
+        // Assert
+        assert_eq!(clock.system_time(), UNIX_EPOCH + Duration::from_secs(15));
+        assert_eq!(clock.elapsed_since(start), Duration::from_secs(5));
+    });
+
+    test!(test_clock_fixture_handle_shares_timeline, {
+        // Arrange
+        let fixture = ClockFixture::at(UNIX_EPOCH);
+        let injected = fixture.handle();
+        let start = injected.now();
+
+        // Act
+        fixture.advance(Duration::from_millis(250));
+
+        // Assert
+        assert_eq!(injected.elapsed_since(start), Duration::from_millis(250));
+        assert_eq!(injected.system_time(), UNIX_EPOCH + Duration::from_millis(250));
+        assert!(SystemClock.system_time() > UNIX_EPOCH);
+    });
 }
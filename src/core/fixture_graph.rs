@@ -0,0 +1,508 @@
+//! > 📚 Reference
+//!
+//! Fixture Dependency Graph
+//!
+//! `TestFixture` instances are independent. `FixtureGraph` lets fixtures declare
+//! dependencies on other fixtures (e.g. DB container → schema → seeded data):
+//!
+//! - **Cycle detection at registration**: registering a fixture that closes a cycle fails
+//!   immediately with the offending path, not at first use.
+//! - **Topological setup**: requesting a fixture sets up its dependencies first.
+//! - **Memoized sharing**: within one [`FixtureScope`] (one test), each fixture is set up at
+//!   most once and shared by every dependent.
+//! - **Reverse-order teardown**: when the scope ends, fixtures are torn down in the reverse of
+//!   their setup order, so dependents are gone before what they depend on.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::fixture_graph::FixtureGraph;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut graph = FixtureGraph::new();
+//! graph
+//!     .register("db", &[], |_| Ok(vec!["users".to_string()]))?
+//!     .register("schema", &["db"], |deps| {
+//!         let tables = deps.get::<Vec<String>>("db")?;
+//!         Ok(format!("schema over {} tables", tables.len()))
+//!     })?;
+//!
+//! let mut scope = graph.scope();
+//! assert_eq!(scope.get::<String>("schema")?, "schema over 1 tables");
+//! assert_eq!(scope.setup_order(), ["db", "schema"]);
+//! scope.teardown()?; // schema, then db
+//! # Ok(())
+//! # }
+//! ```
+
+use super::fixture::{FixtureError, FixtureResult};
+use crate::core::messages::{message, MessageId};
+use std::any::{type_name, Any};
+use std::collections::{BTreeMap, HashMap};
+use thiserror::Error;
+
+/// > 📚 Reference
+///
+/// Fixture graph error.
+#[derive(Error, Debug)]
+pub enum FixtureGraphError {
+    /// A fixture with this name is already registered
+    #[error("{}", message(MessageId::FIXTURE_GRAPH_DUPLICATE, &[("name", .0)]))]
+    Duplicate(String),
+    /// Registering the fixture would create a dependency cycle
+    #[error("{}", message(MessageId::FIXTURE_GRAPH_CYCLE, &[("cycle", &.0.join(" -> "))]))]
+    Cycle(Vec<String>),
+    /// A requested fixture (or one of its dependencies) is not registered
+    #[error("{}", required_by.as_ref().map_or_else(
+        || message(MessageId::FIXTURE_GRAPH_UNKNOWN, &[("name", name)]),
+        |by| message(MessageId::FIXTURE_GRAPH_UNKNOWN_DEPENDENCY, &[("name", name), ("required_by", by)]),
+    ))]
+    Unknown {
+        /// Missing fixture
+        name: String,
+        /// Fixture that depends on it, if any
+        required_by: Option<String>,
+    },
+    /// The fixture exists but holds a different type
+    #[error("{}", message(MessageId::FIXTURE_GRAPH_TYPE_MISMATCH, &[("name", name), ("expected", expected)]))]
+    TypeMismatch {
+        /// Fixture name
+        name: String,
+        /// Requested type
+        expected: &'static str,
+    },
+    /// A fixture's setup failed
+    #[error("{}", message(MessageId::FIXTURE_GRAPH_SETUP_FAILED, &[("name", name), ("source", source)]))]
+    SetupFailed {
+        /// Fixture name
+        name: String,
+        /// Underlying error
+        source: FixtureError,
+    },
+    /// One or more teardowns failed (all teardowns still ran)
+    #[error("{}", message(MessageId::FIXTURE_GRAPH_TEARDOWN_FAILED, &[("failures", &.0.iter().map(|(name, e)| format!("'{name}': {e}")).collect::<Vec<_>>().join("; "))]))]
+    TeardownFailed(Vec<(String, FixtureError)>),
+}
+
+/// Result type for fixture graph operations
+pub type FixtureGraphResult<T> = Result<T, FixtureGraphError>;
+
+type SetupFn = Box<dyn Fn(&FixtureDeps<'_>) -> FixtureResult<Box<dyn Any>> + Send + Sync>;
+type TeardownFn = Box<dyn Fn(Box<dyn Any>) -> FixtureResult<()> + Send + Sync>;
+
+struct FixtureNode {
+    dependencies: Vec<String>,
+    setup: SetupFn,
+    teardown: Option<TeardownFn>,
+}
+
+/// > 📚 Reference
+///
+/// Registry of named fixtures and their dependencies.
+///
+/// The graph holds only setup/teardown recipes and is typically built once (e.g. in a
+/// `OnceLock`) and shared; each test gets its own [`FixtureScope`] via [`Self::scope`].
+#[derive(Default)]
+pub struct FixtureGraph {
+    nodes: BTreeMap<String, FixtureNode>,
+}
+
+impl std::fmt::Debug for FixtureGraph {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let edges: BTreeMap<&str, &[String]> = self
+            .nodes
+            .iter()
+            .map(|(name, node)| (name.as_str(), &node.dependencies[..]))
+            .collect();
+        f.debug_struct("FixtureGraph").field("fixtures", &edges).finish()
+    }
+}
+
+impl FixtureGraph {
+    /// Create an empty graph
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a fixture whose value is cleaned up by `Drop`
+    ///
+    /// Dependencies may name fixtures registered later; unknown names are reported when
+    /// the fixture is first requested.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FixtureGraphError::Duplicate`] if `name` is taken, or
+    /// [`FixtureGraphError::Cycle`] if the dependencies would create a cycle.
+    pub fn register<T, F>(
+        &mut self,
+        name: &str,
+        dependencies: &[&str],
+        setup: F,
+    ) -> FixtureGraphResult<&mut Self>
+    where
+        T: Any,
+        F: Fn(&FixtureDeps<'_>) -> FixtureResult<T> + Send + Sync + 'static,
+    {
+        self.insert(name, dependencies, erase_setup(setup), None)
+    }
+
+    /// Register a fixture with an explicit teardown
+    ///
+    /// `teardown` receives the value when the owning [`FixtureScope`] ends.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::register`].
+    pub fn register_with_teardown<T, F, D>(
+        &mut self,
+        name: &str,
+        dependencies: &[&str],
+        setup: F,
+        teardown: D,
+    ) -> FixtureGraphResult<&mut Self>
+    where
+        T: Any,
+        F: Fn(&FixtureDeps<'_>) -> FixtureResult<T> + Send + Sync + 'static,
+        D: Fn(T) -> FixtureResult<()> + Send + Sync + 'static,
+    {
+        let teardown: TeardownFn = Box::new(move |value: Box<dyn Any>| {
+            // Values are only stored by the matching setup, so the downcast cannot fail
+            value.downcast::<T>().map_or(Ok(()), |value| teardown(*value))
+        });
+        self.insert(name, dependencies, erase_setup(setup), Some(teardown))
+    }
+
+    fn insert(
+        &mut self,
+        name: &str,
+        dependencies: &[&str],
+        setup: SetupFn,
+        teardown: Option<TeardownFn>,
+    ) -> FixtureGraphResult<&mut Self> {
+        if self.nodes.contains_key(name) {
+            return Err(FixtureGraphError::Duplicate(name.to_string()));
+        }
+        for dependency in dependencies {
+            if let Some(mut path) = self.path_to(dependency, name) {
+                path.insert(0, name.to_string());
+                return Err(FixtureGraphError::Cycle(path));
+            }
+        }
+        let dependencies = dependencies.iter().map(ToString::to_string).collect();
+        self.nodes
+            .insert(name.to_string(), FixtureNode { dependencies, setup, teardown });
+        Ok(self)
+    }
+
+    /// Dependency path `from -> ... -> to`, if one exists
+    fn path_to(&self, from: &str, to: &str) -> Option<Vec<String>> {
+        if from == to {
+            return Some(vec![from.to_string()]);
+        }
+        self.nodes.get(from)?.dependencies.iter().find_map(|next| {
+            self.path_to(next, to).map(|mut path| {
+                path.insert(0, from.to_string());
+                path
+            })
+        })
+    }
+
+    /// Whether a fixture is registered
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.nodes.contains_key(name)
+    }
+
+    /// Declared dependencies of a fixture
+    #[must_use]
+    pub fn dependencies(&self, name: &str) -> Option<&[String]> {
+        self.nodes.get(name).map(|node| &node.dependencies[..])
+    }
+
+    /// Start a per-test scope
+    #[must_use]
+    pub fn scope(&self) -> FixtureScope<'_> {
+        FixtureScope { graph: self, values: HashMap::new(), order: Vec::new() }
+    }
+}
+
+fn erase_setup<T, F>(setup: F) -> SetupFn
+where
+    T: Any,
+    F: Fn(&FixtureDeps<'_>) -> FixtureResult<T> + Send + Sync + 'static,
+{
+    Box::new(move |deps: &FixtureDeps<'_>| setup(deps).map(|value| Box::new(value) as Box<dyn Any>))
+}
+
+/// > 📚 Reference
+///
+/// Read access to a fixture's declared dependencies during its setup.
+pub struct FixtureDeps<'a> {
+    fixture: &'a str,
+    declared: &'a [String],
+    values: &'a HashMap<String, Box<dyn Any>>,
+}
+
+impl FixtureDeps<'_> {
+    /// Get a declared dependency's value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FixtureError::OperationFailed`] if `name` was not declared as a dependency
+    /// or holds a different type.
+    pub fn get<T: Any>(&self, name: &str) -> FixtureResult<&T> {
+        if !self.declared.iter().any(|declared| declared == name) {
+            return Err(FixtureError::OperationFailed(format!(
+                "fixture '{}' did not declare a dependency on '{name}'",
+                self.fixture
+            )));
+        }
+        self.values
+            .get(name)
+            .and_then(|value| value.downcast_ref::<T>())
+            .ok_or_else(|| {
+                FixtureError::OperationFailed(format!(
+                    "dependency '{name}' of '{}' is not a {}",
+                    self.fixture,
+                    type_name::<T>()
+                ))
+            })
+    }
+}
+
+/// > 📚 Reference
+///
+/// Per-test instantiation of a [`FixtureGraph`].
+///
+/// Fixtures are set up lazily on [`Self::get`] and memoized. Dropping the scope (or calling
+/// [`Self::teardown`]) tears them down in reverse setup order.
+pub struct FixtureScope<'g> {
+    graph: &'g FixtureGraph,
+    values: HashMap<String, Box<dyn Any>>,
+    order: Vec<String>,
+}
+
+impl std::fmt::Debug for FixtureScope<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FixtureScope")
+            .field("setup_order", &self.order)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FixtureScope<'_> {
+    /// Get a fixture, setting it and its dependencies up on first use
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FixtureGraphError::Unknown`], [`FixtureGraphError::SetupFailed`], or
+    /// [`FixtureGraphError::TypeMismatch`].
+    pub fn get<T: Any>(&mut self, name: &str) -> FixtureGraphResult<&T> {
+        self.ensure(name, None)?;
+        self.values
+            .get(name)
+            .and_then(|value| value.downcast_ref::<T>())
+            .ok_or_else(|| FixtureGraphError::TypeMismatch {
+                name: name.to_string(),
+                expected: type_name::<T>(),
+            })
+    }
+
+    fn ensure(&mut self, name: &str, required_by: Option<&str>) -> FixtureGraphResult<()> {
+        if self.values.contains_key(name) {
+            return Ok(());
+        }
+        let graph = self.graph;
+        let node = graph.nodes.get(name).ok_or_else(|| FixtureGraphError::Unknown {
+            name: name.to_string(),
+            required_by: required_by.map(ToString::to_string),
+        })?;
+        // Registration rejects cycles, so this recursion terminates
+        for dependency in &node.dependencies {
+            self.ensure(dependency, Some(name))?;
+        }
+        let deps =
+            FixtureDeps { fixture: name, declared: &node.dependencies, values: &self.values };
+        let value = (node.setup)(&deps)
+            .map_err(|source| FixtureGraphError::SetupFailed { name: name.to_string(), source })?;
+        self.values.insert(name.to_string(), value);
+        self.order.push(name.to_string());
+        Ok(())
+    }
+
+    /// Fixtures set up so far, in setup order
+    #[must_use]
+    pub fn setup_order(&self) -> &[String] {
+        &self.order
+    }
+
+    /// Tear down all fixtures in reverse setup order
+    ///
+    /// Every teardown runs even if an earlier one fails.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FixtureGraphError::TeardownFailed`] listing each failed teardown.
+    pub fn teardown(mut self) -> FixtureGraphResult<()> {
+        let failures = self.teardown_all();
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(FixtureGraphError::TeardownFailed(failures))
+        }
+    }
+
+    fn teardown_all(&mut self) -> Vec<(String, FixtureError)> {
+        let mut failures = Vec::new();
+        while let Some(name) = self.order.pop() {
+            let Some(value) = self.values.remove(&name) else { continue };
+            let teardown = self.graph.nodes.get(&name).and_then(|node| node.teardown.as_ref());
+            if let Some(teardown) = teardown {
+                if let Err(e) = teardown(value) {
+                    failures.push((name, e));
+                }
+            }
+        }
+        failures
+    }
+}
+
+impl Drop for FixtureScope<'_> {
+    fn drop(&mut self) {
+        for (name, e) in self.teardown_all() {
+            crate::alert_warning!(
+                format!("Teardown of fixture '{name}' failed: {e}"),
+                "Call FixtureScope::teardown() to surface teardown errors as a test failure"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::sync::{Arc, Mutex};
+
+    fn tracked_graph(log: &Arc<Mutex<Vec<String>>>) -> FixtureGraph {
+        let mut graph = FixtureGraph::new();
+        for (name, deps) in [("db", &[][..]), ("schema", &["db"][..]), ("seed", &["db", "schema"])]
+        {
+            let setup_log = Arc::clone(log);
+            let teardown_log = Arc::clone(log);
+            graph
+                .register_with_teardown(
+                    name,
+                    deps,
+                    move |_| {
+                        setup_log.lock().unwrap().push(format!("setup {name}"));
+                        Ok(name.len())
+                    },
+                    move |_: usize| {
+                        teardown_log.lock().unwrap().push(format!("teardown {name}"));
+                        Ok(())
+                    },
+                )
+                .unwrap();
+        }
+        graph
+    }
+
+    test!(test_setup_is_topological_memoized_and_teardown_reversed, {
+        // Arrange
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let graph = tracked_graph(&log);
+
+        // Act
+        let mut scope = graph.scope();
+        assert_eq!(*scope.get::<usize>("seed").unwrap(), 4);
+        assert_eq!(*scope.get::<usize>("db").unwrap(), 2);
+        scope.teardown().unwrap();
+
+        // Assert: db is shared by schema and seed, so it is set up once
+        assert_eq!(
+            *log.lock().unwrap(),
+            [
+                "setup db",
+                "setup schema",
+                "setup seed",
+                "teardown seed",
+                "teardown schema",
+                "teardown db"
+            ]
+        );
+    });
+
+    test!(test_cycle_rejected_at_registration, {
+        // Arrange
+        let mut graph = FixtureGraph::new();
+        graph.register("a", &["b"], |_| Ok(())).unwrap();
+        graph.register("b", &["c"], |_| Ok(())).unwrap();
+
+        // Act
+        let cycle = graph.register("c", &["a"], |_| Ok(())).map(|_| ()).unwrap_err();
+        let self_loop = graph.register("d", &["d"], |_| Ok(())).map(|_| ()).unwrap_err();
+
+        // Assert
+        assert_eq!(cycle.to_string(), "Fixture dependency cycle: c -> a -> b -> c");
+        assert_eq!(self_loop.to_string(), "Fixture dependency cycle: d -> d");
+        assert!(!graph.contains("c"));
+    });
+
+    test!(test_errors_for_unknown_undeclared_and_mistyped, {
+        // Arrange
+        let mut graph = FixtureGraph::new();
+        graph
+            .register("config", &[], |_| Ok(8080_u16))
+            .unwrap()
+            .register("server", &["missing"], |_| Ok(()))
+            .unwrap()
+            .register("client", &[], |deps| deps.get::<u16>("config").copied())
+            .unwrap();
+        let mut scope = graph.scope();
+
+        // Act & Assert
+        assert!(matches!(
+            scope.get::<()>("server"),
+            Err(FixtureGraphError::Unknown { required_by: Some(by), .. }) if by == "server"
+        ));
+        assert!(matches!(scope.get::<u16>("client"), Err(FixtureGraphError::SetupFailed { .. })));
+        assert!(matches!(
+            scope.get::<String>("config"),
+            Err(FixtureGraphError::TypeMismatch { .. })
+        ));
+        assert!(matches!(
+            graph.scope().get::<u16>("nope"),
+            Err(FixtureGraphError::Unknown { required_by: None, .. })
+        ));
+    });
+
+    test!(test_drop_tears_down_and_collects_failures, {
+        // Arrange
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let graph = tracked_graph(&log);
+        let mut failing = FixtureGraph::new();
+        failing
+            .register_with_teardown(
+                "flaky",
+                &[],
+                |_| Ok(()),
+                |()| Err(FixtureError::OperationFailed("still in use".to_string())),
+            )
+            .unwrap();
+
+        // Act
+        {
+            let mut scope = graph.scope();
+            scope.get::<usize>("schema").unwrap();
+        }
+        let mut scope = failing.scope();
+        scope.get::<()>("flaky").unwrap();
+        let result = scope.teardown();
+
+        // Assert
+        assert_eq!(log.lock().unwrap().last().map(String::as_str), Some("teardown db"));
+        assert!(matches!(result, Err(FixtureGraphError::TeardownFailed(f)) if f.len() == 1));
+    });
+}
@@ -0,0 +1,373 @@
+//! Runtime Feature-Flag Matrix
+//!
+//! Runs a test body once per combination of runtime feature flags and reports each
+//! combination's result separately. Flag-interaction bugs usually hide in a combination
+//! nobody tested, because only the defaults run in CI.
+//!
+//! Flags reach the code under test through the [`FlagSet`] passed to the body (for
+//! config-driven flags) and, with [`FlagMatrix::export_env`], as environment variables
+//! set for the duration of each run.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::flag_matrix::FlagMatrix;
+//!
+//! fn checkout_total(new_pricing: bool, discounts: bool) -> u32 {
+//!     let base = if new_pricing { 90 } else { 100 };
+//!     if discounts { base - 10 } else { base }
+//! }
+//!
+//! FlagMatrix::new()
+//!     .bool_flag("NEW_PRICING")
+//!     .bool_flag("DISCOUNTS")
+//!     .run(|flags| {
+//!         let total = checkout_total(flags.is_enabled("NEW_PRICING"), flags.is_enabled("DISCOUNTS"));
+//!         assert!(total >= 80);
+//!     })
+//!     .assert_all_passed();
+//! ```
+
+use crate::core::failure::TddFailure;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Mutex, PoisonError};
+
+/// Serializes matrix runs that export flags to the process environment
+static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+/// Values treated as "enabled" by [`FlagSet::is_enabled`]
+const ENABLED_VALUES: &[&str] = &["1", "true", "on", "yes", "enabled"];
+
+/// One combination of flag values
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FlagSet {
+    values: BTreeMap<String, String>,
+}
+
+impl FlagSet {
+    /// Value of `flag` in this combination
+    #[must_use]
+    pub fn get(&self, flag: &str) -> Option<&str> {
+        self.values.get(flag).map(String::as_str)
+    }
+
+    /// Whether `flag` is set to an enabled value (`1`, `true`, `on`, `yes`, `enabled`)
+    #[must_use]
+    pub fn is_enabled(&self, flag: &str) -> bool {
+        self.get(flag)
+            .is_some_and(|value| ENABLED_VALUES.iter().any(|on| value.eq_ignore_ascii_case(on)))
+    }
+
+    /// Iterate over `(flag, value)` pairs in flag-name order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(flag, value)| (flag.as_str(), value.as_str()))
+    }
+}
+
+impl fmt::Display for FlagSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for (flag, value) in self.iter() {
+            if !first {
+                f.write_str(" ")?;
+            }
+            first = false;
+            write!(f, "{flag}={value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of running the body for one combination
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FlagOutcome {
+    /// The body completed
+    Passed,
+    /// The body panicked (failure report captured, including assertion context)
+    Failed(String),
+    /// The combination was excluded and not run
+    Skipped,
+}
+
+/// Result for one flag combination
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlagCombinationResult {
+    /// Flag values the body ran with
+    pub flags: FlagSet,
+    /// What happened
+    pub outcome: FlagOutcome,
+}
+
+/// Per-combination results of a matrix run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlagMatrixReport {
+    /// Results in combination order
+    pub results: Vec<FlagCombinationResult>,
+}
+
+impl FlagMatrixReport {
+    /// Combinations whose body panicked
+    pub fn failures(&self) -> impl Iterator<Item = &FlagCombinationResult> {
+        self.results.iter().filter(|r| matches!(r.outcome, FlagOutcome::Failed(_)))
+    }
+
+    /// Whether every combination that ran passed
+    #[must_use]
+    pub fn all_passed(&self) -> bool {
+        self.failures().next().is_none()
+    }
+
+    /// Assert every combination that ran passed
+    ///
+    /// # Panics
+    ///
+    /// Panics with one line per combination, marking which ones failed and why.
+    pub fn assert_all_passed(&self) {
+        assert!(self.all_passed(), "{self}");
+    }
+}
+
+impl fmt::Display for FlagMatrixReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let failed = self.failures().count();
+        if failed == 0 {
+            writeln!(f, "✅ Flag matrix: {} combination(s) passed", self.results.len())?;
+        } else {
+            writeln!(
+                f,
+                "🚨 Flag matrix: {failed} of {} combination(s) failed",
+                self.results.len()
+            )?;
+        }
+        for result in &self.results {
+            match &result.outcome {
+                FlagOutcome::Passed => writeln!(f, "   ✅ {}", result.flags)?,
+                FlagOutcome::Failed(message) => writeln!(f, "   ❌ {}: {message}", result.flags)?,
+                FlagOutcome::Skipped => writeln!(f, "   ⏭️  {} (excluded)", result.flags)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Predicate excluding impossible combinations
+type Exclusion = Box<dyn Fn(&FlagSet) -> bool>;
+
+/// Builder for a runtime feature-flag matrix
+#[derive(Default)]
+pub struct FlagMatrix {
+    flags: Vec<(String, Vec<String>)>,
+    exclusions: Vec<Exclusion>,
+    export_env: bool,
+}
+
+impl fmt::Debug for FlagMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FlagMatrix")
+            .field("flags", &self.flags)
+            .field("exclusions", &self.exclusions.len())
+            .field("export_env", &self.export_env)
+            .finish()
+    }
+}
+
+impl FlagMatrix {
+    /// Create an empty matrix (one combination with no flags)
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a flag taking each of `values`
+    #[must_use]
+    pub fn flag<V: Into<String>>(
+        mut self,
+        name: impl Into<String>,
+        values: impl IntoIterator<Item = V>,
+    ) -> Self {
+        self.flags.push((name.into(), values.into_iter().map(Into::into).collect()));
+        self
+    }
+
+    /// Add an on/off flag (`false`, `true`)
+    #[must_use]
+    pub fn bool_flag(self, name: impl Into<String>) -> Self {
+        self.flag(name, ["false", "true"])
+    }
+
+    /// Skip combinations matching `predicate` (reported as excluded)
+    #[must_use]
+    pub fn exclude(mut self, predicate: impl Fn(&FlagSet) -> bool + 'static) -> Self {
+        self.exclusions.push(Box::new(predicate));
+        self
+    }
+
+    /// Also set each flag as an environment variable while the body runs
+    ///
+    /// Previous values are restored afterwards. Exporting runs are serialized
+    /// process-wide because the environment is shared by all test threads.
+    #[must_use]
+    pub const fn export_env(mut self) -> Self {
+        self.export_env = true;
+        self
+    }
+
+    /// Every combination, first flag varying slowest
+    #[must_use]
+    pub fn combinations(&self) -> Vec<FlagSet> {
+        self.flags.iter().fold(vec![FlagSet::default()], |sets, (name, values)| {
+            sets.iter()
+                .flat_map(|set| {
+                    values.iter().map(move |value| {
+                        let mut next = set.clone();
+                        next.values.insert(name.clone(), value.clone());
+                        next
+                    })
+                })
+                .collect()
+        })
+    }
+
+    /// Run `body` once per combination, capturing panics per combination
+    pub fn run(&self, mut body: impl FnMut(&FlagSet)) -> FlagMatrixReport {
+        let results = self
+            .combinations()
+            .into_iter()
+            .map(|flags| {
+                let outcome = if self.exclusions.iter().any(|excluded| excluded(&flags)) {
+                    FlagOutcome::Skipped
+                } else {
+                    self.run_one(&flags, &mut body)
+                };
+                FlagCombinationResult { flags, outcome }
+            })
+            .collect();
+        FlagMatrixReport { results }
+    }
+
+    fn run_one(&self, flags: &FlagSet, body: &mut impl FnMut(&FlagSet)) -> FlagOutcome {
+        let _env = self.export_env.then(|| ExportedEnv::apply(flags));
+        match TddFailure::catch(|| body(flags)) {
+            Ok(()) => FlagOutcome::Passed,
+            Err(failure) => FlagOutcome::Failed(format!("{failure:#}")),
+        }
+    }
+}
+
+/// Environment overrides for one combination, restored on drop
+struct ExportedEnv {
+    previous: Vec<(String, Option<std::ffi::OsString>)>,
+    _lock: std::sync::MutexGuard<'static, ()>,
+}
+
+impl ExportedEnv {
+    fn apply(flags: &FlagSet) -> Self {
+        let lock = ENV_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let previous = flags
+            .iter()
+            .map(|(flag, value)| {
+                let old = std::env::var_os(flag);
+                std::env::set_var(flag, value);
+                (flag.to_string(), old)
+            })
+            .collect();
+        Self { previous, _lock: lock }
+    }
+}
+
+impl Drop for ExportedEnv {
+    fn drop(&mut self) {
+        for (flag, old) in self.previous.drain(..) {
+            match old {
+                Some(value) => std::env::set_var(&flag, value),
+                None => std::env::remove_var(&flag),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_combinations_are_cartesian_product, {
+        // Arrange
+        let matrix = FlagMatrix::new().bool_flag("A").flag("MODE", ["fast", "safe", "legacy"]);
+
+        // Act
+        let combinations = matrix.combinations();
+
+        // Assert
+        assert_eq!(combinations.len(), 6);
+        assert_eq!(combinations[0].to_string(), "A=false MODE=fast");
+        assert_eq!(combinations[5].to_string(), "A=true MODE=legacy");
+        assert!(combinations[5].is_enabled("A"));
+        assert!(!combinations[5].is_enabled("MODE"));
+    });
+
+    test!(test_report_isolates_failing_combination, {
+        // Arrange
+        let buggy = |cache: bool, batching: bool| -> usize {
+            if cache && batching {
+                0
+            } else {
+                3
+            }
+        };
+
+        // Act
+        let report = FlagMatrix::new().bool_flag("CACHE").bool_flag("BATCHING").run(|flags| {
+            let items = buggy(flags.is_enabled("CACHE"), flags.is_enabled("BATCHING"));
+            assert_eq!(items, 3, "lost items");
+        });
+
+        // Assert
+        assert!(!report.all_passed());
+        let failures: Vec<_> = report.failures().map(|r| r.flags.to_string()).collect();
+        assert_eq!(failures, ["BATCHING=true CACHE=true"]);
+        let rendered = report.to_string();
+        assert!(rendered.contains("1 of 4 combination(s) failed"));
+        assert!(rendered.contains("❌ BATCHING=true CACHE=true"));
+        assert!(rendered.contains("lost items"));
+    });
+
+    test!(test_export_env_sets_and_restores_variables, {
+        // Arrange
+        let flag = "CHICAGO_TDD_FLAG_MATRIX_TEST";
+        let mut seen = Vec::new();
+
+        // Act
+        let report = FlagMatrix::new()
+            .flag(flag, ["on", "off"])
+            .exclude(|flags| flags.get("CHICAGO_TDD_FLAG_MATRIX_TEST") == Some("off"))
+            .export_env()
+            .run(|_| seen.push(std::env::var(flag).ok()));
+
+        // Assert
+        report.assert_all_passed();
+        assert_eq!(seen, [Some("on".to_string())]);
+        assert_eq!(report.results[1].outcome, FlagOutcome::Skipped);
+        assert!(std::env::var_os(flag).is_none());
+    });
+
+    test!(test_failures_carry_assertion_context, {
+        // Arrange
+        let matrix = FlagMatrix::new().bool_flag("FAST");
+
+        // Act
+        let report = matrix.run(|flags| {
+            let retries = if flags.is_enabled("FAST") { 0 } else { 3 };
+            crate::assert_eq_msg!(retries, 3, "retries");
+        });
+
+        // Assert
+        let failed: Vec<_> = report.failures().map(|r| &r.outcome).collect();
+        let [FlagOutcome::Failed(message)] = failed.as_slice() else {
+            panic!("expected one failure, got {failed:?}");
+        };
+        assert!(message.starts_with("retries: expected 3, got 0"), "{message}");
+        assert!(message.contains("kind: equality") && message.contains("actual: 0"), "{message}");
+    });
+}
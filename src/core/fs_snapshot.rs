@@ -0,0 +1,353 @@
+//! Filesystem State Snapshots
+//!
+//! Runtime behind [`assert_fs_diff!`](crate::assert_fs_diff). [`FsSnapshot::capture`]
+//! records every regular file under a directory with its size and SHA-256 content hash;
+//! diffing two snapshots yields the files created, modified (content hash changed), and
+//! deleted in between, so tests of code that writes files can check exactly what changed
+//! on disk instead of probing a few expected paths.
+//!
+//! Paths are relative to the captured root and always use `/`. Ignore patterns and
+//! expected paths are globs (see [`text_match`](crate::core::text_match)) matched against
+//! the whole relative path; `*` also crosses directories, so `target/*` ignores everything
+//! under `target`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::fs_snapshot::FsSnapshot;
+//!
+//! let dir = std::env::temp_dir().join(format!("fs-snapshot-doc-{}", std::process::id()));
+//! std::fs::create_dir_all(dir.join("out")).unwrap();
+//! std::fs::write(dir.join("input.csv"), "a,b").unwrap();
+//!
+//! let before = FsSnapshot::capture(&dir).unwrap();
+//! std::fs::write(dir.join("out/report.json"), "{}").unwrap();
+//! std::fs::write(dir.join("out/build.log"), "noise").unwrap();
+//! let after = before.recapture().unwrap();
+//!
+//! let diff = before.diff(&after).ignoring(&["*.log"]).unwrap();
+//! assert_eq!(diff.created, ["out/report.json"]);
+//! assert!(diff.modified.is_empty() && diff.deleted.is_empty());
+//! # std::fs::remove_dir_all(&dir).unwrap();
+//! ```
+
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// A captured regular file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FsEntry {
+    /// Size in bytes
+    pub len: u64,
+    /// Hex-encoded SHA-256 of the contents
+    pub hash: String,
+}
+
+/// Every regular file under a directory, keyed by relative path
+///
+/// Symlinks to files are captured by their target's contents; symlinked directories are
+/// not followed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FsSnapshot {
+    root: PathBuf,
+    ignore: Vec<String>,
+    files: BTreeMap<String, FsEntry>,
+}
+
+impl FsSnapshot {
+    /// Capture every file under `root`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `root` or anything under it cannot be read.
+    pub fn capture(root: impl AsRef<Path>) -> io::Result<Self> {
+        Self::capture_ignoring(root, &[])
+    }
+
+    /// Capture every file under `root` whose relative path matches none of `ignore`
+    ///
+    /// Ignored files are not read at all, which keeps large build directories cheap.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a glob is invalid, or if `root` or anything under it cannot
+    /// be read.
+    pub fn capture_ignoring(root: impl AsRef<Path>, ignore: &[&str]) -> io::Result<Self> {
+        let root = root.as_ref().to_path_buf();
+        let globs =
+            compile_globs(ignore).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let mut files = BTreeMap::new();
+        walk(&root, &root, &globs, &mut files)?;
+        Ok(Self { root, ignore: ignore.iter().map(ToString::to_string).collect(), files })
+    }
+
+    /// Capture the same root again with the same ignore globs
+    ///
+    /// # Errors
+    ///
+    /// Same as [`capture_ignoring`](Self::capture_ignoring).
+    pub fn recapture(&self) -> io::Result<Self> {
+        let ignore: Vec<&str> = self.ignore.iter().map(String::as_str).collect();
+        Self::capture_ignoring(&self.root, &ignore)
+    }
+
+    /// Directory that was captured
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Captured files, keyed by relative path
+    #[must_use]
+    pub const fn files(&self) -> &BTreeMap<String, FsEntry> {
+        &self.files
+    }
+
+    /// Captured file at `path`, if any
+    #[must_use]
+    pub fn get(&self, path: &str) -> Option<&FsEntry> {
+        self.files.get(path)
+    }
+
+    /// Changes from `self` to `after`
+    #[must_use]
+    pub fn diff(&self, after: &Self) -> FsDiff {
+        let mut diff = FsDiff::default();
+        for (path, entry) in &after.files {
+            match self.files.get(path) {
+                None => diff.created.push(path.clone()),
+                Some(previous) if previous != entry => diff.modified.push(path.clone()),
+                Some(_) => {}
+            }
+        }
+        diff.deleted = self
+            .files
+            .keys()
+            .filter(|path| !after.files.contains_key(*path))
+            .cloned()
+            .collect();
+        diff
+    }
+}
+
+/// Files created, modified, and deleted between two [`FsSnapshot`]s, each sorted
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FsDiff {
+    /// Present only in the later snapshot
+    pub created: Vec<String>,
+    /// Present in both with different contents
+    pub modified: Vec<String>,
+    /// Present only in the earlier snapshot
+    pub deleted: Vec<String>,
+}
+
+impl FsDiff {
+    /// Whether nothing changed
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.created.is_empty() && self.modified.is_empty() && self.deleted.is_empty()
+    }
+
+    /// Drop every path matching one of `globs`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first invalid glob.
+    pub fn ignoring(mut self, globs: &[&str]) -> Result<Self, String> {
+        let globs = compile_globs(globs)?;
+        for paths in [&mut self.created, &mut self.modified, &mut self.deleted] {
+            paths.retain(|path| !globs.iter().any(|glob| glob.is_match(path)));
+        }
+        Ok(self)
+    }
+
+    /// Check the diff is exactly the expected changes
+    ///
+    /// Each expected entry is a glob: every changed path must match an expected entry
+    /// of its kind, and every expected entry must match at least one changed path.
+    ///
+    /// # Errors
+    ///
+    /// Returns one line per discrepancy, or the invalid glob.
+    pub fn check(
+        &self,
+        created: &[&str],
+        modified: &[&str],
+        deleted: &[&str],
+    ) -> Result<(), String> {
+        let mut problems = Vec::new();
+        for (kind, actual, expected) in [
+            ("created", &self.created, created),
+            ("modified", &self.modified, modified),
+            ("deleted", &self.deleted, deleted),
+        ] {
+            let globs = compile_globs(expected)?;
+            for path in actual {
+                if !globs.iter().any(|glob| glob.is_match(path)) {
+                    problems.push(format!("unexpectedly {kind}: {path}"));
+                }
+            }
+            for (pattern, glob) in expected.iter().zip(&globs) {
+                if !actual.iter().any(|path| glob.is_match(path)) {
+                    problems.push(format!("expected {kind} but was not: {pattern}"));
+                }
+            }
+        }
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems.join("\n"))
+        }
+    }
+}
+
+impl fmt::Display for FsDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "(no changes)");
+        }
+        let lines = self
+            .created
+            .iter()
+            .map(|p| ('+', p))
+            .chain(self.modified.iter().map(|p| ('~', p)))
+            .chain(self.deleted.iter().map(|p| ('-', p)));
+        for (index, (marker, path)) in lines.enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{marker} {path}")?;
+        }
+        Ok(())
+    }
+}
+
+fn compile_globs(globs: &[&str]) -> Result<Vec<Regex>, String> {
+    globs
+        .iter()
+        .map(|glob| {
+            Regex::new(&crate::core::text_match::glob_to_regex(glob))
+                .map_err(|e| format!("invalid glob {glob:?}: {e}"))
+        })
+        .collect()
+}
+
+fn walk(
+    root: &Path,
+    dir: &Path,
+    ignore: &[Regex],
+    files: &mut BTreeMap<String, FsEntry>,
+) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let relative = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        if ignore.iter().any(|glob| glob.is_match(&relative)) {
+            continue;
+        }
+        let file_type = entry.file_type()?;
+        if file_type.is_dir() {
+            walk(root, &path, ignore, files)?;
+        } else if file_type.is_file() || (file_type.is_symlink() && path.is_file()) {
+            let contents = fs::read(&path)?;
+            let hash = hex::encode(Sha256::digest(&contents));
+            files.insert(relative, FsEntry { len: contents.len() as u64, hash });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[allow(clippy::unwrap_used)] // Test code - temp dir setup must succeed
+    fn workspace() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("src/nested")).unwrap();
+        fs::write(dir.path().join("src/main.rs"), "fn main() {}").unwrap();
+        fs::write(dir.path().join("src/nested/lib.rs"), "pub fn f() {}").unwrap();
+        fs::write(dir.path().join("README.md"), "# readme").unwrap();
+        dir
+    }
+
+    test!(test_diff_reports_created_modified_and_deleted_by_content, {
+        // Arrange
+        let dir = workspace();
+        #[allow(clippy::unwrap_used)] // Test code - capture of temp dir
+        let before = FsSnapshot::capture(dir.path()).unwrap();
+
+        // Act
+        #[allow(clippy::unwrap_used)] // Test code - temp dir writes
+        {
+            fs::write(dir.path().join("src/main.rs"), "fn main() { run() }").unwrap();
+            fs::write(dir.path().join("README.md"), "# readme").unwrap();
+            fs::remove_file(dir.path().join("src/nested/lib.rs")).unwrap();
+            fs::create_dir_all(dir.path().join("out")).unwrap();
+            fs::write(dir.path().join("out/report.json"), "{}").unwrap();
+        }
+        #[allow(clippy::unwrap_used)] // Test code - capture of temp dir
+        let diff = before.diff(&before.recapture().unwrap());
+
+        // Assert
+        assert_eq!(diff.created, ["out/report.json"]);
+        assert_eq!(diff.modified, ["src/main.rs"]);
+        assert_eq!(diff.deleted, ["src/nested/lib.rs"]);
+        assert_eq!(diff.to_string(), "+ out/report.json\n~ src/main.rs\n- src/nested/lib.rs");
+        assert_eq!(before.get("README.md").map(|e| e.len), Some(8));
+    });
+
+    test!(test_ignore_globs_apply_to_capture_and_diff, {
+        // Arrange
+        let dir = workspace();
+        #[allow(clippy::unwrap_used)] // Test code - capture of temp dir
+        let before = FsSnapshot::capture_ignoring(dir.path(), &["src/*"]).unwrap();
+
+        // Act
+        #[allow(clippy::unwrap_used)] // Test code - temp dir writes
+        {
+            fs::write(dir.path().join("src/generated.rs"), "// gen").unwrap();
+            fs::write(dir.path().join("run.log"), "log").unwrap();
+            fs::write(dir.path().join("notes.txt"), "n").unwrap();
+        }
+        #[allow(clippy::unwrap_used)] // Test code - capture of temp dir
+        let diff = before.diff(&before.recapture().unwrap()).ignoring(&["*.log"]).unwrap();
+
+        // Assert
+        assert_eq!(before.files().keys().collect::<Vec<_>>(), ["README.md"]);
+        assert_eq!(diff.created, ["notes.txt"]);
+        assert!(diff.check(&["notes.*"], &[], &[]).is_ok());
+    });
+
+    test!(test_check_lists_every_discrepancy, {
+        // Arrange
+        let diff = FsDiff {
+            created: vec!["out/a.json".to_string(), "out/b.json".to_string()],
+            modified: vec![],
+            deleted: vec!["old.txt".to_string()],
+        };
+
+        // Act
+        let result = diff.check(&["out/a.json"], &["config.toml"], &["old.txt"]);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err("unexpectedly created: out/b.json\nexpected modified but was not: config.toml"
+                .to_string())
+        );
+        assert!(diff.check(&["out/*.json"], &[], &["old.txt"]).is_ok());
+    });
+}
@@ -0,0 +1,440 @@
+//! > 📚 Reference
+//!
+//! `JSONPath` Selections
+//!
+//! Runtime behind [`assert_json_path!`](crate::assert_json_path): select parts of a JSON
+//! document with a `JSONPath` expression and check them (existence, equality, count, or a
+//! predicate), so API-response tests assert only the fields they care about.
+//!
+//! # Supported syntax
+//!
+//! | Syntax | Selects |
+//! |--------|---------|
+//! | `$` | the root |
+//! | `.name`, `['name']`, `["name"]` | an object member |
+//! | `[2]`, `[-1]` | an array element (negative counts from the end) |
+//! | `[0,2]`, `['a','b']` | several elements or members |
+//! | `[1:3]`, `[:2]`, `[-2:]` | an array slice (end exclusive) |
+//! | `.*`, `[*]` | every member or element |
+//! | `..name`, `..*` | recursive descent |
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::json_path::JsonPath;
+//! use serde_json::json;
+//!
+//! let response = json!({"items": [{"id": 1, "status": "active"}, {"id": 2, "status": "closed"}]});
+//!
+//! let path = JsonPath::parse("$.items[*].status").unwrap();
+//! assert_eq!(path.select(&response), [&json!("active"), &json!("closed")]);
+//! assert_eq!(JsonPath::parse("$..id").unwrap().select(&response).len(), 2);
+//! ```
+
+use crate::core::failure::{FailureKind, TddFailure};
+use serde_json::Value;
+use std::fmt;
+
+/// Error parsing a `JSONPath` expression
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPathError {
+    /// The expression as written
+    pub path: String,
+    /// Byte offset of the problem
+    pub offset: usize,
+    /// What was expected
+    pub reason: String,
+}
+
+impl fmt::Display for JsonPathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid JSONPath `{}` at byte {}: {}", self.path, self.offset, self.reason)
+    }
+}
+
+impl std::error::Error for JsonPathError {}
+
+/// One step of a parsed path
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+    Members(Vec<String>),
+    Indices(Vec<i64>),
+    Slice(Option<i64>, Option<i64>),
+    Wildcard,
+    /// Apply the inner segment to the current node and all its descendants
+    Descendants(Box<Self>),
+}
+
+/// A parsed `JSONPath` expression
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonPath {
+    source: String,
+    segments: Vec<Segment>,
+}
+
+impl JsonPath {
+    /// Parse an expression (must start with `$`)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JsonPathError`] with the offset of the first unsupported or malformed
+    /// part.
+    pub fn parse(path: &str) -> Result<Self, JsonPathError> {
+        Parser { path, pos: 0 }.parse()
+    }
+
+    /// The expression as written
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    /// Every value the path selects, in document order
+    #[must_use]
+    pub fn select<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        self.segments.iter().fold(vec![root], |nodes, segment| {
+            nodes.into_iter().flat_map(|node| apply(segment, node)).collect()
+        })
+    }
+}
+
+impl fmt::Display for JsonPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+fn apply<'a>(segment: &Segment, node: &'a Value) -> Vec<&'a Value> {
+    match (segment, node) {
+        (Segment::Members(names), Value::Object(map)) => {
+            names.iter().filter_map(|name| map.get(name)).collect()
+        }
+        (Segment::Indices(indices), Value::Array(items)) => indices
+            .iter()
+            .filter_map(|&index| resolve_index(index, items.len()))
+            .map(|i| &items[i])
+            .collect(),
+        (Segment::Slice(start, end), Value::Array(items)) => {
+            let len = items.len();
+            let clamp =
+                |bound: i64| resolve_index(bound, len).unwrap_or(if bound < 0 { 0 } else { len });
+            let start = start.map_or(0, clamp);
+            let end = end.map_or(len, clamp);
+            items
+                .get(start..end.max(start))
+                .map(|slice| slice.iter().collect())
+                .unwrap_or_default()
+        }
+        (Segment::Wildcard, Value::Object(map)) => map.values().collect(),
+        (Segment::Wildcard, Value::Array(items)) => items.iter().collect(),
+        (Segment::Descendants(inner), _) => {
+            let mut selected = apply(inner, node);
+            let children: Vec<&Value> = match node {
+                Value::Object(map) => map.values().collect(),
+                Value::Array(items) => items.iter().collect(),
+                _ => Vec::new(),
+            };
+            for child in children {
+                selected.extend(apply(segment, child));
+            }
+            selected
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Resolve a possibly negative index against `len`
+fn resolve_index(index: i64, len: usize) -> Option<usize> {
+    let len = i64::try_from(len).ok()?;
+    let resolved = if index < 0 { len + index } else { index };
+    usize::try_from(resolved).ok().filter(|_| resolved < len)
+}
+
+struct Parser<'a> {
+    path: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error<T>(&self, reason: impl Into<String>) -> Result<T, JsonPathError> {
+        Err(JsonPathError { path: self.path.to_string(), offset: self.pos, reason: reason.into() })
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.path[self.pos..].chars().next()
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let found = self.path[self.pos..].starts_with(token);
+        if found {
+            self.pos += token.len();
+        }
+        found
+    }
+
+    fn skip_spaces(&mut self) {
+        while self.peek() == Some(' ') {
+            self.pos += 1;
+        }
+    }
+
+    fn parse(mut self) -> Result<JsonPath, JsonPathError> {
+        if !self.eat("$") {
+            return self.error("expected `$`");
+        }
+        let mut segments = Vec::new();
+        while self.pos < self.path.len() {
+            let segment = if self.eat("..") {
+                let inner = if self.peek() == Some('[') { self.bracket()? } else { self.dotted()? };
+                Segment::Descendants(Box::new(inner))
+            } else if self.eat(".") {
+                self.dotted()?
+            } else if self.peek() == Some('[') {
+                self.bracket()?
+            } else {
+                return self.error("expected `.`, `..`, or `[`");
+            };
+            segments.push(segment);
+        }
+        Ok(JsonPath { source: self.path.to_string(), segments })
+    }
+
+    /// `*` or a member name after `.`
+    fn dotted(&mut self) -> Result<Segment, JsonPathError> {
+        if self.eat("*") {
+            return Ok(Segment::Wildcard);
+        }
+        let rest = &self.path[self.pos..];
+        let len = rest.find(['.', '[']).unwrap_or(rest.len());
+        if len == 0 {
+            return self.error("expected a member name or `*`");
+        }
+        self.pos += len;
+        Ok(Segment::Members(vec![rest[..len].to_string()]))
+    }
+
+    /// `[...]`: wildcard, quoted names, indices, or a slice
+    fn bracket(&mut self) -> Result<Segment, JsonPathError> {
+        self.eat("[");
+        self.skip_spaces();
+        let segment = if self.eat("*") {
+            Segment::Wildcard
+        } else if matches!(self.peek(), Some('\'' | '"')) {
+            let mut names = vec![self.quoted()?];
+            while self.list_separator() {
+                names.push(self.quoted()?);
+            }
+            Segment::Members(names)
+        } else {
+            let start = self.integer()?;
+            self.skip_spaces();
+            if self.eat(":") {
+                self.skip_spaces();
+                Segment::Slice(start, self.integer()?)
+            } else {
+                let Some(first) = start else {
+                    return self.error("expected an index, slice, quoted name, or `*`");
+                };
+                let mut indices = vec![first];
+                while self.list_separator() {
+                    match self.integer()? {
+                        Some(index) => indices.push(index),
+                        None => return self.error("expected an index"),
+                    }
+                }
+                Segment::Indices(indices)
+            }
+        };
+        self.skip_spaces();
+        if !self.eat("]") {
+            return self.error("expected `]`");
+        }
+        Ok(segment)
+    }
+
+    fn list_separator(&mut self) -> bool {
+        self.skip_spaces();
+        let found = self.eat(",");
+        self.skip_spaces();
+        found
+    }
+
+    fn integer(&mut self) -> Result<Option<i64>, JsonPathError> {
+        let rest = &self.path[self.pos..];
+        let sign = usize::from(rest.starts_with('-'));
+        let digits = rest[sign..].bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return if sign == 1 { self.error("expected digits after `-`") } else { Ok(None) };
+        }
+        let Ok(value) = rest[..sign + digits].parse() else {
+            return self.error("index out of range");
+        };
+        self.pos += sign + digits;
+        Ok(Some(value))
+    }
+
+    fn quoted(&mut self) -> Result<String, JsonPathError> {
+        let Some(quote) = self.peek().filter(|c| matches!(c, '\'' | '"')) else {
+            return self.error("expected a quoted name");
+        };
+        self.pos += 1;
+        let mut name = String::new();
+        let mut chars = self.path[self.pos..].chars();
+        while let Some(c) = chars.next() {
+            self.pos += c.len_utf8();
+            match c {
+                '\\' => {
+                    let Some(escaped) = chars.next() else { break };
+                    self.pos += escaped.len_utf8();
+                    name.push(escaped);
+                }
+                c if c == quote => return Ok(name),
+                c => name.push(c),
+            }
+        }
+        self.error("unterminated quoted name")
+    }
+}
+
+/// What [`assert_json_path!`](crate::assert_json_path) checks about a selection
+#[doc(hidden)]
+pub enum JsonPathExpectation<'a> {
+    /// At least one value is selected
+    Exists,
+    /// Nothing is selected
+    Missing,
+    /// Exactly this many values are selected
+    Count(usize),
+    /// Exactly one value is selected and it equals this
+    Equals(Value),
+    /// At least one value is selected and all satisfy the predicate (named by the string)
+    Predicate(&'a dyn Fn(&Value) -> bool, &'static str),
+}
+
+impl JsonPathExpectation<'_> {
+    /// Equality expectation from any serializable value
+    pub fn equals<T: serde::Serialize + ?Sized>(expected: &T) -> Self {
+        Self::Equals(
+            serde_json::to_value(expected)
+                .unwrap_or_else(|e| Value::String(format!("<unserializable: {e}>"))),
+        )
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Exists => "at least one match".to_string(),
+            Self::Missing => "no match".to_string(),
+            Self::Count(count) => format!("{count} match(es)"),
+            Self::Equals(expected) => format!("exactly one match equal to {expected}"),
+            Self::Predicate(_, name) => format!("all matches to satisfy `{name}`"),
+        }
+    }
+
+    fn holds(&self, selected: &[&Value]) -> bool {
+        match self {
+            Self::Exists => !selected.is_empty(),
+            Self::Missing => selected.is_empty(),
+            Self::Count(count) => selected.len() == *count,
+            Self::Equals(expected) => matches!(selected, [only] if *only == expected),
+            Self::Predicate(predicate, _) => {
+                !selected.is_empty() && selected.iter().all(|value| predicate(value))
+            }
+        }
+    }
+}
+
+/// Check `expectation` against the values `path` selects from `value`
+///
+/// Used by [`assert_json_path!`](crate::assert_json_path).
+///
+/// # Panics
+///
+/// Raises a [`FailureKind::Json`] failure if the path is invalid or the check fails.
+#[doc(hidden)]
+#[track_caller]
+pub fn check_json_path(
+    value: &Value,
+    path: &str,
+    expectation: &JsonPathExpectation<'_>,
+    message: Option<&str>,
+) {
+    let prefix = message.map_or_else(|| "assertion failed".to_string(), str::to_string);
+    let parsed = match JsonPath::parse(path) {
+        Ok(parsed) => parsed,
+        Err(error) => TddFailure::new(FailureKind::Json, format!("{prefix}: {error}"))
+            .with_context("path", path)
+            .raise(),
+    };
+    let selected = parsed.select(value);
+    if expectation.holds(&selected) {
+        return;
+    }
+    let rendered =
+        serde_json::to_string_pretty(&selected).unwrap_or_else(|_| format!("{selected:?}"));
+    let mut failure = TddFailure::new(
+        FailureKind::Json,
+        format!(
+            "{prefix}: JSONPath `{path}` expected {}, selected {} value(s):\n{rendered}",
+            expectation.describe(),
+            selected.len()
+        ),
+    )
+    .with_context("path", path)
+    .with_context("selected", rendered);
+    if let JsonPathExpectation::Equals(expected) = expectation {
+        failure = failure.with_context("expected", expected.to_string());
+    }
+    failure.raise()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use serde_json::json;
+
+    fn select(path: &str, value: &Value) -> Vec<Value> {
+        JsonPath::parse(path).unwrap().select(value).into_iter().cloned().collect()
+    }
+
+    test!(test_select_members_indices_and_slices, {
+        // Arrange
+        let doc = json!({"a": {"b c": [10, 20, 30, 40]}, "x": 1});
+
+        // Act, Assert
+        assert_eq!(select("$", &doc), [doc.clone()]);
+        assert_eq!(select("$.x", &doc), [json!(1)]);
+        assert_eq!(select("$.a['b c'][1]", &doc), [json!(20)]);
+        assert_eq!(select("$.a[\"b c\"][-1]", &doc), [json!(40)]);
+        assert_eq!(select("$.a['b c'][0, 2]", &doc), [json!(10), json!(30)]);
+        assert_eq!(select("$.a['b c'][1:3]", &doc), [json!(20), json!(30)]);
+        assert_eq!(select("$.a['b c'][-2:]", &doc), [json!(30), json!(40)]);
+        assert_eq!(select("$.a['b c'][:1]", &doc), [json!(10)]);
+        assert!(select("$.a['b c'][9]", &doc).is_empty());
+        assert!(select("$.missing.deeper", &doc).is_empty());
+    });
+
+    test!(test_select_wildcards_and_descendants, {
+        // Arrange
+        let doc = json!({"items": [{"id": 1, "tags": [{"id": 9}]}, {"id": 2}]});
+
+        // Act, Assert
+        assert_eq!(select("$.items[*].id", &doc), [json!(1), json!(2)]);
+        assert_eq!(select("$.items.*.id", &doc), [json!(1), json!(2)]);
+        assert_eq!(select("$..id", &doc), [json!(1), json!(9), json!(2)]);
+        assert_eq!(select("$..tags[0].id", &doc), [json!(9)]);
+    });
+
+    test!(test_parse_errors_report_offset, {
+        // Arrange, Act
+        let missing_root = JsonPath::parse("items[0]").unwrap_err();
+        let unclosed = JsonPath::parse("$.items[0").unwrap_err();
+        let bad_index = JsonPath::parse("$.items[abc]").unwrap_err();
+
+        // Assert
+        assert_eq!(missing_root.offset, 0);
+        assert_eq!(unclosed.offset, 9);
+        assert_eq!(unclosed.reason, "expected `]`");
+        assert!(bad_index.to_string().contains("at byte 8"), "{bad_index}");
+    });
+}
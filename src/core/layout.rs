@@ -0,0 +1,334 @@
+//! Project Layout
+//!
+//! One place that answers "where is ..." for every path the framework touches: the crate
+//! under test, its workspace, the cargo target directory, test data, artifacts, and
+//! `chicago-tdd-tools.toml`. Resolving these ad hoc (relative to the current directory,
+//! or to `CARGO_MANIFEST_DIR` with no fallback) is a recurring source of "works locally,
+//! fails in CI" bugs: tests run from the crate root, binaries from the workspace root,
+//! and CI often sets `CARGO_TARGET_DIR`.
+//!
+//! Resolution order, highest priority first:
+//!
+//! 1. Environment: `CARGO_MANIFEST_DIR` (crate root), `CARGO_WORKSPACE_DIR` (workspace
+//!    root), `CARGO_TARGET_DIR` / `CARGO_BUILD_TARGET_DIR` (target dir), and
+//!    `CHICAGO_TDD_ARTIFACTS_DIR` (artifacts)
+//! 2. The `[paths]` section of `chicago-tdd-tools.toml` (relative paths resolve against
+//!    the config file's directory)
+//! 3. Detection: the nearest `Cargo.toml` above the current directory is the crate root,
+//!    the nearest `Cargo.toml` with a `[workspace]` table at or above it is the workspace
+//!    root, and everything else is derived from those two
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::layout::ProjectLayout;
+//!
+//! let layout = ProjectLayout::detect();
+//! assert!(layout.crate_root().join("Cargo.toml").exists());
+//! assert!(layout.target_dir().is_absolute());
+//! let fixture = layout.test_data("orders/valid.json");
+//! assert!(fixture.starts_with(layout.test_data_dir()));
+//! ```
+
+use serde::Deserialize;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+
+/// Name of the framework configuration file
+pub const CONFIG_FILE_NAME: &str = "chicago-tdd-tools.toml";
+
+/// Environment variable overriding the artifact root (heap profiles, run reports)
+pub const ARTIFACTS_DIR_ENV_VAR: &str = "CHICAGO_TDD_ARTIFACTS_DIR";
+
+/// Environment variable overriding the workspace root
+pub const WORKSPACE_DIR_ENV_VAR: &str = "CARGO_WORKSPACE_DIR";
+
+/// Directories searched for the config file, starting at the crate root
+const CONFIG_SEARCH_DEPTH: usize = 5;
+
+/// `[paths]` section of `chicago-tdd-tools.toml`
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PathsConfig {
+    workspace_root: Option<PathBuf>,
+    target_dir: Option<PathBuf>,
+    test_data_dir: Option<PathBuf>,
+    artifacts_dir: Option<PathBuf>,
+}
+
+/// > 📚 Reference
+///
+/// Resolved project paths. See the [module docs](self) for the resolution order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProjectLayout {
+    crate_root: PathBuf,
+    workspace_root: PathBuf,
+    target_dir: PathBuf,
+    test_data_dir: PathBuf,
+    artifacts_dir: PathBuf,
+    config_file: Option<PathBuf>,
+}
+
+impl ProjectLayout {
+    /// Resolve the layout from the environment and the current directory
+    ///
+    /// Not cached: environment and working-directory changes are picked up on every call.
+    #[must_use]
+    pub fn detect() -> Self {
+        let cwd = std::env::current_dir().unwrap_or_default();
+        Self::resolve(&cwd, |key| std::env::var_os(key))
+    }
+
+    /// Resolve the layout as if the current directory were `dir` and no cargo
+    /// environment variables were set
+    #[must_use]
+    pub fn from_dir(dir: impl AsRef<Path>) -> Self {
+        Self::resolve(dir.as_ref(), |_| None)
+    }
+
+    fn resolve(cwd: &Path, env: impl Fn(&str) -> Option<OsString>) -> Self {
+        let env_path = |key: &str| env(key).filter(|v| !v.is_empty()).map(PathBuf::from);
+
+        let crate_root = env_path("CARGO_MANIFEST_DIR")
+            .map(|dir| absolute(cwd, dir))
+            .or_else(|| {
+                cwd.ancestors()
+                    .find(|dir| dir.join("Cargo.toml").is_file())
+                    .map(Path::to_path_buf)
+            })
+            .unwrap_or_else(|| cwd.to_path_buf());
+
+        let config_file = config_search_paths(&crate_root).into_iter().find(|p| p.is_file());
+        let paths = config_file.as_deref().map(read_paths_config).unwrap_or_default();
+        let config_dir = config_file.as_deref().and_then(Path::parent).unwrap_or(&crate_root);
+        let configured = |path: Option<PathBuf>| path.map(|p| absolute(config_dir, p));
+
+        let workspace_root = env_path(WORKSPACE_DIR_ENV_VAR)
+            .map(|dir| absolute(cwd, dir))
+            .or_else(|| configured(paths.workspace_root))
+            .or_else(|| find_workspace_root(&crate_root))
+            .unwrap_or_else(|| crate_root.clone());
+
+        let target_dir = env_path("CARGO_TARGET_DIR")
+            .or_else(|| env_path("CARGO_BUILD_TARGET_DIR"))
+            .map(|dir| absolute(&workspace_root, dir))
+            .or_else(|| configured(paths.target_dir))
+            .unwrap_or_else(|| workspace_root.join("target"));
+
+        let test_data_dir = configured(paths.test_data_dir)
+            .unwrap_or_else(|| crate_root.join("tests").join("data"));
+
+        let artifacts_dir = env_path(ARTIFACTS_DIR_ENV_VAR)
+            .map(|dir| absolute(cwd, dir))
+            .or_else(|| configured(paths.artifacts_dir))
+            .unwrap_or_else(|| target_dir.join("chicago-tdd"));
+
+        Self { crate_root, workspace_root, target_dir, test_data_dir, artifacts_dir, config_file }
+    }
+
+    /// Root of the crate under test (the directory holding its `Cargo.toml`)
+    #[must_use]
+    pub fn crate_root(&self) -> &Path {
+        &self.crate_root
+    }
+
+    /// Root of the cargo workspace (the crate root for single-crate projects)
+    #[must_use]
+    pub fn workspace_root(&self) -> &Path {
+        &self.workspace_root
+    }
+
+    /// Cargo target directory
+    #[must_use]
+    pub fn target_dir(&self) -> &Path {
+        &self.target_dir
+    }
+
+    /// Test data directory (default `<crate root>/tests/data`)
+    #[must_use]
+    pub fn test_data_dir(&self) -> &Path {
+        &self.test_data_dir
+    }
+
+    /// Path to a file or directory under the test data directory
+    #[must_use]
+    pub fn test_data(&self, relative: impl AsRef<Path>) -> PathBuf {
+        self.test_data_dir.join(relative)
+    }
+
+    /// Snapshot directory (`<crate root>/tests/snapshots`)
+    #[must_use]
+    pub fn snapshot_dir(&self) -> PathBuf {
+        self.crate_root.join("tests").join("snapshots")
+    }
+
+    /// Root for framework artifacts such as heap profiles and run reports
+    /// (default `<target dir>/chicago-tdd`)
+    #[must_use]
+    pub fn artifacts_dir(&self) -> &Path {
+        &self.artifacts_dir
+    }
+
+    /// OpenTelemetry semantic conventions registry (`<workspace root>/registry`)
+    #[must_use]
+    pub fn registry_dir(&self) -> PathBuf {
+        self.workspace_root.join("registry")
+    }
+
+    /// The `chicago-tdd-tools.toml` in effect, if any
+    #[must_use]
+    pub fn config_file(&self) -> Option<&Path> {
+        self.config_file.as_deref()
+    }
+
+    /// Locations searched for `chicago-tdd-tools.toml`, nearest first
+    #[must_use]
+    pub fn config_search_paths(&self) -> Vec<PathBuf> {
+        config_search_paths(&self.crate_root)
+    }
+}
+
+impl Default for ProjectLayout {
+    fn default() -> Self {
+        Self::detect()
+    }
+}
+
+fn absolute(base: &Path, path: PathBuf) -> PathBuf {
+    if path.is_absolute() {
+        path
+    } else {
+        base.join(path)
+    }
+}
+
+fn config_search_paths(start: &Path) -> Vec<PathBuf> {
+    start
+        .ancestors()
+        .take(CONFIG_SEARCH_DEPTH)
+        .map(|dir| dir.join(CONFIG_FILE_NAME))
+        .collect()
+}
+
+fn find_workspace_root(crate_root: &Path) -> Option<PathBuf> {
+    crate_root
+        .ancestors()
+        .find(|dir| {
+            std::fs::read_to_string(dir.join("Cargo.toml"))
+                .ok()
+                .and_then(|text| text.parse::<toml::Table>().ok())
+                .is_some_and(|manifest| manifest.contains_key("workspace"))
+        })
+        .map(Path::to_path_buf)
+}
+
+fn read_paths_config(config_file: &Path) -> PathsConfig {
+    let parsed = std::fs::read_to_string(config_file)
+        .map_err(|e| e.to_string())
+        .and_then(|text| text.parse::<toml::Table>().map_err(|e| e.to_string()))
+        .and_then(|document| {
+            document.get("paths").map_or_else(
+                || Ok(PathsConfig::default()),
+                |section| section.clone().try_into().map_err(|e: toml::de::Error| e.to_string()),
+            )
+        });
+    parsed.unwrap_or_else(|error| {
+        #[cfg(feature = "logging")]
+        log::warn!(
+            "⚠️  Warning: Config file {}: invalid [paths] section: {error}\n   💡 Using detected paths",
+            config_file.display()
+        );
+        #[cfg(not(feature = "logging"))]
+        eprintln!(
+            "⚠️  Warning: Config file {}: invalid [paths] section: {error}\n   💡 Using detected paths",
+            config_file.display()
+        );
+        PathsConfig::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::fs;
+
+    fn write(path: &Path, contents: &str) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    test!(test_detects_crate_and_workspace_roots_from_nested_dir, {
+        // Arrange
+        let root = tempfile::tempdir().unwrap();
+        let workspace = root.path();
+        let member = workspace.join("crates").join("orders");
+        write(&workspace.join("Cargo.toml"), "[workspace]\nmembers = [\"crates/*\"]\n");
+        write(&member.join("Cargo.toml"), "[package]\nname = \"orders\"\n");
+        fs::create_dir_all(member.join("src")).unwrap();
+
+        // Act
+        let layout = ProjectLayout::from_dir(member.join("src"));
+
+        // Assert
+        assert_eq!(layout.crate_root(), member);
+        assert_eq!(layout.workspace_root(), workspace);
+        assert_eq!(layout.target_dir(), workspace.join("target"));
+        assert_eq!(layout.test_data("a.json"), member.join("tests").join("data").join("a.json"));
+        assert_eq!(layout.artifacts_dir(), workspace.join("target").join("chicago-tdd"));
+        assert_eq!(layout.config_file(), None);
+    });
+
+    test!(test_config_paths_section_overrides_detection, {
+        // Arrange
+        let root = tempfile::tempdir().unwrap();
+        write(&root.path().join("Cargo.toml"), "[package]\nname = \"orders\"\n");
+        write(
+            &root.path().join(CONFIG_FILE_NAME),
+            "[paths]\ntarget_dir = \"build\"\ntest_data_dir = \"fixtures\"\n",
+        );
+
+        // Act
+        let layout = ProjectLayout::from_dir(root.path());
+
+        // Assert
+        assert_eq!(layout.config_file(), Some(root.path().join(CONFIG_FILE_NAME).as_path()));
+        assert_eq!(layout.target_dir(), root.path().join("build"));
+        assert_eq!(layout.test_data_dir(), root.path().join("fixtures"));
+        assert_eq!(layout.artifacts_dir(), root.path().join("build").join("chicago-tdd"));
+    });
+
+    test!(test_environment_overrides_config_and_resolves_relative_paths, {
+        // Arrange
+        let root = tempfile::tempdir().unwrap();
+        let member = root.path().join("orders");
+        write(&member.join("Cargo.toml"), "[package]\nname = \"orders\"\n");
+        write(&member.join(CONFIG_FILE_NAME), "[paths]\ntarget_dir = \"build\"\n");
+        let env = |key: &str| match key {
+            "CARGO_MANIFEST_DIR" => Some(member.clone().into_os_string()),
+            "CARGO_TARGET_DIR" => Some(OsString::from("ci-target")),
+            _ => None,
+        };
+
+        // Act
+        let layout = ProjectLayout::resolve(root.path(), env);
+
+        // Assert
+        assert_eq!(layout.crate_root(), member);
+        assert_eq!(layout.workspace_root(), member);
+        assert_eq!(layout.target_dir(), member.join("ci-target"));
+    });
+
+    test!(test_invalid_paths_section_falls_back_to_detection, {
+        // Arrange
+        let root = tempfile::tempdir().unwrap();
+        write(&root.path().join("Cargo.toml"), "[package]\nname = \"orders\"\n");
+        write(&root.path().join(CONFIG_FILE_NAME), "[paths]\ntarget = \"typo\"\n");
+
+        // Act
+        let layout = ProjectLayout::from_dir(root.path());
+
+        // Assert
+        assert_eq!(layout.target_dir(), root.path().join("target"));
+    });
+}
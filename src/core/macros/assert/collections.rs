@@ -24,25 +24,39 @@
 #[macro_export]
 macro_rules! assert_contains {
     ($collection:expr, $item:expr) => {{
+        $crate::core::receipt::record_assertion();
         let collection_ref = &$collection;
         let item_ref = &$item;
         let found = collection_ref.into_iter().any(|x| x == item_ref);
         if !found {
-            panic!(
-                "Collection does not contain item.\n  collection: {:?}\n  missing item: {:?}",
-                collection_ref, item_ref
-            );
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Collection,
+                format!(
+                    "Collection does not contain item.\n  collection: {:?}\n  missing item: {:?}",
+                    collection_ref, item_ref
+                ),
+            )
+            .with_context("collection", format!("{:?}", collection_ref))
+            .with_context("item", format!("{:?}", item_ref))
+            .raise();
         }
     }};
     ($collection:expr, $item:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         let collection_ref = &$collection;
         let item_ref = &$item;
         let found = collection_ref.into_iter().any(|x| x == item_ref);
         if !found {
-            panic!(
-                "{}: Collection does not contain item.\n  collection: {:?}\n  missing item: {:?}",
-                $msg, collection_ref, item_ref
-            );
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Collection,
+                format!(
+                    "{}: Collection does not contain item.\n  collection: {:?}\n  missing item: {:?}",
+                    $msg, collection_ref, item_ref
+                ),
+            )
+            .with_context("collection", format!("{:?}", collection_ref))
+            .with_context("item", format!("{:?}", item_ref))
+            .raise();
         }
     }};
 }
@@ -69,25 +83,39 @@ macro_rules! assert_contains {
 #[macro_export]
 macro_rules! assert_not_contains {
     ($collection:expr, $item:expr) => {{
+        $crate::core::receipt::record_assertion();
         let collection_ref = &$collection;
         let item_ref = &$item;
         let found = collection_ref.into_iter().any(|x| x == item_ref);
         if found {
-            panic!(
-                "Collection contains item that should not be present.\n  collection: {:?}\n  unexpected item: {:?}",
-                collection_ref, item_ref
-            );
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Collection,
+                format!(
+                    "Collection contains item that should not be present.\n  collection: {:?}\n  unexpected item: {:?}",
+                    collection_ref, item_ref
+                ),
+            )
+            .with_context("collection", format!("{:?}", collection_ref))
+            .with_context("item", format!("{:?}", item_ref))
+            .raise();
         }
     }};
     ($collection:expr, $item:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         let collection_ref = &$collection;
         let item_ref = &$item;
         let found = collection_ref.into_iter().any(|x| x == item_ref);
         if found {
-            panic!(
-                "{}: Collection contains item that should not be present.\n  collection: {:?}\n  unexpected item: {:?}",
-                $msg, collection_ref, item_ref
-            );
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Collection,
+                format!(
+                    "{}: Collection contains item that should not be present.\n  collection: {:?}\n  unexpected item: {:?}",
+                    $msg, collection_ref, item_ref
+                ),
+            )
+            .with_context("collection", format!("{:?}", collection_ref))
+            .with_context("item", format!("{:?}", item_ref))
+            .raise();
         }
     }};
 }
@@ -116,6 +144,7 @@ macro_rules! assert_not_contains {
 #[macro_export]
 macro_rules! assert_subset {
     ($subset:expr, $superset:expr) => {{
+        $crate::core::receipt::record_assertion();
         let subset_ref = &$subset;
         let superset_ref = &$superset;
         let subset_vec: Vec<_> = subset_ref.into_iter().collect();
@@ -127,13 +156,21 @@ macro_rules! assert_subset {
             .collect();
 
         if !missing.is_empty() {
-            panic!(
-                "Subset contains items not in superset.\n  subset: {:?}\n  superset: {:?}\n  missing from superset: {:?}",
-                subset_vec, superset_vec, missing
-            );
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Collection,
+                format!(
+                    "Subset contains items not in superset.\n  subset: {:?}\n  superset: {:?}\n  missing from superset: {:?}",
+                    subset_vec, superset_vec, missing
+                ),
+            )
+            .with_context("subset", format!("{:?}", subset_vec))
+            .with_context("superset", format!("{:?}", superset_vec))
+            .with_context("missing", format!("{:?}", missing))
+            .raise();
         }
     }};
     ($subset:expr, $superset:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         let subset_ref = &$subset;
         let superset_ref = &$superset;
         let subset_vec: Vec<_> = subset_ref.into_iter().collect();
@@ -145,10 +182,17 @@ macro_rules! assert_subset {
             .collect();
 
         if !missing.is_empty() {
-            panic!(
-                "{}: Subset contains items not in superset.\n  subset: {:?}\n  superset: {:?}\n  missing from superset: {:?}",
-                $msg, subset_vec, superset_vec, missing
-            );
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Collection,
+                format!(
+                    "{}: Subset contains items not in superset.\n  subset: {:?}\n  superset: {:?}\n  missing from superset: {:?}",
+                    $msg, subset_vec, superset_vec, missing
+                ),
+            )
+            .with_context("subset", format!("{:?}", subset_vec))
+            .with_context("superset", format!("{:?}", superset_vec))
+            .with_context("missing", format!("{:?}", missing))
+            .raise();
         }
     }};
 }
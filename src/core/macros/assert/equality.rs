@@ -5,6 +5,8 @@
 /// Assert equality with detailed error message and diff output
 ///
 /// Provides better error messages for equality assertions with automatic diff generation.
+/// Scalars report both values; structs and collections report a
+/// [structural diff](crate::core::structural_diff) of only the fields that changed.
 ///
 /// # Example
 ///
@@ -19,40 +21,97 @@
 #[macro_export]
 macro_rules! assert_eq_msg {
     ($actual:expr, $expected:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         let actual_val = &$actual;
         let expected_val = &$expected;
         if actual_val != expected_val {
-            panic!("{}: expected {:?}, got {:?}", $msg, expected_val, actual_val);
+            let message = $crate::core::render::render_structural_mismatch(
+                &format!("{}: values differ", $msg),
+                &format!("{:#?}", actual_val),
+                &format!("{:#?}", expected_val),
+            )
+            .unwrap_or_else(|| {
+                format!("{}: expected {:?}, got {:?}", $msg, expected_val, actual_val)
+            });
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Equality,
+                message,
+            )
+            .with_context("expected", format!("{:?}", expected_val))
+            .with_context("actual", format!("{:?}", actual_val))
+            .raise();
         }
     }};
 }
 
 /// Assert equality with automatic type inference and diff output
 ///
-/// Enhanced version that provides better error messages with context.
+/// Enhanced version that provides better error messages with context. Values are
+/// pretty-printed and rendered by the installed
+/// [`FailureRenderer`](crate::core::render::FailureRenderer): structs and collections get
+/// a [structural diff](crate::core::structural_diff) showing only changed fields with
+/// context, other multi-line values get a line diff, and huge values are truncated
+/// around the first difference.
+///
+/// # Example
+///
+/// ```rust,should_panic
+/// use chicago_tdd_tools::assert_eq_enhanced;
+///
+/// let actual = vec![1, 2, 3];
+/// let expected = vec![1, 2, 4];
+/// assert_eq_enhanced!(actual, expected);
+/// // Panics with a diff:
+/// //   - 3,
+/// //   + 4,
+/// ```
 #[macro_export]
 macro_rules! assert_eq_enhanced {
     ($actual:expr, $expected:expr $(,)?) => {
         {
+            $crate::core::receipt::record_assertion();
             let actual_val = &$actual;
             let expected_val = &$expected;
             if actual_val != expected_val {
-                panic!(
-                    "assertion failed: `(left == right)`\n  left: `{:?}`\n right: `{:?}`",
-                    actual_val, expected_val
-                );
+                let actual = format!("{:#?}", actual_val);
+                let expected = format!("{:#?}", expected_val);
+                $crate::core::failure::TddFailure::new(
+                    $crate::core::failure::FailureKind::Equality,
+                    $crate::core::render::render_mismatch(
+                        "assertion failed: `(left == right)`",
+                        &actual,
+                        &expected,
+                    ),
+                )
+                .with_context("expected", expected)
+                .with_context("actual", actual)
+                .raise();
             }
         }
     };
     ($actual:expr, $expected:expr, $($arg:tt)+) => {
         {
+            $crate::core::receipt::record_assertion();
             let actual_val = &$actual;
             let expected_val = &$expected;
             if actual_val != expected_val {
-                panic!(
-                    "assertion failed: `(left == right)`\n  left: `{:?}`\n right: `{:?}`\n{}",
-                    actual_val, expected_val, format!($($arg)+)
-                );
+                let actual = format!("{:#?}", actual_val);
+                let expected = format!("{:#?}", expected_val);
+                $crate::core::failure::TddFailure::new(
+                    $crate::core::failure::FailureKind::Equality,
+                    format!(
+                        "{}\n{}",
+                        $crate::core::render::render_mismatch(
+                            "assertion failed: `(left == right)`",
+                            &actual,
+                            &expected,
+                        ),
+                        format!($($arg)+)
+                    ),
+                )
+                .with_context("expected", expected)
+                .with_context("actual", actual)
+                .raise();
             }
         }
     };
@@ -83,6 +142,7 @@ macro_rules! assert_eq_enhanced {
 #[macro_export]
 macro_rules! assert_approx_eq {
     ($actual:expr, $expected:expr, $epsilon:expr) => {{
+        $crate::core::receipt::record_assertion();
         #[allow(clippy::float_cmp)] // Intentional approximate comparison
         {
             let actual_val = $actual as f64;
@@ -90,14 +150,22 @@ macro_rules! assert_approx_eq {
             let epsilon_val = $epsilon as f64;
             let diff = (actual_val - expected_val).abs();
             if diff > epsilon_val {
-                panic!(
-                    "Values not approximately equal.\n  actual: {}\n  expected: {}\n  epsilon: {}\n  difference: {}",
-                    actual_val, expected_val, epsilon_val, diff
-                );
+                $crate::core::failure::TddFailure::new(
+                    $crate::core::failure::FailureKind::Equality,
+                    format!(
+                        "Values not approximately equal.\n  actual: {}\n  expected: {}\n  epsilon: {}\n  difference: {}",
+                        actual_val, expected_val, epsilon_val, diff
+                    ),
+                )
+                .with_context("expected", expected_val.to_string())
+                .with_context("actual", actual_val.to_string())
+                .with_context("epsilon", epsilon_val.to_string())
+                .raise();
             }
         }
     }};
     ($actual:expr, $expected:expr, $epsilon:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         #[allow(clippy::float_cmp)] // Intentional approximate comparison
         {
             let actual_val = $actual as f64;
@@ -105,10 +173,17 @@ macro_rules! assert_approx_eq {
             let epsilon_val = $epsilon as f64;
             let diff = (actual_val - expected_val).abs();
             if diff > epsilon_val {
-                panic!(
-                    "{}: Values not approximately equal.\n  actual: {}\n  expected: {}\n  epsilon: {}\n  difference: {}",
-                    $msg, actual_val, expected_val, epsilon_val, diff
-                );
+                $crate::core::failure::TddFailure::new(
+                    $crate::core::failure::FailureKind::Equality,
+                    format!(
+                        "{}: Values not approximately equal.\n  actual: {}\n  expected: {}\n  epsilon: {}\n  difference: {}",
+                        $msg, actual_val, expected_val, epsilon_val, diff
+                    ),
+                )
+                .with_context("expected", expected_val.to_string())
+                .with_context("actual", actual_val.to_string())
+                .with_context("epsilon", epsilon_val.to_string())
+                .raise();
             }
         }
     }};
@@ -139,6 +214,30 @@ mod tests {
         assert_eq_msg!(actual, expected, "Values should match");
     }
 
+    test!(test_assert_eq_msg_reports_structural_diff, {
+        // Arrange: Structs differing in one nested field
+        #[derive(Debug, PartialEq)]
+        struct Line {
+            sku: &'static str,
+            qty: u32,
+        }
+        let actual = vec![Line { sku: "A", qty: 1 }, Line { sku: "B", qty: 3 }];
+        let expected = vec![Line { sku: "A", qty: 1 }, Line { sku: "B", qty: 4 }];
+
+        // Act
+        let failure = crate::core::failure::TddFailure::catch(|| {
+            assert_eq_msg!(actual, expected, "order lines");
+        })
+        .unwrap_err();
+
+        // Assert: Only the changed field is marked
+        let message = failure.message();
+        assert!(message.starts_with("order lines: values differ"), "{message}");
+        assert_eq!(message.matches("qty: 3,").count(), 1, "{message}");
+        assert_eq!(message.matches("qty: 4,").count(), 1, "{message}");
+        assert!(!message.contains("expected ["), "{message}");
+    });
+
     test!(test_assert_approx_eq_macro, {
         // Arrange: Floating-point values
         let pi = 3.14159265;
@@ -162,4 +261,26 @@ mod tests {
         // Act & Assert: Should panic
         assert_approx_eq!(actual, expected, 0.01);
     }
+
+    test!(test_assert_eq_enhanced_macro, {
+        // Arrange: Equal values
+        let actual = vec![1, 2, 3];
+        let expected = vec![1, 2, 3];
+
+        // Act & Assert: Verify equality
+        assert_eq_enhanced!(actual, expected);
+        assert_eq_enhanced!(actual, expected, "vectors should match");
+    });
+
+    #[test]
+    #[should_panic(expected = "assertion failed: `(left == right)`")]
+    fn test_assert_eq_enhanced_macro_fails() {
+        // Arrange: Different multi-line values
+        let actual = vec!["a"; 40];
+        let mut expected = actual.clone();
+        expected[20] = "b";
+
+        // Act & Assert: Should panic with a diff
+        assert_eq_enhanced!(actual, expected, "case {}", 7);
+    }
 }
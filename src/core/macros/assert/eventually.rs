@@ -0,0 +1,182 @@
+//! Eventual Assertion Macros
+//!
+//! Polling assertions for state that converges asynchronously (containers starting,
+//! background workers, replicated stores), replacing hand-rolled sleep loops.
+
+/// Assert that a condition eventually holds, polling with backoff until a deadline
+///
+/// Two forms:
+/// - `assert_eventually!(condition)` re-evaluates a `bool` expression.
+/// - `assert_eventually!(probe => |value| predicate)` re-evaluates `probe` and checks
+///   its value; on failure the last observed value is reported (`probe` must be `Debug`).
+///
+/// Optional settings follow, in this order: `timeout = Duration`, `interval = Duration`
+/// (initial delay), `backoff = f64` (delay multiplier). Defaults are in
+/// [`EventuallyConfig`](crate::core::eventually::EventuallyConfig).
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::assert_eventually;
+/// use std::sync::atomic::{AtomicU32, Ordering};
+/// use std::time::Duration;
+///
+/// let ready = AtomicU32::new(0);
+/// let poll = || ready.fetch_add(1, Ordering::SeqCst) + 1;
+///
+/// assert_eventually!(poll() >= 3, interval = Duration::from_millis(1));
+/// assert_eventually!(
+///     poll() => |count| *count >= 5,
+///     timeout = Duration::from_secs(1),
+///     interval = Duration::from_millis(1)
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_eventually {
+    ($probe:expr => $predicate:expr
+        $(, timeout = $timeout:expr)? $(, interval = $interval:expr)? $(, backoff = $backoff:expr)? $(,)?) => {{
+        $crate::core::receipt::record_assertion();
+        let mut poller = $crate::core::eventually::EventuallyPoller::new(
+            $crate::core::eventually::EventuallyConfig::new()
+                $(.with_timeout($timeout))? $(.with_interval($interval))? $(.with_backoff($backoff))?,
+        );
+        loop {
+            let value = $probe;
+            if $crate::core::eventually::eventually_holds(&value, $predicate) {
+                break;
+            }
+            if !poller.wait() {
+                poller.fail(
+                    concat!("`", stringify!($probe), "` => `", stringify!($predicate), "`"),
+                    Some(&value),
+                );
+            }
+        }
+    }};
+    ($condition:expr
+        $(, timeout = $timeout:expr)? $(, interval = $interval:expr)? $(, backoff = $backoff:expr)? $(,)?) => {{
+        $crate::core::receipt::record_assertion();
+        let mut poller = $crate::core::eventually::EventuallyPoller::new(
+            $crate::core::eventually::EventuallyConfig::new()
+                $(.with_timeout($timeout))? $(.with_interval($interval))? $(.with_backoff($backoff))?,
+        );
+        while !($condition) {
+            if !poller.wait() {
+                poller.fail(concat!("Condition `", stringify!($condition), "`"), None);
+            }
+        }
+    }};
+}
+
+/// Async [`assert_eventually!`]: the probe or condition may `.await`
+///
+/// Same forms and settings as [`assert_eventually!`]; sleeps between attempts with
+/// `tokio::time::sleep`, so it must run inside a Tokio runtime.
+///
+/// **Required feature**: `async`
+///
+/// # Example
+///
+/// ```rust
+/// # #[cfg(feature = "async")]
+/// # tokio::runtime::Builder::new_current_thread().enable_time().build().unwrap().block_on(async {
+/// use chicago_tdd_tools::assert_eventually_async;
+/// use std::time::Duration;
+///
+/// let (tx, mut rx) = tokio::sync::watch::channel(0_u32);
+/// tokio::spawn(async move { for i in 1..=3 { let _ = tx.send(i); } });
+///
+/// assert_eventually_async!(*rx.borrow_and_update() == 3, interval = Duration::from_millis(1));
+/// # });
+/// ```
+#[cfg(feature = "async")]
+#[macro_export]
+macro_rules! assert_eventually_async {
+    ($probe:expr => $predicate:expr
+        $(, timeout = $timeout:expr)? $(, interval = $interval:expr)? $(, backoff = $backoff:expr)? $(,)?) => {{
+        $crate::core::receipt::record_assertion();
+        let mut poller = $crate::core::eventually::EventuallyPoller::new(
+            $crate::core::eventually::EventuallyConfig::new()
+                $(.with_timeout($timeout))? $(.with_interval($interval))? $(.with_backoff($backoff))?,
+        );
+        loop {
+            let value = $probe;
+            if $crate::core::eventually::eventually_holds(&value, $predicate) {
+                break;
+            }
+            if !poller.wait_async().await {
+                poller.fail(
+                    concat!("`", stringify!($probe), "` => `", stringify!($predicate), "`"),
+                    Some(&value),
+                );
+            }
+        }
+    }};
+    ($condition:expr
+        $(, timeout = $timeout:expr)? $(, interval = $interval:expr)? $(, backoff = $backoff:expr)? $(,)?) => {{
+        $crate::core::receipt::record_assertion();
+        let mut poller = $crate::core::eventually::EventuallyPoller::new(
+            $crate::core::eventually::EventuallyConfig::new()
+                $(.with_timeout($timeout))? $(.with_interval($interval))? $(.with_backoff($backoff))?,
+        );
+        while !($condition) {
+            if !poller.wait_async().await {
+                poller.fail(concat!("Condition `", stringify!($condition), "`"), None);
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::failure::{FailureKind, TddFailure};
+    use crate::test;
+    use std::cell::Cell;
+    use std::time::Duration;
+
+    test!(test_assert_eventually_passes_once_condition_holds, {
+        // Arrange
+        let calls = Cell::new(0_u32);
+        let poll = || {
+            calls.set(calls.get() + 1);
+            calls.get()
+        };
+
+        // Act
+        assert_eventually!(poll() >= 3, interval = Duration::from_millis(1));
+        assert_eventually!(poll() => |n| *n >= 5, interval = Duration::from_millis(1), backoff = 1.0);
+
+        // Assert
+        assert_eq!(calls.get(), 5);
+    });
+
+    test!(test_assert_eventually_reports_last_observed_value, {
+        // Arrange
+        let calls = Cell::new(0_u32);
+
+        // Act
+        let failure = TddFailure::catch(|| {
+            assert_eventually!(
+                { calls.set(calls.get() + 1); calls.get() } => |n| *n > 1000,
+                timeout = Duration::from_millis(20),
+                interval = Duration::from_millis(2)
+            );
+        })
+        .unwrap_err();
+
+        // Assert
+        assert_eq!(failure.kind(), FailureKind::Timeout);
+        assert_eq!(failure.context()["last"], calls.get().to_string());
+        assert!(failure.message().contains("did not hold within 20ms"), "{failure}");
+        assert!(
+            failure.message().contains(&format!("last observed: {}", calls.get())),
+            "{failure}"
+        );
+    });
+
+    #[test]
+    #[should_panic(expected = "Condition `false` did not hold within 10ms")]
+    fn test_assert_eventually_times_out() {
+        assert_eventually!(false, timeout = Duration::from_millis(10));
+    }
+}
@@ -0,0 +1,117 @@
+//! Filesystem Diff Assertion Macros
+//!
+//! Assert exactly which files code under test created, modified, and deleted, using
+//! [`FsSnapshot`](crate::core::fs_snapshot::FsSnapshot) captures taken before and after.
+
+/// Assert the files changed between two snapshots are exactly the expected ones
+///
+/// Entries are globs matched against paths relative to the captured root: every
+/// changed path must match an entry of its kind and every entry must match a changed
+/// path. Modification is decided by content hash, so rewriting a file with the same
+/// bytes is not a change. An optional `ignore [...]` list drops matching paths first.
+/// Evaluates to the [`FsDiff`](crate::core::fs_snapshot::FsDiff).
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::assert_fs_diff;
+/// use chicago_tdd_tools::core::fs_snapshot::FsSnapshot;
+///
+/// let dir = std::env::temp_dir().join(format!("fs-diff-doc-{}", std::process::id()));
+/// std::fs::create_dir_all(&dir).unwrap();
+/// let before = FsSnapshot::capture(&dir).unwrap();
+///
+/// std::fs::create_dir_all(dir.join("out")).unwrap();
+/// std::fs::write(dir.join("out/report.json"), "{}").unwrap();
+/// std::fs::write(dir.join("debug.log"), "...").unwrap();
+/// let after = before.recapture().unwrap();
+///
+/// assert_fs_diff!(before, after, expect: created ["out/report.json"], modified [], deleted [],
+///     ignore ["*.log"]);
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+#[macro_export]
+macro_rules! assert_fs_diff {
+    ($before:expr, $after:expr, expect: created [$($created:expr),* $(,)?],
+        modified [$($modified:expr),* $(,)?], deleted [$($deleted:expr),* $(,)?] $(,)?) => {
+        $crate::assert_fs_diff!($before, $after, expect: created [$($created),*],
+            modified [$($modified),*], deleted [$($deleted),*], ignore [])
+    };
+    ($before:expr, $after:expr, expect: created [$($created:expr),* $(,)?],
+        modified [$($modified:expr),* $(,)?], deleted [$($deleted:expr),* $(,)?],
+        ignore [$($ignore:expr),* $(,)?] $(,)?) => {{
+        $crate::core::receipt::record_assertion();
+        let created: &[&str] = &[$($created),*];
+        let modified: &[&str] = &[$($modified),*];
+        let deleted: &[&str] = &[$($deleted),*];
+        let ignore: &[&str] = &[$($ignore),*];
+        let outcome = $before
+            .diff(&$after)
+            .ignoring(ignore)
+            .and_then(|diff| diff.check(created, modified, deleted).map(|()| diff));
+        match outcome {
+            Ok(diff) => diff,
+            Err(problems) => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Collection,
+                format!("filesystem changes did not match:\n{problems}"),
+            )
+            .with_context("actual", $before.diff(&$after).to_string())
+            .with_context("root", $before.root().display().to_string())
+            .raise(),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::failure::{FailureKind, TddFailure};
+    use crate::core::fs_snapshot::FsSnapshot;
+    use crate::test;
+
+    test!(test_assert_fs_diff_passes_on_exact_changes, {
+        // Arrange
+        #[allow(clippy::unwrap_used)] // Test code - temp dir setup
+        let dir = tempfile::tempdir().unwrap();
+        #[allow(clippy::unwrap_used)] // Test code - temp dir setup
+        std::fs::write(dir.path().join("stale.txt"), "x").unwrap();
+        #[allow(clippy::unwrap_used)] // Test code - capture of temp dir
+        let before = FsSnapshot::capture(dir.path()).unwrap();
+
+        // Act
+        #[allow(clippy::unwrap_used)] // Test code - temp dir writes
+        {
+            std::fs::remove_file(dir.path().join("stale.txt")).unwrap();
+            std::fs::write(dir.path().join("report.json"), "{}").unwrap();
+            std::fs::write(dir.path().join("trace.log"), "t").unwrap();
+        }
+        #[allow(clippy::unwrap_used)] // Test code - capture of temp dir
+        let after = before.recapture().unwrap();
+
+        // Assert
+        let diff = assert_fs_diff!(before, after, expect: created ["report.json"], modified [],
+            deleted ["stale.txt"], ignore ["*.log"]);
+        assert_eq!(diff.created, ["report.json"]);
+    });
+
+    test!(test_assert_fs_diff_failure_lists_unexpected_paths, {
+        // Arrange
+        #[allow(clippy::unwrap_used)] // Test code - temp dir setup
+        let dir = tempfile::tempdir().unwrap();
+        #[allow(clippy::unwrap_used)] // Test code - capture of temp dir
+        let before = FsSnapshot::capture(dir.path()).unwrap();
+        #[allow(clippy::unwrap_used)] // Test code - temp dir writes
+        std::fs::write(dir.path().join("surprise.bin"), [0u8]).unwrap();
+        #[allow(clippy::unwrap_used)] // Test code - capture of temp dir
+        let after = before.recapture().unwrap();
+
+        // Act
+        let failure = TddFailure::catch(
+            || assert_fs_diff!(before, after, expect: created [], modified [], deleted []),
+        )
+        .unwrap_err();
+
+        // Assert
+        assert_eq!(failure.kind(), FailureKind::Collection);
+        assert!(failure.message().contains("unexpectedly created: surprise.bin"));
+    });
+}
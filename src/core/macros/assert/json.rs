@@ -1,6 +1,7 @@
 //! JSON Assertion Macros
 //!
 //! **New in v1.3.0**: Assertions for semantic JSON comparison.
+//! Partial assertions on `JSONPath` selections (`assert_json_path!`).
 
 /// Assert that two JSON values are semantically equal
 ///
@@ -35,6 +36,7 @@
 #[macro_export]
 macro_rules! assert_json_eq {
     ($actual:expr, $expected:expr) => {{
+        $crate::core::receipt::record_assertion();
         let actual_ref = &$actual;
         let expected_ref = &$expected;
         if actual_ref != expected_ref {
@@ -42,13 +44,20 @@ macro_rules! assert_json_eq {
                 .unwrap_or_else(|_| format!("{:?}", actual_ref));
             let expected_pretty = serde_json::to_string_pretty(expected_ref)
                 .unwrap_or_else(|_| format!("{:?}", expected_ref));
-            panic!(
-                "JSON values are not equal.\n  actual:\n{}\n  expected:\n{}",
-                actual_pretty, expected_pretty
-            );
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Json,
+                format!(
+                    "JSON values are not equal.\n  actual:\n{}\n  expected:\n{}",
+                    actual_pretty, expected_pretty
+                ),
+            )
+            .with_context("expected", expected_pretty)
+            .with_context("actual", actual_pretty)
+            .raise();
         }
     }};
     ($actual:expr, $expected:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         let actual_ref = &$actual;
         let expected_ref = &$expected;
         if actual_ref != expected_ref {
@@ -56,14 +65,116 @@ macro_rules! assert_json_eq {
                 .unwrap_or_else(|_| format!("{:?}", actual_ref));
             let expected_pretty = serde_json::to_string_pretty(expected_ref)
                 .unwrap_or_else(|_| format!("{:?}", expected_ref));
-            panic!(
-                "{}: JSON values are not equal.\n  actual:\n{}\n  expected:\n{}",
-                $msg, actual_pretty, expected_pretty
-            );
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Json,
+                format!(
+                    "{}: JSON values are not equal.\n  actual:\n{}\n  expected:\n{}",
+                    $msg, actual_pretty, expected_pretty
+                ),
+            )
+            .with_context("expected", expected_pretty)
+            .with_context("actual", actual_pretty)
+            .raise();
         }
     }};
 }
 
+/// Assert on the values a `JSONPath` expression selects from a JSON document
+///
+/// Checks only the part of the document under test, so API-response tests neither
+/// deserialize the whole payload nor assert the entire document. See
+/// [`core::json_path`](crate::core::json_path) for the supported syntax.
+///
+/// Forms (each accepts a trailing custom message):
+/// - `assert_json_path!(value, path, expected)` - exactly one match, equal to `expected`
+///   (any `Serialize` value)
+/// - `assert_json_path!(value, path, exists)` - at least one match
+/// - `assert_json_path!(value, path, missing)` - no match
+/// - `assert_json_path!(value, path, count = n)` - exactly `n` matches
+/// - `assert_json_path!(value, path, |v| predicate)` - at least one match, and every
+///   match satisfies the predicate (`v: &serde_json::Value`)
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::assert_json_path;
+/// use serde_json::json;
+///
+/// let response = json!({
+///     "items": [
+///         {"id": 1, "status": "active", "price": 9.5},
+///         {"id": 2, "status": "closed", "price": 12.0}
+///     ],
+///     "next": null
+/// });
+///
+/// assert_json_path!(response, "$.items[0].status", "active");
+/// assert_json_path!(response, "$.items[*]", count = 2);
+/// assert_json_path!(response, "$.items[*].price", |price| price.as_f64() > Some(0.0));
+/// assert_json_path!(response, "$.next", exists);
+/// assert_json_path!(response, "$.error", missing, "successful responses carry no error");
+/// ```
+#[macro_export]
+macro_rules! assert_json_path {
+    ($value:expr, $path:expr, exists $(, $($msg:tt)+)?) => {
+        $crate::__assert_json_path!(
+            $value, $path, $crate::core::json_path::JsonPathExpectation::Exists $(, $($msg)+)?
+        )
+    };
+    ($value:expr, $path:expr, missing $(, $($msg:tt)+)?) => {
+        $crate::__assert_json_path!(
+            $value, $path, $crate::core::json_path::JsonPathExpectation::Missing $(, $($msg)+)?
+        )
+    };
+    ($value:expr, $path:expr, count = $count:expr $(, $($msg:tt)+)?) => {
+        $crate::__assert_json_path!(
+            $value,
+            $path,
+            $crate::core::json_path::JsonPathExpectation::Count($count)
+            $(, $($msg)+)?
+        )
+    };
+    ($value:expr, $path:expr, |$param:ident| $predicate:expr $(, $($msg:tt)+)?) => {
+        $crate::__assert_json_path!(
+            $value,
+            $path,
+            $crate::core::json_path::JsonPathExpectation::Predicate(
+                &|$param: &::serde_json::Value| -> bool { $predicate },
+                stringify!($predicate),
+            )
+            $(, $($msg)+)?
+        )
+    };
+    ($value:expr, $path:expr, $expected:expr $(, $($msg:tt)+)?) => {
+        $crate::__assert_json_path!(
+            $value,
+            $path,
+            $crate::core::json_path::JsonPathExpectation::equals(&$expected)
+            $(, $($msg)+)?
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_json_path {
+    ($value:expr, $path:expr, $expectation:expr) => {{
+        $crate::core::receipt::record_assertion();
+        let value: &::serde_json::Value = &$value;
+        $crate::core::json_path::check_json_path(value, $path, &$expectation, None);
+    }};
+    ($value:expr, $path:expr, $expectation:expr, $($msg:tt)+) => {{
+        $crate::core::receipt::record_assertion();
+        let value: &::serde_json::Value = &$value;
+        $crate::core::json_path::check_json_path(
+            value,
+            $path,
+            &$expectation,
+            Some(&format!($($msg)+)),
+        );
+    }};
+}
+
 #[cfg(test)]
 #[allow(clippy::panic)] // Test code - panic is appropriate for test failures
 mod tests {
@@ -90,6 +201,51 @@ mod tests {
         assert_json_eq!(response, expected_response, "API response should match");
     });
 
+    test!(test_assert_json_path_macro_forms, {
+        use serde_json::json;
+
+        // Arrange
+        let response =
+            json!({"items": [{"status": "active", "qty": 2}, {"status": "active", "qty": 5}]});
+        let expected_qty = 5;
+
+        // Act & Assert
+        assert_json_path!(response, "$.items[1].qty", expected_qty);
+        assert_json_path!(&response, "$.items[0].status", "active", "first item");
+        assert_json_path!(response, "$..qty", count = 2);
+        assert_json_path!(response, "$.items[*].status", |s| s == "active");
+        assert_json_path!(response, "$.items[0]", exists);
+        assert_json_path!(response, "$.items[2]", missing);
+    });
+
+    test!(test_assert_json_path_failure_reports_selection, {
+        use crate::core::failure::{FailureKind, TddFailure};
+        use serde_json::json;
+
+        // Arrange
+        let response = json!({"items": [{"status": "closed"}]});
+
+        // Act
+        let failure = TddFailure::catch(|| {
+            assert_json_path!(response, "$.items[0].status", "active", "order {}", 7);
+        })
+        .unwrap_err();
+
+        // Assert
+        assert_eq!(failure.kind(), FailureKind::Json);
+        assert_eq!(failure.context()["expected"], "\"active\"");
+        assert_eq!(failure.context()["selected"], "[\n  \"closed\"\n]");
+        assert!(failure.message().starts_with("order 7: JSONPath `$.items[0].status` expected"));
+    });
+
+    #[test]
+    #[should_panic(expected = "expected 3 match(es), selected 1 value(s)")]
+    fn test_assert_json_path_count_fails() {
+        use serde_json::json;
+
+        assert_json_path!(json!({"items": [1]}), "$.items[*]", count = 3);
+    }
+
     #[test]
     #[should_panic(expected = "JSON values are not equal")]
     fn test_assert_json_eq_macro_fails() {
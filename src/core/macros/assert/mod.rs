@@ -7,9 +7,12 @@
 //! - [`result`] - Result assertions (`assert_ok`, `assert_err`, `assert_fail`)
 //! - [`equality`] - Equality assertions (`assert_eq_msg`, `assert_eq_enhanced`, `assert_approx_eq`)
 //! - [`collections`] - Collection assertions (`assert_contains`, `assert_not_contains`, `assert_subset`, `assert_superset`) - v1.3.0
-//! - [`json`] - JSON assertions (`assert_json_eq`) - v1.3.0
+//! - [`json`] - JSON assertions (`assert_json_eq`, `assert_json_path`) - v1.3.0
 //! - [`patterns`] - Pattern matching assertions (`assert_matches`) - v1.3.0
-//! - [`performance`] - Performance and constraint assertions (`assert_within_tick_budget`, `assert_in_range`, `assert_guard_constraint`)
+//! - [`performance`] - Performance and constraint assertions (`assert_within_tick_budget`, `assert_in_range`, `assert_guard_constraint`, `assert_elapsed_at_least`, `assert_elapsed_at_most`)
+//! - [`eventually`] - Polling assertions (`assert_eventually`, `assert_eventually_async`)
+//! - [`text`] - Regex and glob text assertions (`assert_matches_regex`, `assert_matches_glob`)
+//! - [`fs`] - Filesystem diff assertions (`assert_fs_diff`)
 //!
 //! # Organization
 //!
@@ -20,6 +23,9 @@
 //! - JSON assertions (v1.3.0) provide semantic JSON comparison
 //! - Pattern assertions (v1.3.0) enable pattern matching in tests
 //! - Performance assertions validate timing and constraint compliance
+//! - Eventual assertions poll converging state with backoff and a deadline
+//! - Text assertions match output against regexes or globs and return the captures
+//! - Filesystem assertions check exactly which files changed between two snapshots
 //!
 //! All macros are re-exported at the crate root for backward compatibility.
 
@@ -41,6 +47,15 @@ pub mod patterns;
 // Performance and constraint assertions
 pub mod performance;
 
+// Polling assertions
+pub mod eventually;
+
+// Regex and glob text assertions
+pub mod text;
+
+// Filesystem diff assertions
+pub mod fs;
+
 // Re-export all assertion macros for convenient access
 // These are already exported by the individual modules via #[macro_export],
 // so they're available at the crate root. This module just organizes them.
@@ -27,50 +27,78 @@
 /// ```
 #[macro_export]
 macro_rules! assert_matches {
-    ($value:expr, $pattern:pat) => {
+    ($value:expr, $pattern:pat) => {{
+        $crate::core::receipt::record_assertion();
         match $value {
             $pattern => {}
-            ref v => panic!(
-                "assertion failed: value does not match pattern\n  value: {:?}\n  expected pattern: {}",
-                v,
-                stringify!($pattern)
-            ),
+            ref v => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Pattern,
+                format!(
+                    "assertion failed: value does not match pattern\n  value: {:?}\n  expected pattern: {}",
+                    v,
+                    stringify!($pattern)
+                ),
+            )
+            .with_context("value", format!("{:?}", v))
+            .with_context("pattern", stringify!($pattern))
+            .raise(),
         }
-    };
-    ($value:expr, $pattern:pat, $msg:expr) => {
+    }};
+    ($value:expr, $pattern:pat, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         match $value {
             $pattern => {}
-            ref v => panic!(
-                "{}: value does not match pattern\n  value: {:?}\n  expected pattern: {}",
-                $msg,
-                v,
-                stringify!($pattern)
-            ),
+            ref v => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Pattern,
+                format!(
+                    "{}: value does not match pattern\n  value: {:?}\n  expected pattern: {}",
+                    $msg,
+                    v,
+                    stringify!($pattern)
+                ),
+            )
+            .with_context("value", format!("{:?}", v))
+            .with_context("pattern", stringify!($pattern))
+            .raise(),
         }
-    };
-    ($value:expr, $pattern:pat if $guard:expr) => {
+    }};
+    ($value:expr, $pattern:pat if $guard:expr) => {{
+        $crate::core::receipt::record_assertion();
         match $value {
             $pattern if $guard => {}
-            ref v => panic!(
-                "assertion failed: value does not match pattern with guard\n  value: {:?}\n  expected pattern: {} if {}",
-                v,
-                stringify!($pattern),
-                stringify!($guard)
-            ),
+            ref v => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Pattern,
+                format!(
+                    "assertion failed: value does not match pattern with guard\n  value: {:?}\n  expected pattern: {} if {}",
+                    v,
+                    stringify!($pattern),
+                    stringify!($guard)
+                ),
+            )
+            .with_context("value", format!("{:?}", v))
+            .with_context("pattern", concat!(stringify!($pattern), " if ", stringify!($guard)))
+            .raise(),
         }
-    };
-    ($value:expr, $pattern:pat if $guard:expr, $msg:expr) => {
+    }};
+    ($value:expr, $pattern:pat if $guard:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         match $value {
             $pattern if $guard => {}
-            ref v => panic!(
-                "{}: value does not match pattern with guard\n  value: {:?}\n  expected pattern: {} if {}",
-                $msg,
-                v,
-                stringify!($pattern),
-                stringify!($guard)
-            ),
+            ref v => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Pattern,
+                format!(
+                    "{}: value does not match pattern with guard\n  value: {:?}\n  expected pattern: {} if {}",
+                    $msg,
+                    v,
+                    stringify!($pattern),
+                    stringify!($guard)
+                ),
+            )
+            .with_context("value", format!("{:?}", v))
+            .with_context("pattern", concat!(stringify!($pattern), " if ", stringify!($guard)))
+            .raise(),
         }
-    };
+    }};
 }
 
 #[cfg(test)]
@@ -20,25 +20,38 @@
 /// ```
 #[macro_export]
 macro_rules! assert_within_tick_budget {
-    ($ticks:expr) => {
+    ($ticks:expr) => {{
+        $crate::core::receipt::record_assertion();
         let max_ticks = if cfg!(debug_assertions) { 1_000_000 } else { 8 };
-        assert!(
-            $ticks <= max_ticks,
-            "Tick budget exceeded: {} > {} (Chatman Constant violation)",
-            $ticks,
-            max_ticks
-        );
-    };
-    ($ticks:expr, $msg:expr) => {
+        if $ticks > max_ticks {
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Constraint,
+                format!(
+                    "Tick budget exceeded: {} > {} (Chatman Constant violation)",
+                    $ticks, max_ticks
+                ),
+            )
+            .with_context("ticks", $ticks.to_string())
+            .with_context("max_ticks", max_ticks.to_string())
+            .raise();
+        }
+    }};
+    ($ticks:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         let max_ticks = if cfg!(debug_assertions) { 1_000_000 } else { 8 };
-        assert!(
-            $ticks <= max_ticks,
-            "{}: Tick budget exceeded: {} > {} (Chatman Constant violation)",
-            $msg,
-            $ticks,
-            max_ticks
-        );
-    };
+        if $ticks > max_ticks {
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Constraint,
+                format!(
+                    "{}: Tick budget exceeded: {} > {} (Chatman Constant violation)",
+                    $msg, $ticks, max_ticks
+                ),
+            )
+            .with_context("ticks", $ticks.to_string())
+            .with_context("max_ticks", max_ticks.to_string())
+            .raise();
+        }
+    }};
 }
 
 /// Assert that a value is within a range with detailed error message
@@ -59,25 +72,32 @@ macro_rules! assert_within_tick_budget {
 /// ```
 #[macro_export]
 macro_rules! assert_in_range {
-    ($value:expr, $min:expr, $max:expr) => {
-        assert!(
-            ($min..=$max).contains(&$value),
-            "Value {} not in range [{}, {}]",
-            $value,
-            $min,
-            $max
-        );
-    };
-    ($value:expr, $min:expr, $max:expr, $msg:expr) => {
-        assert!(
-            ($min..=$max).contains(&$value),
-            "{}: Value {} not in range [{}, {}]",
-            $msg,
-            $value,
-            $min,
-            $max
-        );
-    };
+    ($value:expr, $min:expr, $max:expr) => {{
+        $crate::core::receipt::record_assertion();
+        if !($min..=$max).contains(&$value) {
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Range,
+                format!("Value {} not in range [{}, {}]", $value, $min, $max),
+            )
+            .with_context("value", $value.to_string())
+            .with_context("min", $min.to_string())
+            .with_context("max", $max.to_string())
+            .raise();
+        }
+    }};
+    ($value:expr, $min:expr, $max:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
+        if !($min..=$max).contains(&$value) {
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Range,
+                format!("{}: Value {} not in range [{}, {}]", $msg, $value, $min, $max),
+            )
+            .with_context("value", $value.to_string())
+            .with_context("min", $min.to_string())
+            .with_context("max", $max.to_string())
+            .raise();
+        }
+    }};
 }
 
 /// Assert that a guard constraint is satisfied
@@ -94,9 +114,125 @@ macro_rules! assert_in_range {
 /// ```
 #[macro_export]
 macro_rules! assert_guard_constraint {
-    ($condition:expr, $constraint_name:expr) => {
-        assert!($condition, "Guard constraint violation: {}", $constraint_name);
-    };
+    ($condition:expr, $constraint_name:expr) => {{
+        $crate::core::receipt::record_assertion();
+        if !($condition) {
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Constraint,
+                format!("Guard constraint violation: {}", $constraint_name),
+            )
+            .with_context("constraint", $constraint_name.to_string())
+            .raise();
+        }
+    }};
+}
+
+/// Assert that at least `min` of clock time has elapsed since `since`
+///
+/// Works with any [`Clock`](crate::core::fixture::Clock), including a
+/// [`TestClock`](crate::core::fixture::TestClock) or
+/// [`ClockFixture`](crate::core::fixture::ClockFixture), so time-dependent logic is verified
+/// without real sleeps.
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::assert_elapsed_at_least;
+/// use chicago_tdd_tools::core::fixture::{Clock, TestClock};
+/// use std::time::Duration;
+///
+/// let clock = TestClock::new();
+/// let start = clock.now();
+/// clock.advance(Duration::from_secs(5));
+///
+/// assert_elapsed_at_least!(clock, start, Duration::from_secs(5));
+///
+/// // With custom message
+/// assert_elapsed_at_least!(clock, start, Duration::from_secs(1), "Backoff too short");
+/// ```
+#[macro_export]
+macro_rules! assert_elapsed_at_least {
+    ($clock:expr, $since:expr, $min:expr) => {{
+        use $crate::core::fixture::Clock as _;
+        $crate::core::receipt::record_assertion();
+        let elapsed: ::std::time::Duration = $clock.elapsed_since($since);
+        let min: ::std::time::Duration = $min;
+        if elapsed < min {
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Constraint,
+                format!("Elapsed time {:?} is less than minimum {:?}", elapsed, min),
+            )
+            .with_context("elapsed", format!("{:?}", elapsed))
+            .with_context("min", format!("{:?}", min))
+            .raise();
+        }
+    }};
+    ($clock:expr, $since:expr, $min:expr, $msg:expr) => {{
+        use $crate::core::fixture::Clock as _;
+        $crate::core::receipt::record_assertion();
+        let elapsed: ::std::time::Duration = $clock.elapsed_since($since);
+        let min: ::std::time::Duration = $min;
+        if elapsed < min {
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Constraint,
+                format!("{}: Elapsed time {:?} is less than minimum {:?}", $msg, elapsed, min),
+            )
+            .with_context("elapsed", format!("{:?}", elapsed))
+            .with_context("min", format!("{:?}", min))
+            .raise();
+        }
+    }};
+}
+
+/// Assert that at most `max` of clock time has elapsed since `since`
+///
+/// Counterpart of [`assert_elapsed_at_least!`](crate::assert_elapsed_at_least).
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::assert_elapsed_at_most;
+/// use chicago_tdd_tools::core::fixture::{Clock, TestClock};
+/// use std::time::Duration;
+///
+/// let clock = TestClock::new();
+/// let start = clock.now();
+/// clock.advance(Duration::from_millis(200));
+///
+/// assert_elapsed_at_most!(clock, start, Duration::from_secs(1));
+/// ```
+#[macro_export]
+macro_rules! assert_elapsed_at_most {
+    ($clock:expr, $since:expr, $max:expr) => {{
+        use $crate::core::fixture::Clock as _;
+        $crate::core::receipt::record_assertion();
+        let elapsed: ::std::time::Duration = $clock.elapsed_since($since);
+        let max: ::std::time::Duration = $max;
+        if elapsed > max {
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Constraint,
+                format!("Elapsed time {:?} exceeds maximum {:?}", elapsed, max),
+            )
+            .with_context("elapsed", format!("{:?}", elapsed))
+            .with_context("max", format!("{:?}", max))
+            .raise();
+        }
+    }};
+    ($clock:expr, $since:expr, $max:expr, $msg:expr) => {{
+        use $crate::core::fixture::Clock as _;
+        $crate::core::receipt::record_assertion();
+        let elapsed: ::std::time::Duration = $clock.elapsed_since($since);
+        let max: ::std::time::Duration = $max;
+        if elapsed > max {
+            $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Constraint,
+                format!("{}: Elapsed time {:?} exceeds maximum {:?}", $msg, elapsed, max),
+            )
+            .with_context("elapsed", format!("{:?}", elapsed))
+            .with_context("max", format!("{:?}", max))
+            .raise();
+        }
+    }};
 }
 
 #[cfg(test)]
@@ -178,4 +314,33 @@ mod tests {
         // Act & Assert: Should panic
         assert_guard_constraint!(max_run_len <= 8, "max_run_len");
     }
+
+    test!(test_assert_elapsed_macros_with_test_clock, {
+        // Arrange
+        use crate::core::fixture::{Clock, TestClock};
+        use std::time::Duration;
+        let clock = TestClock::new();
+        let start = clock.now();
+
+        // Act
+        clock.advance(Duration::from_secs(2));
+
+        // Assert
+        assert_elapsed_at_least!(clock, start, Duration::from_secs(2));
+        assert_elapsed_at_most!(clock, start, Duration::from_secs(2), "Frozen clock drifted");
+    });
+
+    #[test]
+    #[should_panic(expected = "is less than minimum")]
+    fn test_assert_elapsed_at_least_macro_fails() {
+        // Arrange
+        use crate::core::fixture::{Clock, TestClock};
+        use std::time::Duration;
+        let clock = TestClock::new();
+        let start = clock.now();
+        clock.advance(Duration::from_millis(999));
+
+        // Act & Assert: Should panic
+        assert_elapsed_at_least!(clock, start, Duration::from_secs(1));
+    }
 }
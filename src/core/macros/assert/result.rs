@@ -20,18 +20,30 @@
 /// ```
 #[macro_export]
 macro_rules! assert_ok {
-    ($result:expr) => {
+    ($result:expr) => {{
+        $crate::core::receipt::record_assertion();
         match $result {
             Ok(_) => {}
-            Err(e) => panic!("Expected Ok, but got Err: {:?}", e),
+            Err(e) => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Result,
+                format!("Expected Ok, but got Err: {:?}", e),
+            )
+            .with_context("error", format!("{:?}", e))
+            .raise(),
         }
-    };
-    ($result:expr, $msg:expr) => {
+    }};
+    ($result:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         match $result {
             Ok(_) => {}
-            Err(e) => panic!("{}: Expected Ok, but got Err: {:?}", $msg, e),
+            Err(e) => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Result,
+                format!("{}: Expected Ok, but got Err: {:?}", $msg, e),
+            )
+            .with_context("error", format!("{:?}", e))
+            .raise(),
         }
-    };
+    }};
 }
 
 /// Assert that a result is an error with detailed message
@@ -52,18 +64,30 @@ macro_rules! assert_ok {
 /// ```
 #[macro_export]
 macro_rules! assert_err {
-    ($result:expr) => {
+    ($result:expr) => {{
+        $crate::core::receipt::record_assertion();
         match $result {
-            Ok(v) => panic!("Expected Err, but got Ok: {:?}", v),
+            Ok(v) => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Result,
+                format!("Expected Err, but got Ok: {:?}", v),
+            )
+            .with_context("value", format!("{:?}", v))
+            .raise(),
             Err(_) => {}
         }
-    };
-    ($result:expr, $msg:expr) => {
+    }};
+    ($result:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         match $result {
-            Ok(v) => panic!("{}: Expected Err, but got Ok: {:?}", $msg, v),
+            Ok(v) => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Result,
+                format!("{}: Expected Err, but got Ok: {:?}", $msg, v),
+            )
+            .with_context("value", format!("{:?}", v))
+            .raise(),
             Err(_) => {}
         }
-    };
+    }};
 }
 
 /// Assert that a function call fails, returning the error value
@@ -103,18 +127,30 @@ macro_rules! assert_err {
 /// ```
 #[macro_export]
 macro_rules! assert_fail {
-    ($call:expr) => {
+    ($call:expr) => {{
+        $crate::core::receipt::record_assertion();
         match $call {
-            Ok(v) => panic!("Expected function to fail, but got Ok: {:?}", v),
+            Ok(v) => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Result,
+                format!("Expected function to fail, but got Ok: {:?}", v),
+            )
+            .with_context("value", format!("{:?}", v))
+            .raise(),
             Err(e) => e,
         }
-    };
-    ($call:expr, $msg:expr) => {
+    }};
+    ($call:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
         match $call {
-            Ok(v) => panic!("{}: Expected function to fail, but got Ok: {:?}", $msg, v),
+            Ok(v) => $crate::core::failure::TddFailure::new(
+                $crate::core::failure::FailureKind::Result,
+                format!("{}: Expected function to fail, but got Ok: {:?}", $msg, v),
+            )
+            .with_context("value", format!("{:?}", v))
+            .raise(),
             Err(e) => e,
         }
-    };
+    }};
 }
 
 #[cfg(test)]
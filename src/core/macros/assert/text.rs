@@ -0,0 +1,144 @@
+//! Text Pattern Assertion Macros
+//!
+//! Regex and glob assertions for log, CLI, and container output, so tests check the
+//! shape of the text rather than its exact bytes. Both macros return the captured
+//! groups on success.
+
+/// Assert that text matches a regular expression, returning the captures
+///
+/// The regex is unanchored (use `^`/`$` to anchor). On success the macro evaluates to
+/// a [`TextMatch`](crate::core::text_match::TextMatch) for extracting groups; on
+/// failure it reports how far the text matched and highlights the first
+/// non-matching region.
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::assert_matches_regex;
+///
+/// let log = "2026-10-16 INFO worker started pid=4242";
+///
+/// let found = assert_matches_regex!(log, r"worker started pid=(?P<pid>\d+)");
+/// assert_eq!(found.name("pid"), Some("4242"));
+///
+/// // With custom message
+/// assert_matches_regex!(log, r"^\d{4}-\d{2}-\d{2} INFO", "log line should start with a date");
+/// ```
+#[macro_export]
+macro_rules! assert_matches_regex {
+    ($text:expr, $pattern:expr $(,)?) => {
+        $crate::__assert_text_match!(
+            $crate::core::text_match::match_regex,
+            $text,
+            $pattern,
+            "assertion failed"
+        )
+    };
+    ($text:expr, $pattern:expr, $($arg:tt)+) => {
+        $crate::__assert_text_match!(
+            $crate::core::text_match::match_regex,
+            $text,
+            $pattern,
+            format!($($arg)+)
+        )
+    };
+}
+
+/// Assert that the whole text matches a glob, returning the wildcard captures
+///
+/// `*` matches any run of characters, `?` one character, `[a-z]`/`[!a-z]` one character
+/// from (or not from) a set, and `\` escapes. Each wildcard and set is a capture group,
+/// numbered from 1. Failures highlight the first non-matching region.
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::assert_matches_glob;
+///
+/// let output = "Listening on http://127.0.0.1:38117";
+///
+/// let found = assert_matches_glob!(output, "Listening on http://*:*");
+/// assert_eq!(found.group(2), Some("38117"));
+/// ```
+#[macro_export]
+macro_rules! assert_matches_glob {
+    ($text:expr, $glob:expr $(,)?) => {
+        $crate::__assert_text_match!(
+            $crate::core::text_match::match_glob,
+            $text,
+            $glob,
+            "assertion failed"
+        )
+    };
+    ($text:expr, $glob:expr, $($arg:tt)+) => {
+        $crate::__assert_text_match!(
+            $crate::core::text_match::match_glob,
+            $text,
+            $glob,
+            format!($($arg)+)
+        )
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_text_match {
+    ($matcher:path, $text:expr, $pattern:expr, $msg:expr) => {{
+        $crate::core::receipt::record_assertion();
+        match $matcher(::core::convert::AsRef::<str>::as_ref(&$text), &$pattern) {
+            Ok(found) => found,
+            Err(error) => {
+                let mut failure = $crate::core::failure::TddFailure::new(
+                    $crate::core::failure::FailureKind::Pattern,
+                    format!("{}: {}", $msg, error),
+                )
+                .with_context("pattern", ::std::string::ToString::to_string(&$pattern));
+                if let Some(offset) = error.mismatch_offset() {
+                    failure = failure.with_context("mismatch_offset", offset.to_string());
+                }
+                failure.raise()
+            }
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::failure::{FailureKind, TddFailure};
+    use crate::test;
+
+    test!(test_assert_matches_regex_returns_captures, {
+        // Arrange
+        let output = String::from("Created order 1182 for alice");
+
+        // Act
+        let found = assert_matches_regex!(output, r"order (\d+) for (?P<user>\w+)");
+
+        // Assert
+        assert_eq!(found.group(1), Some("1182"));
+        assert_eq!(found.name("user"), Some("alice"));
+    });
+
+    test!(test_assert_matches_glob_failure_highlights_region, {
+        // Arrange
+        let output = "exit status: 1";
+
+        // Act
+        let failure = TddFailure::catch(|| {
+            assert_matches_glob!(output, "exit status: 0", "{} should succeed", "command");
+        })
+        .unwrap_err();
+
+        // Assert
+        assert_eq!(failure.kind(), FailureKind::Pattern);
+        assert_eq!(failure.context()["mismatch_offset"], "13");
+        assert!(failure.message().starts_with("command should succeed: "), "{failure}");
+        assert!(failure.message().contains("exit status: 1\n    ~~~~~~~~~~~~~^"), "{failure}");
+    });
+
+    #[test]
+    #[should_panic(expected = "invalid regex `[`")]
+    fn test_assert_matches_regex_invalid_pattern() {
+        assert_matches_regex!("text", "[");
+    }
+}
@@ -29,6 +29,12 @@ pub const DEFAULT_UNIT_TEST_TIMEOUT_SECONDS: u64 = 1;
 /// in macros since constants cannot be referenced in `macro_rules`! expansions.
 pub const DEFAULT_INTEGRATION_TEST_TIMEOUT_SECONDS: u64 = 30;
 
+/// Default end-to-end test timeout in seconds
+///
+/// Budget for tests declared with `category = e2e`; override with `e2e_timeout_seconds`
+/// in chicago-tdd-tools.toml.
+pub const DEFAULT_E2E_TEST_TIMEOUT_SECONDS: u64 = 300;
+
 /// Default test timeout in seconds (SLA compliance)
 ///
 /// **Deprecated**: Use `DEFAULT_UNIT_TEST_TIMEOUT_SECONDS` instead.
@@ -50,6 +56,8 @@ pub const DEFAULT_TEST_TIMEOUT_SECONDS: u64 = DEFAULT_UNIT_TEST_TIMEOUT_SECONDS;
 /// - Unit tests: Automatically wrapped with a 1s timeout for SLA compliance
 /// - Integration tests: No ntest timeout (relies on cargo-nextest profile timeout)
 ///   Integration tests are detected by checking if module path contains "testcontainers"
+/// - Categorized tests (`test!(name, category = unit|integration|e2e, { ... })`) fail when
+///   they run longer than the category's budget (see [`test_category`](crate::core::test_category))
 ///
 /// # Example
 ///
@@ -91,6 +99,12 @@ pub const DEFAULT_TEST_TIMEOUT_SECONDS: u64 = DEFAULT_UNIT_TEST_TIMEOUT_SECONDS;
 #[macro_export]
 macro_rules! test {
     ($name:ident, $body:block) => {
+        $crate::test!(@define $name, , $body);
+    };
+    ($name:ident, category = $category:ident, $body:block) => {
+        $crate::test!(@define $name, $category, $body);
+    };
+    (@define $name:ident, $($category:ident)?, $body:block) => {
         #[test]
         // **Root Cause Fix**: Removed ntest timeout to allow cargo-nextest profiles to handle timeouts
         // Unit tests: Use default profile (1s timeout in .config/nextest.toml)
@@ -122,9 +136,32 @@ macro_rules! test {
             }
 
             // Execute test body - trait converts both () and Result to Result<(), Box<dyn Error>>
-            // This allows ? operator to work in the test body
-            let output = { $body };
-            __chicago_tdd_test_output::TestOutput::into_result(output)
+            // This allows ? operator to work in the test body. The body runs in its own fn so
+            // an early `return` still reaches the receipt.
+            fn __chicago_tdd_test_body() -> Result<(), Box<dyn std::error::Error>> {
+                let output = { $body };
+                __chicago_tdd_test_output::TestOutput::into_result(output)
+            }
+
+            let __receipt = $crate::core::receipt::TestRecording::start(concat!(
+                module_path!(),
+                "::",
+                stringify!($name)
+            ))
+            .at(file!(), line!());
+            let __started = ::std::time::Instant::now();
+            let result = __chicago_tdd_test_body();
+            $(
+                if result.is_ok() {
+                    $crate::core::test_category::enforce_budget(
+                        concat!(module_path!(), "::", stringify!($name)),
+                        $crate::core::test_category::categories::$category,
+                        __started.elapsed(),
+                    );
+                }
+            )?
+            __receipt.finish_result(&result);
+            result
         }
     };
 }
@@ -143,8 +180,14 @@ macro_rules! test {
 /// **Timeout Enforcement**:
 /// - Default: Tests are automatically wrapped with `tokio::time::timeout` (1s) for unit tests
 /// - Integration tests: Use `async_test_with_timeout!` with 30s timeout, or rely on cargo-nextest profile timeout
+/// - Categorized tests: `async_test!(name, category = integration, { ... })` uses the category's
+///   configured budget as the timeout
 /// - Defense in depth: Multiple timeout layers ensure enforcement even if one layer fails
 ///
+/// **Runtime Configuration**: `async_test!(name, runtime(flavor = multi_thread, worker_threads = 4), { ... })`
+/// or `runtime(start_paused = true)` builds the runtime with `tokio::runtime::Builder` (see
+/// [`TestRuntime`](crate::core::test_runtime::TestRuntime)); the timeout then counts wall-clock time.
+///
 /// **Chicago TDD Principle**: "Better to break fast than freeze forever" - timeouts prevent infinite hangs
 ///
 /// # Example
@@ -167,9 +210,27 @@ macro_rules! test {
 /// ```
 #[macro_export]
 macro_rules! async_test {
+    ($name:ident, runtime($($setting:tt)*), $body:block) => {
+        $crate::async_test_with_timeout!($name, 1, runtime($($setting)*), $body);
+    };
+    ($name:ident, category = $category:ident, runtime($($setting:tt)*), $body:block) => {
+        $crate::async_test_with_timeout!(
+            $name,
+            $crate::core::test_category::categories::$category.budget_secs(),
+            runtime($($setting)*),
+            $body
+        );
+    };
     ($name:ident, $body:block) => {
         $crate::async_test_with_timeout!($name, 1, $body);
     };
+    ($name:ident, category = $category:ident, $body:block) => {
+        $crate::async_test_with_timeout!(
+            $name,
+            $crate::core::test_category::categories::$category.budget_secs(),
+            $body
+        );
+    };
 }
 
 /// Macro for async tests with custom timeout
@@ -178,6 +239,7 @@ macro_rules! async_test {
 /// Use this for integration tests that require longer timeouts (e.g., 30s for Docker operations).
 ///
 /// **Timeout Enforcement**: Tests are wrapped with `tokio::time::timeout` using the specified duration.
+/// With a `runtime(...)` clause (as in `async_test!`) the timeout counts wall-clock time.
 ///
 /// # Example
 ///
@@ -200,6 +262,27 @@ macro_rules! async_test {
 /// ```
 #[macro_export]
 macro_rules! async_test_with_timeout {
+    ($name:ident, $timeout_secs:expr, runtime($($setting:tt)*), $body:block) => {
+        #[test]
+        fn $name() {
+            let __receipt = $crate::core::receipt::TestRecording::start(concat!(
+                module_path!(),
+                "::",
+                stringify!($name)
+            ))
+            .at(file!(), line!());
+
+            $crate::__test_runtime!($($setting)*).block_on(
+                stringify!($name),
+                $timeout_secs,
+                async {
+                    let output = async { $body }.await;
+                    $crate::core::test_runtime::AsyncTestOutput::handle(output);
+                },
+            );
+            __receipt.finish(true);
+        }
+    };
     ($name:ident, $timeout_secs:expr, $body:block) => {
         #[tokio::test]
         async fn $name() {
@@ -222,6 +305,13 @@ macro_rules! async_test_with_timeout {
                 }
             }
 
+            let __receipt = $crate::core::receipt::TestRecording::start(concat!(
+                module_path!(),
+                "::",
+                stringify!($name)
+            ))
+            .at(file!(), line!());
+
             // Execute body with specified timeout for SLA compliance
             // **Kaizen improvement**: Comments reference timeout constants for clarity
             // Note: Using literal value since macro_rules! cannot reference constants directly
@@ -249,6 +339,7 @@ macro_rules! async_test_with_timeout {
                     );
                 }
             }
+            __receipt.finish(true);
         }
     };
 }
@@ -260,8 +351,13 @@ macro_rules! async_test_with_timeout {
 /// **Timeout Enforcement**:
 /// - Default: Tests are automatically wrapped with `tokio::time::timeout` (1s) for unit tests
 /// - Integration tests: Use `fixture_test_with_timeout!` with 30s timeout, or rely on cargo-nextest profile timeout
+/// - Categorized tests: `fixture_test!(name, fixture, category = e2e, { ... })` uses the
+///   category's configured budget as the timeout
 /// - Defense in depth: Multiple timeout layers ensure enforcement even if one layer fails
 ///
+/// **Runtime Configuration**: `fixture_test!(name, fixture, runtime(start_paused = true), { ... })`
+/// takes the same `runtime(...)` settings as `async_test!`.
+///
 /// **Chicago TDD Principle**: "Better to break fast than freeze forever" - timeouts prevent infinite hangs
 ///
 /// # Example
@@ -283,9 +379,35 @@ macro_rules! async_test_with_timeout {
 /// ```
 #[macro_export]
 macro_rules! fixture_test {
+    ($name:ident, $fixture_var:ident, runtime($($setting:tt)*), $body:block) => {
+        $crate::fixture_test_with_timeout!($name, $fixture_var, 1, runtime($($setting)*), $body);
+    };
+    (
+        $name:ident,
+        $fixture_var:ident,
+        category = $category:ident,
+        runtime($($setting:tt)*),
+        $body:block
+    ) => {
+        $crate::fixture_test_with_timeout!(
+            $name,
+            $fixture_var,
+            $crate::core::test_category::categories::$category.budget_secs(),
+            runtime($($setting)*),
+            $body
+        );
+    };
     ($name:ident, $fixture_var:ident, $body:block) => {
         $crate::fixture_test_with_timeout!($name, $fixture_var, 1, $body);
     };
+    ($name:ident, $fixture_var:ident, category = $category:ident, $body:block) => {
+        $crate::fixture_test_with_timeout!(
+            $name,
+            $fixture_var,
+            $crate::core::test_category::categories::$category.budget_secs(),
+            $body
+        );
+    };
 }
 
 /// Macro for async tests with fixture and custom timeout
@@ -315,12 +437,45 @@ macro_rules! fixture_test {
 /// ```
 #[macro_export]
 macro_rules! fixture_test_with_timeout {
+    ($name:ident, $fixture_var:ident, $timeout_secs:expr, runtime($($setting:tt)*), $body:block) => {
+        #[allow(unnameable_test_items, unused_mut)]
+        #[test]
+        fn $name() {
+            let __receipt = $crate::core::receipt::TestRecording::start(concat!(
+                module_path!(),
+                "::",
+                stringify!($name)
+            ))
+            .at(file!(), line!());
+
+            // Arrange: Create fixture
+            #[allow(unused_mut)] // Fixture may not require mutation in every test body
+            let mut $fixture_var = $crate::core::fixture::TestFixture::new()
+                .unwrap_or_else(|e| panic!("Failed to create test fixture: {}", e));
+
+            $crate::__test_runtime!($($setting)*).block_on(
+                stringify!($name),
+                $timeout_secs,
+                async { $body },
+            );
+            __receipt.finish(true);
+
+            // Cleanup: Automatic teardown via Drop
+        }
+    };
     ($name:ident, $fixture_var:ident, $timeout_secs:expr, $body:block) => {
         #[allow(unnameable_test_items, unused_mut)]
         #[tokio::test]
         async fn $name() {
             use tokio::time::{timeout, Duration};
 
+            let __receipt = $crate::core::receipt::TestRecording::start(concat!(
+                module_path!(),
+                "::",
+                stringify!($name)
+            ))
+            .at(file!(), line!());
+
             // Arrange: Create fixture
             #[allow(clippy::expect_used)] // Macro - panic is appropriate if fixture creation fails
             #[allow(unused_mut)] // Fixture may not require mutation in every test body
@@ -351,6 +506,7 @@ macro_rules! fixture_test_with_timeout {
                     );
                 }
             }
+            __receipt.finish(true);
 
             // Cleanup: Automatic teardown via Drop
         }
@@ -381,8 +537,44 @@ macro_rules! fixture_test_with_timeout {
 ///     assert_eq!(result, 42);
 /// });
 /// ```
+///
+/// # Allocation Budgets
+///
+/// With the `heap-profiling` feature, a budget of `allocations <= N`, `heap_bytes <= N`,
+/// or `peak_heap_bytes <= N` profiles the body with DHAT. A failure lists the
+/// allocation hotspots and saves the profile as an artifact (see
+/// [`validation::heap_profile`](crate::validation::heap_profile) for the required
+/// global allocator setup).
+///
+/// ```rust,ignore
+/// performance_test!(test_lookup_is_allocation_free, allocations <= 0, {
+///     let hit = index.lookup("sku-1");
+///     assert!(hit.is_some());
+/// });
+/// ```
 #[macro_export]
 macro_rules! performance_test {
+    ($name:ident, allocations <= $max:expr, $body:block) => {
+        $crate::performance_test!(@heap $name, assert_max_allocations, $max, $body);
+    };
+    ($name:ident, heap_bytes <= $max:expr, $body:block) => {
+        $crate::performance_test!(@heap $name, assert_max_bytes, $max, $body);
+    };
+    ($name:ident, peak_heap_bytes <= $max:expr, $body:block) => {
+        $crate::performance_test!(@heap $name, assert_max_peak_bytes, $max, $body);
+    };
+    (@heap $name:ident, $assertion:ident, $max:expr, $body:block) => {
+        #[test]
+        fn $name() {
+            let profile = $crate::validation::heap_profile::HeapProfile::start(concat!(
+                module_path!(),
+                "::",
+                stringify!($name)
+            ));
+            $body
+            profile.$assertion($max);
+        }
+    };
     ($name:ident, $body:block) => {
         #[test]
         fn $name() {
@@ -544,6 +736,64 @@ mod tests {
         assert_that_with_msg(&result, |v| *v > 0, "Result should be greater than 0");
     });
 
+    // Categorized tests run under their category's configured budget
+    async_test!(test_async_categorized_within_budget, category = integration, {
+        // Arrange
+        let input = 21;
+
+        // Act
+        let doubled = async { input * 2 }.await;
+
+        // Assert
+        assert_eq!(doubled, 42);
+    });
+
+    fixture_test!(test_fixture_categorized_within_budget, fixture, category = unit, {
+        // Act
+        let counter = fixture.test_counter();
+
+        // Assert
+        assert!(counter < u64::MAX);
+    });
+
+    // Runtime clauses build the requested tokio runtime
+    async_test!(
+        test_async_multi_thread_runtime,
+        runtime(flavor = multi_thread, worker_threads = 2),
+        {
+            // Act
+            let flavor = tokio::runtime::Handle::current().runtime_flavor();
+            let on_worker = tokio::spawn(async { std::thread::current().id() })
+                .await
+                .unwrap_or_else(|e| panic!("{e}"));
+
+            // Assert
+            assert_eq!(flavor, tokio::runtime::RuntimeFlavor::MultiThread);
+            assert_ne!(on_worker, std::thread::current().id());
+        }
+    );
+
+    #[cfg(feature = "async")]
+    async_test!(test_async_paused_runtime, category = unit, runtime(start_paused = true), {
+        // Arrange
+        let start = tokio::time::Instant::now();
+
+        // Act: Paused time auto-advances past the 1s wall-clock budget instantly
+        tokio::time::sleep(std::time::Duration::from_secs(3600)).await;
+
+        // Assert
+        assert!(start.elapsed() >= std::time::Duration::from_secs(3600));
+    });
+
+    #[cfg(feature = "async")]
+    fixture_test!(test_fixture_paused_runtime, fixture, runtime(start_paused = true), {
+        // Act
+        tokio::time::sleep(std::time::Duration::from_secs(60)).await;
+
+        // Assert
+        assert!(fixture.test_counter() < u64::MAX);
+    });
+
     #[cfg(feature = "parameterized-testing")]
     #[test]
     fn test_parameterized_macro() {
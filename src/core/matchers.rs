@@ -0,0 +1,237 @@
+//! > 📚 Reference
+//!
+//! Matchers
+//!
+//! Reusable, self-describing predicates for the fluent assertion API
+//! ([`expect`](crate::core::assertions::expect)). A [`Matcher`] both checks a value and
+//! describes what it expected, so failures read "expected greater than 3" instead of
+//! "predicate returned false".
+//!
+//! Built-in matchers cover equality ([`equal_to`]) and ordering ([`greater_than`], ...); [`satisfies`] wraps a closure with a
+//! description, and [`all_of`], [`any_of`], [`not`] (or [`Matcher::and`] /
+//! [`Matcher::or`]) combine them. Implement [`Matcher`] for domain-specific checks.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::assertions::expect;
+//! use chicago_tdd_tools::core::matchers::{greater_than, less_than, not, Matcher};
+//!
+//! expect(7).to_match(greater_than(3).and(less_than(10))).to_match(not(greater_than(8)));
+//! ```
+
+use std::fmt::Debug;
+
+/// A self-describing check on values of type `T`
+pub trait Matcher<T: ?Sized> {
+    /// Whether `actual` satisfies the matcher
+    fn matches(&self, actual: &T) -> bool;
+
+    /// What the matcher expects, phrased to follow "expected", e.g. `greater than 3`
+    fn describe(&self) -> String;
+
+    /// Matches when both `self` and `other` match
+    fn and<M: Matcher<T> + 'static>(self, other: M) -> AllOf<'static, T>
+    where
+        Self: Sized + 'static,
+    {
+        all_of(vec![Box::new(self), Box::new(other)])
+    }
+
+    /// Matches when `self` or `other` matches
+    fn or<M: Matcher<T> + 'static>(self, other: M) -> AnyOf<'static, T>
+    where
+        Self: Sized + 'static,
+    {
+        any_of(vec![Box::new(self), Box::new(other)])
+    }
+}
+
+impl<T: ?Sized, M: Matcher<T> + ?Sized> Matcher<T> for Box<M> {
+    fn matches(&self, actual: &T) -> bool {
+        (**self).matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+}
+
+/// Matcher built from a closure and a description (see [`satisfies`])
+pub struct Satisfies<F> {
+    description: String,
+    predicate: F,
+}
+
+impl<T: ?Sized, F: Fn(&T) -> bool> Matcher<T> for Satisfies<F> {
+    fn matches(&self, actual: &T) -> bool {
+        (self.predicate)(actual)
+    }
+
+    fn describe(&self) -> String {
+        self.description.clone()
+    }
+}
+
+/// Matches values for which `predicate` returns `true`
+///
+/// `description` completes "expected ...", e.g. `an even number`.
+#[must_use]
+pub fn satisfies<T: ?Sized, F: Fn(&T) -> bool>(
+    description: impl Into<String>,
+    predicate: F,
+) -> Satisfies<F> {
+    Satisfies { description: description.into(), predicate }
+}
+
+/// Equality matcher (see [`equal_to`])
+#[derive(Debug, Clone)]
+pub struct EqualTo<U> {
+    expected: U,
+}
+
+impl<T: PartialEq<U> + ?Sized, U: Debug> Matcher<T> for EqualTo<U> {
+    fn matches(&self, actual: &T) -> bool {
+        actual == &self.expected
+    }
+
+    fn describe(&self) -> String {
+        format!("equal to {:?}", self.expected)
+    }
+}
+
+/// Matches values equal to `expected`
+#[must_use]
+pub const fn equal_to<U>(expected: U) -> EqualTo<U> {
+    EqualTo { expected }
+}
+
+/// Comparison performed by a [`Compare`] matcher
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    Greater,
+    Less,
+    AtLeast,
+    AtMost,
+}
+
+/// Ordering matcher against a bound (see [`greater_than`] and friends)
+#[derive(Debug, Clone)]
+pub struct Compare<U> {
+    expected: U,
+    comparison: Comparison,
+}
+
+impl<T: PartialOrd<U> + ?Sized, U: Debug> Matcher<T> for Compare<U> {
+    fn matches(&self, actual: &T) -> bool {
+        match self.comparison {
+            Comparison::Greater => actual > &self.expected,
+            Comparison::Less => actual < &self.expected,
+            Comparison::AtLeast => actual >= &self.expected,
+            Comparison::AtMost => actual <= &self.expected,
+        }
+    }
+
+    fn describe(&self) -> String {
+        let relation = match self.comparison {
+            Comparison::Greater => "greater than",
+            Comparison::Less => "less than",
+            Comparison::AtLeast => "at least",
+            Comparison::AtMost => "at most",
+        };
+        format!("{relation} {:?}", self.expected)
+    }
+}
+
+/// Matches values greater than `bound`
+#[must_use]
+pub const fn greater_than<U>(bound: U) -> Compare<U> {
+    Compare { expected: bound, comparison: Comparison::Greater }
+}
+
+/// Matches values less than `bound`
+#[must_use]
+pub const fn less_than<U>(bound: U) -> Compare<U> {
+    Compare { expected: bound, comparison: Comparison::Less }
+}
+
+/// Matches values greater than or equal to `bound`
+#[must_use]
+pub const fn at_least<U>(bound: U) -> Compare<U> {
+    Compare { expected: bound, comparison: Comparison::AtLeast }
+}
+
+/// Matches values less than or equal to `bound`
+#[must_use]
+pub const fn at_most<U>(bound: U) -> Compare<U> {
+    Compare { expected: bound, comparison: Comparison::AtMost }
+}
+
+/// Matches when every inner matcher matches (see [`all_of`])
+pub struct AllOf<'m, T: ?Sized> {
+    matchers: Vec<Box<dyn Matcher<T> + 'm>>,
+}
+
+impl<T: ?Sized> Matcher<T> for AllOf<'_, T> {
+    fn matches(&self, actual: &T) -> bool {
+        self.matchers.iter().all(|m| m.matches(actual))
+    }
+
+    fn describe(&self) -> String {
+        join_descriptions(&self.matchers, " and ")
+    }
+}
+
+/// Matches when every matcher in `matchers` matches (vacuously true when empty)
+#[must_use]
+pub fn all_of<'m, T: ?Sized>(matchers: Vec<Box<dyn Matcher<T> + 'm>>) -> AllOf<'m, T> {
+    AllOf { matchers }
+}
+
+/// Matches when any inner matcher matches (see [`any_of`])
+pub struct AnyOf<'m, T: ?Sized> {
+    matchers: Vec<Box<dyn Matcher<T> + 'm>>,
+}
+
+impl<T: ?Sized> Matcher<T> for AnyOf<'_, T> {
+    fn matches(&self, actual: &T) -> bool {
+        self.matchers.iter().any(|m| m.matches(actual))
+    }
+
+    fn describe(&self) -> String {
+        join_descriptions(&self.matchers, " or ")
+    }
+}
+
+/// Matches when at least one matcher in `matchers` matches (false when empty)
+#[must_use]
+pub fn any_of<'m, T: ?Sized>(matchers: Vec<Box<dyn Matcher<T> + 'm>>) -> AnyOf<'m, T> {
+    AnyOf { matchers }
+}
+
+/// Matches when the inner matcher does not (see [`not`])
+#[derive(Debug, Clone)]
+pub struct Not<M> {
+    inner: M,
+}
+
+impl<T: ?Sized, M: Matcher<T>> Matcher<T> for Not<M> {
+    fn matches(&self, actual: &T) -> bool {
+        !self.inner.matches(actual)
+    }
+
+    fn describe(&self) -> String {
+        format!("not {}", self.inner.describe())
+    }
+}
+
+/// Matches when `matcher` does not match
+#[must_use]
+pub const fn not<M>(matcher: M) -> Not<M> {
+    Not { inner: matcher }
+}
+
+fn join_descriptions<T: ?Sized>(matchers: &[Box<dyn Matcher<T> + '_>], separator: &str) -> String {
+    let parts: Vec<String> = matchers.iter().map(Matcher::describe).collect();
+    format!("({})", parts.join(separator))
+}
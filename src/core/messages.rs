@@ -0,0 +1,394 @@
+//! Message Catalog
+//!
+//! Framework error and alert text keyed by stable message IDs. The English templates
+//! ship built in; downstream crates can install a translated (or reworded) catalog,
+//! and log-based automation can key on the ID instead of matching English prose.
+//!
+//! Templates use `{name}` placeholders (`{{` and `}}` for literal braces). A catalog
+//! that lacks an ID falls back to the English template, so a partial translation never
+//! loses a message.
+//!
+//! IDs are part of the public API: once published, an ID is never renamed or reused
+//! for a different meaning. New messages get new IDs.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::messages::{self, MessageCatalog, MessageId};
+//!
+//! let german = MessageCatalog::from_json(
+//!     "de",
+//!     r#"{ "fixture.creation_failed": "Fixture konnte nicht erstellt werden: {reason}" }"#,
+//! )
+//! .unwrap();
+//! german.validate().unwrap();
+//!
+//! messages::set_catalog(german.show_ids(true));
+//! let text = messages::message(MessageId::FIXTURE_CREATION_FAILED, &[("reason", &"no db")]);
+//! assert_eq!(text, "[fixture.creation_failed] Fixture konnte nicht erstellt werden: no db");
+//! messages::reset_catalog();
+//! ```
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::sync::{Arc, PoisonError, RwLock};
+use thiserror::Error;
+
+/// Stable identifier of a catalog message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MessageId(&'static str);
+
+impl MessageId {
+    /// `FixtureError::CreationFailed`: `{reason}`
+    pub const FIXTURE_CREATION_FAILED: Self = Self("fixture.creation_failed");
+    /// `FixtureError::OperationFailed`: `{reason}`
+    pub const FIXTURE_OPERATION_FAILED: Self = Self("fixture.operation_failed");
+    /// `FixtureGraphError::Duplicate`: `{name}`
+    pub const FIXTURE_GRAPH_DUPLICATE: Self = Self("fixture_graph.duplicate");
+    /// `FixtureGraphError::Cycle`: `{cycle}`
+    pub const FIXTURE_GRAPH_CYCLE: Self = Self("fixture_graph.cycle");
+    /// `FixtureGraphError::Unknown`: `{name}`
+    pub const FIXTURE_GRAPH_UNKNOWN: Self = Self("fixture_graph.unknown");
+    /// `FixtureGraphError::Unknown` with a dependent: `{name}`, `{required_by}`
+    pub const FIXTURE_GRAPH_UNKNOWN_DEPENDENCY: Self = Self("fixture_graph.unknown_dependency");
+    /// `FixtureGraphError::TypeMismatch`: `{name}`, `{expected}`
+    pub const FIXTURE_GRAPH_TYPE_MISMATCH: Self = Self("fixture_graph.type_mismatch");
+    /// `FixtureGraphError::SetupFailed`: `{name}`, `{source}`
+    pub const FIXTURE_GRAPH_SETUP_FAILED: Self = Self("fixture_graph.setup_failed");
+    /// `FixtureGraphError::TeardownFailed`: `{failures}`
+    pub const FIXTURE_GRAPH_TEARDOWN_FAILED: Self = Self("fixture_graph.teardown_failed");
+    /// `CommandError::NotFound`: `{command}`
+    pub const COMMAND_NOT_FOUND: Self = Self("command.not_found");
+    /// `CommandError::SpawnFailed`: `{command}`, `{source}`
+    pub const COMMAND_SPAWN_FAILED: Self = Self("command.spawn_failed");
+    /// `CommandError::Io`: `{command}`, `{source}`
+    pub const COMMAND_IO: Self = Self("command.io");
+    /// `CommandError::TimedOut`: `{command}`, `{timeout}`, `{stderr}`
+    pub const COMMAND_TIMEOUT: Self = Self("command.timeout");
+    /// `CommandError::Failed`: `{command}`, `{status}`, `{stderr}`
+    pub const COMMAND_FAILED: Self = Self("command.failed");
+    /// `RequirementsError`: `{count}`, `{requirements}`
+    pub const REQUIREMENTS_UNMET: Self = Self("requirements.unmet");
+    /// `CommandError::Cancelled`: `{command}`, `{reason}`
+    pub const COMMAND_CANCELLED: Self = Self("command.cancelled");
+
+    /// The ID string (e.g. `fixture.creation_failed`)
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        self.0
+    }
+}
+
+impl fmt::Display for MessageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0)
+    }
+}
+
+/// Built-in English templates
+const ENGLISH: &[(MessageId, &str)] = &[
+    (MessageId::FIXTURE_CREATION_FAILED, "Failed to create fixture: {reason}"),
+    (MessageId::FIXTURE_OPERATION_FAILED, "Fixture operation failed: {reason}"),
+    (MessageId::FIXTURE_GRAPH_DUPLICATE, "Fixture '{name}' is already registered"),
+    (MessageId::FIXTURE_GRAPH_CYCLE, "Fixture dependency cycle: {cycle}"),
+    (MessageId::FIXTURE_GRAPH_UNKNOWN, "Unknown fixture '{name}'"),
+    (
+        MessageId::FIXTURE_GRAPH_UNKNOWN_DEPENDENCY,
+        "Unknown fixture '{name}' (required by '{required_by}')",
+    ),
+    (MessageId::FIXTURE_GRAPH_TYPE_MISMATCH, "Fixture '{name}' is not a {expected}"),
+    (MessageId::FIXTURE_GRAPH_SETUP_FAILED, "Setup of fixture '{name}' failed: {source}"),
+    (MessageId::FIXTURE_GRAPH_TEARDOWN_FAILED, "Teardown failed for {failures}"),
+    (
+        MessageId::COMMAND_NOT_FOUND,
+        "🚨 Command not found: {command}\n   💡 FIX: Install the program or add it to PATH",
+    ),
+    (MessageId::COMMAND_SPAWN_FAILED, "🚨 Failed to start `{command}`: {source}"),
+    (MessageId::COMMAND_IO, "🚨 I/O error while running `{command}`: {source}"),
+    (
+        MessageId::COMMAND_TIMEOUT,
+        "🚨 `{command}` timed out after {timeout} and was killed\n   stderr: {stderr}",
+    ),
+    (MessageId::COMMAND_FAILED, "🚨 `{command}` failed with {status}\n   stderr: {stderr}"),
+    (
+        MessageId::REQUIREMENTS_UNMET,
+        "🚨 Test environment requirements not met ({count}):\n{requirements}",
+    ),
+    (
+        MessageId::COMMAND_CANCELLED,
+        "🚨 `{command}` was killed because the test was cancelled: {reason}",
+    ),
+];
+
+/// Message catalog errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MessageCatalogError {
+    /// Catalog source is not a flat JSON object of strings
+    #[error("Invalid message catalog: {0}")]
+    Parse(String),
+    /// Catalog defines an ID the framework does not know
+    #[error("Message catalog defines unknown ID '{0}'")]
+    UnknownId(String),
+    /// A template's placeholders differ from the English template's
+    #[error("Message '{id}' must use placeholders {expected:?}, found {found:?}")]
+    PlaceholderMismatch {
+        /// Message ID
+        id: String,
+        /// Placeholders of the English template
+        expected: Vec<String>,
+        /// Placeholders of the catalog's template
+        found: Vec<String>,
+    },
+}
+
+/// Result type for message catalog operations
+pub type MessageCatalogResult<T> = Result<T, MessageCatalogError>;
+
+/// Templates for one locale
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MessageCatalog {
+    locale: String,
+    templates: BTreeMap<String, String>,
+    show_ids: bool,
+}
+
+impl Default for MessageCatalog {
+    fn default() -> Self {
+        Self::english()
+    }
+}
+
+impl MessageCatalog {
+    /// The built-in English catalog
+    #[must_use]
+    pub fn english() -> Self {
+        let templates =
+            ENGLISH.iter().map(|(id, text)| (id.as_str().to_string(), (*text).to_string()));
+        Self { locale: "en".to_string(), templates: templates.collect(), show_ids: false }
+    }
+
+    /// A catalog with no templates of its own (everything falls back to English)
+    #[must_use]
+    pub fn empty(locale: impl Into<String>) -> Self {
+        Self { locale: locale.into(), templates: BTreeMap::new(), show_ids: false }
+    }
+
+    /// Parse a flat JSON object mapping message IDs to templates
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `json` is not an object of strings.
+    pub fn from_json(locale: impl Into<String>, json: &str) -> MessageCatalogResult<Self> {
+        let templates: BTreeMap<String, String> =
+            serde_json::from_str(json).map_err(|e| MessageCatalogError::Parse(e.to_string()))?;
+        Ok(Self { templates, ..Self::empty(locale) })
+    }
+
+    /// Set the template for `id`
+    #[must_use]
+    pub fn with(mut self, id: MessageId, template: impl Into<String>) -> Self {
+        self.templates.insert(id.as_str().to_string(), template.into());
+        self
+    }
+
+    /// Prefix every message with its ID in brackets (e.g. `[command.timeout] ...`)
+    #[must_use]
+    pub const fn show_ids(mut self, show: bool) -> Self {
+        self.show_ids = show;
+        self
+    }
+
+    /// Locale tag this catalog was created for
+    #[must_use]
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// Template for `id`, falling back to English
+    #[must_use]
+    pub fn template(&self, id: MessageId) -> &str {
+        self.templates
+            .get(id.as_str())
+            .map(String::as_str)
+            .or_else(|| english(id))
+            .unwrap_or_else(|| id.as_str())
+    }
+
+    /// Check every template is for a known ID and uses the English placeholders
+    ///
+    /// # Errors
+    ///
+    /// Returns the first unknown ID or placeholder mismatch.
+    pub fn validate(&self) -> MessageCatalogResult<()> {
+        for (id, template) in &self.templates {
+            let reference = ENGLISH
+                .iter()
+                .find(|(known, _)| known.as_str() == id)
+                .map(|(_, text)| *text)
+                .ok_or_else(|| MessageCatalogError::UnknownId(id.clone()))?;
+            let expected = placeholders(reference);
+            let found = placeholders(template);
+            if expected != found {
+                return Err(MessageCatalogError::PlaceholderMismatch {
+                    id: id.clone(),
+                    expected: expected.into_iter().collect(),
+                    found: found.into_iter().collect(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Render `id` with named `args`
+    ///
+    /// Placeholders without a matching argument are left as written.
+    #[must_use]
+    pub fn format(&self, id: MessageId, args: &[(&str, &dyn fmt::Display)]) -> String {
+        let mut out = if self.show_ids { format!("[{id}] ") } else { String::new() };
+        substitute(self.template(id), &mut out, |name| {
+            args.iter().find(|(arg, _)| *arg == name).map(|(_, value)| value.to_string())
+        });
+        out
+    }
+}
+
+/// All message IDs the framework defines, in catalog order
+#[must_use]
+pub fn message_ids() -> Vec<MessageId> {
+    ENGLISH.iter().map(|(id, _)| *id).collect()
+}
+
+fn english(id: MessageId) -> Option<&'static str> {
+    ENGLISH.iter().find(|(known, _)| *known == id).map(|(_, text)| *text)
+}
+
+/// Placeholder names used by `template`
+fn placeholders(template: &str) -> BTreeSet<String> {
+    let mut names = BTreeSet::new();
+    substitute(template, &mut String::new(), |name| {
+        names.insert(name.to_string());
+        None
+    });
+    names
+}
+
+/// Expand `{name}` placeholders of `template` into `out` using `lookup`
+fn substitute(template: &str, out: &mut String, mut lookup: impl FnMut(&str) -> Option<String>) {
+    let mut rest = template;
+    while let Some(pos) = rest.find(['{', '}']) {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+        } else if let Some(end) = tail.strip_prefix('{').and_then(|t| t.find('}')) {
+            let name = &tail[1..=end];
+            match lookup(name) {
+                Some(value) => out.push_str(&value),
+                None => out.push_str(&tail[..end + 2]),
+            }
+            rest = &tail[end + 2..];
+        } else {
+            out.push_str(&tail[..1]);
+            rest = &tail[1..];
+        }
+    }
+    out.push_str(rest);
+}
+
+static CATALOG: RwLock<Option<Arc<MessageCatalog>>> = RwLock::new(None);
+
+/// The installed catalog (English by default)
+#[must_use]
+pub fn catalog() -> Arc<MessageCatalog> {
+    let installed = CATALOG.read().unwrap_or_else(PoisonError::into_inner).clone();
+    installed.unwrap_or_else(|| {
+        let mut slot = CATALOG.write().unwrap_or_else(PoisonError::into_inner);
+        Arc::clone(slot.get_or_insert_with(|| Arc::new(MessageCatalog::english())))
+    })
+}
+
+/// Install `catalog` for all framework messages in this process
+pub fn set_catalog(catalog: MessageCatalog) {
+    *CATALOG.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(catalog));
+}
+
+/// Go back to the built-in English catalog
+pub fn reset_catalog() {
+    *CATALOG.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Render `id` with the installed catalog
+#[must_use]
+pub fn message(id: MessageId, args: &[(&str, &dyn fmt::Display)]) -> String {
+    catalog().format(id, args)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_message_ids_are_stable, {
+        // Arrange
+        // IDs are a public contract for log-based automation: extend this list, never edit it.
+        let published = [
+            "fixture.creation_failed",
+            "fixture.operation_failed",
+            "fixture_graph.duplicate",
+            "fixture_graph.cycle",
+            "fixture_graph.unknown",
+            "fixture_graph.unknown_dependency",
+            "fixture_graph.type_mismatch",
+            "fixture_graph.setup_failed",
+            "fixture_graph.teardown_failed",
+            "command.not_found",
+            "command.spawn_failed",
+            "command.io",
+            "command.timeout",
+            "command.failed",
+            "requirements.unmet",
+        ];
+
+        // Act
+        let ids: Vec<&str> = message_ids().into_iter().map(MessageId::as_str).collect();
+
+        // Assert
+        assert_eq!(ids[..published.len()], published);
+        let unique: BTreeSet<&str> = ids.iter().copied().collect();
+        assert_eq!(unique.len(), ids.len(), "duplicate message ID");
+    });
+
+    test!(test_format_substitutes_and_falls_back, {
+        // Arrange
+        let catalog = MessageCatalog::empty("fr")
+            .with(MessageId::FIXTURE_GRAPH_DUPLICATE, "La fixture « {name} » existe déjà {{sic}}");
+
+        // Act
+        let translated = catalog.format(MessageId::FIXTURE_GRAPH_DUPLICATE, &[("name", &"db")]);
+        let fallback = catalog.format(MessageId::FIXTURE_GRAPH_CYCLE, &[("cycle", &"a -> a")]);
+        let missing_arg = catalog.format(MessageId::FIXTURE_GRAPH_CYCLE, &[]);
+
+        // Assert
+        assert_eq!(translated, "La fixture « db » existe déjà {sic}");
+        assert_eq!(fallback, "Fixture dependency cycle: a -> a");
+        assert_eq!(missing_arg, "Fixture dependency cycle: {cycle}");
+    });
+
+    test!(test_validate_rejects_unknown_ids_and_placeholder_drift, {
+        // Arrange
+        let unknown = MessageCatalog::from_json("de", r#"{ "fixture.gone": "x" }"#).unwrap();
+        let drift = MessageCatalog::empty("de")
+            .with(MessageId::COMMAND_FAILED, "`{command}` fehlgeschlagen: {code}");
+
+        // Act
+        let unknown = unknown.validate();
+        let drift = drift.validate();
+
+        // Assert
+        assert_eq!(unknown, Err(MessageCatalogError::UnknownId("fixture.gone".to_string())));
+        assert!(matches!(drift, Err(MessageCatalogError::PlaceholderMismatch { .. })));
+        assert!(MessageCatalog::english().validate().is_ok());
+        assert!(MessageCatalog::from_json("de", "[1]").is_err());
+    });
+}
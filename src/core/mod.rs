@@ -1,8 +1,21 @@
 //! Core Testing Infrastructure
 //!
-//! Foundational testing primitives that all tests use: fixtures, builders,
-//! assertions, macros, state management, compile-time assertions, alert helpers,
-//! and common test utilities.
+//! Foundational testing primitives that all tests use:
+//!
+//! - Fixtures, builders, macros, state management, and common test utilities
+//! - Assertions with fluent matchers and compile-time assertions
+//! - Alert helpers, a message catalog, and structured failure payloads
+//! - Failure output rendering with structural diffs, and redaction rules shared by
+//!   every capture path
+//! - Tracked cross-test shared state and free port allocation
+//! - A plugin API for third-party capability modules
+//! - Environment diagnostics (`doctor`) and a test event bus
+//! - Run report annotations and CI reporters (`JUnit` XML, GitHub Actions annotations,
+//!   summary tables)
+//! - Test-level cancellation of wait loops, per-category test timing budgets, and
+//!   configurable async test runtimes
+//! - Runtime feature-flag matrices, filesystem state snapshots, and subprocess leak
+//!   detection
 //!
 //! ## Fail-Fast Hardening
 //!
@@ -14,25 +27,53 @@ pub mod alert;
 pub mod assertions;
 pub mod async_fixture;
 pub mod builders;
+pub mod cancellation;
+pub mod command;
 pub mod config;
 pub mod const_assert;
 pub mod contract;
+pub mod doctor;
+pub mod events;
+pub mod eventually;
 /// Strict verification pipeline with fail-fast semantics for all 12 phases.
 pub mod fail_fast;
+pub mod failure;
 pub mod fixture;
+pub mod fixture_graph;
+pub mod flag_matrix;
+pub mod fs_snapshot;
 pub mod governance;
 /// Property-based tests validating invariant detection using proptest.
 pub mod invariant_properties;
 /// Unrecoverable invariant violations - core type system for hardening.
 pub mod invariants;
+pub mod json_path;
+pub mod layout;
 pub mod macros;
+pub mod matchers;
+pub mod messages;
+#[cfg(feature = "plugins")]
+pub mod plugin;
 pub mod poka_yoke;
+pub mod ports;
+pub mod presets;
 
 // Note: poka_yoke is NOT re-exported via glob to avoid conflicts with
 // poka_yoke modules in otel and testcontainers features
 pub mod receipt;
+pub mod redaction;
+pub mod render;
+pub mod report;
+pub mod reporting;
+pub mod requirements;
+pub mod shared_state;
 pub mod state;
+pub mod structural_diff;
+pub mod subprocess_guard;
+pub mod test_category;
+pub mod test_runtime;
 pub mod test_utils;
+pub mod text_match;
 pub mod type_level;
 pub mod verification_pipeline;
 
@@ -42,16 +83,41 @@ pub use assertions::*;
 #[cfg(feature = "async")]
 pub use async_fixture::*;
 pub use builders::*;
+pub use cancellation::*;
+pub use command::*;
 pub use const_assert::*;
 pub use contract::*;
+pub use doctor::*;
+pub use events::*;
+pub use eventually::*;
 pub use fail_fast::*;
+pub use failure::*;
 pub use fixture::*;
+pub use fixture_graph::*;
+pub use flag_matrix::*;
+pub use fs_snapshot::*;
 pub use governance::*;
 pub use invariant_properties::helpers;
 pub use invariants::*;
+pub use json_path::*;
+pub use matchers::*;
+pub use messages::*;
+pub use ports::*;
+pub use presets::*;
 // poka_yoke types are accessed via core::poka_yoke::* to avoid glob conflicts
 pub use receipt::*;
+pub use redaction::*;
+pub use render::*;
+pub use report::*;
+pub use reporting::*;
+pub use requirements::*;
+pub use shared_state::*;
 pub use state::*;
+pub use structural_diff::*;
+pub use subprocess_guard::*;
+pub use test_category::*;
+pub use test_runtime::*;
 pub use test_utils::*;
+pub use text_match::*;
 pub use type_level::*;
 pub use verification_pipeline::*;
@@ -0,0 +1,491 @@
+//! > 📚 Reference
+//!
+//! Framework Plugins
+//!
+//! Third-party crates (a Mongo preset pack, a company policy pack, ...) extend the
+//! framework without forking it by implementing [`FrameworkPlugin`] and registering the
+//! plugin with [`register_plugin!`](crate::register_plugin). Registration happens at link
+//! time, so a plugin is active as soon as its crate is linked into the test binary; no
+//! central list needs editing.
+//!
+//! A plugin can contribute:
+//!
+//! - **Initialization**: one-time setup, e.g. registering [`TestDataBuilder`] presets
+//! - **Capabilities**: named features other code can query with [`PluginRegistry::has_capability`]
+//! - **Fixtures**: entries in a [`FixtureGraph`]
+//! - **Report sections**: Markdown sections appended to test reports
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::fixture_graph::{FixtureGraph, FixtureGraphResult};
+//! use chicago_tdd_tools::core::plugin::{
+//!     installed_plugins, FrameworkPlugin, PluginCapability, ReportSection,
+//! };
+//! use chicago_tdd_tools::register_plugin;
+//!
+//! struct MongoPresets;
+//!
+//! impl FrameworkPlugin for MongoPresets {
+//!     fn name(&self) -> &'static str {
+//!         "mongo-presets"
+//!     }
+//!
+//!     fn capabilities(&self) -> Vec<PluginCapability> {
+//!         vec![PluginCapability::new("preset:mongo", "MongoDB connection fixture")]
+//!     }
+//!
+//!     fn register_fixtures(&self, graph: &mut FixtureGraph) -> FixtureGraphResult<()> {
+//!         graph.register("mongo_url", &[], |_| Ok("mongodb://localhost:27017".to_string()))?;
+//!         Ok(())
+//!     }
+//!
+//!     fn report_sections(&self) -> Vec<ReportSection> {
+//!         vec![ReportSection::new("MongoDB", "Fixtures ran against a local mongod.")]
+//!     }
+//! }
+//!
+//! register_plugin!(MongoPresets);
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let plugins = installed_plugins()?;
+//! assert!(plugins.has_capability("preset:mongo"));
+//!
+//! let graph = plugins.fixture_graph()?;
+//! let mut scope = graph.scope();
+//! assert_eq!(scope.get::<String>("mongo_url")?, "mongodb://localhost:27017");
+//! # scope.teardown()?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [`TestDataBuilder`]: crate::core::builders::TestDataBuilder
+
+use crate::core::fixture_graph::{FixtureGraph, FixtureGraphError, FixtureGraphResult};
+use std::fmt;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+#[doc(hidden)]
+pub use inventory as __inventory;
+
+/// Error returned by a plugin's [`FrameworkPlugin::init`]
+pub type PluginInitError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Plugin registration and initialization errors
+#[derive(Error, Debug)]
+pub enum PluginError {
+    /// Two plugins share a name
+    #[error("Plugin '{0}' is registered more than once")]
+    Duplicate(String),
+    /// A plugin's initialization failed
+    #[error("Plugin '{plugin}' failed to initialize: {source}")]
+    InitFailed {
+        /// Plugin name
+        plugin: String,
+        /// Error returned by the plugin
+        source: PluginInitError,
+    },
+    /// A plugin's fixtures could not be added to the graph
+    #[error("Plugin '{plugin}' contributed invalid fixtures: {source}")]
+    Fixtures {
+        /// Plugin name
+        plugin: String,
+        /// Underlying graph error
+        source: FixtureGraphError,
+    },
+}
+
+/// Result type for plugin operations
+pub type PluginResult<T> = Result<T, PluginError>;
+
+/// A named feature contributed by a plugin
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PluginCapability {
+    /// Capability name, e.g. `preset:mongo` or `policy:no-sleep`
+    pub name: String,
+    /// Human-readable description
+    pub description: String,
+}
+
+impl PluginCapability {
+    /// Create a capability
+    #[must_use]
+    pub fn new(name: impl Into<String>, description: impl Into<String>) -> Self {
+        Self { name: name.into(), description: description.into() }
+    }
+}
+
+/// A Markdown section contributed to test reports
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportSection {
+    /// Section heading
+    pub title: String,
+    /// Markdown body
+    pub body: String,
+}
+
+impl ReportSection {
+    /// Create a report section
+    #[must_use]
+    pub fn new(title: impl Into<String>, body: impl Into<String>) -> Self {
+        Self { title: title.into(), body: body.into() }
+    }
+}
+
+/// > 📚 Reference
+///
+/// Extension point for third-party capability modules.
+///
+/// Only [`Self::name`] is required; every contribution defaults to nothing. Names must be
+/// unique across all linked plugins.
+pub trait FrameworkPlugin: Send + Sync {
+    /// Unique plugin name, e.g. the crate name
+    fn name(&self) -> &'static str;
+
+    /// One-time setup, run before any contributions are collected
+    ///
+    /// # Errors
+    ///
+    /// Any error aborts plugin initialization and is reported as
+    /// [`PluginError::InitFailed`].
+    fn init(&self) -> Result<(), PluginInitError> {
+        Ok(())
+    }
+
+    /// Capabilities this plugin provides
+    fn capabilities(&self) -> Vec<PluginCapability> {
+        Vec::new()
+    }
+
+    /// Add this plugin's fixtures to `graph`
+    ///
+    /// # Errors
+    ///
+    /// Returns the graph's error if a fixture name is taken or a dependency would form a
+    /// cycle.
+    fn register_fixtures(&self, graph: &mut FixtureGraph) -> FixtureGraphResult<()> {
+        let _ = graph;
+        Ok(())
+    }
+
+    /// Sections this plugin adds to test reports
+    fn report_sections(&self) -> Vec<ReportSection> {
+        Vec::new()
+    }
+}
+
+/// Link-time registration entry created by [`register_plugin!`](crate::register_plugin)
+#[doc(hidden)]
+pub struct PluginRegistration(pub &'static dyn FrameworkPlugin);
+
+inventory::collect!(PluginRegistration);
+
+/// Register a [`FrameworkPlugin`] at link time
+///
+/// Takes a constant expression (typically a unit struct or a `static`). Registered
+/// plugins are picked up by [`PluginRegistry::discover`] and [`installed_plugins`].
+///
+/// [`PluginRegistry::discover`]: crate::core::plugin::PluginRegistry::discover
+/// [`installed_plugins`]: crate::core::plugin::installed_plugins
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::core::plugin::FrameworkPlugin;
+/// use chicago_tdd_tools::register_plugin;
+///
+/// struct PolicyPack;
+///
+/// impl FrameworkPlugin for PolicyPack {
+///     fn name(&self) -> &'static str {
+///         "acme-policy-pack"
+///     }
+/// }
+///
+/// register_plugin!(PolicyPack);
+/// ```
+#[macro_export]
+macro_rules! register_plugin {
+    ($plugin:expr $(,)?) => {
+        $crate::core::plugin::__inventory::submit! {
+            $crate::core::plugin::PluginRegistration(&$plugin)
+        }
+    };
+}
+
+/// > 📚 Reference
+///
+/// Ordered set of plugins and their combined contributions.
+///
+/// Plugins are kept sorted by name, so contributions are collected in the same order
+/// regardless of link order.
+#[derive(Default)]
+pub struct PluginRegistry {
+    plugins: Vec<&'static dyn FrameworkPlugin>,
+}
+
+impl fmt::Debug for PluginRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PluginRegistry").field("plugins", &self.names()).finish()
+    }
+}
+
+impl PluginRegistry {
+    /// Create an empty registry
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collect every plugin registered with [`register_plugin!`](crate::register_plugin)
+    ///
+    /// Plugins are not initialized; call [`Self::init`] (or use [`installed_plugins`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PluginError::Duplicate`] if two linked plugins share a name.
+    pub fn discover() -> PluginResult<Self> {
+        let mut registry = Self::new();
+        for registration in inventory::iter::<PluginRegistration> {
+            registry.register(registration.0)?;
+        }
+        Ok(registry)
+    }
+
+    /// Add a plugin explicitly
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PluginError::Duplicate`] if a plugin with the same name is registered.
+    pub fn register(&mut self, plugin: &'static dyn FrameworkPlugin) -> PluginResult<&mut Self> {
+        match self.plugins.binary_search_by(|p| p.name().cmp(plugin.name())) {
+            Ok(_) => Err(PluginError::Duplicate(plugin.name().to_string())),
+            Err(index) => {
+                self.plugins.insert(index, plugin);
+                Ok(self)
+            }
+        }
+    }
+
+    /// Registered plugin names, sorted
+    #[must_use]
+    pub fn names(&self) -> Vec<&'static str> {
+        self.plugins.iter().map(|p| p.name()).collect()
+    }
+
+    /// Look up a plugin by name
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&'static dyn FrameworkPlugin> {
+        self.plugins.iter().find(|p| p.name() == name).copied()
+    }
+
+    /// Run every plugin's [`FrameworkPlugin::init`], stopping at the first failure
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PluginError::InitFailed`] naming the failing plugin.
+    pub fn init(&self) -> PluginResult<()> {
+        for plugin in &self.plugins {
+            plugin.init().map_err(|source| PluginError::InitFailed {
+                plugin: plugin.name().to_string(),
+                source,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// All contributed capabilities, paired with the contributing plugin's name
+    #[must_use]
+    pub fn capabilities(&self) -> Vec<(&'static str, PluginCapability)> {
+        self.plugins
+            .iter()
+            .flat_map(|p| p.capabilities().into_iter().map(move |c| (p.name(), c)))
+            .collect()
+    }
+
+    /// Whether any plugin contributes the named capability
+    #[must_use]
+    pub fn has_capability(&self, name: &str) -> bool {
+        self.plugins.iter().any(|p| p.capabilities().iter().any(|c| c.name == name))
+    }
+
+    /// Add every plugin's fixtures to an existing graph
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PluginError::Fixtures`] naming the plugin whose fixtures were rejected.
+    pub fn register_fixtures(&self, graph: &mut FixtureGraph) -> PluginResult<()> {
+        for plugin in &self.plugins {
+            plugin.register_fixtures(graph).map_err(|source| PluginError::Fixtures {
+                plugin: plugin.name().to_string(),
+                source,
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Build a graph holding only plugin-contributed fixtures
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::register_fixtures`].
+    pub fn fixture_graph(&self) -> PluginResult<FixtureGraph> {
+        let mut graph = FixtureGraph::new();
+        self.register_fixtures(&mut graph)?;
+        Ok(graph)
+    }
+
+    /// All contributed report sections, paired with the contributing plugin's name
+    #[must_use]
+    pub fn report_sections(&self) -> Vec<(&'static str, ReportSection)> {
+        self.plugins
+            .iter()
+            .flat_map(|p| p.report_sections().into_iter().map(move |s| (p.name(), s)))
+            .collect()
+    }
+
+    /// Render all report sections as Markdown, one `##` heading per section
+    #[must_use]
+    pub fn render_report_sections(&self) -> String {
+        self.report_sections()
+            .into_iter()
+            .map(|(plugin, section)| {
+                format!("## {}\n\n_Contributed by `{plugin}`_\n\n{}\n", section.title, section.body)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Discover and initialize all linked plugins, once per process
+///
+/// The first call runs [`PluginRegistry::discover`] and [`PluginRegistry::init`]; later
+/// calls return the same registry (or the same error).
+///
+/// # Errors
+///
+/// Returns the discovery or initialization error from the first call.
+pub fn installed_plugins() -> Result<&'static PluginRegistry, &'static PluginError> {
+    static INSTALLED: OnceLock<PluginResult<PluginRegistry>> = OnceLock::new();
+    INSTALLED
+        .get_or_init(|| {
+            let registry = PluginRegistry::discover()?;
+            registry.init()?;
+            Ok(registry)
+        })
+        .as_ref()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    static INIT_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    struct LinkedPlugin;
+
+    impl FrameworkPlugin for LinkedPlugin {
+        fn name(&self) -> &'static str {
+            "linked"
+        }
+
+        fn init(&self) -> Result<(), PluginInitError> {
+            INIT_CALLS.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+
+        fn capabilities(&self) -> Vec<PluginCapability> {
+            vec![PluginCapability::new("preset:linked", "Linked test preset")]
+        }
+
+        fn register_fixtures(&self, graph: &mut FixtureGraph) -> FixtureGraphResult<()> {
+            graph.register("linked_port", &[], |_| Ok(27017_u16))?;
+            Ok(())
+        }
+
+        fn report_sections(&self) -> Vec<ReportSection> {
+            vec![ReportSection::new("Linked", "All linked checks passed.")]
+        }
+    }
+
+    register_plugin!(LinkedPlugin);
+
+    struct NamedPlugin(&'static str);
+
+    impl FrameworkPlugin for NamedPlugin {
+        fn name(&self) -> &'static str {
+            self.0
+        }
+
+        fn init(&self) -> Result<(), PluginInitError> {
+            Err("missing MONGO_URL".into())
+        }
+
+        fn register_fixtures(&self, graph: &mut FixtureGraph) -> FixtureGraphResult<()> {
+            graph.register("shared", &[], |_| Ok(()))?;
+            Ok(())
+        }
+    }
+
+    static ALPHA: NamedPlugin = NamedPlugin("alpha");
+    static BETA: NamedPlugin = NamedPlugin("beta");
+
+    test!(test_installed_plugins_discovers_and_inits_once, {
+        // Act
+        let plugins = installed_plugins().unwrap();
+        let again = installed_plugins().unwrap();
+
+        // Assert
+        assert!(std::ptr::eq(plugins, again));
+        assert_eq!(INIT_CALLS.load(Ordering::SeqCst), 1);
+        assert!(plugins.has_capability("preset:linked"));
+        let graph = plugins.fixture_graph().unwrap();
+        let mut scope = graph.scope();
+        assert_eq!(*scope.get::<u16>("linked_port").unwrap(), 27017);
+    });
+
+    test!(test_registry_sorts_and_rejects_duplicates, {
+        // Arrange
+        let mut registry = PluginRegistry::new();
+        registry.register(&BETA).unwrap().register(&ALPHA).unwrap();
+
+        // Act
+        let duplicate = registry.register(&NamedPlugin("alpha")).unwrap_err();
+
+        // Assert
+        assert_eq!(registry.names(), ["alpha", "beta"]);
+        assert_eq!(duplicate.to_string(), "Plugin 'alpha' is registered more than once");
+    });
+
+    test!(test_registry_names_failing_plugin, {
+        // Arrange
+        let mut registry = PluginRegistry::new();
+        registry.register(&ALPHA).unwrap().register(&BETA).unwrap();
+
+        // Act
+        let init = registry.init().unwrap_err();
+        let fixtures = registry.fixture_graph().unwrap_err();
+
+        // Assert
+        assert_eq!(init.to_string(), "Plugin 'alpha' failed to initialize: missing MONGO_URL");
+        assert!(matches!(fixtures, PluginError::Fixtures { ref plugin, .. } if plugin == "beta"));
+    });
+
+    test!(test_render_report_sections_attributes_plugin, {
+        // Arrange
+        let mut registry = PluginRegistry::new();
+        registry.register(&LinkedPlugin).unwrap();
+
+        // Act
+        let markdown = registry.render_report_sections();
+
+        // Assert
+        assert_eq!(
+            markdown,
+            "## Linked\n\n_Contributed by `linked`_\n\nAll linked checks passed.\n"
+        );
+    });
+}
@@ -34,9 +34,79 @@
 //! let value = test_result.assert_ok("Operation should succeed");
 //! assert_eq!(value, 42);
 //! ```
+//!
+//! # Custom Typestate Machines
+//!
+//! [`derive@Typestate`] builds the same kind of API for your own states: derive it on an
+//! enum of states with a transition table, and it generates a zero-sized marker type
+//! per state and a `Machine<S>` type exposing, in each state, only the transitions
+//! that leave it.
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::poka_yoke::Typestate;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Eq, Typestate)]
+//! #[typestate(
+//!     machine = Order,
+//!     data = Vec<String>,
+//!     transitions(
+//!         pay: Created -> Paid,
+//!         ship: Paid -> Shipped,
+//!         cancel: Created | Paid -> Cancelled,
+//!     )
+//! )]
+//! pub enum OrderState {
+//!     Created,
+//!     Paid,
+//!     Shipped,
+//!     Cancelled,
+//! }
+//!
+//! let mut order = Order::new(vec!["book".to_string()]);
+//! order.data_mut().push("pen".to_string());
+//! let order = order.pay().ship();
+//!
+//! assert_eq!(order.state(), OrderState::Shipped);
+//! assert_eq!(order.into_data(), ["book", "pen"]);
+//! ```
+//!
+//! Illegal transitions do not compile:
+//!
+//! ```rust,compile_fail
+//! use chicago_tdd_tools::core::poka_yoke::Typestate;
+//!
+//! #[derive(Typestate)]
+//! #[typestate(machine = Order, transitions(pay: Created -> Paid, ship: Paid -> Shipped))]
+//! pub enum OrderState {
+//!     Created,
+//!     Paid,
+//!     Shipped,
+//! }
+//!
+//! // No `ship` on `Order<Created>`: an order must be paid first
+//! let order = Order::new().ship();
+//! ```
+//!
+//! ```rust,compile_fail
+//! use chicago_tdd_tools::core::poka_yoke::Typestate;
+//!
+//! #[derive(Typestate)]
+//! #[typestate(machine = Order, transitions(pay: Created -> Paid, ship: Paid -> Shipped))]
+//! pub enum OrderState {
+//!     Created,
+//!     Paid,
+//!     Shipped,
+//! }
+//!
+//! // `new` only exists in the initial state
+//! let order = Order::<Shipped>::new();
+//! ```
 
 use std::marker::PhantomData;
 
+/// Derive a typestate machine from an enum of states (see the [module docs](self))
+pub use chicago_tdd_tools_proc_macros::Typestate;
+
 // ============================================================================
 // Behavior Verification Enforcement
 // ============================================================================
@@ -294,6 +364,40 @@ mod tests {
         let _ = test_result.assert_ok("Should fail");
     }
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Typestate)]
+    #[typestate(
+        machine = Connection,
+        initial = Disconnected,
+        data = u32,
+        transitions(
+            connect: Disconnected -> Connected,
+            authenticate: Connected -> Ready,
+            drop_link: Connected | Ready -> Disconnected,
+        )
+    )]
+    enum ConnectionState {
+        Ready,
+        Connected,
+        Disconnected,
+    }
+
+    #[test]
+    fn test_derived_typestate_machine() {
+        // Arrange
+        let connection = Connection::new(0);
+
+        // Act
+        let mut ready = connection.connect().authenticate();
+        *ready.data_mut() += 1;
+        let reconnected = ready.drop_link().connect();
+
+        // Assert
+        assert_eq!(reconnected.state(), ConnectionState::Connected);
+        assert_eq!(*reconnected.data(), 1);
+        assert_eq!(<Ready as ConnectionStateMarker>::STATE, ConnectionState::Ready);
+        assert_eq!(std::mem::size_of::<Connection<Ready>>(), std::mem::size_of::<u32>());
+    }
+
     #[test]
     fn test_test_result_assert_err() {
         // Arrange
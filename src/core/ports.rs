@@ -0,0 +1,143 @@
+//! Free Port Allocation
+//!
+//! Tests that start servers on fixed ports — Weaver on 4317/4320, containers mapped to
+//! fixed host ports — collide as soon as they run in parallel. [`PortAllocator`] hands
+//! out free ephemeral ports and keeps each one reserved in a process-wide registry until
+//! its [`ReservedPort`] is dropped, so no two tests in the process get the same port.
+//!
+//! The probe socket is closed before the port is handed out (the listener is usually a
+//! child process such as Weaver, or Docker), so another process could still take the
+//! port in between; drawing from the OS ephemeral range makes that rare.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::ports::PortAllocator;
+//!
+//! let port = PortAllocator::allocate().unwrap();
+//! let endpoint = format!("http://127.0.0.1:{port}");
+//! assert!(PortAllocator::is_reserved(port.port()));
+//! drop(port); // released for other tests
+//! # let _ = endpoint;
+//! ```
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::net::{Ipv4Addr, TcpListener};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use thiserror::Error;
+
+/// How many OS-assigned ports to try before giving up
+const MAX_ATTEMPTS: usize = 64;
+
+/// Ports currently reserved by live [`ReservedPort`]s in this process
+static RESERVED: Mutex<BTreeSet<u16>> = Mutex::new(BTreeSet::new());
+
+fn reserved() -> MutexGuard<'static, BTreeSet<u16>> {
+    RESERVED.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Port allocation errors
+#[derive(Error, Debug)]
+pub enum PortAllocationError {
+    /// The OS refused to assign an ephemeral port
+    #[error("🚨 Failed to allocate a free port: {0}\n   ⚠️  STOP: Cannot bind 127.0.0.1\n   💡 FIX: Check the loopback interface and the process's socket limits")]
+    Bind(#[source] std::io::Error),
+    /// Every port the OS assigned was already reserved by this process
+    #[error("🚨 No unreserved port found after {attempts} attempts\n   ⚠️  STOP: Too many ports are reserved by this test process\n   💡 FIX: Drop ReservedPort handles when tests finish")]
+    Exhausted {
+        /// Ports tried
+        attempts: usize,
+    },
+}
+
+/// Result type for port allocation
+pub type PortAllocationResult<T> = Result<T, PortAllocationError>;
+
+/// Allocates free local ports for test servers
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PortAllocator;
+
+impl PortAllocator {
+    /// Reserve one free port on `127.0.0.1`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no port can be bound or every candidate is already reserved.
+    pub fn allocate() -> PortAllocationResult<ReservedPort> {
+        for _ in 0..MAX_ATTEMPTS {
+            let listener =
+                TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).map_err(PortAllocationError::Bind)?;
+            let port = listener.local_addr().map_err(PortAllocationError::Bind)?.port();
+            if reserved().insert(port) {
+                return Ok(ReservedPort { port });
+            }
+        }
+        Err(PortAllocationError::Exhausted { attempts: MAX_ATTEMPTS })
+    }
+
+    /// Reserve `count` distinct free ports
+    ///
+    /// # Errors
+    ///
+    /// Same as [`PortAllocator::allocate`]; ports reserved before the failure are released.
+    pub fn allocate_many(count: usize) -> PortAllocationResult<Vec<ReservedPort>> {
+        (0..count).map(|_| Self::allocate()).collect()
+    }
+
+    /// Whether `port` is currently reserved by this process
+    #[must_use]
+    pub fn is_reserved(port: u16) -> bool {
+        reserved().contains(&port)
+    }
+}
+
+/// A port reserved for the lifetime of this handle
+///
+/// Keep it alive as long as the server using the port runs; dropping it returns the
+/// port to the pool.
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ReservedPort {
+    port: u16,
+}
+
+impl ReservedPort {
+    /// The reserved port number
+    #[must_use]
+    pub const fn port(&self) -> u16 {
+        self.port
+    }
+}
+
+impl fmt::Display for ReservedPort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.port)
+    }
+}
+
+impl Drop for ReservedPort {
+    fn drop(&mut self) {
+        reserved().remove(&self.port);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_allocated_ports_are_distinct_free_and_released_on_drop, {
+        // Arrange & Act
+        let ports = PortAllocator::allocate_many(4).unwrap();
+        let numbers: BTreeSet<u16> = ports.iter().map(ReservedPort::port).collect();
+
+        // Assert
+        assert_eq!(numbers.len(), 4);
+        for port in &ports {
+            assert!(PortAllocator::is_reserved(port.port()));
+            assert!(TcpListener::bind((Ipv4Addr::LOCALHOST, port.port())).is_ok());
+        }
+        drop(ports);
+        assert!(numbers.iter().all(|port| !PortAllocator::is_reserved(*port)));
+    });
+}
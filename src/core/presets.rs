@@ -0,0 +1,564 @@
+//! > 📚 Reference
+//!
+//! Versioned Builder Presets
+//!
+//! Data-file presets for [`TestDataBuilder`] carry a `version` tag. When a domain type
+//! renames or drops a field, bump the schema version and register a [`PresetMigration`]
+//! instead of rewriting every preset file: old files are migrated on load and each
+//! applied step is reported as a [`PresetWarning`], so presets can be updated gradually.
+//!
+//! # Preset File Format
+//!
+//! TOML, JSON, and YAML are supported (chosen by file extension):
+//!
+//! ```toml
+//! version = 1
+//! [data]
+//! order_id = "ORD-001"
+//! amount = 100
+//! ```
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::presets::{PresetFormat, PresetMigration, PresetSchema};
+//!
+//! // v2 renamed `amount` to `total_amount` and added `currency`
+//! let schema = PresetSchema::new(2).migration(
+//!     1,
+//!     PresetMigration::new().rename("amount", "total_amount").default_value("currency", "USD"),
+//! );
+//!
+//! let preset = schema
+//!     .parse("version = 1\n[data]\norder_id = \"ORD-001\"\namount = 100\n", PresetFormat::Toml)
+//!     .unwrap();
+//!
+//! assert_eq!(preset.warnings.len(), 2);
+//! let data = preset.into_builder().build();
+//! assert_eq!(data.get("total_amount").map(String::as_str), Some("100"));
+//! assert_eq!(data.get("currency").map(String::as_str), Some("USD"));
+//! ```
+
+use crate::core::builders::TestDataBuilder;
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::Path;
+use thiserror::Error;
+
+/// Preset loading and migration errors
+#[derive(Error, Debug)]
+pub enum PresetError {
+    /// Preset file could not be read
+    #[error("Failed to read preset {path}: {source}")]
+    Io {
+        /// Preset path
+        path: String,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// Preset text is not valid for its format
+    #[error("Invalid {format} preset: {message}")]
+    Parse {
+        /// Preset format
+        format: PresetFormat,
+        /// Parser message
+        message: String,
+    },
+    /// File extension is not a supported preset format
+    #[error("Unsupported preset format: {0}")]
+    UnsupportedFormat(String),
+    /// Preset was written by a newer schema than the one loading it
+    #[error("Preset version {found} is newer than schema version {current}")]
+    FutureVersion {
+        /// Version in the preset
+        found: u32,
+        /// Current schema version
+        current: u32,
+    },
+    /// No migration registered for a version step
+    #[error("No migration registered from preset version {0}")]
+    MissingMigration(u32),
+    /// A migration step failed
+    #[error("Migration from version {from_version} failed: {message}")]
+    MigrationFailed {
+        /// Version being migrated from
+        from_version: u32,
+        /// Failure message
+        message: String,
+    },
+    /// Strict schema refused an outdated preset
+    #[error("Preset is outdated (version {found}, current {current}): {warnings}")]
+    Outdated {
+        /// Version in the preset
+        found: u32,
+        /// Current schema version
+        current: u32,
+        /// Warnings that would have been reported
+        warnings: String,
+    },
+    /// Registering the migrated preset failed
+    #[error("Failed to register preset: {0}")]
+    Registry(String),
+}
+
+/// Result type for preset operations
+pub type PresetResult<T> = Result<T, PresetError>;
+
+/// Preset file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PresetFormat {
+    /// TOML
+    Toml,
+    /// JSON
+    Json,
+    /// YAML
+    Yaml,
+}
+
+impl PresetFormat {
+    /// Detect the format from a file extension
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PresetError::UnsupportedFormat`] for unknown extensions.
+    pub fn from_path(path: &Path) -> PresetResult<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Ok(Self::Toml),
+            Some("json") => Ok(Self::Json),
+            Some("yaml" | "yml") => Ok(Self::Yaml),
+            _ => Err(PresetError::UnsupportedFormat(path.display().to_string())),
+        }
+    }
+}
+
+impl fmt::Display for PresetFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Toml => "TOML",
+            Self::Json => "JSON",
+            Self::Yaml => "YAML",
+        })
+    }
+}
+
+/// On-disk preset layout
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PresetFile {
+    #[serde(default = "default_version")]
+    version: u32,
+    #[serde(default)]
+    data: BTreeMap<String, serde_json::Value>,
+}
+
+const fn default_version() -> u32 {
+    1
+}
+
+/// Custom field transformation applied during migration
+type TransformFn = Box<dyn Fn(&str) -> Result<String, String> + Send + Sync>;
+
+enum MigrationStep {
+    Rename { from: String, to: String },
+    Remove { field: String },
+    Default { field: String, value: String },
+    Transform { field: String, transform: TransformFn },
+}
+
+/// Field changes that upgrade a preset by exactly one version
+#[derive(Default)]
+pub struct PresetMigration {
+    steps: Vec<MigrationStep>,
+}
+
+impl fmt::Debug for PresetMigration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PresetMigration").field("steps", &self.steps.len()).finish()
+    }
+}
+
+impl PresetMigration {
+    /// Create an empty migration
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rename a field, keeping its value
+    #[must_use]
+    pub fn rename(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.steps.push(MigrationStep::Rename { from: from.into(), to: to.into() });
+        self
+    }
+
+    /// Drop a field that no longer exists
+    #[must_use]
+    pub fn remove(mut self, field: impl Into<String>) -> Self {
+        self.steps.push(MigrationStep::Remove { field: field.into() });
+        self
+    }
+
+    /// Supply a value for a new field when the preset does not set it
+    #[must_use]
+    pub fn default_value(mut self, field: impl Into<String>, value: impl Into<String>) -> Self {
+        self.steps
+            .push(MigrationStep::Default { field: field.into(), value: value.into() });
+        self
+    }
+
+    /// Rewrite a field's value (e.g. a unit or format change)
+    #[must_use]
+    pub fn transform<F>(mut self, field: impl Into<String>, transform: F) -> Self
+    where
+        F: Fn(&str) -> Result<String, String> + Send + Sync + 'static,
+    {
+        self.steps
+            .push(MigrationStep::Transform { field: field.into(), transform: Box::new(transform) });
+        self
+    }
+
+    fn apply(
+        &self,
+        from_version: u32,
+        data: &mut HashMap<String, String>,
+        warnings: &mut Vec<PresetWarning>,
+    ) -> PresetResult<()> {
+        let mut warn = |message: String| warnings.push(PresetWarning { from_version, message });
+        for step in &self.steps {
+            match step {
+                MigrationStep::Rename { from, to } => {
+                    if let Some(value) = data.remove(from) {
+                        data.insert(to.clone(), value);
+                        warn(format!("field '{from}' renamed to '{to}'"));
+                    }
+                }
+                MigrationStep::Remove { field } => {
+                    if data.remove(field).is_some() {
+                        warn(format!("field '{field}' removed"));
+                    }
+                }
+                MigrationStep::Default { field, value } => {
+                    if !data.contains_key(field) {
+                        data.insert(field.clone(), value.clone());
+                        warn(format!("field '{field}' defaulted to '{value}'"));
+                    }
+                }
+                MigrationStep::Transform { field, transform } => {
+                    if let Some(value) = data.get_mut(field) {
+                        let migrated = transform(value).map_err(|message| {
+                            PresetError::MigrationFailed { from_version, message }
+                        })?;
+                        warn(format!("field '{field}' rewritten from '{value}' to '{migrated}'"));
+                        *value = migrated;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A change applied while migrating an outdated preset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PresetWarning {
+    /// Version the change migrated away from
+    pub from_version: u32,
+    /// Description of the change
+    pub message: String,
+}
+
+impl fmt::Display for PresetWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{} -> v{}: {}", self.from_version, self.from_version + 1, self.message)
+    }
+}
+
+/// A preset upgraded to the current schema version
+#[derive(Debug, Clone)]
+pub struct VersionedPreset {
+    /// Version the preset was written with
+    pub original_version: u32,
+    /// Schema version the preset was migrated to
+    pub current_version: u32,
+    /// Migrated data
+    pub data: HashMap<String, String>,
+    /// Changes applied during migration (empty for up-to-date presets)
+    pub warnings: Vec<PresetWarning>,
+}
+
+impl VersionedPreset {
+    /// Whether the preset was written at an older schema version
+    ///
+    /// True even when no migration step changed any of its fields.
+    #[must_use]
+    pub const fn is_outdated(&self) -> bool {
+        self.original_version < self.current_version
+    }
+
+    /// Start a [`TestDataBuilder`] from the migrated data
+    #[must_use]
+    pub fn into_builder(self) -> TestDataBuilder {
+        self.data
+            .into_iter()
+            .fold(TestDataBuilder::new(), |builder, (key, value)| builder.with_var(key, value))
+    }
+}
+
+/// Current preset version plus the migrations that reach it
+#[derive(Debug)]
+pub struct PresetSchema {
+    current_version: u32,
+    migrations: BTreeMap<u32, PresetMigration>,
+    strict: bool,
+}
+
+impl PresetSchema {
+    /// Create a schema at `current_version`
+    #[must_use]
+    pub const fn new(current_version: u32) -> Self {
+        Self { current_version, migrations: BTreeMap::new(), strict: false }
+    }
+
+    /// Register the migration from `from_version` to `from_version + 1`
+    #[must_use]
+    pub fn migration(mut self, from_version: u32, migration: PresetMigration) -> Self {
+        self.migrations.insert(from_version, migration);
+        self
+    }
+
+    /// Reject outdated presets instead of migrating them with warnings
+    ///
+    /// Useful in CI once all preset files have been updated.
+    #[must_use]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Current schema version
+    #[must_use]
+    pub const fn current_version(&self) -> u32 {
+        self.current_version
+    }
+
+    /// Migrate raw preset data written at `version`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PresetError::FutureVersion`], [`PresetError::MissingMigration`],
+    /// [`PresetError::MigrationFailed`], or [`PresetError::Outdated`] in strict mode.
+    pub fn migrate(
+        &self,
+        version: u32,
+        mut data: HashMap<String, String>,
+    ) -> PresetResult<VersionedPreset> {
+        if version > self.current_version {
+            return Err(PresetError::FutureVersion {
+                found: version,
+                current: self.current_version,
+            });
+        }
+        let mut warnings = Vec::new();
+        for from_version in version..self.current_version {
+            self.migrations
+                .get(&from_version)
+                .ok_or(PresetError::MissingMigration(from_version))?
+                .apply(from_version, &mut data, &mut warnings)?;
+        }
+        if self.strict && version < self.current_version {
+            return Err(PresetError::Outdated {
+                found: version,
+                current: self.current_version,
+                warnings: warnings.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "),
+            });
+        }
+        Ok(VersionedPreset {
+            original_version: version,
+            current_version: self.current_version,
+            data,
+            warnings,
+        })
+    }
+
+    /// Parse and migrate preset text
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PresetError::Parse`] or any [`Self::migrate`] error.
+    pub fn parse(&self, text: &str, format: PresetFormat) -> PresetResult<VersionedPreset> {
+        let parse_err = |message: String| PresetError::Parse { format, message };
+        let file: PresetFile = match format {
+            PresetFormat::Toml => toml::from_str(text).map_err(|e| parse_err(e.to_string()))?,
+            PresetFormat::Json => {
+                serde_json::from_str(text).map_err(|e| parse_err(e.to_string()))?
+            }
+            PresetFormat::Yaml => {
+                serde_yaml::from_str(text).map_err(|e| parse_err(e.to_string()))?
+            }
+        };
+        let data = file
+            .data
+            .into_iter()
+            .map(|(key, value)| match value {
+                serde_json::Value::String(text) => (key, text),
+                other => (key, other.to_string()),
+            })
+            .collect();
+        self.migrate(file.version, data)
+    }
+
+    /// Load and migrate a preset file, emitting a warning alert per migration step
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PresetError::Io`], [`PresetError::UnsupportedFormat`], or any
+    /// [`Self::parse`] error.
+    pub fn load(&self, path: impl AsRef<Path>) -> PresetResult<VersionedPreset> {
+        let path = path.as_ref();
+        let format = PresetFormat::from_path(path)?;
+        let text = std::fs::read_to_string(path)
+            .map_err(|source| PresetError::Io { path: path.display().to_string(), source })?;
+        let preset = self.parse(&text, format)?;
+        for warning in &preset.warnings {
+            crate::alert_warning!(
+                format!("Outdated preset {}: {warning}", path.display()),
+                format!("Update the preset file to version {}", self.current_version)
+            );
+        }
+        Ok(preset)
+    }
+
+    /// Load a preset file and register it under `name` for [`TestDataBuilder::preset`]
+    ///
+    /// Returns the migration warnings so callers can surface them.
+    ///
+    /// # Errors
+    ///
+    /// Returns any [`Self::load`] error or [`PresetError::Registry`].
+    pub fn register(
+        &self,
+        name: impl Into<String>,
+        path: impl AsRef<Path>,
+    ) -> PresetResult<Vec<PresetWarning>> {
+        let VersionedPreset { data, warnings, .. } = self.load(path)?;
+        TestDataBuilder::register_preset(name, move |builder| {
+            data.iter().fold(builder, |builder, (key, value)| builder.with_var(key, value))
+        })
+        .map_err(PresetError::Registry)?;
+        Ok(warnings)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn order_schema() -> PresetSchema {
+        PresetSchema::new(3)
+            .migration(1, PresetMigration::new().rename("amount", "total_amount"))
+            .migration(
+                2,
+                PresetMigration::new()
+                    .remove("legacy_flag")
+                    .default_value("currency", "USD")
+                    .transform("total_amount", |value| {
+                        value
+                            .parse::<u64>()
+                            .map(|cents| format!("{cents}00"))
+                            .map_err(|e| e.to_string())
+                    }),
+            )
+    }
+
+    test!(test_current_preset_loads_without_warnings, {
+        // Arrange
+        let text = r#"{"version": 3, "data": {"total_amount": "500", "currency": "EUR"}}"#;
+
+        // Act
+        let preset = order_schema().parse(text, PresetFormat::Json).unwrap();
+
+        // Assert
+        assert!(!preset.is_outdated());
+        assert_eq!(preset.data.get("currency").map(String::as_str), Some("EUR"));
+    });
+
+    test!(test_old_preset_migrates_through_every_version, {
+        // Arrange
+        let text = "version: 1\ndata:\n  amount: 5\n  legacy_flag: true\n";
+
+        // Act
+        let preset = order_schema().parse(text, PresetFormat::Yaml).unwrap();
+
+        // Assert
+        assert_eq!(preset.original_version, 1);
+        assert_eq!(preset.data.get("total_amount").map(String::as_str), Some("500"));
+        assert_eq!(preset.data.get("currency").map(String::as_str), Some("USD"));
+        assert!(!preset.data.contains_key("legacy_flag"));
+        let rendered: Vec<String> = preset.warnings.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            rendered,
+            vec![
+                "v1 -> v2: field 'amount' renamed to 'total_amount'",
+                "v2 -> v3: field 'legacy_flag' removed",
+                "v2 -> v3: field 'currency' defaulted to 'USD'",
+                "v2 -> v3: field 'total_amount' rewritten from '5' to '500'",
+            ]
+        );
+    });
+
+    test!(test_old_preset_is_outdated_without_applicable_migrations, {
+        // Arrange: The v1 -> v2 rename does not touch any field of this preset
+        let schema = PresetSchema::new(2)
+            .migration(1, PresetMigration::new().rename("amount", "total_amount"));
+        let text = r#"{"version": 1, "data": {"currency": "EUR"}}"#;
+
+        // Act
+        let preset = schema.parse(text, PresetFormat::Json).unwrap();
+
+        // Assert
+        assert!(preset.warnings.is_empty());
+        assert!(preset.is_outdated());
+        assert_eq!(preset.current_version, 2);
+    });
+
+    test!(test_migration_errors_are_reported, {
+        // Arrange
+        let schema = order_schema();
+
+        // Act & Assert
+        assert!(matches!(
+            schema.parse("version = 4", PresetFormat::Toml),
+            Err(PresetError::FutureVersion { found: 4, current: 3 })
+        ));
+        assert!(matches!(
+            PresetSchema::new(2).parse("version = 1", PresetFormat::Toml),
+            Err(PresetError::MissingMigration(1))
+        ));
+        assert!(matches!(
+            schema.parse("version = 2\n[data]\ntotal_amount = \"x\"", PresetFormat::Toml),
+            Err(PresetError::MigrationFailed { from_version: 2, .. })
+        ));
+        assert!(matches!(
+            order_schema().strict(true).parse("version = 2", PresetFormat::Toml),
+            Err(PresetError::Outdated { found: 2, current: 3, .. })
+        ));
+    });
+
+    test!(test_register_makes_migrated_preset_available, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("order.toml");
+        std::fs::write(&path, "version = 1\n[data]\namount = \"7\"\n").unwrap();
+
+        // Act
+        let warnings = order_schema().register("presets_test_migrated_order", &path).unwrap();
+        let data = TestDataBuilder::preset("presets_test_migrated_order").unwrap().build();
+
+        // Assert
+        assert_eq!(warnings.len(), 3);
+        assert_eq!(data.get("total_amount").map(String::as_str), Some("700"));
+        assert_eq!(data.get("currency").map(String::as_str), Some("USD"));
+    });
+}
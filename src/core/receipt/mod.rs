@@ -12,6 +12,9 @@
 //! Receipts make tests observable operations with cryptographic provenance,
 //! like everything else in the AHI architecture.
 //!
+//! [`recorder`] streams a lighter per-test receipt for every `test!`/`#[tdd_test]` run
+//! and aggregates the stream into a run-level receipt with a merkle root.
+//!
 //! # Architecture
 //!
 //! ```text
@@ -30,6 +33,13 @@
 //! Governance/deployment gates
 //! ```
 
+pub mod recorder;
+
+pub use recorder::{
+    current_phase_timings, enter_phase, read_receipts, record_assertion, record_fixture, AaaPhase,
+    PhaseTimings, ReceiptRecorder, RunReceipt, TestExecutionReceipt, TestRecording, RECEIPTS_ENV,
+};
+
 use crate::core::contract::TestContract;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
@@ -79,7 +89,7 @@ pub struct TestReceipt {
 }
 
 /// Environment fingerprint: captures execution environment
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct EnvironmentFingerprint {
     /// Rust version (e.g., "1.75.0")
     pub rust_version: String,
@@ -0,0 +1,664 @@
+//! Per-Test Receipt Recording
+//!
+//! When enabled, every `test!`, `async_test!`, `fixture_test!`, and `#[tdd_test]` run
+//! appends a [`TestExecutionReceipt`] — name, outcome, AAA phase timings, assertions
+//! executed, fixtures used — to a JSON lines stream. [`RunReceipt`] aggregates a stream
+//! into a run-level receipt whose merkle root commits to every test receipt, so the
+//! stream can later be checked against it.
+//!
+//! Recording is off by default. Set `CHICAGO_TDD_RECEIPTS=<path>` (or call
+//! [`ReceiptRecorder::enable`]) to turn it on:
+//!
+//! ```bash
+//! CHICAGO_TDD_RECEIPTS=target/receipts.jsonl cargo test
+//! ```
+//!
+//! The recorder is fed by the test event bus (see [`crate::core::events`]): the hooks
+//! below publish events, and receipts are assembled from them.
+//!
+//! Phase timings are recorded only for tests that mark their phases (`#[tdd_test]` marks
+//! them from its `// Arrange`, `// Act`, and `// Assert` comments); time before the
+//! first marker counts as Arrange. Assertions are counted for the framework's
+//! `assert_*` macros and fluent matchers on the test's own thread.
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::receipt::{enter_phase, AaaPhase};
+//! use chicago_tdd_tools::test;
+//!
+//! test!(test_marks_phases, {
+//!     // Arrange
+//!     let input = 2;
+//!
+//!     enter_phase(AaaPhase::Act);
+//!     let doubled = input * 2;
+//!
+//!     enter_phase(AaaPhase::Assert);
+//!     chicago_tdd_tools::assert_in_range!(doubled, 4, 4);
+//! });
+//! ```
+
+use std::cell::RefCell;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, Once, PoisonError};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use super::{EnvironmentFingerprint, TestOutcome};
+use crate::core::events::{emit, Event};
+
+/// Environment variable naming the JSON lines file receipts are appended to
+pub const RECEIPTS_ENV: &str = "CHICAGO_TDD_RECEIPTS";
+
+/// Arrange-Act-Assert phase of a running test
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AaaPhase {
+    /// Setting up inputs and fixtures
+    Arrange,
+    /// Exercising the code under test
+    Act,
+    /// Verifying the outcome
+    Assert,
+}
+
+/// Time spent in each AAA phase, in nanoseconds
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhaseTimings {
+    /// Arrange phase (including time before the first marker)
+    pub arrange: u64,
+    /// Act phase
+    pub act: u64,
+    /// Assert phase
+    pub assert: u64,
+}
+
+impl PhaseTimings {
+    const fn add(&mut self, phase: AaaPhase, nanos: u64) {
+        let slot = match phase {
+            AaaPhase::Arrange => &mut self.arrange,
+            AaaPhase::Act => &mut self.act,
+            AaaPhase::Assert => &mut self.assert,
+        };
+        *slot = slot.saturating_add(nanos);
+    }
+
+    /// Nanoseconds spent in `phase`
+    #[must_use]
+    pub const fn get(&self, phase: AaaPhase) -> u64 {
+        match phase {
+            AaaPhase::Arrange => self.arrange,
+            AaaPhase::Act => self.act,
+            AaaPhase::Assert => self.assert,
+        }
+    }
+
+    /// Nanoseconds spent in all phases
+    #[must_use]
+    pub const fn total(&self) -> u64 {
+        self.arrange.saturating_add(self.act).saturating_add(self.assert)
+    }
+
+    /// Fraction of the total time spent in `phase` (0.0 when nothing was timed)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // A ratio does not need nanosecond precision
+    pub fn share(&self, phase: AaaPhase) -> f64 {
+        match self.total() {
+            0 => 0.0,
+            total => self.get(phase) as f64 / total as f64,
+        }
+    }
+
+    /// Phase that took the longest (the earliest phase on a tie)
+    #[must_use]
+    pub fn dominant(&self) -> AaaPhase {
+        [AaaPhase::Arrange, AaaPhase::Act, AaaPhase::Assert]
+            .into_iter()
+            .rev()
+            .max_by_key(|phase| self.get(*phase))
+            .unwrap_or(AaaPhase::Arrange)
+    }
+
+    /// Assert that `phase` took at most `max_share` (0.0-1.0) of the test's time
+    ///
+    /// # Panics
+    ///
+    /// Panics with the per-phase breakdown if `phase` took a larger share.
+    pub fn assert_share_at_most(&self, phase: AaaPhase, max_share: f64) {
+        let share = self.share(phase);
+        assert!(
+            share <= max_share,
+            "🚨 {phase:?} took {:.0}% of the test (limit {:.0}%)\n   \
+             📋 arrange {}ns, act {}ns, assert {}ns\n   \
+             💡 FIX: Move expensive setup into a shared fixture",
+            share * 100.0,
+            max_share * 100.0,
+            self.arrange,
+            self.act,
+            self.assert,
+        );
+    }
+}
+
+/// Machine-readable record of one test run
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestExecutionReceipt {
+    /// Fully qualified test name (`module::path::test_name`)
+    pub test_name: String,
+    /// Whether the test passed
+    pub outcome: TestOutcome,
+    /// Wall-clock duration in nanoseconds
+    pub duration_ns: u64,
+    /// Per-phase timings (`None` if the test marked no phases)
+    pub phases: Option<PhaseTimings>,
+    /// Framework assertions executed
+    pub assertions: u64,
+    /// Fixtures created, in order of first use
+    pub fixtures: Vec<String>,
+    /// Why the test failed, when known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure: Option<String>,
+    /// Source file declaring the test
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
+    /// Line of the test declaration in `file`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+    /// Timestamp (Unix epoch milliseconds)
+    pub timestamp: u64,
+}
+
+impl TestExecutionReceipt {
+    /// SHA-256 of the receipt's JSON form (its merkle leaf)
+    #[must_use]
+    pub fn digest(&self) -> String {
+        hex::encode(self.leaf())
+    }
+
+    fn leaf(&self) -> [u8; 32] {
+        Sha256::digest(serde_json::to_vec(self).unwrap_or_default()).into()
+    }
+}
+
+/// Run-level receipt aggregated from per-test receipts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunReceipt {
+    /// Environment the run was aggregated in
+    pub environment: EnvironmentFingerprint,
+    /// Number of test receipts
+    pub total: usize,
+    /// Tests that passed
+    pub passed: usize,
+    /// Tests that did not pass
+    pub failed: usize,
+    /// Assertions executed across all tests
+    pub assertions: u64,
+    /// Merkle root over the test receipt digests (ordered by test name, then digest)
+    pub merkle_root: String,
+    /// Timestamp (Unix epoch milliseconds)
+    pub timestamp: u64,
+}
+
+impl RunReceipt {
+    /// Aggregate `receipts`
+    #[must_use]
+    pub fn from_receipts(receipts: &[TestExecutionReceipt]) -> Self {
+        let passed = receipts.iter().filter(|r| r.outcome == TestOutcome::Pass).count();
+        Self {
+            environment: EnvironmentFingerprint::capture(),
+            total: receipts.len(),
+            passed,
+            failed: receipts.len() - passed,
+            assertions: receipts.iter().map(|r| r.assertions).sum(),
+            merkle_root: merkle_root(receipts),
+            timestamp: now_millis(),
+        }
+    }
+
+    /// Aggregate the receipts in a JSON lines stream
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or a line is not a receipt.
+    pub fn from_jsonl(path: impl AsRef<Path>) -> io::Result<Self> {
+        read_receipts(path).map(|receipts| Self::from_receipts(&receipts))
+    }
+
+    /// Whether `receipts` are exactly the ones this run receipt commits to
+    #[must_use]
+    pub fn verify(&self, receipts: &[TestExecutionReceipt]) -> bool {
+        self.total == receipts.len() && self.merkle_root == merkle_root(receipts)
+    }
+
+    /// Serialize to pretty JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns error if serialization fails
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| format!("Serialization error: {e}"))
+    }
+}
+
+/// Read every receipt from a JSON lines stream
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or a line is not a receipt.
+pub fn read_receipts(path: impl AsRef<Path>) -> io::Result<Vec<TestExecutionReceipt>> {
+    let file = File::open(path)?;
+    BufReader::new(file)
+        .lines()
+        .filter(|line| line.as_ref().map_or(true, |line| !line.trim().is_empty()))
+        .map(|line| {
+            serde_json::from_str(&line?).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
+        .collect()
+}
+
+/// Binary merkle tree over the receipt digests; an odd node is paired with itself
+fn merkle_root(receipts: &[TestExecutionReceipt]) -> String {
+    let mut leaves: Vec<(&str, [u8; 32])> =
+        receipts.iter().map(|r| (r.test_name.as_str(), r.leaf())).collect();
+    // Parallel tests finish in any order; sort so the root only depends on the set
+    leaves.sort_unstable();
+    let mut level: Vec<[u8; 32]> = leaves.into_iter().map(|(_, leaf)| leaf).collect();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| {
+                let (left, right) = (pair[0], pair.get(1).copied().unwrap_or(pair[0]));
+                Sha256::new().chain_update(left).chain_update(right).finalize().into()
+            })
+            .collect();
+    }
+    level.first().map_or_else(|| hex::encode(Sha256::digest(b"")), hex::encode)
+}
+
+#[allow(clippy::cast_possible_truncation)] // Milliseconds since 1970 fit in u64
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+#[allow(clippy::cast_possible_truncation)] // Test durations are far below 584 years
+fn nanos_since(start: Instant) -> u64 {
+    start.elapsed().as_nanos() as u64
+}
+
+/// Open receipt stream
+#[derive(Debug)]
+struct ReceiptStream {
+    path: PathBuf,
+    file: File,
+}
+
+static STREAM: Mutex<Option<ReceiptStream>> = Mutex::new(None);
+static FROM_ENV: Once = Once::new();
+
+fn stream() -> MutexGuard<'static, Option<ReceiptStream>> {
+    FROM_ENV.call_once(|| {
+        if let Some(path) = std::env::var_os(RECEIPTS_ENV).filter(|path| !path.is_empty()) {
+            if let Err(e) = open_stream(PathBuf::from(path)) {
+                eprintln!("⚠️  {RECEIPTS_ENV}: cannot open receipt stream: {e}");
+            }
+        }
+    });
+    STREAM.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn open_stream(path: PathBuf) -> io::Result<()> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(&path)?;
+    *STREAM.lock().unwrap_or_else(PoisonError::into_inner) = Some(ReceiptStream { path, file });
+    Ok(())
+}
+
+/// Process-wide receipt stream
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReceiptRecorder;
+
+impl ReceiptRecorder {
+    /// Append receipts to `path` (overrides `CHICAGO_TDD_RECEIPTS`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn enable(path: impl Into<PathBuf>) -> io::Result<()> {
+        FROM_ENV.call_once(|| {});
+        open_stream(path.into())
+    }
+
+    /// Stop recording receipts
+    pub fn disable() {
+        FROM_ENV.call_once(|| {});
+        *STREAM.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Whether receipts are being recorded
+    #[must_use]
+    pub fn is_enabled() -> bool {
+        stream().is_some()
+    }
+
+    /// File receipts are appended to, if enabled
+    #[must_use]
+    pub fn path() -> Option<PathBuf> {
+        stream().as_ref().map(|stream| stream.path.clone())
+    }
+
+    /// Append `receipt` to the stream (no-op when disabled)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn record(receipt: &TestExecutionReceipt) -> io::Result<()> {
+        let mut line = serde_json::to_vec(receipt).map_err(io::Error::other)?;
+        line.push(b'\n');
+        let mut stream = stream();
+        match stream.as_mut() {
+            // One write per line keeps concurrent test binaries from interleaving
+            Some(stream) => stream.file.write_all(&line),
+            None => Ok(()),
+        }
+    }
+}
+
+/// State of the test running on this thread
+#[derive(Debug)]
+struct ActiveTest {
+    started: Instant,
+    phase: Option<(AaaPhase, Instant)>,
+    timings: PhaseTimings,
+    assertions: u64,
+    fixtures: Vec<String>,
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Option<ActiveTest>> = const { RefCell::new(None) };
+}
+
+fn with_active(update: impl FnOnce(&mut ActiveTest)) {
+    ACTIVE.with(|active| {
+        if let Some(test) = active.borrow_mut().as_mut() {
+            update(test);
+        }
+    });
+}
+
+/// Mark the start of `phase` in the running test
+pub fn enter_phase(phase: AaaPhase) {
+    emit(Event::PhaseEntered { phase });
+}
+
+/// Count one assertion in the running test
+pub fn record_assertion() {
+    emit(Event::AssertionEvaluated);
+}
+
+/// Note that the running test used fixture `name`
+pub fn record_fixture(name: &str) {
+    emit(Event::FixtureSetup { fixture: name.to_string() });
+}
+
+/// Phase timings of the test running on this thread, up to now
+///
+/// Returns `None` outside a test or if the test has not marked a phase yet. Lets a test
+/// check its own phase balance, e.g. that Arrange is not dominating:
+///
+/// ```rust
+/// use chicago_tdd_tools::core::receipt::{current_phase_timings, enter_phase, AaaPhase};
+/// use chicago_tdd_tools::test;
+///
+/// test!(test_setup_stays_cheap, {
+///     // Arrange
+///     let input = vec![3, 1, 2];
+///
+///     enter_phase(AaaPhase::Act);
+///     let mut sorted = input.clone();
+///     sorted.sort_unstable();
+///
+///     enter_phase(AaaPhase::Assert);
+///     assert_eq!(sorted, [1, 2, 3]);
+///     let timings = current_phase_timings().expect("phases are marked");
+///     assert!(timings.share(AaaPhase::Arrange) <= 1.0);
+/// });
+/// ```
+#[must_use]
+pub fn current_phase_timings() -> Option<PhaseTimings> {
+    ACTIVE.with(|active| {
+        let active = active.borrow();
+        let test = active.as_ref()?;
+        let (phase, since) = test.phase?;
+        let mut timings = test.timings;
+        timings.add(phase, nanos_since(since));
+        Some(timings)
+    })
+}
+
+/// Update this thread's receipt from a bus event (called by [`emit`] before subscribers)
+pub(crate) fn on_event(event: &Event) {
+    match event {
+        Event::TestStarted { .. } => ACTIVE.with(|active| {
+            *active.borrow_mut() = Some(ActiveTest {
+                started: Instant::now(),
+                phase: None,
+                timings: PhaseTimings::default(),
+                assertions: 0,
+                fixtures: Vec::new(),
+            });
+        }),
+        Event::PhaseEntered { phase } => with_active(|test| {
+            let now = Instant::now();
+            let (current, since) = test.phase.unwrap_or((AaaPhase::Arrange, test.started));
+            test.timings.add(current, nanos_since(since));
+            test.phase = Some((*phase, now));
+        }),
+        Event::AssertionEvaluated => with_active(|test| test.assertions += 1),
+        Event::FixtureSetup { fixture } => with_active(|test| {
+            if !test.fixtures.iter().any(|name| name == fixture) {
+                test.fixtures.push(fixture.clone());
+            }
+        }),
+        Event::AlertEmitted { .. } => {}
+        Event::TestFinished { test: test_name, outcome, duration_ns, failure, file, line } => {
+            let Some(mut test) = ACTIVE.with(|active| active.borrow_mut().take()) else {
+                return;
+            };
+            if !ReceiptRecorder::is_enabled() {
+                return;
+            }
+            let phases = test.phase.map(|(phase, since)| {
+                test.timings.add(phase, nanos_since(since));
+                test.timings
+            });
+            let receipt = TestExecutionReceipt {
+                test_name: test_name.clone(),
+                outcome: *outcome,
+                duration_ns: *duration_ns,
+                phases,
+                assertions: test.assertions,
+                fixtures: test.fixtures,
+                failure: failure.clone(),
+                file: file.clone(),
+                line: *line,
+                timestamp: now_millis(),
+            };
+            if let Err(e) = ReceiptRecorder::record(&receipt) {
+                eprintln!("⚠️  Failed to record test receipt for {}: {e}", receipt.test_name);
+            }
+        }
+    }
+}
+
+/// Guard announcing one test run on the event bus
+///
+/// Created by the test macros. [`finish`](Self::finish) publishes the outcome; dropping
+/// the guard unfinished (a panic or early return) publishes a failure.
+#[derive(Debug)]
+pub struct TestRecording {
+    name: Option<String>,
+    started: Instant,
+    location: Option<(&'static str, u32)>,
+}
+
+impl TestRecording {
+    /// Announce that `test_name` started on this thread
+    #[must_use]
+    pub fn start(test_name: &str) -> Self {
+        emit(Event::TestStarted { test: test_name.to_string() });
+        Self { name: Some(test_name.to_string()), started: Instant::now(), location: None }
+    }
+
+    /// Attach the source location of the test declaration
+    #[must_use]
+    pub const fn at(mut self, file: &'static str, line: u32) -> Self {
+        self.location = Some((file, line));
+        self
+    }
+
+    /// Record the test as passed or failed
+    pub fn finish(mut self, passed: bool) {
+        self.complete(if passed { TestOutcome::Pass } else { TestOutcome::Fail }, None);
+    }
+
+    /// Record the outcome of a test body returning `Result`, keeping the error message
+    pub fn finish_result<E: std::fmt::Display>(mut self, result: &Result<(), E>) {
+        match result {
+            Ok(()) => self.complete(TestOutcome::Pass, None),
+            Err(e) => self.complete(TestOutcome::Fail, Some(e.to_string())),
+        }
+    }
+
+    fn complete(&mut self, outcome: TestOutcome, failure: Option<String>) {
+        let Some(test) = self.name.take() else {
+            return;
+        };
+        emit(Event::TestFinished {
+            test,
+            outcome,
+            duration_ns: nanos_since(self.started),
+            failure,
+            file: self.location.map(|(file, _)| file.to_string()),
+            line: self.location.map(|(_, line)| line),
+        });
+    }
+}
+
+impl Drop for TestRecording {
+    fn drop(&mut self) {
+        let failure = if std::thread::panicking() { "panicked" } else { "did not finish" };
+        self.complete(TestOutcome::Fail, Some(failure.to_string()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt(name: &str, outcome: TestOutcome, assertions: u64) -> TestExecutionReceipt {
+        TestExecutionReceipt {
+            test_name: name.to_string(),
+            outcome,
+            duration_ns: 10,
+            phases: None,
+            assertions,
+            fixtures: Vec::new(),
+            failure: None,
+            file: None,
+            line: None,
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_run_receipt_merkle_root_is_order_independent_and_tamper_evident() {
+        let receipts = vec![
+            receipt("a", TestOutcome::Pass, 2),
+            receipt("b", TestOutcome::Fail, 1),
+            receipt("c", TestOutcome::Pass, 0),
+        ];
+        let run = RunReceipt::from_receipts(&receipts);
+
+        assert_eq!((run.total, run.passed, run.failed, run.assertions), (3, 2, 1, 3));
+        let mut reordered = receipts.clone();
+        reordered.reverse();
+        assert!(run.verify(&reordered));
+
+        let mut tampered = receipts;
+        tampered[1].outcome = TestOutcome::Pass;
+        assert!(!run.verify(&tampered));
+        assert_ne!(RunReceipt::from_receipts(&[]).merkle_root, run.merkle_root);
+    }
+
+    #[test]
+    fn test_phase_timings_report_shares_and_dominant_phase() {
+        let timings = PhaseTimings { arrange: 600, act: 300, assert: 100 };
+
+        assert_eq!(timings.total(), 1000);
+        assert!((timings.share(AaaPhase::Arrange) - 0.6).abs() < f64::EPSILON);
+        assert_eq!(timings.dominant(), AaaPhase::Arrange);
+        assert_eq!(PhaseTimings::default().share(AaaPhase::Act), 0.0);
+        assert_eq!(PhaseTimings { arrange: 5, act: 5, assert: 1 }.dominant(), AaaPhase::Arrange);
+        timings.assert_share_at_most(AaaPhase::Act, 0.5);
+        let failure = crate::core::failure::TddFailure::catch(|| {
+            timings.assert_share_at_most(AaaPhase::Arrange, 0.5);
+        })
+        .unwrap_err();
+        assert!(failure.message().contains("Arrange took 60% of the test"), "{failure}");
+    }
+
+    #[test]
+    fn test_current_phase_timings_track_the_running_test() {
+        assert_eq!(current_phase_timings(), None);
+        let recording = TestRecording::start("suite::current");
+        assert_eq!(current_phase_timings(), None);
+
+        enter_phase(AaaPhase::Act);
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        let timings = current_phase_timings().unwrap();
+        recording.finish(true);
+
+        assert!(timings.act >= 1_000_000, "{timings:?}");
+        assert_eq!(current_phase_timings(), None);
+    }
+
+    #[test]
+    fn test_recording_streams_phases_assertions_and_fixtures() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("receipts.jsonl");
+        ReceiptRecorder::enable(&path).unwrap();
+
+        let recording = TestRecording::start("suite::passes");
+        record_fixture("TestFixture");
+        record_fixture("TestFixture");
+        enter_phase(AaaPhase::Act);
+        enter_phase(AaaPhase::Assert);
+        record_assertion();
+        record_assertion();
+        recording.finish(true);
+        drop(TestRecording::start("suite::panics"));
+        ReceiptRecorder::disable();
+        // Only this test enables the recorder, so concurrently running tests may
+        // have appended their own receipts; look at ours.
+        let receipts: Vec<TestExecutionReceipt> = read_receipts(&path)
+            .unwrap()
+            .into_iter()
+            .filter(|r| r.test_name.starts_with("suite::"))
+            .collect();
+
+        assert_eq!(receipts.len(), 2);
+        assert_eq!(receipts[0].outcome, TestOutcome::Pass);
+        assert_eq!(receipts[0].assertions, 2);
+        assert_eq!(receipts[0].fixtures, vec!["TestFixture".to_string()]);
+        assert!(receipts[0].phases.is_some());
+        assert_eq!(receipts[1].outcome, TestOutcome::Fail);
+        assert_eq!(receipts[1].phases, None);
+        assert_eq!(receipts[1].failure.as_deref(), Some("did not finish"));
+        assert!(RunReceipt::from_jsonl(&path).unwrap().total >= 2);
+    }
+}
@@ -0,0 +1,508 @@
+//! > 📚 Reference
+//!
+//! Redaction Rules
+//!
+//! One [`RedactionRuleSet`] scrubs secrets from everything the framework captures:
+//! snapshots, CLI scenario output, interactive session errors, telemetry exported by
+//! `TelemetryCapture`, and failure messages. Rules are configured once in
+//! `chicago-tdd-tools.toml`, so a secret redacted in one capture path cannot slip
+//! through another.
+//!
+//! Three kinds of rule are supported:
+//!
+//! - **Field**: replaces the value of a named key wherever it appears: JSON object keys,
+//!   telemetry attribute keys, and `name=value` / `name: value` pairs in text
+//!   (case-insensitive)
+//! - **Path**: replaces the value at a dot-notation JSON path (`.user.token`,
+//!   `.items.0.id`); the key is added if the parent object lacks it, matching the
+//!   v1.3.0 snapshot redactions
+//! - **Pattern**: replaces every regex match in text and JSON string values
+//!
+//! # Configuration
+//!
+//! ```toml
+//! [redaction]
+//! common = true  # built-in UUID, timestamp, token, and password rules
+//!
+//! [[redaction.rules]]
+//! field = "api_key"
+//!
+//! [[redaction.rules]]
+//! pattern = "sk_live_[A-Za-z0-9]+"
+//! replacement = "[STRIPE_KEY]"
+//!
+//! [[redaction.rules]]
+//! path = ".session.id"
+//! replacement = "[SESSION]"
+//! ```
+//!
+//! Rules without a `replacement` use [`DEFAULT_REPLACEMENT`]. Without a `[redaction]`
+//! section nothing is redacted.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::redaction::RedactionRuleSet;
+//!
+//! let rules = RedactionRuleSet::new()
+//!     .field("password", "[PASSWORD]")
+//!     .pattern(r"sk_live_\w+", "[STRIPE_KEY]")
+//!     .unwrap();
+//!
+//! assert_eq!(
+//!     rules.redact_text("login password=hunter2 key=sk_live_4eC39H"),
+//!     "login password=[PASSWORD] key=[STRIPE_KEY]"
+//! );
+//!
+//! let mut body = serde_json::json!({"user": {"password": "hunter2"}});
+//! rules.redact_json(&mut body);
+//! assert_eq!(body["user"]["password"], "[PASSWORD]");
+//! ```
+
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::Value;
+use std::borrow::Cow;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+/// Replacement used by rules that do not name their own
+pub const DEFAULT_REPLACEMENT: &str = "[REDACTED]";
+
+/// Redaction rule errors
+#[derive(Error, Debug)]
+pub enum RedactionError {
+    /// A pattern rule is not a valid regex
+    #[error("Invalid redaction pattern `{pattern}`: {reason}")]
+    InvalidPattern {
+        /// Offending pattern
+        pattern: String,
+        /// Regex compiler message
+        reason: String,
+    },
+    /// The `[redaction]` configuration is malformed
+    #[error("Invalid [redaction] configuration: {0}")]
+    InvalidConfig(String),
+}
+
+#[derive(Debug, Clone)]
+enum RedactionRule {
+    Field { name: String, replacement: String, in_text: Regex },
+    Path { segments: Vec<String>, replacement: String },
+    Pattern { regex: Regex, replacement: String },
+}
+
+/// > 📚 Reference
+///
+/// Ordered set of redaction rules applied to captured output.
+///
+/// Field rules run before pattern rules, so a field's replacement is not re-matched by
+/// a broader pattern.
+#[derive(Debug, Clone, Default)]
+pub struct RedactionRuleSet {
+    rules: Vec<RedactionRule>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RedactionConfig {
+    #[serde(default)]
+    common: bool,
+    #[serde(default)]
+    rules: Vec<RuleConfig>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleConfig {
+    field: Option<String>,
+    path: Option<String>,
+    pattern: Option<String>,
+    replacement: Option<String>,
+}
+
+impl RedactionRuleSet {
+    /// Create an empty rule set (redacts nothing)
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Built-in rules for common volatile and secret fields
+    ///
+    /// Top-level `id`/`uuid` become `[UUID]`, `timestamp`/`created_at`/`updated_at`
+    /// become `[TIMESTAMP]`, and `token`/`password`/`secret` become `[TOKEN]`,
+    /// `[PASSWORD]`, and `[SECRET]`.
+    #[must_use]
+    pub fn common() -> Self {
+        [
+            (".id", "[UUID]"),
+            (".uuid", "[UUID]"),
+            (".timestamp", "[TIMESTAMP]"),
+            (".created_at", "[TIMESTAMP]"),
+            (".updated_at", "[TIMESTAMP]"),
+            (".token", "[TOKEN]"),
+            (".password", "[PASSWORD]"),
+            (".secret", "[SECRET]"),
+        ]
+        .into_iter()
+        .fold(Self::new(), |rules, (path, replacement)| rules.path(path, replacement))
+    }
+
+    /// The rules configured in `chicago-tdd-tools.toml`, loaded once per process
+    ///
+    /// An invalid `[redaction]` section is logged and treated as empty.
+    #[must_use]
+    pub fn configured() -> &'static Self {
+        static CONFIGURED: OnceLock<RedactionRuleSet> = OnceLock::new();
+        CONFIGURED.get_or_init(crate::core::config::loading::redaction_rules)
+    }
+
+    /// Parse the `[redaction]` section of a `chicago-tdd-tools.toml` document
+    ///
+    /// Returns an empty set if the document has no `[redaction]` section.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RedactionError::InvalidConfig`] if the document is not valid TOML, the
+    /// section has unknown keys, or a rule does not set exactly one of `field`, `path`,
+    /// and `pattern`; returns [`RedactionError::InvalidPattern`] for a bad regex.
+    pub fn from_config_str(text: &str) -> Result<Self, RedactionError> {
+        let document: toml::Table =
+            text.parse().map_err(|e| RedactionError::InvalidConfig(format!("{e}")))?;
+        let Some(section) = document.get("redaction") else {
+            return Ok(Self::new());
+        };
+        let config: RedactionConfig = section
+            .clone()
+            .try_into()
+            .map_err(|e| RedactionError::InvalidConfig(format!("{e}")))?;
+
+        let mut rules = if config.common { Self::common() } else { Self::new() };
+        for (index, rule) in config.rules.into_iter().enumerate() {
+            let replacement = rule.replacement.as_deref().unwrap_or(DEFAULT_REPLACEMENT);
+            rules = match (rule.field, rule.path, rule.pattern) {
+                (Some(field), None, None) => rules.field(field, replacement),
+                (None, Some(path), None) => rules.path(&path, replacement),
+                (None, None, Some(pattern)) => rules.pattern(&pattern, replacement)?,
+                _ => {
+                    return Err(RedactionError::InvalidConfig(format!(
+                        "rule {} must set exactly one of `field`, `path`, or `pattern`",
+                        index + 1
+                    )))
+                }
+            };
+        }
+        Ok(rules)
+    }
+
+    /// Redact the value of every key named `name` (case-insensitive)
+    #[must_use]
+    pub fn field(mut self, name: impl Into<String>, replacement: impl Into<String>) -> Self {
+        let name = name.into();
+        let in_text = field_in_text_regex(&name);
+        self.rules
+            .push(RedactionRule::Field { name, replacement: replacement.into(), in_text });
+        self
+    }
+
+    /// Redact the JSON value at a dot-notation path (e.g. `.user.token`, `.items.0.id`)
+    #[must_use]
+    pub fn path(mut self, path: &str, replacement: impl Into<String>) -> Self {
+        let segments = path.trim_start_matches('.').split('.').map(str::to_string).collect();
+        self.rules
+            .push(RedactionRule::Path { segments, replacement: replacement.into() });
+        self
+    }
+
+    /// Redact every match of a regex
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RedactionError::InvalidPattern`] if `pattern` is not a valid regex.
+    pub fn pattern(
+        mut self,
+        pattern: &str,
+        replacement: impl Into<String>,
+    ) -> Result<Self, RedactionError> {
+        let regex = Regex::new(pattern).map_err(|e| RedactionError::InvalidPattern {
+            pattern: pattern.to_string(),
+            reason: e.to_string(),
+        })?;
+        self.rules
+            .push(RedactionRule::Pattern { regex, replacement: replacement.into() });
+        Ok(self)
+    }
+
+    /// Append another set's rules after this set's
+    #[must_use]
+    pub fn extend(mut self, other: &Self) -> Self {
+        self.rules.extend(other.rules.iter().cloned());
+        self
+    }
+
+    /// Number of rules
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Whether the set redacts nothing
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Redact captured text (CLI output, transcripts, failure messages)
+    ///
+    /// Field rules replace the value in `name=value` and `name: value` pairs (quoted or
+    /// not) up to the next whitespace, quote, comma, semicolon, `&`, or `}`; pattern
+    /// rules replace every match. Path rules do not apply to text.
+    #[must_use]
+    pub fn redact_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(text);
+        for rule in self.ordered() {
+            let replaced = match rule {
+                RedactionRule::Field { replacement, in_text, .. } => in_text
+                    .replace_all(&text, |caps: &regex::Captures<'_>| {
+                        format!("{}{replacement}", &caps[1])
+                    }),
+                RedactionRule::Pattern { regex, replacement } => {
+                    regex.replace_all(&text, regex::NoExpand(replacement))
+                }
+                RedactionRule::Path { .. } => continue,
+            };
+            if let Cow::Owned(replaced) = replaced {
+                text = Cow::Owned(replaced);
+            }
+        }
+        text
+    }
+
+    /// Redact a JSON value in place (snapshots, structured captures)
+    ///
+    /// Path rules apply at their path, field rules to matching object keys at any depth,
+    /// and pattern rules to every string value.
+    pub fn redact_json(&self, value: &mut Value) {
+        for rule in &self.rules {
+            if let RedactionRule::Path { segments, replacement } = rule {
+                let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+                set_json_path(value, &segments, Value::String(replacement.clone()));
+            }
+        }
+        self.redact_json_values(value);
+    }
+
+    /// Redact one key/value pair (telemetry attributes, headers)
+    ///
+    /// Returns the replacement if `key` matches a field rule, or the value with pattern
+    /// rules applied if any matched; `None` if nothing was redacted.
+    #[must_use]
+    pub fn redact_attribute(&self, key: &str, value: &str) -> Option<String> {
+        if let Some(replacement) = self.field_replacement(key) {
+            return Some(replacement.to_string());
+        }
+        match self.redact_patterns(value) {
+            Cow::Owned(redacted) => Some(redacted),
+            Cow::Borrowed(_) => None,
+        }
+    }
+
+    /// Replacement for a key matched by a field rule, if any
+    #[must_use]
+    pub fn field_replacement(&self, key: &str) -> Option<&str> {
+        self.rules.iter().find_map(|rule| match rule {
+            RedactionRule::Field { name, replacement, .. } if name.eq_ignore_ascii_case(key) => {
+                Some(replacement.as_str())
+            }
+            _ => None,
+        })
+    }
+
+    /// Field rules first, then patterns
+    fn ordered(&self) -> impl Iterator<Item = &RedactionRule> {
+        let fields = self.rules.iter().filter(|r| matches!(r, RedactionRule::Field { .. }));
+        fields.chain(self.rules.iter().filter(|r| !matches!(r, RedactionRule::Field { .. })))
+    }
+
+    fn redact_patterns<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut text = Cow::Borrowed(text);
+        for rule in &self.rules {
+            if let RedactionRule::Pattern { regex, replacement } = rule {
+                if let Cow::Owned(replaced) = regex.replace_all(&text, regex::NoExpand(replacement))
+                {
+                    text = Cow::Owned(replaced);
+                }
+            }
+        }
+        text
+    }
+
+    fn redact_json_values(&self, value: &mut Value) {
+        match value {
+            Value::Object(map) => {
+                for (key, nested) in map.iter_mut() {
+                    match self.field_replacement(key) {
+                        Some(replacement) => *nested = Value::String(replacement.to_string()),
+                        None => self.redact_json_values(nested),
+                    }
+                }
+            }
+            Value::Array(items) => items.iter_mut().for_each(|item| self.redact_json_values(item)),
+            Value::String(text) => {
+                if let Cow::Owned(redacted) = self.redact_patterns(text) {
+                    *text = redacted;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// `name` followed by `=` or `:`, optionally quoted; group 1 is everything up to the value
+fn field_in_text_regex(name: &str) -> Regex {
+    let pattern = format!(r#"(?i)(\b{}["']?\s*[=:]\s*["']?)[^\s"',;&}}]+"#, regex::escape(name));
+    #[allow(clippy::expect_used)] // The name is escaped, so the pattern is always valid
+    Regex::new(&pattern).expect("escaped field name forms a valid regex")
+}
+
+/// Set a value at a dot-notation path, adding the final key to an existing object
+fn set_json_path(value: &mut Value, path: &[&str], replacement: Value) {
+    let Some((first, rest)) = path.split_first() else {
+        return;
+    };
+    match value {
+        Value::Object(map) => {
+            if rest.is_empty() {
+                map.insert((*first).to_string(), replacement);
+            } else if let Some(nested) = map.get_mut(*first) {
+                set_json_path(nested, rest, replacement);
+            }
+        }
+        Value::Array(items) => {
+            if let Some(item) = first.parse::<usize>().ok().and_then(|i| items.get_mut(i)) {
+                if rest.is_empty() {
+                    *item = replacement;
+                } else {
+                    set_json_path(item, rest, replacement);
+                }
+            }
+        }
+        // Leaf values have no children to redact
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use serde_json::json;
+
+    test!(test_redact_text_fields_and_patterns, {
+        // Arrange
+        let rules = RedactionRuleSet::new()
+            .field("api_key", "[KEY]")
+            .pattern(r"\d{3}-\d{2}-\d{4}", "$SSN")
+            .unwrap();
+
+        // Act
+        let redacted = rules.redact_text(
+            "GET /v1?API_KEY=abc123&page=2\n{\"api_key\": \"abc123\"}\nssn 123-45-6789",
+        );
+
+        // Assert
+        assert_eq!(redacted, "GET /v1?API_KEY=[KEY]&page=2\n{\"api_key\": \"[KEY]\"}\nssn $SSN");
+        assert!(matches!(rules.redact_text("nothing to hide"), Cow::Borrowed(_)));
+    });
+
+    test!(test_redact_json_applies_every_rule_kind, {
+        // Arrange
+        let rules = RedactionRuleSet::new()
+            .path(".items.0.id", "[ID]")
+            .field("Token", "[TOKEN]")
+            .pattern("secret-[a-z]+", "[SECRET]")
+            .unwrap();
+        let mut value = json!({
+            "items": [{"id": 7, "token": "t1"}, {"id": 8, "note": "uses secret-abc"}],
+        });
+
+        // Act
+        rules.redact_json(&mut value);
+
+        // Assert
+        assert_eq!(
+            value,
+            json!({
+                "items": [{"id": "[ID]", "token": "[TOKEN]"}, {"id": 8, "note": "uses [SECRET]"}],
+            })
+        );
+    });
+
+    test!(test_redact_attribute, {
+        // Arrange
+        let rules = RedactionRuleSet::new()
+            .field("http.request.header.authorization", DEFAULT_REPLACEMENT)
+            .pattern("Bearer \\S+", "Bearer [REDACTED]")
+            .unwrap();
+
+        // Act & Assert
+        assert_eq!(
+            rules.redact_attribute("http.request.header.authorization", "Basic dXNlcg=="),
+            Some("[REDACTED]".to_string())
+        );
+        assert_eq!(
+            rules.redact_attribute("message", "sent Bearer abc.def"),
+            Some("sent Bearer [REDACTED]".to_string())
+        );
+        assert_eq!(rules.redact_attribute("http.route", "/orders"), None);
+    });
+
+    test!(test_from_config_str, {
+        // Arrange
+        let config = r#"
+            [test]
+            unit_timeout_seconds = 1
+
+            [redaction]
+            common = true
+
+            [[redaction.rules]]
+            field = "api_key"
+
+            [[redaction.rules]]
+            pattern = "sk_live_\\w+"
+            replacement = "[STRIPE_KEY]"
+        "#;
+
+        // Act
+        let rules = RedactionRuleSet::from_config_str(config).unwrap();
+
+        // Assert
+        assert_eq!(rules.len(), RedactionRuleSet::common().len() + 2);
+        assert_eq!(rules.redact_text("api_key=k1 sk_live_42"), "api_key=[REDACTED] [STRIPE_KEY]");
+    });
+
+    test!(test_from_config_str_rejects_ambiguous_rule, {
+        // Arrange
+        let config = "[[redaction.rules]]\nfield = \"a\"\npattern = \"b\"\n";
+
+        // Act
+        let error = RedactionRuleSet::from_config_str(config).unwrap_err();
+
+        // Assert
+        assert_eq!(
+            error.to_string(),
+            "Invalid [redaction] configuration: rule 1 must set exactly one of `field`, `path`, or `pattern`"
+        );
+    });
+
+    test!(test_shipped_config_redacts_nothing, {
+        // Act
+        let rules = RedactionRuleSet::from_config_str(include_str!("../../chicago-tdd-tools.toml"))
+            .unwrap();
+
+        // Assert
+        assert!(rules.is_empty());
+    });
+}
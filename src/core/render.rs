@@ -0,0 +1,833 @@
+//! Failure Output Rendering
+//!
+//! Pluggable rendering for alert and assertion-failure output. The default renderer
+//! adapts to where the output goes instead of assuming an emoji-capable, wide, colour
+//! terminal:
+//!
+//! - **Colour**: only on a terminal, never when `NO_COLOR` is set (<https://no-color.org>);
+//!   `CLICOLOR_FORCE=1` forces it on.
+//! - **Width**: long lines wrap to `COLUMNS` (default 100).
+//! - **ASCII-only**: `CHICAGO_TDD_ASCII=1` (or a `C`/`POSIX` locale) replaces emoji with
+//!   plain markers such as `[CRITICAL]`.
+//! - **Truncation**: huge values are cut around the first difference and long diffs are
+//!   collapsed; `CHICAGO_TDD_FULL_DIFF=1` shows everything.
+//! - **Structural diffs**: mismatched structs and collections (as `Debug` output) are
+//!   diffed field by field, showing only changed fields plus context
+//!   (see [`structural_diff`](crate::core::structural_diff)).
+//!
+//! Install a custom [`FailureRenderer`] with [`set_renderer`] to change the format
+//! (for example, machine-readable output for a CI system).
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::render::{AlertLevel, AlertText, DefaultRenderer, FailureRenderer, RenderOptions};
+//!
+//! let renderer = DefaultRenderer::new(RenderOptions { ascii: true, ..RenderOptions::plain() });
+//! let text = renderer.alert(&AlertText::new(AlertLevel::Critical, "Docker is not running").fix("Start Docker"));
+//!
+//! assert_eq!(text, "[CRITICAL] Docker is not running\n   -> FIX: Start Docker");
+//! ```
+
+use crate::core::structural_diff::{structural_diff, DebugTree, StructuralLineKind};
+use std::fmt::Write as _;
+use std::io::IsTerminal;
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// Default wrap width when `COLUMNS` is not set
+const DEFAULT_WIDTH: usize = 100;
+/// Narrowest width wrapping will use
+const MIN_WIDTH: usize = 40;
+/// Indentation of alert detail lines
+const DETAIL_INDENT: &str = "   ";
+/// Largest line-diff problem (left lines × right lines) solved exactly
+const MAX_DIFF_CELLS: usize = 4_000_000;
+
+/// Severity of an alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertLevel {
+    /// Must stop immediately
+    Critical,
+    /// Should stop and investigate
+    Warning,
+    /// Informational
+    Info,
+    /// Operation succeeded
+    Success,
+    /// Diagnostic detail
+    Debug,
+}
+
+/// Marker printed in front of an alert line
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Glyph {
+    /// Severity marker for a level
+    Level(AlertLevel),
+    /// "Cannot proceed" marker
+    Stop,
+    /// Suggested fix marker
+    Fix,
+    /// Follow-up action marker
+    Action,
+    /// Caller-supplied marker (e.g. an emoji passed to `alert!`)
+    Custom(String),
+}
+
+impl Glyph {
+    /// Marker text in the requested mode
+    #[must_use]
+    pub fn text(&self, ascii: bool) -> &str {
+        match self {
+            Self::Custom(marker) if !ascii => marker,
+            Self::Custom(marker) => Self::from_emoji(marker)
+                .and_then(|glyph| glyph.builtin_text(true))
+                .unwrap_or_else(|| ascii_fallback(marker)),
+            builtin => builtin.builtin_text(ascii).unwrap_or_default(),
+        }
+    }
+
+    const fn builtin_text(&self, ascii: bool) -> Option<&'static str> {
+        Some(match (self, ascii) {
+            (Self::Level(AlertLevel::Critical), false) => "🚨",
+            (Self::Level(AlertLevel::Critical), true) => "[CRITICAL]",
+            (Self::Level(AlertLevel::Warning) | Self::Stop, false) => "⚠️ ",
+            (Self::Level(AlertLevel::Warning), true) => "[WARNING]",
+            (Self::Level(AlertLevel::Info), false) => "ℹ️ ",
+            (Self::Level(AlertLevel::Info), true) => "[INFO]",
+            (Self::Level(AlertLevel::Success), false) => "✅",
+            (Self::Level(AlertLevel::Success), true) => "[OK]",
+            (Self::Level(AlertLevel::Debug), false) => "🔍",
+            (Self::Level(AlertLevel::Debug), true) => "[DEBUG]",
+            (Self::Stop, true) => "!!",
+            (Self::Fix, false) => "💡",
+            (Self::Fix, true) => "->",
+            (Self::Action, false) => "📋",
+            (Self::Action, true) => "-",
+            (Self::Custom(_), _) => return None,
+        })
+    }
+
+    /// Map one of the framework's emoji markers back to its glyph
+    #[must_use]
+    pub fn from_emoji(marker: &str) -> Option<Self> {
+        match marker.trim() {
+            "🚨" => Some(Self::Level(AlertLevel::Critical)),
+            "⚠️" | "⚠" => Some(Self::Level(AlertLevel::Warning)),
+            "ℹ️" | "ℹ" => Some(Self::Level(AlertLevel::Info)),
+            "✅" => Some(Self::Level(AlertLevel::Success)),
+            "🔍" => Some(Self::Level(AlertLevel::Debug)),
+            "💡" => Some(Self::Fix),
+            "📋" => Some(Self::Action),
+            _ => None,
+        }
+    }
+}
+
+const fn ascii_fallback(marker: &str) -> &str {
+    if marker.is_ascii() {
+        marker
+    } else {
+        "[ALERT]"
+    }
+}
+
+/// Structured alert content, rendered by a [`FailureRenderer`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlertText {
+    /// Headline marker
+    pub glyph: Glyph,
+    /// Headline message
+    pub message: String,
+    /// Detail lines with their markers
+    pub lines: Vec<(Glyph, String)>,
+    /// Whether to print the headline marker (loggers add their own)
+    pub headline_glyph: bool,
+}
+
+impl AlertText {
+    /// Create an alert for a standard level
+    #[must_use]
+    pub fn new(level: AlertLevel, message: impl Into<String>) -> Self {
+        Self::with_glyph(Glyph::Level(level), message)
+    }
+
+    /// Create an alert with a caller-supplied severity marker
+    #[must_use]
+    pub fn custom(severity: &str, message: impl Into<String>) -> Self {
+        Self::with_glyph(Glyph::Custom(severity.to_string()), message)
+    }
+
+    fn with_glyph(glyph: Glyph, message: impl Into<String>) -> Self {
+        Self { glyph, message: message.into(), lines: Vec::new(), headline_glyph: true }
+    }
+
+    /// Add a detail line
+    #[must_use]
+    pub fn line(mut self, glyph: Glyph, text: impl Into<String>) -> Self {
+        self.lines.push((glyph, text.into()));
+        self
+    }
+
+    /// Add a detail line marked with the headline's severity marker
+    #[must_use]
+    pub fn severity_line(self, text: impl Into<String>) -> Self {
+        let glyph = self.glyph.clone();
+        self.line(glyph, text)
+    }
+
+    /// Add a "cannot proceed" line
+    #[must_use]
+    pub fn stop(self, text: impl Into<String>) -> Self {
+        self.line(Glyph::Stop, text)
+    }
+
+    /// Add a `FIX:` line
+    #[must_use]
+    pub fn fix(self, fix: impl Into<String>) -> Self {
+        self.line(Glyph::Fix, format!("FIX: {}", fix.into()))
+    }
+
+    /// Add one line per item, all with the same marker
+    #[must_use]
+    pub fn lines<S: Into<String>>(self, glyph: &Glyph, items: impl IntoIterator<Item = S>) -> Self {
+        items.into_iter().fold(self, |alert, item| alert.line(glyph.clone(), item))
+    }
+
+    /// Add one line per follow-up action
+    #[must_use]
+    pub fn actions<S: Into<String>>(self, actions: impl IntoIterator<Item = S>) -> Self {
+        self.lines(&Glyph::Action, actions)
+    }
+
+    /// Omit the headline marker (for `log` records, whose logger adds one)
+    #[must_use]
+    pub const fn without_headline_glyph(mut self) -> Self {
+        self.headline_glyph = false;
+        self
+    }
+
+    /// Render with the installed renderer
+    #[must_use]
+    pub fn render(&self) -> String {
+        renderer().alert(self)
+    }
+}
+
+/// How much of a failing value or diff to show
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TruncationPolicy {
+    /// Longest single-line value shown; longer values are cut around the first difference
+    pub max_value_chars: Option<usize>,
+    /// Unchanged lines kept around each change in a multi-line diff
+    pub context_lines: usize,
+    /// Longest multi-line diff shown
+    pub max_diff_lines: Option<usize>,
+}
+
+impl TruncationPolicy {
+    /// Never truncate
+    #[must_use]
+    pub const fn full() -> Self {
+        Self { max_value_chars: None, context_lines: usize::MAX, max_diff_lines: None }
+    }
+}
+
+impl Default for TruncationPolicy {
+    fn default() -> Self {
+        Self { max_value_chars: Some(400), context_lines: 3, max_diff_lines: Some(200) }
+    }
+}
+
+/// Options for the [`DefaultRenderer`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Emit ANSI colour codes
+    pub color: bool,
+    /// Replace emoji markers with ASCII
+    pub ascii: bool,
+    /// Wrap lines to this many columns
+    pub width: usize,
+    /// Value and diff truncation
+    pub truncation: TruncationPolicy,
+}
+
+impl RenderOptions {
+    /// No colour, emoji markers, default width and truncation
+    #[must_use]
+    pub fn plain() -> Self {
+        Self {
+            color: false,
+            ascii: false,
+            width: DEFAULT_WIDTH,
+            truncation: TruncationPolicy::default(),
+        }
+    }
+
+    /// Detect options from the environment and whether stderr is a terminal
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_lookup(|name| std::env::var(name).ok(), std::io::stderr().is_terminal())
+    }
+
+    /// Detect options from `lookup` (environment variable reader) and terminal status
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>, is_terminal: bool) -> Self {
+        let set = |name: &str| lookup(name).is_some_and(|v| !v.is_empty() && v != "0");
+        let color = if lookup("NO_COLOR").is_some_and(|v| !v.is_empty()) {
+            false
+        } else if set("CLICOLOR_FORCE") {
+            true
+        } else {
+            is_terminal && lookup("TERM").as_deref() != Some("dumb")
+        };
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .find_map(|name| lookup(name).filter(|v| !v.is_empty()));
+        let ascii = set("CHICAGO_TDD_ASCII") || matches!(locale.as_deref(), Some("C" | "POSIX"));
+        let width = lookup("COLUMNS")
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .map_or(DEFAULT_WIDTH, |w| w.max(MIN_WIDTH));
+        let truncation = if set("CHICAGO_TDD_FULL_DIFF") {
+            TruncationPolicy::full()
+        } else {
+            TruncationPolicy::default()
+        };
+        Self { color, ascii, width, truncation }
+    }
+}
+
+/// Renders alerts and assertion mismatches to text
+///
+/// Implement this to customise failure output, then install it with [`set_renderer`].
+pub trait FailureRenderer: Send + Sync {
+    /// Render an alert
+    fn alert(&self, alert: &AlertText) -> String;
+
+    /// Render an equality failure between two (already formatted) values
+    fn mismatch(&self, label: &str, left: &str, right: &str) -> String;
+
+    /// Render an equality failure as a field-level diff of two `Debug` values
+    ///
+    /// Returns `None` (the default) when no structural rendering applies; callers then
+    /// fall back to [`FailureRenderer::mismatch`] or their own message.
+    fn structural_mismatch(&self, _label: &str, _left: &str, _right: &str) -> Option<String> {
+        None
+    }
+}
+
+/// ANSI styles used by the default renderer
+#[derive(Debug, Clone, Copy)]
+enum Style {
+    Red,
+    Yellow,
+    Green,
+    Cyan,
+    Dim,
+}
+
+impl Style {
+    const fn code(self) -> &'static str {
+        match self {
+            Self::Red => "\u{1b}[31m",
+            Self::Yellow => "\u{1b}[33m",
+            Self::Green => "\u{1b}[32m",
+            Self::Cyan => "\u{1b}[36m",
+            Self::Dim => "\u{1b}[2m",
+        }
+    }
+}
+
+/// Width-aware, `NO_COLOR`-compliant renderer used unless another is installed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DefaultRenderer {
+    options: RenderOptions,
+}
+
+impl DefaultRenderer {
+    /// Create a renderer with explicit options
+    #[must_use]
+    pub const fn new(options: RenderOptions) -> Self {
+        Self { options }
+    }
+
+    /// Create a renderer configured from the environment
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::new(RenderOptions::from_env())
+    }
+
+    /// Options in use
+    #[must_use]
+    pub const fn options(&self) -> &RenderOptions {
+        &self.options
+    }
+
+    fn paint(&self, style: Style, text: &str) -> String {
+        if self.options.color {
+            format!("{}{text}\u{1b}[0m", style.code())
+        } else {
+            text.to_string()
+        }
+    }
+
+    /// Prefix `text` with `marker`, wrapping to the configured width
+    fn marked_line(&self, indent: &str, marker: &str, text: &str) -> String {
+        let prefix =
+            if marker.is_empty() { indent.to_string() } else { format!("{indent}{marker} ") };
+        let available =
+            self.options.width.saturating_sub(display_width(&prefix)).max(MIN_WIDTH / 2);
+        let continuation = " ".repeat(display_width(&prefix));
+        text.lines()
+            .flat_map(|line| wrap(line, available))
+            .enumerate()
+            .map(
+                |(i, chunk)| {
+                    if i == 0 {
+                        format!("{prefix}{chunk}")
+                    } else {
+                        format!("{continuation}{chunk}")
+                    }
+                },
+            )
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    fn truncate_value(&self, value: &str, first_diff: usize) -> String {
+        let Some(max) = self.options.truncation.max_value_chars else {
+            return value.to_string();
+        };
+        let total = value.chars().count();
+        if total <= max {
+            return value.to_string();
+        }
+        let start = first_diff.saturating_sub(max / 2).min(total - max);
+        let shown: String = value.chars().skip(start).take(max).collect();
+        let before = if start > 0 { format!("…[{start} chars]…") } else { String::new() };
+        let after_count = total - start - max;
+        let after =
+            if after_count > 0 { format!("…[{after_count} chars]…") } else { String::new() };
+        format!("{before}{shown}{after}")
+    }
+
+    fn single_line_mismatch(&self, left: &str, right: &str) -> String {
+        let first_diff = left.chars().zip(right.chars()).take_while(|(l, r)| l == r).count();
+        let left = self.paint(Style::Red, &self.truncate_value(left, first_diff));
+        let right = self.paint(Style::Green, &self.truncate_value(right, first_diff));
+        format!("  left: `{left}`\n right: `{right}`")
+    }
+
+    fn multi_line_mismatch(&self, left: &str, right: &str) -> String {
+        let left: Vec<&str> = left.lines().collect();
+        let right: Vec<&str> = right.lines().collect();
+        let ops = diff_lines(&left, &right);
+        let context = self.options.truncation.context_lines;
+        let near_change = |index: usize| {
+            let lo = index.saturating_sub(context);
+            let hi = index.saturating_add(context).min(ops.len().saturating_sub(1));
+            ops[lo..=hi].iter().any(|op| !matches!(op, DiffOp::Same(_)))
+        };
+        let mut out =
+            vec![self.paint(Style::Red, "--- left"), self.paint(Style::Green, "+++ right")];
+        let mut hidden = 0_usize;
+        for (index, op) in ops.iter().enumerate() {
+            if matches!(op, DiffOp::Same(_)) && !near_change(index) {
+                hidden += 1;
+                continue;
+            }
+            if hidden > 0 {
+                out.push(self.paint(Style::Dim, &format!("  … {hidden} unchanged line(s) …")));
+                hidden = 0;
+            }
+            out.push(match op {
+                DiffOp::Same(line) => format!("  {line}"),
+                DiffOp::Removed(line) => self.paint(Style::Red, &format!("- {line}")),
+                DiffOp::Added(line) => self.paint(Style::Green, &format!("+ {line}")),
+            });
+        }
+        if hidden > 0 {
+            out.push(self.paint(Style::Dim, &format!("  … {hidden} unchanged line(s) …")));
+        }
+        self.finish_diff(out)
+    }
+
+    fn structural_diff_body(&self, left: &str, right: &str) -> Option<String> {
+        let (left, right) = (DebugTree::parse(left)?, DebugTree::parse(right)?);
+        if !left.is_composite() || !right.is_composite() {
+            return None;
+        }
+        let diff = structural_diff(&left, &right, self.options.truncation.context_lines);
+        if diff.changes == 0 {
+            return None;
+        }
+        let mut out = vec![
+            self.paint(Style::Red, "--- left"),
+            self.paint(Style::Green, "+++ right"),
+            self.paint(Style::Dim, &format!("  {} field(s)/item(s) differ", diff.changes)),
+        ];
+        out.extend(diff.lines.iter().map(|line| {
+            let text =
+                format!("{}{}", "    ".repeat(line.depth), self.truncate_value(&line.text, 0));
+            match line.kind {
+                StructuralLineKind::Same => format!("  {text}"),
+                StructuralLineKind::Removed => self.paint(Style::Red, &format!("- {text}")),
+                StructuralLineKind::Added => self.paint(Style::Green, &format!("+ {text}")),
+                StructuralLineKind::Collapsed => self.paint(Style::Dim, &format!("  {text}")),
+            }
+        }));
+        Some(self.finish_diff(out))
+    }
+
+    /// Apply the diff length limit and indent
+    fn finish_diff(&self, mut out: Vec<String>) -> String {
+        if let Some(max) = self.options.truncation.max_diff_lines {
+            if out.len() > max {
+                let cut = out.len() - max;
+                out.truncate(max);
+                out.push(format!(
+                    "  … {cut} more diff line(s) (set CHICAGO_TDD_FULL_DIFF=1 to show all)"
+                ));
+            }
+        }
+        out.iter().map(|line| format!("  {line}")).collect::<Vec<_>>().join("\n")
+    }
+}
+
+impl Default for DefaultRenderer {
+    fn default() -> Self {
+        Self::from_env()
+    }
+}
+
+impl FailureRenderer for DefaultRenderer {
+    fn alert(&self, alert: &AlertText) -> String {
+        let ascii = self.options.ascii;
+        let marker = if alert.headline_glyph { alert.glyph.text(ascii) } else { "" };
+        let headline = self.marked_line("", marker, &alert.message);
+        let style = match alert.glyph {
+            Glyph::Level(AlertLevel::Critical) => Some(Style::Red),
+            Glyph::Level(AlertLevel::Warning) => Some(Style::Yellow),
+            Glyph::Level(AlertLevel::Info) => Some(Style::Cyan),
+            Glyph::Level(AlertLevel::Success) => Some(Style::Green),
+            Glyph::Level(AlertLevel::Debug) => Some(Style::Dim),
+            _ => None,
+        };
+        let mut out = style.map_or_else(|| headline.clone(), |style| self.paint(style, &headline));
+        for (glyph, text) in &alert.lines {
+            let _ = write!(out, "\n{}", self.marked_line(DETAIL_INDENT, glyph.text(ascii), text));
+        }
+        out
+    }
+
+    fn mismatch(&self, label: &str, left: &str, right: &str) -> String {
+        if let Some(text) = self.structural_mismatch(label, left, right) {
+            return text;
+        }
+        let body = if left.contains('\n') || right.contains('\n') {
+            self.multi_line_mismatch(left, right)
+        } else {
+            self.single_line_mismatch(left, right)
+        };
+        format!("{label}\n{body}")
+    }
+
+    fn structural_mismatch(&self, label: &str, left: &str, right: &str) -> Option<String> {
+        self.structural_diff_body(left, right).map(|body| format!("{label}\n{body}"))
+    }
+}
+
+/// Installed renderer (`None` until first use or [`set_renderer`])
+static RENDERER: RwLock<Option<Arc<dyn FailureRenderer>>> = RwLock::new(None);
+
+/// The installed failure renderer (a [`DefaultRenderer`] from the environment by default)
+#[must_use]
+pub fn renderer() -> Arc<dyn FailureRenderer> {
+    let installed = RENDERER.read().unwrap_or_else(PoisonError::into_inner).clone();
+    installed.unwrap_or_else(|| {
+        let mut slot = RENDERER.write().unwrap_or_else(PoisonError::into_inner);
+        Arc::clone(slot.get_or_insert_with(|| Arc::new(DefaultRenderer::from_env())))
+    })
+}
+
+/// Install `renderer` for all subsequent alert and assertion output
+pub fn set_renderer(renderer: impl FailureRenderer + 'static) {
+    *RENDERER.write().unwrap_or_else(PoisonError::into_inner) = Some(Arc::new(renderer));
+}
+
+/// Go back to the environment-configured [`DefaultRenderer`]
+pub fn reset_renderer() {
+    *RENDERER.write().unwrap_or_else(PoisonError::into_inner) = None;
+}
+
+/// Render an equality failure with the installed renderer
+#[must_use]
+pub fn render_mismatch(label: &str, left: &str, right: &str) -> String {
+    renderer().mismatch(label, left, right)
+}
+
+/// Render a field-level diff of two `Debug` values with the installed renderer
+///
+/// `None` when the values are not composite `Debug` output (or the renderer has no
+/// structural form).
+#[must_use]
+pub fn render_structural_mismatch(label: &str, left: &str, right: &str) -> Option<String> {
+    renderer().structural_mismatch(label, left, right)
+}
+
+/// Approximate terminal column width (emoji count as two, variation selectors as zero)
+fn display_width(text: &str) -> usize {
+    text.chars()
+        .map(|c| match c {
+            '\u{FE00}'..='\u{FE0F}' | '\u{200D}' => 0,
+            '\u{1F000}'..='\u{1FAFF}' | '\u{2600}'..='\u{27BF}' => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Word-wrap one line to `width` columns (words longer than `width` are split)
+fn wrap(line: &str, width: usize) -> Vec<String> {
+    if display_width(line) <= width {
+        return vec![line.to_string()];
+    }
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    for word in line.split(' ') {
+        let needed =
+            display_width(&current) + usize::from(!current.is_empty()) + display_width(word);
+        if needed > width && !current.is_empty() {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(word);
+        while display_width(&current) > width {
+            let split: String = current.chars().take(width).collect();
+            current = current.chars().skip(width).collect();
+            lines.push(split);
+        }
+    }
+    lines.push(current);
+    lines
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffOp<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Line diff: exact LCS for small inputs, prefix/suffix trimming otherwise
+fn diff_lines<'a>(left: &[&'a str], right: &[&'a str]) -> Vec<DiffOp<'a>> {
+    let prefix = left.iter().zip(right).take_while(|(l, r)| l == r).count();
+    let suffix = left[prefix..]
+        .iter()
+        .rev()
+        .zip(right[prefix..].iter().rev())
+        .take_while(|(l, r)| l == r)
+        .count();
+    let (l_mid, r_mid) = (&left[prefix..left.len() - suffix], &right[prefix..right.len() - suffix]);
+    let mut ops: Vec<DiffOp<'a>> = left[..prefix].iter().map(|l| DiffOp::Same(l)).collect();
+    if l_mid.len().saturating_mul(r_mid.len()) > MAX_DIFF_CELLS {
+        ops.extend(l_mid.iter().map(|l| DiffOp::Removed(l)));
+        ops.extend(r_mid.iter().map(|r| DiffOp::Added(r)));
+    } else {
+        ops.extend(lcs_diff(l_mid, r_mid));
+    }
+    ops.extend(left[left.len() - suffix..].iter().map(|l| DiffOp::Same(l)));
+    ops
+}
+
+fn lcs_diff<'a>(left: &[&'a str], right: &[&'a str]) -> Vec<DiffOp<'a>> {
+    let (n, m) = (left.len(), right.len());
+    let mut table = vec![0_usize; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[i * (m + 1) + j] = if left[i] == right[j] {
+                table[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                table[(i + 1) * (m + 1) + j].max(table[i * (m + 1) + j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut ops = Vec::with_capacity(n + m);
+    while i < n && j < m {
+        if left[i] == right[j] {
+            ops.push(DiffOp::Same(left[i]));
+            i += 1;
+            j += 1;
+        } else if table[(i + 1) * (m + 1) + j] >= table[i * (m + 1) + j + 1] {
+            ops.push(DiffOp::Removed(left[i]));
+            i += 1;
+        } else {
+            ops.push(DiffOp::Added(right[j]));
+            j += 1;
+        }
+    }
+    ops.extend(left[i..].iter().map(|l| DiffOp::Removed(l)));
+    ops.extend(right[j..].iter().map(|r| DiffOp::Added(r)));
+    ops
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> =
+            vars.iter().map(|(k, v)| ((*k).to_string(), (*v).to_string())).collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    test!(test_options_honor_no_color_and_ascii, {
+        // Arrange & Act
+        let no_color = RenderOptions::from_lookup(lookup(&[("NO_COLOR", "1")]), true);
+        let forced = RenderOptions::from_lookup(lookup(&[("CLICOLOR_FORCE", "1")]), false);
+        let ci = RenderOptions::from_lookup(
+            lookup(&[("LANG", "C"), ("COLUMNS", "20"), ("CHICAGO_TDD_FULL_DIFF", "1")]),
+            false,
+        );
+        let tty = RenderOptions::from_lookup(lookup(&[("LANG", "en_US.UTF-8")]), true);
+
+        // Assert
+        assert!(!no_color.color);
+        assert!(forced.color);
+        assert!(!ci.color);
+        assert!(ci.ascii);
+        assert_eq!(ci.width, MIN_WIDTH);
+        assert_eq!(ci.truncation, TruncationPolicy::full());
+        assert!(tty.color);
+        assert!(!tty.ascii);
+        assert_eq!(tty.width, DEFAULT_WIDTH);
+    });
+
+    test!(test_alert_rendering_unicode_ascii_and_color, {
+        // Arrange
+        let alert = AlertText::new(AlertLevel::Critical, "Docker daemon is not running")
+            .stop("STOP: Cannot proceed")
+            .fix("Start Docker")
+            .actions(["docker info"]);
+        let unicode = DefaultRenderer::new(RenderOptions::plain());
+        let ascii = DefaultRenderer::new(RenderOptions { ascii: true, ..RenderOptions::plain() });
+        let color = DefaultRenderer::new(RenderOptions { color: true, ..RenderOptions::plain() });
+
+        // Act & Assert
+        assert_eq!(
+            unicode.alert(&alert),
+            "🚨 Docker daemon is not running\n   ⚠️  STOP: Cannot proceed\n   💡 FIX: Start Docker\n   📋 docker info"
+        );
+        let ascii_text = ascii.alert(&alert);
+        assert!(ascii_text.is_ascii());
+        assert!(ascii_text.starts_with("[CRITICAL] Docker daemon"));
+        assert!(ascii_text.contains("   !! STOP: Cannot proceed"));
+        assert!(color.alert(&alert).starts_with("\u{1b}[31m🚨"));
+        assert!(!unicode.alert(&alert).contains('\u{1b}'));
+        assert_eq!(ascii.alert(&AlertText::custom("🔥", "hot")), "[ALERT] hot");
+    });
+
+    test!(test_alert_wraps_to_width, {
+        // Arrange
+        let renderer = DefaultRenderer::new(RenderOptions {
+            width: 40,
+            ascii: true,
+            ..RenderOptions::plain()
+        });
+        let alert = AlertText::new(AlertLevel::Info, "word ".repeat(20).trim_end());
+
+        // Act
+        let text = renderer.alert(&alert);
+
+        // Assert
+        assert!(text.lines().count() > 1);
+        assert!(text.lines().all(|line| line.len() <= 40), "{text}");
+        assert!(text.lines().skip(1).all(|line| line.starts_with("       ")));
+    });
+
+    test!(test_single_line_mismatch_truncates_around_first_difference, {
+        // Arrange
+        let renderer = DefaultRenderer::new(RenderOptions {
+            truncation: TruncationPolicy {
+                max_value_chars: Some(20),
+                ..TruncationPolicy::default()
+            },
+            ..RenderOptions::plain()
+        });
+        let left = format!("{}X{}", "a".repeat(500), "b".repeat(500));
+        let right = format!("{}Y{}", "a".repeat(500), "b".repeat(500));
+
+        // Act
+        let text = renderer.mismatch("assertion failed", &left, &right);
+
+        // Assert
+        assert!(text.contains("…[490 chars]…aaaaaaaaaaXbbbbbbbbb…[491 chars]…"), "{text}");
+        assert!(text.contains("aaaaaaaaaaYbbbbbbbbb"));
+        assert!(text.len() < 200);
+    });
+
+    test!(test_multi_line_mismatch_collapses_unchanged_lines, {
+        // Arrange
+        let renderer = DefaultRenderer::new(RenderOptions::plain());
+        let left: String = (0..50).map(|i| format!("line {i}\n")).collect();
+        let right = left.replace("line 25\n", "line twenty-five\n");
+
+        // Act
+        let text = renderer.mismatch("assertion failed", &left, &right);
+
+        // Assert
+        assert!(text.contains("  - line 25\n  + line twenty-five"), "{text}");
+        assert!(text.contains("… 22 unchanged line(s) …"));
+        assert!(text.contains("… 21 unchanged line(s) …"));
+        assert_eq!(text.lines().count(), 1 + 2 + 1 + 3 + 2 + 3 + 1);
+    });
+
+    test!(test_struct_mismatch_renders_changed_fields_only, {
+        // Arrange
+        let renderer = DefaultRenderer::new(RenderOptions {
+            truncation: TruncationPolicy { context_lines: 1, ..TruncationPolicy::default() },
+            ..RenderOptions::plain()
+        });
+        let fields = |port: u16| {
+            let names = ["a", "b", "c", "d", "e", "f", "g", "h"];
+            let filler: Vec<String> = names.iter().map(|n| format!("{n}: \"{n}\"")).collect();
+            format!("Config {{ {}, net: Net {{ host: \"db\", port: {port} }} }}", filler.join(", "))
+        };
+
+        // Act
+        let text = renderer.mismatch("assertion failed", &fields(5432), &fields(5433));
+
+        // Assert
+        assert!(text.contains("  1 field(s)/item(s) differ"), "{text}");
+        assert!(text.contains("  -         port: 5432,\n  +         port: 5433,"), "{text}");
+        assert!(text.contains("… 7 unchanged field(s) …"), "{text}");
+        assert!(!text.contains("\"a\""), "{text}");
+        assert_eq!(renderer.structural_mismatch("x", "1", "2"), None);
+    });
+
+    test!(test_custom_renderer_is_pluggable, {
+        // Arrange
+        struct Terse;
+        impl FailureRenderer for Terse {
+            fn alert(&self, alert: &AlertText) -> String {
+                alert.message.clone()
+            }
+            fn mismatch(&self, label: &str, _: &str, _: &str) -> String {
+                label.to_string()
+            }
+        }
+
+        // Act
+        set_renderer(Terse);
+        let alert = AlertText::new(AlertLevel::Warning, "terse").fix("ignored").render();
+        let mismatch = render_mismatch("values differ", "1", "2");
+        reset_renderer();
+
+        // Assert
+        assert_eq!(alert, "terse");
+        assert_eq!(mismatch, "values differ");
+    });
+}
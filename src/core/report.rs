@@ -0,0 +1,379 @@
+//! > 📚 Reference
+//!
+//! Run Report Annotations
+//!
+//! Tests attach diagnostics to their entry in the run report with
+//! [`report!`](crate::report): key-value metrics, links (dashboards, trace URLs), and
+//! Markdown notes. Annotations are keyed by the running test (libtest names each test's
+//! thread after its path) and written as JSON and HTML after every call, so the report
+//! is complete even when the test binary exits early.
+//!
+//! Reports go to `report/<test binary>.{json,html}` under the artifacts directory
+//! resolved by [`ProjectLayout`] (`<target dir>/chicago-tdd` unless
+//! `CHICAGO_TDD_ARTIFACTS_DIR` or the `[paths]` config section says otherwise).
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::report;
+//!
+//! # let dashboard = "https://grafana.example.com/d/orders";
+//! let rows = 1_000;
+//! report!(rows = rows, p95_ms = 12.5, "db.pool" = "primary");
+//! report!(link "Grafana" = dashboard);
+//! report!(note = "Broker rejected the first batch; accepted after **one** retry.");
+//! ```
+
+use crate::core::layout::ProjectLayout;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+/// Entry name used when the current thread has no name
+const UNNAMED_TEST: &str = "<unnamed>";
+
+/// A titled link attached to a test
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReportLink {
+    /// Link text
+    pub title: String,
+    /// Target URL
+    pub url: String,
+}
+
+/// Everything one test attached to the report
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestAnnotations {
+    /// Metrics by key (numbers, strings, or any serializable value); later values replace
+    /// earlier ones
+    pub metrics: BTreeMap<String, serde_json::Value>,
+    /// Links, in the order attached
+    pub links: Vec<ReportLink>,
+    /// Markdown notes, in the order attached
+    pub notes: Vec<String>,
+}
+
+impl TestAnnotations {
+    /// Whether nothing has been attached
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.metrics.is_empty() && self.links.is_empty() && self.notes.is_empty()
+    }
+}
+
+/// > 📚 Reference
+///
+/// Annotations for one test run, keyed by test name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunReport {
+    /// Report name (the test binary for the process-wide report)
+    pub name: String,
+    /// Annotations by test name
+    pub tests: BTreeMap<String, TestAnnotations>,
+}
+
+impl RunReport {
+    /// Create an empty report
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), tests: BTreeMap::new() }
+    }
+
+    /// Annotations for `test`, created on first use
+    pub fn test_mut(&mut self, test: &str) -> &mut TestAnnotations {
+        self.tests.entry(test.to_string()).or_default()
+    }
+
+    /// Set a metric on `test`
+    pub fn metric(&mut self, test: &str, key: impl Into<String>, value: serde_json::Value) {
+        self.test_mut(test).metrics.insert(key.into(), value);
+    }
+
+    /// Attach a link to `test`
+    pub fn link(&mut self, test: &str, title: impl Into<String>, url: impl Into<String>) {
+        self.test_mut(test)
+            .links
+            .push(ReportLink { title: title.into(), url: url.into() });
+    }
+
+    /// Attach a Markdown note to `test`
+    pub fn note(&mut self, test: &str, markdown: impl Into<String>) {
+        self.test_mut(test).notes.push(markdown.into());
+    }
+
+    /// Render as pretty-printed JSON
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Render as a self-contained HTML page
+    ///
+    /// Only `http`/`https` links are clickable; notes are shown as preformatted Markdown.
+    #[must_use]
+    pub fn to_html(&self) -> String {
+        let title = escape_html(&self.name);
+        let mut html = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>{title} - test report</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+             <h1>{title}</h1>\n"
+        );
+        for (test, annotations) in &self.tests {
+            let _ = write!(html, "<section>\n<h2><code>{}</code></h2>\n", escape_html(test));
+            if !annotations.metrics.is_empty() {
+                html.push_str("<table>\n<tr><th>Metric</th><th>Value</th></tr>\n");
+                for (key, value) in &annotations.metrics {
+                    let value = value.as_str().map_or_else(|| value.to_string(), str::to_string);
+                    let _ = writeln!(
+                        html,
+                        "<tr><td>{}</td><td>{}</td></tr>",
+                        escape_html(key),
+                        escape_html(&value)
+                    );
+                }
+                html.push_str("</table>\n");
+            }
+            if !annotations.links.is_empty() {
+                html.push_str("<ul>\n");
+                for link in &annotations.links {
+                    let (title, url) = (escape_html(&link.title), escape_html(&link.url));
+                    if link.url.starts_with("http://") || link.url.starts_with("https://") {
+                        let _ = writeln!(html, "<li><a href=\"{url}\">{title}</a></li>");
+                    } else {
+                        let _ = writeln!(html, "<li>{title}: <code>{url}</code></li>");
+                    }
+                }
+                html.push_str("</ul>\n");
+            }
+            for note in &annotations.notes {
+                let _ = writeln!(html, "<pre class=\"note\">{}</pre>", escape_html(note));
+            }
+            html.push_str("</section>\n");
+        }
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// Write `<name>.json` and `<name>.html` into `dir`, returning their paths
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if `dir` cannot be created or a file cannot be written.
+    pub fn write_to(&self, dir: impl AsRef<Path>) -> std::io::Result<(PathBuf, PathBuf)> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        let stem: String = self
+            .name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+            .collect();
+        let json = dir.join(format!("{stem}.json"));
+        let html = dir.join(format!("{stem}.html"));
+        std::fs::write(&json, self.to_json())?;
+        std::fs::write(&html, self.to_html())?;
+        Ok((json, html))
+    }
+}
+
+const STYLE: &str = "body{font-family:sans-serif;margin:2em;max-width:60em}\
+section{border-top:1px solid #ccc;padding:.5em 0}\
+table{border-collapse:collapse}td,th{border:1px solid #ddd;padding:.2em .6em;text-align:left}\
+pre.note{background:#f6f8fa;padding:.6em;white-space:pre-wrap}";
+
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+// ============================================================================
+// Process-wide report used by `report!`
+// ============================================================================
+
+fn run_report() -> &'static Mutex<RunReport> {
+    static REPORT: OnceLock<Mutex<RunReport>> = OnceLock::new();
+    REPORT.get_or_init(|| {
+        let binary = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.file_stem().map(|stem| stem.to_string_lossy().into_owned()))
+            .unwrap_or_else(|| "tests".to_string());
+        Mutex::new(RunReport::new(binary))
+    })
+}
+
+/// Directory the process-wide report is written to
+#[must_use]
+pub fn report_dir() -> PathBuf {
+    ProjectLayout::detect().artifacts_dir().join("report")
+}
+
+/// Name of the running test (libtest names each test thread after the test path)
+#[must_use]
+pub fn current_test_name() -> String {
+    std::thread::current().name().unwrap_or(UNNAMED_TEST).to_string()
+}
+
+/// Snapshot of the process-wide report
+#[must_use]
+pub fn current_report() -> RunReport {
+    run_report().lock().unwrap_or_else(PoisonError::into_inner).clone()
+}
+
+/// Annotations the running test has attached so far
+#[must_use]
+pub fn current_annotations() -> TestAnnotations {
+    let test = current_test_name();
+    run_report()
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .tests
+        .get(&test)
+        .cloned()
+        .unwrap_or_default()
+}
+
+fn update(change: impl FnOnce(&mut RunReport, &str)) {
+    let test = current_test_name();
+    let mut report = run_report().lock().unwrap_or_else(PoisonError::into_inner);
+    change(&mut report, &test);
+    // Annotations are diagnostics: a report that cannot be written never fails the test
+    let dir = report_dir();
+    if let Err(e) = report.write_to(&dir) {
+        #[cfg(feature = "logging")]
+        log::warn!("Failed to write test report to {}: {e}", dir.display());
+        #[cfg(not(feature = "logging"))]
+        eprintln!("Warning: Failed to write test report to {}: {e}", dir.display());
+    }
+}
+
+/// Record a metric for the running test (used by [`report!`](crate::report))
+pub fn record_metric<T: Serialize + ?Sized>(key: &str, value: &T) {
+    let value = serde_json::to_value(value)
+        .unwrap_or_else(|e| serde_json::Value::String(format!("<unserializable: {e}>")));
+    update(|report, test| report.metric(test, key, value));
+}
+
+/// Record a link for the running test (used by [`report!`](crate::report))
+pub fn record_link(title: &str, url: impl Display) {
+    update(|report, test| report.link(test, title, url.to_string()));
+}
+
+/// Record a Markdown note for the running test (used by [`report!`](crate::report))
+pub fn record_note(markdown: impl Display) {
+    update(|report, test| report.note(test, markdown.to_string()));
+}
+
+/// Attach metrics, links, and notes to the running test's report entry
+///
+/// Entries are comma-separated and can be mixed:
+///
+/// - `key = value` or `"dotted.key" = value`: a metric (any `Serialize` value)
+/// - `link "Title" = url`: a link (any `Display` value)
+/// - `note = markdown`: a Markdown note (any `Display` value)
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::core::report::current_annotations;
+/// use chicago_tdd_tools::report;
+///
+/// # let trace_id = "4bf92f3577b34da6a3ce929d0e0e4736";
+/// report!(
+///     retries = 2,
+///     link "Trace" = format!("https://jaeger.example.com/trace/{trace_id}"),
+///     note = "Second attempt hit the **warm** cache",
+/// );
+///
+/// let annotations = current_annotations();
+/// assert_eq!(annotations.metrics["retries"], 2);
+/// assert_eq!(annotations.links[0].title, "Trace");
+/// ```
+#[macro_export]
+macro_rules! report {
+    () => {};
+    (link $title:literal = $url:expr $(, $($rest:tt)*)?) => {
+        $crate::core::report::record_link($title, &$url);
+        $($crate::report!($($rest)*);)?
+    };
+    (note = $note:expr $(, $($rest:tt)*)?) => {
+        $crate::core::report::record_note(&$note);
+        $($crate::report!($($rest)*);)?
+    };
+    ($key:ident = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::core::report::record_metric(stringify!($key), &$value);
+        $($crate::report!($($rest)*);)?
+    };
+    ($key:literal = $value:expr $(, $($rest:tt)*)?) => {
+        $crate::core::report::record_metric($key, &$value);
+        $($crate::report!($($rest)*);)?
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_report_macro_annotates_current_test, {
+        // Act
+        report!(rows = 1_000, "db.pool" = "primary", p95_ms = 12.5);
+        report!(link "Grafana" = "https://grafana.example.com/d/orders", note = "**slow** path");
+        report!(rows = 2_000);
+
+        // Assert
+        let annotations = current_annotations();
+        assert_eq!(annotations.metrics["rows"], 2_000);
+        assert_eq!(annotations.metrics["db.pool"], "primary");
+        assert_eq!(annotations.metrics["p95_ms"], 12.5);
+        assert_eq!(annotations.links[0].url, "https://grafana.example.com/d/orders");
+        assert_eq!(annotations.notes, ["**slow** path"]);
+        let entry = &current_report().tests[&current_test_name()];
+        assert_eq!(entry, &annotations);
+    });
+
+    test!(test_html_escapes_and_only_links_http, {
+        // Arrange
+        let mut report = RunReport::new("orders");
+        report.metric("t", "query", serde_json::json!("<select>"));
+        report.link("t", "Trace", "https://trace.example.com/?a=1&b=2");
+        report.link("t", "Local", "javascript:alert(1)");
+        report.note("t", "<b>raw</b>");
+
+        // Act
+        let html = report.to_html();
+
+        // Assert
+        assert!(html.contains("<td>query</td><td>&lt;select&gt;</td>"), "{html}");
+        assert!(html.contains("<a href=\"https://trace.example.com/?a=1&amp;b=2\">Trace</a>"));
+        assert!(html.contains("<li>Local: <code>javascript:alert(1)</code></li>"));
+        assert!(html.contains("<pre class=\"note\">&lt;b&gt;raw&lt;/b&gt;</pre>"));
+    });
+
+    test!(test_write_to_roundtrips_json, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut report = RunReport::new("orders-1a2b");
+        report.metric("orders::test_checkout", "latency_ms", serde_json::json!(41));
+
+        // Act
+        let (json, html) = report.write_to(dir.path()).unwrap();
+
+        // Assert
+        assert_eq!(json, dir.path().join("orders-1a2b.json"));
+        assert!(html.exists());
+        let parsed: RunReport =
+            serde_json::from_str(&std::fs::read_to_string(json).unwrap()).unwrap();
+        assert_eq!(parsed, report);
+    });
+}
@@ -0,0 +1,476 @@
+//! > 📚 Reference
+//!
+//! CI Reporters
+//!
+//! Reporters turn test results into formats CI UIs understand: [`JUnitReporter`] writes
+//! `JUnit` XML (GitLab, Jenkins, and most test-report actions read it),
+//! [`GithubAnnotationsReporter`] emits `::error` workflow commands that GitHub Actions shows
+//! inline on the diff, and [`SummaryReporter`] renders a table for humans. Every reporter
+//! implements [`Reporter`], so custom formats plug in alongside them.
+//!
+//! Reporters consume [`TestEvent`]s, which convert from the per-test receipts written by
+//! the receipt recorder (see [`crate::core::receipt::recorder`]). A typical CI job records
+//! receipts during `cargo test` and renders them afterwards:
+//!
+//! ```bash
+//! CHICAGO_TDD_RECEIPTS=target/receipts.jsonl cargo test
+//! ```
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::receipt::TestOutcome;
+//! use chicago_tdd_tools::core::reporting::{GithubAnnotationsReporter, JUnitReporter, Reporter, TestEvent};
+//! use std::time::Duration;
+//!
+//! let events = vec![
+//!     TestEvent::new("orders::test_create", TestOutcome::Pass, Duration::from_millis(3)),
+//!     TestEvent::new("orders::test_cancel", TestOutcome::Fail, Duration::from_millis(1))
+//!         .with_message("expected Cancelled, got Open")
+//!         .at("tests/orders.rs", 42),
+//! ];
+//!
+//! let xml = JUnitReporter::new("orders").render(&events);
+//! assert!(xml.contains(r#"<testsuite name="orders" tests="2" failures="1""#));
+//!
+//! let annotations = GithubAnnotationsReporter.render(&events);
+//! assert!(annotations.starts_with("::error file=tests/orders.rs,line=42,"));
+//! ```
+
+use crate::core::receipt::{read_receipts, TestExecutionReceipt, TestOutcome};
+use crate::core::report::escape_html;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+/// Outcome of one test, as reporters see it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestEvent {
+    /// Fully qualified test name (`module::path::test_name`)
+    pub name: String,
+    /// How the test ended
+    pub outcome: TestOutcome,
+    /// Wall-clock duration
+    pub duration: Duration,
+    /// Framework assertions executed
+    pub assertions: u64,
+    /// Failure or skip reason
+    pub message: Option<String>,
+    /// Source file declaring the test
+    pub file: Option<String>,
+    /// Line of the test declaration in `file`
+    pub line: Option<u32>,
+}
+
+impl TestEvent {
+    /// Event for `name` with no message or location
+    #[must_use]
+    pub fn new(name: impl Into<String>, outcome: TestOutcome, duration: Duration) -> Self {
+        Self {
+            name: name.into(),
+            outcome,
+            duration,
+            assertions: 0,
+            message: None,
+            file: None,
+            line: None,
+        }
+    }
+
+    /// Attach a failure or skip reason
+    #[must_use]
+    pub fn with_message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Set the number of assertions executed
+    #[must_use]
+    pub const fn with_assertions(mut self, assertions: u64) -> Self {
+        self.assertions = assertions;
+        self
+    }
+
+    /// Attach the source location of the test
+    #[must_use]
+    pub fn at(mut self, file: impl Into<String>, line: u32) -> Self {
+        self.file = Some(file.into());
+        self.line = Some(line);
+        self
+    }
+
+    /// Module path of the test (`orders` for `orders::test_create`)
+    #[must_use]
+    pub fn suite(&self) -> &str {
+        self.name.rsplit_once("::").map_or("", |(suite, _)| suite)
+    }
+
+    /// Test name without its module path
+    #[must_use]
+    pub fn short_name(&self) -> &str {
+        self.name.rsplit_once("::").map_or(self.name.as_str(), |(_, name)| name)
+    }
+
+    /// Whether the test failed or errored
+    #[must_use]
+    pub const fn is_failure(&self) -> bool {
+        matches!(self.outcome, TestOutcome::Fail | TestOutcome::Error)
+    }
+
+    /// Load events from a receipts JSON lines file
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the file cannot be read or a line is not a receipt.
+    pub fn from_receipts_file(path: impl AsRef<Path>) -> io::Result<Vec<Self>> {
+        Ok(read_receipts(path)?.into_iter().map(Self::from).collect())
+    }
+}
+
+impl From<TestExecutionReceipt> for TestEvent {
+    fn from(receipt: TestExecutionReceipt) -> Self {
+        Self {
+            name: receipt.test_name,
+            outcome: receipt.outcome,
+            duration: Duration::from_nanos(receipt.duration_ns),
+            assertions: receipt.assertions,
+            message: receipt.failure,
+            file: receipt.file,
+            line: receipt.line,
+        }
+    }
+}
+
+impl From<&TestExecutionReceipt> for TestEvent {
+    fn from(receipt: &TestExecutionReceipt) -> Self {
+        Self::from(receipt.clone())
+    }
+}
+
+/// Renders test events in one output format
+pub trait Reporter {
+    /// Render `events`
+    fn render(&self, events: &[TestEvent]) -> String;
+
+    /// Render `events` into the file at `path`, creating parent directories
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O error if the directory or file cannot be written.
+    fn write_to(&self, events: &[TestEvent], path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.render(events))
+    }
+}
+
+/// Pass/fail/error/skip counts and total time for a set of events
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Tally {
+    tests: usize,
+    failures: usize,
+    errors: usize,
+    skipped: usize,
+    time: Duration,
+}
+
+impl Tally {
+    fn of<'a>(events: impl IntoIterator<Item = &'a TestEvent>) -> Self {
+        events.into_iter().fold(Self::default(), |mut tally, event| {
+            tally.tests += 1;
+            match event.outcome {
+                TestOutcome::Pass => {}
+                TestOutcome::Fail => tally.failures += 1,
+                TestOutcome::Error => tally.errors += 1,
+                TestOutcome::Skip => tally.skipped += 1,
+            }
+            tally.time += event.duration;
+            tally
+        })
+    }
+
+    fn attributes(&self) -> String {
+        format!(
+            "tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.3}\"",
+            self.tests,
+            self.failures,
+            self.errors,
+            self.skipped,
+            self.time.as_secs_f64()
+        )
+    }
+}
+
+/// `JUnit` XML, one `<testsuite>` per module path
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JUnitReporter {
+    name: String,
+}
+
+impl JUnitReporter {
+    /// Reporter naming the `<testsuites>` root `name`
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl Reporter for JUnitReporter {
+    fn render(&self, events: &[TestEvent]) -> String {
+        let mut suites: BTreeMap<&str, Vec<&TestEvent>> = BTreeMap::new();
+        for event in events {
+            suites.entry(event.suite()).or_default().push(event);
+        }
+
+        let mut xml = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"{}\" {}>\n",
+            xml_escape(&self.name),
+            Tally::of(events).attributes()
+        );
+        for (suite, events) in suites {
+            let suite = if suite.is_empty() { self.name.as_str() } else { suite };
+            let _ = writeln!(
+                xml,
+                "  <testsuite name=\"{}\" {}>",
+                xml_escape(suite),
+                Tally::of(events.iter().copied()).attributes()
+            );
+            for event in events {
+                let _ = write!(
+                    xml,
+                    "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\"",
+                    xml_escape(event.short_name()),
+                    xml_escape(suite),
+                    event.duration.as_secs_f64()
+                );
+                if let Some(file) = &event.file {
+                    let _ = write!(xml, " file=\"{}\"", xml_escape(file));
+                }
+                if let Some(line) = event.line {
+                    let _ = write!(xml, " line=\"{line}\"");
+                }
+                let element = match event.outcome {
+                    TestOutcome::Pass => {
+                        xml.push_str("/>\n");
+                        continue;
+                    }
+                    TestOutcome::Fail => "failure",
+                    TestOutcome::Error => "error",
+                    TestOutcome::Skip => "skipped",
+                };
+                xml.push_str(">\n");
+                match &event.message {
+                    Some(message) => {
+                        let summary = message.lines().next().unwrap_or_default();
+                        let _ = writeln!(
+                            xml,
+                            "      <{element} message=\"{}\">{}</{element}>",
+                            xml_escape(summary),
+                            xml_escape(message)
+                        );
+                    }
+                    None => {
+                        let _ = writeln!(xml, "      <{element}/>");
+                    }
+                }
+                xml.push_str("    </testcase>\n");
+            }
+            xml.push_str("  </testsuite>\n");
+        }
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+}
+
+/// Escape text for XML content or attributes, dropping characters XML 1.0 forbids
+fn xml_escape(text: &str) -> String {
+    let allowed: String = text
+        .chars()
+        .filter(|c| !c.is_control() || matches!(c, '\t' | '\n' | '\r'))
+        .collect();
+    escape_html(&allowed)
+}
+
+/// GitHub Actions `::error` annotations, one per failed or errored test
+///
+/// Print the output from a workflow step and GitHub attaches each failure to its file and
+/// line. Tests without a recorded location are annotated on the run instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GithubAnnotationsReporter;
+
+impl Reporter for GithubAnnotationsReporter {
+    fn render(&self, events: &[TestEvent]) -> String {
+        let mut output = String::new();
+        for event in events.iter().filter(|event| event.is_failure()) {
+            let mut properties = Vec::new();
+            if let Some(file) = &event.file {
+                properties.push(format!("file={}", escape_property(file)));
+            }
+            if let Some(line) = event.line {
+                properties.push(format!("line={line}"));
+            }
+            properties.push(format!("title={}", escape_property(&event.name)));
+            let message = event
+                .message
+                .clone()
+                .unwrap_or_else(|| format!("{} ({})", event.name, event.outcome));
+            let _ = writeln!(output, "::error {}::{}", properties.join(","), escape_data(&message));
+        }
+        output
+    }
+}
+
+/// Escape a workflow command message
+fn escape_data(text: &str) -> String {
+    text.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value
+fn escape_property(text: &str) -> String {
+    escape_data(text).replace(':', "%3A").replace(',', "%2C")
+}
+
+/// Human-readable results table with a totals line and failure details
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SummaryReporter;
+
+impl Reporter for SummaryReporter {
+    fn render(&self, events: &[TestEvent]) -> String {
+        let width = events.iter().map(|event| event.name.chars().count()).max().unwrap_or(0);
+        let width = width.max("TEST".len());
+        let mut output = format!(
+            "{:<8}  {:<width$}  {:>10}  {:>10}\n",
+            "STATUS", "TEST", "DURATION", "ASSERTIONS"
+        );
+        for event in events {
+            let status = match event.outcome {
+                TestOutcome::Pass => "✅ pass",
+                TestOutcome::Fail => "❌ fail",
+                TestOutcome::Error => "💥 error",
+                TestOutcome::Skip => "⏭️ skip",
+            };
+            let _ = writeln!(
+                output,
+                "{status:<8}  {:<width$}  {:>10}  {:>10}",
+                event.name,
+                format!("{:?}", event.duration),
+                event.assertions
+            );
+        }
+
+        let tally = Tally::of(events);
+        let _ = writeln!(
+            output,
+            "\n{} tests: {} passed, {} failed, {} errors, {} skipped ({:?})",
+            tally.tests,
+            tally.tests - tally.failures - tally.errors - tally.skipped,
+            tally.failures,
+            tally.errors,
+            tally.skipped,
+            tally.time
+        );
+        for event in events.iter().filter(|event| event.is_failure()) {
+            let _ = write!(output, "\n{}", event.name);
+            if let (Some(file), Some(line)) = (&event.file, event.line) {
+                let _ = write!(output, " ({file}:{line})");
+            }
+            output.push('\n');
+            if let Some(message) = &event.message {
+                for line in message.lines() {
+                    let _ = writeln!(output, "    {line}");
+                }
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn events() -> Vec<TestEvent> {
+        vec![
+            TestEvent::new("orders::test_create", TestOutcome::Pass, Duration::from_millis(3)),
+            TestEvent::new("orders::test_cancel", TestOutcome::Fail, Duration::from_millis(2))
+                .with_message("expected <Cancelled>\ngot Open, 100%")
+                .at("tests/orders.rs", 42),
+            TestEvent::new("billing::test_refund", TestOutcome::Error, Duration::from_millis(1)),
+            TestEvent::new("test_skipped", TestOutcome::Skip, Duration::ZERO),
+        ]
+    }
+
+    test!(test_junit_groups_suites_and_escapes_messages, {
+        // Act
+        let xml = JUnitReporter::new("run").render(&events());
+
+        // Assert
+        assert!(xml.contains(
+            r#"<testsuites name="run" tests="4" failures="1" errors="1" skipped="1" time="0.006">"#
+        ));
+        assert!(xml.contains(
+            r#"<testsuite name="billing" tests="1" failures="0" errors="1" skipped="0" time="0.001">"#
+        ));
+        assert!(xml.contains(
+            "<testcase name=\"test_cancel\" classname=\"orders\" time=\"0.002\" file=\"tests/orders.rs\" line=\"42\">\n      \
+             <failure message=\"expected &lt;Cancelled&gt;\">expected &lt;Cancelled&gt;\ngot Open, 100%</failure>"
+        ));
+        assert!(xml.contains("<error/>"));
+        assert!(
+            xml.contains(r#"<testsuite name="run" tests="1" failures="0" errors="0" skipped="1""#)
+        );
+        assert!(xml.contains(r#"<testcase name="test_create" classname="orders" time="0.003"/>"#));
+    });
+
+    test!(test_github_annotations_cover_failures_only, {
+        // Act
+        let annotations = GithubAnnotationsReporter.render(&events());
+
+        // Assert
+        assert_eq!(
+            annotations,
+            "::error file=tests/orders.rs,line=42,title=orders%3A%3Atest_cancel::expected <Cancelled>%0Agot Open, 100%25\n\
+             ::error title=billing%3A%3Atest_refund::billing::test_refund (ERROR)\n"
+        );
+    });
+
+    test!(test_summary_tallies_and_lists_failures, {
+        // Act
+        let summary = SummaryReporter.render(&events());
+
+        // Assert
+        assert!(summary.contains("4 tests: 1 passed, 1 failed, 1 errors, 1 skipped (6ms)"));
+        assert!(summary.contains("\norders::test_cancel (tests/orders.rs:42)\n    expected <Cancelled>\n    got Open, 100%\n"));
+        assert!(summary.lines().nth(1).is_some_and(|line| line.starts_with("✅ pass")));
+    });
+
+    test!(test_events_convert_from_receipts, {
+        // Arrange
+        let receipt = TestExecutionReceipt {
+            test_name: "orders::test_cancel".to_string(),
+            outcome: TestOutcome::Fail,
+            duration_ns: 1_500,
+            phases: None,
+            assertions: 3,
+            fixtures: Vec::new(),
+            failure: Some("boom".to_string()),
+            file: Some("tests/orders.rs".to_string()),
+            line: Some(7),
+            timestamp: 0,
+        };
+
+        // Act
+        let event = TestEvent::from(&receipt);
+
+        // Assert
+        assert_eq!(
+            event,
+            TestEvent::new("orders::test_cancel", TestOutcome::Fail, Duration::from_nanos(1_500))
+                .with_message("boom")
+                .at("tests/orders.rs", 7)
+                .with_assertions(3)
+        );
+    });
+}
@@ -0,0 +1,361 @@
+//! Test Environment Requirements
+//!
+//! Declares what a test module needs from its environment (env vars, binaries on `PATH`,
+//! listening services, free ports) and checks all of it up front. A missing
+//! `DATABASE_URL` then produces one consolidated, actionable report instead of dozens of
+//! unrelated-looking test failures.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::requirements;
+//!
+//! requirements! {
+//!     env: ["DATABASE_URL"],
+//!     bin: ["weaver"],
+//!     port: [5432],
+//! }
+//!
+//! #[test]
+//! fn test_migrations_apply() {
+//!     check_requirements();
+//!     // ...
+//! }
+//! ```
+//!
+//! The first test to call `check_requirements()` fails with the full report; the rest of
+//! the module's tests fail with a one-line pointer to it.
+
+use crate::core::messages::{message, MessageId};
+use std::fmt;
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Connect timeout when probing a required port
+const PORT_PROBE_TIMEOUT: Duration = Duration::from_millis(250);
+
+/// A single requirement that is not satisfied
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnmetRequirement {
+    /// Environment variable is unset or empty
+    EnvVar(String),
+    /// Binary is not an executable on `PATH` (or at the given path)
+    Binary(String),
+    /// Nothing accepts connections on `127.0.0.1:<port>`
+    PortNotListening(u16),
+    /// `127.0.0.1:<port>` is already bound by another process
+    PortInUse(u16),
+}
+
+impl fmt::Display for UnmetRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EnvVar(name) => {
+                write!(f, "env var `{name}` is not set\n     💡 FIX: export {name}=<value>")
+            }
+            Self::Binary(name) => write!(
+                f,
+                "binary `{name}` not found on PATH\n     💡 FIX: install `{name}` or add its directory to PATH"
+            ),
+            Self::PortNotListening(port) => write!(
+                f,
+                "nothing is listening on 127.0.0.1:{port}\n     💡 FIX: start the service expected on port {port}"
+            ),
+            Self::PortInUse(port) => write!(
+                f,
+                "port {port} is already in use\n     💡 FIX: stop the process bound to port {port}"
+            ),
+        }
+    }
+}
+
+/// Requirements errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RequirementsError {
+    /// One or more requirements are not satisfied
+    #[error("{}", message(MessageId::REQUIREMENTS_UNMET, &[("count", &.0.len()), ("requirements", &.0.iter().map(|r| format!("   - {r}")).collect::<Vec<_>>().join("\n"))]))]
+    Unmet(Vec<UnmetRequirement>),
+}
+
+/// Result type for requirement checks
+pub type RequirementsResult<T> = Result<T, RequirementsError>;
+
+/// Declared environment requirements
+///
+/// Usually built by the [`requirements!`](crate::requirements) macro.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Requirements {
+    env: Vec<String>,
+    bins: Vec<String>,
+    ports: Vec<u16>,
+    free_ports: Vec<u16>,
+}
+
+impl Requirements {
+    /// Create an empty requirement set
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Require a non-empty environment variable
+    #[must_use]
+    pub fn env(mut self, name: impl Into<String>) -> Self {
+        self.env.push(name.into());
+        self
+    }
+
+    /// Require an executable on `PATH` (or at a path containing a separator)
+    #[must_use]
+    pub fn bin(mut self, name: impl Into<String>) -> Self {
+        self.bins.push(name.into());
+        self
+    }
+
+    /// Require a service listening on `127.0.0.1:<port>`
+    #[must_use]
+    pub fn port(mut self, port: u16) -> Self {
+        self.ports.push(port);
+        self
+    }
+
+    /// Require `127.0.0.1:<port>` to be free for the tests to bind
+    #[must_use]
+    pub fn free_port(mut self, port: u16) -> Self {
+        self.free_ports.push(port);
+        self
+    }
+
+    /// Check every requirement and report all failures together
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RequirementsError::Unmet`] listing each unmet requirement.
+    pub fn check(&self) -> RequirementsResult<()> {
+        let env = self
+            .env
+            .iter()
+            .filter(|name| std::env::var_os(name).is_none_or(|value| value.is_empty()))
+            .map(|name| UnmetRequirement::EnvVar(name.clone()));
+        let bins = self
+            .bins
+            .iter()
+            .filter(|name| find_executable(name).is_none())
+            .map(|name| UnmetRequirement::Binary(name.clone()));
+        let ports = self
+            .ports
+            .iter()
+            .filter(|port| !is_listening(**port))
+            .map(|port| UnmetRequirement::PortNotListening(*port));
+        let free_ports = self
+            .free_ports
+            .iter()
+            .filter(|port| TcpListener::bind((Ipv4Addr::LOCALHOST, **port)).is_err())
+            .map(|port| UnmetRequirement::PortInUse(*port));
+        let unmet: Vec<_> = env.chain(bins).chain(ports).chain(free_ports).collect();
+        if unmet.is_empty() {
+            Ok(())
+        } else {
+            Err(RequirementsError::Unmet(unmet))
+        }
+    }
+}
+
+fn is_listening(port: u16) -> bool {
+    TcpStream::connect_timeout(&SocketAddr::from((Ipv4Addr::LOCALHOST, port)), PORT_PROBE_TIMEOUT)
+        .is_ok()
+}
+
+/// Locate an executable by name on `PATH`, or verify an explicit path
+#[must_use]
+pub fn find_executable(name: &str) -> Option<PathBuf> {
+    let candidate = Path::new(name);
+    if candidate.components().count() > 1 {
+        return is_executable(candidate).then(|| candidate.to_path_buf());
+    }
+    let path = std::env::var_os("PATH")?;
+    std::env::split_paths(&path).find_map(|dir| {
+        let extensions: &[&str] = if cfg!(windows) { &["exe", "cmd", "bat"] } else { &[] };
+        std::iter::once(dir.join(name))
+            .chain(extensions.iter().map(|ext| dir.join(name).with_extension(ext)))
+            .find(|path| is_executable(path))
+    })
+}
+
+fn is_executable(path: &Path) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        path.metadata()
+            .is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+    }
+    #[cfg(not(unix))]
+    {
+        path.is_file()
+    }
+}
+
+/// Once-per-module gate behind `check_requirements()`
+///
+/// Caches the check result so the environment is probed once per test binary, and
+/// reports the full error only to the first failing caller.
+#[derive(Debug)]
+pub struct RequirementsGate {
+    result: OnceLock<RequirementsResult<()>>,
+    reported: AtomicBool,
+}
+
+impl RequirementsGate {
+    /// Create an unchecked gate (usable in a `static`)
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { result: OnceLock::new(), reported: AtomicBool::new(false) }
+    }
+
+    /// Check the requirements built by `declare` (first call only) and fail if unmet
+    ///
+    /// # Panics
+    ///
+    /// Panics with the consolidated report on the first call after a failed check, and
+    /// with a one-line pointer to it on every later call.
+    #[allow(clippy::panic)] // Test gate - panic is the test failure
+    pub fn enforce(&self, module: &str, declare: impl FnOnce() -> Requirements) {
+        let Err(error) = self.result.get_or_init(|| declare().check()) else {
+            return;
+        };
+        if self.reported.swap(true, Ordering::SeqCst) {
+            let RequirementsError::Unmet(unmet) = error;
+            panic!(
+                "🚨 {module}: {} test environment requirement(s) not met (full report in the first failing test)",
+                unmet.len()
+            );
+        }
+        panic!("{module}: {error}");
+    }
+}
+
+impl Default for RequirementsGate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Declare a test module's environment requirements
+///
+/// Generates a `check_requirements()` function for the enclosing module. Call it first in
+/// each test; the checks run once and all unmet requirements are reported together.
+///
+/// Supported keys: `env` (non-empty env vars), `bin` (executables on `PATH`), `port`
+/// (services listening on localhost), and `free_port` (localhost ports that must be free).
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::requirements;
+///
+/// requirements! {
+///     env: ["PATH"],
+///     bin: ["sh"],
+/// }
+///
+/// # #[cfg(unix)]
+/// check_requirements();
+/// ```
+#[macro_export]
+macro_rules! requirements {
+    ($($kind:ident: [$($value:expr),* $(,)?]),* $(,)?) => {
+        /// Fail unless this module's declared environment requirements are met
+        #[allow(dead_code)]
+        fn check_requirements() {
+            static GATE: $crate::core::requirements::RequirementsGate =
+                $crate::core::requirements::RequirementsGate::new();
+            GATE.enforce(module_path!(), || {
+                $crate::core::requirements::Requirements::new() $($(.$kind($value))*)*
+            });
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_check_reports_all_unmet_requirements_together, {
+        // Arrange
+        let occupied = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let occupied_port = occupied.local_addr().unwrap().port();
+        let closed_port = TcpListener::bind((Ipv4Addr::LOCALHOST, 0))
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let requirements = Requirements::new()
+            .env("CHICAGO_TDD_SURELY_UNSET_VAR")
+            .bin("definitely-not-installed-xyz")
+            .port(closed_port)
+            .free_port(occupied_port);
+
+        // Act
+        let err = requirements.check().unwrap_err();
+
+        // Assert
+        let RequirementsError::Unmet(unmet) = &err;
+        assert_eq!(
+            unmet,
+            &[
+                UnmetRequirement::EnvVar("CHICAGO_TDD_SURELY_UNSET_VAR".to_string()),
+                UnmetRequirement::Binary("definitely-not-installed-xyz".to_string()),
+                UnmetRequirement::PortNotListening(closed_port),
+                UnmetRequirement::PortInUse(occupied_port),
+            ]
+        );
+        let report = err.to_string();
+        assert!(report.contains("not met (4)"));
+        assert!(report.contains("export CHICAGO_TDD_SURELY_UNSET_VAR=<value>"));
+    });
+
+    test!(test_check_passes_when_satisfied, {
+        // Arrange
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let requirements = Requirements::new().env("PATH").port(port);
+
+        // Act & Assert
+        assert_eq!(requirements.check(), Ok(()));
+    });
+
+    #[cfg(unix)]
+    mod declared {
+        crate::requirements! {
+            env: ["PATH"],
+            bin: ["sh", "/bin/sh"],
+        }
+
+        #[test]
+        fn test_macro_generated_check_passes() {
+            check_requirements();
+        }
+    }
+
+    #[test]
+    fn test_gate_reports_full_error_once() {
+        // Arrange
+        let gate = RequirementsGate::new();
+        let declare = || Requirements::new().env("CHICAGO_TDD_SURELY_UNSET_VAR");
+
+        // Act
+        let first = std::panic::catch_unwind(|| gate.enforce("suite", declare)).unwrap_err();
+        let second = std::panic::catch_unwind(|| gate.enforce("suite", declare)).unwrap_err();
+
+        // Assert
+        let first = first.downcast_ref::<String>().unwrap();
+        let second = second.downcast_ref::<String>().unwrap();
+        assert!(first.contains("export CHICAGO_TDD_SURELY_UNSET_VAR"));
+        assert!(second.contains("full report in the first failing test"));
+    }
+}
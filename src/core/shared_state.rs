@@ -0,0 +1,303 @@
+//! > 📚 Reference
+//!
+//! Shared Suite State
+//!
+//! An explicit, typed channel for the rare cases where tests must share expensive
+//! computed state (an indexed corpus, a trained model, a generated schema). Sharing
+//! through ad-hoc `static`s is invisible to the isolation story; [`SharedSuiteState`]
+//! makes it a declared contract instead:
+//!
+//! - The state is registered as a session-scoped global fixture
+//!   ([`TestFixture::shared`]), so it is computed once and torn down with the other
+//!   shared fixtures.
+//! - Every access goes through [`SharedSuiteState::read`] or [`SharedSuiteState::write`],
+//!   which record the accessing test (the libtest thread name).
+//! - [`SharedSuiteState::report`] / [`shared_state_reports`] list which tests read and
+//!   wrote each state, and flag states whose readers may observe another test's
+//!   writes (i.e. whose results depend on test order).
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::shared_state::SharedSuiteState;
+//!
+//! let corpus = SharedSuiteState::register("doc-corpus", || Ok(vec!["alpha", "beta"])).unwrap();
+//!
+//! let count = corpus.read(|docs| docs.len());
+//!
+//! assert_eq!(count, 2);
+//! assert_eq!(corpus.report().reads, 1);
+//! assert!(!corpus.report().is_order_dependent());
+//! ```
+
+use crate::core::fixture::{FixtureResult, FixtureScopeKind, SharedFixture, TestFixture};
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+/// Test name recorded for accesses from threads without a name
+const UNNAMED_TEST: &str = "<unnamed>";
+
+/// How a test touched a shared state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StateAccessKind {
+    /// Shared read via [`SharedSuiteState::read`]
+    Read,
+    /// Exclusive write via [`SharedSuiteState::write`]
+    Write,
+}
+
+/// Access log for one shared state (type-erased so every state can be reported)
+#[derive(Debug)]
+struct AccessLog {
+    name: String,
+    type_name: &'static str,
+    accesses: Mutex<Vec<(String, StateAccessKind)>>,
+}
+
+impl AccessLog {
+    fn record(&self, kind: StateAccessKind) {
+        let test = std::thread::current().name().unwrap_or(UNNAMED_TEST).to_string();
+        self.accesses.lock().unwrap_or_else(PoisonError::into_inner).push((test, kind));
+    }
+
+    fn report(&self) -> SharedStateReport {
+        let accesses = self.accesses.lock().unwrap_or_else(PoisonError::into_inner).clone();
+        let mut report = SharedStateReport {
+            name: self.name.clone(),
+            type_name: self.type_name,
+            readers: BTreeSet::new(),
+            writers: BTreeSet::new(),
+            reads: 0,
+            writes: 0,
+        };
+        for (test, kind) in accesses {
+            match kind {
+                StateAccessKind::Read => {
+                    report.reads += 1;
+                    report.readers.insert(test);
+                }
+                StateAccessKind::Write => {
+                    report.writes += 1;
+                    report.writers.insert(test);
+                }
+            }
+        }
+        report
+    }
+}
+
+/// Every access log created in this process, in registration order
+static ACCESS_LOGS: Mutex<Vec<Arc<AccessLog>>> = Mutex::new(Vec::new());
+
+/// The value held by the global fixture
+struct StateCell<T> {
+    value: RwLock<T>,
+    log: Arc<AccessLog>,
+}
+
+/// > 📚 Reference
+///
+/// Handle to typed state shared by tests, with tracked access.
+///
+/// Handles are cheap to clone; all handles registered under the same name (and type)
+/// share one value and one access log.
+pub struct SharedSuiteState<T> {
+    cell: SharedFixture<StateCell<T>>,
+}
+
+impl<T: Send + Sync + 'static> SharedSuiteState<T> {
+    /// Get or create the shared state registered under `name`
+    ///
+    /// `init` runs once per process (concurrent callers wait for it); a failed `init`
+    /// is not cached.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error from `init`.
+    pub fn register(name: &str, init: impl FnOnce() -> FixtureResult<T>) -> FixtureResult<Self> {
+        let cell = TestFixture::shared(FixtureScopeKind::Session, name, || {
+            let log = Arc::new(AccessLog {
+                name: name.to_string(),
+                type_name: std::any::type_name::<T>(),
+                accesses: Mutex::new(Vec::new()),
+            });
+            let value = RwLock::new(init()?);
+            ACCESS_LOGS
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .push(Arc::clone(&log));
+            Ok(StateCell { value, log })
+        })?;
+        Ok(Self { cell })
+    }
+
+    /// Read the state, recording the current test as a reader
+    pub fn read<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        self.cell.log.record(StateAccessKind::Read);
+        f(&self.cell.value.read().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Mutate the state, recording the current test as a writer
+    ///
+    /// Prefer read-only sharing: every writer makes later readers order-dependent.
+    pub fn write<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        self.cell.log.record(StateAccessKind::Write);
+        f(&mut self.cell.value.write().unwrap_or_else(PoisonError::into_inner))
+    }
+
+    /// Name the state was registered under
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.cell.log.name
+    }
+
+    /// Which tests have read and written this state so far
+    #[must_use]
+    pub fn report(&self) -> SharedStateReport {
+        self.cell.log.report()
+    }
+}
+
+impl<T> Clone for SharedSuiteState<T> {
+    fn clone(&self) -> Self {
+        Self { cell: self.cell.clone() }
+    }
+}
+
+impl<T> fmt::Debug for SharedSuiteState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSuiteState")
+            .field("name", &self.cell.log.name)
+            .field("type", &self.cell.log.type_name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Access summary for one [`SharedSuiteState`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SharedStateReport {
+    /// Registered name
+    pub name: String,
+    /// Rust type of the state
+    pub type_name: &'static str,
+    /// Tests that read the state
+    pub readers: BTreeSet<String>,
+    /// Tests that wrote the state
+    pub writers: BTreeSet<String>,
+    /// Total reads
+    pub reads: usize,
+    /// Total writes
+    pub writes: usize,
+}
+
+impl SharedStateReport {
+    /// Whether some test reads state another test writes
+    ///
+    /// Such a reader sees different values depending on which tests ran before it.
+    #[must_use]
+    pub fn is_order_dependent(&self) -> bool {
+        self.readers
+            .iter()
+            .any(|reader| self.writers.iter().any(|writer| writer != reader))
+    }
+}
+
+impl fmt::Display for SharedStateReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |tests: &BTreeSet<String>| {
+            if tests.is_empty() {
+                "none".to_string()
+            } else {
+                tests.iter().cloned().collect::<Vec<_>>().join(", ")
+            }
+        };
+        write!(
+            f,
+            "shared state '{}' ({}): {} read(s) by {}; {} write(s) by {}",
+            self.name,
+            self.type_name,
+            self.reads,
+            list(&self.readers),
+            self.writes,
+            list(&self.writers)
+        )?;
+        if self.is_order_dependent() {
+            write!(
+                f,
+                "\n   ⚠️  Readers observe other tests' writes; results depend on test order"
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Access reports for every shared state registered in this process
+///
+/// Call at the end of a suite (or from a diagnostic test) to see which tests share
+/// state and through what.
+#[must_use]
+pub fn shared_state_reports() -> Vec<SharedStateReport> {
+    let logs = ACCESS_LOGS.lock().unwrap_or_else(PoisonError::into_inner).clone();
+    logs.iter().map(|log| log.report()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::fixture::FixtureError;
+    use crate::test;
+
+    test!(test_register_initializes_once_and_shares_value, {
+        // Arrange
+        let first = SharedSuiteState::register("shared-state-once", || Ok(1_u32)).unwrap();
+
+        // Act
+        let second = SharedSuiteState::register("shared-state-once", || Ok(99_u32)).unwrap();
+        second.write(|value| *value += 1);
+
+        // Assert
+        assert_eq!(first.read(|value| *value), 2);
+        assert_eq!(first.name(), "shared-state-once");
+    });
+
+    test!(test_report_tracks_readers_and_writers, {
+        // Arrange
+        let state =
+            SharedSuiteState::register("shared-state-tracked", || Ok(Vec::<u8>::new())).unwrap();
+        let writer = std::thread::Builder::new()
+            .name("suite::writer_test".to_string())
+            .spawn({
+                let state = state.clone();
+                move || state.write(|v| v.push(1))
+            })
+            .unwrap();
+        writer.join().unwrap();
+
+        // Act
+        let len = state.read(Vec::len);
+        let report = state.report();
+
+        // Assert
+        assert_eq!(len, 1);
+        assert_eq!((report.reads, report.writes), (1, 1));
+        assert!(report.writers.contains("suite::writer_test"));
+        assert!(report.is_order_dependent());
+        assert!(report.to_string().contains("depend on test order"), "{report}");
+        assert!(shared_state_reports().iter().any(|r| r.name == "shared-state-tracked"));
+    });
+
+    test!(test_failed_init_is_not_cached, {
+        // Arrange
+        let failed = SharedSuiteState::<u8>::register("shared-state-retry", || {
+            Err(FixtureError::CreationFailed("not yet".to_string()))
+        });
+
+        // Act
+        let retried = SharedSuiteState::register("shared-state-retry", || Ok(7_u8)).unwrap();
+
+        // Assert
+        assert!(failed.is_err());
+        assert_eq!(retried.read(|v| *v), 7);
+    });
+}
@@ -0,0 +1,650 @@
+//! Structural Diff of `Debug` Output
+//!
+//! Parses `Debug` output (compact `{:?}` or pretty `{:#?}`) into a [`DebugTree`] and
+//! diffs two trees field by field. Unchanged fields far from a change collapse into
+//! `… n unchanged field(s) …` markers, so a one-field difference in a large struct shows
+//! as that field plus a few lines of context instead of two full dumps.
+//!
+//! Structs and tuple structs are compared by field, lists and sets by aligned items, and
+//! maps by key (so `HashMap` iteration order does not matter). Anything that does not
+//! parse as `Debug` output (custom `Debug` impls, free text) is left to the renderer's
+//! line diff.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::structural_diff::{structural_diff, DebugTree, StructuralLineKind};
+//!
+//! let left = DebugTree::parse(r#"Order { id: 7, status: "open", total: 30 }"#).unwrap();
+//! let right = DebugTree::parse(r#"Order { id: 7, status: "paid", total: 30 }"#).unwrap();
+//!
+//! let diff = structural_diff(&left, &right, 0);
+//! let text: Vec<&str> = diff.lines.iter().map(|line| line.text.as_str()).collect();
+//!
+//! assert_eq!(diff.changes, 1);
+//! assert_eq!(diff.lines[2].kind, StructuralLineKind::Removed);
+//! assert_eq!(
+//!     text,
+//!     [
+//!         "Order {",
+//!         "… 1 unchanged field(s) …",
+//!         r#"status: "open","#,
+//!         r#"status: "paid","#,
+//!         "… 1 unchanged field(s) …",
+//!         "}",
+//!     ]
+//! );
+//! ```
+
+use std::fmt;
+
+/// Deepest nesting parsed before giving up (guards against pathological input)
+const MAX_DEPTH: usize = 128;
+
+/// Largest item alignment (`left × right` items) done exactly; larger lists pair by index
+const MAX_ALIGN_CELLS: usize = 250_000;
+
+/// A value parsed from `Debug` output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DebugTree {
+    /// Scalar or opaque value: `42`, `"text"`, `None`, `1.5s`
+    Leaf(String),
+    /// `Name { field: value }`
+    Struct {
+        /// Type name
+        name: String,
+        /// Fields in declaration order
+        fields: Vec<(String, Self)>,
+    },
+    /// `Name(a, b)`, or an anonymous tuple `(a, b)` with an empty name
+    Tuple {
+        /// Type or variant name (empty for tuples)
+        name: String,
+        /// Positional items
+        items: Vec<Self>,
+    },
+    /// `[a, b]`
+    List(Vec<Self>),
+    /// `{a, b}`
+    Set(Vec<Self>),
+    /// `{key: value}`
+    Map(Vec<(Self, Self)>),
+}
+
+impl DebugTree {
+    /// Parse `Debug` output, or `None` if `text` is not entirely `Debug`-shaped
+    #[must_use]
+    pub fn parse(text: &str) -> Option<Self> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut parser = Parser { chars: &chars, pos: 0, depth: 0 };
+        let tree = parser.value()?;
+        parser.skip_ws();
+        (parser.pos == chars.len()).then_some(tree)
+    }
+
+    /// Whether this is a struct, tuple, or collection rather than a leaf
+    #[must_use]
+    pub const fn is_composite(&self) -> bool {
+        !matches!(self, Self::Leaf(_))
+    }
+}
+
+/// Compact single-line form, e.g. `Item { sku: "A", qty: 3 }`
+impl fmt::Display for DebugTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn join<T>(
+            f: &mut fmt::Formatter<'_>,
+            items: &[T],
+            mut each: impl FnMut(&mut fmt::Formatter<'_>, &T) -> fmt::Result,
+        ) -> fmt::Result {
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    f.write_str(", ")?;
+                }
+                each(f, item)?;
+            }
+            Ok(())
+        }
+
+        match self {
+            Self::Leaf(text) => f.write_str(text),
+            Self::Struct { name, fields } => {
+                write!(f, "{name} {{ ")?;
+                join(f, fields, |f, (key, value)| write!(f, "{key}: {value}"))?;
+                f.write_str(" }")
+            }
+            Self::Tuple { name, items } => {
+                write!(f, "{name}(")?;
+                join(f, items, |f, item| write!(f, "{item}"))?;
+                f.write_str(")")
+            }
+            Self::List(items) => {
+                f.write_str("[")?;
+                join(f, items, |f, item| write!(f, "{item}"))?;
+                f.write_str("]")
+            }
+            Self::Set(items) => {
+                f.write_str("{")?;
+                join(f, items, |f, item| write!(f, "{item}"))?;
+                f.write_str("}")
+            }
+            Self::Map(entries) => {
+                f.write_str("{")?;
+                join(f, entries, |f, (key, value)| write!(f, "{key}: {value}"))?;
+                f.write_str("}")
+            }
+        }
+    }
+}
+
+struct Parser<'a> {
+    chars: &'a [char],
+    pos: usize,
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let matched = self.peek() == Some(c);
+        if matched {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn skip_ws(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+    }
+
+    fn value(&mut self) -> Option<DebugTree> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            return None;
+        }
+        self.skip_ws();
+        let tree = match self.peek()? {
+            '"' => self.quoted('"').map(DebugTree::Leaf),
+            '\'' => self.quoted('\'').map(DebugTree::Leaf),
+            '[' => {
+                self.pos += 1;
+                self.items(']').map(DebugTree::List)
+            }
+            '(' => {
+                self.pos += 1;
+                self.items(')').map(|items| DebugTree::Tuple { name: String::new(), items })
+            }
+            '{' => {
+                self.pos += 1;
+                self.braces()
+            }
+            _ => self.named(),
+        };
+        self.depth -= 1;
+        tree
+    }
+
+    fn quoted(&mut self, quote: char) -> Option<String> {
+        let start = self.pos;
+        self.pos += 1;
+        loop {
+            match self.peek()? {
+                '\\' => self.pos += 2,
+                c if c == quote => break,
+                _ => self.pos += 1,
+            }
+        }
+        self.pos += 1;
+        Some(self.chars[start..self.pos].iter().collect())
+    }
+
+    /// Bare token: number, identifier, or path (`a::B`)
+    fn atom(&mut self) -> String {
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c == ':' && self.chars.get(self.pos + 1) == Some(&':') {
+                self.pos += 2;
+                continue;
+            }
+            if c.is_whitespace() || ",:()[]{}\"'".contains(c) {
+                break;
+            }
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn named(&mut self) -> Option<DebugTree> {
+        let name = self.atom();
+        if name.is_empty() {
+            return None;
+        }
+        if self.eat('(') {
+            return self.items(')').map(|items| DebugTree::Tuple { name, items });
+        }
+        let after_name = self.pos;
+        self.skip_ws();
+        if self.eat('{') {
+            return self.fields().map(|fields| DebugTree::Struct { name, fields });
+        }
+        self.pos = after_name;
+        Some(DebugTree::Leaf(name))
+    }
+
+    /// After an element: a separating comma, or the closing delimiter next
+    fn separator(&mut self, close: char) -> Option<()> {
+        self.skip_ws();
+        (self.eat(',') || self.peek() == Some(close)).then_some(())
+    }
+
+    fn items(&mut self, close: char) -> Option<Vec<DebugTree>> {
+        let mut items = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.eat(close) {
+                return Some(items);
+            }
+            items.push(self.value()?);
+            self.separator(close)?;
+        }
+    }
+
+    fn fields(&mut self) -> Option<Vec<(String, DebugTree)>> {
+        let mut fields = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.eat('}') {
+                return Some(fields);
+            }
+            let name = self.atom();
+            self.skip_ws();
+            if name.is_empty() || !self.eat(':') {
+                return None;
+            }
+            fields.push((name, self.value()?));
+            self.separator('}')?;
+        }
+    }
+
+    fn braces(&mut self) -> Option<DebugTree> {
+        let mut set = Vec::new();
+        let mut map = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.eat('}') {
+                return Some(if set.is_empty() {
+                    DebugTree::Map(map)
+                } else {
+                    DebugTree::Set(set)
+                });
+            }
+            let key = self.value()?;
+            self.skip_ws();
+            if self.eat(':') {
+                if !set.is_empty() {
+                    return None;
+                }
+                map.push((key, self.value()?));
+            } else {
+                if !map.is_empty() {
+                    return None;
+                }
+                set.push(key);
+            }
+            self.separator('}')?;
+        }
+    }
+}
+
+/// How a [`StructuralLine`] relates the two values
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructuralLineKind {
+    /// Present and equal in both (context or enclosing structure)
+    Same,
+    /// Only in the left value
+    Removed,
+    /// Only in the right value
+    Added,
+    /// Marker standing in for unchanged siblings
+    Collapsed,
+}
+
+/// One line of a structural diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructuralLine {
+    /// How the line relates the two values
+    pub kind: StructuralLineKind,
+    /// Nesting depth (for indentation)
+    pub depth: usize,
+    /// Line text without indentation, e.g. `qty: 3,`
+    pub text: String,
+}
+
+/// Result of [`structural_diff`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StructuralDiff {
+    /// Pretty-printed diff, unchanged siblings collapsed
+    pub lines: Vec<StructuralLine>,
+    /// Number of changed, added, or removed fields/items
+    pub changes: usize,
+}
+
+/// Diff two trees, keeping `context` unchanged siblings around each change
+#[must_use]
+pub fn structural_diff(left: &DebugTree, right: &DebugTree, context: usize) -> StructuralDiff {
+    let mut differ = Differ { context, changes: 0 };
+    let lines = differ.node("", left, right, "", 0);
+    StructuralDiff { lines, changes: differ.changes }
+}
+
+/// Children of two same-shaped composites, paired for recursive diffing
+struct Paired<'a> {
+    open: String,
+    close: &'static str,
+    unit: &'static str,
+    children: Vec<(String, Option<&'a DebugTree>, Option<&'a DebugTree>)>,
+}
+
+struct Differ {
+    context: usize,
+    changes: usize,
+}
+
+impl Differ {
+    fn node(
+        &mut self,
+        prefix: &str,
+        left: &DebugTree,
+        right: &DebugTree,
+        suffix: &str,
+        depth: usize,
+    ) -> Vec<StructuralLine> {
+        let (l, r) = (left.to_string(), right.to_string());
+        if l == r {
+            return vec![line(StructuralLineKind::Same, depth, format!("{prefix}{l}{suffix}"))];
+        }
+        let Some(paired) = pair(left, right) else {
+            self.changes += 1;
+            return vec![
+                line(StructuralLineKind::Removed, depth, format!("{prefix}{l}{suffix}")),
+                line(StructuralLineKind::Added, depth, format!("{prefix}{r}{suffix}")),
+            ];
+        };
+
+        let blocks: Vec<Vec<StructuralLine>> = paired
+            .children
+            .iter()
+            .map(|(key, l, r)| match (l, r) {
+                (Some(l), Some(r)) => self.node(key, l, r, ",", depth + 1),
+                (Some(l), None) => {
+                    self.changes += 1;
+                    vec![line(StructuralLineKind::Removed, depth + 1, format!("{key}{l},"))]
+                }
+                (None, Some(r)) => {
+                    self.changes += 1;
+                    vec![line(StructuralLineKind::Added, depth + 1, format!("{key}{r},"))]
+                }
+                (None, None) => Vec::new(),
+            })
+            .collect();
+        let changed: Vec<bool> = blocks
+            .iter()
+            .map(|block| block.iter().any(|l| l.kind != StructuralLineKind::Same))
+            .collect();
+        let near_change = |index: usize| {
+            let lo = index.saturating_sub(self.context);
+            let hi = index.saturating_add(self.context).min(changed.len() - 1);
+            changed[lo..=hi].iter().any(|c| *c)
+        };
+
+        let mut out =
+            vec![line(StructuralLineKind::Same, depth, format!("{prefix}{}", paired.open))];
+        let mut hidden = 0_usize;
+        for (index, block) in blocks.into_iter().enumerate() {
+            if !near_change(index) {
+                hidden += 1;
+                continue;
+            }
+            collapse(&mut out, &mut hidden, depth + 1, paired.unit);
+            out.extend(block);
+        }
+        collapse(&mut out, &mut hidden, depth + 1, paired.unit);
+        out.push(line(StructuralLineKind::Same, depth, format!("{}{suffix}", paired.close)));
+        out
+    }
+}
+
+const fn line(kind: StructuralLineKind, depth: usize, text: String) -> StructuralLine {
+    StructuralLine { kind, depth, text }
+}
+
+fn collapse(out: &mut Vec<StructuralLine>, hidden: &mut usize, depth: usize, unit: &str) {
+    if *hidden > 0 {
+        let text = format!("… {hidden} unchanged {unit} …");
+        out.push(line(StructuralLineKind::Collapsed, depth, text));
+        *hidden = 0;
+    }
+}
+
+fn pair<'a>(left: &'a DebugTree, right: &'a DebugTree) -> Option<Paired<'a>> {
+    let unkeyed = |items: Vec<(Option<&'a DebugTree>, Option<&'a DebugTree>)>| {
+        items.into_iter().map(|(l, r)| (String::new(), l, r)).collect()
+    };
+    match (left, right) {
+        (
+            DebugTree::Struct { name, fields: left_fields },
+            DebugTree::Struct { name: right_name, fields: right_fields },
+        ) if name == right_name => {
+            let mut children: Vec<_> = left_fields
+                .iter()
+                .map(|(key, l)| {
+                    let r = right_fields.iter().find(|(k, _)| k == key).map(|(_, r)| r);
+                    (format!("{key}: "), Some(l), r)
+                })
+                .collect();
+            children.extend(
+                right_fields
+                    .iter()
+                    .filter(|(key, _)| !left_fields.iter().any(|(k, _)| k == key))
+                    .map(|(key, r)| (format!("{key}: "), None, Some(r))),
+            );
+            Some(Paired { open: format!("{name} {{"), close: "}", unit: "field(s)", children })
+        }
+        (
+            DebugTree::Tuple { name, items: left_items },
+            DebugTree::Tuple { name: right_name, items: right_items },
+        ) if name == right_name && left_items.len() == right_items.len() => Some(Paired {
+            open: format!("{name}("),
+            close: ")",
+            unit: "item(s)",
+            children: unkeyed(
+                left_items.iter().zip(right_items).map(|(l, r)| (Some(l), Some(r))).collect(),
+            ),
+        }),
+        (DebugTree::List(l), DebugTree::List(r)) => Some(Paired {
+            open: "[".into(),
+            close: "]",
+            unit: "item(s)",
+            children: unkeyed(align(l, r)),
+        }),
+        (DebugTree::Set(l), DebugTree::Set(r)) => Some(Paired {
+            open: "{".into(),
+            close: "}",
+            unit: "item(s)",
+            children: unkeyed(align(l, r)),
+        }),
+        (DebugTree::Map(left_entries), DebugTree::Map(right_entries)) => {
+            let key_of = |(key, _): &(DebugTree, DebugTree)| key.to_string();
+            let mut children: Vec<_> = left_entries
+                .iter()
+                .map(|entry| {
+                    let key = key_of(entry);
+                    let r = right_entries.iter().find(|e| key_of(e) == key).map(|(_, r)| r);
+                    (format!("{key}: "), Some(&entry.1), r)
+                })
+                .collect();
+            children.extend(right_entries.iter().filter_map(|entry| {
+                let key = key_of(entry);
+                (!left_entries.iter().any(|e| key_of(e) == key))
+                    .then(|| (format!("{key}: "), None, Some(&entry.1)))
+            }));
+            Some(Paired { open: "{".into(), close: "}", unit: "entry(ies)", children })
+        }
+        _ => None,
+    }
+}
+
+/// Align two item lists: equal items match, and a run of removed items facing a run of
+/// added items is paired up so changed items diff field by field
+fn align<'a>(
+    left: &'a [DebugTree],
+    right: &'a [DebugTree],
+) -> Vec<(Option<&'a DebugTree>, Option<&'a DebugTree>)> {
+    let (n, m) = (left.len(), right.len());
+    if n.saturating_mul(m) > MAX_ALIGN_CELLS {
+        return (0..n.max(m)).map(|i| (left.get(i), right.get(i))).collect();
+    }
+    let (left_keys, right_keys): (Vec<String>, Vec<String>) = (
+        left.iter().map(ToString::to_string).collect(),
+        right.iter().map(ToString::to_string).collect(),
+    );
+    let mut table = vec![0_usize; (n + 1) * (m + 1)];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            table[i * (m + 1) + j] = if left_keys[i] == right_keys[j] {
+                table[(i + 1) * (m + 1) + j + 1] + 1
+            } else {
+                table[(i + 1) * (m + 1) + j].max(table[i * (m + 1) + j + 1])
+            };
+        }
+    }
+
+    let mut out = Vec::with_capacity(n.max(m));
+    let (mut removed, mut added) = (Vec::new(), Vec::new());
+    let flush = |out: &mut Vec<_>, removed: &mut Vec<usize>, added: &mut Vec<usize>| {
+        for k in 0..removed.len().max(added.len()) {
+            out.push((removed.get(k).map(|&i| &left[i]), added.get(k).map(|&j| &right[j])));
+        }
+        removed.clear();
+        added.clear();
+    };
+    let (mut i, mut j) = (0, 0);
+    while i < n || j < m {
+        let same = left_keys.get(i).is_some_and(|key| right_keys.get(j) == Some(key));
+        if same {
+            flush(&mut out, &mut removed, &mut added);
+            out.push((Some(&left[i]), Some(&right[j])));
+            i += 1;
+            j += 1;
+        } else if j == m || (i < n && table[(i + 1) * (m + 1) + j] >= table[i * (m + 1) + j + 1]) {
+            removed.push(i);
+            i += 1;
+        } else {
+            added.push(j);
+            j += 1;
+        }
+    }
+    flush(&mut out, &mut removed, &mut added);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::collections::BTreeMap;
+
+    #[derive(Debug)]
+    #[allow(dead_code)] // Fields are read through Debug
+    struct Item {
+        sku: &'static str,
+        qty: u32,
+    }
+
+    #[derive(Debug)]
+    #[allow(dead_code)] // Fields are read through Debug
+    struct Order {
+        id: u64,
+        note: Option<String>,
+        items: Vec<Item>,
+        tags: BTreeMap<&'static str, (i32, char)>,
+    }
+
+    fn order(qty: u32) -> Order {
+        Order {
+            id: 7,
+            note: Some("leave at \"door\", thanks".to_string()),
+            items: vec![
+                Item { sku: "A", qty: 1 },
+                Item { sku: "B", qty },
+                Item { sku: "C", qty: 1 },
+            ],
+            tags: BTreeMap::from([("gift", (-1, ':')), ("rush", (2, '}'))]),
+        }
+    }
+
+    fn changed(diff: &StructuralDiff) -> Vec<String> {
+        diff.lines
+            .iter()
+            .filter(|l| matches!(l.kind, StructuralLineKind::Removed | StructuralLineKind::Added))
+            .map(|l| l.text.clone())
+            .collect()
+    }
+
+    test!(test_parse_round_trips_compact_and_pretty_debug, {
+        // Arrange
+        let value = order(3);
+
+        // Act
+        let compact = DebugTree::parse(&format!("{value:?}")).unwrap();
+        let pretty = DebugTree::parse(&format!("{value:#?}")).unwrap();
+
+        // Assert
+        assert_eq!(compact, pretty);
+        assert_eq!(compact.to_string(), format!("{value:?}"));
+        assert!(compact.is_composite());
+        assert!(DebugTree::parse("line 1\nline 2").is_none());
+        assert!(!DebugTree::parse("42").unwrap().is_composite());
+    });
+
+    test!(test_diff_shows_only_changed_field_with_context, {
+        // Arrange
+        let left = DebugTree::parse(&format!("{:#?}", order(3))).unwrap();
+        let right = DebugTree::parse(&format!("{:#?}", order(4))).unwrap();
+
+        // Act
+        let diff = structural_diff(&left, &right, 0);
+
+        // Assert
+        assert_eq!(diff.changes, 1);
+        assert_eq!(changed(&diff), ["qty: 3,", "qty: 4,"]);
+        let texts: Vec<&str> = diff.lines.iter().map(|l| l.text.as_str()).collect();
+        assert!(texts.contains(&"items: ["), "{texts:?}");
+        assert!(texts.contains(&"Item {"), "{texts:?}");
+        assert!(texts.contains(&"… 2 unchanged field(s) …"), "{texts:?}");
+        assert!(!texts.iter().any(|t| t.contains("gift")), "{texts:?}");
+    });
+
+    test!(test_maps_diff_by_key_and_lists_align_insertions, {
+        // Arrange
+        let left = DebugTree::parse(r#"{"a": 1, "b": 2, "c": 3} "#).unwrap();
+        let right = DebugTree::parse(r#"{"c": 3, "b": 20, "d": 4}"#).unwrap();
+        let before = DebugTree::parse("[10, 20, 30]").unwrap();
+        let after = DebugTree::parse("[5, 10, 20, 30]").unwrap();
+
+        // Act
+        let map_diff = structural_diff(&left, &right, 1);
+        let list_diff = structural_diff(&before, &after, 1);
+
+        // Assert
+        assert_eq!(changed(&map_diff), [r#""a": 1,"#, r#""b": 2,"#, r#""b": 20,"#, r#""d": 4,"#]);
+        assert_eq!(map_diff.changes, 3);
+        assert_eq!(changed(&list_diff), ["5,"]);
+        assert_eq!(list_diff.lines.last().map(|l| l.text.as_str()), Some("]"));
+    });
+}
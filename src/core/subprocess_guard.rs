@@ -0,0 +1,373 @@
+//! Subprocess Leak Detection
+//!
+//! [`SubprocessGuard`] records the test process's descendants at Arrange and, at
+//! teardown, fails the test if any new descendant is still alive (or is an unreaped
+//! zombie), listing each offender's PID and command line. It catches `weaver`, `docker`,
+//! and user binaries that a test spawned and never waited on, which otherwise pile up
+//! over long CI runs.
+//!
+//! The process tree is read from `/proc`, so detection is Linux-only; on other platforms
+//! [`ProcessTable::capture`] returns [`io::ErrorKind::Unsupported`].
+//!
+//! # Parallel tests
+//!
+//! Tests in one binary share a process, so a child spawned by a concurrently running test
+//! also appears as a new descendant. Restrict the guard to the commands the test starts
+//! with [`watch`](SubprocessGuard::watch), or run guarded tests serially. Processes that
+//! double-fork and reparent to init are outside the tree and are not detected.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::core::subprocess_guard::SubprocessGuard;
+//! use std::time::Duration;
+//!
+//! let guard = SubprocessGuard::new().unwrap().watch("weaver").grace(Duration::from_secs(2));
+//!
+//! // ... run code that starts and should stop `weaver` ...
+//!
+//! guard.verify(); // or let it drop: teardown verifies too
+//! ```
+
+use crate::core::cancellation::cancellable_sleep;
+use crate::core::failure::{FailureKind, TddFailure};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::io;
+use std::time::{Duration, Instant};
+
+/// Default time leaked-looking children get to exit before the guard fails
+pub const DEFAULT_LEAK_GRACE: Duration = Duration::from_millis(500);
+
+/// Poll interval while waiting out the grace period
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// One process from the process table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProcessInfo {
+    /// Process ID
+    pub pid: u32,
+    /// Parent process ID
+    pub ppid: u32,
+    /// Scheduler state from `/proc/<pid>/stat` (`R`, `S`, `Z`, ...)
+    pub state: char,
+    /// Full command line, or `[name]` when unavailable (e.g. zombies)
+    pub command: String,
+}
+
+impl ProcessInfo {
+    /// Whether the process has exited but was never reaped by its parent
+    #[must_use]
+    pub const fn is_zombie(&self) -> bool {
+        self.state == 'Z'
+    }
+}
+
+impl fmt::Display for ProcessInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pid {} (parent {}): {}", self.pid, self.ppid, self.command)?;
+        if self.is_zombie() {
+            write!(f, " [zombie]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Snapshot of every process visible to this one
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProcessTable {
+    processes: BTreeMap<u32, ProcessInfo>,
+}
+
+impl ProcessTable {
+    /// Read the current process table
+    ///
+    /// Processes that exit while the table is being read are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `/proc` cannot be listed, or
+    /// [`io::ErrorKind::Unsupported`] on platforms other than Linux.
+    #[cfg(target_os = "linux")]
+    pub fn capture() -> io::Result<Self> {
+        let mut processes = BTreeMap::new();
+        for entry in std::fs::read_dir("/proc")? {
+            let Some(pid) = entry?.file_name().to_str().and_then(|name| name.parse().ok()) else {
+                continue;
+            };
+            if let Some(info) = read_process(pid) {
+                processes.insert(pid, info);
+            }
+        }
+        Ok(Self { processes })
+    }
+
+    /// Read the current process table
+    ///
+    /// # Errors
+    ///
+    /// Always returns [`io::ErrorKind::Unsupported`]: process tables are read from `/proc`.
+    #[cfg(not(target_os = "linux"))]
+    pub fn capture() -> io::Result<Self> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "subprocess leak detection requires Linux /proc",
+        ))
+    }
+
+    /// Process by PID
+    #[must_use]
+    pub fn get(&self, pid: u32) -> Option<&ProcessInfo> {
+        self.processes.get(&pid)
+    }
+
+    /// Every process, ordered by PID
+    pub fn iter(&self) -> impl Iterator<Item = &ProcessInfo> {
+        self.processes.values()
+    }
+
+    /// Children, grandchildren, ... of `pid`, ordered by PID
+    #[must_use]
+    pub fn descendants_of(&self, pid: u32) -> Vec<&ProcessInfo> {
+        let mut found = BTreeSet::new();
+        let mut frontier = vec![pid];
+        while let Some(parent) = frontier.pop() {
+            for child in self.processes.values().filter(|p| p.ppid == parent) {
+                if found.insert(child.pid) {
+                    frontier.push(child.pid);
+                }
+            }
+        }
+        found.iter().filter_map(|pid| self.processes.get(pid)).collect()
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn read_process(pid: u32) -> Option<ProcessInfo> {
+    let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+    // `pid (comm) state ppid ...`; comm may itself contain spaces and parentheses
+    let open = stat.find('(')?;
+    let close = stat.rfind(')')?;
+    let name = stat.get(open + 1..close)?;
+    let mut fields = stat.get(close + 1..)?.split_whitespace();
+    let state = fields.next()?.chars().next()?;
+    let parent = fields.next()?.parse().ok()?;
+    let cmdline = std::fs::read(format!("/proc/{pid}/cmdline")).unwrap_or_default();
+    let command = cmdline
+        .split(|&b| b == 0)
+        .filter(|arg| !arg.is_empty())
+        .map(String::from_utf8_lossy)
+        .collect::<Vec<_>>()
+        .join(" ");
+    let command = if command.is_empty() { format!("[{name}]") } else { command };
+    Some(ProcessInfo { pid, ppid: parent, state, command })
+}
+
+/// Fixture that fails the test if it leaks child processes
+///
+/// Created at Arrange; [`verify`](Self::verify) (or drop) checks that every descendant
+/// started since then has exited and been reaped, after a grace period. Dropping during
+/// a panic skips the check so the original failure is reported.
+#[derive(Debug)]
+pub struct SubprocessGuard {
+    root: u32,
+    baseline: BTreeSet<u32>,
+    watched: Vec<String>,
+    allowed: Vec<String>,
+    grace: Duration,
+    verified: bool,
+}
+
+impl SubprocessGuard {
+    /// Record the current process's descendants as the baseline
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process table cannot be read (see [`ProcessTable::capture`]).
+    pub fn new() -> io::Result<Self> {
+        let root = std::process::id();
+        let baseline =
+            ProcessTable::capture()?.descendants_of(root).iter().map(|p| p.pid).collect();
+        Ok(Self {
+            root,
+            baseline,
+            watched: Vec::new(),
+            allowed: Vec::new(),
+            grace: DEFAULT_LEAK_GRACE,
+            verified: false,
+        })
+    }
+
+    /// Only consider descendants whose command line contains `pattern`
+    ///
+    /// May be called repeatedly; a process matching any watched pattern is considered.
+    #[must_use]
+    pub fn watch(mut self, pattern: impl Into<String>) -> Self {
+        self.watched.push(pattern.into());
+        self
+    }
+
+    /// Ignore descendants whose command line contains `pattern` (e.g. a shared sidecar)
+    #[must_use]
+    pub fn allow(mut self, pattern: impl Into<String>) -> Self {
+        self.allowed.push(pattern.into());
+        self
+    }
+
+    /// How long new descendants get to exit before they count as leaked
+    #[must_use]
+    pub const fn grace(mut self, grace: Duration) -> Self {
+        self.grace = grace;
+        self
+    }
+
+    /// New descendants alive right now (no grace period)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the process table cannot be read.
+    pub fn leaked(&self) -> io::Result<Vec<ProcessInfo>> {
+        let table = ProcessTable::capture()?;
+        Ok(table
+            .descendants_of(self.root)
+            .into_iter()
+            .filter(|p| !self.baseline.contains(&p.pid))
+            .filter(|p| {
+                self.watched.is_empty() || self.watched.iter().any(|w| p.command.contains(w))
+            })
+            .filter(|p| !self.allowed.iter().any(|a| p.command.contains(a)))
+            .cloned()
+            .collect())
+    }
+
+    /// Fail the test if leaked descendants remain once the grace period passes
+    ///
+    /// # Panics
+    ///
+    /// Panics with every leaked PID and command line, or if the process table cannot
+    /// be read.
+    #[track_caller]
+    pub fn verify(mut self) {
+        self.verified = true;
+        if let Some(failure) = self.check() {
+            failure.raise();
+        }
+    }
+
+    #[track_caller]
+    fn check(&self) -> Option<TddFailure> {
+        let deadline = Instant::now() + self.grace;
+        loop {
+            let leaked = match self.leaked() {
+                Ok(leaked) => leaked,
+                Err(e) => {
+                    return Some(TddFailure::new(
+                        FailureKind::Constraint,
+                        format!("🚨 Could not read the process table to check for leaks: {e}"),
+                    ));
+                }
+            };
+            if leaked.is_empty() {
+                return None;
+            }
+            if Instant::now() >= deadline
+                || cancellable_sleep("leaked subprocesses to exit", POLL_INTERVAL).is_err()
+            {
+                return Some(Self::failure(&leaked, self.grace));
+            }
+        }
+    }
+
+    #[track_caller]
+    fn failure(leaked: &[ProcessInfo], grace: Duration) -> TddFailure {
+        let list = leaked.iter().fold(String::new(), |mut out, p| {
+            out.push_str("\n   - ");
+            out.push_str(&p.to_string());
+            out
+        });
+        let pids = leaked.iter().map(|p| p.pid.to_string()).collect::<Vec<_>>().join(",");
+        TddFailure::new(
+            FailureKind::Constraint,
+            format!(
+                "🚨 Test leaked {} child process(es) still running {grace:?} after teardown:{list}\n   💡 FIX: Wait on or kill every child the test starts",
+                leaked.len()
+            ),
+        )
+        .with_context("leaked_pids", pids)
+    }
+}
+
+impl Drop for SubprocessGuard {
+    fn drop(&mut self) {
+        if self.verified || std::thread::panicking() {
+            return;
+        }
+        self.verified = true;
+        if let Some(failure) = self.check() {
+            failure.raise();
+        }
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::process::Command;
+
+    test!(test_process_table_lists_children_with_command_lines, {
+        // Arrange
+        #[allow(clippy::unwrap_used)] // Test code - `sleep` is on every Linux host
+        let mut child = Command::new("sleep").arg("7.101").spawn().unwrap();
+
+        // Act
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let table = ProcessTable::capture().unwrap();
+        let _ = child.kill();
+        let _ = child.wait();
+
+        // Assert
+        let descendants = table.descendants_of(std::process::id());
+        let found = descendants.iter().find(|p| p.pid == child.id());
+        assert_eq!(found.map(|p| p.command.as_str()), Some("sleep 7.101"));
+        assert!(table.get(std::process::id()).is_some());
+    });
+
+    test!(test_guard_reports_unreaped_child_and_passes_once_reaped, {
+        // Arrange
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let guard = SubprocessGuard::new().unwrap().watch("7.202").grace(Duration::ZERO);
+        #[allow(clippy::unwrap_used)] // Test code - `sleep` is on every Linux host
+        let mut child = Command::new("sleep").arg("7.202").spawn().unwrap();
+
+        // Act
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let leaked = guard.leaked().unwrap();
+        let _ = child.kill();
+        let _ = child.wait();
+
+        // Assert
+        assert_eq!(leaked.iter().map(|p| p.pid).collect::<Vec<_>>(), [child.id()]);
+        guard.verify();
+    });
+
+    test!(test_verify_fails_with_pids_and_commands, {
+        // Arrange
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let guard = SubprocessGuard::new().unwrap().watch("7.303").grace(Duration::from_millis(50));
+        #[allow(clippy::unwrap_used)] // Test code - `sleep` is on every Linux host
+        let mut child = Command::new("sleep").arg("7.303").spawn().unwrap();
+
+        // Act
+        let result = TddFailure::catch(|| guard.verify());
+        let _ = child.kill();
+        let _ = child.wait();
+
+        // Assert
+        #[allow(clippy::unwrap_used)] // Test code - verify must fail while the child runs
+        let failure = result.unwrap_err();
+        assert_eq!(failure.kind(), FailureKind::Constraint);
+        assert!(failure.message().contains(&format!("pid {}", child.id())));
+        assert!(failure.message().contains("sleep 7.303"));
+    });
+}
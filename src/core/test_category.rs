@@ -0,0 +1,183 @@
+//! Test Categories and Timing Budgets
+//!
+//! Tests declare a category — `unit`, `integration`, or `e2e` — and the test macros
+//! enforce that category's wall-clock budget: a test that runs longer fails with a
+//! critical alert naming the category, its budget, and the time taken.
+//!
+//! Budgets come from the `[test]` section of `chicago-tdd-tools.toml`:
+//!
+//! ```toml
+//! [test]
+//! unit_timeout_seconds = 1
+//! integration_timeout_seconds = 30
+//! e2e_timeout_seconds = 300
+//! ```
+//!
+//! ```rust
+//! use chicago_tdd_tools::test;
+//!
+//! test!(test_parses_order, category = unit, {
+//!     // Arrange
+//!     let raw = "42";
+//!
+//!     // Act
+//!     let id: u32 = raw.parse()?;
+//!
+//!     // Assert
+//!     assert_eq!(id, 42);
+//!     Ok::<(), std::num::ParseIntError>(())
+//! });
+//! ```
+//!
+//! Synchronous tests are timed and fail after the body returns; `async_test!` and
+//! `fixture_test!` use the budget as their timeout. Tests that declare no category are
+//! not budgeted.
+
+use crate::core::config::loading::{ChicagoConfig, TestSection};
+use crate::core::failure::{FailureKind, TddFailure};
+use std::fmt;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// Kind of test, which determines its wall-clock budget
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TestCategory {
+    /// Fast, isolated tests (`unit_timeout_seconds`, default 1s)
+    Unit,
+    /// Tests touching containers, networks, or other processes
+    /// (`integration_timeout_seconds`, default 30s)
+    Integration,
+    /// Full system flows (`e2e_timeout_seconds`, default 300s)
+    E2e,
+}
+
+impl TestCategory {
+    /// Every category
+    pub const ALL: [Self; 3] = [Self::Unit, Self::Integration, Self::E2e];
+
+    /// Name used in test declarations, e.g. `integration`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Unit => "unit",
+            Self::Integration => "integration",
+            Self::E2e => "e2e",
+        }
+    }
+
+    /// Configured budget in whole seconds (read once per process)
+    #[must_use]
+    pub fn budget_secs(self) -> u64 {
+        static BUDGETS: OnceLock<TestSection> = OnceLock::new();
+        let budgets = BUDGETS.get_or_init(|| ChicagoConfig::current().test);
+        match self {
+            Self::Unit => budgets.unit_timeout_seconds.get(),
+            Self::Integration => budgets.integration_timeout_seconds.get(),
+            Self::E2e => budgets.e2e_timeout_seconds.get(),
+        }
+    }
+
+    /// Configured budget
+    #[must_use]
+    pub fn budget(self) -> Duration {
+        Duration::from_secs(self.budget_secs())
+    }
+}
+
+impl fmt::Display for TestCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Categories by the names tests declare them with (`category = integration`)
+///
+/// The test macros resolve the declared name here, so a misspelled category is a
+/// compile error.
+#[allow(non_upper_case_globals)] // Mirror the lowercase names used in test declarations
+pub mod categories {
+    use super::TestCategory;
+
+    /// [`TestCategory::Unit`]
+    pub const unit: TestCategory = TestCategory::Unit;
+    /// [`TestCategory::Integration`]
+    pub const integration: TestCategory = TestCategory::Integration;
+    /// [`TestCategory::E2e`]
+    pub const e2e: TestCategory = TestCategory::E2e;
+}
+
+/// Fail `test` if `elapsed` exceeds the budget of `category`
+///
+/// Called by the test macros after a categorized test body returns.
+///
+/// # Panics
+///
+/// Raises a [`FailureKind::Constraint`] failure, after a critical alert, when the budget
+/// is exceeded.
+#[track_caller]
+pub fn enforce_budget(test: &str, category: TestCategory, elapsed: Duration) {
+    let budget = category.budget();
+    if elapsed <= budget {
+        return;
+    }
+    let message = format!(
+        "Test '{test}' took {elapsed:?}, exceeding the {category} test budget of {budget:?}"
+    );
+    crate::alert_critical!(
+        &message,
+        format!(
+            "Speed the test up, or declare a slower category (or raise `{category}_timeout_seconds` in chicago-tdd-tools.toml)"
+        )
+    );
+    TddFailure::new(FailureKind::Constraint, message)
+        .with_context("category", category.as_str())
+        .with_context("elapsed", format!("{elapsed:?}"))
+        .with_context("budget", format!("{budget:?}"))
+        .raise()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_categories_resolve_configured_budgets, {
+        // Arrange
+        let budgets: Vec<u64> = TestCategory::ALL.iter().map(|c| c.budget_secs()).collect();
+
+        // Assert
+        assert_eq!(budgets, vec![1, 30, 300]);
+        assert_eq!(categories::integration, TestCategory::Integration);
+        assert_eq!(TestCategory::E2e.to_string(), "e2e");
+    });
+
+    test!(test_enforce_budget_fails_over_budget_tests, {
+        // Act
+        let within = TddFailure::catch(|| {
+            enforce_budget("t", TestCategory::Unit, Duration::from_millis(10));
+        });
+        let over = TddFailure::catch(|| {
+            enforce_budget("suite::slow", TestCategory::Unit, Duration::from_secs(2));
+        });
+
+        // Assert
+        assert!(within.is_ok());
+        let failure = over.unwrap_err();
+        assert_eq!(failure.kind(), FailureKind::Constraint);
+        assert_eq!(
+            failure.message(),
+            "Test 'suite::slow' took 2s, exceeding the unit test budget of 1s"
+        );
+    });
+
+    test!(test_categorized_test_within_budget, category = unit, {
+        // Arrange
+        let values = [3, 1, 2];
+
+        // Act
+        let max = values.iter().max();
+
+        // Assert
+        assert_eq!(max, Some(&3));
+    });
+}
@@ -0,0 +1,301 @@
+//! Async Test Runtimes
+//!
+//! `async_test!` and `fixture_test!` run on `#[tokio::test]`'s current-thread runtime by
+//! default. A `runtime(...)` clause builds a [`TestRuntime`] instead, so a test can pick
+//! its scheduling without dropping down to raw `#[tokio::test]`:
+//!
+//! - `flavor = current_thread` or `flavor = multi_thread`
+//! - `worker_threads = N` (multi-thread only)
+//! - `start_paused = true` (current-thread only, requires the `async` feature): tokio time
+//!   starts paused and auto-advances whenever the runtime is idle
+//!
+//! The test's timeout counts wall-clock time, so a paused test can sleep for an hour of
+//! tokio time within a one-second budget.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::async_test;
+//!
+//! async_test!(test_runs_on_worker_pool, runtime(flavor = multi_thread, worker_threads = 2), {
+//!     // Act
+//!     let flavor = tokio::runtime::Handle::current().runtime_flavor();
+//!
+//!     // Assert
+//!     assert_eq!(flavor, tokio::runtime::RuntimeFlavor::MultiThread);
+//! });
+//! ```
+
+use std::future::Future;
+use std::pin::pin;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Scheduler a [`TestRuntime`] runs on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RuntimeFlavor {
+    /// Everything runs on the test thread (the `#[tokio::test]` default)
+    #[default]
+    CurrentThread,
+    /// Tasks run on a pool of worker threads
+    MultiThread,
+}
+
+/// Invalid runtime configuration
+#[derive(Error, Debug)]
+pub enum TestRuntimeError {
+    /// `start_paused` was requested for a multi-thread runtime
+    #[error("start_paused requires flavor = current_thread")]
+    PausedMultiThread,
+    /// `start_paused` was requested without tokio's test utilities
+    #[error("start_paused requires the `async` feature")]
+    PausedUnsupported,
+    /// `worker_threads` was set for a current-thread runtime, or set to zero
+    #[error("worker_threads = {0} is invalid (requires flavor = multi_thread and at least 1)")]
+    WorkerThreads(usize),
+    /// Tokio failed to build the runtime
+    #[error("failed to build tokio runtime: {0}")]
+    Build(#[source] std::io::Error),
+}
+
+/// Tokio runtime configuration for one async test
+///
+/// Built by the `runtime(...)` clause of `async_test!` and `fixture_test!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TestRuntime {
+    flavor: RuntimeFlavor,
+    worker_threads: Option<usize>,
+    start_paused: bool,
+}
+
+impl TestRuntime {
+    /// Current-thread runtime on the real clock
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { flavor: RuntimeFlavor::CurrentThread, worker_threads: None, start_paused: false }
+    }
+
+    /// Set the scheduler
+    #[must_use]
+    pub const fn flavor(mut self, flavor: RuntimeFlavor) -> Self {
+        self.flavor = flavor;
+        self
+    }
+
+    /// Set the number of worker threads (multi-thread only)
+    #[must_use]
+    pub const fn worker_threads(mut self, count: usize) -> Self {
+        self.worker_threads = Some(count);
+        self
+    }
+
+    /// Start with tokio time paused (current-thread only)
+    #[must_use]
+    pub const fn start_paused(mut self, paused: bool) -> Self {
+        self.start_paused = paused;
+        self
+    }
+
+    /// Build the tokio runtime, with every driver enabled
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the configuration is inconsistent or tokio fails to build it.
+    pub fn build(&self) -> Result<tokio::runtime::Runtime, TestRuntimeError> {
+        let mut builder = match (self.flavor, self.worker_threads) {
+            (RuntimeFlavor::CurrentThread, None) => tokio::runtime::Builder::new_current_thread(),
+            (RuntimeFlavor::CurrentThread, Some(count)) | (_, Some(count @ 0)) => {
+                return Err(TestRuntimeError::WorkerThreads(count))
+            }
+            (RuntimeFlavor::MultiThread, count) => {
+                let mut builder = tokio::runtime::Builder::new_multi_thread();
+                if let Some(count) = count {
+                    builder.worker_threads(count);
+                }
+                builder
+            }
+        };
+        builder.enable_all();
+        if self.start_paused {
+            if self.flavor == RuntimeFlavor::MultiThread {
+                return Err(TestRuntimeError::PausedMultiThread);
+            }
+            #[cfg(feature = "async")]
+            builder.start_paused(true);
+            #[cfg(not(feature = "async"))]
+            return Err(TestRuntimeError::PausedUnsupported);
+        }
+        builder.build().map_err(TestRuntimeError::Build)
+    }
+
+    /// Run `future` to completion on a fresh runtime, failing the test `test` if it
+    /// takes longer than `timeout_secs` of wall-clock time
+    ///
+    /// # Panics
+    ///
+    /// Panics if the runtime cannot be built or the timeout passes.
+    #[allow(clippy::panic)] // Test harness - panicking fails the test
+    pub fn block_on<F: Future>(&self, test: &str, timeout_secs: u64, future: F) -> F::Output {
+        let runtime = self
+            .build()
+            .unwrap_or_else(|error| panic!("Test '{test}' has an invalid runtime: {error}"));
+        // A thread outside the runtime keeps the deadline, so paused tokio time still
+        // auto-advances and only real time counts against the budget
+        let (expired_tx, expired_rx) = futures::channel::oneshot::channel::<()>();
+        let (done_tx, done_rx) = mpsc::channel::<()>();
+        let watchdog = std::thread::spawn(move || {
+            if done_rx.recv_timeout(Duration::from_secs(timeout_secs))
+                == Err(RecvTimeoutError::Timeout)
+            {
+                expired_tx.send(()).ok();
+            }
+        });
+        let future = pin!(future);
+        let outcome = runtime.block_on(futures::future::select(future, expired_rx));
+        drop(done_tx);
+        drop(watchdog.join());
+        match outcome {
+            futures::future::Either::Left((output, _)) => output,
+            futures::future::Either::Right(_) => panic!(
+                "Test '{test}' exceeded {timeout_secs}s timeout (SLA violation). \
+                Expected timeout: {timeout_secs}s. \
+                Use async_test_with_timeout! or fixture_test_with_timeout! with a longer \
+                timeout for integration tests."
+            ),
+        }
+    }
+}
+
+/// Test body output accepted by the async test macros: `()` or `Result<(), E>`
+#[doc(hidden)]
+pub trait AsyncTestOutput {
+    /// Fail the test if the body returned an error
+    fn handle(self);
+}
+
+impl AsyncTestOutput for () {
+    fn handle(self) {}
+}
+
+impl<E: std::fmt::Debug> AsyncTestOutput for Result<(), E> {
+    #[allow(clippy::panic)] // Test harness - an error return fails the test
+    fn handle(self) {
+        if let Err(e) = self {
+            panic!("Test failed: {e:?}");
+        }
+    }
+}
+
+/// Build a [`TestRuntime`] from `runtime(...)` settings
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __test_runtime {
+    (@build $runtime:expr;) => {
+        $runtime
+    };
+    (@build $runtime:expr; flavor = current_thread $(, $($rest:tt)*)?) => {
+        $crate::__test_runtime!(
+            @build $runtime.flavor($crate::core::test_runtime::RuntimeFlavor::CurrentThread);
+            $($($rest)*)?
+        )
+    };
+    (@build $runtime:expr; flavor = multi_thread $(, $($rest:tt)*)?) => {
+        $crate::__test_runtime!(
+            @build $runtime.flavor($crate::core::test_runtime::RuntimeFlavor::MultiThread);
+            $($($rest)*)?
+        )
+    };
+    (@build $runtime:expr; worker_threads = $count:expr $(, $($rest:tt)*)?) => {
+        $crate::__test_runtime!(@build $runtime.worker_threads($count); $($($rest)*)?)
+    };
+    (@build $runtime:expr; start_paused = $paused:expr $(, $($rest:tt)*)?) => {
+        $crate::__test_runtime!(@build $runtime.start_paused($paused); $($($rest)*)?)
+    };
+    (@build $runtime:expr; $($unknown:tt)+) => {
+        compile_error!(concat!(
+            "unknown runtime setting `",
+            stringify!($($unknown)+),
+            "` (expected flavor = current_thread | multi_thread, worker_threads = N, start_paused = bool)"
+        ))
+    };
+    ($($setting:tt)*) => {
+        $crate::__test_runtime!(@build $crate::core::test_runtime::TestRuntime::new(); $($setting)*)
+    };
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Test code: unwrap is acceptable
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_default_runtime_is_current_thread, {
+        // Arrange
+        let runtime = crate::__test_runtime!();
+
+        // Act
+        let flavor = runtime.build().unwrap().handle().runtime_flavor();
+
+        // Assert
+        assert_eq!(runtime, TestRuntime::new());
+        assert_eq!(flavor, tokio::runtime::RuntimeFlavor::CurrentThread);
+    });
+
+    test!(test_settings_build_multi_thread_runtime, {
+        // Arrange
+        let runtime = crate::__test_runtime!(flavor = multi_thread, worker_threads = 2,);
+
+        // Act
+        let flavor = runtime.build().unwrap().handle().runtime_flavor();
+
+        // Assert
+        assert_eq!(
+            runtime,
+            TestRuntime::new().flavor(RuntimeFlavor::MultiThread).worker_threads(2)
+        );
+        assert_eq!(flavor, tokio::runtime::RuntimeFlavor::MultiThread);
+    });
+
+    test!(test_inconsistent_settings_are_rejected, {
+        // Arrange
+        let paused_pool = TestRuntime::new().flavor(RuntimeFlavor::MultiThread).start_paused(true);
+        let single_with_workers = TestRuntime::new().worker_threads(4);
+        let empty_pool = TestRuntime::new().flavor(RuntimeFlavor::MultiThread).worker_threads(0);
+
+        // Act + Assert
+        assert!(matches!(paused_pool.build(), Err(TestRuntimeError::PausedMultiThread)));
+        assert!(matches!(single_with_workers.build(), Err(TestRuntimeError::WorkerThreads(4))));
+        assert!(matches!(empty_pool.build(), Err(TestRuntimeError::WorkerThreads(0))));
+    });
+
+    test!(test_block_on_times_out_on_wall_clock, {
+        // Arrange
+        let runtime = TestRuntime::new();
+
+        // Act
+        let failure = crate::core::failure::TddFailure::catch(|| {
+            runtime.block_on("hangs", 0, std::future::pending::<()>());
+        })
+        .unwrap_err();
+
+        // Assert
+        assert!(failure.message().contains("Test 'hangs' exceeded 0s timeout"), "{failure}");
+    });
+
+    #[cfg(feature = "async")]
+    test!(test_paused_runtime_auto_advances_within_timeout, {
+        // Arrange
+        let runtime = crate::__test_runtime!(start_paused = true);
+
+        // Act: An hour of tokio time passes instantly
+        let elapsed = runtime.block_on("paused", 1, async {
+            let start = tokio::time::Instant::now();
+            tokio::time::sleep(Duration::from_secs(3600)).await;
+            start.elapsed()
+        });
+
+        // Assert
+        assert!(elapsed >= Duration::from_secs(3600));
+    });
+}
@@ -2,6 +2,7 @@
 //!
 //! Common testing utilities that address frequently requested features from the Rust testing community.
 
+use crate::core::cancellation::cancellable_sleep;
 use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
@@ -70,7 +71,9 @@ impl RetryConfig {
     ///
     /// # Errors
     ///
-    /// Returns the last error if all retry attempts fail.
+    /// Returns the last error if all retry attempts fail, or if the current
+    /// [`TestCancellation`](crate::core::cancellation::TestCancellation) is cancelled
+    /// between attempts.
     ///
     /// # Panics
     ///
@@ -95,7 +98,10 @@ impl RetryConfig {
                         } else {
                             self.delay
                         };
-                        std::thread::sleep(delay);
+                        // A cancelled test stops retrying and reports the last error
+                        if cancellable_sleep("retry", delay).is_err() {
+                            break;
+                        }
                     }
                 }
             }
@@ -0,0 +1,400 @@
+//! > 📚 Reference
+//!
+//! Regex and Glob Text Matching
+//!
+//! Runtime behind [`assert_matches_regex!`](crate::assert_matches_regex) and
+//! [`assert_matches_glob!`](crate::assert_matches_glob). A successful match returns a
+//! [`TextMatch`] with the captured groups (for globs, one group per wildcard), so
+//! tests can pull values such as ports or IDs out of log and CLI output instead of
+//! comparing it exactly. A failed match reports how far the text matched the pattern
+//! and highlights the first non-matching region.
+//!
+//! # Glob syntax
+//!
+//! A glob must match the whole text:
+//!
+//! - `*` matches any run of characters (including newlines)
+//! - `?` matches one character
+//! - `[abc]`, `[a-z]`, `[!abc]` match one character from (or not from) a set
+//! - `\` escapes the next character
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::text_match::{match_glob, match_regex};
+//!
+//! let line = "INFO server listening on 127.0.0.1:8080";
+//!
+//! let found = match_regex(line, r"listening on (?P<host>[\d.]+):(\d+)").unwrap();
+//! assert_eq!(found.name("host"), Some("127.0.0.1"));
+//! assert_eq!(found.group(2), Some("8080"));
+//!
+//! let found = match_glob(line, "INFO * on *:*").unwrap();
+//! assert_eq!(found.group(3), Some("8080"));
+//!
+//! let error = match_glob(line, "INFO server stopped*").unwrap_err();
+//! assert_eq!(error.matched_len(), Some("INFO server ".len()));
+//! ```
+
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Upper bound on DFA steps spent locating the non-matching region
+///
+/// Finding the region is quadratic in the text length for unanchored patterns; past
+/// this budget the failure is reported without a highlight.
+pub const MISMATCH_SEARCH_BUDGET: usize = 1_000_000;
+
+/// Pattern language of a [`TextMatchError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternSyntax {
+    /// Regular expression (`regex` crate syntax, unanchored)
+    Regex,
+    /// Glob (whole-text match)
+    Glob,
+}
+
+impl fmt::Display for PatternSyntax {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Regex => "regex",
+            Self::Glob => "glob",
+        })
+    }
+}
+
+/// A successful match with its captured groups
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextMatch {
+    groups: Vec<Option<String>>,
+    names: BTreeMap<String, usize>,
+}
+
+impl TextMatch {
+    fn from_captures(regex: &Regex, captures: &regex::Captures<'_>) -> Self {
+        let groups = captures.iter().map(|group| group.map(|m| m.as_str().to_string())).collect();
+        let names = regex
+            .capture_names()
+            .enumerate()
+            .filter_map(|(index, name)| name.map(|name| (name.to_string(), index)))
+            .collect();
+        Self { groups, names }
+    }
+
+    /// The matched text (the whole text for globs)
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        self.group(0).unwrap_or_default()
+    }
+
+    /// Captured group by index (`0` is the whole match; globs number wildcards from `1`)
+    ///
+    /// Returns `None` for an unknown index or a group that did not participate.
+    #[must_use]
+    pub fn group(&self, index: usize) -> Option<&str> {
+        self.groups.get(index)?.as_deref()
+    }
+
+    /// Captured group by name (`(?P<name>...)`, regex only)
+    #[must_use]
+    pub fn name(&self, name: &str) -> Option<&str> {
+        self.group(*self.names.get(name)?)
+    }
+
+    /// Captured groups after the whole match, in order
+    #[must_use]
+    pub fn groups(&self) -> Vec<Option<&str>> {
+        self.groups.iter().skip(1).map(Option::as_deref).collect()
+    }
+}
+
+/// A failed match
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextMatchError {
+    /// The pattern itself is invalid
+    InvalidPattern {
+        /// Pattern language
+        syntax: PatternSyntax,
+        /// The pattern as written
+        pattern: String,
+        /// Why it is invalid
+        reason: String,
+    },
+    /// The text does not match
+    NoMatch {
+        /// Pattern language
+        syntax: PatternSyntax,
+        /// The pattern as written
+        pattern: String,
+        /// The text that was searched
+        text: String,
+        /// Bytes of `text` consistent with the pattern before it diverged
+        matched: Option<(usize, usize)>,
+    },
+}
+
+impl TextMatchError {
+    /// Length in bytes of the longest part of the text the pattern could still match
+    ///
+    /// `None` for invalid patterns and when the region could not be determined.
+    #[must_use]
+    pub fn matched_len(&self) -> Option<usize> {
+        match self {
+            Self::NoMatch { matched, .. } => matched.map(|(start, end)| end - start),
+            Self::InvalidPattern { .. } => None,
+        }
+    }
+
+    /// Byte offset in the text where matching broke down
+    #[must_use]
+    pub fn mismatch_offset(&self) -> Option<usize> {
+        match self {
+            Self::NoMatch { matched, .. } => matched.map(|(_, end)| end),
+            Self::InvalidPattern { .. } => None,
+        }
+    }
+
+    /// The line containing the mismatch with a `^` marker under its non-matching part
+    #[must_use]
+    pub fn highlight(&self) -> Option<String> {
+        let Self::NoMatch { text, matched: Some((start, end)), .. } = self else {
+            return None;
+        };
+        let line_start = text[..*end].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = text[*end..].find('\n').map_or(text.len(), |i| end + i);
+        let line = &text[line_start..line_end];
+        if line.chars().any(char::is_control) {
+            return None;
+        }
+        let matched_from = (*start).max(line_start);
+        let offset = |to: usize| text[line_start..to].chars().count();
+        let marker = format!(
+            "{}{}{}",
+            " ".repeat(offset(matched_from)),
+            "~".repeat(offset(*end) - offset(matched_from)),
+            "^".repeat(text[*end..line_end].chars().count().max(1)),
+        );
+        Some(format!("{line}\n{marker}"))
+    }
+}
+
+impl fmt::Display for TextMatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPattern { syntax, pattern, reason } => {
+                write!(f, "invalid {syntax} `{pattern}`: {reason}")
+            }
+            Self::NoMatch { syntax, pattern, text, matched } => {
+                write!(f, "text does not match {syntax} `{pattern}`\n  text: {text:?}")?;
+                match (matched, self.highlight()) {
+                    (Some((_, end)), Some(highlight)) => {
+                        write!(f, "\n  first non-matching region at byte {end} (`^`):")?;
+                        for line in highlight.lines() {
+                            write!(f, "\n    {line}")?;
+                        }
+                        Ok(())
+                    }
+                    (Some((_, end)), None) => {
+                        write!(
+                            f,
+                            "\n  first non-matching region at byte {end}: {:?}",
+                            &text[*end..]
+                        )
+                    }
+                    (None, _) => Ok(()),
+                }
+            }
+        }
+    }
+}
+
+impl std::error::Error for TextMatchError {}
+
+/// Search `text` for `pattern` (unanchored; use `^`/`$` to anchor)
+///
+/// # Errors
+///
+/// Returns [`TextMatchError::InvalidPattern`] if `pattern` does not compile, or
+/// [`TextMatchError::NoMatch`] with the non-matching region if it does not match.
+pub fn match_regex(text: &str, pattern: &str) -> Result<TextMatch, TextMatchError> {
+    match_compiled(text, pattern, pattern, PatternSyntax::Regex)
+}
+
+/// Match the whole of `text` against `glob`
+///
+/// # Errors
+///
+/// Returns [`TextMatchError::NoMatch`] with the non-matching region if it does not match.
+pub fn match_glob(text: &str, glob: &str) -> Result<TextMatch, TextMatchError> {
+    match_compiled(text, &glob_to_regex(glob), glob, PatternSyntax::Glob)
+}
+
+/// Translate a glob into an anchored regex with one capture group per wildcard
+#[must_use]
+pub fn glob_to_regex(glob: &str) -> String {
+    let mut regex = String::from("(?s)^");
+    let mut chars = glob.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' => regex.push_str("(.*?)"),
+            '?' => regex.push_str("(.)"),
+            '\\' => regex.push_str(&regex::escape(&chars.next().unwrap_or('\\').to_string())),
+            '[' => {
+                let rest: String = chars.clone().collect();
+                // `]` right after `[` or `[!` is a literal member, not the end of the set
+                let body_start = usize::from(rest.starts_with('!'));
+                let close = rest
+                    .char_indices()
+                    .skip(body_start + 1)
+                    .find(|&(_, c)| c == ']')
+                    .map(|(i, _)| i);
+                let Some(close) = close.filter(|&close| close > body_start) else {
+                    regex.push_str(r"\[");
+                    continue;
+                };
+                regex.push_str(if body_start == 1 { "([^" } else { "([" });
+                for member in rest[body_start..close].chars() {
+                    if matches!(member, '[' | ']' | '\\' | '&' | '~' | '^') {
+                        regex.push('\\');
+                    }
+                    regex.push(member);
+                }
+                regex.push_str("])");
+                for _ in 0..rest[..=close].chars().count() {
+                    chars.next();
+                }
+            }
+            other => regex.push_str(&regex::escape(&other.to_string())),
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+fn match_compiled(
+    text: &str,
+    regex: &str,
+    pattern: &str,
+    syntax: PatternSyntax,
+) -> Result<TextMatch, TextMatchError> {
+    let compiled = Regex::new(regex).map_err(|e| TextMatchError::InvalidPattern {
+        syntax,
+        pattern: pattern.to_string(),
+        reason: e.to_string(),
+    })?;
+    if let Some(captures) = compiled.captures(text) {
+        return Ok(TextMatch::from_captures(&compiled, &captures));
+    }
+    Err(TextMatchError::NoMatch {
+        syntax,
+        pattern: pattern.to_string(),
+        text: text.to_string(),
+        matched: longest_viable_prefix(regex, text),
+    })
+}
+
+/// Find the start and end of the longest stretch of `text` that is a prefix of some
+/// match of `regex`, by walking a lazy DFA from each start position
+fn longest_viable_prefix(regex: &str, text: &str) -> Option<(usize, usize)> {
+    use regex_automata::hybrid::dfa::DFA;
+    use regex_automata::{Anchored, Input};
+
+    let dfa = DFA::new(regex).ok()?;
+    let mut cache = dfa.create_cache();
+    let bytes = text.as_bytes();
+    let mut budget = MISMATCH_SEARCH_BUDGET;
+    let mut best: Option<(usize, usize)> = None;
+
+    for start in (0..=bytes.len()).filter(|&i| text.is_char_boundary(i)) {
+        let input = Input::new(text).range(start..).anchored(Anchored::Yes);
+        let mut state = dfa.start_state_forward(&mut cache, &input).ok()?;
+        let mut end = start;
+        for &byte in &bytes[start..] {
+            budget = budget.checked_sub(1)?;
+            state = dfa.next_state(&mut cache, state, byte).ok()?;
+            if state.is_quit() {
+                return None;
+            }
+            if state.is_dead() {
+                break;
+            }
+            end += 1;
+        }
+        // Back off to a character boundary so the region can be sliced and displayed
+        while !text.is_char_boundary(end) {
+            end -= 1;
+        }
+        if best.is_none_or(|(s, e)| end - start > e - s) {
+            best = Some((start, end));
+        }
+        if end == bytes.len() {
+            break;
+        }
+    }
+    best
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_glob_wildcards_capture_in_order, {
+        // Arrange
+        let text = "GET /orders/42 HTTP/1.1";
+
+        // Act
+        let found = match_glob(text, "GET /orders/* HTTP/?.?").unwrap();
+
+        // Assert
+        assert_eq!(found.as_str(), text);
+        assert_eq!(found.groups(), [Some("42"), Some("1"), Some("1")]);
+    });
+
+    test!(test_glob_character_classes_and_escapes, {
+        assert!(match_glob("file-7.log", "file-[0-9].log").is_ok());
+        assert!(match_glob("file-x.log", "file-[!0-9].log").is_ok());
+        assert!(match_glob("file-x.log", "file-[0-9].log").is_err());
+        assert!(match_glob("a*b", r"a\*b").is_ok());
+        assert!(match_glob("axb", r"a\*b").is_err());
+        assert!(match_glob("[x", "[x").is_ok());
+        assert_eq!(glob_to_regex("a.[]]"), r"(?s)^a\.([\]])$");
+    });
+
+    test!(test_regex_named_groups, {
+        // Arrange, Act
+        let found = match_regex("user=alice id=7", r"user=(?P<user>\w+) id=(\d+)").unwrap();
+
+        // Assert
+        assert_eq!(found.name("user"), Some("alice"));
+        assert_eq!(found.group(2), Some("7"));
+        assert_eq!(found.name("missing"), None);
+    });
+
+    test!(test_mismatch_reports_longest_matching_prefix, {
+        // Arrange, Act
+        let error = match_regex("status: ok\nready: no", r"ready: yes").unwrap_err();
+
+        // Assert
+        assert_eq!(error.mismatch_offset(), Some("status: ok\nready: ".len()));
+        assert_eq!(error.matched_len(), Some("ready: ".len()));
+        assert_eq!(error.highlight().unwrap(), "ready: no\n~~~~~~~^^");
+        assert!(error.to_string().contains("first non-matching region at byte 18"), "{error}");
+    });
+
+    test!(test_glob_mismatch_when_text_ends_early, {
+        // Arrange, Act
+        let error = match_glob("Listening on", "Listening on *:*").unwrap_err();
+
+        // Assert
+        assert_eq!(error.mismatch_offset(), Some("Listening on".len()));
+        assert_eq!(error.highlight().unwrap(), "Listening on\n~~~~~~~~~~~~^");
+    });
+
+    test!(test_invalid_regex_is_reported, {
+        let error = match_regex("text", "(unclosed").unwrap_err();
+        assert!(matches!(error, TextMatchError::InvalidPattern { .. }));
+        assert!(error.to_string().starts_with("invalid regex `(unclosed`"), "{error}");
+    });
+}
@@ -386,6 +386,7 @@ impl VerificationPipeline {
                         allow_storage: true,
                     },
                     metadata: HashMap::new(),
+                    requirements: HashMap::new(),
                 }
             })
             .collect()
@@ -0,0 +1,692 @@
+//! Database Fixture and SQL Assertions
+//!
+//! `DbFixture` wraps any database driver behind the small [`SqlExecutor`] trait and adds
+//! DB-aware assertions on top of it:
+//!
+//! - [`assert_row_count!`](crate::assert_row_count) for table cardinality
+//! - [`DbFixture::assert_query_snapshot`] for query results, normalized per column type
+//!   (timestamps and UUIDs redacted, floats rounded, JSON canonicalized, bytes hex-encoded)
+//! - [`DbFixture::assert_plan_snapshot`] for `EXPLAIN` plan regressions, with cost
+//!   estimates stripped
+//!
+//! Snapshots are plain-text files. Set `CHICAGO_TDD_BLESS=1` (the same switch as the CLI
+//! scenario runner) to write missing or outdated snapshots instead of failing.
+//!
+//! Arrange phases seed data declaratively with a [`SeedPlan`] (see [`seed`]), which
+//! records the inserted primary keys and deletes exactly those rows on teardown.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use chicago_tdd_tools::assert_row_count;
+//! use chicago_tdd_tools::integration::db::DbFixture;
+//!
+//! let mut db = DbFixture::new(MyPostgresExecutor::connect(&url)?);
+//! db.execute("INSERT INTO users (name) VALUES ('ada')")?;
+//!
+//! assert_row_count!(db, "users", 1);
+//! db.assert_query_snapshot("users_after_insert", "SELECT * FROM users")?;
+//! db.assert_plan_snapshot("users_by_name", "SELECT * FROM users WHERE name = 'ada'")?;
+//! ```
+
+pub mod seed;
+
+pub use seed::*;
+
+use crate::core::layout::ProjectLayout;
+use std::fmt::{self, Write as _};
+use std::path::PathBuf;
+use thiserror::Error;
+
+/// Environment variable that enables snapshot blessing
+const BLESS_ENV_VAR: &str = "CHICAGO_TDD_BLESS";
+
+/// Database fixture errors
+#[derive(Error, Debug)]
+pub enum DbError {
+    /// The driver rejected or failed a statement
+    #[error("🚨 Query failed: {message}\n   sql: {sql}")]
+    Query {
+        /// Statement text
+        sql: String,
+        /// Driver error message
+        message: String,
+    },
+    /// A table name is not a plain (optionally schema-qualified) identifier
+    #[error("🚨 Invalid SQL identifier: {0:?}")]
+    InvalidIdentifier(String),
+    /// A seed plan has duplicate steps, unknown dependencies, a cycle, or a dangling key
+    #[error("🚨 Invalid seed plan: {0}")]
+    InvalidSeedPlan(String),
+    /// The query returned a shape the assertion cannot use
+    #[error("🚨 Unexpected result for `{sql}`: {message}")]
+    UnexpectedResult {
+        /// Statement text
+        sql: String,
+        /// What was wrong
+        message: String,
+    },
+    /// The snapshot file is missing or differs from the actual output
+    #[error("🚨 Snapshot '{name}' {problem} ({path}); set {BLESS_ENV_VAR}=1 to update\n{detail}")]
+    SnapshotMismatch {
+        /// Snapshot name
+        name: String,
+        /// Snapshot file
+        path: String,
+        /// "is missing" or "differs"
+        problem: &'static str,
+        /// Rendered actual output or first differing line
+        detail: String,
+    },
+    /// Reading or writing a snapshot failed
+    #[error("🚨 Snapshot I/O failed for {path}: {source}")]
+    Io {
+        /// Snapshot file
+        path: String,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+}
+
+/// Result type for database fixture operations
+pub type DbResult<T> = Result<T, DbError>;
+
+/// Column type, used to pick a normalization
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlType {
+    /// Boolean
+    Bool,
+    /// Integer of any width
+    Int,
+    /// Floating point or numeric
+    Float,
+    /// Text of any kind
+    Text,
+    /// Binary data
+    Bytes,
+    /// Date, time, or timestamp
+    Timestamp,
+    /// UUID
+    Uuid,
+    /// JSON / JSONB
+    Json,
+}
+
+impl fmt::Display for SqlType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Bool => "bool",
+            Self::Int => "int",
+            Self::Float => "float",
+            Self::Text => "text",
+            Self::Bytes => "bytes",
+            Self::Timestamp => "timestamp",
+            Self::Uuid => "uuid",
+            Self::Json => "json",
+        };
+        f.write_str(name)
+    }
+}
+
+/// SQL dialect, for statements whose syntax differs between databases
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SqlDialect {
+    /// `PostgreSQL` (also the default)
+    #[default]
+    Postgres,
+    /// `MySQL` / `MariaDB`
+    MySql,
+    /// `SQLite`
+    Sqlite,
+}
+
+/// A single cell value
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlValue {
+    /// SQL NULL
+    Null,
+    /// Boolean
+    Bool(bool),
+    /// Integer
+    Int(i64),
+    /// Floating point
+    Float(f64),
+    /// Text (also used for timestamps and UUIDs in their textual form)
+    Text(String),
+    /// Binary data
+    Bytes(Vec<u8>),
+    /// JSON document
+    Json(serde_json::Value),
+}
+
+impl From<bool> for SqlValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<i32> for SqlValue {
+    fn from(value: i32) -> Self {
+        Self::Int(i64::from(value))
+    }
+}
+
+impl From<i64> for SqlValue {
+    fn from(value: i64) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<f64> for SqlValue {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<&str> for SqlValue {
+    fn from(value: &str) -> Self {
+        Self::Text(value.to_string())
+    }
+}
+
+impl From<String> for SqlValue {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+impl From<Vec<u8>> for SqlValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<serde_json::Value> for SqlValue {
+    fn from(value: serde_json::Value) -> Self {
+        Self::Json(value)
+    }
+}
+
+impl<T: Into<Self>> From<Option<T>> for SqlValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Self::Null, Into::into)
+    }
+}
+
+/// Column metadata
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SqlColumn {
+    /// Column name
+    pub name: String,
+    /// Column type
+    pub kind: SqlType,
+}
+
+impl SqlColumn {
+    /// Create a column description
+    #[must_use]
+    pub fn new(name: impl Into<String>, kind: SqlType) -> Self {
+        Self { name: name.into(), kind }
+    }
+}
+
+/// Rows returned by a query
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct QueryResult {
+    /// Result columns
+    pub columns: Vec<SqlColumn>,
+    /// Row values, one entry per column
+    pub rows: Vec<Vec<SqlValue>>,
+}
+
+/// Minimal driver interface behind [`DbFixture`]
+///
+/// Implement this for the driver your tests already use (postgres, rusqlite, sqlx, ...);
+/// only result conversion into [`QueryResult`] is needed.
+pub trait SqlExecutor {
+    /// Driver error type
+    type Error: fmt::Display;
+
+    /// Run a statement and return its rows (empty for statements without results)
+    ///
+    /// # Errors
+    ///
+    /// Returns the driver's error if the statement fails.
+    fn query(&mut self, sql: &str) -> Result<QueryResult, Self::Error>;
+
+    /// Prefix that turns a query into a plan query (`EXPLAIN QUERY PLAN` on `SQLite`)
+    fn explain_prefix(&self) -> &'static str {
+        "EXPLAIN "
+    }
+
+    /// Dialect used when generating statements (seed inserts and deletes)
+    fn dialect(&self) -> SqlDialect {
+        SqlDialect::Postgres
+    }
+}
+
+/// How query results are normalized before snapshotting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotNormalization {
+    /// Decimal places kept for float columns
+    pub float_precision: usize,
+    /// Replace timestamp values with `[timestamp]`
+    pub redact_timestamps: bool,
+    /// Replace UUID values with `[uuid]`
+    pub redact_uuids: bool,
+    /// Sort rows, for queries without a deterministic `ORDER BY`
+    pub sort_rows: bool,
+}
+
+impl Default for SnapshotNormalization {
+    fn default() -> Self {
+        Self { float_precision: 6, redact_timestamps: true, redact_uuids: true, sort_rows: false }
+    }
+}
+
+impl SnapshotNormalization {
+    fn cell(&self, kind: SqlType, value: &SqlValue) -> String {
+        match (kind, value) {
+            (_, SqlValue::Null) => "NULL".to_string(),
+            (SqlType::Timestamp, _) if self.redact_timestamps => "[timestamp]".to_string(),
+            (SqlType::Uuid, _) if self.redact_uuids => "[uuid]".to_string(),
+            (_, SqlValue::Float(value)) => format!("{value:.*}", self.float_precision),
+            (_, SqlValue::Bool(value)) => value.to_string(),
+            (_, SqlValue::Int(value)) => value.to_string(),
+            (_, SqlValue::Text(value)) => value.clone(),
+            (_, SqlValue::Bytes(bytes)) => format!("\\x{}", hex::encode(bytes)),
+            // serde_json maps are sorted, so this is canonical
+            (_, SqlValue::Json(value)) => value.to_string(),
+        }
+    }
+
+    /// Render a result as a normalized text table
+    #[must_use]
+    pub fn render(&self, result: &QueryResult) -> String {
+        let header: Vec<String> =
+            result.columns.iter().map(|c| format!("{} ({})", c.name, c.kind)).collect();
+        let mut rows: Vec<String> = result
+            .rows
+            .iter()
+            .map(|row| {
+                let cells: Vec<String> = result
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .map(|(column, value)| self.cell(column.kind, value))
+                    .collect();
+                cells.join(" | ")
+            })
+            .collect();
+        if self.sort_rows {
+            rows.sort();
+        }
+        let mut out = header.join(" | ");
+        out.push('\n');
+        for row in rows {
+            out.push_str(&row);
+            out.push('\n');
+        }
+        let _ = writeln!(out, "({} rows)", result.rows.len());
+        out
+    }
+}
+
+/// Whether `name` is a plain, optionally schema-qualified identifier
+fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && name.split('.').all(|part| {
+            !part.is_empty()
+                && !part.starts_with(|c: char| c.is_ascii_digit())
+                && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        })
+}
+
+/// Remove planner cost estimates (`(cost=... rows=... width=...)`) from a plan line
+fn strip_plan_costs(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut rest = line;
+    while let Some(start) = rest.find("(cost=") {
+        out.push_str(rest[..start].trim_end());
+        rest = rest[start..].find(')').map_or("", |end| &rest[start + end + 1..]);
+    }
+    out.push_str(rest);
+    out.trim_end().to_string()
+}
+
+/// Database fixture with SQL assertions
+#[derive(Debug)]
+pub struct DbFixture<E: SqlExecutor> {
+    executor: E,
+    snapshot_dir: PathBuf,
+    normalization: SnapshotNormalization,
+    bless: bool,
+}
+
+impl<E: SqlExecutor> DbFixture<E> {
+    /// Wrap a driver connection
+    ///
+    /// Snapshots default to `tests/snapshots/db` under the crate being tested.
+    pub fn new(executor: E) -> Self {
+        Self {
+            executor,
+            snapshot_dir: ProjectLayout::detect().snapshot_dir().join("db"),
+            normalization: SnapshotNormalization::default(),
+            bless: std::env::var_os(BLESS_ENV_VAR).is_some_and(|value| value != "0"),
+        }
+    }
+
+    /// Directory snapshot files are read from and written to
+    #[must_use]
+    pub fn with_snapshot_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.snapshot_dir = dir.into();
+        self
+    }
+
+    /// Normalization applied to query snapshots
+    #[must_use]
+    pub const fn with_normalization(mut self, normalization: SnapshotNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Write snapshots instead of comparing (defaults to `CHICAGO_TDD_BLESS`)
+    #[must_use]
+    pub const fn with_bless(mut self, bless: bool) -> Self {
+        self.bless = bless;
+        self
+    }
+
+    /// Access the underlying driver
+    pub const fn executor_mut(&mut self) -> &mut E {
+        &mut self.executor
+    }
+
+    /// Run a query and return its rows
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbError::Query`] if the driver fails.
+    pub fn query(&mut self, sql: &str) -> DbResult<QueryResult> {
+        self.executor
+            .query(sql)
+            .map_err(|e| DbError::Query { sql: sql.to_string(), message: e.to_string() })
+    }
+
+    /// Run a statement, discarding any rows
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbError::Query`] if the driver fails.
+    pub fn execute(&mut self, sql: &str) -> DbResult<()> {
+        self.query(sql).map(drop)
+    }
+
+    /// Count rows in a table
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbError::InvalidIdentifier`] for anything but a plain identifier,
+    /// [`DbError::Query`], or [`DbError::UnexpectedResult`] if the count is not one integer.
+    pub fn row_count(&mut self, table: &str) -> DbResult<u64> {
+        if !is_identifier(table) {
+            return Err(DbError::InvalidIdentifier(table.to_string()));
+        }
+        let sql = format!("SELECT COUNT(*) FROM {table}");
+        let result = self.query(&sql)?;
+        match result.rows.as_slice() {
+            [row] => match row.as_slice() {
+                [SqlValue::Int(count)] => u64::try_from(*count).map_err(|_| {
+                    DbError::UnexpectedResult { sql, message: format!("negative count {count}") }
+                }),
+                other => Err(DbError::UnexpectedResult {
+                    sql,
+                    message: format!("expected one integer, got {other:?}"),
+                }),
+            },
+            rows => Err(DbError::UnexpectedResult {
+                sql,
+                message: format!("expected one row, got {}", rows.len()),
+            }),
+        }
+    }
+
+    /// Compare a normalized query result with the snapshot `name`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbError::SnapshotMismatch`] when the snapshot is missing or differs (and
+    /// blessing is off), plus [`DbError::Query`] / [`DbError::Io`].
+    pub fn assert_query_snapshot(&mut self, name: &str, sql: &str) -> DbResult<()> {
+        let result = self.query(sql)?;
+        let rendered = format!("-- {sql}\n{}", self.normalization.render(&result));
+        self.compare_snapshot(name, &rendered)
+    }
+
+    /// Compare the `EXPLAIN` plan of a query with the snapshot `name`
+    ///
+    /// Cost estimates are stripped so only plan shape changes (index vs scan, join
+    /// order) are flagged.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::assert_query_snapshot`].
+    pub fn assert_plan_snapshot(&mut self, name: &str, sql: &str) -> DbResult<()> {
+        let explain = format!("{}{sql}", self.executor.explain_prefix());
+        let plan = self.query(&explain)?;
+        let mut rendered = format!("-- {explain}\n");
+        for row in &plan.rows {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|value| match value {
+                    SqlValue::Text(text) => text.clone(),
+                    other => self.normalization.cell(SqlType::Text, other),
+                })
+                .collect();
+            rendered.push_str(&strip_plan_costs(&cells.join(" | ")));
+            rendered.push('\n');
+        }
+        self.compare_snapshot(name, &rendered)
+    }
+
+    fn compare_snapshot(&self, name: &str, actual: &str) -> DbResult<()> {
+        let path = self.snapshot_dir.join(format!("{name}.snap"));
+        let io_error = |source| DbError::Io { path: path.display().to_string(), source };
+        if self.bless {
+            std::fs::create_dir_all(&self.snapshot_dir).map_err(io_error)?;
+            return std::fs::write(&path, actual).map_err(io_error);
+        }
+        let mismatch = |problem, detail| DbError::SnapshotMismatch {
+            name: name.to_string(),
+            path: path.display().to_string(),
+            problem,
+            detail,
+        };
+        let expected = match std::fs::read_to_string(&path) {
+            Ok(expected) => expected,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(mismatch("is missing", actual.to_string()));
+            }
+            Err(e) => return Err(io_error(e)),
+        };
+        if expected == actual {
+            return Ok(());
+        }
+        let detail = first_difference(&expected, actual);
+        Err(mismatch("differs", detail))
+    }
+}
+
+fn first_difference(expected: &str, actual: &str) -> String {
+    let mut expected_lines = expected.lines();
+    let mut actual_lines = actual.lines();
+    for line in 1.. {
+        match (expected_lines.next(), actual_lines.next()) {
+            (Some(e), Some(a)) if e == a => {}
+            (None, None) => break,
+            (e, a) => {
+                return format!(
+                    "   line {line}:\n   - {}\n   + {}",
+                    e.unwrap_or("<end>"),
+                    a.unwrap_or("<end>")
+                );
+            }
+        }
+    }
+    "   (line endings differ)".to_string()
+}
+
+/// Assert the number of rows in a table
+///
+/// # Example
+///
+/// ```rust,ignore
+/// assert_row_count!(db, "users", 3);
+/// ```
+#[macro_export]
+macro_rules! assert_row_count {
+    ($db:expr, $table:expr, $expected:expr) => {{
+        let table: &str = $table;
+        let expected: u64 = $expected;
+        match $db.row_count(table) {
+            Ok(actual) if actual == expected => {}
+            Ok(actual) => panic!("🚨 Table `{}` has {} rows, expected {}", table, actual, expected),
+            Err(e) => panic!("🚨 Could not count rows in `{}`: {}", table, e),
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::collections::HashMap;
+
+    /// Canned executor: maps statement text to results
+    #[derive(Default)]
+    struct FakeExecutor {
+        results: HashMap<String, QueryResult>,
+        explain_prefix: &'static str,
+    }
+
+    impl FakeExecutor {
+        fn with(mut self, sql: &str, result: QueryResult) -> Self {
+            self.results.insert(sql.to_string(), result);
+            self
+        }
+    }
+
+    impl SqlExecutor for FakeExecutor {
+        type Error = String;
+
+        fn query(&mut self, sql: &str) -> Result<QueryResult, String> {
+            self.results
+                .get(sql)
+                .cloned()
+                .ok_or_else(|| format!("no such table for `{sql}`"))
+        }
+
+        fn explain_prefix(&self) -> &'static str {
+            self.explain_prefix
+        }
+    }
+
+    fn count(n: i64) -> QueryResult {
+        QueryResult {
+            columns: vec![SqlColumn::new("count", SqlType::Int)],
+            rows: vec![vec![SqlValue::Int(n)]],
+        }
+    }
+
+    test!(test_row_count_and_macro, {
+        // Arrange
+        let mut db = DbFixture::new(
+            FakeExecutor::default().with("SELECT COUNT(*) FROM app.users", count(3)),
+        );
+
+        // Act & Assert
+        assert_row_count!(db, "app.users", 3);
+        assert!(matches!(db.row_count("users; DROP TABLE x"), Err(DbError::InvalidIdentifier(_))));
+        assert!(matches!(db.row_count("orders"), Err(DbError::Query { .. })));
+    });
+
+    test!(test_render_normalizes_by_column_type, {
+        // Arrange
+        let result = QueryResult {
+            columns: vec![
+                SqlColumn::new("id", SqlType::Uuid),
+                SqlColumn::new("score", SqlType::Float),
+                SqlColumn::new("created", SqlType::Timestamp),
+                SqlColumn::new("meta", SqlType::Json),
+                SqlColumn::new("blob", SqlType::Bytes),
+            ],
+            rows: vec![
+                vec![
+                    SqlValue::Text("6f1c...".to_string()),
+                    SqlValue::Float(0.1 + 0.2),
+                    SqlValue::Text("2026-01-01T00:00:00Z".to_string()),
+                    SqlValue::Json(serde_json::json!({"b": 1, "a": 2})),
+                    SqlValue::Bytes(vec![0xde, 0xad]),
+                ],
+                vec![SqlValue::Null; 5],
+            ],
+        };
+        let normalization =
+            SnapshotNormalization { float_precision: 2, sort_rows: true, ..Default::default() };
+
+        // Act
+        let rendered = normalization.render(&result);
+
+        // Assert
+        assert_eq!(
+            rendered,
+            "id (uuid) | score (float) | created (timestamp) | meta (json) | blob (bytes)\n\
+             NULL | NULL | NULL | NULL | NULL\n\
+             [uuid] | 0.30 | [timestamp] | {\"a\":2,\"b\":1} | \\xdead\n\
+             (2 rows)\n"
+        );
+    });
+
+    test!(test_strip_plan_costs, {
+        assert_eq!(
+            strip_plan_costs("Seq Scan on users  (cost=0.00..35.50 rows=2550 width=4)"),
+            "Seq Scan on users"
+        );
+        assert_eq!(strip_plan_costs("SCAN users"), "SCAN users");
+    });
+
+    test!(test_query_and_plan_snapshots_bless_then_compare, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let plan = QueryResult {
+            columns: vec![SqlColumn::new("QUERY PLAN", SqlType::Text)],
+            rows: vec![vec![SqlValue::Text(
+                "Index Scan using users_pkey on users  (cost=0.15..8.17 rows=1 width=36)"
+                    .to_string(),
+            )]],
+        };
+        let executor = FakeExecutor { explain_prefix: "EXPLAIN ", ..Default::default() }
+            .with("SELECT COUNT(*) FROM users", count(1))
+            .with("EXPLAIN SELECT * FROM users WHERE id = 1", plan);
+        let mut db = DbFixture::new(executor).with_snapshot_dir(dir.path()).with_bless(false);
+
+        // Act
+        let missing = db.assert_query_snapshot("count", "SELECT COUNT(*) FROM users");
+        let mut db = db.with_bless(true);
+        db.assert_query_snapshot("count", "SELECT COUNT(*) FROM users").unwrap();
+        db.assert_plan_snapshot("by_id", "SELECT * FROM users WHERE id = 1").unwrap();
+        let mut db = db.with_bless(false);
+
+        // Assert
+        assert!(matches!(missing, Err(DbError::SnapshotMismatch { problem: "is missing", .. })));
+        db.assert_query_snapshot("count", "SELECT COUNT(*) FROM users").unwrap();
+        db.assert_plan_snapshot("by_id", "SELECT * FROM users WHERE id = 1").unwrap();
+        let plan_file = std::fs::read_to_string(dir.path().join("by_id.snap")).unwrap();
+        assert!(plan_file.ends_with("Index Scan using users_pkey on users\n"));
+        db.executor_mut()
+            .results
+            .insert("SELECT COUNT(*) FROM users".to_string(), count(2));
+        let err = db.assert_query_snapshot("count", "SELECT COUNT(*) FROM users").unwrap_err();
+        assert!(err.to_string().contains("- 1\n   + 2"));
+    });
+}
@@ -0,0 +1,659 @@
+//! Declarative Test Data Seeding
+//!
+//! A [`SeedPlan`] describes the Arrange-phase data of a database test as steps:
+//!
+//! - [`SeedTable`]: rows inserted into one table, one `INSERT` per row, with the primary key
+//!   of every row recorded
+//! - [`SeedSql`]: a raw SQL block, with an optional cleanup statement
+//!
+//! Steps run in dependency order (declaration order where unconstrained). A row can reference
+//! the primary key of a row seeded by an earlier step with [`SeedRow::key`], which also makes
+//! that step a dependency. [`SeedPlan::run`] returns a [`Seeded`] handle with the recorded
+//! keys; [`Seeded::teardown`] deletes exactly those rows (and runs raw-block cleanups) in
+//! reverse order, leaving anything else in the database untouched.
+//!
+//! Keys come back through `RETURNING` on `PostgreSQL` and `SQLite`, and through
+//! `SELECT LAST_INSERT_ID()` on `MySQL` (see [`SqlExecutor::dialect`]).
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use chicago_tdd_tools::integration::db::{DbFixture, SeedPlan, SeedRow, SeedTable};
+//!
+//! let mut db = DbFixture::new(MyPostgresExecutor::connect(&url)?);
+//! let seeded = SeedPlan::new()
+//!     .table(SeedTable::new("orders").row(SeedRow::new().set("total", 5).key("user_id", "users", 0)))
+//!     .table(SeedTable::new("users").row(SeedRow::new().set("name", "ada")))
+//!     .run(&mut db)?;
+//!
+//! let user_id = seeded.int("users", 0).unwrap();
+//! // Act and Assert against the seeded rows ...
+//! seeded.teardown(&mut db)?;
+//! ```
+
+use super::{is_identifier, DbError, DbFixture, DbResult, SqlDialect, SqlExecutor, SqlValue};
+
+/// Default primary key column of a [`SeedTable`]
+pub const DEFAULT_PRIMARY_KEY: &str = "id";
+
+/// Value of one column in a seeded row
+#[derive(Debug, Clone, PartialEq)]
+pub enum SeedValue {
+    /// A literal value
+    Value(SqlValue),
+    /// The primary key of row `row` seeded into `table`
+    Key {
+        /// Seeded table
+        table: String,
+        /// Row index within that table's step
+        row: usize,
+    },
+}
+
+/// One row of a [`SeedTable`]
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SeedRow {
+    columns: Vec<(String, SeedValue)>,
+}
+
+impl SeedRow {
+    /// Empty row (all columns take their defaults)
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set `column` to a literal value
+    #[must_use]
+    pub fn set(mut self, column: impl Into<String>, value: impl Into<SqlValue>) -> Self {
+        self.columns.push((column.into(), SeedValue::Value(value.into())));
+        self
+    }
+
+    /// Set `column` to the primary key of row `row` seeded into `table`
+    #[must_use]
+    pub fn key(mut self, column: impl Into<String>, table: impl Into<String>, row: usize) -> Self {
+        self.columns.push((column.into(), SeedValue::Key { table: table.into(), row }));
+        self
+    }
+
+    /// Tables whose keys this row references
+    fn references(&self) -> impl Iterator<Item = &str> {
+        self.columns.iter().filter_map(|(_, value)| match value {
+            SeedValue::Key { table, .. } => Some(table.as_str()),
+            SeedValue::Value(_) => None,
+        })
+    }
+}
+
+/// Rows to insert into one table
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeedTable {
+    table: String,
+    primary_key: String,
+    depends_on: Vec<String>,
+    rows: Vec<SeedRow>,
+}
+
+impl SeedTable {
+    /// Seed `table`, whose primary key is [`DEFAULT_PRIMARY_KEY`]
+    #[must_use]
+    pub fn new(table: impl Into<String>) -> Self {
+        Self {
+            table: table.into(),
+            primary_key: DEFAULT_PRIMARY_KEY.to_string(),
+            depends_on: Vec::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Primary key column returned for each inserted row
+    #[must_use]
+    pub fn primary_key(mut self, column: impl Into<String>) -> Self {
+        self.primary_key = column.into();
+        self
+    }
+
+    /// Run after the step named `step`
+    #[must_use]
+    pub fn depends_on(mut self, step: impl Into<String>) -> Self {
+        self.depends_on.push(step.into());
+        self
+    }
+
+    /// Add a row
+    #[must_use]
+    pub fn row(mut self, row: SeedRow) -> Self {
+        self.rows.push(row);
+        self
+    }
+}
+
+/// A raw SQL block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeedSql {
+    name: String,
+    sql: String,
+    cleanup: Option<String>,
+    depends_on: Vec<String>,
+}
+
+impl SeedSql {
+    /// Block `name` running `sql` as one statement
+    #[must_use]
+    pub fn new(name: impl Into<String>, sql: impl Into<String>) -> Self {
+        Self { name: name.into(), sql: sql.into(), cleanup: None, depends_on: Vec::new() }
+    }
+
+    /// Statement undoing the block on teardown
+    #[must_use]
+    pub fn cleanup(mut self, sql: impl Into<String>) -> Self {
+        self.cleanup = Some(sql.into());
+        self
+    }
+
+    /// Run after the step named `step`
+    #[must_use]
+    pub fn depends_on(mut self, step: impl Into<String>) -> Self {
+        self.depends_on.push(step.into());
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum SeedStep {
+    Table(SeedTable),
+    Sql(SeedSql),
+}
+
+impl SeedStep {
+    fn name(&self) -> &str {
+        match self {
+            Self::Table(table) => &table.table,
+            Self::Sql(block) => &block.name,
+        }
+    }
+
+    /// Explicit dependencies plus tables referenced through keys
+    fn dependencies(&self) -> Vec<&str> {
+        let mut dependencies: Vec<&str> = match self {
+            Self::Table(table) => {
+                let explicit = table.depends_on.iter().map(String::as_str);
+                explicit.chain(table.rows.iter().flat_map(SeedRow::references)).collect()
+            }
+            Self::Sql(block) => block.depends_on.iter().map(String::as_str).collect(),
+        };
+        dependencies.sort_unstable();
+        dependencies.dedup();
+        dependencies
+    }
+}
+
+/// Declarative Arrange-phase data for a database test
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SeedPlan {
+    steps: Vec<SeedStep>,
+}
+
+impl SeedPlan {
+    /// Empty plan
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a table step (named after its table)
+    #[must_use]
+    pub fn table(mut self, table: SeedTable) -> Self {
+        self.steps.push(SeedStep::Table(table));
+        self
+    }
+
+    /// Add a raw SQL step
+    #[must_use]
+    pub fn sql(mut self, block: SeedSql) -> Self {
+        self.steps.push(SeedStep::Sql(block));
+        self
+    }
+
+    /// Step names in the order they will run
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DbError::InvalidSeedPlan`] for duplicate names, unknown dependencies, or a
+    /// dependency cycle, and [`DbError::InvalidIdentifier`] for table or column names that
+    /// are not plain identifiers.
+    pub fn order(&self) -> DbResult<Vec<&str>> {
+        Ok(self.ordered_steps()?.into_iter().map(SeedStep::name).collect())
+    }
+
+    /// Execute the plan and record the seeded keys
+    ///
+    /// If a step fails, everything seeded so far is torn down before the error is returned.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::order`], plus [`DbError::Query`] and [`DbError::UnexpectedResult`]
+    /// when an insert fails or does not return exactly one key.
+    pub fn run<E: SqlExecutor>(&self, db: &mut DbFixture<E>) -> DbResult<Seeded> {
+        let steps = self.ordered_steps()?;
+        let mut seeded = Seeded { dialect: db.executor.dialect(), steps: Vec::new() };
+        for step in steps {
+            if let Err(error) = seeded.apply(db, step) {
+                // Best effort: the original error is the one worth reporting
+                let _ = seeded.teardown(db);
+                return Err(error);
+            }
+        }
+        Ok(seeded)
+    }
+
+    /// Validate the plan and sort steps by dependency, preferring declaration order
+    fn ordered_steps(&self) -> DbResult<Vec<&SeedStep>> {
+        let mut names: Vec<&str> = Vec::with_capacity(self.steps.len());
+        for step in &self.steps {
+            if names.contains(&step.name()) {
+                return Err(DbError::InvalidSeedPlan(format!("duplicate step '{}'", step.name())));
+            }
+            names.push(step.name());
+            if let SeedStep::Table(table) = step {
+                let columns = table.rows.iter().flat_map(|row| row.columns.iter().map(|(c, _)| c));
+                for identifier in [&table.table, &table.primary_key].into_iter().chain(columns) {
+                    if !is_identifier(identifier) {
+                        return Err(DbError::InvalidIdentifier(identifier.clone()));
+                    }
+                }
+            }
+        }
+        for step in &self.steps {
+            if let Some(unknown) = step.dependencies().into_iter().find(|d| !names.contains(d)) {
+                return Err(DbError::InvalidSeedPlan(format!(
+                    "step '{}' depends on unknown step '{unknown}'",
+                    step.name()
+                )));
+            }
+        }
+
+        let mut ordered: Vec<&SeedStep> = Vec::with_capacity(self.steps.len());
+        let mut remaining: Vec<&SeedStep> = self.steps.iter().collect();
+        while !remaining.is_empty() {
+            let ready = remaining.iter().position(|step| {
+                step.dependencies()
+                    .into_iter()
+                    .all(|d| ordered.iter().any(|done| done.name() == d))
+            });
+            let Some(index) = ready else {
+                let cycle: Vec<&str> = remaining.iter().map(|step| step.name()).collect();
+                return Err(DbError::InvalidSeedPlan(format!(
+                    "dependency cycle among {}",
+                    cycle.join(", ")
+                )));
+            };
+            ordered.push(remaining.remove(index));
+        }
+        Ok(ordered)
+    }
+}
+
+/// What one executed step left behind
+#[derive(Debug, Clone, PartialEq)]
+enum SeededStep {
+    Table { table: String, primary_key: String, keys: Vec<SqlValue> },
+    Sql { name: String, cleanup: Option<String> },
+}
+
+/// Keys recorded by [`SeedPlan::run`]; tear down with [`Seeded::teardown`]
+#[derive(Debug, Clone, PartialEq)]
+#[must_use = "call teardown to remove the seeded rows"]
+pub struct Seeded {
+    dialect: SqlDialect,
+    steps: Vec<SeededStep>,
+}
+
+impl Seeded {
+    /// Primary keys seeded into `table`, in row order
+    #[must_use]
+    pub fn keys(&self, table: &str) -> &[SqlValue] {
+        self.steps
+            .iter()
+            .find_map(|step| match step {
+                SeededStep::Table { table: name, keys, .. } if name == table => {
+                    Some(keys.as_slice())
+                }
+                _ => None,
+            })
+            .unwrap_or_default()
+    }
+
+    /// Primary key of row `row` seeded into `table`
+    #[must_use]
+    pub fn key(&self, table: &str, row: usize) -> Option<&SqlValue> {
+        self.keys(table).get(row)
+    }
+
+    /// Integer primary key of row `row` seeded into `table`
+    #[must_use]
+    pub fn int(&self, table: &str, row: usize) -> Option<i64> {
+        match self.key(table, row) {
+            Some(SqlValue::Int(key)) => Some(*key),
+            _ => None,
+        }
+    }
+
+    /// Text (or UUID) primary key of row `row` seeded into `table`
+    #[must_use]
+    pub fn text(&self, table: &str, row: usize) -> Option<&str> {
+        match self.key(table, row) {
+            Some(SqlValue::Text(key)) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Names of the executed steps, in execution order
+    #[must_use]
+    pub fn steps(&self) -> Vec<&str> {
+        self.steps
+            .iter()
+            .map(|step| match step {
+                SeededStep::Table { table, .. } => table.as_str(),
+                SeededStep::Sql { name, .. } => name.as_str(),
+            })
+            .collect()
+    }
+
+    /// Delete the seeded rows and run raw-block cleanups, in reverse execution order
+    ///
+    /// # Errors
+    ///
+    /// Returns the first failing statement; later steps are not torn down.
+    pub fn teardown<E: SqlExecutor>(mut self, db: &mut DbFixture<E>) -> DbResult<()> {
+        while let Some(step) = self.steps.pop() {
+            let statement = match step {
+                SeededStep::Table { keys, .. } if keys.is_empty() => None,
+                SeededStep::Table { table, primary_key, keys } => {
+                    let keys: Vec<String> =
+                        keys.iter().map(|key| literal(self.dialect, key)).collect();
+                    Some(format!(
+                        "DELETE FROM {table} WHERE {primary_key} IN ({})",
+                        keys.join(", ")
+                    ))
+                }
+                SeededStep::Sql { cleanup, .. } => cleanup,
+            };
+            if let Some(statement) = statement {
+                db.execute(&statement)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn apply<E: SqlExecutor>(&mut self, db: &mut DbFixture<E>, step: &SeedStep) -> DbResult<()> {
+        match step {
+            SeedStep::Sql(block) => {
+                db.execute(&block.sql)?;
+                self.steps.push(SeededStep::Sql {
+                    name: block.name.clone(),
+                    cleanup: block.cleanup.clone(),
+                });
+            }
+            SeedStep::Table(table) => {
+                self.steps.push(SeededStep::Table {
+                    table: table.table.clone(),
+                    primary_key: table.primary_key.clone(),
+                    keys: Vec::new(),
+                });
+                for row in &table.rows {
+                    let key = self.insert(db, table, row)?;
+                    if let Some(SeededStep::Table { keys, .. }) = self.steps.last_mut() {
+                        keys.push(key);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Insert one row and return its primary key
+    fn insert<E: SqlExecutor>(
+        &self,
+        db: &mut DbFixture<E>,
+        table: &SeedTable,
+        row: &SeedRow,
+    ) -> DbResult<SqlValue> {
+        let mut columns = Vec::with_capacity(row.columns.len());
+        let mut values = Vec::with_capacity(row.columns.len());
+        let mut explicit_key = None;
+        for (column, value) in &row.columns {
+            let value = match value {
+                SeedValue::Value(value) => value,
+                SeedValue::Key { table: referenced, row: index } => {
+                    self.key(referenced, *index).ok_or_else(|| {
+                        DbError::InvalidSeedPlan(format!(
+                            "'{}.{column}' references row {index} of '{referenced}', which seeded {} rows",
+                            table.table,
+                            self.keys(referenced).len()
+                        ))
+                    })?
+                }
+            };
+            if *column == table.primary_key {
+                explicit_key = Some(value.clone());
+            }
+            columns.push(column.as_str());
+            values.push(literal(self.dialect, value));
+        }
+
+        let sql = if columns.is_empty() {
+            match self.dialect {
+                SqlDialect::MySql => format!("INSERT INTO {} () VALUES ()", table.table),
+                SqlDialect::Postgres | SqlDialect::Sqlite => {
+                    format!("INSERT INTO {} DEFAULT VALUES", table.table)
+                }
+            }
+        } else {
+            format!(
+                "INSERT INTO {} ({}) VALUES ({})",
+                table.table,
+                columns.join(", "),
+                values.join(", ")
+            )
+        };
+        let sql = match self.dialect {
+            SqlDialect::MySql => {
+                db.execute(&sql)?;
+                if let Some(key) = explicit_key {
+                    return Ok(key);
+                }
+                "SELECT LAST_INSERT_ID()".to_string()
+            }
+            SqlDialect::Postgres | SqlDialect::Sqlite => {
+                format!("{sql} RETURNING {}", table.primary_key)
+            }
+        };
+        let result = db.query(&sql)?;
+        match result.rows.as_slice() {
+            [row] => match row.as_slice() {
+                [key] if *key != SqlValue::Null => Ok(key.clone()),
+                other => Err(DbError::UnexpectedResult {
+                    sql,
+                    message: format!("expected one non-null key, got {other:?}"),
+                }),
+            },
+            rows => Err(DbError::UnexpectedResult {
+                sql,
+                message: format!("expected one row, got {}", rows.len()),
+            }),
+        }
+    }
+}
+
+/// Render a value as a SQL literal for `dialect`
+fn literal(dialect: SqlDialect, value: &SqlValue) -> String {
+    let quote = |text: &str| {
+        let escaped = text.replace('\'', "''");
+        match dialect {
+            // MySQL treats backslash as an escape character inside string literals
+            SqlDialect::MySql => format!("'{}'", escaped.replace('\\', "\\\\")),
+            SqlDialect::Postgres | SqlDialect::Sqlite => format!("'{escaped}'"),
+        }
+    };
+    match value {
+        SqlValue::Null => "NULL".to_string(),
+        SqlValue::Bool(value) => match dialect {
+            SqlDialect::Sqlite => u8::from(*value).to_string(),
+            SqlDialect::Postgres | SqlDialect::MySql => value.to_string().to_uppercase(),
+        },
+        SqlValue::Int(value) => value.to_string(),
+        SqlValue::Float(value) if value.is_finite() => value.to_string(),
+        SqlValue::Float(value) => quote(&value.to_string()),
+        SqlValue::Text(text) => quote(text),
+        SqlValue::Bytes(bytes) => match dialect {
+            SqlDialect::Postgres => format!("'\\x{}'::bytea", hex::encode(bytes)),
+            SqlDialect::MySql | SqlDialect::Sqlite => format!("X'{}'", hex::encode(bytes)),
+        },
+        SqlValue::Json(value) => quote(&value.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integration::db::{QueryResult, SqlColumn, SqlType};
+    use crate::test;
+
+    /// Executor that records statements and hands out sequential keys
+    #[derive(Default)]
+    struct RecordingExecutor {
+        dialect: SqlDialect,
+        statements: Vec<String>,
+        next_key: i64,
+        fail_on: Option<&'static str>,
+    }
+
+    impl SqlExecutor for RecordingExecutor {
+        type Error = String;
+
+        fn query(&mut self, sql: &str) -> Result<QueryResult, String> {
+            if self.fail_on.is_some_and(|pattern| sql.contains(pattern)) {
+                return Err(format!("constraint violation in `{sql}`"));
+            }
+            self.statements.push(sql.to_string());
+            if sql.contains("RETURNING") || sql == "SELECT LAST_INSERT_ID()" {
+                self.next_key += 1;
+                return Ok(QueryResult {
+                    columns: vec![SqlColumn::new("id", SqlType::Int)],
+                    rows: vec![vec![SqlValue::Int(self.next_key)]],
+                });
+            }
+            Ok(QueryResult::default())
+        }
+
+        fn dialect(&self) -> SqlDialect {
+            self.dialect
+        }
+    }
+
+    fn plan() -> SeedPlan {
+        SeedPlan::new()
+            .table(
+                SeedTable::new("orders")
+                    .row(SeedRow::new().set("total", 5).key("user_id", "users", 1)),
+            )
+            .sql(
+                SeedSql::new("grants", "INSERT INTO grants VALUES ('admin')")
+                    .depends_on("users")
+                    .cleanup("DELETE FROM grants WHERE role = 'admin'"),
+            )
+            .table(
+                SeedTable::new("users")
+                    .row(SeedRow::new().set("name", "ada"))
+                    .row(SeedRow::new().set("name", "o'brien").set("active", true)),
+            )
+    }
+
+    test!(test_seed_plan_runs_in_dependency_order_and_tears_down_seeded_rows, {
+        // Arrange
+        let mut db = DbFixture::new(RecordingExecutor::default());
+
+        // Act
+        let seeded = plan().run(&mut db).unwrap();
+        let user = seeded.int("users", 1);
+        seeded.teardown(&mut db).unwrap();
+
+        // Assert
+        assert_eq!(user, Some(2));
+        assert_eq!(
+            db.executor_mut().statements,
+            [
+                "INSERT INTO users (name) VALUES ('ada') RETURNING id",
+                "INSERT INTO users (name, active) VALUES ('o''brien', TRUE) RETURNING id",
+                "INSERT INTO orders (total, user_id) VALUES (5, 2) RETURNING id",
+                "INSERT INTO grants VALUES ('admin')",
+                "DELETE FROM grants WHERE role = 'admin'",
+                "DELETE FROM orders WHERE id IN (3)",
+                "DELETE FROM users WHERE id IN (1, 2)",
+            ]
+        );
+    });
+
+    test!(test_mysql_reads_last_insert_id, {
+        // Arrange
+        let executor = RecordingExecutor { dialect: SqlDialect::MySql, ..Default::default() };
+        let mut db = DbFixture::new(executor);
+        let plan = SeedPlan::new().table(
+            SeedTable::new("users")
+                .row(SeedRow::new().set("name", "a\\b"))
+                .row(SeedRow::new().set("id", 40).set("name", "b")),
+        );
+
+        // Act
+        let seeded = plan.run(&mut db).unwrap();
+
+        // Assert
+        assert_eq!(seeded.keys("users"), [SqlValue::Int(1), SqlValue::Int(40)]);
+        assert_eq!(
+            db.executor_mut().statements,
+            [
+                "INSERT INTO users (name) VALUES ('a\\\\b')",
+                "SELECT LAST_INSERT_ID()",
+                "INSERT INTO users (id, name) VALUES (40, 'b')",
+            ]
+        );
+    });
+
+    test!(test_failed_step_tears_down_partial_seed, {
+        // Arrange
+        let executor =
+            RecordingExecutor { fail_on: Some("INSERT INTO orders"), ..Default::default() };
+        let mut db = DbFixture::new(executor);
+
+        // Act
+        let result = plan().run(&mut db);
+
+        // Assert
+        assert!(matches!(result, Err(DbError::Query { .. })));
+        assert_eq!(
+            db.executor_mut().statements.last().map(String::as_str),
+            Some("DELETE FROM users WHERE id IN (1, 2)")
+        );
+    });
+
+    test!(test_invalid_plans_are_rejected, {
+        // Arrange
+        let cycle = SeedPlan::new()
+            .table(SeedTable::new("a").depends_on("b"))
+            .table(SeedTable::new("b").depends_on("a"));
+        let unknown = SeedPlan::new().table(SeedTable::new("a").depends_on("missing"));
+        let duplicate =
+            SeedPlan::new().table(SeedTable::new("a")).sql(SeedSql::new("a", "SELECT 1"));
+        let injection = SeedPlan::new().table(SeedTable::new("users; DROP TABLE x"));
+
+        // Act & Assert
+        let message = |plan: &SeedPlan| plan.order().unwrap_err().to_string();
+        assert!(message(&cycle).contains("dependency cycle among a, b"));
+        assert!(message(&unknown).contains("unknown step 'missing'"));
+        assert!(message(&duplicate).contains("duplicate step 'a'"));
+        assert!(matches!(injection.order(), Err(DbError::InvalidIdentifier(_))));
+        assert_eq!(plan().order().unwrap(), ["users", "orders", "grants"]);
+    });
+}
@@ -0,0 +1,511 @@
+//! HTTP Test Server Fixture
+//!
+//! Spins up a real local HTTP server (axum on hyper) with user-registered routes and
+//! records every request it receives. HTTP clients, webhooks, and SDKs are tested
+//! against a real socket instead of a mocked transport, without any external network.
+//!
+//! **Required feature**: `http-testing`
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::assert_received;
+//! use chicago_tdd_tools::integration::http_server::{HttpResponse, HttpServerFixture};
+//!
+//! let server = HttpServerFixture::builder()
+//!     .route("POST", "/orders", |req| {
+//!         HttpResponse::new(201).json(&serde_json::json!({ "echo": req.body_text() }))
+//!     })
+//!     .route("GET", "/health", |_| HttpResponse::ok().text("ok"))
+//!     .start()
+//!     .unwrap();
+//!
+//! // Point the client under test at server.url("/orders") ...
+//!
+//! assert_received!(server, POST "/orders", times = 2);
+//! assert_received!(server, GET "/health");
+//! ```
+
+use axum::body::{to_bytes, Body};
+use axum::extract::{Request, State};
+use axum::response::Response;
+use axum::Router;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::net::{Ipv4Addr, SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+use thiserror::Error;
+
+/// Largest request body the server records
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// HTTP server fixture errors
+#[derive(Error, Debug)]
+pub enum HttpServerError {
+    /// Could not bind the local listener
+    #[error("🚨 Failed to bind HTTP test server: {0}")]
+    Bind(#[source] std::io::Error),
+    /// Could not start the server runtime
+    #[error("🚨 Failed to start HTTP test server runtime: {0}")]
+    Runtime(#[source] std::io::Error),
+}
+
+/// Result type for HTTP server fixture operations
+pub type HttpServerResult<T> = Result<T, HttpServerError>;
+
+/// A request received by the server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedRequest {
+    /// HTTP method (uppercase)
+    pub method: String,
+    /// Request path, without the query string
+    pub path: String,
+    /// Raw query string, if any
+    pub query: Option<String>,
+    /// Headers keyed by lowercase name (repeated headers joined with `, `)
+    pub headers: BTreeMap<String, String>,
+    /// Request body
+    pub body: Vec<u8>,
+}
+
+impl RecordedRequest {
+    /// Header value by case-insensitive name
+    #[must_use]
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_ascii_lowercase()).map(String::as_str)
+    }
+
+    /// Body as UTF-8 text (lossy)
+    #[must_use]
+    pub fn body_text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    /// Body parsed as JSON
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body is not valid JSON for `T`.
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_slice(&self.body)
+    }
+}
+
+impl fmt::Display for RecordedRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.path)?;
+        if let Some(query) = &self.query {
+            write!(f, "?{query}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Response returned by a route handler
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Empty response with `status`
+    #[must_use]
+    pub const fn new(status: u16) -> Self {
+        Self { status, headers: Vec::new(), body: Vec::new() }
+    }
+
+    /// Empty `200 OK` response
+    #[must_use]
+    pub const fn ok() -> Self {
+        Self::new(200)
+    }
+
+    /// Add a response header
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Set a raw body
+    #[must_use]
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Set a `text/plain` body
+    #[must_use]
+    pub fn text(self, text: impl Into<String>) -> Self {
+        self.header("content-type", "text/plain; charset=utf-8").body(text.into())
+    }
+
+    /// Set an `application/json` body
+    #[must_use]
+    pub fn json(self, value: &serde_json::Value) -> Self {
+        self.header("content-type", "application/json").body(value.to_string())
+    }
+
+    fn into_response(self) -> Response {
+        let mut builder = Response::builder().status(self.status);
+        for (name, value) in &self.headers {
+            builder = builder.header(name, value);
+        }
+        builder.body(Body::from(self.body)).unwrap_or_else(|_| {
+            let mut fallback = Response::new(Body::from("invalid test response"));
+            *fallback.status_mut() = axum::http::StatusCode::INTERNAL_SERVER_ERROR;
+            fallback
+        })
+    }
+}
+
+/// Route handler
+type Handler = Arc<dyn Fn(&RecordedRequest) -> HttpResponse + Send + Sync>;
+
+/// A registered route: method, path pattern, handler
+#[derive(Clone)]
+struct Route {
+    method: String,
+    pattern: String,
+    handler: Handler,
+}
+
+impl Route {
+    /// Exact match, or prefix match for patterns ending in `*`
+    fn matches(&self, method: &str, path: &str) -> bool {
+        (self.method == "*" || self.method == method)
+            && self
+                .pattern
+                .strip_suffix('*')
+                .map_or(self.pattern == path, |prefix| path.starts_with(prefix))
+    }
+}
+
+#[derive(Default)]
+struct ServerState {
+    routes: Vec<Route>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl ServerState {
+    fn requests(&self) -> MutexGuard<'_, Vec<RecordedRequest>> {
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Builder for [`HttpServerFixture`]
+#[derive(Default)]
+pub struct HttpServerBuilder {
+    routes: Vec<Route>,
+}
+
+impl fmt::Debug for HttpServerBuilder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let routes: Vec<String> =
+            self.routes.iter().map(|r| format!("{} {}", r.method, r.pattern)).collect();
+        f.debug_struct("HttpServerBuilder").field("routes", &routes).finish()
+    }
+}
+
+impl HttpServerBuilder {
+    /// Register a route
+    ///
+    /// `method` is an HTTP method or `*` for any. `path` matches exactly, or as a prefix
+    /// when it ends in `*` (e.g. `/files/*`). The first matching route wins; unmatched
+    /// requests get `404` and are still recorded.
+    #[must_use]
+    pub fn route(
+        mut self,
+        method: &str,
+        path: &str,
+        handler: impl Fn(&RecordedRequest) -> HttpResponse + Send + Sync + 'static,
+    ) -> Self {
+        self.routes.push(Route {
+            method: method.to_ascii_uppercase(),
+            pattern: path.to_string(),
+            handler: Arc::new(handler),
+        });
+        self
+    }
+
+    /// Bind `127.0.0.1` on a free port and start serving on a background thread
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener cannot be bound or the runtime cannot start.
+    pub fn start(self) -> HttpServerResult<HttpServerFixture> {
+        let listener =
+            TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).map_err(HttpServerError::Bind)?;
+        let addr = listener.local_addr().map_err(HttpServerError::Bind)?;
+        listener.set_nonblocking(true).map_err(HttpServerError::Bind)?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .map_err(HttpServerError::Runtime)?;
+        let listener = {
+            let _entered = runtime.enter();
+            tokio::net::TcpListener::from_std(listener).map_err(HttpServerError::Bind)?
+        };
+        let state = Arc::new(ServerState { routes: self.routes, requests: Mutex::default() });
+        let app = Router::new().fallback(handle).with_state(Arc::clone(&state));
+        let (shutdown, signal) = tokio::sync::oneshot::channel::<()>();
+        let thread = std::thread::spawn(move || {
+            runtime.block_on(async move {
+                let served = axum::serve(listener, app)
+                    .with_graceful_shutdown(async {
+                        let _ = signal.await;
+                    })
+                    .await;
+                if let Err(error) = served {
+                    crate::alert_warning!(
+                        format!("HTTP test server stopped with an error: {error}"),
+                        "Check the test's network environment"
+                    );
+                }
+            });
+        });
+        Ok(HttpServerFixture { addr, state, shutdown: Some(shutdown), thread: Some(thread) })
+    }
+}
+
+async fn handle(State(state): State<Arc<ServerState>>, request: Request) -> Response {
+    let (parts, body) = request.into_parts();
+    let mut headers: BTreeMap<String, String> = BTreeMap::new();
+    for (name, value) in &parts.headers {
+        let value = String::from_utf8_lossy(value.as_bytes()).into_owned();
+        headers
+            .entry(name.as_str().to_string())
+            .and_modify(|existing| {
+                existing.push_str(", ");
+                existing.push_str(&value);
+            })
+            .or_insert(value);
+    }
+    let body = to_bytes(body, MAX_BODY_BYTES).await.map(|b| b.to_vec()).unwrap_or_default();
+    let recorded = RecordedRequest {
+        method: parts.method.as_str().to_string(),
+        path: parts.uri.path().to_string(),
+        query: parts.uri.query().map(ToString::to_string),
+        headers,
+        body,
+    };
+    let route = state
+        .routes
+        .iter()
+        .find(|r| r.matches(&recorded.method, &recorded.path))
+        .cloned();
+    state.requests().push(recorded.clone());
+    let Some(route) = route else {
+        return HttpResponse::new(404).text(format!("no route for {recorded}")).into_response();
+    };
+    // Handlers are synchronous and may block (e.g. forwarding upstream), so keep them
+    // off the server's I/O thread.
+    tokio::task::spawn_blocking(move || (route.handler)(&recorded))
+        .await
+        .unwrap_or_else(|_| HttpResponse::new(500).text("route handler panicked"))
+        .into_response()
+}
+
+/// Running local HTTP server that records received requests
+///
+/// The server stops when the fixture is dropped.
+pub struct HttpServerFixture {
+    addr: SocketAddr,
+    state: Arc<ServerState>,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl fmt::Debug for HttpServerFixture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpServerFixture")
+            .field("addr", &self.addr)
+            .field("received", &self.state.requests().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpServerFixture {
+    /// Start building a server
+    #[must_use]
+    pub fn builder() -> HttpServerBuilder {
+        HttpServerBuilder::default()
+    }
+
+    /// Socket address the server listens on
+    #[must_use]
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Base URL, e.g. `http://127.0.0.1:41234`
+    #[must_use]
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// Absolute URL for `path`
+    #[must_use]
+    pub fn url(&self, path: &str) -> String {
+        format!("{}{path}", self.base_url())
+    }
+
+    /// Snapshot of every request received so far, in arrival order
+    #[must_use]
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.state.requests().clone()
+    }
+
+    /// Requests received for `method` and `path` (exact path match)
+    #[must_use]
+    pub fn received(&self, method: &str, path: &str) -> Vec<RecordedRequest> {
+        self.state
+            .requests()
+            .iter()
+            .filter(|r| r.method.eq_ignore_ascii_case(method) && r.path == path)
+            .cloned()
+            .collect()
+    }
+
+    /// Forget all recorded requests
+    pub fn clear(&self) {
+        self.state.requests().clear();
+    }
+
+    /// Assert `method path` was received `times` times (or at least once if `None`)
+    ///
+    /// Prefer the [`assert_received!`](crate::assert_received) macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics with every recorded request if the count does not match.
+    pub fn assert_received(&self, method: &str, path: &str, times: Option<usize>) {
+        let count = self.received(method, path).len();
+        let ok = times.map_or(count > 0, |expected| count == expected);
+        if ok {
+            return;
+        }
+        let expected = times.map_or_else(|| "at least 1".to_string(), |n| n.to_string());
+        let received = self.requests().iter().fold(String::new(), |mut out, r| {
+            out.push_str("\n   - ");
+            out.push_str(&r.to_string());
+            out
+        });
+        let received = if received.is_empty() { " (none)".to_string() } else { received };
+        assert!(
+            ok,
+            "🚨 Expected {expected} request(s) to {method} {path}, received {count}\n   Received:{received}"
+        );
+    }
+}
+
+impl Drop for HttpServerFixture {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Assert an [`HttpServerFixture`] received a request
+///
+/// # Example
+///
+/// ```rust,ignore
+/// assert_received!(server, POST "/orders", times = 2);
+/// assert_received!(server, GET "/health"); // at least once
+/// ```
+#[macro_export]
+macro_rules! assert_received {
+    ($fixture:expr, $method:ident $path:expr) => {
+        $fixture.assert_received(stringify!($method), $path, None)
+    };
+    ($fixture:expr, $method:ident $path:expr, times = $times:expr) => {
+        $fixture.assert_received(stringify!($method), $path, Some($times))
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    /// Minimal HTTP/1.1 client: returns (status, body)
+    fn send(server: &HttpServerFixture, method: &str, path: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(server.addr()).unwrap();
+        write!(
+            stream,
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nX-Trace: abc\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let body = response.split_once("\r\n\r\n").map(|(_, b)| b.to_string()).unwrap();
+        (status, body)
+    }
+
+    test!(test_routes_respond_and_requests_are_recorded, {
+        // Arrange
+        let server = HttpServerFixture::builder()
+            .route("POST", "/orders", |req| {
+                HttpResponse::new(201).json(&serde_json::json!({ "echo": req.body_text() }))
+            })
+            .route("GET", "/files/*", |req| HttpResponse::ok().text(req.path.clone()))
+            .start()
+            .unwrap();
+
+        // Act
+        let created = send(&server, "POST", "/orders", "{\"qty\":2}");
+        let file = send(&server, "GET", "/files/a.txt?v=1", "");
+        let missing = send(&server, "DELETE", "/orders", "");
+
+        // Assert
+        assert_eq!(created, (201, r#"{"echo":"{\"qty\":2}"}"#.to_string()));
+        assert_eq!(file, (200, "/files/a.txt".to_string()));
+        assert_eq!(missing.0, 404);
+        let requests = server.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].json::<serde_json::Value>().unwrap()["qty"], 2);
+        assert_eq!(requests[0].header("X-TRACE"), Some("abc"));
+        assert_eq!(requests[1].query.as_deref(), Some("v=1"));
+    });
+
+    test!(test_assert_received_macro_counts, {
+        // Arrange
+        let server = HttpServerFixture::builder()
+            .route("*", "/webhook", |_| HttpResponse::new(204))
+            .start()
+            .unwrap();
+
+        // Act
+        send(&server, "POST", "/webhook", "a");
+        send(&server, "POST", "/webhook", "b");
+
+        // Assert
+        assert_received!(server, POST "/webhook", times = 2);
+        assert_received!(server, POST "/webhook");
+        server.clear();
+        assert_received!(server, POST "/webhook", times = 0);
+    });
+
+    #[test]
+    #[should_panic(expected = "Expected 1 request(s) to GET /health, received 0")]
+    fn test_assert_received_reports_what_was_received() {
+        // Arrange
+        let server = HttpServerFixture::builder().start().unwrap();
+        send(&server, "GET", "/healthz", "");
+
+        // Act & Assert: Should panic
+        assert_received!(server, GET "/health", times = 1);
+    }
+}
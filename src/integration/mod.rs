@@ -1,10 +1,13 @@
 //! Integration Testing
 //!
 //! External system integration for integration testing with external
-//! dependencies, such as Testcontainers for Docker support.
+//! dependencies, such as Testcontainers for Docker support, supervised
+//! host-process sidecars, database fixtures with SQL assertions, and a local
+//! HTTP test server that records requests, and a scripted WebSocket test client.
 //!
 //! **Required Features**:
 //! - `testcontainers`: Enable Docker container support (`chicago-tdd-tools = { features = ["testcontainers"] }`)
+//! - `http-testing`: Enable the HTTP test server fixture and WebSocket test client (`chicago-tdd-tools = { features = ["http-testing"] }`)
 //!
 //! **Usage**:
 //! ```rust,ignore
@@ -14,8 +17,14 @@
 //! use chicago_tdd_tools::integration::testcontainers::*;
 //! ```
 
+pub mod db;
+#[cfg(feature = "http-testing")]
+pub mod http_server;
+pub mod sidecar;
 #[cfg(feature = "testcontainers")]
 pub mod testcontainers;
+#[cfg(feature = "http-testing")]
+pub mod ws_client;
 
 // When the `testcontainers` feature is disabled, the `testcontainers` module is absent.
 // Users who try to import it will receive a compile error. Enable the feature:
@@ -45,5 +54,11 @@ mod testcontainers_placeholder {
 }
 
 // Re-export commonly used items
+pub use db::*;
+#[cfg(feature = "http-testing")]
+pub use http_server::*;
+pub use sidecar::*;
 #[cfg(feature = "testcontainers")]
 pub use testcontainers::*;
+#[cfg(feature = "http-testing")]
+pub use ws_client::*;
@@ -0,0 +1,663 @@
+//! Sidecar Process Supervision
+//!
+//! `SidecarProcess` runs a host binary (not a container) alongside a test: a local
+//! collector, a mock server, a license daemon. It waits for a readiness probe, captures
+//! stdout/stderr, optionally restarts the process when it crashes, and guarantees the
+//! process (and, on Unix, its whole process group) is killed when the fixture drops.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::integration::sidecar::{ReadinessProbe, RestartPolicy, SidecarProcess};
+//! use std::time::Duration;
+//!
+//! let sidecar = SidecarProcess::builder("my-mock-server")
+//!     .args(["--port", "9000"])
+//!     .readiness(ReadinessProbe::TcpPort(9000))
+//!     .ready_timeout(Duration::from_secs(5))
+//!     .restart_policy(RestartPolicy::OnFailure { max_restarts: 2 })
+//!     .spawn()
+//!     .unwrap();
+//!
+//! // ... exercise the system under test ...
+//! println!("{}", sidecar.logs_text());
+//! // Dropping `sidecar` terminates the process group
+//! ```
+
+use crate::core::cancellation::cancellable_sleep;
+use crate::core::command::CheckedCommand;
+use std::collections::BTreeMap;
+use std::ffi::OsString;
+use std::fmt;
+use std::io::{BufRead, BufReader, Read};
+use std::net::{SocketAddr, TcpStream};
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Default time allowed for the readiness probe to pass
+pub const DEFAULT_READY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Default time between SIGTERM and SIGKILL on shutdown
+pub const DEFAULT_SHUTDOWN_GRACE: Duration = Duration::from_secs(2);
+
+/// Interval for supervision and readiness polling
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Sidecar errors
+#[derive(Error, Debug)]
+pub enum SidecarError {
+    /// The process could not be started
+    #[error("🚨 Failed to start sidecar `{command}`: {source}")]
+    SpawnFailed {
+        /// Rendered command line
+        command: String,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// The readiness probe did not pass in time
+    #[error("🚨 Sidecar `{command}` not ready after {timeout:?}\n   logs:\n{logs}")]
+    NotReady {
+        /// Rendered command line
+        command: String,
+        /// Readiness timeout
+        timeout: Duration,
+        /// Captured logs
+        logs: String,
+    },
+    /// The current test was cancelled while waiting for readiness
+    #[error("🚨 Sidecar `{command}` readiness wait cancelled: {reason}\n   logs:\n{logs}")]
+    Cancelled {
+        /// Rendered command line
+        command: String,
+        /// Why the test was cancelled
+        reason: String,
+        /// Captured logs
+        logs: String,
+    },
+    /// The process exited before becoming ready
+    #[error("🚨 Sidecar `{command}` exited with {status} before becoming ready\n   logs:\n{logs}")]
+    ExitedBeforeReady {
+        /// Rendered command line
+        command: String,
+        /// Exit status
+        status: ExitStatus,
+        /// Captured logs
+        logs: String,
+    },
+}
+
+/// Result type for sidecar operations
+pub type SidecarResult<T> = Result<T, SidecarError>;
+
+/// How readiness is determined after spawning
+#[derive(Clone)]
+pub enum ReadinessProbe {
+    /// Ready as soon as the process is spawned
+    Immediate,
+    /// Ready when `127.0.0.1:<port>` accepts TCP connections
+    TcpPort(u16),
+    /// Ready when an address accepts TCP connections
+    TcpAddr(SocketAddr),
+    /// Ready when a line containing the text appears on stdout or stderr
+    LogContains(String),
+    /// Ready when the predicate returns `true`
+    Custom(Arc<dyn Fn() -> bool + Send + Sync>),
+}
+
+impl fmt::Debug for ReadinessProbe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Immediate => f.write_str("Immediate"),
+            Self::TcpPort(port) => write!(f, "TcpPort({port})"),
+            Self::TcpAddr(addr) => write!(f, "TcpAddr({addr})"),
+            Self::LogContains(text) => write!(f, "LogContains({text:?})"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// What to do when the sidecar exits on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartPolicy {
+    /// Leave it stopped
+    Never,
+    /// Restart after a non-zero exit, up to `max_restarts` times
+    OnFailure {
+        /// Restart budget
+        max_restarts: u32,
+    },
+    /// Restart after any exit, up to `max_restarts` times
+    Always {
+        /// Restart budget
+        max_restarts: u32,
+    },
+}
+
+impl RestartPolicy {
+    fn should_restart(self, status: ExitStatus, restarts: u32) -> bool {
+        match self {
+            Self::Never => false,
+            Self::OnFailure { max_restarts } => !status.success() && restarts < max_restarts,
+            Self::Always { max_restarts } => restarts < max_restarts,
+        }
+    }
+}
+
+/// Output stream a log line came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogStream {
+    /// Standard output
+    Stdout,
+    /// Standard error
+    Stderr,
+}
+
+/// One captured line of sidecar output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// Source stream
+    pub stream: LogStream,
+    /// Process generation (0 for the first run, incremented per restart)
+    pub generation: u32,
+    /// Line text without the trailing newline
+    pub line: String,
+}
+
+/// Builder for [`SidecarProcess`]
+#[derive(Debug, Clone)]
+pub struct SidecarBuilder {
+    program: OsString,
+    args: Vec<OsString>,
+    env: BTreeMap<OsString, OsString>,
+    current_dir: Option<PathBuf>,
+    readiness: ReadinessProbe,
+    ready_timeout: Duration,
+    restart_policy: RestartPolicy,
+    shutdown_grace: Duration,
+}
+
+impl SidecarBuilder {
+    /// Append an argument
+    #[must_use]
+    pub fn arg(mut self, arg: impl Into<OsString>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Append several arguments
+    #[must_use]
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<OsString>,
+    {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Set an environment variable
+    #[must_use]
+    pub fn env(mut self, key: impl Into<OsString>, value: impl Into<OsString>) -> Self {
+        self.env.insert(key.into(), value.into());
+        self
+    }
+
+    /// Set the working directory
+    #[must_use]
+    pub fn current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(dir.into());
+        self
+    }
+
+    /// Readiness probe (default: [`ReadinessProbe::Immediate`])
+    #[must_use]
+    pub fn readiness(mut self, probe: ReadinessProbe) -> Self {
+        self.readiness = probe;
+        self
+    }
+
+    /// Time allowed for readiness (default: [`DEFAULT_READY_TIMEOUT`])
+    #[must_use]
+    pub const fn ready_timeout(mut self, timeout: Duration) -> Self {
+        self.ready_timeout = timeout;
+        self
+    }
+
+    /// Restart policy (default: [`RestartPolicy::Never`])
+    #[must_use]
+    pub const fn restart_policy(mut self, policy: RestartPolicy) -> Self {
+        self.restart_policy = policy;
+        self
+    }
+
+    /// Time between graceful termination and kill (default: [`DEFAULT_SHUTDOWN_GRACE`])
+    #[must_use]
+    pub const fn shutdown_grace(mut self, grace: Duration) -> Self {
+        self.shutdown_grace = grace;
+        self
+    }
+
+    fn command_line(&self) -> String {
+        std::iter::once(&self.program)
+            .chain(&self.args)
+            .map(|part| part.to_string_lossy().into_owned())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn spawn_child(
+        &self,
+        generation: u32,
+        logs: &Arc<Mutex<Vec<LogLine>>>,
+    ) -> SidecarResult<Child> {
+        let mut command = Command::new(&self.program);
+        command
+            .args(&self.args)
+            .envs(&self.env)
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if let Some(dir) = &self.current_dir {
+            command.current_dir(dir);
+        }
+        #[cfg(unix)]
+        {
+            // Own process group so shutdown reaches grandchildren too
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+        let mut child = command
+            .spawn()
+            .map_err(|source| SidecarError::SpawnFailed { command: self.command_line(), source })?;
+        if let Some(stdout) = child.stdout.take() {
+            capture(stdout, LogStream::Stdout, generation, Arc::clone(logs));
+        }
+        if let Some(stderr) = child.stderr.take() {
+            capture(stderr, LogStream::Stderr, generation, Arc::clone(logs));
+        }
+        Ok(child)
+    }
+
+    /// Spawn the sidecar and wait for readiness
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SidecarError::SpawnFailed`], [`SidecarError::ExitedBeforeReady`],
+    /// [`SidecarError::NotReady`], or [`SidecarError::Cancelled`]. The process is cleaned
+    /// up on every error path.
+    pub fn spawn(self) -> SidecarResult<SidecarProcess> {
+        let logs = Arc::new(Mutex::new(Vec::new()));
+        let child = self.spawn_child(0, &logs)?;
+        let shared = Arc::new(Shared {
+            child: Mutex::new(child),
+            logs,
+            restarts: AtomicU32::new(0),
+            stopping: AtomicBool::new(false),
+            config: self,
+        });
+        let mut sidecar = SidecarProcess { shared, supervisor: None };
+        sidecar.wait_ready()?;
+        let supervisor_shared = Arc::clone(&sidecar.shared);
+        sidecar.supervisor = Some(thread::spawn(move || supervise(&supervisor_shared)));
+        Ok(sidecar)
+    }
+}
+
+struct Shared {
+    child: Mutex<Child>,
+    logs: Arc<Mutex<Vec<LogLine>>>,
+    restarts: AtomicU32,
+    stopping: AtomicBool,
+    config: SidecarBuilder,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // A panicking log reader must not prevent cleanup
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn capture(
+    pipe: impl Read + Send + 'static,
+    stream: LogStream,
+    generation: u32,
+    logs: Arc<Mutex<Vec<LogLine>>>,
+) {
+    thread::spawn(move || {
+        for line in BufReader::new(pipe).lines().map_while(Result::ok) {
+            lock(&logs).push(LogLine { stream, generation, line });
+        }
+    });
+}
+
+fn supervise(shared: &Shared) {
+    while !shared.stopping.load(Ordering::SeqCst) {
+        thread::sleep(POLL_INTERVAL);
+        restart_if_exited(shared, &mut lock(&shared.child));
+    }
+}
+
+fn restart_if_exited(shared: &Shared, child: &mut Child) {
+    if shared.stopping.load(Ordering::SeqCst) {
+        return;
+    }
+    let Ok(Some(status)) = child.try_wait() else {
+        return;
+    };
+    let restarts = shared.restarts.load(Ordering::SeqCst);
+    if !shared.config.restart_policy.should_restart(status, restarts) {
+        return;
+    }
+    // Kill what is left of the old group (e.g. a grandchild holding the port) before
+    // the replacement starts; a failed spawn is retried on the next poll
+    terminate_group(child, Duration::ZERO);
+    if let Ok(replacement) = shared.config.spawn_child(restarts + 1, &shared.logs) {
+        *child = replacement;
+        shared.restarts.store(restarts + 1, Ordering::SeqCst);
+    }
+}
+
+/// Terminate the child's process group: SIGTERM, grace period, then SIGKILL
+fn terminate_group(child: &mut Child, grace: Duration) {
+    #[cfg(unix)]
+    {
+        let group = format!("-{}", child.id());
+        let signal = |name: &str| {
+            // `kill` exits non-zero once the group is gone; that is the goal
+            drop(CheckedCommand::new("kill").args([name, "--", &group]).output());
+        };
+        if !grace.is_zero() && matches!(child.try_wait(), Ok(None)) {
+            signal("-TERM");
+            let deadline = Instant::now() + grace;
+            while matches!(child.try_wait(), Ok(None)) && Instant::now() < deadline {
+                thread::sleep(POLL_INTERVAL);
+            }
+        }
+        signal("-KILL");
+    }
+    #[cfg(not(unix))]
+    let _ = grace;
+    // Kill fails if the child already exited; wait() reaps it either way
+    drop(child.kill());
+    drop(child.wait());
+}
+
+/// A supervised host process that is killed on drop
+pub struct SidecarProcess {
+    shared: Arc<Shared>,
+    supervisor: Option<JoinHandle<()>>,
+}
+
+impl fmt::Debug for SidecarProcess {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SidecarProcess")
+            .field("command", &self.shared.config.command_line())
+            .field("pid", &self.pid())
+            .field("restarts", &self.restarts())
+            .finish_non_exhaustive()
+    }
+}
+
+impl SidecarProcess {
+    /// Start configuring a sidecar for `program`
+    #[must_use]
+    pub fn builder(program: impl Into<OsString>) -> SidecarBuilder {
+        SidecarBuilder {
+            program: program.into(),
+            args: Vec::new(),
+            env: BTreeMap::new(),
+            current_dir: None,
+            readiness: ReadinessProbe::Immediate,
+            ready_timeout: DEFAULT_READY_TIMEOUT,
+            restart_policy: RestartPolicy::Never,
+            shutdown_grace: DEFAULT_SHUTDOWN_GRACE,
+        }
+    }
+
+    fn wait_ready(&self) -> SidecarResult<()> {
+        let config = &self.shared.config;
+        let deadline = Instant::now() + config.ready_timeout;
+        let label = format!("sidecar `{}` readiness", config.command_line());
+        loop {
+            let exited = lock(&self.shared.child).try_wait();
+            if let Ok(Some(status)) = exited {
+                // Give the log readers a moment to flush the final output
+                thread::sleep(POLL_INTERVAL);
+                return Err(SidecarError::ExitedBeforeReady {
+                    command: config.command_line(),
+                    status,
+                    logs: self.logs_text(),
+                });
+            }
+            if self.probe() {
+                return Ok(());
+            }
+            if Instant::now() >= deadline {
+                return Err(SidecarError::NotReady {
+                    command: config.command_line(),
+                    timeout: config.ready_timeout,
+                    logs: self.logs_text(),
+                });
+            }
+            if let Err(cancelled) = cancellable_sleep(&label, POLL_INTERVAL) {
+                return Err(SidecarError::Cancelled {
+                    command: config.command_line(),
+                    reason: cancelled.reason,
+                    logs: self.logs_text(),
+                });
+            }
+        }
+    }
+
+    fn probe(&self) -> bool {
+        let connect = |addr: SocketAddr| TcpStream::connect_timeout(&addr, POLL_INTERVAL).is_ok();
+        match &self.shared.config.readiness {
+            ReadinessProbe::Immediate => true,
+            ReadinessProbe::TcpPort(port) => connect(SocketAddr::from(([127, 0, 0, 1], *port))),
+            ReadinessProbe::TcpAddr(addr) => connect(*addr),
+            ReadinessProbe::LogContains(text) => {
+                lock(&self.shared.logs).iter().any(|log| log.line.contains(text.as_str()))
+            }
+            ReadinessProbe::Custom(predicate) => predicate(),
+        }
+    }
+
+    /// OS process id of the current generation
+    #[must_use]
+    pub fn pid(&self) -> u32 {
+        lock(&self.shared.child).id()
+    }
+
+    /// Number of restarts performed by the supervisor
+    #[must_use]
+    pub fn restarts(&self) -> u32 {
+        self.shared.restarts.load(Ordering::SeqCst)
+    }
+
+    /// Whether the current generation is still running
+    #[must_use]
+    pub fn is_running(&self) -> bool {
+        matches!(lock(&self.shared.child).try_wait(), Ok(None))
+    }
+
+    /// Captured output so far, across all generations
+    #[must_use]
+    pub fn logs(&self) -> Vec<LogLine> {
+        lock(&self.shared.logs).clone()
+    }
+
+    /// Captured output as text, one line per log entry
+    #[must_use]
+    pub fn logs_text(&self) -> String {
+        lock(&self.shared.logs)
+            .iter()
+            .map(|log| log.line.as_str())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Stop supervision and terminate the process group
+    ///
+    /// Called automatically on drop.
+    pub fn stop(&mut self) {
+        self.shared.stopping.store(true, Ordering::SeqCst);
+        if let Some(supervisor) = self.supervisor.take() {
+            drop(supervisor.join());
+        }
+        terminate_group(&mut lock(&self.shared.child), self.shared.config.shutdown_grace);
+    }
+}
+
+impl Drop for SidecarProcess {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_restart_policy_budget() {
+        // Arrange
+        let status = CheckedCommand::new("false").output().unwrap().status;
+
+        // Act & Assert
+        assert!(RestartPolicy::OnFailure { max_restarts: 1 }.should_restart(status, 0));
+        assert!(!RestartPolicy::OnFailure { max_restarts: 1 }.should_restart(status, 1));
+        assert!(!RestartPolicy::Never.should_restart(status, 0));
+    }
+
+    test!(test_missing_binary_fails_to_spawn, {
+        // Arrange & Act
+        let result = SidecarProcess::builder("definitely-not-a-real-sidecar-xyz").spawn();
+
+        // Assert
+        assert!(matches!(result, Err(SidecarError::SpawnFailed { .. })));
+    });
+
+    #[cfg(unix)]
+    #[test]
+    fn test_log_readiness_and_capture() {
+        // Arrange & Act
+        let sidecar = SidecarProcess::builder("sh")
+            .args(["-c", "echo booting; echo listening >&2; sleep 30"])
+            .readiness(ReadinessProbe::LogContains("listening".to_string()))
+            .ready_timeout(Duration::from_secs(5))
+            .spawn()
+            .unwrap();
+
+        // Assert
+        assert!(sidecar.is_running());
+        let logs = sidecar.logs();
+        assert!(logs
+            .iter()
+            .any(|log| log.stream == LogStream::Stderr && log.line == "listening"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_exit_before_ready_reports_logs() {
+        // Arrange & Act
+        let result = SidecarProcess::builder("sh")
+            .args(["-c", "echo bad config >&2; exit 3"])
+            .readiness(ReadinessProbe::TcpPort(1))
+            .spawn();
+
+        // Assert
+        let err = result.unwrap_err();
+        assert!(matches!(err, SidecarError::ExitedBeforeReady { .. }));
+        assert!(err.to_string().contains("bad config"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_restarts_on_failure_within_budget() {
+        // Arrange
+        let sidecar = SidecarProcess::builder("sh")
+            .args(["-c", "echo up; sleep 0.05; exit 1"])
+            .restart_policy(RestartPolicy::OnFailure { max_restarts: 2 })
+            .spawn()
+            .unwrap();
+
+        // Act
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while sidecar.restarts() < 2 && Instant::now() < deadline {
+            thread::sleep(POLL_INTERVAL);
+        }
+        thread::sleep(Duration::from_millis(200));
+
+        // Assert
+        assert_eq!(sidecar.restarts(), 2);
+        assert!(!sidecar.is_running());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_drop_kills_whole_process_group() {
+        // Arrange: the sidecar forks a grandchild and reports its pid
+        let sidecar = SidecarProcess::builder("sh")
+            .args(["-c", "sleep 30 & echo grandchild=$!; wait"])
+            .readiness(ReadinessProbe::LogContains("grandchild=".to_string()))
+            .shutdown_grace(Duration::from_millis(100))
+            .spawn()
+            .unwrap();
+        let grandchild = sidecar
+            .logs()
+            .iter()
+            .find_map(|log| log.line.strip_prefix("grandchild=").map(str::to_string))
+            .unwrap();
+
+        // Act
+        drop(sidecar);
+
+        // Assert
+        // A killed grandchild may linger as a zombie until init reaps it
+        let state = CheckedCommand::new("ps").args(["-o", "stat=", "-p", &grandchild]).output();
+        let state = state.unwrap().stdout_lossy();
+        assert!(
+            state.trim().is_empty() || state.trim().starts_with('Z'),
+            "grandchild {grandchild} survived sidecar drop: {state}"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_restart_kills_old_process_group_first() {
+        // Arrange: each generation forks a grandchild, reports its pid, then fails
+        let sidecar = SidecarProcess::builder("sh")
+            .args(["-c", "sleep 30 & echo grandchild=$!; sleep 0.1; exit 1"])
+            .readiness(ReadinessProbe::LogContains("grandchild=".to_string()))
+            .restart_policy(RestartPolicy::OnFailure { max_restarts: 1 })
+            .spawn()
+            .unwrap();
+        let first = sidecar
+            .logs()
+            .iter()
+            .find(|log| log.generation == 0)
+            .and_then(|log| log.line.strip_prefix("grandchild=").map(str::to_string))
+            .unwrap();
+
+        // Act
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while sidecar.restarts() < 1 && Instant::now() < deadline {
+            thread::sleep(POLL_INTERVAL);
+        }
+
+        // Assert
+        assert_eq!(sidecar.restarts(), 1);
+        let state = CheckedCommand::new("ps").args(["-o", "stat=", "-p", &first]).output();
+        let state = state.unwrap().stdout_lossy();
+        assert!(
+            state.trim().is_empty() || state.trim().starts_with('Z'),
+            "grandchild {first} of the failed generation survived the restart: {state}"
+        );
+    }
+}
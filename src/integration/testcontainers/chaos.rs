@@ -0,0 +1,433 @@
+//! Chaos Testing for Containers
+//!
+//! [`ContainerChaos`] disrupts a running container mid-test through the Docker CLI so recovery
+//! and reconnect logic is exercised against the real service:
+//!
+//! - **Pause / unpause**: freeze every process (connections stay open but stop answering)
+//! - **Kill**: send a signal (default `SIGKILL`), leaving the container stopped
+//! - **Restart**: stop and start the container
+//! - **Network disconnect**: detach from one network, or from all of them (a partition)
+//!
+//! Every disruption is recorded, and whatever is still in effect when the `ContainerChaos`
+//! is dropped is undone: the container is unpaused, started again if it was killed, and
+//! reconnected to every network it was disconnected from. Call
+//! [`restore`](ContainerChaos::restore) to heal explicitly and see restoration errors.
+//!
+//! Host ports of a killed or restarted container may change; read them again with
+//! `get_host_port` after restoring.
+//!
+//! ## Usage
+//!
+//! ```rust
+//! # #[cfg(feature = "testcontainers")]
+//! use chicago_tdd_tools::testcontainers::{ContainerClient, GenericContainer};
+//!
+//! # #[cfg(feature = "testcontainers")]
+//! # fn example() -> Result<(), chicago_tdd_tools::testcontainers::TestcontainersError> {
+//! let client = ContainerClient::new();
+//! let container = GenericContainer::with_command(
+//!     client.client(),
+//!     "alpine",
+//!     "latest",
+//!     "sleep",
+//!     &["infinity"],
+//!     None,
+//! )?;
+//!
+//! let mut chaos = container.chaos()?;
+//! chaos.pause()?;
+//! // Act: exercise client timeouts against the frozen service
+//! chaos.restore()?;
+//!
+//! let result = container.exec("echo", &["recovered"])?;
+//! assert_eq!(result.stdout.trim(), "recovered");
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{TestcontainersError, TestcontainersResult};
+use crate::core::command::CheckedCommand;
+use std::fmt;
+
+/// Signal sent by [`ContainerChaos::kill`]
+pub const DEFAULT_KILL_SIGNAL: &str = "SIGKILL";
+
+/// A disruption (or restoration step) applied to a container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChaosAction {
+    /// Freeze all processes
+    Pause,
+    /// Resume frozen processes
+    Unpause,
+    /// Send a signal to the main process
+    Kill(String),
+    /// Start a stopped container
+    Start,
+    /// Stop and start the container
+    Restart,
+    /// Detach from a network
+    Disconnect(String),
+    /// Attach to a network
+    Connect(String),
+}
+
+impl ChaosAction {
+    /// Docker CLI arguments performing this action on `container_id`
+    #[must_use]
+    pub fn docker_args(&self, container_id: &str) -> Vec<String> {
+        let args: Vec<&str> = match self {
+            Self::Pause => vec!["pause", container_id],
+            Self::Unpause => vec!["unpause", container_id],
+            Self::Kill(signal) => vec!["kill", "--signal", signal, container_id],
+            Self::Start => vec!["start", container_id],
+            Self::Restart => vec!["restart", container_id],
+            Self::Disconnect(network) => vec!["network", "disconnect", network, container_id],
+            Self::Connect(network) => vec!["network", "connect", network, container_id],
+        };
+        args.into_iter().map(str::to_string).collect()
+    }
+}
+
+impl fmt::Display for ChaosAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pause => write!(f, "pause"),
+            Self::Unpause => write!(f, "unpause"),
+            Self::Kill(signal) => write!(f, "kill ({signal})"),
+            Self::Start => write!(f, "start"),
+            Self::Restart => write!(f, "restart"),
+            Self::Disconnect(network) => write!(f, "disconnect from {network}"),
+            Self::Connect(network) => write!(f, "connect to {network}"),
+        }
+    }
+}
+
+/// Disruptions currently in effect
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct ChaosState {
+    paused: bool,
+    killed: bool,
+    disconnected: Vec<String>,
+}
+
+impl ChaosState {
+    /// Record the effect of a successfully applied action
+    fn apply(&mut self, action: &ChaosAction) {
+        match action {
+            ChaosAction::Pause => self.paused = true,
+            ChaosAction::Unpause => self.paused = false,
+            ChaosAction::Kill(_) => {
+                self.paused = false;
+                self.killed = true;
+            }
+            ChaosAction::Start | ChaosAction::Restart => {
+                self.paused = false;
+                self.killed = false;
+            }
+            ChaosAction::Disconnect(network) => {
+                if !self.disconnected.contains(network) {
+                    self.disconnected.push(network.clone());
+                }
+            }
+            ChaosAction::Connect(network) => self.disconnected.retain(|n| n != network),
+        }
+    }
+
+    /// Steps that undo every disruption, in the order they must run
+    fn restore_steps(&self) -> Vec<ChaosAction> {
+        let mut steps = Vec::new();
+        if self.paused {
+            steps.push(ChaosAction::Unpause);
+        }
+        if self.killed {
+            steps.push(ChaosAction::Start);
+        }
+        steps.extend(self.disconnected.iter().rev().cloned().map(ChaosAction::Connect));
+        steps
+    }
+}
+
+/// Disrupts a running container and restores it on drop
+#[derive(Debug)]
+pub struct ContainerChaos {
+    container_id: String,
+    state: ChaosState,
+    history: Vec<ChaosAction>,
+}
+
+impl ContainerChaos {
+    /// Chaos handle for the container with `container_id`
+    #[must_use]
+    pub fn for_id(container_id: impl Into<String>) -> Self {
+        Self {
+            container_id: container_id.into(),
+            state: ChaosState::default(),
+            history: Vec::new(),
+        }
+    }
+
+    /// Id of the disrupted container
+    #[must_use]
+    pub fn container_id(&self) -> &str {
+        &self.container_id
+    }
+
+    /// Every action applied so far, including restoration steps
+    #[must_use]
+    pub fn history(&self) -> &[ChaosAction] {
+        &self.history
+    }
+
+    /// Whether no disruption is currently in effect
+    #[must_use]
+    pub fn is_restored(&self) -> bool {
+        self.state.restore_steps().is_empty()
+    }
+
+    /// Freeze every process in the container
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestcontainersError::OperationFailed`] if `docker pause` fails.
+    pub fn pause(&mut self) -> TestcontainersResult<()> {
+        self.perform(ChaosAction::Pause)
+    }
+
+    /// Resume a paused container
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestcontainersError::OperationFailed`] if `docker unpause` fails.
+    pub fn unpause(&mut self) -> TestcontainersResult<()> {
+        self.perform(ChaosAction::Unpause)
+    }
+
+    /// Kill the container with [`DEFAULT_KILL_SIGNAL`]
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestcontainersError::OperationFailed`] if `docker kill` fails.
+    pub fn kill(&mut self) -> TestcontainersResult<()> {
+        self.kill_with_signal(DEFAULT_KILL_SIGNAL)
+    }
+
+    /// Send `signal` (e.g. `"SIGTERM"`) to the container's main process
+    ///
+    /// The container is treated as stopped afterwards and started again on restore.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestcontainersError::OperationFailed`] if `docker kill` fails.
+    pub fn kill_with_signal(&mut self, signal: &str) -> TestcontainersResult<()> {
+        self.perform(ChaosAction::Kill(signal.to_string()))
+    }
+
+    /// Start a killed container again
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestcontainersError::OperationFailed`] if `docker start` fails.
+    pub fn start(&mut self) -> TestcontainersResult<()> {
+        self.perform(ChaosAction::Start)
+    }
+
+    /// Stop and start the container
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestcontainersError::OperationFailed`] if `docker restart` fails.
+    pub fn restart(&mut self) -> TestcontainersResult<()> {
+        self.perform(ChaosAction::Restart)
+    }
+
+    /// Detach the container from `network`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestcontainersError::OperationFailed`] if `docker network disconnect` fails.
+    pub fn disconnect(&mut self, network: &str) -> TestcontainersResult<()> {
+        self.perform(ChaosAction::Disconnect(network.to_string()))
+    }
+
+    /// Reattach the container to `network`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestcontainersError::OperationFailed`] if `docker network connect` fails.
+    pub fn connect(&mut self, network: &str) -> TestcontainersResult<()> {
+        self.perform(ChaosAction::Connect(network.to_string()))
+    }
+
+    /// Networks the container is currently attached to
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestcontainersError::OperationFailed`] if `docker inspect` fails.
+    pub fn networks(&self) -> TestcontainersResult<Vec<String>> {
+        let output = CheckedCommand::new("docker")
+            .args([
+                "inspect",
+                "--format",
+                "{{range $name, $net := .NetworkSettings.Networks}}{{println $name}}{{end}}",
+                &self.container_id,
+            ])
+            .run()
+            .map_err(|e| {
+                TestcontainersError::OperationFailed(format!(
+                    "Failed to inspect networks of container {}: {e}",
+                    self.container_id
+                ))
+            })?;
+        Ok(output
+            .stdout_lossy()
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(str::to_string)
+            .collect())
+    }
+
+    /// Disconnect the container from every network it is attached to
+    ///
+    /// Returns the networks it was disconnected from.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TestcontainersError::OperationFailed`] if inspecting or disconnecting fails.
+    pub fn partition(&mut self) -> TestcontainersResult<Vec<String>> {
+        let networks = self.networks()?;
+        for network in &networks {
+            self.disconnect(network)?;
+        }
+        Ok(networks)
+    }
+
+    /// Undo every disruption still in effect
+    ///
+    /// # Errors
+    ///
+    /// Returns the first failing restoration step; later steps are not attempted.
+    pub fn restore(&mut self) -> TestcontainersResult<()> {
+        for step in self.state.restore_steps() {
+            self.perform(step)?;
+        }
+        Ok(())
+    }
+
+    fn perform(&mut self, action: ChaosAction) -> TestcontainersResult<()> {
+        CheckedCommand::new("docker").args(action.docker_args(&self.container_id)).run().map_err(
+            |e| {
+                TestcontainersError::OperationFailed(format!(
+                    "Chaos action '{action}' failed for container {}: {e}\n   💡 FIX: Check the container is still present and in a state that allows '{action}'",
+                    self.container_id
+                ))
+            },
+        )?;
+        self.state.apply(&action);
+        self.history.push(action);
+        Ok(())
+    }
+}
+
+/// Automatic restoration for `ContainerChaos`
+///
+/// Restoration is best-effort: failures are logged, never panicked on (the container may
+/// already have been removed).
+impl Drop for ContainerChaos {
+    fn drop(&mut self) {
+        if let Err(e) = self.restore() {
+            eprintln!(
+                "⚠️  WARNING: Failed to restore container {} after chaos: {e}",
+                self.container_id
+            );
+        }
+    }
+}
+
+#[cfg(feature = "testcontainers")]
+mod implementation {
+    use super::{ContainerChaos, TestcontainersError, TestcontainersResult};
+    use crate::integration::testcontainers::implementation::GenericContainer;
+
+    impl GenericContainer {
+        /// Chaos handle for this container
+        ///
+        /// Drop the handle before the container so restoration runs while it still exists.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`TestcontainersError::OperationFailed`] if the container has no id.
+        pub fn chaos(&self) -> TestcontainersResult<ContainerChaos> {
+            if let Some(container_id) = self.docker_cli_container_id() {
+                return Ok(ContainerChaos::for_id(container_id));
+            }
+            self.container()
+                .map(|container| ContainerChaos::for_id(container.id()))
+                .ok_or_else(|| {
+                    TestcontainersError::OperationFailed(
+                        "Container is not available - this should not happen".to_string(),
+                    )
+                })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_docker_args_target_container, {
+        // Arrange
+        let kill = ChaosAction::Kill("SIGTERM".to_string());
+        let disconnect = ChaosAction::Disconnect("bridge".to_string());
+
+        // Act
+        let kill_args = kill.docker_args("abc");
+        let disconnect_args = disconnect.docker_args("abc");
+
+        // Assert
+        assert_eq!(kill_args, ["kill", "--signal", "SIGTERM", "abc"]);
+        assert_eq!(disconnect_args, ["network", "disconnect", "bridge", "abc"]);
+    });
+
+    test!(test_restore_steps_undo_outstanding_disruptions, {
+        // Arrange
+        let mut state = ChaosState::default();
+        for action in [
+            ChaosAction::Disconnect("a".to_string()),
+            ChaosAction::Disconnect("b".to_string()),
+            ChaosAction::Pause,
+            ChaosAction::Kill(DEFAULT_KILL_SIGNAL.to_string()),
+        ] {
+            state.apply(&action);
+        }
+
+        // Act
+        let steps = state.restore_steps();
+
+        // Assert: kill supersedes pause; networks reconnect in reverse order
+        assert_eq!(
+            steps,
+            [
+                ChaosAction::Start,
+                ChaosAction::Connect("b".to_string()),
+                ChaosAction::Connect("a".to_string()),
+            ]
+        );
+    });
+
+    test!(test_restore_steps_empty_after_manual_healing, {
+        // Arrange
+        let mut state = ChaosState::default();
+        state.apply(&ChaosAction::Pause);
+        state.apply(&ChaosAction::Disconnect("a".to_string()));
+
+        // Act
+        state.apply(&ChaosAction::Unpause);
+        state.apply(&ChaosAction::Connect("a".to_string()));
+
+        // Assert
+        assert!(state.restore_steps().is_empty());
+    });
+}
@@ -226,15 +226,15 @@ mod implementation {
             command: &str,
             args: &[&str],
         ) -> TestcontainersResult<ExecResult> {
-            use std::process::Command;
+            use crate::core::command::CheckedCommand;
 
             // Build docker exec command
             // Format: docker exec <container_id> <command> <args...>
-            let mut docker_cmd = Command::new("docker");
-            docker_cmd.arg("exec");
-            docker_cmd.arg(container_id);
-            docker_cmd.arg(command);
-            docker_cmd.args(args);
+            let docker_cmd = CheckedCommand::new("docker")
+                .arg("exec")
+                .arg(container_id)
+                .arg(command)
+                .args(args);
 
             let output = docker_cmd.output().map_err(|e| {
                 TestcontainersError::CommandExecutionFailed(format!(
@@ -11,6 +11,7 @@
 //! - **Command Execution**: Execute commands inside containers and get stdout/stderr/exit code
 //! - **Wait Conditions**: Wait for containers to be ready (e.g., HTTP health checks)
 //! - **Automatic Cleanup**: Containers cleaned up automatically on Drop
+//! - **Chaos Testing**: Pause, kill, restart, or partition a running container (see `chaos` module)
 //! - **Poka-Yoke Design**: Type-level state machine prevents invalid operations (see `poka_yoke` module)
 //!
 //! ## Chicago TDD Alignment
@@ -154,12 +155,16 @@ pub enum TestcontainersError {
     /// Failed to get exit code
     #[error("⚠️  Failed to get exit code: {0}\n   ⚠️  WARNING: Could not determine command exit status\n   💡 FIX: Check container is running and command completed")]
     ExitCodeFailed(String),
+    /// The current test was cancelled while waiting on Docker
+    #[error("🚨 Container wait cancelled: {0}\n   ⚠️  STOP: The test was cancelled before the wait completed\n   💡 FIX: See the test's cancellation report for the interrupted waits")]
+    Cancelled(String),
 }
 
 /// Result type for testcontainers operations
 pub type TestcontainersResult<T> = Result<T, TestcontainersError>;
 
 // Re-export exec and wait functionality
+pub mod chaos;
 pub mod exec;
 pub mod wait;
 
@@ -168,6 +173,7 @@ pub mod wait;
 /// **Poka-yoke**: Type-level state machine prevents invalid container operations.
 /// See module documentation for examples.
 pub mod poka_yoke;
+pub use chaos::{ChaosAction, ContainerChaos};
 pub use exec::ExecResult;
 
 #[cfg(feature = "testcontainers")]
@@ -177,7 +183,9 @@ pub use exec::ExecResult;
 /// These types are feature-gated and only available when the `testcontainers` feature is enabled.
 pub mod implementation {
     use super::{HashMap, TestcontainersError, TestcontainersResult};
-    use std::process::Command;
+    use crate::core::cancellation::cancellable_sleep;
+    use crate::core::command::{CheckedCommand, CommandError, PROBE_COMMAND_TIMEOUT};
+    use crate::core::ports::{PortAllocator, ReservedPort};
 
     /// Container startup delay in milliseconds
     ///
@@ -212,8 +220,9 @@ pub mod implementation {
     ///
     /// **Root Cause Fix**: Added timeout to prevent hanging when Docker daemon is not running.
     /// Pattern: All external commands must have timeout protection to fail fast.
-    /// Implementation: Spawn command in thread, use mpsc channel with `recv_timeout`.
-    /// Timeout duration: 500ms (fast enough to fail within 1s test timeout, enough time for docker info when Docker is running).
+    /// Implementation: `docker info` runs through [`CheckedCommand`](crate::core::command::CheckedCommand),
+    /// which kills the child when the 5s timeout passes (enough time for docker info when Docker
+    /// is running under load, and no orphaned `docker` processes when it is not).
     /// This prevents the function from hanging indefinitely when Docker daemon is stopped.
     ///
     /// Returns 🚨 CRITICAL signal if Docker is unavailable.
@@ -228,93 +237,65 @@ pub mod implementation {
     ///
     /// Returns an error if Docker is unavailable or not responding.
     pub fn check_docker_available() -> TestcontainersResult<()> {
-        use std::process::Command;
-        use std::sync::mpsc;
-        use std::thread;
         use std::time::Duration;
 
-        // **Root Cause Fix**: Add timeout to prevent hanging when Docker daemon is not running.
-        // Pattern: All external commands should have timeouts to fail fast.
-        // Implementation: Spawn command in thread, use mpsc channel with recv_timeout.
-        // Timeout duration: 5000ms (5 seconds) - increased to handle Docker Desktop startup delays
-        // and parallel test execution. Fast enough to fail within test timeout, enough time for
-        // docker info when Docker is running under load. This prevents the function from hanging
-        // indefinitely when Docker daemon is stopped.
-        // Aligns with codebase timeout standards (see docs/features/TIMEOUT_ENFORCEMENT.md).
-        const DOCKER_CHECK_TIMEOUT_MILLIS: u64 = 5000;
         const MAX_RETRIES: u32 = 2;
 
+        // Timeout duration: PROBE_COMMAND_TIMEOUT (5 seconds) - handles Docker Desktop startup
+        // delays and parallel test execution.
+        // Aligns with codebase timeout standards (see docs/features/TIMEOUT_ENFORCEMENT.md).
+        let docker_info = CheckedCommand::new("docker").arg("info").timeout(PROBE_COMMAND_TIMEOUT);
+
         // Retry logic for parallel test execution - Docker may be slow to respond under load
+        let mut last_error = None;
         for attempt in 0..=MAX_RETRIES {
-            // Use docker info to verify daemon is running
-            // Spawn command in thread to enable timeout
-            let (tx, rx) = mpsc::channel();
-            let _handle = thread::spawn(move || {
-                let output = Command::new("docker").args(["info"]).output();
-                tx.send(output).ok();
-            });
-
-            // Wait for result with timeout
-            if let Ok(docker_check) =
-                rx.recv_timeout(Duration::from_millis(DOCKER_CHECK_TIMEOUT_MILLIS))
-            {
-                match docker_check {
-                    Ok(output) => {
-                        if output.status.success() {
-                            // Verify Docker daemon is responding by checking output
-                            let stdout = String::from_utf8_lossy(&output.stdout);
-                            if stdout.contains("Server Version")
-                                || stdout.contains("Docker Root Dir")
-                            {
-                                // ✅ Docker daemon is running and responding
-                                return Ok(());
-                            }
-                        }
-                        // If we get here and it's not the last attempt, retry with delay
-                        if attempt < MAX_RETRIES {
-                            // Small delay to reduce contention when multiple tests check Docker simultaneously
-                            thread::sleep(Duration::from_millis(100 * u64::from(attempt + 1)));
-                            continue;
-                        }
-                        // Last attempt failed - return error
-                        let stderr = String::from_utf8_lossy(&output.stderr);
-                        return Err(TestcontainersError::DockerUnavailable(format!(
-                            "Docker daemon is not running. Error: {stderr}"
-                        )));
-                    }
-                    Err(e) => {
-                        if attempt < MAX_RETRIES {
-                            // Small delay to reduce contention
-                            thread::sleep(Duration::from_millis(100 * u64::from(attempt + 1)));
-                            continue;
-                        }
-                        if e.kind() == std::io::ErrorKind::NotFound {
-                            return Err(TestcontainersError::DockerUnavailable(
-                                "Docker command not found. Please install Docker.".to_string(),
-                            ));
-                        }
-                        return Err(TestcontainersError::DockerUnavailable(format!(
-                            "Failed to check Docker availability: {e}"
-                        )));
+            if attempt > 0 {
+                // Small delay to reduce contention when multiple tests check Docker simultaneously
+                cancellable_sleep(
+                    "docker availability check",
+                    Duration::from_millis(100 * u64::from(attempt)),
+                )
+                .map_err(|e| TestcontainersError::Cancelled(e.to_string()))?;
+            }
+            match docker_info.run() {
+                Ok(output) => {
+                    // Verify Docker daemon is responding by checking output
+                    let stdout = output.stdout_lossy();
+                    if stdout.contains("Server Version") || stdout.contains("Docker Root Dir") {
+                        // ✅ Docker daemon is running and responding
+                        return Ok(());
                     }
+                    last_error = Some(format!(
+                        "Docker daemon is not running. Error: {}",
+                        output.stderr_lossy()
+                    ));
+                }
+                // Docker missing entirely - retrying will not help
+                Err(CommandError::NotFound { .. }) => {
+                    return Err(TestcontainersError::DockerUnavailable(
+                        "Docker command not found. Please install Docker.".to_string(),
+                    ));
+                }
+                Err(CommandError::Failed { stderr, .. }) => {
+                    last_error = Some(format!("Docker daemon is not running. Error: {stderr}"));
+                }
+                // 🚨 Timeout - Docker command hung (likely Docker daemon not running or under heavy load)
+                Err(CommandError::TimedOut { .. }) => {
+                    last_error = Some(format!(
+                        "Docker check timed out after {}ms after {} attempts (Docker daemon likely not running or under heavy load). This prevents hanging indefinitely when Docker is unavailable.",
+                        PROBE_COMMAND_TIMEOUT.as_millis(),
+                        attempt + 1
+                    ));
+                }
+                Err(e) => {
+                    last_error = Some(format!("Failed to check Docker availability: {e}"));
                 }
             }
-            // 🚨 Timeout - Docker command hung (likely Docker daemon not running or under heavy load)
-            if attempt < MAX_RETRIES {
-                // Retry on timeout - Docker might be slow under parallel test load
-                // Exponential backoff: 100ms, 200ms delays
-                thread::sleep(Duration::from_millis(100 * u64::from(attempt + 1)));
-                continue;
-            }
-            return Err(TestcontainersError::DockerUnavailable(format!(
-                "Docker check timed out after {DOCKER_CHECK_TIMEOUT_MILLIS}ms after {} attempts (Docker daemon likely not running or under heavy load). This prevents hanging indefinitely when Docker is unavailable.",
-                attempt + 1
-            )));
         }
 
-        // Should never reach here, but provide fallback error
         Err(TestcontainersError::DockerUnavailable(
-            "Docker check failed after all retry attempts".to_string(),
+            last_error
+                .unwrap_or_else(|| "Docker check failed after all retry attempts".to_string()),
         ))
     }
 
@@ -360,19 +341,22 @@ pub mod implementation {
     /// # Errors
     ///
     /// Returns `Err(TestcontainersError::OperationFailed)` when all retries are exhausted and the
-    /// container has still not reached the `running` state.
+    /// container has still not reached the `running` state, or
+    /// `Err(TestcontainersError::Cancelled)` when the current test is cancelled mid-wait.
     fn wait_for_container_ready(container_id: &str) -> TestcontainersResult<()> {
-        use std::thread;
         use std::time::Duration;
 
+        let label = format!("container {container_id} readiness");
+
         for attempt in 0..=CONTAINER_STARTUP_MAX_RETRIES {
             // Check if container is running using docker ps
-            let output = Command::new("docker")
+            let output = CheckedCommand::new("docker")
                 .args(["ps", "--filter", &format!("id={container_id}"), "--format", "{{.State}}"])
+                .timeout(PROBE_COMMAND_TIMEOUT)
                 .output();
 
             if let Ok(out) = output {
-                let state = String::from_utf8_lossy(&out.stdout).trim().to_string();
+                let state = out.stdout_lossy().trim().to_string();
                 // Container is running if docker ps finds it in any non-empty state
                 if !state.is_empty() && state == "running" {
                     return Ok(());
@@ -383,7 +367,8 @@ pub mod implementation {
             // Not ready yet - retry with exponential backoff if not last attempt
             if attempt < CONTAINER_STARTUP_MAX_RETRIES {
                 let delay_ms = CONTAINER_RETRY_INITIAL_DELAY_MS * 2_u64.pow(attempt);
-                thread::sleep(Duration::from_millis(delay_ms));
+                cancellable_sleep(&label, Duration::from_millis(delay_ms))
+                    .map_err(|e| TestcontainersError::Cancelled(e.to_string()))?;
             }
         }
 
@@ -459,6 +444,8 @@ pub mod implementation {
         /// Container ID for Docker CLI-created containers (used for entrypoint override workaround)
         /// When Some, exec operations use docker exec directly instead of testcontainers exec
         docker_cli_container_id: Option<String>,
+        /// Host ports allocated by `with_free_host_ports`, reserved while the container runs
+        port_reservations: Vec<ReservedPort>,
     }
 
     impl GenericContainer {
@@ -497,7 +484,7 @@ pub mod implementation {
             })?;
 
             // ✅ Container created successfully
-            Ok(Self { container: Some(container), docker_cli_container_id: None })
+            Ok(Self::from_container(container))
         }
 
         /// Create a `GenericContainer` from an existing Container
@@ -505,13 +492,21 @@ pub mod implementation {
         /// This is used internally by other methods (e.g., `with_wait_for`) to construct
         /// a `GenericContainer` from a Container that was created with additional configuration.
         pub(crate) const fn from_container(container: Container<GenericImage>) -> Self {
-            Self { container: Some(container), docker_cli_container_id: None }
+            Self {
+                container: Some(container),
+                docker_cli_container_id: None,
+                port_reservations: Vec::new(),
+            }
         }
 
         /// Create a `GenericContainer` from a Docker CLI-created container ID
         /// This is used for entrypoint override workaround when testcontainers doesn't support it
         pub(crate) const fn from_docker_cli_container_id(container_id: String) -> Self {
-            Self { container: None, docker_cli_container_id: Some(container_id) }
+            Self {
+                container: None,
+                docker_cli_container_id: Some(container_id),
+                port_reservations: Vec::new(),
+            }
         }
 
         /// Create a new generic container with environment variables and optional command
@@ -560,7 +555,7 @@ pub mod implementation {
                 }
             })?;
 
-            Ok(Self { container: Some(container), docker_cli_container_id: None })
+            Ok(Self::from_container(container))
         }
 
         /// Create a new generic container with environment variables
@@ -600,7 +595,7 @@ pub mod implementation {
                 }
             })?;
 
-            Ok(Self { container: Some(container), docker_cli_container_id: None })
+            Ok(Self::from_container(container))
         }
 
         /// Create a new generic container with command (and optional entrypoint override)
@@ -700,7 +695,7 @@ pub mod implementation {
                 let cmd_str = cmd_args.join(" ");
 
                 // Create container with entrypoint override
-                let create_output = Command::new("docker")
+                let create_output = CheckedCommand::new("docker")
                     .args([
                         "create",
                         "--entrypoint",
@@ -708,21 +703,16 @@ pub mod implementation {
                         &image_tag,
                     ])
                     .args(&cmd_args)
-                    .output()
-                    .map_err(|e| {
-                        TestcontainersError::CreationFailed(format!(
-                            "Failed to create container with entrypoint override: {e}\n   ⚠️  STOP: Docker CLI command failed\n   💡 FIX: Check Docker is installed and running"
-                        ))
+                    .run()
+                    .map_err(|e| match e {
+                        CommandError::Failed { status, stderr, .. } => TestcontainersError::CreationFailed(format!(
+                            "Failed to create container with entrypoint override: {status}\n   ⚠️  STOP: Container creation failed\n   💡 FIX: Check Docker image exists and entrypoint is valid\n   Command: docker create --entrypoint {entrypoint_str} {image_tag} {cmd_str}\n   Error: {stderr}"
+                        )),
+                        other => TestcontainersError::CreationFailed(format!(
+                            "Failed to create container with entrypoint override: {other}\n   ⚠️  STOP: Docker CLI command failed\n   💡 FIX: Check Docker is installed and running"
+                        )),
                     })?;
 
-                if !create_output.status.success() {
-                    let stderr = String::from_utf8_lossy(&create_output.stderr);
-                    return Err(TestcontainersError::CreationFailed(format!(
-                        "Failed to create container with entrypoint override: {}\n   ⚠️  STOP: Container creation failed\n   💡 FIX: Check Docker image exists and entrypoint is valid\n   Command: docker create --entrypoint {} {} {}\n   Error: {}",
-                        create_output.status, entrypoint_str, image_tag, cmd_str, stderr
-                    )));
-                }
-
                 // Get container ID from output
                 let container_id = String::from_utf8(create_output.stdout)
                     .map_err(|e| {
@@ -740,7 +730,7 @@ pub mod implementation {
                 }
 
                 // Start the container
-                let start_output = Command::new("docker")
+                let start_output = CheckedCommand::new("docker")
                     .args(["start", &container_id])
                     .output()
                     .map_err(|e| {
@@ -749,12 +739,12 @@ pub mod implementation {
                         ))
                     })?;
 
-                if !start_output.status.success() {
-                    let stderr = String::from_utf8_lossy(&start_output.stderr);
+                if !start_output.success() {
+                    let stderr = start_output.stderr_lossy();
                     // Clean up the created container on failure
                     // **Gemba Fix**: Log cleanup attempt (non-critical, but useful for debugging)
                     let cleanup_result =
-                        Command::new("docker").args(["rm", "-f", &container_id]).output();
+                        CheckedCommand::new("docker").args(["rm", "-f", &container_id]).output();
                     if let Err(e) = cleanup_result {
                         // Log cleanup failure but don't fail the operation (container creation already failed)
                         eprintln!(
@@ -798,7 +788,7 @@ pub mod implementation {
                 }
             })?;
 
-            Ok(Self { container: Some(container), docker_cli_container_id: None })
+            Ok(Self::from_container(container))
         }
 
         /// Create a new generic container with entrypoint override and command
@@ -871,7 +861,57 @@ pub mod implementation {
                 }
             })?;
 
-            Ok(Self { container: Some(container), docker_cli_container_id: None })
+            Ok(Self::from_container(container))
+        }
+
+        /// Create a new generic container with ports mapped to free, pre-allocated host ports
+        ///
+        /// Unlike [`GenericContainer::with_ports`], the host ports are chosen by
+        /// [`PortAllocator`] before the container starts and stay reserved until it is
+        /// dropped, so the same port is never handed to another test (or a Weaver
+        /// validator) in this process. Look them up with [`GenericContainer::get_host_port`].
+        ///
+        /// # Arguments
+        ///
+        /// * `_client` - Container client instance (unused in minimal implementation)
+        /// * `image` - Docker image name
+        /// * `tag` - Docker image tag
+        /// * `ports` - Container ports to map to host ports
+        ///
+        /// # Errors
+        ///
+        /// Returns error if free host ports cannot be allocated or container creation fails
+        pub fn with_free_host_ports(
+            _client: &ContainerClient,
+            image: &str,
+            tag: &str,
+            ports: &[u16],
+        ) -> TestcontainersResult<Self> {
+            // 🚨 Verify Docker is still available
+            check_docker_available()?;
+
+            let reservations = PortAllocator::allocate_many(ports.len()).map_err(|e| {
+                TestcontainersError::InvalidConfig(format!("Failed to allocate host ports: {e}"))
+            })?;
+            let mut request: testcontainers::core::ContainerRequest<GenericImage> =
+                GenericImage::new(image, tag).into();
+            for (port, host_port) in ports.iter().zip(&reservations) {
+                request = request.with_mapped_port(host_port.port(), ContainerPort::Tcp(*port));
+            }
+            let container = request.start().map_err(|e| {
+                let error_msg = format!("{e}");
+                if is_docker_unavailable_error(&error_msg) {
+                    TestcontainersError::DockerUnavailable(format!(
+                        "Docker daemon connection failed during container start: {e}\n   ⚠️  STOP: Cannot connect to Docker daemon\n   💡 FIX: Start Docker Desktop or Docker daemon"
+                    ))
+                } else {
+                    TestcontainersError::CreationFailed(format!("Failed to start container: {e}"))
+                }
+            })?;
+
+            let mut container = Self::from_container(container);
+            container.port_reservations = reservations;
+            Ok(container)
         }
 
         /// Get the host port for a container port
@@ -927,12 +967,12 @@ pub mod implementation {
         fn drop(&mut self) {
             // Clean up Docker CLI-created containers
             if let Some(container_id) = &self.docker_cli_container_id {
-                use std::process::Command;
+                use crate::core::command::CheckedCommand;
                 // Use -f flag to force remove even if container is running
                 // This ensures cleanup even if container didn't stop properly
                 // **Gemba Fix**: Log cleanup failures for debugging (non-critical but useful)
                 let cleanup_result =
-                    Command::new("docker").args(["rm", "-f", container_id]).output();
+                    CheckedCommand::new("docker").args(["rm", "-f", container_id]).output();
                 if let Err(e) = cleanup_result {
                     // Log cleanup failure but don't panic (Drop must not panic)
                     eprintln!(
@@ -1013,6 +1053,17 @@ mod stubs {
             ))
         }
 
+        pub fn with_free_host_ports(
+            _client: &ContainerClient,
+            _image: &str,
+            _tag: &str,
+            _ports: &[u16],
+        ) -> TestcontainersResult<Self> {
+            Err(TestcontainersError::InvalidConfig(
+                "testcontainers feature is not enabled".to_string(),
+            ))
+        }
+
         pub fn get_host_port(&self, _container_port: u16) -> TestcontainersResult<u16> {
             Err(TestcontainersError::InvalidConfig(
                 "testcontainers feature is not enabled".to_string(),
@@ -1055,6 +1106,7 @@ mod tests {
             TestcontainersError::StdoutReadFailed("test".to_string()),
             TestcontainersError::StderrReadFailed("test".to_string()),
             TestcontainersError::ExitCodeFailed("test".to_string()),
+            TestcontainersError::Cancelled("test".to_string()),
         ];
 
         // Act & Assert: Verify all error variants display correctly
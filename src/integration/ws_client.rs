@@ -0,0 +1,729 @@
+//! WebSocket Test Client
+//!
+//! Connects to a WebSocket server under test over a real socket and drives it with
+//! scripted send/expect conversations. Every message sent and received is captured in a
+//! [`WsTranscript`], so a failing expectation reports the whole conversation and a
+//! passing one can be pinned with a snapshot for regression.
+//!
+//! **Required feature**: `http-testing` (transcript snapshots also need `snapshot-testing`)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::integration::ws_client::{WsScript, WsTestClient};
+//! use std::time::Duration;
+//!
+//! let mut client = WsTestClient::connect("ws://127.0.0.1:8080/chat").unwrap();
+//!
+//! WsScript::new()
+//!     .send_json(&serde_json::json!({ "type": "join", "room": "lobby" }))
+//!     .expect_json(&serde_json::json!({ "type": "joined", "room": "lobby" }))
+//!     .send_text("hello")
+//!     .expect_contains_within("hello", Duration::from_millis(500))
+//!     .run(&mut client)
+//!     .unwrap();
+//!
+//! println!("{}", client.transcript());
+//! ```
+
+use std::fmt;
+use std::io::ErrorKind;
+use std::net::{TcpStream, ToSocketAddrs};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tungstenite::client::IntoClientRequest;
+use tungstenite::protocol::frame::coding::CloseCode;
+use tungstenite::protocol::CloseFrame;
+use tungstenite::{Message, WebSocket};
+
+/// Default time to wait for an expected message
+pub const DEFAULT_EXPECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// WebSocket test client errors
+#[derive(Error, Debug)]
+pub enum WsError {
+    /// Could not open the connection or complete the upgrade handshake
+    #[error("🚨 Failed to connect WebSocket test client to {url}: {reason}")]
+    Connect {
+        /// URL the client tried to reach
+        url: String,
+        /// Why the connection failed
+        reason: String,
+    },
+    /// Could not send or receive a frame
+    #[error("🚨 WebSocket transport error: {0}")]
+    Transport(String),
+    /// The server closed the connection
+    #[error("🚨 WebSocket connection closed while waiting for {expected}")]
+    Closed {
+        /// What the client was waiting for
+        expected: String,
+    },
+    /// No message arrived within the timeout
+    #[error("🚨 Timed out after {waited:?} waiting for {expected}")]
+    Timeout {
+        /// What the client was waiting for
+        expected: String,
+        /// How long it waited
+        waited: Duration,
+    },
+    /// A message arrived but did not match the expectation
+    #[error("🚨 Expected {expected}, received {received}")]
+    Unexpected {
+        /// What the client was waiting for
+        expected: String,
+        /// The message that arrived instead
+        received: WsMessage,
+    },
+    /// A script step failed; carries the transcript up to the failure
+    #[error("🚨 WebSocket script step {step} failed: {reason}\n   Transcript:\n{transcript}")]
+    Script {
+        /// Zero-based index of the failing step
+        step: usize,
+        /// The underlying failure
+        reason: Box<Self>,
+        /// Conversation up to and including the failure
+        transcript: WsTranscript,
+    },
+}
+
+/// Result type for WebSocket test client operations
+pub type WsResult<T> = Result<T, WsError>;
+
+/// A data or close message exchanged with the server
+///
+/// Ping and pong frames are answered automatically and are not surfaced, so they
+/// never make transcripts timing-dependent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WsMessage {
+    /// UTF-8 text message
+    Text(String),
+    /// Binary message
+    Binary(Vec<u8>),
+    /// Close frame with status code and reason
+    Close {
+        /// Close status code (`1005` when the peer sent none)
+        code: u16,
+        /// Close reason
+        reason: String,
+    },
+}
+
+impl WsMessage {
+    /// Text payload, if this is a text message
+    #[must_use]
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            Self::Binary(_) | Self::Close { .. } => None,
+        }
+    }
+
+    fn from_frame(message: Message) -> Option<Self> {
+        match message {
+            Message::Text(text) => Some(Self::Text(text.as_str().to_string())),
+            Message::Binary(bytes) => Some(Self::Binary(bytes.to_vec())),
+            Message::Close(frame) => Some(frame.map_or_else(
+                || Self::Close { code: u16::from(CloseCode::Status), reason: String::new() },
+                |frame| Self::Close {
+                    code: u16::from(frame.code),
+                    reason: frame.reason.as_str().to_string(),
+                },
+            )),
+            Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => None,
+        }
+    }
+
+    fn into_frame(self) -> Message {
+        match self {
+            Self::Text(text) => Message::text(text),
+            Self::Binary(bytes) => Message::binary(bytes),
+            Self::Close { code, reason } => Message::Close(Some(CloseFrame {
+                code: CloseCode::from(code),
+                reason: reason.into(),
+            })),
+        }
+    }
+}
+
+impl fmt::Display for WsMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => write!(f, "{text}"),
+            Self::Binary(bytes) => write!(f, "[binary {}]", hex::encode(bytes)),
+            Self::Close { code, reason } if reason.is_empty() => write!(f, "[close {code}]"),
+            Self::Close { code, reason } => write!(f, "[close {code} {reason}]"),
+        }
+    }
+}
+
+/// Which side produced a transcript entry
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsDirection {
+    /// Sent by the client
+    Sent,
+    /// Received from the server
+    Received,
+}
+
+/// One message in a [`WsTranscript`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WsTranscriptEntry {
+    /// Who sent the message
+    pub direction: WsDirection,
+    /// The message
+    pub message: WsMessage,
+    /// Time since the client connected
+    pub at: Duration,
+}
+
+/// Ordered record of a WebSocket conversation
+///
+/// Displays one message per line, `> ` for sent and `< ` for received. Timings are
+/// kept on the entries but left out of the rendering, so it is stable enough to snapshot.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WsTranscript {
+    entries: Vec<WsTranscriptEntry>,
+}
+
+impl WsTranscript {
+    /// Every entry, in order
+    #[must_use]
+    pub fn entries(&self) -> &[WsTranscriptEntry] {
+        &self.entries
+    }
+
+    /// Messages sent by the client
+    #[must_use]
+    pub fn sent(&self) -> Vec<&WsMessage> {
+        self.messages(WsDirection::Sent)
+    }
+
+    /// Messages received from the server
+    #[must_use]
+    pub fn received(&self) -> Vec<&WsMessage> {
+        self.messages(WsDirection::Received)
+    }
+
+    /// Number of entries
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether nothing has been exchanged yet
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    fn messages(&self, direction: WsDirection) -> Vec<&WsMessage> {
+        self.entries
+            .iter()
+            .filter(|e| e.direction == direction)
+            .map(|e| &e.message)
+            .collect()
+    }
+}
+
+impl fmt::Display for WsTranscript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            let arrow = match entry.direction {
+                WsDirection::Sent => '>',
+                WsDirection::Received => '<',
+            };
+            writeln!(f, "{arrow} {}", entry.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// What an expected message must look like
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WsExpect {
+    /// Text message equal to the string
+    Text(String),
+    /// Text message containing the string
+    TextContains(String),
+    /// Text message that parses to the JSON value
+    Json(serde_json::Value),
+    /// Binary message equal to the bytes
+    Binary(Vec<u8>),
+    /// Close frame, with any code
+    Close,
+}
+
+impl WsExpect {
+    /// Whether `message` satisfies this expectation
+    #[must_use]
+    pub fn matches(&self, message: &WsMessage) -> bool {
+        match (self, message) {
+            (Self::Text(expected), WsMessage::Text(text)) => expected == text,
+            (Self::TextContains(needle), WsMessage::Text(text)) => text.contains(needle.as_str()),
+            (Self::Json(expected), WsMessage::Text(text)) => {
+                serde_json::from_str::<serde_json::Value>(text).is_ok_and(|v| &v == expected)
+            }
+            (Self::Binary(expected), WsMessage::Binary(bytes)) => expected == bytes,
+            (Self::Close, WsMessage::Close { .. }) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for WsExpect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Text(text) => write!(f, "text {text:?}"),
+            Self::TextContains(needle) => write!(f, "text containing {needle:?}"),
+            Self::Json(value) => write!(f, "JSON {value}"),
+            Self::Binary(bytes) => write!(f, "binary {}", hex::encode(bytes)),
+            Self::Close => write!(f, "close frame"),
+        }
+    }
+}
+
+/// WebSocket client connected to a server under test
+///
+/// Blocking and single-threaded: each call sends or waits on the calling thread. The
+/// connection is closed when the client is dropped.
+pub struct WsTestClient {
+    url: String,
+    socket: WebSocket<TcpStream>,
+    transcript: WsTranscript,
+    started: Instant,
+    timeout: Duration,
+}
+
+impl fmt::Debug for WsTestClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WsTestClient")
+            .field("url", &self.url)
+            .field("timeout", &self.timeout)
+            .field("messages", &self.transcript.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl WsTestClient {
+    /// Connect to a `ws://` URL and complete the upgrade handshake
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WsError::Connect`] if the URL is invalid, not `ws://`, unreachable, or
+    /// the server rejects the upgrade.
+    pub fn connect(url: &str) -> WsResult<Self> {
+        let connect_error = |reason: String| WsError::Connect { url: url.to_string(), reason };
+        let request = url.into_client_request().map_err(|e| connect_error(e.to_string()))?;
+        let uri = request.uri();
+        if uri.scheme_str() != Some("ws") {
+            return Err(connect_error("only ws:// URLs are supported".to_string()));
+        }
+        let host = uri.host().ok_or_else(|| connect_error("missing host".to_string()))?;
+        let port = uri.port_u16().unwrap_or(80);
+        let addr = (host, port)
+            .to_socket_addrs()
+            .map_err(|e| connect_error(e.to_string()))?
+            .next()
+            .ok_or_else(|| connect_error(format!("{host} did not resolve")))?;
+        let stream = TcpStream::connect_timeout(&addr, DEFAULT_EXPECT_TIMEOUT)
+            .map_err(|e| connect_error(e.to_string()))?;
+        stream
+            .set_read_timeout(Some(DEFAULT_EXPECT_TIMEOUT))
+            .map_err(|e| connect_error(e.to_string()))?;
+        let (socket, _) =
+            tungstenite::client(request, stream).map_err(|e| connect_error(e.to_string()))?;
+        Ok(Self {
+            url: url.to_string(),
+            socket,
+            transcript: WsTranscript::default(),
+            started: Instant::now(),
+            timeout: DEFAULT_EXPECT_TIMEOUT,
+        })
+    }
+
+    /// Set the default wait for [`expect`](Self::expect) and [`recv`](Self::recv)
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// URL the client connected to
+    #[must_use]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Conversation so far
+    #[must_use]
+    pub const fn transcript(&self) -> &WsTranscript {
+        &self.transcript
+    }
+
+    /// Send a message
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WsError::Transport`] if the frame cannot be written.
+    pub fn send(&mut self, message: WsMessage) -> WsResult<()> {
+        self.socket
+            .send(message.clone().into_frame())
+            .map_err(|e| WsError::Transport(e.to_string()))?;
+        self.push(WsDirection::Sent, message);
+        Ok(())
+    }
+
+    /// Send a text message
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WsError::Transport`] if the frame cannot be written.
+    pub fn send_text(&mut self, text: impl Into<String>) -> WsResult<()> {
+        self.send(WsMessage::Text(text.into()))
+    }
+
+    /// Send a value as a JSON text message
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WsError::Transport`] if the frame cannot be written.
+    pub fn send_json(&mut self, value: &serde_json::Value) -> WsResult<()> {
+        self.send_text(value.to_string())
+    }
+
+    /// Send a binary message
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WsError::Transport`] if the frame cannot be written.
+    pub fn send_binary(&mut self, bytes: impl Into<Vec<u8>>) -> WsResult<()> {
+        self.send(WsMessage::Binary(bytes.into()))
+    }
+
+    /// Wait for the next message, up to the client's timeout
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WsError::Timeout`], [`WsError::Closed`], or [`WsError::Transport`].
+    pub fn recv(&mut self) -> WsResult<WsMessage> {
+        self.recv_within(self.timeout, "any message")
+    }
+
+    /// Wait for the next message and check it against `expected`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WsError::Unexpected`] if the next message does not match, or any
+    /// [`recv`](Self::recv) error.
+    pub fn expect(&mut self, expected: &WsExpect) -> WsResult<WsMessage> {
+        self.expect_within(expected, self.timeout)
+    }
+
+    /// [`expect`](Self::expect) with an explicit timeout
+    ///
+    /// # Errors
+    ///
+    /// Same as [`expect`](Self::expect).
+    pub fn expect_within(&mut self, expected: &WsExpect, timeout: Duration) -> WsResult<WsMessage> {
+        let description = expected.to_string();
+        let received = self.recv_within(timeout, &description)?;
+        if expected.matches(&received) {
+            Ok(received)
+        } else {
+            Err(WsError::Unexpected { expected: description, received })
+        }
+    }
+
+    /// Expect a text message equal to `text`
+    ///
+    /// # Errors
+    ///
+    /// Same as [`expect`](Self::expect).
+    pub fn expect_text(&mut self, text: &str) -> WsResult<WsMessage> {
+        self.expect(&WsExpect::Text(text.to_string()))
+    }
+
+    /// Send a close frame and wait for the server to acknowledge it
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WsError::Transport`] if the close frame cannot be written, or a
+    /// [`recv`](Self::recv) error if no acknowledgement arrives.
+    pub fn close(&mut self) -> WsResult<()> {
+        self.send(WsMessage::Close { code: u16::from(CloseCode::Normal), reason: String::new() })?;
+        self.expect(&WsExpect::Close).map(|_| ())
+    }
+
+    /// Assert the transcript matches the stored snapshot `name`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the rendered transcript differs from the snapshot.
+    #[cfg(feature = "snapshot-testing")]
+    pub fn assert_transcript_snapshot(&self, name: &str) {
+        crate::testing::snapshot::SnapshotAssert::assert_matches(&self.transcript, name);
+    }
+
+    fn recv_within(&mut self, timeout: Duration, expected: &str) -> WsResult<WsMessage> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(WsError::Timeout { expected: expected.to_string(), waited: timeout });
+            }
+            self.socket
+                .get_mut()
+                .set_read_timeout(Some(remaining))
+                .map_err(|e| WsError::Transport(e.to_string()))?;
+            match self.socket.read() {
+                Ok(frame) => {
+                    if let Some(message) = WsMessage::from_frame(frame) {
+                        self.push(WsDirection::Received, message.clone());
+                        return Ok(message);
+                    }
+                }
+                Err(tungstenite::Error::Io(e))
+                    if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => {}
+                Err(tungstenite::Error::ConnectionClosed | tungstenite::Error::AlreadyClosed) => {
+                    return Err(WsError::Closed { expected: expected.to_string() });
+                }
+                Err(e) => return Err(WsError::Transport(e.to_string())),
+            }
+        }
+    }
+
+    fn push(&mut self, direction: WsDirection, message: WsMessage) {
+        let at = self.started.elapsed();
+        self.transcript.entries.push(WsTranscriptEntry { direction, message, at });
+    }
+}
+
+impl Drop for WsTestClient {
+    fn drop(&mut self) {
+        if self.socket.can_write() {
+            let _ = self.socket.close(None);
+            let _ = self.socket.flush();
+        }
+    }
+}
+
+/// A scripted step
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum WsStep {
+    Send(WsMessage),
+    Expect(WsExpect, Option<Duration>),
+}
+
+/// Ordered send/expect conversation to run against a [`WsTestClient`]
+///
+/// Steps run in order; the first failing expectation stops the script and reports
+/// the step index with the transcript so far.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WsScript {
+    steps: Vec<WsStep>,
+}
+
+impl WsScript {
+    /// Empty script
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send a message
+    #[must_use]
+    pub fn send(mut self, message: WsMessage) -> Self {
+        self.steps.push(WsStep::Send(message));
+        self
+    }
+
+    /// Send a text message
+    #[must_use]
+    pub fn send_text(self, text: impl Into<String>) -> Self {
+        self.send(WsMessage::Text(text.into()))
+    }
+
+    /// Send a value as a JSON text message
+    #[must_use]
+    pub fn send_json(self, value: &serde_json::Value) -> Self {
+        self.send_text(value.to_string())
+    }
+
+    /// Send a binary message
+    #[must_use]
+    pub fn send_binary(self, bytes: impl Into<Vec<u8>>) -> Self {
+        self.send(WsMessage::Binary(bytes.into()))
+    }
+
+    /// Expect the next message to match, within the client's timeout
+    #[must_use]
+    pub fn expect(mut self, expected: WsExpect) -> Self {
+        self.steps.push(WsStep::Expect(expected, None));
+        self
+    }
+
+    /// Expect the next message to match within `timeout`
+    #[must_use]
+    pub fn expect_within(mut self, expected: WsExpect, timeout: Duration) -> Self {
+        self.steps.push(WsStep::Expect(expected, Some(timeout)));
+        self
+    }
+
+    /// Expect a text message equal to `text`
+    #[must_use]
+    pub fn expect_text(self, text: impl Into<String>) -> Self {
+        self.expect(WsExpect::Text(text.into()))
+    }
+
+    /// Expect a text message containing `needle` within `timeout`
+    #[must_use]
+    pub fn expect_contains_within(self, needle: impl Into<String>, timeout: Duration) -> Self {
+        self.expect_within(WsExpect::TextContains(needle.into()), timeout)
+    }
+
+    /// Expect a text message that parses to `value`
+    #[must_use]
+    pub fn expect_json(self, value: &serde_json::Value) -> Self {
+        self.expect(WsExpect::Json(value.clone()))
+    }
+
+    /// Number of steps
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    /// Whether the script has no steps
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Run every step against `client`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WsError::Script`] wrapping the first failing step.
+    pub fn run(&self, client: &mut WsTestClient) -> WsResult<()> {
+        for (step, action) in self.steps.iter().enumerate() {
+            let outcome = match action {
+                WsStep::Send(message) => client.send(message.clone()),
+                WsStep::Expect(expected, timeout) => {
+                    client.expect_within(expected, timeout.unwrap_or(client.timeout)).map(|_| ())
+                }
+            };
+            if let Err(reason) = outcome {
+                return Err(WsError::Script {
+                    step,
+                    reason: Box::new(reason),
+                    transcript: client.transcript.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::net::{Ipv4Addr, TcpListener};
+    use std::thread::JoinHandle;
+
+    /// Accept one connection and echo data messages back with an `echo: ` prefix
+    #[allow(clippy::unwrap_used)] // Test code - a broken echo server should fail the test
+    fn echo_server() -> (String, JoinHandle<()>) {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let url = format!("ws://{}/echo", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = tungstenite::accept(stream).unwrap();
+            while let Ok(message) = socket.read() {
+                let reply = match message {
+                    Message::Text(text) if text.as_str() == "silence" => continue,
+                    Message::Text(text) => Message::text(format!("echo: {}", text.as_str())),
+                    Message::Binary(bytes) => Message::Binary(bytes),
+                    _ => continue,
+                };
+                if socket.send(reply).is_err() {
+                    break;
+                }
+            }
+        });
+        (url, handle)
+    }
+
+    test!(test_script_send_expect_builds_transcript, {
+        // Arrange
+        let (url, server) = echo_server();
+        #[allow(clippy::unwrap_used)] // Test code - server is local
+        let mut client = WsTestClient::connect(&url).unwrap();
+        let script = WsScript::new()
+            .send_text("hello")
+            .expect_text("echo: hello")
+            .send_json(&serde_json::json!({ "n": 1 }))
+            .expect_contains_within("\"n\":1", Duration::from_secs(1))
+            .send_binary(vec![0xca, 0xfe])
+            .expect(WsExpect::Binary(vec![0xca, 0xfe]));
+
+        // Act
+        let outcome = script.run(&mut client);
+        #[allow(clippy::unwrap_used)] // Test code - echo server acknowledges close
+        client.close().unwrap();
+
+        // Assert
+        assert!(outcome.is_ok(), "{outcome:?}");
+        assert_eq!(
+            client.transcript().to_string(),
+            "> hello\n< echo: hello\n> {\"n\":1}\n< echo: {\"n\":1}\n> [binary cafe]\n< [binary cafe]\n> [close 1000]\n< [close 1000]\n"
+        );
+        assert_eq!(client.transcript().sent().len(), 4);
+        drop(client);
+        #[allow(clippy::unwrap_used)] // Test code - server thread should exit cleanly
+        server.join().unwrap();
+    });
+
+    test!(test_script_failure_reports_step_and_transcript, {
+        // Arrange
+        let (url, _server) = echo_server();
+        #[allow(clippy::unwrap_used)] // Test code - server is local
+        let mut client = WsTestClient::connect(&url).unwrap();
+        let script = WsScript::new().send_text("ping").expect_text("pong");
+
+        // Act
+        let error = script.run(&mut client).unwrap_err();
+
+        // Assert
+        let WsError::Script { step, reason, transcript } = &error else {
+            panic!("expected script error, got {error:?}");
+        };
+        assert_eq!(*step, 1);
+        assert!(matches!(**reason, WsError::Unexpected { .. }));
+        assert_eq!(transcript.to_string(), "> ping\n< echo: ping\n");
+        assert!(error.to_string().contains("Expected text \"pong\", received echo: ping"));
+    });
+
+    test!(test_expect_times_out_when_server_is_silent, {
+        // Arrange
+        let (url, _server) = echo_server();
+        #[allow(clippy::unwrap_used)] // Test code - server is local
+        let mut client =
+            WsTestClient::connect(&url).unwrap().with_timeout(Duration::from_millis(100));
+
+        // Act
+        #[allow(clippy::unwrap_used)] // Test code - send to local server
+        client.send_text("silence").unwrap();
+        let result = client.expect_text("anything");
+
+        // Assert
+        assert!(
+            matches!(result, Err(WsError::Timeout { waited, .. }) if waited == Duration::from_millis(100))
+        );
+        assert!(matches!(
+            WsTestClient::connect("http://127.0.0.1:1/"),
+            Err(WsError::Connect { .. })
+        ));
+    });
+}
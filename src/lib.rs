@@ -53,8 +53,9 @@
 //! - `macros`: Test macros for AAA pattern enforcement and assertions
 //! - `state`: Type-level AAA enforcement
 //! - `poka_yoke`: Error prevention through type-level safety (prevents invalid states)
-//! - `const_assert`: Compile-time assertions
-//! - `alert`: Alert helpers for visual problem indicators (with optional `log` crate integration)
+//! - `const_assert`: Compile-time assertions (type layout, enum variant counts, string prefixes)
+//! - `alert`: Alert helpers for visual problem indicators (with optional `log` crate integration
+//!   and pluggable sinks: JSON lines file, in-memory capture, webhook)
 //!
 //! ### Advanced Testing Techniques (`testing`)
 //! - `property`: Property-based testing framework
@@ -66,12 +67,16 @@
 //!
 //! ### Quality & Validation (`validation`)
 //! - `coverage`: Test coverage analysis
-//! - `guards`: Guard constraint enforcement (`MAX_RUN_LEN` ≤ 8, `MAX_BATCH_SIZE`)
+//! - `guards`: Guard constraint enforcement (`MAX_RUN_LEN` ≤ 8, `MAX_BATCH_SIZE`, named guard profiles)
 //! - `jtbd`: Jobs To Be Done validation framework (validates code accomplishes intended purpose)
 //! - `performance`: RDTSC benchmarking and tick measurement
 //!
 //! ### Telemetry & Observability (`observability`)
-//! - `otel`: OTEL span/metric validation (requires `otel` feature)
+//! - `otel`: OTEL span/metric validation and trace tree assertions (requires `otel` feature);
+//!   in-process SDK span and metric collectors (requires `otel-sdk` feature); OTLP
+//!   protobuf/JSON payload decoding (requires `otlp` feature)
+//! - `receiver`: Embedded OTLP/HTTP and OTLP/gRPC test receiver capturing exported spans,
+//!   metrics, and logs (requires `otlp-receiver` feature)
 //! - `weaver`: Weaver live validation integration (requires `weaver` feature)
 //!
 //! ### Integration Testing (`integration`)
@@ -94,6 +99,9 @@
 //! - `#[fixture]`: Procedural macro for automatic fixture setup/teardown
 //!   - Import: `use chicago_tdd_tools::fixture;` (re-exported) or `use chicago_tdd_tools_proc_macros::fixture;`
 //! - `#[derive(TestBuilder)]`: Derive macro for fluent builder generation
+//! - `#[derive(Typestate)]`: Derive macro for poka-yoke typestate machines (`core::poka_yoke`)
+//! - `#[jtbd(job = "...", scenario = "...")]`: Links a test to a JTBD scenario (requires `jtbd-linkage` feature)
+//!   - Import: `use chicago_tdd_tools::jtbd;` (re-exported) or `use chicago_tdd_tools_proc_macros::jtbd;`
 //!
 //! ## Declarative Macros
 //!
@@ -167,6 +175,14 @@ pub use chicago_tdd_tools_proc_macros::tdd_test;
 // Re-export TestBuilder derive macro (users will use #[derive(TestBuilder)])
 pub use chicago_tdd_tools_proc_macros::TestBuilder;
 
+// Re-export the Typestate derive macro (also available as core::poka_yoke::Typestate)
+pub use chicago_tdd_tools_proc_macros::Typestate;
+
+// Re-export the JTBD linkage attribute (shares its name with the `jtbd` module re-export;
+// attributes live in the macro namespace)
+#[cfg(feature = "jtbd-linkage")]
+pub use chicago_tdd_tools_proc_macros::jtbd;
+
 // Capability groups - organized by functionality
 //
 // **Kaizen improvement**: Module declaration pattern to prevent dead code.
@@ -192,6 +208,7 @@ pub mod macros;
 // Re-export new "go the extra mile" types
 pub use core::assertions::{AssertionBuilder, ValidatedAssertion};
 pub use core::builders::{GenericTestDataBuilder, ValidatedTestDataBuilder};
+pub use core::doctor::{doctor, Doctor, DoctorCheck, DoctorReport, DoctorStatus};
 pub use operator_registry::{
     global_registry, GuardType, OperatorDescriptor, OperatorProperties, OperatorRegistry,
 };
@@ -260,6 +277,7 @@ pub mod prelude {
     pub use crate::core::builders::*;
     pub use crate::core::fixture::*;
     pub use crate::core::governance::*;
+    pub use crate::core::matchers::*;
     pub use crate::core::state::*;
     // Re-export macros in prelude for use without manual root import
     pub use crate::{
@@ -292,7 +310,7 @@ pub mod prelude {
 
     #[cfg(feature = "otel")]
     pub use crate::observability::otel::{
-        MetricValidator, OtelValidationError, OtelValidationResult, SpanValidator,
+        MetricValidator, OtelValidationError, OtelValidationResult, SpanValidator, TraceAssertions,
     };
     // Note: otel::poka_yoke is NOT re-exported via glob to avoid conflicts with testcontainers::poka_yoke
 
@@ -0,0 +1,267 @@
+//! Span Expectation Blocks
+//!
+//! Declarative span expectations for [`ObservabilityTest`](super::ObservabilityTest):
+//! declare what the code under test should emit in Arrange, run it, then verify the
+//! captured spans in Assert. Verification checks exactly the declared expectations —
+//! every expectation must be met, and every captured span must be covered by one — and
+//! fails with a report listing missing and unexpected spans.
+//!
+//! ```rust
+//! use chicago_tdd_tools::observability::expectations::SpanExpectations;
+//!
+//! let expectations = SpanExpectations::new()
+//!     .named("http.request")
+//!     .named("db.query")
+//!     .with_attr("db.system")
+//!     .with_attr_value("db.operation", "SELECT");
+//! assert_eq!(expectations.len(), 2);
+//! ```
+
+use std::fmt::{self, Write as _};
+
+use crate::observability::otel::types::Span;
+
+/// One expected span: a name plus required attributes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpanExpectation {
+    name: String,
+    attributes: Vec<(String, Option<String>)>,
+    count: Option<usize>,
+}
+
+impl SpanExpectation {
+    /// Whether `span` satisfies this expectation
+    #[must_use]
+    pub fn matches(&self, span: &Span) -> bool {
+        span.name == self.name
+            && self
+                .attributes
+                .iter()
+                .all(|(key, value)| match (span.attributes.get(key), value) {
+                    (Some(actual), Some(expected)) => actual == expected,
+                    (Some(_), None) => true,
+                    (None, _) => false,
+                })
+    }
+
+    /// Attribute requirements `span` fails, rendered for the report
+    fn unmet_attributes(&self, span: &Span) -> Vec<String> {
+        self.attributes
+            .iter()
+            .filter_map(|(key, expected)| match (span.attributes.get(key), expected) {
+                (None, _) => Some(format!("{key} missing")),
+                (Some(actual), Some(expected)) if actual != expected => {
+                    Some(format!("{key}={actual:?}, expected {expected:?}"))
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for SpanExpectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if !self.attributes.is_empty() {
+            let attributes: Vec<String> = self
+                .attributes
+                .iter()
+                .map(|(key, value)| {
+                    value.as_ref().map_or_else(|| key.clone(), |value| format!("{key}={value:?}"))
+                })
+                .collect();
+            write!(f, " [{}]", attributes.join(", "))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, " ×{count}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Set of span expectations, built by chaining
+///
+/// [`named`](Self::named) starts a new expectation; `with_attr`, `with_attr_value`, and
+/// `times` refine the most recent one. Without `times`, at least one matching span
+/// is expected.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpanExpectations {
+    expectations: Vec<SpanExpectation>,
+}
+
+impl SpanExpectations {
+    /// Empty set of expectations
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { expectations: Vec::new() }
+    }
+
+    /// Expect a span named `name`
+    #[must_use]
+    pub fn named(mut self, name: impl Into<String>) -> Self {
+        self.expectations.push(SpanExpectation {
+            name: name.into(),
+            attributes: Vec::new(),
+            count: None,
+        });
+        self
+    }
+
+    /// Require the current span to carry attribute `key` (any value)
+    #[must_use]
+    pub fn with_attr(self, key: impl Into<String>) -> Self {
+        self.refine(|expectation| expectation.attributes.push((key.into(), None)))
+    }
+
+    /// Require the current span to carry attribute `key` with exactly `value`
+    #[must_use]
+    pub fn with_attr_value(self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.refine(|expectation| expectation.attributes.push((key.into(), Some(value.into()))))
+    }
+
+    /// Require exactly `count` spans matching the current expectation
+    #[must_use]
+    pub fn times(self, count: usize) -> Self {
+        self.refine(|expectation| expectation.count = Some(count))
+    }
+
+    fn refine(mut self, apply: impl FnOnce(&mut SpanExpectation)) -> Self {
+        // Refinements before any `named` have nothing to apply to; `verify` reports
+        // the empty set instead of silently passing.
+        if let Some(expectation) = self.expectations.last_mut() {
+            apply(expectation);
+        }
+        self
+    }
+
+    /// Declared expectations, in order
+    #[must_use]
+    pub fn expectations(&self) -> &[SpanExpectation] {
+        &self.expectations
+    }
+
+    /// Number of declared expectations
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.expectations.len()
+    }
+
+    /// Whether nothing has been declared
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.expectations.is_empty()
+    }
+
+    /// Add all expectations from `other`
+    pub(crate) fn extend(&mut self, other: Self) {
+        self.expectations.extend(other.expectations);
+    }
+
+    /// Check `spans` against exactly these expectations
+    ///
+    /// # Errors
+    ///
+    /// Returns the rendered report if an expectation is not met or a span is not
+    /// covered by any expectation.
+    pub fn check(&self, spans: &[Span]) -> Result<(), String> {
+        let mut missing = Vec::new();
+        for expectation in &self.expectations {
+            let matched = spans.iter().filter(|span| expectation.matches(span)).count();
+            let met = expectation.count.map_or(matched > 0, |count| matched == count);
+            if met {
+                continue;
+            }
+            let same_name: Vec<&Span> =
+                spans.iter().filter(|span| span.name == expectation.name).collect();
+            let detail = if same_name.is_empty() {
+                "no span with this name".to_string()
+            } else if matched > 0 {
+                format!("{matched} matching span(s)")
+            } else {
+                let unmet: Vec<String> =
+                    same_name.iter().flat_map(|span| expectation.unmet_attributes(span)).collect();
+                format!("{} span(s) with this name: {}", same_name.len(), unmet.join("; "))
+            };
+            missing.push(format!("{expectation} — {detail}"));
+        }
+
+        let unexpected: Vec<String> = spans
+            .iter()
+            .filter(|span| !self.expectations.iter().any(|e| e.name == span.name))
+            .map(|span| format!("{} (span {:016x})", span.name, span.context.span_id.0))
+            .collect();
+
+        if self.expectations.is_empty() {
+            missing.push("(no expectations declared)".to_string());
+        }
+        if missing.is_empty() && unexpected.is_empty() {
+            return Ok(());
+        }
+
+        let mut report = String::from("Span expectations not met");
+        for (label, entries) in [("missing", &missing), ("unexpected", &unexpected)] {
+            if !entries.is_empty() {
+                let _ = write!(report, "\n  {label}:");
+                for entry in entries {
+                    let _ = write!(report, "\n    - {entry}");
+                }
+            }
+        }
+        let _ = write!(report, "\n  captured: {} span(s)", spans.len());
+        Err(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observability::otel::types::{Attributes, SpanContext, SpanId, SpanStatus, TraceId};
+
+    fn span(id: u64, name: &str, attributes: &[(&str, &str)]) -> Span {
+        let attributes: Attributes =
+            attributes.iter().map(|(k, v)| ((*k).to_string(), (*v).to_string())).collect();
+        Span::new_active(
+            SpanContext::root(TraceId(1), SpanId(id), 1),
+            name.to_string(),
+            0,
+            attributes,
+            Vec::new(),
+            SpanStatus::Ok,
+        )
+    }
+
+    #[test]
+    fn test_check_passes_when_exactly_the_declared_spans_were_captured() {
+        let spans = [
+            span(1, "http.request", &[]),
+            span(2, "db.query", &[("db.system", "postgresql")]),
+            span(3, "db.query", &[("db.system", "postgresql")]),
+        ];
+
+        let expectations = SpanExpectations::new()
+            .named("http.request")
+            .named("db.query")
+            .with_attr_value("db.system", "postgresql")
+            .times(2);
+
+        assert_eq!(expectations.check(&spans), Ok(()));
+    }
+
+    #[test]
+    fn test_check_reports_missing_and_unexpected_spans() {
+        let spans = [span(0xab, "db.query", &[]), span(0xcd, "cache.get", &[])];
+
+        let report = SpanExpectations::new()
+            .named("db.query")
+            .with_attr("db.system")
+            .named("http.request")
+            .check(&spans)
+            .unwrap_err();
+
+        assert_eq!(
+            report,
+            "Span expectations not met\n  missing:\n    - db.query [db.system] — 1 span(s) with this name: db.system missing\n    - http.request — no span with this name\n  unexpected:\n    - cache.get (span 00000000000000cd)\n  captured: 2 span(s)"
+        );
+        assert!(SpanExpectations::new().check(&[]).is_err());
+    }
+}
@@ -19,6 +19,21 @@ pub fn assert_telemetry_valid(results: &ValidationResults) -> ObservabilityResul
     }
 }
 
+/// Ensure no custom Rego policy reported a violation.
+///
+/// Built-in registry advice is ignored; use [`assert_telemetry_valid`] to check both.
+///
+/// # Errors
+///
+/// Returns an error listing the policy violations, if any.
+pub fn assert_no_policy_violations(results: &ValidationResults) -> ObservabilityResult<()> {
+    if results.has_policy_violations() {
+        Err(ObservabilityError::ValidationFailed(results.violations_summary()))
+    } else {
+        Ok(())
+    }
+}
+
 /// Ensure the number of Weaver violations matches the expected value.
 ///
 /// # Errors
@@ -4,18 +4,30 @@
 //! Weaver live-check endpoint used by the [`super::WeaverTestFixture`].  The exported
 //! tracers automatically flush on drop to ensure telemetry reaches Weaver
 //! before validation runs.
+//!
+//! Span names, attributes, and event attributes are redacted with the configured
+//! [`RedactionRuleSet`] before export, so secrets recorded by instrumented code never
+//! leave the test process.
 
 #![cfg(all(feature = "weaver", feature = "otel"))]
 
+use std::borrow::Cow;
+use std::future::Future;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use opentelemetry::trace::TracerProvider as _;
+use opentelemetry::{KeyValue, Value};
 use opentelemetry_otlp::WithExportConfig;
 use opentelemetry_sdk::{
+    error::OTelSdkResult,
     resource::Resource,
-    trace::{self, SdkTracerProvider},
+    trace::{self, SdkTracerProvider, SpanData, SpanExporter},
 };
 
+use crate::core::redaction::RedactionRuleSet;
+
+use crate::observability::weaver::otlp_http_signal_endpoint;
 use crate::observability::{ObservabilityError, ObservabilityResult};
 
 /// Helper that provisions tracers which automatically export spans to Weaver.
@@ -51,7 +63,7 @@ impl TelemetryCapture {
     ) -> ObservabilityResult<TelemetryTracer> {
         let exporter = opentelemetry_otlp::SpanExporter::builder()
             .with_http()
-            .with_endpoint(self.endpoint.clone())
+            .with_endpoint(otlp_http_signal_endpoint(&self.endpoint, "/v1/traces"))
             .build()
             .map_err(|err| {
                 ObservabilityError::ValidationFailed(format!(
@@ -61,6 +73,8 @@ impl TelemetryCapture {
 
         let resource = Resource::builder().with_service_name(service_name.to_string()).build();
 
+        let exporter =
+            RedactingSpanExporter { inner: exporter, rules: RedactionRuleSet::configured() };
         let provider = SdkTracerProvider::builder()
             .with_simple_exporter(exporter)
             .with_resource(resource)
@@ -148,3 +162,80 @@ impl TelemetryTracer {
         self.inner.force_flush()
     }
 }
+
+/// Span exporter that applies redaction rules before forwarding spans
+#[derive(Debug)]
+struct RedactingSpanExporter<E> {
+    inner: E,
+    rules: &'static RedactionRuleSet,
+}
+
+impl<E: SpanExporter> SpanExporter for RedactingSpanExporter<E> {
+    fn export(&self, mut batch: Vec<SpanData>) -> impl Future<Output = OTelSdkResult> + Send {
+        if !self.rules.is_empty() {
+            for span in &mut batch {
+                if let Cow::Owned(name) = self.rules.redact_text(&span.name) {
+                    span.name = Cow::Owned(name);
+                }
+                redact_attributes(self.rules, &mut span.attributes);
+                for event in &mut span.events.events {
+                    redact_attributes(self.rules, &mut event.attributes);
+                }
+            }
+        }
+        self.inner.export(batch)
+    }
+
+    fn shutdown_with_timeout(&mut self, timeout: Duration) -> OTelSdkResult {
+        self.inner.shutdown_with_timeout(timeout)
+    }
+
+    fn force_flush(&mut self) -> OTelSdkResult {
+        self.inner.force_flush()
+    }
+
+    fn set_resource(&mut self, resource: &Resource) {
+        self.inner.set_resource(resource);
+    }
+}
+
+/// Redact attribute values in place: any value under a field-rule key, and string values
+/// matching a pattern rule
+fn redact_attributes(rules: &RedactionRuleSet, attributes: &mut [KeyValue]) {
+    for attribute in attributes {
+        let redacted = match &attribute.value {
+            Value::String(value) => rules.redact_attribute(attribute.key.as_str(), value.as_str()),
+            _ => rules.field_replacement(attribute.key.as_str()).map(str::to_string),
+        };
+        if let Some(redacted) = redacted {
+            attribute.value = Value::from(redacted);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_redact_attributes_by_key_and_pattern, {
+        // Arrange
+        let rules = RedactionRuleSet::new()
+            .field("db.password", "[PASSWORD]")
+            .pattern(r"\d{16}", "[CARD]")
+            .unwrap();
+        let mut attributes = vec![
+            KeyValue::new("db.password", 1234_i64),
+            KeyValue::new("payment.note", "charged 4111111111111111"),
+            KeyValue::new("http.route", "/orders"),
+        ];
+
+        // Act
+        redact_attributes(&rules, &mut attributes);
+
+        // Assert
+        assert_eq!(attributes[0].value, Value::from("[PASSWORD]"));
+        assert_eq!(attributes[1].value, Value::from("charged [CARD]"));
+        assert_eq!(attributes[2].value, Value::from("/orders"));
+    });
+}
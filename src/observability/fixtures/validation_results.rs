@@ -3,6 +3,10 @@
 //! Weaver emits newline separated JSON objects containing advice and summary
 //! statistics.  This module converts that output into type-safe Rust structures
 //! that can be consumed by Chicago TDD assertions.
+//!
+//! Advice produced by custom Rego policies (passed with `with_policies` /
+//! `TestConfig::weaver_policies_dir`) is reported as [`AdviceSource::Policy`], so
+//! organization-specific conventions can be asserted separately from the registry.
 
 #![cfg(all(feature = "weaver", feature = "otel"))]
 
@@ -91,6 +95,20 @@ impl ValidationResults {
         self.advices.iter().any(|advice| matches!(advice.level, AdviceLevel::Violation))
     }
 
+    /// Whether any `violation` level advice came from a custom Rego policy.
+    #[must_use]
+    pub fn has_policy_violations(&self) -> bool {
+        self.policy_violations().next().is_some()
+    }
+
+    /// Iterator over `violation` level advice emitted by custom Rego policies.
+    pub fn policy_violations(&self) -> impl Iterator<Item = &LiveCheckAdvice> {
+        self.advices.iter().filter(|advice| {
+            matches!(advice.level, AdviceLevel::Violation)
+                && advice.source() == AdviceSource::Policy
+        })
+    }
+
     /// Iterator over all advice records.
     pub fn advices(&self) -> impl Iterator<Item = &LiveCheckAdvice> {
         self.advices.iter()
@@ -106,8 +124,12 @@ impl ValidationResults {
         let mut lines = vec!["Weaver live-check detected violations:".to_string()];
 
         for advice in self.advices.iter().filter(|a| matches!(a.level, AdviceLevel::Violation)) {
+            let source = match advice.source() {
+                AdviceSource::Builtin => "",
+                AdviceSource::Policy => " (policy)",
+            };
             lines.push(format!(
-                "- [{}] {} :: {}",
+                "- [{}] {}{source} :: {}",
                 advice.signal_descriptor(),
                 advice.advice_type,
                 advice.message
@@ -183,6 +205,20 @@ pub struct LiveCheckAdvice {
 }
 
 impl LiveCheckAdvice {
+    /// Whether the advice came from Weaver itself or from a custom Rego policy.
+    ///
+    /// Attribution is by advice type: anything outside [`BUILTIN_ADVICE_TYPES`] is
+    /// treated as policy advice.
+    #[must_use]
+    pub fn source(&self) -> AdviceSource {
+        if self.advice_type.is_empty() || BUILTIN_ADVICE_TYPES.contains(&self.advice_type.as_str())
+        {
+            AdviceSource::Builtin
+        } else {
+            AdviceSource::Policy
+        }
+    }
+
     fn signal_descriptor(&self) -> String {
         match (self.signal_type.as_deref(), self.signal_name.as_deref()) {
             (Some(signal_type), Some(signal_name)) => format!("{signal_type}:{signal_name}"),
@@ -193,6 +229,32 @@ impl LiveCheckAdvice {
     }
 }
 
+/// Advice types emitted by Weaver's built-in advisors and default live-check policies.
+pub const BUILTIN_ADVICE_TYPES: &[&str] = &[
+    "deprecated",
+    "extends_namespace",
+    "illegal_namespace",
+    "invalid_format",
+    "missing_attribute",
+    "missing_event",
+    "missing_metric",
+    "missing_namespace",
+    "stability",
+    "template_attribute",
+    "type_mismatch",
+    "undefined_enum_variant",
+    "unit_mismatch",
+];
+
+/// Origin of a live-check advice record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdviceSource {
+    /// Weaver's built-in registry checks.
+    Builtin,
+    /// A custom Rego advice policy.
+    Policy,
+}
+
 /// Aggregated statistics emitted once live-check completes.
 #[derive(Debug, Clone, Deserialize)]
 pub struct LiveCheckStatistics {
@@ -248,3 +310,34 @@ impl Display for AdviceLevel {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_advice_is_surfaced_separately() {
+        let dir = tempfile::tempdir().unwrap();
+        let report = [
+            r#"{"live_check_result":{"all_advice":[{"advice_level":"violation","advice_type":"missing_attribute","message":"http.route missing","signal_type":"span","signal_name":"GET /"}]}}"#,
+            r#"{"live_check_result":{"all_advice":[{"advice_level":"violation","advice_type":"acme_team_attribute","message":"acme.team is required","signal_type":"span","signal_name":"GET /"},{"advice_level":"improvement","advice_type":"acme_naming","message":"prefer snake_case"}]}}"#,
+        ]
+        .join("\n");
+        std::fs::write(dir.path().join("live_check.json"), report).unwrap();
+
+        let results = ValidationResults::from_report_dir(dir.path()).unwrap();
+
+        assert!(results.has_policy_violations());
+        let policy: Vec<_> = results.policy_violations().map(|a| a.advice_type.as_str()).collect();
+        assert_eq!(policy, ["acme_team_attribute"]);
+        let summary = results.violations_summary();
+        assert!(
+            summary.contains("[span:GET /] missing_attribute :: http.route missing"),
+            "{summary}"
+        );
+        assert!(
+            summary.contains("acme_team_attribute (policy) :: acme.team is required"),
+            "{summary}"
+        );
+    }
+}
@@ -22,15 +22,20 @@ pub struct WeaverTestFixture {
 impl WeaverTestFixture {
     /// Create a new Weaver test fixture with zero configuration.
     ///
+    /// Weaver listens on free ports allocated for this fixture, so fixtures in
+    /// parallel tests do not collide.
+    ///
     /// # Errors
     ///
     /// Returns an error if Weaver cannot be started or configured.
     pub fn new() -> ObservabilityResult<Self> {
-        Self::with_config(TestConfig::default())
+        Self::with_config(TestConfig { otlp_grpc_port: 0, admin_port: 0, ..TestConfig::default() })
     }
 
     /// Create a new Weaver test fixture with custom configuration.
     ///
+    /// Set `otlp_grpc_port` / `admin_port` to `0` to allocate free ports.
+    ///
     /// # Errors
     ///
     /// Returns an error if Weaver cannot be started or configured.
@@ -26,6 +26,7 @@
 //!
 //! **Required Features**:
 //! - `otel`: Enable OTEL span/metric validation (`chicago-tdd-tools = { features = ["otel"] }`)
+//! - `otlp-receiver`: Embedded OTLP endpoint capturing exported telemetry in-process
 //! - `weaver`: Enable Weaver live validation (`chicago-tdd-tools = { features = ["weaver"] }`)
 
 // Unified API (new implementation)
@@ -43,10 +44,21 @@ pub use unified::{ObservabilityError, ObservabilityResult, ObservabilityTest, Te
 #[cfg(feature = "otel")]
 pub mod otel;
 
+/// Declarative span expectations for `ObservabilityTest::expect_spans`
+#[cfg(feature = "otel")]
+pub mod expectations;
+
 // Poka-yoke types are re-exported through otel::poka_yoke module
 #[cfg(feature = "weaver")]
 pub mod weaver;
 
+/// Embedded OTLP test receiver (no Weaver, no Docker)
+#[cfg(feature = "otlp-receiver")]
+pub mod receiver;
+
+#[cfg(feature = "otlp-receiver")]
+pub use receiver::OtlpTestReceiver;
+
 /// OCEL 2.0 (Object-Centric Event Log) Support
 pub mod ocel;
 
@@ -0,0 +1,421 @@
+//! In-Process Span Collector
+//!
+//! [`InMemorySpanCollector`] is a real OpenTelemetry SDK [`SpanExporter`]. Install it in a
+//! tracer provider, run the code under test, then read the finished spans back as crate
+//! [`Span`]s and hand them to [`SpanValidator`] or [`TraceAssertions`](super::TraceAssertions)
+//! — no hand-built span structs.
+//!
+//! The provider from [`InMemorySpanCollector::tracer_provider`] exports synchronously, so
+//! spans are available as soon as they end.
+//!
+//! [`InMemoryMetricCollector`] does the same for metrics: each
+//! [`snapshot`](InMemoryMetricCollector::snapshot) flushes the meter provider and returns a
+//! [`MetricSnapshot`] for the temporal assertions on [`MetricValidator`](super::MetricValidator).
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::otel::collector::InMemorySpanCollector;
+//! use chicago_tdd_tools::otel::TraceAssertions;
+//! use opentelemetry::trace::{Tracer, TracerProvider};
+//!
+//! let collector = InMemorySpanCollector::new();
+//! let tracer = collector.tracer_provider().tracer("orders");
+//!
+//! tracer.in_span("handle_request", |_| {
+//!     tracer.in_span("db.query", |_| {});
+//! });
+//!
+//! let spans = collector.spans();
+//! TraceAssertions::new(&spans).assert_child_of("db.query", "handle_request");
+//! ```
+
+use std::future::Future;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use opentelemetry::metrics::{Meter, MeterProvider as _};
+use opentelemetry::trace::{Event, Status};
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::error::OTelSdkResult;
+use opentelemetry_sdk::metrics::data::{AggregatedMetrics, Histogram, MetricData, ResourceMetrics};
+use opentelemetry_sdk::metrics::exporter::PushMetricExporter;
+use opentelemetry_sdk::metrics::{SdkMeterProvider, Temporality};
+use opentelemetry_sdk::trace::{SdkTracerProvider, SpanData, SpanExporter};
+
+use super::{MetricSnapshot, OtelValidationError, OtelValidationResult, SpanValidator};
+use crate::observability::otel::types::{
+    Attributes, Metric, MetricValue, Span, SpanContext, SpanEvent, SpanId, SpanState, SpanStatus,
+    TraceId,
+};
+
+/// Span exporter that keeps finished spans in memory for assertions
+///
+/// Clones share the same storage, so the clone installed in a provider and the one kept by
+/// the test see the same spans.
+#[derive(Debug, Clone, Default)]
+pub struct InMemorySpanCollector {
+    spans: Arc<Mutex<Vec<SpanData>>>,
+}
+
+impl InMemorySpanCollector {
+    /// Create an empty collector
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Tracer provider that exports every finished span to this collector synchronously
+    #[must_use]
+    pub fn tracer_provider(&self) -> SdkTracerProvider {
+        SdkTracerProvider::builder().with_simple_exporter(self.clone()).build()
+    }
+
+    // A panicking test must not hide the spans from later tests sharing the collector
+    fn lock(&self) -> MutexGuard<'_, Vec<SpanData>> {
+        self.spans.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Collected spans converted to crate [`Span`]s, in export (end) order
+    #[must_use]
+    pub fn spans(&self) -> Vec<Span> {
+        self.lock().iter().map(span_from_sdk).collect()
+    }
+
+    /// Raw SDK span data, in export (end) order
+    #[must_use]
+    pub fn span_data(&self) -> Vec<SpanData> {
+        self.lock().clone()
+    }
+
+    /// Number of collected spans
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Whether no spans have been collected
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Drop all collected spans
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Validate every collected span
+    ///
+    /// # Errors
+    ///
+    /// Returns the first validation error from `validator`.
+    pub fn validate(&self, validator: &SpanValidator) -> OtelValidationResult<()> {
+        validator.validate_spans(&self.spans())
+    }
+}
+
+impl SpanExporter for InMemorySpanCollector {
+    fn export(&self, batch: Vec<SpanData>) -> impl Future<Output = OTelSdkResult> + Send {
+        self.lock().extend(batch);
+        std::future::ready(Ok(()))
+    }
+}
+
+/// Convert SDK span data to a crate [`Span`]
+#[must_use]
+pub fn span_from_sdk(data: &SpanData) -> Span {
+    let trace_id = TraceId(u128::from_be_bytes(data.span_context.trace_id().to_bytes()));
+    let span_id = SpanId(u64::from_be_bytes(data.span_context.span_id().to_bytes()));
+    let flags = data.span_context.trace_flags().to_u8();
+    let context = if data.parent_span_id == opentelemetry::trace::SpanId::INVALID {
+        SpanContext::root(trace_id, span_id, flags)
+    } else {
+        let parent = SpanId(u64::from_be_bytes(data.parent_span_id.to_bytes()));
+        SpanContext::child(trace_id, span_id, parent, flags)
+    };
+    let start_time_ms = millis_since_epoch(data.start_time);
+    // The SDK takes end times from the wall clock, which may step backwards
+    let end_time_ms = millis_since_epoch(data.end_time).max(start_time_ms);
+    Span {
+        context,
+        name: data.name.to_string(),
+        state: SpanState::Completed { start_time_ms, end_time_ms },
+        attributes: attributes_from_sdk(&data.attributes),
+        events: data.events.iter().map(event_from_sdk).collect(),
+        status: match data.status {
+            Status::Ok => SpanStatus::Ok,
+            Status::Error { .. } => SpanStatus::Error,
+            Status::Unset => SpanStatus::Unset,
+        },
+    }
+}
+
+fn event_from_sdk(event: &Event) -> SpanEvent {
+    SpanEvent {
+        name: event.name.to_string(),
+        timestamp_ms: millis_since_epoch(event.timestamp),
+        attributes: attributes_from_sdk(&event.attributes),
+    }
+}
+
+fn attributes_from_sdk(attributes: &[KeyValue]) -> Attributes {
+    attributes_from_iter(attributes.iter())
+}
+
+/// Meter provider fixture that captures metric snapshots in memory
+///
+/// Uses cumulative temporality, so each snapshot holds running totals; diff two snapshots
+/// with [`MetricSnapshot::since`] or pass both to [`MetricValidator`](super::MetricValidator).
+#[derive(Debug, Clone)]
+pub struct InMemoryMetricCollector {
+    latest: Arc<Mutex<Vec<Metric>>>,
+    provider: SdkMeterProvider,
+}
+
+impl Default for InMemoryMetricCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InMemoryMetricCollector {
+    /// Create a collector with its own meter provider
+    #[must_use]
+    pub fn new() -> Self {
+        let latest = Arc::new(Mutex::new(Vec::new()));
+        let provider = SdkMeterProvider::builder()
+            .with_periodic_exporter(MetricSink { latest: Arc::clone(&latest) })
+            .build();
+        Self { latest, provider }
+    }
+
+    /// Meter provider whose instruments this collector snapshots
+    #[must_use]
+    pub const fn meter_provider(&self) -> &SdkMeterProvider {
+        &self.provider
+    }
+
+    /// Meter for the code under test
+    #[must_use]
+    pub fn meter(&self, name: &'static str) -> Meter {
+        self.provider.meter(name)
+    }
+
+    /// Flush the meter provider and capture every instrument's current value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OtelValidationError::MetricValidationFailed`] if the flush fails.
+    pub fn snapshot(&self) -> OtelValidationResult<MetricSnapshot> {
+        self.provider.force_flush().map_err(|err| {
+            OtelValidationError::MetricValidationFailed(format!("Failed to flush metrics: {err}"))
+        })?;
+        let metrics = self.latest.lock().unwrap_or_else(PoisonError::into_inner).clone();
+        Ok(MetricSnapshot::new(metrics))
+    }
+}
+
+/// Push exporter behind [`InMemoryMetricCollector`]; keeps the latest export
+#[derive(Debug)]
+struct MetricSink {
+    latest: Arc<Mutex<Vec<Metric>>>,
+}
+
+impl PushMetricExporter for MetricSink {
+    fn export(&self, metrics: &ResourceMetrics) -> impl Future<Output = OTelSdkResult> + Send {
+        let converted = metrics
+            .scope_metrics()
+            .flat_map(opentelemetry_sdk::metrics::data::ScopeMetrics::metrics)
+            .flat_map(|metric| {
+                let timestamp_ms = millis_since_epoch(SystemTime::now());
+                let points = match metric.data() {
+                    AggregatedMetrics::F64(data) => metric_points(data),
+                    AggregatedMetrics::U64(data) => metric_points(data),
+                    AggregatedMetrics::I64(data) => metric_points(data),
+                };
+                points.into_iter().map(move |(value, attributes)| Metric {
+                    name: metric.name().to_string(),
+                    value,
+                    timestamp_ms,
+                    attributes,
+                })
+            })
+            .collect();
+        *self.latest.lock().unwrap_or_else(PoisonError::into_inner) = converted;
+        std::future::ready(Ok(()))
+    }
+
+    fn force_flush(&self) -> OTelSdkResult {
+        Ok(())
+    }
+
+    fn shutdown_with_timeout(&self, _timeout: Duration) -> OTelSdkResult {
+        Ok(())
+    }
+
+    fn temporality(&self) -> Temporality {
+        Temporality::Cumulative
+    }
+}
+
+/// SDK data point value widened to `f64`
+trait PointValue: Copy {
+    fn to_f64(self) -> f64;
+}
+
+impl PointValue for f64 {
+    fn to_f64(self) -> f64 {
+        self
+    }
+}
+
+impl PointValue for u64 {
+    #[allow(clippy::cast_precision_loss)] // Metric values beyond 2^53 are not asserted on exactly
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+impl PointValue for i64 {
+    #[allow(clippy::cast_precision_loss)] // Metric values beyond 2^53 are not asserted on exactly
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+}
+
+/// Convert one instrument's data points; monotonic sums become counters, non-monotonic
+/// sums (up/down counters) and gauges become gauges
+///
+/// Exponential histograms have no explicit bounds and are skipped.
+fn metric_points<T: PointValue>(data: &MetricData<T>) -> Vec<(MetricValue, Attributes)> {
+    match data {
+        MetricData::Gauge(gauge) => gauge
+            .data_points()
+            .map(|point| {
+                (
+                    MetricValue::Gauge(point.value().to_f64()),
+                    attributes_from_iter(point.attributes()),
+                )
+            })
+            .collect(),
+        MetricData::Sum(sum) => sum
+            .data_points()
+            .map(|point| {
+                let value = point.value().to_f64();
+                let value = if sum.is_monotonic() {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    // Monotonic sums are non-negative; fractional counts round to the nearest unit
+                    MetricValue::Counter(value.round() as u64)
+                } else {
+                    MetricValue::Gauge(value)
+                };
+                (value, attributes_from_iter(point.attributes()))
+            })
+            .collect(),
+        MetricData::Histogram(histogram) => histogram_points(histogram),
+        MetricData::ExponentialHistogram(_) => Vec::new(),
+    }
+}
+
+fn histogram_points<T>(histogram: &Histogram<T>) -> Vec<(MetricValue, Attributes)> {
+    histogram
+        .data_points()
+        .map(|point| {
+            let value = MetricValue::BucketedHistogram {
+                bounds: point.bounds().collect(),
+                bucket_counts: point.bucket_counts().collect(),
+            };
+            (value, attributes_from_iter(point.attributes()))
+        })
+        .collect()
+}
+
+fn attributes_from_iter<'a>(attributes: impl Iterator<Item = &'a KeyValue>) -> Attributes {
+    attributes.map(|kv| (kv.key.to_string(), kv.value.to_string())).collect()
+}
+
+fn millis_since_epoch(time: SystemTime) -> u64 {
+    let millis = time.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+    u64::try_from(millis).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observability::otel::{MetricValidator, TraceAssertions};
+    use opentelemetry::trace::{Span as _, TraceContextExt, Tracer, TracerProvider};
+
+    #[test]
+    fn test_collects_nested_spans_from_sdk_tracer() {
+        let collector = InMemorySpanCollector::new();
+        let tracer = collector.tracer_provider().tracer("test");
+
+        tracer.in_span("handle_request", |cx| {
+            cx.span().set_attribute(KeyValue::new("http.route", "/orders"));
+            tracer.in_span("db.query", |cx| {
+                cx.span().add_event("rows", vec![KeyValue::new("count", 3_i64)]);
+                cx.span().set_status(Status::error("timeout"));
+            });
+        });
+
+        let spans = collector.spans();
+        assert_eq!(spans.len(), 2);
+        let (query, request) = (&spans[0], &spans[1]);
+        assert_eq!(query.name, "db.query");
+        assert_eq!(query.context.trace_id, request.context.trace_id);
+        assert_eq!(query.context.parent_span_id(), Some(request.context.span_id));
+        assert_eq!(query.status, SpanStatus::Error);
+        assert_eq!(query.events[0].attributes.get("count").map(String::as_str), Some("3"));
+        assert_eq!(request.attributes.get("http.route").map(String::as_str), Some("/orders"));
+        assert!(request.context.is_root());
+        assert!(collector.validate(&SpanValidator::new()).is_ok());
+        TraceAssertions::new(&spans)
+            .assert_child_of("db.query", "handle_request")
+            .assert_span_order(&["handle_request", "db.query"])
+            .assert_no_orphans();
+    }
+
+    #[test]
+    fn test_clear_drops_collected_spans() {
+        let collector = InMemorySpanCollector::new();
+        let provider = collector.tracer_provider();
+        provider.tracer("test").start("work").end();
+        assert_eq!(collector.len(), 1);
+
+        collector.clear();
+
+        assert!(collector.is_empty());
+        assert!(collector.span_data().is_empty());
+    }
+
+    #[test]
+    fn test_metric_snapshots_capture_sdk_instruments() {
+        let collector = InMemoryMetricCollector::new();
+        let meter = collector.meter("test");
+        let requests = meter.u64_counter("http.requests").build();
+        let in_flight = meter.i64_up_down_counter("http.in_flight").build();
+        let latency = meter
+            .f64_histogram("http.latency")
+            .with_boundaries(vec![5.0, 25.0, 100.0])
+            .build();
+        requests.add(2, &[]);
+        let before = collector.snapshot().unwrap();
+
+        requests.add(3, &[KeyValue::new("route", "/orders")]);
+        in_flight.add(4, &[]);
+        for ms in [1.0, 12.0, 20.0, 22.0] {
+            latency.record(ms, &[]);
+        }
+        let after = collector.snapshot().unwrap();
+
+        let validator = MetricValidator::new();
+        validator.assert_counter_increased_by(&before, &after, "http.requests", 3);
+        validator.assert_gauge_in_range(&after, "http.in_flight", 4.0..=4.0);
+        validator.assert_p95_bucket_below(&after.since(&before), "http.latency", 25.0);
+        assert_eq!(
+            after.histogram("http.latency"),
+            Some((vec![5.0, 25.0, 100.0], vec![1, 3, 0, 0]))
+        );
+    }
+}
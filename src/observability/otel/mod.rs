@@ -5,6 +5,8 @@
 
 #[cfg(feature = "otel")]
 use crate::observability::otel::types::{Metric, Span, SpanId};
+#[cfg(feature = "otel")]
+use std::ops::RangeInclusive;
 use thiserror::Error;
 
 pub mod types;
@@ -15,6 +17,41 @@ pub mod types;
 /// See module documentation for examples.
 pub mod poka_yoke;
 
+/// Trace tree assertions (parent/child links, ordering, depth, orphans)
+#[cfg(feature = "otel")]
+pub mod trace;
+
+#[cfg(feature = "otel")]
+pub use trace::TraceAssertions;
+
+/// Metric snapshots for before/after (temporal) assertions
+#[cfg(feature = "otel")]
+pub mod snapshot;
+
+#[cfg(feature = "otel")]
+pub use snapshot::MetricSnapshot;
+
+/// Test runs exported as OTLP traces, with a span per AAA phase
+#[cfg(feature = "otel")]
+pub mod test_trace;
+
+#[cfg(feature = "otel")]
+pub use test_trace::{TestTrace, TestTraceRecorder};
+
+/// In-process span collector for the OpenTelemetry SDK
+#[cfg(feature = "otel-sdk")]
+pub mod collector;
+
+#[cfg(feature = "otel-sdk")]
+pub use collector::{InMemoryMetricCollector, InMemorySpanCollector};
+
+/// OTLP protobuf and OTLP/JSON payload decoding
+#[cfg(feature = "otlp")]
+pub mod otlp;
+
+#[cfg(feature = "otlp")]
+pub use otlp::OtlpEncoding;
+
 /// OTEL validation error
 #[derive(Error, Debug)]
 pub enum OtelValidationError {
@@ -39,6 +76,12 @@ pub enum OtelValidationError {
     /// Invalid span ID
     #[error("🚨 Invalid span ID: {0}\n   ⚠️  STOP: Span ID is invalid\n   💡 FIX: Use valid 64-bit span ID (cannot be zero)")]
     InvalidSpanId(String),
+    /// Trace tree assertion failed
+    #[error("🚨 Trace assertion failed: {0}\n   ⚠️  STOP: Span tree does not have the expected shape\n   💡 FIX: Check span parent propagation and ordering in the traced code")]
+    TraceAssertionFailed(String),
+    /// OTLP payload could not be decoded
+    #[error("🚨 OTLP decode failed: {0}\n   ⚠️  STOP: Payload is not a valid OTLP export request\n   💡 FIX: Check the payload encoding (protobuf vs JSON) and the exporter output")]
+    OtlpDecodeFailed(String),
 }
 
 /// Result type for OTEL validation
@@ -142,6 +185,25 @@ impl SpanValidator {
         }
         Ok(())
     }
+
+    /// Decode an OTLP trace export payload and validate every span
+    ///
+    /// Returns the decoded spans for further assertions (e.g. [`TraceAssertions`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OtelValidationError::OtlpDecodeFailed`] if the payload cannot be decoded, or
+    /// the first span validation error.
+    #[cfg(feature = "otlp")]
+    pub fn validate_otlp(
+        &self,
+        payload: &[u8],
+        encoding: OtlpEncoding,
+    ) -> OtelValidationResult<Vec<Span>> {
+        let spans = otlp::decode_spans(payload, encoding)?;
+        self.validate_spans(&spans)?;
+        Ok(spans)
+    }
 }
 
 /// OTEL metric validator
@@ -217,6 +279,26 @@ impl MetricValidator {
                     )));
                 }
             }
+            crate::observability::otel::types::MetricValue::BucketedHistogram {
+                bounds,
+                bucket_counts,
+            } => {
+                if bucket_counts.len() != bounds.len() + 1 {
+                    return Err(OtelValidationError::MetricValidationFailed(format!(
+                        "Metric '{}' has {} bucket counts for {} bounds (expected {})",
+                        metric.name,
+                        bucket_counts.len(),
+                        bounds.len(),
+                        bounds.len() + 1
+                    )));
+                }
+                if bounds.windows(2).any(|pair| pair[0] >= pair[1]) {
+                    return Err(OtelValidationError::MetricValidationFailed(format!(
+                        "Metric '{}' has histogram bounds that are not strictly increasing",
+                        metric.name
+                    )));
+                }
+            }
         }
 
         Ok(())
@@ -238,6 +320,166 @@ impl MetricValidator {
         }
         Ok(())
     }
+
+    /// Decode an OTLP metrics export payload and validate every data point
+    ///
+    /// Returns the decoded metrics; wrap them in a [`MetricSnapshot`] for the temporal
+    /// assertions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OtelValidationError::OtlpDecodeFailed`] if the payload cannot be decoded, or
+    /// the first metric validation error.
+    #[cfg(feature = "otlp")]
+    pub fn validate_otlp(
+        &self,
+        payload: &[u8],
+        encoding: OtlpEncoding,
+    ) -> OtelValidationResult<Vec<Metric>> {
+        let metrics = otlp::decode_metrics(payload, encoding)?;
+        self.validate_metrics(&metrics)?;
+        Ok(metrics)
+    }
+
+    /// Validate the data points of `name` in a snapshot, failing if there are none
+    fn validate_points(&self, snapshot: &MetricSnapshot, name: &str) -> OtelValidationResult<()> {
+        if !snapshot.contains(name) {
+            return Err(OtelValidationError::MetricValidationFailed(format!(
+                "Metric '{name}' not found in snapshot"
+            )));
+        }
+        snapshot.points(name).try_for_each(|metric| self.validate(metric))
+    }
+
+    /// Check that counter `name` increased by exactly `n` between two snapshots
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the counter is missing from `after`, fails validation, or
+    /// increased by a different amount.
+    pub fn check_counter_increased_by(
+        &self,
+        before: &MetricSnapshot,
+        after: &MetricSnapshot,
+        name: &str,
+        n: u64,
+    ) -> OtelValidationResult<()> {
+        self.validate_points(after, name)?;
+        let increase = after.since(before).counter(name).ok_or_else(|| {
+            OtelValidationError::MetricValidationFailed(format!("Metric '{name}' is not a counter"))
+        })?;
+        if increase != n {
+            return Err(OtelValidationError::MetricValidationFailed(format!(
+                "Counter '{name}' increased by {increase}, expected {n}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check that every data point of gauge `name` lies within `range`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the gauge is missing, fails validation, or a value is out of range.
+    pub fn check_gauge_in_range(
+        &self,
+        snapshot: &MetricSnapshot,
+        name: &str,
+        range: RangeInclusive<f64>,
+    ) -> OtelValidationResult<()> {
+        self.validate_points(snapshot, name)?;
+        let values = snapshot.gauges(name);
+        if values.is_empty() {
+            return Err(OtelValidationError::MetricValidationFailed(format!(
+                "Metric '{name}' is not a gauge"
+            )));
+        }
+        if let Some(value) = values.iter().find(|value| !range.contains(value)) {
+            return Err(OtelValidationError::MetricValidationFailed(format!(
+                "Gauge '{name}' is {value}, expected {}..={}",
+                range.start(),
+                range.end()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Check that the bucket holding the 95th percentile of histogram `name` has an upper
+    /// bound at or below `bound`
+    ///
+    /// Pass a [`MetricSnapshot::since`] delta to check only the observations recorded by the
+    /// code under test.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the histogram is missing, has no observations, fails validation,
+    /// or its p95 bucket bound exceeds `bound`.
+    pub fn check_p95_bucket_below(
+        &self,
+        snapshot: &MetricSnapshot,
+        name: &str,
+        bound: f64,
+    ) -> OtelValidationResult<()> {
+        self.validate_points(snapshot, name)?;
+        let p95 = snapshot.histogram_percentile_bound(name, 95).ok_or_else(|| {
+            OtelValidationError::MetricValidationFailed(format!(
+                "Histogram '{name}' has no observations (or inconsistent bucket bounds)"
+            ))
+        })?;
+        if p95 > bound {
+            return Err(OtelValidationError::MetricValidationFailed(format!(
+                "Histogram '{name}' p95 falls in the bucket bounded by {p95}, expected <= {bound}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Assert [`Self::check_counter_increased_by`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the check fails.
+    pub fn assert_counter_increased_by(
+        &self,
+        before: &MetricSnapshot,
+        after: &MetricSnapshot,
+        name: &str,
+        n: u64,
+    ) {
+        #[allow(clippy::panic)] // Test helper - panic is appropriate
+        if let Err(e) = self.check_counter_increased_by(before, after, name, n) {
+            panic!("{e}");
+        }
+    }
+
+    /// Assert [`Self::check_gauge_in_range`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the check fails.
+    pub fn assert_gauge_in_range(
+        &self,
+        snapshot: &MetricSnapshot,
+        name: &str,
+        range: RangeInclusive<f64>,
+    ) {
+        #[allow(clippy::panic)] // Test helper - panic is appropriate
+        if let Err(e) = self.check_gauge_in_range(snapshot, name, range) {
+            panic!("{e}");
+        }
+    }
+
+    /// Assert [`Self::check_p95_bucket_below`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the check fails.
+    pub fn assert_p95_bucket_below(&self, snapshot: &MetricSnapshot, name: &str, bound: f64) {
+        #[allow(clippy::panic)] // Test helper - panic is appropriate
+        if let Err(e) = self.check_p95_bucket_below(snapshot, name, bound) {
+            panic!("{e}");
+        }
+    }
 }
 
 /// OTEL validation helper for test utilities
@@ -472,6 +714,8 @@ mod tests {
             OtelValidationError::InvalidSpanStatus("test".to_string()),
             OtelValidationError::InvalidTraceId("test".to_string()),
             OtelValidationError::InvalidSpanId("test".to_string()),
+            OtelValidationError::TraceAssertionFailed("test".to_string()),
+            OtelValidationError::OtlpDecodeFailed("test".to_string()),
         ];
 
         for error in errors {
@@ -577,4 +821,65 @@ mod tests {
 
         assert!(validator.validate(&metric).is_err());
     }
+
+    #[cfg(feature = "otel")]
+    fn request_metrics(requests: u64, depth: f64, latency: Vec<u64>) -> MetricSnapshot {
+        use crate::observability::otel::types::MetricValue;
+
+        let metric = |name: &str, value| Metric {
+            name: name.to_string(),
+            value,
+            timestamp_ms: 1000,
+            attributes: Default::default(),
+        };
+        MetricSnapshot::new(vec![
+            metric("http.requests", MetricValue::Counter(requests)),
+            metric("queue.depth", MetricValue::Gauge(depth)),
+            metric(
+                "http.latency",
+                MetricValue::BucketedHistogram {
+                    bounds: vec![5.0, 25.0, 100.0],
+                    bucket_counts: latency,
+                },
+            ),
+        ])
+    }
+
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_metric_validator_temporal_assertions() {
+        let validator = MetricValidator::new();
+        let before = request_metrics(10, 3.0, vec![5, 0, 0, 0]);
+        let after = request_metrics(30, 7.0, vec![23, 18, 1, 0]);
+        let delta = after.since(&before);
+
+        validator.assert_counter_increased_by(&before, &after, "http.requests", 20);
+        validator.assert_gauge_in_range(&after, "queue.depth", 0.0..=10.0);
+        validator.assert_p95_bucket_below(&delta, "http.latency", 25.0);
+
+        let increase = validator.check_counter_increased_by(&before, &after, "http.requests", 5);
+        assert!(increase.unwrap_err().to_string().contains("increased by 20, expected 5"));
+        assert!(validator.check_gauge_in_range(&after, "queue.depth", 0.0..=5.0).is_err());
+        assert!(validator.check_p95_bucket_below(&delta, "http.latency", 5.0).is_err());
+        assert!(validator.check_gauge_in_range(&after, "http.requests", 0.0..=1.0).is_err());
+        assert!(validator.check_counter_increased_by(&before, &after, "missing", 1).is_err());
+    }
+
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_metric_validator_rejects_malformed_bucketed_histogram() {
+        use crate::observability::otel::types::MetricValue;
+
+        let validator = MetricValidator::new();
+        let metric = |bounds: Vec<f64>, bucket_counts: Vec<u64>| Metric {
+            name: "http.latency".to_string(),
+            value: MetricValue::BucketedHistogram { bounds, bucket_counts },
+            timestamp_ms: 1000,
+            attributes: Default::default(),
+        };
+
+        assert!(validator.validate(&metric(vec![1.0, 2.0], vec![0, 1, 2])).is_ok());
+        assert!(validator.validate(&metric(vec![1.0, 2.0], vec![0, 1])).is_err());
+        assert!(validator.validate(&metric(vec![2.0, 1.0], vec![0, 1, 2])).is_err());
+    }
 }
@@ -0,0 +1,784 @@
+//! OTLP Wire-Format Ingestion
+//!
+//! Decodes what an application actually exports — OTLP protobuf or OTLP/JSON trace,
+//! metrics, and logs export requests — into the crate's [`Span`], [`Metric`], and
+//! [`LogRecord`] types, so [`SpanValidator`](super::SpanValidator),
+//! [`MetricValidator`](super::MetricValidator), and
+//! [`TraceAssertions`](super::TraceAssertions) can validate real telemetry instead of
+//! hand-built structs.
+//!
+//! Payloads can come from a file (e.g. the collector's file exporter, which writes one
+//! JSON request per line) or from the body of any request received by an in-test OTLP/HTTP
+//! receiver; [`OtlpEncoding::from_content_type`] maps its `Content-Type` header.
+//!
+//! OTLP/JSON follows the protobuf JSON mapping: `lowerCamelCase` keys (`snake_case` is also
+//! accepted), hex-encoded trace and span ids, and 64-bit integers as strings or numbers.
+//!
+//! Exponential histogram and summary metrics have no crate representation and are skipped.
+
+use std::path::Path;
+
+use opentelemetry_proto::tonic::collector::logs::v1::ExportLogsServiceRequest;
+use opentelemetry_proto::tonic::collector::metrics::v1::ExportMetricsServiceRequest;
+use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+use opentelemetry_proto::tonic::common::v1::{any_value, AnyValue, KeyValue};
+use opentelemetry_proto::tonic::logs::v1 as proto_logs;
+use opentelemetry_proto::tonic::metrics::v1 as proto_metrics;
+use opentelemetry_proto::tonic::trace::v1 as proto_trace;
+use prost::Message;
+use serde_json::Value;
+
+use super::{OtelValidationError, OtelValidationResult};
+use crate::observability::otel::types::{
+    Attributes, LogRecord, Metric, MetricValue, Span, SpanContext, SpanEvent, SpanId, SpanStatus,
+    TraceId,
+};
+
+/// OTLP payload encoding
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtlpEncoding {
+    /// Binary protobuf (`application/x-protobuf`)
+    Protobuf,
+    /// OTLP/JSON (`application/json`), one request or newline-delimited requests
+    Json,
+}
+
+impl OtlpEncoding {
+    /// Encoding for an OTLP/HTTP `Content-Type` header value
+    #[must_use]
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        let mime = content_type.split(';').next().unwrap_or_default().trim();
+        match mime {
+            "application/x-protobuf" | "application/protobuf" => Some(Self::Protobuf),
+            "application/json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    /// Encoding for a file extension (`.json`/`.jsonl` or `.pb`/`.bin`/`.protobuf`)
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "json" | "jsonl" | "ndjson" => Some(Self::Json),
+            "pb" | "bin" | "protobuf" => Some(Self::Protobuf),
+            _ => None,
+        }
+    }
+}
+
+fn decode_error(message: impl Into<String>) -> OtelValidationError {
+    OtelValidationError::OtlpDecodeFailed(message.into())
+}
+
+/// Decode an OTLP trace export payload into spans
+///
+/// # Errors
+///
+/// Returns [`OtelValidationError::OtlpDecodeFailed`] if the payload is malformed or a span
+/// has invalid ids or timestamps.
+pub fn decode_spans(payload: &[u8], encoding: OtlpEncoding) -> OtelValidationResult<Vec<Span>> {
+    let requests = match encoding {
+        OtlpEncoding::Protobuf => vec![ExportTraceServiceRequest::decode(payload)
+            .map_err(|e| decode_error(format!("invalid OTLP trace protobuf: {e}")))?],
+        OtlpEncoding::Json => json_documents(payload)?
+            .iter()
+            .map(trace_request_from_json)
+            .collect::<OtelValidationResult<_>>()?,
+    };
+    requests
+        .iter()
+        .flat_map(|request| &request.resource_spans)
+        .flat_map(|resource| &resource.scope_spans)
+        .flat_map(|scope| &scope.spans)
+        .map(span_from_proto)
+        .collect()
+}
+
+/// Decode an OTLP metrics export payload into metric data points
+///
+/// # Errors
+///
+/// Returns [`OtelValidationError::OtlpDecodeFailed`] if the payload is malformed or a
+/// monotonic sum is negative.
+pub fn decode_metrics(payload: &[u8], encoding: OtlpEncoding) -> OtelValidationResult<Vec<Metric>> {
+    let requests = match encoding {
+        OtlpEncoding::Protobuf => vec![ExportMetricsServiceRequest::decode(payload)
+            .map_err(|e| decode_error(format!("invalid OTLP metrics protobuf: {e}")))?],
+        OtlpEncoding::Json => {
+            json_documents(payload)?.iter().map(metrics_request_from_json).collect()
+        }
+    };
+    let mut metrics = Vec::new();
+    for metric in requests
+        .iter()
+        .flat_map(|request| &request.resource_metrics)
+        .flat_map(|resource| &resource.scope_metrics)
+        .flat_map(|scope| &scope.metrics)
+    {
+        metrics.extend(metric_points_from_proto(metric)?);
+    }
+    Ok(metrics)
+}
+
+/// Decode an OTLP logs export payload into log records
+///
+/// # Errors
+///
+/// Returns [`OtelValidationError::OtlpDecodeFailed`] if the payload is malformed.
+pub fn decode_logs(payload: &[u8], encoding: OtlpEncoding) -> OtelValidationResult<Vec<LogRecord>> {
+    let requests = match encoding {
+        OtlpEncoding::Protobuf => vec![ExportLogsServiceRequest::decode(payload)
+            .map_err(|e| decode_error(format!("invalid OTLP logs protobuf: {e}")))?],
+        OtlpEncoding::Json => json_documents(payload)?
+            .iter()
+            .map(logs_request_from_json)
+            .collect::<OtelValidationResult<_>>()?,
+    };
+    Ok(requests
+        .iter()
+        .flat_map(|request| &request.resource_logs)
+        .flat_map(|resource| &resource.scope_logs)
+        .flat_map(|scope| &scope.log_records)
+        .map(log_record_from_proto)
+        .collect())
+}
+
+fn read_payload(path: &Path) -> OtelValidationResult<(Vec<u8>, OtlpEncoding)> {
+    let encoding = OtlpEncoding::from_path(path).ok_or_else(|| {
+        decode_error(format!(
+            "cannot infer OTLP encoding of '{}' (use .json/.jsonl or .pb/.bin)",
+            path.display()
+        ))
+    })?;
+    let payload = std::fs::read(path)
+        .map_err(|e| decode_error(format!("failed to read '{}': {e}", path.display())))?;
+    Ok((payload, encoding))
+}
+
+/// Read and decode an OTLP trace export file, inferring the encoding from its extension
+///
+/// # Errors
+///
+/// Returns [`OtelValidationError::OtlpDecodeFailed`] if the file cannot be read, has an
+/// unknown extension, or fails to decode.
+pub fn read_spans(path: impl AsRef<Path>) -> OtelValidationResult<Vec<Span>> {
+    let (payload, encoding) = read_payload(path.as_ref())?;
+    decode_spans(&payload, encoding)
+}
+
+/// Read and decode an OTLP metrics export file, inferring the encoding from its extension
+///
+/// # Errors
+///
+/// Returns [`OtelValidationError::OtlpDecodeFailed`] if the file cannot be read, has an
+/// unknown extension, or fails to decode.
+pub fn read_metrics(path: impl AsRef<Path>) -> OtelValidationResult<Vec<Metric>> {
+    let (payload, encoding) = read_payload(path.as_ref())?;
+    decode_metrics(&payload, encoding)
+}
+
+// ============================================================================
+// Protobuf messages -> crate types
+// ============================================================================
+
+fn span_from_proto(span: &proto_trace::Span) -> OtelValidationResult<Span> {
+    let trace_id = <[u8; 16]>::try_from(span.trace_id.as_slice())
+        .map(|bytes| TraceId(u128::from_be_bytes(bytes)))
+        .map_err(|_| decode_error(format!("span '{}' has a malformed trace id", span.name)))?;
+    let span_id = span_id_from_bytes(&span.span_id)
+        .ok_or_else(|| decode_error(format!("span '{}' has a malformed span id", span.name)))?;
+    // Only the low byte of `flags` carries W3C trace flags
+    let flags = span.flags.to_le_bytes()[0];
+    let context = if span.parent_span_id.is_empty() {
+        SpanContext::root(trace_id, span_id, flags)
+    } else {
+        let parent = span_id_from_bytes(&span.parent_span_id).ok_or_else(|| {
+            decode_error(format!("span '{}' has a malformed parent span id", span.name))
+        })?;
+        SpanContext::child(trace_id, span_id, parent, flags)
+    };
+    let status = match span.status.as_ref().map(|status| status.code) {
+        Some(1) => SpanStatus::Ok,
+        Some(2) => SpanStatus::Error,
+        _ => SpanStatus::Unset,
+    };
+    let events = span
+        .events
+        .iter()
+        .map(|event| SpanEvent {
+            name: event.name.clone(),
+            timestamp_ms: nanos_to_ms(event.time_unix_nano),
+            attributes: attributes_from_proto(&event.attributes),
+        })
+        .collect();
+    Span::new_completed(
+        context,
+        span.name.clone(),
+        nanos_to_ms(span.start_time_unix_nano),
+        nanos_to_ms(span.end_time_unix_nano),
+        attributes_from_proto(&span.attributes),
+        events,
+        status,
+    )
+    .map_err(|e| decode_error(format!("span '{}': {e}", span.name)))
+}
+
+fn log_record_from_proto(record: &proto_logs::LogRecord) -> LogRecord {
+    let time = if record.time_unix_nano == 0 {
+        record.observed_time_unix_nano
+    } else {
+        record.time_unix_nano
+    };
+    LogRecord {
+        timestamp_ms: nanos_to_ms(time),
+        severity_number: record.severity_number,
+        severity_text: record.severity_text.clone(),
+        body: record.body.as_ref().map(any_value_to_string).unwrap_or_default(),
+        attributes: attributes_from_proto(&record.attributes),
+        trace_id: <[u8; 16]>::try_from(record.trace_id.as_slice())
+            .ok()
+            .map(|bytes| TraceId(u128::from_be_bytes(bytes))),
+        span_id: span_id_from_bytes(&record.span_id),
+    }
+}
+
+fn span_id_from_bytes(bytes: &[u8]) -> Option<SpanId> {
+    <[u8; 8]>::try_from(bytes).ok().map(|bytes| SpanId(u64::from_be_bytes(bytes)))
+}
+
+const fn nanos_to_ms(nanos: u64) -> u64 {
+    nanos / 1_000_000
+}
+
+fn attributes_from_proto(attributes: &[KeyValue]) -> Attributes {
+    attributes
+        .iter()
+        .map(|kv| (kv.key.clone(), kv.value.as_ref().map(any_value_to_string).unwrap_or_default()))
+        .collect()
+}
+
+fn any_value_to_string(value: &AnyValue) -> String {
+    match &value.value {
+        None => String::new(),
+        Some(any_value::Value::StringValue(s)) => s.clone(),
+        Some(any_value::Value::BoolValue(b)) => b.to_string(),
+        Some(any_value::Value::IntValue(i)) => i.to_string(),
+        Some(any_value::Value::DoubleValue(d)) => d.to_string(),
+        Some(any_value::Value::BytesValue(bytes)) => hex::encode(bytes),
+        Some(any_value::Value::ArrayValue(array)) => {
+            let items: Vec<String> = array.values.iter().map(any_value_to_string).collect();
+            format!("[{}]", items.join(", "))
+        }
+        Some(any_value::Value::KvlistValue(list)) => {
+            let items: Vec<String> = list
+                .values
+                .iter()
+                .map(|kv| {
+                    let value = kv.value.as_ref().map(any_value_to_string).unwrap_or_default();
+                    format!("{}={value}", kv.key)
+                })
+                .collect();
+            format!("{{{}}}", items.join(", "))
+        }
+    }
+}
+
+fn metric_points_from_proto(metric: &proto_metrics::Metric) -> OtelValidationResult<Vec<Metric>> {
+    let point = |value, attributes: &[KeyValue], time_unix_nano| Metric {
+        name: metric.name.clone(),
+        value,
+        timestamp_ms: nanos_to_ms(time_unix_nano),
+        attributes: attributes_from_proto(attributes),
+    };
+    let Some(data) = &metric.data else {
+        return Ok(Vec::new());
+    };
+    match data {
+        proto_metrics::metric::Data::Gauge(gauge) => Ok(gauge
+            .data_points
+            .iter()
+            .map(|p| point(MetricValue::Gauge(number_as_f64(p)), &p.attributes, p.time_unix_nano))
+            .collect()),
+        proto_metrics::metric::Data::Sum(sum) => sum
+            .data_points
+            .iter()
+            .map(|p| {
+                let value = if sum.is_monotonic {
+                    MetricValue::Counter(number_as_counter(p).ok_or_else(|| {
+                        decode_error(format!("monotonic sum '{}' is negative", metric.name))
+                    })?)
+                } else {
+                    MetricValue::Gauge(number_as_f64(p))
+                };
+                Ok(point(value, &p.attributes, p.time_unix_nano))
+            })
+            .collect(),
+        proto_metrics::metric::Data::Histogram(histogram) => Ok(histogram
+            .data_points
+            .iter()
+            .map(|p| {
+                let value = MetricValue::BucketedHistogram {
+                    bounds: p.explicit_bounds.clone(),
+                    bucket_counts: p.bucket_counts.clone(),
+                };
+                point(value, &p.attributes, p.time_unix_nano)
+            })
+            .collect()),
+        proto_metrics::metric::Data::ExponentialHistogram(_)
+        | proto_metrics::metric::Data::Summary(_) => Ok(Vec::new()),
+    }
+}
+
+#[allow(clippy::cast_precision_loss)] // Metric values beyond 2^53 are not asserted on exactly
+const fn number_as_f64(point: &proto_metrics::NumberDataPoint) -> f64 {
+    match point.value {
+        Some(proto_metrics::number_data_point::Value::AsDouble(d)) => d,
+        Some(proto_metrics::number_data_point::Value::AsInt(i)) => i as f64,
+        None => 0.0,
+    }
+}
+
+fn number_as_counter(point: &proto_metrics::NumberDataPoint) -> Option<u64> {
+    match point.value {
+        Some(proto_metrics::number_data_point::Value::AsInt(i)) => u64::try_from(i).ok(),
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        // Checked non-negative; fractional counts round to the nearest unit
+        Some(proto_metrics::number_data_point::Value::AsDouble(d)) => {
+            (d >= 0.0).then(|| d.round() as u64)
+        }
+        None => Some(0),
+    }
+}
+
+// ============================================================================
+// OTLP/JSON -> protobuf messages
+// ============================================================================
+
+/// Parse one JSON document or a stream of newline-delimited documents
+fn json_documents(payload: &[u8]) -> OtelValidationResult<Vec<Value>> {
+    serde_json::Deserializer::from_slice(payload)
+        .into_iter::<Value>()
+        .collect::<Result<_, _>>()
+        .map_err(|e| decode_error(format!("invalid OTLP/JSON: {e}")))
+}
+
+/// Field by its `lowerCamelCase` name, falling back to `snake_case`
+fn field<'a>(value: &'a Value, camel: &str) -> Option<&'a Value> {
+    value.get(camel).or_else(|| {
+        let snake: String = camel
+            .chars()
+            .flat_map(|c| {
+                let lower = c.to_ascii_lowercase();
+                if c.is_ascii_uppercase() {
+                    vec!['_', lower]
+                } else {
+                    vec![c]
+                }
+            })
+            .collect();
+        value.get(snake)
+    })
+}
+
+fn array<'a>(value: &'a Value, camel: &str) -> &'a [Value] {
+    field(value, camel).and_then(Value::as_array).map_or(&[], Vec::as_slice)
+}
+
+fn string(value: &Value, camel: &str) -> String {
+    field(value, camel).and_then(Value::as_str).unwrap_or_default().to_string()
+}
+
+/// 64-bit integers may be encoded as JSON numbers or decimal strings
+fn u64_value(value: &Value) -> Option<u64> {
+    value.as_u64().or_else(|| value.as_str()?.parse().ok())
+}
+
+fn i64_value(value: &Value) -> Option<i64> {
+    value.as_i64().or_else(|| value.as_str()?.parse().ok())
+}
+
+fn f64_value(value: &Value) -> Option<f64> {
+    value.as_f64().or_else(|| value.as_str()?.parse().ok())
+}
+
+fn u64_field(value: &Value, camel: &str) -> u64 {
+    field(value, camel).and_then(u64_value).unwrap_or_default()
+}
+
+fn u32_field(value: &Value, camel: &str) -> u32 {
+    u32::try_from(u64_field(value, camel)).unwrap_or_default()
+}
+
+fn bool_field(value: &Value, camel: &str) -> bool {
+    field(value, camel).and_then(Value::as_bool).unwrap_or_default()
+}
+
+/// Enums may be encoded as integers or (per the JSON mapping) as their names
+fn enum_field(value: &Value, camel: &str, names: &[&str]) -> i32 {
+    field(value, camel)
+        .and_then(|v| {
+            v.as_i64().and_then(|i| i32::try_from(i).ok()).or_else(|| {
+                names
+                    .iter()
+                    .position(|name| Some(*name) == v.as_str())
+                    .and_then(|i| i32::try_from(i).ok())
+            })
+        })
+        .unwrap_or_default()
+}
+
+/// `SeverityNumber` enum names, indexed by value
+const SEVERITY_NUMBERS: &[&str] = &[
+    "SEVERITY_NUMBER_UNSPECIFIED",
+    "SEVERITY_NUMBER_TRACE",
+    "SEVERITY_NUMBER_TRACE2",
+    "SEVERITY_NUMBER_TRACE3",
+    "SEVERITY_NUMBER_TRACE4",
+    "SEVERITY_NUMBER_DEBUG",
+    "SEVERITY_NUMBER_DEBUG2",
+    "SEVERITY_NUMBER_DEBUG3",
+    "SEVERITY_NUMBER_DEBUG4",
+    "SEVERITY_NUMBER_INFO",
+    "SEVERITY_NUMBER_INFO2",
+    "SEVERITY_NUMBER_INFO3",
+    "SEVERITY_NUMBER_INFO4",
+    "SEVERITY_NUMBER_WARN",
+    "SEVERITY_NUMBER_WARN2",
+    "SEVERITY_NUMBER_WARN3",
+    "SEVERITY_NUMBER_WARN4",
+    "SEVERITY_NUMBER_ERROR",
+    "SEVERITY_NUMBER_ERROR2",
+    "SEVERITY_NUMBER_ERROR3",
+    "SEVERITY_NUMBER_ERROR4",
+    "SEVERITY_NUMBER_FATAL",
+    "SEVERITY_NUMBER_FATAL2",
+    "SEVERITY_NUMBER_FATAL3",
+    "SEVERITY_NUMBER_FATAL4",
+];
+
+fn hex_field(value: &Value, camel: &str) -> OtelValidationResult<Vec<u8>> {
+    let encoded = string(value, camel);
+    hex::decode(&encoded)
+        .map_err(|e| decode_error(format!("'{camel}' is not valid hex ({encoded}): {e}")))
+}
+
+fn any_value_from_json(value: &Value) -> AnyValue {
+    AnyValue { value: any_value_kind_from_json(value) }
+}
+
+fn any_value_kind_from_json(value: &Value) -> Option<any_value::Value> {
+    if let Some(s) = field(value, "stringValue").and_then(Value::as_str) {
+        return Some(any_value::Value::StringValue(s.to_string()));
+    }
+    if let Some(b) = field(value, "boolValue").and_then(Value::as_bool) {
+        return Some(any_value::Value::BoolValue(b));
+    }
+    if let Some(i) = field(value, "intValue").and_then(i64_value) {
+        return Some(any_value::Value::IntValue(i));
+    }
+    if let Some(d) = field(value, "doubleValue").and_then(f64_value) {
+        return Some(any_value::Value::DoubleValue(d));
+    }
+    if let Some(array) = field(value, "arrayValue") {
+        return Some(any_value::Value::ArrayValue(
+            opentelemetry_proto::tonic::common::v1::ArrayValue {
+                values: self::array(array, "values").iter().map(any_value_from_json).collect(),
+            },
+        ));
+    }
+    if let Some(list) = field(value, "kvlistValue") {
+        return Some(any_value::Value::KvlistValue(
+            opentelemetry_proto::tonic::common::v1::KeyValueList {
+                values: key_values_from_json(list, "values"),
+            },
+        ));
+    }
+    // Bytes are base64 in OTLP/JSON; keep the encoded text rather than pull in a decoder
+    field(value, "bytesValue")
+        .and_then(Value::as_str)
+        .map(|s| any_value::Value::StringValue(s.to_string()))
+}
+
+fn key_values_from_json(value: &Value, camel: &str) -> Vec<KeyValue> {
+    array(value, camel)
+        .iter()
+        .map(|kv| KeyValue {
+            key: string(kv, "key"),
+            value: field(kv, "value").map(any_value_from_json),
+        })
+        .collect()
+}
+
+fn trace_request_from_json(value: &Value) -> OtelValidationResult<ExportTraceServiceRequest> {
+    let resource_spans = array(value, "resourceSpans")
+        .iter()
+        .map(|resource| {
+            let scope_spans = array(resource, "scopeSpans")
+                .iter()
+                .map(|scope| {
+                    let spans = array(scope, "spans")
+                        .iter()
+                        .map(span_proto_from_json)
+                        .collect::<OtelValidationResult<_>>()?;
+                    Ok(proto_trace::ScopeSpans { spans, ..Default::default() })
+                })
+                .collect::<OtelValidationResult<_>>()?;
+            Ok(proto_trace::ResourceSpans { scope_spans, ..Default::default() })
+        })
+        .collect::<OtelValidationResult<_>>()?;
+    Ok(ExportTraceServiceRequest { resource_spans })
+}
+
+fn span_proto_from_json(span: &Value) -> OtelValidationResult<proto_trace::Span> {
+    let events = array(span, "events")
+        .iter()
+        .map(|event| proto_trace::span::Event {
+            time_unix_nano: u64_field(event, "timeUnixNano"),
+            name: string(event, "name"),
+            attributes: key_values_from_json(event, "attributes"),
+            ..Default::default()
+        })
+        .collect();
+    let status = field(span, "status").map(|status| proto_trace::Status {
+        message: string(status, "message"),
+        code: enum_field(
+            status,
+            "code",
+            &["STATUS_CODE_UNSET", "STATUS_CODE_OK", "STATUS_CODE_ERROR"],
+        ),
+    });
+    Ok(proto_trace::Span {
+        trace_id: hex_field(span, "traceId")?,
+        span_id: hex_field(span, "spanId")?,
+        parent_span_id: hex_field(span, "parentSpanId")?,
+        flags: u32_field(span, "flags"),
+        name: string(span, "name"),
+        start_time_unix_nano: u64_field(span, "startTimeUnixNano"),
+        end_time_unix_nano: u64_field(span, "endTimeUnixNano"),
+        attributes: key_values_from_json(span, "attributes"),
+        events,
+        status,
+        ..Default::default()
+    })
+}
+
+fn logs_request_from_json(value: &Value) -> OtelValidationResult<ExportLogsServiceRequest> {
+    let resource_logs = array(value, "resourceLogs")
+        .iter()
+        .map(|resource| {
+            let scope_logs = array(resource, "scopeLogs")
+                .iter()
+                .map(|scope| {
+                    let log_records = array(scope, "logRecords")
+                        .iter()
+                        .map(|record| {
+                            Ok(proto_logs::LogRecord {
+                                time_unix_nano: u64_field(record, "timeUnixNano"),
+                                observed_time_unix_nano: u64_field(record, "observedTimeUnixNano"),
+                                severity_number: enum_field(
+                                    record,
+                                    "severityNumber",
+                                    SEVERITY_NUMBERS,
+                                ),
+                                severity_text: string(record, "severityText"),
+                                body: field(record, "body").map(any_value_from_json),
+                                attributes: key_values_from_json(record, "attributes"),
+                                trace_id: hex_field(record, "traceId")?,
+                                span_id: hex_field(record, "spanId")?,
+                                ..Default::default()
+                            })
+                        })
+                        .collect::<OtelValidationResult<_>>()?;
+                    Ok(proto_logs::ScopeLogs { log_records, ..Default::default() })
+                })
+                .collect::<OtelValidationResult<_>>()?;
+            Ok(proto_logs::ResourceLogs { scope_logs, ..Default::default() })
+        })
+        .collect::<OtelValidationResult<_>>()?;
+    Ok(ExportLogsServiceRequest { resource_logs })
+}
+
+fn metrics_request_from_json(value: &Value) -> ExportMetricsServiceRequest {
+    let resource_metrics = array(value, "resourceMetrics")
+        .iter()
+        .map(|resource| {
+            let scope_metrics = array(resource, "scopeMetrics")
+                .iter()
+                .map(|scope| proto_metrics::ScopeMetrics {
+                    metrics: array(scope, "metrics").iter().map(metric_proto_from_json).collect(),
+                    ..Default::default()
+                })
+                .collect();
+            proto_metrics::ResourceMetrics { scope_metrics, ..Default::default() }
+        })
+        .collect();
+    ExportMetricsServiceRequest { resource_metrics }
+}
+
+fn metric_proto_from_json(metric: &Value) -> proto_metrics::Metric {
+    proto_metrics::Metric {
+        name: string(metric, "name"),
+        data: metric_data_from_json(metric),
+        ..Default::default()
+    }
+}
+
+fn metric_data_from_json(metric: &Value) -> Option<proto_metrics::metric::Data> {
+    if let Some(gauge) = field(metric, "gauge") {
+        return Some(proto_metrics::metric::Data::Gauge(proto_metrics::Gauge {
+            data_points: number_points_from_json(gauge),
+        }));
+    }
+    if let Some(sum) = field(metric, "sum") {
+        return Some(proto_metrics::metric::Data::Sum(proto_metrics::Sum {
+            data_points: number_points_from_json(sum),
+            is_monotonic: bool_field(sum, "isMonotonic"),
+            ..Default::default()
+        }));
+    }
+    field(metric, "histogram").map(|histogram| {
+        proto_metrics::metric::Data::Histogram(proto_metrics::Histogram {
+            data_points: array(histogram, "dataPoints")
+                .iter()
+                .map(|p| proto_metrics::HistogramDataPoint {
+                    attributes: key_values_from_json(p, "attributes"),
+                    time_unix_nano: u64_field(p, "timeUnixNano"),
+                    bucket_counts: array(p, "bucketCounts").iter().filter_map(u64_value).collect(),
+                    explicit_bounds: array(p, "explicitBounds")
+                        .iter()
+                        .filter_map(f64_value)
+                        .collect(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        })
+    })
+}
+
+fn number_points_from_json(data: &Value) -> Vec<proto_metrics::NumberDataPoint> {
+    array(data, "dataPoints")
+        .iter()
+        .map(|p| proto_metrics::NumberDataPoint {
+            attributes: key_values_from_json(p, "attributes"),
+            time_unix_nano: u64_field(p, "timeUnixNano"),
+            value: field(p, "asInt")
+                .and_then(i64_value)
+                .map(proto_metrics::number_data_point::Value::AsInt)
+                .or_else(|| {
+                    field(p, "asDouble")
+                        .and_then(f64_value)
+                        .map(proto_metrics::number_data_point::Value::AsDouble)
+                }),
+            ..Default::default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observability::otel::SpanValidator;
+
+    const TRACE_JSON: &str = r#"{"resourceSpans":[{"scopeSpans":[{"spans":[
+        {"traceId":"5b8efff798038103d269b633813fc60c","spanId":"eee19b7ec3c1b174",
+         "name":"handle_request","startTimeUnixNano":"1000000000","endTimeUnixNano":"1050000000",
+         "attributes":[{"key":"http.route","value":{"stringValue":"/orders"}},
+                       {"key":"http.status_code","value":{"intValue":"200"}}],
+         "status":{"code":1}},
+        {"traceId":"5b8efff798038103d269b633813fc60c","spanId":"eee19b7ec3c1b175",
+         "parentSpanId":"eee19b7ec3c1b174","name":"db.query",
+         "startTimeUnixNano":1010000000,"endTimeUnixNano":1040000000,
+         "events":[{"name":"retry","timeUnixNano":"1020000000"}],
+         "status":{"code":"STATUS_CODE_ERROR"}}
+    ]}]}]}"#;
+
+    #[test]
+    fn test_decode_spans_from_otlp_json() {
+        let spans = decode_spans(TRACE_JSON.as_bytes(), OtlpEncoding::Json).unwrap();
+
+        assert_eq!(spans.len(), 2);
+        let (request, query) = (&spans[0], &spans[1]);
+        assert_eq!(request.context.trace_id, TraceId(0x5b8e_fff7_9803_8103_d269_b633_813f_c60c));
+        assert!(request.context.is_root());
+        assert_eq!(request.start_time_ms(), 1000);
+        assert_eq!(request.end_time_ms(), Some(1050));
+        assert_eq!(request.attributes.get("http.status_code").map(String::as_str), Some("200"));
+        assert_eq!(request.status, SpanStatus::Ok);
+        assert_eq!(query.context.parent_span_id(), Some(request.context.span_id));
+        assert_eq!(query.events[0].timestamp_ms, 1020);
+        assert_eq!(query.status, SpanStatus::Error);
+        let validated =
+            SpanValidator::new().validate_otlp(TRACE_JSON.as_bytes(), OtlpEncoding::Json);
+        assert_eq!(validated.unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_protobuf_and_json_decode_to_the_same_spans() {
+        let request = trace_request_from_json(&serde_json::from_str(TRACE_JSON).unwrap()).unwrap();
+        let protobuf = request.encode_to_vec();
+
+        let from_protobuf = decode_spans(&protobuf, OtlpEncoding::Protobuf).unwrap();
+        let from_json = decode_spans(TRACE_JSON.as_bytes(), OtlpEncoding::Json).unwrap();
+
+        assert_eq!(format!("{from_protobuf:?}"), format!("{from_json:?}"));
+    }
+
+    #[test]
+    fn test_decode_metrics_from_ndjson() {
+        let payload = concat!(
+            r#"{"resourceMetrics":[{"scopeMetrics":[{"metrics":[{"name":"http.requests","sum":{"isMonotonic":true,"dataPoints":[{"asInt":"7","timeUnixNano":"2000000000"}]}}]}]}]}"#,
+            "\n",
+            r#"{"resourceMetrics":[{"scopeMetrics":[{"metrics":[{"name":"queue.depth","gauge":{"dataPoints":[{"asDouble":2.5}]}},{"name":"http.latency","histogram":{"dataPoints":[{"bucketCounts":["1","2","0"],"explicitBounds":[10,100]}]}}]}]}]}"#,
+        );
+
+        let metrics = decode_metrics(payload.as_bytes(), OtlpEncoding::Json).unwrap();
+
+        assert_eq!(metrics.len(), 3);
+        assert!(matches!(metrics[0].value, MetricValue::Counter(7)));
+        assert_eq!(metrics[0].timestamp_ms, 2000);
+        assert!(
+            matches!(metrics[1].value, MetricValue::Gauge(v) if (v - 2.5).abs() < f64::EPSILON)
+        );
+        assert!(matches!(
+            &metrics[2].value,
+            MetricValue::BucketedHistogram { bounds, bucket_counts }
+                if bounds == &[10.0, 100.0] && bucket_counts == &[1, 2, 0]
+        ));
+    }
+
+    #[test]
+    fn test_decode_logs_from_otlp_json() {
+        let payload = r#"{"resourceLogs":[{"scopeLogs":[{"logRecords":[
+            {"observedTimeUnixNano":"3000000000","severityNumber":"SEVERITY_NUMBER_ERROR",
+             "severityText":"ERROR","body":{"stringValue":"payment declined"},
+             "traceId":"5b8efff798038103d269b633813fc60c","spanId":"eee19b7ec3c1b174"},
+            {"timeUnixNano":"4000000000","severityNumber":9,"body":{"intValue":"42"}}
+        ]}]}]}"#;
+
+        let logs = decode_logs(payload.as_bytes(), OtlpEncoding::Json).unwrap();
+
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].timestamp_ms, 3000);
+        assert_eq!(logs[0].severity_number, 17);
+        assert_eq!(logs[0].body, "payment declined");
+        assert_eq!(logs[0].span_id, Some(SpanId(0xeee1_9b7e_c3c1_b174)));
+        assert_eq!((logs[1].severity_number, logs[1].body.as_str()), (9, "42"));
+        assert_eq!(logs[1].trace_id, None);
+    }
+
+    #[test]
+    fn test_malformed_payloads_are_rejected() {
+        let bad_id = TRACE_JSON.replacen("eee19b7ec3c1b174", "zz", 1);
+
+        assert!(decode_spans(b"{not json", OtlpEncoding::Json).is_err());
+        assert!(decode_spans(&[0xff, 0xff, 0xff], OtlpEncoding::Protobuf).is_err());
+        assert!(decode_spans(bad_id.as_bytes(), OtlpEncoding::Json).is_err());
+        assert_eq!(
+            OtlpEncoding::from_content_type("application/json; charset=utf-8"),
+            Some(OtlpEncoding::Json)
+        );
+        assert_eq!(OtlpEncoding::from_path(Path::new("traces.pb")), Some(OtlpEncoding::Protobuf));
+        assert!(read_spans("traces.txt").is_err());
+    }
+}
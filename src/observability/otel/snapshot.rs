@@ -0,0 +1,209 @@
+//! Metric Snapshots
+//!
+//! A [`MetricSnapshot`] is the set of metric data points observed at one instant (for
+//! example from `InMemoryMetricCollector::snapshot` with the `otel-sdk` feature). Taking one snapshot before and one after the code under
+//! test, then [`MetricSnapshot::since`], isolates what that code recorded; the temporal
+//! assertions on [`MetricValidator`](super::MetricValidator) operate on these snapshots.
+//!
+//! Lookups are by metric name and aggregate across attribute sets: counters and histogram
+//! buckets are summed, gauges are reported per data point.
+
+use crate::observability::otel::types::{Metric, MetricValue};
+
+/// Metric data points captured at one instant
+#[derive(Debug, Clone, Default)]
+pub struct MetricSnapshot {
+    metrics: Vec<Metric>,
+}
+
+impl MetricSnapshot {
+    /// Snapshot of the given data points
+    #[must_use]
+    pub const fn new(metrics: Vec<Metric>) -> Self {
+        Self { metrics }
+    }
+
+    /// All data points
+    #[must_use]
+    pub fn metrics(&self) -> &[Metric] {
+        &self.metrics
+    }
+
+    /// Data points for a metric name
+    pub fn points<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Metric> + 'a {
+        self.metrics.iter().filter(move |m| m.name == name)
+    }
+
+    /// Whether the snapshot has any data point for `name`
+    #[must_use]
+    pub fn contains(&self, name: &str) -> bool {
+        self.points(name).next().is_some()
+    }
+
+    /// Counter total across attribute sets, or `None` if `name` has no counter points
+    #[must_use]
+    pub fn counter(&self, name: &str) -> Option<u64> {
+        self.points(name)
+            .filter_map(|m| match m.value {
+                MetricValue::Counter(count) => Some(count),
+                _ => None,
+            })
+            .reduce(u64::saturating_add)
+    }
+
+    /// Gauge values, one per attribute set
+    #[must_use]
+    pub fn gauges(&self, name: &str) -> Vec<f64> {
+        self.points(name)
+            .filter_map(|m| match m.value {
+                MetricValue::Gauge(value) => Some(value),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Bucket bounds and summed bucket counts of an explicit-bucket histogram
+    ///
+    /// Returns `None` if `name` has no [`MetricValue::BucketedHistogram`] points, or its
+    /// points disagree on bounds.
+    #[must_use]
+    pub fn histogram(&self, name: &str) -> Option<(Vec<f64>, Vec<u64>)> {
+        let mut merged: Option<(Vec<f64>, Vec<u64>)> = None;
+        for metric in self.points(name) {
+            let MetricValue::BucketedHistogram { bounds, bucket_counts } = &metric.value else {
+                continue;
+            };
+            match &mut merged {
+                None => merged = Some((bounds.clone(), bucket_counts.clone())),
+                Some((merged_bounds, _)) if merged_bounds != bounds => return None,
+                Some((_, merged_counts)) => {
+                    for (total, count) in merged_counts.iter_mut().zip(bucket_counts) {
+                        *total = total.saturating_add(*count);
+                    }
+                }
+            }
+        }
+        merged
+    }
+
+    /// Upper bound of the bucket holding the given percentile (1–100) of observations
+    ///
+    /// Returns `f64::INFINITY` when the percentile falls in the overflow bucket, and
+    /// `None` if the histogram is missing or has no observations.
+    #[must_use]
+    pub fn histogram_percentile_bound(&self, name: &str, percentile: u64) -> Option<f64> {
+        let (bounds, counts) = self.histogram(name)?;
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        // Rank of the observation at the percentile (1-based, rounded up)
+        let rank = total.saturating_mul(percentile.clamp(1, 100)).div_ceil(100);
+        let mut seen = 0;
+        for (index, count) in counts.iter().enumerate() {
+            seen += count;
+            if seen >= rank {
+                return Some(bounds.get(index).copied().unwrap_or(f64::INFINITY));
+            }
+        }
+        None
+    }
+
+    /// What changed between `before` and this snapshot
+    ///
+    /// Counters and histogram buckets become the increase since `before` (matched by name
+    /// and attributes; points absent from `before` count from zero). Gauges keep their
+    /// current value.
+    #[must_use]
+    pub fn since(&self, before: &Self) -> Self {
+        let metrics = self
+            .metrics
+            .iter()
+            .map(|metric| {
+                let previous = before
+                    .metrics
+                    .iter()
+                    .find(|m| m.name == metric.name && m.attributes == metric.attributes);
+                let value = match (&metric.value, previous.map(|m| &m.value)) {
+                    (MetricValue::Counter(now), Some(MetricValue::Counter(then))) => {
+                        MetricValue::Counter(now.saturating_sub(*then))
+                    }
+                    (
+                        MetricValue::BucketedHistogram { bounds, bucket_counts },
+                        Some(MetricValue::BucketedHistogram {
+                            bounds: then_bounds,
+                            bucket_counts: then_counts,
+                        }),
+                    ) if bounds == then_bounds => MetricValue::BucketedHistogram {
+                        bounds: bounds.clone(),
+                        bucket_counts: bucket_counts
+                            .iter()
+                            .zip(then_counts)
+                            .map(|(now, then)| now.saturating_sub(*then))
+                            .collect(),
+                    },
+                    (value, _) => value.clone(),
+                };
+                Metric { value, ..metric.clone() }
+            })
+            .collect();
+        Self { metrics }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::observability::otel::types::Attributes;
+
+    fn metric(name: &str, route: &str, value: MetricValue) -> Metric {
+        let mut attributes = Attributes::new();
+        attributes.insert("route".to_string(), route.to_string());
+        Metric { name: name.to_string(), value, timestamp_ms: 0, attributes }
+    }
+
+    fn latency(counts: Vec<u64>) -> MetricValue {
+        MetricValue::BucketedHistogram { bounds: vec![10.0, 50.0, 100.0], bucket_counts: counts }
+    }
+
+    #[test]
+    fn test_lookups_aggregate_across_attribute_sets() {
+        let snapshot = MetricSnapshot::new(vec![
+            metric("requests", "/a", MetricValue::Counter(3)),
+            metric("requests", "/b", MetricValue::Counter(4)),
+            metric("queue.depth", "/a", MetricValue::Gauge(2.0)),
+            metric("latency", "/a", latency(vec![1, 2, 0, 0])),
+            metric("latency", "/b", latency(vec![0, 1, 1, 0])),
+        ]);
+
+        assert_eq!(snapshot.counter("requests"), Some(7));
+        assert_eq!(snapshot.counter("missing"), None);
+        assert_eq!(snapshot.gauges("queue.depth"), vec![2.0]);
+        assert_eq!(
+            snapshot.histogram("latency"),
+            Some((vec![10.0, 50.0, 100.0], vec![1, 3, 1, 0]))
+        );
+        assert_eq!(snapshot.histogram_percentile_bound("latency", 50), Some(50.0));
+        assert_eq!(snapshot.histogram_percentile_bound("latency", 95), Some(100.0));
+    }
+
+    #[test]
+    fn test_since_subtracts_counters_and_buckets() {
+        let before = MetricSnapshot::new(vec![
+            metric("requests", "/a", MetricValue::Counter(3)),
+            metric("latency", "/a", latency(vec![1, 0, 0, 0])),
+        ]);
+        let after = MetricSnapshot::new(vec![
+            metric("requests", "/a", MetricValue::Counter(5)),
+            metric("requests", "/b", MetricValue::Counter(1)),
+            metric("latency", "/a", latency(vec![1, 0, 0, 2])),
+        ]);
+
+        let delta = after.since(&before);
+
+        assert_eq!(delta.counter("requests"), Some(3));
+        assert_eq!(delta.histogram("latency").map(|(_, counts)| counts), Some(vec![0, 0, 0, 2]));
+        assert_eq!(delta.histogram_percentile_bound("latency", 95), Some(f64::INFINITY));
+        assert_eq!(before.since(&before).histogram_percentile_bound("latency", 95), None);
+    }
+}
@@ -0,0 +1,583 @@
+//! Test Execution Traces
+//!
+//! Every test run by the framework's macros can be exported as an OpenTelemetry trace:
+//! a root span named after the test, carrying its metadata (`test.name`, `test.outcome`,
+//! `code.filepath`, `code.lineno`, ...), with one child span per AAA phase the test
+//! marked. `#[tdd_test]` marks phases from its `// Arrange`, `// Act`, and `// Assert`
+//! comments, so a trace view shows at a glance whether Arrange dominates a test.
+//!
+//! Traces are built from the test event bus (see [`crate::core::events`]) and appended
+//! to a file as OTLP/JSON trace export requests, one per line - the format read by the
+//! collector's `otlpjsonfile` receiver - so test runs can be loaded into any OTLP
+//! backend. Recording is off by default. Set `CHICAGO_TDD_TRACES=<path>` (or call
+//! [`TestTraceRecorder::enable`]) to turn it on:
+//!
+//! ```bash
+//! CHICAGO_TDD_TRACES=target/test-traces.jsonl cargo test --features otel
+//! ```
+//!
+//! [`TestTrace::spans`] converts a trace into the crate's [`Span`] type, so
+//! [`TraceAssertions`](super::TraceAssertions) can check it in-process.
+
+use std::cell::RefCell;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, Once, PoisonError};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Value};
+
+use crate::core::events::Event;
+use crate::core::receipt::{AaaPhase, TestOutcome};
+use crate::observability::otel::types::{
+    Attributes, Span, SpanContext, SpanId, SpanStatus, TraceId,
+};
+
+/// Environment variable naming the OTLP/JSON file test traces are appended to
+pub const TRACES_ENV: &str = "CHICAGO_TDD_TRACES";
+
+/// Instrumentation scope of test trace spans
+const SCOPE_NAME: &str = "chicago-tdd-tools";
+
+/// OTLP `SPAN_KIND_INTERNAL`
+const SPAN_KIND_INTERNAL: u8 = 1;
+
+/// One AAA phase of a traced test
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhaseSpan {
+    /// Span id of the phase span
+    pub span_id: SpanId,
+    /// Phase
+    pub phase: AaaPhase,
+    /// Phase start (Unix epoch nanoseconds)
+    pub start_unix_nano: u64,
+    /// Phase end (Unix epoch nanoseconds)
+    pub end_unix_nano: u64,
+}
+
+/// One finished test run, as a root span with a child span per phase
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestTrace {
+    /// Trace id shared by the test span and its phase spans
+    pub trace_id: TraceId,
+    /// Span id of the test (root) span
+    pub span_id: SpanId,
+    /// Fully qualified test name (`module::path::test_name`)
+    pub test: String,
+    /// How the test ended
+    pub outcome: TestOutcome,
+    /// Why the test failed, when known
+    pub failure: Option<String>,
+    /// Source file declaring the test
+    pub file: Option<String>,
+    /// Line of the test declaration in `file`
+    pub line: Option<u32>,
+    /// Name of the thread the test ran on
+    pub thread: Option<String>,
+    /// Test start (Unix epoch nanoseconds)
+    pub start_unix_nano: u64,
+    /// Test end (Unix epoch nanoseconds)
+    pub end_unix_nano: u64,
+    /// Marked phases, in order (empty if the test marked none)
+    pub phases: Vec<PhaseSpan>,
+    /// Framework assertions executed
+    pub assertions: u64,
+    /// Fixtures created, in order of first use
+    pub fixtures: Vec<String>,
+}
+
+/// Attribute value, kept typed for OTLP export
+enum AttributeValue {
+    Str(String),
+    Int(u64),
+}
+
+impl TestTrace {
+    /// Metadata attributes of the test span
+    fn metadata(&self) -> Vec<(&'static str, AttributeValue)> {
+        let mut attributes = vec![
+            ("test.name", AttributeValue::Str(self.test.clone())),
+            ("test.outcome", AttributeValue::Str(self.outcome.to_string().to_lowercase())),
+            ("test.assertions", AttributeValue::Int(self.assertions)),
+        ];
+        if let Some((namespace, function)) = self.test.rsplit_once("::") {
+            attributes.push(("code.namespace", AttributeValue::Str(namespace.to_string())));
+            attributes.push(("code.function", AttributeValue::Str(function.to_string())));
+        }
+        if let Some(file) = &self.file {
+            attributes.push(("code.filepath", AttributeValue::Str(file.clone())));
+        }
+        if let Some(line) = self.line {
+            attributes.push(("code.lineno", AttributeValue::Int(u64::from(line))));
+        }
+        if let Some(thread) = &self.thread {
+            attributes.push(("thread.name", AttributeValue::Str(thread.clone())));
+        }
+        if !self.fixtures.is_empty() {
+            attributes.push(("test.fixtures", AttributeValue::Str(self.fixtures.join(","))));
+        }
+        if let Some(failure) = &self.failure {
+            attributes.push(("test.failure", AttributeValue::Str(failure.clone())));
+        }
+        attributes
+    }
+
+    fn phase_metadata(&self, phase: AaaPhase) -> Vec<(&'static str, AttributeValue)> {
+        vec![
+            ("test.name", AttributeValue::Str(self.test.clone())),
+            ("test.phase", AttributeValue::Str(phase_name(phase).to_string())),
+        ]
+    }
+
+    const fn status(&self) -> SpanStatus {
+        match self.outcome {
+            TestOutcome::Pass => SpanStatus::Ok,
+            TestOutcome::Fail | TestOutcome::Error => SpanStatus::Error,
+            TestOutcome::Skip => SpanStatus::Unset,
+        }
+    }
+
+    /// The test span followed by its phase spans, as crate spans (millisecond precision)
+    #[must_use]
+    pub fn spans(&self) -> Vec<Span> {
+        let attributes = |metadata: Vec<(&'static str, AttributeValue)>| -> Attributes {
+            metadata
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = match value {
+                        AttributeValue::Str(value) => value,
+                        AttributeValue::Int(value) => value.to_string(),
+                    };
+                    (key.to_string(), value)
+                })
+                .collect()
+        };
+        let root = Span::new_completed(
+            SpanContext::root(self.trace_id, self.span_id, 1),
+            self.test.clone(),
+            self.start_unix_nano / 1_000_000,
+            self.end_unix_nano.max(self.start_unix_nano) / 1_000_000,
+            attributes(self.metadata()),
+            Vec::new(),
+            self.status(),
+        );
+        let phases = self.phases.iter().map(|phase| {
+            Span::new_completed(
+                SpanContext::child(self.trace_id, phase.span_id, self.span_id, 1),
+                phase_name(phase.phase).to_string(),
+                phase.start_unix_nano / 1_000_000,
+                phase.end_unix_nano.max(phase.start_unix_nano) / 1_000_000,
+                attributes(self.phase_metadata(phase.phase)),
+                Vec::new(),
+                SpanStatus::Unset,
+            )
+        });
+        // End times are clamped to start times above, so completion cannot fail
+        std::iter::once(root).chain(phases).filter_map(Result::ok).collect()
+    }
+
+    /// OTLP/JSON trace export request holding this trace
+    #[must_use]
+    pub fn to_otlp_json(&self) -> Value {
+        let service = std::env::var("CARGO_PKG_NAME").unwrap_or_else(|_| SCOPE_NAME.to_string());
+        let mut status = json!({ "code": otlp_status_code(self.status()) });
+        if let Some(failure) = &self.failure {
+            status["message"] = Value::String(failure.clone());
+        }
+        let mut spans = vec![json!({
+            "traceId": format!("{:032x}", self.trace_id.0),
+            "spanId": format!("{:016x}", self.span_id.0),
+            "name": self.test,
+            "kind": SPAN_KIND_INTERNAL,
+            "startTimeUnixNano": self.start_unix_nano.to_string(),
+            "endTimeUnixNano": self.end_unix_nano.to_string(),
+            "attributes": otlp_attributes(self.metadata()),
+            "status": status,
+        })];
+        spans.extend(self.phases.iter().map(|phase| {
+            json!({
+                "traceId": format!("{:032x}", self.trace_id.0),
+                "spanId": format!("{:016x}", phase.span_id.0),
+                "parentSpanId": format!("{:016x}", self.span_id.0),
+                "name": phase_name(phase.phase),
+                "kind": SPAN_KIND_INTERNAL,
+                "startTimeUnixNano": phase.start_unix_nano.to_string(),
+                "endTimeUnixNano": phase.end_unix_nano.to_string(),
+                "attributes": otlp_attributes(self.phase_metadata(phase.phase)),
+            })
+        }));
+        json!({
+            "resourceSpans": [{
+                "resource": {
+                    "attributes": otlp_attributes(vec![("service.name", AttributeValue::Str(service))]),
+                },
+                "scopeSpans": [{
+                    "scope": { "name": SCOPE_NAME, "version": env!("CARGO_PKG_VERSION") },
+                    "spans": spans,
+                }],
+            }],
+        })
+    }
+}
+
+const fn phase_name(phase: AaaPhase) -> &'static str {
+    match phase {
+        AaaPhase::Arrange => "arrange",
+        AaaPhase::Act => "act",
+        AaaPhase::Assert => "assert",
+    }
+}
+
+const fn otlp_status_code(status: SpanStatus) -> u8 {
+    match status {
+        SpanStatus::Unset => 0,
+        SpanStatus::Ok => 1,
+        SpanStatus::Error => 2,
+    }
+}
+
+fn otlp_attributes(attributes: Vec<(&'static str, AttributeValue)>) -> Value {
+    attributes
+        .into_iter()
+        .map(|(key, value)| {
+            let value = match value {
+                AttributeValue::Str(value) => json!({ "stringValue": value }),
+                // OTLP/JSON encodes 64-bit integers as strings
+                AttributeValue::Int(value) => json!({ "intValue": value.to_string() }),
+            };
+            json!({ "key": key, "value": value })
+        })
+        .collect()
+}
+
+/// Span id source: process id in the high half, a counter in the low half (never zero)
+static NEXT_SPAN: AtomicU64 = AtomicU64::new(1);
+
+fn next_span_id() -> SpanId {
+    SpanId((u64::from(std::process::id()) << 32) | NEXT_SPAN.fetch_add(1, Ordering::Relaxed))
+}
+
+#[allow(clippy::cast_possible_truncation)] // Nanoseconds since 1970 fit in u64 until 2554
+fn now_nanos() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos() as u64
+}
+
+/// Trace being built for the test running on this thread
+struct ActiveTrace {
+    trace: TestTrace,
+    phase: Option<(AaaPhase, u64)>,
+}
+
+impl ActiveTrace {
+    fn close_phase(&mut self, now: u64) {
+        if let Some((phase, since)) = self.phase.take() {
+            self.trace.phases.push(PhaseSpan {
+                span_id: next_span_id(),
+                phase,
+                start_unix_nano: since,
+                end_unix_nano: now,
+            });
+        }
+    }
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Option<ActiveTrace>> = const { RefCell::new(None) };
+}
+
+fn with_active(update: impl FnOnce(&mut ActiveTrace)) {
+    ACTIVE.with(|active| {
+        if let Some(trace) = active.borrow_mut().as_mut() {
+            update(trace);
+        }
+    });
+}
+
+/// Update this thread's trace from a bus event (called by the event bus before subscribers)
+pub(crate) fn on_event(event: &Event) {
+    match event {
+        Event::TestStarted { test } => {
+            if !TestTraceRecorder::is_enabled() {
+                return;
+            }
+            let start = now_nanos();
+            let span_id = next_span_id();
+            let trace = TestTrace {
+                trace_id: TraceId((u128::from(start) << 64) | u128::from(span_id.0)),
+                span_id,
+                test: test.clone(),
+                outcome: TestOutcome::Fail,
+                failure: None,
+                file: None,
+                line: None,
+                thread: std::thread::current().name().map(str::to_string),
+                start_unix_nano: start,
+                end_unix_nano: start,
+                phases: Vec::new(),
+                assertions: 0,
+                fixtures: Vec::new(),
+            };
+            ACTIVE.with(|active| *active.borrow_mut() = Some(ActiveTrace { trace, phase: None }));
+        }
+        Event::PhaseEntered { phase } => with_active(|active| {
+            let now = now_nanos();
+            match active.phase {
+                Some((current, _)) if current == *phase => {}
+                Some(_) => {
+                    active.close_phase(now);
+                    active.phase = Some((*phase, now));
+                }
+                // Time before the first marker counts as Arrange
+                None if *phase == AaaPhase::Arrange => {
+                    active.phase = Some((AaaPhase::Arrange, active.trace.start_unix_nano));
+                }
+                None => {
+                    active.phase = Some((AaaPhase::Arrange, active.trace.start_unix_nano));
+                    active.close_phase(now);
+                    active.phase = Some((*phase, now));
+                }
+            }
+        }),
+        Event::AssertionEvaluated => with_active(|active| active.trace.assertions += 1),
+        Event::FixtureSetup { fixture } => with_active(|active| {
+            if !active.trace.fixtures.iter().any(|name| name == fixture) {
+                active.trace.fixtures.push(fixture.clone());
+            }
+        }),
+        Event::AlertEmitted { .. } => {}
+        Event::TestFinished { outcome, failure, file, line, .. } => {
+            let Some(mut active) = ACTIVE.with(|active| active.borrow_mut().take()) else {
+                return;
+            };
+            let now = now_nanos();
+            active.close_phase(now);
+            let trace = TestTrace {
+                outcome: *outcome,
+                failure: failure.clone(),
+                file: file.clone(),
+                line: *line,
+                end_unix_nano: now,
+                ..active.trace
+            };
+            if let Err(e) = TestTraceRecorder::record(&trace) {
+                eprintln!("⚠️  Failed to record test trace for {}: {e}", trace.test);
+            }
+        }
+    }
+}
+
+/// Open trace stream
+#[derive(Debug)]
+struct TraceStream {
+    path: PathBuf,
+    file: File,
+}
+
+static STREAM: Mutex<Option<TraceStream>> = Mutex::new(None);
+static FROM_ENV: Once = Once::new();
+
+fn stream() -> MutexGuard<'static, Option<TraceStream>> {
+    FROM_ENV.call_once(|| {
+        if let Some(path) = std::env::var_os(TRACES_ENV).filter(|path| !path.is_empty()) {
+            if let Err(e) = open_stream(PathBuf::from(path)) {
+                eprintln!("⚠️  {TRACES_ENV}: cannot open trace stream: {e}");
+            }
+        }
+    });
+    STREAM.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn open_stream(path: PathBuf) -> io::Result<()> {
+    if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    let file = OpenOptions::new().create(true).append(true).open(&path)?;
+    *STREAM.lock().unwrap_or_else(PoisonError::into_inner) = Some(TraceStream { path, file });
+    Ok(())
+}
+
+/// Process-wide OTLP/JSON test trace stream
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TestTraceRecorder;
+
+impl TestTraceRecorder {
+    /// Append test traces to `path` (overrides `CHICAGO_TDD_TRACES`)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be opened.
+    pub fn enable(path: impl Into<PathBuf>) -> io::Result<()> {
+        FROM_ENV.call_once(|| {});
+        open_stream(path.into())
+    }
+
+    /// Stop recording test traces
+    pub fn disable() {
+        FROM_ENV.call_once(|| {});
+        *STREAM.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Whether test traces are being recorded
+    #[must_use]
+    pub fn is_enabled() -> bool {
+        stream().is_some()
+    }
+
+    /// File test traces are appended to, if enabled
+    #[must_use]
+    pub fn path() -> Option<PathBuf> {
+        stream().as_ref().map(|stream| stream.path.clone())
+    }
+
+    /// Append `trace` to the stream as one OTLP/JSON line (no-op when disabled)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn record(trace: &TestTrace) -> io::Result<()> {
+        let mut line = serde_json::to_vec(&trace.to_otlp_json()).map_err(io::Error::other)?;
+        line.push(b'\n');
+        let mut stream = stream();
+        match stream.as_mut() {
+            // One write per line keeps concurrent test binaries from interleaving
+            Some(stream) => stream.file.write_all(&line),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Test code: unwrap is acceptable
+mod tests {
+    use super::*;
+    use crate::core::receipt::{enter_phase, record_assertion, record_fixture, TestRecording};
+    use crate::observability::otel::TraceAssertions;
+    use crate::test;
+
+    fn trace() -> TestTrace {
+        TestTrace {
+            trace_id: TraceId(7),
+            span_id: SpanId(1),
+            test: "orders::test_total".to_string(),
+            outcome: TestOutcome::Fail,
+            failure: Some("expected 3".to_string()),
+            file: Some("src/orders.rs".to_string()),
+            line: Some(42),
+            thread: None,
+            start_unix_nano: 1_000_000_000,
+            end_unix_nano: 1_009_000_000,
+            phases: vec![
+                PhaseSpan {
+                    span_id: SpanId(2),
+                    phase: AaaPhase::Arrange,
+                    start_unix_nano: 1_000_000_000,
+                    end_unix_nano: 1_006_000_000,
+                },
+                PhaseSpan {
+                    span_id: SpanId(3),
+                    phase: AaaPhase::Act,
+                    start_unix_nano: 1_006_000_000,
+                    end_unix_nano: 1_009_000_000,
+                },
+            ],
+            assertions: 1,
+            fixtures: Vec::new(),
+        }
+    }
+
+    test!(test_spans_nest_phases_under_the_test, {
+        // Arrange
+        let trace = trace();
+
+        // Act
+        let spans = trace.spans();
+
+        // Assert
+        let assertions = TraceAssertions::new(&spans);
+        assertions
+            .assert_child_of("arrange", "orders::test_total")
+            .assert_child_of("act", "orders::test_total")
+            .assert_span_order(&["arrange", "act"])
+            .assert_no_orphans();
+        assert_eq!(spans[0].status, SpanStatus::Error);
+        assert_eq!(spans[0].attributes["code.function"], "test_total");
+        assert_eq!(spans[0].attributes["code.lineno"], "42");
+        assert_eq!(spans[1].attributes["test.phase"], "arrange");
+    });
+
+    test!(test_otlp_json_carries_metadata_and_parent_links, {
+        // Arrange
+        let trace = trace();
+
+        // Act
+        let request = trace.to_otlp_json();
+
+        // Assert
+        let spans = &request["resourceSpans"][0]["scopeSpans"][0]["spans"];
+        assert_eq!(spans[0]["traceId"], format!("{:032x}", 7));
+        assert_eq!(spans[0]["status"]["code"], 2);
+        assert_eq!(spans[0]["status"]["message"], "expected 3");
+        assert_eq!(spans[1]["parentSpanId"], "0000000000000001");
+        assert_eq!(spans[1]["startTimeUnixNano"], "1000000000");
+        let lineno = spans[0]["attributes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|attribute| attribute["key"] == "code.lineno")
+            .unwrap();
+        assert_eq!(lineno["value"]["intValue"], "42");
+    });
+
+    test!(test_recorder_streams_one_trace_per_test, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("traces.jsonl");
+        TestTraceRecorder::enable(&path).unwrap();
+
+        // Act
+        let recording = TestRecording::start("trace_suite::passes").at("src/suite.rs", 9);
+        record_fixture("TestFixture");
+        enter_phase(AaaPhase::Act);
+        enter_phase(AaaPhase::Assert);
+        record_assertion();
+        recording.finish(true);
+        TestTraceRecorder::disable();
+
+        // Assert: Concurrently running tests may have appended their own traces
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let request: Value = contents
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .find(|request| {
+                request["resourceSpans"][0]["scopeSpans"][0]["spans"][0]["name"]
+                    == "trace_suite::passes"
+            })
+            .unwrap();
+        let spans = request["resourceSpans"][0]["scopeSpans"][0]["spans"].as_array().unwrap();
+        let names: Vec<&str> = spans.iter().map(|span| span["name"].as_str().unwrap()).collect();
+        assert_eq!(names, ["trace_suite::passes", "arrange", "act", "assert"]);
+        assert_eq!(spans[0]["status"]["code"], 1);
+        assert!(spans[1..].iter().all(|span| span["parentSpanId"] == spans[0]["spanId"]));
+    });
+
+    #[cfg(feature = "otlp")]
+    test!(test_exported_traces_decode_as_otlp, {
+        // Arrange
+        let trace = trace();
+        let payload = serde_json::to_vec(&trace.to_otlp_json()).unwrap();
+
+        // Act
+        let spans = crate::observability::otel::otlp::decode_spans(
+            &payload,
+            crate::observability::otel::otlp::OtlpEncoding::Json,
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(spans.len(), 3);
+        TraceAssertions::new(&spans).assert_child_of("act", "orders::test_total");
+        assert_eq!(spans[0].attributes["test.outcome"], "fail");
+    });
+}
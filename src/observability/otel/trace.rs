@@ -0,0 +1,414 @@
+//! Trace Tree Assertions
+//!
+//! [`SpanValidator`](super::SpanValidator) checks spans one at a time. [`TraceAssertions`]
+//! rebuilds the span tree from a flat slice of spans (as exported, in any order) and
+//! checks its shape: parent/child links, start order, depth, and orphaned spans whose
+//! parent was never exported.
+//!
+//! Every check has a `check_*` form returning [`OtelValidationResult`] and an `assert_*`
+//! form that panics with the rendered tree, for chaining in tests.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::otel::trace::TraceAssertions;
+//! use chicago_tdd_tools::otel::types::{
+//!     Attributes, Span, SpanContext, SpanId, SpanStatus, TraceId,
+//! };
+//!
+//! let span = |name: &str, context: SpanContext, start: u64| {
+//!     Span::new_completed(
+//!         context, name.to_string(), start, start + 5, Attributes::new(), Vec::new(), SpanStatus::Ok,
+//!     )
+//!     .unwrap()
+//! };
+//! let trace = TraceId(7);
+//! let spans = vec![
+//!     span("db.query", SpanContext::child(trace, SpanId(2), SpanId(1), 1), 10),
+//!     span("handle_request", SpanContext::root(trace, SpanId(1), 1), 0),
+//!     span("render", SpanContext::child(trace, SpanId(3), SpanId(1), 1), 20),
+//! ];
+//!
+//! TraceAssertions::new(&spans)
+//!     .assert_child_of("db.query", "handle_request")
+//!     .assert_span_order(&["handle_request", "db.query", "render"])
+//!     .assert_trace_depth_at_most(2)
+//!     .assert_no_orphans();
+//! ```
+
+use super::{OtelValidationError, OtelValidationResult};
+use crate::observability::otel::types::{Span, SpanId, TraceId};
+use std::fmt::Write as _;
+
+/// Span tree reconstructed from a slice of spans
+#[derive(Debug, Clone, Copy)]
+pub struct TraceAssertions<'a> {
+    spans: &'a [Span],
+}
+
+impl<'a> TraceAssertions<'a> {
+    /// Build the tree from spans in any order (one or more traces)
+    #[must_use]
+    pub const fn new(spans: &'a [Span]) -> Self {
+        Self { spans }
+    }
+
+    fn find_by_id(&self, trace_id: TraceId, span_id: SpanId) -> Option<&'a Span> {
+        self.spans
+            .iter()
+            .find(|s| s.context.trace_id == trace_id && s.context.span_id == span_id)
+    }
+
+    /// Spans with the given name, in slice order
+    #[must_use]
+    pub fn spans_named(&self, name: &str) -> Vec<&'a Span> {
+        self.spans.iter().filter(|s| s.name == name).collect()
+    }
+
+    /// The span's parent, if it is a child and the parent is in the slice
+    #[must_use]
+    pub fn parent(&self, span: &Span) -> Option<&'a Span> {
+        let parent_id = span.context.parent_span_id()?;
+        self.find_by_id(span.context.trace_id, parent_id)
+    }
+
+    /// Direct children of `span`, ordered by start time
+    #[must_use]
+    pub fn children(&self, span: &Span) -> Vec<&'a Span> {
+        let mut children: Vec<&Span> = self
+            .spans
+            .iter()
+            .filter(|s| {
+                s.context.trace_id == span.context.trace_id
+                    && s.context.parent_span_id() == Some(span.context.span_id)
+            })
+            .collect();
+        children.sort_by_key(|s| s.start_time_ms());
+        children
+    }
+
+    /// Root spans, ordered by start time
+    #[must_use]
+    pub fn roots(&self) -> Vec<&'a Span> {
+        let mut roots: Vec<&Span> = self.spans.iter().filter(|s| s.context.is_root()).collect();
+        roots.sort_by_key(|s| s.start_time_ms());
+        roots
+    }
+
+    /// Child spans whose parent is not in the slice, in slice order
+    #[must_use]
+    pub fn orphans(&self) -> Vec<&'a Span> {
+        self.spans
+            .iter()
+            .filter(|s| s.context.is_child() && self.parent(s).is_none())
+            .collect()
+    }
+
+    /// Depth of a span: 1 for a root or orphan, 2 for its children, and so on
+    ///
+    /// Parent cycles (malformed input) stop counting after `spans.len()` levels.
+    #[must_use]
+    pub fn depth_of(&self, span: &Span) -> usize {
+        let mut depth = 1;
+        let mut current = span;
+        while let Some(parent) = self.parent(current) {
+            if depth > self.spans.len() {
+                break;
+            }
+            depth += 1;
+            current = parent;
+        }
+        depth
+    }
+
+    /// Depth of the deepest span (0 for no spans)
+    #[must_use]
+    pub fn depth(&self) -> usize {
+        self.spans.iter().map(|s| self.depth_of(s)).max().unwrap_or(0)
+    }
+
+    /// Indented rendering of the tree, orphans listed last
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        for root in self.roots() {
+            self.render_node(&mut out, root, 0);
+        }
+        for orphan in self.orphans() {
+            let parent = orphan.context.parent_span_id().map_or(0, |id| id.0);
+            let _ = writeln!(out, "⚠️  orphan (parent {parent:#x} missing):");
+            self.render_node(&mut out, orphan, 1);
+        }
+        out
+    }
+
+    fn render_node(&self, out: &mut String, span: &Span, level: usize) {
+        let _ = writeln!(out, "{}{} @{}ms", "  ".repeat(level), span.name, span.start_time_ms());
+        // Depth bound guards against parent cycles
+        if level < self.spans.len() {
+            for child in self.children(span) {
+                self.render_node(out, child, level + 1);
+            }
+        }
+    }
+
+    fn fail(&self, message: &str) -> OtelValidationError {
+        OtelValidationError::TraceAssertionFailed(format!("{message}\n{}", self.render()))
+    }
+
+    /// Check that every span named `child` is a direct child of a span named `parent`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OtelValidationError::TraceAssertionFailed`] if there is no `child` span,
+    /// or one of them has another parent (or none).
+    pub fn check_child_of(&self, child: &str, parent: &str) -> OtelValidationResult<()> {
+        let children = self.spans_named(child);
+        if children.is_empty() {
+            return Err(self.fail(&format!("no span named '{child}'")));
+        }
+        for span in children {
+            match self.parent(span) {
+                Some(actual) if actual.name == parent => {}
+                Some(actual) => {
+                    return Err(self.fail(&format!(
+                        "'{child}' is a child of '{}', expected '{parent}'",
+                        actual.name
+                    )))
+                }
+                None if span.context.is_root() => {
+                    return Err(self.fail(&format!(
+                        "'{child}' is a root span, expected a child of '{parent}'"
+                    )))
+                }
+                None => {
+                    return Err(self.fail(&format!(
+                    "'{child}' is orphaned (its parent is missing), expected a child of '{parent}'"
+                )))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the first span of each name starts in the given order
+    ///
+    /// Each span must start at or after the previous one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OtelValidationError::TraceAssertionFailed`] if a name has no span or the
+    /// spans started out of order.
+    pub fn check_span_order(&self, names: &[&str]) -> OtelValidationResult<()> {
+        let mut previous: Option<&Span> = None;
+        for name in names {
+            let span = self
+                .spans
+                .iter()
+                .filter(|s| s.name == *name)
+                .min_by_key(|s| s.start_time_ms())
+                .ok_or_else(|| self.fail(&format!("no span named '{name}'")))?;
+            if let Some(before) = previous {
+                if span.start_time_ms() < before.start_time_ms() {
+                    return Err(self.fail(&format!(
+                        "'{name}' started at {}ms, before '{}' at {}ms",
+                        span.start_time_ms(),
+                        before.name,
+                        before.start_time_ms()
+                    )));
+                }
+            }
+            previous = Some(span);
+        }
+        Ok(())
+    }
+
+    /// Check that no span is nested deeper than `max_depth` (roots are depth 1)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OtelValidationError::TraceAssertionFailed`] naming the deepest span.
+    pub fn check_trace_depth_at_most(&self, max_depth: usize) -> OtelValidationResult<()> {
+        match self.spans.iter().max_by_key(|s| self.depth_of(s)) {
+            Some(deepest) if self.depth_of(deepest) > max_depth => Err(self.fail(&format!(
+                "trace depth {} exceeds {max_depth} (deepest span '{}')",
+                self.depth_of(deepest),
+                deepest.name
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that every child span's parent is in the slice
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OtelValidationError::TraceAssertionFailed`] listing the orphaned spans.
+    pub fn check_no_orphans(&self) -> OtelValidationResult<()> {
+        let orphans = self.orphans();
+        if orphans.is_empty() {
+            return Ok(());
+        }
+        let names: Vec<&str> = orphans.iter().map(|s| s.name.as_str()).collect();
+        Err(self.fail(&format!("{} orphaned span(s): {}", names.len(), names.join(", "))))
+    }
+
+    /// Assert [`Self::check_child_of`]
+    ///
+    /// # Panics
+    ///
+    /// Panics with the rendered tree if the check fails.
+    #[allow(clippy::must_use_candidate)] // Called for the assertion; returns self for chaining
+    pub fn assert_child_of(&self, child: &str, parent: &str) -> &Self {
+        Self::assert(self.check_child_of(child, parent));
+        self
+    }
+
+    /// Assert [`Self::check_span_order`]
+    ///
+    /// # Panics
+    ///
+    /// Panics with the rendered tree if the check fails.
+    #[allow(clippy::must_use_candidate)] // Called for the assertion; returns self for chaining
+    pub fn assert_span_order(&self, names: &[&str]) -> &Self {
+        Self::assert(self.check_span_order(names));
+        self
+    }
+
+    /// Assert [`Self::check_trace_depth_at_most`]
+    ///
+    /// # Panics
+    ///
+    /// Panics with the rendered tree if the check fails.
+    #[allow(clippy::must_use_candidate)] // Called for the assertion; returns self for chaining
+    pub fn assert_trace_depth_at_most(&self, max_depth: usize) -> &Self {
+        Self::assert(self.check_trace_depth_at_most(max_depth));
+        self
+    }
+
+    /// Assert [`Self::check_no_orphans`]
+    ///
+    /// # Panics
+    ///
+    /// Panics with the rendered tree if the check fails.
+    #[allow(clippy::must_use_candidate)] // Called for the assertion; returns self for chaining
+    pub fn assert_no_orphans(&self) -> &Self {
+        Self::assert(self.check_no_orphans());
+        self
+    }
+
+    #[allow(clippy::panic)] // Test helper - panic is appropriate
+    fn assert(result: OtelValidationResult<()>) {
+        if let Err(e) = result {
+            panic!("{e}");
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Test code - unwrap is acceptable
+mod tests {
+    use super::*;
+    use crate::observability::otel::types::{Attributes, SpanContext, SpanStatus};
+
+    const TRACE: TraceId = TraceId(42);
+
+    fn span(name: &str, id: u64, parent: Option<u64>, start: u64) -> Span {
+        let context = parent.map_or_else(
+            || SpanContext::root(TRACE, SpanId(id), 1),
+            |parent| SpanContext::child(TRACE, SpanId(id), SpanId(parent), 1),
+        );
+        Span::new_completed(
+            context,
+            name.to_string(),
+            start,
+            start + 10,
+            Attributes::new(),
+            Vec::new(),
+            SpanStatus::Ok,
+        )
+        .unwrap()
+    }
+
+    fn request_trace() -> Vec<Span> {
+        vec![
+            span("serialize", 4, Some(3), 30),
+            span("db.query", 2, Some(1), 10),
+            span("handle_request", 1, None, 0),
+            span("render", 3, Some(1), 20),
+        ]
+    }
+
+    #[test]
+    fn test_tree_is_rebuilt_from_unordered_spans() {
+        // Arrange
+        let spans = request_trace();
+        let trace = TraceAssertions::new(&spans);
+
+        // Act
+        let roots = trace.roots();
+        let children = trace.children(roots[0]);
+
+        // Assert
+        assert_eq!(roots.len(), 1);
+        let names: Vec<&str> = children.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["db.query", "render"]);
+        assert_eq!(trace.depth(), 3);
+        assert_eq!(
+            trace.render(),
+            "handle_request @0ms\n  db.query @10ms\n  render @20ms\n    serialize @30ms\n"
+        );
+        trace
+            .assert_child_of("db.query", "handle_request")
+            .assert_span_order(&["handle_request", "db.query", "render"])
+            .assert_trace_depth_at_most(3)
+            .assert_no_orphans();
+    }
+
+    #[test]
+    fn test_checks_report_violations_with_tree() {
+        // Arrange
+        let spans = request_trace();
+        let trace = TraceAssertions::new(&spans);
+
+        // Act
+        let wrong_parent = trace.check_child_of("serialize", "handle_request").unwrap_err();
+        let root = trace.check_child_of("handle_request", "render").unwrap_err();
+        let order = trace.check_span_order(&["render", "db.query"]).unwrap_err();
+        let depth = trace.check_trace_depth_at_most(2).unwrap_err();
+
+        // Assert
+        assert!(wrong_parent.to_string().contains("'serialize' is a child of 'render'"));
+        assert!(wrong_parent.to_string().contains("    serialize @30ms"));
+        assert!(root.to_string().contains("'handle_request' is a root span"));
+        assert!(order
+            .to_string()
+            .contains("'db.query' started at 10ms, before 'render' at 20ms"));
+        assert!(depth.to_string().contains("trace depth 3 exceeds 2 (deepest span 'serialize')"));
+        assert!(trace.check_span_order(&["missing"]).is_err());
+    }
+
+    #[test]
+    fn test_orphaned_spans_are_detected() {
+        // Arrange
+        let mut spans = request_trace();
+        spans.push(span("cache.get", 9, Some(99), 15));
+        let trace = TraceAssertions::new(&spans);
+
+        // Act
+        let result = trace.check_no_orphans();
+
+        // Assert
+        let message = result.unwrap_err().to_string();
+        assert!(message.contains("1 orphaned span(s): cache.get"), "{message}");
+        assert!(message.contains("orphan (parent 0x63 missing):\n  cache.get @15ms"), "{message}");
+        assert!(trace.check_child_of("cache.get", "handle_request").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "no span named 'db.write'")]
+    fn test_assert_child_of_panics_for_missing_span() {
+        let spans = request_trace();
+        TraceAssertions::new(&spans).assert_child_of("db.write", "handle_request");
+    }
+}
@@ -342,6 +342,16 @@ pub enum MetricValue {
     Gauge(f64),
     /// Histogram metric (distribution of values)
     Histogram(Vec<u64>),
+    /// Explicit-bucket histogram, as exported by the OpenTelemetry SDK
+    ///
+    /// `bucket_counts[i]` counts observations `<= bounds[i]` (and above the previous
+    /// bound); the last count is the overflow bucket, so it has one more entry than `bounds`.
+    BucketedHistogram {
+        /// Upper bucket bounds, strictly increasing
+        bounds: Vec<f64>,
+        /// Observation count per bucket (`bounds.len() + 1` entries)
+        bucket_counts: Vec<u64>,
+    },
 }
 
 /// Metric
@@ -357,6 +367,25 @@ pub struct Metric {
     pub attributes: Attributes,
 }
 
+/// Log record
+#[derive(Debug, Clone)]
+pub struct LogRecord {
+    /// Timestamp in milliseconds since epoch (observed time if the event time is unset)
+    pub timestamp_ms: u64,
+    /// Severity number (1-24, 0 if unspecified)
+    pub severity_number: i32,
+    /// Severity text (e.g. `ERROR`)
+    pub severity_text: String,
+    /// Log body rendered as text
+    pub body: String,
+    /// Log attributes (key-value pairs)
+    pub attributes: Attributes,
+    /// Trace the record was emitted in, if any
+    pub trace_id: Option<TraceId>,
+    /// Span the record was emitted in, if any
+    pub span_id: Option<SpanId>,
+}
+
 #[cfg(test)]
 #[allow(clippy::panic)] // Test code - panic is appropriate for test failures
 mod tests {
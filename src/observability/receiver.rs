@@ -0,0 +1,619 @@
+//! Embedded OTLP Test Receiver
+//!
+//! [`OtlpTestReceiver`] binds a local OTLP endpoint inside the test process and captures
+//! everything the application exports — spans, metrics, and logs — for assertions with
+//! [`SpanValidator`](crate::otel::SpanValidator), [`TraceAssertions`](crate::otel::TraceAssertions),
+//! and [`MetricValidator`](crate::otel::MetricValidator). No Weaver binary, no Docker.
+//!
+//! One port serves both transports:
+//! - **OTLP/HTTP**: `POST /v1/traces`, `/v1/metrics`, `/v1/logs` with protobuf or JSON bodies
+//! - **OTLP/gRPC**: the `Export` methods of the trace, metrics, and logs services over
+//!   cleartext HTTP/2 (uncompressed messages only)
+//!
+//! Point exporters at [`OtlpTestReceiver::endpoint`] (gRPC, or the OTLP/HTTP base URL) or
+//! the per-signal URLs. Exporters usually send asynchronously, so wait for telemetry with
+//! [`OtlpTestReceiver::wait_for_spans`] and friends; the waits honour
+//! [`TestCancellation`](crate::core::cancellation::TestCancellation).
+//!
+//! **Required feature**: `otlp-receiver`
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::observability::receiver::OtlpTestReceiver;
+//! use chicago_tdd_tools::otel::TraceAssertions;
+//! use std::time::Duration;
+//!
+//! let receiver = OtlpTestReceiver::start().unwrap();
+//! // Configure the application's exporter with receiver.endpoint()
+//! // (or OTEL_EXPORTER_OTLP_ENDPOINT) and exercise it ...
+//!
+//! let spans = receiver.wait_for_spans(2, Duration::from_secs(5)).unwrap();
+//! TraceAssertions::new(&spans).assert_child_of("db.query", "handle_request");
+//! ```
+
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::fmt;
+use std::net::{Ipv4Addr, SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use axum::body::{to_bytes, Body, Bytes};
+use axum::extract::{Request, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{HeaderMap, HeaderValue, Method, StatusCode};
+use axum::response::Response;
+use axum::Router;
+use http_body_util::{BodyExt, Full};
+use thiserror::Error;
+
+use crate::core::cancellation::{cancellable_sleep, Cancelled};
+use crate::observability::otel::otlp::{self, OtlpEncoding};
+use crate::observability::otel::types::{Attributes, LogRecord, Metric, Span};
+use crate::observability::otel::MetricSnapshot;
+
+/// Largest export request the receiver accepts
+const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// How often `wait_for_*` re-checks captured telemetry
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// OTLP test receiver errors
+#[derive(Error, Debug)]
+pub enum OtlpReceiverError {
+    /// Could not bind the local listener
+    #[error("🚨 Failed to bind OTLP test receiver: {0}")]
+    Bind(#[source] std::io::Error),
+    /// Could not start the receiver runtime
+    #[error("🚨 Failed to start OTLP test receiver runtime: {0}")]
+    Runtime(#[source] std::io::Error),
+    /// Telemetry did not arrive in time
+    #[error("🚨 Timed out after {waited:?} waiting for {expected} {signal}, received {received}\n   ⚠️  STOP: The application did not export the expected telemetry\n   💡 FIX: Check the exporter endpoint ({endpoint}) and flush the provider before asserting{rejected}")]
+    Timeout {
+        /// Signal waited for (`spans`, `metrics`, or `logs`)
+        signal: &'static str,
+        /// Number of items expected
+        expected: usize,
+        /// Number of items received
+        received: usize,
+        /// How long the receiver waited
+        waited: Duration,
+        /// Receiver endpoint
+        endpoint: String,
+        /// Rejected payloads, pre-rendered for the message (empty if none)
+        rejected: String,
+    },
+    /// The wait was cancelled
+    #[error("🚨 {0}")]
+    Cancelled(#[from] Cancelled),
+}
+
+/// Result type for OTLP test receiver operations
+pub type OtlpReceiverResult<T> = Result<T, OtlpReceiverError>;
+
+/// Telemetry signal carried by an export request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Signal {
+    Traces,
+    Metrics,
+    Logs,
+}
+
+impl Signal {
+    /// Signal and whether the path is a gRPC method
+    fn from_path(path: &str) -> Option<(Self, bool)> {
+        match path {
+            "/v1/traces" => Some((Self::Traces, false)),
+            "/v1/metrics" => Some((Self::Metrics, false)),
+            "/v1/logs" => Some((Self::Logs, false)),
+            "/opentelemetry.proto.collector.trace.v1.TraceService/Export" => {
+                Some((Self::Traces, true))
+            }
+            "/opentelemetry.proto.collector.metrics.v1.MetricsService/Export" => {
+                Some((Self::Metrics, true))
+            }
+            "/opentelemetry.proto.collector.logs.v1.LogsService/Export" => Some((Self::Logs, true)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Captured {
+    spans: Vec<Span>,
+    metrics: Vec<Metric>,
+    logs: Vec<LogRecord>,
+    rejected: Vec<String>,
+}
+
+#[derive(Debug, Default)]
+struct ReceiverState {
+    captured: Mutex<Captured>,
+}
+
+impl ReceiverState {
+    fn captured(&self) -> MutexGuard<'_, Captured> {
+        self.captured.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Decode and store one export request, recording it as rejected if it fails to decode
+    fn ingest(&self, signal: Signal, payload: &[u8], encoding: OtlpEncoding) -> Result<(), String> {
+        let decoded = match signal {
+            Signal::Traces => otlp::decode_spans(payload, encoding)
+                .map(|spans| self.captured().spans.extend(spans)),
+            Signal::Metrics => otlp::decode_metrics(payload, encoding)
+                .map(|metrics| self.captured().metrics.extend(metrics)),
+            Signal::Logs => {
+                otlp::decode_logs(payload, encoding).map(|logs| self.captured().logs.extend(logs))
+            }
+        };
+        decoded.map_err(|error| {
+            let message = error.to_string();
+            self.captured().rejected.push(message.clone());
+            message
+        })
+    }
+}
+
+async fn handle(State(state): State<Arc<ReceiverState>>, request: Request) -> Response {
+    let (parts, body) = request.into_parts();
+    let Some((signal, grpc)) = Signal::from_path(parts.uri.path()) else {
+        return plain(StatusCode::NOT_FOUND, format!("no OTLP endpoint at {}", parts.uri.path()));
+    };
+    if parts.method != Method::POST {
+        return plain(StatusCode::METHOD_NOT_ALLOWED, "OTLP export requests use POST");
+    }
+    let Ok(body) = to_bytes(body, MAX_BODY_BYTES).await else {
+        return plain(StatusCode::PAYLOAD_TOO_LARGE, "export request too large");
+    };
+    let content_type = parts
+        .headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    if grpc {
+        return match grpc_message(&body) {
+            Ok(message) => match state.ingest(signal, message, OtlpEncoding::Protobuf) {
+                Ok(()) => grpc_response(0, ""),
+                // INVALID_ARGUMENT
+                Err(error) => grpc_response(3, &error),
+            },
+            // UNIMPLEMENTED
+            Err(error) => grpc_response(12, error),
+        };
+    }
+    if parts.headers.contains_key("content-encoding") {
+        return plain(
+            StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            "compressed export requests are not supported",
+        );
+    }
+    let encoding = OtlpEncoding::from_content_type(content_type).unwrap_or(OtlpEncoding::Protobuf);
+    match state.ingest(signal, &body, encoding) {
+        // An empty message is a valid (empty) Export*ServiceResponse in either encoding
+        Ok(()) => match encoding {
+            OtlpEncoding::Protobuf => {
+                with_content_type(StatusCode::OK, "application/x-protobuf", "")
+            }
+            OtlpEncoding::Json => with_content_type(StatusCode::OK, "application/json", "{}"),
+        },
+        Err(error) => plain(StatusCode::BAD_REQUEST, error),
+    }
+}
+
+/// Payload of a single length-prefixed gRPC message
+fn grpc_message(body: &[u8]) -> Result<&[u8], &'static str> {
+    let (header, rest) = body.split_at_checked(5).ok_or("truncated gRPC message")?;
+    if header[0] != 0 {
+        return Err("compressed gRPC messages are not supported");
+    }
+    let len = u32::from_be_bytes([header[1], header[2], header[3], header[4]]);
+    let len = usize::try_from(len).map_err(|_| "gRPC message too large")?;
+    rest.get(..len).ok_or("truncated gRPC message")
+}
+
+fn grpc_response(status: u8, message: &str) -> Response {
+    let mut trailers = HeaderMap::new();
+    trailers.insert("grpc-status", HeaderValue::from(u16::from(status)));
+    if let Ok(message) = HeaderValue::from_str(message) {
+        if !message.is_empty() {
+            trailers.insert("grpc-message", message);
+        }
+    }
+    // A successful unary call carries one (empty) response message
+    let data = if status == 0 { Bytes::from_static(&[0, 0, 0, 0, 0]) } else { Bytes::new() };
+    let body = Full::new(data).with_trailers(async move { Some(Ok::<_, Infallible>(trailers)) });
+    let mut response = Response::new(Body::new(body));
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/grpc"));
+    response
+}
+
+fn with_content_type(
+    status: StatusCode,
+    content_type: &'static str,
+    body: impl Into<Body>,
+) -> Response {
+    let mut response = Response::new(body.into());
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    response
+}
+
+fn plain(status: StatusCode, message: impl Into<String>) -> Response {
+    with_content_type(status, "text/plain; charset=utf-8", message.into())
+}
+
+/// Running in-process OTLP endpoint that captures exported telemetry
+///
+/// The receiver stops when dropped.
+pub struct OtlpTestReceiver {
+    addr: SocketAddr,
+    state: Arc<ReceiverState>,
+    shutdown: Option<tokio::sync::oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl fmt::Debug for OtlpTestReceiver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let captured = self.state.captured();
+        f.debug_struct("OtlpTestReceiver")
+            .field("addr", &self.addr)
+            .field("spans", &captured.spans.len())
+            .field("metrics", &captured.metrics.len())
+            .field("logs", &captured.logs.len())
+            .field("rejected", &captured.rejected.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl OtlpTestReceiver {
+    /// Bind `127.0.0.1` on a free port and start receiving on a background thread
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the listener cannot be bound or the runtime cannot start.
+    pub fn start() -> OtlpReceiverResult<Self> {
+        let listener =
+            TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).map_err(OtlpReceiverError::Bind)?;
+        let addr = listener.local_addr().map_err(OtlpReceiverError::Bind)?;
+        listener.set_nonblocking(true).map_err(OtlpReceiverError::Bind)?;
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_io()
+            .build()
+            .map_err(OtlpReceiverError::Runtime)?;
+        let listener = {
+            let _entered = runtime.enter();
+            tokio::net::TcpListener::from_std(listener).map_err(OtlpReceiverError::Bind)?
+        };
+        let state = Arc::new(ReceiverState::default());
+        let app = Router::new().fallback(handle).with_state(Arc::clone(&state));
+        let (shutdown, signal) = tokio::sync::oneshot::channel::<()>();
+        let thread = std::thread::spawn(move || {
+            runtime.block_on(async move {
+                let served = axum::serve(listener, app)
+                    .with_graceful_shutdown(async {
+                        let _ = signal.await;
+                    })
+                    .await;
+                if let Err(error) = served {
+                    crate::alert_warning!(
+                        format!("OTLP test receiver stopped with an error: {error}"),
+                        "Check the test's network environment"
+                    );
+                }
+            });
+        });
+        Ok(Self { addr, state, shutdown: Some(shutdown), thread: Some(thread) })
+    }
+
+    /// Socket address the receiver listens on
+    #[must_use]
+    pub const fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// Endpoint URL, e.g. `http://127.0.0.1:41234`
+    ///
+    /// Use it as the gRPC exporter endpoint, or as `OTEL_EXPORTER_OTLP_ENDPOINT` for
+    /// OTLP/HTTP exporters (which append `/v1/<signal>`).
+    #[must_use]
+    pub fn endpoint(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+
+    /// OTLP/HTTP traces URL (`<endpoint>/v1/traces`)
+    #[must_use]
+    pub fn traces_url(&self) -> String {
+        format!("{}/v1/traces", self.endpoint())
+    }
+
+    /// OTLP/HTTP metrics URL (`<endpoint>/v1/metrics`)
+    #[must_use]
+    pub fn metrics_url(&self) -> String {
+        format!("{}/v1/metrics", self.endpoint())
+    }
+
+    /// OTLP/HTTP logs URL (`<endpoint>/v1/logs`)
+    #[must_use]
+    pub fn logs_url(&self) -> String {
+        format!("{}/v1/logs", self.endpoint())
+    }
+
+    /// Spans received so far, in arrival order
+    #[must_use]
+    pub fn spans(&self) -> Vec<Span> {
+        self.state.captured().spans.clone()
+    }
+
+    /// Metric data points received so far, in arrival order
+    #[must_use]
+    pub fn metrics(&self) -> Vec<Metric> {
+        self.state.captured().metrics.clone()
+    }
+
+    /// Latest data point per metric name and attribute set
+    ///
+    /// With cumulative exporters (the default) this is the current value of every
+    /// instrument; diff two of these with [`MetricSnapshot::since`].
+    #[must_use]
+    pub fn metric_snapshot(&self) -> MetricSnapshot {
+        let mut latest: BTreeMap<(String, Attributes), Metric> = BTreeMap::new();
+        for metric in &self.state.captured().metrics {
+            latest.insert((metric.name.clone(), metric.attributes.clone()), metric.clone());
+        }
+        MetricSnapshot::new(latest.into_values().collect())
+    }
+
+    /// Log records received so far, in arrival order
+    #[must_use]
+    pub fn logs(&self) -> Vec<LogRecord> {
+        self.state.captured().logs.clone()
+    }
+
+    /// Decode errors for export requests the receiver rejected
+    #[must_use]
+    pub fn rejected(&self) -> Vec<String> {
+        self.state.captured().rejected.clone()
+    }
+
+    /// Forget all captured telemetry
+    pub fn clear(&self) {
+        *self.state.captured() = Captured::default();
+    }
+
+    /// Wait until at least `count` spans have arrived, then return all spans
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OtlpReceiverError::Timeout`] if fewer arrive within `timeout`, or
+    /// [`OtlpReceiverError::Cancelled`] if the current test is cancelled.
+    pub fn wait_for_spans(&self, count: usize, timeout: Duration) -> OtlpReceiverResult<Vec<Span>> {
+        self.wait_for("spans", count, timeout, |captured| &captured.spans)
+    }
+
+    /// Wait until at least `count` metric data points have arrived, then return them all
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OtlpReceiverError::Timeout`] if fewer arrive within `timeout`, or
+    /// [`OtlpReceiverError::Cancelled`] if the current test is cancelled.
+    pub fn wait_for_metrics(
+        &self,
+        count: usize,
+        timeout: Duration,
+    ) -> OtlpReceiverResult<Vec<Metric>> {
+        self.wait_for("metrics", count, timeout, |captured| &captured.metrics)
+    }
+
+    /// Wait until at least `count` log records have arrived, then return them all
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OtlpReceiverError::Timeout`] if fewer arrive within `timeout`, or
+    /// [`OtlpReceiverError::Cancelled`] if the current test is cancelled.
+    pub fn wait_for_logs(
+        &self,
+        count: usize,
+        timeout: Duration,
+    ) -> OtlpReceiverResult<Vec<LogRecord>> {
+        self.wait_for("logs", count, timeout, |captured| &captured.logs)
+    }
+
+    fn wait_for<T: Clone>(
+        &self,
+        signal: &'static str,
+        count: usize,
+        timeout: Duration,
+        select: impl Fn(&Captured) -> &Vec<T>,
+    ) -> OtlpReceiverResult<Vec<T>> {
+        let started = Instant::now();
+        let label = format!("OTLP receiver {signal}");
+        loop {
+            let captured = self.state.captured();
+            let items = select(&captured);
+            if items.len() >= count {
+                return Ok(items.clone());
+            }
+            if started.elapsed() >= timeout {
+                let received = items.len();
+                let rejected = captured.rejected.iter().fold(String::new(), |mut out, r| {
+                    out.push_str("\n   Rejected: ");
+                    out.push_str(r.lines().next().unwrap_or_default());
+                    out
+                });
+                drop(captured);
+                return Err(OtlpReceiverError::Timeout {
+                    signal,
+                    expected: count,
+                    received,
+                    waited: started.elapsed(),
+                    endpoint: self.endpoint(),
+                    rejected,
+                });
+            }
+            drop(captured);
+            cancellable_sleep(&label, POLL_INTERVAL)?;
+        }
+    }
+}
+
+impl Drop for OtlpTestReceiver {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::io::{Read, Write};
+    use std::net::TcpStream;
+
+    /// Minimal HTTP/1.1 client: returns (status, body)
+    fn post(
+        receiver: &OtlpTestReceiver,
+        path: &str,
+        content_type: &str,
+        body: &[u8],
+    ) -> (u16, String) {
+        let mut stream = TcpStream::connect(receiver.addr()).unwrap();
+        write!(
+            stream,
+            "POST {path} HTTP/1.1\r\nHost: localhost\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+            body.len()
+        )
+        .unwrap();
+        stream.write_all(body).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let body = response.split_once("\r\n\r\n").map(|(_, b)| b.to_string()).unwrap();
+        (status, body)
+    }
+
+    const SPANS_JSON: &str = r#"{"resourceSpans":[{"scopeSpans":[{"spans":[
+        {"traceId":"5b8efff798038103d269b633813fc60c","spanId":"eee19b7ec3c1b174",
+         "name":"handle_request","startTimeUnixNano":"1000000000","endTimeUnixNano":"1050000000"}
+    ]}]}]}"#;
+
+    test!(test_http_json_and_protobuf_exports_are_captured, {
+        // Arrange
+        let receiver = OtlpTestReceiver::start().unwrap();
+        let metrics = r#"{"resourceMetrics":[{"scopeMetrics":[{"metrics":[{"name":"http.requests","sum":{"isMonotonic":true,"dataPoints":[{"asInt":"3"}]}}]}]}]}"#;
+
+        // Act
+        let traces = post(&receiver, "/v1/traces", "application/json", SPANS_JSON.as_bytes());
+        let metrics = post(&receiver, "/v1/metrics", "application/json", metrics.as_bytes());
+        let empty_logs = post(&receiver, "/v1/logs", "application/x-protobuf", &[]);
+
+        // Assert
+        assert_eq!(traces, (200, "{}".to_string()));
+        assert_eq!(metrics.0, 200);
+        assert_eq!(empty_logs, (200, String::new()));
+        let spans = receiver.wait_for_spans(1, Duration::from_secs(1)).unwrap();
+        assert_eq!(spans[0].name, "handle_request");
+        assert_eq!(receiver.metric_snapshot().counter("http.requests"), Some(3));
+        assert!(receiver.logs().is_empty());
+        assert!(receiver.rejected().is_empty());
+    });
+
+    test!(test_malformed_exports_are_rejected_and_reported, {
+        // Arrange
+        let receiver = OtlpTestReceiver::start().unwrap();
+
+        // Act
+        let rejected = post(&receiver, "/v1/traces", "application/json", b"{not json");
+        let unknown = post(&receiver, "/v1/profiles", "application/json", b"{}");
+        let timeout = receiver.wait_for_spans(1, Duration::from_millis(50)).unwrap_err();
+
+        // Assert
+        assert_eq!(rejected.0, 400);
+        assert_eq!(unknown.0, 404);
+        assert_eq!(receiver.rejected().len(), 1);
+        let message = timeout.to_string();
+        assert!(message.contains("waiting for 1 spans, received 0"), "{message}");
+        assert!(message.contains("Rejected: 🚨 OTLP decode failed"), "{message}");
+    });
+
+    test!(test_grpc_export_is_captured, {
+        // Arrange
+        use hyper_util::rt::{TokioExecutor, TokioIo};
+        use opentelemetry_proto::tonic::collector::trace::v1::ExportTraceServiceRequest;
+        use opentelemetry_proto::tonic::trace::v1::{ResourceSpans, ScopeSpans, Span as ProtoSpan};
+        use prost::Message;
+
+        let receiver = OtlpTestReceiver::start().unwrap();
+        let span = ProtoSpan {
+            trace_id: vec![1; 16],
+            span_id: vec![2; 8],
+            name: "grpc.export".to_string(),
+            start_time_unix_nano: 1_000_000,
+            end_time_unix_nano: 2_000_000,
+            ..ProtoSpan::default()
+        };
+        let message = ExportTraceServiceRequest {
+            resource_spans: vec![ResourceSpans {
+                scope_spans: vec![ScopeSpans { spans: vec![span], ..ScopeSpans::default() }],
+                ..ResourceSpans::default()
+            }],
+        }
+        .encode_to_vec();
+        let mut framed = vec![0];
+        framed.extend_from_slice(&u32::try_from(message.len()).unwrap().to_be_bytes());
+        framed.extend_from_slice(&message);
+        let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap();
+
+        // Act
+        let (status, trailers) = runtime.block_on(async {
+            let stream = tokio::net::TcpStream::connect(receiver.addr()).await.unwrap();
+            let (mut sender, connection) =
+                hyper::client::conn::http2::handshake(TokioExecutor::new(), TokioIo::new(stream))
+                    .await
+                    .unwrap();
+            tokio::spawn(connection);
+            let request = axum::http::Request::post(format!(
+                "{}/opentelemetry.proto.collector.trace.v1.TraceService/Export",
+                receiver.endpoint()
+            ))
+            .header(CONTENT_TYPE, "application/grpc")
+            .header("te", "trailers")
+            .body(Full::new(Bytes::from(framed)))
+            .unwrap();
+            let response = sender.send_request(request).await.unwrap();
+            let status = response.status();
+            let collected = response.into_body().collect().await.unwrap();
+            (status, collected.trailers().cloned())
+        });
+
+        // Assert
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(trailers.unwrap()["grpc-status"], "0");
+        assert_eq!(receiver.spans()[0].name, "grpc.export");
+    });
+
+    test!(test_grpc_message_framing, {
+        // Arrange
+        let framed = [0, 0, 0, 0, 3, b'a', b'b', b'c'];
+
+        // Act & Assert
+        assert_eq!(grpc_message(&framed), Ok(&b"abc"[..]));
+        assert!(grpc_message(&[1, 0, 0, 0, 0]).is_err());
+        assert!(grpc_message(&[0, 0, 0, 0, 9, 1]).is_err());
+        assert_eq!(
+            Signal::from_path("/opentelemetry.proto.collector.logs.v1.LogsService/Export"),
+            Some((Signal::Logs, true))
+        );
+    });
+}
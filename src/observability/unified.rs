@@ -30,6 +30,8 @@ use std::path::PathBuf;
 use std::process::Child;
 use thiserror::Error;
 
+#[cfg(feature = "otel")]
+use crate::observability::expectations::SpanExpectations;
 #[cfg(all(feature = "weaver", feature = "otel"))]
 use crate::observability::fixtures::ValidationResults;
 #[cfg(feature = "otel")]
@@ -69,6 +71,10 @@ pub enum ObservabilityError {
     #[cfg(feature = "otel")]
     #[error("🚨 Metric validation failed: {0}")]
     MetricValidationFailed(String),
+    /// Declared span expectations were not met
+    #[cfg(feature = "otel")]
+    #[error("🚨 {0}\n   ⚠️  STOP: Captured telemetry does not match the declared expectations\n   💡 FIX: Instrument the missing spans, or declare the unexpected ones with expect_spans")]
+    SpanExpectationsNotMet(String),
     /// Required feature disabled
     #[error(
         "🚨 Required feature disabled: {0}\n   ⚠️  STOP: Enable required feature to use observability tools\n   💡 FIX: Enable the `{0}` feature in Cargo.toml"
@@ -98,9 +104,9 @@ pub struct ValidationState {
 pub struct TestConfig {
     /// Registry path for Weaver validation
     pub registry_path: Option<PathBuf>,
-    /// OTLP gRPC port
+    /// OTLP gRPC port (`0` allocates a free port when Weaver starts)
     pub otlp_grpc_port: u16,
-    /// Admin port
+    /// Admin port (`0` allocates a free port when Weaver starts)
     pub admin_port: u16,
     /// Enable Weaver validation
     pub weaver_enabled: bool,
@@ -108,6 +114,8 @@ pub struct TestConfig {
     pub compile_time_validation: bool,
     /// Optional directory for Weaver JSON reports
     pub weaver_output_dir: Option<PathBuf>,
+    /// Optional directory of custom Rego advice policies for Weaver live-check
+    pub weaver_policies_dir: Option<PathBuf>,
 }
 
 impl Default for TestConfig {
@@ -119,6 +127,7 @@ impl Default for TestConfig {
             weaver_enabled: false, // Disable by default to avoid auto-detection in unit tests
             compile_time_validation: true,
             weaver_output_dir: None,
+            weaver_policies_dir: None,
         }
     }
 }
@@ -144,10 +153,17 @@ pub struct ObservabilityTest {
     #[cfg(feature = "weaver")]
     #[allow(dead_code)] // Used in Weaver fixture integration
     weaver_output_dir: Option<PathBuf>,
+    /// Ports allocated for Weaver, reserved while the test runs
+    #[cfg(feature = "weaver")]
+    #[allow(dead_code)] // Held for the reservation, never read
+    port_reservations: Vec<crate::core::ports::ReservedPort>,
     /// Cached Weaver validation results
     #[cfg(all(feature = "weaver", feature = "otel"))]
     #[allow(dead_code)] // Used in Weaver fixture integration
     validation_results: Option<ValidationResults>,
+    /// Span expectations declared for the current Arrange/Act/Assert block
+    #[cfg(feature = "otel")]
+    span_expectations: SpanExpectations,
     /// Type-level validation state (`PhantomData` for compile-time guarantees)
     _validation_state: PhantomData<ValidationState>,
 }
@@ -217,6 +233,7 @@ impl WeaverValidator {
         otlp_port: u16,
         admin_port: u16,
         output_dir: Option<&std::path::Path>,
+        policies_dir: Option<&std::path::Path>,
     ) -> ObservabilityResult<Child> {
         use crate::observability::weaver::types::WeaverLiveCheck;
 
@@ -248,6 +265,18 @@ impl WeaverValidator {
             live_check = live_check.with_output("./weaver-reports".to_string());
         }
 
+        if let Some(dir) = policies_dir {
+            if !dir.is_dir() {
+                return Err(ObservabilityError::WeaverStartFailed(format!(
+                    "Rego policies directory does not exist: {}",
+                    dir.display()
+                )));
+            }
+            if let Some(dir_str) = dir.to_str() {
+                live_check = live_check.with_policies(dir_str.to_string());
+            }
+        }
+
         let process = live_check.start().map_err(ObservabilityError::WeaverStartFailed)?;
 
         self.live_check = Some(live_check);
@@ -304,6 +333,23 @@ impl ObservabilityTest {
         let mut weaver_process = None;
         #[cfg(feature = "weaver")]
         let mut weaver_output_dir = None;
+        #[cfg(feature = "weaver")]
+        let mut port_reservations = Vec::new();
+
+        // Replace unset (0) ports with free ones so parallel tests don't collide
+        #[cfg(feature = "weaver")]
+        let mut config = config;
+        #[cfg(feature = "weaver")]
+        if config.weaver_enabled {
+            for port in [&mut config.otlp_grpc_port, &mut config.admin_port] {
+                if *port == 0 {
+                    let reserved = crate::core::ports::PortAllocator::allocate()
+                        .map_err(|e| ObservabilityError::WeaverStartFailed(e.to_string()))?;
+                    *port = reserved.port();
+                    port_reservations.push(reserved);
+                }
+            }
+        }
 
         // Auto-detect registry path if not provided and Weaver is enabled
         #[cfg(feature = "weaver")]
@@ -359,6 +405,7 @@ impl ObservabilityTest {
                     config.otlp_grpc_port,
                     config.admin_port,
                     Some(selected_output_dir.as_path()),
+                    config.weaver_policies_dir.as_deref(),
                 )?;
                 weaver_process = Some(process);
             }
@@ -374,8 +421,12 @@ impl ObservabilityTest {
             weaver_process,
             #[cfg(feature = "weaver")]
             weaver_output_dir,
+            #[cfg(feature = "weaver")]
+            port_reservations,
             #[cfg(all(feature = "weaver", feature = "otel"))]
             validation_results: None,
+            #[cfg(feature = "otel")]
+            span_expectations: SpanExpectations::new(),
             _validation_state: PhantomData,
         })
     }
@@ -418,6 +469,41 @@ impl ObservabilityTest {
         self
     }
 
+    /// Declare spans the code under test must emit (Arrange)
+    ///
+    /// Expectations accumulate across calls until [`verify`](Self::verify) checks them.
+    ///
+    /// ```rust
+    /// # use chicago_tdd_tools::observability::{ObservabilityTest, TestConfig};
+    /// # let mut test = ObservabilityTest::with_config(TestConfig::default()).unwrap();
+    /// test.expect_spans(|e| e.named("db.query").with_attr("db.system"));
+    /// ```
+    #[cfg(feature = "otel")]
+    pub fn expect_spans(
+        &mut self,
+        declare: impl FnOnce(SpanExpectations) -> SpanExpectations,
+    ) -> &mut Self {
+        self.span_expectations.extend(declare(SpanExpectations::new()));
+        self
+    }
+
+    /// Check captured spans against exactly the declared expectations (Assert)
+    ///
+    /// Pass the spans the code under test exported, e.g. from
+    /// `InMemorySpanCollector::spans` or `OtlpTestReceiver::spans`. The expectations are
+    /// consumed, so the next block starts from a clean slate.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ObservabilityError::SpanExpectationsNotMet`] listing missing and
+    /// unexpected spans.
+    #[cfg(feature = "otel")]
+    pub fn verify(&mut self, spans: &[Span]) -> ObservabilityResult<()> {
+        std::mem::take(&mut self.span_expectations)
+            .check(spans)
+            .map_err(ObservabilityError::SpanExpectationsNotMet)
+    }
+
     /// Validate a span
     ///
     /// Performs compile-time validation (if enabled) and runtime validation.
@@ -513,7 +599,10 @@ impl ObservabilityTest {
 
     /// Auto-detect registry path
     ///
-    /// Checks common locations for OpenTelemetry semantic conventions registry.
+    /// Checks common locations for OpenTelemetry semantic conventions registry: the
+    /// workspace and crate roots resolved by `ProjectLayout`, a sibling
+    /// `semantic-conventions` checkout, and finally `registry` under the current directory.
+    /// If none exists, the registry is cloned into `<workspace root>/registry`.
     ///
     /// # Errors
     ///
@@ -521,12 +610,14 @@ impl ObservabilityTest {
     #[allow(dead_code)] // Used in with_config, but compiler doesn't see it due to feature gates
     #[cfg(feature = "otel")]
     fn auto_detect_registry() -> ObservabilityResult<PathBuf> {
+        let layout = crate::core::layout::ProjectLayout::detect();
+
         // Check common registry paths
-        let common_paths = vec![
-            PathBuf::from("registry"),
-            PathBuf::from("./registry"),
-            PathBuf::from("../semantic-conventions"),
-        ];
+        let mut common_paths = vec![layout.registry_dir(), layout.crate_root().join("registry")];
+        if let Some(parent) = layout.workspace_root().parent() {
+            common_paths.push(parent.join("semantic-conventions"));
+        }
+        common_paths.push(PathBuf::from("registry"));
 
         for path in common_paths {
             if path.exists() {
@@ -535,7 +626,7 @@ impl ObservabilityTest {
         }
 
         // Try to clone registry if not found
-        let registry_path = PathBuf::from("registry");
+        let registry_path = layout.registry_dir();
         Self::clone_registry(&registry_path)?;
 
         Ok(registry_path)
@@ -549,10 +640,10 @@ impl ObservabilityTest {
     #[allow(dead_code)] // Used in auto_detect_registry, but compiler doesn't see it due to feature gates
     #[cfg(feature = "otel")]
     fn clone_registry(path: &std::path::Path) -> ObservabilityResult<()> {
-        use std::process::Command;
+        use crate::core::command::{CheckedCommand, DOWNLOAD_COMMAND_TIMEOUT};
 
         // Check if git is available
-        if Command::new("git").arg("--version").output().is_err() {
+        if !CheckedCommand::is_available("git") {
             return Err(ObservabilityError::RegistryNotFound(format!(
                 "{} (git not found for auto-clone)",
                 path.display()
@@ -560,31 +651,20 @@ impl ObservabilityTest {
         }
 
         let registry_url = "https://github.com/open-telemetry/semantic-conventions.git";
-        let registry_str = path
-            .to_str()
-            .ok_or_else(|| {
-                ObservabilityError::RegistryNotFound("Registry path is not valid UTF-8".to_string())
-            })?
-            .to_string();
 
         // Clone with shallow clone for faster download
-        let status = Command::new("git")
-            .args(["clone", "--depth", "1", "--single-branch", registry_url, &registry_str])
-            .status()
+        CheckedCommand::new("git")
+            .args(["clone", "--depth", "1", "--single-branch", registry_url])
+            .arg(path)
+            .timeout(DOWNLOAD_COMMAND_TIMEOUT)
+            .run()
             .map_err(|e| {
                 ObservabilityError::RegistryNotFound(format!(
-                    "{} (failed to clone: {e})",
+                    "{} (git clone failed: {e})",
                     path.display()
                 ))
             })?;
 
-        if !status.success() {
-            return Err(ObservabilityError::RegistryNotFound(format!(
-                "{} (git clone failed)",
-                path.display()
-            )));
-        }
-
         Ok(())
     }
 
@@ -741,6 +821,33 @@ mod tests {
         }
     }
 
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_expect_spans_then_verify_consumes_expectations() {
+        use crate::observability::otel::types::{SpanContext, SpanId, SpanStatus, TraceId};
+
+        let mut test = ObservabilityTest::with_config(TestConfig::default()).unwrap();
+        let mut attributes = crate::observability::otel::types::Attributes::new();
+        attributes.insert("db.system".to_string(), "sqlite".to_string());
+        let span = Span::new_active(
+            SpanContext::root(TraceId(1), SpanId(2), 1),
+            "db.query".to_string(),
+            0,
+            attributes,
+            Vec::new(),
+            SpanStatus::Ok,
+        );
+
+        test.expect_spans(|e| e.named("db.query").with_attr("db.system"));
+        assert!(test.verify(std::slice::from_ref(&span)).is_ok());
+
+        // Next block: nothing declared yet, so the same span is unexpected
+        test.expect_spans(|e| e.named("http.request"));
+        let error = test.verify(&[span]).unwrap_err().to_string();
+        assert!(error.contains("- http.request — no span with this name"), "{error}");
+        assert!(error.contains("unexpected:\n    - db.query"), "{error}");
+    }
+
     #[cfg(feature = "otel")]
     #[test]
     fn test_observability_test_validate_metric() {
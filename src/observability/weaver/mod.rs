@@ -7,10 +7,15 @@
 use crate::observability::weaver::types::WeaverLiveCheck;
 use std::path::{Path, PathBuf};
 use std::process::Child;
+use std::time::Duration;
 use thiserror::Error;
 
 #[cfg(feature = "weaver")]
 pub mod poka_yoke;
+#[cfg(feature = "weaver")]
+pub mod scenario;
+#[cfg(feature = "weaver")]
+pub mod toolchain;
 pub mod types;
 
 /// Poka-yoke types for Weaver process lifecycle
@@ -44,6 +49,10 @@ pub mod lifecycle {
         otlp_grpc_port: u16,
         /// Admin port
         admin_port: u16,
+        /// Custom Rego advice policies directory
+        policies_dir: Option<PathBuf>,
+        /// Allocated ports, reserved for the validator's lifetime
+        port_reservations: Vec<crate::core::ports::ReservedPort>,
         /// Process handle (only Some when Running)
         process: Option<Child>,
         /// State marker (compile-time guarantee)
@@ -77,11 +86,41 @@ pub mod lifecycle {
                 registry_path,
                 otlp_grpc_port: crate::observability::weaver::DEFAULT_OTLP_GRPC_PORT,
                 admin_port: crate::observability::weaver::DEFAULT_ADMIN_PORT,
+                policies_dir: None,
+                port_reservations: Vec::new(),
                 process: None,
                 _state: PhantomData,
             })
         }
 
+        /// Listen on free OTLP gRPC and admin ports instead of 4317/4320
+        ///
+        /// The ports stay reserved (see [`PortAllocator`](crate::core::ports::PortAllocator))
+        /// until the validator is dropped.
+        ///
+        /// # Errors
+        ///
+        /// Returns `PortAllocation` if no free ports are available.
+        pub fn with_free_ports(
+            mut self,
+        ) -> crate::observability::weaver::WeaverValidationResult<Self> {
+            let [otlp, admin] = crate::observability::weaver::allocate_ports()?;
+            self.otlp_grpc_port = otlp.port();
+            self.admin_port = admin.port();
+            self.port_reservations = vec![otlp, admin];
+            Ok(self)
+        }
+
+        /// Evaluate the custom Rego advice policies in `policies_dir` during live-check
+        ///
+        /// Passed to `weaver registry live-check --advice-policies`; the directory is
+        /// checked when the validator starts.
+        #[must_use]
+        pub fn with_policies(mut self, policies_dir: impl Into<PathBuf>) -> Self {
+            self.policies_dir = Some(policies_dir.into());
+            self
+        }
+
         /// Start the Weaver validator
         ///
         /// **Poka-yoke**: Changes type from `WeaverValidator<Stopped>` to `WeaverValidator<Running>`.
@@ -94,9 +133,9 @@ pub mod lifecycle {
             self,
         ) -> crate::observability::weaver::WeaverValidationResult<WeaverValidator<state::Running>>
         {
+            use crate::core::command::CheckedCommand;
             use crate::observability::weaver::types::WeaverLiveCheck;
             use crate::observability::weaver::WeaverValidationError;
-            use std::process::Command;
 
             // Check Docker if testcontainers feature enabled
             #[cfg(feature = "testcontainers")]
@@ -113,8 +152,11 @@ pub mod lifecycle {
             let weaver_binary = WeaverLiveCheck::find_weaver_binary()
                 .ok_or(WeaverValidationError::BinaryNotFound)?;
 
+            let policies =
+                crate::observability::weaver::policies_arg(self.policies_dir.as_deref())?;
+
             // Spawn the Weaver live-check process
-            let child = Command::new(&weaver_binary)
+            let child = CheckedCommand::new(&weaver_binary)
                 .args([
                     "registry",
                     "live-check",
@@ -129,9 +171,10 @@ pub mod lifecycle {
                     "--admin-port",
                     &self.admin_port.to_string(),
                 ])
+                .args(policies.iter().flat_map(|dir| ["--advice-policies", dir.as_str()]))
                 .spawn()
                 .map_err(|e| {
-                    if e.kind() == std::io::ErrorKind::NotFound {
+                    if e.is_not_found() {
                         WeaverValidationError::BinaryNotFound
                     } else {
                         WeaverValidationError::ProcessStartFailed(e.to_string())
@@ -142,6 +185,8 @@ pub mod lifecycle {
                 registry_path: self.registry_path,
                 otlp_grpc_port: self.otlp_grpc_port,
                 admin_port: self.admin_port,
+                policies_dir: self.policies_dir,
+                port_reservations: self.port_reservations,
                 process: Some(child),
                 _state: PhantomData,
             })
@@ -173,28 +218,35 @@ pub mod lifecycle {
         /// **Poka-yoke**: Changes type from `WeaverValidator<Running>` to `WeaverValidator<Stopped>`.
         /// After this call, validator cannot validate telemetry.
         ///
+        /// Asks Weaver to drain via the admin endpoint and waits up to
+        /// [`DEFAULT_DRAIN_TIMEOUT`](crate::observability::weaver::DEFAULT_DRAIN_TIMEOUT)
+        /// for it to flush its report and exit before killing it.
+        ///
         /// # Errors
         ///
-        /// Returns error if killing the child process fails.
+        /// Returns `DrainTimedOut` if Weaver does not exit in time, or
+        /// `ProcessStopFailed` if it cannot be asked to drain (it is killed in both cases).
         pub fn stop(
             mut self,
         ) -> crate::observability::weaver::WeaverValidationResult<WeaverValidator<state::Stopped>>
         {
-            use crate::observability::weaver::WeaverValidationError;
+            use crate::observability::weaver::types::WeaverLiveCheck;
 
             if let Some(ref mut child) = self.process {
-                child
-                    .kill()
-                    .map_err(|e| WeaverValidationError::ProcessStopFailed(e.to_string()))?;
-                child
-                    .wait()
-                    .map_err(|e| WeaverValidationError::ProcessStopFailed(e.to_string()))?;
+                let admin = WeaverLiveCheck::new().with_admin_port(self.admin_port);
+                crate::observability::weaver::drain_process(
+                    child,
+                    || admin.stop(),
+                    crate::observability::weaver::DEFAULT_DRAIN_TIMEOUT,
+                )?;
             }
 
             Ok(WeaverValidator::<state::Stopped> {
                 registry_path: self.registry_path,
                 otlp_grpc_port: self.otlp_grpc_port,
                 admin_port: self.admin_port,
+                policies_dir: self.policies_dir,
+                port_reservations: self.port_reservations,
                 process: None,
                 _state: PhantomData,
             })
@@ -239,12 +291,24 @@ pub enum WeaverValidationError {
     /// Registry path does not exist
     #[error("🚨 Registry path does not exist: {0}\n   ⚠️  STOP: Cannot proceed with Weaver validation\n   💡 FIX: Provide valid registry path\n   📋 Registry: Path to OpenTelemetry semantic conventions registry")]
     RegistryNotFound(String),
+    /// Custom Rego policies directory does not exist
+    #[error("🚨 Weaver policies directory does not exist: {0}\n   ⚠️  STOP: Cannot proceed with Weaver validation\n   💡 FIX: Provide the directory containing your .rego advice policies\n   📋 Policies: Passed to weaver registry live-check --advice-policies")]
+    PolicyDirNotFound(String),
     /// Failed to start Weaver process
     #[error("🚨 Failed to start Weaver process: {0}\n   ⚠️  STOP: Cannot start Weaver live-check\n   💡 FIX: Check Weaver binary is installed and accessible\n   📋 Verify: weaver --version")]
     ProcessStartFailed(String),
     /// Failed to stop Weaver process
     #[error("⚠️  Failed to stop Weaver process: {0}\n   ⚠️  WARNING: Weaver process may still be running\n   💡 FIX: Manually stop Weaver process if needed\n   📋 Check: ps aux | grep weaver")]
     ProcessStopFailed(String),
+    /// Weaver did not flush its report and exit before the drain deadline (it was killed)
+    #[error("🚨 Weaver did not finish draining within {timeout:?}\n   ⚠️  WARNING: Process was killed; the live-check report may be missing in-flight telemetry\n   💡 FIX: Increase the drain timeout (WeaverValidator::stop_with_timeout) or check Weaver logs")]
+    DrainTimedOut {
+        /// How long `stop` waited after requesting the drain
+        timeout: Duration,
+    },
+    /// No free ports could be allocated for Weaver
+    #[error("{0}")]
+    PortAllocation(#[from] crate::core::ports::PortAllocationError),
     /// Weaver process not running
     #[error("⚠️  Weaver process is not running\n   ⚠️  WARNING: Expected Weaver process to be running\n   💡 FIX: Start Weaver process before operation")]
     ProcessNotRunning,
@@ -272,12 +336,118 @@ pub const DEFAULT_ADMIN_PORT: u16 = 4320;
 /// Pattern: Use named constants for timeouts and durations.
 pub const DEFAULT_INACTIVITY_TIMEOUT_SECONDS: u64 = 300;
 
+/// Default time `stop()` waits for Weaver to flush its report and exit
+///
+/// Pattern: Use named constants for timeouts and durations.
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Poll interval while waiting for Weaver to exit after a drain request
+#[cfg(feature = "weaver")]
+const DRAIN_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Ask a live-check process to drain, then wait for it to exit before `timeout`
+///
+/// `request_drain` sends the admin stop request. A process that has already exited is
+/// left alone; one that cannot be asked to drain, or does not exit in time, is killed.
+#[cfg(feature = "weaver")]
+fn drain_process(
+    process: &mut Child,
+    request_drain: impl FnOnce() -> Result<(), String>,
+    timeout: Duration,
+) -> WeaverValidationResult<()> {
+    let kill = |process: &mut Child| {
+        let _ = process.kill();
+        let _ = process.wait();
+    };
+
+    if matches!(process.try_wait(), Ok(Some(_))) {
+        return Ok(());
+    }
+    if let Err(e) = request_drain() {
+        // Exited between the check and the request: nothing left in flight
+        if matches!(process.try_wait(), Ok(Some(_))) {
+            return Ok(());
+        }
+        kill(process);
+        return Err(WeaverValidationError::ProcessStopFailed(format!(
+            "{e} (process killed without draining)"
+        )));
+    }
+
+    // The report is flushed when the process exits
+    let deadline = std::time::Instant::now() + timeout;
+    loop {
+        match process.try_wait() {
+            Ok(Some(_)) => return Ok(()),
+            Ok(None) if std::time::Instant::now() < deadline => {
+                if let Err(cancelled) = crate::core::cancellation::cancellable_sleep(
+                    "weaver drain",
+                    DRAIN_POLL_INTERVAL,
+                ) {
+                    kill(process);
+                    return Err(WeaverValidationError::ProcessStopFailed(format!(
+                        "{cancelled} (process killed)"
+                    )));
+                }
+            }
+            Ok(None) => {
+                kill(process);
+                return Err(WeaverValidationError::DrainTimedOut { timeout });
+            }
+            Err(e) => {
+                kill(process);
+                return Err(WeaverValidationError::ProcessStopFailed(e.to_string()));
+            }
+        }
+    }
+}
+
 /// Localhost IP address for client connections
 ///
 /// **Kaizen improvement**: Extracted magic string `"127.0.0.1"` to named constant.
 /// Pattern: Use named constants for network addresses and endpoints.
 pub const LOCALHOST: &str = "127.0.0.1";
 
+/// OTLP/HTTP paths per signal, appended to the base endpoint
+pub const OTLP_HTTP_SIGNAL_PATHS: [&str; 3] = ["/v1/traces", "/v1/metrics", "/v1/logs"];
+
+/// Full OTLP/HTTP URL for one signal (`/v1/traces`, `/v1/metrics`, `/v1/logs`)
+///
+/// `endpoint` may be the base URL or already end in any signal path. Exporters are
+/// given this URL directly instead of reading `OTEL_EXPORTER_OTLP_ENDPOINT`, so senders
+/// with different endpoints can run in parallel.
+#[must_use]
+pub fn otlp_http_signal_endpoint(endpoint: &str, signal_path: &str) -> String {
+    let base = endpoint.trim_end_matches('/');
+    let base = OTLP_HTTP_SIGNAL_PATHS
+        .iter()
+        .find_map(|path| base.strip_suffix(path))
+        .unwrap_or(base)
+        .trim_end_matches('/');
+    format!("{base}{signal_path}")
+}
+
+/// Free OTLP gRPC and admin ports, reserved until the handles are dropped
+#[cfg(feature = "weaver")]
+fn allocate_ports() -> WeaverValidationResult<[crate::core::ports::ReservedPort; 2]> {
+    use crate::core::ports::PortAllocator;
+    Ok([PortAllocator::allocate()?, PortAllocator::allocate()?])
+}
+
+/// Validated `--advice-policies` value for live-check (`None` when no policies are set)
+#[cfg(feature = "weaver")]
+fn policies_arg(policies_dir: Option<&Path>) -> WeaverValidationResult<Option<String>> {
+    let Some(dir) = policies_dir else {
+        return Ok(None);
+    };
+    if !dir.is_dir() {
+        return Err(WeaverValidationError::PolicyDirNotFound(dir.display().to_string()));
+    }
+    dir.to_str().map(|dir| Some(dir.to_string())).ok_or_else(|| {
+        WeaverValidationError::ValidationFailed("Policies path is not valid UTF-8".to_string())
+    })
+}
+
 /// Weaver live validation helper
 #[cfg(feature = "weaver")]
 pub struct WeaverValidator {
@@ -286,6 +456,8 @@ pub struct WeaverValidator {
     registry_path: PathBuf,
     otlp_grpc_port: u16,
     admin_port: u16,
+    policies_dir: Option<PathBuf>,
+    port_reservations: Vec<crate::core::ports::ReservedPort>,
 }
 
 #[cfg(feature = "weaver")]
@@ -299,13 +471,50 @@ impl WeaverValidator {
             registry_path,
             otlp_grpc_port: DEFAULT_OTLP_GRPC_PORT,
             admin_port: DEFAULT_ADMIN_PORT,
+            policies_dir: None,
+            port_reservations: Vec::new(),
         }
     }
 
     /// Create a Weaver validator with custom configuration
     #[must_use]
     pub const fn with_config(registry_path: PathBuf, otlp_grpc_port: u16, admin_port: u16) -> Self {
-        Self { live_check: None, process: None, registry_path, otlp_grpc_port, admin_port }
+        Self {
+            live_check: None,
+            process: None,
+            registry_path,
+            otlp_grpc_port,
+            admin_port,
+            policies_dir: None,
+            port_reservations: Vec::new(),
+        }
+    }
+
+    /// Create a Weaver validator listening on free OTLP gRPC and admin ports
+    ///
+    /// Use this instead of [`WeaverValidator::new`] when tests run in parallel: the
+    /// default 4317/4320 can only be bound once. The ports stay reserved (see
+    /// [`PortAllocator`](crate::core::ports::PortAllocator)) until the validator is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WeaverValidationError::PortAllocation`] if no free ports are available.
+    pub fn with_free_ports(registry_path: PathBuf) -> WeaverValidationResult<Self> {
+        let [otlp, admin] = allocate_ports()?;
+        let mut validator = Self::with_config(registry_path, otlp.port(), admin.port());
+        validator.port_reservations = vec![otlp, admin];
+        Ok(validator)
+    }
+
+    /// Evaluate the custom Rego advice policies in `policies_dir` during live-check
+    ///
+    /// Lets organizations enforce their own telemetry conventions on top of the
+    /// registry. Policy advice is reported alongside Weaver's built-in advice; see
+    /// `ValidationResults::policy_violations` in the fixtures module.
+    #[must_use]
+    pub fn with_policies(mut self, policies_dir: impl Into<PathBuf>) -> Self {
+        self.policies_dir = Some(policies_dir.into());
+        self
     }
 
     /// Check if Weaver binary is available
@@ -328,10 +537,10 @@ impl WeaverValidator {
     ///
     /// Returns an error if git is not available or clone fails.
     fn clone_registry_runtime(registry_path: &Path) -> WeaverValidationResult<()> {
-        use std::process::Command;
+        use crate::core::command::{CheckedCommand, DOWNLOAD_COMMAND_TIMEOUT};
 
         // Check if git is available
-        if Command::new("git").arg("--version").output().is_err() {
+        if !CheckedCommand::is_available("git") {
             return Err(WeaverValidationError::RegistryNotFound(format!(
                 "{} (git not found for runtime clone)",
                 registry_path.display()
@@ -345,23 +554,17 @@ impl WeaverValidator {
 
         // Clone with shallow clone for faster download
         // Use --depth 1 to only clone the latest commit
-        let status = Command::new("git")
+        CheckedCommand::new("git")
             .args(["clone", "--depth", "1", "--single-branch", registry_url, registry_str])
-            .status()
+            .timeout(DOWNLOAD_COMMAND_TIMEOUT)
+            .run()
             .map_err(|e| {
                 WeaverValidationError::RegistryNotFound(format!(
-                    "{} (failed to clone: {e})",
+                    "{} (git clone failed: {e})",
                     registry_path.display()
                 ))
             })?;
 
-        if !status.success() {
-            return Err(WeaverValidationError::RegistryNotFound(format!(
-                "{} (git clone failed)",
-                registry_path.display()
-            )));
-        }
-
         Ok(())
     }
 
@@ -409,13 +612,19 @@ impl WeaverValidator {
             WeaverValidationError::ValidationFailed("Registry path is not valid UTF-8".to_string())
         })?;
 
-        let live_check = WeaverLiveCheck::new()
+        // 🚨 Verify custom policies directory exists
+        let policies = policies_arg(self.policies_dir.as_deref())?;
+
+        let mut live_check = WeaverLiveCheck::new()
             .with_registry(registry_str.to_string())
             .with_otlp_port(self.otlp_grpc_port)
             .with_admin_port(self.admin_port)
             .with_inactivity_timeout(DEFAULT_INACTIVITY_TIMEOUT_SECONDS) // 5 minutes (longer for tests)
             .with_format("json".to_string()) // Use JSON format for parsing
             .with_output("./weaver-reports".to_string()); // Output to directory for parsing
+        if let Some(policies) = policies {
+            live_check = live_check.with_policies(policies);
+        }
 
         // Start Weaver live-check process
         let process = live_check.start().map_err(WeaverValidationError::ProcessStartFailed)?;
@@ -426,22 +635,36 @@ impl WeaverValidator {
         Ok(())
     }
 
-    /// Stop Weaver live-check
+    /// Stop Weaver live-check gracefully
+    ///
+    /// Requests a drain through the admin endpoint, then waits up to
+    /// [`DEFAULT_DRAIN_TIMEOUT`] for Weaver to flush its final report and exit. Only a
+    /// process that misses the deadline (or cannot be reached) is killed, so telemetry
+    /// still in flight makes it into the report.
     ///
     /// # Errors
     ///
-    /// Returns an error if stopping the process fails.
+    /// Returns [`WeaverValidationError::DrainTimedOut`] if Weaver does not exit in time,
+    /// or [`WeaverValidationError::ProcessStopFailed`] if the drain cannot be requested.
     pub fn stop(&mut self) -> WeaverValidationResult<()> {
-        if let Some(ref live_check) = self.live_check {
-            live_check.stop().map_err(WeaverValidationError::ProcessStopFailed)?;
-        }
-
-        if let Some(mut process) = self.process.take() {
-            let _ = process.kill();
-        }
+        self.stop_with_timeout(DEFAULT_DRAIN_TIMEOUT)
+    }
 
-        self.live_check = None;
-        Ok(())
+    /// Stop Weaver live-check gracefully, waiting at most `timeout` for the drain
+    ///
+    /// # Errors
+    ///
+    /// Same as [`WeaverValidator::stop`].
+    pub fn stop_with_timeout(&mut self, timeout: Duration) -> WeaverValidationResult<()> {
+        let live_check = self.live_check.take();
+        let Some(mut process) = self.process.take() else {
+            return Ok(());
+        };
+        drain_process(
+            &mut process,
+            || live_check.as_ref().map_or(Ok(()), WeaverLiveCheck::stop),
+            timeout,
+        )
     }
 
     /// Get OTLP endpoint for sending telemetry
@@ -472,13 +695,8 @@ impl Drop for WeaverValidator {
 
 /// Send a test span to Weaver OTLP endpoint for validation
 ///
-/// Creates a simple test span and sends it to the Weaver OTLP endpoint.
-/// This is used for integration testing to verify that Weaver validates telemetry.
-///
-/// # Note
-///
-/// This function requires OpenTelemetry SDK dependencies which may have API changes.
-/// For production use, configure OpenTelemetry exporters directly in your application.
+/// Sends a single-span [`scenario::TelemetryScenario`]. For related spans, metrics,
+/// and logs, or for sending several scenarios, use [`scenario::TelemetrySender`].
 ///
 /// # Example
 ///
@@ -498,78 +716,14 @@ impl Drop for WeaverValidator {
 /// Returns an error if sending the span to Weaver fails.
 #[cfg(feature = "weaver")]
 pub fn send_test_span_to_weaver(endpoint: &str, span_name: &str) -> WeaverValidationResult<()> {
-    // Items (use statements) must come before statements (Rust requirement)
-    use opentelemetry::trace::{Span, Tracer, TracerProvider as _};
     use opentelemetry::KeyValue;
-    use opentelemetry_sdk::trace::{RandomIdGenerator, Sampler, SdkTracerProvider};
-    use opentelemetry_sdk::Resource;
-    use std::time::Duration;
-
-    // Create OTLP HTTP exporter and tracer provider
-    // Using OpenTelemetry 0.31 API pattern from knhk
-    // Set endpoint via environment variable (required by exporter)
-    let base_endpoint = endpoint.trim_end_matches("/v1/traces").trim_end_matches('/');
-    std::env::set_var("OTEL_EXPORTER_OTLP_ENDPOINT", base_endpoint);
-
-    // Create OTLP HTTP exporter using builder pattern
-    let exporter =
-        opentelemetry_otlp::SpanExporter::builder().with_http().build().map_err(|e| {
-            WeaverValidationError::ValidationFailed(format!(
-                "🚨 Failed to create OTLP HTTP exporter: {e}\n   ⚠️  STOP: Cannot create OTLP exporter\n   💡 FIX: Check OpenTelemetry SDK configuration and endpoint"
-            ))
-        })?;
-
-    // Create resource with service information
-    let resource = Resource::builder_empty()
-        .with_service_name("chicago-tdd-tools-test")
-        .with_attributes([
-            KeyValue::new("service.version", env!("CARGO_PKG_VERSION")),
-            KeyValue::new("telemetry.sdk.language", "rust"),
-            KeyValue::new("telemetry.sdk.name", "opentelemetry"),
-            KeyValue::new("telemetry.sdk.version", "0.31.0"),
-        ])
-        .build();
-
-    // Create tracer provider with batch exporter (sync pattern from knhk)
-    let provider = SdkTracerProvider::builder()
-        .with_batch_exporter(exporter)
-        .with_sampler(Sampler::TraceIdRatioBased(1.0)) // Always sample for tests
-        .with_id_generator(RandomIdGenerator::default())
-        .with_resource(resource)
-        .build();
-
-    // Get tracer
-    let tracer = provider.tracer("chicago-tdd-tools");
-
-    // Create and start span using span_builder pattern
-    // Kaizen improvement: Create owned string once and reuse to avoid unnecessary clone
-    let span_name_owned = span_name.to_string();
-    let mut span = tracer.span_builder(span_name_owned.clone()).start(&tracer);
-
-    // Set test attributes
-    span.set_attribute(KeyValue::new("test.operation", span_name_owned));
-    span.set_attribute(KeyValue::new("test.framework", "chicago-tdd-tools"));
-    span.set_attribute(KeyValue::new("span.kind", "internal"));
-
-    // End span (this triggers export)
-    span.end();
-
-    // Force flush to ensure span is exported before shutdown
-    provider.force_flush().map_err(|e| {
-        WeaverValidationError::ValidationFailed(format!("⚠️  Failed to flush traces: {e}\n   ⚠️  WARNING: Traces may not be exported\n   💡 FIX: Check OTLP endpoint connectivity"))
-    })?;
+    use scenario::{ScenarioSpan, TelemetryScenario};
 
-    // Give async exports time to complete
-    std::thread::sleep(Duration::from_millis(500));
-
-    // Shutdown tracer provider
-    provider.shutdown().map_err(|e| {
-        WeaverValidationError::ValidationFailed(format!(
-            "⚠️  Failed to shutdown tracer provider: {e}\n   ⚠️  WARNING: Tracer provider may not have shut down cleanly\n   💡 FIX: Check resource cleanup"
-        ))
-    })?;
-
-    Ok(())
+    let scenario = TelemetryScenario::new(span_name).span(
+        ScenarioSpan::new(span_name)
+            .attribute(KeyValue::new("test.operation", span_name.to_string())),
+    );
+    scenario::send_scenario_to_weaver(endpoint, &scenario)
 }
 
 /// Run Weaver static schema validation
@@ -596,8 +750,8 @@ pub fn send_test_span_to_weaver(endpoint: &str, span_name: &str) -> WeaverValida
 #[cfg(feature = "weaver")]
 pub fn validate_schema_static(registry_path: &std::path::Path) -> WeaverValidationResult<()> {
     // Items (use statements) must come before statements (Rust requirement)
+    use crate::core::command::{CheckedCommand, CommandError};
     use crate::observability::weaver::types::WeaverLiveCheck;
-    use std::process::Command;
 
     // Check Weaver binary availability
     WeaverValidator::check_weaver_available()?;
@@ -616,26 +770,19 @@ pub fn validate_schema_static(registry_path: &std::path::Path) -> WeaverValidati
     let weaver_binary =
         WeaverLiveCheck::find_weaver_binary().ok_or(WeaverValidationError::BinaryNotFound)?;
 
-    let output = Command::new(&weaver_binary)
+    CheckedCommand::new(&weaver_binary)
         .args(["registry", "check", "-r", registry_str])
-        .output()
-        .map_err(|e| {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                WeaverValidationError::BinaryNotFound
-            } else {
-                WeaverValidationError::ValidationFailed(format!(
-                    "🚨 Failed to execute weaver check: {e}\n   ⚠️  STOP: Weaver schema validation failed\n   💡 FIX: Check Weaver binary is installed and registry path is valid"
-                ))
-            }
+        .run()
+        .map_err(|e| match e {
+            CommandError::NotFound { .. } => WeaverValidationError::BinaryNotFound,
+            CommandError::Failed { stderr, .. } => WeaverValidationError::ValidationFailed(format!(
+                "🚨 Weaver schema validation failed: {stderr}\n   ⚠️  STOP: Schema does not conform to semantic conventions\n   💡 FIX: Check registry schema and telemetry structure"
+            )),
+            other => WeaverValidationError::ValidationFailed(format!(
+                "🚨 Failed to execute weaver check: {other}\n   ⚠️  STOP: Weaver schema validation failed\n   💡 FIX: Check Weaver binary is installed and registry path is valid"
+            )),
         })?;
 
-    if !output.status.success() {
-        let stderr = String::from_utf8_lossy(&output.stderr);
-        return Err(WeaverValidationError::ValidationFailed(format!(
-            "🚨 Weaver schema validation failed: {stderr}\n   ⚠️  STOP: Schema does not conform to semantic conventions\n   💡 FIX: Check registry schema and telemetry structure"
-        )));
-    }
-
     Ok(())
 }
 
@@ -672,6 +819,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_otlp_http_signal_endpoint_normalizes_base() {
+        for endpoint in [
+            "http://127.0.0.1:4318",
+            "http://127.0.0.1:4318/",
+            "http://127.0.0.1:4318/v1/traces",
+            "http://127.0.0.1:4318/v1/logs/",
+        ] {
+            assert_eq!(
+                otlp_http_signal_endpoint(endpoint, "/v1/metrics"),
+                "http://127.0.0.1:4318/v1/metrics",
+                "endpoint {endpoint}"
+            );
+        }
+    }
+
     #[cfg(feature = "weaver")]
     #[test]
     fn test_weaver_validation_error_debug() {
@@ -689,8 +852,10 @@ mod tests {
             WeaverValidationError::BinaryNotFound,
             WeaverValidationError::ValidationFailed("test validation".to_string()),
             WeaverValidationError::RegistryNotFound("/nonexistent/path".to_string()),
+            WeaverValidationError::PolicyDirNotFound("/nonexistent/policies".to_string()),
             WeaverValidationError::ProcessStartFailed("failed to start".to_string()),
             WeaverValidationError::ProcessStopFailed("failed to stop".to_string()),
+            WeaverValidationError::DrainTimedOut { timeout: Duration::from_secs(1) },
             WeaverValidationError::ProcessNotRunning,
         ];
 
@@ -764,6 +929,39 @@ mod tests {
         assert_eq!(validator.admin_port, 8081);
     }
 
+    #[cfg(feature = "weaver")]
+    #[test]
+    fn test_weaver_validator_with_free_ports() {
+        use crate::core::ports::PortAllocator;
+
+        let first = WeaverValidator::with_free_ports(PathBuf::from("registry/")).unwrap();
+        let second = WeaverValidator::with_free_ports(PathBuf::from("registry/")).unwrap();
+        let ports =
+            [first.otlp_grpc_port, first.admin_port, second.otlp_grpc_port, second.admin_port];
+
+        assert_eq!(ports.iter().collect::<std::collections::BTreeSet<_>>().len(), 4);
+        assert_ne!(first.otlp_grpc_port, DEFAULT_OTLP_GRPC_PORT);
+        assert_eq!(first.otlp_endpoint(), format!("http://{LOCALHOST}:{}", first.otlp_grpc_port));
+        assert!(PortAllocator::is_reserved(first.admin_port));
+        let released = first.admin_port;
+        drop(first);
+        assert!(!PortAllocator::is_reserved(released));
+    }
+
+    #[cfg(feature = "weaver")]
+    #[test]
+    fn test_weaver_validator_with_policies() {
+        let validator = WeaverValidator::new(PathBuf::from("registry/")).with_policies("policies/");
+        assert_eq!(validator.policies_dir, Some(PathBuf::from("policies/")));
+
+        // Policies directory is validated before Weaver is spawned
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(policies_arg(None).unwrap(), None);
+        assert_eq!(policies_arg(Some(dir.path())).unwrap().as_deref(), dir.path().to_str());
+        let missing = policies_arg(Some(&dir.path().join("missing"))).unwrap_err();
+        assert!(matches!(missing, WeaverValidationError::PolicyDirNotFound(_)));
+    }
+
     #[cfg(feature = "weaver")]
     #[test]
     fn test_weaver_validator_otlp_endpoint() {
@@ -905,6 +1103,55 @@ mod tests {
         assert!(!validator.is_running(), "Validator should not be running initially");
     }
 
+    #[cfg(all(feature = "weaver", unix))]
+    #[test]
+    fn test_drain_process_waits_for_graceful_exit() {
+        // Arrange: a process that exits shortly after the drain request
+        let mut child = std::process::Command::new("sleep").arg("0.2").spawn().unwrap();
+
+        // Act
+        let result = drain_process(&mut child, || Ok(()), Duration::from_secs(5));
+
+        // Assert
+        assert!(result.is_ok(), "{result:?}");
+        assert!(child.try_wait().unwrap().is_some());
+    }
+
+    #[cfg(all(feature = "weaver", unix))]
+    #[test]
+    fn test_drain_process_kills_after_deadline() {
+        // Arrange: a process that ignores the drain request
+        let mut child = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+
+        // Act
+        let result = drain_process(&mut child, || Ok(()), Duration::from_millis(100));
+
+        // Assert
+        assert!(matches!(result, Err(WeaverValidationError::DrainTimedOut { .. })), "{result:?}");
+        assert!(child.try_wait().unwrap().is_some(), "process should be killed");
+    }
+
+    #[cfg(all(feature = "weaver", unix))]
+    #[test]
+    fn test_drain_process_kills_when_drain_request_fails() {
+        // Arrange
+        let mut child = std::process::Command::new("sleep").arg("30").spawn().unwrap();
+
+        // Act
+        let result = drain_process(
+            &mut child,
+            || Err("connection refused".to_string()),
+            DEFAULT_DRAIN_TIMEOUT,
+        );
+
+        // Assert
+        assert!(
+            matches!(&result, Err(WeaverValidationError::ProcessStopFailed(msg)) if msg.contains("without draining")),
+            "{result:?}"
+        );
+        assert!(child.try_wait().unwrap().is_some());
+    }
+
     // **Poka-yoke**: Integration test moved to tests/weaver_integration.rs
     // Unit tests in src/ should only test types and validators, not integration with external services
 }
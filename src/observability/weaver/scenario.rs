@@ -0,0 +1,692 @@
+//! Telemetry Scenarios for Weaver Live-Check
+//!
+//! A [`TelemetryScenario`] describes a set of related spans, metrics, and logs
+//! (parent-child spans, span links, error spans, logs correlated to spans) that a
+//! [`TelemetrySender`] emits to an OTLP endpoint in one call. The sender builds its
+//! tracer, meter, and logger providers once and flushes them after each scenario, so
+//! tests can send many scenarios without per-call provider setup or fixed sleeps.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use chicago_tdd_tools::observability::weaver::scenario::{
+//!     ScenarioLog, ScenarioMetric, ScenarioSpan, TelemetryScenario, TelemetrySender,
+//! };
+//! use opentelemetry::trace::SpanKind;
+//!
+//! let scenario = TelemetryScenario::new("checkout")
+//!     .span(ScenarioSpan::new("http.request").kind(SpanKind::Server))
+//!     .span(ScenarioSpan::new("db.query").child_of("http.request"))
+//!     .span(ScenarioSpan::new("payment.charge").child_of("http.request").error("card declined"))
+//!     .span(ScenarioSpan::new("audit.write").linked_to("payment.charge"))
+//!     .metric(ScenarioMetric::counter("http.server.request.count", 1))
+//!     .log(ScenarioLog::error("payment failed").in_span("payment.charge"));
+//!
+//! let sender = TelemetrySender::new(&validator.otlp_endpoint())?;
+//! sender.send(&scenario)?;
+//! sender.shutdown()?;
+//! ```
+
+use super::{WeaverValidationError, WeaverValidationResult};
+use opentelemetry::logs::Severity;
+use opentelemetry::trace::{SpanContext, SpanKind};
+use opentelemetry::KeyValue;
+use opentelemetry_sdk::logs::SdkLoggerProvider;
+use opentelemetry_sdk::metrics::SdkMeterProvider;
+use opentelemetry_sdk::trace::SdkTracerProvider;
+use std::collections::HashMap;
+
+/// Instrumentation scope name used for all scenario telemetry
+pub const SCENARIO_INSTRUMENTATION_SCOPE: &str = "chicago-tdd-tools";
+
+/// Service name reported in the resource of all scenario telemetry
+pub const SCENARIO_SERVICE_NAME: &str = "chicago-tdd-tools-test";
+
+/// One span in a [`TelemetryScenario`]
+///
+/// Spans are referenced by name: `child_of` and `linked_to` must name a span that was
+/// added to the scenario earlier.
+#[derive(Debug, Clone)]
+pub struct ScenarioSpan {
+    name: String,
+    kind: SpanKind,
+    parent: Option<String>,
+    links: Vec<String>,
+    attributes: Vec<KeyValue>,
+    error: Option<String>,
+}
+
+impl ScenarioSpan {
+    /// Create an internal span with no parent, links, or attributes
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            kind: SpanKind::Internal,
+            parent: None,
+            links: Vec::new(),
+            attributes: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Set the span kind
+    #[must_use]
+    pub const fn kind(mut self, kind: SpanKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Make this span a child of an earlier span
+    #[must_use]
+    pub fn child_of(mut self, parent: impl Into<String>) -> Self {
+        self.parent = Some(parent.into());
+        self
+    }
+
+    /// Add a link to an earlier span
+    #[must_use]
+    pub fn linked_to(mut self, span: impl Into<String>) -> Self {
+        self.links.push(span.into());
+        self
+    }
+
+    /// Add an attribute
+    #[must_use]
+    pub fn attribute(mut self, attribute: KeyValue) -> Self {
+        self.attributes.push(attribute);
+        self
+    }
+
+    /// Mark the span as failed: error status plus an `exception` event
+    #[must_use]
+    pub fn error(mut self, message: impl Into<String>) -> Self {
+        self.error = Some(message.into());
+        self
+    }
+
+    /// Span name
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+/// Instrument type of a [`ScenarioMetric`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScenarioMetricKind {
+    /// Monotonic `u64` counter
+    Counter,
+    /// `f64` histogram
+    Histogram,
+    /// `f64` gauge
+    Gauge,
+}
+
+/// One metric measurement in a [`TelemetryScenario`]
+#[derive(Debug, Clone)]
+pub struct ScenarioMetric {
+    name: String,
+    kind: ScenarioMetricKind,
+    value: f64,
+    attributes: Vec<KeyValue>,
+}
+
+impl ScenarioMetric {
+    /// Counter incremented by `value`
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Test counters stay far below 2^53
+    pub fn counter(name: impl Into<String>, value: u64) -> Self {
+        Self::new(name, ScenarioMetricKind::Counter, value as f64)
+    }
+
+    /// Histogram recording `value`
+    #[must_use]
+    pub fn histogram(name: impl Into<String>, value: f64) -> Self {
+        Self::new(name, ScenarioMetricKind::Histogram, value)
+    }
+
+    /// Gauge set to `value`
+    #[must_use]
+    pub fn gauge(name: impl Into<String>, value: f64) -> Self {
+        Self::new(name, ScenarioMetricKind::Gauge, value)
+    }
+
+    fn new(name: impl Into<String>, kind: ScenarioMetricKind, value: f64) -> Self {
+        Self { name: name.into(), kind, value, attributes: Vec::new() }
+    }
+
+    /// Add an attribute to the measurement
+    #[must_use]
+    pub fn attribute(mut self, attribute: KeyValue) -> Self {
+        self.attributes.push(attribute);
+        self
+    }
+}
+
+/// One log record in a [`TelemetryScenario`]
+#[derive(Debug, Clone)]
+pub struct ScenarioLog {
+    severity: Severity,
+    body: String,
+    span: Option<String>,
+    attributes: Vec<KeyValue>,
+}
+
+impl ScenarioLog {
+    /// Log record with the given severity
+    #[must_use]
+    pub fn new(severity: Severity, body: impl Into<String>) -> Self {
+        Self { severity, body: body.into(), span: None, attributes: Vec::new() }
+    }
+
+    /// `INFO` log record
+    #[must_use]
+    pub fn info(body: impl Into<String>) -> Self {
+        Self::new(Severity::Info, body)
+    }
+
+    /// `ERROR` log record
+    #[must_use]
+    pub fn error(body: impl Into<String>) -> Self {
+        Self::new(Severity::Error, body)
+    }
+
+    /// Correlate the record with a span of the scenario (trace and span ID)
+    #[must_use]
+    pub fn in_span(mut self, span: impl Into<String>) -> Self {
+        self.span = Some(span.into());
+        self
+    }
+
+    /// Add an attribute
+    #[must_use]
+    pub fn attribute(mut self, attribute: KeyValue) -> Self {
+        self.attributes.push(attribute);
+        self
+    }
+}
+
+/// A set of related spans, metrics, and logs sent together
+#[derive(Debug, Clone, Default)]
+pub struct TelemetryScenario {
+    name: String,
+    spans: Vec<ScenarioSpan>,
+    metrics: Vec<ScenarioMetric>,
+    logs: Vec<ScenarioLog>,
+}
+
+impl TelemetryScenario {
+    /// Create an empty scenario
+    ///
+    /// The name is attached to every span, metric, and log as `test.scenario`.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into(), ..Self::default() }
+    }
+
+    /// Add a span (after any span it references)
+    #[must_use]
+    pub fn span(mut self, span: ScenarioSpan) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    /// Add a metric measurement
+    #[must_use]
+    pub fn metric(mut self, metric: ScenarioMetric) -> Self {
+        self.metrics.push(metric);
+        self
+    }
+
+    /// Add a log record
+    #[must_use]
+    pub fn log(mut self, log: ScenarioLog) -> Self {
+        self.logs.push(log);
+        self
+    }
+
+    /// Scenario name
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Spans in declaration order
+    #[must_use]
+    pub fn spans(&self) -> &[ScenarioSpan] {
+        &self.spans
+    }
+
+    /// Check that span names are unique and every reference names an earlier span
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationFailed` naming the first duplicate or dangling reference.
+    pub fn validate(&self) -> WeaverValidationResult<()> {
+        let invalid = |detail: String| {
+            Err(WeaverValidationError::ValidationFailed(format!(
+                "Invalid telemetry scenario '{}': {detail}",
+                self.name
+            )))
+        };
+
+        let mut declared: Vec<&str> = Vec::with_capacity(self.spans.len());
+        for span in &self.spans {
+            if declared.contains(&span.name.as_str()) {
+                return invalid(format!("duplicate span '{}'", span.name));
+            }
+            for reference in span.parent.iter().chain(&span.links) {
+                if !declared.contains(&reference.as_str()) {
+                    return invalid(format!(
+                        "span '{}' references '{reference}', which is not declared before it",
+                        span.name
+                    ));
+                }
+            }
+            declared.push(&span.name);
+        }
+        for log in &self.logs {
+            if let Some(span) = &log.span {
+                if !declared.contains(&span.as_str()) {
+                    return invalid(format!("log '{}' references unknown span '{span}'", log.body));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Sends [`TelemetryScenario`]s to an OTLP HTTP endpoint
+///
+/// Providers are created once per sender; [`TelemetrySender::send`] flushes them, so the
+/// scenario has been exported when it returns.
+pub struct TelemetrySender {
+    traces: SdkTracerProvider,
+    metrics: SdkMeterProvider,
+    logs: SdkLoggerProvider,
+}
+
+impl TelemetrySender {
+    /// Create OTLP HTTP exporters for traces, metrics, and logs
+    ///
+    /// `endpoint` is the OTLP base URL (a trailing signal path such as `/v1/traces` is
+    /// accepted). It is passed to each exporter directly; the process environment is
+    /// not modified.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationFailed` if an exporter cannot be created.
+    pub fn new(endpoint: &str) -> WeaverValidationResult<Self> {
+        use super::otlp_http_signal_endpoint;
+        use opentelemetry_otlp::WithExportConfig;
+        use opentelemetry_sdk::trace::{RandomIdGenerator, Sampler};
+        use opentelemetry_sdk::Resource;
+
+        let resource = Resource::builder_empty()
+            .with_service_name(SCENARIO_SERVICE_NAME)
+            .with_attributes([
+                KeyValue::new("service.version", env!("CARGO_PKG_VERSION")),
+                KeyValue::new("telemetry.sdk.language", "rust"),
+                KeyValue::new("telemetry.sdk.name", "opentelemetry"),
+                KeyValue::new("telemetry.sdk.version", "0.31.0"),
+            ])
+            .build();
+
+        let span_exporter = opentelemetry_otlp::SpanExporter::builder()
+            .with_http()
+            .with_endpoint(otlp_http_signal_endpoint(endpoint, "/v1/traces"))
+            .build()
+            .map_err(|e| exporter_error("span", &e))?;
+        let metric_exporter = opentelemetry_otlp::MetricExporter::builder()
+            .with_http()
+            .with_endpoint(otlp_http_signal_endpoint(endpoint, "/v1/metrics"))
+            .build()
+            .map_err(|e| exporter_error("metric", &e))?;
+        let log_exporter = opentelemetry_otlp::LogExporter::builder()
+            .with_http()
+            .with_endpoint(otlp_http_signal_endpoint(endpoint, "/v1/logs"))
+            .build()
+            .map_err(|e| exporter_error("log", &e))?;
+
+        Ok(Self {
+            traces: SdkTracerProvider::builder()
+                .with_batch_exporter(span_exporter)
+                .with_sampler(Sampler::AlwaysOn)
+                .with_id_generator(RandomIdGenerator::default())
+                .with_resource(resource.clone())
+                .build(),
+            metrics: SdkMeterProvider::builder()
+                .with_periodic_exporter(metric_exporter)
+                .with_resource(resource.clone())
+                .build(),
+            logs: SdkLoggerProvider::builder()
+                .with_batch_exporter(log_exporter)
+                .with_resource(resource)
+                .build(),
+        })
+    }
+
+    /// Emit every span, metric, and log of `scenario`, then flush all exporters
+    ///
+    /// Spans start in declaration order and end in reverse, so children end before
+    /// their parents. Logs are emitted while their span is still open.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationFailed` if the scenario is invalid or a flush fails.
+    pub fn send(&self, scenario: &TelemetryScenario) -> WeaverValidationResult<()> {
+        use opentelemetry::logs::{AnyValue, LogRecord as _, Logger as _, LoggerProvider as _};
+        use opentelemetry::metrics::MeterProvider as _;
+        use opentelemetry::trace::{
+            Link, Span as _, Status, TraceContextExt as _, Tracer as _, TracerProvider as _,
+        };
+        use opentelemetry::Context;
+
+        scenario.validate()?;
+        let scenario_attribute = KeyValue::new("test.scenario", scenario.name.clone());
+
+        // Spans: start in order so parents and link targets exist before they are referenced
+        let tracer = self.traces.tracer(SCENARIO_INSTRUMENTATION_SCOPE);
+        let mut contexts: HashMap<&str, SpanContext> = HashMap::new();
+        let mut open_spans = Vec::with_capacity(scenario.spans.len());
+        for spec in &scenario.spans {
+            let mut attributes = spec.attributes.clone();
+            attributes.push(scenario_attribute.clone());
+            attributes.push(KeyValue::new("test.framework", "chicago-tdd-tools"));
+            let builder = tracer
+                .span_builder(spec.name.clone())
+                .with_kind(spec.kind.clone())
+                .with_attributes(attributes)
+                .with_links(
+                    spec.links
+                        .iter()
+                        .map(|link| Link::with_context(contexts[link.as_str()].clone()))
+                        .collect(),
+                );
+            let mut span = match &spec.parent {
+                Some(parent) => builder.start_with_context(
+                    &tracer,
+                    &Context::new().with_remote_span_context(contexts[parent.as_str()].clone()),
+                ),
+                None => builder.start(&tracer),
+            };
+            if let Some(message) = &spec.error {
+                span.add_event(
+                    "exception",
+                    vec![
+                        KeyValue::new("exception.type", "TestScenarioError"),
+                        KeyValue::new("exception.message", message.clone()),
+                    ],
+                );
+                span.set_status(Status::error(message.clone()));
+            }
+            contexts.insert(&spec.name, span.span_context().clone());
+            open_spans.push(span);
+        }
+
+        // Logs: correlated with their span's trace and span IDs
+        let logger = self.logs.logger(SCENARIO_INSTRUMENTATION_SCOPE);
+        for spec in &scenario.logs {
+            let mut record = logger.create_log_record();
+            record.set_severity_number(spec.severity);
+            record.set_severity_text(spec.severity.name());
+            record.set_body(AnyValue::from(spec.body.clone()));
+            record.add_attribute(
+                scenario_attribute.key.clone(),
+                scenario_attribute.value.to_string(),
+            );
+            for attribute in &spec.attributes {
+                record.add_attribute(attribute.key.clone(), attribute.value.to_string());
+            }
+            if let Some(context) = spec.span.as_deref().map(|span| &contexts[span]) {
+                record.set_trace_context(
+                    context.trace_id(),
+                    context.span_id(),
+                    Some(context.trace_flags()),
+                );
+            }
+            logger.emit(record);
+        }
+
+        for mut span in open_spans.into_iter().rev() {
+            span.end();
+        }
+
+        // Metrics
+        let meter = self.metrics.meter(SCENARIO_INSTRUMENTATION_SCOPE);
+        for spec in &scenario.metrics {
+            let mut attributes = spec.attributes.clone();
+            attributes.push(scenario_attribute.clone());
+            match spec.kind {
+                ScenarioMetricKind::Counter => {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    // Built from a u64 in ScenarioMetric::counter
+                    let value = spec.value as u64;
+                    meter.u64_counter(spec.name.clone()).build().add(value, &attributes);
+                }
+                ScenarioMetricKind::Histogram => {
+                    meter.f64_histogram(spec.name.clone()).build().record(spec.value, &attributes);
+                }
+                ScenarioMetricKind::Gauge => {
+                    meter.f64_gauge(spec.name.clone()).build().record(spec.value, &attributes);
+                }
+            }
+        }
+
+        self.flush()
+    }
+
+    /// Export everything recorded so far
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationFailed` naming the first signal whose flush failed.
+    pub fn flush(&self) -> WeaverValidationResult<()> {
+        self.traces.force_flush().map_err(|e| flush_error("traces", &e))?;
+        self.logs.force_flush().map_err(|e| flush_error("logs", &e))?;
+        self.metrics.force_flush().map_err(|e| flush_error("metrics", &e))
+    }
+
+    /// Flush and shut down all providers
+    ///
+    /// # Errors
+    ///
+    /// Returns `ValidationFailed` if a provider did not shut down cleanly.
+    pub fn shutdown(self) -> WeaverValidationResult<()> {
+        self.traces.shutdown().map_err(|e| shutdown_error("tracer", &e))?;
+        self.logs.shutdown().map_err(|e| shutdown_error("logger", &e))?;
+        self.metrics.shutdown().map_err(|e| shutdown_error("meter", &e))
+    }
+}
+
+impl std::fmt::Debug for TelemetrySender {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TelemetrySender").finish_non_exhaustive()
+    }
+}
+
+fn exporter_error(signal: &str, error: &dyn std::fmt::Display) -> WeaverValidationError {
+    WeaverValidationError::ValidationFailed(format!(
+        "🚨 Failed to create OTLP HTTP {signal} exporter: {error}\n   ⚠️  STOP: Cannot create OTLP exporter\n   💡 FIX: Check OpenTelemetry SDK configuration and endpoint"
+    ))
+}
+
+fn flush_error(signal: &str, error: &dyn std::fmt::Display) -> WeaverValidationError {
+    WeaverValidationError::ValidationFailed(format!(
+        "⚠️  Failed to flush {signal}: {error}\n   ⚠️  WARNING: Telemetry may not be exported\n   💡 FIX: Check OTLP endpoint connectivity"
+    ))
+}
+
+fn shutdown_error(provider: &str, error: &dyn std::fmt::Display) -> WeaverValidationError {
+    WeaverValidationError::ValidationFailed(format!(
+        "⚠️  Failed to shutdown {provider} provider: {error}\n   ⚠️  WARNING: Provider may not have shut down cleanly\n   💡 FIX: Check resource cleanup"
+    ))
+}
+
+/// Send one scenario with a short-lived [`TelemetrySender`]
+///
+/// Prefer a long-lived sender when a test sends several scenarios.
+///
+/// # Errors
+///
+/// Returns an error if the scenario is invalid or exporting it fails.
+pub fn send_scenario_to_weaver(
+    endpoint: &str,
+    scenario: &TelemetryScenario,
+) -> WeaverValidationResult<()> {
+    let sender = TelemetrySender::new(endpoint)?;
+    sender.send(scenario)?;
+    sender.shutdown()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::{Arc, Mutex};
+
+    /// Minimal OTLP/HTTP sink recording `(path, body)` of every request
+    fn spawn_otlp_sink() -> (String, Arc<Mutex<Vec<(String, Vec<u8>)>>>) {
+        let listener = TcpListener::bind((super::super::LOCALHOST, 0)).unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let recorded = Arc::clone(&recorded);
+                std::thread::spawn(move || serve_connection(stream, &recorded));
+            }
+        });
+        (endpoint, requests)
+    }
+
+    fn serve_connection(stream: TcpStream, recorded: &Mutex<Vec<(String, Vec<u8>)>>) {
+        let mut writer = stream.try_clone().unwrap();
+        let mut reader = BufReader::new(stream);
+        loop {
+            let mut request_line = String::new();
+            if reader.read_line(&mut request_line).unwrap_or(0) == 0 {
+                return;
+            }
+            let path = request_line.split_whitespace().nth(1).unwrap_or_default().to_string();
+            let mut content_length = 0;
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = header.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            recorded.lock().unwrap().push((path, body));
+            writer.write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n").unwrap();
+        }
+    }
+
+    fn contains(haystack: &[u8], needle: &str) -> bool {
+        haystack.windows(needle.len()).any(|window| window == needle.as_bytes())
+    }
+
+    fn assert_invalid(scenario: &TelemetryScenario, expected: &str) {
+        match scenario.validate() {
+            Err(WeaverValidationError::ValidationFailed(message)) => {
+                assert!(message.contains(expected), "{message}");
+            }
+            other => panic!("expected ValidationFailed containing {expected:?}, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_scenario_with_related_spans_is_valid() {
+        let scenario = TelemetryScenario::new("checkout")
+            .span(ScenarioSpan::new("http.request").kind(SpanKind::Server))
+            .span(ScenarioSpan::new("db.query").child_of("http.request"))
+            .span(ScenarioSpan::new("payment").child_of("http.request").error("declined"))
+            .span(ScenarioSpan::new("audit").linked_to("payment").linked_to("db.query"))
+            .metric(ScenarioMetric::counter("requests", 1))
+            .log(ScenarioLog::error("payment failed").in_span("payment"));
+
+        assert!(scenario.validate().is_ok());
+        assert_eq!(
+            scenario.spans().iter().map(ScenarioSpan::name).collect::<Vec<_>>(),
+            ["http.request", "db.query", "payment", "audit"]
+        );
+    }
+
+    #[test]
+    fn test_scenario_rejects_forward_and_dangling_references() {
+        assert_invalid(
+            &TelemetryScenario::new("s")
+                .span(ScenarioSpan::new("child").child_of("parent"))
+                .span(ScenarioSpan::new("parent")),
+            "span 'child' references 'parent'",
+        );
+        assert_invalid(
+            &TelemetryScenario::new("s").span(ScenarioSpan::new("a").linked_to("missing")),
+            "references 'missing'",
+        );
+        assert_invalid(
+            &TelemetryScenario::new("s").log(ScenarioLog::info("hello").in_span("missing")),
+            "unknown span 'missing'",
+        );
+    }
+
+    #[test]
+    fn test_scenario_rejects_duplicate_span_names() {
+        assert_invalid(
+            &TelemetryScenario::new("s")
+                .span(ScenarioSpan::new("a"))
+                .span(ScenarioSpan::new("a")),
+            "duplicate span 'a'",
+        );
+    }
+
+    #[test]
+    fn test_parallel_senders_with_different_endpoints_do_not_interfere() {
+        let (endpoint_a, requests_a) = spawn_otlp_sink();
+        let (endpoint_b, requests_b) = spawn_otlp_sink();
+
+        let send_all = |endpoint: String, name: &'static str| {
+            std::thread::spawn(move || {
+                let scenario = TelemetryScenario::new(name)
+                    .span(ScenarioSpan::new(format!("{name}.span")))
+                    .metric(ScenarioMetric::counter(format!("{name}.count"), 1))
+                    .log(ScenarioLog::info(format!("{name}.log")).in_span(format!("{name}.span")));
+                let sender = TelemetrySender::new(&endpoint).unwrap();
+                for _ in 0..5 {
+                    sender.send(&scenario).unwrap();
+                }
+                sender.shutdown().unwrap();
+            })
+        };
+        let a = send_all(endpoint_a, "scenario-alpha");
+        let b = send_all(endpoint_b, "scenario-beta");
+        a.join().unwrap();
+        b.join().unwrap();
+
+        for (requests, own, other) in [
+            (&requests_a, "scenario-alpha", "scenario-beta"),
+            (&requests_b, "scenario-beta", "scenario-alpha"),
+        ] {
+            let requests = requests.lock().unwrap();
+            for signal in super::super::OTLP_HTTP_SIGNAL_PATHS {
+                assert!(
+                    requests.iter().any(|(path, body)| path == signal && contains(body, own)),
+                    "{own}: no {signal} request carrying its telemetry"
+                );
+            }
+            assert!(
+                requests.iter().all(|(_, body)| !contains(body, other)),
+                "{own}: received telemetry sent to the other endpoint"
+            );
+        }
+    }
+}
@@ -0,0 +1,416 @@
+//! Weaver Toolchain
+//!
+//! Locates, verifies, and downloads the `weaver` CLI. A binary is only accepted if
+//! `weaver --version` satisfies the required semver range, so a stale `weaver` on
+//! `PATH` is skipped in favour of a matching one — or a fresh download of the pinned
+//! release for the host triple into the cache directory.
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::observability::weaver::toolchain::WeaverToolchain;
+//!
+//! let weaver = WeaverToolchain::new().resolve()?;
+//! println!("using weaver {} at {}", weaver.version(), weaver.path().display());
+//! # Ok::<(), chicago_tdd_tools::observability::weaver::toolchain::WeaverToolchainError>(())
+//! ```
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use semver::{Version, VersionReq};
+use thiserror::Error;
+
+use crate::core::command::{CheckedCommand, DOWNLOAD_COMMAND_TIMEOUT, PROBE_COMMAND_TIMEOUT};
+use crate::core::layout::ProjectLayout;
+
+/// Weaver release downloaded when no matching binary is found
+pub const WEAVER_RELEASE: &str = "0.19.0";
+
+/// Weaver versions accepted by default
+pub const WEAVER_REQUIREMENT: &str = "^0.19";
+
+/// Weaver toolchain errors
+#[derive(Error, Debug)]
+pub enum WeaverToolchainError {
+    /// `weaver --version` could not be run or its output not parsed
+    #[error("🚨 Cannot determine Weaver version of {path}: {reason}\n   ⚠️  STOP: Binary may be missing or corrupted\n   💡 FIX: Run cargo make weaver-bootstrap")]
+    VersionCheck {
+        /// Binary that was probed
+        path: PathBuf,
+        /// Why the probe failed
+        reason: String,
+    },
+    /// The binary's version is outside the required range
+    #[error("🚨 Weaver {found} at {path} does not satisfy {required}\n   ⚠️  STOP: Incompatible Weaver version\n   💡 FIX: Install a matching release or relax the requirement (WeaverToolchain::with_requirement)")]
+    VersionMismatch {
+        /// Binary that was probed
+        path: PathBuf,
+        /// Version it reported
+        found: Version,
+        /// Required range
+        required: VersionReq,
+    },
+    /// No Weaver release is published for this host
+    #[error("🚨 No Weaver release for host {arch}-{os}\n   ⚠️  STOP: Cannot download Weaver\n   💡 FIX: Build from source: cargo install weaver")]
+    UnsupportedHost {
+        /// `std::env::consts::ARCH`
+        arch: &'static str,
+        /// `std::env::consts::OS`
+        os: &'static str,
+    },
+    /// Downloading or unpacking the release failed
+    #[error("🚨 Failed to download Weaver from {url}: {reason}\n   ⚠️  STOP: No Weaver binary satisfying {required} is available\n   💡 FIX: Run cargo make weaver-bootstrap\n   📋 Manual: cargo install weaver\n   📋 Download: https://github.com/open-telemetry/weaver/releases")]
+    Download {
+        /// Release archive URL
+        url: String,
+        /// Required range
+        required: VersionReq,
+        /// Why the download failed
+        reason: String,
+    },
+}
+
+/// Result type for Weaver toolchain operations
+pub type WeaverToolchainResult<T> = Result<T, WeaverToolchainError>;
+
+/// A verified Weaver binary
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedWeaver {
+    path: PathBuf,
+    version: Version,
+}
+
+impl ResolvedWeaver {
+    /// Path to run (a bare `weaver` when found on `PATH`)
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Version reported by `weaver --version`
+    #[must_use]
+    pub const fn version(&self) -> &Version {
+        &self.version
+    }
+
+    /// Consume into the binary path
+    #[must_use]
+    pub fn into_path(self) -> PathBuf {
+        self.path
+    }
+}
+
+/// Locates, verifies, and downloads the Weaver CLI
+///
+/// Candidates are tried in order: `weaver` on `PATH`, the cache directory, then
+/// `target/debug` and `target/release` (where `build.rs` places its download).
+#[derive(Debug, Clone)]
+pub struct WeaverToolchain {
+    requirement: VersionReq,
+    release: Version,
+    cache_dir: PathBuf,
+}
+
+impl WeaverToolchain {
+    /// Toolchain requiring [`WEAVER_REQUIREMENT`], downloading [`WEAVER_RELEASE`] into
+    /// `target/<profile>`
+    #[must_use]
+    pub fn new() -> Self {
+        let profile = if cfg!(debug_assertions) { "debug" } else { "release" };
+        Self {
+            requirement: VersionReq::parse(WEAVER_REQUIREMENT).unwrap_or(VersionReq::STAR),
+            release: Version::parse(WEAVER_RELEASE).unwrap_or_else(|_| Version::new(0, 0, 0)),
+            cache_dir: ProjectLayout::detect().target_dir().join(profile),
+        }
+    }
+
+    /// Accept only versions matching `requirement`
+    #[must_use]
+    pub fn with_requirement(mut self, requirement: VersionReq) -> Self {
+        self.requirement = requirement;
+        self
+    }
+
+    /// Download `release` when no matching binary is found
+    #[must_use]
+    pub fn with_release(mut self, release: Version) -> Self {
+        self.release = release;
+        self
+    }
+
+    /// Download into `cache_dir`
+    #[must_use]
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = cache_dir.into();
+        self
+    }
+
+    /// Required version range
+    #[must_use]
+    pub const fn requirement(&self) -> &VersionReq {
+        &self.requirement
+    }
+
+    /// Cache directory downloads are placed in
+    #[must_use]
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Path the downloaded binary is stored at
+    #[must_use]
+    pub fn cached_binary(&self) -> PathBuf {
+        self.cache_dir.join(binary_name())
+    }
+
+    /// Binaries to probe, in order
+    #[must_use]
+    pub fn candidates(&self) -> Vec<PathBuf> {
+        let target_dir = ProjectLayout::detect().target_dir().to_path_buf();
+        let mut candidates = vec![PathBuf::from("weaver"), self.cached_binary()];
+        for profile in ["debug", "release"] {
+            let path = target_dir.join(profile).join(binary_name());
+            if !candidates.contains(&path) {
+                candidates.push(path);
+            }
+        }
+        candidates
+    }
+
+    /// Version reported by `path --version`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the binary cannot be run or prints no semver version.
+    pub fn version_of(path: &Path) -> WeaverToolchainResult<Version> {
+        let output = CheckedCommand::new(path)
+            .arg("--version")
+            .timeout(PROBE_COMMAND_TIMEOUT)
+            .run()
+            .map_err(|e| WeaverToolchainError::VersionCheck {
+                path: path.to_path_buf(),
+                reason: e.to_string(),
+            })?;
+        let stdout = output.stdout_lossy();
+        parse_version(&stdout).ok_or_else(|| WeaverToolchainError::VersionCheck {
+            path: path.to_path_buf(),
+            reason: format!("no version in output {:?}", stdout.trim()),
+        })
+    }
+
+    /// Check that `path` runs and satisfies the required range
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version cannot be determined or is out of range.
+    pub fn verify(&self, path: &Path) -> WeaverToolchainResult<ResolvedWeaver> {
+        let version = Self::version_of(path)?;
+        if self.requirement.matches(&version) {
+            Ok(ResolvedWeaver { path: path.to_path_buf(), version })
+        } else {
+            Err(WeaverToolchainError::VersionMismatch {
+                path: path.to_path_buf(),
+                found: version,
+                required: self.requirement.clone(),
+            })
+        }
+    }
+
+    /// First candidate satisfying the required range, without downloading
+    #[must_use]
+    pub fn locate(&self) -> Option<ResolvedWeaver> {
+        self.candidates().iter().find_map(|path| self.verify(path).ok())
+    }
+
+    /// Locate a matching binary, downloading the release if none is found
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no candidate matches and the download fails or yields a
+    /// binary outside the required range.
+    pub fn resolve(&self) -> WeaverToolchainResult<ResolvedWeaver> {
+        if let Some(weaver) = self.locate() {
+            return Ok(weaver);
+        }
+        let path = self.download()?;
+        self.verify(&path)
+    }
+
+    /// Release archive URL for this host
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no release is published for the host triple.
+    pub fn release_url(&self) -> WeaverToolchainResult<String> {
+        let triple = host_triple()?;
+        let extension = if cfg!(windows) { "zip" } else { "tar.xz" };
+        Ok(format!(
+            "https://github.com/open-telemetry/weaver/releases/download/v{}/weaver-{triple}.{extension}",
+            self.release
+        ))
+    }
+
+    /// Download the release for this host into the cache directory
+    ///
+    /// Replaces any binary already cached there.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the host is unsupported or downloading or unpacking fails.
+    pub fn download(&self) -> WeaverToolchainResult<PathBuf> {
+        let url = self.release_url()?;
+        self.fetch(&url).map_err(|reason| WeaverToolchainError::Download {
+            url,
+            required: self.requirement.clone(),
+            reason,
+        })
+    }
+
+    fn fetch(&self, url: &str) -> Result<PathBuf, String> {
+        fs::create_dir_all(&self.cache_dir)
+            .map_err(|e| format!("Failed to create {}: {e}", self.cache_dir.display()))?;
+        let staging = tempfile::tempdir_in(&self.cache_dir)
+            .map_err(|e| format!("Failed to create staging directory: {e}"))?;
+        let archive = staging.path().join(url.rsplit('/').next().unwrap_or("weaver-archive"));
+        let archive_str =
+            archive.to_str().ok_or_else(|| "Archive path is not valid UTF-8".to_string())?;
+
+        if CheckedCommand::is_available("curl") {
+            CheckedCommand::new("curl")
+                .args(["-fsSL", "-o", archive_str, url])
+                .timeout(DOWNLOAD_COMMAND_TIMEOUT)
+                .run()
+                .map_err(|e| format!("curl download failed: {e}"))?;
+        } else if CheckedCommand::is_available("wget") {
+            CheckedCommand::new("wget")
+                .args(["-q", "-O", archive_str, url])
+                .timeout(DOWNLOAD_COMMAND_TIMEOUT)
+                .run()
+                .map_err(|e| format!("wget download failed: {e}"))?;
+        } else {
+            return Err("Neither curl nor wget found".to_string());
+        }
+
+        // tar (bsdtar on Windows) unpacks both the .tar.xz and .zip archives
+        CheckedCommand::new("tar")
+            .arg("-xf")
+            .arg(&archive)
+            .arg("-C")
+            .arg(staging.path())
+            .timeout(DOWNLOAD_COMMAND_TIMEOUT)
+            .run()
+            .map_err(|e| format!("Failed to extract {archive_str}: {e}"))?;
+
+        // Release archives hold the binary at the root or under `weaver-<triple>/`
+        let unpacked = host_triple()
+            .ok()
+            .map(|triple| staging.path().join(format!("weaver-{triple}")).join(binary_name()))
+            .into_iter()
+            .chain([staging.path().join(binary_name())])
+            .find(|path| path.is_file())
+            .ok_or_else(|| format!("{} not found in {archive_str}", binary_name()))?;
+
+        let cached = self.cached_binary();
+        fs::rename(&unpacked, &cached)
+            .map_err(|e| format!("Failed to move binary to {}: {e}", cached.display()))?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            fs::set_permissions(&cached, fs::Permissions::from_mode(0o755))
+                .map_err(|e| format!("Failed to make {} executable: {e}", cached.display()))?;
+        }
+
+        Ok(cached)
+    }
+}
+
+impl Default for WeaverToolchain {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Target triple of the published release matching this host
+///
+/// # Errors
+///
+/// Returns an error if Weaver publishes no release for the host.
+pub fn host_triple() -> WeaverToolchainResult<&'static str> {
+    use std::env::consts::{ARCH, OS};
+    match (ARCH, OS) {
+        ("x86_64", "linux") => Ok("x86_64-unknown-linux-gnu"),
+        ("aarch64", "linux") => Ok("aarch64-unknown-linux-gnu"),
+        ("x86_64", "macos") => Ok("x86_64-apple-darwin"),
+        ("aarch64", "macos") => Ok("aarch64-apple-darwin"),
+        ("x86_64", "windows") => Ok("x86_64-pc-windows-msvc"),
+        (arch, os) => Err(WeaverToolchainError::UnsupportedHost { arch, os }),
+    }
+}
+
+const fn binary_name() -> &'static str {
+    if cfg!(windows) {
+        "weaver.exe"
+    } else {
+        "weaver"
+    }
+}
+
+/// First semver version in `weaver --version` output (`weaver 0.19.0`)
+fn parse_version(output: &str) -> Option<Version> {
+    output
+        .split_whitespace()
+        .find_map(|token| Version::parse(token.trim_start_matches('v')).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_version_from_weaver_output() {
+        assert_eq!(parse_version("weaver 0.19.0\n"), Some(Version::new(0, 19, 0)));
+        assert_eq!(parse_version("weaver v0.20.1"), Some(Version::new(0, 20, 1)));
+        assert_eq!(parse_version("weaver (unknown)"), None);
+    }
+
+    #[test]
+    fn test_toolchain_defaults_and_candidates() {
+        let cache = tempfile::tempdir().unwrap();
+        let toolchain = WeaverToolchain::new().with_cache_dir(cache.path());
+
+        assert!(toolchain.requirement().matches(&Version::parse(WEAVER_RELEASE).unwrap()));
+        assert!(!toolchain.requirement().matches(&Version::new(0, 18, 0)));
+        let candidates = toolchain.candidates();
+        assert_eq!(candidates[0], PathBuf::from("weaver"));
+        assert_eq!(candidates[1], cache.path().join(binary_name()));
+        if let Ok(triple) = host_triple() {
+            let url = toolchain.release_url().unwrap();
+            assert!(url.contains(&format!("/v{WEAVER_RELEASE}/weaver-{triple}.")));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_verify_checks_version_against_requirement() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let fake = dir.path().join("weaver");
+        fs::write(&fake, "#!/bin/sh\necho weaver 0.19.2\n").unwrap();
+        fs::set_permissions(&fake, fs::Permissions::from_mode(0o755)).unwrap();
+
+        let resolved = WeaverToolchain::new().verify(&fake).unwrap();
+        assert_eq!(resolved.path(), fake);
+        assert_eq!(resolved.version(), &Version::new(0, 19, 2));
+
+        let strict = WeaverToolchain::new().with_requirement(VersionReq::parse(">=0.20").unwrap());
+        assert!(matches!(
+            strict.verify(&fake),
+            Err(WeaverToolchainError::VersionMismatch { found, .. }) if found == Version::new(0, 19, 2)
+        ));
+        assert!(matches!(
+            WeaverToolchain::new().verify(&dir.path().join("missing")),
+            Err(WeaverToolchainError::VersionCheck { .. })
+        ));
+    }
+}
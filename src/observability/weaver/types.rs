@@ -3,6 +3,9 @@
 //! `WeaverLiveCheck` implementation ported from knhk-otel for standalone use.
 //! Used for live validation of OpenTelemetry telemetry against semantic conventions.
 
+use crate::observability::weaver::toolchain::{
+    ResolvedWeaver, WeaverToolchain, WeaverToolchainError,
+};
 use std::process::Child;
 use thiserror::Error;
 
@@ -21,6 +24,9 @@ pub enum WeaverValidationError {
     /// Failed to stop Weaver process
     #[error("Failed to stop Weaver: {0}")]
     StopFailed(String),
+    /// No compatible Weaver binary could be located or downloaded
+    #[error("{0}")]
+    Toolchain(#[from] WeaverToolchainError),
 }
 
 /// Weaver live-check integration for telemetry validation
@@ -33,6 +39,7 @@ pub struct WeaverLiveCheck {
     inactivity_timeout: u64,
     format: String,
     output: Option<String>,
+    advice_policies: Option<String>,
 }
 
 impl WeaverLiveCheck {
@@ -47,6 +54,7 @@ impl WeaverLiveCheck {
             inactivity_timeout: 10,     // Match weaver default (not 60)
             format: "ansi".to_string(), // Match weaver default (not "json")
             output: None,
+            advice_policies: None,
         }
     }
 
@@ -99,81 +107,36 @@ impl WeaverLiveCheck {
         self
     }
 
-    /// Find weaver binary in multiple locations
-    /// Checks: PATH, target/debug/weaver, target/release/weaver
+    /// Set a directory of custom Rego advice policies (`--advice-policies`)
+    ///
+    /// Weaver evaluates these policies instead of its default live-check policies; the
+    /// built-in advisors (deprecation, stability, type, unit) still run.
     #[must_use]
-    pub fn find_weaver_binary() -> Option<std::path::PathBuf> {
-        use std::path::PathBuf;
-        use std::process::Command;
-
-        // 1. Check PATH first
-        if Command::new("weaver").arg("--version").output().is_ok() {
-            return Some(PathBuf::from("weaver"));
-        }
-
-        // 2. Check target/debug/weaver
-        let debug_path = PathBuf::from("target/debug/weaver");
-        if debug_path.exists() {
-            return Some(debug_path);
-        }
-
-        // 3. Check target/release/weaver
-        let release_path = PathBuf::from("target/release/weaver");
-        if release_path.exists() {
-            return Some(release_path);
-        }
+    pub fn with_policies(mut self, policies_dir: String) -> Self {
+        self.advice_policies = Some(policies_dir);
+        self
+    }
 
-        None
+    /// Find a Weaver binary satisfying the toolchain's version range
+    ///
+    /// Checks `PATH`, the download cache, then `target/debug/weaver` and
+    /// `target/release/weaver` (see [`WeaverToolchain`]); never downloads.
+    #[must_use]
+    pub fn find_weaver_binary() -> Option<std::path::PathBuf> {
+        WeaverToolchain::new().locate().map(ResolvedWeaver::into_path)
     }
 
-    /// Check if Weaver binary is available (checks multiple locations)
+    /// Check if a compatible Weaver binary is available, downloading it if needed
     ///
-    /// 🚨 CRITICAL - Returns error if Weaver binary not found.
+    /// 🚨 CRITICAL - Returns error if no Weaver binary satisfying the version range can be found
+    /// or downloaded.
     ///
     /// # Errors
     ///
     /// Returns an error if Weaver binary is not found.
     pub fn check_weaver_available() -> Result<(), WeaverValidationError> {
-        use std::process::Command;
-
-        // Try to find weaver binary
-        if let Some(binary_path) = Self::find_weaver_binary() {
-            // Try to run weaver --version to check if it exists and works
-            match Command::new(&binary_path).arg("--version").output() {
-                Ok(output) => {
-                    if output.status.success() {
-                        // ✅ Weaver binary is available and working
-                        Ok(())
-                    } else {
-                        Err(WeaverValidationError::BinaryNotFound(
-                            "Weaver binary found but --version failed. Binary may be corrupted."
-                                .to_string(),
-                        ))
-                    }
-                }
-                Err(e) => Err(WeaverValidationError::BinaryNotFound(format!(
-                    "Failed to execute weaver binary: {e}"
-                ))),
-            }
-        } else {
-            // Try runtime download if not found (only if weaver feature is enabled)
-            #[cfg(feature = "weaver")]
-            {
-                if let Err(e) = Self::download_weaver_runtime() {
-                    return Err(WeaverValidationError::BinaryNotFound(format!(
-                        "Weaver binary not found. Run cargo make weaver-bootstrap. Manual fallback: cargo install weaver or download from https://github.com/open-telemetry/weaver/releases ({e})."
-                    )));
-                }
-                // Retry after download
-                Self::check_weaver_available()
-            }
-            #[cfg(not(feature = "weaver"))]
-            {
-                Err(WeaverValidationError::BinaryNotFound(
-                    "Weaver binary not found. Run cargo make weaver-bootstrap. Manual fallback: cargo install weaver or download from https://github.com/open-telemetry/weaver/releases".to_string()
-                ))
-            }
-        }
+        WeaverToolchain::new().resolve()?;
+        Ok(())
     }
 
     /// Check if Weaver semantic convention registry is available and accessible
@@ -183,7 +146,7 @@ impl WeaverLiveCheck {
     /// Solution: Check path exists, is readable, contains required files (5s timeout max).
     ///
     /// Checks:
-    /// 1. Registry path exists at `./registry/`
+    /// 1. Registry path exists at `<workspace root>/registry/` (see `ProjectLayout`)
     /// 2. Registry is readable (not permission denied)
     /// 3. Contains expected semantic convention files
     /// 4. All operations complete within timeout
@@ -205,11 +168,11 @@ impl WeaverLiveCheck {
     pub fn check_registry_available() -> Result<(), String> {
         const MAX_CHECK_TIME_MS: u128 = 5000; // 5 second timeout
 
+        use crate::core::layout::ProjectLayout;
         use std::fs;
-        use std::path::PathBuf;
         use std::time::Instant;
 
-        let registry_path = PathBuf::from("registry");
+        let registry_path = ProjectLayout::detect().registry_dir();
         let start_time = Instant::now();
 
         // Check 1: Path exists
@@ -301,138 +264,6 @@ impl WeaverLiveCheck {
         Ok(())
     }
 
-    /// Download weaver binary at runtime if not found
-    #[cfg(feature = "weaver")]
-    fn download_weaver_runtime() -> Result<(), String> {
-        use std::env;
-        use std::fs;
-        use std::path::PathBuf;
-        use std::process::Command;
-
-        // Determine target directory
-        let profile = env::var("PROFILE").unwrap_or_else(|_| "debug".to_string());
-        let output_path = PathBuf::from(format!("target/{profile}/weaver"));
-
-        // Skip if already exists
-        if output_path.exists() {
-            return Ok(());
-        }
-
-        // Detect platform
-        let target = env::var("TARGET").unwrap_or_else(|_| "unknown".to_string());
-        let (arch, os) = Self::detect_platform_from_target(&target);
-        let weaver_version = "0.19.0";
-
-        // Construct download URL
-        let download_url = format!(
-            "https://github.com/open-telemetry/weaver/releases/download/v{weaver_version}/weaver-{arch}-{os}.tar.xz"
-        );
-
-        // Create parent directory
-        if let Some(parent) = output_path.parent() {
-            fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {e}"))?;
-        }
-
-        // Download using curl or wget
-        let archive_path = output_path.with_extension("tar.xz");
-        if Command::new("curl").arg("--version").output().is_ok() {
-            let archive_str = archive_path
-                .to_str()
-                .ok_or_else(|| "Archive path is not valid UTF-8".to_string())?;
-            let status = Command::new("curl")
-                .args(["-L", "-o", archive_str, &download_url])
-                .status()
-                .map_err(|e| format!("Failed to execute curl: {e}"))?;
-
-            if !status.success() {
-                return Err("curl download failed".to_string());
-            }
-        } else if Command::new("wget").arg("--version").output().is_ok() {
-            let archive_str = archive_path
-                .to_str()
-                .ok_or_else(|| "Archive path is not valid UTF-8".to_string())?;
-            let status = Command::new("wget")
-                .args(["-O", archive_str, &download_url])
-                .status()
-                .map_err(|e| format!("Failed to execute wget: {e}"))?;
-
-            if !status.success() {
-                return Err("wget download failed".to_string());
-            }
-        } else {
-            return Err(
-                "Neither curl nor wget found. Please install one to download weaver.".to_string()
-            );
-        }
-
-        // Extract tar.xz
-        let output_dir = archive_path
-            .parent()
-            .ok_or_else(|| "Archive path has no parent directory".to_string())?;
-        let archive_str = archive_path
-            .to_str()
-            .ok_or_else(|| "Archive path is not valid UTF-8".to_string())?;
-        let output_dir_str = output_dir
-            .to_str()
-            .ok_or_else(|| "Output directory path is not valid UTF-8".to_string())?;
-        let status = Command::new("tar")
-            .args(["-xJf", archive_str, "-C", output_dir_str])
-            .status()
-            .map_err(|e| format!("Failed to extract tar.xz: {e}"))?;
-
-        if !status.success() {
-            return Err("tar extraction failed".to_string());
-        }
-
-        // Find and move weaver binary
-        let weaver_binary = output_dir.join("weaver");
-        if weaver_binary.exists() {
-            fs::rename(&weaver_binary, &output_path)
-                .map_err(|e| format!("Failed to move weaver binary: {e}"))?;
-        }
-
-        // Clean up archive
-        let _ = fs::remove_file(&archive_path);
-
-        // Make executable (Unix-like systems)
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            if let Ok(mut perms) = fs::metadata(&output_path).map(|m| m.permissions()) {
-                perms.set_mode(0o755);
-                let _ = fs::set_permissions(&output_path, perms);
-            }
-        }
-
-        Ok(())
-    }
-
-    /// Detect platform from TARGET environment variable
-    #[cfg(feature = "weaver")]
-    fn detect_platform_from_target(target: &str) -> (&'static str, &'static str) {
-        if target.contains("x86_64") {
-            if target.contains("linux") {
-                ("x86_64", "unknown-linux-gnu")
-            } else if target.contains("darwin") || target.contains("macos") {
-                ("x86_64", "apple-darwin")
-            } else if target.contains("windows") {
-                ("x86_64", "pc-windows-msvc")
-            } else {
-                ("x86_64", "unknown")
-            }
-        } else if target.contains("aarch64") || target.contains("arm64") {
-            if target.contains("linux") {
-                ("aarch64", "unknown-linux-gnu")
-            } else if target.contains("darwin") || target.contains("macos") {
-                ("aarch64", "apple-darwin")
-            } else {
-                ("aarch64", "unknown")
-            }
-        } else {
-            ("unknown", "unknown")
-        }
-    }
-
     /// Check Weaver health by querying the admin endpoint
     ///
     /// # Errors
@@ -461,36 +292,36 @@ impl WeaverLiveCheck {
     /// Returns an error if Weaver binary is not available or starting the process fails.
     pub fn start(&self) -> Result<Child, String> {
         // Items (use statements) must come before statements (Rust requirement)
-        use std::process::Command;
-
-        // Check Weaver binary availability first (may trigger runtime download)
-        Self::check_weaver_available().map_err(|e| format!("{e}"))?;
+        use crate::core::command::CheckedCommand;
 
-        // Find weaver binary path
-        let weaver_binary = Self::find_weaver_binary()
-            .ok_or_else(|| "Weaver binary not found after check".to_string())?;
+        // Resolve a compatible Weaver binary (may trigger runtime download)
+        let weaver = WeaverToolchain::new().resolve().map_err(|e| format!("{e}"))?;
 
-        let mut cmd = Command::new(&weaver_binary);
-
-        cmd.args(["registry", "live-check"]);
+        let mut cmd = CheckedCommand::new(weaver.path()).args(["registry", "live-check"]);
 
         if let Some(ref registry) = self.registry_path {
-            cmd.args(["--registry", registry]);
+            cmd = cmd.args(["--registry", registry]);
         }
 
-        cmd.args(["--otlp-grpc-address", &self.otlp_grpc_address]);
-        cmd.args(["--otlp-grpc-port", &self.otlp_grpc_port.to_string()]);
-        cmd.args(["--admin-port", &self.admin_port.to_string()]);
-        cmd.args(["--inactivity-timeout", &self.inactivity_timeout.to_string()]);
-        cmd.args(["--format", &self.format]);
+        cmd = cmd
+            .args(["--otlp-grpc-address", &self.otlp_grpc_address])
+            .args(["--otlp-grpc-port", &self.otlp_grpc_port.to_string()])
+            .args(["--admin-port", &self.admin_port.to_string()])
+            .args(["--inactivity-timeout", &self.inactivity_timeout.to_string()])
+            .args(["--format", &self.format]);
 
         if let Some(ref output) = self.output {
-            cmd.args(["--output", output]);
+            cmd = cmd.args(["--output", output]);
+        }
+
+        if let Some(ref policies) = self.advice_policies {
+            cmd = cmd.args(["--advice-policies", policies]);
         }
 
+        // Long-running process: the caller owns the child, so no timeout applies
         cmd.spawn()
             .map_err(|e| {
-                if e.kind() == std::io::ErrorKind::NotFound {
+                if e.is_not_found() {
                     "🚨 Weaver binary not found in PATH. Run cargo make weaver-bootstrap (installs CLI locally) or cargo install weaver".to_string()
                 } else {
                     format!("Failed to start Weaver live-check: {e}. Ensure Weaver is installed and in PATH.")
@@ -628,6 +459,7 @@ mod tests {
         assert_eq!(check.format, "ansi"); // Match weaver default
         assert!(check.registry_path.is_none());
         assert!(check.output.is_none());
+        assert!(check.advice_policies.is_none());
     }
 
     #[test]
@@ -649,7 +481,8 @@ mod tests {
             .with_admin_port(8081)
             .with_inactivity_timeout(120)
             .with_format("ansi".to_string())
-            .with_output("/tmp/output".to_string());
+            .with_output("/tmp/output".to_string())
+            .with_policies("policies/live_check".to_string());
 
         assert_eq!(check.registry_path, Some("/path/to/registry".to_string()));
         assert_eq!(check.otlp_grpc_address, "0.0.0.0");
@@ -658,6 +491,7 @@ mod tests {
         assert_eq!(check.inactivity_timeout, 120);
         assert_eq!(check.format, "ansi");
         assert_eq!(check.output, Some("/tmp/output".to_string()));
+        assert_eq!(check.advice_policies, Some("policies/live_check".to_string()));
     }
 
     #[test]
@@ -0,0 +1,776 @@
+//! Ontology-to-Rust Code Generation
+//!
+//! Reads a TTL ontology and emits Rust types for its workflow stages, guard types,
+//! guard constraints, and knowledge hooks, with a constant for every IRI. The output
+//! is committed as `rdf::generated`; a test regenerates it from
+//! `ontology/chatman-equation.ttl` and fails when the two drift apart.
+//!
+//! Items are recognised by local name so the generator works across prefixes:
+//! - guard types are instances of a `Guard_Type`/`GuardType` class
+//! - guard constraints are instances of a `Guard` class with a `guardType`
+//! - knowledge hooks are subjects with a `hookId`
+//! - workflow stages are instances of a class whose name ends in `Stage`
+//!
+//! Run `cargo make rdf-codegen` to rewrite the generated module after changing the
+//! ontology.
+
+use super::turtle::{Term, TurtleDocument, TurtleError, RDF_TYPE};
+use std::fmt::{self, Write as _};
+use std::path::{Path, PathBuf};
+
+/// Environment variable that makes the drift test rewrite the generated module
+pub const UPDATE_ENV: &str = "UPDATE_RDF_CODEGEN";
+
+/// Errors raised by the code generator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CodegenError {
+    /// Reading or writing a file failed
+    Io {
+        /// File path
+        path: PathBuf,
+        /// Underlying I/O error
+        reason: String,
+    },
+    /// The ontology is not valid Turtle
+    Parse(TurtleError),
+    /// The ontology is valid Turtle but cannot be mapped to Rust types
+    Model(String),
+    /// The generated file does not match the ontology
+    Drift {
+        /// Generated file that is out of date
+        path: PathBuf,
+        /// First differing line (1-based)
+        line: usize,
+        /// Line the ontology produces
+        expected: String,
+        /// Line found in the file
+        actual: String,
+    },
+}
+
+impl fmt::Display for CodegenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, reason } => write!(f, "{}: {reason}", path.display()),
+            Self::Parse(err) => write!(f, "{err}"),
+            Self::Model(reason) => write!(f, "Ontology cannot be mapped to Rust: {reason}"),
+            Self::Drift { path, line, expected, actual } => write!(
+                f,
+                "{} is out of date with the ontology at line {line}\n  expected: {expected}\n  \
+                 actual:   {actual}\nRun `cargo make rdf-codegen` to regenerate it",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CodegenError {}
+
+impl From<TurtleError> for CodegenError {
+    fn from(err: TurtleError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+#[derive(Debug)]
+struct Item {
+    iri: String,
+    ident: String,
+    label: String,
+    comment: Option<String>,
+}
+
+#[derive(Debug)]
+struct Constraint {
+    item: Item,
+    guard_type: String,
+}
+
+#[derive(Debug)]
+struct Hook {
+    item: Item,
+    id: String,
+    deterministic: bool,
+    bounded: bool,
+    max_latency_ns: u64,
+    guards: Vec<String>,
+}
+
+#[derive(Debug)]
+struct Stage {
+    item: Item,
+    number: u32,
+    deterministic: bool,
+    max_latency_seconds: u32,
+}
+
+/// Generates Rust types from a TTL ontology
+#[derive(Debug)]
+pub struct OntologyCodegen {
+    source: String,
+    guard_types: Vec<Item>,
+    constraints: Vec<Constraint>,
+    hooks: Vec<Hook>,
+    stages: Vec<Stage>,
+}
+
+impl OntologyCodegen {
+    /// Build a generator from TTL text; `source` names the ontology in the output header
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TTL does not parse or references undefined guard types.
+    pub fn from_turtle(source: &str, ttl: &str) -> Result<Self, CodegenError> {
+        let doc = TurtleDocument::parse(ttl)?;
+        let mut codegen = Self {
+            source: source.to_string(),
+            guard_types: Vec::new(),
+            constraints: Vec::new(),
+            hooks: Vec::new(),
+            stages: Vec::new(),
+        };
+
+        for subject in doc.subjects() {
+            let Some(iri) = subject.as_iri() else { continue };
+            let classes: Vec<&str> = doc
+                .objects(subject, RDF_TYPE)
+                .iter()
+                .filter_map(|t| t.as_iri())
+                .map(local_name)
+                .collect();
+            let item = |ident: String| Item {
+                iri: iri.to_string(),
+                ident,
+                label: literal(&doc, subject, "label")
+                    .unwrap_or_else(|| local_name(iri).to_string()),
+                comment: literal(&doc, subject, "comment"),
+            };
+
+            if classes.iter().any(|c| matches!(*c, "Guard_Type" | "GuardType")) {
+                codegen.guard_types.push(item(pascal_case(local_name(iri))));
+            } else if classes.contains(&"Guard") {
+                let guard_type = iri_property(&doc, subject, "guardType")
+                    .ok_or_else(|| CodegenError::Model(format!("guard {iri} has no guardType")))?;
+                codegen.constraints.push(Constraint {
+                    item: item(screaming_case(local_name(iri))),
+                    guard_type: guard_type.to_string(),
+                });
+            } else if let Some(hook_id) = literal(&doc, subject, "hookId") {
+                let mut hook_item = item(pascal_case(&hook_id));
+                if let Some(name) = literal(&doc, subject, "patternName") {
+                    hook_item.label = name;
+                }
+                codegen.hooks.push(Hook {
+                    item: hook_item,
+                    deterministic: flag(&doc, subject, "deterministic"),
+                    bounded: flag(&doc, subject, "bounded"),
+                    max_latency_ns: number(&doc, subject, "maxLatencyNs")?.unwrap_or(0),
+                    guards: doc
+                        .objects(subject, &predicate(&doc, subject, "hasGuard"))
+                        .iter()
+                        .filter_map(|t| t.as_iri().map(str::to_string))
+                        .collect(),
+                    id: hook_id,
+                });
+            } else if classes.iter().any(|c| c.ends_with("Stage")) {
+                let mut stage_item = item(pascal_case(local_name(iri)));
+                if let Some(name) = literal(&doc, subject, "stageName") {
+                    stage_item.label = name;
+                }
+                codegen.stages.push(Stage {
+                    item: stage_item,
+                    number: number(&doc, subject, "stageNumber")?.unwrap_or(0),
+                    deterministic: flag(&doc, subject, "deterministic"),
+                    max_latency_seconds: number(&doc, subject, "maxLatencySeconds")?.unwrap_or(0),
+                });
+            }
+        }
+
+        codegen.validate()?;
+        Ok(codegen)
+    }
+
+    /// Build a generator from a TTL file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or mapped to Rust types.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, CodegenError> {
+        let path = path.as_ref();
+        let ttl = std::fs::read_to_string(path).map_err(|e| io_error(path, &e))?;
+        let source = path.file_name().map_or_else(
+            || path.display().to_string(),
+            |name| format!("ontology/{}", name.to_string_lossy()),
+        );
+        Self::from_turtle(&source, &ttl)
+    }
+
+    /// Number of generated workflow stages, guard types, guard constraints, and hooks
+    #[must_use]
+    pub const fn counts(&self) -> (usize, usize, usize, usize) {
+        (self.stages.len(), self.guard_types.len(), self.constraints.len(), self.hooks.len())
+    }
+
+    /// Check that `path` holds exactly what [`generate`](Self::generate) produces
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CodegenError::Drift`] at the first differing line.
+    pub fn check(&self, path: impl AsRef<Path>) -> Result<(), CodegenError> {
+        let path = path.as_ref();
+        let actual = std::fs::read_to_string(path).map_err(|e| io_error(path, &e))?;
+        let expected = self.generate();
+        if actual == expected {
+            return Ok(());
+        }
+
+        let mut expected_lines = expected.lines();
+        let mut actual_lines = actual.lines();
+        let mut line = 1;
+        loop {
+            match (expected_lines.next(), actual_lines.next()) {
+                (Some(e), Some(a)) if e == a => line += 1,
+                (e, a) => {
+                    return Err(CodegenError::Drift {
+                        path: path.to_path_buf(),
+                        line,
+                        expected: e.unwrap_or("<end of file>").to_string(),
+                        actual: a.unwrap_or("<end of file>").to_string(),
+                    })
+                }
+            }
+        }
+    }
+
+    /// Write the generated module to `path`, returning whether its content changed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<bool, CodegenError> {
+        let path = path.as_ref();
+        let generated = self.generate();
+        if std::fs::read_to_string(path).is_ok_and(|current| current == generated) {
+            return Ok(false);
+        }
+        std::fs::write(path, generated).map_err(|e| io_error(path, &e))?;
+        Ok(true)
+    }
+
+    /// Generate the Rust module source
+    #[must_use]
+    pub fn generate(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(out, "//! Rust types generated from `{}`", self.source);
+        out.push_str("//!\n");
+        out.push_str("//! @generated by `sector_stacks::rdf::codegen`. Do not edit by hand; run\n");
+        out.push_str("//! `cargo make rdf-codegen` after changing the ontology.\n\n");
+        out.push_str(
+            "#![allow(clippy::enum_variant_names, clippy::match_same_arms)] \
+             // One variant and arm per ontology item, named as in the ontology\n\n",
+        );
+
+        self.emit_iris(&mut out);
+        if !self.stages.is_empty() {
+            self.emit_stages(&mut out);
+        }
+        if !self.guard_types.is_empty() {
+            self.emit_guard_types(&mut out);
+        }
+        if !self.constraints.is_empty() {
+            self.emit_constraints(&mut out);
+        }
+        if !self.hooks.is_empty() {
+            self.emit_hooks(&mut out);
+        }
+        out
+    }
+
+    fn validate(&self) -> Result<(), CodegenError> {
+        let known = |iri: &str| self.guard_types.iter().any(|g| g.iri == iri);
+        for constraint in &self.constraints {
+            if !known(&constraint.guard_type) {
+                return Err(CodegenError::Model(format!(
+                    "guard {} references undefined guard type {}",
+                    constraint.item.iri, constraint.guard_type
+                )));
+            }
+        }
+        for hook in &self.hooks {
+            if let Some(guard) = hook.guards.iter().find(|g| !known(g)) {
+                return Err(CodegenError::Model(format!(
+                    "hook {} references undefined guard type {guard}",
+                    hook.id
+                )));
+            }
+        }
+
+        let mut idents: Vec<(&str, &str)> = self
+            .stages
+            .iter()
+            .map(|s| ("stage", s.item.ident.as_str()))
+            .chain(self.guard_types.iter().map(|g| ("guard type", g.ident.as_str())))
+            .chain(self.constraints.iter().map(|c| ("guard", c.item.ident.as_str())))
+            .chain(self.hooks.iter().map(|h| ("hook", h.item.ident.as_str())))
+            .collect();
+        idents.sort_unstable();
+        if let Some(pair) = idents.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(CodegenError::Model(format!(
+                "two {}s map to the Rust name {}",
+                pair[0].0, pair[0].1
+            )));
+        }
+        Ok(())
+    }
+
+    fn guard_type_ident(&self, iri: &str) -> &str {
+        self.guard_types.iter().find(|g| g.iri == iri).map_or("", |g| g.ident.as_str())
+    }
+
+    fn emit_iris(&self, out: &mut String) {
+        out.push_str("/// IRIs of every generated item\n");
+        out.push_str("pub mod iri {\n");
+        let groups: [(&str, Vec<&Item>); 4] = [
+            ("STAGE", self.stages.iter().map(|s| &s.item).collect()),
+            ("GUARD_TYPE", self.guard_types.iter().collect()),
+            ("GUARD", self.constraints.iter().map(|c| &c.item).collect()),
+            ("HOOK", self.hooks.iter().map(|h| &h.item).collect()),
+        ];
+        let mut first = true;
+        for (prefix, items) in &groups {
+            for item in items {
+                if !first {
+                    out.push('\n');
+                }
+                first = false;
+                let _ = writeln!(out, "    /// {}", doc_line(&item.label));
+                let _ = writeln!(
+                    out,
+                    "    pub const {}: &str = {:?};",
+                    iri_const(prefix, &item.ident),
+                    item.iri
+                );
+            }
+        }
+        out.push_str("}\n");
+    }
+
+    fn emit_stages(&self, out: &mut String) {
+        let stages: Vec<&Item> = self.stages.iter().map(|s| &s.item).collect();
+        emit_enum(out, "WorkflowStage", "Workflow stages defined in the ontology", &stages);
+        out.push_str("\nimpl WorkflowStage {\n");
+        emit_all(out, "Every stage, in ontology order", &stages);
+        emit_iri_fn(out, "STAGE", "stage", &stages);
+        emit_match_fn(out, "Stage name", "name", "&'static str", &self.stages, |s| {
+            (s.item.ident.clone(), format!("{:?}", s.item.label))
+        });
+        emit_match_fn(
+            out,
+            "Position of the stage in the workflow",
+            "stage_number",
+            "u32",
+            &self.stages,
+            |s| (s.item.ident.clone(), separated(s.number)),
+        );
+        emit_match_fn(
+            out,
+            "Whether the stage is deterministic",
+            "is_deterministic",
+            "bool",
+            &self.stages,
+            |s| (s.item.ident.clone(), s.deterministic.to_string()),
+        );
+        emit_match_fn(
+            out,
+            "Maximum latency in seconds",
+            "max_latency_seconds",
+            "u32",
+            &self.stages,
+            |s| (s.item.ident.clone(), separated(s.max_latency_seconds)),
+        );
+        out.push_str("}\n");
+    }
+
+    fn emit_guard_types(&self, out: &mut String) {
+        let guard_types: Vec<&Item> = self.guard_types.iter().collect();
+        emit_enum(out, "GuardType", "Guard types defined in the ontology", &guard_types);
+        out.push_str("\nimpl GuardType {\n");
+        emit_all(out, "Every guard type, in ontology order", &guard_types);
+        emit_iri_fn(out, "GUARD_TYPE", "guard type", &guard_types);
+        emit_match_fn(
+            out,
+            "Label from the ontology",
+            "label",
+            "&'static str",
+            &self.guard_types,
+            |g| (g.ident.clone(), format!("{:?}", g.label)),
+        );
+        out.push_str("}\n");
+    }
+
+    fn emit_constraints(&self, out: &mut String) {
+        out.push_str("\n/// A guard constraint instance defined in the ontology\n");
+        out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq)]\n");
+        out.push_str("pub struct GuardConstraint {\n");
+        out.push_str("    /// Constraint IRI\n    pub iri: &'static str,\n");
+        out.push_str("    /// Label from the ontology\n    pub label: &'static str,\n");
+        out.push_str("    /// Description from the ontology\n    pub description: &'static str,\n");
+        out.push_str(
+            "    /// Guard type the constraint enforces\n    pub guard_type: GuardType,\n",
+        );
+        out.push_str("}\n");
+
+        for constraint in &self.constraints {
+            let item = &constraint.item;
+            let _ = writeln!(out, "\n/// {}", doc_line(&item.label));
+            let _ = writeln!(out, "pub const {}: GuardConstraint = GuardConstraint {{", item.ident);
+            let _ = writeln!(out, "    iri: iri::{},", iri_const("GUARD", &item.ident));
+            let _ = writeln!(out, "    label: {:?},", item.label);
+            let _ = writeln!(out, "    description: {:?},", item.comment.as_deref().unwrap_or(""));
+            let _ = writeln!(
+                out,
+                "    guard_type: GuardType::{},",
+                self.guard_type_ident(&constraint.guard_type)
+            );
+            out.push_str("};\n");
+        }
+
+        out.push_str("\n/// Every guard constraint, in ontology order\n");
+        let _ = writeln!(
+            out,
+            "pub const GUARD_CONSTRAINTS: [GuardConstraint; {}] = [",
+            self.constraints.len()
+        );
+        for constraint in &self.constraints {
+            let _ = writeln!(out, "    {},", constraint.item.ident);
+        }
+        out.push_str("];\n");
+    }
+
+    fn emit_hooks(&self, out: &mut String) {
+        let hooks: Vec<&Item> = self.hooks.iter().map(|h| &h.item).collect();
+        emit_enum(out, "KnowledgeHook", "Knowledge hooks defined in the ontology", &hooks);
+        out.push_str("\nimpl KnowledgeHook {\n");
+        emit_all(out, "Every hook, in ontology order", &hooks);
+        emit_iri_fn(out, "HOOK", "hook", &hooks);
+        emit_match_fn(out, "Hook identifier", "hook_id", "&'static str", &self.hooks, |h| {
+            (h.item.ident.clone(), format!("{:?}", h.id))
+        });
+        emit_match_fn(out, "Hook name", "name", "&'static str", &self.hooks, |h| {
+            (h.item.ident.clone(), format!("{:?}", h.item.label))
+        });
+        emit_match_fn(
+            out,
+            "Whether the hook is deterministic",
+            "is_deterministic",
+            "bool",
+            &self.hooks,
+            |h| (h.item.ident.clone(), h.deterministic.to_string()),
+        );
+        emit_match_fn(
+            out,
+            "Whether the hook's execution time is bounded",
+            "is_bounded",
+            "bool",
+            &self.hooks,
+            |h| (h.item.ident.clone(), h.bounded.to_string()),
+        );
+        emit_match_fn(
+            out,
+            "Maximum latency in nanoseconds (0 when unbounded)",
+            "max_latency_ns",
+            "u64",
+            &self.hooks,
+            |h| (h.item.ident.clone(), separated(h.max_latency_ns)),
+        );
+        emit_match_fn(
+            out,
+            "Guards that must hold before the hook runs",
+            "guards",
+            "&'static [GuardType]",
+            &self.hooks,
+            |h| {
+                let guards: Vec<String> = h
+                    .guards
+                    .iter()
+                    .map(|g| format!("GuardType::{}", self.guard_type_ident(g)))
+                    .collect();
+                (h.item.ident.clone(), format!("&[{}]", guards.join(", ")))
+            },
+        );
+        out.push_str("}\n");
+    }
+}
+
+fn emit_enum(out: &mut String, name: &str, doc: &str, items: &[&Item]) {
+    let _ = writeln!(out, "\n/// {doc}");
+    out.push_str("#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n");
+    let _ = writeln!(out, "pub enum {name} {{");
+    for item in items {
+        let _ =
+            writeln!(out, "    /// {}", doc_line(item.comment.as_deref().unwrap_or(&item.label)));
+        let _ = writeln!(out, "    {},", item.ident);
+    }
+    out.push_str("}\n");
+}
+
+fn emit_all(out: &mut String, doc: &str, items: &[&Item]) {
+    let _ = writeln!(out, "    /// {doc}");
+    let _ = writeln!(out, "    pub const ALL: [Self; {}] = [", items.len());
+    for item in items {
+        let _ = writeln!(out, "        Self::{},", item.ident);
+    }
+    out.push_str("    ];\n");
+}
+
+fn emit_iri_fn(out: &mut String, prefix: &str, noun: &str, items: &[&Item]) {
+    let arms: Vec<(String, String)> = items
+        .iter()
+        .map(|item| (item.ident.clone(), format!("iri::{}", iri_const(prefix, &item.ident))))
+        .collect();
+    emit_match(out, &format!("IRI of this {noun}"), "iri", "&'static str", &arms);
+}
+
+fn emit_match_fn<T>(
+    out: &mut String,
+    doc: &str,
+    name: &str,
+    ty: &str,
+    items: &[T],
+    arm: impl Fn(&T) -> (String, String),
+) {
+    let arms: Vec<(String, String)> = items.iter().map(arm).collect();
+    emit_match(out, doc, name, ty, &arms);
+}
+
+fn emit_match(out: &mut String, doc: &str, name: &str, ty: &str, arms: &[(String, String)]) {
+    let _ = writeln!(out, "\n    /// {doc}");
+    out.push_str("    #[must_use]\n");
+    let _ = writeln!(out, "    pub const fn {name}(self) -> {ty} {{");
+    out.push_str("        match self {\n");
+    for (variant, value) in arms {
+        let _ = writeln!(out, "            Self::{variant} => {value},");
+    }
+    out.push_str("        }\n    }\n");
+}
+
+fn io_error(path: &Path, err: &std::io::Error) -> CodegenError {
+    CodegenError::Io { path: path.to_path_buf(), reason: err.to_string() }
+}
+
+fn local_name(iri: &str) -> &str {
+    iri.rsplit(['#', '/']).next().unwrap_or(iri)
+}
+
+/// Full IRI of the first predicate of `subject` whose local name is `name`
+fn predicate(doc: &TurtleDocument, subject: &Term, name: &str) -> String {
+    doc.triples
+        .iter()
+        .find(|t| &t.subject == subject && local_name(&t.predicate) == name)
+        .map_or_else(String::new, |t| t.predicate.clone())
+}
+
+fn literal(doc: &TurtleDocument, subject: &Term, name: &str) -> Option<String> {
+    doc.objects(subject, &predicate(doc, subject, name))
+        .first()
+        .and_then(|t| t.as_literal())
+        .map(str::to_string)
+}
+
+fn iri_property<'a>(doc: &'a TurtleDocument, subject: &'a Term, name: &str) -> Option<&'a str> {
+    let predicate = predicate(doc, subject, name);
+    doc.triples
+        .iter()
+        .find(|t| &t.subject == subject && t.predicate == predicate)
+        .and_then(|t| t.object.as_iri())
+}
+
+fn flag(doc: &TurtleDocument, subject: &Term, name: &str) -> bool {
+    literal(doc, subject, name).is_some_and(|value| value == "true")
+}
+
+fn number<T: std::str::FromStr>(
+    doc: &TurtleDocument,
+    subject: &Term,
+    name: &str,
+) -> Result<Option<T>, CodegenError> {
+    literal(doc, subject, name)
+        .map(|value| {
+            value.trim().parse().map_err(|_| {
+                CodegenError::Model(format!("{subject} has a non-numeric {name}: {value:?}"))
+            })
+        })
+        .transpose()
+}
+
+fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+    for c in name.chars() {
+        if !c.is_ascii_alphanumeric() {
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
+        }
+        if c.is_ascii_uppercase() && prev_lower {
+            words.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        current.push(c);
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+fn pascal_case(name: &str) -> String {
+    let ident: String = words(name)
+        .iter()
+        .map(|word| {
+            let lower = word.to_ascii_lowercase();
+            let mut chars = lower.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("N{ident}")
+    } else {
+        ident
+    }
+}
+
+fn screaming_case(name: &str) -> String {
+    let ident = words(name).join("_").to_ascii_uppercase();
+    if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("N_{ident}")
+    } else {
+        ident
+    }
+}
+
+fn iri_const(prefix: &str, ident: &str) -> String {
+    format!("{prefix}_{}", screaming_case(ident))
+}
+
+/// Integer literal with `_` separators, as clippy's `unreadable_literal` expects
+fn separated(value: impl Into<u64>) -> String {
+    let digits = value.into().to_string();
+    if digits.len() <= 4 {
+        return digits;
+    }
+    let mut out = String::new();
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push('_');
+        }
+        out.push(c);
+    }
+    out
+}
+
+fn doc_line(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STAGES_TTL: &str = r#"
+        @prefix cp: <http://chatman-equation.org/claims/> .
+        @prefix ce: <http://chatman-equation.org/core/> .
+        @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+        <http://chatman-equation.org/guards/Budget> a ce:Guard_Type ; rdfs:label "Budget Guard" .
+
+        <http://chatman-equation.org/claims/stage/fraud-detection> a cp:Stage ;
+            cp:stageNumber 2 ; cp:stageName "Fraud Detection" ;
+            cp:deterministic true ; cp:maxLatencySeconds 5 .
+
+        <http://chatman-equation.org/claims/hooks/settle> ce:hookId "settle_claim" ;
+            ce:bounded true ; ce:maxLatencyNs 2000 ;
+            ce:hasGuard <http://chatman-equation.org/guards/Budget> .
+    "#;
+
+    #[test]
+    fn test_generates_stages_guards_and_hooks() {
+        let codegen = OntologyCodegen::from_turtle("ontology/claims.ttl", STAGES_TTL).unwrap();
+
+        let generated = codegen.generate();
+
+        assert_eq!(codegen.counts(), (1, 1, 0, 1));
+        assert!(generated.starts_with("//! Rust types generated from `ontology/claims.ttl`"));
+        assert!(generated.contains(
+            "    pub const STAGE_FRAUD_DETECTION: &str = \
+             \"http://chatman-equation.org/claims/stage/fraud-detection\";"
+        ));
+        assert!(generated.contains("            Self::FraudDetection => \"Fraud Detection\","));
+        assert!(generated.contains("            Self::FraudDetection => 2,"));
+        assert!(generated.contains("            Self::SettleClaim => &[GuardType::Budget],"));
+        assert!(!generated.contains("GuardConstraint"));
+    }
+
+    #[test]
+    fn test_rejects_undefined_guard_types() {
+        let ttl = STAGES_TTL.replace("guards/Budget> a", "guards/Other> a");
+
+        let err = OntologyCodegen::from_turtle("claims.ttl", &ttl).unwrap_err();
+
+        assert!(matches!(&err, CodegenError::Model(reason) if reason.contains("settle_claim")));
+    }
+
+    #[test]
+    fn test_check_reports_first_drifting_line() {
+        let codegen = OntologyCodegen::from_turtle("claims.ttl", STAGES_TTL).unwrap();
+        let path = std::env::temp_dir().join(format!("rdf-codegen-{}.rs", std::process::id()));
+        let stale = codegen.generate().replace("=> 2,", "=> 3,");
+        std::fs::write(&path, &stale).unwrap();
+
+        let err = codegen.check(&path).unwrap_err();
+        let rewritten = codegen.write(&path).unwrap();
+        let checked = codegen.check(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            &err,
+            CodegenError::Drift { expected, actual, .. }
+                if expected.ends_with("=> 2,") && actual.ends_with("=> 3,")
+        ));
+        assert!(rewritten);
+        assert!(checked.is_ok());
+    }
+
+    #[test]
+    fn test_identifiers() {
+        assert_eq!(pascal_case("fraud-detection"), "FraudDetection");
+        assert_eq!(pascal_case("incl_or_join_op"), "InclOrJoinOp");
+        assert_eq!(pascal_case("Guard_Type"), "GuardType");
+        assert_eq!(screaming_case("legality_001"), "LEGALITY_001");
+        assert_eq!(screaming_case("SequenceOp"), "SEQUENCE_OP");
+        assert_eq!(separated(86_400_u32), "86_400");
+        assert_eq!(separated(1000_u32), "1000");
+    }
+
+    /// Drift gate: `generated.rs` must match `ontology/chatman-equation.ttl`
+    #[test]
+    fn test_generated_module_matches_ontology() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let codegen =
+            OntologyCodegen::from_file(root.join("ontology/chatman-equation.ttl")).unwrap();
+        let generated = root.join("src/sector_stacks/rdf/generated.rs");
+
+        if std::env::var_os(UPDATE_ENV).is_some() {
+            codegen.write(&generated).unwrap();
+        }
+
+        if let Err(err) = codegen.check(&generated) {
+            panic!("{err}");
+        }
+    }
+}
@@ -0,0 +1,369 @@
+//! Rust types generated from `ontology/chatman-equation.ttl`
+//!
+//! @generated by `sector_stacks::rdf::codegen`. Do not edit by hand; run
+//! `cargo make rdf-codegen` after changing the ontology.
+
+#![allow(clippy::enum_variant_names, clippy::match_same_arms)] // One variant and arm per ontology item, named as in the ontology
+
+/// IRIs of every generated item
+pub mod iri {
+    /// Legality Guard
+    pub const GUARD_TYPE_LEGALITY: &str = "http://chatman-equation.org/guards/Legality";
+
+    /// Budget Guard
+    pub const GUARD_TYPE_BUDGET: &str = "http://chatman-equation.org/guards/Budget";
+
+    /// Chronology Guard
+    pub const GUARD_TYPE_CHRONOLOGY: &str = "http://chatman-equation.org/guards/Chronology";
+
+    /// Causality Guard
+    pub const GUARD_TYPE_CAUSALITY: &str = "http://chatman-equation.org/guards/Causality";
+
+    /// Recursion Guard
+    pub const GUARD_TYPE_RECURSION: &str = "http://chatman-equation.org/guards/Recursion";
+
+    /// Valid AAA Sequence
+    pub const GUARD_LEGALITY_001: &str = "http://chatman-equation.org/guards/instance/legality_001";
+
+    /// Execution Time Limit
+    pub const GUARD_BUDGET_001: &str = "http://chatman-equation.org/guards/instance/budget_001";
+
+    /// Chatman Constant Recursion Bound
+    pub const GUARD_RECURSION_001: &str = "http://chatman-equation.org/guards/instance/recursion_001";
+
+    /// Sequence
+    pub const HOOK_SEQUENCE_OP: &str = "http://chatman-equation.org/operators/YAWL_001_Sequence";
+
+    /// Parallel Split
+    pub const HOOK_PARALLEL_SPLIT_OP: &str = "http://chatman-equation.org/operators/YAWL_002_Parallel_Split";
+
+    /// Synchronization
+    pub const HOOK_SYNCHRONIZATION_OP: &str = "http://chatman-equation.org/operators/YAWL_003_Synchronization";
+
+    /// Exclusive Choice
+    pub const HOOK_EXCLUSIVE_CHOICE_OP: &str = "http://chatman-equation.org/operators/YAWL_004_Exclusive_Choice";
+
+    /// Simple Merge
+    pub const HOOK_SIMPLE_MERGE_OP: &str = "http://chatman-equation.org/operators/YAWL_005_Simple_Merge";
+
+    /// Multiple Choice
+    pub const HOOK_MULTIPLE_CHOICE_OP: &str = "http://chatman-equation.org/operators/YAWL_006_Multiple_Choice";
+
+    /// Structured Synchronizing Merge
+    pub const HOOK_SYNC_MERGE_OP: &str = "http://chatman-equation.org/operators/YAWL_007_Structured_Synchronizing_Merge";
+
+    /// Deferred Choice
+    pub const HOOK_DEFERRED_CHOICE_OP: &str = "http://chatman-equation.org/operators/YAWL_015_Deferred_Choice";
+
+    /// Arbitrary Cycles
+    pub const HOOK_CYCLES_OP: &str = "http://chatman-equation.org/operators/YAWL_020_Arbitrary_Cycles";
+
+    /// Inclusive Or with Multiple Instance Join
+    pub const HOOK_INCL_OR_JOIN_OP: &str = "http://chatman-equation.org/operators/YAWL_025_Inclusive_Or_with_Multiple_Instance_Join";
+
+    /// Multiple Instance Parallel
+    pub const HOOK_MULTI_PARALLEL_OP: &str = "http://chatman-equation.org/operators/YAWL_030_Multiple_Instance_Parallel";
+
+    /// State-Based Concurrency
+    pub const HOOK_STATE_CONCURRENCY_OP: &str = "http://chatman-equation.org/operators/YAWL_035_State_Based_Concurrency";
+
+    /// Cancellation Region
+    pub const HOOK_CANCEL_REGION_OP: &str = "http://chatman-equation.org/operators/YAWL_040_Cancellation_Region";
+
+    /// Force Completion
+    pub const HOOK_FORCE_COMPLETE_OP: &str = "http://chatman-equation.org/operators/YAWL_043_Force_Completion";
+}
+
+/// Guard types defined in the ontology
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GuardType {
+    /// Prevents illegal state transitions
+    Legality,
+    /// Prevents exceeding resource limits (time, memory, iterations)
+    Budget,
+    /// Enforces proper temporal ordering
+    Chronology,
+    /// Ensures causal dependencies are respected
+    Causality,
+    /// Bounds recursion depth (Chatman Constant = 8)
+    Recursion,
+}
+
+impl GuardType {
+    /// Every guard type, in ontology order
+    pub const ALL: [Self; 5] = [
+        Self::Legality,
+        Self::Budget,
+        Self::Chronology,
+        Self::Causality,
+        Self::Recursion,
+    ];
+
+    /// IRI of this guard type
+    #[must_use]
+    pub const fn iri(self) -> &'static str {
+        match self {
+            Self::Legality => iri::GUARD_TYPE_LEGALITY,
+            Self::Budget => iri::GUARD_TYPE_BUDGET,
+            Self::Chronology => iri::GUARD_TYPE_CHRONOLOGY,
+            Self::Causality => iri::GUARD_TYPE_CAUSALITY,
+            Self::Recursion => iri::GUARD_TYPE_RECURSION,
+        }
+    }
+
+    /// Label from the ontology
+    #[must_use]
+    pub const fn label(self) -> &'static str {
+        match self {
+            Self::Legality => "Legality Guard",
+            Self::Budget => "Budget Guard",
+            Self::Chronology => "Chronology Guard",
+            Self::Causality => "Causality Guard",
+            Self::Recursion => "Recursion Guard",
+        }
+    }
+}
+
+/// A guard constraint instance defined in the ontology
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuardConstraint {
+    /// Constraint IRI
+    pub iri: &'static str,
+    /// Label from the ontology
+    pub label: &'static str,
+    /// Description from the ontology
+    pub description: &'static str,
+    /// Guard type the constraint enforces
+    pub guard_type: GuardType,
+}
+
+/// Valid AAA Sequence
+pub const LEGALITY_001: GuardConstraint = GuardConstraint {
+    iri: iri::GUARD_LEGALITY_001,
+    label: "Valid AAA Sequence",
+    description: "Ensures AAA pattern: Arrange -> Act -> Assert",
+    guard_type: GuardType::Legality,
+};
+
+/// Execution Time Limit
+pub const BUDGET_001: GuardConstraint = GuardConstraint {
+    iri: iri::GUARD_BUDGET_001,
+    label: "Execution Time Limit",
+    description: "Prevents test execution from exceeding time limit",
+    guard_type: GuardType::Budget,
+};
+
+/// Chatman Constant Recursion Bound
+pub const RECURSION_001: GuardConstraint = GuardConstraint {
+    iri: iri::GUARD_RECURSION_001,
+    label: "Chatman Constant Recursion Bound",
+    description: "Limits recursion depth to Chatman Constant (8)",
+    guard_type: GuardType::Recursion,
+};
+
+/// Every guard constraint, in ontology order
+pub const GUARD_CONSTRAINTS: [GuardConstraint; 3] = [
+    LEGALITY_001,
+    BUDGET_001,
+    RECURSION_001,
+];
+
+/// Knowledge hooks defined in the ontology
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KnowledgeHook {
+    /// Two tasks executed sequentially
+    SequenceOp,
+    /// Fork into multiple parallel tasks
+    ParallelSplitOp,
+    /// Join parallel tasks at synchronization point
+    SynchronizationOp,
+    /// One path chosen based on condition
+    ExclusiveChoiceOp,
+    /// Merge two branches (uncontrolled)
+    SimpleMergeOp,
+    /// Multiple paths selected based on condition
+    MultipleChoiceOp,
+    /// Structured Synchronizing Merge
+    SyncMergeOp,
+    /// Deferred Choice
+    DeferredChoiceOp,
+    /// Arbitrary Cycles
+    CyclesOp,
+    /// Inclusive Or with Multiple Instance Join
+    InclOrJoinOp,
+    /// Multiple Instance Parallel
+    MultiParallelOp,
+    /// State-Based Concurrency
+    StateConcurrencyOp,
+    /// Cancellation Region
+    CancelRegionOp,
+    /// Force Completion
+    ForceCompleteOp,
+}
+
+impl KnowledgeHook {
+    /// Every hook, in ontology order
+    pub const ALL: [Self; 14] = [
+        Self::SequenceOp,
+        Self::ParallelSplitOp,
+        Self::SynchronizationOp,
+        Self::ExclusiveChoiceOp,
+        Self::SimpleMergeOp,
+        Self::MultipleChoiceOp,
+        Self::SyncMergeOp,
+        Self::DeferredChoiceOp,
+        Self::CyclesOp,
+        Self::InclOrJoinOp,
+        Self::MultiParallelOp,
+        Self::StateConcurrencyOp,
+        Self::CancelRegionOp,
+        Self::ForceCompleteOp,
+    ];
+
+    /// IRI of this hook
+    #[must_use]
+    pub const fn iri(self) -> &'static str {
+        match self {
+            Self::SequenceOp => iri::HOOK_SEQUENCE_OP,
+            Self::ParallelSplitOp => iri::HOOK_PARALLEL_SPLIT_OP,
+            Self::SynchronizationOp => iri::HOOK_SYNCHRONIZATION_OP,
+            Self::ExclusiveChoiceOp => iri::HOOK_EXCLUSIVE_CHOICE_OP,
+            Self::SimpleMergeOp => iri::HOOK_SIMPLE_MERGE_OP,
+            Self::MultipleChoiceOp => iri::HOOK_MULTIPLE_CHOICE_OP,
+            Self::SyncMergeOp => iri::HOOK_SYNC_MERGE_OP,
+            Self::DeferredChoiceOp => iri::HOOK_DEFERRED_CHOICE_OP,
+            Self::CyclesOp => iri::HOOK_CYCLES_OP,
+            Self::InclOrJoinOp => iri::HOOK_INCL_OR_JOIN_OP,
+            Self::MultiParallelOp => iri::HOOK_MULTI_PARALLEL_OP,
+            Self::StateConcurrencyOp => iri::HOOK_STATE_CONCURRENCY_OP,
+            Self::CancelRegionOp => iri::HOOK_CANCEL_REGION_OP,
+            Self::ForceCompleteOp => iri::HOOK_FORCE_COMPLETE_OP,
+        }
+    }
+
+    /// Hook identifier
+    #[must_use]
+    pub const fn hook_id(self) -> &'static str {
+        match self {
+            Self::SequenceOp => "sequence_op",
+            Self::ParallelSplitOp => "parallel_split_op",
+            Self::SynchronizationOp => "synchronization_op",
+            Self::ExclusiveChoiceOp => "exclusive_choice_op",
+            Self::SimpleMergeOp => "simple_merge_op",
+            Self::MultipleChoiceOp => "multiple_choice_op",
+            Self::SyncMergeOp => "sync_merge_op",
+            Self::DeferredChoiceOp => "deferred_choice_op",
+            Self::CyclesOp => "cycles_op",
+            Self::InclOrJoinOp => "incl_or_join_op",
+            Self::MultiParallelOp => "multi_parallel_op",
+            Self::StateConcurrencyOp => "state_concurrency_op",
+            Self::CancelRegionOp => "cancel_region_op",
+            Self::ForceCompleteOp => "force_complete_op",
+        }
+    }
+
+    /// Hook name
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::SequenceOp => "Sequence",
+            Self::ParallelSplitOp => "Parallel Split",
+            Self::SynchronizationOp => "Synchronization",
+            Self::ExclusiveChoiceOp => "Exclusive Choice",
+            Self::SimpleMergeOp => "Simple Merge",
+            Self::MultipleChoiceOp => "Multiple Choice",
+            Self::SyncMergeOp => "Structured Synchronizing Merge",
+            Self::DeferredChoiceOp => "Deferred Choice",
+            Self::CyclesOp => "Arbitrary Cycles",
+            Self::InclOrJoinOp => "Inclusive Or with Multiple Instance Join",
+            Self::MultiParallelOp => "Multiple Instance Parallel",
+            Self::StateConcurrencyOp => "State-Based Concurrency",
+            Self::CancelRegionOp => "Cancellation Region",
+            Self::ForceCompleteOp => "Force Completion",
+        }
+    }
+
+    /// Whether the hook is deterministic
+    #[must_use]
+    pub const fn is_deterministic(self) -> bool {
+        match self {
+            Self::SequenceOp => true,
+            Self::ParallelSplitOp => true,
+            Self::SynchronizationOp => true,
+            Self::ExclusiveChoiceOp => true,
+            Self::SimpleMergeOp => false,
+            Self::MultipleChoiceOp => true,
+            Self::SyncMergeOp => true,
+            Self::DeferredChoiceOp => false,
+            Self::CyclesOp => true,
+            Self::InclOrJoinOp => true,
+            Self::MultiParallelOp => true,
+            Self::StateConcurrencyOp => false,
+            Self::CancelRegionOp => true,
+            Self::ForceCompleteOp => true,
+        }
+    }
+
+    /// Whether the hook's execution time is bounded
+    #[must_use]
+    pub const fn is_bounded(self) -> bool {
+        match self {
+            Self::SequenceOp => true,
+            Self::ParallelSplitOp => true,
+            Self::SynchronizationOp => true,
+            Self::ExclusiveChoiceOp => true,
+            Self::SimpleMergeOp => true,
+            Self::MultipleChoiceOp => true,
+            Self::SyncMergeOp => true,
+            Self::DeferredChoiceOp => true,
+            Self::CyclesOp => true,
+            Self::InclOrJoinOp => true,
+            Self::MultiParallelOp => true,
+            Self::StateConcurrencyOp => false,
+            Self::CancelRegionOp => true,
+            Self::ForceCompleteOp => true,
+        }
+    }
+
+    /// Maximum latency in nanoseconds (0 when unbounded)
+    #[must_use]
+    pub const fn max_latency_ns(self) -> u64 {
+        match self {
+            Self::SequenceOp => 1_000_000_000,
+            Self::ParallelSplitOp => 5_000_000_000,
+            Self::SynchronizationOp => 5_000_000_000,
+            Self::ExclusiveChoiceOp => 1_000_000_000,
+            Self::SimpleMergeOp => 1_000_000_000,
+            Self::MultipleChoiceOp => 2_000_000_000,
+            Self::SyncMergeOp => 5_000_000_000,
+            Self::DeferredChoiceOp => 10_000_000_000,
+            Self::CyclesOp => 30_000_000_000,
+            Self::InclOrJoinOp => 10_000_000_000,
+            Self::MultiParallelOp => 50_000_000_000,
+            Self::StateConcurrencyOp => 0,
+            Self::CancelRegionOp => 5_000_000_000,
+            Self::ForceCompleteOp => 1_000_000_000,
+        }
+    }
+
+    /// Guards that must hold before the hook runs
+    #[must_use]
+    pub const fn guards(self) -> &'static [GuardType] {
+        match self {
+            Self::SequenceOp => &[GuardType::Chronology],
+            Self::ParallelSplitOp => &[GuardType::Legality, GuardType::Causality],
+            Self::SynchronizationOp => &[GuardType::Causality],
+            Self::ExclusiveChoiceOp => &[GuardType::Legality],
+            Self::SimpleMergeOp => &[GuardType::Legality],
+            Self::MultipleChoiceOp => &[GuardType::Legality],
+            Self::SyncMergeOp => &[GuardType::Causality, GuardType::Chronology],
+            Self::DeferredChoiceOp => &[GuardType::Legality, GuardType::Budget],
+            Self::CyclesOp => &[GuardType::Recursion, GuardType::Budget],
+            Self::InclOrJoinOp => &[GuardType::Legality, GuardType::Causality],
+            Self::MultiParallelOp => &[GuardType::Budget, GuardType::Recursion],
+            Self::StateConcurrencyOp => &[GuardType::Legality, GuardType::Chronology],
+            Self::CancelRegionOp => &[GuardType::Legality, GuardType::Budget],
+            Self::ForceCompleteOp => &[GuardType::Legality],
+        }
+    }
+}
@@ -11,11 +11,31 @@
 //! - **`GuardConstraint`**: Safety constraints (Budget, Chronology, etc.)
 //! - **`KnowledgeHook`**: Operations within workflows
 //! - **`RdfOperationValidator`**: Runtime validation against ontology
+//! - **`TurtleDocument`**: Minimal TTL parser for the sector ontologies
+//! - **`OntologyCodegen`**: Generates Rust types from a TTL ontology
+//! - **`ShapesGraph`**: SHACL shapes that instance data is validated against
+//!
+//! ## Generated Types
+//!
+//! `generated` holds the Rust types for `ontology/chatman-equation.ttl` (guard types,
+//! guard constraints, knowledge hooks, and their IRIs). A test fails when the module
+//! drifts from the ontology; run `cargo make rdf-codegen` to regenerate it.
 
+pub mod codegen;
+#[rustfmt::skip] // Generated by `codegen`; the drift test compares it byte for byte
+pub mod generated;
 pub mod ontology;
+pub mod shacl;
+pub mod turtle;
 pub mod validation;
 
+pub use codegen::{CodegenError, OntologyCodegen};
 pub use ontology::{GuardConstraint, KnowledgeHook, SectorOntology, WorkflowStage};
+pub use shacl::{
+    ConstraintComponent, PropertyPath, Severity, ShaclError, ShaclReport, ShaclViolation,
+    ShapesGraph,
+};
+pub use turtle::{Term, Triple, TurtleDocument, TurtleError};
 pub use validation::{RdfOperationValidator, RdfValidationError, RdfValidationResult};
 
 #[cfg(test)]
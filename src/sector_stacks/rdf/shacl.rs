@@ -0,0 +1,932 @@
+//! SHACL Shape Validation
+//!
+//! Validates instance data against SHACL shapes loaded from TTL files that sit
+//! alongside the ontology (see `ontology/shapes/`). Supports the SHACL Core subset the
+//! sector ontologies need:
+//!
+//! - Targets: `sh:targetClass` (including subclasses), `sh:targetNode`,
+//!   `sh:targetSubjectsOf`, `sh:targetObjectsOf`
+//! - Paths: predicate paths and `sh:inversePath`
+//! - Constraints: `sh:minCount`, `sh:maxCount`, `sh:datatype`, `sh:class`,
+//!   `sh:nodeKind`, `sh:in`, `sh:hasValue`, `sh:pattern`/`sh:flags`, `sh:minLength`,
+//!   `sh:maxLength`, `sh:minInclusive`, `sh:maxInclusive`, `sh:minExclusive`,
+//!   `sh:maxExclusive`, `sh:node`
+//! - `sh:severity`, `sh:message`, `sh:deactivated`
+//!
+//! Any other `sh:` constraint is rejected when the shapes load, so a shape never
+//! passes silently because part of it was ignored.
+
+use super::turtle::{Term, TurtleDocument, TurtleError, RDF_TYPE};
+use regex::Regex;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// SHACL namespace
+pub const SH: &str = "http://www.w3.org/ns/shacl#";
+
+const RDFS_SUBCLASS_OF: &str = "http://www.w3.org/2000/01/rdf-schema#subClassOf";
+const RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+/// Maximum depth of nested `sh:node` checks (the Chatman constant)
+const MAX_NODE_DEPTH: usize = 8;
+
+/// Errors raised while loading shapes
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShaclError {
+    /// The shapes file could not be read
+    Io {
+        /// Shapes file path
+        path: PathBuf,
+        /// Underlying I/O error
+        reason: String,
+    },
+    /// The shapes file is not valid Turtle
+    Parse(TurtleError),
+    /// A shape uses an unsupported or malformed constraint
+    InvalidShape {
+        /// Shape identifier
+        shape: String,
+        /// What is wrong with it
+        reason: String,
+    },
+}
+
+impl fmt::Display for ShaclError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io { path, reason } => write!(f, "{}: {reason}", path.display()),
+            Self::Parse(err) => write!(f, "{err}"),
+            Self::InvalidShape { shape, reason } => write!(f, "Invalid shape {shape}: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for ShaclError {}
+
+impl From<TurtleError> for ShaclError {
+    fn from(err: TurtleError) -> Self {
+        Self::Parse(err)
+    }
+}
+
+/// Severity of a validation result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// `sh:Info`
+    Info,
+    /// `sh:Warning`
+    Warning,
+    /// `sh:Violation` (the default)
+    Violation,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Info => "Info",
+            Self::Warning => "Warning",
+            Self::Violation => "Violation",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Path from a focus node to the values a property shape constrains
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropertyPath {
+    /// Objects of the predicate
+    Predicate(String),
+    /// Subjects of the predicate (`sh:inversePath`)
+    Inverse(String),
+}
+
+impl PropertyPath {
+    fn values<'a>(&self, data: &'a TurtleDocument, focus: &Term) -> Vec<&'a Term> {
+        match self {
+            Self::Predicate(predicate) => data
+                .triples
+                .iter()
+                .filter(|t| &t.subject == focus && &t.predicate == predicate)
+                .map(|t| &t.object)
+                .collect(),
+            Self::Inverse(predicate) => data
+                .triples
+                .iter()
+                .filter(|t| &t.object == focus && &t.predicate == predicate)
+                .map(|t| &t.subject)
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for PropertyPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Predicate(predicate) => write!(f, "<{predicate}>"),
+            Self::Inverse(predicate) => write!(f, "^<{predicate}>"),
+        }
+    }
+}
+
+/// SHACL constraint component that produced a result
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConstraintComponent {
+    /// `sh:minCount`
+    MinCount,
+    /// `sh:maxCount`
+    MaxCount,
+    /// `sh:datatype`
+    Datatype,
+    /// `sh:class`
+    Class,
+    /// `sh:nodeKind`
+    NodeKind,
+    /// `sh:in`
+    In,
+    /// `sh:hasValue`
+    HasValue,
+    /// `sh:pattern`
+    Pattern,
+    /// `sh:minLength`
+    MinLength,
+    /// `sh:maxLength`
+    MaxLength,
+    /// `sh:minInclusive`
+    MinInclusive,
+    /// `sh:maxInclusive`
+    MaxInclusive,
+    /// `sh:minExclusive`
+    MinExclusive,
+    /// `sh:maxExclusive`
+    MaxExclusive,
+    /// `sh:node`
+    Node,
+}
+
+impl ConstraintComponent {
+    /// IRI of the component (e.g., `sh:MinCountConstraintComponent`)
+    #[must_use]
+    pub fn iri(self) -> String {
+        format!("{SH}{}ConstraintComponent", self.name())
+    }
+
+    const fn name(self) -> &'static str {
+        match self {
+            Self::MinCount => "MinCount",
+            Self::MaxCount => "MaxCount",
+            Self::Datatype => "Datatype",
+            Self::Class => "Class",
+            Self::NodeKind => "NodeKind",
+            Self::In => "In",
+            Self::HasValue => "HasValue",
+            Self::Pattern => "Pattern",
+            Self::MinLength => "MinLength",
+            Self::MaxLength => "MaxLength",
+            Self::MinInclusive => "MinInclusive",
+            Self::MaxInclusive => "MaxInclusive",
+            Self::MinExclusive => "MinExclusive",
+            Self::MaxExclusive => "MaxExclusive",
+            Self::Node => "Node",
+        }
+    }
+}
+
+impl fmt::Display for ConstraintComponent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sh:{}ConstraintComponent", self.name())
+    }
+}
+
+/// A single validation result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShaclViolation {
+    /// Node that failed the shape
+    pub focus_node: Term,
+    /// Path of the failing property, if the shape is a property shape
+    pub path: Option<PropertyPath>,
+    /// Offending value, if the constraint applies per value
+    pub value: Option<Term>,
+    /// Constraint that failed
+    pub constraint: ConstraintComponent,
+    /// Shape that declared the constraint
+    pub source_shape: Term,
+    /// Result severity
+    pub severity: Severity,
+    /// `sh:message` of the shape, or a description of the failure
+    pub message: String,
+}
+
+impl fmt::Display for ShaclViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.focus_node)?;
+        if let Some(path) = &self.path {
+            write!(f, " {path}")?;
+        }
+        write!(f, ": {} ({})", self.message, self.constraint)
+    }
+}
+
+/// Outcome of validating data against a shapes graph
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShaclReport {
+    /// Every validation result, in shape and focus node order
+    pub violations: Vec<ShaclViolation>,
+}
+
+impl ShaclReport {
+    /// Whether the data produced no validation results
+    #[must_use]
+    pub const fn conforms(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Results with at least `severity`
+    #[must_use]
+    pub fn at_least(&self, severity: Severity) -> Vec<&ShaclViolation> {
+        self.violations.iter().filter(|v| v.severity >= severity).collect()
+    }
+
+    /// Results for the focus node with IRI `iri`
+    #[must_use]
+    pub fn for_focus(&self, iri: &str) -> Vec<&ShaclViolation> {
+        self.violations.iter().filter(|v| v.focus_node.as_iri() == Some(iri)).collect()
+    }
+}
+
+impl fmt::Display for ShaclReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.conforms() {
+            return f.write_str("data conforms to all shapes");
+        }
+        write!(f, "{} SHACL validation result(s):", self.violations.len())?;
+        for violation in &self.violations {
+            write!(f, "\n  {violation}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Target {
+    Class(String),
+    Node(Term),
+    SubjectsOf(String),
+    ObjectsOf(String),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum NodeKind {
+    Iri,
+    BlankNode,
+    Literal,
+    BlankNodeOrIri,
+    BlankNodeOrLiteral,
+    IriOrLiteral,
+}
+
+impl NodeKind {
+    const fn matches(self, term: &Term) -> bool {
+        matches!(
+            (self, term),
+            (Self::Iri | Self::BlankNodeOrIri | Self::IriOrLiteral, Term::Iri(_))
+                | (
+                    Self::BlankNode | Self::BlankNodeOrIri | Self::BlankNodeOrLiteral,
+                    Term::Blank(_)
+                )
+                | (
+                    Self::Literal | Self::BlankNodeOrLiteral | Self::IriOrLiteral,
+                    Term::Literal { .. }
+                )
+        )
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Constraint {
+    MinCount(usize),
+    MaxCount(usize),
+    Datatype(String),
+    Class(String),
+    NodeKind(NodeKind, String),
+    In(Vec<Term>),
+    HasValue(Term),
+    Pattern(Regex),
+    MinLength(usize),
+    MaxLength(usize),
+    MinInclusive(f64),
+    MaxInclusive(f64),
+    MinExclusive(f64),
+    MaxExclusive(f64),
+    Node(Term),
+}
+
+#[derive(Debug, Clone)]
+struct Shape {
+    id: Term,
+    targets: Vec<Target>,
+    path: Option<PropertyPath>,
+    constraints: Vec<Constraint>,
+    properties: Vec<Self>,
+    severity: Severity,
+    message: Option<String>,
+    deactivated: bool,
+}
+
+/// A set of SHACL shapes
+#[derive(Debug, Clone, Default)]
+pub struct ShapesGraph {
+    shapes: Vec<Shape>,
+    by_id: BTreeMap<Term, usize>,
+}
+
+impl ShapesGraph {
+    /// Parse shapes from TTL text
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the TTL does not parse or a shape is unsupported.
+    pub fn from_turtle(ttl: &str) -> Result<Self, ShaclError> {
+        let doc = TurtleDocument::parse(ttl)?;
+        let shape_classes = [format!("{SH}NodeShape"), format!("{SH}PropertyShape")];
+        let target_predicates: Vec<String> =
+            ["targetClass", "targetNode", "targetSubjectsOf", "targetObjectsOf"]
+                .iter()
+                .map(|local| format!("{SH}{local}"))
+                .collect();
+        let nested: BTreeSet<&Term> = doc
+            .triples
+            .iter()
+            .filter(|t| t.predicate == format!("{SH}property"))
+            .map(|t| &t.object)
+            .collect();
+
+        let mut graph = Self::default();
+        for subject in doc.subjects() {
+            let typed = doc
+                .objects(subject, RDF_TYPE)
+                .iter()
+                .any(|t| t.as_iri().is_some_and(|iri| shape_classes.iter().any(|c| c == iri)));
+            let targeted = doc
+                .triples
+                .iter()
+                .any(|t| &t.subject == subject && target_predicates.contains(&t.predicate));
+            if (typed || targeted) && !nested.contains(subject) {
+                let shape = parse_shape(&doc, subject)?;
+                graph.by_id.insert(shape.id.clone(), graph.shapes.len());
+                graph.shapes.push(shape);
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Load shapes from a TTL file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or its shapes cannot be loaded.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ShaclError> {
+        let path = path.as_ref();
+        let ttl = std::fs::read_to_string(path)
+            .map_err(|e| ShaclError::Io { path: path.to_path_buf(), reason: e.to_string() })?;
+        Self::from_turtle(&ttl)
+    }
+
+    /// Combine two shapes graphs (e.g., one per shapes file)
+    #[must_use]
+    pub fn merge(mut self, other: Self) -> Self {
+        for shape in other.shapes {
+            self.by_id.insert(shape.id.clone(), self.shapes.len());
+            self.shapes.push(shape);
+        }
+        self
+    }
+
+    /// Number of top-level shapes
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    /// Whether the graph has no shapes
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Validate `data` against every targeted shape
+    #[must_use]
+    pub fn validate(&self, data: &TurtleDocument) -> ShaclReport {
+        let mut report = ShaclReport::default();
+        for shape in self.shapes.iter().filter(|s| !s.deactivated) {
+            for focus in focus_nodes(&shape.targets, data) {
+                self.check_shape(shape, &focus, data, 0, &mut report.violations);
+            }
+        }
+        report
+    }
+
+    fn check_shape(
+        &self,
+        shape: &Shape,
+        focus: &Term,
+        data: &TurtleDocument,
+        depth: usize,
+        out: &mut Vec<ShaclViolation>,
+    ) {
+        if shape.deactivated {
+            return;
+        }
+        let values: Vec<&Term> =
+            shape.path.as_ref().map_or_else(|| vec![focus], |path| path.values(data, focus));
+
+        for constraint in &shape.constraints {
+            for (component, value, default_message) in
+                self.check_constraint(constraint, &values, data, depth)
+            {
+                out.push(ShaclViolation {
+                    focus_node: focus.clone(),
+                    path: shape.path.clone(),
+                    value,
+                    constraint: component,
+                    source_shape: shape.id.clone(),
+                    severity: shape.severity,
+                    message: shape.message.clone().unwrap_or(default_message),
+                });
+            }
+        }
+
+        for property in &shape.properties {
+            self.check_shape(property, focus, data, depth, out);
+        }
+    }
+
+    fn check_constraint(
+        &self,
+        constraint: &Constraint,
+        values: &[&Term],
+        data: &TurtleDocument,
+        depth: usize,
+    ) -> Vec<(ConstraintComponent, Option<Term>, String)> {
+        let count = values.len();
+        match constraint {
+            Constraint::MinCount(min) if count < *min => vec![(
+                ConstraintComponent::MinCount,
+                None,
+                format!("expected at least {min} value(s), found {count}"),
+            )],
+            Constraint::MaxCount(max) if count > *max => vec![(
+                ConstraintComponent::MaxCount,
+                None,
+                format!("expected at most {max} value(s), found {count}"),
+            )],
+            Constraint::HasValue(expected) if !values.contains(&expected) => vec![(
+                ConstraintComponent::HasValue,
+                None,
+                format!("missing required value {expected}"),
+            )],
+            Constraint::MinCount(_) | Constraint::MaxCount(_) | Constraint::HasValue(_) => {
+                Vec::new()
+            }
+            _ => values
+                .iter()
+                .filter_map(|value| {
+                    self.check_value(constraint, value, data, depth)
+                        .map(|(component, message)| (component, Some((*value).clone()), message))
+                })
+                .collect(),
+        }
+    }
+
+    fn check_value(
+        &self,
+        constraint: &Constraint,
+        value: &Term,
+        data: &TurtleDocument,
+        depth: usize,
+    ) -> Option<(ConstraintComponent, String)> {
+        let failure = |component: ConstraintComponent, message: String| Some((component, message));
+        match constraint {
+            Constraint::Datatype(datatype) if !has_datatype(value, datatype) => failure(
+                ConstraintComponent::Datatype,
+                format!("{value} is not a valid <{datatype}>"),
+            ),
+            Constraint::Class(class) if !is_instance_of(data, value, class) => failure(
+                ConstraintComponent::Class,
+                format!("{value} is not an instance of <{class}>"),
+            ),
+            Constraint::NodeKind(kind, name) if !kind.matches(value) => {
+                failure(ConstraintComponent::NodeKind, format!("{value} is not a {name}"))
+            }
+            Constraint::In(allowed) if !allowed.contains(value) => failure(
+                ConstraintComponent::In,
+                format!("{value} is not one of the allowed values"),
+            ),
+            Constraint::Pattern(pattern)
+                if !lexical_form(value).is_some_and(|text| pattern.is_match(text)) =>
+            {
+                failure(
+                    ConstraintComponent::Pattern,
+                    format!("{value} does not match /{}/", pattern.as_str()),
+                )
+            }
+            Constraint::MinLength(min)
+                if lexical_form(value).is_none_or(|text| text.chars().count() < *min) =>
+            {
+                failure(
+                    ConstraintComponent::MinLength,
+                    format!("{value} is shorter than {min} characters"),
+                )
+            }
+            Constraint::MaxLength(max)
+                if lexical_form(value).is_none_or(|text| text.chars().count() > *max) =>
+            {
+                failure(
+                    ConstraintComponent::MaxLength,
+                    format!("{value} is longer than {max} characters"),
+                )
+            }
+            Constraint::MinInclusive(bound) if !numeric(value).is_some_and(|n| n >= *bound) => {
+                failure(ConstraintComponent::MinInclusive, format!("{value} is less than {bound}"))
+            }
+            Constraint::MaxInclusive(bound) if !numeric(value).is_some_and(|n| n <= *bound) => {
+                failure(
+                    ConstraintComponent::MaxInclusive,
+                    format!("{value} is greater than {bound}"),
+                )
+            }
+            Constraint::MinExclusive(bound) if !numeric(value).is_some_and(|n| n > *bound) => {
+                failure(
+                    ConstraintComponent::MinExclusive,
+                    format!("{value} is not greater than {bound}"),
+                )
+            }
+            Constraint::MaxExclusive(bound) if !numeric(value).is_some_and(|n| n < *bound) => {
+                failure(
+                    ConstraintComponent::MaxExclusive,
+                    format!("{value} is not less than {bound}"),
+                )
+            }
+            Constraint::Node(shape_id) => {
+                let conforms = depth < MAX_NODE_DEPTH
+                    && self.by_id.get(shape_id).is_some_and(|&index| {
+                        let mut nested = Vec::new();
+                        self.check_shape(&self.shapes[index], value, data, depth + 1, &mut nested);
+                        nested.is_empty()
+                    });
+                if conforms {
+                    None
+                } else {
+                    failure(
+                        ConstraintComponent::Node,
+                        format!("{value} does not conform to shape {shape_id}"),
+                    )
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+fn parse_shape(doc: &TurtleDocument, id: &Term) -> Result<Shape, ShaclError> {
+    let invalid = |reason: String| ShaclError::InvalidShape { shape: id.to_string(), reason };
+    let iri_of = |term: &Term, what: &str| {
+        term.as_iri()
+            .map(str::to_string)
+            .ok_or_else(|| invalid(format!("{what} must be an IRI")))
+    };
+
+    let mut shape = Shape {
+        id: id.clone(),
+        targets: Vec::new(),
+        path: None,
+        constraints: Vec::new(),
+        properties: Vec::new(),
+        severity: Severity::Violation,
+        message: None,
+        deactivated: false,
+    };
+
+    for triple in doc.triples.iter().filter(|t| &t.subject == id) {
+        let Some(local) = triple.predicate.strip_prefix(SH) else { continue };
+        let object = &triple.object;
+        match local {
+            "targetClass" => shape.targets.push(Target::Class(iri_of(object, "sh:targetClass")?)),
+            "targetNode" => shape.targets.push(Target::Node(object.clone())),
+            "targetSubjectsOf" => {
+                shape.targets.push(Target::SubjectsOf(iri_of(object, "sh:targetSubjectsOf")?));
+            }
+            "targetObjectsOf" => {
+                shape.targets.push(Target::ObjectsOf(iri_of(object, "sh:targetObjectsOf")?));
+            }
+            "path" => {
+                shape.path = Some(parse_path(doc, object).ok_or_else(|| {
+                    invalid("only predicate and sh:inversePath paths are supported".to_string())
+                })?);
+            }
+            "property" => shape.properties.push(parse_shape(doc, object)?),
+            "severity" => {
+                shape.severity = match object.as_iri().and_then(|iri| iri.strip_prefix(SH)) {
+                    Some("Info") => Severity::Info,
+                    Some("Warning") => Severity::Warning,
+                    Some("Violation") => Severity::Violation,
+                    _ => return Err(invalid(format!("unknown sh:severity {object}"))),
+                };
+            }
+            "message" => shape.message = object.as_literal().map(str::to_string),
+            "deactivated" => shape.deactivated = object.as_literal() == Some("true"),
+            // Non-validating annotations, and flags (read with sh:pattern)
+            "name" | "description" | "order" | "group" | "defaultValue" | "flags" => {}
+            other => shape.constraints.push(parse_constraint(doc, id, other, object)?),
+        }
+    }
+
+    Ok(shape)
+}
+
+fn parse_constraint(
+    doc: &TurtleDocument,
+    id: &Term,
+    local: &str,
+    object: &Term,
+) -> Result<Constraint, ShaclError> {
+    let invalid = |reason: String| ShaclError::InvalidShape { shape: id.to_string(), reason };
+    let iri_of = |term: &Term| {
+        term.as_iri()
+            .map(str::to_string)
+            .ok_or_else(|| invalid(format!("sh:{local} must be an IRI")))
+    };
+    let count_of = |term: &Term| {
+        term.as_literal()
+            .and_then(|value| value.parse::<usize>().ok())
+            .ok_or_else(|| invalid(format!("sh:{local} must be a non-negative integer")))
+    };
+    let number_of =
+        |term: &Term| numeric(term).ok_or_else(|| invalid(format!("sh:{local} must be numeric")));
+
+    Ok(match local {
+        "minCount" => Constraint::MinCount(count_of(object)?),
+        "maxCount" => Constraint::MaxCount(count_of(object)?),
+        "minLength" => Constraint::MinLength(count_of(object)?),
+        "maxLength" => Constraint::MaxLength(count_of(object)?),
+        "minInclusive" => Constraint::MinInclusive(number_of(object)?),
+        "maxInclusive" => Constraint::MaxInclusive(number_of(object)?),
+        "minExclusive" => Constraint::MinExclusive(number_of(object)?),
+        "maxExclusive" => Constraint::MaxExclusive(number_of(object)?),
+        "datatype" => Constraint::Datatype(iri_of(object)?),
+        "class" => Constraint::Class(iri_of(object)?),
+        "nodeKind" => {
+            let name = iri_of(object)?;
+            let kind = match name.strip_prefix(SH) {
+                Some("IRI") => NodeKind::Iri,
+                Some("BlankNode") => NodeKind::BlankNode,
+                Some("Literal") => NodeKind::Literal,
+                Some("BlankNodeOrIRI") => NodeKind::BlankNodeOrIri,
+                Some("BlankNodeOrLiteral") => NodeKind::BlankNodeOrLiteral,
+                Some("IRIOrLiteral") => NodeKind::IriOrLiteral,
+                _ => return Err(invalid(format!("unknown sh:nodeKind <{name}>"))),
+            };
+            let label = name.strip_prefix(SH).unwrap_or(&name).to_string();
+            Constraint::NodeKind(kind, label)
+        }
+        "in" => {
+            let members = doc
+                .list(object)
+                .ok_or_else(|| invalid("sh:in must be a collection".to_string()))?;
+            Constraint::In(members.into_iter().cloned().collect())
+        }
+        "hasValue" => Constraint::HasValue(object.clone()),
+        "pattern" => {
+            let pattern = object
+                .as_literal()
+                .ok_or_else(|| invalid("sh:pattern must be a literal".to_string()))?;
+            let flags = doc
+                .objects(id, &format!("{SH}flags"))
+                .first()
+                .and_then(|t| t.as_literal())
+                .unwrap_or("");
+            let regex = Regex::new(&if flags.is_empty() {
+                pattern.to_string()
+            } else {
+                format!("(?{flags}){pattern}")
+            })
+            .map_err(|e| invalid(format!("invalid sh:pattern: {e}")))?;
+            Constraint::Pattern(regex)
+        }
+        "node" => Constraint::Node(object.clone()),
+        other => return Err(invalid(format!("unsupported SHACL constraint sh:{other}"))),
+    })
+}
+
+fn parse_path(doc: &TurtleDocument, term: &Term) -> Option<PropertyPath> {
+    match term {
+        Term::Iri(iri) => Some(PropertyPath::Predicate(iri.clone())),
+        Term::Blank(_) => {
+            let inverse = doc.objects(term, &format!("{SH}inversePath"));
+            inverse.first()?.as_iri().map(|iri| PropertyPath::Inverse(iri.to_string()))
+        }
+        Term::Literal { .. } => None,
+    }
+}
+
+fn focus_nodes(targets: &[Target], data: &TurtleDocument) -> Vec<Term> {
+    let mut nodes: Vec<Term> = Vec::new();
+    let mut push = |term: &Term| {
+        if !nodes.contains(term) {
+            nodes.push(term.clone());
+        }
+    };
+    for target in targets {
+        match target {
+            Target::Class(class) => data
+                .subjects()
+                .into_iter()
+                .filter(|s| is_instance_of(data, s, class))
+                .for_each(&mut push),
+            Target::Node(node) => push(node),
+            Target::SubjectsOf(predicate) => data
+                .triples
+                .iter()
+                .filter(|t| &t.predicate == predicate)
+                .for_each(|t| push(&t.subject)),
+            Target::ObjectsOf(predicate) => data
+                .triples
+                .iter()
+                .filter(|t| &t.predicate == predicate)
+                .for_each(|t| push(&t.object)),
+        }
+    }
+    nodes
+}
+
+/// Whether `node` has `rdf:type` `class` or a subclass of it
+fn is_instance_of(data: &TurtleDocument, node: &Term, class: &str) -> bool {
+    let mut pending: Vec<&Term> = data.objects(node, RDF_TYPE);
+    let mut seen = BTreeSet::new();
+    while let Some(current) = pending.pop() {
+        if current.as_iri() == Some(class) {
+            return true;
+        }
+        if seen.insert(current) {
+            pending.extend(data.objects(current, RDFS_SUBCLASS_OF));
+        }
+    }
+    false
+}
+
+fn has_datatype(value: &Term, datatype: &str) -> bool {
+    let Term::Literal { value, datatype: actual, language } = value else { return false };
+    let effective = match (actual, language) {
+        (Some(actual), _) => actual.clone(),
+        (None, Some(_)) => RDF_LANG_STRING.to_string(),
+        (None, None) => format!("{XSD}string"),
+    };
+    if effective != datatype {
+        return false;
+    }
+    // Ill-formed literals of the common XSD types do not have the datatype
+    match datatype.strip_prefix(XSD) {
+        Some("integer" | "long" | "int") => value.parse::<i128>().is_ok(),
+        Some("decimal" | "double" | "float") => value.parse::<f64>().is_ok(),
+        Some("boolean") => matches!(value.as_str(), "true" | "false" | "1" | "0"),
+        _ => true,
+    }
+}
+
+fn lexical_form(term: &Term) -> Option<&str> {
+    match term {
+        Term::Iri(iri) => Some(iri),
+        Term::Literal { value, .. } => Some(value),
+        Term::Blank(_) => None,
+    }
+}
+
+fn numeric(term: &Term) -> Option<f64> {
+    term.as_literal()?.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHAPES: &str = r#"
+        @prefix sh: <http://www.w3.org/ns/shacl#> .
+        @prefix xsd: <http://www.w3.org/2001/XMLSchema#> .
+        @prefix ex: <http://example.org/> .
+
+        ex:StageShape a sh:NodeShape ;
+            sh:targetClass ex:Stage ;
+            sh:property [
+                sh:path ex:stageNumber ;
+                sh:minCount 1 ; sh:maxCount 1 ;
+                sh:datatype xsd:integer ;
+                sh:minInclusive 1 ; sh:maxInclusive 10
+            ] ;
+            sh:property [
+                sh:path ex:name ;
+                sh:minCount 1 ;
+                sh:pattern "^[A-Z]" ;
+                sh:message "stage names start with a capital letter"
+            ] ;
+            sh:property [
+                sh:path ex:guard ;
+                sh:in ( "Legality" "Budget" ) ;
+                sh:severity sh:Warning
+            ] ;
+            sh:property [ sh:path ex:next ; sh:class ex:Stage ; sh:node ex:StageShape ] .
+    "#;
+
+    fn data(ttl: &str) -> TurtleDocument {
+        TurtleDocument::parse(&format!("@prefix ex: <http://example.org/> .\n{ttl}")).unwrap()
+    }
+
+    #[test]
+    fn test_conforming_data_has_no_results() {
+        let shapes = ShapesGraph::from_turtle(SHAPES).unwrap();
+        let data = data(
+            "ex:Special rdfs:subClassOf ex:Stage .\n\
+             ex:a a ex:Stage ; ex:stageNumber 1 ; ex:name \"Validation\" ; ex:next ex:b .\n\
+             ex:b a ex:Special ; ex:stageNumber 2 ; ex:name \"Payment\" ; ex:guard \"Budget\" .\n"
+                .replace("rdfs:subClassOf", "<http://www.w3.org/2000/01/rdf-schema#subClassOf>")
+                .as_str(),
+        );
+
+        let report = shapes.validate(&data);
+
+        assert_eq!(shapes.len(), 1);
+        assert!(report.conforms(), "{report}");
+    }
+
+    #[test]
+    fn test_violations_report_focus_path_and_constraint() {
+        let shapes = ShapesGraph::from_turtle(SHAPES).unwrap();
+        let data = data(
+            "ex:a a ex:Stage ; ex:stageNumber 11 , 2 ; ex:name \"lower\" ; ex:guard \"Other\" .\n\
+             ex:b a ex:Stage ; ex:stageNumber \"x\" ; ex:name \"B\" ; ex:next ex:c .\n\
+             ex:c ex:name \"C\" .\n",
+        );
+
+        let report = shapes.validate(&data);
+        let a: Vec<(ConstraintComponent, Severity)> = report
+            .for_focus("http://example.org/a")
+            .iter()
+            .map(|v| (v.constraint, v.severity))
+            .collect();
+        let b: Vec<ConstraintComponent> =
+            report.for_focus("http://example.org/b").iter().map(|v| v.constraint).collect();
+
+        assert!(!report.conforms());
+        assert_eq!(
+            a,
+            vec![
+                (ConstraintComponent::MaxCount, Severity::Violation),
+                (ConstraintComponent::MaxInclusive, Severity::Violation),
+                (ConstraintComponent::Pattern, Severity::Violation),
+                (ConstraintComponent::In, Severity::Warning),
+            ]
+        );
+        assert_eq!(
+            b,
+            vec![
+                ConstraintComponent::Datatype,
+                ConstraintComponent::MinInclusive,
+                ConstraintComponent::MaxInclusive,
+                ConstraintComponent::Class,
+                ConstraintComponent::Node,
+            ]
+        );
+        let pattern = report.for_focus("http://example.org/a")[2];
+        assert_eq!(pattern.message, "stage names start with a capital letter");
+        assert_eq!(pattern.path, Some(PropertyPath::Predicate("http://example.org/name".into())));
+        assert_eq!(report.at_least(Severity::Violation).len(), report.violations.len() - 1);
+        assert!(report.to_string().contains("sh:MaxCountConstraintComponent"), "{report}");
+    }
+
+    #[test]
+    fn test_unsupported_constraints_are_rejected() {
+        let err = ShapesGraph::from_turtle(
+            "@prefix sh: <http://www.w3.org/ns/shacl#> .\n\
+             <http://example.org/S> a sh:NodeShape ; sh:closed true .",
+        )
+        .unwrap_err();
+
+        assert!(
+            matches!(&err, ShaclError::InvalidShape { reason, .. } if reason.contains("sh:closed"))
+        );
+    }
+
+    #[test]
+    fn test_core_ontology_conforms_to_its_shapes() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("ontology");
+        let shapes = ShapesGraph::load(root.join("shapes/chatman-equation.shapes.ttl")).unwrap();
+        let ontology = TurtleDocument::parse(
+            &std::fs::read_to_string(root.join("chatman-equation.ttl")).unwrap(),
+        )
+        .unwrap();
+
+        let report = shapes.validate(&ontology);
+
+        assert!(report.conforms(), "{report}");
+    }
+}
@@ -0,0 +1,570 @@
+//! Minimal Turtle Parser
+//!
+//! Parses the subset of Turtle used by the sector ontologies: `@prefix`/`PREFIX`
+//! declarations, IRIs, prefixed names, `a`, string/numeric/boolean literals with
+//! datatypes or language tags, `;`/`,` lists, `[ ... ]` blank nodes, and `( ... )`
+//! collections. `@base` is not supported.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// `rdf:type`
+pub const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+/// `rdf:first`
+pub const RDF_FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+/// `rdf:rest`
+pub const RDF_REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+/// `rdf:nil`
+pub const RDF_NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+const XSD_BOOLEAN: &str = "http://www.w3.org/2001/XMLSchema#boolean";
+const XSD_DECIMAL: &str = "http://www.w3.org/2001/XMLSchema#decimal";
+const XSD_INTEGER: &str = "http://www.w3.org/2001/XMLSchema#integer";
+
+/// An RDF term
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Term {
+    /// Absolute IRI
+    Iri(String),
+    /// Blank node label
+    Blank(String),
+    /// Literal value
+    Literal {
+        /// Lexical value
+        value: String,
+        /// Datatype IRI, if any
+        datatype: Option<String>,
+        /// Language tag, if any
+        language: Option<String>,
+    },
+}
+
+impl Term {
+    /// IRI of this term, if it is one
+    #[must_use]
+    pub fn as_iri(&self) -> Option<&str> {
+        match self {
+            Self::Iri(iri) => Some(iri),
+            _ => None,
+        }
+    }
+
+    /// Lexical value of this term, if it is a literal
+    #[must_use]
+    pub fn as_literal(&self) -> Option<&str> {
+        match self {
+            Self::Literal { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Iri(iri) => write!(f, "<{iri}>"),
+            Self::Blank(label) => write!(f, "_:{label}"),
+            Self::Literal { value, datatype, language } => {
+                write!(f, "{value:?}")?;
+                if let Some(language) = language {
+                    write!(f, "@{language}")?;
+                }
+                if let Some(datatype) = datatype {
+                    write!(f, "^^<{datatype}>")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// A single RDF statement
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Triple {
+    /// Subject (IRI or blank node)
+    pub subject: Term,
+    /// Predicate IRI
+    pub predicate: String,
+    /// Object
+    pub object: Term,
+}
+
+/// Error raised while parsing Turtle
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TurtleError {
+    /// 1-based line of the error
+    pub line: usize,
+    /// What went wrong
+    pub message: String,
+}
+
+impl fmt::Display for TurtleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Turtle parse error on line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for TurtleError {}
+
+/// A parsed Turtle document
+#[derive(Debug, Clone, Default)]
+pub struct TurtleDocument {
+    /// Declared prefixes
+    pub prefixes: BTreeMap<String, String>,
+    /// Statements in document order
+    pub triples: Vec<Triple>,
+}
+
+impl TurtleDocument {
+    /// Parse a Turtle document
+    ///
+    /// # Errors
+    ///
+    /// Returns an error for malformed or unsupported syntax.
+    pub fn parse(input: &str) -> Result<Self, TurtleError> {
+        let mut parser = Parser { input, pos: 0, doc: Self::default(), blank_count: 0 };
+        parser.document()?;
+        Ok(parser.doc)
+    }
+
+    /// Subjects in order of first appearance
+    #[must_use]
+    pub fn subjects(&self) -> Vec<&Term> {
+        let mut seen = Vec::new();
+        for triple in &self.triples {
+            if !seen.contains(&&triple.subject) {
+                seen.push(&triple.subject);
+            }
+        }
+        seen
+    }
+
+    /// Objects of `subject`'s statements with `predicate`, in document order
+    #[must_use]
+    pub fn objects<'a>(&'a self, subject: &Term, predicate: &str) -> Vec<&'a Term> {
+        self.triples
+            .iter()
+            .filter(|t| &t.subject == subject && t.predicate == predicate)
+            .map(|t| &t.object)
+            .collect()
+    }
+
+    /// Subjects with `rdf:type` `class`, in order of first appearance
+    #[must_use]
+    pub fn instances_of(&self, class: &str) -> Vec<&Term> {
+        self.subjects()
+            .into_iter()
+            .filter(|s| self.objects(s, RDF_TYPE).iter().any(|o| o.as_iri() == Some(class)))
+            .collect()
+    }
+
+    /// Members of the collection starting at `head`, or `None` if it is not a well-formed list
+    #[must_use]
+    pub fn list<'a>(&'a self, head: &'a Term) -> Option<Vec<&'a Term>> {
+        let mut members = Vec::new();
+        let mut node = head;
+        while node.as_iri() != Some(RDF_NIL) {
+            members.push(*self.objects(node, RDF_FIRST).first()?);
+            node = self.objects(node, RDF_REST).first()?;
+        }
+        Some(members)
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+    doc: TurtleDocument,
+    blank_count: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn document(&mut self) -> Result<(), TurtleError> {
+        loop {
+            self.skip_ws();
+            if self.pos >= self.input.len() {
+                return Ok(());
+            }
+            if self.eat("@prefix") {
+                self.prefix_decl(true)?;
+            } else if self.eat_keyword("PREFIX") {
+                self.prefix_decl(false)?;
+            } else if self.rest().starts_with('@') {
+                return Err(self.error("unsupported directive"));
+            } else {
+                let subject = self.subject()?;
+                self.skip_ws();
+                self.predicate_object_list(&subject)?;
+                self.expect('.')?;
+            }
+        }
+    }
+
+    fn prefix_decl(&mut self, dotted: bool) -> Result<(), TurtleError> {
+        self.skip_ws();
+        let name_end = self.rest().find(':').ok_or_else(|| self.error("expected prefix name"))?;
+        let name = self.rest()[..name_end].trim().to_string();
+        self.pos += name_end + 1;
+        self.skip_ws();
+        let iri = self.iri_ref()?;
+        self.doc.prefixes.insert(name, iri);
+        if dotted {
+            self.expect('.')?;
+        }
+        Ok(())
+    }
+
+    fn subject(&mut self) -> Result<Term, TurtleError> {
+        match self.peek() {
+            Some('[') => self.blank_node_property_list(),
+            Some('_') => self.blank_node_label(),
+            _ => Ok(Term::Iri(self.iri()?)),
+        }
+    }
+
+    fn predicate_object_list(&mut self, subject: &Term) -> Result<(), TurtleError> {
+        loop {
+            self.skip_ws();
+            let predicate = if self.eat_keyword("a") { RDF_TYPE.to_string() } else { self.iri()? };
+            loop {
+                self.skip_ws();
+                let object = self.object()?;
+                self.doc.triples.push(Triple {
+                    subject: subject.clone(),
+                    predicate: predicate.clone(),
+                    object,
+                });
+                self.skip_ws();
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.skip_ws();
+            if !self.eat(";") {
+                return Ok(());
+            }
+            self.skip_ws();
+            // A trailing `;` before `.` or `]` is allowed
+            if matches!(self.peek(), Some('.' | ']')) {
+                return Ok(());
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Term, TurtleError> {
+        match self.peek() {
+            Some('"' | '\'') => self.literal(),
+            Some('[') => self.blank_node_property_list(),
+            Some('_') => self.blank_node_label(),
+            Some('(') => self.collection(),
+            Some(c) if c.is_ascii_digit() || c == '-' || c == '+' => Ok(self.number()),
+            _ if self.eat_keyword("true") => Ok(typed("true", XSD_BOOLEAN)),
+            _ if self.eat_keyword("false") => Ok(typed("false", XSD_BOOLEAN)),
+            _ => Ok(Term::Iri(self.iri()?)),
+        }
+    }
+
+    fn blank_node_property_list(&mut self) -> Result<Term, TurtleError> {
+        self.expect('[')?;
+        self.blank_count += 1;
+        let node = Term::Blank(format!("b{}", self.blank_count));
+        self.skip_ws();
+        if !self.eat("]") {
+            self.predicate_object_list(&node)?;
+            self.expect(']')?;
+        }
+        Ok(node)
+    }
+
+    fn collection(&mut self) -> Result<Term, TurtleError> {
+        self.expect('(')?;
+        let mut members = Vec::new();
+        loop {
+            self.skip_ws();
+            if self.eat(")") {
+                break;
+            }
+            members.push(self.object()?);
+        }
+
+        let mut list = Term::Iri(RDF_NIL.to_string());
+        for member in members.into_iter().rev() {
+            self.blank_count += 1;
+            let node = Term::Blank(format!("b{}", self.blank_count));
+            for (predicate, object) in [(RDF_FIRST, member), (RDF_REST, list)] {
+                self.doc.triples.push(Triple {
+                    subject: node.clone(),
+                    predicate: predicate.to_string(),
+                    object,
+                });
+            }
+            list = node;
+        }
+        Ok(list)
+    }
+
+    fn blank_node_label(&mut self) -> Result<Term, TurtleError> {
+        if !self.eat("_:") {
+            return Err(self.error("expected blank node label"));
+        }
+        Ok(Term::Blank(self.take_name()))
+    }
+
+    fn literal(&mut self) -> Result<Term, TurtleError> {
+        let value = self.string()?;
+        if self.eat("^^") {
+            let datatype = self.iri()?;
+            return Ok(Term::Literal { value, datatype: Some(datatype), language: None });
+        }
+        if self.eat("@") {
+            let language = self.take_name();
+            return Ok(Term::Literal { value, datatype: None, language: Some(language) });
+        }
+        Ok(Term::Literal { value, datatype: None, language: None })
+    }
+
+    fn string(&mut self) -> Result<String, TurtleError> {
+        let quote = self.peek().ok_or_else(|| self.error("expected string"))?;
+        let long: String = std::iter::repeat_n(quote, 3).collect();
+        let (delimiter, multiline) =
+            if self.rest().starts_with(&long) { (long, true) } else { (quote.to_string(), false) };
+        self.pos += delimiter.len();
+
+        let mut value = String::new();
+        loop {
+            if self.rest().starts_with(&delimiter) {
+                // Quotes right before a long string's closing delimiter belong to the value
+                if multiline && self.rest()[delimiter.len()..].starts_with(quote) {
+                    value.push(quote);
+                    self.pos += quote.len_utf8();
+                    continue;
+                }
+                self.pos += delimiter.len();
+                return Ok(value);
+            }
+            let c = self.next_char().ok_or_else(|| self.error("unterminated string"))?;
+            match c {
+                '\\' => {
+                    let escaped =
+                        self.next_char().ok_or_else(|| self.error("unterminated string"))?;
+                    value.push(match escaped {
+                        'n' => '\n',
+                        't' => '\t',
+                        'r' => '\r',
+                        other => other,
+                    });
+                }
+                '\n' if !multiline => return Err(self.error("newline in string")),
+                c => value.push(c),
+            }
+        }
+    }
+
+    fn number(&mut self) -> Term {
+        let len = self
+            .rest()
+            .char_indices()
+            .take_while(|&(i, c)| {
+                c.is_ascii_digit() || (i == 0 && (c == '-' || c == '+')) || c == '.'
+            })
+            .count();
+        // A trailing `.` ends the statement rather than the number
+        let mut text = &self.rest()[..len];
+        if text.ends_with('.') {
+            text = &text[..text.len() - 1];
+        }
+        self.pos += text.len();
+        let datatype = if text.contains('.') { XSD_DECIMAL } else { XSD_INTEGER };
+        typed(text, datatype)
+    }
+
+    fn iri(&mut self) -> Result<String, TurtleError> {
+        if self.peek() == Some('<') {
+            return self.iri_ref();
+        }
+        let name = self.take_name();
+        let Some((prefix, local)) = name.split_once(':') else {
+            return Err(self.error(&format!("expected IRI, found {:?}", self.snippet())));
+        };
+        let namespace = self
+            .doc
+            .prefixes
+            .get(prefix)
+            .ok_or_else(|| self.error(&format!("undeclared prefix {prefix:?}")))?;
+        Ok(format!("{namespace}{local}"))
+    }
+
+    fn iri_ref(&mut self) -> Result<String, TurtleError> {
+        self.expect('<')?;
+        let end = self.rest().find('>').ok_or_else(|| self.error("unterminated IRI"))?;
+        let iri = self.rest()[..end].to_string();
+        self.pos += end + 1;
+        Ok(iri)
+    }
+
+    fn take_name(&mut self) -> String {
+        let len: usize = self
+            .rest()
+            .chars()
+            .take_while(|&c| c.is_alphanumeric() || matches!(c, '_' | '-' | ':' | '.'))
+            .map(char::len_utf8)
+            .sum();
+        // A trailing `.` ends the statement rather than the name
+        let name = self.rest()[..len].trim_end_matches('.').to_string();
+        self.pos += name.len();
+        name
+    }
+
+    fn skip_ws(&mut self) {
+        loop {
+            let trimmed = self.rest().trim_start();
+            self.pos = self.input.len() - trimmed.len();
+            if trimmed.starts_with('#') {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), TurtleError> {
+        self.skip_ws();
+        if self.peek() == Some(c) {
+            self.pos += c.len_utf8();
+            Ok(())
+        } else {
+            Err(self.error(&format!("expected '{c}', found {:?}", self.snippet())))
+        }
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        if self.rest().starts_with(token) {
+            self.pos += token.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        let rest = self.rest();
+        let followed_by_name = rest[keyword.len().min(rest.len())..]
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphanumeric() || matches!(c, '_' | '-' | ':'));
+        if rest.starts_with(keyword) && !followed_by_name {
+            self.pos += keyword.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn snippet(&self) -> String {
+        self.rest().chars().take(20).collect()
+    }
+
+    fn error(&self, message: &str) -> TurtleError {
+        let line = self.input[..self.pos].matches('\n').count() + 1;
+        TurtleError { line, message: message.to_string() }
+    }
+}
+
+fn typed(value: &str, datatype: &str) -> Term {
+    Term::Literal { value: value.to_string(), datatype: Some(datatype.to_string()), language: None }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_prefixed_statements() {
+        let doc = TurtleDocument::parse(
+            "@prefix ex: <http://example.org/> .\n\
+             # comment\n\
+             ex:a a ex:Thing ;\n  ex:label \"A # not a comment\"@en ;\n  ex:n 42 , -1.5 ;\n  \
+             ex:flag true ;\n  ex:link <http://other.org/b> .\n",
+        )
+        .unwrap();
+
+        let a = Term::Iri("http://example.org/a".to_string());
+        assert_eq!(doc.instances_of("http://example.org/Thing"), vec![&a]);
+        assert_eq!(
+            doc.objects(&a, "http://example.org/label")[0].as_literal(),
+            Some("A # not a comment")
+        );
+        assert_eq!(
+            doc.objects(&a, "http://example.org/n"),
+            vec![&typed("42", XSD_INTEGER), &typed("-1.5", XSD_DECIMAL)]
+        );
+        assert_eq!(doc.objects(&a, "http://example.org/flag"), vec![&typed("true", XSD_BOOLEAN)]);
+        assert_eq!(
+            doc.objects(&a, "http://example.org/link")[0].as_iri(),
+            Some("http://other.org/b")
+        );
+    }
+
+    #[test]
+    fn test_parse_blank_nodes_and_long_strings() {
+        let doc = TurtleDocument::parse(
+            "PREFIX ex: <http://example.org/>\n\
+             ex:a ex:rule [ ex:kind \"Budget\" ; ex:limit 10 ] ;\n  \
+             ex:note \"\"\"line one\nline \"two\"\"\"\" ;\n  ex:kinds ( ex:x \"y\" 3 ) , () .\n",
+        )
+        .unwrap();
+
+        let a = Term::Iri("http://example.org/a".to_string());
+        let rule = doc.objects(&a, "http://example.org/rule")[0];
+        assert!(matches!(rule, Term::Blank(_)));
+        assert_eq!(doc.objects(rule, "http://example.org/kind")[0].as_literal(), Some("Budget"));
+        assert_eq!(
+            doc.objects(&a, "http://example.org/note")[0].as_literal(),
+            Some("line one\nline \"two\"")
+        );
+        let kinds = doc.objects(&a, "http://example.org/kinds");
+        assert_eq!(
+            doc.list(kinds[0]).unwrap(),
+            vec![
+                &Term::Iri("http://example.org/x".to_string()),
+                &Term::Literal { value: "y".to_string(), datatype: None, language: None },
+                &typed("3", XSD_INTEGER),
+            ]
+        );
+        assert!(doc.list(kinds[1]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_errors_report_line() {
+        let err = TurtleDocument::parse("@prefix ex: <http://example.org/> .\nex:a ex:b ex:c\n")
+            .unwrap_err();
+        assert_eq!(err.line, 3);
+
+        let err = TurtleDocument::parse("\nundeclared:a undeclared:b 1 .").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert!(err.message.contains("undeclared"), "{err}");
+    }
+
+    #[test]
+    fn test_parse_core_ontology() {
+        let ttl = include_str!("../../../ontology/chatman-equation.ttl");
+
+        let doc = TurtleDocument::parse(ttl).unwrap();
+
+        assert_eq!(doc.instances_of("http://chatman-equation.org/core/Guard_Type").len(), 5);
+        assert_eq!(doc.instances_of("http://chatman-equation.org/core/YAWLPattern").len(), 14);
+    }
+}
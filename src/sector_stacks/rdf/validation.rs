@@ -2,8 +2,13 @@
 //!
 //! This module closes the loop between RDF ontology definitions and Rust runtime operations
 //! by validating sector operations against their ontology specifications.
+//!
+//! Instance data is validated against SHACL shapes (see [`super::shacl`]) loaded
+//! alongside the ontology with [`RdfOperationValidator::with_shapes`].
 
 use super::ontology::{GuardConstraint, SectorOntology};
+use super::shacl::{Severity, ShaclReport, ShaclViolation, ShapesGraph};
+use super::turtle::TurtleDocument;
 
 /// Result of RDF validation
 pub type RdfValidationResult = Result<(), RdfValidationError>;
@@ -43,6 +48,10 @@ pub enum RdfValidationError {
     },
     /// Ontology not loaded
     OntologyNotLoaded,
+    /// SHACL shapes not loaded
+    ShapesNotLoaded,
+    /// Instance data violates SHACL shapes
+    ShapeViolations(Vec<ShaclViolation>),
 }
 
 impl std::fmt::Display for RdfValidationError {
@@ -63,6 +72,16 @@ impl std::fmt::Display for RdfValidationError {
             Self::OntologyNotLoaded => {
                 write!(f, "Ontology not loaded")
             }
+            Self::ShapesNotLoaded => {
+                write!(f, "SHACL shapes not loaded")
+            }
+            Self::ShapeViolations(violations) => {
+                write!(f, "{} SHACL violation(s)", violations.len())?;
+                for violation in violations {
+                    write!(f, "\n  {violation}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -72,13 +91,14 @@ impl std::error::Error for RdfValidationError {}
 /// Validates sector operations against RDF ontology definitions
 pub struct RdfOperationValidator {
     ontology: Option<SectorOntology>,
+    shapes: Option<ShapesGraph>,
 }
 
 impl RdfOperationValidator {
     /// Create a new validator
     #[must_use]
     pub const fn new() -> Self {
-        Self { ontology: None }
+        Self { ontology: None, shapes: None }
     }
 
     /// Set the ontology for validation
@@ -88,6 +108,41 @@ impl RdfOperationValidator {
         self
     }
 
+    /// Set the SHACL shapes instance data is validated against
+    #[must_use]
+    pub fn with_shapes(mut self, shapes: ShapesGraph) -> Self {
+        self.shapes = Some(shapes);
+        self
+    }
+
+    /// Validate instance data against the SHACL shapes
+    ///
+    /// Returns every result, including warnings and infos.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no shapes are loaded.
+    pub fn validate_data(&self, data: &TurtleDocument) -> Result<ShaclReport, RdfValidationError> {
+        let shapes = self.shapes.as_ref().ok_or(RdfValidationError::ShapesNotLoaded)?;
+        Ok(shapes.validate(data))
+    }
+
+    /// Validate that instance data has no `sh:Violation` results
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no shapes are loaded or the data violates a shape.
+    pub fn validate_data_conforms(&self, data: &TurtleDocument) -> RdfValidationResult {
+        let report = self.validate_data(data)?;
+        let violations: Vec<ShaclViolation> =
+            report.at_least(Severity::Violation).into_iter().cloned().collect();
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(RdfValidationError::ShapeViolations(violations))
+        }
+    }
+
     /// Validate that an operation is defined in the ontology
     ///
     /// # Errors
@@ -315,4 +370,35 @@ mod tests {
         let validator = RdfOperationValidator::new().with_ontology(ontology);
         assert!(validator.all_stages_deterministic().unwrap());
     }
+
+    #[test]
+    fn test_shape_validation() {
+        let shapes = ShapesGraph::from_turtle(
+            "@prefix sh: <http://www.w3.org/ns/shacl#> .\n\
+             @prefix ex: <http://example.org/> .\n\
+             ex:HookShape a sh:NodeShape ; sh:targetClass ex:Hook ;\n\
+               sh:property [ sh:path ex:hookId ; sh:minCount 1 ] ;\n\
+               sh:property [ sh:path ex:note ; sh:minCount 1 ; sh:severity sh:Warning ] .",
+        )
+        .unwrap();
+        let data = TurtleDocument::parse(
+            "@prefix ex: <http://example.org/> .\n\
+             ex:a a ex:Hook ; ex:hookId \"a_op\" .\n\
+             ex:b a ex:Hook .",
+        )
+        .unwrap();
+
+        let unloaded = RdfOperationValidator::new().validate_data(&data);
+        let validator = RdfOperationValidator::new().with_shapes(shapes);
+        let report = validator.validate_data(&data).unwrap();
+        let err = validator.validate_data_conforms(&data).unwrap_err();
+
+        assert!(matches!(unloaded, Err(RdfValidationError::ShapesNotLoaded)));
+        assert_eq!(report.violations.len(), 3);
+        let RdfValidationError::ShapeViolations(violations) = err else {
+            panic!("expected shape violations, got {err}");
+        };
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].focus_node.as_iri(), Some("http://example.org/b"));
+    }
 }
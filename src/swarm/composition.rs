@@ -3,9 +3,18 @@
 //! Enables composing operations from multiple sectors into deterministic chains.
 //! A composed operation chains knowledge hooks in sequence, with output of one
 //! becoming input to the next.
+//!
+//! Before a chain runs, [`OperationChain::validate`] checks it end-to-end: the steps'
+//! cumulative run length must stay within the guard limit (`MAX_RUN_LEN` = 8), and no
+//! two hooks may mutate the same state key. A valid chain yields a
+//! [`CompositionReceipt`] recording the execution order that was checked.
 
+use crate::validation::guards::{GuardConstraintError, GuardValidator};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::fmt;
+use thiserror::Error;
 
 /// A single step in an operation chain
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +31,16 @@ pub struct CompositionStep {
     pub output: String,
     /// Execution order (lower first)
     pub order: u32,
+    /// Run length this step adds to the chain (guarded by `MAX_RUN_LEN`)
+    #[serde(default = "default_run_len")]
+    pub run_len: usize,
+    /// State keys this step mutates
+    #[serde(default)]
+    pub writes: Vec<String>,
+}
+
+const fn default_run_len() -> usize {
+    1
 }
 
 impl CompositionStep {
@@ -29,7 +48,16 @@ impl CompositionStep {
     #[must_use]
     #[allow(clippy::missing_const_for_fn)] // Cannot be const: uses String::new()
     pub fn new(id: String, sector: String, operation: String, input: String) -> Self {
-        Self { id, sector, operation, input, output: String::new(), order: 0 }
+        Self {
+            id,
+            sector,
+            operation,
+            input,
+            output: String::new(),
+            order: 0,
+            run_len: default_run_len(),
+            writes: Vec::new(),
+        }
     }
 
     /// Set execution order
@@ -39,6 +67,91 @@ impl CompositionStep {
         self.order = order;
         self
     }
+
+    /// Set run length
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)] // Cannot be const: mutates self
+    pub fn with_run_len(mut self, run_len: usize) -> Self {
+        self.run_len = run_len;
+        self
+    }
+
+    /// Declare a state key this step mutates
+    #[must_use]
+    pub fn with_write(mut self, key: impl Into<String>) -> Self {
+        self.writes.push(key.into());
+        self
+    }
+}
+
+/// Two steps in a chain that mutate the same state key
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HookConflict {
+    /// State key both steps mutate
+    pub key: String,
+    /// Step that runs first
+    pub first: String,
+    /// Step that runs second
+    pub second: String,
+}
+
+impl fmt::Display for HookConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "steps '{}' and '{}' both mutate '{}'", self.first, self.second, self.key)
+    }
+}
+
+/// Composition validation error
+#[derive(Error, Debug)]
+pub enum CompositionError {
+    /// Cumulative run length exceeds the guard limit
+    #[error("Chain '{chain}': {source}")]
+    Guard {
+        /// Chain ID
+        chain: String,
+        /// Guard violation
+        #[source]
+        source: GuardConstraintError,
+    },
+    /// Hooks mutate the same state key
+    #[error("Chain '{chain}' has conflicting hooks: {}", format_conflicts(.conflicts))]
+    Conflicts {
+        /// Chain ID
+        chain: String,
+        /// Every conflicting pair, in execution order
+        conflicts: Vec<HookConflict>,
+    },
+}
+
+fn format_conflicts(conflicts: &[HookConflict]) -> String {
+    conflicts.iter().map(ToString::to_string).collect::<Vec<_>>().join("; ")
+}
+
+/// Receipt of a composition check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompositionReceipt {
+    /// Chain ID
+    pub chain_id: String,
+    /// Step IDs in the order they execute
+    pub ordering: Vec<String>,
+    /// Sectors involved
+    pub sectors: Vec<String>,
+    /// Cumulative run length of all steps
+    pub run_len: usize,
+    /// Run length limit checked against
+    pub max_run_len: usize,
+    /// Steps mutating the same state key
+    pub conflicts: Vec<HookConflict>,
+    /// SHA-256 over the ordering and each step's operation and writes
+    pub digest: String,
+}
+
+impl CompositionReceipt {
+    /// Check if the composition passed every check
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.run_len <= self.max_run_len && self.conflicts.is_empty()
+    }
 }
 
 /// A composition of operations across sectors
@@ -135,6 +248,97 @@ impl OperationChain {
         sectors.dedup();
         sectors
     }
+
+    /// Get cumulative run length of all steps
+    #[must_use]
+    pub fn run_len(&self) -> usize {
+        self.steps.iter().map(|s| s.run_len).sum()
+    }
+
+    /// Find steps that mutate the same state key
+    ///
+    /// Each later writer of a key is paired with the key's first writer.
+    #[must_use]
+    pub fn conflicts(&self) -> Vec<HookConflict> {
+        let mut first_writers: HashMap<&str, &str> = HashMap::new();
+        let mut conflicts = Vec::new();
+        for step in &self.steps {
+            for key in &step.writes {
+                match first_writers.get(key.as_str()) {
+                    Some(first) if *first != step.id => conflicts.push(HookConflict {
+                        key: key.clone(),
+                        first: (*first).to_string(),
+                        second: step.id.clone(),
+                    }),
+                    Some(_) => {}
+                    None => {
+                        first_writers.insert(key, &step.id);
+                    }
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Check the chain against `validator`'s run length limit and for conflicting hooks
+    ///
+    /// Always returns a receipt; see [`CompositionReceipt::is_valid`].
+    #[must_use]
+    pub fn composition_receipt(&self, validator: &GuardValidator) -> CompositionReceipt {
+        let ordering: Vec<String> = self.steps.iter().map(|s| s.id.clone()).collect();
+        let mut hasher = Sha256::new();
+        hasher.update(self.id.as_bytes());
+        for step in &self.steps {
+            hasher.update(step.id.as_bytes());
+            hasher.update(step.operation.as_bytes());
+            for key in &step.writes {
+                hasher.update(key.as_bytes());
+            }
+        }
+        CompositionReceipt {
+            chain_id: self.id.clone(),
+            ordering,
+            sectors: self.sectors(),
+            run_len: self.run_len(),
+            max_run_len: validator.max_run_len(),
+            conflicts: self.conflicts(),
+            digest: format!("{:x}", hasher.finalize()),
+        }
+    }
+
+    /// Validate the chain against the default guards (`MAX_RUN_LEN` = 8)
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompositionError::Guard`] if the cumulative run length exceeds the
+    /// limit, or [`CompositionError::Conflicts`] if hooks mutate the same state key.
+    pub fn validate(&self) -> Result<CompositionReceipt, CompositionError> {
+        self.validate_with(&GuardValidator::new())
+    }
+
+    /// Validate the chain against `validator`'s limits
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompositionError::Guard`] if the cumulative run length exceeds the
+    /// limit, or [`CompositionError::Conflicts`] if hooks mutate the same state key.
+    pub fn validate_with(
+        &self,
+        validator: &GuardValidator,
+    ) -> Result<CompositionReceipt, CompositionError> {
+        validator
+            .validate_run_len(self.run_len())
+            .map_err(|source| CompositionError::Guard { chain: self.id.clone(), source })?;
+        let receipt = self.composition_receipt(validator);
+        if receipt.conflicts.is_empty() {
+            Ok(receipt)
+        } else {
+            Err(CompositionError::Conflicts {
+                chain: self.id.clone(),
+                conflicts: receipt.conflicts,
+            })
+        }
+    }
 }
 
 /// Result of composing operations
@@ -300,6 +504,81 @@ mod tests {
         assert!(sectors.contains(&"Claims".to_string()));
     }
 
+    fn step(id: &str, order: u32) -> CompositionStep {
+        CompositionStep::new(
+            id.to_string(),
+            "Claims".to_string(),
+            format!("{id}-hook"),
+            "claim".to_string(),
+        )
+        .with_order(order)
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)] // Test code: unwrap is acceptable
+    fn test_validate_records_execution_order() {
+        let mut chain = OperationChain::new("chain-1".to_string(), "Claims".to_string());
+        chain.add_step(step("settle", 3).with_run_len(3).with_write("claim.status"));
+        chain.add_step(step("intake", 1).with_run_len(2).with_write("claim.record"));
+        chain.add_step(step("assess", 2).with_run_len(3).with_write("claim.score"));
+
+        let receipt = chain.validate().unwrap();
+
+        assert_eq!(receipt.ordering, ["intake", "assess", "settle"]);
+        assert_eq!(receipt.run_len, 8);
+        assert_eq!(receipt.max_run_len, 8);
+        assert!(receipt.is_valid());
+        assert_eq!(receipt.digest, chain.composition_receipt(&GuardValidator::new()).digest);
+    }
+
+    #[test]
+    fn test_validate_rejects_cumulative_run_len_over_limit() {
+        let mut chain = OperationChain::new("chain-1".to_string(), "Claims".to_string());
+        chain.add_step(step("intake", 1).with_run_len(5));
+        chain.add_step(step("assess", 2).with_run_len(4));
+
+        let result = chain.validate();
+
+        assert!(matches!(
+            result,
+            Err(CompositionError::Guard {
+                source: GuardConstraintError::MaxRunLengthExceeded(9, 8),
+                ..
+            })
+        ));
+        assert!(!chain.composition_receipt(&GuardValidator::new()).is_valid());
+        assert!(chain.validate_with(&GuardValidator::with_constraints(16, 1000)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_detects_hooks_mutating_same_key() {
+        let mut chain = OperationChain::new("chain-1".to_string(), "Claims".to_string());
+        chain.add_step(step("intake", 1).with_write("claim.status"));
+        chain.add_step(step("assess", 2).with_write("claim.score"));
+        chain.add_step(step("settle", 3).with_write("claim.status").with_write("claim.score"));
+
+        let result = chain.validate();
+
+        let Err(CompositionError::Conflicts { conflicts, .. }) = result else {
+            panic!("expected conflicts, got {result:?}");
+        };
+        assert_eq!(
+            conflicts,
+            [
+                HookConflict {
+                    key: "claim.status".to_string(),
+                    first: "intake".to_string(),
+                    second: "settle".to_string(),
+                },
+                HookConflict {
+                    key: "claim.score".to_string(),
+                    first: "assess".to_string(),
+                    second: "settle".to_string(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_composed_operation() {
         let chain = OperationChain::new("chain-1".to_string(), "Multi-Sector".to_string());
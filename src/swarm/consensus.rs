@@ -0,0 +1,741 @@
+//! Swarm Consensus: Raft-Style Agreement on Coordinator Decisions
+//!
+//! Each coordinator replica runs a [`RaftNode`]. The elected leader proposes
+//! [`Decision`]s (task assignments and composed operation chains); an entry is
+//! committed once a quorum has it in its log, and every replica then applies the
+//! committed decisions in log order with
+//! [`SwarmCoordinator::apply_decision`](super::SwarmCoordinator::apply_decision).
+//!
+//! The node is a deterministic state machine without I/O or a clock: the caller
+//! advances time with [`RaftNode::tick`], delivers messages with [`RaftNode::step`],
+//! and sends whatever [`RaftNode::take_messages`] returns. The same node runs over a
+//! network or inside a simulation that drops, delays, and reorders messages.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::swarm::consensus::{Decision, RaftConfig, RaftNode};
+//!
+//! let mut node = RaftNode::new("coordinator-1", ["coordinator-1"], RaftConfig::default());
+//! while !node.is_leader() {
+//!     node.tick();
+//! }
+//!
+//! node.propose(Decision::assign_task("task-1", "agent-1")).unwrap();
+//!
+//! assert_eq!(node.take_committed(), [Decision::assign_task("task-1", "agent-1")]);
+//! ```
+
+use super::composition::OperationChain;
+use crate::testing::state_machine::ModelRng;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use thiserror::Error;
+
+/// Most log entries sent in one `AppendEntries` message
+const MAX_APPEND_ENTRIES: usize = 64;
+
+/// Default minimum election timeout, in ticks
+pub const DEFAULT_ELECTION_TIMEOUT_MIN: u32 = 10;
+
+/// Default maximum election timeout, in ticks
+pub const DEFAULT_ELECTION_TIMEOUT_MAX: u32 = 20;
+
+/// Default interval between leader heartbeats, in ticks
+pub const DEFAULT_HEARTBEAT_INTERVAL: u32 = 3;
+
+/// Raft timing settings, in ticks
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RaftConfig {
+    /// Fewest ticks without a leader before a follower stands for election
+    pub election_timeout_min: u32,
+    /// Most ticks without a leader before a follower stands for election
+    pub election_timeout_max: u32,
+    /// Ticks between heartbeats from the leader (must be below the election timeout)
+    pub heartbeat_interval: u32,
+    /// Seed for randomized election timeouts; combined with each node's ID
+    pub seed: u64,
+}
+
+impl RaftConfig {
+    /// Set the election timeout range
+    #[must_use]
+    pub const fn with_election_timeout(mut self, min: u32, max: u32) -> Self {
+        self.election_timeout_min = min;
+        self.election_timeout_max = max;
+        self
+    }
+
+    /// Set the heartbeat interval
+    #[must_use]
+    pub const fn with_heartbeat_interval(mut self, ticks: u32) -> Self {
+        self.heartbeat_interval = ticks;
+        self
+    }
+
+    /// Set the seed for randomized election timeouts
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+}
+
+impl Default for RaftConfig {
+    fn default() -> Self {
+        Self {
+            election_timeout_min: DEFAULT_ELECTION_TIMEOUT_MIN,
+            election_timeout_max: DEFAULT_ELECTION_TIMEOUT_MAX,
+            heartbeat_interval: DEFAULT_HEARTBEAT_INTERVAL,
+            seed: 0,
+        }
+    }
+}
+
+/// A coordinator decision agreed on by the swarm
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Decision {
+    /// Assign a queued task to a member
+    AssignTask {
+        /// Task identifier
+        task_id: String,
+        /// Member that executes the task
+        member_id: String,
+    },
+    /// Adopt a composed operation chain
+    ComposeChain {
+        /// Chain identifier
+        chain_id: String,
+        /// Sectors the chain spans, in step order
+        sectors: Vec<String>,
+    },
+}
+
+impl Decision {
+    /// Decision to assign `task_id` to `member_id`
+    #[must_use]
+    pub fn assign_task(task_id: impl Into<String>, member_id: impl Into<String>) -> Self {
+        Self::AssignTask { task_id: task_id.into(), member_id: member_id.into() }
+    }
+
+    /// Decision to adopt `chain`
+    #[must_use]
+    pub fn compose_chain(chain: &OperationChain) -> Self {
+        Self::ComposeChain { chain_id: chain.id.clone(), sectors: chain.sectors() }
+    }
+}
+
+impl fmt::Display for Decision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AssignTask { task_id, member_id } => write!(f, "assign {task_id} to {member_id}"),
+            Self::ComposeChain { chain_id, sectors } => {
+                write!(f, "compose {chain_id} across {}", sectors.join(" → "))
+            }
+        }
+    }
+}
+
+/// A replicated log entry
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogEntry {
+    /// Term in which the leader appended the entry
+    pub term: u64,
+    /// The decision (`None` for the no-op a new leader appends to commit entries
+    /// left over from earlier terms)
+    pub decision: Option<Decision>,
+}
+
+/// Role of a node in the current term
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Role {
+    /// Replicates the leader's log
+    Follower,
+    /// Requesting votes to become leader
+    Candidate,
+    /// Accepts proposals and replicates them
+    Leader,
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Follower => write!(f, "Follower"),
+            Self::Candidate => write!(f, "Candidate"),
+            Self::Leader => write!(f, "Leader"),
+        }
+    }
+}
+
+/// Raft RPCs and their responses
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RaftMessage {
+    /// Candidate asks for a vote
+    RequestVote {
+        /// Candidate's term
+        term: u64,
+        /// Index of the candidate's last log entry
+        last_log_index: u64,
+        /// Term of the candidate's last log entry
+        last_log_term: u64,
+    },
+    /// Answer to `RequestVote`
+    Vote {
+        /// Voter's term
+        term: u64,
+        /// Whether the vote was granted
+        granted: bool,
+    },
+    /// Leader replicates entries (none for a heartbeat)
+    AppendEntries {
+        /// Leader's term
+        term: u64,
+        /// Index of the entry preceding `entries`
+        prev_log_index: u64,
+        /// Term of the entry at `prev_log_index`
+        prev_log_term: u64,
+        /// Entries to append
+        entries: Vec<LogEntry>,
+        /// Leader's commit index
+        leader_commit: u64,
+    },
+    /// Answer to `AppendEntries`
+    AppendResponse {
+        /// Follower's term
+        term: u64,
+        /// Whether the follower's log matched at `prev_log_index`
+        success: bool,
+        /// Last index known to match the leader's log
+        match_index: u64,
+    },
+}
+
+impl RaftMessage {
+    /// Term the message was sent in
+    #[must_use]
+    pub const fn term(&self) -> u64 {
+        match self {
+            Self::RequestVote { term, .. }
+            | Self::Vote { term, .. }
+            | Self::AppendEntries { term, .. }
+            | Self::AppendResponse { term, .. } => *term,
+        }
+    }
+}
+
+/// A message addressed from one node to another
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RaftEnvelope {
+    /// Sending node
+    pub from: String,
+    /// Receiving node
+    pub to: String,
+    /// The message
+    pub message: RaftMessage,
+}
+
+/// Consensus errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConsensusError {
+    /// Only the leader accepts proposals
+    #[error("{node} is not the leader (leader: {})", leader.as_deref().unwrap_or("unknown"))]
+    NotLeader {
+        /// Node the proposal was sent to
+        node: String,
+        /// Leader the node knows of, if any
+        leader: Option<String>,
+    },
+}
+
+/// One replica's Raft state machine
+#[derive(Debug, Clone)]
+pub struct RaftNode {
+    id: String,
+    peers: Vec<String>,
+    config: RaftConfig,
+    rng: ModelRng,
+    role: Role,
+    term: u64,
+    voted_for: Option<String>,
+    log: Vec<LogEntry>,
+    commit_index: u64,
+    applied_index: u64,
+    leader: Option<String>,
+    elapsed: u32,
+    election_timeout: u32,
+    votes: HashSet<String>,
+    next_index: HashMap<String, u64>,
+    match_index: HashMap<String, u64>,
+    outbox: Vec<RaftEnvelope>,
+}
+
+impl RaftNode {
+    /// Create a follower in term 0
+    ///
+    /// `members` lists every node in the cluster; it may include `id`.
+    #[must_use]
+    pub fn new(
+        id: impl Into<String>,
+        members: impl IntoIterator<Item = impl Into<String>>,
+        config: RaftConfig,
+    ) -> Self {
+        let id = id.into();
+        let mut peers: Vec<String> =
+            members.into_iter().map(Into::into).filter(|member| *member != id).collect();
+        peers.sort();
+        peers.dedup();
+        // FNV-1a over the ID, so nodes sharing a seed still time out at different ticks
+        let seed = id.bytes().fold(config.seed ^ 0xCBF2_9CE4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01B3)
+        });
+
+        let mut node = Self {
+            id,
+            peers,
+            config,
+            rng: ModelRng::new(seed),
+            role: Role::Follower,
+            term: 0,
+            voted_for: None,
+            log: Vec::new(),
+            commit_index: 0,
+            applied_index: 0,
+            leader: None,
+            elapsed: 0,
+            election_timeout: config.election_timeout_max,
+            votes: HashSet::new(),
+            next_index: HashMap::new(),
+            match_index: HashMap::new(),
+            outbox: Vec::new(),
+        };
+        node.reset_election_timer();
+        node
+    }
+
+    /// Node ID
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Current role
+    #[must_use]
+    pub const fn role(&self) -> Role {
+        self.role
+    }
+
+    /// Whether this node is the leader of its current term
+    #[must_use]
+    pub fn is_leader(&self) -> bool {
+        self.role == Role::Leader
+    }
+
+    /// Current term
+    #[must_use]
+    pub const fn term(&self) -> u64 {
+        self.term
+    }
+
+    /// Leader of the current term, if known
+    #[must_use]
+    pub fn leader(&self) -> Option<&str> {
+        self.leader.as_deref()
+    }
+
+    /// The whole log, committed or not
+    #[must_use]
+    pub fn log(&self) -> &[LogEntry] {
+        &self.log
+    }
+
+    /// Index of the last committed entry (0 when nothing is committed)
+    #[must_use]
+    pub const fn commit_index(&self) -> u64 {
+        self.commit_index
+    }
+
+    /// Committed entries, in log order
+    #[must_use]
+    pub fn committed(&self) -> &[LogEntry] {
+        &self.log[..to_usize(self.commit_index)]
+    }
+
+    /// Decisions committed since the previous call, in log order
+    pub fn take_committed(&mut self) -> Vec<Decision> {
+        let newly = self.log[to_usize(self.applied_index)..to_usize(self.commit_index)]
+            .iter()
+            .filter_map(|entry| entry.decision.clone())
+            .collect();
+        self.applied_index = self.commit_index;
+        newly
+    }
+
+    /// Messages to deliver, in the order they were produced
+    pub fn take_messages(&mut self) -> Vec<RaftEnvelope> {
+        std::mem::take(&mut self.outbox)
+    }
+
+    /// Append a decision to the leader's log and start replicating it
+    ///
+    /// Returns the entry's log index; the decision is agreed once
+    /// [`commit_index`](Self::commit_index) reaches it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConsensusError::NotLeader`] (naming the known leader) on any other node.
+    pub fn propose(&mut self, decision: Decision) -> Result<u64, ConsensusError> {
+        if !self.is_leader() {
+            return Err(ConsensusError::NotLeader {
+                node: self.id.clone(),
+                leader: self.leader.clone(),
+            });
+        }
+        self.log.push(LogEntry { term: self.term, decision: Some(decision) });
+        self.advance_commit();
+        for peer in self.peers.clone() {
+            self.send_append(&peer);
+        }
+        Ok(self.last_index())
+    }
+
+    /// Advance logical time by one tick
+    ///
+    /// Followers and candidates stand for election when their randomized timeout
+    /// expires; leaders send heartbeats every `heartbeat_interval` ticks.
+    pub fn tick(&mut self) {
+        self.elapsed += 1;
+        match self.role {
+            Role::Leader if self.elapsed >= self.config.heartbeat_interval => {
+                self.elapsed = 0;
+                for peer in self.peers.clone() {
+                    self.send_append(&peer);
+                }
+            }
+            Role::Follower | Role::Candidate if self.elapsed >= self.election_timeout => {
+                self.start_election();
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle a message from another node
+    ///
+    /// Messages addressed to other nodes are ignored.
+    pub fn step(&mut self, envelope: RaftEnvelope) {
+        if envelope.to != self.id {
+            return;
+        }
+        let RaftEnvelope { from, message, .. } = envelope;
+        if message.term() > self.term {
+            self.term = message.term();
+            self.voted_for = None;
+            self.role = Role::Follower;
+            self.leader = None;
+        }
+
+        match message {
+            RaftMessage::RequestVote { term, last_log_index, last_log_term } => {
+                let up_to_date =
+                    (last_log_term, last_log_index) >= (self.last_term(), self.last_index());
+                let granted = term == self.term
+                    && up_to_date
+                    && self.voted_for.as_ref().is_none_or(|voted| *voted == from);
+                if granted {
+                    self.voted_for = Some(from.clone());
+                    self.reset_election_timer();
+                }
+                self.send(&from, RaftMessage::Vote { term: self.term, granted });
+            }
+            RaftMessage::Vote { term, granted } => {
+                if granted && term == self.term && self.role == Role::Candidate {
+                    self.votes.insert(from);
+                    if self.votes.len() >= self.quorum() {
+                        self.become_leader();
+                    }
+                }
+            }
+            RaftMessage::AppendEntries {
+                term,
+                prev_log_index,
+                prev_log_term,
+                entries,
+                leader_commit,
+            } => self.append_entries(
+                &from,
+                term,
+                prev_log_index,
+                prev_log_term,
+                entries,
+                leader_commit,
+            ),
+            RaftMessage::AppendResponse { term, success, match_index } => {
+                if term != self.term || self.role != Role::Leader {
+                    return;
+                }
+                if success {
+                    let known = self.match_index.entry(from.clone()).or_insert(0);
+                    *known = (*known).max(match_index);
+                    let next = *known + 1;
+                    self.next_index.insert(from, next);
+                    self.advance_commit();
+                } else {
+                    let next = self.next_index.entry(from.clone()).or_insert(1);
+                    *next = next.saturating_sub(1).max(1);
+                    self.send_append(&from);
+                }
+            }
+        }
+    }
+
+    fn append_entries(
+        &mut self,
+        leader: &str,
+        term: u64,
+        prev_log_index: u64,
+        prev_log_term: u64,
+        entries: Vec<LogEntry>,
+        leader_commit: u64,
+    ) {
+        let reject =
+            RaftMessage::AppendResponse { term: self.term, success: false, match_index: 0 };
+        if term < self.term {
+            self.send(leader, reject);
+            return;
+        }
+        self.role = Role::Follower;
+        self.leader = Some(leader.to_string());
+        self.reset_election_timer();
+        if prev_log_index > self.last_index() || self.term_at(prev_log_index) != prev_log_term {
+            self.send(leader, reject);
+            return;
+        }
+
+        let match_index = prev_log_index + entries.len() as u64;
+        for (index, entry) in (prev_log_index + 1..).zip(entries) {
+            if index <= self.last_index() {
+                if self.term_at(index) == entry.term {
+                    continue;
+                }
+                // Conflicting suffix: the leader's log wins
+                self.log.truncate(to_usize(index - 1));
+            }
+            self.log.push(entry);
+        }
+        // A reordered, older append must not move the commit index backwards
+        self.commit_index = self.commit_index.max(leader_commit.min(match_index));
+        self.send(
+            leader,
+            RaftMessage::AppendResponse { term: self.term, success: true, match_index },
+        );
+    }
+
+    fn start_election(&mut self) {
+        self.term += 1;
+        self.role = Role::Candidate;
+        self.voted_for = Some(self.id.clone());
+        self.votes = HashSet::from([self.id.clone()]);
+        self.leader = None;
+        self.reset_election_timer();
+        if self.votes.len() >= self.quorum() {
+            self.become_leader();
+            return;
+        }
+        let request = RaftMessage::RequestVote {
+            term: self.term,
+            last_log_index: self.last_index(),
+            last_log_term: self.last_term(),
+        };
+        for peer in self.peers.clone() {
+            self.send(&peer, request.clone());
+        }
+    }
+
+    fn become_leader(&mut self) {
+        self.role = Role::Leader;
+        self.leader = Some(self.id.clone());
+        self.elapsed = 0;
+        let next = self.last_index() + 1;
+        // A leader only commits entries of its own term (Raft §5.4.2, §8)
+        self.log.push(LogEntry { term: self.term, decision: None });
+        self.advance_commit();
+        for peer in self.peers.clone() {
+            self.next_index.insert(peer.clone(), next);
+            self.match_index.insert(peer.clone(), 0);
+            self.send_append(&peer);
+        }
+    }
+
+    /// Commit the highest current-term entry a quorum has replicated
+    ///
+    /// Entries from earlier terms commit along with it (Raft §5.4.2).
+    fn advance_commit(&mut self) {
+        let quorum = self.quorum();
+        for index in (self.commit_index + 1..=self.last_index()).rev() {
+            if self.term_at(index) != self.term {
+                break;
+            }
+            let replicated =
+                1 + self.match_index.values().filter(|matched| **matched >= index).count();
+            if replicated >= quorum {
+                self.commit_index = index;
+                break;
+            }
+        }
+    }
+
+    fn send_append(&mut self, peer: &str) {
+        let next = self.next_index.get(peer).copied().unwrap_or(1).max(1);
+        let prev_log_index = next - 1;
+        let entries = self.log[to_usize(prev_log_index)..]
+            .iter()
+            .take(MAX_APPEND_ENTRIES)
+            .cloned()
+            .collect();
+        let message = RaftMessage::AppendEntries {
+            term: self.term,
+            prev_log_index,
+            prev_log_term: self.term_at(prev_log_index),
+            entries,
+            leader_commit: self.commit_index,
+        };
+        self.send(peer, message);
+    }
+
+    fn send(&mut self, to: &str, message: RaftMessage) {
+        self.outbox
+            .push(RaftEnvelope { from: self.id.clone(), to: to.to_string(), message });
+    }
+
+    fn reset_election_timer(&mut self) {
+        let min = self.config.election_timeout_min;
+        let spread = self.config.election_timeout_max.saturating_sub(min) as usize + 1;
+        self.elapsed = 0;
+        self.election_timeout = min + u32::try_from(self.rng.below(spread)).unwrap_or(0);
+    }
+
+    const fn quorum(&self) -> usize {
+        let cluster = self.peers.len() + 1;
+        cluster / 2 + 1
+    }
+
+    const fn last_index(&self) -> u64 {
+        self.log.len() as u64
+    }
+
+    fn last_term(&self) -> u64 {
+        self.log.last().map_or(0, |entry| entry.term)
+    }
+
+    /// Term of the entry at 1-based `index` (0 for the empty prefix)
+    fn term_at(&self, index: u64) -> u64 {
+        match index {
+            0 => 0,
+            _ => self.log.get(to_usize(index - 1)).map_or(0, |entry| entry.term),
+        }
+    }
+}
+
+/// Log indices are bounded by the log's length, so they always fit in `usize`
+fn to_usize(index: u64) -> usize {
+    usize::try_from(index).unwrap_or(usize::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deliver messages until none are left, in send order
+    fn settle(nodes: &mut [RaftNode]) {
+        loop {
+            let messages: Vec<RaftEnvelope> =
+                nodes.iter_mut().flat_map(RaftNode::take_messages).collect();
+            if messages.is_empty() {
+                return;
+            }
+            for envelope in messages {
+                if let Some(node) = nodes.iter_mut().find(|node| node.id == envelope.to) {
+                    node.step(envelope);
+                }
+            }
+        }
+    }
+
+    fn cluster(ids: &[&str]) -> Vec<RaftNode> {
+        ids.iter()
+            .map(|id| RaftNode::new(*id, ids.iter().copied(), RaftConfig::default()))
+            .collect()
+    }
+
+    #[test]
+    fn test_single_node_commits_immediately() {
+        let mut node = RaftNode::new("a", ["a"], RaftConfig::default());
+        while !node.is_leader() {
+            node.tick();
+        }
+
+        let index = node.propose(Decision::assign_task("task-1", "agent-1"));
+
+        assert_eq!(index, Ok(2), "the leader's no-op comes first");
+        assert_eq!(node.commit_index(), 2);
+        assert_eq!(node.take_committed(), [Decision::assign_task("task-1", "agent-1")]);
+        assert!(node.take_committed().is_empty());
+    }
+
+    #[test]
+    fn test_followers_reject_proposals_and_name_the_leader() {
+        let mut nodes = cluster(&["a", "b", "c"]);
+        nodes[0].start_election();
+        settle(&mut nodes);
+
+        let result = nodes[1].propose(Decision::assign_task("task-1", "agent-1"));
+
+        assert!(nodes[0].is_leader());
+        assert_eq!(
+            result,
+            Err(ConsensusError::NotLeader { node: "b".to_string(), leader: Some("a".to_string()) })
+        );
+    }
+
+    #[test]
+    fn test_entry_commits_on_quorum_and_replicates() {
+        let mut nodes = cluster(&["a", "b", "c"]);
+        nodes[0].start_election();
+        settle(&mut nodes);
+
+        nodes[0].propose(Decision::assign_task("task-1", "agent-1")).ok();
+        settle(&mut nodes);
+        // Followers learn the commit index from the next heartbeat
+        for _ in 0..DEFAULT_HEARTBEAT_INTERVAL {
+            nodes[0].tick();
+        }
+        settle(&mut nodes);
+
+        assert!(nodes.iter().all(|node| node.commit_index() == 2), "{nodes:#?}");
+    }
+
+    #[test]
+    fn test_stale_candidate_is_denied_a_vote() {
+        let mut nodes = cluster(&["a", "b", "c"]);
+        nodes[0].start_election();
+        settle(&mut nodes);
+        nodes[0].propose(Decision::assign_task("task-1", "agent-1")).ok();
+        settle(&mut nodes);
+
+        // Make `c` a candidate that missed the entry
+        nodes[2].log.clear();
+        nodes[2].commit_index = 0;
+        nodes[2].start_election();
+        for request in nodes[2].take_messages() {
+            if let Some(voter) = nodes[..2].iter_mut().find(|node| node.id == request.to) {
+                voter.step(request);
+            }
+        }
+        let votes: Vec<RaftEnvelope> =
+            nodes[..2].iter_mut().flat_map(RaftNode::take_messages).collect();
+
+        assert!(votes
+            .iter()
+            .all(|vote| matches!(vote.message, RaftMessage::Vote { granted: false, .. })));
+    }
+}
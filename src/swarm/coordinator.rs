@@ -3,6 +3,7 @@
 //! Coordinates swarm members, manages task distribution, and ensures
 //! deterministic consensus across the swarm.
 
+use super::consensus::Decision;
 use super::member::SwarmMember;
 use super::task::{TaskQueue, TaskReceipt, TaskRequest};
 use serde::{Deserialize, Serialize};
@@ -96,6 +97,8 @@ pub struct SwarmCoordinator {
     pub task_queue: TaskQueue,
     /// Task-to-member assignments
     task_assignments: HashMap<String, String>,
+    /// Adopted operation chains and the sectors they span
+    composed_chains: HashMap<String, Vec<String>>,
     /// Consensus threshold (% of members that must agree)
     consensus_threshold: f32,
 }
@@ -108,6 +111,7 @@ impl SwarmCoordinator {
             membership: SwarmMembership::new(),
             task_queue: TaskQueue::new(),
             task_assignments: HashMap::new(),
+            composed_chains: HashMap::new(),
             consensus_threshold: DEFAULT_CONSENSUS_THRESHOLD,
         }
     }
@@ -135,14 +139,71 @@ impl SwarmCoordinator {
         // Find best member for task
         let member_id = self.find_best_member(&task)?;
 
-        // Assign task to member
-        if let Some(member) = self.membership.get_member_mut(&member_id) {
-            member.assign_task()?;
+        self.assign(&task.id, &member_id)?;
+
+        Ok((task.id, member_id))
+    }
+
+    /// Choose a member for the next queued task without assigning it
+    ///
+    /// The returned decision is proposed to the swarm's consensus group; every replica
+    /// (this one included) assigns the task when it applies the committed decision.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(String)` if no tasks are queued or no available members can handle the task.
+    pub fn plan_next_task(&mut self) -> Result<Decision, String> {
+        let Some(task) = self.task_queue.dequeue() else {
+            return Err("No tasks queued".to_string());
+        };
+        match self.find_best_member(&task) {
+            Ok(member_id) => Ok(Decision::assign_task(task.id, member_id)),
+            Err(e) => {
+                self.task_queue.enqueue(task);
+                Err(e)
+            }
+        }
+    }
+
+    /// Apply a decision committed by the swarm's consensus group
+    ///
+    /// Replicas apply committed decisions in log order, so they all reach the same
+    /// assignments and adopted chains.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(String)` if the assigned member cannot take the task.
+    pub fn apply_decision(&mut self, decision: &Decision) -> Result<(), String> {
+        match decision {
+            Decision::AssignTask { task_id, member_id } => {
+                self.task_queue.remove(task_id);
+                self.assign(task_id, member_id)
+            }
+            Decision::ComposeChain { chain_id, sectors } => {
+                self.composed_chains.insert(chain_id.clone(), sectors.clone());
+                Ok(())
+            }
         }
+    }
 
-        self.task_assignments.insert(task.id.clone(), member_id.clone());
+    /// Member a task was assigned to
+    #[must_use]
+    pub fn assignment(&self, task_id: &str) -> Option<&str> {
+        self.task_assignments.get(task_id).map(String::as_str)
+    }
 
-        Ok((task.id, member_id))
+    /// Sectors spanned by an adopted operation chain
+    #[must_use]
+    pub fn composed_chain(&self, chain_id: &str) -> Option<&[String]> {
+        self.composed_chains.get(chain_id).map(Vec::as_slice)
+    }
+
+    fn assign(&mut self, task_id: &str, member_id: &str) -> Result<(), String> {
+        if let Some(member) = self.membership.get_member_mut(member_id) {
+            member.assign_task()?;
+        }
+        self.task_assignments.insert(task_id.to_string(), member_id.to_string());
+        Ok(())
     }
 
     /// Find the best member for a task
@@ -316,6 +377,29 @@ mod tests {
         assert_eq!(member_id, "agent-1");
     }
 
+    #[test]
+    fn test_planned_task_is_assigned_when_decision_applies() {
+        let mut coordinator = SwarmCoordinator::new();
+        coordinator.register_member(
+            SwarmMember::new("agent-1".to_string(), "Agent".to_string())
+                .with_sector("Academic".to_string()),
+        );
+        coordinator.submit_task(TaskRequest::new(
+            "task-1".to_string(),
+            "Academic".to_string(),
+            "op".to_string(),
+            "data".to_string(),
+        ));
+
+        let decision = coordinator.plan_next_task().unwrap(); // Test code: unwrap is acceptable
+        assert_eq!(decision, Decision::assign_task("task-1", "agent-1"));
+        assert_eq!(coordinator.assignment("task-1"), None);
+
+        assert!(coordinator.apply_decision(&decision).is_ok());
+        assert_eq!(coordinator.assignment("task-1"), Some("agent-1"));
+        assert_eq!(coordinator.membership.total_current_tasks(), 1);
+    }
+
     #[test]
     fn test_swarm_status() {
         let mut coordinator = SwarmCoordinator::new();
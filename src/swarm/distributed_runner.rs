@@ -0,0 +1,659 @@
+//! Distributed Test Execution: Sharding a Plan Across Worker Processes
+//!
+//! [`DistributedRunner`] splits a [`TestPlan`] into shards and hands each one to the next
+//! idle worker, a local process or a command run over SSH, then merges the receipts into a
+//! single [`TestExecutionResult`] in plan order. Heavy container and Weaver suites finish
+//! in roughly the time of their slowest shard instead of the sum of all tests.
+//!
+//! # Protocol
+//!
+//! The runner writes one JSON [`Shard`] line to the worker's stdin and closes it. The
+//! worker runs the shard's contracts in order and streams one line per finished test to
+//! stdout, `ctt-result <ShardResult JSON>`, followed by `ctt-done <shard_id>`. Other
+//! output is ignored, including text before a marker on the same line, so test-harness
+//! output and SSH banners can share the stream. [`serve_shard`] and [`serve_stdio`] implement the worker side.
+//!
+//! # Worker failure
+//!
+//! A worker that exits, fails to start, or exceeds the shard timeout before `ctt-done` is
+//! retired. Results it already streamed are kept, and the rest of its shard goes back on
+//! the queue for another worker, up to `max_attempts` times per shard. Tests that run out
+//! of attempts or of workers get `Error` receipts with an `error` metadata entry.
+//!
+//! After `ctt-done` the runner waits only briefly for the worker to close stdout, so a
+//! container or daemon it left running does not turn a finished shard into a timeout.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::swarm::distributed_runner::{DistributedRunner, WorkerSpec};
+//! use chicago_tdd_tools::swarm::{ResourceBudget, QoSClass, TestPlan};
+//! use std::collections::HashMap;
+//!
+//! let plan = TestPlan {
+//!     plan_id: "nightly".to_string(),
+//!     contracts: vec!["weaver_live_check".to_string(), "postgres_roundtrip".to_string()],
+//!     requester: "ci".to_string(),
+//!     priority: 50,
+//!     qos: QoSClass::Standard,
+//!     resource_budget: ResourceBudget::unlimited(),
+//!     metadata: HashMap::new(),
+//!     requirements: HashMap::new(),
+//! };
+//!
+//! let runner = DistributedRunner::new(vec![
+//!     WorkerSpec::local("local", "target/debug/test-worker"),
+//!     WorkerSpec::ssh("ci-2.example.com", "/opt/ci/test-worker"),
+//! ]);
+//! let report = runner.run(&plan).unwrap();
+//!
+//! assert!(report.result.summary.all_passed(), "{:?}", report.failures);
+//! ```
+
+use super::test_orchestrator::{plan_receipt, TestExecutionResult, TestPlan};
+use crate::core::command::CheckedCommand;
+use crate::core::contract::TestContractRegistry;
+use crate::core::receipt::{TestOutcome, TestReceipt};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Write};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Prefix of a worker's per-test result line
+pub const RESULT_PREFIX: &str = "ctt-result ";
+
+/// Prefix of a worker's end-of-shard line
+pub const DONE_PREFIX: &str = "ctt-done ";
+
+/// Default number of times a shard is attempted before its tests error
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+
+/// Default time a worker gets to finish a shard before it is retired
+pub const DEFAULT_SHARD_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Time a worker gets to close stdout after `ctt-done` before the runner stops waiting
+///
+/// A container or helper daemon the worker started can hold stdout open long after the
+/// shard is finished.
+const DONE_GRACE: Duration = Duration::from_secs(2);
+
+/// How to start one worker
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkerSpec {
+    /// Name recorded in receipts and failures
+    pub name: String,
+    /// Program to run
+    pub program: String,
+    /// Program arguments
+    pub args: Vec<String>,
+    /// Extra environment variables for the program
+    pub env: Vec<(String, String)>,
+}
+
+impl WorkerSpec {
+    /// Worker running `program` locally
+    #[must_use]
+    pub fn local(name: impl Into<String>, program: impl Into<String>) -> Self {
+        Self { name: name.into(), program: program.into(), args: Vec::new(), env: Vec::new() }
+    }
+
+    /// Worker running `remote_command` on `host` over non-interactive SSH
+    #[must_use]
+    pub fn ssh(host: impl Into<String>, remote_command: impl Into<String>) -> Self {
+        let host = host.into();
+        Self::local(host.clone(), "ssh")
+            .with_arg("-o")
+            .with_arg("BatchMode=yes")
+            .with_arg(host)
+            .with_arg(remote_command)
+    }
+
+    /// Append an argument
+    #[must_use]
+    pub fn with_arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Set an environment variable for the program
+    ///
+    /// SSH does not forward it; put remote variables in the remote command instead.
+    #[must_use]
+    pub fn with_env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    fn spawn(&self) -> io::Result<Child> {
+        let mut command = Command::new(&self.program);
+        command
+            .args(&self.args)
+            .envs(self.env.iter().map(|(key, value)| (key, value)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit());
+        #[cfg(unix)]
+        {
+            // Own process group so a timeout also kills the worker's children
+            use std::os::unix::process::CommandExt;
+            command.process_group(0);
+        }
+        command.spawn()
+    }
+}
+
+/// Tests sent to one worker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Shard {
+    /// Shard ID (kept when the shard is re-assigned)
+    pub shard_id: usize,
+    /// Contract names to run, in order
+    pub contracts: Vec<String>,
+}
+
+/// One finished test, streamed back by a worker
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShardResult {
+    /// Contract name
+    pub contract: String,
+    /// Test outcome
+    pub outcome: TestOutcome,
+    /// Wall clock time in milliseconds
+    pub wall_clock_ms: u64,
+}
+
+/// A worker that was retired while running a shard
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkerFailure {
+    /// Worker name
+    pub worker: String,
+    /// Shard it was running
+    pub shard_id: usize,
+    /// What went wrong
+    pub reason: String,
+}
+
+/// Merged result of a distributed run
+#[derive(Debug, Clone)]
+pub struct DistributedReport {
+    /// Receipts for every contract in plan order, with summary
+    pub result: TestExecutionResult,
+    /// Workers retired during the run
+    pub failures: Vec<WorkerFailure>,
+}
+
+/// Distributed runner error
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DistributedError {
+    /// A run needs at least one worker
+    #[error("distributed runner has no workers")]
+    NoWorkers,
+}
+
+/// Shards test plans across worker processes
+#[derive(Debug, Clone)]
+pub struct DistributedRunner {
+    workers: Vec<WorkerSpec>,
+    registry: TestContractRegistry,
+    shard_size: Option<usize>,
+    max_attempts: u32,
+    shard_timeout: Duration,
+}
+
+impl DistributedRunner {
+    /// Runner over `workers`, with one shard per worker and a
+    /// [`DEFAULT_SHARD_TIMEOUT`] shard timeout
+    #[must_use]
+    pub const fn new(workers: Vec<WorkerSpec>) -> Self {
+        Self {
+            workers,
+            registry: TestContractRegistry::new(&[]),
+            shard_size: None,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            shard_timeout: DEFAULT_SHARD_TIMEOUT,
+        }
+    }
+
+    /// Build receipts from `registry`'s contracts (invariants, thermal class)
+    #[must_use]
+    pub const fn with_registry(mut self, registry: TestContractRegistry) -> Self {
+        self.registry = registry;
+        self
+    }
+
+    /// Put at most `tests` tests in each shard (smaller shards balance load better)
+    #[must_use]
+    pub const fn with_shard_size(mut self, tests: usize) -> Self {
+        self.shard_size = Some(if tests == 0 { 1 } else { tests });
+        self
+    }
+
+    /// Attempt each shard at most `attempts` times
+    #[must_use]
+    pub const fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = if attempts == 0 { 1 } else { attempts };
+        self
+    }
+
+    /// Retire a worker that has not finished its shard within `timeout`
+    #[must_use]
+    pub const fn with_shard_timeout(mut self, timeout: Duration) -> Self {
+        self.shard_timeout = timeout;
+        self
+    }
+
+    /// Run `plan` across the workers and merge the receipts
+    ///
+    /// Every receipt records the `worker`, `shard`, and `attempt` that produced it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DistributedError::NoWorkers`] when the runner has no workers. Worker
+    /// failures are not errors: they are reported in [`DistributedReport::failures`].
+    pub fn run(&self, plan: &TestPlan) -> Result<DistributedReport, DistributedError> {
+        if self.workers.is_empty() {
+            return Err(DistributedError::NoWorkers);
+        }
+        let shard_size = self
+            .shard_size
+            .unwrap_or_else(|| plan.contracts.len().div_ceil(self.workers.len()))
+            .max(1);
+        let indices: Vec<usize> = (0..plan.contracts.len()).collect();
+        let queue = indices
+            .chunks(shard_size)
+            .enumerate()
+            .map(|(shard_id, tests)| PendingShard { shard_id, attempt: 1, tests: tests.to_vec() })
+            .collect();
+        let progress = Mutex::new(Progress {
+            queue,
+            in_flight: 0,
+            receipts: vec![None; plan.contracts.len()],
+            failures: Vec::new(),
+        });
+        let changed = Condvar::new();
+
+        std::thread::scope(|scope| {
+            for worker in &self.workers {
+                let (progress, changed) = (&progress, &changed);
+                scope.spawn(move || {
+                    while let Some(pending) = next_shard(progress, changed) {
+                        let retired = self.run_pending(plan, worker, pending, progress);
+                        changed.notify_all();
+                        if retired {
+                            return;
+                        }
+                    }
+                });
+            }
+        });
+
+        let mut progress = progress.into_inner().unwrap_or_else(PoisonError::into_inner);
+        while let Some(pending) = progress.queue.pop_front() {
+            let reason = format!("no workers left to run shard {}", pending.shard_id);
+            for index in pending.tests {
+                progress.receipts[index] = Some(self.error_receipt(plan, index, &reason));
+            }
+        }
+        let receipts = progress.receipts.into_iter().flatten().collect();
+        Ok(DistributedReport {
+            result: TestExecutionResult::from_receipts(&plan.plan_id, receipts),
+            failures: progress.failures,
+        })
+    }
+
+    /// Run one shard on `worker` and record its results; returns whether the worker retired
+    fn run_pending(
+        &self,
+        plan: &TestPlan,
+        worker: &WorkerSpec,
+        pending: PendingShard,
+        progress: &Mutex<Progress>,
+    ) -> bool {
+        let shard = Shard {
+            shard_id: pending.shard_id,
+            contracts: pending.tests.iter().map(|index| plan.contracts[*index].clone()).collect(),
+        };
+        let run = run_shard(worker, &shard, self.shard_timeout);
+
+        let mut progress = progress.lock().unwrap_or_else(PoisonError::into_inner);
+        progress.in_flight -= 1;
+        let mut remaining = pending.tests;
+        for result in run.results {
+            let Some(position) =
+                remaining.iter().position(|index| plan.contracts[*index] == result.contract)
+            else {
+                continue;
+            };
+            let index = remaining.remove(position);
+            let mut receipt = plan_receipt(
+                &self.registry,
+                plan,
+                &result.contract,
+                result.outcome,
+                result.wall_clock_ms,
+            );
+            receipt.add_metadata("worker", worker.name.clone());
+            receipt.add_metadata("shard", pending.shard_id.to_string());
+            receipt.add_metadata("attempt", pending.attempt.to_string());
+            progress.receipts[index] = Some(receipt);
+        }
+
+        let Some(reason) = run.failure else {
+            let reason =
+                format!("{} finished shard {} without reporting", worker.name, shard.shard_id);
+            for index in remaining {
+                progress.receipts[index] = Some(self.error_receipt(plan, index, &reason));
+            }
+            return false;
+        };
+        if !remaining.is_empty() {
+            if pending.attempt < self.max_attempts {
+                progress.queue.push_front(PendingShard {
+                    shard_id: pending.shard_id,
+                    attempt: pending.attempt + 1,
+                    tests: remaining,
+                });
+            } else {
+                let reason = format!(
+                    "shard {} failed {} times; last failure on {}: {reason}",
+                    pending.shard_id, pending.attempt, worker.name
+                );
+                for index in remaining {
+                    progress.receipts[index] = Some(self.error_receipt(plan, index, &reason));
+                }
+            }
+        }
+        progress.failures.push(WorkerFailure {
+            worker: worker.name.clone(),
+            shard_id: pending.shard_id,
+            reason,
+        });
+        true
+    }
+
+    fn error_receipt(&self, plan: &TestPlan, index: usize, reason: &str) -> TestReceipt {
+        let mut receipt =
+            plan_receipt(&self.registry, plan, &plan.contracts[index], TestOutcome::Error, 0);
+        receipt.add_metadata("error", reason);
+        receipt
+    }
+}
+
+/// A shard waiting for a worker; `tests` index into the plan's contracts
+struct PendingShard {
+    shard_id: usize,
+    attempt: u32,
+    tests: Vec<usize>,
+}
+
+/// Shared state of a run
+struct Progress {
+    queue: VecDeque<PendingShard>,
+    in_flight: usize,
+    receipts: Vec<Option<TestReceipt>>,
+    failures: Vec<WorkerFailure>,
+}
+
+/// Take the next shard, waiting while running shards may still be re-queued
+///
+/// Returns `None` once the queue is empty and no shard is running.
+fn next_shard(progress: &Mutex<Progress>, changed: &Condvar) -> Option<PendingShard> {
+    let mut progress = progress.lock().unwrap_or_else(PoisonError::into_inner);
+    loop {
+        if let Some(pending) = progress.queue.pop_front() {
+            progress.in_flight += 1;
+            return Some(pending);
+        }
+        if progress.in_flight == 0 {
+            return None;
+        }
+        progress = changed.wait(progress).unwrap_or_else(PoisonError::into_inner);
+    }
+}
+
+/// Protocol line from a worker
+#[derive(Debug, PartialEq, Eq)]
+enum WorkerMessage {
+    Result(ShardResult),
+    Done(usize),
+}
+
+impl WorkerMessage {
+    /// Parse the message in `line`, skipping any unterminated output before it
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim_end();
+        if let Some(start) = line.find(RESULT_PREFIX) {
+            serde_json::from_str(&line[start + RESULT_PREFIX.len()..])
+                .ok()
+                .map(Self::Result)
+        } else {
+            let start = line.find(DONE_PREFIX)?;
+            line[start + DONE_PREFIX.len()..].parse().ok().map(Self::Done)
+        }
+    }
+}
+
+/// What one worker process reported for a shard
+struct ShardRun {
+    results: Vec<ShardResult>,
+    /// Why the worker did not finish the shard
+    failure: Option<String>,
+}
+
+fn run_shard(worker: &WorkerSpec, shard: &Shard, timeout: Duration) -> ShardRun {
+    let mut child = match worker.spawn() {
+        Ok(child) => child,
+        Err(error) => {
+            return ShardRun {
+                results: Vec::new(),
+                failure: Some(format!("failed to start `{}`: {error}", worker.program)),
+            }
+        }
+    };
+    if let Some(mut stdin) = child.stdin.take() {
+        // A worker that exits without reading its shard is reported through its exit status
+        serde_json::to_writer(&mut stdin, shard)
+            .map_err(io::Error::from)
+            .and_then(|()| stdin.write_all(b"\n"))
+            .ok();
+    }
+    let (sender, receiver) = mpsc::channel();
+    let stdout = child.stdout.take();
+    let reader = std::thread::spawn(move || {
+        let Some(stdout) = stdout else { return };
+        for line in BufReader::new(stdout).lines() {
+            let Ok(line) = line else { return };
+            if let Some(message) = WorkerMessage::parse(&line) {
+                if sender.send(message).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+
+    // Read until the worker closes stdout, so a finished worker has also exited; after
+    // `ctt-done` only wait out the grace period
+    let mut deadline = Instant::now() + timeout;
+    let mut results = Vec::new();
+    let mut done = false;
+    let mut stdout_closed = false;
+    loop {
+        match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(WorkerMessage::Result(result)) => results.push(result),
+            Ok(WorkerMessage::Done(shard_id)) if shard_id == shard.shard_id => {
+                done = true;
+                deadline = deadline.min(Instant::now() + DONE_GRACE);
+            }
+            Ok(WorkerMessage::Done(_)) => {}
+            Err(RecvTimeoutError::Timeout) => break,
+            Err(RecvTimeoutError::Disconnected) => {
+                stdout_closed = true;
+                break;
+            }
+        }
+    }
+    let timed_out = !stdout_closed && !done;
+    // A finished worker that has not exited is killed; one that exited leaves whatever
+    // holds its stdout running, and the reader thread ends when that closes it
+    if timed_out || (!stdout_closed && matches!(child.try_wait(), Ok(None))) {
+        kill_group(&mut child);
+    }
+    let status = child.wait();
+    if stdout_closed {
+        reader.join().ok();
+    }
+
+    let failure = if timed_out {
+        Some(format!("timed out after {timeout:?}"))
+    } else if done {
+        None
+    } else {
+        Some(match status {
+            Ok(status) => format!("exited ({status}) before finishing the shard"),
+            Err(error) => format!("could not be waited on: {error}"),
+        })
+    };
+    ShardRun { results, failure }
+}
+
+/// Kill the worker and everything in its process group
+///
+/// A grandchild that inherited stdout would otherwise keep the reader thread blocked.
+fn kill_group(child: &mut Child) {
+    #[cfg(unix)]
+    {
+        // `kill` exits non-zero once the group is gone; that is the goal
+        CheckedCommand::new("kill")
+            .args(["-KILL", "--", &format!("-{}", child.id())])
+            .output()
+            .ok();
+    }
+    child.kill().ok();
+}
+
+/// Worker side of the protocol: read a shard from `input`, run each contract with `run`,
+/// and stream the results to `output`
+///
+/// Returns the number of tests run.
+///
+/// # Errors
+///
+/// Returns an error when `input` does not hold a shard or `output` cannot be written.
+pub fn serve_shard<R, W, F>(mut input: R, mut output: W, mut run: F) -> io::Result<usize>
+where
+    R: BufRead,
+    W: Write,
+    F: FnMut(&str) -> TestOutcome,
+{
+    let mut line = String::new();
+    input.read_line(&mut line)?;
+    let shard: Shard = serde_json::from_str(&line)
+        .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+    for contract in &shard.contracts {
+        let started = Instant::now();
+        let outcome = run(contract);
+        let result = ShardResult {
+            contract: contract.clone(),
+            outcome,
+            wall_clock_ms: u64::try_from(started.elapsed().as_millis()).unwrap_or(u64::MAX),
+        };
+        writeln!(output, "{RESULT_PREFIX}{}", serde_json::to_string(&result)?)?;
+        output.flush()?;
+    }
+    writeln!(output, "{DONE_PREFIX}{}", shard.shard_id)?;
+    output.flush()?;
+    Ok(shard.contracts.len())
+}
+
+/// [`serve_shard`] over the process's stdin and stdout
+///
+/// # Errors
+///
+/// Returns an error when stdin does not hold a shard or stdout cannot be written.
+pub fn serve_stdio<F>(run: F) -> io::Result<usize>
+where
+    F: FnMut(&str) -> TestOutcome,
+{
+    serve_shard(io::stdin().lock(), io::stdout().lock(), run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::swarm::test_orchestrator::{QoSClass, ResourceBudget};
+    use std::collections::HashMap;
+
+    #[test]
+    #[allow(clippy::unwrap_used)] // Test code: unwrap is acceptable
+    fn test_serve_shard_streams_results_then_done() {
+        // Arrange
+        let shard = Shard { shard_id: 7, contracts: vec!["a".to_string(), "b".to_string()] };
+        let input = format!("{}\n", serde_json::to_string(&shard).unwrap());
+        let mut output = Vec::new();
+
+        // Act
+        let ran = serve_shard(input.as_bytes(), &mut output, |contract| {
+            if contract == "a" {
+                TestOutcome::Pass
+            } else {
+                TestOutcome::Fail
+            }
+        })
+        .unwrap();
+
+        // Assert
+        let messages: Vec<_> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .filter_map(WorkerMessage::parse)
+            .collect();
+        assert_eq!(ran, 2);
+        assert_eq!(messages.len(), 3);
+        assert!(
+            matches!(&messages[0], WorkerMessage::Result(r) if r.contract == "a" && r.outcome == TestOutcome::Pass)
+        );
+        assert!(
+            matches!(&messages[1], WorkerMessage::Result(r) if r.contract == "b" && r.outcome == TestOutcome::Fail)
+        );
+        assert_eq!(messages[2], WorkerMessage::Done(7));
+    }
+
+    #[test]
+    fn test_unprefixed_lines_are_ignored() {
+        assert_eq!(WorkerMessage::parse("running 1 test"), None);
+        assert_eq!(WorkerMessage::parse("ctt-result not json"), None);
+        assert_eq!(WorkerMessage::parse("ctt-done 3\r"), Some(WorkerMessage::Done(3)));
+        assert_eq!(
+            WorkerMessage::parse("test worker ... ctt-done 4"),
+            Some(WorkerMessage::Done(4))
+        );
+    }
+
+    #[test]
+    fn test_ssh_worker_runs_remote_command() {
+        let worker = WorkerSpec::ssh("ci-2", "/opt/ci/worker --shard");
+        assert_eq!(worker.name, "ci-2");
+        assert_eq!(worker.program, "ssh");
+        assert_eq!(worker.args, ["-o", "BatchMode=yes", "ci-2", "/opt/ci/worker --shard"]);
+    }
+
+    #[test]
+    fn test_runner_without_workers_is_rejected() {
+        let plan = TestPlan {
+            plan_id: "empty".to_string(),
+            contracts: Vec::new(),
+            requester: "agent".to_string(),
+            priority: 0,
+            qos: QoSClass::Standard,
+            resource_budget: ResourceBudget::default_budget(),
+            metadata: HashMap::new(),
+            requirements: HashMap::new(),
+        };
+        let result = DistributedRunner::new(Vec::new()).run(&plan);
+        assert!(matches!(result, Err(DistributedError::NoWorkers)));
+    }
+}
@@ -0,0 +1,685 @@
+//! Swarm Gossip: SWIM-Style Membership over UDP
+//!
+//! Lets swarm members in separate processes or on separate machines discover each
+//! other. Each [`GossipNode`] binds a UDP socket and runs the SWIM failure detector on
+//! a background thread: every protocol period it pings one peer, asks
+//! `indirect_probes` other peers to ping it when the direct ack is late, marks it
+//! suspect when neither answers, and declares it failed once the suspicion times out.
+//! Every ping and ack piggybacks the sender's membership view, so joins, heartbeats,
+//! and failures spread through the swarm without extra messages.
+//!
+//! A suspected member refutes the suspicion by gossiping a higher incarnation number;
+//! a restarted member rejoins the same way. In [`GossipNode::membership`], suspect
+//! peers are reported as [`MemberState::Offline`] and failed peers as
+//! [`MemberState::Failed`].
+
+use super::coordinator::SwarmMembership;
+use super::member::{MemberState, SwarmMember};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io;
+use std::net::{SocketAddr, ToSocketAddrs, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// Largest UDP payload a node sends or accepts
+///
+/// Messages carry the full membership view, which bounds a swarm to a few hundred members.
+const MAX_DATAGRAM: usize = 65_507;
+
+/// How long the protocol thread blocks on the socket before checking its timers
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// Default interval between probes
+pub const DEFAULT_PROTOCOL_PERIOD: Duration = Duration::from_millis(200);
+
+/// Default wait for a direct ack before probing indirectly
+pub const DEFAULT_ACK_TIMEOUT: Duration = Duration::from_millis(80);
+
+/// Default number of peers asked to probe an unresponsive peer
+pub const DEFAULT_INDIRECT_PROBES: usize = 3;
+
+/// Default time a peer stays suspect before it is declared failed
+pub const DEFAULT_SUSPICION_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// SWIM protocol settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GossipConfig {
+    /// Swarm name; messages from other swarms are ignored
+    pub swarm_id: String,
+    /// Interval between probes of successive peers
+    pub protocol_period: Duration,
+    /// How long to wait for a direct ack before probing indirectly
+    pub ack_timeout: Duration,
+    /// Number of peers asked to probe an unresponsive peer
+    pub indirect_probes: usize,
+    /// How long a peer stays suspect before it is declared failed
+    pub suspicion_timeout: Duration,
+    /// Address gossiped to peers (defaults to the bound address)
+    ///
+    /// Set it when binding a wildcard address such as `0.0.0.0:7946`.
+    pub advertise_addr: Option<SocketAddr>,
+}
+
+impl GossipConfig {
+    /// Default settings for the swarm named `swarm_id`
+    #[must_use]
+    pub fn new(swarm_id: impl Into<String>) -> Self {
+        Self { swarm_id: swarm_id.into(), ..Self::default() }
+    }
+
+    /// Set the protocol period
+    #[must_use]
+    pub const fn with_protocol_period(mut self, period: Duration) -> Self {
+        self.protocol_period = period;
+        self
+    }
+
+    /// Set the direct ack timeout
+    #[must_use]
+    pub const fn with_ack_timeout(mut self, timeout: Duration) -> Self {
+        self.ack_timeout = timeout;
+        self
+    }
+
+    /// Set how many peers probe an unresponsive peer
+    #[must_use]
+    pub const fn with_indirect_probes(mut self, count: usize) -> Self {
+        self.indirect_probes = count;
+        self
+    }
+
+    /// Set the suspicion timeout
+    #[must_use]
+    pub const fn with_suspicion_timeout(mut self, timeout: Duration) -> Self {
+        self.suspicion_timeout = timeout;
+        self
+    }
+
+    /// Set the address gossiped to peers
+    #[must_use]
+    pub const fn with_advertise_addr(mut self, addr: SocketAddr) -> Self {
+        self.advertise_addr = Some(addr);
+        self
+    }
+}
+
+impl Default for GossipConfig {
+    fn default() -> Self {
+        Self {
+            swarm_id: "swarm".to_string(),
+            protocol_period: DEFAULT_PROTOCOL_PERIOD,
+            ack_timeout: DEFAULT_ACK_TIMEOUT,
+            indirect_probes: DEFAULT_INDIRECT_PROBES,
+            suspicion_timeout: DEFAULT_SUSPICION_TIMEOUT,
+            advertise_addr: None,
+        }
+    }
+}
+
+/// Liveness of a peer as seen by the failure detector
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PeerStatus {
+    /// Peer answered a recent probe, or nobody has suspected it
+    Alive,
+    /// Peer missed a probe and has not refuted the suspicion yet
+    Suspect,
+    /// Peer stayed suspect past the suspicion timeout
+    Failed,
+}
+
+impl PeerStatus {
+    /// The state reported for a member in this status whose own state is `reported`
+    #[must_use]
+    pub const fn member_state(self, reported: MemberState) -> MemberState {
+        match self {
+            Self::Alive => reported,
+            Self::Suspect => MemberState::Offline,
+            Self::Failed => MemberState::Failed,
+        }
+    }
+}
+
+impl std::fmt::Display for PeerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Alive => write!(f, "Alive"),
+            Self::Suspect => write!(f, "Suspect"),
+            Self::Failed => write!(f, "Failed"),
+        }
+    }
+}
+
+/// One member's entry in a membership view
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Update {
+    member: SwarmMember,
+    addr: SocketAddr,
+    incarnation: u64,
+    status: PeerStatus,
+}
+
+impl Update {
+    /// Whether this entry supersedes `current` under SWIM's precedence rules
+    ///
+    /// Higher incarnations win; at equal incarnations suspicion overrides aliveness.
+    /// A failure is final unless the member rejoins with a higher incarnation.
+    const fn supersedes(&self, current: &Self) -> bool {
+        match (self.status, current.status) {
+            (PeerStatus::Alive, PeerStatus::Failed) => self.incarnation > current.incarnation,
+            (_, PeerStatus::Failed) => false,
+            (PeerStatus::Failed, _) => true,
+            (PeerStatus::Suspect, PeerStatus::Alive) => self.incarnation >= current.incarnation,
+            _ => self.incarnation > current.incarnation,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+enum Message {
+    Ping { seq: u64 },
+    PingReq { seq: u64, target: SocketAddr },
+    Ack { seq: u64 },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Envelope {
+    swarm_id: String,
+    from: String,
+    updates: Vec<Update>,
+    message: Message,
+}
+
+type Outgoing = (SocketAddr, Envelope);
+
+#[derive(Debug)]
+struct Peer {
+    update: Update,
+    changed_at: Instant,
+}
+
+#[derive(Debug)]
+struct Probe {
+    target: String,
+    seq: u64,
+    sent_at: Instant,
+    indirect: bool,
+}
+
+#[derive(Debug)]
+struct Relay {
+    requester: SocketAddr,
+    seq: u64,
+    received_at: Instant,
+}
+
+/// Protocol state shared between a node handle and its protocol thread
+#[derive(Debug)]
+struct GossipState {
+    config: GossipConfig,
+    local: Update,
+    peers: HashMap<String, Peer>,
+    next_seq: u64,
+    probe: Option<Probe>,
+    relays: HashMap<u64, Relay>,
+    cursor: usize,
+    period_started: Instant,
+}
+
+impl GossipState {
+    fn new(member: SwarmMember, addr: SocketAddr, config: GossipConfig, now: Instant) -> Self {
+        Self {
+            config,
+            local: Update { member, addr, incarnation: 0, status: PeerStatus::Alive },
+            peers: HashMap::new(),
+            next_seq: 0,
+            probe: None,
+            relays: HashMap::new(),
+            cursor: 0,
+            period_started: now,
+        }
+    }
+
+    const fn next_seq(&mut self) -> u64 {
+        self.next_seq += 1;
+        self.next_seq
+    }
+
+    fn envelope(&self, message: Message) -> Envelope {
+        let updates = std::iter::once(self.local.clone())
+            .chain(self.peers.values().map(|peer| peer.update.clone()))
+            .collect();
+        Envelope {
+            swarm_id: self.config.swarm_id.clone(),
+            from: self.local.member.id.clone(),
+            updates,
+            message,
+        }
+    }
+
+    /// Merge one gossiped entry into the view
+    fn apply(&mut self, update: Update, now: Instant) {
+        if update.member.id == self.local.member.id {
+            // Refute suspicion (or a stale failure) by outbidding it
+            if update.status != PeerStatus::Alive && update.incarnation >= self.local.incarnation {
+                self.local.incarnation = update.incarnation + 1;
+            }
+            return;
+        }
+        match self.peers.get_mut(&update.member.id) {
+            Some(peer) if update.supersedes(&peer.update) => {
+                if peer.update.status != update.status {
+                    peer.changed_at = now;
+                }
+                peer.update = update;
+            }
+            Some(_) => {}
+            None => {
+                self.peers.insert(update.member.id.clone(), Peer { update, changed_at: now });
+            }
+        }
+    }
+
+    /// Handle one datagram from `src`, returning the replies to send
+    fn receive(&mut self, bytes: &[u8], src: SocketAddr, now: Instant) -> Vec<Outgoing> {
+        let Ok(envelope) = serde_json::from_slice::<Envelope>(bytes) else {
+            return Vec::new();
+        };
+        if envelope.swarm_id != self.config.swarm_id || envelope.from == self.local.member.id {
+            return Vec::new();
+        }
+        for update in envelope.updates {
+            self.apply(update, now);
+        }
+        if let Some(peer) = self.peers.get_mut(&envelope.from) {
+            peer.update.member.last_heartbeat = chrono::Utc::now().to_rfc3339();
+        }
+
+        match envelope.message {
+            Message::Ping { seq } => vec![(src, self.envelope(Message::Ack { seq }))],
+            Message::PingReq { seq, target } => {
+                let relayed = self.next_seq();
+                self.relays.insert(relayed, Relay { requester: src, seq, received_at: now });
+                vec![(target, self.envelope(Message::Ping { seq: relayed }))]
+            }
+            Message::Ack { seq } => {
+                if self.probe.as_ref().is_some_and(|probe| probe.seq == seq) {
+                    self.probe = None;
+                    Vec::new()
+                } else if let Some(relay) = self.relays.remove(&seq) {
+                    vec![(relay.requester, self.envelope(Message::Ack { seq: relay.seq }))]
+                } else {
+                    Vec::new()
+                }
+            }
+        }
+    }
+
+    /// Advance the failure detector's timers, returning the probes to send
+    fn tick(&mut self, now: Instant) -> Vec<Outgoing> {
+        let mut outgoing = Vec::new();
+        let period = self.config.protocol_period;
+
+        if let Some(probe) = self.probe.as_mut() {
+            let waited = now.duration_since(probe.sent_at);
+            if waited >= period {
+                let target = probe.target.clone();
+                self.probe = None;
+                self.suspect(&target, now);
+            } else if !probe.indirect && waited >= self.config.ack_timeout {
+                probe.indirect = true;
+                let (target, seq) = (probe.target.clone(), probe.seq);
+                outgoing.extend(self.indirect_probes(&target, seq));
+            }
+        }
+
+        let suspicion_timeout = self.config.suspicion_timeout;
+        for peer in self.peers.values_mut() {
+            if peer.update.status == PeerStatus::Suspect
+                && now.duration_since(peer.changed_at) >= suspicion_timeout
+            {
+                peer.update.status = PeerStatus::Failed;
+                peer.changed_at = now;
+            }
+        }
+        self.relays.retain(|_, relay| now.duration_since(relay.received_at) < period);
+
+        if self.probe.is_none() && now.duration_since(self.period_started) >= period {
+            self.period_started = now;
+            self.local.member.heartbeat();
+            if let Some(target) = self.next_probe_target() {
+                let seq = self.next_seq();
+                let addr = self.peers[&target].update.addr;
+                self.probe = Some(Probe { target, seq, sent_at: now, indirect: false });
+                outgoing.push((addr, self.envelope(Message::Ping { seq })));
+            }
+        }
+        outgoing
+    }
+
+    fn suspect(&mut self, member_id: &str, now: Instant) {
+        if let Some(peer) = self.peers.get_mut(member_id) {
+            if peer.update.status == PeerStatus::Alive {
+                peer.update.status = PeerStatus::Suspect;
+                peer.changed_at = now;
+            }
+        }
+    }
+
+    /// Peer IDs in probe order, optionally restricted to alive peers
+    fn ordered_peers(&self, alive_only: bool) -> Vec<&String> {
+        let mut ids: Vec<&String> = self
+            .peers
+            .iter()
+            .filter(|(_, peer)| match peer.update.status {
+                PeerStatus::Alive => true,
+                PeerStatus::Suspect => !alive_only,
+                PeerStatus::Failed => false,
+            })
+            .map(|(id, _)| id)
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    /// Round-robin over the peers that are not known to have failed
+    fn next_probe_target(&mut self) -> Option<String> {
+        let ids = self.ordered_peers(false);
+        if ids.is_empty() {
+            return None;
+        }
+        let target = ids[self.cursor % ids.len()].clone();
+        self.cursor = self.cursor.wrapping_add(1);
+        Some(target)
+    }
+
+    fn indirect_probes(&self, target: &str, seq: u64) -> Vec<Outgoing> {
+        let Some(target_addr) = self.peers.get(target).map(|peer| peer.update.addr) else {
+            return Vec::new();
+        };
+        let helpers = self.ordered_peers(true);
+        let helpers: Vec<&String> = helpers.into_iter().filter(|id| *id != target).collect();
+        if helpers.is_empty() {
+            return Vec::new();
+        }
+        let start = self.cursor % helpers.len();
+        helpers
+            .iter()
+            .cycle()
+            .skip(start)
+            .take(self.config.indirect_probes.min(helpers.len()))
+            .map(|id| {
+                let message = Message::PingReq { seq, target: target_addr };
+                (self.peers[*id].update.addr, self.envelope(message))
+            })
+            .collect()
+    }
+
+    fn membership(&self) -> SwarmMembership {
+        let mut membership = SwarmMembership::new();
+        membership.swarm_id.clone_from(&self.config.swarm_id);
+        membership.add_member(self.local.member.clone());
+        for peer in self.peers.values() {
+            let mut member = peer.update.member.clone();
+            member.state = peer.update.status.member_state(member.state);
+            membership.add_member(member);
+        }
+        membership
+    }
+}
+
+/// A swarm member gossiping its membership over UDP
+///
+/// Dropping the node stops its protocol thread; peers then detect it as failed.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use chicago_tdd_tools::swarm::{GossipConfig, GossipNode, SwarmMember};
+///
+/// let member = SwarmMember::new("agent-1".to_string(), "Academic Agent".to_string());
+/// let node = GossipNode::start(member, "10.0.0.5:7946", GossipConfig::new("claims-swarm"))?;
+/// node.join("10.0.0.4:7946")?;
+///
+/// let membership = node.membership();
+/// println!("{} members", membership.member_count());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct GossipNode {
+    id: String,
+    local_addr: SocketAddr,
+    socket: Arc<UdpSocket>,
+    state: Arc<Mutex<GossipState>>,
+    running: Arc<AtomicBool>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl GossipNode {
+    /// Bind `addr` and start gossiping as `member`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket cannot be bound or configured.
+    pub fn start(
+        member: SwarmMember,
+        addr: impl ToSocketAddrs,
+        config: GossipConfig,
+    ) -> io::Result<Self> {
+        let socket = UdpSocket::bind(addr)?;
+        socket.set_read_timeout(Some(POLL_INTERVAL))?;
+        let local_addr = socket.local_addr()?;
+        let advertised = config.advertise_addr.unwrap_or(local_addr);
+
+        let id = member.id.clone();
+        let socket = Arc::new(socket);
+        let state =
+            Arc::new(Mutex::new(GossipState::new(member, advertised, config, Instant::now())));
+        let running = Arc::new(AtomicBool::new(true));
+        let worker = {
+            let (socket, state, running) =
+                (Arc::clone(&socket), Arc::clone(&state), Arc::clone(&running));
+            std::thread::Builder::new()
+                .name(format!("swarm-gossip-{id}"))
+                .spawn(move || run(&socket, &state, &running))?
+        };
+
+        Ok(Self { id, local_addr, socket, state, running, worker: Some(worker) })
+    }
+
+    /// ID of the local member
+    #[must_use]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Address the node's socket is bound to
+    #[must_use]
+    pub const fn local_addr(&self) -> SocketAddr {
+        self.local_addr
+    }
+
+    /// Join a swarm through any existing member at `seed`
+    ///
+    /// The seed learns about this node from the ping and answers with its view, after
+    /// which gossip spreads the join to the rest of the swarm.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `seed` does not resolve or the ping cannot be sent.
+    pub fn join(&self, seed: impl ToSocketAddrs) -> io::Result<()> {
+        let seed = seed
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "seed has no address"))?;
+        let envelope = {
+            let mut state = lock(&self.state);
+            let seq = state.next_seq();
+            state.envelope(Message::Ping { seq })
+        };
+        send(&self.socket, seed, &envelope)
+    }
+
+    /// Snapshot of the swarm as this node sees it, including itself
+    #[must_use]
+    pub fn membership(&self) -> SwarmMembership {
+        lock(&self.state).membership()
+    }
+
+    /// Failure-detector status of a peer, or `None` if the peer is unknown
+    #[must_use]
+    pub fn peer_status(&self, member_id: &str) -> Option<PeerStatus> {
+        lock(&self.state).peers.get(member_id).map(|peer| peer.update.status)
+    }
+
+    /// IDs of peers currently considered alive, sorted
+    #[must_use]
+    pub fn alive_peers(&self) -> Vec<String> {
+        lock(&self.state).ordered_peers(true).into_iter().cloned().collect()
+    }
+
+    /// The local member's incarnation number (raised each time it refutes a suspicion)
+    #[must_use]
+    pub fn incarnation(&self) -> u64 {
+        lock(&self.state).local.incarnation
+    }
+
+    /// Stop gossiping and wait for the protocol thread to exit
+    pub fn shutdown(mut self) {
+        self.stop();
+    }
+
+    fn stop(&mut self) {
+        self.running.store(false, Ordering::Release);
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for GossipNode {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn lock(state: &Mutex<GossipState>) -> MutexGuard<'_, GossipState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn send(socket: &UdpSocket, addr: SocketAddr, envelope: &Envelope) -> io::Result<()> {
+    let bytes = serde_json::to_vec(envelope)?;
+    if bytes.len() > MAX_DATAGRAM {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("gossip message of {} bytes exceeds the UDP limit", bytes.len()),
+        ));
+    }
+    socket.send_to(&bytes, addr).map(|_| ())
+}
+
+/// Protocol thread: receive datagrams, advance timers, send what they produce
+fn run(socket: &UdpSocket, state: &Mutex<GossipState>, running: &AtomicBool) {
+    let mut buffer = vec![0_u8; MAX_DATAGRAM];
+    while running.load(Ordering::Acquire) {
+        // Read timeouts and transient errors (such as ICMP port unreachable) only
+        // mean there is nothing to handle; the timers still advance
+        let received = socket.recv_from(&mut buffer).ok();
+        let outgoing = {
+            let mut state = lock(state);
+            let now = Instant::now();
+            let mut outgoing = received
+                .map(|(len, src)| state.receive(&buffer[..len], src, now))
+                .unwrap_or_default();
+            outgoing.extend(state.tick(now));
+            outgoing
+        };
+        for (addr, envelope) in outgoing {
+            // A lost datagram is indistinguishable from a lost peer; SWIM tolerates both
+            let _ = send(socket, addr, &envelope);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addr(port: u16) -> SocketAddr {
+        SocketAddr::from(([127, 0, 0, 1], port))
+    }
+
+    fn update(id: &str, incarnation: u64, status: PeerStatus) -> Update {
+        Update {
+            member: SwarmMember::new(id.to_string(), id.to_string()),
+            addr: addr(7946),
+            incarnation,
+            status,
+        }
+    }
+
+    fn state(id: &str) -> GossipState {
+        let member = SwarmMember::new(id.to_string(), id.to_string());
+        GossipState::new(member, addr(7000), GossipConfig::default(), Instant::now())
+    }
+
+    #[test]
+    fn test_update_precedence() {
+        let alive = update("b", 1, PeerStatus::Alive);
+        let suspect = update("b", 1, PeerStatus::Suspect);
+        let failed = update("b", 1, PeerStatus::Failed);
+
+        assert!(suspect.supersedes(&alive));
+        assert!(!alive.supersedes(&suspect));
+        assert!(update("b", 2, PeerStatus::Alive).supersedes(&suspect));
+        assert!(failed.supersedes(&update("b", 5, PeerStatus::Alive)));
+        assert!(!update("b", 9, PeerStatus::Suspect).supersedes(&failed));
+        assert!(update("b", 2, PeerStatus::Alive).supersedes(&failed));
+    }
+
+    #[test]
+    fn test_suspected_member_refutes_with_higher_incarnation() {
+        let mut state = state("a");
+
+        state.apply(update("a", 0, PeerStatus::Suspect), Instant::now());
+
+        assert_eq!(state.local.incarnation, 1);
+        assert_eq!(state.local.status, PeerStatus::Alive);
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)] // Test code: unwrap is acceptable
+    fn test_ping_is_acked_and_merges_sender_view() {
+        let mut sender = state("b");
+        let mut receiver = state("a");
+        let ping = serde_json::to_vec(&sender.envelope(Message::Ping { seq: 7 })).unwrap();
+
+        let replies = receiver.receive(&ping, addr(7001), Instant::now());
+
+        assert_eq!(receiver.peers["b"].update.status, PeerStatus::Alive);
+        assert_eq!(replies.len(), 1);
+        assert_eq!(replies[0].0, addr(7001));
+        assert!(matches!(replies[0].1.message, Message::Ack { seq: 7 }));
+        let ack = serde_json::to_vec(&replies[0].1).unwrap();
+        sender.receive(&ack, addr(7000), Instant::now());
+        assert!(sender.peers.contains_key("a"));
+    }
+
+    #[test]
+    fn test_unanswered_probe_leads_to_failure() {
+        let config = GossipConfig::default();
+        let mut state = state("a");
+        let start = Instant::now();
+        state.apply(update("b", 0, PeerStatus::Alive), start);
+
+        let probes = state.tick(start + config.protocol_period);
+        assert!(matches!(probes[0].1.message, Message::Ping { .. }));
+        state.tick(start + config.protocol_period * 2);
+        assert_eq!(state.peers["b"].update.status, PeerStatus::Suspect);
+        state.tick(start + config.protocol_period * 2 + config.suspicion_timeout);
+        assert_eq!(state.peers["b"].update.status, PeerStatus::Failed);
+        assert_eq!(state.membership().get_member("b").map(|m| m.state), Some(MemberState::Failed));
+    }
+}
@@ -15,20 +15,39 @@
 //!    ↓                        ↓                         ↓
 //! TaskQueue          KnowledgeComposition          Consensus
 //! ```
+//!
+//! Across processes or machines, each member runs a [`GossipNode`] that discovers
+//! peers and detects failures with SWIM-style gossip over UDP, and coordinator
+//! replicas agree on task assignments and composed chains through a [`RaftNode`].
+//! A [`DistributedRunner`] shards test plans across worker processes or SSH hosts.
+//! A [`FileTaskStore`] keeps queued and leased tasks on disk, so orchestration
+//! survives a coordinator crash with at-least-once delivery.
 
 pub mod composition;
+pub mod consensus;
 pub mod coordinator;
+pub mod distributed_runner;
+pub mod gossip;
 pub mod member;
 pub mod task;
+pub mod task_store;
 pub mod test_orchestrator;
 pub mod wave;
 
-pub use composition::{ComposedOperation, OperationChain};
+pub use composition::{
+    ComposedOperation, CompositionError, CompositionReceipt, HookConflict, OperationChain,
+};
+pub use consensus::{ConsensusError, Decision, RaftConfig, RaftNode};
 pub use coordinator::{SwarmCoordinator, SwarmMembership};
+pub use distributed_runner::{DistributedReport, DistributedRunner, WorkerSpec};
+pub use gossip::{GossipConfig, GossipNode, PeerStatus};
 pub use member::SwarmMember;
 pub use task::{TaskReceipt, TaskRequest, TaskStatus};
+pub use task_store::{
+    ClaimedTask, FileTaskStore, Lease, MemoryTaskStore, TaskStore, TaskStoreError,
+};
 pub use test_orchestrator::{
-    QoSClass, ResourceBudget, TestOrchestrator, TestPlan, TestPlanningAPI,
+    QoSClass, ResourceBudget, ResourceRequirements, TestOrchestrator, TestPlan, TestPlanningAPI,
 };
 pub use wave::{ResidualClass, Wave, WavePhase, WaveReceipt, WaveStatus};
 
@@ -187,6 +187,12 @@ impl TaskQueue {
         }
     }
 
+    /// Remove a queued task by ID
+    pub fn remove(&mut self, task_id: &str) -> Option<TaskRequest> {
+        let index = self.tasks.iter().position(|task| task.id == task_id)?;
+        Some(self.tasks.remove(index))
+    }
+
     /// Record task completion
     pub fn record_receipt(&mut self, receipt: TaskReceipt) {
         self.receipts.push(receipt);
@@ -0,0 +1,632 @@
+//! Durable Task Store: At-Least-Once Delivery for Swarm Tasks
+//!
+//! [`TaskQueue`](super::task::TaskQueue) lives in the coordinator's memory, so a crash
+//! loses every queued and running task. A [`TaskStore`] keeps them durable instead:
+//!
+//! - **Enqueue** persists the task before returning.
+//! - **Claim** leases the highest-priority available task to a worker for a visibility
+//!   timeout. The task stays in the store, invisible to other claims, until the lease
+//!   expires.
+//! - **Complete** (ack) removes the task and records its receipt; **release** (nack)
+//!   makes it claimable again at once; **extend** renews the lease of a long task.
+//! - A worker that dies mid-task never acks, so its lease expires and the task is
+//!   delivered again, with [`ClaimedTask::attempt`] counting deliveries.
+//!
+//! [`MemoryTaskStore`] keeps state in memory. [`FileTaskStore`] appends every change to
+//! a JSON lines log (synced before the call returns) and replays it on open, so a
+//! restarted coordinator sees the same queue, leases, and receipts.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::swarm::task::{TaskReceipt, TaskRequest, TaskStatus};
+//! use chicago_tdd_tools::swarm::task_store::{FileTaskStore, TaskStore};
+//! use std::time::Duration;
+//!
+//! let dir = tempfile::tempdir().unwrap();
+//! let path = dir.path().join("tasks.jsonl");
+//!
+//! let mut store = FileTaskStore::open(&path).unwrap();
+//! let task = TaskRequest::new("t1".into(), "Academic".into(), "review".into(), "paper".into());
+//! store.enqueue(task).unwrap();
+//! let claimed = store.claim("agent-1", Duration::from_secs(30)).unwrap().unwrap();
+//! drop(store); // coordinator crashes
+//!
+//! // After restart the task is still leased to agent-1, which can finish it
+//! let mut store = FileTaskStore::open(&path).unwrap();
+//! assert_eq!(store.in_flight(), 1);
+//! let sectors = vec!["Academic".to_string()];
+//! let receipt =
+//!     TaskReceipt::new("t1".into(), "agent-1".into(), sectors, TaskStatus::Completed, "ok".into());
+//! store.complete(&claimed.lease, receipt).unwrap();
+//! assert_eq!(store.pending(), 0);
+//! ```
+
+use super::task::{TaskReceipt, TaskRequest};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Task store failure
+#[derive(Error, Debug)]
+pub enum TaskStoreError {
+    /// Reading or writing the log failed
+    #[error("task store I/O failed: {0}")]
+    Io(#[from] io::Error),
+    /// The log holds a record that cannot be replayed
+    #[error("task store log is corrupt at line {line}: {message}")]
+    Corrupt {
+        /// 1-based line of the bad record
+        line: usize,
+        /// What is wrong with it
+        message: String,
+    },
+    /// A task with this ID is already in the store
+    #[error("task '{0}' is already queued")]
+    DuplicateTask(String),
+    /// The lease was released, or expired and was claimed by another worker
+    #[error("lease {lease_id} on task '{task_id}' is no longer held")]
+    LeaseLost {
+        /// Task the lease was for
+        task_id: String,
+        /// The stale lease
+        lease_id: u64,
+    },
+}
+
+/// Result type for task store operations
+pub type TaskStoreResult<T> = Result<T, TaskStoreError>;
+
+/// A worker's claim on a task, valid until it expires or the task is claimed again
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lease {
+    /// Leased task
+    pub task_id: String,
+    /// Lease ID, unique within the store
+    pub id: u64,
+    /// Worker holding the lease
+    pub holder: String,
+    /// When the task becomes claimable again (Unix epoch milliseconds)
+    pub expires_at_ms: u64,
+}
+
+impl Lease {
+    /// Whether the visibility timeout has passed at `now_ms`
+    #[must_use]
+    pub const fn is_expired_at(&self, now_ms: u64) -> bool {
+        now_ms >= self.expires_at_ms
+    }
+}
+
+/// A task handed to a worker by [`TaskStore::claim`]
+#[derive(Debug, Clone)]
+pub struct ClaimedTask {
+    /// The task
+    pub task: TaskRequest,
+    /// Lease to ack, release, or extend with
+    pub lease: Lease,
+    /// Delivery number (1 on first claim, higher on redelivery)
+    pub attempt: u32,
+}
+
+/// Durable queue of swarm tasks with lease-based, at-least-once delivery
+pub trait TaskStore {
+    /// Persist a task for delivery
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskStoreError::DuplicateTask`] if the ID is still in the store, or an
+    /// I/O error if the change cannot be persisted.
+    fn enqueue(&mut self, task: TaskRequest) -> TaskStoreResult<()>;
+
+    /// Lease the highest-priority available task (oldest first on a tie) to `holder`
+    ///
+    /// Returns `None` if every task is leased.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the lease cannot be persisted.
+    fn claim(
+        &mut self,
+        holder: &str,
+        visibility_timeout: Duration,
+    ) -> TaskStoreResult<Option<ClaimedTask>>;
+
+    /// Renew `lease` for another `visibility_timeout`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskStoreError::LeaseLost`] if the lease is no longer held.
+    fn extend(&mut self, lease: &Lease, visibility_timeout: Duration) -> TaskStoreResult<Lease>;
+
+    /// Acknowledge the task: remove it and record its receipt
+    ///
+    /// An expired lease can still complete its task as long as nobody claimed it since.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskStoreError::LeaseLost`] if the lease is no longer held.
+    fn complete(&mut self, lease: &Lease, receipt: TaskReceipt) -> TaskStoreResult<()>;
+
+    /// Give the task back so it can be claimed again immediately
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TaskStoreError::LeaseLost`] if the lease is no longer held.
+    fn release(&mut self, lease: &Lease) -> TaskStoreResult<()>;
+
+    /// Tasks not yet completed (queued or leased)
+    fn pending(&self) -> usize;
+
+    /// Tasks under an unexpired lease
+    fn in_flight(&self) -> usize;
+
+    /// Receipts of completed tasks, in completion order
+    fn receipts(&self) -> &[TaskReceipt];
+}
+
+#[allow(clippy::cast_possible_truncation)] // Milliseconds since 1970 fit in u64
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+#[allow(clippy::cast_possible_truncation)] // Visibility timeouts are far below 584 million years
+const fn expiry(now_ms: u64, visibility_timeout: Duration) -> u64 {
+    now_ms.saturating_add(visibility_timeout.as_millis() as u64)
+}
+
+/// One change to the store, as written to the log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum StoreRecord {
+    Enqueued { task: TaskRequest },
+    Claimed { lease: Lease },
+    Extended { lease: Lease },
+    Released { lease: Lease },
+    Completed { lease: Lease, receipt: TaskReceipt },
+}
+
+/// A task in the store
+#[derive(Debug, Clone)]
+struct StoredTask {
+    seq: u64,
+    task: TaskRequest,
+    attempts: u32,
+    lease: Option<Lease>,
+}
+
+/// Store contents, rebuilt from records
+#[derive(Debug, Default)]
+struct StoreState {
+    tasks: HashMap<String, StoredTask>,
+    receipts: Vec<TaskReceipt>,
+    next_seq: u64,
+    next_lease: u64,
+}
+
+impl StoreState {
+    fn enqueue(&self, task: TaskRequest) -> TaskStoreResult<StoreRecord> {
+        if self.tasks.contains_key(&task.id) {
+            return Err(TaskStoreError::DuplicateTask(task.id));
+        }
+        Ok(StoreRecord::Enqueued { task })
+    }
+
+    fn claim(&self, holder: &str, visibility_timeout: Duration) -> Option<StoreRecord> {
+        let now = now_millis();
+        let next = self
+            .tasks
+            .values()
+            .filter(|stored| stored.lease.as_ref().is_none_or(|lease| lease.is_expired_at(now)))
+            .max_by_key(|stored| (stored.task.priority, std::cmp::Reverse(stored.seq)))?;
+        Some(StoreRecord::Claimed {
+            lease: Lease {
+                task_id: next.task.id.clone(),
+                id: self.next_lease,
+                holder: holder.to_string(),
+                expires_at_ms: expiry(now, visibility_timeout),
+            },
+        })
+    }
+
+    /// The task `lease` is for, if the lease is still the current one
+    fn held(&self, lease: &Lease) -> TaskStoreResult<&StoredTask> {
+        self.tasks
+            .get(&lease.task_id)
+            .filter(|stored| stored.lease.as_ref().is_some_and(|current| current.id == lease.id))
+            .ok_or_else(|| TaskStoreError::LeaseLost {
+                task_id: lease.task_id.clone(),
+                lease_id: lease.id,
+            })
+    }
+
+    fn apply(&mut self, record: StoreRecord) -> TaskStoreResult<()> {
+        match record {
+            StoreRecord::Enqueued { task } => {
+                if self.tasks.contains_key(&task.id) {
+                    return Err(TaskStoreError::DuplicateTask(task.id));
+                }
+                let seq = self.next_seq;
+                self.next_seq += 1;
+                self.tasks
+                    .insert(task.id.clone(), StoredTask { seq, task, attempts: 0, lease: None });
+            }
+            StoreRecord::Claimed { lease } => {
+                let stored = self.tasks.get_mut(&lease.task_id).ok_or_else(|| {
+                    TaskStoreError::LeaseLost { task_id: lease.task_id.clone(), lease_id: lease.id }
+                })?;
+                stored.attempts += 1;
+                self.next_lease = self.next_lease.max(lease.id + 1);
+                stored.lease = Some(lease);
+            }
+            StoreRecord::Extended { lease } => {
+                self.held(&lease)?;
+                if let Some(stored) = self.tasks.get_mut(&lease.task_id) {
+                    stored.lease = Some(lease);
+                }
+            }
+            StoreRecord::Released { lease } => {
+                self.held(&lease)?;
+                if let Some(stored) = self.tasks.get_mut(&lease.task_id) {
+                    stored.lease = None;
+                }
+            }
+            StoreRecord::Completed { lease, receipt } => {
+                self.held(&lease)?;
+                self.tasks.remove(&lease.task_id);
+                self.receipts.push(receipt);
+            }
+        }
+        Ok(())
+    }
+
+    fn claimed(&self, record: &StoreRecord) -> Option<ClaimedTask> {
+        let StoreRecord::Claimed { lease } = record else {
+            return None;
+        };
+        let stored = self.tasks.get(&lease.task_id)?;
+        Some(ClaimedTask {
+            task: stored.task.clone(),
+            lease: lease.clone(),
+            attempt: stored.attempts,
+        })
+    }
+
+    fn extend(&self, lease: &Lease, visibility_timeout: Duration) -> TaskStoreResult<Lease> {
+        self.held(lease)?;
+        Ok(Lease { expires_at_ms: expiry(now_millis(), visibility_timeout), ..lease.clone() })
+    }
+
+    fn in_flight(&self) -> usize {
+        let now = now_millis();
+        self.tasks
+            .values()
+            .filter(|stored| stored.lease.as_ref().is_some_and(|lease| !lease.is_expired_at(now)))
+            .count()
+    }
+}
+
+/// Implement [`TaskStore`] on top of a `commit(record)` that persists and applies a record
+macro_rules! impl_task_store {
+    ($store:ty) => {
+        impl TaskStore for $store {
+            fn enqueue(&mut self, task: TaskRequest) -> TaskStoreResult<()> {
+                let record = self.state.enqueue(task)?;
+                self.commit(record)
+            }
+
+            fn claim(
+                &mut self,
+                holder: &str,
+                visibility_timeout: Duration,
+            ) -> TaskStoreResult<Option<ClaimedTask>> {
+                let Some(record) = self.state.claim(holder, visibility_timeout) else {
+                    return Ok(None);
+                };
+                self.commit(record.clone())?;
+                Ok(self.state.claimed(&record))
+            }
+
+            fn extend(
+                &mut self,
+                lease: &Lease,
+                visibility_timeout: Duration,
+            ) -> TaskStoreResult<Lease> {
+                let lease = self.state.extend(lease, visibility_timeout)?;
+                self.commit(StoreRecord::Extended { lease: lease.clone() })?;
+                Ok(lease)
+            }
+
+            fn complete(&mut self, lease: &Lease, receipt: TaskReceipt) -> TaskStoreResult<()> {
+                self.state.held(lease)?;
+                self.commit(StoreRecord::Completed { lease: lease.clone(), receipt })
+            }
+
+            fn release(&mut self, lease: &Lease) -> TaskStoreResult<()> {
+                self.state.held(lease)?;
+                self.commit(StoreRecord::Released { lease: lease.clone() })
+            }
+
+            fn pending(&self) -> usize {
+                self.state.tasks.len()
+            }
+
+            fn in_flight(&self) -> usize {
+                self.state.in_flight()
+            }
+
+            fn receipts(&self) -> &[TaskReceipt] {
+                &self.state.receipts
+            }
+        }
+    };
+}
+
+/// Task store kept in memory (lost on restart)
+#[derive(Debug, Default)]
+pub struct MemoryTaskStore {
+    state: StoreState,
+}
+
+impl MemoryTaskStore {
+    /// Create an empty store
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn commit(&mut self, record: StoreRecord) -> TaskStoreResult<()> {
+        self.state.apply(record)
+    }
+}
+
+impl_task_store!(MemoryTaskStore);
+
+/// Task store persisted as an append-only JSON lines log
+///
+/// Each change is one line, written with a single write and synced to disk before the
+/// operation returns. Opening the store replays the log; a final line torn by a crash
+/// mid-write is discarded.
+#[derive(Debug)]
+pub struct FileTaskStore {
+    path: PathBuf,
+    file: File,
+    state: StoreState,
+}
+
+impl FileTaskStore {
+    /// Open the log at `path`, creating it if missing, and replay it
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the log cannot be read or opened, or
+    /// [`TaskStoreError::Corrupt`] if a complete line cannot be replayed.
+    pub fn open(path: impl AsRef<Path>) -> TaskStoreResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Raw bytes: a torn final line may end inside a multi-byte UTF-8 character
+        let contents = match std::fs::read(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(e) => return Err(e.into()),
+        };
+        let mut state = StoreState::default();
+        let mut valid_len = 0;
+        for (index, line) in contents.split_inclusive(|byte| *byte == b'\n').enumerate() {
+            // Torn write from a crash: the operation never returned, so drop it even if
+            // the cut fell just before the newline and the record itself parses
+            if !line.ends_with(b"\n") {
+                break;
+            }
+            let replayed = serde_json::from_slice::<StoreRecord>(line)
+                .map_err(|e| e.to_string())
+                .and_then(|record| state.apply(record).map_err(|e| e.to_string()));
+            match replayed {
+                Ok(()) => valid_len += line.len(),
+                Err(message) => return Err(TaskStoreError::Corrupt { line: index + 1, message }),
+            }
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        if valid_len < contents.len() {
+            file.set_len(valid_len as u64)?;
+        }
+        Ok(Self { path, file, state })
+    }
+
+    /// Path of the log
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn commit(&mut self, record: StoreRecord) -> TaskStoreResult<()> {
+        let mut line = serde_json::to_vec(&record).map_err(io::Error::other)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.file.sync_data()?;
+        self.state.apply(record)
+    }
+}
+
+impl_task_store!(FileTaskStore);
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Test code: unwrap is acceptable
+mod tests {
+    use super::*;
+    use crate::swarm::task::TaskStatus;
+
+    const LONG: Duration = Duration::from_secs(300);
+
+    fn task(id: &str) -> TaskRequest {
+        TaskRequest::new(id.to_string(), "Academic".to_string(), "op".to_string(), String::new())
+    }
+
+    fn receipt(id: &str) -> TaskReceipt {
+        TaskReceipt::new(
+            id.to_string(),
+            "agent-1".to_string(),
+            vec!["Academic".to_string()],
+            TaskStatus::Completed,
+            "ok".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_claims_follow_priority_then_age_and_hide_leased_tasks() {
+        let mut store = MemoryTaskStore::new();
+        store.enqueue(task("low")).unwrap();
+        store.enqueue(task("high").with_priority(5)).unwrap();
+        store.enqueue(task("low-2")).unwrap();
+
+        let order: Vec<String> = std::iter::from_fn(|| store.claim("agent-1", LONG).unwrap())
+            .map(|claimed| claimed.task.id)
+            .collect();
+
+        assert_eq!(order, ["high", "low", "low-2"]);
+        assert_eq!((store.pending(), store.in_flight()), (3, 3));
+        assert!(matches!(store.enqueue(task("low")), Err(TaskStoreError::DuplicateTask(_))));
+    }
+
+    #[test]
+    fn test_expired_lease_is_redelivered_and_stale_lease_rejected() {
+        let mut store = MemoryTaskStore::new();
+        store.enqueue(task("t1")).unwrap();
+
+        // Worker 1 stalls past its visibility timeout
+        let first = store.claim("agent-1", Duration::ZERO).unwrap().unwrap();
+        let second = store.claim("agent-2", LONG).unwrap().unwrap();
+
+        assert_eq!((first.attempt, second.attempt), (1, 2));
+        assert!(matches!(
+            store.complete(&first.lease, receipt("t1")),
+            Err(TaskStoreError::LeaseLost { .. })
+        ));
+        store.complete(&second.lease, receipt("t1")).unwrap();
+        assert_eq!(store.pending(), 0);
+        assert_eq!(store.receipts().len(), 1);
+    }
+
+    #[test]
+    fn test_release_and_extend() {
+        let mut store = MemoryTaskStore::new();
+        store.enqueue(task("t1")).unwrap();
+
+        let claimed = store.claim("agent-1", Duration::ZERO).unwrap().unwrap();
+        let extended = store.extend(&claimed.lease, LONG).unwrap();
+        assert!(store.claim("agent-2", LONG).unwrap().is_none());
+        assert_eq!(store.in_flight(), 1);
+
+        store.release(&extended).unwrap();
+        let again = store.claim("agent-2", LONG).unwrap().unwrap();
+        assert_eq!(again.attempt, 2);
+        assert!(store.release(&extended).is_err());
+    }
+
+    #[test]
+    fn test_file_store_replays_queue_leases_and_receipts_after_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tasks.jsonl");
+        {
+            let mut store = FileTaskStore::open(&path).unwrap();
+            store.enqueue(task("done").with_priority(2)).unwrap();
+            store.enqueue(task("crashed").with_priority(1)).unwrap();
+            store.enqueue(task("queued")).unwrap();
+            let done = store.claim("agent-1", LONG).unwrap().unwrap();
+            store.complete(&done.lease, receipt("done")).unwrap();
+            let crashed = store.claim("agent-1", Duration::ZERO).unwrap().unwrap();
+            assert_eq!(crashed.task.id, "crashed");
+        }
+
+        let mut store = FileTaskStore::open(&path).unwrap();
+
+        assert_eq!(store.pending(), 2);
+        assert_eq!(store.receipts()[0].task_id, "done");
+        // The crashed worker's lease expired, so its task is delivered again
+        let redelivered = store.claim("agent-2", LONG).unwrap().unwrap();
+        assert_eq!((redelivered.task.id.as_str(), redelivered.attempt), ("crashed", 2));
+        assert!(redelivered.lease.id > 1);
+    }
+
+    #[test]
+    fn test_file_store_discards_torn_final_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tasks.jsonl");
+        FileTaskStore::open(&path).unwrap().enqueue(task("t1")).unwrap();
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"op\":\"enq")
+            .unwrap();
+
+        let mut store = FileTaskStore::open(&path).unwrap();
+        store.enqueue(task("t2")).unwrap();
+
+        assert_eq!(store.pending(), 2);
+        assert_eq!(FileTaskStore::open(&path).unwrap().pending(), 2);
+    }
+
+    #[test]
+    fn test_file_store_discards_line_torn_inside_multibyte_character() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tasks.jsonl");
+        FileTaskStore::open(&path).unwrap().enqueue(task("t1")).unwrap();
+        // First byte of "é" (0xC3 0xA9); the crash cut the second byte off
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(b"{\"op\":\"enqueue\",\"task\":{\"id\":\"caf\xC3")
+            .unwrap();
+
+        let mut store = FileTaskStore::open(&path).unwrap();
+        store.enqueue(task("t2")).unwrap();
+
+        assert_eq!(store.pending(), 2);
+        assert_eq!(FileTaskStore::open(&path).unwrap().pending(), 2);
+    }
+
+    #[test]
+    fn test_file_store_discards_complete_record_missing_newline() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tasks.jsonl");
+        let scratch = dir.path().join("scratch.jsonl");
+        FileTaskStore::open(&path).unwrap().enqueue(task("t1")).unwrap();
+        FileTaskStore::open(&scratch).unwrap().enqueue(task("torn")).unwrap();
+        // A whole record, cut off just before its newline
+        let record = std::fs::read(&scratch).unwrap();
+        OpenOptions::new()
+            .append(true)
+            .open(&path)
+            .unwrap()
+            .write_all(record.strip_suffix(b"\n").unwrap())
+            .unwrap();
+
+        let mut store = FileTaskStore::open(&path).unwrap();
+        assert_eq!(store.pending(), 1);
+        store.enqueue(task("t2")).unwrap();
+
+        let mut reopened = FileTaskStore::open(&path).unwrap();
+        assert_eq!(reopened.pending(), 2);
+        assert_eq!(reopened.claim("agent-1", LONG).unwrap().unwrap().task.id, "t1");
+        assert_eq!(reopened.claim("agent-1", LONG).unwrap().unwrap().task.id, "t2");
+    }
+
+    #[test]
+    fn test_file_store_rejects_corrupt_complete_line() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("tasks.jsonl");
+        std::fs::write(&path, "not json\n").unwrap();
+
+        let result = FileTaskStore::open(&path);
+
+        assert!(matches!(result, Err(TaskStoreError::Corrupt { line: 1, .. })));
+    }
+}
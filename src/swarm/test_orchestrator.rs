@@ -4,6 +4,8 @@
 //! - Accepts test plans from many agents
 //! - Schedules executions based on tenant/org/priority/QoS
 //! - Hardware-aware scheduling (μ-kernel nodes/cores)
+//! - Resource-aware parallel execution: tests declare Docker, port, exclusive-resource,
+//!   and CPU needs, and never overlap on a contended resource
 //! - Machine-readable planning API
 //! - Suggests minimal sufficient test sets for changes
 //! - Suggests additional tests to improve coverage/mutation score
@@ -24,9 +26,16 @@
 //! ```
 
 use crate::core::contract::{TestContract, TestContractRegistry};
-use crate::core::receipt::{TestOutcome, TestReceipt};
+use crate::core::receipt::{EnvironmentFingerprint, TestOutcome, TestReceipt, TimingMeasurement};
 use serde::{Deserialize, Serialize};
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Condvar, Mutex, PoisonError};
+use std::time::Instant;
+
+/// Exclusive resource held by tests that send telemetry to Weaver's OTLP port
+///
+/// Weaver listens on a single OTLP port, so tests requiring it run one at a time.
+pub const WEAVER_OTLP_RESOURCE: &str = "weaver-otlp";
 
 /// Test plan: describes tests to execute
 ///
@@ -53,6 +62,29 @@ pub struct TestPlan {
 
     /// Metadata
     pub metadata: HashMap<String, String>,
+
+    /// Resource requirements per contract (contracts not listed need one core and nothing else)
+    #[serde(default)]
+    pub requirements: HashMap<String, ResourceRequirements>,
+}
+
+impl TestPlan {
+    /// Declare the resources `contract` needs while it runs
+    #[must_use]
+    pub fn with_requirements(
+        mut self,
+        contract: impl Into<String>,
+        requirements: ResourceRequirements,
+    ) -> Self {
+        self.requirements.insert(contract.into(), requirements);
+        self
+    }
+
+    /// Resources `contract` needs (one core when undeclared)
+    #[must_use]
+    pub fn requirements_for(&self, contract: &str) -> ResourceRequirements {
+        self.requirements.get(contract).cloned().unwrap_or_default()
+    }
 }
 
 /// `QoS` class for test execution
@@ -111,6 +143,149 @@ impl ResourceBudget {
     }
 }
 
+/// Resources a test holds while it runs
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResourceRequirements {
+    /// Needs a Docker daemon (counts as network access)
+    pub docker: bool,
+
+    /// Fixed ports the test binds; tests sharing a port never overlap
+    pub ports: Vec<u16>,
+
+    /// Named external resources held exclusively (e.g., [`WEAVER_OTLP_RESOURCE`])
+    pub exclusive: Vec<String>,
+
+    /// Cores the test occupies
+    pub cpu_weight: usize,
+
+    /// `QoS` class overriding the plan's
+    pub qos: Option<QoSClass>,
+}
+
+impl ResourceRequirements {
+    /// One core, no external resources
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Needs the single Weaver OTLP port
+    #[must_use]
+    pub fn weaver() -> Self {
+        Self::new().with_exclusive(WEAVER_OTLP_RESOURCE)
+    }
+
+    /// Needs a Docker daemon
+    #[must_use]
+    pub const fn with_docker(mut self) -> Self {
+        self.docker = true;
+        self
+    }
+
+    /// Binds a fixed port
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        if !self.ports.contains(&port) {
+            self.ports.push(port);
+        }
+        self
+    }
+
+    /// Holds a named external resource exclusively
+    #[must_use]
+    pub fn with_exclusive(mut self, resource: impl Into<String>) -> Self {
+        let resource = resource.into();
+        if !self.exclusive.contains(&resource) {
+            self.exclusive.push(resource);
+        }
+        self
+    }
+
+    /// Occupies `cores` cores (at least one)
+    #[must_use]
+    pub const fn with_cpu_weight(mut self, cores: usize) -> Self {
+        self.cpu_weight = if cores == 0 { 1 } else { cores };
+        self
+    }
+
+    /// Runs in `qos` regardless of the plan's class
+    #[must_use]
+    pub const fn with_qos(mut self, qos: QoSClass) -> Self {
+        self.qos = Some(qos);
+        self
+    }
+
+    /// Whether the test needs network access
+    #[must_use]
+    pub const fn needs_network(&self) -> bool {
+        self.docker || !self.ports.is_empty()
+    }
+
+    /// Whether the two tests contend on a port or exclusive resource
+    #[must_use]
+    pub fn conflicts_with(&self, other: &Self) -> bool {
+        self.ports.iter().any(|port| other.ports.contains(port))
+            || self.exclusive.iter().any(|resource| other.exclusive.contains(resource))
+    }
+
+    /// Why a plan with `budget` on `workers` workers can never run this test
+    #[must_use]
+    pub fn unschedulable_reason(&self, budget: &ResourceBudget, workers: usize) -> Option<String> {
+        let cores = budget.max_cores.min(workers.max(1));
+        if self.needs_network() && !budget.allow_network {
+            Some("needs network access (Docker or ports) but the budget denies it".to_string())
+        } else if self.cpu_weight > cores {
+            Some(format!("needs {} cores but at most {cores} are available", self.cpu_weight))
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for ResourceRequirements {
+    fn default() -> Self {
+        Self { docker: false, ports: Vec::new(), exclusive: Vec::new(), cpu_weight: 1, qos: None }
+    }
+}
+
+/// Resources held by running tests
+#[derive(Debug, Default)]
+struct ResourceLedger {
+    cores: usize,
+    ports: HashSet<u16>,
+    exclusive: HashSet<String>,
+}
+
+impl ResourceLedger {
+    fn admits(&self, requirements: &ResourceRequirements, max_cores: usize) -> bool {
+        self.cores + requirements.cpu_weight <= max_cores
+            && requirements.ports.iter().all(|port| !self.ports.contains(port))
+            && requirements.exclusive.iter().all(|resource| !self.exclusive.contains(resource))
+    }
+
+    fn acquire(&mut self, requirements: &ResourceRequirements) {
+        self.cores += requirements.cpu_weight;
+        self.ports.extend(&requirements.ports);
+        self.exclusive.extend(requirements.exclusive.iter().cloned());
+    }
+
+    fn release(&mut self, requirements: &ResourceRequirements) {
+        self.cores -= requirements.cpu_weight;
+        for port in &requirements.ports {
+            self.ports.remove(port);
+        }
+        for resource in &requirements.exclusive {
+            self.exclusive.remove(resource);
+        }
+    }
+}
+
+/// Tests waiting to run and the resources running tests hold
+struct WorkQueue {
+    waiting: Vec<(usize, ResourceRequirements)>,
+    ledger: ResourceLedger,
+}
+
 /// Test execution result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestExecutionResult {
@@ -124,6 +299,18 @@ pub struct TestExecutionResult {
     pub summary: ExecutionSummary,
 }
 
+impl TestExecutionResult {
+    /// Result for `plan_id` summarizing `receipts`
+    #[must_use]
+    pub fn from_receipts(plan_id: impl Into<String>, receipts: Vec<TestReceipt>) -> Self {
+        let mut summary = ExecutionSummary::new();
+        for receipt in &receipts {
+            summary.add_receipt(receipt);
+        }
+        Self { plan_id: plan_id.into(), receipts, summary }
+    }
+}
+
 /// Execution summary
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionSummary {
@@ -239,7 +426,8 @@ impl TestOrchestrator {
     /// - Collect receipts
     /// - Generate summary
     ///
-    /// For now, this is a mock implementation.
+    /// For now, this is a mock implementation; [`Self::execute_plan_with`] runs the
+    /// plan's tests.
     #[must_use]
     pub fn execute_plan(&mut self, plan: &TestPlan) -> TestExecutionResult {
         let summary = ExecutionSummary::new();
@@ -251,6 +439,87 @@ impl TestOrchestrator {
         result
     }
 
+    /// Run a plan's tests on `workers` threads, calling `run` with each contract name
+    ///
+    /// Tests start in `QoS` order (a contract's own class, else the plan's), each on the
+    /// first free worker once its resources are available: running tests never use more
+    /// than `max_cores` cores together, and tests sharing a port or exclusive resource
+    /// run one at a time. Tests the budget can never satisfy (network access when it is
+    /// denied, more cores than available) are skipped with a `skip_reason` receipt
+    /// entry. Every receipt records the `worker` that ran it.
+    pub fn execute_plan_with<F>(
+        &mut self,
+        plan: &TestPlan,
+        workers: usize,
+        run: F,
+    ) -> TestExecutionResult
+    where
+        F: Fn(&str) -> TestOutcome + Sync,
+    {
+        let workers = workers.max(1);
+        let max_cores = plan.resource_budget.max_cores.min(workers);
+        let mut receipts: Vec<Option<TestReceipt>> = vec![None; plan.contracts.len()];
+        let mut waiting = Vec::new();
+        for (index, contract) in plan.contracts.iter().enumerate() {
+            let requirements = plan.requirements_for(contract);
+            match requirements.unschedulable_reason(&plan.resource_budget, workers) {
+                Some(reason) => {
+                    let mut receipt =
+                        plan_receipt(&self.registry, plan, contract, TestOutcome::Skip, 0);
+                    receipt.add_metadata("skip_reason", reason);
+                    receipts[index] = Some(receipt);
+                }
+                None => waiting.push((index, requirements)),
+            }
+        }
+        // Stable: equal classes keep plan order
+        waiting.sort_by_key(|(_, requirements)| {
+            std::cmp::Reverse(requirements.qos.unwrap_or(plan.qos))
+        });
+
+        let queue = Mutex::new(WorkQueue { waiting, ledger: ResourceLedger::default() });
+        let released = Condvar::new();
+        let finished = Mutex::new(Vec::new());
+        std::thread::scope(|scope| {
+            for worker in 0..workers {
+                let (queue, released, finished, run) = (&queue, &released, &finished, &run);
+                scope.spawn(move || {
+                    while let Some((index, requirements)) = next_test(queue, released, max_cores) {
+                        let started = Instant::now();
+                        let outcome = run(&plan.contracts[index]);
+                        let elapsed = started.elapsed();
+                        {
+                            let mut queue = queue.lock().unwrap_or_else(PoisonError::into_inner);
+                            queue.ledger.release(&requirements);
+                        }
+                        released.notify_all();
+                        finished
+                            .lock()
+                            .unwrap_or_else(PoisonError::into_inner)
+                            .push((index, worker, outcome, elapsed));
+                    }
+                });
+            }
+        });
+
+        for (index, worker, outcome, elapsed) in
+            finished.into_inner().unwrap_or_else(PoisonError::into_inner)
+        {
+            let elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+            let mut receipt =
+                plan_receipt(&self.registry, plan, &plan.contracts[index], outcome, elapsed_ms);
+            receipt.add_metadata("worker", worker.to_string());
+            receipts[index] = Some(receipt);
+        }
+
+        let result = TestExecutionResult::from_receipts(
+            &plan.plan_id,
+            receipts.into_iter().flatten().collect(),
+        );
+        self.executed.push(result.clone());
+        result
+    }
+
     /// Suggest minimal sufficient test set for a change
     ///
     /// Given a change (Δ Σ), suggests which tests must run.
@@ -322,6 +591,58 @@ impl TestOrchestrator {
     }
 }
 
+/// Receipt for one of `plan`'s contracts, built from the registry contract when known
+pub(crate) fn plan_receipt(
+    registry: &TestContractRegistry,
+    plan: &TestPlan,
+    contract: &str,
+    outcome: TestOutcome,
+    elapsed_ms: u64,
+) -> TestReceipt {
+    let budget_met = elapsed_ms <= plan.resource_budget.max_wall_clock_seconds * 1000;
+    let known = registry.all().iter().find(|known| known.name == contract);
+    let thermal_class = known.map_or_else(|| "cold".to_string(), |c| c.thermal_class().to_string());
+    let timing = TimingMeasurement::new(0, elapsed_ms, thermal_class, budget_met, 0);
+    match known {
+        Some(contract) => TestReceipt::from_contract(contract, timing, outcome),
+        None => TestReceipt::new(
+            contract.to_string(),
+            String::new(),
+            EnvironmentFingerprint::capture(),
+            Vec::new(),
+            timing,
+            Vec::new(),
+            outcome,
+        ),
+    }
+}
+
+/// Take the first waiting test whose resources are free, blocking until one is
+///
+/// Returns `None` once no tests are waiting.
+fn next_test(
+    queue: &Mutex<WorkQueue>,
+    released: &Condvar,
+    max_cores: usize,
+) -> Option<(usize, ResourceRequirements)> {
+    let mut queue = queue.lock().unwrap_or_else(PoisonError::into_inner);
+    loop {
+        if queue.waiting.is_empty() {
+            return None;
+        }
+        let ready = queue
+            .waiting
+            .iter()
+            .position(|(_, requirements)| queue.ledger.admits(requirements, max_cores));
+        if let Some(position) = ready {
+            let (index, requirements) = queue.waiting.remove(position);
+            queue.ledger.acquire(&requirements);
+            return Some((index, requirements));
+        }
+        queue = released.wait(queue).unwrap_or_else(PoisonError::into_inner);
+    }
+}
+
 /// Test planning API: helps agents decide what to test
 pub struct TestPlanningAPI {
     registry: TestContractRegistry,
@@ -414,6 +735,159 @@ mod tests {
         assert!(unlimited.allow_network);
     }
 
+    fn parallel_plan(contracts: &[&str], max_cores: usize) -> TestPlan {
+        TestPlan {
+            plan_id: "parallel".to_string(),
+            contracts: contracts.iter().map(ToString::to_string).collect(),
+            requester: "agent1".to_string(),
+            priority: 50,
+            qos: QoSClass::Standard,
+            resource_budget: ResourceBudget { max_cores, ..ResourceBudget::unlimited() },
+            metadata: HashMap::new(),
+            requirements: HashMap::new(),
+        }
+    }
+
+    /// Runs each test for a few milliseconds, tracking how many run at once per resource
+    #[derive(Default)]
+    struct Probe {
+        weaver: std::sync::atomic::AtomicUsize,
+        weaver_peak: std::sync::atomic::AtomicUsize,
+        cores: std::sync::atomic::AtomicUsize,
+        cores_peak: std::sync::atomic::AtomicUsize,
+    }
+
+    impl Probe {
+        fn run(&self, weaver: bool, weight: usize) -> TestOutcome {
+            use std::sync::atomic::Ordering;
+            let held = self.cores.fetch_add(weight, Ordering::SeqCst) + weight;
+            self.cores_peak.fetch_max(held, Ordering::SeqCst);
+            if weaver {
+                let held = self.weaver.fetch_add(1, Ordering::SeqCst) + 1;
+                self.weaver_peak.fetch_max(held, Ordering::SeqCst);
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            if weaver {
+                self.weaver.fetch_sub(1, Ordering::SeqCst);
+            }
+            self.cores.fetch_sub(weight, Ordering::SeqCst);
+            TestOutcome::Pass
+        }
+    }
+
+    #[test]
+    fn test_weaver_tests_never_overlap() {
+        // Arrange
+        let names = ["w1", "w2", "w3", "w4", "plain1", "plain2"];
+        let mut plan = parallel_plan(&names, 4);
+        for name in &names[..4] {
+            plan = plan.with_requirements(*name, ResourceRequirements::weaver());
+        }
+        let mut orchestrator = TestOrchestrator::new(TestContractRegistry::new(&[]));
+        let probe = Probe::default();
+
+        // Act
+        let result =
+            orchestrator.execute_plan_with(&plan, 4, |name| probe.run(name.starts_with('w'), 1));
+
+        // Assert
+        assert_eq!(probe.weaver_peak.into_inner(), 1);
+        assert_eq!(result.summary.passed, 6);
+        assert_eq!(orchestrator.executed.len(), 1);
+    }
+
+    #[test]
+    fn test_cpu_weight_stays_within_budget() {
+        // Arrange
+        let names = ["heavy1", "heavy2", "light1", "light2", "light3"];
+        let plan = parallel_plan(&names, 3)
+            .with_requirements("heavy1", ResourceRequirements::new().with_cpu_weight(2))
+            .with_requirements("heavy2", ResourceRequirements::new().with_cpu_weight(2));
+        let mut orchestrator = TestOrchestrator::new(TestContractRegistry::new(&[]));
+        let probe = Probe::default();
+
+        // Act
+        let result = orchestrator.execute_plan_with(&plan, 8, |name| {
+            probe.run(false, if name.starts_with("heavy") { 2 } else { 1 })
+        });
+
+        // Assert
+        assert!(probe.cores_peak.into_inner() <= 3);
+        assert_eq!(result.summary.passed, 5);
+    }
+
+    #[test]
+    fn test_independent_tests_run_in_parallel() {
+        // Arrange: both tests wait at a barrier, so running them serially would time out
+        let plan = parallel_plan(&["left", "right"], 2);
+        let mut orchestrator = TestOrchestrator::new(TestContractRegistry::new(&[]));
+        let arrived = (Mutex::new(0), Condvar::new());
+
+        // Act
+        let result = orchestrator.execute_plan_with(&plan, 2, |_| {
+            let (count, both) = &arrived;
+            let mut count = count.lock().unwrap_or_else(PoisonError::into_inner);
+            *count += 1;
+            both.notify_all();
+            let (count, timeout) = both
+                .wait_timeout_while(count, std::time::Duration::from_secs(5), |count| *count < 2)
+                .unwrap_or_else(PoisonError::into_inner);
+            if timeout.timed_out() && *count < 2 {
+                TestOutcome::Fail
+            } else {
+                TestOutcome::Pass
+            }
+        });
+
+        // Assert
+        assert_eq!(result.summary.passed, 2);
+        let mut workers: Vec<_> = result
+            .receipts
+            .iter()
+            .filter_map(|receipt| receipt.get_metadata("worker"))
+            .collect();
+        workers.sort_unstable();
+        assert_eq!(workers, ["0", "1"]);
+    }
+
+    #[test]
+    fn test_unschedulable_tests_are_skipped() {
+        // Arrange
+        const CONTRACTS: &[TestContract] =
+            &[TestContract::cold_path("docker_test", &["module1"], &["inv1"])];
+        let mut plan = parallel_plan(&["docker_test", "huge", "fine"], 2)
+            .with_requirements("docker_test", ResourceRequirements::new().with_docker())
+            .with_requirements("huge", ResourceRequirements::new().with_cpu_weight(4));
+        plan.resource_budget.allow_network = false;
+        let mut orchestrator = TestOrchestrator::new(TestContractRegistry::new(CONTRACTS));
+
+        // Act
+        let result = orchestrator.execute_plan_with(&plan, 4, |_| TestOutcome::Pass);
+
+        // Assert: receipts follow plan order
+        let outcomes: Vec<_> = result.receipts.iter().map(|receipt| receipt.result).collect();
+        assert_eq!(outcomes, [TestOutcome::Skip, TestOutcome::Skip, TestOutcome::Pass]);
+        assert!(result.receipts[0]
+            .get_metadata("skip_reason")
+            .is_some_and(|r| r.contains("network")));
+        assert!(result.receipts[1]
+            .get_metadata("skip_reason")
+            .is_some_and(|r| r.contains("cores")));
+        assert_eq!(result.receipts[0].invariants_checked, ["integration"]);
+        assert_eq!(result.summary.skipped, 2);
+    }
+
+    #[test]
+    fn test_requirements_conflicts() {
+        let weaver = ResourceRequirements::weaver();
+        let port = ResourceRequirements::new().with_port(8080);
+        assert!(weaver.conflicts_with(&ResourceRequirements::weaver()));
+        assert!(port.conflicts_with(&ResourceRequirements::new().with_port(8080)));
+        assert!(!weaver.conflicts_with(&port));
+        assert!(port.needs_network());
+        assert!(!weaver.needs_network());
+    }
+
     #[test]
     fn test_execution_summary() {
         let summary = ExecutionSummary::new();
@@ -440,6 +914,7 @@ mod tests {
             qos: QoSClass::Standard,
             resource_budget: ResourceBudget::default_budget(),
             metadata: HashMap::new(),
+            requirements: HashMap::new(),
         };
 
         let plan2 = TestPlan {
@@ -450,6 +925,7 @@ mod tests {
             qos: QoSClass::Premium,
             resource_budget: ResourceBudget::unlimited(),
             metadata: HashMap::new(),
+            requirements: HashMap::new(),
         };
 
         orchestrator.submit_plan(plan1);
@@ -0,0 +1,482 @@
+//! AAA Structure Lint
+//!
+//! Static analysis of test source: every test should have identifiable Arrange, Act,
+//! and Assert sections, in that order. The lint parses a file with `syn`, finds the
+//! tests in it (`#[test]`-style attributes, including `#[tokio::test]` and
+//! `#[tdd_test]`, and the `test!` family of macros), and checks each body:
+//!
+//! - **Phases** are marked by comments starting with `Arrange`, `Act`, or `Assert`
+//!   (`// Act & Assert` marks both), or by [`enter_phase`](crate::core::receipt::enter_phase)
+//!   calls.
+//! - **Missing phase**: a phase has no marker.
+//! - **Assert before Act**: an assertion (`assert*!` macros, `assert_*` functions and
+//!   methods) appears before the first Act marker.
+//! - **Act after Assert**: an Act marker appears after the Assert phase began.
+//!
+//! The report serializes to JSON for CI tooling.
+//!
+//! ```rust
+//! use chicago_tdd_tools::testing::aaa_lint::{lint_source, AaaViolationKind};
+//!
+//! let report = lint_source(r#"
+//!     #[test]
+//!     fn test_total() {
+//!         // Arrange
+//!         let items = vec![1, 2];
+//!         assert!(!items.is_empty());
+//!
+//!         // Act
+//!         let total: i32 = items.iter().sum();
+//!
+//!         // Assert
+//!         assert_eq!(total, 3);
+//!     }
+//! "#).unwrap();
+//!
+//! assert_eq!(report.tests, 1);
+//! assert_eq!(report.violations[0].kind, AaaViolationKind::AssertBeforeAct);
+//! assert_eq!(report.violations[0].line, 6);
+//! ```
+
+use crate::core::receipt::AaaPhase;
+use proc_macro2::{Delimiter, TokenStream, TokenTree};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use syn::visit::{self, Visit};
+use thiserror::Error;
+
+/// Test-defining macros the lint looks inside
+const TEST_MACROS: &[&str] = &[
+    "test",
+    "async_test",
+    "async_test_with_timeout",
+    "fixture_test",
+    "fixture_test_with_timeout",
+    "performance_test",
+];
+
+/// AAA lint error
+#[derive(Error, Debug)]
+pub enum AaaLintError {
+    /// Source file could not be read
+    #[error("🚨 Cannot read {path}: {source}")]
+    Io {
+        /// File being read
+        path: PathBuf,
+        /// Underlying error
+        source: std::io::Error,
+    },
+    /// Source is not valid Rust
+    #[error("🚨 Cannot parse test source at line {line}: {message}")]
+    Parse {
+        /// Line of the parse error
+        line: usize,
+        /// Parser message
+        message: String,
+    },
+}
+
+/// Result type for AAA lint operations
+pub type AaaLintResult<T> = Result<T, AaaLintError>;
+
+/// Kind of AAA structure violation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum AaaViolationKind {
+    /// The test has no marker for `phase`
+    MissingPhase {
+        /// Phase without a marker
+        phase: AaaPhase,
+    },
+    /// An assertion runs before the Act phase
+    AssertBeforeAct,
+    /// An Act phase starts after the Assert phase began
+    ActAfterAssert,
+}
+
+/// One violation, located at a line of the linted source
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AaaViolation {
+    /// File the test lives in (set by [`lint_file`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<PathBuf>,
+    /// Test function name
+    pub test: String,
+    /// 1-based line of the offending statement or marker (the test's opening line
+    /// for missing phases)
+    pub line: usize,
+    /// What is wrong
+    #[serde(flatten)]
+    pub kind: AaaViolationKind,
+    /// Human-readable description
+    pub message: String,
+}
+
+/// Result of linting one or more sources
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AaaLintReport {
+    /// Number of tests checked
+    pub tests: usize,
+    /// Violations, in source order
+    pub violations: Vec<AaaViolation>,
+}
+
+impl AaaLintReport {
+    /// Whether no violations were found
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Add the tests and violations of `other`
+    pub fn merge(&mut self, other: Self) {
+        self.tests += other.tests;
+        self.violations.extend(other.violations);
+    }
+
+    /// Render as pretty-printed JSON
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+/// Lint the tests in `source`
+///
+/// # Errors
+///
+/// Returns [`AaaLintError::Parse`] if `source` is not a valid Rust file.
+pub fn lint_source(source: &str) -> AaaLintResult<AaaLintReport> {
+    let file = syn::parse_file(source).map_err(|error| AaaLintError::Parse {
+        line: error.span().start().line,
+        message: error.to_string(),
+    })?;
+    let mut finder =
+        TestFinder { lines: source.lines().collect(), report: AaaLintReport::default() };
+    finder.visit_file(&file);
+    Ok(finder.report)
+}
+
+/// Lint the tests in the file at `path`, recording it on each violation
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read or parsed.
+pub fn lint_file(path: impl AsRef<Path>) -> AaaLintResult<AaaLintReport> {
+    let path = path.as_ref();
+    let source = fs::read_to_string(path)
+        .map_err(|source| AaaLintError::Io { path: path.to_path_buf(), source })?;
+    let mut report = lint_source(&source)?;
+    for violation in &mut report.violations {
+        violation.file = Some(path.to_path_buf());
+    }
+    Ok(report)
+}
+
+/// Finds test functions and test macro invocations
+struct TestFinder<'src> {
+    lines: Vec<&'src str>,
+    report: AaaLintReport,
+}
+
+impl<'ast> Visit<'ast> for TestFinder<'_> {
+    fn visit_item_fn(&mut self, item: &'ast syn::ItemFn) {
+        let is_test = item.attrs.iter().any(|attr| {
+            attr.path()
+                .segments
+                .last()
+                .is_some_and(|segment| segment.ident == "test" || segment.ident == "tdd_test")
+        });
+        if is_test {
+            self.check(&item.sig.ident.to_string(), &item.block);
+        }
+        visit::visit_item_fn(self, item);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        let is_test_macro = mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| TEST_MACROS.iter().any(|name| segment.ident == name));
+        if is_test_macro {
+            if let Some((name, body)) = test_macro_parts(mac.tokens.clone()) {
+                self.check(&name, &body);
+            }
+        }
+        visit::visit_macro(self, mac);
+    }
+}
+
+impl TestFinder<'_> {
+    fn check(&mut self, test: &str, body: &syn::Block) {
+        self.report.tests += 1;
+        let span = body.brace_token.span;
+        let (open, close) = (span.open().start().line, span.close().start().line);
+
+        let mut markers: Vec<(usize, AaaPhase)> = self
+            .lines
+            .iter()
+            .enumerate()
+            .map(|(index, text)| (index + 1, text))
+            .filter(|(line, _)| (open..=close).contains(line))
+            .flat_map(|(line, text)| comment_phases(text).into_iter().map(move |p| (line, p)))
+            .collect();
+        let mut body_scan = BodyScan::default();
+        body_scan.visit_block(body);
+        markers.extend(body_scan.phase_calls);
+        markers.sort_by_key(|(line, _)| *line);
+
+        let mut violations = Vec::new();
+        for phase in [AaaPhase::Arrange, AaaPhase::Act, AaaPhase::Assert] {
+            if !markers.iter().any(|(_, marked)| *marked == phase) {
+                violations.push((
+                    open,
+                    AaaViolationKind::MissingPhase { phase },
+                    format!("`{test}` has no {phase:?} section"),
+                ));
+            }
+        }
+
+        let first_act = markers.iter().find(|(_, phase)| *phase == AaaPhase::Act).map(|m| m.0);
+        if let Some(first_act) = first_act {
+            for line in body_scan.assertions.iter().filter(|line| **line < first_act) {
+                violations.push((
+                    *line,
+                    AaaViolationKind::AssertBeforeAct,
+                    format!("`{test}` asserts before its Act section (line {first_act})"),
+                ));
+            }
+            let assert_start = markers
+                .iter()
+                .find(|(_, phase)| *phase == AaaPhase::Assert)
+                .map(|m| m.0)
+                .or_else(|| body_scan.assertions.iter().copied().find(|line| *line > first_act));
+            if let Some(assert_start) = assert_start {
+                for (line, _) in markers
+                    .iter()
+                    .filter(|(line, phase)| *phase == AaaPhase::Act && *line > assert_start)
+                {
+                    violations.push((
+                        *line,
+                        AaaViolationKind::ActAfterAssert,
+                        format!(
+                            "`{test}` starts an Act section after asserting (Assert began at line {assert_start})"
+                        ),
+                    ));
+                }
+            }
+        }
+
+        violations.sort_by_key(|(line, _, _)| *line);
+        self.report
+            .violations
+            .extend(violations.into_iter().map(|(line, kind, message)| AaaViolation {
+                file: None,
+                test: test.to_string(),
+                line,
+                kind,
+                message,
+            }));
+    }
+}
+
+/// Collects assertions and `enter_phase` calls inside a test body
+#[derive(Default)]
+struct BodyScan {
+    assertions: Vec<usize>,
+    phase_calls: Vec<(usize, AaaPhase)>,
+}
+
+impl<'ast> Visit<'ast> for BodyScan {
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        if let Some(segment) = mac.path.segments.last() {
+            if is_assertion_name(&segment.ident.to_string()) {
+                self.assertions.push(segment.ident.span().start().line);
+            }
+        }
+        visit::visit_macro(self, mac);
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast syn::ExprCall) {
+        if let syn::Expr::Path(func) = &*call.func {
+            if let Some(segment) = func.path.segments.last() {
+                let name = segment.ident.to_string();
+                if is_assertion_name(&name) {
+                    self.assertions.push(segment.ident.span().start().line);
+                } else if name == "enter_phase" {
+                    if let Some(phase) = call.args.first().and_then(phase_of_expr) {
+                        self.phase_calls.push((segment.ident.span().start().line, phase));
+                    }
+                }
+            }
+        }
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast syn::ExprMethodCall) {
+        if is_assertion_name(&call.method.to_string()) {
+            self.assertions.push(call.method.span().start().line);
+        }
+        visit::visit_expr_method_call(self, call);
+    }
+}
+
+/// `assert`, `assert_eq`, `debug_assert_ne`, ...
+fn is_assertion_name(name: &str) -> bool {
+    let name = name.strip_prefix("debug_").unwrap_or(name);
+    name == "assert" || name.starts_with("assert_")
+}
+
+/// `AaaPhase::Act` (or a bare `Act`) as a phase
+fn phase_of_expr(expr: &syn::Expr) -> Option<AaaPhase> {
+    let syn::Expr::Path(path) = expr else {
+        return None;
+    };
+    phase_of_word(&path.path.segments.last()?.ident.to_string())
+}
+
+fn phase_of_word(word: &str) -> Option<AaaPhase> {
+    match word.to_ascii_lowercase().as_str() {
+        "arrange" => Some(AaaPhase::Arrange),
+        "act" => Some(AaaPhase::Act),
+        "assert" => Some(AaaPhase::Assert),
+        _ => None,
+    }
+}
+
+/// Phases marked by a `// Arrange`-style comment line (`// Act & Assert` marks both)
+fn comment_phases(line: &str) -> Vec<AaaPhase> {
+    let Some(comment) = line.trim_start().strip_prefix("//") else {
+        return Vec::new();
+    };
+    let mut phases = Vec::new();
+    for word in comment.split(|c: char| !c.is_ascii_alphabetic()).filter(|w| !w.is_empty()) {
+        match phase_of_word(word) {
+            Some(phase) => phases.push(phase),
+            None if !phases.is_empty() && word.eq_ignore_ascii_case("and") => {}
+            None => break,
+        }
+    }
+    phases
+}
+
+/// Name and body block of a `test!(name, ..., { body })` invocation
+fn test_macro_parts(tokens: TokenStream) -> Option<(String, syn::Block)> {
+    let tokens: Vec<TokenTree> = tokens.into_iter().collect();
+    let TokenTree::Ident(name) = tokens.first()? else {
+        return None;
+    };
+    let body = tokens.iter().rev().find_map(|token| match token {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::Brace => {
+            syn::parse2::<syn::Block>(TokenStream::from(token.clone())).ok()
+        }
+        _ => None,
+    })?;
+    Some((name.to_string(), body))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    const SOURCE: &str = r"
+use chicago_tdd_tools::test;
+
+test!(test_well_formed, {
+    // Arrange
+    let input = 2;
+
+    // Act
+    let doubled = input * 2;
+
+    // Assert
+    assert_eq!(doubled, 4);
+});
+
+#[tokio::test]
+async fn test_combined_phases() {
+    // Arrange: nothing to set up
+    let value = 1;
+
+    // Act and Assert
+    assert_eq!(value, 1);
+}
+
+#[test]
+fn test_out_of_order() {
+    // Arrange
+    let mut items = vec![1];
+    assert!(!items.is_empty());
+
+    // Act
+    items.push(2);
+
+    // Assert
+    assert_eq!(items.len(), 2);
+
+    // Act
+    items.clear();
+}
+
+#[test]
+fn test_without_markers() {
+    enter_phase(AaaPhase::Act);
+    assert!(true);
+}
+
+fn not_a_test() {
+    assert!(false);
+}
+";
+
+    test!(test_lint_flags_order_and_missing_phases, {
+        // Act
+        let report = lint_source(SOURCE).unwrap();
+
+        // Assert
+        assert_eq!(report.tests, 4);
+        let found: Vec<(&str, usize, AaaViolationKind)> =
+            report.violations.iter().map(|v| (v.test.as_str(), v.line, v.kind)).collect();
+        assert_eq!(
+            found,
+            vec![
+                ("test_out_of_order", 28, AaaViolationKind::AssertBeforeAct),
+                ("test_out_of_order", 36, AaaViolationKind::ActAfterAssert),
+                (
+                    "test_without_markers",
+                    41,
+                    AaaViolationKind::MissingPhase { phase: AaaPhase::Arrange }
+                ),
+                (
+                    "test_without_markers",
+                    41,
+                    AaaViolationKind::MissingPhase { phase: AaaPhase::Assert }
+                ),
+            ]
+        );
+    });
+
+    test!(test_report_serializes_violation_kinds, {
+        // Arrange
+        let report = lint_source(SOURCE).unwrap();
+
+        // Act
+        let json: serde_json::Value = serde_json::from_str(&report.to_json()).unwrap();
+
+        // Assert
+        assert_eq!(json["violations"][0]["kind"], "assert_before_act");
+        assert_eq!(json["violations"][2]["kind"], "missing_phase");
+        assert_eq!(json["violations"][2]["phase"], "arrange");
+    });
+
+    test!(test_lint_reports_parse_errors_with_line, {
+        // Act
+        let result = lint_source("fn ok() {}\nfn broken( {");
+
+        // Assert
+        assert!(matches!(result, Err(AaaLintError::Parse { line: 2, .. })));
+    });
+}
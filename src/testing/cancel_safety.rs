@@ -0,0 +1,369 @@
+//! Cancellation Safety Testing
+//!
+//! Dropping a future cancels it at whichever `.await` it is suspended on. Code that
+//! updates state across an await point (debit, await, credit) leaves that state half
+//! updated when cancelled by `select!`, a timeout, or a dropped task.
+//!
+//! [`CancelSafety`] finds those bugs. It drives the operation on fresh state, counting the
+//! times it suspends, and runs it again once per suspension point, dropping the future
+//! right after that point. After every cancellation, and after the uncancelled run, it
+//! checks a user-supplied invariant on the state. [`assert_cancel_safe!`] wraps the
+//! common case.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::testing::cancel_safety::CancelSafety;
+//!
+//! struct Ledger { from: u32, to: u32 }
+//!
+//! # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+//! let report = CancelSafety::new()
+//!     .check(
+//!         || Ledger { from: 100, to: 0 },
+//!         async |ledger: &mut Ledger| {
+//!             ledger.from -= 10;
+//!             tokio::task::yield_now().await; // e.g. writing an audit record
+//!             ledger.to += 10;
+//!         },
+//!         |ledger| ledger.from + ledger.to == 100,
+//!     )
+//!     .await;
+//!
+//! // Cancelling during the audit write loses the money
+//! assert_eq!(report.await_points(), 1);
+//! assert!(!report.is_cancel_safe());
+//! # });
+//! ```
+
+use std::fmt;
+use std::future::{poll_fn, Future};
+use std::pin::pin;
+use std::task::Poll;
+
+/// Suspension points tried before giving up on an operation that never completes
+pub const DEFAULT_MAX_AWAIT_POINTS: usize = 1000;
+
+/// Where the state invariant failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancelViolation {
+    /// After dropping the operation at this suspension point (1-based)
+    CancelledAt(usize),
+    /// After the operation ran to completion
+    Completed,
+}
+
+impl fmt::Display for CancelViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CancelledAt(point) => write!(f, "cancelled at await point {point}"),
+            Self::Completed => write!(f, "ran to completion"),
+        }
+    }
+}
+
+/// Outcome of [`CancelSafety::check`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CancelSafetyReport {
+    await_points: usize,
+    truncated: bool,
+    violations: Vec<CancelViolation>,
+}
+
+impl CancelSafetyReport {
+    /// Suspension points found (and cancelled at) in the operation
+    #[must_use]
+    pub const fn await_points(&self) -> usize {
+        self.await_points
+    }
+
+    /// Whether the operation suspended more often than the limit, so later points went
+    /// untested
+    #[must_use]
+    pub const fn truncated(&self) -> bool {
+        self.truncated
+    }
+
+    /// Runs after which the invariant did not hold
+    #[must_use]
+    pub fn violations(&self) -> &[CancelViolation] {
+        &self.violations
+    }
+
+    /// Whether the invariant held after every cancellation and after completion
+    #[must_use]
+    pub const fn is_cancel_safe(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Assert that the operation is cancel safe
+    ///
+    /// # Panics
+    ///
+    /// Panics with every run that broke the invariant.
+    pub fn assert_cancel_safe(&self) {
+        assert!(self.is_cancel_safe(), "{self}");
+    }
+}
+
+impl fmt::Display for CancelSafetyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let limit = if self.truncated { " (limit reached)" } else { "" };
+        if self.is_cancel_safe() {
+            return write!(f, "✅ Cancel safe at {} await points{limit}", self.await_points);
+        }
+        writeln!(
+            f,
+            "🚨 Invariant broken by cancellation ({} await points{limit})",
+            self.await_points
+        )?;
+        for violation in &self.violations {
+            writeln!(f, "   📋 Invariant failed after the operation {violation}")?;
+        }
+        write!(f, "   💡 FIX: Apply state changes after the last .await, or roll them back on drop")
+    }
+}
+
+/// Cancels an async operation at each of its await points and checks a state invariant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CancelSafety {
+    max_await_points: usize,
+}
+
+impl Default for CancelSafety {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CancelSafety {
+    /// Checker that tries up to [`DEFAULT_MAX_AWAIT_POINTS`] suspension points
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { max_await_points: DEFAULT_MAX_AWAIT_POINTS }
+    }
+
+    /// Try at most `max` suspension points
+    #[must_use]
+    pub const fn with_max_await_points(mut self, max: usize) -> Self {
+        self.max_await_points = max;
+        self
+    }
+
+    /// Run `operation` on fresh `setup()` state, cancelling it after each suspension point
+    /// in turn, and check `invariant` after every run
+    ///
+    /// Suspension points are discovered by polling: each `Pending` is one point. Runs
+    /// continue until the operation completes without being cancelled, so timing-dependent
+    /// extra suspensions are covered too. Must run inside whatever runtime the operation
+    /// needs.
+    #[allow(clippy::future_not_send)] // Runs inline in the test's own task
+    pub async fn check<S, T>(
+        &self,
+        mut setup: impl FnMut() -> S,
+        mut operation: impl AsyncFnMut(&mut S) -> T,
+        invariant: impl Fn(&S) -> bool,
+    ) -> CancelSafetyReport {
+        let mut violations = Vec::new();
+        let mut point = 1;
+        loop {
+            let mut state = setup();
+            let completed = {
+                let mut future = pin!(operation(&mut state));
+                let mut suspensions = 0;
+                poll_fn(|cx| match future.as_mut().poll(cx) {
+                    Poll::Ready(_) => Poll::Ready(true),
+                    Poll::Pending if suspensions + 1 == point => Poll::Ready(false),
+                    Poll::Pending => {
+                        suspensions += 1;
+                        Poll::Pending
+                    }
+                })
+                .await
+            };
+            let holds = invariant(&state);
+            if completed {
+                if !holds {
+                    violations.push(CancelViolation::Completed);
+                }
+                return CancelSafetyReport {
+                    await_points: point - 1,
+                    truncated: false,
+                    violations,
+                };
+            }
+            if !holds {
+                violations.push(CancelViolation::CancelledAt(point));
+            }
+            if point >= self.max_await_points {
+                return CancelSafetyReport { await_points: point, truncated: true, violations };
+            }
+            point += 1;
+        }
+    }
+}
+
+/// Assert that an async operation leaves state consistent when cancelled at any await
+///
+/// `assert_cancel_safe!(setup, operation, invariant)` runs
+/// [`CancelSafety::check`](crate::testing::cancel_safety::CancelSafety::check) and fails
+/// with every cancellation point that broke the invariant. Add `max_await_points = N` to
+/// change the limit. Must be used in an async context.
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::assert_cancel_safe;
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// // Both balances change after the last await, so cancellation cannot split them
+/// assert_cancel_safe!(
+///     || (100_u32, 0_u32),
+///     async |(from, to): &mut (u32, u32)| {
+///         tokio::task::yield_now().await; // e.g. checking a limit
+///         *from -= 10;
+///         *to += 10;
+///     },
+///     |(from, to)| from + to == 100,
+/// );
+/// # });
+/// ```
+#[macro_export]
+macro_rules! assert_cancel_safe {
+    ($setup:expr, $operation:expr, $invariant:expr $(, max_await_points = $max:expr)? $(,)?) => {{
+        $crate::core::receipt::record_assertion();
+        $crate::testing::cancel_safety::CancelSafety::new()
+            $(.with_max_await_points($max))?
+            .check($setup, $operation, $invariant)
+            .await
+            .assert_cancel_safe();
+    }};
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Test code: unwrap is acceptable
+mod tests {
+    use super::*;
+    use crate::async_test;
+    use crate::core::failure::TddFailure;
+    use std::future::Future;
+
+    /// Completes after suspending `count` times
+    fn suspend(count: usize) -> impl Future<Output = ()> {
+        let mut remaining = count;
+        poll_fn(move |cx| {
+            if remaining == 0 {
+                return Poll::Ready(());
+            }
+            remaining -= 1;
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        })
+    }
+
+    /// Two-step write: `first` and `second` must always match
+    #[derive(Debug, Default)]
+    struct Pair {
+        first: u32,
+        second: u32,
+    }
+
+    async_test!(test_every_await_point_is_cancelled, {
+        // Arrange: Suspends once between the writes and twice after them
+        let checker = CancelSafety::new();
+
+        // Act
+        let report = checker
+            .check(
+                Pair::default,
+                async |pair: &mut Pair| {
+                    pair.first = 1;
+                    suspend(1).await;
+                    pair.second = 1;
+                    suspend(2).await;
+                },
+                |pair| pair.first == pair.second,
+            )
+            .await;
+
+        // Assert: Only the point between the writes breaks the invariant
+        assert_eq!(report.await_points(), 3);
+        assert!(!report.truncated());
+        assert_eq!(report.violations(), [CancelViolation::CancelledAt(1)]);
+        let rendered = report.to_string();
+        assert!(rendered.contains("cancelled at await point 1"), "{rendered}");
+    });
+
+    async_test!(test_safe_operation_passes_macro, {
+        // Act + Assert: Writes happen after the last await
+        assert_cancel_safe!(
+            Pair::default,
+            async |pair: &mut Pair| {
+                suspend(3).await;
+                pair.first = 1;
+                pair.second = 1;
+            },
+            |pair: &Pair| pair.first == pair.second,
+        );
+    });
+
+    async_test!(test_invariant_is_checked_after_completion, {
+        // Arrange
+        let checker = CancelSafety::new();
+
+        // Act: Never suspends, but leaves the pair inconsistent
+        let report = checker
+            .check(
+                Pair::default,
+                async |pair: &mut Pair| pair.first = 7,
+                |pair| pair.first == pair.second,
+            )
+            .await;
+
+        // Assert
+        assert_eq!(report.await_points(), 0);
+        assert_eq!(report.violations(), [CancelViolation::Completed]);
+    });
+
+    async_test!(test_endless_operation_stops_at_limit, {
+        // Arrange
+        let checker = CancelSafety::new().with_max_await_points(5);
+
+        // Act
+        let report = checker
+            .check(
+                || (),
+                async |()| loop {
+                    suspend(1).await;
+                },
+                |()| true,
+            )
+            .await;
+
+        // Assert
+        assert!(report.truncated());
+        assert_eq!(report.await_points(), 5);
+        assert!(report.is_cancel_safe());
+    });
+
+    async_test!(test_macro_fails_with_report, {
+        // Arrange
+        let report = CancelSafety::new()
+            .check(
+                Pair::default,
+                async |pair: &mut Pair| {
+                    pair.first = 1;
+                    suspend(1).await;
+                    pair.second = 1;
+                },
+                |pair| pair.first == pair.second,
+            )
+            .await;
+
+        // Act
+        let failure = TddFailure::catch(|| report.assert_cancel_safe()).unwrap_err();
+
+        // Assert
+        assert!(failure.message().contains("Invariant broken by cancellation"), "{failure}");
+    });
+}
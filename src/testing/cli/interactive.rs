@@ -0,0 +1,539 @@
+//! Interactive CLI Sessions (PTY)
+//!
+//! `InteractiveSession` spawns a binary under a pseudo-terminal so REPLs and
+//! prompt-driven CLIs behave exactly as they do for a human user (line editing,
+//! `isatty()` checks, prompts without trailing newlines). Tests script the
+//! conversation with `expect(pattern)` / `send_line(line)`, each bounded by a timeout.
+//!
+//! All output is ANSI-stripped and accumulated into a transcript suitable for
+//! snapshot assertions. Output quoted in errors has the configured redaction rules
+//! applied.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "cli-testing")]
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use chicago_tdd_tools::cli::{CliCommandBuilder, InteractiveSession};
+//! use std::time::Duration;
+//!
+//! // Arrange: Start the REPL under a PTY
+//! let mut session = InteractiveSession::spawn(&CliCommandBuilder::new("python3").arg("-q"))?
+//!     .with_timeout(Duration::from_secs(5));
+//!
+//! // Act: Drive the prompt
+//! session.expect(">>> ")?;
+//! session.send_line("1 + 1")?;
+//!
+//! // Assert: Verify the response
+//! session.expect("2")?;
+//! # Ok(())
+//! # }
+//! # #[cfg(not(feature = "cli-testing"))]
+//! # fn main() {}
+//! ```
+
+use super::CliCommandBuilder;
+use crate::core::redaction::RedactionRuleSet;
+use portable_pty::{native_pty_system, Child, CommandBuilder, PtySize};
+use std::io::{Read, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// Default timeout for `expect` calls
+pub const DEFAULT_EXPECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Interactive session error
+#[derive(Error, Debug)]
+pub enum InteractiveError {
+    /// The PTY or child process could not be created
+    #[error("Failed to spawn '{command}' under PTY: {reason}")]
+    SpawnFailed {
+        /// Command line that failed
+        command: String,
+        /// Underlying failure
+        reason: String,
+    },
+    /// Writing to or reading from the PTY failed
+    #[error("PTY I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Pattern did not appear before the deadline
+    #[error("Timed out after {timeout:?} waiting for {pattern:?}. Unmatched output: {output:?}")]
+    Timeout {
+        /// Pattern being waited for
+        pattern: String,
+        /// Timeout that elapsed
+        timeout: Duration,
+        /// Output received since the last match (ANSI-stripped and redacted)
+        output: String,
+    },
+    /// Process closed its terminal before the pattern appeared
+    #[error("Process exited while waiting for {pattern:?}. Unmatched output: {output:?}")]
+    Eof {
+        /// Pattern being waited for
+        pattern: String,
+        /// Output received since the last match (ANSI-stripped and redacted)
+        output: String,
+    },
+}
+
+/// Result type for interactive sessions
+pub type InteractiveResult<T> = Result<T, InteractiveError>;
+
+/// A binary running under a PTY, scripted with expect/send
+///
+/// The child process is killed when the session is dropped.
+pub struct InteractiveSession {
+    command: String,
+    child: Box<dyn Child + Send + Sync>,
+    writer: Box<dyn Write + Send>,
+    output: Receiver<Vec<u8>>,
+    pending: String,
+    transcript: String,
+    raw_tail: Vec<u8>,
+    ansi: AnsiStripper,
+    eof: bool,
+    timeout: Duration,
+}
+
+impl std::fmt::Debug for InteractiveSession {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InteractiveSession")
+            .field("command", &self.command)
+            .field("eof", &self.eof)
+            .field("timeout", &self.timeout)
+            .finish_non_exhaustive()
+    }
+}
+
+impl InteractiveSession {
+    /// Spawn the command under a new 24x80 PTY
+    ///
+    /// The child inherits the current working directory and environment, plus
+    /// any variables set on the builder.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SpawnFailed` if the PTY cannot be opened or the command cannot start.
+    pub fn spawn(builder: &CliCommandBuilder) -> InteractiveResult<Self> {
+        Self::spawn_with_size(builder, 24, 80)
+    }
+
+    /// Spawn the command under a PTY with the given terminal size
+    ///
+    /// # Errors
+    ///
+    /// Returns `SpawnFailed` if the PTY cannot be opened or the command cannot start.
+    pub fn spawn_with_size(
+        builder: &CliCommandBuilder,
+        rows: u16,
+        cols: u16,
+    ) -> InteractiveResult<Self> {
+        let command = builder.build();
+        let spawn_failed =
+            |reason: String| InteractiveError::SpawnFailed { command: command.clone(), reason };
+
+        let pair = native_pty_system()
+            .openpty(PtySize { rows, cols, pixel_width: 0, pixel_height: 0 })
+            .map_err(|e| spawn_failed(e.to_string()))?;
+
+        let mut cmd = CommandBuilder::new(&builder.binary);
+        cmd.args(&builder.args);
+        for (key, value) in &builder.env {
+            cmd.env(key, value);
+        }
+        // portable-pty defaults to $HOME; tests expect the process working directory.
+        if let Ok(cwd) = std::env::current_dir() {
+            cmd.cwd(cwd);
+        }
+
+        let child = pair.slave.spawn_command(cmd).map_err(|e| spawn_failed(e.to_string()))?;
+        // Drop our copy of the slave so EOF is observed once the child exits.
+        drop(pair.slave);
+
+        let mut reader = pair.master.try_clone_reader().map_err(|e| spawn_failed(e.to_string()))?;
+        let writer = pair.master.take_writer().map_err(|e| spawn_failed(e.to_string()))?;
+
+        let (tx, rx) = mpsc::channel();
+        let master = pair.master;
+        thread::spawn(move || {
+            // Keep the master alive for as long as the reader runs.
+            let _master = master;
+            let mut buf = [0_u8; 4096];
+            loop {
+                match reader.read(&mut buf) {
+                    Ok(0) | Err(_) => break,
+                    Ok(n) => {
+                        if tx.send(buf[..n].to_vec()).is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            command,
+            child,
+            writer,
+            output: rx,
+            pending: String::new(),
+            transcript: String::new(),
+            raw_tail: Vec::new(),
+            ansi: AnsiStripper::default(),
+            eof: false,
+            timeout: DEFAULT_EXPECT_TIMEOUT,
+        })
+    }
+
+    /// Set the default timeout used by `expect`
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Wait until `pattern` appears in the output, using the default timeout
+    ///
+    /// Returns everything up to and including the match; output after the match
+    /// remains available to the next `expect`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Timeout` or `Eof` if the pattern never appears.
+    pub fn expect(&mut self, pattern: &str) -> InteractiveResult<String> {
+        self.expect_within(pattern, self.timeout)
+    }
+
+    /// Wait until `pattern` appears in the output within `timeout`
+    ///
+    /// # Errors
+    ///
+    /// Returns `Timeout` or `Eof` if the pattern never appears.
+    pub fn expect_within(&mut self, pattern: &str, timeout: Duration) -> InteractiveResult<String> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(index) = self.pending.find(pattern) {
+                let end = index + pattern.len();
+                let matched: String = self.pending.drain(..end).collect();
+                return Ok(matched);
+            }
+            if self.eof {
+                return Err(InteractiveError::Eof {
+                    pattern: pattern.to_string(),
+                    output: self.unmatched_output(),
+                });
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(InteractiveError::Timeout {
+                    pattern: pattern.to_string(),
+                    timeout,
+                    output: self.unmatched_output(),
+                });
+            }
+            self.receive(remaining);
+        }
+    }
+
+    /// Wait for the process to close its terminal, returning the remaining output
+    ///
+    /// # Errors
+    ///
+    /// Returns `Timeout` if the process is still running after the default timeout.
+    pub fn expect_eof(&mut self) -> InteractiveResult<String> {
+        let deadline = Instant::now() + self.timeout;
+        while !self.eof {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(InteractiveError::Timeout {
+                    pattern: "<EOF>".to_string(),
+                    timeout: self.timeout,
+                    output: self.unmatched_output(),
+                });
+            }
+            self.receive(remaining);
+        }
+        Ok(std::mem::take(&mut self.pending))
+    }
+
+    /// Send raw text to the process
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the PTY is closed.
+    pub fn send(&mut self, text: &str) -> InteractiveResult<()> {
+        self.writer.write_all(text.as_bytes())?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Send a line of input followed by a carriage return (Enter)
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the PTY is closed.
+    pub fn send_line(&mut self, line: &str) -> InteractiveResult<()> {
+        self.send(&format!("{line}\r"))
+    }
+
+    /// Send a control character, e.g. `send_control('c')` for Ctrl-C or `'d'` for EOF
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if the PTY is closed or `c` is not a letter.
+    pub fn send_control(&mut self, c: char) -> InteractiveResult<()> {
+        if !c.is_ascii_alphabetic() {
+            return Err(InteractiveError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("'{c}' has no control-key equivalent"),
+            )));
+        }
+        let byte = (c.to_ascii_lowercase() as u8) - b'a' + 1;
+        self.writer.write_all(&[byte])?;
+        self.writer.flush()?;
+        Ok(())
+    }
+
+    /// Full ANSI-stripped transcript of everything the process printed so far
+    ///
+    /// Includes echoed input, since the terminal echoes what is typed.
+    #[must_use]
+    pub fn transcript(&self) -> &str {
+        &self.transcript
+    }
+
+    /// Wait for the process to exit and return its exit code
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if waiting fails.
+    pub fn wait(&mut self) -> InteractiveResult<u32> {
+        let status = self.child.wait()?;
+        Ok(status.exit_code())
+    }
+
+    /// Process id of the child, if still known
+    #[must_use]
+    pub fn process_id(&self) -> Option<u32> {
+        self.child.process_id()
+    }
+
+    /// Pending output for error messages, with the configured redaction rules applied
+    fn unmatched_output(&self) -> String {
+        RedactionRuleSet::configured().redact_text(&self.pending).into_owned()
+    }
+
+    fn receive(&mut self, timeout: Duration) {
+        match self.output.recv_timeout(timeout) {
+            Ok(bytes) => {
+                self.raw_tail.extend_from_slice(&bytes);
+                // Hold back an incomplete UTF-8 sequence until the rest arrives.
+                let valid = match std::str::from_utf8(&self.raw_tail) {
+                    Err(e) if e.error_len().is_none() => e.valid_up_to(),
+                    _ => self.raw_tail.len(),
+                };
+                let chunk: Vec<u8> = self.raw_tail.drain(..valid).collect();
+                self.push_output(&chunk);
+            }
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => {
+                // Nothing more will complete a held-back sequence, so decode it lossily.
+                let rest = std::mem::take(&mut self.raw_tail);
+                self.push_output(&rest);
+                self.eof = true;
+            }
+        }
+    }
+
+    fn push_output(&mut self, bytes: &[u8]) {
+        if bytes.is_empty() {
+            return;
+        }
+        let text = self.ansi.push(&String::from_utf8_lossy(bytes));
+        self.pending.push_str(&text);
+        self.transcript.push_str(&text);
+    }
+}
+
+impl Drop for InteractiveSession {
+    fn drop(&mut self) {
+        if matches!(self.child.try_wait(), Ok(None)) {
+            // Best-effort cleanup: the process may exit between the check and the kill.
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+}
+
+/// Strip ANSI escape sequences and normalize terminal line endings
+///
+/// Removes CSI (`ESC [ ... final`), OSC (`ESC ] ... BEL|ST`), and two-byte escapes,
+/// and drops carriage returns (so `\r\n` becomes `\n`), so PTY output
+/// can be compared against plain expected text. An escape sequence cut off at the end
+/// of `input` is dropped; use [`AnsiStripper`] for output that arrives in chunks.
+#[must_use]
+pub fn strip_ansi(input: &str) -> String {
+    AnsiStripper::default().push(input)
+}
+
+/// Streaming [`strip_ansi`] for output that arrives in chunks
+///
+/// An escape sequence split across chunks (`ESC [3` then `1mOK`) is held back until
+/// its final byte arrives, so no part of it leaks into the text.
+#[derive(Debug, Clone, Default)]
+pub struct AnsiStripper {
+    unfinished: String,
+}
+
+impl AnsiStripper {
+    /// Strip the next chunk, carrying an unfinished escape sequence to the next call
+    pub fn push(&mut self, chunk: &str) -> String {
+        let input = std::mem::take(&mut self.unfinished) + chunk;
+        let mut out = String::with_capacity(input.len());
+        let mut chars = input.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            match c {
+                '\u{1b}' => {
+                    let complete = match chars.next() {
+                        Some((_, '[')) => {
+                            // CSI: parameters/intermediates until a final byte in @..~
+                            chars.by_ref().any(|(_, c)| ('@'..='~').contains(&c))
+                        }
+                        Some((_, ']')) => {
+                            // OSC: terminated by BEL or ESC \
+                            let mut terminated = false;
+                            while let Some((_, c)) = chars.next() {
+                                if c == '\u{7}' {
+                                    terminated = true;
+                                    break;
+                                }
+                                if c == '\u{1b}' {
+                                    if chars.next_if(|(_, c)| *c == '\\').is_some() {
+                                        terminated = true;
+                                        break;
+                                    }
+                                    if chars.peek().is_none() {
+                                        break;
+                                    }
+                                }
+                            }
+                            terminated
+                        }
+                        Some(_) => true,
+                        None => false,
+                    };
+                    if !complete {
+                        self.unfinished = input[start..].to_string();
+                        break;
+                    }
+                }
+                // Terminals emit \r\n; dropping \r normalizes to \n line endings.
+                '\r' => {}
+                c => out.push(c),
+            }
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_strip_ansi_removes_color_codes, {
+        // Arrange
+        let colored = "\u{1b}[1;32mok\u{1b}[0m done";
+
+        // Act
+        let plain = strip_ansi(colored);
+
+        // Assert
+        assert_eq!(plain, "ok done");
+    });
+
+    test!(test_strip_ansi_removes_osc_and_normalizes_crlf, {
+        // Arrange
+        let raw = "\u{1b}]0;title\u{7}line1\r\nline2\r\n";
+
+        // Act
+        let plain = strip_ansi(raw);
+
+        // Assert
+        assert_eq!(plain, "line1\nline2\n");
+    });
+
+    test!(test_ansi_stripper_carries_escape_split_across_chunks, {
+        // Arrange
+        let mut stripper = AnsiStripper::default();
+
+        // Act
+        let first = stripper.push("ready \u{1b}[3");
+        let second = stripper.push("1mOK\u{1b}]0;ti");
+        let third = stripper.push("tle\u{7} done");
+
+        // Assert
+        assert_eq!(first, "ready ");
+        assert_eq!(second, "OK");
+        assert_eq!(third, " done");
+    });
+
+    #[cfg(unix)]
+    #[test]
+    fn test_session_expect_and_send_line() {
+        // Arrange: `cat` echoes each line back through the terminal
+        let mut session = InteractiveSession::spawn(&CliCommandBuilder::new("cat"))
+            .unwrap()
+            .with_timeout(Duration::from_secs(5));
+
+        // Act
+        session.send_line("hello pty").unwrap();
+        let matched = session.expect("hello pty").unwrap();
+        session.send_control('d').unwrap();
+        let exit_code = session.wait().unwrap();
+
+        // Assert
+        assert!(matched.ends_with("hello pty"));
+        assert!(session.transcript().contains("hello pty"));
+        assert_eq!(exit_code, 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_session_flushes_partial_utf8_at_eof() {
+        // Arrange: output ends with the first byte of a two-byte character
+        let mut session =
+            InteractiveSession::spawn(&CliCommandBuilder::new("printf").arg("done\\303")).unwrap();
+
+        // Act
+        let output = session.expect_eof().unwrap();
+
+        // Assert
+        assert_eq!(output, "done\u{FFFD}");
+        assert!(session.transcript().ends_with("done\u{FFFD}"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_session_expect_times_out_with_unmatched_output() {
+        // Arrange
+        let mut session =
+            InteractiveSession::spawn(&CliCommandBuilder::new("echo").arg("something")).unwrap();
+
+        // Act
+        let result = session.expect_within("never printed", Duration::from_millis(300));
+
+        // Assert
+        match result {
+            Err(
+                InteractiveError::Eof { output, .. } | InteractiveError::Timeout { output, .. },
+            ) => {
+                assert!(output.contains("something"));
+            }
+            other => panic!("Expected Eof/Timeout, got {other:?}"),
+        }
+    }
+}
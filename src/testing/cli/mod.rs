@@ -17,7 +17,31 @@
 //! - `CliAssertions`: Output verification helpers
 //! - `CliEnvironment`: Environment setup for tests
 //! - `CliTestScenario`: Complete test scenario builder
+//!
+//! # Interactive Sessions
+//!
+//! - `InteractiveSession`: PTY-backed `expect`/`send` scripting for REPLs and prompts
+//!
+//! # Declarative Scenarios
+//!
+//! - `ScenarioRunner`: runs a directory of TOML scenarios (args, env, stdin, expected
+//!   exit code, stdout/stderr patterns, golden files) as named cases
 
+#[cfg(feature = "cli-testing")]
+pub mod interactive;
+#[cfg(feature = "cli-testing")]
+pub mod scenario;
+
+#[cfg(feature = "cli-testing")]
+pub use interactive::{
+    strip_ansi, AnsiStripper, InteractiveError, InteractiveResult, InteractiveSession,
+    DEFAULT_EXPECT_TIMEOUT,
+};
+#[cfg(feature = "cli-testing")]
+pub use scenario::{
+    Scenario, ScenarioError, ScenarioExpectation, ScenarioOutcome, ScenarioReport, ScenarioResult,
+    ScenarioRunner, BLESS_ENV_VAR,
+};
 #[cfg(feature = "cli-testing")]
 use std::collections::HashMap;
 #[cfg(feature = "cli-testing")]
@@ -0,0 +1,634 @@
+//! Declarative CLI Scenarios
+//!
+//! Data-driven end-to-end CLI tests. Each scenario is a TOML file describing the
+//! arguments, environment, and stdin for one invocation, plus the expected exit code
+//! and stdout/stderr patterns or golden files. A [`ScenarioRunner`] loads a directory
+//! of scenarios and runs each one as a named case.
+//!
+//! # Scenario Format
+//!
+//! ```toml
+//! # tests/cli/help.toml — the name defaults to the file stem
+//! name = "help"
+//! args = ["--help"]
+//! stdin = ""
+//!
+//! [env]
+//! NO_COLOR = "1"
+//!
+//! [expect]
+//! exit_code = 0
+//! stdout_contains = ["Usage:"]
+//! stdout_not_contains = ["panicked"]
+//! stderr_contains = []
+//! stderr_not_contains = []
+//! stdout_file = "help.stdout"   # golden file, relative to the scenario
+//! ```
+//!
+//! Set `CHICAGO_TDD_BLESS=1` (or call [`ScenarioRunner::bless`]) to write the actual
+//! output into missing or outdated golden files instead of failing. Output is redacted
+//! with the configured redaction rules (plus any passed to [`ScenarioRunner::redact`])
+//! before it is checked or blessed, so secrets never land in golden files.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::testing::cli::ScenarioRunner;
+//!
+//! ScenarioRunner::new("target/debug/my-cli")
+//!     .env("NO_COLOR", "1")
+//!     .run_dir("tests/cli")
+//!     .unwrap()
+//!     .assert_all_passed();
+//! ```
+
+use crate::core::command::{CheckedCommand, CommandError, DEFAULT_COMMAND_TIMEOUT};
+use crate::core::redaction::RedactionRuleSet;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Environment variable that enables golden-file blessing
+pub const BLESS_ENV_VAR: &str = "CHICAGO_TDD_BLESS";
+
+/// Scenario loading and execution errors
+#[derive(Error, Debug)]
+pub enum ScenarioError {
+    /// Scenario file or directory could not be read
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        /// Path being read
+        path: PathBuf,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// Scenario file is not valid TOML or has unknown fields
+    #[error("Invalid scenario {path}: {source}")]
+    Parse {
+        /// Scenario file path
+        path: PathBuf,
+        /// Underlying TOML error
+        source: toml::de::Error,
+    },
+    /// The binary under test could not be started
+    #[error("Failed to run scenario '{scenario}': {reason}")]
+    SpawnFailed {
+        /// Scenario name
+        scenario: String,
+        /// Failure reason
+        reason: String,
+    },
+}
+
+/// Result type for scenario operations
+pub type ScenarioResult<T> = Result<T, ScenarioError>;
+
+/// Expected outcome of a scenario
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct ScenarioExpectation {
+    /// Expected exit code (`0` when omitted)
+    #[serde(default)]
+    pub exit_code: i32,
+    /// Substrings that must appear in stdout
+    #[serde(default)]
+    pub stdout_contains: Vec<String>,
+    /// Substrings that must not appear in stdout
+    #[serde(default)]
+    pub stdout_not_contains: Vec<String>,
+    /// Substrings that must appear in stderr
+    #[serde(default)]
+    pub stderr_contains: Vec<String>,
+    /// Substrings that must not appear in stderr
+    #[serde(default)]
+    pub stderr_not_contains: Vec<String>,
+    /// Golden file for stdout, relative to the scenario file
+    #[serde(default)]
+    pub stdout_file: Option<PathBuf>,
+    /// Golden file for stderr, relative to the scenario file
+    #[serde(default)]
+    pub stderr_file: Option<PathBuf>,
+}
+
+/// One declarative CLI scenario
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Scenario {
+    /// Scenario name (defaults to the file stem)
+    #[serde(default)]
+    pub name: String,
+    /// Arguments passed to the binary
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Extra environment variables
+    #[serde(default)]
+    pub env: BTreeMap<String, String>,
+    /// Data written to stdin (stdin is closed when omitted)
+    #[serde(default)]
+    pub stdin: Option<String>,
+    /// Expected outcome
+    #[serde(default)]
+    pub expect: ScenarioExpectation,
+    /// Directory golden files are resolved against
+    #[serde(skip)]
+    pub base_dir: PathBuf,
+}
+
+impl Scenario {
+    /// Parse a scenario from TOML text
+    ///
+    /// `path` is used for error messages, the default name, and golden file lookup.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScenarioError::Parse`] if the text is not a valid scenario.
+    pub fn from_toml(text: &str, path: &Path) -> ScenarioResult<Self> {
+        let mut scenario: Self = toml::from_str(text)
+            .map_err(|source| ScenarioError::Parse { path: path.to_path_buf(), source })?;
+        if scenario.name.is_empty() {
+            scenario.name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default();
+        }
+        scenario.base_dir = path.parent().map(Path::to_path_buf).unwrap_or_default();
+        Ok(scenario)
+    }
+
+    /// Load a scenario file
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScenarioError::Io`] or [`ScenarioError::Parse`].
+    pub fn load(path: impl AsRef<Path>) -> ScenarioResult<Self> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path)
+            .map_err(|source| ScenarioError::Io { path: path.to_path_buf(), source })?;
+        Self::from_toml(&text, path)
+    }
+
+    /// Load every `*.toml` scenario in a directory, sorted by file name
+    ///
+    /// # Errors
+    ///
+    /// Returns the first read or parse error.
+    pub fn load_dir(dir: impl AsRef<Path>) -> ScenarioResult<Vec<Self>> {
+        let dir = dir.as_ref();
+        let io_err = |source| ScenarioError::Io { path: dir.to_path_buf(), source };
+        let mut paths = Vec::new();
+        for entry in std::fs::read_dir(dir).map_err(io_err)? {
+            let path = entry.map_err(io_err)?.path();
+            if path.is_file() && path.extension().is_some_and(|ext| ext == "toml") {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        paths.iter().map(Self::load).collect()
+    }
+}
+
+/// Result of running one scenario
+#[derive(Debug, Clone)]
+pub struct ScenarioOutcome {
+    /// Scenario name
+    pub name: String,
+    /// Actual exit code (`-1` if terminated by a signal)
+    pub exit_code: i32,
+    /// Captured stdout
+    pub stdout: String,
+    /// Captured stderr
+    pub stderr: String,
+    /// Expectation failures; empty when the scenario passed
+    pub failures: Vec<String>,
+}
+
+impl ScenarioOutcome {
+    /// Whether every expectation held
+    #[must_use]
+    pub const fn passed(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+impl fmt::Display for ScenarioOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.passed() {
+            return write!(f, "scenario {} ... ok", self.name);
+        }
+        writeln!(f, "scenario {} ... FAILED", self.name)?;
+        for failure in &self.failures {
+            writeln!(f, "  - {failure}")?;
+        }
+        write!(f, "  stdout:\n{}\n  stderr:\n{}", self.stdout, self.stderr)
+    }
+}
+
+/// Outcomes of a scenario directory run
+#[derive(Debug, Clone, Default)]
+pub struct ScenarioReport {
+    /// Outcomes in execution order
+    pub outcomes: Vec<ScenarioOutcome>,
+}
+
+impl ScenarioReport {
+    /// Number of passing scenarios
+    #[must_use]
+    pub fn passed(&self) -> usize {
+        self.outcomes.iter().filter(|outcome| outcome.passed()).count()
+    }
+
+    /// Failing scenarios
+    pub fn failed(&self) -> impl Iterator<Item = &ScenarioOutcome> {
+        self.outcomes.iter().filter(|outcome| !outcome.passed())
+    }
+
+    /// Outcome for a scenario by name
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&ScenarioOutcome> {
+        self.outcomes.iter().find(|outcome| outcome.name == name)
+    }
+
+    /// Assert every scenario passed
+    ///
+    /// # Panics
+    ///
+    /// Panics listing each failing scenario and its failed expectations.
+    pub fn assert_all_passed(&self) {
+        let failed: Vec<String> = self.failed().map(ToString::to_string).collect();
+        assert!(
+            failed.is_empty(),
+            "{} of {} CLI scenarios failed:\n{}",
+            failed.len(),
+            self.outcomes.len(),
+            failed.join("\n")
+        );
+    }
+}
+
+/// Runs declarative scenarios against a binary
+#[derive(Debug, Clone)]
+pub struct ScenarioRunner {
+    binary: PathBuf,
+    env: BTreeMap<String, String>,
+    current_dir: Option<PathBuf>,
+    bless: bool,
+    timeout: Duration,
+    redaction: RedactionRuleSet,
+}
+
+impl ScenarioRunner {
+    /// Create a runner for `binary`
+    ///
+    /// Blessing is enabled when the `CHICAGO_TDD_BLESS` environment variable is set.
+    /// Captured output is redacted with the configured redaction rules.
+    #[must_use]
+    pub fn new(binary: impl Into<PathBuf>) -> Self {
+        Self {
+            binary: binary.into(),
+            env: BTreeMap::new(),
+            current_dir: None,
+            bless: std::env::var_os(BLESS_ENV_VAR).is_some_and(|value| value != "0"),
+            timeout: DEFAULT_COMMAND_TIMEOUT,
+            redaction: RedactionRuleSet::configured().clone(),
+        }
+    }
+
+    /// Set an environment variable for every scenario (scenario `env` wins)
+    #[must_use]
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.env.insert(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Working directory for the binary
+    #[must_use]
+    pub fn current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(dir.into());
+        self
+    }
+
+    /// Per-scenario timeout; a scenario that exceeds it is killed and fails
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Redact captured output with `rules` in addition to the configured rules
+    ///
+    /// Expectations and golden files are checked against the redacted output.
+    #[must_use]
+    pub fn redact(mut self, rules: &RedactionRuleSet) -> Self {
+        self.redaction = self.redaction.extend(rules);
+        self
+    }
+
+    /// Write actual output into golden files instead of comparing
+    #[must_use]
+    pub const fn bless(mut self, bless: bool) -> Self {
+        self.bless = bless;
+        self
+    }
+
+    /// Run a single scenario
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ScenarioError::SpawnFailed`] if the binary cannot be executed, or
+    /// [`ScenarioError::Io`] if blessing a golden file fails. Expectation mismatches
+    /// and timeouts are reported in the outcome, not as errors.
+    pub fn run(&self, scenario: &Scenario) -> ScenarioResult<ScenarioOutcome> {
+        let mut command =
+            CheckedCommand::new(&self.binary).args(&scenario.args).timeout(self.timeout);
+        for (key, value) in self.env.iter().chain(&scenario.env) {
+            command = command.env(key, value);
+        }
+        if let Some(dir) = &self.current_dir {
+            command = command.current_dir(dir);
+        }
+        if let Some(input) = &scenario.stdin {
+            command = command.stdin(input.as_bytes());
+        }
+
+        let mut outcome = ScenarioOutcome {
+            name: scenario.name.clone(),
+            exit_code: -1,
+            stdout: String::new(),
+            stderr: String::new(),
+            failures: Vec::new(),
+        };
+        match command.output() {
+            Ok(output) => {
+                outcome.exit_code = output.code().unwrap_or(-1);
+                outcome.stdout = self.redacted(output.stdout_lossy());
+                outcome.stderr = self.redacted(output.stderr_lossy());
+            }
+            Err(CommandError::TimedOut { timeout, stdout, stderr, .. }) => {
+                outcome.stdout = self.redacted(stdout);
+                outcome.stderr = self.redacted(stderr);
+                outcome.failures.push(format!("timed out after {timeout:?}"));
+                return Ok(outcome);
+            }
+            Err(e) => {
+                return Err(ScenarioError::SpawnFailed {
+                    scenario: scenario.name.clone(),
+                    reason: e.to_string(),
+                })
+            }
+        }
+        self.check(scenario, &mut outcome)?;
+        Ok(outcome)
+    }
+
+    /// Load and run every scenario in a directory
+    ///
+    /// # Errors
+    ///
+    /// Returns loading errors and [`Self::run`] errors.
+    pub fn run_dir(&self, dir: impl AsRef<Path>) -> ScenarioResult<ScenarioReport> {
+        let outcomes = Scenario::load_dir(dir)?
+            .iter()
+            .map(|scenario| self.run(scenario))
+            .collect::<ScenarioResult<Vec<_>>>()?;
+        Ok(ScenarioReport { outcomes })
+    }
+
+    fn redacted(&self, output: String) -> String {
+        match self.redaction.redact_text(&output) {
+            Cow::Owned(redacted) => redacted,
+            Cow::Borrowed(_) => output,
+        }
+    }
+
+    fn check(&self, scenario: &Scenario, outcome: &mut ScenarioOutcome) -> ScenarioResult<()> {
+        let expect = &scenario.expect;
+        let mut failures = Vec::new();
+        if outcome.exit_code != expect.exit_code {
+            failures.push(format!(
+                "exit code: expected {}, got {}",
+                expect.exit_code, outcome.exit_code
+            ));
+        }
+        for (stream, actual, contains, not_contains, golden) in [
+            (
+                "stdout",
+                &outcome.stdout,
+                &expect.stdout_contains,
+                &expect.stdout_not_contains,
+                &expect.stdout_file,
+            ),
+            (
+                "stderr",
+                &outcome.stderr,
+                &expect.stderr_contains,
+                &expect.stderr_not_contains,
+                &expect.stderr_file,
+            ),
+        ] {
+            for pattern in contains.iter().filter(|pattern| !actual.contains(pattern.as_str())) {
+                failures.push(format!("{stream} does not contain {pattern:?}"));
+            }
+            for pattern in not_contains.iter().filter(|pattern| actual.contains(pattern.as_str())) {
+                failures.push(format!("{stream} contains unexpected {pattern:?}"));
+            }
+            if let Some(file) = golden {
+                let path = scenario.base_dir.join(file);
+                if let Some(failure) = self.check_golden(stream, actual, &path)? {
+                    failures.push(failure);
+                }
+            }
+        }
+        outcome.failures = failures;
+        Ok(())
+    }
+
+    fn check_golden(
+        &self,
+        stream: &str,
+        actual: &str,
+        path: &Path,
+    ) -> ScenarioResult<Option<String>> {
+        let expected = std::fs::read_to_string(path).ok();
+        if expected.as_deref() == Some(actual) {
+            return Ok(None);
+        }
+        if self.bless {
+            std::fs::write(path, actual)
+                .map_err(|source| ScenarioError::Io { path: path.to_path_buf(), source })?;
+            return Ok(None);
+        }
+        Ok(Some(match expected {
+            Some(_) => format!(
+                "{stream} differs from golden file {} (set {BLESS_ENV_VAR}=1 to update)",
+                path.display()
+            ),
+            None => format!(
+                "golden file {} is missing (set {BLESS_ENV_VAR}=1 to create)",
+                path.display()
+            ),
+        }))
+    }
+}
+
+/// Generate a named `#[test]` for one CLI scenario file
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use chicago_tdd_tools::cli_scenario_test;
+///
+/// cli_scenario_test!(cli_help, env!("CARGO_BIN_EXE_my-cli"), "tests/cli/help.toml");
+/// ```
+#[macro_export]
+macro_rules! cli_scenario_test {
+    ($name:ident, $binary:expr, $path:expr) => {
+        #[test]
+        fn $name() {
+            let scenario =
+                $crate::testing::cli::Scenario::load($path).unwrap_or_else(|e| panic!("{e}"));
+            let outcome = $crate::testing::cli::ScenarioRunner::new($binary)
+                .run(&scenario)
+                .unwrap_or_else(|e| panic!("{e}"));
+            assert!(outcome.passed(), "{outcome}");
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn write_scenario(dir: &Path, file: &str, text: &str) -> PathBuf {
+        let path = dir.join(file);
+        std::fs::write(&path, text).unwrap();
+        path
+    }
+
+    test!(test_scenario_parses_with_defaults, {
+        // Arrange
+        let text = "args = [\"--help\"]\n[expect]\nstdout_contains = [\"Usage\"]\n";
+
+        // Act
+        let scenario = Scenario::from_toml(text, Path::new("cases/help.toml")).unwrap();
+
+        // Assert
+        assert_eq!(scenario.name, "help");
+        assert_eq!(scenario.args, vec!["--help"]);
+        assert_eq!(scenario.expect.exit_code, 0);
+        assert_eq!(scenario.base_dir, PathBuf::from("cases"));
+        assert!(scenario.stdin.is_none());
+    });
+
+    test!(test_scenario_rejects_unknown_fields, {
+        // Arrange & Act
+        let result = Scenario::from_toml("argz = []", Path::new("bad.toml"));
+
+        // Assert
+        assert!(matches!(result, Err(ScenarioError::Parse { .. })));
+    });
+
+    #[cfg(unix)]
+    #[test]
+    fn test_runner_reports_each_scenario_by_name() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        write_scenario(
+            dir.path(),
+            "a_echo.toml",
+            "args = [\"-c\", \"echo hello $GREETING\"]\n[env]\nGREETING = \"world\"\n\
+             [expect]\nstdout_contains = [\"hello world\"]\n",
+        );
+        write_scenario(
+            dir.path(),
+            "b_stdin.toml",
+            "args = [\"-c\", \"cat; exit 3\"]\nstdin = \"piped\"\n\
+             [expect]\nexit_code = 3\nstdout_contains = [\"piped\"]\n",
+        );
+        write_scenario(
+            dir.path(),
+            "c_fail.toml",
+            "args = [\"-c\", \"echo oops >&2\"]\n[expect]\nexit_code = 1\nstderr_not_contains = [\"oops\"]\n",
+        );
+
+        // Act
+        let report = ScenarioRunner::new("sh").run_dir(dir.path()).unwrap();
+
+        // Assert
+        assert_eq!(report.outcomes.len(), 3);
+        assert_eq!(report.passed(), 2);
+        let failed = report.get("c_fail").unwrap();
+        assert_eq!(failed.failures.len(), 2);
+        assert!(failed.to_string().contains("scenario c_fail ... FAILED"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_runner_fails_scenarios_that_time_out() {
+        // Arrange
+        let scenario = Scenario::from_toml("args = [\"5\"]", Path::new("slow.toml")).unwrap();
+
+        // Act
+        let outcome = ScenarioRunner::new("sleep")
+            .timeout(std::time::Duration::from_millis(50))
+            .run(&scenario)
+            .unwrap();
+
+        // Assert
+        assert!(!outcome.passed());
+        assert!(outcome.failures[0].starts_with("timed out after"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_runner_blesses_and_compares_golden_files() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_scenario(
+            dir.path(),
+            "golden.toml",
+            "args = [\"-c\", \"echo golden\"]\n[expect]\nstdout_file = \"golden.stdout\"\n",
+        );
+        let scenario = Scenario::load(&path).unwrap();
+
+        // Act
+        let missing = ScenarioRunner::new("sh").bless(false).run(&scenario).unwrap();
+        let blessed = ScenarioRunner::new("sh").bless(true).run(&scenario).unwrap();
+        let compared = ScenarioRunner::new("sh").bless(false).run(&scenario).unwrap();
+
+        // Assert
+        assert!(missing.failures[0].contains("is missing"));
+        assert!(blessed.passed());
+        assert!(compared.passed());
+        assert_eq!(std::fs::read_to_string(dir.path().join("golden.stdout")).unwrap(), "golden\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_runner_redacts_output_before_blessing() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = write_scenario(
+            dir.path(),
+            "login.toml",
+            "args = [\"-c\", \"echo token=abc123\"]\n[expect]\nstdout_contains = [\"token=[TOKEN]\"]\nstdout_file = \"login.stdout\"\n",
+        );
+        let scenario = Scenario::load(&path).unwrap();
+        let rules = RedactionRuleSet::new().field("token", "[TOKEN]");
+
+        // Act
+        let outcome = ScenarioRunner::new("sh").redact(&rules).bless(true).run(&scenario).unwrap();
+
+        // Assert
+        assert!(outcome.passed(), "{outcome}");
+        assert_eq!(outcome.stdout, "token=[TOKEN]\n");
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("login.stdout")).unwrap(),
+            "token=[TOKEN]\n"
+        );
+    }
+}
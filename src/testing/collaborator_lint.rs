@@ -0,0 +1,646 @@
+//! Real-Collaborator Lint
+//!
+//! Chicago TDD tests exercise real collaborators and assert on the state they end up
+//! in; they do not replace collaborators with mocks and verify the calls made to them.
+//! This lint scans a project's test code for the London-school alternative:
+//!
+//! | Rule | Flags |
+//! |------|-------|
+//! | `mocking-crate` | paths into mocking crates (`mockall::`, `mockito::`, `wiremock::`, ...), wiremock types (`MockServer`, `Mock::given`, `ResponseTemplate`), and mocking crates in `Cargo.toml` |
+//! | `mock-definition` | generated mocks (`#[automock]`, `mock! { .. }`) |
+//! | `interaction-verification` | expectations on calls (`.expect_*()`, `.times(n)`, `.returning(..)`, `.withf(..)`, `.checkpoint()`, `.expect(n)`, `.received_requests()`, `.assert_hits(n)`) |
+//!
+//! Test code is every `.rs` file under a `tests`, `benches`, or `examples` directory,
+//! plus, in other files, everything from the first `#[cfg(test)]` on and any
+//! `#[cfg_attr(test, ..)]` line. Comments and string literals are ignored. Like the
+//! [compliance audit](crate::validation::audit), matching is line-based, so the lint
+//! flags likely violations rather than proving their absence.
+//!
+//! # Allowlists
+//!
+//! Violations can be allowed by crate, by path prefix, by rule, or inline with a
+//! `collaborator-lint: allow` comment on the offending line or the line above it.
+//! [`CollaboratorLint::for_project`] reads the allowlists from the `[collaborators]`
+//! section of the project's `chicago-tdd-tools.toml`:
+//!
+//! ```toml
+//! [collaborators]
+//! allow_crates = ["wiremock"]
+//! allow_paths = ["tests/third_party"]
+//! allow_rules = ["mock-definition"]
+//! exclude = ["target", "vendor"]
+//! ```
+//!
+//! # Self-Test
+//!
+//! [`assert_real_collaborators`] runs the lint over a project and fails the calling
+//! test if anything is found, so a suite can police itself:
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::test;
+//! use chicago_tdd_tools::testing::collaborator_lint::assert_real_collaborators;
+//!
+//! test!(test_suite_uses_real_collaborators, {
+//!     // Act & Assert
+//!     assert_real_collaborators(env!("CARGO_MANIFEST_DIR"));
+//! });
+//! ```
+
+use crate::core::failure::{FailureKind, TddFailure};
+use crate::validation::audit::{mask, AUDIT_CONFIG_FILE};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use thiserror::Error;
+
+/// Crates the `mocking-crate` rule reports
+pub const MOCKING_CRATES: [&str; 9] = [
+    "mockall",
+    "mockito",
+    "wiremock",
+    "httpmock",
+    "faux",
+    "mockers",
+    "mry",
+    "unimock",
+    "mocktopus",
+];
+
+/// Inline marker that allows the violations on its line and the line below
+pub const ALLOW_MARKER: &str = "collaborator-lint: allow";
+
+/// Collaborator lint error
+#[derive(Error, Debug)]
+pub enum CollaboratorLintError {
+    /// A project file could not be read
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        /// File or directory that failed
+        path: PathBuf,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// The `[collaborators]` configuration is invalid
+    #[error("Invalid [collaborators] configuration: {0}")]
+    InvalidConfig(String),
+}
+
+/// Result type for collaborator lint operations
+pub type CollaboratorLintResult<T> = Result<T, CollaboratorLintError>;
+
+/// What a violation breaks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum CollaboratorRule {
+    /// Use of (or dependency on) a mocking crate
+    MockingCrate,
+    /// A generated mock type
+    MockDefinition,
+    /// Verification of the calls made to a collaborator
+    InteractionVerification,
+}
+
+impl CollaboratorRule {
+    /// Stable kebab-case name, e.g. `interaction-verification`
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::MockingCrate => "mocking-crate",
+            Self::MockDefinition => "mock-definition",
+            Self::InteractionVerification => "interaction-verification",
+        }
+    }
+}
+
+impl fmt::Display for CollaboratorRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// One use of a mock, located at a line of a project file
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollaboratorViolation {
+    /// File, relative to the project root
+    pub file: PathBuf,
+    /// 1-based line
+    pub line: usize,
+    /// Rule broken
+    pub rule: CollaboratorRule,
+    /// Mocking crate the matched code belongs to
+    #[serde(rename = "crate")]
+    pub crate_name: String,
+    /// Matched code
+    pub matched: String,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl fmt::Display for CollaboratorViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {} [{}]", self.file.display(), self.line, self.message, self.rule)
+    }
+}
+
+/// Result of linting a project
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollaboratorReport {
+    /// Number of files scanned
+    pub files: usize,
+    /// Violations, by file then line
+    pub violations: Vec<CollaboratorViolation>,
+}
+
+impl CollaboratorReport {
+    /// Whether no violations were found
+    #[must_use]
+    pub const fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Render as pretty-printed JSON
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Fail the calling test if any violation was found
+    ///
+    /// # Panics
+    ///
+    /// Raises a [`FailureKind::Constraint`] failure listing the violations.
+    #[track_caller]
+    pub fn assert_clean(&self) {
+        if self.is_clean() {
+            return;
+        }
+        let listing: Vec<String> = self.violations.iter().map(ToString::to_string).collect();
+        TddFailure::new(
+            FailureKind::Constraint,
+            format!(
+                "{} mock use(s) bypass real collaborators:\n{}",
+                self.violations.len(),
+                listing.join("\n")
+            ),
+        )
+        .with_context("files", self.files.to_string())
+        .with_context(
+            "fix",
+            "exercise the real collaborator and assert on its state, or allow the use in [collaborators]",
+        )
+        .raise()
+    }
+}
+
+/// Allowlists and scan settings
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CollaboratorLintConfig {
+    /// Mocking crates whose use is allowed
+    pub allow_crates: Vec<String>,
+    /// Path prefixes, relative to the project root, where mocks are allowed
+    pub allow_paths: Vec<PathBuf>,
+    /// Rules that are not enforced
+    pub allow_rules: Vec<CollaboratorRule>,
+    /// Directory names skipped while scanning (hidden directories are always skipped)
+    pub exclude: Vec<String>,
+}
+
+impl Default for CollaboratorLintConfig {
+    fn default() -> Self {
+        Self {
+            allow_crates: Vec::new(),
+            allow_paths: Vec::new(),
+            allow_rules: Vec::new(),
+            exclude: vec!["target".to_string()],
+        }
+    }
+}
+
+impl CollaboratorLintConfig {
+    /// Parse the `[collaborators]` section of a `chicago-tdd-tools.toml` document
+    ///
+    /// A document without the section yields the defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollaboratorLintError::InvalidConfig`] if the TOML or the section is
+    /// invalid.
+    pub fn from_config_str(text: &str) -> CollaboratorLintResult<Self> {
+        let document: toml::Table =
+            text.parse().map_err(|e| CollaboratorLintError::InvalidConfig(format!("{e}")))?;
+        let Some(section) = document.get("collaborators") else {
+            return Ok(Self::default());
+        };
+        section
+            .clone()
+            .try_into()
+            .map_err(|e| CollaboratorLintError::InvalidConfig(format!("{e}")))
+    }
+
+    /// Load the `[collaborators]` section from `<root>/chicago-tdd-tools.toml`, if present
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollaboratorLintError::Io`] if the file exists but cannot be read, or
+    /// [`CollaboratorLintError::InvalidConfig`] if it is invalid.
+    pub fn from_project(root: impl AsRef<Path>) -> CollaboratorLintResult<Self> {
+        let path = root.as_ref().join(AUDIT_CONFIG_FILE);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(&path)
+            .map_err(|source| CollaboratorLintError::Io { path, source })?;
+        Self::from_config_str(&text)
+    }
+}
+
+/// Scanner for mocks in a project's test code
+#[derive(Debug, Clone, Default)]
+pub struct CollaboratorLint {
+    config: CollaboratorLintConfig,
+}
+
+impl CollaboratorLint {
+    /// Lint with no allowlists
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Lint with the `[collaborators]` section of `<root>/chicago-tdd-tools.toml`
+    ///
+    /// # Errors
+    ///
+    /// Same as [`CollaboratorLintConfig::from_project`].
+    pub fn for_project(root: impl AsRef<Path>) -> CollaboratorLintResult<Self> {
+        Ok(Self { config: CollaboratorLintConfig::from_project(root)? })
+    }
+
+    /// Replace the configuration
+    #[must_use]
+    pub fn with_config(mut self, config: CollaboratorLintConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Allow uses of a mocking crate
+    #[must_use]
+    pub fn allow_crate(mut self, name: impl Into<String>) -> Self {
+        self.config.allow_crates.push(name.into());
+        self
+    }
+
+    /// Allow mocks in files under `prefix` (relative to the project root)
+    #[must_use]
+    pub fn allow_path(mut self, prefix: impl Into<PathBuf>) -> Self {
+        self.config.allow_paths.push(prefix.into());
+        self
+    }
+
+    /// Stop enforcing `rule`
+    #[must_use]
+    pub fn allow_rule(mut self, rule: CollaboratorRule) -> Self {
+        self.config.allow_rules.push(rule);
+        self
+    }
+
+    /// The configuration in effect
+    #[must_use]
+    pub const fn config(&self) -> &CollaboratorLintConfig {
+        &self.config
+    }
+
+    /// Lint one file's contents; `path` (relative to the project root) decides which
+    /// parts are test code and which allowlists apply
+    #[must_use]
+    pub fn lint_source(&self, path: impl AsRef<Path>, text: &str) -> Vec<CollaboratorViolation> {
+        let path = path.as_ref();
+        if self.config.allow_paths.iter().any(|prefix| path.starts_with(prefix)) {
+            return Vec::new();
+        }
+        let candidates = if path.file_name().is_some_and(|name| name == "Cargo.toml") {
+            scan_manifest(path, text)
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            scan_rust(path, text)
+        } else {
+            Vec::new()
+        };
+        candidates
+            .into_iter()
+            .filter(|v| {
+                !self.config.allow_rules.contains(&v.rule)
+                    && !self.config.allow_crates.contains(&v.crate_name)
+            })
+            .collect()
+    }
+
+    /// Lint every `.rs` file and `Cargo.toml` under `root`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CollaboratorLintError::Io`] if the project cannot be read.
+    pub fn lint_project(
+        &self,
+        root: impl AsRef<Path>,
+    ) -> CollaboratorLintResult<CollaboratorReport> {
+        let root = root.as_ref();
+        let mut files = Vec::new();
+        collect_files(root, &self.config.exclude, &mut files)?;
+        files.sort();
+
+        let mut report = CollaboratorReport { files: files.len(), violations: Vec::new() };
+        for path in files {
+            let text = std::fs::read_to_string(&path)
+                .map_err(|source| CollaboratorLintError::Io { path: path.clone(), source })?;
+            let relative = path.strip_prefix(root).map_or_else(|_| path.clone(), Path::to_path_buf);
+            report.violations.extend(self.lint_source(relative, &text));
+        }
+        Ok(report)
+    }
+}
+
+/// Lint the project at `root` (with its `[collaborators]` allowlists) and fail the
+/// calling test if its tests use mocks
+///
+/// # Panics
+///
+/// Raises a [`FailureKind::Constraint`] failure if the project cannot be linted or has
+/// violations.
+#[track_caller]
+pub fn assert_real_collaborators(root: impl AsRef<Path>) {
+    let root = root.as_ref();
+    match CollaboratorLint::for_project(root).and_then(|lint| lint.lint_project(root)) {
+        Ok(report) => report.assert_clean(),
+        Err(error) => TddFailure::new(
+            FailureKind::Constraint,
+            format!("Cannot lint {} for mocks: {error}", root.display()),
+        )
+        .raise(),
+    }
+}
+
+// ============================================================================
+// Scanning
+// ============================================================================
+
+/// A code pattern, the rule it breaks, and the crate it belongs to (`None`: the crate
+/// is the first capture group)
+struct Pattern {
+    rule: CollaboratorRule,
+    crate_name: Option<&'static str>,
+    regex: Regex,
+}
+
+fn pattern(rule: CollaboratorRule, crate_name: Option<&'static str>, regex: &str) -> Pattern {
+    #[allow(clippy::expect_used)] // Constant patterns, exercised by the tests below
+    let regex = Regex::new(regex).expect("valid regex");
+    Pattern { rule, crate_name, regex }
+}
+
+static PATTERNS: LazyLock<Vec<Pattern>> = LazyLock::new(|| {
+    use CollaboratorRule::{InteractionVerification, MockDefinition, MockingCrate};
+    vec![
+        pattern(MockingCrate, None, &format!(r"\b({})::", MOCKING_CRATES.join("|"))),
+        pattern(
+            MockingCrate,
+            Some("wiremock"),
+            r"\b(?:MockServer|ResponseTemplate)\b|\bMock::given\b",
+        ),
+        pattern(MockDefinition, Some("mockall"), r"#\[\s*(?:\w+::)*automock\b|\bmock!\s*[{(]"),
+        pattern(
+            InteractionVerification,
+            Some("mockall"),
+            r"\.(?:expect_\w+|times|returning|return_const|withf|checkpoint)\s*\(",
+        ),
+        pattern(
+            InteractionVerification,
+            Some("wiremock"),
+            r"\.(?:expect\s*\(\s*\d|received_requests\s*\()",
+        ),
+        pattern(InteractionVerification, Some("httpmock"), r"\.assert_hits\s*\("),
+    ]
+});
+
+static MOCK_DEPENDENCY: LazyLock<Regex> = LazyLock::new(|| {
+    #[allow(clippy::expect_used)] // Constant pattern, exercised by the tests below
+    Regex::new(&format!(
+        r"^\s*(?:\[[\w.-]*dependencies\.)?({})\s*(?:=|\])",
+        MOCKING_CRATES.join("|")
+    ))
+    .expect("valid regex")
+});
+
+fn scan_rust(path: &Path, text: &str) -> Vec<CollaboratorViolation> {
+    let masked = mask(text);
+    let test_only = path
+        .components()
+        .any(|c| matches!(c.as_os_str().to_str(), Some("tests" | "benches" | "examples")));
+    let test_start =
+        if test_only { 0 } else { masked.find("#[cfg(test)]").unwrap_or(masked.len()) };
+    let lines: Vec<&str> = text.lines().collect();
+    let allowed = |index: usize| {
+        lines[index].contains(ALLOW_MARKER)
+            || index.checked_sub(1).is_some_and(|above| lines[above].contains(ALLOW_MARKER))
+    };
+
+    let mut violations = Vec::new();
+    let mut offset = 0;
+    for (index, line) in masked.split_inclusive('\n').enumerate() {
+        let start = offset;
+        offset += line.len();
+        if (start < test_start && !line.contains("cfg_attr(test")) || allowed(index) {
+            continue;
+        }
+        // One violation per rule and line, for the first pattern that matches
+        let mut seen = Vec::new();
+        for pattern in PATTERNS.iter() {
+            if seen.contains(&pattern.rule) {
+                continue;
+            }
+            let Some(captures) = pattern.regex.captures(line) else {
+                continue;
+            };
+            seen.push(pattern.rule);
+            let matched = captures[0].trim().to_string();
+            let crate_name =
+                pattern.crate_name.map_or_else(|| captures[1].to_string(), ToString::to_string);
+            let message = match pattern.rule {
+                CollaboratorRule::MockingCrate => {
+                    format!("uses mocking crate `{crate_name}` (`{matched}`)")
+                }
+                CollaboratorRule::MockDefinition => format!("defines a mock (`{matched}`)"),
+                CollaboratorRule::InteractionVerification => format!(
+                    "verifies interactions (`{matched}`) instead of the collaborator's resulting state"
+                ),
+            };
+            violations.push(CollaboratorViolation {
+                file: path.to_path_buf(),
+                line: index + 1,
+                rule: pattern.rule,
+                crate_name,
+                matched,
+                message,
+            });
+        }
+    }
+    violations
+}
+
+fn scan_manifest(path: &Path, text: &str) -> Vec<CollaboratorViolation> {
+    text.lines()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let captures = MOCK_DEPENDENCY.captures(line)?;
+            let crate_name = captures[1].to_string();
+            Some(CollaboratorViolation {
+                file: path.to_path_buf(),
+                line: index + 1,
+                rule: CollaboratorRule::MockingCrate,
+                message: format!("depends on mocking crate `{crate_name}`"),
+                matched: line.trim().to_string(),
+                crate_name,
+            })
+        })
+        .collect()
+}
+
+fn collect_files(
+    dir: &Path,
+    exclude: &[String],
+    files: &mut Vec<PathBuf>,
+) -> CollaboratorLintResult<()> {
+    let io_error = |source| CollaboratorLintError::Io { path: dir.to_path_buf(), source };
+    for entry in std::fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        if path.is_dir() {
+            if !name.starts_with('.') && !exclude.iter().any(|e| e == name) {
+                collect_files(&path, exclude, files)?;
+            }
+        } else if name == "Cargo.toml" || path.extension().is_some_and(|ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::fs;
+
+    const MOCKED_TEST: &str = r#"pub trait Store {
+    fn get(&self, id: u64) -> Option<String>;
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    mockall::mock! { Repo {} }
+
+    #[test]
+    fn test_loads() {
+        // "mockall::" in a string and .times( in a comment are ignored
+        let mut store = MockStore::new();
+        store.expect_get().with(eq(1)).times(1).returning(|_| None);
+        // collaborator-lint: allow
+        store.checkpoint();
+    }
+}
+"#;
+
+    test!(test_flags_mocks_in_test_code, {
+        // Arrange
+        let lint = CollaboratorLint::new();
+
+        // Act
+        let violations = lint.lint_source("src/store.rs", MOCKED_TEST);
+        let production = lint.lint_source("src/store.rs", "use mockall::automock;\n");
+
+        // Assert
+        let found: Vec<(usize, CollaboratorRule, &str)> =
+            violations.iter().map(|v| (v.line, v.rule, v.crate_name.as_str())).collect();
+        assert_eq!(
+            found,
+            [
+                (7, CollaboratorRule::MockingCrate, "mockall"),
+                (9, CollaboratorRule::MockingCrate, "mockall"),
+                (9, CollaboratorRule::MockDefinition, "mockall"),
+                (15, CollaboratorRule::InteractionVerification, "mockall"),
+            ]
+        );
+        assert_eq!(
+            violations[3].to_string(),
+            "src/store.rs:15: verifies interactions (`.expect_get(`) instead of the collaborator's resulting state [interaction-verification]"
+        );
+        assert!(production.is_empty());
+    });
+
+    test!(test_allowlists_and_wiremock, {
+        // Arrange
+        let source = "let server = MockServer::start().await;\nMock::given(method(\"GET\")).expect(1).mount(&server).await;\n";
+        let config = CollaboratorLintConfig::from_config_str(
+            "[collaborators]\nallow_paths = [\"tests/http\"]\nallow_rules = [\"interaction-verification\"]\n",
+        )
+        .unwrap();
+
+        // Act
+        let strict = CollaboratorLint::new().lint_source("tests/api.rs", source);
+        let configured =
+            CollaboratorLint::new().with_config(config).lint_source("tests/api.rs", source);
+        let allowed_path = CollaboratorLint::new()
+            .allow_path("tests/http")
+            .lint_source("tests/http/client.rs", source);
+        let allowed_crate = CollaboratorLint::new()
+            .allow_crate("wiremock")
+            .lint_source("tests/api.rs", source);
+
+        // Assert
+        let rules: Vec<CollaboratorRule> = strict.iter().map(|v| v.rule).collect();
+        assert_eq!(
+            rules,
+            [
+                CollaboratorRule::MockingCrate,
+                CollaboratorRule::MockingCrate,
+                CollaboratorRule::InteractionVerification
+            ]
+        );
+        assert_eq!(configured.len(), 2);
+        assert!(allowed_path.is_empty());
+        assert!(allowed_crate.is_empty());
+        assert!(CollaboratorLintConfig::from_config_str("[collaborators]\nallow = []\n").is_err());
+    });
+
+    test!(test_project_self_test, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("tests")).unwrap();
+        fs::write(dir.path().join("Cargo.toml"), "[dev-dependencies]\nmockito = \"1\"\n").unwrap();
+        fs::write(dir.path().join("tests/api.rs"), "fn main() {}\n").unwrap();
+
+        // Act
+        let report = CollaboratorLint::new().lint_project(dir.path()).unwrap();
+        fs::write(
+            dir.path().join(AUDIT_CONFIG_FILE),
+            "[collaborators]\nallow_crates = [\"mockito\"]\n",
+        )
+        .unwrap();
+        let allowed = TddFailure::catch(|| assert_real_collaborators(dir.path()));
+
+        // Assert
+        assert_eq!(report.files, 2);
+        assert_eq!(report.violations[0].message, "depends on mocking crate `mockito`");
+        assert!(report.to_json().contains("\"crate\": \"mockito\""));
+        let failure = TddFailure::catch(|| report.assert_clean()).unwrap_err();
+        assert_eq!(failure.kind(), FailureKind::Constraint);
+        assert!(failure
+            .message()
+            .starts_with("1 mock use(s) bypass real collaborators:\nCargo.toml:2:"));
+        assert!(allowed.is_ok());
+    });
+}
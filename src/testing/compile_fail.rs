@@ -0,0 +1,448 @@
+//! Compile-Fail Testing
+//!
+//! Many of this crate's guarantees are "this does not compile": `ValidatedRun::<9>`
+//! has no constructor, a typestate machine has no method for an illegal transition, a
+//! failed `const_assert_*!` stops the build. A doc comment saying so proves nothing;
+//! this module checks it. Declare snippets that must fail to compile, with text the
+//! compiler error must contain, and the harness runs each through `rustc` in a scratch
+//! directory and reports which ones compiled or failed for the wrong reason.
+//!
+//! Snippets are compiled like doctests: a snippet without `fn main` is wrapped in
+//! one. [`CompileFailHarness::for_crate`] links them against a crate's library, found
+//! next to the running test binary (`target/<profile>/deps`), so the harness is meant
+//! for integration tests (`tests/*.rs`), where cargo has built that library.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::testing::compile_fail::{CompileFailCase, CompileFailHarness};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! CompileFailHarness::for_crate("chicago_tdd_tools")?
+//!     .case(
+//!         CompileFailCase::new(
+//!             "run_longer_than_max",
+//!             "use chicago_tdd_tools::guards::validated::ValidatedRun;\n\
+//!              let _ = ValidatedRun::<9>::new(vec![0; 9]);",
+//!         )
+//!         .expect_error("AssertRunLen<9>"),
+//!     )
+//!     .run()?
+//!     .assert_passed();
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::core::command::{CheckedCommand, CommandError, DEFAULT_COMMAND_TIMEOUT};
+use crate::core::failure::{FailureKind, TddFailure};
+use crate::core::layout::ProjectLayout;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Compile-fail harness error
+#[derive(Error, Debug)]
+pub enum CompileFailError {
+    /// A scratch file or directory could not be written or read
+    #[error("Failed to access {path}: {source}")]
+    Io {
+        /// File or directory that failed
+        path: PathBuf,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// No compiled library of the crate was found
+    #[error("No compiled library for crate `{name}` in {dir} (build it first, e.g. by running the snippets from an integration test)")]
+    CrateNotFound {
+        /// Crate name
+        name: String,
+        /// Directory searched
+        dir: PathBuf,
+    },
+    /// `rustc` could not be started or did not finish within the timeout
+    #[error("Failed to run rustc: {0}")]
+    Rustc(#[source] CommandError),
+}
+
+/// Result type for compile-fail operations
+pub type CompileFailResult<T> = Result<T, CompileFailError>;
+
+/// A snippet that must not compile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileFailCase {
+    name: String,
+    source: String,
+    expected: Vec<String>,
+}
+
+impl CompileFailCase {
+    /// Declare a snippet; `name` is used for its scratch file and in reports
+    #[must_use]
+    pub fn new(name: impl Into<String>, source: impl Into<String>) -> Self {
+        Self { name: name.into(), source: source.into(), expected: Vec::new() }
+    }
+
+    /// Require the compiler output to contain `text` (an error code such as `E0599`,
+    /// or part of a message)
+    #[must_use]
+    pub fn expect_error(mut self, text: impl Into<String>) -> Self {
+        self.expected.push(text.into());
+        self
+    }
+
+    /// Case name
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Source as compiled: wrapped in `fn main` unless it defines one
+    #[must_use]
+    pub fn program(&self) -> String {
+        if self.source.contains("fn main") {
+            self.source.clone()
+        } else {
+            format!("fn main() {{\n{}\n}}\n", self.source)
+        }
+    }
+}
+
+/// How a case turned out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CompileFailStatus {
+    /// Failed to compile, with every expected text in the output
+    Failed,
+    /// Compiled successfully
+    Compiled,
+    /// Failed to compile, but the output lacks these expected texts
+    WrongError(Vec<String>),
+}
+
+/// Result of compiling one case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompileFailOutcome {
+    /// Case name
+    pub name: String,
+    /// How it turned out
+    pub status: CompileFailStatus,
+    /// Compiler output
+    pub stderr: String,
+}
+
+impl CompileFailOutcome {
+    /// Whether the case failed to compile for the expected reason
+    #[must_use]
+    pub const fn passed(&self) -> bool {
+        matches!(self.status, CompileFailStatus::Failed)
+    }
+}
+
+impl fmt::Display for CompileFailOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.status {
+            CompileFailStatus::Failed => write!(f, "{}: failed to compile as expected", self.name),
+            CompileFailStatus::Compiled => write!(f, "{}: compiled, but must not", self.name),
+            CompileFailStatus::WrongError(missing) => write!(
+                f,
+                "{}: failed to compile, but the error lacks {}\n{}",
+                self.name,
+                missing.iter().map(|text| format!("`{text}`")).collect::<Vec<_>>().join(", "),
+                self.stderr.trim_end()
+            ),
+        }
+    }
+}
+
+/// Outcomes of a harness run, in case order
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CompileFailReport {
+    /// One outcome per case
+    pub outcomes: Vec<CompileFailOutcome>,
+}
+
+impl CompileFailReport {
+    /// Whether every case failed to compile for the expected reason
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.outcomes.iter().all(CompileFailOutcome::passed)
+    }
+
+    /// Fail the calling test unless every case failed to compile for the expected reason
+    ///
+    /// # Panics
+    ///
+    /// Raises a [`FailureKind::Constraint`] failure describing each case that compiled
+    /// or failed with a different error.
+    #[track_caller]
+    pub fn assert_passed(&self) {
+        let failed: Vec<String> = self
+            .outcomes
+            .iter()
+            .filter(|outcome| !outcome.passed())
+            .map(ToString::to_string)
+            .collect();
+        if failed.is_empty() {
+            return;
+        }
+        TddFailure::new(
+            FailureKind::Constraint,
+            format!(
+                "{} of {} compile-fail case(s) did not fail as expected:\n{}",
+                failed.len(),
+                self.outcomes.len(),
+                failed.join("\n")
+            ),
+        )
+        .raise()
+    }
+}
+
+/// Compiles snippets with `rustc` and checks that they fail
+#[derive(Debug, Clone)]
+pub struct CompileFailHarness {
+    cases: Vec<CompileFailCase>,
+    externs: Vec<(String, PathBuf)>,
+    deps_dir: Option<PathBuf>,
+    scratch_dir: PathBuf,
+    edition: String,
+    timeout: Duration,
+}
+
+impl Default for CompileFailHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CompileFailHarness {
+    /// Harness linking no crates but `std`, with scratch files under
+    /// `<artifacts dir>/compile-fail`
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            cases: Vec::new(),
+            externs: Vec::new(),
+            deps_dir: None,
+            scratch_dir: ProjectLayout::detect().artifacts_dir().join("compile-fail"),
+            edition: "2021".to_string(),
+            timeout: DEFAULT_COMMAND_TIMEOUT,
+        }
+    }
+
+    /// Harness linking the compiled library of crate `name` (`-` and `_` are
+    /// interchangeable), found in the running test binary's `deps` directory
+    ///
+    /// When several builds of the library exist, the most recently built is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompileFailError::CrateNotFound`] if no library is found, or
+    /// [`CompileFailError::Io`] if the directory cannot be read.
+    pub fn for_crate(name: &str) -> CompileFailResult<Self> {
+        let name = name.replace('-', "_");
+        let deps_dir = default_deps_dir();
+        let library = newest_library(&deps_dir, &name)?.ok_or_else(|| {
+            CompileFailError::CrateNotFound { name: name.clone(), dir: deps_dir.clone() }
+        })?;
+        Ok(Self::new().with_deps_dir(deps_dir).with_extern(name, library))
+    }
+
+    /// Link a crate from the given library (`--extern name=path`)
+    #[must_use]
+    pub fn with_extern(mut self, name: impl Into<String>, library: impl Into<PathBuf>) -> Self {
+        self.externs.push((name.into(), library.into()));
+        self
+    }
+
+    /// Directory holding the dependencies of the linked crates (`-L dependency=dir`)
+    #[must_use]
+    pub fn with_deps_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.deps_dir = Some(dir.into());
+        self
+    }
+
+    /// Directory for the generated sources and compiler output
+    #[must_use]
+    pub fn with_scratch_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.scratch_dir = dir.into();
+        self
+    }
+
+    /// Rust edition the snippets are compiled with (default `2021`)
+    #[must_use]
+    pub fn with_edition(mut self, edition: impl Into<String>) -> Self {
+        self.edition = edition.into();
+        self
+    }
+
+    /// Deadline for compiling each case (default [`DEFAULT_COMMAND_TIMEOUT`])
+    #[must_use]
+    pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Add a case
+    #[must_use]
+    pub fn case(mut self, case: CompileFailCase) -> Self {
+        self.cases.push(case);
+        self
+    }
+
+    /// Compile every case and report how each turned out
+    ///
+    /// Only type checking runs (`--emit=metadata`), which is enough for type, trait,
+    /// and const-evaluation errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CompileFailError::Io`] if a scratch file cannot be written, or
+    /// [`CompileFailError::Rustc`] if the compiler cannot be started or a case takes
+    /// longer than the timeout to compile.
+    pub fn run(&self) -> CompileFailResult<CompileFailReport> {
+        let io_err = |path: &Path| {
+            let path = path.to_path_buf();
+            move |source| CompileFailError::Io { path, source }
+        };
+        let out_dir = self.scratch_dir.join("out");
+        fs::create_dir_all(&out_dir).map_err(io_err(&out_dir))?;
+        let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+
+        let mut report = CompileFailReport::default();
+        for case in &self.cases {
+            let source = self.scratch_dir.join(format!("{}.rs", case.name));
+            fs::write(&source, case.program()).map_err(io_err(&source))?;
+
+            let mut command = CheckedCommand::new(&rustc)
+                .arg(&source)
+                .args(["--edition", &self.edition, "--crate-type", "bin", "--emit=metadata"])
+                .args(["--color", "never", "--crate-name", "compile_fail_case"])
+                .arg("--out-dir")
+                .arg(&out_dir)
+                .timeout(self.timeout);
+            if let Some(deps_dir) = &self.deps_dir {
+                command = command.arg("-L").arg(format!("dependency={}", deps_dir.display()));
+            }
+            for (name, library) in &self.externs {
+                command = command.arg("--extern").arg(format!("{name}={}", library.display()));
+            }
+            let output = command.output().map_err(CompileFailError::Rustc)?;
+
+            let stderr = output.stderr_lossy();
+            let missing: Vec<String> = case
+                .expected
+                .iter()
+                .filter(|text| !stderr.contains(text.as_str()))
+                .cloned()
+                .collect();
+            let status = if output.status.success() {
+                CompileFailStatus::Compiled
+            } else if missing.is_empty() {
+                CompileFailStatus::Failed
+            } else {
+                CompileFailStatus::WrongError(missing)
+            };
+            report
+                .outcomes
+                .push(CompileFailOutcome { name: case.name.clone(), status, stderr });
+        }
+        Ok(report)
+    }
+}
+
+/// `deps` directory of the running test binary, or `<target>/debug/deps`
+fn default_deps_dir() -> PathBuf {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        .filter(|dir| dir.ends_with("deps"))
+        .unwrap_or_else(|| ProjectLayout::detect().target_dir().join("debug").join("deps"))
+}
+
+/// Most recently modified `lib<name>-*.rlib` in `dir`
+fn newest_library(dir: &Path, name: &str) -> CompileFailResult<Option<PathBuf>> {
+    let io_err = |source| CompileFailError::Io { path: dir.to_path_buf(), source };
+    let prefix = format!("lib{name}-");
+    let mut newest: Option<(std::time::SystemTime, PathBuf)> = None;
+    for entry in fs::read_dir(dir).map_err(io_err)? {
+        let entry = entry.map_err(io_err)?;
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        if !file_name.starts_with(&prefix) || !file_name.ends_with(".rlib") {
+            continue;
+        }
+        let modified = entry.metadata().and_then(|m| m.modified()).map_err(io_err)?;
+        if newest.as_ref().is_none_or(|(time, _)| modified > *time) {
+            newest = Some((modified, entry.path()));
+        }
+    }
+    Ok(newest.map(|(_, path)| path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_reports_how_each_case_turned_out, {
+        // Arrange
+        let scratch = tempfile::tempdir().unwrap();
+        let harness = CompileFailHarness::new()
+            .with_scratch_dir(scratch.path())
+            .case(
+                CompileFailCase::new("mismatched_types", "let count: u32 = \"three\";")
+                    .expect_error("E0308"),
+            )
+            .case(CompileFailCase::new("compiles", "let count: u32 = 3;\nlet _ = count;"))
+            .case(
+                CompileFailCase::new("wrong_error", "let count: u32 = missing;")
+                    .expect_error("E0308"),
+            );
+
+        // Act
+        let report = harness.run().unwrap();
+
+        // Assert
+        let statuses: Vec<&CompileFailStatus> = report.outcomes.iter().map(|o| &o.status).collect();
+        assert_eq!(
+            statuses,
+            [
+                &CompileFailStatus::Failed,
+                &CompileFailStatus::Compiled,
+                &CompileFailStatus::WrongError(vec!["E0308".to_string()])
+            ]
+        );
+        assert!(report.outcomes[2].stderr.contains("E0425"));
+        let failure = TddFailure::catch(|| report.assert_passed()).unwrap_err();
+        assert!(failure
+            .message()
+            .starts_with("2 of 3 compile-fail case(s) did not fail as expected:\ncompiles: compiled, but must not\nwrong_error:"));
+    });
+
+    test!(test_slow_compile_times_out, {
+        // Arrange
+        let scratch = tempfile::tempdir().unwrap();
+        let harness = CompileFailHarness::new()
+            .with_scratch_dir(scratch.path())
+            .with_timeout(Duration::from_millis(1))
+            .case(CompileFailCase::new("slow", "let count: u32 = \"three\";"));
+
+        // Act
+        let result = harness.run();
+
+        // Assert
+        assert!(matches!(result, Err(CompileFailError::Rustc(CommandError::TimedOut { .. }))));
+    });
+
+    test!(test_case_wraps_statements_in_main, {
+        // Arrange
+        let statements = CompileFailCase::new("statements", "let x = 1;");
+        let program = CompileFailCase::new("program", "fn main() {}");
+
+        // Assert
+        assert_eq!(statements.program(), "fn main() {\nlet x = 1;\n}\n");
+        assert_eq!(program.program(), "fn main() {}");
+    });
+}
@@ -10,6 +10,63 @@
 //! - **State-Based Testing**: Verifies concurrent state transitions
 //! - **Behavior Verification**: Tests what concurrent code does under all interleavings
 //! - **AAA Pattern**: Arrange (setup threads), Act (execute concurrently), Assert (verify state)
+//!
+//! # Linearizability
+//!
+//! Loom explores interleavings of a model; [`LinearizabilityChecker`] checks what real threads
+//! actually did. Each thread records the invocation and return of every operation it performs
+//! on the structure under test, and the resulting history is checked against a
+//! [`SequentialModel`] with the Wing-Gong search (with Lowe's memoization of visited
+//! linearized-set/state pairs). A history is linearizable when every operation can be given
+//! a single instant between its invocation and return such that the sequential model,
+//! applied in that order, produces the observed return values.
+//!
+//! ```rust
+//! use chicago_tdd_tools::concurrency::{LinearizabilityChecker, SequentialModel};
+//! use std::sync::atomic::{AtomicU64, Ordering};
+//! use std::sync::Arc;
+//!
+//! /// A counter whose `Add` returns the previous value
+//! struct Counter;
+//!
+//! impl SequentialModel for Counter {
+//!     type State = u64;
+//!     type Op = u64;
+//!     type Ret = u64;
+//!
+//!     fn init(&self) -> u64 {
+//!         0
+//!     }
+//!
+//!     fn step(&self, state: &u64, op: &u64) -> (u64, u64) {
+//!         (state + op, *state)
+//!     }
+//! }
+//!
+//! let checker = LinearizabilityChecker::new(Counter);
+//! let counter = Arc::new(AtomicU64::new(0));
+//! let handles: Vec<_> = (0..4)
+//!     .map(|_| {
+//!         let recorder = checker.recorder();
+//!         let counter = Arc::clone(&counter);
+//!         std::thread::spawn(move || {
+//!             for _ in 0..10 {
+//!                 recorder.record(1, || counter.fetch_add(1, Ordering::SeqCst));
+//!             }
+//!         })
+//!     })
+//!     .collect();
+//! for handle in handles {
+//!     handle.join().unwrap();
+//! }
+//! checker.check().assert_linearizable();
+//! ```
+
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 /// Concurrency test helper for Chicago TDD
 ///
@@ -86,6 +143,353 @@ impl ConcurrencyTest {
     }
 }
 
+/// Sequential specification a concurrent history is checked against
+///
+/// `step` must be deterministic: the checker replays operations in many candidate orders.
+pub trait SequentialModel {
+    /// Abstract state of the structure (memoized, so it must be hashable)
+    type State: Clone + Eq + Hash + fmt::Debug;
+    /// An operation invoked on the structure
+    type Op: Clone + fmt::Debug;
+    /// The value an operation returns
+    type Ret: Clone + PartialEq + fmt::Debug;
+
+    /// State before any operation
+    fn init(&self) -> Self::State;
+
+    /// Apply `op` to `state`, returning the next state and the value `op` must return
+    fn step(&self, state: &Self::State, op: &Self::Op) -> (Self::State, Self::Ret);
+}
+
+/// One operation in a recorded history
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoryOperation<Op, Ret> {
+    /// Operation id, in invocation order
+    pub id: usize,
+    /// Recorder (thread) that performed the operation
+    pub thread: usize,
+    /// The operation
+    pub op: Op,
+    /// Returned value (`None` = never returned, e.g. the thread panicked)
+    pub ret: Option<Ret>,
+    /// Position of the invocation in the global event order
+    pub invoked_at: usize,
+    /// Position of the return in the global event order
+    pub returned_at: Option<usize>,
+}
+
+impl<Op: fmt::Debug, Ret: fmt::Debug> fmt::Display for HistoryOperation<Op, Ret> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{} [thread {}] {:?}", self.id, self.thread, self.op)?;
+        match (&self.ret, self.returned_at) {
+            (Some(ret), Some(returned_at)) => {
+                write!(f, " -> {ret:?} (events {}..{returned_at})", self.invoked_at)
+            }
+            _ => write!(f, " -> pending (event {}..)", self.invoked_at),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct History<Op, Ret> {
+    operations: Vec<HistoryOperation<Op, Ret>>,
+    events: usize,
+}
+
+type SharedHistory<Op, Ret> = Arc<Mutex<History<Op, Ret>>>;
+
+fn lock<Op, Ret>(history: &SharedHistory<Op, Ret>) -> MutexGuard<'_, History<Op, Ret>> {
+    // A panicking recorder thread leaves its call pending, which the checker handles
+    history.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Per-thread handle that records invocations and returns
+///
+/// Obtain one per thread from [`LinearizabilityChecker::recorder`].
+#[derive(Debug)]
+pub struct HistoryRecorder<Op, Ret> {
+    thread: usize,
+    history: SharedHistory<Op, Ret>,
+}
+
+impl<Op, Ret> HistoryRecorder<Op, Ret> {
+    /// Thread number assigned to this recorder
+    #[must_use]
+    pub const fn thread(&self) -> usize {
+        self.thread
+    }
+
+    /// Record the invocation of `op`; complete the returned call with its result
+    ///
+    /// Invoke immediately before calling into the structure under test, and complete
+    /// immediately after it returns, so the recorded interval covers the real call.
+    #[must_use = "complete the call with the value the operation returned"]
+    pub fn invoke(&self, op: Op) -> PendingCall<Op, Ret> {
+        let mut history = lock(&self.history);
+        let id = history.operations.len();
+        let invoked_at = history.events;
+        history.events += 1;
+        history.operations.push(HistoryOperation {
+            id,
+            thread: self.thread,
+            op,
+            ret: None,
+            invoked_at,
+            returned_at: None,
+        });
+        drop(history);
+        PendingCall { id, history: Arc::clone(&self.history) }
+    }
+
+    /// Record `op` around `call`, returning what `call` returned
+    pub fn record<F>(&self, op: Op, call: F) -> Ret
+    where
+        F: FnOnce() -> Ret,
+        Ret: Clone,
+    {
+        let pending = self.invoke(op);
+        let ret = call();
+        pending.complete(ret.clone());
+        ret
+    }
+}
+
+/// An invoked operation that has not returned yet
+///
+/// Dropping it without [`complete`](Self::complete) leaves the operation pending: it may or
+/// may not have taken effect, and its return value is not checked.
+#[derive(Debug)]
+pub struct PendingCall<Op, Ret> {
+    id: usize,
+    history: SharedHistory<Op, Ret>,
+}
+
+impl<Op, Ret> PendingCall<Op, Ret> {
+    /// Record that the operation returned `ret`
+    pub fn complete(self, ret: Ret) {
+        let mut history = lock(&self.history);
+        let returned_at = history.events;
+        history.events += 1;
+        if let Some(operation) = history.operations.get_mut(self.id) {
+            operation.ret = Some(ret);
+            operation.returned_at = Some(returned_at);
+        }
+    }
+}
+
+/// Where the linearization search got deepest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NonLinearizableWindow {
+    /// Longest order of operation ids the model accepted
+    pub prefix: Vec<usize>,
+    /// Model state after `prefix`, rendered with `Debug`
+    pub state: String,
+    /// Ids of the operations that could take effect next, none of which the model accepts
+    pub window: Vec<usize>,
+}
+
+/// Outcome of a linearizability check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearizabilityReport<Op, Ret> {
+    /// The checked history, indexed by operation id
+    pub history: Vec<HistoryOperation<Op, Ret>>,
+    /// A valid linearization (operation ids in effect order), if one exists
+    pub linearization: Option<Vec<usize>>,
+    /// The non-linearizable window, if no linearization exists
+    pub violation: Option<NonLinearizableWindow>,
+}
+
+impl<Op: fmt::Debug, Ret: fmt::Debug> LinearizabilityReport<Op, Ret> {
+    /// Whether the history is linearizable
+    #[must_use]
+    pub const fn is_linearizable(&self) -> bool {
+        self.violation.is_none()
+    }
+
+    /// Assert that the history is linearizable
+    ///
+    /// # Panics
+    ///
+    /// Panics with the longest linearizable prefix and the window that could not extend it.
+    pub fn assert_linearizable(&self) {
+        assert!(self.is_linearizable(), "{self}");
+    }
+
+    fn write_operations(&self, f: &mut fmt::Formatter<'_>, ids: &[usize]) -> fmt::Result {
+        for operation in ids.iter().filter_map(|id| self.history.get(*id)) {
+            writeln!(f, "     - {operation}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<Op: fmt::Debug, Ret: fmt::Debug> fmt::Display for LinearizabilityReport<Op, Ret> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(violation) = &self.violation else {
+            return write!(f, "✅ {} operations, linearizable", self.history.len());
+        };
+        writeln!(f, "🚨 history of {} operations is not linearizable", self.history.len())?;
+        writeln!(
+            f,
+            "   longest linearizable prefix ({} operations), model state {}:",
+            violation.prefix.len(),
+            violation.state
+        )?;
+        self.write_operations(f, &violation.prefix)?;
+        writeln!(f, "   no operation in this window can take effect next:")?;
+        self.write_operations(f, &violation.window)
+    }
+}
+
+/// Records concurrent histories and checks them for linearizability
+///
+/// Hand one [`HistoryRecorder`] to each thread, run the threads, then call
+/// [`check`](Self::check).
+#[derive(Debug)]
+pub struct LinearizabilityChecker<M: SequentialModel> {
+    model: M,
+    history: SharedHistory<M::Op, M::Ret>,
+    threads: AtomicUsize,
+}
+
+impl<M: SequentialModel> LinearizabilityChecker<M> {
+    /// Create a checker with an empty history
+    pub fn new(model: M) -> Self {
+        Self {
+            model,
+            history: Arc::new(Mutex::new(History { operations: Vec::new(), events: 0 })),
+            threads: AtomicUsize::new(0),
+        }
+    }
+
+    /// A recorder for one thread; each call assigns the next thread number
+    pub fn recorder(&self) -> HistoryRecorder<M::Op, M::Ret> {
+        HistoryRecorder {
+            thread: self.threads.fetch_add(1, Ordering::Relaxed),
+            history: Arc::clone(&self.history),
+        }
+    }
+
+    /// Snapshot of the history recorded so far
+    pub fn history(&self) -> Vec<HistoryOperation<M::Op, M::Ret>> {
+        lock(&self.history).operations.clone()
+    }
+
+    /// Check the recorded history against the model
+    ///
+    /// Operations that never returned may take effect at any point after their invocation,
+    /// or not at all.
+    pub fn check(&self) -> LinearizabilityReport<M::Op, M::Ret> {
+        let history = self.history();
+        let (linearization, violation) = match self.search(&history) {
+            Ok(order) => (Some(order), None),
+            Err(window) => (None, Some(window)),
+        };
+        LinearizabilityReport { history, linearization, violation }
+    }
+
+    /// Wing-Gong depth-first search over linearization orders
+    ///
+    /// Each frame holds the model state after the current prefix and the operations that
+    /// may take effect next. `(linearized set, state)` pairs already explored are skipped.
+    fn search(
+        &self,
+        history: &[HistoryOperation<M::Op, M::Ret>],
+    ) -> Result<Vec<usize>, NonLinearizableWindow> {
+        let mut linearized = Linearized::new(history.len());
+        if linearized.covers_completed(history) {
+            return Ok(Vec::new());
+        }
+        let mut seen: HashSet<(Vec<u64>, M::State)> = HashSet::new();
+        let mut path: Vec<usize> = Vec::new();
+        let initial = self.model.init();
+        let candidates = linearized.candidates(history);
+        let mut deepest = NonLinearizableWindow {
+            prefix: Vec::new(),
+            state: format!("{initial:?}"),
+            window: candidates.clone(),
+        };
+        let mut stack = vec![Frame { state: initial, candidates, next: 0 }];
+
+        while let Some(frame) = stack.last_mut() {
+            let Some(&id) = frame.candidates.get(frame.next) else {
+                stack.pop();
+                if let Some(id) = path.pop() {
+                    linearized.clear(id);
+                }
+                continue;
+            };
+            frame.next += 1;
+            let (state, ret) = self.model.step(&frame.state, &history[id].op);
+            if history[id].ret.as_ref().is_some_and(|expected| *expected != ret) {
+                continue;
+            }
+            linearized.set(id);
+            if !seen.insert((linearized.bits.clone(), state.clone())) {
+                linearized.clear(id);
+                continue;
+            }
+            path.push(id);
+            if linearized.covers_completed(history) {
+                return Ok(path);
+            }
+            let candidates = linearized.candidates(history);
+            if path.len() > deepest.prefix.len() {
+                deepest = NonLinearizableWindow {
+                    prefix: path.clone(),
+                    state: format!("{state:?}"),
+                    window: candidates.clone(),
+                };
+            }
+            stack.push(Frame { state, candidates, next: 0 });
+        }
+        Err(deepest)
+    }
+}
+
+struct Frame<S> {
+    state: S,
+    candidates: Vec<usize>,
+    next: usize,
+}
+
+/// Bitset of operation ids already placed in the linearization
+struct Linearized {
+    bits: Vec<u64>,
+}
+
+impl Linearized {
+    fn new(operations: usize) -> Self {
+        Self { bits: vec![0; operations.div_ceil(64)] }
+    }
+
+    fn contains(&self, id: usize) -> bool {
+        self.bits[id / 64] & (1 << (id % 64)) != 0
+    }
+
+    fn set(&mut self, id: usize) {
+        self.bits[id / 64] |= 1 << (id % 64);
+    }
+
+    fn clear(&mut self, id: usize) {
+        self.bits[id / 64] &= !(1 << (id % 64));
+    }
+
+    /// Whether every operation that returned has been linearized
+    fn covers_completed<Op, Ret>(&self, history: &[HistoryOperation<Op, Ret>]) -> bool {
+        history.iter().all(|op| op.returned_at.is_none() || self.contains(op.id))
+    }
+
+    /// Remaining operations invoked before the earliest remaining return
+    ///
+    /// Anything invoked later must take effect after that return, so it cannot be next.
+    fn candidates<Op, Ret>(&self, history: &[HistoryOperation<Op, Ret>]) -> Vec<usize> {
+        let remaining = || history.iter().filter(|op| !self.contains(op.id));
+        let deadline = remaining().filter_map(|op| op.returned_at).min().unwrap_or(usize::MAX);
+        remaining().filter(|op| op.invoked_at < deadline).map(|op| op.id).collect()
+    }
+}
+
 #[cfg(feature = "concurrency-testing")]
 #[cfg(test)]
 #[allow(clippy::panic)] // Test code - panic is appropriate for test failures
@@ -123,4 +527,115 @@ mod tests {
             vec.lock().unwrap().push(2);
         });
     }
+
+    /// Read/write register: `Some(v)` writes, `None` reads
+    struct Register;
+
+    impl SequentialModel for Register {
+        type State = u32;
+        type Op = Option<u32>;
+        type Ret = u32;
+
+        fn init(&self) -> u32 {
+            0
+        }
+
+        fn step(&self, state: &u32, op: &Option<u32>) -> (u32, u32) {
+            op.map_or((*state, *state), |value| (value, value))
+        }
+    }
+
+    #[test]
+    fn test_overlapping_read_may_observe_write() {
+        // Arrange: read overlaps a write and observes its value
+        let checker = LinearizabilityChecker::new(Register);
+        let (writer, reader) = (checker.recorder(), checker.recorder());
+        let write = writer.invoke(Some(1));
+        let read = reader.invoke(None);
+        read.complete(1);
+        write.complete(1);
+
+        // Act
+        let report = checker.check();
+
+        // Assert
+        report.assert_linearizable();
+        assert_eq!(report.linearization, Some(vec![0, 1]));
+    }
+
+    #[test]
+    fn test_stale_read_reports_non_linearizable_window() {
+        // Arrange: a read that starts after a write returned still sees the old value
+        let checker = LinearizabilityChecker::new(Register);
+        let (writer, reader) = (checker.recorder(), checker.recorder());
+        writer.record(Some(1), || 1);
+        reader.record(None, || 0);
+        let pending = writer.invoke(Some(2));
+        drop(pending);
+
+        // Act
+        let report = checker.check();
+
+        // Assert
+        assert!(!report.is_linearizable());
+        let violation = report.violation.clone().unwrap();
+        assert_eq!(violation.prefix, vec![0]);
+        assert_eq!(violation.state, "1");
+        assert_eq!(violation.window, vec![1]);
+        let rendered = report.to_string();
+        assert!(rendered.contains("#1 [thread 1] None -> 0"), "{rendered}");
+    }
+
+    #[test]
+    fn test_pending_operation_may_take_effect() {
+        // Arrange: a write whose thread never recorded the return, seen by a later read
+        let checker = LinearizabilityChecker::new(Register);
+        let (writer, reader) = (checker.recorder(), checker.recorder());
+        let pending = writer.invoke(Some(7));
+        reader.record(None, || 7);
+        drop(pending);
+
+        // Act
+        let report = checker.check();
+
+        // Assert
+        report.assert_linearizable();
+        assert_eq!(report.linearization, Some(vec![0, 1]));
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)] // Test code - joining test threads should not fail
+    fn test_real_threads_on_locked_register_are_linearizable() {
+        // Arrange
+        let checker = LinearizabilityChecker::new(Register);
+        let register = std::sync::Arc::new(std::sync::Mutex::new(0_u32));
+
+        // Act
+        let handles: Vec<_> = (0..4_u32)
+            .map(|thread| {
+                let recorder = checker.recorder();
+                let register = std::sync::Arc::clone(&register);
+                std::thread::spawn(move || {
+                    for i in 0..8 {
+                        let op = (i % 2 == 0).then_some(thread * 100 + i);
+                        recorder.record(op, || {
+                            let mut value = register.lock().unwrap();
+                            if let Some(write) = op {
+                                *value = write;
+                            }
+                            *value
+                        });
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // Assert
+        let report = checker.check();
+        report.assert_linearizable();
+        assert_eq!(report.history.len(), 32);
+    }
 }
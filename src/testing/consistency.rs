@@ -0,0 +1,623 @@
+//! Consistency Checking for Cache + Store Layers
+//!
+//! Drives reads and writes against real collaborators (e.g. a Redis cache in front of
+//! Postgres) according to generated schedules, then checks the observed history against
+//! consistency models:
+//!
+//! - [`ConsistencyModel::ReadYourWrites`]: a session always sees its own latest write
+//! - [`ConsistencyModel::MonotonicReads`]: a session never sees a value older than one it
+//!   already read
+//! - [`ConsistencyModel::Strong`]: every read returns the globally latest write
+//!
+//! Each collaborator access path is a [`ConsistencyLayer`]; schedules choose which layer
+//! each operation goes through, so "write to the DB, read through the cache" interleavings
+//! (the classic missed-invalidation bug) are exercised systematically.
+//!
+//! Written values are versions: every write in a schedule stores a value larger than all
+//! earlier writes, so "older" is a plain numeric comparison.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::testing::consistency::{
+//!     ConsistencyChecker, ConsistencyLayer, ConsistencyModel, ConsistencyScheduleConfig,
+//! };
+//! use std::cell::RefCell;
+//! use std::collections::HashMap;
+//! use std::rc::Rc;
+//!
+//! type Store = Rc<RefCell<HashMap<String, u64>>>;
+//!
+//! /// Direct access to the backing store
+//! struct Db(Store);
+//!
+//! impl ConsistencyLayer for Db {
+//!     fn write(&mut self, key: &str, value: u64) -> Result<(), String> {
+//!         self.0.borrow_mut().insert(key.to_string(), value);
+//!         Ok(())
+//!     }
+//!     fn read(&mut self, key: &str) -> Result<Option<u64>, String> {
+//!         Ok(self.0.borrow().get(key).copied())
+//!     }
+//! }
+//!
+//! let store = Store::default();
+//! let report = ConsistencyChecker::new()
+//!     .layer("db", Db(store.clone()))
+//!     .model(ConsistencyModel::ReadYourWrites)
+//!     .model(ConsistencyModel::MonotonicReads)
+//!     .check_generated(&ConsistencyScheduleConfig::new(["db"], ["db"]), 42, 20)
+//!     .unwrap();
+//! report.assert_consistent();
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use thiserror::Error;
+
+/// Consistency checker errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConsistencyError {
+    /// A schedule references a layer that was not registered
+    #[error("🚨 Unknown consistency layer '{0}'")]
+    UnknownLayer(String),
+    /// A collaborator call failed
+    #[error("🚨 Layer '{layer}' failed at step {step} ({operation}): {message}")]
+    Layer {
+        /// Layer name
+        layer: String,
+        /// Index in the schedule
+        step: usize,
+        /// Rendered operation
+        operation: String,
+        /// Collaborator error
+        message: String,
+    },
+}
+
+/// Result type for consistency checks
+pub type ConsistencyResult<T> = Result<T, ConsistencyError>;
+
+/// One access path to the system under test (cache, database, service API, ...)
+pub trait ConsistencyLayer {
+    /// Store `value` under `key` through this layer
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the collaborator failure.
+    fn write(&mut self, key: &str, value: u64) -> Result<(), String>;
+
+    /// Read `key` through this layer
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of the collaborator failure.
+    fn read(&mut self, key: &str) -> Result<Option<u64>, String>;
+}
+
+/// Consistency guarantee to check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ConsistencyModel {
+    /// Reads reflect the reading session's own latest write to the key
+    ReadYourWrites,
+    /// A session's reads of a key never go backwards
+    MonotonicReads,
+    /// Every read returns the latest write to the key by any session
+    Strong,
+}
+
+impl fmt::Display for ConsistencyModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::ReadYourWrites => "read-your-writes",
+            Self::MonotonicReads => "monotonic reads",
+            Self::Strong => "strong consistency",
+        })
+    }
+}
+
+/// What an operation does
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsistencyAction {
+    /// Write this version
+    Write(u64),
+    /// Read the current version
+    Read,
+}
+
+/// One scheduled step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsistencyOperation {
+    /// Logical client performing the step
+    pub session: usize,
+    /// Layer the step goes through
+    pub layer: String,
+    /// Key accessed
+    pub key: String,
+    /// Read or write
+    pub action: ConsistencyAction,
+}
+
+impl fmt::Display for ConsistencyOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.action {
+            ConsistencyAction::Write(value) => {
+                write!(f, "s{} write {}={value} via {}", self.session, self.key, self.layer)
+            }
+            ConsistencyAction::Read => {
+                write!(f, "s{} read {} via {}", self.session, self.key, self.layer)
+            }
+        }
+    }
+}
+
+/// Shape of generated schedules
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsistencyScheduleConfig {
+    /// Number of concurrent logical sessions
+    pub sessions: usize,
+    /// Number of distinct keys
+    pub keys: usize,
+    /// Operations per schedule
+    pub operations: usize,
+    /// Percentage of operations that are writes (0-100)
+    pub write_percent: u8,
+    /// Layers writes may go through
+    pub write_layers: Vec<String>,
+    /// Layers reads may go through
+    pub read_layers: Vec<String>,
+}
+
+impl ConsistencyScheduleConfig {
+    /// Two sessions, two keys, 24 operations, 40% writes
+    pub fn new<W, R>(write_layers: W, read_layers: R) -> Self
+    where
+        W: IntoIterator,
+        W::Item: Into<String>,
+        R: IntoIterator,
+        R::Item: Into<String>,
+    {
+        Self {
+            sessions: 2,
+            keys: 2,
+            operations: 24,
+            write_percent: 40,
+            write_layers: write_layers.into_iter().map(Into::into).collect(),
+            read_layers: read_layers.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// A sequence of operations
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ConsistencySchedule {
+    /// Operations in execution order
+    pub operations: Vec<ConsistencyOperation>,
+}
+
+impl ConsistencySchedule {
+    /// Generate a schedule; keys are prefixed with `key_prefix` so cases don't collide
+    ///
+    /// Equal seeds produce equal schedules.
+    #[must_use]
+    pub fn generate(config: &ConsistencyScheduleConfig, seed: u64, key_prefix: &str) -> Self {
+        let mut rng = SplitMix64(seed);
+        let pick = |rng: &mut SplitMix64, n: usize| {
+            usize::try_from(rng.next() % (n.max(1) as u64)).unwrap_or(0)
+        };
+        let mut version = 0;
+        let operations = (0..config.operations)
+            .filter_map(|_| {
+                let session = pick(&mut rng, config.sessions);
+                let key = format!("{key_prefix}k{}", pick(&mut rng, config.keys));
+                let write = rng.next() % 100 < u64::from(config.write_percent);
+                let (layers, action) = if write {
+                    version += 1;
+                    (&config.write_layers, ConsistencyAction::Write(version))
+                } else {
+                    (&config.read_layers, ConsistencyAction::Read)
+                };
+                let layer = layers.get(pick(&mut rng, layers.len()))?.clone();
+                Some(ConsistencyOperation { session, layer, key, action })
+            })
+            .collect();
+        Self { operations }
+    }
+}
+
+impl fmt::Display for ConsistencySchedule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (step, operation) in self.operations.iter().enumerate() {
+            writeln!(f, "   {step:>3}: {operation}")?;
+        }
+        Ok(())
+    }
+}
+
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    const fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// A read that broke a consistency model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsistencyViolation {
+    /// Index of the offending read in the schedule
+    pub step: usize,
+    /// Model that was broken
+    pub model: ConsistencyModel,
+    /// The read operation
+    pub operation: ConsistencyOperation,
+    /// Oldest version the model allowed
+    pub expected_at_least: u64,
+    /// Version actually read (`None` = key missing)
+    pub observed: Option<u64>,
+}
+
+impl fmt::Display for ConsistencyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let observed = self.observed.map_or_else(|| "nothing".to_string(), |v| v.to_string());
+        write!(
+            f,
+            "step {}: {} violates {}: read {observed}, expected at least {}",
+            self.step, self.operation, self.model, self.expected_at_least
+        )
+    }
+}
+
+/// Outcome of running a schedule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConsistencyReport {
+    /// Seed that generated the schedule, if generated
+    pub seed: Option<u64>,
+    /// The executed schedule
+    pub schedule: ConsistencySchedule,
+    /// Value returned by each read, by step
+    pub observations: Vec<(usize, Option<u64>)>,
+    /// Model violations, in step order
+    pub violations: Vec<ConsistencyViolation>,
+}
+
+impl ConsistencyReport {
+    /// Whether no model was violated
+    #[must_use]
+    pub const fn is_consistent(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    /// Assert that no model was violated
+    ///
+    /// # Panics
+    ///
+    /// Panics with the violations, the seed, and the schedule.
+    pub fn assert_consistent(&self) {
+        assert!(self.is_consistent(), "{self}");
+    }
+}
+
+impl fmt::Display for ConsistencyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_consistent() {
+            return write!(f, "✅ {} operations, no violations", self.schedule.operations.len());
+        }
+        writeln!(f, "🚨 {} consistency violation(s):", self.violations.len())?;
+        for violation in &self.violations {
+            writeln!(f, "   - {violation}")?;
+        }
+        if let Some(seed) = self.seed {
+            writeln!(f, "   seed: {seed}")?;
+        }
+        write!(f, "   schedule:\n{}", self.schedule)
+    }
+}
+
+/// Runs schedules against registered layers and checks consistency models
+#[derive(Default)]
+pub struct ConsistencyChecker {
+    layers: HashMap<String, Box<dyn ConsistencyLayer>>,
+    models: Vec<ConsistencyModel>,
+}
+
+impl fmt::Debug for ConsistencyChecker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut layers: Vec<&String> = self.layers.keys().collect();
+        layers.sort();
+        f.debug_struct("ConsistencyChecker")
+            .field("layers", &layers)
+            .field("models", &self.models)
+            .finish()
+    }
+}
+
+impl ConsistencyChecker {
+    /// Create a checker with no layers and no models
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register an access path under `name`
+    #[must_use]
+    pub fn layer(
+        mut self,
+        name: impl Into<String>,
+        layer: impl ConsistencyLayer + 'static,
+    ) -> Self {
+        self.layers.insert(name.into(), Box::new(layer));
+        self
+    }
+
+    /// Add a model to check
+    #[must_use]
+    pub fn model(mut self, model: ConsistencyModel) -> Self {
+        if !self.models.contains(&model) {
+            self.models.push(model);
+        }
+        self
+    }
+
+    /// Execute a schedule and check the history
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConsistencyError::UnknownLayer`] or [`ConsistencyError::Layer`]; model
+    /// violations are reported in the [`ConsistencyReport`], not as errors.
+    pub fn run(&mut self, schedule: &ConsistencySchedule) -> ConsistencyResult<ConsistencyReport> {
+        let mut latest: HashMap<&str, u64> = HashMap::new();
+        let mut written: HashMap<(usize, &str), u64> = HashMap::new();
+        let mut read: HashMap<(usize, &str), u64> = HashMap::new();
+        let mut observations = Vec::new();
+        let mut violations = Vec::new();
+
+        for (step, operation) in schedule.operations.iter().enumerate() {
+            let layer = self
+                .layers
+                .get_mut(&operation.layer)
+                .ok_or_else(|| ConsistencyError::UnknownLayer(operation.layer.clone()))?;
+            let layer_error = |message| ConsistencyError::Layer {
+                layer: operation.layer.clone(),
+                step,
+                operation: operation.to_string(),
+                message,
+            };
+            let key = operation.key.as_str();
+            let slot = (operation.session, key);
+            match operation.action {
+                ConsistencyAction::Write(value) => {
+                    layer.write(key, value).map_err(layer_error)?;
+                    latest.insert(key, value);
+                    written.insert(slot, value);
+                }
+                ConsistencyAction::Read => {
+                    let observed = layer.read(key).map_err(layer_error)?;
+                    observations.push((step, observed));
+                    for model in &self.models {
+                        let floor = match model {
+                            ConsistencyModel::ReadYourWrites => written.get(&slot),
+                            ConsistencyModel::MonotonicReads => read.get(&slot),
+                            ConsistencyModel::Strong => latest.get(key),
+                        };
+                        if let Some(&floor) = floor {
+                            if observed.is_none_or(|value| value < floor) {
+                                violations.push(ConsistencyViolation {
+                                    step,
+                                    model: *model,
+                                    operation: operation.clone(),
+                                    expected_at_least: floor,
+                                    observed,
+                                });
+                            }
+                        }
+                    }
+                    if let Some(value) = observed {
+                        let seen = read.entry(slot).or_insert(value);
+                        *seen = (*seen).max(value);
+                    }
+                }
+            }
+        }
+        Ok(ConsistencyReport { seed: None, schedule: schedule.clone(), observations, violations })
+    }
+
+    /// Run `cases` generated schedules, stopping at the first inconsistent one
+    ///
+    /// Case `i` uses seed `seed + i` and keys prefixed `c{i}-`, so state left by earlier
+    /// cases in the real collaborators does not interfere. The returned report is the
+    /// first inconsistent case, or the last case if all passed.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`Self::run`].
+    pub fn check_generated(
+        &mut self,
+        config: &ConsistencyScheduleConfig,
+        seed: u64,
+        cases: u32,
+    ) -> ConsistencyResult<ConsistencyReport> {
+        let mut report = ConsistencyReport {
+            seed: Some(seed),
+            schedule: ConsistencySchedule::default(),
+            observations: Vec::new(),
+            violations: Vec::new(),
+        };
+        for case in 0..cases {
+            let case_seed = seed.wrapping_add(u64::from(case));
+            let schedule = ConsistencySchedule::generate(config, case_seed, &format!("c{case}-"));
+            report = self.run(&schedule)?;
+            report.seed = Some(case_seed);
+            if !report.is_consistent() {
+                break;
+            }
+        }
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    type Store = Rc<RefCell<HashMap<String, u64>>>;
+
+    /// Backing database
+    struct Db(Store);
+
+    impl ConsistencyLayer for Db {
+        fn write(&mut self, key: &str, value: u64) -> Result<(), String> {
+            self.0.borrow_mut().insert(key.to_string(), value);
+            Ok(())
+        }
+
+        fn read(&mut self, key: &str) -> Result<Option<u64>, String> {
+            Ok(self.0.borrow().get(key).copied())
+        }
+    }
+
+    /// Read-through cache that invalidates on its own writes
+    struct Cache {
+        db: Store,
+        cache: Store,
+    }
+
+    impl ConsistencyLayer for Cache {
+        fn write(&mut self, key: &str, value: u64) -> Result<(), String> {
+            self.db.borrow_mut().insert(key.to_string(), value);
+            self.cache.borrow_mut().remove(key);
+            Ok(())
+        }
+
+        fn read(&mut self, key: &str) -> Result<Option<u64>, String> {
+            if let Some(value) = self.cache.borrow().get(key) {
+                return Ok(Some(*value));
+            }
+            let value = self.db.borrow().get(key).copied();
+            if let Some(value) = value {
+                self.cache.borrow_mut().insert(key.to_string(), value);
+            }
+            Ok(value)
+        }
+    }
+
+    /// Direct DB writer that may forget to invalidate the cache
+    struct DbWriter {
+        db: Store,
+        cache: Store,
+        invalidate: bool,
+    }
+
+    impl ConsistencyLayer for DbWriter {
+        fn write(&mut self, key: &str, value: u64) -> Result<(), String> {
+            self.db.borrow_mut().insert(key.to_string(), value);
+            if self.invalidate {
+                self.cache.borrow_mut().remove(key);
+            }
+            Ok(())
+        }
+
+        fn read(&mut self, key: &str) -> Result<Option<u64>, String> {
+            Ok(self.db.borrow().get(key).copied())
+        }
+    }
+
+    fn checker(invalidate: bool) -> ConsistencyChecker {
+        let (db, cache) = (Store::default(), Store::default());
+        ConsistencyChecker::new()
+            .layer("db", DbWriter { db: db.clone(), cache: cache.clone(), invalidate })
+            .layer("cache", Cache { db, cache })
+            .model(ConsistencyModel::ReadYourWrites)
+            .model(ConsistencyModel::MonotonicReads)
+    }
+
+    test!(test_schedule_generation_is_deterministic, {
+        // Arrange
+        let config = ConsistencyScheduleConfig::new(["db"], ["cache", "db"]);
+
+        // Act
+        let a = ConsistencySchedule::generate(&config, 7, "p-");
+        let b = ConsistencySchedule::generate(&config, 7, "p-");
+
+        // Assert
+        assert_eq!(a, b);
+        assert_eq!(a.operations.len(), config.operations);
+        let versions: Vec<u64> = a
+            .operations
+            .iter()
+            .filter_map(|op| match op.action {
+                ConsistencyAction::Write(v) => Some(v),
+                ConsistencyAction::Read => None,
+            })
+            .collect();
+        assert!(versions.windows(2).all(|w| w[0] < w[1]));
+    });
+
+    test!(test_invalidating_cache_is_consistent, {
+        // Arrange
+        let mut checker = checker(true);
+        let config = ConsistencyScheduleConfig::new(["db", "cache"], ["cache", "db"]);
+
+        // Act
+        let report = checker.check_generated(&config, 1, 50).unwrap();
+
+        // Assert
+        report.assert_consistent();
+    });
+
+    test!(test_missed_invalidation_breaks_read_your_writes, {
+        // Arrange
+        let mut checker = checker(false);
+        let config = ConsistencyScheduleConfig::new(["db"], ["cache"]);
+
+        // Act
+        let report = checker.check_generated(&config, 1, 50).unwrap();
+
+        // Assert
+        assert!(!report.is_consistent());
+        let first = &report.violations[0];
+        assert_eq!(first.model, ConsistencyModel::ReadYourWrites);
+        assert!(report.to_string().contains("seed:"));
+    });
+
+    test!(test_strong_model_and_unknown_layer, {
+        // Arrange
+        let store = Store::default();
+        let mut checker =
+            ConsistencyChecker::new().layer("db", Db(store)).model(ConsistencyModel::Strong);
+        let op = |session, action| ConsistencyOperation {
+            session,
+            layer: "db".to_string(),
+            key: "k".to_string(),
+            action,
+        };
+        let schedule = ConsistencySchedule {
+            operations: vec![
+                op(0, ConsistencyAction::Write(1)),
+                op(1, ConsistencyAction::Read),
+                op(0, ConsistencyAction::Read),
+            ],
+        };
+        let bad = ConsistencySchedule {
+            operations: vec![ConsistencyOperation {
+                layer: "redis".to_string(),
+                ..op(0, ConsistencyAction::Read)
+            }],
+        };
+
+        // Act
+        let report = checker.run(&schedule).unwrap();
+
+        // Assert
+        report.assert_consistent();
+        assert_eq!(report.observations, [(1, Some(1)), (2, Some(1))]);
+        assert_eq!(checker.run(&bad), Err(ConsistencyError::UnknownLayer("redis".to_string())));
+    });
+}
@@ -0,0 +1,697 @@
+//! Golden Corpus Management
+//!
+//! Recorded test data (HTTP cassettes, telemetry captures, fuzz corpora) tends to
+//! accumulate unmanaged. `Corpus` gives each recorded artifact a home in a directory
+//! with a JSON index carrying metadata, enforces size budgets, and deduplicates
+//! identical artifacts by content hash.
+//!
+//! # Layout
+//!
+//! ```text
+//! <root>/
+//! ├── corpus.json                 # Index (entries + budget)
+//! └── <kind>/<sha256>.bin         # Artifact content, one file per unique hash
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::testing::corpus::{Corpus, CorpusKind, SizeBudget};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut corpus = Corpus::open("tests/corpus")?
+//!     .with_budget(SizeBudget::new().max_total_bytes(10 * 1024 * 1024));
+//!
+//! corpus.add("orders-api", CorpusKind::HttpCassette, b"recorded bytes".to_vec())?;
+//! let report = corpus.prune(&chicago_tdd_tools::testing::corpus::PrunePolicy::to_budget())?;
+//! assert!(report.removed.is_empty());
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Name of the index file inside a corpus directory
+pub const CORPUS_INDEX_FILE: &str = "corpus.json";
+
+/// Corpus error
+#[derive(Error, Debug)]
+pub enum CorpusError {
+    /// Filesystem operation failed
+    #[error("Corpus I/O error at {path}: {source}")]
+    Io {
+        /// Path being accessed
+        path: PathBuf,
+        /// Underlying error
+        source: std::io::Error,
+    },
+    /// Index file could not be parsed or written
+    #[error("Corpus index error: {0}")]
+    Index(#[from] serde_json::Error),
+    /// Adding the artifact would exceed the configured budget
+    #[error("Corpus budget exceeded: {0}")]
+    BudgetExceeded(String),
+    /// No entry with the given id and kind
+    #[error("Corpus entry not found: {0}")]
+    NotFound(String),
+    /// A custom kind name is not a safe directory name
+    #[error("Invalid corpus kind '{0}': only ASCII letters, digits, '_' and '-' are allowed")]
+    InvalidKind(String),
+}
+
+/// Result type for corpus operations
+pub type CorpusResult<T> = Result<T, CorpusError>;
+
+fn io_err(path: &Path) -> impl FnOnce(std::io::Error) -> CorpusError + '_ {
+    move |source| CorpusError::Io { path: path.to_path_buf(), source }
+}
+
+/// Kind of recorded artifact
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CorpusKind {
+    /// Recorded HTTP interactions (VCR-style cassette)
+    HttpCassette,
+    /// Captured telemetry (spans, metrics, logs)
+    TelemetryCapture,
+    /// Fuzz or property-test input corpus
+    FuzzCorpus,
+    /// Any other golden artifact
+    ///
+    /// The name becomes part of a directory name, so it may only contain ASCII
+    /// letters, digits, `_` and `-`; the corpus rejects anything else.
+    Other(String),
+}
+
+impl CorpusKind {
+    /// Directory name used for this kind
+    #[must_use]
+    pub fn dir_name(&self) -> String {
+        match self {
+            Self::HttpCassette => "http_cassette".to_string(),
+            Self::TelemetryCapture => "telemetry_capture".to_string(),
+            Self::FuzzCorpus => "fuzz_corpus".to_string(),
+            Self::Other(name) => format!("other_{name}"),
+        }
+    }
+
+    /// Check that the kind maps to a directory inside the corpus
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidKind` if an [`Other`](Self::Other) name is empty or contains
+    /// anything but ASCII letters, digits, `_` and `-`.
+    pub fn validate(&self) -> CorpusResult<()> {
+        match self {
+            Self::Other(name)
+                if name.is_empty()
+                    || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') =>
+            {
+                Err(CorpusError::InvalidKind(name.clone()))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Parse a kind from its CLI/display name
+    #[must_use]
+    pub fn parse(name: &str) -> Self {
+        match name {
+            "http" | "http_cassette" | "cassette" => Self::HttpCassette,
+            "telemetry" | "telemetry_capture" => Self::TelemetryCapture,
+            "fuzz" | "fuzz_corpus" => Self::FuzzCorpus,
+            other => Self::Other(other.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for CorpusKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Other(name) => write!(f, "{name}"),
+            kind => write!(f, "{}", kind.dir_name()),
+        }
+    }
+}
+
+/// Metadata for one recorded artifact
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CorpusEntry {
+    /// Content hash (SHA-256, hex) - also the entry id
+    pub id: String,
+    /// Human-readable name (e.g. test or scenario that produced it)
+    pub name: String,
+    /// Artifact kind
+    pub kind: CorpusKind,
+    /// Size of the content in bytes
+    pub size_bytes: u64,
+    /// When the artifact was added (seconds since UNIX epoch)
+    pub added_at: u64,
+    /// Free-form metadata (source, recorder version, seed, ...)
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
+}
+
+impl CorpusEntry {
+    fn relative_path(&self) -> PathBuf {
+        PathBuf::from(self.kind.dir_name()).join(format!("{}.bin", self.id))
+    }
+}
+
+/// Size budget for a corpus
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeBudget {
+    /// Maximum total bytes across all entries
+    pub max_total_bytes: Option<u64>,
+    /// Maximum number of entries
+    pub max_entries: Option<usize>,
+}
+
+impl SizeBudget {
+    /// Unlimited budget
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { max_total_bytes: None, max_entries: None }
+    }
+
+    /// Limit total bytes
+    #[must_use]
+    pub const fn max_total_bytes(mut self, bytes: u64) -> Self {
+        self.max_total_bytes = Some(bytes);
+        self
+    }
+
+    /// Limit entry count
+    #[must_use]
+    pub const fn max_entries(mut self, entries: usize) -> Self {
+        self.max_entries = Some(entries);
+        self
+    }
+
+    fn allows(&self, entries: usize, bytes: u64) -> bool {
+        self.max_entries.is_none_or(|max| entries <= max)
+            && self.max_total_bytes.is_none_or(|max| bytes <= max)
+    }
+}
+
+/// Outcome of adding an artifact
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AddOutcome {
+    /// New content stored under this id
+    Added(String),
+    /// Identical content already present under this id; nothing stored
+    Duplicate(String),
+}
+
+impl AddOutcome {
+    /// Entry id regardless of outcome
+    #[must_use]
+    pub fn id(&self) -> &str {
+        match self {
+            Self::Added(id) | Self::Duplicate(id) => id,
+        }
+    }
+}
+
+/// Which entries `prune` should remove
+#[derive(Debug, Clone, Default)]
+pub struct PrunePolicy {
+    older_than: Option<Duration>,
+    kind: Option<CorpusKind>,
+    enforce_budget: bool,
+}
+
+impl PrunePolicy {
+    /// Remove oldest entries until the corpus fits its budget
+    #[must_use]
+    pub fn to_budget() -> Self {
+        Self { enforce_budget: true, ..Self::default() }
+    }
+
+    /// Remove entries older than `age`
+    #[must_use]
+    pub fn older_than(age: Duration) -> Self {
+        Self { older_than: Some(age), ..Self::default() }
+    }
+
+    /// Restrict pruning to one kind
+    #[must_use]
+    pub fn only_kind(mut self, kind: CorpusKind) -> Self {
+        self.kind = Some(kind);
+        self
+    }
+
+    /// Additionally enforce the budget after age-based pruning
+    #[must_use]
+    pub const fn and_budget(mut self) -> Self {
+        self.enforce_budget = true;
+        self
+    }
+}
+
+/// Result of a prune run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PruneReport {
+    /// Entries removed
+    pub removed: Vec<CorpusEntry>,
+    /// Bytes reclaimed
+    pub bytes_reclaimed: u64,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct CorpusIndex {
+    #[serde(default)]
+    budget: SizeBudget,
+    #[serde(default)]
+    entries: Vec<CorpusEntry>,
+}
+
+/// Golden corpus rooted at a directory
+#[derive(Debug)]
+pub struct Corpus {
+    root: PathBuf,
+    index: CorpusIndex,
+}
+
+impl Corpus {
+    /// Open (or create) a corpus at `root`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be created, the index cannot be parsed,
+    /// or the index contains an entry with an invalid kind.
+    pub fn open(root: impl AsRef<Path>) -> CorpusResult<Self> {
+        let root = root.as_ref().to_path_buf();
+        fs::create_dir_all(&root).map_err(io_err(&root))?;
+        let index_path = root.join(CORPUS_INDEX_FILE);
+        let index = if index_path.exists() {
+            let raw = fs::read_to_string(&index_path).map_err(io_err(&index_path))?;
+            serde_json::from_str::<CorpusIndex>(&raw)?
+        } else {
+            CorpusIndex::default()
+        };
+        for entry in &index.entries {
+            entry.kind.validate()?;
+        }
+        Ok(Self { root, index })
+    }
+
+    /// Set the size budget (persisted with the index on next write)
+    #[must_use]
+    pub const fn with_budget(mut self, budget: SizeBudget) -> Self {
+        self.index.budget = budget;
+        self
+    }
+
+    /// Configured budget
+    #[must_use]
+    pub const fn budget(&self) -> SizeBudget {
+        self.index.budget
+    }
+
+    /// Corpus root directory
+    #[must_use]
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Add an artifact, deduplicating by content hash
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidKind` for an unsafe custom kind, `BudgetExceeded` if the new
+    /// artifact does not fit the budget, or an I/O error.
+    pub fn add(
+        &mut self,
+        name: impl Into<String>,
+        kind: CorpusKind,
+        content: Vec<u8>,
+    ) -> CorpusResult<AddOutcome> {
+        self.add_with_metadata(name, kind, content, BTreeMap::new())
+    }
+
+    /// Add an artifact with metadata, deduplicating by content hash
+    ///
+    /// # Errors
+    ///
+    /// Returns `InvalidKind` for an unsafe custom kind, `BudgetExceeded` if the new
+    /// artifact does not fit the budget, or an I/O error.
+    pub fn add_with_metadata(
+        &mut self,
+        name: impl Into<String>,
+        kind: CorpusKind,
+        content: Vec<u8>,
+        metadata: BTreeMap<String, String>,
+    ) -> CorpusResult<AddOutcome> {
+        kind.validate()?;
+        let id = hex::encode(Sha256::digest(&content));
+        if self.index.entries.iter().any(|e| e.id == id && e.kind == kind) {
+            return Ok(AddOutcome::Duplicate(id));
+        }
+
+        let size_bytes = content.len() as u64;
+        let entries_after = self.index.entries.len() + 1;
+        let bytes_after = self.total_bytes() + size_bytes;
+        if !self.index.budget.allows(entries_after, bytes_after) {
+            return Err(CorpusError::BudgetExceeded(format!(
+                "adding {size_bytes} bytes would bring corpus to {entries_after} entries / \
+                 {bytes_after} bytes (budget: {:?})",
+                self.index.budget
+            )));
+        }
+
+        let entry = CorpusEntry {
+            id: id.clone(),
+            name: name.into(),
+            kind,
+            size_bytes,
+            added_at: now_secs(),
+            metadata,
+        };
+        let path = self.root.join(entry.relative_path());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).map_err(io_err(parent))?;
+        }
+        fs::write(&path, content).map_err(io_err(&path))?;
+        self.index.entries.push(entry);
+        self.save()?;
+        Ok(AddOutcome::Added(id))
+    }
+
+    /// Add an artifact by copying an existing file
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or the add fails.
+    pub fn add_file(
+        &mut self,
+        path: impl AsRef<Path>,
+        kind: CorpusKind,
+    ) -> CorpusResult<AddOutcome> {
+        let path = path.as_ref();
+        let content = fs::read(path).map_err(io_err(path))?;
+        let name = path
+            .file_name()
+            .map_or_else(|| path.display().to_string(), |n| n.to_string_lossy().into_owned());
+        let mut metadata = BTreeMap::new();
+        metadata.insert("source".to_string(), path.display().to_string());
+        self.add_with_metadata(name, kind, content, metadata)
+    }
+
+    /// All entries, optionally filtered by kind (oldest first)
+    #[must_use]
+    pub fn list(&self, kind: Option<&CorpusKind>) -> Vec<&CorpusEntry> {
+        self.index
+            .entries
+            .iter()
+            .filter(|e| kind.is_none_or(|k| &e.kind == k))
+            .collect()
+    }
+
+    /// Look up an entry by id and kind
+    ///
+    /// The same content may be recorded under several kinds with the same id, so
+    /// entries are keyed by both.
+    #[must_use]
+    pub fn get(&self, id: &str, kind: &CorpusKind) -> Option<&CorpusEntry> {
+        self.index.entries.iter().find(|e| e.id == id && &e.kind == kind)
+    }
+
+    /// Read the content of an entry
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotFound` for an unknown id/kind pair, or an I/O error.
+    pub fn read(&self, id: &str, kind: &CorpusKind) -> CorpusResult<Vec<u8>> {
+        let entry = self.get(id, kind).ok_or_else(|| not_found(id, kind))?;
+        let path = self.root.join(entry.relative_path());
+        fs::read(&path).map_err(io_err(&path))
+    }
+
+    /// Remove an entry and its content
+    ///
+    /// # Errors
+    ///
+    /// Returns `NotFound` for an unknown id/kind pair, or an I/O error.
+    pub fn remove(&mut self, id: &str, kind: &CorpusKind) -> CorpusResult<CorpusEntry> {
+        let position = self
+            .index
+            .entries
+            .iter()
+            .position(|e| e.id == id && &e.kind == kind)
+            .ok_or_else(|| not_found(id, kind))?;
+        let entry = self.index.entries.remove(position);
+        self.delete_content(&entry)?;
+        self.save()?;
+        Ok(entry)
+    }
+
+    /// Total bytes stored
+    #[must_use]
+    pub fn total_bytes(&self) -> u64 {
+        self.index.entries.iter().map(|e| e.size_bytes).sum()
+    }
+
+    /// Number of entries
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.index.entries.len()
+    }
+
+    /// Whether the corpus is empty
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.index.entries.is_empty()
+    }
+
+    /// Remove entries according to `policy`
+    ///
+    /// Age-based removal runs first; budget enforcement then evicts the oldest
+    /// remaining entries (within the policy's kind filter) until the budget holds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an I/O error if content cannot be deleted or the index cannot be written.
+    pub fn prune(&mut self, policy: &PrunePolicy) -> CorpusResult<PruneReport> {
+        let now = now_secs();
+        let in_scope = |e: &CorpusEntry| policy.kind.as_ref().is_none_or(|k| &e.kind == k);
+
+        let mut entries = std::mem::take(&mut self.index.entries);
+        entries.sort_by_key(|e| e.added_at);
+        let mut removed = Vec::new();
+
+        if let Some(age) = policy.older_than {
+            let cutoff = now.saturating_sub(age.as_secs());
+            let (old, keep): (Vec<_>, Vec<_>) =
+                entries.into_iter().partition(|e| in_scope(e) && e.added_at < cutoff);
+            removed.extend(old);
+            entries = keep;
+        }
+
+        if policy.enforce_budget {
+            let mut bytes: u64 = entries.iter().map(|e| e.size_bytes).sum();
+            while !self.index.budget.allows(entries.len(), bytes) {
+                let Some(position) = entries.iter().position(&in_scope) else {
+                    break;
+                };
+                let entry = entries.remove(position);
+                bytes -= entry.size_bytes;
+                removed.push(entry);
+            }
+        }
+
+        self.index.entries = entries;
+        for entry in &removed {
+            self.delete_content(entry)?;
+        }
+        self.save()?;
+        let bytes_reclaimed = removed.iter().map(|e| e.size_bytes).sum();
+        Ok(PruneReport { removed, bytes_reclaimed })
+    }
+
+    fn delete_content(&self, entry: &CorpusEntry) -> CorpusResult<()> {
+        // Same content may be indexed under another kind; only the per-kind file is removed.
+        let path = self.root.join(entry.relative_path());
+        match fs::remove_file(&path) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(io_err(&path)(e)),
+            _ => Ok(()),
+        }
+    }
+
+    fn save(&self) -> CorpusResult<()> {
+        let path = self.root.join(CORPUS_INDEX_FILE);
+        let raw = serde_json::to_string_pretty(&self.index)?;
+        fs::write(&path, raw).map_err(io_err(&path))
+    }
+}
+
+fn not_found(id: &str, kind: &CorpusKind) -> CorpusError {
+    CorpusError::NotFound(format!("{id} ({kind})"))
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_add_and_read_roundtrip, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut corpus = Corpus::open(dir.path()).unwrap();
+
+        // Act
+        let outcome =
+            corpus.add("orders", CorpusKind::HttpCassette, b"GET /orders".to_vec()).unwrap();
+
+        // Assert
+        assert!(matches!(outcome, AddOutcome::Added(_)));
+        assert_eq!(corpus.read(outcome.id(), &CorpusKind::HttpCassette).unwrap(), b"GET /orders");
+        assert_eq!(corpus.len(), 1);
+    });
+
+    test!(test_add_deduplicates_identical_content, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut corpus = Corpus::open(dir.path()).unwrap();
+        corpus.add("first", CorpusKind::FuzzCorpus, vec![1, 2, 3]).unwrap();
+
+        // Act
+        let outcome = corpus.add("second", CorpusKind::FuzzCorpus, vec![1, 2, 3]).unwrap();
+
+        // Assert
+        assert!(matches!(outcome, AddOutcome::Duplicate(_)));
+        assert_eq!(corpus.len(), 1);
+    });
+
+    test!(test_budget_rejects_oversized_add, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut corpus = Corpus::open(dir.path())
+            .unwrap()
+            .with_budget(SizeBudget::new().max_total_bytes(4));
+
+        // Act
+        let result = corpus.add("big", CorpusKind::TelemetryCapture, vec![0; 5]);
+
+        // Assert
+        assert!(matches!(result, Err(CorpusError::BudgetExceeded(_))));
+        assert!(corpus.is_empty());
+    });
+
+    test!(test_prune_to_budget_evicts_oldest, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut corpus = Corpus::open(dir.path()).unwrap();
+        let old = corpus.add("old", CorpusKind::FuzzCorpus, vec![1]).unwrap();
+        corpus.add("new", CorpusKind::FuzzCorpus, vec![2]).unwrap();
+        corpus.index.entries[0].added_at = 1;
+        let mut corpus = corpus.with_budget(SizeBudget::new().max_entries(1));
+
+        // Act
+        let report = corpus.prune(&PrunePolicy::to_budget()).unwrap();
+
+        // Assert
+        assert_eq!(report.removed.len(), 1);
+        assert_eq!(report.removed[0].id, old.id());
+        assert_eq!(report.bytes_reclaimed, 1);
+        assert_eq!(corpus.list(None)[0].name, "new");
+    });
+
+    test!(test_prune_older_than_respects_kind_filter, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut corpus = Corpus::open(dir.path()).unwrap();
+        corpus.add("cassette", CorpusKind::HttpCassette, vec![1]).unwrap();
+        corpus.add("fuzz", CorpusKind::FuzzCorpus, vec![2]).unwrap();
+        for entry in &mut corpus.index.entries {
+            entry.added_at = 1;
+        }
+
+        // Act
+        let policy =
+            PrunePolicy::older_than(Duration::from_secs(60)).only_kind(CorpusKind::FuzzCorpus);
+        let report = corpus.prune(&policy).unwrap();
+
+        // Assert
+        assert_eq!(report.removed.len(), 1);
+        assert_eq!(corpus.list(Some(&CorpusKind::HttpCassette)).len(), 1);
+        assert!(corpus.list(Some(&CorpusKind::FuzzCorpus)).is_empty());
+    });
+
+    test!(test_index_persists_across_open, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        {
+            let mut corpus = Corpus::open(dir.path()).unwrap();
+            let mut metadata = BTreeMap::new();
+            metadata.insert("seed".to_string(), "42".to_string());
+            corpus
+                .add_with_metadata("seeded", CorpusKind::FuzzCorpus, vec![9], metadata)
+                .unwrap();
+        }
+
+        // Act
+        let reopened = Corpus::open(dir.path()).unwrap();
+
+        // Assert
+        let entries = reopened.list(None);
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].metadata.get("seed").map(String::as_str), Some("42"));
+    });
+
+    test!(test_remove_unknown_id_is_not_found, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut corpus = Corpus::open(dir.path()).unwrap();
+
+        // Act
+        let result = corpus.remove("deadbeef", &CorpusKind::FuzzCorpus);
+
+        // Assert
+        assert!(matches!(result, Err(CorpusError::NotFound(_))));
+    });
+
+    test!(test_same_content_is_keyed_by_kind, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut corpus = Corpus::open(dir.path()).unwrap();
+        let id = corpus.add("fuzz", CorpusKind::FuzzCorpus, vec![7]).unwrap().id().to_string();
+        corpus.add("capture", CorpusKind::TelemetryCapture, vec![7]).unwrap();
+
+        // Act
+        let removed = corpus.remove(&id, &CorpusKind::TelemetryCapture).unwrap();
+
+        // Assert
+        assert_eq!(removed.name, "capture");
+        assert_eq!(corpus.get(&id, &CorpusKind::FuzzCorpus).unwrap().name, "fuzz");
+        assert_eq!(corpus.read(&id, &CorpusKind::FuzzCorpus).unwrap(), vec![7]);
+        assert!(corpus.get(&id, &CorpusKind::TelemetryCapture).is_none());
+    });
+
+    test!(test_unsafe_custom_kind_is_rejected, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut corpus = Corpus::open(dir.path().join("corpus")).unwrap();
+
+        // Act
+        let traversal = corpus.add("escape", CorpusKind::parse("../../escape"), vec![1]);
+        let custom = corpus.add("replay", CorpusKind::parse("grpc-replay_v2"), vec![2]);
+
+        // Assert
+        assert!(matches!(traversal, Err(CorpusError::InvalidKind(_))));
+        assert!(custom.is_ok());
+        assert_eq!(corpus.len(), 1);
+        assert!(!dir.path().join("escape").exists());
+    });
+}
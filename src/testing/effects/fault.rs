@@ -0,0 +1,495 @@
+//! Fault Injection for Effectful Collaborators
+//!
+//! [`EffectProxy<T>`] wraps a real collaborator (a DB pool, an HTTP client, a filesystem
+//! root) and routes every call through a [`FaultSchedule`]. On each call the schedule decides
+//! whether to inject:
+//!
+//! - [`Fault::Latency`]: delay, then perform the real call
+//! - [`Fault::Error`]: fail without touching the collaborator
+//! - [`Fault::PartialFailure`]: perform the real call, then report failure anyway (the
+//!   "write landed but the acknowledgement was lost" case)
+//!
+//! Decisions depend only on the seed, the call index, and the operation name, so a failing
+//! resilience test reproduces from its seed. Every call is logged, and tests assert on the
+//! collaborator's resulting state rather than on interactions.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::testing::effects::fault::{
+//!     EffectProxy, Fault, FaultSchedule, InjectedFault,
+//! };
+//! use std::cell::RefCell;
+//!
+//! #[derive(Debug)]
+//! struct StoreError(String);
+//!
+//! impl From<InjectedFault> for StoreError {
+//!     fn from(fault: InjectedFault) -> Self {
+//!         Self(fault.to_string())
+//!     }
+//! }
+//!
+//! // Real collaborator: an append-only log
+//! let proxy = EffectProxy::new(
+//!     RefCell::new(Vec::new()),
+//!     FaultSchedule::new(7).at_call(0, Fault::error("connection reset")),
+//! );
+//!
+//! // Code under test: append an entry
+//! let append = |entry: &str| -> Result<(), StoreError> {
+//!     proxy.call("append", |log: &RefCell<Vec<String>>| {
+//!         log.borrow_mut().push(entry.to_string());
+//!         Ok(())
+//!     })
+//! };
+//! assert!(append("a").is_err());
+//! append("a").unwrap();
+//!
+//! assert_eq!(*proxy.inner().borrow(), vec!["a".to_string()]);
+//! assert_eq!(proxy.injected(), 1);
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+use thiserror::Error;
+
+/// A fault injected into one collaborator call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fault {
+    /// Delay the call, then perform it
+    Latency(Duration),
+    /// Fail the call without reaching the collaborator
+    Error(String),
+    /// Perform the call, then fail it
+    PartialFailure(String),
+}
+
+impl Fault {
+    /// Latency fault
+    #[must_use]
+    pub const fn latency(delay: Duration) -> Self {
+        Self::Latency(delay)
+    }
+
+    /// Error fault
+    #[must_use]
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::Error(message.into())
+    }
+
+    /// Partial-failure fault
+    #[must_use]
+    pub fn partial(message: impl Into<String>) -> Self {
+        Self::PartialFailure(message.into())
+    }
+}
+
+impl fmt::Display for Fault {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Latency(delay) => write!(f, "latency {delay:?}"),
+            Self::Error(message) => write!(f, "error: {message}"),
+            Self::PartialFailure(message) => write!(f, "partial failure: {message}"),
+        }
+    }
+}
+
+/// Error returned for an injected [`Fault::Error`] or [`Fault::PartialFailure`]
+///
+/// Collaborator error types implement `From<InjectedFault>` so proxied calls keep their
+/// natural signature.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("🚨 Injected fault in '{operation}' (call {call}): {fault}")]
+pub struct InjectedFault {
+    /// Operation name passed to the proxy
+    pub operation: String,
+    /// Index of the call through the proxy
+    pub call: usize,
+    /// The injected fault
+    pub fault: Fault,
+}
+
+impl InjectedFault {
+    /// Whether the collaborator was actually called before the failure was reported
+    #[must_use]
+    pub const fn reached_collaborator(&self) -> bool {
+        matches!(self.fault, Fault::PartialFailure(_))
+    }
+}
+
+/// Probabilistic fault rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaultRule {
+    /// Operation the rule applies to (`None` = every operation)
+    pub operation: Option<String>,
+    /// Chance of injecting on a matching call, in percent
+    pub percent: u8,
+    /// Fault to inject
+    pub fault: Fault,
+}
+
+/// Deterministic schedule deciding which calls get which faults
+///
+/// Scripted faults ([`at_call`](Self::at_call)) take precedence; otherwise rules are tried in
+/// the order they were added and the first one that fires wins.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FaultSchedule {
+    seed: u64,
+    scripted: BTreeMap<usize, Fault>,
+    rules: Vec<FaultRule>,
+}
+
+impl FaultSchedule {
+    /// Empty schedule; `seed` drives the probabilistic rules
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { seed, ..Self::default() }
+    }
+
+    /// Seed of the schedule
+    #[must_use]
+    pub const fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Inject `fault` on call `index` (0-based, across all operations)
+    #[must_use]
+    pub fn at_call(mut self, index: usize, fault: Fault) -> Self {
+        self.scripted.insert(index, fault);
+        self
+    }
+
+    /// Inject `fault` on `percent`% of calls to `operation`
+    #[must_use]
+    pub fn inject(mut self, operation: impl Into<String>, percent: u8, fault: Fault) -> Self {
+        self.rules.push(FaultRule { operation: Some(operation.into()), percent, fault });
+        self
+    }
+
+    /// Inject `fault` on `percent`% of calls to any operation
+    #[must_use]
+    pub fn inject_any(mut self, percent: u8, fault: Fault) -> Self {
+        self.rules.push(FaultRule { operation: None, percent, fault });
+        self
+    }
+
+    /// Fault for call `index` to `operation`, if any
+    #[must_use]
+    pub fn decide(&self, index: usize, operation: &str) -> Option<Fault> {
+        if let Some(fault) = self.scripted.get(&index) {
+            return Some(fault.clone());
+        }
+        let mut rng = SplitMix64(self.seed ^ (index as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+        self.rules
+            .iter()
+            .filter(|rule| rule.operation.as_deref().is_none_or(|name| name == operation))
+            .find(|rule| rng.next() % 100 < u64::from(rule.percent))
+            .map(|rule| rule.fault.clone())
+    }
+}
+
+/// Private `SplitMix64` generator (deterministic across platforms)
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    const fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// One call through an [`EffectProxy`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaultRecord {
+    /// Index of the call
+    pub call: usize,
+    /// Operation name
+    pub operation: String,
+    /// Injected fault, if any
+    pub fault: Option<Fault>,
+}
+
+impl fmt::Display for FaultRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.fault {
+            Some(fault) => write!(f, "call {} {}: {fault}", self.call, self.operation),
+            None => write!(f, "call {} {}: ok", self.call, self.operation),
+        }
+    }
+}
+
+type Sleeper = Arc<dyn Fn(Duration) + Send + Sync>;
+
+/// Wraps a real collaborator and injects faults according to a [`FaultSchedule`]
+///
+/// Calls may come from several threads; the log records them in the order they reached the
+/// proxy, so schedules are only reproducible when that order is.
+pub struct EffectProxy<T> {
+    inner: T,
+    schedule: FaultSchedule,
+    log: Mutex<Vec<FaultRecord>>,
+    sleeper: Sleeper,
+}
+
+impl<T: fmt::Debug> fmt::Debug for EffectProxy<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EffectProxy")
+            .field("inner", &self.inner)
+            .field("schedule", &self.schedule)
+            .field("log", &*self.lock_log())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> EffectProxy<T> {
+    /// Wrap `inner`; latency faults sleep the calling thread
+    pub fn new(inner: T, schedule: FaultSchedule) -> Self {
+        Self { inner, schedule, log: Mutex::new(Vec::new()), sleeper: Arc::new(std::thread::sleep) }
+    }
+
+    /// Replace how latency is applied (e.g. advance a virtual clock instead of sleeping)
+    #[must_use]
+    pub fn with_sleeper<F>(mut self, sleeper: F) -> Self
+    where
+        F: Fn(Duration) + Send + Sync + 'static,
+    {
+        self.sleeper = Arc::new(sleeper);
+        self
+    }
+
+    /// The wrapped collaborator, for state-based assertions
+    pub const fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Mutable access to the wrapped collaborator, bypassing the schedule
+    pub const fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Unwrap the collaborator
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// The schedule driving this proxy
+    pub const fn schedule(&self) -> &FaultSchedule {
+        &self.schedule
+    }
+
+    /// Every call made through the proxy, in order
+    pub fn log(&self) -> Vec<FaultRecord> {
+        self.lock_log().clone()
+    }
+
+    /// Number of calls that had a fault injected
+    pub fn injected(&self) -> usize {
+        self.lock_log().iter().filter(|record| record.fault.is_some()).count()
+    }
+
+    /// Advance the schedule for one call to `operation` without performing it
+    ///
+    /// For collaborators [`call`](Self::call) cannot wrap (e.g. async clients): apply the
+    /// returned fault yourself. The call is logged like any other.
+    pub fn next_fault(&self, operation: &str) -> Option<Fault> {
+        self.schedule_call(operation).1
+    }
+
+    /// Call the collaborator through the schedule
+    ///
+    /// # Errors
+    ///
+    /// Returns the collaborator's error, or an [`InjectedFault`] converted into it.
+    pub fn call<R, E, F>(&self, operation: &str, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&T) -> Result<R, E>,
+        E: From<InjectedFault>,
+    {
+        let (call, fault) = self.schedule_call(operation);
+        Self::perform(&self.sleeper, operation, call, fault, || f(&self.inner))
+    }
+
+    /// Call the collaborator mutably through the schedule
+    ///
+    /// # Errors
+    ///
+    /// Returns the collaborator's error, or an [`InjectedFault`] converted into it.
+    pub fn call_mut<R, E, F>(&mut self, operation: &str, f: F) -> Result<R, E>
+    where
+        F: FnOnce(&mut T) -> Result<R, E>,
+        E: From<InjectedFault>,
+    {
+        let (call, fault) = self.schedule_call(operation);
+        let inner = &mut self.inner;
+        Self::perform(&self.sleeper, operation, call, fault, || f(inner))
+    }
+
+    /// Log the next call and decide its fault
+    fn schedule_call(&self, operation: &str) -> (usize, Option<Fault>) {
+        let mut log = self.lock_log();
+        let call = log.len();
+        let fault = self.schedule.decide(call, operation);
+        log.push(FaultRecord { call, operation: operation.to_string(), fault: fault.clone() });
+        drop(log);
+        (call, fault)
+    }
+
+    fn perform<R, E>(
+        sleeper: &Sleeper,
+        operation: &str,
+        call: usize,
+        fault: Option<Fault>,
+        call_inner: impl FnOnce() -> Result<R, E>,
+    ) -> Result<R, E>
+    where
+        E: From<InjectedFault>,
+    {
+        let injected = |fault| InjectedFault { operation: operation.to_string(), call, fault };
+        match fault {
+            None => call_inner(),
+            Some(Fault::Latency(delay)) => {
+                sleeper(delay);
+                call_inner()
+            }
+            Some(fault @ Fault::Error(_)) => Err(injected(fault).into()),
+            Some(fault @ Fault::PartialFailure(_)) => {
+                call_inner()?;
+                Err(injected(fault).into())
+            }
+        }
+    }
+
+    fn lock_log(&self) -> MutexGuard<'_, Vec<FaultRecord>> {
+        self.log.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    #[derive(Debug)]
+    enum StoreError {
+        Io(std::io::Error),
+        Fault(InjectedFault),
+    }
+
+    impl From<InjectedFault> for StoreError {
+        fn from(fault: InjectedFault) -> Self {
+            Self::Fault(fault)
+        }
+    }
+
+    /// Filesystem-backed key/value store
+    #[derive(Debug)]
+    struct FileStore(PathBuf);
+
+    impl FileStore {
+        fn put(&self, key: &str, value: &str) -> Result<(), StoreError> {
+            fs::write(self.0.join(key), value).map_err(StoreError::Io)
+        }
+
+        fn path(&self, key: &str) -> PathBuf {
+            self.0.join(key)
+        }
+    }
+
+    /// Code under test: write with up to `attempts` tries
+    fn put_with_retry(
+        proxy: &EffectProxy<FileStore>,
+        key: &str,
+        value: &str,
+        attempts: usize,
+    ) -> Result<usize, StoreError> {
+        let mut tried = 0;
+        loop {
+            tried += 1;
+            match proxy.call("put", |store: &FileStore| store.put(key, value)) {
+                Ok(()) => return Ok(tried),
+                Err(StoreError::Fault(_)) if tried < attempts => {}
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    fn store(dir: &Path) -> FileStore {
+        FileStore(dir.to_path_buf())
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)] // Test code - temp dir and reads should not fail
+    fn test_error_fault_skips_collaborator_and_retry_recovers() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let schedule = FaultSchedule::new(1).at_call(0, Fault::error("disk unavailable"));
+        let proxy = EffectProxy::new(store(dir.path()), schedule);
+
+        // Act
+        let tried = put_with_retry(&proxy, "k", "v", 3).unwrap();
+
+        // Assert
+        assert_eq!(tried, 2);
+        assert_eq!(fs::read_to_string(proxy.inner().path("k")).unwrap(), "v");
+        assert_eq!(proxy.injected(), 1);
+        assert_eq!(proxy.log()[0].to_string(), "call 0 put: error: disk unavailable");
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used, clippy::panic)] // Test code - temp dir and reads should not fail
+    fn test_partial_failure_reaches_collaborator() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let schedule = FaultSchedule::new(1).inject("put", 100, Fault::partial("ack lost"));
+        let proxy = EffectProxy::new(store(dir.path()), schedule);
+
+        // Act
+        let result = put_with_retry(&proxy, "k", "v", 1);
+
+        // Assert
+        let Err(StoreError::Fault(fault)) = result else {
+            panic!("expected injected fault, got {result:?}");
+        };
+        assert!(fault.reached_collaborator());
+        assert_eq!(fs::read_to_string(proxy.inner().path("k")).unwrap(), "v");
+    }
+
+    #[test]
+    #[allow(clippy::unwrap_used)] // Test code - temp dir and lock should not fail
+    fn test_latency_uses_sleeper_and_schedule_is_seeded() {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let slept = Arc::new(Mutex::new(Duration::ZERO));
+        let total = Arc::clone(&slept);
+        let schedule = FaultSchedule::new(42)
+            .inject("put", 50, Fault::latency(Duration::from_secs(1)))
+            .inject_any(10, Fault::error("timeout"));
+        let proxy = EffectProxy::new(store(dir.path()), schedule.clone())
+            .with_sleeper(move |delay| *total.lock().unwrap() += delay);
+
+        // Act
+        for i in 0..40 {
+            let _ = proxy.call("put", |s: &FileStore| s.put(&format!("k{i}"), "v"));
+        }
+
+        // Assert
+        let log = proxy.log();
+        let latencies = log.iter().filter(|r| matches!(r.fault, Some(Fault::Latency(_)))).count();
+        assert!(latencies > 0 && latencies < 40, "{latencies} latency faults");
+        assert_eq!(*slept.lock().unwrap(), Duration::from_secs(latencies as u64));
+        let decisions = |s: &FaultSchedule| (0..40).map(|i| s.decide(i, "put")).collect::<Vec<_>>();
+        let recorded: Vec<_> = log.into_iter().map(|r| r.fault).collect();
+        assert_eq!(decisions(&schedule), recorded);
+        let reseeded = FaultSchedule::new(43)
+            .inject("put", 50, Fault::latency(Duration::from_secs(1)))
+            .inject_any(10, Fault::error("timeout"));
+        assert_ne!(decisions(&reseeded), recorded);
+    }
+}
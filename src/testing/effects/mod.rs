@@ -28,6 +28,16 @@
 //!     - Cannot call any effectful operations
 //!     - Guaranteed side-effect free
 //! ```
+//!
+//! # Fault Injection
+//!
+//! [`fault`] wraps real collaborators in an [`EffectProxy`] that injects latency, errors, and
+//! partial failures on a deterministic seeded schedule, so resilience paths are exercised
+//! against the real thing instead of a mock.
+
+pub mod fault;
+
+pub use fault::*;
 
 use std::marker::PhantomData;
 
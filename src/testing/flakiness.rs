@@ -0,0 +1,565 @@
+//! Flaky Test Detection and Quarantine
+//!
+//! `FlakinessTracker` keeps a pass/fail history per test in a JSON file that survives
+//! across runs, scores how flaky each test is, and manages a quarantine registry.
+//! Quarantined tests are retried automatically (every attempt is recorded), and each
+//! quarantine carries a deadline: once it passes, the test fails the run until someone
+//! fixes it or releases it, so quarantine cannot become a permanent hiding place.
+//!
+//! # Flakiness Score
+//!
+//! The score is the fraction of consecutive recorded attempts whose outcome flipped
+//! (pass → fail or fail → pass): `0.0` for a test that always passes or always fails,
+//! `1.0` for one that alternates. Only the most recent attempts (see
+//! [`FlakinessTracker::with_history_limit`]) count.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::testing::flakiness::FlakinessTracker;
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let mut tracker = FlakinessTracker::open("target/flakiness.json")?.with_max_retries(3);
+//! tracker.quarantine("orders::test_sync", "races the broker; see #412")?;
+//!
+//! // Retried up to 3 times because it is quarantined; panics if every attempt fails,
+//! // errors if the quarantine deadline has passed.
+//! let outcome = tracker.run("orders::test_sync", || {
+//!     // test body
+//! })?;
+//! println!("passed after {} attempt(s)", outcome.attempts.len());
+//!
+//! // CI gate: fail the run if any quarantine outlived its deadline.
+//! tracker.check_deadlines()?;
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+/// Default number of retries for quarantined tests
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default time a test may stay quarantined (14 days)
+pub const DEFAULT_QUARANTINE_PERIOD: Duration = Duration::from_secs(14 * 24 * 60 * 60);
+
+/// Default number of attempts kept per test
+pub const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+/// Flakiness tracking error
+#[derive(Error, Debug)]
+pub enum FlakinessError {
+    /// Filesystem operation failed
+    #[error("Flakiness history I/O error at {path}: {source}")]
+    Io {
+        /// Path being accessed
+        path: PathBuf,
+        /// Underlying error
+        source: std::io::Error,
+    },
+    /// History file could not be parsed or written
+    #[error("Flakiness history error: {0}")]
+    Store(#[from] serde_json::Error),
+    /// Quarantined tests outlived their deadline
+    #[error(
+        "🚨 Quarantine deadline passed for: {}\n   ⚠️  STOP: Quarantined tests must be fixed or released before their deadline\n   💡 FIX: Fix the flakiness, or call `release` (or extend the quarantine) deliberately",
+        .0.join(", ")
+    )]
+    QuarantineExpired(Vec<String>),
+}
+
+/// Result type for flakiness tracking
+pub type FlakinessResult<T> = Result<T, FlakinessError>;
+
+fn io_err(path: &Path) -> impl FnOnce(std::io::Error) -> FlakinessError + '_ {
+    move |source| FlakinessError::Io { path: path.to_path_buf(), source }
+}
+
+/// One recorded execution of a test
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestAttempt {
+    /// Whether the attempt passed
+    pub passed: bool,
+    /// Retry number within its run (`0` for the first attempt)
+    pub retry: u32,
+    /// When the attempt finished (Unix epoch milliseconds)
+    pub timestamp: u64,
+}
+
+/// Why and until when a test is quarantined
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Quarantine {
+    /// Reason given when quarantining (ticket, symptom)
+    pub reason: String,
+    /// When the test was quarantined (Unix epoch milliseconds)
+    pub since: u64,
+    /// When the quarantine expires (Unix epoch milliseconds)
+    pub deadline: u64,
+}
+
+impl Quarantine {
+    /// Whether the deadline has passed
+    #[must_use]
+    pub fn is_expired(&self) -> bool {
+        now_millis() > self.deadline
+    }
+}
+
+/// Recorded history of one test
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestHistory {
+    /// Attempts, oldest first
+    pub attempts: Vec<TestAttempt>,
+    /// Active quarantine, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quarantine: Option<Quarantine>,
+}
+
+impl TestHistory {
+    /// Fraction of consecutive attempts whose outcome flipped (`0.0` – `1.0`)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Attempt counts are far below 2^52
+    pub fn score(&self) -> f64 {
+        if self.attempts.len() < 2 {
+            return 0.0;
+        }
+        let flips =
+            self.attempts.windows(2).filter(|pair| pair[0].passed != pair[1].passed).count();
+        flips as f64 / (self.attempts.len() - 1) as f64
+    }
+}
+
+/// Persisted form of the tracker
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct FlakinessStore {
+    tests: BTreeMap<String, TestHistory>,
+}
+
+/// Result of a passing [`FlakinessTracker::run`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryOutcome {
+    /// Outcome of every attempt, in order (the last one passed)
+    pub attempts: Vec<bool>,
+    /// Whether the test was quarantined (and therefore retried)
+    pub quarantined: bool,
+}
+
+impl RetryOutcome {
+    /// Whether the test failed at least once before passing
+    #[must_use]
+    pub const fn was_flaky(&self) -> bool {
+        self.attempts.len() > 1
+    }
+}
+
+/// Serializes read-modify-write cycles on history files within this process, so tests
+/// running in parallel do not lose each other's updates
+static STORE_LOCK: Mutex<()> = Mutex::new(());
+
+/// > 📚 Reference
+///
+/// Per-test pass/fail history and quarantine registry, persisted to a JSON file.
+///
+/// Every mutation re-reads the file before writing it back, so several trackers (one per
+/// test, say) can share a file within a process.
+#[derive(Debug, Clone)]
+pub struct FlakinessTracker {
+    path: PathBuf,
+    store: FlakinessStore,
+    max_retries: u32,
+    quarantine_period: Duration,
+    history_limit: usize,
+    auto_quarantine: Option<f64>,
+}
+
+impl FlakinessTracker {
+    /// Open (or start) the history file at `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn open(path: impl AsRef<Path>) -> FlakinessResult<Self> {
+        let path = path.as_ref().to_path_buf();
+        let store = load(&path)?;
+        Ok(Self {
+            path,
+            store,
+            max_retries: DEFAULT_MAX_RETRIES,
+            quarantine_period: DEFAULT_QUARANTINE_PERIOD,
+            history_limit: DEFAULT_HISTORY_LIMIT,
+            auto_quarantine: None,
+        })
+    }
+
+    /// Retry quarantined tests up to `max_retries` times after a failure
+    #[must_use]
+    pub const fn with_max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Deadline for new quarantines, counted from when the test is quarantined
+    #[must_use]
+    pub const fn with_quarantine_period(mut self, period: Duration) -> Self {
+        self.quarantine_period = period;
+        self
+    }
+
+    /// Keep at most `limit` attempts per test (older ones are dropped)
+    #[must_use]
+    pub const fn with_history_limit(mut self, limit: usize) -> Self {
+        self.history_limit = limit;
+        self
+    }
+
+    /// Quarantine tests automatically once their score reaches `threshold`
+    #[must_use]
+    pub const fn with_auto_quarantine(mut self, threshold: f64) -> Self {
+        self.auto_quarantine = Some(threshold);
+        self
+    }
+
+    /// Path of the history file
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// History of `test` (`None` if never recorded or quarantined)
+    #[must_use]
+    pub fn history(&self, test: &str) -> Option<&TestHistory> {
+        self.store.tests.get(test)
+    }
+
+    /// Flakiness score of `test` (`0.0` if unknown)
+    #[must_use]
+    pub fn score(&self, test: &str) -> f64 {
+        self.history(test).map_or(0.0, TestHistory::score)
+    }
+
+    /// Tests whose score is at least `threshold`, flakiest first
+    #[must_use]
+    pub fn flaky_tests(&self, threshold: f64) -> Vec<(&str, f64)> {
+        let mut flaky: Vec<(&str, f64)> = self
+            .store
+            .tests
+            .iter()
+            .map(|(name, history)| (name.as_str(), history.score()))
+            .filter(|(_, score)| *score >= threshold && *score > 0.0)
+            .collect();
+        flaky.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        flaky
+    }
+
+    /// Whether `test` is quarantined
+    #[must_use]
+    pub fn is_quarantined(&self, test: &str) -> bool {
+        self.quarantine_of(test).is_some()
+    }
+
+    /// Active quarantine of `test`
+    #[must_use]
+    pub fn quarantine_of(&self, test: &str) -> Option<&Quarantine> {
+        self.history(test).and_then(|history| history.quarantine.as_ref())
+    }
+
+    /// Quarantined tests with their quarantine, by name
+    #[must_use]
+    pub fn quarantined(&self) -> Vec<(&str, &Quarantine)> {
+        self.store
+            .tests
+            .iter()
+            .filter_map(|(name, history)| history.quarantine.as_ref().map(|q| (name.as_str(), q)))
+            .collect()
+    }
+
+    /// Record one attempt of `test`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history file cannot be read or written.
+    pub fn record(&mut self, test: &str, passed: bool) -> FlakinessResult<()> {
+        self.record_attempt(test, passed, 0)
+    }
+
+    /// Quarantine `test` for the configured period
+    ///
+    /// Re-quarantining an already quarantined test keeps its original deadline.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history file cannot be read or written.
+    pub fn quarantine(&mut self, test: &str, reason: &str) -> FlakinessResult<()> {
+        let deadline = now_millis().saturating_add(millis(self.quarantine_period));
+        self.update(|store| {
+            let history = store.tests.entry(test.to_string()).or_default();
+            if history.quarantine.is_none() {
+                history.quarantine =
+                    Some(Quarantine { reason: reason.to_string(), since: now_millis(), deadline });
+            }
+        })
+    }
+
+    /// Quarantine `test` until `deadline`, replacing any existing quarantine
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history file cannot be read or written.
+    pub fn quarantine_until(
+        &mut self,
+        test: &str,
+        reason: &str,
+        deadline: SystemTime,
+    ) -> FlakinessResult<()> {
+        let deadline = deadline.duration_since(UNIX_EPOCH).map_or(0, millis);
+        self.update(|store| {
+            store.tests.entry(test.to_string()).or_default().quarantine =
+                Some(Quarantine { reason: reason.to_string(), since: now_millis(), deadline });
+        })
+    }
+
+    /// Release `test` from quarantine
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history file cannot be read or written.
+    pub fn release(&mut self, test: &str) -> FlakinessResult<()> {
+        self.update(|store| {
+            if let Some(history) = store.tests.get_mut(test) {
+                history.quarantine = None;
+            }
+        })
+    }
+
+    /// Fail if any quarantine has outlived its deadline
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FlakinessError::QuarantineExpired`] naming every expired test.
+    pub fn check_deadlines(&self) -> FlakinessResult<()> {
+        let expired: Vec<String> = self
+            .quarantined()
+            .into_iter()
+            .filter(|(_, quarantine)| quarantine.is_expired())
+            .map(|(name, _)| name.to_string())
+            .collect();
+        if expired.is_empty() {
+            Ok(())
+        } else {
+            Err(FlakinessError::QuarantineExpired(expired))
+        }
+    }
+
+    /// Run `body` as `test`, recording every attempt
+    ///
+    /// A failure is a panic. Quarantined tests are retried up to the configured number of
+    /// times; other tests run once. If the last attempt fails, its panic is resumed so the
+    /// test fails as usual (after the attempts are recorded).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FlakinessError::QuarantineExpired`] without running `body` if the test's
+    /// quarantine deadline has passed, or an error if the history file cannot be written.
+    pub fn run(&mut self, test: &str, mut body: impl FnMut()) -> FlakinessResult<RetryOutcome> {
+        self.refresh()?;
+        let quarantined = match self.quarantine_of(test) {
+            Some(quarantine) if quarantine.is_expired() => {
+                return Err(FlakinessError::QuarantineExpired(vec![test.to_string()]));
+            }
+            Some(_) => true,
+            None => false,
+        };
+        let retries = if quarantined { self.max_retries } else { 0 };
+
+        let mut attempts = Vec::new();
+        for retry in 0..=retries {
+            let result = panic::catch_unwind(AssertUnwindSafe(&mut body));
+            let passed = result.is_ok();
+            attempts.push(passed);
+            self.record_attempt(test, passed, retry)?;
+            match result {
+                Ok(()) => break,
+                Err(payload) if retry == retries => panic::resume_unwind(payload),
+                Err(_) => {}
+            }
+        }
+        Ok(RetryOutcome { attempts, quarantined })
+    }
+
+    fn record_attempt(&mut self, test: &str, passed: bool, retry: u32) -> FlakinessResult<()> {
+        let (limit, auto_quarantine) = (self.history_limit, self.auto_quarantine);
+        let deadline = now_millis().saturating_add(millis(self.quarantine_period));
+        self.update(|store| {
+            let history = store.tests.entry(test.to_string()).or_default();
+            history.attempts.push(TestAttempt { passed, retry, timestamp: now_millis() });
+            let excess = history.attempts.len().saturating_sub(limit);
+            history.attempts.drain(..excess);
+            if let Some(threshold) = auto_quarantine {
+                let score = history.score();
+                if history.quarantine.is_none() && score > 0.0 && score >= threshold {
+                    history.quarantine = Some(Quarantine {
+                        reason: format!("auto-quarantined: flakiness score {score:.2}"),
+                        since: now_millis(),
+                        deadline,
+                    });
+                }
+            }
+        })
+    }
+
+    /// Reload the history file
+    fn refresh(&mut self) -> FlakinessResult<()> {
+        let _lock = STORE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        self.store = load(&self.path)?;
+        Ok(())
+    }
+
+    /// Re-read the file, apply `change`, and write it back
+    fn update(&mut self, change: impl FnOnce(&mut FlakinessStore)) -> FlakinessResult<()> {
+        let _lock = STORE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let mut store = load(&self.path)?;
+        change(&mut store);
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(io_err(parent))?;
+        }
+        let raw = serde_json::to_string_pretty(&store)?;
+        fs::write(&self.path, raw).map_err(io_err(&self.path))?;
+        self.store = store;
+        Ok(())
+    }
+}
+
+fn load(path: &Path) -> FlakinessResult<FlakinessStore> {
+    if !path.exists() {
+        return Ok(FlakinessStore::default());
+    }
+    let raw = fs::read_to_string(path).map_err(io_err(path))?;
+    Ok(serde_json::from_str(&raw)?)
+}
+
+#[allow(clippy::cast_possible_truncation)] // u64 milliseconds cover 584 million years
+const fn millis(duration: Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, millis)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_history_persists_and_scores_flips, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("flakiness.json");
+        let mut tracker = FlakinessTracker::open(&path).unwrap().with_history_limit(4);
+
+        // Act
+        for passed in [true, true, false, true, false] {
+            tracker.record("flaky", passed).unwrap();
+        }
+        tracker.record("stable", true).unwrap();
+        tracker.record("stable", true).unwrap();
+        let reopened = FlakinessTracker::open(&path).unwrap();
+
+        // Assert
+        assert_eq!(reopened.history("flaky").unwrap().attempts.len(), 4);
+        assert!((reopened.score("flaky") - 1.0).abs() < f64::EPSILON);
+        assert!(reopened.score("stable").abs() < f64::EPSILON);
+        assert_eq!(reopened.flaky_tests(0.5), vec![("flaky", 1.0)]);
+    });
+
+    test!(test_quarantined_tests_are_retried_and_every_attempt_recorded, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut tracker = FlakinessTracker::open(dir.path().join("flakiness.json"))
+            .unwrap()
+            .with_max_retries(3);
+        tracker.quarantine("orders::test_sync", "races the broker").unwrap();
+        let mut calls = 0;
+
+        // Act
+        let outcome = tracker
+            .run("orders::test_sync", || {
+                calls += 1;
+                assert!(calls >= 3, "attempt {calls} fails");
+            })
+            .unwrap();
+
+        // Assert
+        assert_eq!(outcome.attempts, vec![false, false, true]);
+        assert!(outcome.was_flaky() && outcome.quarantined);
+        let retries: Vec<u32> = tracker
+            .history("orders::test_sync")
+            .unwrap()
+            .attempts
+            .iter()
+            .map(|attempt| attempt.retry)
+            .collect();
+        assert_eq!(retries, vec![0, 1, 2]);
+    });
+
+    test!(test_unquarantined_failure_is_not_retried, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut tracker = FlakinessTracker::open(dir.path().join("flakiness.json")).unwrap();
+
+        // Act
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            tracker.run("orders::test_create", || panic!("boom")).unwrap();
+        }));
+
+        // Assert
+        assert!(result.is_err());
+        assert_eq!(tracker.history("orders::test_create").unwrap().attempts.len(), 1);
+    });
+
+    test!(test_expired_quarantine_fails_the_run, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut tracker = FlakinessTracker::open(dir.path().join("flakiness.json")).unwrap();
+        tracker.quarantine("fresh", "new").unwrap();
+        tracker
+            .quarantine_until("stale", "old", UNIX_EPOCH + Duration::from_secs(1))
+            .unwrap();
+
+        // Act
+        let gate = tracker.check_deadlines();
+        let run = tracker.run("stale", || {});
+
+        // Assert
+        assert!(
+            matches!(gate, Err(FlakinessError::QuarantineExpired(ref tests)) if tests == &["stale"])
+        );
+        assert!(matches!(run, Err(FlakinessError::QuarantineExpired(_))));
+        tracker.release("stale").unwrap();
+        assert!(tracker.check_deadlines().is_ok());
+        assert!(tracker.is_quarantined("fresh"));
+    });
+
+    test!(test_auto_quarantine_at_threshold, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let mut tracker = FlakinessTracker::open(dir.path().join("flakiness.json"))
+            .unwrap()
+            .with_auto_quarantine(0.5);
+
+        // Act
+        tracker.record("orders::test_sync", true).unwrap();
+        tracker.record("orders::test_sync", false).unwrap();
+
+        // Assert
+        let quarantine = tracker.quarantine_of("orders::test_sync").unwrap();
+        assert_eq!(quarantine.reason, "auto-quarantined: flakiness score 1.00");
+    });
+}
@@ -2,6 +2,44 @@
 //!
 //! Generates test code from specifications.
 //! Uses const fn for compile-time test data generation.
+//!
+//! # Property-Test Stubs from Type Definitions
+//!
+//! [`TestGenerator::generate_property_tests`] takes a struct or enum definition
+//! ([`TypeSpec::parse`]) and emits a proptest `Arbitrary` impl for it plus property
+//! tests checking roundtrip invariants ([`RoundtripInvariant`]) through
+//! `property::ProptestStrategy`. The output is source text to paste into (or
+//! `include!` from) the crate that owns the type.
+//!
+//! ```rust
+//! use chicago_tdd_tools::generator::{RoundtripInvariant, TestGenerator, TypeSpec};
+//!
+//! let spec = TypeSpec::parse("pub struct Order { id: u64, items: Vec<String> }").unwrap();
+//! let code = TestGenerator::new()
+//!     .generate_property_tests(&spec, &[RoundtripInvariant::SerdeJson])
+//!     .unwrap();
+//! assert!(code.contains("impl Arbitrary for Order"));
+//! assert!(code.contains("fn order_serde_json_roundtrip()"));
+//! ```
+
+#[cfg(feature = "aaa-lint")]
+use crate::testing::aaa_lint::{lint_source, AaaLintReport, AaaLintResult};
+use std::fmt::Write as _;
+use thiserror::Error;
+
+/// Errors from deriving generators out of type definitions
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum GeneratorError {
+    /// The definition could not be parsed
+    #[error("🚨 Cannot parse type definition: {0}")]
+    Parse(String),
+    /// The definition parsed but cannot be generated for
+    #[error("🚨 Unsupported type definition: {0}")]
+    Unsupported(String),
+}
+
+/// Result type for generator operations
+pub type GeneratorResult<T> = Result<T, GeneratorError>;
 
 /// Test generator
 pub struct TestGenerator {
@@ -38,12 +76,360 @@ impl TestGenerator {
     }
 }
 
+#[cfg(feature = "aaa-lint")]
+impl TestGenerator {
+    /// Check the Arrange-Act-Assert structure of the tests in `source`
+    ///
+    /// See [`aaa_lint`](crate::testing::aaa_lint) for the rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `source` is not a valid Rust file.
+    pub fn lint_aaa(source: &str) -> AaaLintResult<AaaLintReport> {
+        lint_source(source)
+    }
+
+    /// Check the Arrange-Act-Assert structure of the tests generated so far
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the generated code does not parse.
+    pub fn lint_generated(&self) -> AaaLintResult<AaaLintReport> {
+        lint_source(&self.tests.join("\n"))
+    }
+}
+
 impl Default for TestGenerator {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Fields of a struct or enum variant
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeFields {
+    /// `Name` / `Name;`
+    Unit,
+    /// `Name(A, B)` - field types in order
+    Tuple(Vec<String>),
+    /// `Name { a: A, b: B }` - `(field, type)` pairs in order
+    Named(Vec<(String, String)>),
+}
+
+/// Parsed struct or enum definition
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TypeSpec {
+    /// A struct
+    Struct {
+        /// Type name
+        name: String,
+        /// Struct fields
+        fields: TypeFields,
+    },
+    /// An enum
+    Enum {
+        /// Type name
+        name: String,
+        /// `(variant, fields)` pairs in declaration order
+        variants: Vec<(String, TypeFields)>,
+    },
+}
+
+/// Roundtrip invariant to emit a property test for
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoundtripInvariant {
+    /// `serde_json::from_str(&serde_json::to_string(&value)?)? == value`
+    SerdeJson,
+    /// Building from a value's fields via `Type::builder()` yields equal getters
+    ///
+    /// Assumes one setter and one getter per field, named after the field.
+    /// Only valid for structs with named fields.
+    BuilderGetters,
+}
+
+impl TypeSpec {
+    /// Parse a non-generic struct or enum definition
+    ///
+    /// Attributes, doc comments, line comments, visibility, and explicit enum
+    /// discriminants are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeneratorError::Parse`] for malformed input and
+    /// [`GeneratorError::Unsupported`] for generic types and empty enums.
+    pub fn parse(source: &str) -> GeneratorResult<Self> {
+        let source = strip_attributes(&strip_line_comments(source));
+        let words: Vec<&str> = source.split_whitespace().collect();
+        let keyword_at = words
+            .iter()
+            .position(|w| *w == "struct" || *w == "enum")
+            .ok_or_else(|| GeneratorError::Parse("expected `struct` or `enum`".to_string()))?;
+        let is_enum = words[keyword_at] == "enum";
+        let rest = words[keyword_at + 1..].join(" ");
+        let name_len =
+            rest.find(|c: char| !(c.is_alphanumeric() || c == '_')).unwrap_or(rest.len());
+        let (name, body) = rest.split_at(name_len);
+        if name.is_empty() {
+            return Err(GeneratorError::Parse("missing type name".to_string()));
+        }
+        let name = name.to_string();
+        let body = body.trim();
+        if body.starts_with('<') {
+            return Err(GeneratorError::Unsupported(format!("`{name}` is generic")));
+        }
+        if is_enum {
+            let inner = delimited(body, '{', '}')
+                .ok_or_else(|| GeneratorError::Parse(format!("`{name}` has no enum body")))?;
+            let variants = split_top_level(inner)
+                .into_iter()
+                .map(|variant| {
+                    let variant = variant.split_once('=').map_or(variant, |(v, _)| v).trim();
+                    let name_len = variant
+                        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                        .unwrap_or(variant.len());
+                    let (variant_name, fields) = variant.split_at(name_len);
+                    Ok((variant_name.to_string(), parse_fields(fields.trim())?))
+                })
+                .collect::<GeneratorResult<Vec<_>>>()?;
+            if variants.is_empty() {
+                return Err(GeneratorError::Unsupported(format!("`{name}` has no variants")));
+            }
+            Ok(Self::Enum { name, variants })
+        } else {
+            let fields = parse_fields(body.trim_end_matches(';').trim())?;
+            Ok(Self::Struct { name, fields })
+        }
+    }
+
+    /// Type name
+    #[must_use]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Struct { name, .. } | Self::Enum { name, .. } => name,
+        }
+    }
+}
+
+fn strip_line_comments(source: &str) -> String {
+    source
+        .lines()
+        .map(|line| line.find("//").map_or(line, |at| &line[..at]))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn strip_attributes(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    let mut chars = source.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '#' && chars.peek() == Some(&'[') {
+            let mut depth = 0usize;
+            for inner in chars.by_ref() {
+                match inner {
+                    '[' => depth += 1,
+                    ']' => {
+                        depth -= 1;
+                        if depth == 0 {
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Contents between `open` at the start of `body` and its matching `close`
+fn delimited(body: &str, open: char, close: char) -> Option<&str> {
+    let body = body.strip_prefix(open)?;
+    let end = body.rfind(close)?;
+    Some(&body[..end])
+}
+
+/// Split on commas outside of `<>`, `()`, `[]`, and `{}`
+fn split_top_level(body: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0;
+    for (i, c) in body.char_indices() {
+        match c {
+            '<' | '(' | '[' | '{' => depth += 1,
+            '>' | ')' | ']' | '}' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push(body[start..i].trim());
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(body[start..].trim());
+    parts.retain(|part| !part.is_empty());
+    parts
+}
+
+fn strip_visibility(field: &str) -> &str {
+    let Some(rest) = field.strip_prefix("pub") else {
+        return field;
+    };
+    let rest = rest.trim_start();
+    if rest.starts_with('(') {
+        rest.find(')').map_or(rest, |end| rest[end + 1..].trim_start())
+    } else {
+        rest
+    }
+}
+
+fn parse_fields(body: &str) -> GeneratorResult<TypeFields> {
+    if body.is_empty() {
+        return Ok(TypeFields::Unit);
+    }
+    if let Some(inner) = delimited(body, '(', ')') {
+        let types = split_top_level(inner).into_iter().map(|ty| strip_visibility(ty).to_string());
+        return Ok(TypeFields::Tuple(types.collect()));
+    }
+    if let Some(inner) = delimited(body, '{', '}') {
+        let fields = split_top_level(inner)
+            .into_iter()
+            .map(|field| {
+                strip_visibility(field)
+                    .split_once(':')
+                    .map(|(name, ty)| (name.trim().to_string(), ty.trim().to_string()))
+                    .ok_or_else(|| GeneratorError::Parse(format!("field `{field}` has no type")))
+            })
+            .collect::<GeneratorResult<Vec<_>>>()?;
+        return Ok(TypeFields::Named(fields));
+    }
+    Err(GeneratorError::Parse(format!("unexpected fields `{body}`")))
+}
+
+fn snake_case(name: &str) -> String {
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Strategy expression constructing `path` from `fields`
+///
+/// Up to 12 fields are drawn from one tuple strategy (proptest's limit); larger
+/// types nest tuples of up to 12.
+fn construct_strategy(path: &str, fields: &TypeFields) -> String {
+    let types: Vec<&str> = match fields {
+        TypeFields::Unit => return format!("Just({path})"),
+        TypeFields::Tuple(types) => types.iter().map(String::as_str).collect(),
+        TypeFields::Named(fields) => fields.iter().map(|(_, ty)| ty.as_str()).collect(),
+    };
+    let bindings: Vec<String> = (0..types.len()).map(|i| format!("f{i}")).collect();
+    let (strategy, pattern) = if types.len() <= 12 {
+        let anys: Vec<String> = types.iter().map(|ty| format!("any::<{ty}>()")).collect();
+        (format!("({},)", anys.join(", ")), format!("({},)", bindings.join(", ")))
+    } else {
+        let (strategies, patterns): (Vec<String>, Vec<String>) = types
+            .chunks(12)
+            .zip(bindings.chunks(12))
+            .map(|(tys, names)| {
+                let anys: Vec<String> = tys.iter().map(|ty| format!("any::<{ty}>()")).collect();
+                (format!("({},)", anys.join(", ")), format!("({},)", names.join(", ")))
+            })
+            .unzip();
+        (format!("({},)", strategies.join(", ")), format!("({},)", patterns.join(", ")))
+    };
+    let value = match fields {
+        TypeFields::Named(fields) => {
+            let inits: Vec<String> = fields
+                .iter()
+                .zip(&bindings)
+                .map(|((name, _), b)| format!("{name}: {b}"))
+                .collect();
+            format!("{path} {{ {} }}", inits.join(", "))
+        }
+        _ => format!("{path}({})", bindings.join(", ")),
+    };
+    format!("{strategy}.prop_map(|{pattern}| {value})")
+}
+
+impl TestGenerator {
+    /// Generate an `Arbitrary` impl and roundtrip property tests for a type
+    ///
+    /// The emitted module is `#[cfg(test)]`, imports the type via `use super::*`,
+    /// and runs each invariant through `property::ProptestStrategy`, so the
+    /// owning crate needs `proptest` and chicago-tdd-tools' `property-testing`
+    /// feature as dev-dependencies (plus `serde_json` for
+    /// [`RoundtripInvariant::SerdeJson`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GeneratorError::Unsupported`] if an invariant does not apply to
+    /// the type (e.g. [`RoundtripInvariant::BuilderGetters`] on an enum).
+    pub fn generate_property_tests(
+        &mut self,
+        spec: &TypeSpec,
+        invariants: &[RoundtripInvariant],
+    ) -> GeneratorResult<String> {
+        let name = spec.name();
+        let snake = snake_case(name);
+        let strategy = match spec {
+            TypeSpec::Struct { fields, .. } => construct_strategy(name, fields),
+            TypeSpec::Enum { variants, .. } => {
+                let arms: Vec<String> = variants
+                    .iter()
+                    .map(|(variant, fields)| {
+                        construct_strategy(&format!("{name}::{variant}"), fields)
+                    })
+                    .collect();
+                format!(
+                    "prop_oneof![\n                {},\n            ]",
+                    arms.join(",\n                ")
+                )
+            }
+        };
+
+        let mut code = format!(
+            "// Generated by chicago_tdd_tools::generator from `{name}`\n#[cfg(test)]\nmod {snake}_properties {{\n    use super::*;\n    use chicago_tdd_tools::property::ProptestStrategy;\n    use proptest::prelude::*;\n\n    impl Arbitrary for {name} {{\n        type Parameters = ();\n        type Strategy = BoxedStrategy<Self>;\n\n        fn arbitrary_with((): Self::Parameters) -> Self::Strategy {{\n            {strategy}.boxed()\n        }}\n    }}\n",
+        );
+        for invariant in invariants {
+            let test = match invariant {
+                RoundtripInvariant::SerdeJson => format!(
+                    "\n    #[test]\n    fn {snake}_serde_json_roundtrip() {{\n        ProptestStrategy::new().test_roundtrip(\n            |value: &{name}| serde_json::to_string(value),\n            |json: String| serde_json::from_str::<{name}>(&json),\n        );\n    }}\n",
+                ),
+                RoundtripInvariant::BuilderGetters => {
+                    let TypeSpec::Struct { fields: TypeFields::Named(fields), .. } = spec else {
+                        return Err(GeneratorError::Unsupported(format!(
+                            "builder/getter invariant needs a struct with named fields, `{name}` has none"
+                        )));
+                    };
+                    let setters = fields.iter().fold(String::new(), |mut out, (field, _)| {
+                        let _ = write!(out, "\n                .{field}(value.{field}.clone())");
+                        out
+                    });
+                    let checks: Vec<String> =
+                        fields.iter().map(|(field, _)| format!("built.{field}() == &value.{field}")).collect();
+                    let checks = if checks.is_empty() { "true".to_string() } else { checks.join("\n                && ") };
+                    format!(
+                        "\n    #[test]\n    fn {snake}_builder_getter_consistency() {{\n        ProptestStrategy::new().test_default(|value: {name}| {{\n            let built = {name}::builder(){setters}\n                .build();\n            {checks}\n        }});\n    }}\n",
+                    )
+                }
+            };
+            code.push_str(&test);
+        }
+        code.push_str("}\n");
+        self.tests.push(code.clone());
+        Ok(code)
+    }
+}
+
 /// Generate a test array at compile time
 ///
 /// Uses const fn to generate arrays of any size at compile time.
@@ -113,6 +499,19 @@ mod tests {
     // 1. TEST GENERATOR - Test code generation
     // ========================================================================
 
+    #[cfg(feature = "aaa-lint")]
+    #[test]
+    fn test_generated_tests_pass_aaa_lint() {
+        let mut generator = TestGenerator::new();
+        generator.generate_test("test_order_total", "sums line items");
+        generator.generate_test("test_order_empty", "rejects empty orders");
+
+        let report = generator.lint_generated().unwrap();
+
+        assert_eq!(report.tests, 2);
+        assert!(report.is_clean(), "{}", report.to_json());
+    }
+
     #[test]
     fn test_test_generator_new() {
         let generator = TestGenerator::new();
@@ -249,6 +648,74 @@ mod tests {
         assert!(test_code.contains("fn ()"));
     }
 
+    // ========================================================================
+    // 5. PROPERTY-TEST STUBS - Type definitions to Arbitrary impls
+    // ========================================================================
+
+    #[test]
+    fn test_type_spec_parse_struct() {
+        let spec = TypeSpec::parse(
+            "/// An order\n#[derive(Debug, Clone)]\npub struct Order {\n    pub(crate) id: u64, // key\n    items: HashMap<String, Vec<u8>>,\n}",
+        )
+        .unwrap();
+        assert_eq!(
+            spec,
+            TypeSpec::Struct {
+                name: "Order".to_string(),
+                fields: TypeFields::Named(vec![
+                    ("id".to_string(), "u64".to_string()),
+                    ("items".to_string(), "HashMap<String, Vec<u8>>".to_string()),
+                ]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_type_spec_parse_enum_and_rejects_generics() {
+        let spec =
+            TypeSpec::parse("enum Status { Active = 1, Failed(String, u8), Moved { to: u32 } }")
+                .unwrap();
+        let TypeSpec::Enum { variants, .. } = spec else { panic!("expected enum") };
+        assert_eq!(variants[0], ("Active".to_string(), TypeFields::Unit));
+        assert_eq!(
+            variants[1],
+            ("Failed".to_string(), TypeFields::Tuple(vec!["String".to_string(), "u8".to_string()]))
+        );
+        assert!(matches!(
+            TypeSpec::parse("struct Wrapper<T>(T);"),
+            Err(GeneratorError::Unsupported(_))
+        ));
+        assert!(matches!(TypeSpec::parse("fn main() {}"), Err(GeneratorError::Parse(_))));
+    }
+
+    #[test]
+    fn test_generate_property_tests_emits_arbitrary_and_stubs() {
+        let mut generator = TestGenerator::new();
+        let spec = TypeSpec::parse("struct HttpRequest { path: String, retries: u8 }").unwrap();
+        let code = generator
+            .generate_property_tests(
+                &spec,
+                &[RoundtripInvariant::SerdeJson, RoundtripInvariant::BuilderGetters],
+            )
+            .unwrap();
+        assert!(code.contains("mod http_request_properties"));
+        assert!(code.contains("(any::<String>(), any::<u8>(),).prop_map(|(f0, f1,)| HttpRequest { path: f0, retries: f1 })"));
+        assert!(code.contains("fn http_request_serde_json_roundtrip()"));
+        assert!(code.contains(".retries(value.retries.clone())"));
+        assert!(code.contains("built.path() == &value.path"));
+        assert_eq!(generator.get_tests().len(), 1);
+
+        let status = TypeSpec::parse("enum Status { Idle, Busy(u32) }").unwrap();
+        assert!(generator
+            .generate_property_tests(&status, &[RoundtripInvariant::SerdeJson])
+            .unwrap()
+            .contains("Just(Status::Idle)"));
+        assert!(matches!(
+            generator.generate_property_tests(&status, &[RoundtripInvariant::BuilderGetters]),
+            Err(GeneratorError::Unsupported(_))
+        ));
+    }
+
     #[test]
     fn test_test_generator_empty_spec() {
         let mut generator = TestGenerator::new();
@@ -0,0 +1,383 @@
+//! Hermetic Test Sandbox
+//!
+//! Runs a test body in a kernel-enforced sandbox so "this test is hermetic" is checked
+//! rather than taken on trust. Inside the sandbox the body may read anything, but may
+//! only write beneath its own temporary workspace (plus `/dev/null` and any paths
+//! explicitly allowed), and may not open TCP connections or bind TCP ports.
+//!
+//! **Required feature**: `hermetic`
+//!
+//! # How violations are reported
+//!
+//! Enforcement uses [Landlock](https://landlock.io) (Linux 5.13+ for files, 6.7+ for
+//! TCP). A forbidden `open`/`connect`/`bind` fails with `Permission denied`; when the
+//! body then fails, the failure is re-raised as a hermeticity violation that names the
+//! sandbox policy, instead of surfacing as an unexplained I/O error.
+//!
+//! The sandbox is applied to a dedicated thread running the body, so other tests in
+//! the process are unaffected. Threads the body spawns inherit the restrictions; child
+//! processes do too.
+//!
+//! Where the kernel cannot enforce the policy (non-Linux, Landlock disabled) the body
+//! still runs and [`HermeticReport::enforcement`] says so. Set
+//! `CHICAGO_TDD_REQUIRE_HERMETIC=1` in CI (or call [`HermeticSandbox::require_enforcement`])
+//! to fail instead.
+//!
+//! UDP (including DNS lookups) and Unix sockets are not covered by Landlock and are not
+//! restricted.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::hermetic_test;
+//!
+//! hermetic_test!(test_renders_report_into_workspace, |workspace| {
+//!     let out = workspace.join("report.txt");
+//!     std::fs::write(&out, "ok").unwrap();
+//!     assert_eq!(std::fs::read_to_string(out).unwrap(), "ok");
+//! });
+//! ```
+
+use crate::core::failure::TddFailure;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Environment variable that makes missing kernel enforcement a failure
+pub const HERMETIC_REQUIRE_ENV_VAR: &str = "CHICAGO_TDD_REQUIRE_HERMETIC";
+
+/// Paths that stay writable in every sandbox
+const ALWAYS_WRITABLE: &[&str] = &["/dev/null"];
+
+/// How much of the sandbox policy the kernel enforced
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxEnforcement {
+    /// Write and network restrictions are both enforced
+    Full,
+    /// Some restrictions are enforced (e.g. writes but not TCP on kernels before 6.7)
+    Partial,
+    /// Nothing is enforced; the body ran unsandboxed
+    NotEnforced,
+}
+
+impl fmt::Display for SandboxEnforcement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Full => "fully enforced",
+            Self::Partial => "partially enforced",
+            Self::NotEnforced => "not enforced",
+        })
+    }
+}
+
+/// What the sandbox allowed and how much of it was enforced
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HermeticReport {
+    /// Temporary workspace the body could write to (removed afterwards)
+    pub workspace: PathBuf,
+    /// Every writable path, workspace first
+    pub writable: Vec<PathBuf>,
+    /// Whether TCP connect/bind was denied by policy
+    pub network_denied: bool,
+    /// How much of the policy the kernel enforced
+    pub enforcement: SandboxEnforcement,
+}
+
+impl fmt::Display for HermeticReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let writable: Vec<String> = self.writable.iter().map(|p| p.display().to_string()).collect();
+        write!(
+            f,
+            "hermetic sandbox ({}): writes allowed under {}; network {}",
+            self.enforcement,
+            writable.join(", "),
+            if self.network_denied { "denied" } else { "allowed" }
+        )
+    }
+}
+
+/// Errors setting up a hermetic sandbox
+#[derive(Error, Debug)]
+pub enum HermeticError {
+    /// The temporary workspace or sandbox thread could not be created
+    #[error("🚨 Hermetic sandbox setup failed: {0}")]
+    Io(#[from] std::io::Error),
+    /// The kernel rejected the sandbox policy
+    #[error("🚨 Hermetic sandbox policy rejected: {0}")]
+    Policy(String),
+    /// Enforcement was required but the kernel could not provide it
+    #[error("🚨 Hermetic sandbox required but {}\n   💡 FIX: Run on Linux 6.7+ with Landlock enabled, or unset {HERMETIC_REQUIRE_ENV_VAR}", report.enforcement)]
+    NotEnforced {
+        /// What the sandbox would have allowed
+        report: HermeticReport,
+    },
+}
+
+/// Result type for hermetic sandbox operations
+pub type HermeticResult<T> = Result<T, HermeticError>;
+
+/// Builder for a sandbox that denies network access and writes outside a workspace
+#[derive(Debug, Clone, Default)]
+pub struct HermeticSandbox {
+    writable: Vec<PathBuf>,
+    allow_network: bool,
+    require_enforcement: bool,
+}
+
+impl HermeticSandbox {
+    /// Sandbox with the default policy: workspace-only writes, no TCP
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Also allow writes beneath `path`
+    #[must_use]
+    pub fn allow_write(mut self, path: impl Into<PathBuf>) -> Self {
+        self.writable.push(path.into());
+        self
+    }
+
+    /// Do not restrict TCP (for tests that only need to be filesystem-hermetic)
+    #[must_use]
+    pub const fn allow_network(mut self) -> Self {
+        self.allow_network = true;
+        self
+    }
+
+    /// Fail instead of running unsandboxed when the kernel cannot fully enforce the policy
+    ///
+    /// Also enabled by `CHICAGO_TDD_REQUIRE_HERMETIC=1`.
+    #[must_use]
+    pub const fn require_enforcement(mut self) -> Self {
+        self.require_enforcement = true;
+        self
+    }
+
+    /// Run `body` in the sandbox with a fresh workspace, returning its value and the report
+    ///
+    /// If `body` panics, the panic is re-raised after running the body, annotated with
+    /// the sandbox policy (and as a hermeticity violation when a permission was denied).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HermeticError`] if the sandbox cannot be set up, or if enforcement is
+    /// required and the kernel cannot provide it.
+    ///
+    /// # Panics
+    ///
+    /// Re-raises panics from `body`.
+    pub fn run<T: Send>(
+        &self,
+        body: impl FnOnce(&Path) -> T + Send,
+    ) -> HermeticResult<(T, HermeticReport)> {
+        let workspace = tempfile::Builder::new().prefix("chicago-tdd-hermetic-").tempdir()?;
+        let mut report = HermeticReport {
+            workspace: workspace.path().to_path_buf(),
+            writable: std::iter::once(workspace.path().to_path_buf())
+                .chain(self.writable.iter().cloned())
+                .chain(ALWAYS_WRITABLE.iter().map(PathBuf::from))
+                .collect(),
+            network_denied: !self.allow_network,
+            enforcement: SandboxEnforcement::NotEnforced,
+        };
+        let required = self.require_enforcement
+            || std::env::var(HERMETIC_REQUIRE_ENV_VAR).is_ok_and(|v| !v.is_empty() && v != "0");
+
+        // Landlock restricts the calling thread, so the body gets a thread of its own
+        // (named like the test so per-test state keyed on the thread name still works)
+        let mut thread = std::thread::Builder::new();
+        if let Some(name) = std::thread::current().name() {
+            thread = thread.name(name.to_string());
+        }
+        let (enforcement, outcome) = std::thread::scope(|scope| {
+            let report = &report;
+            let sandboxed = thread.spawn_scoped(scope, move || {
+                let enforcement = restrict_current_thread(report)?;
+                if required && enforcement != SandboxEnforcement::Full {
+                    return Err(HermeticError::NotEnforced {
+                        report: HermeticReport { enforcement, ..report.clone() },
+                    });
+                }
+                Ok((enforcement, TddFailure::catch(|| body(&report.workspace))))
+            })?;
+            sandboxed.join().unwrap_or_else(|payload| {
+                Err(HermeticError::Policy(TddFailure::from_payload(payload).to_string()))
+            })
+        })?;
+        report.enforcement = enforcement;
+        drop(workspace);
+
+        match outcome {
+            Ok(value) => Ok((value, report)),
+            Err(failure) => raise_annotated(failure, &report),
+        }
+    }
+
+    /// Run `body` like [`HermeticSandbox::run`], panicking on sandbox setup errors
+    ///
+    /// Used by [`hermetic_test!`](crate::hermetic_test).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sandbox cannot be set up, and re-raises panics from `body`.
+    #[track_caller]
+    #[allow(clippy::panic)] // Test harness: setup failures fail the test
+    pub fn run_test<T: Send>(&self, body: impl FnOnce(&Path) -> T + Send) -> T {
+        match self.run(body) {
+            Ok((value, _)) => value,
+            Err(e) => panic!("{e}"),
+        }
+    }
+}
+
+/// Re-raise a body failure with the sandbox policy attached
+fn raise_annotated(failure: TddFailure, report: &HermeticReport) -> ! {
+    let denied = ["Permission denied", "PermissionDenied", "os error 13"]
+        .iter()
+        .any(|needle| failure.message().contains(needle));
+    let message = if denied && report.enforcement != SandboxEnforcement::NotEnforced {
+        format!(
+            "🚨 Hermeticity violation: the test was denied network access or a write outside its workspace\n   {report}\n   {}",
+            failure.message()
+        )
+    } else {
+        format!("{}\n   ({report})", failure.message())
+    };
+    failure
+        .with_context("hermetic", report.to_string())
+        .with_message(message)
+        .raise()
+}
+
+#[cfg(target_os = "linux")]
+fn restrict_current_thread(report: &HermeticReport) -> HermeticResult<SandboxEnforcement> {
+    use landlock::{
+        path_beneath_rules, AccessFs, AccessNet, Ruleset, RulesetAttr, RulesetCreatedAttr,
+        RulesetStatus, ABI,
+    };
+
+    let policy = |e: landlock::RulesetError| HermeticError::Policy(e.to_string());
+    let write = AccessFs::from_write(ABI::V6);
+    let mut ruleset = Ruleset::default().handle_access(write).map_err(policy)?;
+    if report.network_denied {
+        ruleset = ruleset
+            .handle_access(AccessNet::BindTcp | AccessNet::ConnectTcp)
+            .map_err(policy)?;
+    }
+    let status = ruleset
+        .create()
+        .and_then(|created| created.add_rules(path_beneath_rules(&report.writable, write)))
+        .and_then(landlock::RulesetCreated::restrict_self)
+        .map_err(policy)?;
+    Ok(match status.ruleset {
+        RulesetStatus::FullyEnforced => SandboxEnforcement::Full,
+        RulesetStatus::PartiallyEnforced => SandboxEnforcement::Partial,
+        RulesetStatus::NotEnforced => SandboxEnforcement::NotEnforced,
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+#[allow(clippy::unnecessary_wraps)] // Same signature as the Linux implementation
+const fn restrict_current_thread(_report: &HermeticReport) -> HermeticResult<SandboxEnforcement> {
+    Ok(SandboxEnforcement::NotEnforced)
+}
+
+/// Define a `#[test]` whose body runs in a [`HermeticSandbox`]
+///
+/// The closure parameter is the test's temporary workspace (`&Path`), the only place
+/// it may write. TCP is denied.
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::hermetic_test;
+///
+/// hermetic_test!(test_cache_persists_to_workspace, |workspace| {
+///     std::fs::write(workspace.join("cache.json"), "{}").unwrap();
+/// });
+/// ```
+#[macro_export]
+macro_rules! hermetic_test {
+    ($name:ident, |$workspace:ident| $body:block) => {
+        #[test]
+        fn $name() {
+            $crate::testing::hermetic::HermeticSandbox::new()
+                .run_test(|$workspace: &::std::path::Path| $body);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_workspace_is_writable_and_removed, {
+        // Arrange
+        let sandbox = HermeticSandbox::new();
+
+        // Act
+        let (written, report) = sandbox
+            .run(|workspace| {
+                std::fs::write(workspace.join("out.txt"), "data").is_ok()
+                    && workspace.join("out.txt").exists()
+            })
+            .unwrap();
+
+        // Assert
+        assert!(written);
+        assert!(!report.workspace.exists());
+        assert_eq!(report.writable[0], report.workspace);
+        assert!(report.to_string().contains("network denied"), "{report}");
+    });
+
+    test!(test_writes_and_connections_outside_policy_are_denied, {
+        // Arrange
+        let outside = tempfile::tempdir().unwrap();
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let target = outside.path().join("escape.txt");
+
+        // Act
+        let ((write, connect), report) = HermeticSandbox::new()
+            .run(|_| {
+                (std::fs::write(&target, "x").err(), std::net::TcpStream::connect(address).err())
+            })
+            .unwrap();
+
+        // Assert: only checkable where the kernel enforces the policy
+        if report.enforcement != SandboxEnforcement::NotEnforced {
+            assert_eq!(write.map(|e| e.kind()), Some(std::io::ErrorKind::PermissionDenied));
+            assert!(!target.exists());
+        }
+        if report.enforcement == SandboxEnforcement::Full {
+            assert_eq!(connect.map(|e| e.kind()), Some(std::io::ErrorKind::PermissionDenied));
+        }
+    });
+
+    test!(test_denied_failures_are_reported_as_violations, {
+        // Arrange
+        let outside = tempfile::tempdir().unwrap();
+        let target = outside.path().join("escape.txt");
+
+        // Act
+        let failure = TddFailure::catch(|| {
+            HermeticSandbox::new()
+                .allow_network()
+                .run(|_| std::fs::write(&target, "x").unwrap())
+                .map(|(_, report)| report)
+        });
+
+        // Assert
+        match failure {
+            Err(failure) => {
+                assert!(failure.message().starts_with("🚨 Hermeticity violation"), "{failure}");
+                assert!(failure.context()["hermetic"].contains("network allowed"));
+            }
+            Ok(report) => {
+                assert_eq!(report.unwrap().enforcement, SandboxEnforcement::NotEnforced);
+            }
+        }
+    });
+}
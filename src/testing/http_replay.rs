@@ -0,0 +1,871 @@
+//! HTTP Record/Replay
+//!
+//! VCR-style cassettes for tests that talk to third-party HTTP APIs. The first run
+//! records real interactions to a JSON cassette file; later runs replay them, so the
+//! suite is fast, deterministic, and works offline while still exercising real
+//! response shapes.
+//!
+//! The core ([`ReplaySession`]) is transport-agnostic: the caller hands it a request
+//! and a closure performing the real call, used only while recording. With the
+//! `http-replay` feature, [`ReplayProxy`] wraps a session in a local HTTP server that
+//! forwards to the real API, so any client can be pointed at it unchanged.
+//!
+//! Secrets never reach the cassette: `authorization`, `cookie`, `set-cookie`, and
+//! `x-api-key` headers are redacted by default, and further headers, query
+//! parameters, and body content can be redacted with hooks. Matching happens on the
+//! redacted request, so replays match regardless of the live secret value.
+//!
+//! # Modes
+//!
+//! - [`ReplayMode::Auto`] (default): record when the cassette file is missing,
+//!   otherwise replay.
+//! - [`ReplayMode::Record`]: always call the real API and overwrite the cassette.
+//!   Setting `CHICAGO_TDD_RECORD=1` forces this mode for every session.
+//! - [`ReplayMode::Replay`]: never touch the network.
+//!
+//! In strict mode, a replayed request with no matching recording is an error (instead of
+//! being forwarded and appended), and [`ReplaySession::finish`] fails if recorded
+//! interactions were never replayed.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::testing::http_replay::{HttpReplay, ReplayRequest, ReplayResponse};
+//!
+//! # let dir = std::env::temp_dir().join(format!("cassette-doc-{}", std::process::id()));
+//! let session = HttpReplay::cassette(dir.join("weather.json"))
+//!     .redact_query_param("api_key")
+//!     .strict()
+//!     .open()
+//!     .unwrap();
+//!
+//! let request = ReplayRequest::new("GET", "https://api.example.com/weather?city=Chicago&api_key=s3cret");
+//! let response = session
+//!     .handle(&request, |_| Ok(ReplayResponse::new(200).body(r#"{"temp_f":41}"#)))
+//!     .unwrap();
+//!
+//! assert_eq!(response.status, 200);
+//! session.finish().unwrap();
+//! # std::fs::remove_dir_all(&dir).ok();
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use thiserror::Error;
+
+/// Environment variable forcing [`ReplayMode::Record`]
+pub const RECORD_ENV_VAR: &str = "CHICAGO_TDD_RECORD";
+
+/// Placeholder written in place of redacted values
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Headers redacted unless [`HttpReplay::keep_default_redactions`] is turned off
+const DEFAULT_REDACTED_HEADERS: &[&str] = &["authorization", "cookie", "set-cookie", "x-api-key"];
+
+/// HTTP replay errors
+#[derive(Error, Debug)]
+pub enum ReplayError {
+    /// Cassette could not be read or written
+    #[error("🚨 Cassette I/O failed for {path}: {source}")]
+    Io {
+        /// Cassette path
+        path: PathBuf,
+        /// Underlying error
+        #[source]
+        source: std::io::Error,
+    },
+    /// Cassette file is not a valid cassette
+    #[error("🚨 Invalid cassette {path}: {message}")]
+    InvalidCassette {
+        /// Cassette path
+        path: PathBuf,
+        /// Parse error
+        message: String,
+    },
+    /// A request had no matching recording and could not be forwarded
+    #[error("🚨 Unexpected request {request} (no matching interaction in {path})")]
+    UnexpectedRequest {
+        /// Redacted request line
+        request: String,
+        /// Cassette path
+        path: PathBuf,
+    },
+    /// The real call failed while recording
+    #[error("🚨 Upstream request {request} failed: {message}")]
+    Upstream {
+        /// Redacted request line
+        request: String,
+        /// Failure reported by the transport
+        message: String,
+    },
+    /// Strict replay finished with problems
+    #[error("🚨 Cassette {path} was not replayed cleanly:\n{}", .problems.join("\n"))]
+    Unclean {
+        /// Cassette path
+        path: PathBuf,
+        /// One line per unexpected request or unplayed interaction
+        problems: Vec<String>,
+    },
+}
+
+/// Result type for HTTP replay operations
+pub type ReplayResult<T> = Result<T, ReplayError>;
+
+/// Whether a session records or replays
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReplayMode {
+    /// Record if the cassette is missing, otherwise replay
+    #[default]
+    Auto,
+    /// Always call the real API and overwrite the cassette
+    Record,
+    /// Only serve recorded interactions
+    Replay,
+}
+
+/// A request as stored in a cassette
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayRequest {
+    /// HTTP method (uppercase)
+    pub method: String,
+    /// Full URL including query string
+    pub url: String,
+    /// Headers keyed by lowercase name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// Body as UTF-8 text
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+}
+
+impl ReplayRequest {
+    /// Request with no headers or body
+    #[must_use]
+    pub fn new(method: &str, url: impl Into<String>) -> Self {
+        Self { method: method.to_ascii_uppercase(), url: url.into(), ..Self::default() }
+    }
+
+    /// Add a header (name is lowercased)
+    #[must_use]
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.insert(name.to_ascii_lowercase(), value.into());
+        self
+    }
+
+    /// Set the body
+    #[must_use]
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+impl fmt::Display for ReplayRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.method, self.url)
+    }
+}
+
+/// A response as stored in a cassette
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayResponse {
+    /// Status code
+    pub status: u16,
+    /// Headers keyed by lowercase name
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+    /// Body as UTF-8 text
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub body: String,
+}
+
+impl ReplayResponse {
+    /// Response with `status` and no headers or body
+    #[must_use]
+    pub fn new(status: u16) -> Self {
+        Self { status, ..Self::default() }
+    }
+
+    /// Add a header (name is lowercased)
+    #[must_use]
+    pub fn header(mut self, name: &str, value: impl Into<String>) -> Self {
+        self.headers.insert(name.to_ascii_lowercase(), value.into());
+        self
+    }
+
+    /// Set the body
+    #[must_use]
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+/// One recorded request/response pair
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HttpInteraction {
+    /// Redacted request
+    pub request: ReplayRequest,
+    /// Redacted response
+    pub response: ReplayResponse,
+}
+
+/// Cassette file contents
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Cassette {
+    /// Interactions in recording order
+    pub interactions: Vec<HttpInteraction>,
+}
+
+impl Cassette {
+    /// Load a cassette from `path`
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a cassette.
+    pub fn load(path: &Path) -> ReplayResult<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|source| ReplayError::Io { path: path.to_path_buf(), source })?;
+        serde_json::from_str(&text).map_err(|e| ReplayError::InvalidCassette {
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Write the cassette to `path` as pretty JSON, creating parent directories
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> ReplayResult<()> {
+        let io = |source| ReplayError::Io { path: path.to_path_buf(), source };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(io)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|e| {
+            ReplayError::InvalidCassette { path: path.to_path_buf(), message: e.to_string() }
+        })?;
+        std::fs::write(path, json + "\n").map_err(io)
+    }
+}
+
+/// Body redaction hook
+type BodyRedactor = Box<dyn Fn(&str) -> String + Send + Sync>;
+
+/// Free-form redaction hook
+type InteractionRedactor = Box<dyn Fn(&mut HttpInteraction) + Send + Sync>;
+
+/// Builder for a [`ReplaySession`]
+pub struct HttpReplay {
+    path: PathBuf,
+    mode: ReplayMode,
+    strict: bool,
+    match_body: bool,
+    headers: Vec<String>,
+    query_params: Vec<String>,
+    body_redactors: Vec<BodyRedactor>,
+    interaction_redactors: Vec<InteractionRedactor>,
+}
+
+impl fmt::Debug for HttpReplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpReplay")
+            .field("path", &self.path)
+            .field("mode", &self.mode)
+            .field("strict", &self.strict)
+            .field("match_body", &self.match_body)
+            .field("headers", &self.headers)
+            .field("query_params", &self.query_params)
+            .field("body_redactors", &self.body_redactors.len())
+            .field("interaction_redactors", &self.interaction_redactors.len())
+            .finish()
+    }
+}
+
+impl HttpReplay {
+    /// Session backed by the cassette file at `path`
+    #[must_use]
+    pub fn cassette(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            mode: ReplayMode::Auto,
+            strict: false,
+            match_body: false,
+            headers: DEFAULT_REDACTED_HEADERS.iter().map(ToString::to_string).collect(),
+            query_params: Vec::new(),
+            body_redactors: Vec::new(),
+            interaction_redactors: Vec::new(),
+        }
+    }
+
+    /// Set the mode (overridden by `CHICAGO_TDD_RECORD=1`)
+    #[must_use]
+    pub const fn mode(mut self, mode: ReplayMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Fail on unexpected requests and on interactions that were never replayed
+    #[must_use]
+    pub const fn strict(mut self) -> Self {
+        self.strict = true;
+        self
+    }
+
+    /// Also require request bodies to match (method and URL always must)
+    #[must_use]
+    pub const fn match_body(mut self) -> Self {
+        self.match_body = true;
+        self
+    }
+
+    /// Redact a request and response header (case-insensitive)
+    #[must_use]
+    pub fn redact_header(mut self, name: &str) -> Self {
+        self.headers.push(name.to_ascii_lowercase());
+        self
+    }
+
+    /// Redact a query parameter in request URLs
+    #[must_use]
+    pub fn redact_query_param(mut self, name: impl Into<String>) -> Self {
+        self.query_params.push(name.into());
+        self
+    }
+
+    /// Rewrite request and response bodies before they are stored or matched
+    #[must_use]
+    pub fn redact_body(mut self, redact: impl Fn(&str) -> String + Send + Sync + 'static) -> Self {
+        self.body_redactors.push(Box::new(redact));
+        self
+    }
+
+    /// Arbitrary rewrite of each interaction before it is stored
+    ///
+    /// Runs after the header, query, and body redactions. Replayed requests are
+    /// matched after the same rewrite, with an empty response.
+    #[must_use]
+    pub fn redact_with(
+        mut self,
+        redact: impl Fn(&mut HttpInteraction) + Send + Sync + 'static,
+    ) -> Self {
+        self.interaction_redactors.push(Box::new(redact));
+        self
+    }
+
+    /// Do not redact `authorization`, `cookie`, `set-cookie`, and `x-api-key` by default
+    #[must_use]
+    pub fn keep_default_redactions(mut self, keep: bool) -> Self {
+        if !keep {
+            self.headers.retain(|h| !DEFAULT_REDACTED_HEADERS.contains(&h.as_str()));
+        }
+        self
+    }
+
+    /// Resolve the mode and load the cassette when replaying
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if replaying and the cassette cannot be loaded.
+    pub fn open(self) -> ReplayResult<ReplaySession> {
+        let forced = std::env::var(RECORD_ENV_VAR).is_ok_and(|v| v == "1" || v == "true");
+        let recording = forced
+            || match self.mode {
+                ReplayMode::Record => true,
+                ReplayMode::Replay => false,
+                ReplayMode::Auto => !self.path.exists(),
+            };
+        let cassette = if recording { Cassette::default() } else { Cassette::load(&self.path)? };
+        let played = vec![false; cassette.interactions.len()];
+        Ok(ReplaySession {
+            recording,
+            state: Mutex::new(SessionState {
+                cassette,
+                played,
+                unexpected: Vec::new(),
+                dirty: recording,
+            }),
+            config: self,
+        })
+    }
+
+    fn redact(&self, interaction: &mut HttpInteraction) {
+        let request = &mut interaction.request;
+        let response = &mut interaction.response;
+        for name in &self.headers {
+            for headers in [&mut request.headers, &mut response.headers] {
+                if let Some(value) = headers.get_mut(name) {
+                    REDACTED.clone_into(value);
+                }
+            }
+        }
+        request.url = redact_query(&request.url, &self.query_params);
+        for redact in &self.body_redactors {
+            request.body = redact(&request.body);
+            response.body = redact(&response.body);
+        }
+        for redact in &self.interaction_redactors {
+            redact(interaction);
+        }
+    }
+
+    fn matches(&self, recorded: &ReplayRequest, request: &ReplayRequest) -> bool {
+        recorded.method == request.method
+            && recorded.url == request.url
+            && (!self.match_body || recorded.body == request.body)
+    }
+}
+
+/// Replace the values of `params` in the query string of `url`
+fn redact_query(url: &str, params: &[String]) -> String {
+    let Some((base, query)) = url.split_once('?') else {
+        return url.to_string();
+    };
+    let query: Vec<String> = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if params.iter().any(|p| p == key) => format!("{key}={REDACTED}"),
+            _ => pair.to_string(),
+        })
+        .collect();
+    format!("{base}?{}", query.join("&"))
+}
+
+struct SessionState {
+    cassette: Cassette,
+    played: Vec<bool>,
+    unexpected: Vec<String>,
+    dirty: bool,
+}
+
+/// An open cassette serving or recording requests
+///
+/// Recorded interactions are written when the session is finished or dropped.
+pub struct ReplaySession {
+    config: HttpReplay,
+    recording: bool,
+    state: Mutex<SessionState>,
+}
+
+impl fmt::Debug for ReplaySession {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReplaySession")
+            .field("path", &self.config.path)
+            .field("recording", &self.recording)
+            .field("interactions", &self.state().cassette.interactions.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl ReplaySession {
+    /// Whether this session calls the real API
+    #[must_use]
+    pub const fn is_recording(&self) -> bool {
+        self.recording
+    }
+
+    /// Cassette file path
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.config.path
+    }
+
+    /// Snapshot of the (redacted) interactions held by the session
+    #[must_use]
+    pub fn interactions(&self) -> Vec<HttpInteraction> {
+        self.state().cassette.interactions.clone()
+    }
+
+    /// Serve `request`, calling `upstream` only when it must reach the real API
+    ///
+    /// While recording, `upstream` is always called and its real (unredacted) response
+    /// returned. While replaying, the first unplayed recording matching the redacted
+    /// request is returned; a request with no match is forwarded to `upstream` and
+    /// appended, or rejected in strict mode.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `upstream` fails or a strict replay sees an unexpected request.
+    pub fn handle(
+        &self,
+        request: &ReplayRequest,
+        upstream: impl FnOnce(&ReplayRequest) -> Result<ReplayResponse, String>,
+    ) -> ReplayResult<ReplayResponse> {
+        let mut probe =
+            HttpInteraction { request: request.clone(), response: ReplayResponse::default() };
+        self.config.redact(&mut probe);
+
+        if !self.recording {
+            if let Some(replayed) = self.replay(&probe.request) {
+                return replayed;
+            }
+        }
+
+        let response = upstream(request).map_err(|message| ReplayError::Upstream {
+            request: probe.request.to_string(),
+            message,
+        })?;
+        let mut interaction =
+            HttpInteraction { request: request.clone(), response: response.clone() };
+        self.config.redact(&mut interaction);
+        {
+            let mut state = self.state();
+            state.cassette.interactions.push(interaction);
+            state.played.push(true);
+            state.dirty = true;
+        }
+        Ok(response)
+    }
+
+    /// Recorded response for `request`, or the strict-mode rejection
+    fn replay(&self, request: &ReplayRequest) -> Option<ReplayResult<ReplayResponse>> {
+        let mut state = self.state();
+        let SessionState { cassette, played, unexpected, .. } = &mut *state;
+        let found = cassette.interactions.iter().enumerate().find(|(index, recorded)| {
+            !played[*index] && self.config.matches(&recorded.request, request)
+        });
+        if let Some((index, recorded)) = found {
+            played[index] = true;
+            return Some(Ok(recorded.response.clone()));
+        }
+        if !self.config.strict {
+            return None;
+        }
+        unexpected.push(request.to_string());
+        drop(state);
+        Some(Err(ReplayError::UnexpectedRequest {
+            request: request.to_string(),
+            path: self.config.path.clone(),
+        }))
+    }
+
+    /// Save any new recordings and, in strict mode, check the replay was clean
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if saving fails, or in strict mode if there were unexpected
+    /// requests or recorded interactions that were never replayed.
+    pub fn finish(self) -> ReplayResult<()> {
+        self.save()?;
+        if !self.config.strict {
+            return Ok(());
+        }
+        let problems: Vec<String> = {
+            let state = self.state();
+            let unplayed = state
+                .cassette
+                .interactions
+                .iter()
+                .zip(&state.played)
+                .filter(|(_, played)| !**played)
+                .map(|(interaction, _)| format!("   ⏭️  never replayed: {}", interaction.request));
+            state
+                .unexpected
+                .iter()
+                .map(|request| format!("   ❌ unexpected: {request}"))
+                .chain(unplayed)
+                .collect()
+        };
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ReplayError::Unclean { path: self.config.path.clone(), problems })
+        }
+    }
+
+    fn save(&self) -> ReplayResult<()> {
+        let mut state = self.state();
+        if state.dirty {
+            state.cassette.save(&self.config.path)?;
+            state.dirty = false;
+        }
+        drop(state);
+        Ok(())
+    }
+
+    fn state(&self) -> MutexGuard<'_, SessionState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for ReplaySession {
+    fn drop(&mut self) {
+        if let Err(e) = self.save() {
+            crate::alert_warning!("{}", e);
+        }
+    }
+}
+
+#[cfg(feature = "http-replay")]
+pub use proxy::ReplayProxy;
+
+#[cfg(feature = "http-replay")]
+mod proxy {
+    use super::{ReplayRequest, ReplayResponse, ReplayResult, ReplaySession};
+    use crate::integration::http_server::{
+        HttpResponse, HttpServerFixture, HttpServerResult, RecordedRequest,
+    };
+    use std::sync::Arc;
+
+    /// Response headers not copied from recordings (the server sets its own)
+    const HOP_HEADERS: &[&str] = &["connection", "content-length", "transfer-encoding"];
+
+    /// Local HTTP server recording or replaying a real API
+    ///
+    /// Point the client under test at [`ReplayProxy::base_url`] instead of the real
+    /// API's base URL. Requests are forwarded to `upstream` while recording.
+    #[derive(Debug)]
+    pub struct ReplayProxy {
+        session: Arc<ReplaySession>,
+        server: HttpServerFixture,
+    }
+
+    impl ReplayProxy {
+        /// Start a proxy for `upstream` (e.g. `https://api.example.com`)
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the local server cannot start.
+        pub fn start(session: ReplaySession, upstream: &str) -> HttpServerResult<Self> {
+            let session = Arc::new(session);
+            let upstream = upstream.trim_end_matches('/').to_string();
+            let handler_session = Arc::clone(&session);
+            let server = HttpServerFixture::builder()
+                .route("*", "/*", move |received| {
+                    let request = to_replay_request(&upstream, received);
+                    handler_session.handle(&request, forward).map_or_else(
+                        |e| HttpResponse::new(599).text(e.to_string()),
+                        |response| to_http_response(&response),
+                    )
+                })
+                .start()?;
+            Ok(Self { session, server })
+        }
+
+        /// Base URL to use instead of the upstream API
+        #[must_use]
+        pub fn base_url(&self) -> String {
+            self.server.base_url()
+        }
+
+        /// The underlying session
+        #[must_use]
+        pub fn session(&self) -> &ReplaySession {
+            &self.session
+        }
+
+        /// Stop the server and finish the session
+        ///
+        /// # Errors
+        ///
+        /// See [`ReplaySession::finish`].
+        pub fn finish(self) -> ReplayResult<()> {
+            let Self { session, server } = self;
+            drop(server);
+            Arc::try_unwrap(session).map_or(Ok(()), ReplaySession::finish)
+        }
+    }
+
+    fn to_replay_request(upstream: &str, received: &RecordedRequest) -> ReplayRequest {
+        let mut url = format!("{upstream}{}", received.path);
+        if let Some(query) = &received.query {
+            url = format!("{url}?{query}");
+        }
+        let mut request = ReplayRequest::new(&received.method, url).body(received.body_text());
+        request.headers = received
+            .headers
+            .iter()
+            .filter(|(name, _)| name.as_str() != "host" && !HOP_HEADERS.contains(&name.as_str()))
+            .map(|(name, value)| (name.clone(), value.clone()))
+            .collect();
+        request
+    }
+
+    fn to_http_response(response: &ReplayResponse) -> HttpResponse {
+        response
+            .headers
+            .iter()
+            .filter(|(name, _)| !HOP_HEADERS.contains(&name.as_str()))
+            .fold(HttpResponse::new(response.status), |acc, (name, value)| {
+                acc.header(name.clone(), value.clone())
+            })
+            .body(response.body.clone())
+    }
+
+    fn forward(request: &ReplayRequest) -> Result<ReplayResponse, String> {
+        let method =
+            reqwest::Method::from_bytes(request.method.as_bytes()).map_err(|e| e.to_string())?;
+        let client = reqwest::blocking::Client::new();
+        let mut builder = client.request(method, &request.url).body(request.body.clone());
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        let response = builder.send().map_err(|e| e.to_string())?;
+        let mut replayed = ReplayResponse::new(response.status().as_u16());
+        for (name, value) in response.headers() {
+            replayed = replayed.header(name.as_str(), String::from_utf8_lossy(value.as_bytes()));
+        }
+        Ok(replayed.body(response.text().map_err(|e| e.to_string())?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::cell::Cell;
+
+    fn weather(_: &ReplayRequest) -> Result<ReplayResponse, String> {
+        Ok(ReplayResponse::new(200)
+            .header("Set-Cookie", "session=abc123")
+            .body(r#"{"temp_f":41,"token":"tok_live_42"}"#))
+    }
+
+    test!(test_records_then_replays_without_upstream, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("weather.json");
+        let request = ReplayRequest::new("get", "https://api.example.com/weather?city=Chicago");
+        let calls = Cell::new(0);
+        let upstream = |r: &ReplayRequest| {
+            calls.set(calls.get() + 1);
+            weather(r)
+        };
+
+        // Act
+        let recorder = HttpReplay::cassette(&path).open().unwrap();
+        let recorded = recorder.handle(&request, upstream).unwrap();
+        recorder.finish().unwrap();
+        let player = HttpReplay::cassette(&path).open().unwrap();
+        let replayed = player.handle(&request, upstream).unwrap();
+
+        // Assert
+        assert!(!player.is_recording());
+        assert_eq!(calls.get(), 1);
+        assert_eq!(replayed.body, recorded.body);
+        assert_eq!(recorded.headers["set-cookie"], "session=abc123");
+        assert_eq!(replayed.headers["set-cookie"], REDACTED);
+    });
+
+    test!(test_secrets_are_redacted_in_cassette, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secrets.json");
+        let request = ReplayRequest::new("POST", "https://api.example.com/v1?api_key=s3cret&x=1")
+            .header("Authorization", "Bearer s3cret")
+            .body(r#"{"password":"hunter2"}"#);
+
+        // Act
+        let session = HttpReplay::cassette(&path)
+            .redact_query_param("api_key")
+            .redact_body(|body| body.replace("hunter2", REDACTED).replace("tok_live_42", REDACTED))
+            .open()
+            .unwrap();
+        session.handle(&request, weather).unwrap();
+        session.finish().unwrap();
+        let saved = std::fs::read_to_string(&path).unwrap();
+
+        // Assert
+        for secret in ["s3cret", "hunter2", "tok_live_42", "abc123"] {
+            assert!(!saved.contains(secret), "{secret} leaked into cassette:\n{saved}");
+        }
+        assert!(saved.contains("api_key=[REDACTED]&x=1"));
+        // Replays match on the redacted request, whatever the live secret is
+        let replay = HttpReplay::cassette(&path).redact_query_param("api_key").open().unwrap();
+        let other_key =
+            ReplayRequest::new("POST", "https://api.example.com/v1?api_key=rotated&x=1");
+        assert_eq!(replay.handle(&other_key, |_| Err("offline".into())).unwrap().status, 200);
+    });
+
+    test!(test_strict_mode_rejects_unexpected_and_unplayed, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("strict.json");
+        let recorder = HttpReplay::cassette(&path).mode(ReplayMode::Record).open().unwrap();
+        recorder
+            .handle(&ReplayRequest::new("GET", "https://api.example.com/a"), weather)
+            .unwrap();
+        recorder
+            .handle(&ReplayRequest::new("GET", "https://api.example.com/b"), weather)
+            .unwrap();
+        recorder.finish().unwrap();
+
+        // Act
+        let player = HttpReplay::cassette(&path).strict().open().unwrap();
+        let unexpected = player
+            .handle(&ReplayRequest::new("DELETE", "https://api.example.com/a"), weather)
+            .unwrap_err();
+        player
+            .handle(&ReplayRequest::new("GET", "https://api.example.com/a"), weather)
+            .unwrap();
+        let finished = player.finish().unwrap_err();
+
+        // Assert
+        assert!(matches!(unexpected, ReplayError::UnexpectedRequest { .. }));
+        let message = finished.to_string();
+        assert!(message.contains("unexpected: DELETE https://api.example.com/a"), "{message}");
+        assert!(message.contains("never replayed: GET https://api.example.com/b"), "{message}");
+    });
+
+    test!(test_non_strict_replay_appends_new_interactions, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("append.json");
+        Cassette::default().save(&path).unwrap();
+
+        // Act
+        let session = HttpReplay::cassette(&path).open().unwrap();
+        session
+            .handle(&ReplayRequest::new("GET", "https://api.example.com/new"), weather)
+            .unwrap();
+        drop(session);
+
+        // Assert
+        assert_eq!(Cassette::load(&path).unwrap().interactions.len(), 1);
+    });
+
+    #[cfg(feature = "http-replay")]
+    test!(test_proxy_replays_after_upstream_is_gone, {
+        // Arrange
+        use crate::integration::http_server::{HttpResponse, HttpServerFixture};
+        use std::io::{Read, Write};
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("proxy.json");
+        let get = |base: &str| {
+            let addr = base.trim_start_matches("http://");
+            let mut stream = std::net::TcpStream::connect(addr).unwrap();
+            write!(
+                stream,
+                "GET /users/7?fields=name HTTP/1.1\r\nHost: {addr}\r\nConnection: close\r\n\r\n"
+            )
+            .unwrap();
+            let mut raw = String::new();
+            stream.read_to_string(&mut raw).unwrap();
+            raw
+        };
+        let upstream = HttpServerFixture::builder()
+            .route("GET", "/users/*", |req| HttpResponse::ok().text(format!("user {}", req.path)))
+            .start()
+            .unwrap();
+
+        // Act
+        let upstream_url = upstream.base_url();
+        let recorder = HttpReplay::cassette(&path).open().unwrap();
+        let proxy = ReplayProxy::start(recorder, &upstream_url).unwrap();
+        let live = get(&proxy.base_url());
+        proxy.finish().unwrap();
+        drop(upstream);
+        let player = HttpReplay::cassette(&path).strict().open().unwrap();
+        let proxy = ReplayProxy::start(player, &upstream_url).unwrap();
+        let replayed = get(&proxy.base_url());
+
+        // Assert
+        assert!(live.ends_with("user /users/7"), "{live}");
+        assert!(replayed.starts_with("HTTP/1.1 200"), "{replayed}");
+        assert!(replayed.ends_with("user /users/7"), "{replayed}");
+        proxy.finish().unwrap();
+    });
+}
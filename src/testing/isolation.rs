@@ -0,0 +1,531 @@
+//! Test Isolation Auditing
+//!
+//! Fixtures promise that every test starts from a clean slate, but a test that sets an
+//! environment variable, leaves a file behind, or bumps a `static` counter breaks that
+//! promise for whichever test runs next. [`IsolationAuditor`] runs a designated set of
+//! tests twice in different orders, in one process so leaked state carries over, and
+//! reports:
+//!
+//! - **Order-dependent tests**: tests that pass in one order and fail in the other, with
+//!   the leaking tests that ran before them only in the failing order as suspects.
+//! - **Leaks**: global state a test changed and did not restore: environment variables,
+//!   files under watched directories ([`IsolationAuditor::watch_dir`]), and values read by
+//!   state probes ([`IsolationAuditor::probe`], e.g. a fixture's static counter).
+//!
+//! The second pass runs the tests in reverse, or shuffled by
+//! [`IsolationAuditor::with_seed`]; the seed is in the report so an order replays exactly.
+//! Environment changes made by other threads while a test runs are attributed to that
+//! test, so audit tests that no other test in the process touches the environment of.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::testing::isolation::IsolationAuditor;
+//! use std::sync::atomic::{AtomicU32, Ordering};
+//!
+//! static CONNECTIONS: AtomicU32 = AtomicU32::new(0);
+//!
+//! let report = IsolationAuditor::new()
+//!     .probe("connections", || CONNECTIONS.load(Ordering::SeqCst).to_string())
+//!     .test("expects_no_connections", || {
+//!         assert_eq!(CONNECTIONS.load(Ordering::SeqCst), 0);
+//!     })
+//!     .test("opens_connection", || {
+//!         CONNECTIONS.fetch_add(1, Ordering::SeqCst); // never closed
+//!     })
+//!     .run();
+//!
+//! assert_eq!(report.order_dependent()[0].test, "expects_no_connections");
+//! assert_eq!(report.order_dependent()[0].suspects, ["opens_connection"]);
+//! assert_eq!(report.leaks("opens_connection")[0].key, "connections");
+//! ```
+
+use crate::core::failure::TddFailure;
+use crate::core::fs_snapshot::FsSnapshot;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::PathBuf;
+
+type TestBody = Box<dyn Fn()>;
+type StateProbe = Box<dyn Fn() -> String>;
+
+/// Kind of global state a test leaked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LeakKind {
+    /// Environment variable set, changed, or removed
+    Env,
+    /// File created, modified, or deleted under a watched directory
+    File,
+    /// Value read by a state probe changed
+    Probe,
+}
+
+impl fmt::Display for LeakKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Env => "env",
+            Self::File => "file",
+            Self::Probe => "probe",
+        })
+    }
+}
+
+/// Global state a test changed and did not restore
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateLeak {
+    /// What kind of state
+    pub kind: LeakKind,
+    /// Variable name, file path, or probe name
+    pub key: String,
+    /// Value before the test (`None` = absent)
+    pub before: Option<String>,
+    /// Value after the test (`None` = absent)
+    pub after: Option<String>,
+}
+
+impl fmt::Display for StateLeak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = |value: &Option<String>| value.as_deref().unwrap_or("<absent>").to_string();
+        write!(f, "{} {}: {} → {}", self.kind, self.key, value(&self.before), value(&self.after))
+    }
+}
+
+/// Outcome of one test in one pass
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestRun {
+    /// Position in the pass's order
+    pub position: usize,
+    /// Panic message, if the test failed
+    pub failure: Option<String>,
+    /// State the test leaked in this pass
+    pub leaks: Vec<StateLeak>,
+}
+
+impl TestRun {
+    /// Whether the test passed
+    #[must_use]
+    pub const fn passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+/// A test whose result depends on the order it ran in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OrderDependence {
+    /// The test
+    pub test: String,
+    /// Pass (0 or 1) in which it failed
+    pub failed_in: usize,
+    /// Why it failed
+    pub failure: String,
+    /// Leaking tests that ran before it in the failing pass but not in the passing one
+    pub suspects: Vec<String>,
+}
+
+impl fmt::Display for OrderDependence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} failed only in pass {}: {}", self.test, self.failed_in + 1, self.failure)?;
+        if !self.suspects.is_empty() {
+            write!(f, " (suspects: {})", self.suspects.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Result of [`IsolationAuditor::run`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IsolationReport {
+    /// Test order of each pass
+    pub orders: [Vec<String>; 2],
+    /// Seed that shuffled the second pass (`None` = reversed)
+    pub seed: Option<u64>,
+    /// Each test's run in each pass, by name
+    pub runs: BTreeMap<String, [TestRun; 2]>,
+}
+
+impl IsolationReport {
+    /// Tests that passed in one order and failed in the other
+    #[must_use]
+    pub fn order_dependent(&self) -> Vec<OrderDependence> {
+        let leaked = |test: &str, pass: usize| {
+            self.runs.get(test).is_some_and(|runs| !runs[pass].leaks.is_empty())
+        };
+        self.orders[0]
+            .iter()
+            .filter_map(|test| {
+                let runs = self.runs.get(test)?;
+                let failed_in = match (runs[0].passed(), runs[1].passed()) {
+                    (true, false) => 1,
+                    (false, true) => 0,
+                    _ => return None,
+                };
+                let before = |pass: usize| &self.orders[pass][..runs[pass].position];
+                let suspects = before(failed_in)
+                    .iter()
+                    .filter(|other| leaked(other, failed_in))
+                    .filter(|other| !before(1 - failed_in).contains(other))
+                    .cloned()
+                    .collect();
+                Some(OrderDependence {
+                    test: test.clone(),
+                    failed_in,
+                    failure: runs[failed_in].failure.clone().unwrap_or_default(),
+                    suspects,
+                })
+            })
+            .collect()
+    }
+
+    /// State `test` leaked in either pass, each leak once
+    #[must_use]
+    pub fn leaks(&self, test: &str) -> Vec<&StateLeak> {
+        let mut leaks: Vec<&StateLeak> = Vec::new();
+        for leak in self.runs.get(test).into_iter().flatten().flat_map(|run| &run.leaks) {
+            if !leaks.iter().any(|seen| seen.kind == leak.kind && seen.key == leak.key) {
+                leaks.push(leak);
+            }
+        }
+        leaks
+    }
+
+    /// Tests that leaked state, in declaration order
+    #[must_use]
+    pub fn leaking(&self) -> Vec<&str> {
+        self.orders[0]
+            .iter()
+            .filter(|test| !self.leaks(test).is_empty())
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Whether every test gave the same result in both orders and leaked nothing
+    #[must_use]
+    pub fn is_isolated(&self) -> bool {
+        self.order_dependent().is_empty() && self.leaking().is_empty()
+    }
+
+    /// Assert that the audited tests are isolated
+    ///
+    /// # Panics
+    ///
+    /// Panics with the order-dependent tests, the leaks, and both orders.
+    pub fn assert_isolated(&self) {
+        assert!(self.is_isolated(), "{self}");
+    }
+}
+
+impl fmt::Display for IsolationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_isolated() {
+            return write!(f, "✅ {} tests isolated in both orders", self.runs.len());
+        }
+        writeln!(f, "🚨 Test isolation violated")?;
+        for dependence in self.order_dependent() {
+            writeln!(f, "   📋 Order-dependent: {dependence}")?;
+        }
+        for test in self.leaking() {
+            for leak in self.leaks(test) {
+                writeln!(f, "   📋 Leak in {test}: {leak}")?;
+            }
+        }
+        writeln!(f, "   📋 Pass 1: {}", self.orders[0].join(", "))?;
+        match self.seed {
+            Some(seed) => writeln!(f, "   📋 Pass 2 (seed {seed}): {}", self.orders[1].join(", "))?,
+            None => writeln!(f, "   📋 Pass 2 (reversed): {}", self.orders[1].join(", "))?,
+        }
+        write!(f, "   💡 FIX: Restore global state in each test (or its fixture's teardown)")
+    }
+}
+
+/// Runs tests in two orders and reports order dependence and leaked global state
+pub struct IsolationAuditor {
+    tests: Vec<(String, TestBody)>,
+    probes: Vec<(String, StateProbe)>,
+    dirs: Vec<PathBuf>,
+    watch_env: bool,
+    seed: Option<u64>,
+}
+
+impl IsolationAuditor {
+    /// Auditor watching the environment, with no tests
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tests: Vec::new(),
+            probes: Vec::new(),
+            dirs: Vec::new(),
+            watch_env: true,
+            seed: None,
+        }
+    }
+
+    /// Add a test; a panic is a failure
+    #[must_use]
+    pub fn test(mut self, name: impl Into<String>, body: impl Fn() + 'static) -> Self {
+        self.tests.push((name.into(), Box::new(body)));
+        self
+    }
+
+    /// Watch global state read by `probe` (e.g. a fixture's static counter)
+    #[must_use]
+    pub fn probe(mut self, name: impl Into<String>, probe: impl Fn() -> String + 'static) -> Self {
+        self.probes.push((name.into(), Box::new(probe)));
+        self
+    }
+
+    /// Watch files under `dir` (e.g. a shared temp directory)
+    #[must_use]
+    pub fn watch_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.dirs.push(dir.into());
+        self
+    }
+
+    /// Do not watch environment variables
+    #[must_use]
+    pub const fn without_env(mut self) -> Self {
+        self.watch_env = false;
+        self
+    }
+
+    /// Shuffle the second pass with `seed` instead of reversing it
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Run every test once in declaration order, then once in the second order
+    #[must_use]
+    pub fn run(&self) -> IsolationReport {
+        let forward: Vec<usize> = (0..self.tests.len()).collect();
+        let second = self.seed.map_or_else(
+            || forward.iter().rev().copied().collect(),
+            |seed| shuffled(&forward, seed),
+        );
+        let mut runs: BTreeMap<String, Vec<TestRun>> = BTreeMap::new();
+        for order in [&forward, &second] {
+            for (position, index) in order.iter().enumerate() {
+                let (name, body) = &self.tests[*index];
+                let before = self.capture();
+                let failure =
+                    TddFailure::catch(body).err().map(|failure| failure.message().to_string());
+                let leaks = before.leaks(&self.capture());
+                runs.entry(name.clone()).or_default().push(TestRun { position, failure, leaks });
+            }
+        }
+        let names =
+            |order: &[usize]| order.iter().map(|index| self.tests[*index].0.clone()).collect();
+        IsolationReport {
+            orders: [names(&forward), names(&second)],
+            seed: self.seed,
+            runs: runs
+                .into_iter()
+                .filter_map(|(name, runs)| Some((name, <[TestRun; 2]>::try_from(runs).ok()?)))
+                .collect(),
+        }
+    }
+
+    fn capture(&self) -> GlobalState {
+        let env = if self.watch_env {
+            std::env::vars_os()
+                .map(|(key, value)| {
+                    (key.to_string_lossy().into_owned(), value.to_string_lossy().into_owned())
+                })
+                .collect()
+        } else {
+            BTreeMap::new()
+        };
+        let mut files = BTreeMap::new();
+        for dir in &self.dirs {
+            if let Ok(snapshot) = FsSnapshot::capture(dir) {
+                for (path, entry) in snapshot.files() {
+                    files.insert(dir.join(path).display().to_string(), entry.hash.clone());
+                }
+            }
+        }
+        let probes = self.probes.iter().map(|(name, probe)| (name.clone(), probe())).collect();
+        GlobalState { env, files, probes }
+    }
+}
+
+impl Default for IsolationAuditor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for IsolationAuditor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IsolationAuditor")
+            .field("tests", &self.tests.iter().map(|(name, _)| name).collect::<Vec<_>>())
+            .field("probes", &self.probes.iter().map(|(name, _)| name).collect::<Vec<_>>())
+            .field("dirs", &self.dirs)
+            .field("watch_env", &self.watch_env)
+            .field("seed", &self.seed)
+            .finish()
+    }
+}
+
+/// Global state captured around one test
+struct GlobalState {
+    env: BTreeMap<String, String>,
+    /// Watched file path → content hash
+    files: BTreeMap<String, String>,
+    probes: BTreeMap<String, String>,
+}
+
+impl GlobalState {
+    fn leaks(&self, after: &Self) -> Vec<StateLeak> {
+        let mut leaks = diff(LeakKind::Env, &self.env, &after.env);
+        leaks.extend(diff(LeakKind::File, &self.files, &after.files));
+        leaks.extend(diff(LeakKind::Probe, &self.probes, &after.probes));
+        leaks
+    }
+}
+
+fn diff(
+    kind: LeakKind,
+    before: &BTreeMap<String, String>,
+    after: &BTreeMap<String, String>,
+) -> Vec<StateLeak> {
+    let mut keys: Vec<&String> = before.keys().chain(after.keys()).collect();
+    keys.sort_unstable();
+    keys.dedup();
+    keys.into_iter()
+        .filter(|key| before.get(*key) != after.get(*key))
+        .map(|key| StateLeak {
+            kind,
+            key: key.clone(),
+            before: before.get(key).cloned(),
+            after: after.get(key).cloned(),
+        })
+        .collect()
+}
+
+/// Fisher-Yates shuffle driven by `seed`
+fn shuffled(order: &[usize], seed: u64) -> Vec<usize> {
+    let mut order = order.to_vec();
+    let mut rng = SplitMix64(seed);
+    for i in (1..order.len()).rev() {
+        // The modulus is at most `order.len()`, so the result fits in usize
+        #[allow(clippy::cast_possible_truncation)]
+        let j = (rng.next() % (i as u64 + 1)) as usize;
+        order.swap(i, j);
+    }
+    order
+}
+
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    const fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Test code: unwrap is acceptable
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+    use std::rc::Rc;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_isolated_tests_pass_audit() {
+        // Arrange
+        let auditor = IsolationAuditor::new()
+            .without_env()
+            .test("a", || assert_eq!(1 + 1, 2))
+            .test("b", || assert!(!"text".is_empty()));
+
+        // Act
+        let report = auditor.run();
+
+        // Assert
+        assert!(report.is_isolated(), "{report}");
+        assert_eq!(report.orders, [vec!["a", "b"], vec!["b", "a"]]);
+        report.assert_isolated();
+    }
+
+    #[test]
+    fn test_order_dependence_names_leaking_suspect() {
+        // Arrange: "writer" leaks a counter that "reader" expects to be zero
+        let counter = Rc::new(Cell::new(0));
+        let (probe, writer, reader) = (counter.clone(), counter.clone(), counter);
+        let auditor = IsolationAuditor::new()
+            .without_env()
+            .probe("counter", move || probe.get().to_string())
+            .test("reader", move || assert_eq!(reader.get(), 0, "counter not reset"))
+            .test("writer", move || writer.set(writer.get() + 1))
+            .test("bystander", || {});
+
+        // Act
+        let report = auditor.run();
+
+        // Assert
+        let dependent = report.order_dependent();
+        assert_eq!(dependent.len(), 1, "{report}");
+        assert_eq!(dependent[0].test, "reader");
+        assert_eq!(dependent[0].failed_in, 1);
+        assert!(dependent[0].failure.contains("counter not reset"));
+        assert_eq!(dependent[0].suspects, ["writer"]);
+        assert_eq!(report.leaking(), ["writer"]);
+        assert_eq!(report.leaks("writer").len(), 1);
+        assert!(!report.is_isolated());
+        assert!(report.to_string().contains("Leak in writer: probe counter: 0 → 1"));
+    }
+
+    #[test]
+    fn test_env_and_file_leaks_reported() {
+        // Arrange
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().to_path_buf();
+        let var = format!("CTT_ISOLATION_LEAK_{}", std::process::id());
+        let leaked = var.clone();
+        let auditor = IsolationAuditor::new()
+            .watch_dir(dir.path())
+            .test("sets_env", move || std::env::set_var(&leaked, "1"))
+            .test("writes_file", move || {
+                std::fs::write(path.join("left-behind.txt"), "x").unwrap()
+            });
+
+        // Act
+        let report = auditor.run();
+        std::env::remove_var(&var);
+
+        // Assert
+        assert!(report
+            .leaks("sets_env")
+            .iter()
+            .any(|leak| leak.kind == LeakKind::Env && leak.key == var && leak.before.is_none()));
+        let file = report.leaks("writes_file");
+        assert!(file
+            .iter()
+            .any(|leak| leak.kind == LeakKind::File && leak.key.ends_with("left-behind.txt")));
+        assert!(report.order_dependent().is_empty());
+    }
+
+    #[test]
+    fn test_seed_replays_the_same_order() {
+        // Arrange
+        let auditor = || {
+            (0..8).fold(IsolationAuditor::new().without_env().with_seed(7), |auditor, i| {
+                auditor.test(format!("t{i}"), || {})
+            })
+        };
+
+        // Act
+        let first = auditor().run();
+        let second = auditor().run();
+
+        // Assert
+        assert_eq!(first.orders[1], second.orders[1]);
+        assert_ne!(first.orders[1], first.orders[0]);
+        assert_eq!(first.seed, Some(7));
+    }
+}
@@ -1,34 +1,76 @@
 //! Advanced Testing Techniques
 //!
 //! Specialized testing methodologies that extend core capabilities:
-//! property-based testing, mutation testing, snapshot testing, concurrency
-//! testing, CLI testing, and test code generation.
+//! property-based testing, structured quantities, mutation testing, snapshot testing, concurrency
+//! testing, deterministic scheduling, cache/store consistency checking, rate limiter testing,
+//! HTTP record/replay, fault injection, flaky test tracking and quarantine, test isolation auditing, async cancellation safety, CLI testing, virtual time, hermetic sandboxing, socket leak detection,
+//! test code generation, AAA structure linting, real-collaborator linting, and compile-fail testing.
 
+#[cfg(feature = "aaa-lint")]
+pub mod aaa_lint;
+pub mod cancel_safety;
 #[cfg(feature = "cli-testing")]
 pub mod cli;
+pub mod collaborator_lint;
+pub mod compile_fail;
 #[cfg(feature = "concurrency-testing")]
 pub mod concurrency;
+pub mod consistency;
 pub mod continuous_learning;
+pub mod corpus;
 pub mod effects;
+pub mod flakiness;
 pub mod generator;
+#[cfg(feature = "hermetic")]
+pub mod hermetic;
+pub mod http_replay;
+pub mod isolation;
 pub mod mutation;
 pub mod property;
+pub mod quantity;
+pub mod rate_limit;
+#[cfg(feature = "leak-detection")]
+pub mod resource_leaks;
+#[cfg(feature = "deterministic-scheduling")]
+pub mod scheduling;
 #[cfg(feature = "snapshot-testing")]
 pub mod snapshot;
 pub mod state_machine;
+#[cfg(any(feature = "async", feature = "concurrency-testing"))]
+pub mod virtual_time;
 
 // Re-export commonly used items
+#[cfg(feature = "aaa-lint")]
+pub use aaa_lint::*;
+pub use cancel_safety::*;
 #[cfg(feature = "cli-testing")]
 pub use cli::*;
+pub use collaborator_lint::*;
+pub use compile_fail::*;
 #[cfg(feature = "concurrency-testing")]
 pub use concurrency::*;
+pub use consistency::*;
 pub use continuous_learning::*;
+pub use corpus::*;
 pub use effects::*;
+pub use flakiness::*;
 pub use generator::*;
+#[cfg(feature = "hermetic")]
+pub use hermetic::*;
+pub use http_replay::*;
+pub use isolation::*;
 #[cfg(feature = "mutation-testing")]
 pub use mutation::*;
 #[cfg(feature = "property-testing")]
 pub use property::*;
+pub use quantity::*;
+pub use rate_limit::*;
+#[cfg(feature = "leak-detection")]
+pub use resource_leaks::*;
+#[cfg(feature = "deterministic-scheduling")]
+pub use scheduling::*;
 #[cfg(feature = "snapshot-testing")]
 pub use snapshot::*;
 pub use state_machine::*;
+#[cfg(any(feature = "async", feature = "concurrency-testing"))]
+pub use virtual_time::*;
@@ -9,6 +9,14 @@
 //! property-based testing using proptest, which offers better shrinking strategies
 //! and more advanced features. The original `PropertyTestGenerator` remains available
 //! for backward compatibility.
+//!
+//! # Async Properties
+//!
+//! With `async` also enabled, [`ProptestStrategy::test_async`] checks async property
+//! bodies. Every case (including each shrinking step) runs on a fresh current-thread
+//! tokio runtime that is dropped afterwards, so tasks spawned by one case cannot leak
+//! into the next. [`AsyncRuntimeMode::PausedTime`] starts that runtime with tokio time
+//! paused, and each case receives an [`AsyncCase`] carrying its own [`ClockFixture`].
 
 use std::collections::HashMap;
 
@@ -17,6 +25,13 @@ use proptest::prelude::*;
 #[cfg(feature = "property-testing")]
 use proptest::test_runner::{Config, TestRunner};
 
+#[cfg(all(feature = "property-testing", feature = "async"))]
+use crate::core::fixture::ClockFixture;
+#[cfg(all(feature = "property-testing", feature = "async"))]
+use std::future::Future;
+#[cfg(all(feature = "property-testing", feature = "async"))]
+use std::time::Duration;
+
 /// Property test generator with const generics for compile-time configuration
 ///
 /// `MAX_ITEMS` and `MAX_DEPTH` are validated at compile time, providing
@@ -150,6 +165,9 @@ pub struct ProptestStrategy {
     /// via [`proptest::test_runner::TestRng::from_seed`] so that the same
     /// sequence of test cases is generated across runs.
     seed: Option<[u8; 32]>,
+    /// Runtime flavor for [`Self::test_async`] cases
+    #[cfg(feature = "async")]
+    runtime_mode: AsyncRuntimeMode,
 }
 
 #[cfg(feature = "property-testing")]
@@ -157,7 +175,12 @@ impl ProptestStrategy {
     /// Create a new proptest strategy with default configuration
     #[must_use]
     pub fn new() -> Self {
-        Self { config: Config::default(), seed: None }
+        Self {
+            config: Config::default(),
+            seed: None,
+            #[cfg(feature = "async")]
+            runtime_mode: AsyncRuntimeMode::CurrentThread,
+        }
     }
 
     /// Set the number of test cases to run
@@ -186,6 +209,14 @@ impl ProptestStrategy {
         self
     }
 
+    /// Set the runtime flavor used for each [`Self::test_async`] case
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub const fn with_runtime_mode(mut self, mode: AsyncRuntimeMode) -> Self {
+        self.runtime_mode = mode;
+        self
+    }
+
     /// Build a `TestRunner` honouring the stored seed (if any).
     fn build_runner(&self) -> TestRunner {
         use proptest::test_runner::{RngAlgorithm, TestRng};
@@ -242,6 +273,159 @@ impl ProptestStrategy {
     {
         self.test(any::<T>(), property);
     }
+
+    /// Run a roundtrip property: `decode(encode(value)) == value` for generated values
+    ///
+    /// Used by the stubs emitted from
+    /// [`TestGenerator::generate_property_tests`](crate::generator::TestGenerator::generate_property_tests)
+    /// to check serialize/deserialize and similar encode/decode pairs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if encoding or decoding fails, or the decoded value differs from the original.
+    pub fn test_roundtrip<T, R, E1, E2, Enc, Dec>(&self, encode: Enc, decode: Dec)
+    where
+        T: Arbitrary + PartialEq + std::fmt::Debug,
+        Enc: Fn(&T) -> Result<R, E1>,
+        Dec: Fn(R) -> Result<T, E2>,
+    {
+        self.test_default(|value: T| {
+            encode(&value)
+                .ok()
+                .and_then(|encoded| decode(encoded).ok())
+                .is_some_and(|v| v == value)
+        });
+    }
+
+    /// Run an async property test with a strategy
+    ///
+    /// Each case runs on a fresh current-thread runtime built for that case alone and
+    /// dropped when the case ends, cancelling anything it spawned. The property receives
+    /// the generated value and an [`AsyncCase`] with a fresh clock fixture.
+    ///
+    /// Call this from a synchronous `#[test]`: the per-case runtimes cannot be started
+    /// from inside another runtime.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(all(feature = "property-testing", feature = "async"))]
+    /// # {
+    /// use chicago_tdd_tools::property::{AsyncRuntimeMode, ProptestStrategy};
+    /// use proptest::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// ProptestStrategy::new()
+    ///     .with_cases(32)
+    ///     .with_runtime_mode(AsyncRuntimeMode::PausedTime)
+    ///     .test_async(1_u64..3_600, |secs, case| async move {
+    ///         // An hour-long backoff completes instantly on paused time
+    ///         case.advance(Duration::from_secs(secs)).await;
+    ///         case.clock().elapsed() == Duration::from_secs(secs)
+    ///     });
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the property fails (returns `false` or panics) for any generated test
+    /// case, or if a runtime cannot be built.
+    #[cfg(feature = "async")]
+    #[allow(clippy::panic)] // Property test - panic is appropriate for test failures
+    pub fn test_async<S, F, Fut>(&self, strategy: S, property: F)
+    where
+        S: Strategy,
+        S::Value: std::fmt::Debug,
+        F: Fn(S::Value, AsyncCase) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        let mode = self.runtime_mode;
+        let mut runner = self.build_runner();
+        runner
+            .run(&strategy, |value| {
+                let runtime = mode
+                    .build_runtime()
+                    .unwrap_or_else(|e| panic!("Failed to build async property runtime: {e}"));
+                let case = AsyncCase { clock: ClockFixture::new(), mode };
+                let holds = runtime.block_on(property(value, case));
+                // Dropping the runtime cancels every task the case left behind
+                drop(runtime);
+                prop_assert!(holds);
+                Ok(())
+            })
+            .unwrap_or_else(|e| panic!("Property test failed: {e:?}"));
+    }
+
+    /// Run an async property test with the default strategy for a type
+    ///
+    /// # Panics
+    ///
+    /// Panics if the property fails for any generated test case.
+    #[cfg(feature = "async")]
+    pub fn test_async_default<T, F, Fut>(&self, property: F)
+    where
+        T: Arbitrary + std::fmt::Debug,
+        F: Fn(T, AsyncCase) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        self.test_async(any::<T>(), property);
+    }
+}
+
+/// Runtime flavor for async property cases
+#[cfg(all(feature = "property-testing", feature = "async"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AsyncRuntimeMode {
+    /// Current-thread runtime on the real clock
+    #[default]
+    CurrentThread,
+    /// Current-thread runtime with tokio time paused: sleeps and timeouts complete as
+    /// soon as the runtime is idle, and [`AsyncCase::advance`] moves tokio time
+    PausedTime,
+}
+
+#[cfg(all(feature = "property-testing", feature = "async"))]
+impl AsyncRuntimeMode {
+    fn build_runtime(self) -> std::io::Result<tokio::runtime::Runtime> {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_time()
+            .start_paused(self == Self::PausedTime)
+            .build()
+    }
+}
+
+/// Per-case context passed to async properties
+///
+/// Owns a fresh frozen [`ClockFixture`]; inject [`ClockFixture::handle`] into code under
+/// test and move time with [`Self::advance`].
+#[cfg(all(feature = "property-testing", feature = "async"))]
+#[derive(Debug)]
+pub struct AsyncCase {
+    clock: ClockFixture,
+    mode: AsyncRuntimeMode,
+}
+
+#[cfg(all(feature = "property-testing", feature = "async"))]
+impl AsyncCase {
+    /// This case's clock fixture
+    #[must_use]
+    pub const fn clock(&self) -> &ClockFixture {
+        &self.clock
+    }
+
+    /// Runtime flavor this case runs on
+    #[must_use]
+    pub const fn runtime_mode(&self) -> AsyncRuntimeMode {
+        self.mode
+    }
+
+    /// Advance the clock fixture, and tokio time when it is paused, by `duration`
+    pub async fn advance(&self, duration: Duration) {
+        self.clock.advance(duration);
+        if self.mode == AsyncRuntimeMode::PausedTime {
+            tokio::time::advance(duration).await;
+        }
+    }
 }
 
 #[cfg(feature = "property-testing")]
@@ -431,4 +615,84 @@ mod proptest_tests {
             s.len() == s.chars().count() || s.len() >= s.chars().count()
         });
     }
+
+    #[test]
+    fn test_proptest_strategy_roundtrip() {
+        let strategy = ProptestStrategy::new().with_cases(DEFAULT_PROPERTY_TEST_CASES);
+        strategy.test_roundtrip(
+            |value: &(u32, String)| serde_json::to_string(value),
+            |json: String| serde_json::from_str::<(u32, String)>(&json),
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_proptest_strategy_async_cases_do_not_leak_tasks() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        static CANCELLED: AtomicU32 = AtomicU32::new(0);
+        struct OnCancel;
+        impl Drop for OnCancel {
+            fn drop(&mut self) {
+                CANCELLED.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+
+        // Arrange: every case spawns a task that never finishes on its own
+        let strategy = ProptestStrategy::new().with_cases(8);
+
+        // Act
+        strategy.test_async(any::<u8>(), |_, _| async {
+            let guard = OnCancel;
+            tokio::spawn(async move {
+                let _guard = guard;
+                std::future::pending::<()>().await;
+            });
+            tokio::task::yield_now().await;
+            true
+        });
+
+        // Assert: each case's runtime cancelled its task
+        assert_eq!(CANCELLED.load(Ordering::SeqCst), 8);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_proptest_strategy_async_paused_time() {
+        // Arrange
+        let strategy = ProptestStrategy::new()
+            .with_cases(DEFAULT_PROPERTY_TEST_CASES)
+            .with_runtime_mode(AsyncRuntimeMode::PausedTime);
+        let started = std::time::Instant::now();
+
+        // Act & Assert: a one-hour timeout fires without real waiting
+        strategy.test_async(1_u64..60, |secs, case| async move {
+            let slow = tokio::time::sleep(Duration::from_secs(secs * 60));
+            let timed_out = tokio::time::timeout(Duration::from_secs(secs), slow).await.is_err();
+            case.advance(Duration::from_secs(secs)).await;
+            timed_out && case.clock().elapsed() == Duration::from_secs(secs)
+        });
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    #[should_panic(expected = "Property test failed")]
+    fn test_proptest_strategy_async_detects_failure() {
+        let strategy = ProptestStrategy::new().with_cases(DEFAULT_PROPERTY_TEST_CASES);
+        strategy.test_async(any::<u32>(), |x, _| async move {
+            tokio::task::yield_now().await;
+            x < 1_000
+        });
+    }
+
+    #[test]
+    #[should_panic(expected = "Property test failed")]
+    fn test_proptest_strategy_roundtrip_detects_lossy_encoding() {
+        let strategy = ProptestStrategy::new().with_cases(DEFAULT_PROPERTY_TEST_CASES);
+        strategy.test_roundtrip(
+            |value: &u32| Ok::<_, std::convert::Infallible>(value / 2),
+            |half: u32| Ok::<_, std::convert::Infallible>(half * 2),
+        );
+    }
 }
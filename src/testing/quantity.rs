@@ -0,0 +1,1026 @@
+//! Structured Quantities for Property Testing
+//!
+//! Fixed-point decimals, currency amounts, and unit-tagged quantities (durations,
+//! byte sizes) with explicit rounding-mode control, plus seeded generators and
+//! exact/approximate assertions for them.
+//!
+//! Financial code should never be checked with `f64` tolerances: binary floating point
+//! cannot represent `0.10` exactly, so `assert_approx_eq!` on money produces flaky
+//! results that depend on the order of operations. The types here keep values as
+//! integer mantissas with a decimal scale, so comparisons and rounding are exact.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::testing::quantity::{
+//!     assert_money_eq, Currency, Decimal, Money, QuantityGenerator, RoundingMode,
+//! };
+//!
+//! // 10.00 USD split three ways, rounded half-even to cents
+//! let total = Money::parse("10.00", Currency::USD).unwrap();
+//! let share = total.amount().checked_div_int(3, RoundingMode::HalfEven).unwrap();
+//! let share = Money::new(share, Currency::USD);
+//! assert_money_eq(&share, &Money::parse("3.33", Currency::USD).unwrap(), RoundingMode::HalfEven);
+//!
+//! // Generators are deterministic for a given seed
+//! let mut generator = QuantityGenerator::new(42);
+//! let price = generator.money(Currency::EUR, 0, 100_000);
+//! assert_eq!(price.currency(), Currency::EUR);
+//! assert!(price.amount() >= Decimal::ZERO);
+//! ```
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Largest supported decimal scale (digits after the decimal point)
+pub const MAX_SCALE: u32 = 28;
+
+/// Quantity errors
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum QuantityError {
+    /// Text could not be parsed as a decimal
+    #[error("Invalid decimal literal: {0:?}")]
+    InvalidDecimal(String),
+    /// Arithmetic overflowed the fixed-point representation
+    #[error("Decimal arithmetic overflow")]
+    Overflow,
+    /// Requested scale exceeds [`MAX_SCALE`]
+    #[error("Scale {0} exceeds maximum of {MAX_SCALE}")]
+    ScaleTooLarge(u32),
+    /// Division by zero
+    #[error("Division by zero")]
+    DivisionByZero,
+    /// Money amounts in different currencies were combined
+    #[error("Currency mismatch: {left} vs {right}")]
+    CurrencyMismatch {
+        /// Left-hand currency code
+        left: &'static str,
+        /// Right-hand currency code
+        right: &'static str,
+    },
+}
+
+/// Result type for quantity operations
+pub type QuantityResult<T> = Result<T, QuantityError>;
+
+/// Rounding mode applied when a value loses precision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Round toward zero (truncate)
+    Down,
+    /// Round away from zero
+    Up,
+    /// Round toward negative infinity
+    Floor,
+    /// Round toward positive infinity
+    Ceiling,
+    /// Round to nearest, ties away from zero (commercial rounding)
+    HalfUp,
+    /// Round to nearest, ties toward zero
+    HalfDown,
+    /// Round to nearest, ties to even (banker's rounding)
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// All rounding modes, useful for property tests that must hold for every mode
+    pub const ALL: [Self; 7] = [
+        Self::Down,
+        Self::Up,
+        Self::Floor,
+        Self::Ceiling,
+        Self::HalfUp,
+        Self::HalfDown,
+        Self::HalfEven,
+    ];
+
+    /// Divide `numerator` by a positive `divisor`, rounding the quotient with this mode
+    #[must_use]
+    pub const fn divide(self, numerator: i128, divisor: i128) -> i128 {
+        let quotient = numerator / divisor;
+        let remainder = numerator % divisor;
+        if remainder == 0 {
+            return quotient;
+        }
+        let twice = remainder.unsigned_abs() * 2;
+        let divisor_abs = divisor.unsigned_abs();
+        let away = match self {
+            Self::Down => false,
+            Self::Up => true,
+            Self::Floor => numerator < 0,
+            Self::Ceiling => numerator > 0,
+            Self::HalfUp => twice >= divisor_abs,
+            Self::HalfDown => twice > divisor_abs,
+            Self::HalfEven => twice > divisor_abs || (twice == divisor_abs && quotient % 2 != 0),
+        };
+        if away {
+            quotient + numerator.signum()
+        } else {
+            quotient
+        }
+    }
+}
+
+fn pow10(exponent: u32) -> QuantityResult<i128> {
+    10_i128.checked_pow(exponent).ok_or(QuantityError::Overflow)
+}
+
+/// Fixed-point decimal: `mantissa × 10^-scale`
+///
+/// Equality and ordering are numeric, so `1.5` equals `1.50`.
+#[derive(Debug, Clone, Copy)]
+pub struct Decimal {
+    mantissa: i128,
+    scale: u32,
+}
+
+impl Decimal {
+    /// Zero with scale 0
+    pub const ZERO: Self = Self { mantissa: 0, scale: 0 };
+
+    /// Create a decimal from an integer mantissa and scale
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::ScaleTooLarge`] if `scale` exceeds [`MAX_SCALE`].
+    pub const fn new(mantissa: i128, scale: u32) -> QuantityResult<Self> {
+        if scale > MAX_SCALE {
+            return Err(QuantityError::ScaleTooLarge(scale));
+        }
+        Ok(Self { mantissa, scale })
+    }
+
+    /// Create a whole-number decimal
+    #[must_use]
+    pub const fn from_int(value: i64) -> Self {
+        Self { mantissa: value as i128, scale: 0 }
+    }
+
+    /// Parse a decimal literal such as `"-12.345"`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::InvalidDecimal`] for malformed input and
+    /// [`QuantityError::Overflow`] if the digits do not fit.
+    pub fn parse(text: &str) -> QuantityResult<Self> {
+        let invalid = || QuantityError::InvalidDecimal(text.to_string());
+        let trimmed = text.trim();
+        let (negative, unsigned) = match trimmed.as_bytes().first() {
+            Some(b'-') => (true, &trimmed[1..]),
+            Some(b'+') => (false, &trimmed[1..]),
+            _ => (false, trimmed),
+        };
+        let (whole, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(invalid());
+        }
+        let mut mantissa: i128 = 0;
+        for byte in whole.bytes().chain(fraction.bytes()) {
+            if !byte.is_ascii_digit() {
+                return Err(invalid());
+            }
+            mantissa = mantissa
+                .checked_mul(10)
+                .and_then(|m| m.checked_add(i128::from(byte - b'0')))
+                .ok_or(QuantityError::Overflow)?;
+        }
+        let scale = u32::try_from(fraction.len()).map_err(|_| invalid())?;
+        Self::new(if negative { -mantissa } else { mantissa }, scale)
+    }
+
+    /// Integer mantissa
+    #[must_use]
+    pub const fn mantissa(&self) -> i128 {
+        self.mantissa
+    }
+
+    /// Number of digits after the decimal point
+    #[must_use]
+    pub const fn scale(&self) -> u32 {
+        self.scale
+    }
+
+    /// Whether the value is negative
+    #[must_use]
+    pub const fn is_negative(&self) -> bool {
+        self.mantissa < 0
+    }
+
+    /// Absolute value
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::Overflow`] for the most negative mantissa, whose
+    /// absolute value does not fit.
+    pub const fn checked_abs(&self) -> QuantityResult<Self> {
+        match self.mantissa.checked_abs() {
+            Some(mantissa) => Ok(Self { mantissa, scale: self.scale }),
+            None => Err(QuantityError::Overflow),
+        }
+    }
+
+    /// Change the scale, rounding with `mode` when precision is lost
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::ScaleTooLarge`] or [`QuantityError::Overflow`]
+    /// if the value cannot be represented at the new scale.
+    pub fn rescale(&self, scale: u32, mode: RoundingMode) -> QuantityResult<Self> {
+        if scale > MAX_SCALE {
+            return Err(QuantityError::ScaleTooLarge(scale));
+        }
+        let mantissa = match scale.cmp(&self.scale) {
+            Ordering::Equal => self.mantissa,
+            Ordering::Greater => self
+                .mantissa
+                .checked_mul(pow10(scale - self.scale)?)
+                .ok_or(QuantityError::Overflow)?,
+            Ordering::Less => mode.divide(self.mantissa, pow10(self.scale - scale)?),
+        };
+        Ok(Self { mantissa, scale })
+    }
+
+    /// Round to `scale` digits; never increases the scale
+    ///
+    /// # Errors
+    ///
+    /// Propagates [`Self::rescale`] errors.
+    pub fn round(&self, scale: u32, mode: RoundingMode) -> QuantityResult<Self> {
+        self.rescale(scale.min(self.scale), mode)
+    }
+
+    /// Whether rounding to `scale` digits would lose precision
+    #[must_use]
+    pub fn fits_scale(&self, scale: u32) -> bool {
+        self.round(scale, RoundingMode::Down).is_ok_and(|rounded| rounded == *self)
+    }
+
+    fn aligned(&self, other: &Self) -> QuantityResult<(i128, i128, u32)> {
+        let scale = self.scale.max(other.scale);
+        let left = self.rescale(scale, RoundingMode::Down)?.mantissa;
+        let right = other.rescale(scale, RoundingMode::Down)?.mantissa;
+        Ok((left, right, scale))
+    }
+
+    /// Exact addition
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::Overflow`] if the result does not fit.
+    pub fn checked_add(&self, other: &Self) -> QuantityResult<Self> {
+        let (left, right, scale) = self.aligned(other)?;
+        let mantissa = left.checked_add(right).ok_or(QuantityError::Overflow)?;
+        Ok(Self { mantissa, scale })
+    }
+
+    /// Exact subtraction
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::Overflow`] if the result does not fit.
+    pub fn checked_sub(&self, other: &Self) -> QuantityResult<Self> {
+        let (left, right, scale) = self.aligned(other)?;
+        let mantissa = left.checked_sub(right).ok_or(QuantityError::Overflow)?;
+        Ok(Self { mantissa, scale })
+    }
+
+    /// Exact multiplication; the result scale is the sum of both scales
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::Overflow`] or [`QuantityError::ScaleTooLarge`].
+    pub fn checked_mul(&self, other: &Self) -> QuantityResult<Self> {
+        let mantissa = self.mantissa.checked_mul(other.mantissa).ok_or(QuantityError::Overflow)?;
+        Self::new(mantissa, self.scale + other.scale)
+    }
+
+    /// Divide by an integer, keeping the current scale and rounding the last digit
+    /// with `mode`
+    ///
+    /// Combine with [`Self::rescale`] first to keep extra digits for later rounding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::DivisionByZero`] if `divisor` is zero, or
+    /// [`QuantityError::Overflow`] if the quotient does not fit.
+    pub const fn checked_div_int(&self, divisor: i64, mode: RoundingMode) -> QuantityResult<Self> {
+        if divisor == 0 {
+            return Err(QuantityError::DivisionByZero);
+        }
+        // `RoundingMode::divide` expects a positive divisor, so move the sign to the
+        // numerator; negating the most negative mantissa overflows
+        let (numerator, divisor) = if divisor < 0 {
+            match self.mantissa.checked_neg() {
+                Some(numerator) => (numerator, -(divisor as i128)),
+                None => return Err(QuantityError::Overflow),
+            }
+        } else {
+            (self.mantissa, divisor as i128)
+        };
+        Ok(Self { mantissa: mode.divide(numerator, divisor), scale: self.scale })
+    }
+
+    /// Absolute difference between two decimals
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::Overflow`] if the difference does not fit.
+    pub fn abs_diff(&self, other: &Self) -> QuantityResult<Self> {
+        self.checked_sub(other)?.checked_abs()
+    }
+}
+
+impl PartialEq for Decimal {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Decimal {}
+
+impl PartialOrd for Decimal {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Decimal {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.aligned(other) {
+            Ok((left, right, _)) => left.cmp(&right),
+            // Aligning overflowed: the value being scaled up dominates in magnitude
+            Err(_) if self.scale < other.scale => self.mantissa.signum().cmp(&0),
+            Err(_) => 0.cmp(&other.mantissa.signum()),
+        }
+    }
+}
+
+impl fmt::Display for Decimal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.mantissa.unsigned_abs().to_string();
+        let sign = if self.mantissa < 0 { "-" } else { "" };
+        let scale = self.scale as usize;
+        if scale == 0 {
+            return write!(f, "{sign}{digits}");
+        }
+        let padded = format!("{digits:0>width$}", width = scale + 1);
+        let (whole, fraction) = padded.split_at(padded.len() - scale);
+        write!(f, "{sign}{whole}.{fraction}")
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = QuantityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// ISO-4217 style currency with its number of minor units
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: &'static str,
+    minor_units: u32,
+}
+
+impl Currency {
+    /// US dollar (2 minor units)
+    pub const USD: Self = Self::new("USD", 2);
+    /// Euro (2 minor units)
+    pub const EUR: Self = Self::new("EUR", 2);
+    /// Pound sterling (2 minor units)
+    pub const GBP: Self = Self::new("GBP", 2);
+    /// Japanese yen (no minor units)
+    pub const JPY: Self = Self::new("JPY", 0);
+    /// Bahraini dinar (3 minor units)
+    pub const BHD: Self = Self::new("BHD", 3);
+
+    /// Define a currency
+    #[must_use]
+    pub const fn new(code: &'static str, minor_units: u32) -> Self {
+        Self { code, minor_units }
+    }
+
+    /// Currency code
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// Number of digits after the decimal point
+    #[must_use]
+    pub const fn minor_units(&self) -> u32 {
+        self.minor_units
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code)
+    }
+}
+
+/// Decimal amount tagged with a currency
+///
+/// The amount may carry more precision than the currency's minor units (e.g. an
+/// intermediate interest calculation); use [`Money::round`] to settle it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Money {
+    amount: Decimal,
+    currency: Currency,
+}
+
+impl Money {
+    /// Create a money value
+    #[must_use]
+    pub const fn new(amount: Decimal, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    /// Create a money value from minor units (e.g. cents)
+    #[must_use]
+    pub const fn from_minor(minor: i128, currency: Currency) -> Self {
+        Self { amount: Decimal { mantissa: minor, scale: currency.minor_units }, currency }
+    }
+
+    /// Parse an amount in the given currency
+    ///
+    /// # Errors
+    ///
+    /// Propagates [`Decimal::parse`] errors.
+    pub fn parse(text: &str, currency: Currency) -> QuantityResult<Self> {
+        Ok(Self::new(Decimal::parse(text)?, currency))
+    }
+
+    /// Amount
+    #[must_use]
+    pub const fn amount(&self) -> Decimal {
+        self.amount
+    }
+
+    /// Currency
+    #[must_use]
+    pub const fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    /// Round the amount to the currency's minor units
+    ///
+    /// # Errors
+    ///
+    /// Propagates [`Decimal::rescale`] errors.
+    pub fn round(&self, mode: RoundingMode) -> QuantityResult<Self> {
+        Ok(Self::new(self.amount.rescale(self.currency.minor_units, mode)?, self.currency))
+    }
+
+    /// Whether the amount is representable in the currency's minor units
+    #[must_use]
+    pub fn is_settled(&self) -> bool {
+        self.amount.fits_scale(self.currency.minor_units)
+    }
+
+    /// Add two amounts in the same currency
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::CurrencyMismatch`] for different currencies, or
+    /// [`QuantityError::Overflow`].
+    pub fn checked_add(&self, other: &Self) -> QuantityResult<Self> {
+        self.ensure_same_currency(other)?;
+        Ok(Self::new(self.amount.checked_add(&other.amount)?, self.currency))
+    }
+
+    /// Subtract two amounts in the same currency
+    ///
+    /// # Errors
+    ///
+    /// Returns [`QuantityError::CurrencyMismatch`] for different currencies, or
+    /// [`QuantityError::Overflow`].
+    pub fn checked_sub(&self, other: &Self) -> QuantityResult<Self> {
+        self.ensure_same_currency(other)?;
+        Ok(Self::new(self.amount.checked_sub(&other.amount)?, self.currency))
+    }
+
+    const fn ensure_same_currency(&self, other: &Self) -> QuantityResult<()> {
+        if self.currency.minor_units != other.currency.minor_units
+            || !str_eq(self.currency.code, other.currency.code)
+        {
+            return Err(QuantityError::CurrencyMismatch {
+                left: self.currency.code,
+                right: other.currency.code,
+            });
+        }
+        Ok(())
+    }
+}
+
+const fn str_eq(left: &str, right: &str) -> bool {
+    let (left, right) = (left.as_bytes(), right.as_bytes());
+    if left.len() != right.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < left.len() {
+        if left[i] != right[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.currency)
+    }
+}
+
+/// Byte count tagged as a size, so it cannot be confused with other integers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ByteSize(u64);
+
+impl ByteSize {
+    /// One kibibyte
+    pub const KIB: Self = Self(1024);
+    /// One mebibyte
+    pub const MIB: Self = Self(1024 * 1024);
+    /// One gibibyte
+    pub const GIB: Self = Self(1024 * 1024 * 1024);
+
+    /// Create a size from a byte count
+    #[must_use]
+    pub const fn new(bytes: u64) -> Self {
+        Self(bytes)
+    }
+
+    /// Create a size from kibibytes (saturating)
+    #[must_use]
+    pub const fn from_kib(kib: u64) -> Self {
+        Self(kib.saturating_mul(1024))
+    }
+
+    /// Create a size from mebibytes (saturating)
+    #[must_use]
+    pub const fn from_mib(mib: u64) -> Self {
+        Self(mib.saturating_mul(1024 * 1024))
+    }
+
+    /// Byte count
+    #[must_use]
+    pub const fn bytes(self) -> u64 {
+        self.0
+    }
+
+    /// Absolute difference
+    #[must_use]
+    pub const fn abs_diff(self, other: Self) -> Self {
+        Self(self.0.abs_diff(other.0))
+    }
+
+    /// Round to a multiple of `unit` (e.g. page or block size)
+    ///
+    /// A zero `unit` leaves the size unchanged. Saturates at `u64::MAX`.
+    #[must_use]
+    pub fn round_to(self, unit: Self, mode: RoundingMode) -> Self {
+        if unit.0 == 0 {
+            return self;
+        }
+        let units = mode.divide(i128::from(self.0), i128::from(unit.0));
+        Self(u64::try_from(units * i128::from(unit.0)).unwrap_or(u64::MAX))
+    }
+}
+
+impl fmt::Display for ByteSize {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} B", self.0)
+    }
+}
+
+/// Round a duration to a multiple of `unit` (e.g. whole milliseconds)
+///
+/// A zero `unit` leaves the duration unchanged. Saturates at [`Duration::MAX`].
+#[must_use]
+pub fn round_duration(duration: Duration, unit: Duration, mode: RoundingMode) -> Duration {
+    let unit_nanos = unit.as_nanos();
+    if unit_nanos == 0 {
+        return duration;
+    }
+    #[allow(clippy::cast_possible_wrap)] // u128 nanos of a Duration fit well within i128
+    let units = mode.divide(duration.as_nanos() as i128, unit_nanos as i128);
+    let nanos = units.unsigned_abs() * unit_nanos;
+    let secs = u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX);
+    #[allow(clippy::cast_possible_truncation)] // remainder is < 1_000_000_000
+    let subsec = (nanos % 1_000_000_000) as u32;
+    Duration::new(secs, subsec)
+}
+
+/// Deterministic generator for structured quantities
+///
+/// Roughly one draw in eight returns a boundary value (the minimum, the maximum, or
+/// zero when in range) so properties are exercised at their edges.
+#[derive(Debug, Clone)]
+pub struct QuantityGenerator {
+    state: u64,
+}
+
+impl QuantityGenerator {
+    /// Create a generator from a seed
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    const fn next_u64(&mut self) -> u64 {
+        // SplitMix64
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn int_in(&mut self, min: i128, max: i128) -> i128 {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        match self.next_u64() % 16 {
+            0 => return min,
+            1 => return max,
+            2 if min <= 0 && max >= 0 => return 0,
+            _ => {}
+        }
+        let span = max.abs_diff(min).saturating_add(1);
+        let raw = (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64());
+        let offset = if span == 0 { raw } else { raw % span };
+        #[allow(clippy::cast_possible_wrap)] // offset < span, which fits the i128 range
+        min.wrapping_add(offset as i128)
+    }
+
+    /// Generate a decimal at `scale` with mantissa in `[min_mantissa, max_mantissa]`
+    ///
+    /// Scales above [`MAX_SCALE`] are clamped.
+    pub fn decimal(&mut self, scale: u32, min_mantissa: i128, max_mantissa: i128) -> Decimal {
+        Decimal { mantissa: self.int_in(min_mantissa, max_mantissa), scale: scale.min(MAX_SCALE) }
+    }
+
+    /// Generate a money amount with minor units in `[min_minor, max_minor]`
+    pub fn money(&mut self, currency: Currency, min_minor: i128, max_minor: i128) -> Money {
+        Money::from_minor(self.int_in(min_minor, max_minor), currency)
+    }
+
+    /// Generate a money amount with `extra_digits` of precision beyond the minor units,
+    /// for exercising rounding
+    ///
+    /// `extra_digits` is capped so the amount's scale stays within [`MAX_SCALE`].
+    pub fn unsettled_money(
+        &mut self,
+        currency: Currency,
+        extra_digits: u32,
+        min_minor: i128,
+        max_minor: i128,
+    ) -> Money {
+        let extra_digits = extra_digits.min(MAX_SCALE.saturating_sub(currency.minor_units));
+        // extra_digits <= MAX_SCALE, and 10^28 fits in i128
+        let factor = 10_i128.pow(extra_digits);
+        let amount = self.decimal(
+            currency.minor_units + extra_digits,
+            min_minor.saturating_mul(factor),
+            max_minor.saturating_mul(factor),
+        );
+        Money::new(amount, currency)
+    }
+
+    /// Generate a duration in `[min, max]` with nanosecond resolution
+    pub fn duration(&mut self, min: Duration, max: Duration) -> Duration {
+        #[allow(clippy::cast_possible_wrap)] // Duration nanos fit within i128
+        let nanos = self.int_in(min.as_nanos() as i128, max.as_nanos() as i128).unsigned_abs();
+        let secs = u64::try_from(nanos / 1_000_000_000).unwrap_or(u64::MAX);
+        #[allow(clippy::cast_possible_truncation)] // remainder is < 1_000_000_000
+        let subsec = (nanos % 1_000_000_000) as u32;
+        Duration::new(secs, subsec)
+    }
+
+    /// Generate a byte size in `[min, max]`
+    pub fn byte_size(&mut self, min: ByteSize, max: ByteSize) -> ByteSize {
+        let bytes = self.int_in(i128::from(min.0), i128::from(max.0));
+        ByteSize(u64::try_from(bytes).unwrap_or(u64::MAX))
+    }
+
+    /// Pick a rounding mode
+    pub const fn rounding_mode(&mut self) -> RoundingMode {
+        #[allow(clippy::cast_possible_truncation)] // modulo keeps the index < 7
+        let index = (self.next_u64() % RoundingMode::ALL.len() as u64) as usize;
+        RoundingMode::ALL[index]
+    }
+}
+
+#[cfg(feature = "property-testing")]
+/// proptest strategies for structured quantities
+pub mod strategies {
+    use super::{ByteSize, Currency, Decimal, Money, MAX_SCALE};
+    use proptest::prelude::*;
+
+    /// Decimals at `scale` with mantissa in `[min_mantissa, max_mantissa]`
+    ///
+    /// As with [`QuantityGenerator`](super::QuantityGenerator), reversed bounds are
+    /// swapped in every strategy here rather than rejected.
+    pub fn decimal(
+        scale: u32,
+        min_mantissa: i128,
+        max_mantissa: i128,
+    ) -> impl Strategy<Value = Decimal> {
+        let scale = scale.min(MAX_SCALE);
+        let (min_mantissa, max_mantissa) = ordered(min_mantissa, max_mantissa);
+        (min_mantissa..=max_mantissa).prop_map(move |mantissa| Decimal { mantissa, scale })
+    }
+
+    /// Money amounts with minor units in `[min_minor, max_minor]`
+    pub fn money(
+        currency: Currency,
+        min_minor: i128,
+        max_minor: i128,
+    ) -> impl Strategy<Value = Money> {
+        let (min_minor, max_minor) = ordered(min_minor, max_minor);
+        (min_minor..=max_minor).prop_map(move |minor| Money::from_minor(minor, currency))
+    }
+
+    /// Byte sizes in `[min, max]`
+    pub fn byte_size(min: ByteSize, max: ByteSize) -> impl Strategy<Value = ByteSize> {
+        let (min, max) = ordered(min.0, max.0);
+        (min..=max).prop_map(ByteSize)
+    }
+
+    /// Swap reversed bounds, as [`QuantityGenerator`](super::QuantityGenerator) does
+    fn ordered<T: PartialOrd>(min: T, max: T) -> (T, T) {
+        if min <= max {
+            (min, max)
+        } else {
+            (max, min)
+        }
+    }
+}
+
+/// Assert two decimals differ by at most `tolerance`
+///
+/// # Panics
+///
+/// Panics if the absolute difference exceeds `tolerance` or cannot be computed.
+pub fn assert_decimal_approx_eq(actual: Decimal, expected: Decimal, tolerance: Decimal) {
+    let diff = actual.abs_diff(&expected);
+    assert!(diff.is_ok(), "Cannot compare {actual} with {expected}: {diff:?}");
+    let diff = diff.unwrap_or(Decimal::ZERO);
+    assert!(
+        tolerance.checked_abs().ok().is_none_or(|tolerance| diff <= tolerance),
+        "Decimals not approximately equal.\n  actual: {actual}\n  expected: {expected}\n  tolerance: {tolerance}\n  difference: {diff}"
+    );
+}
+
+/// Assert two decimals are equal after rounding both to `scale` with `mode`
+///
+/// # Panics
+///
+/// Panics if the rounded values differ or rounding fails.
+pub fn assert_decimal_eq_rounded(
+    actual: Decimal,
+    expected: Decimal,
+    scale: u32,
+    mode: RoundingMode,
+) {
+    let round = |value: Decimal| {
+        let rounded = value.rescale(scale, mode);
+        assert!(rounded.is_ok(), "Cannot round {value} to scale {scale}: {rounded:?}");
+        rounded.unwrap_or(value)
+    };
+    let (rounded_actual, rounded_expected) = (round(actual), round(expected));
+    assert!(
+        rounded_actual == rounded_expected,
+        "Decimals differ after {mode:?} rounding to scale {scale}.\n  actual: {actual} -> {rounded_actual}\n  expected: {expected} -> {rounded_expected}"
+    );
+}
+
+/// Assert two money values are equal once both are rounded to minor units with `mode`
+///
+/// # Panics
+///
+/// Panics on currency mismatch or if the rounded amounts differ.
+pub fn assert_money_eq(actual: &Money, expected: &Money, mode: RoundingMode) {
+    let same_currency = actual.ensure_same_currency(expected);
+    assert!(same_currency.is_ok(), "Cannot compare {actual} with {expected}: {same_currency:?}");
+    assert_decimal_eq_rounded(actual.amount, expected.amount, actual.currency.minor_units, mode);
+}
+
+/// Assert two durations differ by at most `tolerance`
+///
+/// # Panics
+///
+/// Panics if the absolute difference exceeds `tolerance`.
+pub fn assert_duration_approx_eq(actual: Duration, expected: Duration, tolerance: Duration) {
+    let diff = actual.abs_diff(expected);
+    assert!(
+        diff <= tolerance,
+        "Durations not approximately equal.\n  actual: {actual:?}\n  expected: {expected:?}\n  tolerance: {tolerance:?}\n  difference: {diff:?}"
+    );
+}
+
+/// Assert two byte sizes differ by at most `tolerance`
+///
+/// # Panics
+///
+/// Panics if the absolute difference exceeds `tolerance`.
+pub fn assert_bytes_approx_eq(actual: ByteSize, expected: ByteSize, tolerance: ByteSize) {
+    let diff = actual.abs_diff(expected);
+    assert!(
+        diff <= tolerance,
+        "Byte sizes not approximately equal.\n  actual: {actual}\n  expected: {expected}\n  tolerance: {tolerance}\n  difference: {diff}"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn dec(text: &str) -> Decimal {
+        Decimal::parse(text).unwrap()
+    }
+
+    test!(test_decimal_parse_display_and_numeric_equality, {
+        // Arrange & Act
+        let value = dec("-12.050");
+
+        // Assert
+        assert_eq!(value.mantissa(), -12_050);
+        assert_eq!(value.scale(), 3);
+        assert_eq!(value.to_string(), "-12.050");
+        assert_eq!(dec("0.05").to_string(), "0.05");
+        assert_eq!(value, dec("-12.05"));
+        assert!(dec("1.5") > dec("1.49"));
+        assert!(Decimal::parse("1.2.3").is_err());
+        assert!(Decimal::parse("-").is_err());
+    });
+
+    test!(test_rounding_modes_on_ties_and_negatives, {
+        // Arrange
+        let cases = [
+            ("2.5", RoundingMode::HalfEven, "2"),
+            ("3.5", RoundingMode::HalfEven, "4"),
+            ("2.5", RoundingMode::HalfUp, "3"),
+            ("2.5", RoundingMode::HalfDown, "2"),
+            ("-2.5", RoundingMode::HalfUp, "-3"),
+            ("-2.1", RoundingMode::Floor, "-3"),
+            ("-2.9", RoundingMode::Ceiling, "-2"),
+            ("2.1", RoundingMode::Up, "3"),
+            ("-2.9", RoundingMode::Down, "-2"),
+        ];
+
+        // Act & Assert
+        for (input, mode, expected) in cases {
+            let rounded = dec(input).rescale(0, mode).unwrap();
+            assert_eq!(rounded, dec(expected), "{input} with {mode:?}");
+        }
+    });
+
+    test!(test_money_rounds_to_minor_units_and_rejects_mixed_currency, {
+        // Arrange
+        let interest = Money::parse("10.125", Currency::USD).unwrap();
+        let yen = Money::from_minor(500, Currency::JPY);
+
+        // Act
+        let settled = interest.round(RoundingMode::HalfEven).unwrap();
+
+        // Assert
+        assert!(!interest.is_settled());
+        assert_eq!(settled.to_string(), "10.12 USD");
+        assert!(settled.is_settled());
+        assert_money_eq(&interest, &Money::from_minor(1013, Currency::USD), RoundingMode::HalfUp);
+        assert!(matches!(
+            interest.checked_add(&yen),
+            Err(QuantityError::CurrencyMismatch { left: "USD", right: "JPY" })
+        ));
+    });
+
+    test!(test_generator_is_deterministic_and_in_range, {
+        // Arrange
+        let mut first = QuantityGenerator::new(7);
+        let mut second = QuantityGenerator::new(7);
+
+        // Act & Assert
+        for _ in 0..500 {
+            let money = first.money(Currency::BHD, -1_000, 1_000);
+            assert_eq!(money, second.money(Currency::BHD, -1_000, 1_000));
+            assert!(money.is_settled());
+            assert!(money.amount() >= dec("-1.000") && money.amount() <= dec("1.000"));
+
+            let size = first.byte_size(ByteSize::KIB, ByteSize::MIB);
+            second.byte_size(ByteSize::KIB, ByteSize::MIB);
+            assert!((ByteSize::KIB..=ByteSize::MIB).contains(&size));
+
+            let duration = first.duration(Duration::from_millis(5), Duration::from_secs(2));
+            second.duration(Duration::from_millis(5), Duration::from_secs(2));
+            assert!(duration >= Duration::from_millis(5) && duration <= Duration::from_secs(2));
+        }
+    });
+
+    test!(test_rounding_never_moves_more_than_one_unit, {
+        // Arrange
+        let mut generator = QuantityGenerator::new(99);
+
+        // Act & Assert: property holds for every mode and generated amount
+        for _ in 0..500 {
+            let money = generator.unsettled_money(Currency::USD, 3, -100_000, 100_000);
+            let mode = generator.rounding_mode();
+            let settled = money.round(mode).unwrap();
+            assert!(settled.is_settled());
+            assert_decimal_approx_eq(settled.amount(), money.amount(), dec("0.01"));
+        }
+    });
+
+    test!(test_unsettled_money_caps_extra_digits_at_max_scale, {
+        // Arrange
+        let mut generator = QuantityGenerator::new(3);
+
+        // Act & Assert: USD has 2 minor units, so 26 extra digits is the most that fit
+        for extra_digits in [26, 27, u32::MAX] {
+            for _ in 0..200 {
+                let money = generator.unsettled_money(Currency::USD, extra_digits, -500, 500);
+                assert_eq!(money.amount().scale(), MAX_SCALE);
+                assert!(money.amount() >= dec("-5") && money.amount() <= dec("5"), "{money}");
+            }
+        }
+    });
+
+    #[cfg(feature = "property-testing")]
+    test!(test_strategies_swap_reversed_bounds, {
+        use proptest::strategy::{Strategy, ValueTree};
+        use proptest::test_runner::TestRunner;
+
+        // Arrange
+        let mut runner = TestRunner::deterministic();
+
+        // Act
+        let decimal = strategies::decimal(2, 100, -100).new_tree(&mut runner).unwrap().current();
+        let money = strategies::money(Currency::USD, 100, -100)
+            .new_tree(&mut runner)
+            .unwrap()
+            .current();
+        let size = strategies::byte_size(ByteSize::MIB, ByteSize::KIB)
+            .new_tree(&mut runner)
+            .unwrap()
+            .current();
+
+        // Assert
+        assert!(decimal >= dec("-1") && decimal <= dec("1"), "{decimal}");
+        assert!(money.amount() >= dec("-1") && money.amount() <= dec("1"), "{money}");
+        assert!((ByteSize::KIB..=ByteSize::MIB).contains(&size));
+    });
+
+    test!(test_unit_rounding_for_durations_and_bytes, {
+        // Arrange & Act
+        let duration = round_duration(
+            Duration::from_micros(1_500),
+            Duration::from_millis(1),
+            RoundingMode::HalfEven,
+        );
+        let size = ByteSize::new(5_000).round_to(ByteSize::new(4_096), RoundingMode::Ceiling);
+
+        // Assert
+        assert_eq!(duration, Duration::from_millis(2));
+        assert_eq!(size, ByteSize::new(8_192));
+        assert_duration_approx_eq(
+            Duration::from_millis(101),
+            Duration::from_millis(100),
+            Duration::from_millis(1),
+        );
+        assert_bytes_approx_eq(ByteSize::from_kib(1), ByteSize::new(1_000), ByteSize::new(24));
+    });
+
+    test!(test_div_int_rounds_with_mode_and_reports_overflow, {
+        // Arrange
+        let total = dec("10.00");
+        let most_negative = Decimal::new(i128::MIN, 0).unwrap();
+
+        // Act & Assert
+        assert_eq!(total.checked_div_int(3, RoundingMode::HalfEven).unwrap(), dec("3.33"));
+        assert_eq!(total.checked_div_int(3, RoundingMode::Ceiling).unwrap(), dec("3.34"));
+        assert_eq!(total.checked_div_int(-3, RoundingMode::Floor).unwrap(), dec("-3.34"));
+        assert_eq!(dec("0.05").checked_div_int(-2, RoundingMode::HalfEven).unwrap(), dec("-0.02"));
+        assert_eq!(
+            total.checked_div_int(0, RoundingMode::Down),
+            Err(QuantityError::DivisionByZero)
+        );
+        assert_eq!(
+            most_negative.checked_div_int(-1, RoundingMode::Down),
+            Err(QuantityError::Overflow)
+        );
+        assert_eq!(most_negative.checked_abs(), Err(QuantityError::Overflow));
+        assert_eq!(most_negative.abs_diff(&Decimal::ZERO), Err(QuantityError::Overflow));
+    });
+
+    #[test]
+    #[should_panic(expected = "Decimals differ after HalfEven rounding")]
+    fn test_assert_decimal_eq_rounded_reports_mismatch() {
+        assert_decimal_eq_rounded(dec("0.125"), dec("0.13"), 2, RoundingMode::HalfEven);
+    }
+}
@@ -0,0 +1,443 @@
+//! Rate Limiter and Quota Testing
+//!
+//! Drives a rate-limited API (a real collaborator) with a precise virtual-time request
+//! schedule and records which requests were allowed or denied, together with their
+//! response headers. Rate-limit logic is then verified in microseconds instead of with
+//! real sleeps.
+//!
+//! The API under test reads time from an injected [`Clock`]; the harness owns the
+//! matching [`TestClock`] and advances it to each request's offset before sending it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::core::fixture::{Clock, TestClock};
+//! use chicago_tdd_tools::testing::rate_limit::{
+//!     RateLimitHarness, RateLimitResponse, RateLimitSchedule,
+//! };
+//! use std::time::{Duration, Instant};
+//!
+//! // Real collaborator: fixed window of 3 requests per second
+//! struct Limiter<C: Clock> {
+//!     clock: C,
+//!     window_start: Instant,
+//!     used: u32,
+//! }
+//!
+//! impl<C: Clock> Limiter<C> {
+//!     fn handle(&mut self) -> RateLimitResponse {
+//!         if self.clock.elapsed_since(self.window_start) >= Duration::from_secs(1) {
+//!             self.window_start = self.clock.now();
+//!             self.used = 0;
+//!         }
+//!         let allowed = self.used < 3;
+//!         self.used += u32::from(allowed);
+//!         RateLimitResponse::new(allowed).header("X-RateLimit-Remaining", 3 - self.used)
+//!     }
+//! }
+//!
+//! let harness = RateLimitHarness::new(TestClock::new());
+//! let mut limiter = Limiter { clock: harness.clock(), window_start: harness.clock().now(), used: 0 };
+//!
+//! let schedule = RateLimitSchedule::new().burst(4).wait(Duration::from_secs(1)).burst(1);
+//! let trace = harness.run(&schedule, |_| limiter.handle());
+//!
+//! trace.assert_pattern("AAADA");
+//! trace.assert_header(2, "x-ratelimit-remaining", "0");
+//! ```
+
+use crate::core::fixture::{Clock, TestClock};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fmt::Write as _;
+use std::time::Duration;
+
+/// Pattern character for an allowed request
+const ALLOWED: char = 'A';
+/// Pattern character for a denied request
+const DENIED: char = 'D';
+
+/// Virtual-time request schedule
+///
+/// Requests are placed at offsets from the start of the run. The builder keeps a cursor:
+/// [`burst`](Self::burst) sends at the cursor, [`wait`](Self::wait) moves it forward.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitSchedule {
+    offsets: Vec<Duration>,
+    cursor: Duration,
+}
+
+impl RateLimitSchedule {
+    /// Create an empty schedule with the cursor at zero
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send `count` requests at the cursor, back to back
+    #[must_use]
+    pub fn burst(mut self, count: usize) -> Self {
+        self.offsets.extend(std::iter::repeat_n(self.cursor, count));
+        self
+    }
+
+    /// Move the cursor forward by `duration`
+    #[must_use]
+    pub const fn wait(mut self, duration: Duration) -> Self {
+        self.cursor = self.cursor.saturating_add(duration);
+        self
+    }
+
+    /// Send `count` requests spaced `interval` apart, starting at the cursor
+    ///
+    /// The cursor ends one `interval` after the last request.
+    #[must_use]
+    pub fn every(mut self, interval: Duration, count: usize) -> Self {
+        for _ in 0..count {
+            self.offsets.push(self.cursor);
+            self.cursor = self.cursor.saturating_add(interval);
+        }
+        self
+    }
+
+    /// Send one request at an absolute offset (moves the cursor there)
+    #[must_use]
+    pub fn at(mut self, offset: Duration) -> Self {
+        self.cursor = offset;
+        self.offsets.push(offset);
+        self
+    }
+
+    /// Request offsets in send order
+    #[must_use]
+    pub fn offsets(&self) -> &[Duration] {
+        &self.offsets
+    }
+}
+
+/// Decision and headers returned by the API under test for one request
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitResponse {
+    allowed: bool,
+    headers: BTreeMap<String, String>,
+}
+
+impl RateLimitResponse {
+    /// Create a response with the given decision
+    #[must_use]
+    pub const fn new(allowed: bool) -> Self {
+        Self { allowed, headers: BTreeMap::new() }
+    }
+
+    /// Create an allowed response
+    #[must_use]
+    pub const fn allowed() -> Self {
+        Self::new(true)
+    }
+
+    /// Create a denied response
+    #[must_use]
+    pub const fn denied() -> Self {
+        Self::new(false)
+    }
+
+    /// Build a response from an HTTP status code (429 counts as denied)
+    #[must_use]
+    pub const fn from_status(status: u16) -> Self {
+        Self::new(status != 429)
+    }
+
+    /// Attach a header (names are case-insensitive)
+    #[must_use]
+    pub fn header(mut self, name: &str, value: impl fmt::Display) -> Self {
+        self.headers.insert(name.to_ascii_lowercase(), value.to_string());
+        self
+    }
+}
+
+/// One request as observed by the harness
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitOutcome {
+    /// Position in the schedule
+    pub index: usize,
+    /// Virtual-time offset the request was sent at
+    pub at: Duration,
+    /// Whether the API allowed the request
+    pub allowed: bool,
+    /// Response headers, keyed by lowercase name
+    pub headers: BTreeMap<String, String>,
+}
+
+/// Recorded outcomes of a schedule run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitTrace {
+    /// Outcomes in send order
+    pub outcomes: Vec<RateLimitOutcome>,
+}
+
+impl RateLimitTrace {
+    /// Allowed/denied pattern, e.g. `"AAAD"` (`A` = allowed, `D` = denied)
+    #[must_use]
+    pub fn pattern(&self) -> String {
+        self.outcomes.iter().map(|o| if o.allowed { ALLOWED } else { DENIED }).collect()
+    }
+
+    /// Number of allowed requests
+    #[must_use]
+    pub fn allowed_count(&self) -> usize {
+        self.outcomes.iter().filter(|o| o.allowed).count()
+    }
+
+    /// Number of denied requests
+    #[must_use]
+    pub fn denied_count(&self) -> usize {
+        self.outcomes.len() - self.allowed_count()
+    }
+
+    /// Value of header `name` on request `index`
+    #[must_use]
+    pub fn header(&self, index: usize, name: &str) -> Option<&str> {
+        self.outcomes
+            .get(index)?
+            .headers
+            .get(&name.to_ascii_lowercase())
+            .map(String::as_str)
+    }
+
+    /// Values of header `name` across all requests, in send order
+    #[must_use]
+    pub fn header_values(&self, name: &str) -> Vec<Option<&str>> {
+        (0..self.outcomes.len()).map(|index| self.header(index, name)).collect()
+    }
+
+    /// Largest number of allowed requests in any window of length `window`
+    #[must_use]
+    pub fn max_allowed_in_window(&self, window: Duration) -> usize {
+        let allowed: Vec<Duration> =
+            self.outcomes.iter().filter(|o| o.allowed).map(|o| o.at).collect();
+        allowed
+            .iter()
+            .enumerate()
+            .map(|(start, from)| {
+                allowed[start..]
+                    .iter()
+                    .take_while(|at| at.saturating_sub(*from) < window)
+                    .count()
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Assert the allowed/denied pattern matches exactly
+    ///
+    /// # Panics
+    ///
+    /// Panics with the full timeline if the pattern differs.
+    pub fn assert_pattern(&self, expected: &str) {
+        assert!(
+            self.pattern() == expected,
+            "🚨 Rate limit pattern mismatch: expected {expected}, got {}\n{self}",
+            self.pattern()
+        );
+    }
+
+    /// Assert no window of length `window` allowed more than `max` requests
+    ///
+    /// # Panics
+    ///
+    /// Panics with the full timeline if the limit was exceeded.
+    pub fn assert_at_most_allowed_in_window(&self, max: usize, window: Duration) {
+        let observed = self.max_allowed_in_window(window);
+        assert!(
+            observed <= max,
+            "🚨 {observed} requests allowed within {window:?} (limit {max})\n{self}"
+        );
+    }
+
+    /// Assert header `name` on request `index` equals `expected`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the header is missing or has a different value.
+    pub fn assert_header(&self, index: usize, name: &str, expected: &str) {
+        let actual = self.header(index, name);
+        assert!(
+            actual == Some(expected),
+            "🚨 Header '{name}' on request #{index}: expected {expected:?}, got {actual:?}\n{self}"
+        );
+    }
+}
+
+impl fmt::Display for RateLimitTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Rate limit timeline ({}):", self.pattern())?;
+        for outcome in &self.outcomes {
+            let headers = outcome.headers.iter().fold(String::new(), |mut out, (k, v)| {
+                let _ = write!(out, " {k}={v}");
+                out
+            });
+            writeln!(
+                f,
+                "   #{:<3} +{:?} {}{headers}",
+                outcome.index,
+                outcome.at,
+                if outcome.allowed { "allowed" } else { "denied" }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Drives an API under test through a [`RateLimitSchedule`] on virtual time
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitHarness {
+    clock: TestClock,
+}
+
+impl RateLimitHarness {
+    /// Create a harness driving `clock` (frozen clocks give exact offsets)
+    #[must_use]
+    pub const fn new(clock: TestClock) -> Self {
+        Self { clock }
+    }
+
+    /// Clock handle to inject into the API under test
+    #[must_use]
+    pub fn clock(&self) -> TestClock {
+        self.clock.clone()
+    }
+
+    /// Send every scheduled request, advancing the clock to its offset first
+    ///
+    /// `send` receives the request index and returns the API's response. Offsets are
+    /// relative to the clock's reading when `run` is called.
+    pub fn run(
+        &self,
+        schedule: &RateLimitSchedule,
+        mut send: impl FnMut(usize) -> RateLimitResponse,
+    ) -> RateLimitTrace {
+        let start = self.clock.now();
+        let outcomes = schedule
+            .offsets()
+            .iter()
+            .enumerate()
+            .map(|(index, at)| {
+                self.clock.advance(at.saturating_sub(self.clock.elapsed_since(start)));
+                let response = send(index);
+                RateLimitOutcome {
+                    index,
+                    at: *at,
+                    allowed: response.allowed,
+                    headers: response.headers,
+                }
+            })
+            .collect();
+        RateLimitTrace { outcomes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::time::Instant;
+
+    /// Token bucket: `capacity` tokens, one refilled every `refill`
+    struct TokenBucket<C: Clock> {
+        clock: C,
+        capacity: u32,
+        refill: Duration,
+        tokens: u32,
+        last_refill: Instant,
+    }
+
+    impl<C: Clock> TokenBucket<C> {
+        fn new(clock: C, capacity: u32, refill: Duration) -> Self {
+            let last_refill = clock.now();
+            Self { clock, capacity, refill, tokens: capacity, last_refill }
+        }
+
+        fn handle(&mut self) -> RateLimitResponse {
+            let elapsed = self.clock.elapsed_since(self.last_refill);
+            let refilled = u32::try_from(elapsed.as_nanos() / self.refill.as_nanos()).unwrap();
+            if refilled > 0 {
+                self.tokens = (self.tokens + refilled).min(self.capacity);
+                self.last_refill += self.refill * refilled;
+            }
+            if self.tokens == 0 {
+                return RateLimitResponse::from_status(429).header("Retry-After", 1);
+            }
+            self.tokens -= 1;
+            RateLimitResponse::allowed().header("X-RateLimit-Remaining", self.tokens)
+        }
+    }
+
+    test!(test_schedule_builder_offsets, {
+        // Arrange & Act
+        let schedule = RateLimitSchedule::new()
+            .burst(2)
+            .wait(Duration::from_millis(100))
+            .every(Duration::from_millis(10), 3)
+            .at(Duration::from_secs(1));
+
+        // Assert
+        let ms: Vec<u128> = schedule.offsets().iter().map(Duration::as_millis).collect();
+        assert_eq!(ms, [0, 0, 100, 110, 120, 1000]);
+    });
+
+    test!(test_token_bucket_burst_then_refill, {
+        // Arrange
+        let harness = RateLimitHarness::new(TestClock::new());
+        let mut bucket = TokenBucket::new(harness.clock(), 3, Duration::from_millis(500));
+        let schedule = RateLimitSchedule::new()
+            .burst(5)
+            .wait(Duration::from_millis(500))
+            .burst(2)
+            .wait(Duration::from_secs(10))
+            .burst(4);
+
+        // Act
+        let trace = harness.run(&schedule, |_| bucket.handle());
+
+        // Assert
+        trace.assert_pattern("AAADDADAAAD");
+        assert_eq!(trace.allowed_count(), 7);
+        assert_eq!(trace.denied_count(), 4);
+        trace.assert_at_most_allowed_in_window(3, Duration::from_millis(500));
+        trace.assert_header(0, "x-ratelimit-remaining", "2");
+        trace.assert_header(3, "Retry-After", "1");
+        assert_eq!(
+            trace.header_values("X-RateLimit-Remaining")[..3],
+            [Some("2"), Some("1"), Some("0")]
+        );
+    });
+
+    test!(test_steady_rate_never_denied, {
+        // Arrange
+        let harness = RateLimitHarness::new(TestClock::new());
+        let mut bucket = TokenBucket::new(harness.clock(), 1, Duration::from_millis(100));
+        let schedule = RateLimitSchedule::new().every(Duration::from_millis(100), 50);
+
+        // Act
+        let trace = harness.run(&schedule, |_| bucket.handle());
+
+        // Assert
+        assert_eq!(trace.denied_count(), 0);
+        assert_eq!(trace.max_allowed_in_window(Duration::from_millis(100)), 1);
+        assert_eq!(trace.outcomes[49].at, Duration::from_millis(4900));
+    });
+
+    #[test]
+    #[should_panic(expected = "requests allowed within")]
+    fn test_window_assertion_reports_overrun() {
+        // Arrange
+        let harness = RateLimitHarness::new(TestClock::new());
+        let schedule = RateLimitSchedule::new().every(Duration::from_millis(10), 5);
+
+        // Act
+        let trace = harness.run(&schedule, |_| RateLimitResponse::allowed());
+
+        // Assert: Should panic
+        trace.assert_at_most_allowed_in_window(2, Duration::from_secs(1));
+    }
+}
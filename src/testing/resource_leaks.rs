@@ -0,0 +1,583 @@
+//! Socket and Listener Leak Detection
+//!
+//! [`ResourceLeakChecker`] snapshots the sockets the test process holds open before a
+//! test and, at teardown, fails the test if new listeners are still open, naming each
+//! one's protocol and address. Drop-based cleanup in fixtures is supposed to close
+//! servers and listeners; this verifies it did, instead of letting a leaked listener
+//! surface later as an `AddrInUse` in an unrelated test.
+//!
+//! Only listeners count by default (TCP `LISTEN`, bound unconnected UDP, listening Unix
+//! sockets); [`include_connections`](ResourceLeakChecker::include_connections) also
+//! fails on leaked connected sockets.
+//!
+//! **Required feature**: `leak-detection`
+//!
+//! **Platforms**: Linux reads `/proc/self/fd` and `/proc/self/net/*` (TCP, UDP, and Unix
+//! sockets); macOS runs `lsof` (TCP and UDP only). Elsewhere [`SocketTable::capture`]
+//! returns [`io::ErrorKind::Unsupported`].
+//!
+//! # Parallel tests
+//!
+//! Tests in one binary share a process, so a listener opened by a concurrently running
+//! test also appears as new. Restrict the checker to the ports the test uses with
+//! [`watch_port`](ResourceLeakChecker::watch_port), or run checked tests serially.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::testing::resource_leaks::ResourceLeakChecker;
+//!
+//! let checker = ResourceLeakChecker::new().unwrap().allow_port(5432);
+//!
+//! // ... start and stop a server under test ...
+//!
+//! checker.verify(); // or let it drop: teardown verifies too
+//! ```
+
+use crate::core::cancellation::cancellable_sleep;
+use crate::core::failure::{FailureKind, TddFailure};
+use crate::core::subprocess_guard::DEFAULT_LEAK_GRACE;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::io;
+use std::time::{Duration, Instant};
+
+/// Poll interval while waiting out the grace period
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// Socket protocol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SocketProtocol {
+    /// TCP over IPv4 or IPv6
+    Tcp,
+    /// UDP over IPv4 or IPv6
+    Udp,
+    /// Unix domain socket
+    Unix,
+}
+
+impl fmt::Display for SocketProtocol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Tcp => "tcp",
+            Self::Udp => "udp",
+            Self::Unix => "unix",
+        })
+    }
+}
+
+/// A socket held open by this process
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SocketInfo {
+    /// Stable identity while open (socket inode on Linux, descriptor on macOS)
+    pub id: u64,
+    /// Protocol
+    pub protocol: SocketProtocol,
+    /// Local address (`ip:port`, or the path for Unix sockets; empty if unnamed)
+    pub local: String,
+    /// Peer address for connected sockets
+    pub remote: Option<String>,
+    /// Whether the socket accepts connections or datagrams (a listener)
+    pub listening: bool,
+}
+
+impl SocketInfo {
+    /// Local port, for TCP and UDP sockets
+    #[must_use]
+    pub fn local_port(&self) -> Option<u16> {
+        port_of(&self.local)
+    }
+
+    /// Peer port, for connected TCP and UDP sockets
+    #[must_use]
+    pub fn remote_port(&self) -> Option<u16> {
+        self.remote.as_deref().and_then(port_of)
+    }
+
+    fn uses_port(&self, port: u16) -> bool {
+        self.local_port() == Some(port) || self.remote_port() == Some(port)
+    }
+}
+
+impl fmt::Display for SocketInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let local = if self.local.is_empty() { "(unnamed)" } else { &self.local };
+        write!(f, "{} {local}", self.protocol)?;
+        if let Some(remote) = &self.remote {
+            write!(f, " -> {remote}")?;
+        }
+        if self.listening {
+            write!(f, " (listening)")?;
+        }
+        Ok(())
+    }
+}
+
+fn port_of(address: &str) -> Option<u16> {
+    address.rsplit_once(':').and_then(|(_, port)| port.parse().ok())
+}
+
+/// Snapshot of the sockets this process holds open
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SocketTable {
+    sockets: BTreeMap<u64, SocketInfo>,
+}
+
+impl SocketTable {
+    /// Read the sockets this process holds open
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the platform's socket tables cannot be read, or
+    /// [`io::ErrorKind::Unsupported`] on platforms other than Linux and macOS.
+    pub fn capture() -> io::Result<Self> {
+        let sockets = platform::capture()?;
+        Ok(Self { sockets: sockets.into_iter().map(|s| (s.id, s)).collect() })
+    }
+
+    /// Every socket, ordered by id
+    pub fn iter(&self) -> impl Iterator<Item = &SocketInfo> {
+        self.sockets.values()
+    }
+
+    /// Listening sockets only
+    pub fn listeners(&self) -> impl Iterator<Item = &SocketInfo> {
+        self.iter().filter(|s| s.listening)
+    }
+
+    /// Socket by id
+    #[must_use]
+    pub fn get(&self, id: u64) -> Option<&SocketInfo> {
+        self.sockets.get(&id)
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use super::{SocketInfo, SocketProtocol};
+    use std::collections::BTreeSet;
+    use std::fs;
+    use std::io;
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    /// TCP `LISTEN` state in `/proc/net/tcp*`
+    const TCP_LISTEN: &str = "0A";
+    /// `__SO_ACCEPTCON` flag in `/proc/net/unix`
+    const UNIX_ACCEPTING: u32 = 0x0001_0000;
+
+    pub fn capture() -> io::Result<Vec<SocketInfo>> {
+        let owned = owned_inodes()?;
+        let mut sockets = Vec::new();
+        for (table, protocol) in [
+            ("tcp", SocketProtocol::Tcp),
+            ("tcp6", SocketProtocol::Tcp),
+            ("udp", SocketProtocol::Udp),
+            ("udp6", SocketProtocol::Udp),
+        ] {
+            // IPv6 tables are absent when IPv6 is disabled
+            let Ok(contents) = fs::read_to_string(format!("/proc/self/net/{table}")) else {
+                continue;
+            };
+            sockets.extend(contents.lines().skip(1).filter_map(|line| parse_inet(line, protocol)));
+        }
+        if let Ok(contents) = fs::read_to_string("/proc/self/net/unix") {
+            sockets.extend(contents.lines().skip(1).filter_map(parse_unix));
+        }
+        sockets.retain(|s| owned.contains(&s.id));
+        Ok(sockets)
+    }
+
+    /// Inodes of every socket descriptor this process holds
+    fn owned_inodes() -> io::Result<BTreeSet<u64>> {
+        let mut inodes = BTreeSet::new();
+        for entry in fs::read_dir("/proc/self/fd")? {
+            // Descriptors can close between listing and reading the link
+            let Ok(target) = fs::read_link(entry?.path()) else {
+                continue;
+            };
+            let inode = target
+                .to_str()
+                .and_then(|t| t.strip_prefix("socket:["))
+                .and_then(|t| t.strip_suffix(']'))
+                .and_then(|t| t.parse::<u64>().ok());
+            inodes.extend(inode);
+        }
+        Ok(inodes)
+    }
+
+    /// `sl local_address rem_address st tx:rx tr:when retrnsmt uid timeout inode ...`
+    fn parse_inet(line: &str, protocol: SocketProtocol) -> Option<SocketInfo> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let local = parse_address(fields.get(1)?)?;
+        let remote = parse_address(fields.get(2)?)?;
+        let state = *fields.get(3)?;
+        let id = fields.get(9)?.parse().ok()?;
+        let unconnected = port_is_zero(&remote);
+        let listening = match protocol {
+            SocketProtocol::Tcp => state == TCP_LISTEN,
+            SocketProtocol::Udp | SocketProtocol::Unix => unconnected,
+        };
+        let remote = if unconnected { None } else { Some(remote) };
+        Some(SocketInfo { id, protocol, local, remote, listening })
+    }
+
+    /// `Num RefCount Protocol Flags Type St Inode [Path]`
+    fn parse_unix(line: &str) -> Option<SocketInfo> {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let flags = u32::from_str_radix(fields.get(3)?, 16).ok()?;
+        let id = fields.get(6)?.parse().ok()?;
+        let local = fields.get(7).map(ToString::to_string).unwrap_or_default();
+        let listening = flags & UNIX_ACCEPTING != 0;
+        Some(SocketInfo { id, protocol: SocketProtocol::Unix, local, remote: None, listening })
+    }
+
+    fn port_is_zero(address: &str) -> bool {
+        super::port_of(address) == Some(0)
+    }
+
+    /// `0100007F:1F90` -> `127.0.0.1:8080`; each 32-bit word is printed in host order
+    fn parse_address(field: &str) -> Option<String> {
+        let (ip, port) = field.split_once(':')?;
+        let port = u16::from_str_radix(port, 16).ok()?;
+        let mut bytes = Vec::with_capacity(16);
+        for chunk in ip.as_bytes().chunks(8) {
+            let word = u32::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+            bytes.extend_from_slice(&word.to_ne_bytes());
+        }
+        match bytes.len() {
+            4 => Some(format!("{}:{port}", Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3]))),
+            16 => {
+                let octets: [u8; 16] = bytes.try_into().ok()?;
+                Some(format!("[{}]:{port}", Ipv6Addr::from(octets)))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::{SocketInfo, SocketProtocol};
+    use crate::core::command::CheckedCommand;
+    use std::io;
+
+    pub fn capture() -> io::Result<Vec<SocketInfo>> {
+        let output = CheckedCommand::new("lsof")
+            .args(["-nP", "-a", "-p", &std::process::id().to_string(), "-i", "-Ts", "-F", "fPnT"])
+            .output()
+            .map_err(|e| io::Error::other(e.to_string()))?;
+        Ok(parse_lsof(&output.stdout_lossy()))
+    }
+
+    /// `lsof -F fPnT` output: one field per line, a new `f` line per descriptor
+    fn parse_lsof(output: &str) -> Vec<SocketInfo> {
+        let mut sockets: Vec<SocketInfo> = Vec::new();
+        for line in output.lines() {
+            let (tag, value) = line.split_at(line.len().min(1));
+            if tag == "f" {
+                let Ok(id) = value.parse() else { continue };
+                sockets.push(SocketInfo {
+                    id,
+                    protocol: SocketProtocol::Tcp,
+                    local: String::new(),
+                    remote: None,
+                    listening: false,
+                });
+                continue;
+            }
+            let Some(socket) = sockets.last_mut() else { continue };
+            match tag {
+                "P" if value == "UDP" => socket.protocol = SocketProtocol::Udp,
+                "n" => {
+                    let (local, remote) = value
+                        .split_once("->")
+                        .map_or((value, None), |(local, remote)| (local, Some(remote)));
+                    socket.local = local.to_string();
+                    socket.remote = remote.map(ToString::to_string);
+                    if socket.protocol == SocketProtocol::Udp {
+                        socket.listening = socket.remote.is_none();
+                    }
+                }
+                "T" if value == "ST=LISTEN" => socket.listening = true,
+                _ => {}
+            }
+        }
+        sockets
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos")))]
+mod platform {
+    use super::SocketInfo;
+    use std::io;
+
+    pub fn capture() -> io::Result<Vec<SocketInfo>> {
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "socket leak detection requires Linux /proc or macOS lsof",
+        ))
+    }
+}
+
+/// Fixture that fails the test if it leaks listeners (or, optionally, connections)
+///
+/// Created at Arrange; [`verify`](Self::verify) (or drop) checks that every socket
+/// opened since then is closed, after a grace period. Dropping during a panic skips the
+/// check so the original failure is reported.
+#[derive(Debug)]
+pub struct ResourceLeakChecker {
+    baseline: BTreeSet<u64>,
+    watched_ports: BTreeSet<u16>,
+    allowed_ports: BTreeSet<u16>,
+    allowed: Vec<String>,
+    include_connections: bool,
+    grace: Duration,
+    verified: bool,
+}
+
+impl ResourceLeakChecker {
+    /// Record the sockets currently open as the baseline
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket table cannot be read (see [`SocketTable::capture`]).
+    pub fn new() -> io::Result<Self> {
+        let baseline = SocketTable::capture()?.iter().map(|s| s.id).collect();
+        Ok(Self {
+            baseline,
+            watched_ports: BTreeSet::new(),
+            allowed_ports: BTreeSet::new(),
+            allowed: Vec::new(),
+            include_connections: false,
+            grace: DEFAULT_LEAK_GRACE,
+            verified: false,
+        })
+    }
+
+    /// Only consider sockets on `port` (local or peer)
+    ///
+    /// May be called repeatedly; a socket on any watched port is considered.
+    #[must_use]
+    pub fn watch_port(mut self, port: u16) -> Self {
+        self.watched_ports.insert(port);
+        self
+    }
+
+    /// Ignore sockets on `port` (local or peer), e.g. a shared database
+    #[must_use]
+    pub fn allow_port(mut self, port: u16) -> Self {
+        self.allowed_ports.insert(port);
+        self
+    }
+
+    /// Ignore sockets whose description contains `pattern` (e.g. a Unix socket path)
+    #[must_use]
+    pub fn allow(mut self, pattern: impl Into<String>) -> Self {
+        self.allowed.push(pattern.into());
+        self
+    }
+
+    /// Also fail on leaked connected sockets, not only listeners
+    #[must_use]
+    pub const fn include_connections(mut self) -> Self {
+        self.include_connections = true;
+        self
+    }
+
+    /// How long new sockets get to close before they count as leaked
+    #[must_use]
+    pub const fn grace(mut self, grace: Duration) -> Self {
+        self.grace = grace;
+        self
+    }
+
+    /// New sockets open right now (no grace period)
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the socket table cannot be read.
+    pub fn leaked(&self) -> io::Result<Vec<SocketInfo>> {
+        let table = SocketTable::capture()?;
+        Ok(table
+            .iter()
+            .filter(|s| !self.baseline.contains(&s.id))
+            .filter(|s| self.include_connections || s.listening)
+            .filter(|s| {
+                self.watched_ports.is_empty() || self.watched_ports.iter().any(|&p| s.uses_port(p))
+            })
+            .filter(|s| !self.allowed_ports.iter().any(|&p| s.uses_port(p)))
+            .filter(|s| {
+                let description = s.to_string();
+                !self.allowed.iter().any(|a| description.contains(a.as_str()))
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Fail the test if leaked sockets remain once the grace period passes
+    ///
+    /// # Panics
+    ///
+    /// Panics with every leaked socket, or if the socket table cannot be read.
+    #[track_caller]
+    pub fn verify(mut self) {
+        self.verified = true;
+        if let Some(failure) = self.check() {
+            failure.raise();
+        }
+    }
+
+    #[track_caller]
+    fn check(&self) -> Option<TddFailure> {
+        let deadline = Instant::now() + self.grace;
+        loop {
+            let leaked = match self.leaked() {
+                Ok(leaked) => leaked,
+                Err(e) => {
+                    return Some(TddFailure::new(
+                        FailureKind::Constraint,
+                        format!("🚨 Could not read the socket table to check for leaks: {e}"),
+                    ));
+                }
+            };
+            if leaked.is_empty() {
+                return None;
+            }
+            if Instant::now() >= deadline
+                || cancellable_sleep("leaked sockets to close", POLL_INTERVAL).is_err()
+            {
+                return Some(Self::failure(&leaked, self.grace));
+            }
+        }
+    }
+
+    #[track_caller]
+    fn failure(leaked: &[SocketInfo], grace: Duration) -> TddFailure {
+        let list = leaked.iter().fold(String::new(), |mut out, s| {
+            out.push_str("\n   - ");
+            out.push_str(&s.to_string());
+            out
+        });
+        let addresses = leaked.iter().map(|s| s.local.clone()).collect::<Vec<_>>().join(",");
+        TddFailure::new(
+            FailureKind::Constraint,
+            format!(
+                "🚨 Test leaked {} socket(s) still open {grace:?} after teardown:{list}\n   💡 FIX: Drop listeners and servers before the test ends, or allow shared ones",
+                leaked.len()
+            ),
+        )
+        .with_context("leaked_sockets", addresses)
+    }
+}
+
+impl Drop for ResourceLeakChecker {
+    fn drop(&mut self) {
+        if self.verified || std::thread::panicking() {
+            return;
+        }
+        self.verified = true;
+        if let Some(failure) = self.check() {
+            failure.raise();
+        }
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::net::{Ipv4Addr, TcpListener, TcpStream, UdpSocket};
+
+    #[allow(clippy::unwrap_used)] // Test code - loopback bind must succeed
+    fn listener() -> (TcpListener, u16) {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        (listener, port)
+    }
+
+    test!(test_socket_table_reports_tcp_and_udp_listeners, {
+        // Arrange
+        let (tcp, tcp_port) = listener();
+        #[allow(clippy::unwrap_used)] // Test code - loopback bind must succeed
+        let udp = UdpSocket::bind((Ipv4Addr::LOCALHOST, 0)).unwrap();
+        #[allow(clippy::unwrap_used)] // Test code - bound socket has an address
+        let udp_port = udp.local_addr().unwrap().port();
+
+        // Act
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let table = SocketTable::capture().unwrap();
+
+        // Assert
+        let described: Vec<String> = table.listeners().map(ToString::to_string).collect();
+        assert!(described.contains(&format!("tcp 127.0.0.1:{tcp_port} (listening)")));
+        assert!(described.contains(&format!("udp 127.0.0.1:{udp_port} (listening)")));
+        drop((tcp, udp));
+    });
+
+    test!(test_checker_passes_once_listener_is_dropped, {
+        // Arrange
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let checker = ResourceLeakChecker::new().unwrap().grace(Duration::ZERO);
+        let (tcp, port) = listener();
+        let checker = checker.watch_port(port);
+
+        // Act
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let leaked = checker.leaked().unwrap();
+        drop(tcp);
+
+        // Assert
+        assert_eq!(leaked.iter().map(SocketInfo::local_port).collect::<Vec<_>>(), [Some(port)]);
+        checker.verify();
+    });
+
+    test!(test_verify_fails_with_leaked_listener_and_honours_allowlist, {
+        // Arrange
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let checker = ResourceLeakChecker::new().unwrap().grace(Duration::from_millis(40));
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let allowed = ResourceLeakChecker::new().unwrap().grace(Duration::ZERO);
+        let (tcp, port) = listener();
+        let checker = checker.watch_port(port);
+        let allowed = allowed.watch_port(port).allow_port(port);
+
+        // Act
+        let result = TddFailure::catch(|| checker.verify());
+
+        // Assert
+        #[allow(clippy::unwrap_used)] // Test code - verify must fail while the listener is open
+        let failure = result.unwrap_err();
+        assert_eq!(failure.kind(), FailureKind::Constraint);
+        assert!(failure.message().contains(&format!("tcp 127.0.0.1:{port} (listening)")));
+        allowed.verify();
+        drop(tcp);
+    });
+
+    test!(test_connections_count_only_when_included, {
+        // Arrange
+        let (server, port) = listener();
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let listeners_only = ResourceLeakChecker::new().unwrap().watch_port(port);
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let with_connections =
+            ResourceLeakChecker::new().unwrap().watch_port(port).include_connections();
+
+        // Act
+        #[allow(clippy::unwrap_used)] // Test code - loopback connect must succeed
+        let client = TcpStream::connect(("127.0.0.1", port)).unwrap();
+        #[allow(clippy::unwrap_used)] // Test code - pending connection is queued
+        let (accepted, _) = server.accept().unwrap();
+
+        // Assert
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let leaked = with_connections.leaked().unwrap();
+        assert_eq!(leaked.len(), 2, "{leaked:?}");
+        assert!(leaked.iter().all(|s| s.remote.is_some() && !s.listening));
+        #[allow(clippy::unwrap_used)] // Test code - /proc is available on Linux
+        let only_listeners = listeners_only.leaked().unwrap();
+        assert!(only_listeners.is_empty());
+        drop((client, accepted));
+        listeners_only.verify();
+        with_connections.verify();
+    });
+}
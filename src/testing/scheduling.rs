@@ -0,0 +1,464 @@
+//! > 📚 Reference
+//!
+//! Deterministic Scheduling
+//!
+//! Test-scoped replacement for rayon's global thread pool. Parallel code run through
+//! [`DeterministicPool::install`] executes on a single worker thread, so `join`, `scope`,
+//! and parallel iterators run in one reproducible order and tests can make exact
+//! assertions (ordering of side effects, floating-point reductions, first-match
+//! searches) before the same code is stress-tested on a real pool.
+//!
+//! Work submitted through the pool itself ([`DeterministicPool::map`],
+//! [`DeterministicPool::join`], [`DeterministicPool::scope`]) follows the pool's
+//! [`SchedulingMode`]: [`SchedulingMode::Sequential`] runs tasks in submission order, and
+//! [`SchedulingMode::Seeded`] runs them in a seed-determined permutation, so order-dependent
+//! bugs show up deterministically and a failing seed replays exactly.
+//!
+//! Rayon's global pool cannot be swapped once built; code under test must run inside
+//! [`DeterministicPool::install`] (which [`deterministic_test!`](crate::deterministic_test)
+//! does for the whole test body).
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::testing::scheduling::{DeterministicPool, SchedulingMode};
+//! use rayon::prelude::*;
+//! use std::sync::Mutex;
+//!
+//! let pool = DeterministicPool::new(SchedulingMode::Sequential).unwrap();
+//! let visited = Mutex::new(Vec::new());
+//!
+//! pool.install(|| {
+//!     (0..4).into_par_iter().for_each(|i| visited.lock().unwrap().push(i));
+//! });
+//!
+//! // One worker, one order: the parallel loop visits items in sequence
+//! assert_eq!(*visited.lock().unwrap(), [0, 1, 2, 3]);
+//!
+//! // Seeded pools permute submitted tasks reproducibly; results stay in input order
+//! let seeded = DeterministicPool::new(SchedulingMode::Seeded(7)).unwrap();
+//! let squares = seeded.map(vec![1, 2, 3], |x| x * x);
+//! assert_eq!(squares, [1, 4, 9]);
+//! ```
+
+use crate::core::failure::TddFailure;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Mutex, PoisonError};
+use thiserror::Error;
+
+/// Name of the pool's worker thread
+pub const WORKER_THREAD_NAME: &str = "chicago-tdd-deterministic";
+
+/// Deterministic scheduling errors
+#[derive(Error, Debug)]
+pub enum SchedulingError {
+    /// The single-worker rayon pool could not be created
+    #[error("Failed to build deterministic pool: {0}")]
+    PoolBuild(#[from] rayon::ThreadPoolBuildError),
+}
+
+/// Result type for deterministic scheduling
+pub type SchedulingResult<T> = Result<T, SchedulingError>;
+
+/// Order in which a [`DeterministicPool`] runs submitted tasks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum SchedulingMode {
+    /// Submission order
+    #[default]
+    Sequential,
+    /// A permutation derived from the seed; equal seeds give equal orders
+    Seeded(u64),
+}
+
+impl fmt::Display for SchedulingMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sequential => f.write_str("sequential"),
+            Self::Seeded(seed) => write!(f, "seeded({seed})"),
+        }
+    }
+}
+
+/// > 📚 Reference
+///
+/// Single-worker thread pool with a reproducible task order.
+pub struct DeterministicPool {
+    schedule: SchedulingMode,
+    pool: rayon::ThreadPool,
+    rng: Mutex<SplitMix64>,
+}
+
+impl fmt::Debug for DeterministicPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeterministicPool")
+            .field("schedule", &self.schedule)
+            .finish_non_exhaustive()
+    }
+}
+
+impl DeterministicPool {
+    /// Create a pool with one worker thread
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchedulingError::PoolBuild`] if the worker thread cannot be spawned.
+    pub fn new(schedule: SchedulingMode) -> SchedulingResult<Self> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(1)
+            .thread_name(|_| WORKER_THREAD_NAME.to_string())
+            .build()?;
+        let seed = match schedule {
+            SchedulingMode::Sequential => 0,
+            SchedulingMode::Seeded(seed) => seed,
+        };
+        Ok(Self { schedule, pool, rng: Mutex::new(SplitMix64(seed)) })
+    }
+
+    /// The pool's schedule
+    #[must_use]
+    pub const fn schedule(&self) -> SchedulingMode {
+        self.schedule
+    }
+
+    /// Run `op` on the worker thread
+    ///
+    /// Rayon `join`, `scope`, and parallel iterators called from `op` use this pool
+    /// instead of the global one, so they run one task at a time in a fixed order.
+    /// Panics in `op` propagate to the caller.
+    pub fn install<OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        self.pool.install(op)
+    }
+
+    /// Permutation of `0..len` giving the order in which `len` submitted tasks run
+    ///
+    /// Each call on a seeded pool advances its generator, so successive batches get
+    /// different (but reproducible) orders.
+    #[must_use]
+    pub fn execution_order(&self, len: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..len).collect();
+        if let SchedulingMode::Seeded(_) = self.schedule {
+            let mut rng = self.rng.lock().unwrap_or_else(PoisonError::into_inner);
+            // Fisher-Yates
+            for i in (1..len).rev() {
+                order.swap(i, rng.below(i + 1));
+            }
+        }
+        order
+    }
+
+    /// Apply `f` to every item, in schedule order, returning results in input order
+    pub fn map<T, R, F>(&self, items: Vec<T>, f: F) -> Vec<R>
+    where
+        T: Send,
+        R: Send,
+        F: Fn(T) -> R + Send,
+    {
+        let order = self.execution_order(items.len());
+        self.install(move || {
+            let mut items: Vec<Option<T>> = items.into_iter().map(Some).collect();
+            let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+            for index in order {
+                if let Some(item) = items[index].take() {
+                    results[index] = Some(f(item));
+                }
+            }
+            results.into_iter().flatten().collect()
+        })
+    }
+
+    /// Run two closures, in schedule order, returning their results as `(a, b)`
+    pub fn join<A, B, RA, RB>(&self, a: A, b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA + Send,
+        B: FnOnce() -> RB + Send,
+        RA: Send,
+        RB: Send,
+    {
+        let b_first = self.execution_order(2)[0] == 1;
+        self.install(move || {
+            if b_first {
+                let rb = b();
+                (a(), rb)
+            } else {
+                let ra = a();
+                (ra, b())
+            }
+        })
+    }
+
+    /// Create a scope for spawning tasks that may borrow from the caller
+    ///
+    /// Tasks spawned with [`DeterministicScope::spawn`] (including tasks spawned by
+    /// other tasks) run after `op` returns and before `scope` does, one at a time in
+    /// schedule order.
+    pub fn scope<'scope, OP, R>(&self, op: OP) -> R
+    where
+        OP: FnOnce(&DeterministicScope<'scope>) -> R + Send,
+        R: Send,
+    {
+        self.install(|| {
+            let scope = DeterministicScope { tasks: Mutex::new(Vec::new()) };
+            let result = op(&scope);
+            while let Some(task) = self.next_task(&scope) {
+                task(&scope);
+            }
+            result
+        })
+    }
+
+    fn next_task<'scope>(&self, scope: &DeterministicScope<'scope>) -> Option<ScopeTask<'scope>> {
+        let mut tasks = scope.tasks.lock().unwrap_or_else(PoisonError::into_inner);
+        if tasks.is_empty() {
+            return None;
+        }
+        let index = match self.schedule {
+            SchedulingMode::Sequential => 0,
+            SchedulingMode::Seeded(_) => {
+                self.rng.lock().unwrap_or_else(PoisonError::into_inner).below(tasks.len())
+            }
+        };
+        Some(tasks.remove(index))
+    }
+
+    /// Run `body` on a fresh seeded pool for each seed, inside [`Self::install`]
+    ///
+    /// A failure is re-raised with a `schedule` context entry naming the failing seed,
+    /// so it can be replayed with `SchedulingMode::Seeded(seed)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SchedulingError::PoolBuild`] if a pool cannot be created.
+    pub fn for_each_seed<I, F>(seeds: I, mut body: F) -> SchedulingResult<()>
+    where
+        I: IntoIterator<Item = u64>,
+        F: FnMut(&Self) + Send,
+    {
+        for seed in seeds {
+            let pool = Self::new(SchedulingMode::Seeded(seed))?;
+            let outcome = panic::catch_unwind(AssertUnwindSafe(|| pool.install(|| body(&pool))));
+            if let Err(payload) = outcome {
+                TddFailure::from_payload(payload)
+                    .with_context("schedule", pool.schedule().to_string())
+                    .raise();
+            }
+        }
+        Ok(())
+    }
+}
+
+type ScopeTask<'scope> = Box<dyn FnOnce(&DeterministicScope<'scope>) + Send + 'scope>;
+
+/// Task spawner passed to [`DeterministicPool::scope`]
+pub struct DeterministicScope<'scope> {
+    tasks: Mutex<Vec<ScopeTask<'scope>>>,
+}
+
+impl<'scope> DeterministicScope<'scope> {
+    /// Queue a task; it runs before the enclosing [`DeterministicPool::scope`] returns
+    pub fn spawn<F>(&self, task: F)
+    where
+        F: FnOnce(&Self) + Send + 'scope,
+    {
+        self.tasks.lock().unwrap_or_else(PoisonError::into_inner).push(Box::new(task));
+    }
+}
+
+/// Run a test body inside a [`DeterministicPool`]
+///
+/// The pool is bound to the given identifier, and the body runs inside
+/// [`DeterministicPool::install`] so rayon code it calls is deterministic.
+///
+/// - `deterministic_test!(name, pool, { .. })`: sequential schedule
+/// - `deterministic_test!(name, pool, seed = 42, { .. })`: one seeded schedule
+/// - `deterministic_test!(name, pool, seeds = 0..16, { .. })`: every seed in turn; a
+///   failure names the seed
+///
+/// [`DeterministicPool`]: crate::testing::scheduling::DeterministicPool
+/// [`DeterministicPool::install`]: crate::testing::scheduling::DeterministicPool::install
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::deterministic_test;
+/// use rayon::prelude::*;
+///
+/// deterministic_test!(test_find_any_returns_first_match, pool, seeds = 0..8, {
+///     // Arrange
+///     let orders: Vec<u32> = (1..1000).collect();
+///
+///     // Act: `find_any` may return any match on a real pool
+///     let found = orders.par_iter().find_any(|id| *id % 7 == 0);
+///     let labels = pool.map(vec![1, 2, 3], |id| format!("order-{id}"));
+///
+///     // Assert: on one worker it is always the first
+///     assert_eq!(found, Some(&7));
+///     assert_eq!(labels, ["order-1", "order-2", "order-3"]);
+/// });
+/// ```
+#[macro_export]
+macro_rules! deterministic_test {
+    ($name:ident, $pool:ident, seeds = $seeds:expr, $body:block) => {
+        #[test]
+        fn $name() {
+            #[allow(clippy::expect_used)] // Test macro - failing to spawn the worker is fatal
+            $crate::testing::scheduling::DeterministicPool::for_each_seed($seeds, |$pool| $body)
+                .expect("failed to build deterministic pool");
+        }
+    };
+    ($name:ident, $pool:ident, seed = $seed:expr, $body:block) => {
+        $crate::deterministic_test!(
+            @run $name,
+            $pool,
+            $crate::testing::scheduling::SchedulingMode::Seeded($seed),
+            $body
+        );
+    };
+    ($name:ident, $pool:ident, $body:block) => {
+        $crate::deterministic_test!(
+            @run $name,
+            $pool,
+            $crate::testing::scheduling::SchedulingMode::Sequential,
+            $body
+        );
+    };
+    (@run $name:ident, $pool:ident, $schedule:expr, $body:block) => {
+        #[test]
+        fn $name() {
+            #[allow(clippy::expect_used)] // Test macro - failing to spawn the worker is fatal
+            let $pool = $crate::testing::scheduling::DeterministicPool::new($schedule)
+                .expect("failed to build deterministic pool");
+            let $pool = &$pool;
+            $pool.install(|| $body);
+        }
+    };
+}
+
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    const fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform-enough value in `0..bound` (`bound > 0`)
+    const fn below(&mut self, bound: usize) -> usize {
+        // Task counts fit in u64, and the remainder is below `bound`
+        #[allow(clippy::cast_possible_truncation)]
+        let value = (self.next() % bound as u64) as usize;
+        value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::failure::FailureKind;
+    use crate::test;
+    use rayon::prelude::*;
+
+    test!(test_install_runs_parallel_iterators_in_order, {
+        // Arrange
+        let pool = DeterministicPool::new(SchedulingMode::Sequential).unwrap();
+        let visited = Mutex::new(Vec::new());
+
+        // Act
+        let threads = pool.install(|| {
+            (0..64).into_par_iter().for_each(|i| visited.lock().unwrap().push(i));
+            rayon::current_num_threads()
+        });
+
+        // Assert
+        assert_eq!(threads, 1);
+        assert_eq!(visited.into_inner().unwrap(), (0..64).collect::<Vec<_>>());
+    });
+
+    test!(test_seeded_order_is_reproducible_permutation, {
+        // Arrange
+        let first = DeterministicPool::new(SchedulingMode::Seeded(42)).unwrap();
+        let second = DeterministicPool::new(SchedulingMode::Seeded(42)).unwrap();
+
+        // Act
+        let orders = (first.execution_order(16), second.execution_order(16));
+        let next = first.execution_order(16);
+
+        // Assert
+        assert_eq!(orders.0, orders.1);
+        assert_ne!(orders.0, (0..16).collect::<Vec<_>>());
+        assert_ne!(next, orders.0);
+        let mut sorted = orders.0.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..16).collect::<Vec<_>>());
+    });
+
+    test!(test_map_runs_in_schedule_order_and_keeps_input_order, {
+        // Arrange
+        let pool = DeterministicPool::new(SchedulingMode::Seeded(3)).unwrap();
+        let expected_order =
+            DeterministicPool::new(SchedulingMode::Seeded(3)).unwrap().execution_order(5);
+        let ran = Mutex::new(Vec::new());
+
+        // Act
+        let doubled = pool.map(vec![10, 11, 12, 13, 14], |x| {
+            ran.lock().unwrap().push(x - 10);
+            x * 2
+        });
+
+        // Assert
+        assert_eq!(doubled, [20, 22, 24, 26, 28]);
+        assert_eq!(ran.into_inner().unwrap(), expected_order);
+    });
+
+    test!(test_scope_runs_nested_spawns_and_borrows, {
+        // Arrange
+        let pool = DeterministicPool::new(SchedulingMode::Sequential).unwrap();
+        let log = Mutex::new(Vec::new());
+
+        // Act
+        pool.scope(|scope| {
+            scope.spawn(|scope| {
+                log.lock().unwrap().push("a");
+                scope.spawn(|_| log.lock().unwrap().push("a.1"));
+            });
+            scope.spawn(|_| log.lock().unwrap().push("b"));
+        });
+
+        // Assert
+        assert_eq!(log.into_inner().unwrap(), ["a", "b", "a.1"]);
+    });
+
+    test!(test_for_each_seed_reports_failing_seed, {
+        // Act
+        let failure = TddFailure::catch(|| {
+            DeterministicPool::for_each_seed(0..4, |pool| {
+                let (a, b) = pool.join(|| 1, || 2);
+                assert_eq!((a, b), (1, 2));
+                assert_ne!(pool.schedule(), SchedulingMode::Seeded(2), "order-dependent bug");
+            })
+            .unwrap();
+        })
+        .unwrap_err();
+
+        // Assert
+        assert_eq!(failure.kind(), FailureKind::Panic);
+        assert_eq!(failure.context()["schedule"], "seeded(2)");
+        assert!(failure.message().contains("order-dependent bug"), "{failure}");
+    });
+
+    deterministic_test!(test_deterministic_test_macro_binds_pool, pool, seed = 9, {
+        // Act
+        let worker = std::thread::current().name().map(str::to_string);
+
+        // Assert
+        assert_eq!(pool.schedule(), SchedulingMode::Seeded(9));
+        assert_eq!(worker.as_deref(), Some(WORKER_THREAD_NAME));
+    });
+}
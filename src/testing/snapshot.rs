@@ -10,9 +10,17 @@
 //! - **State-Based Testing**: Verifies outputs and state, not implementation
 //! - **Behavior Verification**: Tests what code produces, not how it produces it
 //! - **AAA Pattern**: Arrange (setup), Act (execute), Assert (snapshot comparison)
+//!
+//! # Redaction
+//!
+//! Every assertion applies the rules configured under `[redaction]` in
+//! `chicago-tdd-tools.toml` (see [`RedactionRuleSet`]) before comparing, so secrets never
+//! reach a stored snapshot.
 
 #[cfg(feature = "snapshot-testing")]
-use insta::{assert_debug_snapshot, assert_snapshot, Settings};
+use crate::core::redaction::RedactionRuleSet;
+#[cfg(feature = "snapshot-testing")]
+use insta::{assert_snapshot, Settings};
 #[cfg(feature = "snapshot-testing")]
 use std::collections::HashMap;
 
@@ -52,7 +60,7 @@ impl SnapshotAssert {
     /// SnapshotAssert::assert_matches(&data, "test_data");
     /// ```
     pub fn assert_matches<T: std::fmt::Display>(value: &T, snapshot_name: &str) {
-        assert_snapshot!(snapshot_name, value);
+        assert_snapshot!(snapshot_name, redact_text(&value.to_string()));
     }
 
     /// Assert that a debug representation matches a snapshot
@@ -66,7 +74,7 @@ impl SnapshotAssert {
     ///
     /// Panics if the debug representation doesn't match the stored snapshot.
     pub fn assert_debug_matches<T: std::fmt::Debug>(value: &T, snapshot_name: &str) {
-        assert_snapshot!(snapshot_name, format!("{:#?}", value));
+        assert_snapshot!(snapshot_name, redact_text(&format!("{value:#?}")));
     }
 
     /// Assert that a JSON value matches a snapshot
@@ -80,10 +88,7 @@ impl SnapshotAssert {
     ///
     /// Panics if the JSON doesn't match the stored snapshot.
     pub fn assert_json_matches(value: &serde_json::Value, snapshot_name: &str) {
-        assert_snapshot!(
-            snapshot_name,
-            serde_json::to_string_pretty(value).unwrap_or_else(|_| "invalid json".to_string())
-        );
+        assert_snapshot!(snapshot_name, redact_json(value));
     }
 
     /// Configure snapshot settings for a test
@@ -158,7 +163,7 @@ impl SnapshotAssert {
         let mut settings = Settings::clone_current();
         settings.set_snapshot_suffix(sanitized);
         settings.bind(|| {
-            assert_snapshot!(format!("{value}"));
+            assert_snapshot!(redact_text(&value.to_string()));
         });
     }
 
@@ -175,7 +180,7 @@ impl SnapshotAssert {
         let mut settings = Settings::clone_current();
         settings.set_snapshot_suffix(sanitized);
         settings.bind(|| {
-            assert_debug_snapshot!(value);
+            assert_snapshot!(redact_text(&format!("{value:#?}")));
         });
     }
 
@@ -192,9 +197,7 @@ impl SnapshotAssert {
         let mut settings = Settings::clone_current();
         settings.set_snapshot_suffix(sanitized);
         settings.bind(|| {
-            assert_snapshot!(
-                serde_json::to_string_pretty(value).unwrap_or_else(|_| "invalid json".to_string())
-            );
+            assert_snapshot!(redact_json(value));
         });
     }
 
@@ -241,51 +244,38 @@ impl SnapshotAssert {
         snapshot_name: &str,
         redactions: &HashMap<String, String>,
     ) {
-        // Apply redactions by modifying the JSON value before snapshotting
-        let mut redacted_value = value.clone();
-        Self::apply_redactions(&mut redacted_value, redactions);
-        Self::assert_json_matches(&redacted_value, snapshot_name);
+        let rules =
+            redactions.iter().fold(RedactionRuleSet::new(), |rules, (path, replacement)| {
+                rules.path(path, replacement)
+            });
+        Self::assert_with_rules(value, snapshot_name, &rules);
     }
 
-    /// Apply redactions to a JSON value using dot-notation paths
-    fn apply_redactions(value: &mut serde_json::Value, redactions: &HashMap<String, String>) {
-        for (selector, replacement) in redactions {
-            let path: Vec<&str> = selector.trim_start_matches('.').split('.').collect();
-            Self::set_json_path(value, &path, serde_json::Value::String(replacement.clone()));
-        }
-    }
-
-    /// Set a value at a JSON path (dot-notation)
-    fn set_json_path(value: &mut serde_json::Value, path: &[&str], replacement: serde_json::Value) {
-        if path.is_empty() {
-            return;
-        }
-
-        match value {
-            serde_json::Value::Object(map) => {
-                let key = path[0];
-                if path.len() == 1 {
-                    map.insert(key.to_string(), replacement);
-                } else if let Some(nested) = map.get_mut(key) {
-                    Self::set_json_path(nested, &path[1..], replacement);
-                }
-            }
-            serde_json::Value::Array(arr) => {
-                if let Ok(index) = path[0].parse::<usize>() {
-                    if index < arr.len() {
-                        if path.len() == 1 {
-                            arr[index] = replacement;
-                        } else {
-                            Self::set_json_path(&mut arr[index], &path[1..], replacement);
-                        }
-                    }
-                }
-            }
-            _ => {
-                // For non-object/non-array values, we can't apply path-based redaction
-                // This is expected for leaf values
-            }
-        }
+    /// Assert with a [`RedactionRuleSet`]
+    ///
+    /// Applies `rules` (field, path, and pattern rules) and then the configured rules
+    /// before snapshot comparison.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the redacted value doesn't match the stored snapshot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use chicago_tdd_tools::core::redaction::RedactionRuleSet;
+    ///
+    /// let rules = RedactionRuleSet::common().field("api_key", "[KEY]");
+    /// // SnapshotAssert::assert_with_rules(&response, "api_response", &rules);
+    /// ```
+    pub fn assert_with_rules(
+        value: &serde_json::Value,
+        snapshot_name: &str,
+        rules: &RedactionRuleSet,
+    ) {
+        let mut redacted_value = value.clone();
+        rules.redact_json(&mut redacted_value);
+        Self::assert_json_matches(&redacted_value, snapshot_name);
     }
 
     /// Assert with profile (v1.3.0)
@@ -362,6 +352,26 @@ impl SnapshotAssert {
     }
 }
 
+/// Apply the configured redaction rules to rendered snapshot text
+#[cfg(feature = "snapshot-testing")]
+fn redact_text(text: &str) -> String {
+    RedactionRuleSet::configured().redact_text(text).into_owned()
+}
+
+/// Apply the configured redaction rules to a JSON value and pretty-print it
+#[cfg(feature = "snapshot-testing")]
+fn redact_json(value: &serde_json::Value) -> String {
+    let rules = RedactionRuleSet::configured();
+    let rendered = if rules.is_empty() {
+        serde_json::to_string_pretty(value)
+    } else {
+        let mut value = value.clone();
+        rules.redact_json(&mut value);
+        serde_json::to_string_pretty(&value)
+    };
+    rendered.unwrap_or_else(|_| "invalid json".to_string())
+}
+
 #[cfg(test)]
 #[allow(clippy::panic)] // Test code - panic is appropriate for test failures
 mod tests {
@@ -22,7 +22,76 @@
 //!     - Illegal sequences cannot compile
 //!     - All legal sequences explored (bounded depth)
 //! ```
+//!
+//! # Model-Based Testing
+//!
+//! [`ModelTester`] checks a real system against a [`StateModel`]: it generates random
+//! command sequences that respect the model's preconditions (at most `MAX_RUN_LEN`
+//! commands per run, the guard limit), executes them against a fresh system, checks
+//! each output and the resulting state against the model, and shrinks a failing
+//! sequence to a minimal one.
+//!
+//! ```rust
+//! use chicago_tdd_tools::testing::state_machine::{ModelRng, ModelTester, StateModel};
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum Cmd {
+//!     Push(u8),
+//!     Pop,
+//! }
+//!
+//! struct StackModel;
+//!
+//! impl StateModel for StackModel {
+//!     type State = Vec<u8>;
+//!     type Command = Cmd;
+//!     type System = Vec<u8>;
+//!     type Output = Option<u8>;
+//!
+//!     fn initial_state(&self) -> Vec<u8> {
+//!         Vec::new()
+//!     }
+//!     fn new_system(&self) -> Vec<u8> {
+//!         Vec::new()
+//!     }
+//!     fn generate(&self, _state: &Vec<u8>, rng: &mut ModelRng) -> Cmd {
+//!         if rng.chance(60) { Cmd::Push(rng.below(10) as u8) } else { Cmd::Pop }
+//!     }
+//!     fn precondition(&self, state: &Vec<u8>, command: &Cmd) -> bool {
+//!         *command != Cmd::Pop || !state.is_empty()
+//!     }
+//!     fn next_state(&self, state: &Vec<u8>, command: &Cmd) -> Vec<u8> {
+//!         let mut next = state.clone();
+//!         match command {
+//!             Cmd::Push(value) => next.push(*value),
+//!             Cmd::Pop => {
+//!                 next.pop();
+//!             }
+//!         }
+//!         next
+//!     }
+//!     fn execute(&self, system: &mut Vec<u8>, command: &Cmd) -> Option<u8> {
+//!         match command {
+//!             Cmd::Push(value) => {
+//!                 system.push(*value);
+//!                 None
+//!             }
+//!             Cmd::Pop => system.pop(),
+//!         }
+//!     }
+//!     fn postcondition(&self, state: &Vec<u8>, command: &Cmd, output: &Option<u8>) -> Result<(), String> {
+//!         let expected = if *command == Cmd::Pop { state.last().copied() } else { None };
+//!         if *output == expected { Ok(()) } else { Err(format!("expected {expected:?}, got {output:?}")) }
+//!     }
+//! }
+//!
+//! let report = ModelTester::new(StackModel).with_seed(7).with_runs(50).check().unwrap();
+//! report.assert_passed();
+//! ```
 
+use crate::core::failure::{FailureKind, TddFailure};
+use crate::validation::guards::{GuardConstraintResult, GuardValidator, MAX_RUN_LEN};
+use std::fmt;
 use std::marker::PhantomData;
 
 /// State marker trait
@@ -344,6 +413,401 @@ impl ModelChecker {
     }
 }
 
+// ============================================================================
+// Model-Based Testing
+// ============================================================================
+
+/// A model of a system under test, checked by [`ModelTester`]
+///
+/// The model state says what the system's state should be; commands are applied to
+/// both, and each command's output is checked against the model.
+pub trait StateModel {
+    /// What the system's state should be
+    type State: Clone;
+    /// Operation applied to the model and the system
+    type Command: Clone + fmt::Debug;
+    /// The real system
+    type System;
+    /// What the system returns for a command
+    type Output;
+
+    /// Model state of a fresh system
+    fn initial_state(&self) -> Self::State;
+
+    /// Fresh real system, in the state [`initial_state`](Self::initial_state) models
+    fn new_system(&self) -> Self::System;
+
+    /// Generate a command for `state` (regenerated while it fails its precondition)
+    fn generate(&self, state: &Self::State, rng: &mut ModelRng) -> Self::Command;
+
+    /// Whether `command` may run in `state` (default: always)
+    fn precondition(&self, _state: &Self::State, _command: &Self::Command) -> bool {
+        true
+    }
+
+    /// Model state after `command` runs in `state`
+    fn next_state(&self, state: &Self::State, command: &Self::Command) -> Self::State;
+
+    /// Run `command` against the real system
+    fn execute(&self, system: &mut Self::System, command: &Self::Command) -> Self::Output;
+
+    /// Check the output of `command`, which ran in model state `state` (default: no
+    /// check)
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of how the output differs from the model.
+    fn postcondition(
+        &self,
+        _state: &Self::State,
+        _command: &Self::Command,
+        _output: &Self::Output,
+    ) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Compare the system with the model state after a command (default: no check)
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of how the system differs from the model.
+    fn check_state(&self, _state: &Self::State, _system: &Self::System) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Simpler variants of `command`, tried while shrinking (default: none)
+    fn shrink_command(&self, _command: &Self::Command) -> Vec<Self::Command> {
+        Vec::new()
+    }
+}
+
+/// Seeded random source for command generation (`SplitMix64`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelRng(u64);
+
+impl ModelRng {
+    /// Create a generator; equal seeds produce equal sequences
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Next random value
+    pub const fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Random value in `0..n` (`0` when `n` is `0`)
+    pub fn below(&mut self, n: usize) -> usize {
+        usize::try_from(self.next_u64() % (n.max(1) as u64)).unwrap_or(0)
+    }
+
+    /// `true` with the given percentage (0-100)
+    pub fn chance(&mut self, percent: u8) -> bool {
+        self.next_u64() % 100 < u64::from(percent)
+    }
+
+    /// Random element of `items` (`None` when empty)
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        items.get(self.below(items.len()))
+    }
+}
+
+/// Attempts to generate a command satisfying its precondition before a run ends early
+const GENERATE_ATTEMPTS: usize = 100;
+
+/// Successful shrink steps before shrinking stops (bounds non-terminating
+/// [`StateModel::shrink_command`] implementations)
+const MAX_SHRINKS: usize = 1000;
+
+/// The command at which a sequence diverged from the model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelStepFailure {
+    /// Index of the failing command
+    pub step: usize,
+    /// What went wrong
+    pub message: String,
+}
+
+impl fmt::Display for ModelStepFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "step {}: {}", self.step, self.message)
+    }
+}
+
+/// A failing command sequence, before and after shrinking
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelFailure<C> {
+    /// Seed that generated the failing run
+    pub seed: u64,
+    /// Sequence as generated
+    pub original: Vec<C>,
+    /// Minimal failing sequence found by shrinking
+    pub commands: Vec<C>,
+    /// Where the minimal sequence fails
+    pub failure: ModelStepFailure,
+    /// Successful shrink steps
+    pub shrinks: usize,
+}
+
+/// Outcome of [`ModelTester::check`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModelReport<C> {
+    /// Base seed of the check
+    pub seed: u64,
+    /// Runs executed (including the failing one)
+    pub runs: usize,
+    /// Commands executed by passing runs
+    pub commands: usize,
+    /// First failure, shrunk
+    pub failure: Option<ModelFailure<C>>,
+}
+
+impl<C> ModelReport<C> {
+    /// Whether every run matched the model
+    #[must_use]
+    pub const fn is_passed(&self) -> bool {
+        self.failure.is_none()
+    }
+}
+
+impl<C: fmt::Debug> ModelReport<C> {
+    /// Fail the calling test if a run diverged from the model
+    ///
+    /// # Panics
+    ///
+    /// Raises a [`FailureKind::Constraint`] failure with the shrunk sequence and the
+    /// seed that reproduces it.
+    #[track_caller]
+    pub fn assert_passed(&self) {
+        let Some(failure) = &self.failure else {
+            return;
+        };
+        TddFailure::new(FailureKind::Constraint, self.to_string())
+            .with_context("seed", failure.seed.to_string())
+            .with_context("commands", format!("{:?}", failure.commands))
+            .raise()
+    }
+}
+
+impl<C: fmt::Debug> fmt::Display for ModelReport<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(failure) = &self.failure else {
+            return write!(
+                f,
+                "✅ {} runs, {} commands matched the model",
+                self.runs, self.commands
+            );
+        };
+        writeln!(
+            f,
+            "🚨 Run {} diverged from the model at {} (seed {})",
+            self.runs, failure.failure, failure.seed
+        )?;
+        writeln!(
+            f,
+            "   shrunk from {} to {} command(s) in {} step(s):",
+            failure.original.len(),
+            failure.commands.len(),
+            failure.shrinks
+        )?;
+        for (step, command) in failure.commands.iter().enumerate() {
+            writeln!(f, "   {step:>3}: {command:?}")?;
+        }
+        Ok(())
+    }
+}
+
+/// How a replayed sequence ended
+enum Replay {
+    Passed,
+    /// A precondition did not hold at this step
+    Discarded(usize),
+    Failed(ModelStepFailure),
+}
+
+/// Generates command sequences from a [`StateModel`], runs them against the real
+/// system, and shrinks failures
+#[derive(Debug, Clone)]
+pub struct ModelTester<M: StateModel> {
+    model: M,
+    seed: u64,
+    runs: usize,
+    run_len: usize,
+}
+
+impl<M: StateModel> ModelTester<M> {
+    /// Tester running 100 sequences of `MAX_RUN_LEN` commands from seed 0
+    #[must_use]
+    pub const fn new(model: M) -> Self {
+        Self { model, seed: 0, runs: 100, run_len: MAX_RUN_LEN }
+    }
+
+    /// Base seed; run `i` uses `seed + i`
+    #[must_use]
+    pub const fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Number of sequences to run
+    #[must_use]
+    pub const fn with_runs(mut self, runs: usize) -> Self {
+        self.runs = runs;
+        self
+    }
+
+    /// Commands per sequence (at most `MAX_RUN_LEN`, checked by [`check`](Self::check))
+    #[must_use]
+    pub const fn with_run_len(mut self, run_len: usize) -> Self {
+        self.run_len = run_len;
+        self
+    }
+
+    /// The model
+    #[must_use]
+    pub const fn model(&self) -> &M {
+        &self.model
+    }
+
+    /// Generate the sequence for `seed`
+    ///
+    /// The sequence ends early if no command satisfying its precondition can be
+    /// generated.
+    #[must_use]
+    pub fn generate(&self, seed: u64) -> Vec<M::Command> {
+        let mut rng = ModelRng::new(seed);
+        let mut state = self.model.initial_state();
+        let mut commands = Vec::with_capacity(self.run_len);
+        'steps: for _ in 0..self.run_len {
+            for _ in 0..GENERATE_ATTEMPTS {
+                let command = self.model.generate(&state, &mut rng);
+                if self.model.precondition(&state, &command) {
+                    state = self.model.next_state(&state, &command);
+                    commands.push(command);
+                    continue 'steps;
+                }
+            }
+            break;
+        }
+        commands
+    }
+
+    /// Run `commands` against a fresh system, checking each against the model
+    ///
+    /// # Errors
+    ///
+    /// Returns the first step whose precondition does not hold, whose output or
+    /// resulting state differs from the model, or that panics.
+    pub fn replay(&self, commands: &[M::Command]) -> Result<(), ModelStepFailure> {
+        match self.execute(commands) {
+            Replay::Passed => Ok(()),
+            Replay::Discarded(step) => {
+                Err(ModelStepFailure { step, message: "precondition does not hold".to_string() })
+            }
+            Replay::Failed(failure) => Err(failure),
+        }
+    }
+
+    /// Run the configured number of generated sequences, stopping at the first
+    /// failure and shrinking it
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GuardConstraintError::MaxRunLengthExceeded`](crate::validation::guards::GuardConstraintError::MaxRunLengthExceeded)
+    /// if the run length exceeds `MAX_RUN_LEN`; divergences from the model are reported
+    /// in the [`ModelReport`], not as errors.
+    pub fn check(&self) -> GuardConstraintResult<ModelReport<M::Command>> {
+        GuardValidator::new().validate_run_len(self.run_len)?;
+        let mut report = ModelReport { seed: self.seed, runs: 0, commands: 0, failure: None };
+        for run in 0..self.runs {
+            let seed = self.seed.wrapping_add(run as u64);
+            let commands = self.generate(seed);
+            report.runs += 1;
+            match self.execute(&commands) {
+                Replay::Failed(failure) => {
+                    report.failure = Some(self.shrink(seed, commands, failure));
+                    break;
+                }
+                Replay::Passed | Replay::Discarded(_) => report.commands += commands.len(),
+            }
+        }
+        Ok(report)
+    }
+
+    fn execute(&self, commands: &[M::Command]) -> Replay {
+        let mut system = self.model.new_system();
+        let mut state = self.model.initial_state();
+        for (step, command) in commands.iter().enumerate() {
+            if !self.model.precondition(&state, command) {
+                return Replay::Discarded(step);
+            }
+            let failed = |message| Replay::Failed(ModelStepFailure { step, message });
+            let output = match TddFailure::catch(|| self.model.execute(&mut system, command)) {
+                Ok(output) => output,
+                Err(failure) => {
+                    return failed(format!("{command:?} panicked: {}", failure.message()));
+                }
+            };
+            if let Err(message) = self.model.postcondition(&state, command, &output) {
+                return failed(format!("{command:?}: {message}"));
+            }
+            state = self.model.next_state(&state, command);
+            if let Err(message) = self.model.check_state(&state, &system) {
+                return failed(format!("after {command:?}: {message}"));
+            }
+        }
+        Replay::Passed
+    }
+
+    /// Greedily drop, then simplify, commands while the sequence still fails
+    fn shrink(
+        &self,
+        seed: u64,
+        original: Vec<M::Command>,
+        mut failure: ModelStepFailure,
+    ) -> ModelFailure<M::Command> {
+        // Commands after the failing step never ran
+        let mut commands = original[..=failure.step].to_vec();
+        let mut shrinks = 0;
+        'shrink: while shrinks < MAX_SHRINKS {
+            for mut candidate in self.shrink_candidates(&commands) {
+                if let Replay::Failed(smaller) = self.execute(&candidate) {
+                    candidate.truncate(smaller.step + 1);
+                    commands = candidate;
+                    failure = smaller;
+                    shrinks += 1;
+                    continue 'shrink;
+                }
+            }
+            break;
+        }
+        ModelFailure { seed, original, commands, failure, shrinks }
+    }
+
+    /// `commands` with one command dropped, then with one command simplified
+    fn shrink_candidates(&self, commands: &[M::Command]) -> Vec<Vec<M::Command>> {
+        let mut candidates = Vec::new();
+        for index in 0..commands.len() {
+            let mut candidate = commands.to_vec();
+            candidate.remove(index);
+            candidates.push(candidate);
+        }
+        for (index, command) in commands.iter().enumerate() {
+            for simpler in self.model.shrink_command(command) {
+                let mut candidate = commands.to_vec();
+                candidate[index] = simpler;
+                candidates.push(candidate);
+            }
+        }
+        candidates
+    }
+}
+
 // Example: Lock state machine
 
 /// Lock state: Locked
@@ -462,6 +926,117 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    enum CounterCommand {
+        Add(u32),
+        Get,
+    }
+
+    /// Counter model; the buggy system under-counts additions of 5 or more
+    struct CounterModel {
+        buggy: bool,
+    }
+
+    impl StateModel for CounterModel {
+        type State = u32;
+        type Command = CounterCommand;
+        type System = u32;
+        type Output = Option<u32>;
+
+        fn initial_state(&self) -> u32 {
+            0
+        }
+
+        fn new_system(&self) -> u32 {
+            0
+        }
+
+        fn generate(&self, _state: &u32, rng: &mut ModelRng) -> CounterCommand {
+            if rng.chance(70) {
+                CounterCommand::Add(u32::try_from(rng.below(10)).unwrap())
+            } else {
+                CounterCommand::Get
+            }
+        }
+
+        fn next_state(&self, state: &u32, command: &CounterCommand) -> u32 {
+            match command {
+                CounterCommand::Add(n) => state + n,
+                CounterCommand::Get => *state,
+            }
+        }
+
+        fn execute(&self, system: &mut u32, command: &CounterCommand) -> Option<u32> {
+            match command {
+                CounterCommand::Add(n) if self.buggy && *n >= 5 => *system += n - 1,
+                CounterCommand::Add(n) => *system += n,
+                CounterCommand::Get => return Some(*system),
+            }
+            None
+        }
+
+        fn postcondition(
+            &self,
+            state: &u32,
+            command: &CounterCommand,
+            output: &Option<u32>,
+        ) -> Result<(), String> {
+            match (command, output) {
+                (CounterCommand::Get, Some(value)) if value != state => {
+                    Err(format!("expected {state}, got {value}"))
+                }
+                _ => Ok(()),
+            }
+        }
+
+        fn shrink_command(&self, command: &CounterCommand) -> Vec<CounterCommand> {
+            match command {
+                CounterCommand::Add(n) if *n > 0 => {
+                    vec![CounterCommand::Add(n / 2), CounterCommand::Add(n - 1)]
+                }
+                _ => Vec::new(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_model_tester_shrinks_failing_sequences() {
+        // Arrange
+        let tester = ModelTester::new(CounterModel { buggy: true }).with_seed(3);
+
+        // Act
+        let report = tester.check().unwrap();
+
+        // Assert
+        let failure = report.failure.as_ref().unwrap();
+        assert_eq!(failure.commands, [CounterCommand::Add(5), CounterCommand::Get]);
+        assert_eq!(failure.failure.step, 1);
+        assert_eq!(failure.failure.message, "Get: expected 5, got 4");
+        assert!(failure.original.len() <= MAX_RUN_LEN);
+        assert_eq!(tester.generate(failure.seed), failure.original);
+        assert_eq!(tester.replay(&failure.commands), Err(failure.failure.clone()));
+        let raised = TddFailure::catch(|| report.assert_passed()).unwrap_err();
+        assert!(raised.message().contains("   0: Add(5)\n     1: Get"));
+    }
+
+    #[test]
+    fn test_model_tester_passes_matching_systems() {
+        // Arrange
+        let tester = ModelTester::new(CounterModel { buggy: false }).with_runs(50);
+
+        // Act
+        let report = tester.check().unwrap();
+        let too_long = ModelTester::new(CounterModel { buggy: false }).with_run_len(9).check();
+
+        // Assert
+        assert!(report.is_passed(), "{report}");
+        assert_eq!((report.runs, report.commands), (50, 50 * MAX_RUN_LEN));
+        assert!(matches!(
+            too_long,
+            Err(crate::validation::guards::GuardConstraintError::MaxRunLengthExceeded(9, 8))
+        ));
+    }
+
     // Example of compile-time enforcement:
     // This would NOT compile (uncomment to verify):
     // #[test]
@@ -0,0 +1,447 @@
+//! Virtual Time for Async Concurrency Testing
+//!
+//! Provides a `VirtualClock` fixture that lets tests advance time deterministically
+//! with `clock.advance(Duration)`. Timeout logic, retry backoff, and periodic tasks
+//! can be tested without real sleeps and without flaky wall-clock dependence.
+//!
+//! The clock is executor-agnostic: its futures (`sleep`, `timeout`, `interval`) are
+//! woken by `advance()`, never by the wall clock, so they work under tokio, the
+//! `futures` executor, or a hand-driven poll loop.
+//!
+//! # Chicago TDD Alignment
+//!
+//! - **State-Based Testing**: Assert on observable state after advancing time
+//! - **Real Collaborators**: Code under test receives a real clock handle, not a mock
+//! - **AAA Pattern**: Arrange (create clock), Act (advance), Assert (verify state)
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use chicago_tdd_tools::testing::virtual_time::VirtualClock;
+//! use std::time::Duration;
+//!
+//! let clock = VirtualClock::new();
+//! let sleep = clock.sleep(Duration::from_secs(30));
+//!
+//! // Act: Jump forward 30 virtual seconds (no real waiting)
+//! clock.advance(Duration::from_secs(30));
+//!
+//! // Assert: The sleep is now complete
+//! futures::executor::block_on(sleep);
+//! ```
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Error returned when a virtual timeout elapses before the inner future completes
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("Virtual timeout of {0:?} elapsed")]
+pub struct VirtualTimeoutError(pub Duration);
+
+/// A point in virtual time, measured from clock creation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct VirtualInstant(Duration);
+
+impl VirtualInstant {
+    /// Time elapsed since the clock was created
+    #[must_use]
+    pub const fn since_start(self) -> Duration {
+        self.0
+    }
+
+    /// Duration from `earlier` to `self` (zero if `earlier` is later)
+    #[must_use]
+    pub const fn saturating_duration_since(self, earlier: Self) -> Duration {
+        self.0.saturating_sub(earlier.0)
+    }
+}
+
+impl std::ops::Add<Duration> for VirtualInstant {
+    type Output = Self;
+
+    fn add(self, rhs: Duration) -> Self {
+        Self(self.0.saturating_add(rhs))
+    }
+}
+
+/// Timer key: deadline plus registration sequence (keeps equal deadlines FIFO)
+type TimerKey = (Duration, u64);
+
+#[derive(Debug, Default)]
+struct ClockState {
+    now: Duration,
+    next_timer_id: u64,
+    timers: BTreeMap<TimerKey, Option<Waker>>,
+}
+
+/// Deterministic virtual clock fixture
+///
+/// Cloning a `VirtualClock` yields another handle to the same timeline, so the
+/// test can keep one handle while the code under test holds another.
+#[derive(Debug, Clone, Default)]
+pub struct VirtualClock {
+    state: Arc<Mutex<ClockState>>,
+}
+
+impl VirtualClock {
+    /// Create a new virtual clock starting at time zero
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ClockState> {
+        // A poisoned clock only means another test thread panicked mid-update;
+        // the timer map is still structurally valid, so keep going.
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Current virtual time
+    #[must_use]
+    pub fn now(&self) -> VirtualInstant {
+        VirtualInstant(self.lock().now)
+    }
+
+    /// Total virtual time elapsed since the clock was created
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.lock().now
+    }
+
+    /// Number of sleeps/timeouts currently waiting for a future deadline
+    #[must_use]
+    pub fn pending_timers(&self) -> usize {
+        self.lock().timers.len()
+    }
+
+    /// Deadline of the earliest pending timer, if any
+    #[must_use]
+    pub fn next_deadline(&self) -> Option<VirtualInstant> {
+        self.lock().timers.keys().next().map(|(deadline, _)| VirtualInstant(*deadline))
+    }
+
+    /// Advance virtual time by `duration`, waking every timer that becomes due
+    ///
+    /// Returns the number of timers fired. Woken tasks run the next time their
+    /// executor polls them; the clock never sleeps on the wall clock.
+    pub fn advance(&self, duration: Duration) -> usize {
+        let wakers = Self::advance_locked(&mut self.lock(), duration);
+        let fired = wakers.len();
+        wakers.into_iter().for_each(Waker::wake);
+        fired
+    }
+
+    /// Advance to an absolute virtual instant (no-op if it is in the past)
+    #[allow(clippy::must_use_candidate)] // Called for its side effect; fired count is informational
+    pub fn advance_to(&self, instant: VirtualInstant) -> usize {
+        let delta = instant.saturating_duration_since(self.now());
+        self.advance(delta)
+    }
+
+    /// Jump directly to the earliest pending deadline and fire it
+    ///
+    /// Returns the amount of virtual time advanced, or `None` when no timers are pending.
+    /// Useful for draining backoff schedules step by step.
+    #[allow(clippy::must_use_candidate)] // Called for its side effect; delta is informational
+    pub fn advance_to_next_timer(&self) -> Option<Duration> {
+        let next = self.next_deadline()?;
+        let delta = next.saturating_duration_since(self.now());
+        self.advance(delta);
+        Some(delta)
+    }
+
+    /// Create a future that completes once `duration` of virtual time has passed
+    pub fn sleep(&self, duration: Duration) -> VirtualSleep {
+        self.sleep_until(self.now() + duration)
+    }
+
+    /// Create a future that completes once virtual time reaches `deadline`
+    pub fn sleep_until(&self, deadline: VirtualInstant) -> VirtualSleep {
+        VirtualSleep { clock: self.clone(), deadline: deadline.0, key: None }
+    }
+
+    /// Run `future` with a virtual-time deadline
+    ///
+    /// Resolves to `Err(VirtualTimeoutError)` if the clock is advanced past the
+    /// deadline before `future` completes.
+    pub fn timeout<F: Future>(&self, duration: Duration, future: F) -> VirtualTimeout<F> {
+        VirtualTimeout { future: Box::pin(future), sleep: self.sleep(duration), duration }
+    }
+
+    /// Create a periodic ticker; the first tick completes immediately
+    #[must_use]
+    pub fn interval(&self, period: Duration) -> VirtualInterval {
+        VirtualInterval { clock: self.clone(), next: self.now(), period }
+    }
+
+    fn advance_locked(state: &mut ClockState, duration: Duration) -> Vec<Waker> {
+        state.now = state.now.saturating_add(duration);
+        let now = state.now;
+        let pending = state.timers.split_off(&(now.saturating_add(Duration::from_nanos(1)), 0));
+        let due = std::mem::replace(&mut state.timers, pending);
+        due.into_values().flatten().collect()
+    }
+
+    fn register(
+        &self,
+        deadline: Duration,
+        key: Option<TimerKey>,
+        waker: &Waker,
+    ) -> Option<TimerKey> {
+        Self::register_locked(&mut self.lock(), deadline, key, waker)
+    }
+
+    fn register_locked(
+        state: &mut ClockState,
+        deadline: Duration,
+        key: Option<TimerKey>,
+        waker: &Waker,
+    ) -> Option<TimerKey> {
+        if state.now >= deadline {
+            if let Some(key) = key {
+                state.timers.remove(&key);
+            }
+            return None;
+        }
+        let key = key.unwrap_or_else(|| {
+            let id = state.next_timer_id;
+            state.next_timer_id += 1;
+            (deadline, id)
+        });
+        state.timers.insert(key, Some(waker.clone()));
+        Some(key)
+    }
+
+    fn cancel(&self, key: TimerKey) {
+        self.lock().timers.remove(&key);
+    }
+}
+
+/// Future returned by [`VirtualClock::sleep`]
+#[derive(Debug)]
+#[must_use = "futures do nothing unless polled"]
+pub struct VirtualSleep {
+    clock: VirtualClock,
+    deadline: Duration,
+    key: Option<TimerKey>,
+}
+
+impl VirtualSleep {
+    /// Deadline of this sleep
+    #[must_use]
+    pub const fn deadline(&self) -> VirtualInstant {
+        VirtualInstant(self.deadline)
+    }
+
+    /// Whether the deadline has been reached
+    #[must_use]
+    pub fn is_elapsed(&self) -> bool {
+        self.clock.now().0 >= self.deadline
+    }
+}
+
+impl Future for VirtualSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let key = self.clock.register(self.deadline, self.key, cx.waker());
+        self.key = key;
+        if key.is_none() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for VirtualSleep {
+    fn drop(&mut self) {
+        if let Some(key) = self.key.take() {
+            self.clock.cancel(key);
+        }
+    }
+}
+
+/// Future returned by [`VirtualClock::timeout`]
+#[must_use = "futures do nothing unless polled"]
+pub struct VirtualTimeout<F: Future> {
+    future: Pin<Box<F>>,
+    sleep: VirtualSleep,
+    duration: Duration,
+}
+
+impl<F: Future> Future for VirtualTimeout<F> {
+    type Output = Result<F::Output, VirtualTimeoutError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if let Poll::Ready(value) = self.future.as_mut().poll(cx) {
+            return Poll::Ready(Ok(value));
+        }
+        let duration = self.duration;
+        match Pin::new(&mut self.sleep).poll(cx) {
+            Poll::Ready(()) => Poll::Ready(Err(VirtualTimeoutError(duration))),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// Periodic ticker returned by [`VirtualClock::interval`]
+///
+/// Missed ticks are delivered in a burst (each `tick()` completes immediately
+/// until the schedule catches up with virtual time).
+#[derive(Debug)]
+pub struct VirtualInterval {
+    clock: VirtualClock,
+    next: VirtualInstant,
+    period: Duration,
+}
+
+impl VirtualInterval {
+    /// Wait for the next tick, returning the instant it was scheduled for
+    ///
+    /// Cancel-safe: the schedule only moves on once the tick completes, so a
+    /// dropped `tick()` future does not skip a tick.
+    pub async fn tick(&mut self) -> VirtualInstant {
+        let scheduled = self.next;
+        self.clock.sleep_until(scheduled).await;
+        self.next = scheduled + self.period;
+        scheduled
+    }
+
+    /// Tick period
+    #[must_use]
+    pub const fn period(&self) -> Duration {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::task::Context;
+
+    fn poll_once<F: Future + Unpin>(future: &mut F) -> Poll<F::Output> {
+        let mut cx = Context::from_waker(futures::task::noop_waker_ref());
+        Pin::new(future).poll(&mut cx)
+    }
+
+    test!(test_sleep_completes_only_after_advance, {
+        // Arrange
+        let clock = VirtualClock::new();
+        let mut sleep = clock.sleep(Duration::from_secs(5));
+
+        // Act & Assert: Pending until enough virtual time has passed
+        assert!(poll_once(&mut sleep).is_pending());
+        assert_eq!(clock.pending_timers(), 1);
+        assert_eq!(clock.advance(Duration::from_secs(4)), 0);
+        assert!(poll_once(&mut sleep).is_pending());
+        assert_eq!(clock.advance(Duration::from_secs(1)), 1);
+        assert!(poll_once(&mut sleep).is_ready());
+        assert_eq!(clock.elapsed(), Duration::from_secs(5));
+    });
+
+    test!(test_timeout_elapses_on_advance, {
+        // Arrange
+        let clock = VirtualClock::new();
+        let never = clock.sleep(Duration::from_secs(3600));
+        let mut timeout = clock.timeout(Duration::from_millis(250), never);
+
+        // Act
+        assert!(poll_once(&mut timeout).is_pending());
+        clock.advance(Duration::from_millis(250));
+
+        // Assert
+        assert_eq!(
+            poll_once(&mut timeout),
+            Poll::Ready(Err(VirtualTimeoutError(Duration::from_millis(250))))
+        );
+    });
+
+    test!(test_timeout_returns_inner_value, {
+        // Arrange
+        let clock = VirtualClock::new();
+        let timeout = clock.timeout(Duration::from_secs(1), async { 42 });
+
+        // Act
+        let result = futures::executor::block_on(timeout);
+
+        // Assert
+        assert_eq!(result, Ok(42));
+    });
+
+    test!(test_advance_to_next_timer_walks_backoff_schedule, {
+        // Arrange: Exponential backoff 100ms, 200ms, 400ms
+        let clock = VirtualClock::new();
+        let mut sleeps: Vec<_> = [100, 200, 400]
+            .iter()
+            .map(|ms| clock.sleep(Duration::from_millis(*ms)))
+            .collect();
+        sleeps.iter_mut().for_each(|s| assert!(poll_once(s).is_pending()));
+
+        // Act
+        let steps: Vec<_> = std::iter::from_fn(|| clock.advance_to_next_timer()).collect();
+
+        // Assert
+        assert_eq!(
+            steps,
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(100),
+                Duration::from_millis(200)
+            ]
+        );
+        assert_eq!(clock.pending_timers(), 0);
+    });
+
+    test!(test_interval_ticks_at_period, {
+        // Arrange
+        let clock = VirtualClock::new();
+        let mut interval = clock.interval(Duration::from_secs(10));
+
+        // Act: First tick immediate, then one per advance
+        let first = futures::executor::block_on(interval.tick());
+        clock.advance(Duration::from_secs(10));
+        let second = futures::executor::block_on(interval.tick());
+
+        // Assert
+        assert_eq!(first.since_start(), Duration::ZERO);
+        assert_eq!(second.since_start(), Duration::from_secs(10));
+    });
+
+    test!(test_cancelled_tick_does_not_skip_a_tick, {
+        // Arrange
+        let clock = VirtualClock::new();
+        let mut interval = clock.interval(Duration::from_secs(10));
+        futures::executor::block_on(interval.tick());
+
+        // Act: Drop a tick that is still waiting, as `select!` would
+        {
+            let mut tick = Box::pin(interval.tick());
+            assert!(poll_once(&mut tick).is_pending());
+        }
+        clock.advance(Duration::from_secs(10));
+        let next = futures::executor::block_on(interval.tick());
+
+        // Assert
+        assert_eq!(next.since_start(), Duration::from_secs(10));
+    });
+
+    test!(test_dropped_sleep_deregisters_timer, {
+        // Arrange
+        let clock = VirtualClock::new();
+        let mut sleep = clock.sleep(Duration::from_secs(1));
+        assert!(poll_once(&mut sleep).is_pending());
+
+        // Act
+        drop(sleep);
+
+        // Assert
+        assert_eq!(clock.pending_timers(), 0);
+    });
+}
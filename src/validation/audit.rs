@@ -0,0 +1,1176 @@
+//! > 📚 Reference
+//!
+//! Chicago TDD Compliance Audit
+//!
+//! Runs a configurable bundle of framework analyzers against a project and combines
+//! their results into a single [`Scorecard`] answering "how Chicago-TDD-compliant is this
+//! repo?". The analyzers are line-based heuristics over the project's sources (no Rust
+//! parsing), so they flag likely problems rather than prove compliance.
+//!
+//! | Check | Examines | Flags |
+//! |-------|----------|-------|
+//! | `aaa` | test functions | tests without `// Act` and `// Assert` sections |
+//! | `mock-policy` | `.rs` files and `Cargo.toml` | use of mocking frameworks (prefer real collaborators) |
+//! | `coverage` | an LCOV report | line coverage below `coverage_threshold` |
+//! | `guard-ingress` | `pub fn`s taking slices or `Vec`s | ingress points that never call a guard |
+//! | `assertion-strength` | test functions | tests with no assertions, or only weak ones |
+//!
+//! Each check scores 0-100 (for `coverage`, the line coverage itself) and passes at
+//! `min_score` (`coverage_threshold` for coverage). The overall score is the mean of the
+//! checks that ran; `coverage` is skipped when no LCOV report is found.
+//!
+//! # Configuration
+//!
+//! [`QualityAudit::for_project`] reads the `[audit]` section of the project's
+//! `chicago-tdd-tools.toml`:
+//!
+//! ```toml
+//! [audit]
+//! checks = ["aaa", "assertion-strength", "coverage"]
+//! min_score = 90.0
+//! coverage_threshold = 75.0
+//! lcov = "target/llvm-cov/lcov.info"
+//! exclude = ["target", "vendor"]
+//! ```
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use chicago_tdd_tools::validation::audit::{AuditCheck, QualityAudit};
+//!
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let scorecard = QualityAudit::for_project("path/to/project")?
+//!     .with_checks(vec![AuditCheck::Aaa, AuditCheck::AssertionStrength])
+//!     .run()?;
+//!
+//! println!("{}", scorecard.to_markdown());
+//! assert!(scorecard.passed, "project scored {:.1}", scorecard.score);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::validation::coverage::CoverageReport;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+use thiserror::Error;
+
+/// Project configuration file holding the `[audit]` section
+pub const AUDIT_CONFIG_FILE: &str = "chicago-tdd-tools.toml";
+
+/// LCOV report locations tried (relative to the project root) when `lcov` is not set
+pub const DEFAULT_LCOV_PATHS: [&str; 4] =
+    ["lcov.info", "coverage.lcov", "coverage/lcov.info", "target/llvm-cov/lcov.info"];
+
+/// Crates whose use the `mock-policy` check reports
+pub const MOCK_CRATES: [&str; 6] = ["mockall", "mockito", "faux", "mockers", "mry", "unimock"];
+
+/// Audit errors
+#[derive(Error, Debug)]
+pub enum AuditError {
+    /// A project file could not be read
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        /// File or directory that failed
+        path: PathBuf,
+        /// Underlying I/O error
+        source: std::io::Error,
+    },
+    /// The `[audit]` configuration is invalid
+    #[error("Invalid audit configuration: {0}")]
+    InvalidConfig(String),
+    /// A check name was not recognized
+    #[error(
+        "Unknown audit check '{0}' (expected one of: aaa, mock-policy, coverage, guard-ingress, assertion-strength)"
+    )]
+    UnknownCheck(String),
+}
+
+/// Result type for audits
+pub type AuditResult<T> = Result<T, AuditError>;
+
+/// An analyzer in the audit bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AuditCheck {
+    /// Tests are structured as Arrange/Act/Assert
+    Aaa,
+    /// No mocking frameworks
+    MockPolicy,
+    /// Line coverage meets the threshold
+    Coverage,
+    /// Public collection-taking functions validate their input with guards
+    GuardIngress,
+    /// Tests make meaningful assertions
+    AssertionStrength,
+}
+
+impl AuditCheck {
+    /// Every check, in report order
+    pub const ALL: [Self; 5] =
+        [Self::Aaa, Self::MockPolicy, Self::Coverage, Self::GuardIngress, Self::AssertionStrength];
+
+    /// Check name as used in configuration and on the command line
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Aaa => "aaa",
+            Self::MockPolicy => "mock-policy",
+            Self::Coverage => "coverage",
+            Self::GuardIngress => "guard-ingress",
+            Self::AssertionStrength => "assertion-strength",
+        }
+    }
+
+    /// Parse a check name
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuditError::UnknownCheck`] for an unrecognized name.
+    pub fn parse(name: &str) -> AuditResult<Self> {
+        let name = name.trim();
+        Self::ALL
+            .into_iter()
+            .find(|check| check.as_str().eq_ignore_ascii_case(name))
+            .ok_or_else(|| AuditError::UnknownCheck(name.to_string()))
+    }
+
+    /// Parse a comma-separated list of check names
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuditError::UnknownCheck`] for the first unrecognized name.
+    pub fn parse_list(names: &str) -> AuditResult<Vec<Self>> {
+        names
+            .split(',')
+            .filter(|name| !name.trim().is_empty())
+            .map(Self::parse)
+            .collect()
+    }
+}
+
+impl fmt::Display for AuditCheck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Which checks run and what they must score
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct AuditConfig {
+    /// Checks to run
+    pub checks: Vec<AuditCheck>,
+    /// Minimum score (0-100) for each heuristic check to pass
+    pub min_score: f64,
+    /// Minimum line coverage percentage for the `coverage` check to pass
+    pub coverage_threshold: f64,
+    /// LCOV report, relative to the project root (defaults to [`DEFAULT_LCOV_PATHS`])
+    pub lcov: Option<PathBuf>,
+    /// Directory names skipped while scanning (hidden directories are always skipped)
+    pub exclude: Vec<String>,
+}
+
+impl Default for AuditConfig {
+    fn default() -> Self {
+        Self {
+            checks: AuditCheck::ALL.to_vec(),
+            min_score: 80.0,
+            coverage_threshold: 80.0,
+            lcov: None,
+            exclude: vec!["target".to_string()],
+        }
+    }
+}
+
+impl AuditConfig {
+    /// Parse the `[audit]` section of a `chicago-tdd-tools.toml` document
+    ///
+    /// A document without the section yields the defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuditError::InvalidConfig`] if the TOML or the section is invalid.
+    pub fn from_config_str(text: &str) -> AuditResult<Self> {
+        let document: toml::Table =
+            text.parse().map_err(|e| AuditError::InvalidConfig(format!("{e}")))?;
+        let Some(section) = document.get("audit") else {
+            return Ok(Self::default());
+        };
+        let config: Self = section
+            .clone()
+            .try_into()
+            .map_err(|e| AuditError::InvalidConfig(format!("{e}")))?;
+        for (key, value) in
+            [("min_score", config.min_score), ("coverage_threshold", config.coverage_threshold)]
+        {
+            if !(0.0..=100.0).contains(&value) {
+                return Err(AuditError::InvalidConfig(format!(
+                    "`{key}` must be between 0 and 100, got {value}"
+                )));
+            }
+        }
+        Ok(config)
+    }
+
+    /// Load the `[audit]` section from `<root>/chicago-tdd-tools.toml`, if present
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuditError::Io`] if the file exists but cannot be read, or
+    /// [`AuditError::InvalidConfig`] if it is invalid.
+    pub fn from_project(root: impl AsRef<Path>) -> AuditResult<Self> {
+        let path = root.as_ref().join(AUDIT_CONFIG_FILE);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+        let text =
+            std::fs::read_to_string(&path).map_err(|source| AuditError::Io { path, source })?;
+        Self::from_config_str(&text)
+    }
+}
+
+/// Outcome of one check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    /// Score met the threshold
+    Passed,
+    /// Score fell below the threshold
+    Failed,
+    /// The check could not run (e.g. no LCOV report); excluded from the overall score
+    Skipped,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Passed => "passed",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+        })
+    }
+}
+
+/// A single problem reported by a check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Finding {
+    /// File, relative to the project root
+    pub file: PathBuf,
+    /// 1-based line, when the finding points at one
+    pub line: Option<usize>,
+    /// What is wrong
+    pub message: String,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{line}: {}", self.file.display(), self.message),
+            None => write!(f, "{}: {}", self.file.display(), self.message),
+        }
+    }
+}
+
+/// Result of one check
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckReport {
+    /// Which check ran
+    pub check: AuditCheck,
+    /// Pass, fail, or skip
+    pub status: CheckStatus,
+    /// Score from 0 to 100
+    pub score: f64,
+    /// Number of items examined (tests, files, functions, or lines)
+    pub examined: usize,
+    /// One-line summary
+    pub summary: String,
+    /// Problems found
+    pub findings: Vec<Finding>,
+}
+
+/// > 📚 Reference
+///
+/// Combined result of a [`QualityAudit`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Scorecard {
+    /// Audited project root
+    pub project: PathBuf,
+    /// Mean score of the checks that ran (0-100)
+    pub score: f64,
+    /// Whether every check that ran passed
+    pub passed: bool,
+    /// Per-check results, in [`AuditCheck::ALL`] order
+    pub checks: Vec<CheckReport>,
+}
+
+impl Scorecard {
+    fn new(project: PathBuf, checks: Vec<CheckReport>) -> Self {
+        let ran: Vec<&CheckReport> =
+            checks.iter().filter(|report| report.status != CheckStatus::Skipped).collect();
+        // Check counts are tiny; the conversion is exact
+        #[allow(clippy::cast_precision_loss)]
+        let score = if ran.is_empty() {
+            0.0
+        } else {
+            ran.iter().map(|report| report.score).sum::<f64>() / ran.len() as f64
+        };
+        let passed =
+            !ran.is_empty() && ran.iter().all(|report| report.status == CheckStatus::Passed);
+        Self { project, score, passed, checks }
+    }
+
+    /// The report for `check`, if it ran
+    #[must_use]
+    pub fn check(&self, check: AuditCheck) -> Option<&CheckReport> {
+        self.checks.iter().find(|report| report.check == check)
+    }
+
+    /// All findings across checks
+    pub fn findings(&self) -> impl Iterator<Item = (AuditCheck, &Finding)> {
+        self.checks
+            .iter()
+            .flat_map(|report| report.findings.iter().map(|f| (report.check, f)))
+    }
+
+    /// Render as Markdown: a summary table followed by each check's findings
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let verdict = if self.passed { "PASSED" } else { "FAILED" };
+        let mut markdown = format!(
+            "# Chicago TDD Scorecard\n\n**Project**: `{}`\n\n**Score**: {:.1}/100 ({verdict})\n\n\
+             | Check | Status | Score | Examined | Summary |\n\
+             |-------|--------|-------|----------|---------|\n",
+            self.project.display(),
+            self.score
+        );
+        for report in &self.checks {
+            let _ = writeln!(
+                markdown,
+                "| {} | {} | {:.1} | {} | {} |",
+                report.check, report.status, report.score, report.examined, report.summary
+            );
+        }
+        for report in self.checks.iter().filter(|report| !report.findings.is_empty()) {
+            let _ = write!(markdown, "\n## {}\n\n", report.check);
+            for finding in &report.findings {
+                let _ = writeln!(markdown, "- {finding}");
+            }
+        }
+        markdown
+    }
+}
+
+/// > 📚 Reference
+///
+/// Runs the configured checks against a project root.
+#[derive(Debug, Clone)]
+pub struct QualityAudit {
+    root: PathBuf,
+    config: AuditConfig,
+}
+
+impl QualityAudit {
+    /// Audit `root` with the default configuration
+    #[must_use]
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into(), config: AuditConfig::default() }
+    }
+
+    /// Audit `root` with the `[audit]` section of its `chicago-tdd-tools.toml`
+    ///
+    /// # Errors
+    ///
+    /// Same as [`AuditConfig::from_project`].
+    pub fn for_project(root: impl Into<PathBuf>) -> AuditResult<Self> {
+        let root = root.into();
+        let config = AuditConfig::from_project(&root)?;
+        Ok(Self { root, config })
+    }
+
+    /// Replace the configuration
+    #[must_use]
+    pub fn with_config(mut self, config: AuditConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Run only these checks
+    #[must_use]
+    pub fn with_checks(mut self, checks: Vec<AuditCheck>) -> Self {
+        self.config.checks = checks;
+        self
+    }
+
+    /// The configuration in effect
+    #[must_use]
+    pub const fn config(&self) -> &AuditConfig {
+        &self.config
+    }
+
+    /// Scan the project and run every configured check
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AuditError::Io`] if the project or its LCOV report cannot be read.
+    pub fn run(&self) -> AuditResult<Scorecard> {
+        let mut files = Vec::new();
+        collect_sources(&self.root, &self.root, &self.config.exclude, &mut files)?;
+        files.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let mut checks = self.config.checks.clone();
+        checks.sort_unstable();
+        checks.dedup();
+
+        let reports = checks
+            .into_iter()
+            .map(|check| match check {
+                AuditCheck::Aaa => Ok(self.check_aaa(&files)),
+                AuditCheck::MockPolicy => Ok(self.check_mock_policy(&files)),
+                AuditCheck::Coverage => self.check_coverage(),
+                AuditCheck::GuardIngress => Ok(self.check_guard_ingress(&files)),
+                AuditCheck::AssertionStrength => Ok(self.check_assertion_strength(&files)),
+            })
+            .collect::<AuditResult<Vec<_>>>()?;
+        Ok(Scorecard::new(self.root.clone(), reports))
+    }
+
+    fn report(
+        &self,
+        check: AuditCheck,
+        examined: usize,
+        violations: usize,
+        summary: String,
+        findings: Vec<Finding>,
+    ) -> CheckReport {
+        // Counts are far below 2^52; the conversion is exact
+        #[allow(clippy::cast_precision_loss)]
+        let score = if examined == 0 {
+            100.0
+        } else {
+            100.0 * (examined - violations) as f64 / examined as f64
+        };
+        let status =
+            if score >= self.config.min_score { CheckStatus::Passed } else { CheckStatus::Failed };
+        CheckReport { check, status, score, examined, summary, findings }
+    }
+
+    fn check_aaa(&self, files: &[SourceFile]) -> CheckReport {
+        let mut examined = 0;
+        let mut findings = Vec::new();
+        for file in files.iter().filter(|f| f.is_rust()) {
+            for test in file.tests() {
+                examined += 1;
+                let sections = aaa_sections(&file.text[test.body.clone()]);
+                let missing: Vec<&str> = [("Act", sections.act), ("Assert", sections.assert)]
+                    .into_iter()
+                    .filter(|(_, present)| !present)
+                    .map(|(name, _)| name)
+                    .collect();
+                if !missing.is_empty() {
+                    findings.push(file.finding(
+                        test.line,
+                        format!(
+                            "test `{}` has no `// {}` section",
+                            test.name,
+                            missing.join("`/`// ")
+                        ),
+                    ));
+                }
+            }
+        }
+        if examined == 0 {
+            return no_tests(AuditCheck::Aaa);
+        }
+        let summary =
+            format!("{}/{examined} tests have Act and Assert sections", examined - findings.len());
+        self.report(AuditCheck::Aaa, examined, findings.len(), summary, findings)
+    }
+
+    fn check_mock_policy(&self, files: &[SourceFile]) -> CheckReport {
+        let mut violating = 0;
+        let mut findings = Vec::new();
+        for file in files {
+            let before = findings.len();
+            if file.is_rust() {
+                for (index, line) in file.masked.lines().enumerate() {
+                    if let Some(found) = MOCK_USE.find(line) {
+                        findings.push(file.finding(
+                            index + 1,
+                            format!("uses mocking framework (`{}`)", found.as_str().trim()),
+                        ));
+                    }
+                }
+            } else {
+                for (index, line) in file.text.lines().enumerate() {
+                    if let Some(captures) = MOCK_DEPENDENCY.captures(line) {
+                        findings.push(file.finding(
+                            index + 1,
+                            format!("depends on mocking crate `{}`", &captures[1]),
+                        ));
+                    }
+                }
+            }
+            violating += usize::from(findings.len() > before);
+        }
+        let summary = format!("{violating}/{} files use mocking frameworks", files.len());
+        self.report(AuditCheck::MockPolicy, files.len(), violating, summary, findings)
+    }
+
+    fn check_coverage(&self) -> AuditResult<CheckReport> {
+        let path = self.config.lcov.as_ref().map_or_else(
+            || DEFAULT_LCOV_PATHS.iter().map(|p| self.root.join(p)).find(|p| p.is_file()),
+            |lcov| Some(self.root.join(lcov)),
+        );
+        let Some(path) = path.filter(|p| p.is_file()) else {
+            return Ok(CheckReport {
+                check: AuditCheck::Coverage,
+                status: CheckStatus::Skipped,
+                score: 0.0,
+                examined: 0,
+                summary: "no LCOV report found".to_string(),
+                findings: Vec::new(),
+            });
+        };
+        let text =
+            std::fs::read_to_string(&path).map_err(|source| AuditError::Io { path, source })?;
+        let (total, per_file) = parse_lcov(&text);
+
+        let threshold = self.config.coverage_threshold;
+        let score = total.percentage.get();
+        let findings = per_file
+            .into_iter()
+            .filter(|(_, report)| report.percentage.get() < threshold)
+            .map(|(file, report)| Finding {
+                file: relative_to(&self.root, Path::new(&file)),
+                line: None,
+                message: format!(
+                    "{:.1}% line coverage ({}/{})",
+                    report.percentage.get(),
+                    report.covered.get(),
+                    report.total.get()
+                ),
+            })
+            .collect();
+        Ok(CheckReport {
+            check: AuditCheck::Coverage,
+            status: if score >= threshold { CheckStatus::Passed } else { CheckStatus::Failed },
+            score,
+            examined: total.total.get(),
+            summary: format!(
+                "{:.1}% line coverage ({}/{} lines, threshold {threshold:.1}%)",
+                score,
+                total.covered.get(),
+                total.total.get()
+            ),
+            findings,
+        })
+    }
+
+    fn check_guard_ingress(&self, files: &[SourceFile]) -> CheckReport {
+        let mut examined = 0;
+        let mut findings = Vec::new();
+        for file in files.iter().filter(|f| f.is_rust() && !f.is_test_only()) {
+            let production = &file.masked[..file.test_module_start()];
+            for captures in PUB_FN.captures_iter(production) {
+                let (Some(item), Some(name)) = (captures.get(0), captures.get(1)) else {
+                    continue;
+                };
+                let Some(open) =
+                    production[item.end()..].find(['{', ';']).map(|offset| item.end() + offset)
+                else {
+                    continue;
+                };
+                // `;` ends a bodyless trait method declaration
+                if production.as_bytes()[open] != b'{'
+                    || !COLLECTION_PARAM.is_match(&production[item.end()..open])
+                {
+                    continue;
+                }
+                let Some(close) = matching_close(production, open) else {
+                    continue;
+                };
+                examined += 1;
+                if !GUARD_CALL.is_match(&production[open..close]) {
+                    findings.push(file.finding(
+                        file.line_of(item.start()),
+                        format!("`{}` takes a collection but never calls a guard", name.as_str()),
+                    ));
+                }
+            }
+        }
+        let summary = if examined == 0 {
+            "no public functions take collections".to_string()
+        } else {
+            format!(
+                "{}/{examined} collection-taking public functions validate input",
+                examined - findings.len()
+            )
+        };
+        self.report(AuditCheck::GuardIngress, examined, findings.len(), summary, findings)
+    }
+
+    fn check_assertion_strength(&self, files: &[SourceFile]) -> CheckReport {
+        let mut examined = 0;
+        let mut findings = Vec::new();
+        for file in files.iter().filter(|f| f.is_rust()) {
+            for test in file.tests().into_iter().filter(|test| !test.should_panic) {
+                examined += 1;
+                let assertions = assertions(&file.masked, test.body.clone());
+                if assertions.is_empty() {
+                    findings.push(
+                        file.finding(
+                            test.line,
+                            format!("test `{}` makes no assertions", test.name),
+                        ),
+                    );
+                } else if let Some(weak) =
+                    assertions.iter().find(|a| a.weak).filter(|_| assertions.iter().all(|a| a.weak))
+                {
+                    let text = collapse_whitespace(&file.text[weak.span.clone()]);
+                    findings.push(file.finding(
+                        file.line_of(weak.span.start),
+                        format!("test `{}` only makes weak assertions (`{text}`)", test.name),
+                    ));
+                }
+            }
+        }
+        if examined == 0 {
+            return no_tests(AuditCheck::AssertionStrength);
+        }
+        let summary =
+            format!("{}/{examined} tests make a strong assertion", examined - findings.len());
+        self.report(AuditCheck::AssertionStrength, examined, findings.len(), summary, findings)
+    }
+}
+
+fn no_tests(check: AuditCheck) -> CheckReport {
+    CheckReport {
+        check,
+        status: CheckStatus::Failed,
+        score: 0.0,
+        examined: 0,
+        summary: "no tests found".to_string(),
+        findings: Vec::new(),
+    }
+}
+
+// ============================================================================
+// Source scanning
+// ============================================================================
+
+/// Compile one of this module's constant patterns
+fn regex(pattern: &str) -> Regex {
+    #[allow(clippy::expect_used)] // Constant patterns, exercised by the tests below
+    Regex::new(pattern).expect("valid regex")
+}
+
+static TEST_MACRO: LazyLock<Regex> = LazyLock::new(|| {
+    regex(
+        r"^\s*(?:\$crate::|chicago_tdd_tools::)?(?:test|async_test|async_test_with_timeout|fixture_test|fixture_test_with_timeout|performance_test)!\s*\(\s*(\w+)",
+    )
+});
+
+static TEST_ATTRIBUTE: LazyLock<Regex> = LazyLock::new(|| regex(r"^\s*#\[(?:\w+::)*test\b"));
+
+static FN_NAME: LazyLock<Regex> = LazyLock::new(|| regex(r"\bfn\s+(\w+)"));
+
+static MOCK_USE: LazyLock<Regex> = LazyLock::new(|| {
+    regex(&format!(
+        r"\b(?:{})::|#\[\s*(?:\w+::)*(?:automock|create)\b|\bmock!\s*[{{(]",
+        MOCK_CRATES.join("|")
+    ))
+});
+
+static MOCK_DEPENDENCY: LazyLock<Regex> = LazyLock::new(|| {
+    regex(&format!(r"^\s*(?:\[[\w.-]*dependencies\.)?({})\s*(?:=|\])", MOCK_CRATES.join("|")))
+});
+
+static PUB_FN: LazyLock<Regex> =
+    LazyLock::new(|| regex(r"(?m)^[ \t]*pub\s+(?:const\s+)?(?:async\s+)?(?:unsafe\s+)?fn\s+(\w+)"));
+
+static COLLECTION_PARAM: LazyLock<Regex> =
+    LazyLock::new(|| regex(r":\s*(?:&\s*(?:'\w+\s+)?(?:mut\s+)?\[|Vec<|impl\s+IntoIterator\b)"));
+
+static GUARD_CALL: LazyLock<Regex> = LazyLock::new(|| regex(r"(?i)guard|validate_(?:run|batch)"));
+
+static ASSERTION: LazyLock<Regex> =
+    LazyLock::new(|| regex(r"\b((?:debug_|prop_)?assert\w*)!\s*\("));
+
+static WEAK_ASSERTION: LazyLock<Regex> = LazyLock::new(|| {
+    regex(r"^(?:true|.*\.is_(?:ok|some)\(\)|.*>=\s*0|.*<\s*u(?:8|16|32|64|128|size)::MAX)$")
+});
+
+/// A scanned project file
+struct SourceFile {
+    /// Path relative to the project root
+    path: PathBuf,
+    text: String,
+    /// `text` with comments and string/char literal contents blanked (same byte offsets)
+    masked: String,
+}
+
+/// A test function found in a file
+struct TestFn {
+    name: String,
+    line: usize,
+    should_panic: bool,
+    /// Byte range of the body, braces included
+    body: std::ops::Range<usize>,
+}
+
+impl SourceFile {
+    fn is_rust(&self) -> bool {
+        self.path.extension().is_some_and(|ext| ext == "rs")
+    }
+
+    /// Integration tests, benches, and examples
+    fn is_test_only(&self) -> bool {
+        self.path
+            .components()
+            .any(|c| matches!(c.as_os_str().to_str(), Some("tests" | "benches" | "examples")))
+    }
+
+    /// Byte offset of the first `#[cfg(test)]`, or the end of the file
+    fn test_module_start(&self) -> usize {
+        self.masked.find("#[cfg(test)]").unwrap_or(self.masked.len())
+    }
+
+    fn line_of(&self, offset: usize) -> usize {
+        self.text[..offset].matches('\n').count() + 1
+    }
+
+    fn finding(&self, line: usize, message: String) -> Finding {
+        Finding { file: self.path.clone(), line: Some(line), message }
+    }
+
+    fn tests(&self) -> Vec<TestFn> {
+        let mut tests = Vec::new();
+        // Line of a pending `#[test]` attribute, and whether its block has `#[should_panic]`
+        let mut pending: Option<usize> = None;
+        let mut should_panic = false;
+        let mut offset = 0;
+        for (index, line) in self.masked.split_inclusive('\n').enumerate() {
+            let start = offset;
+            offset += line.len();
+            let trimmed = line.trim_start();
+            if trimmed.starts_with("#[") {
+                should_panic |= trimmed.starts_with("#[should_panic");
+                if TEST_ATTRIBUTE.is_match(line) {
+                    pending = Some(index + 1);
+                }
+                continue;
+            }
+            if trimmed.is_empty() {
+                continue;
+            }
+            let found = TEST_MACRO.captures(line).map_or_else(
+                || {
+                    pending.and_then(|test_line| {
+                        FN_NAME
+                            .captures(line)
+                            .map(|captures| (captures[1].to_string(), test_line, should_panic))
+                    })
+                },
+                |captures| Some((captures[1].to_string(), index + 1, false)),
+            );
+            pending = None;
+            should_panic = false;
+            let Some((name, line_number, panics)) = found else {
+                continue;
+            };
+            let Some(open) = self.masked[start..].find('{').map(|i| start + i) else {
+                continue;
+            };
+            if let Some(close) = matching_close(&self.masked, open) {
+                tests.push(TestFn {
+                    name,
+                    line: line_number,
+                    should_panic: panics,
+                    body: open..close,
+                });
+            }
+        }
+        tests
+    }
+}
+
+fn collect_sources(
+    root: &Path,
+    dir: &Path,
+    exclude: &[String],
+    files: &mut Vec<SourceFile>,
+) -> AuditResult<()> {
+    let io_error = |source| AuditError::Io { path: dir.to_path_buf(), source };
+    for entry in std::fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        if path.is_dir() {
+            if !name.starts_with('.') && !exclude.iter().any(|e| e == name) {
+                collect_sources(root, &path, exclude, files)?;
+            }
+        } else if name == "Cargo.toml" || path.extension().is_some_and(|ext| ext == "rs") {
+            let text = std::fs::read_to_string(&path)
+                .map_err(|source| AuditError::Io { path: path.clone(), source })?;
+            files.push(SourceFile { path: relative_to(root, &path), masked: mask(&text), text });
+        }
+    }
+    Ok(())
+}
+
+fn relative_to(root: &Path, path: &Path) -> PathBuf {
+    path.strip_prefix(root).map_or_else(|_| path.to_path_buf(), Path::to_path_buf)
+}
+
+/// Blank comments and the contents of string and char literals, preserving byte offsets
+/// and newlines, so braces and macro calls can be found with plain text searches
+pub(crate) fn mask(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut out = bytes.to_vec();
+    let blank = |out: &mut Vec<u8>, range: std::ops::Range<usize>| {
+        for byte in &mut out[range] {
+            if *byte != b'\n' {
+                *byte = b' ';
+            }
+        }
+    };
+    let mut i = 0;
+    while i < bytes.len() {
+        let rest = &bytes[i..];
+        let ident_before = i > 0 && (bytes[i - 1].is_ascii_alphanumeric() || bytes[i - 1] == b'_');
+        if rest.starts_with(b"//") {
+            let end = rest.iter().position(|&b| b == b'\n').map_or(bytes.len(), |p| i + p);
+            blank(&mut out, i..end);
+            i = end;
+        } else if rest.starts_with(b"/*") {
+            let end = text[i + 2..].find("*/").map_or(bytes.len(), |p| i + 2 + p + 2);
+            blank(&mut out, i..end);
+            i = end;
+        } else if !ident_before && (rest[0] == b'r' || rest.starts_with(b"br")) {
+            let prefix = if rest[0] == b'b' { 2 } else { 1 };
+            let hashes = rest[prefix..].iter().take_while(|&&b| b == b'#').count();
+            if rest.get(prefix + hashes) == Some(&b'"') {
+                let body = i + prefix + hashes + 1;
+                let terminator = format!("\"{}", "#".repeat(hashes));
+                let end = text[body..].find(&terminator).map_or(bytes.len(), |p| body + p);
+                blank(&mut out, body..end);
+                i = end + terminator.len();
+            } else {
+                i += 1;
+            }
+        } else if rest[0] == b'"' {
+            let mut end = i + 1;
+            while end < bytes.len() && bytes[end] != b'"' {
+                end += if bytes[end] == b'\\' { 2 } else { 1 };
+            }
+            let end = end.min(bytes.len());
+            blank(&mut out, i + 1..end);
+            i = end + 1;
+        } else if rest[0] == b'\'' {
+            // Char literal ('x', '\n', '\u{..}') vs lifetime ('a)
+            let len = if rest.get(1) == Some(&b'\\') {
+                rest[2..].iter().position(|&b| b == b'\'').map(|p| p + 3)
+            } else {
+                let width = text[i + 1..].chars().next().map_or(1, char::len_utf8);
+                (rest.get(1 + width) == Some(&b'\'')).then_some(width + 2)
+            };
+            match len {
+                Some(len) => {
+                    blank(&mut out, i + 1..i + len - 1);
+                    i += len;
+                }
+                None => i += 1,
+            }
+        } else {
+            i += 1;
+        }
+    }
+    // Blanked ranges start and end at ASCII delimiters, so only whole characters were
+    // replaced and the bytes are still valid UTF-8
+    String::from_utf8(out).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
+}
+
+/// Offset just past the delimiter closing the one at `open` (`{`/`}` or `(`/`)`)
+fn matching_close(masked: &str, open: usize) -> Option<usize> {
+    let (opening, closing) = match masked.as_bytes()[open] {
+        b'(' => (b'(', b')'),
+        _ => (b'{', b'}'),
+    };
+    let mut depth = 0usize;
+    for (i, &byte) in masked.as_bytes()[open..].iter().enumerate() {
+        if byte == opening {
+            depth += 1;
+        } else if byte == closing {
+            depth -= 1;
+            if depth == 0 {
+                return Some(open + i + 1);
+            }
+        }
+    }
+    None
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct AaaSections {
+    arrange: bool,
+    act: bool,
+    assert: bool,
+}
+
+/// Find `// Arrange`, `// Act`, `// Assert` markers (also combined, e.g. `// Act & Assert`)
+fn aaa_sections(body: &str) -> AaaSections {
+    let mut sections = AaaSections::default();
+    for comment in body.lines().filter_map(|line| line.trim_start().strip_prefix("//")) {
+        let heading = comment.split([':', '-']).next().unwrap_or_default();
+        for word in heading.split(|c: char| !c.is_ascii_alphabetic()).filter(|w| !w.is_empty()) {
+            match word.to_ascii_lowercase().as_str() {
+                "arrange" => sections.arrange = true,
+                "act" => sections.act = true,
+                "assert" => sections.assert = true,
+                "and" => {}
+                _ => break,
+            }
+        }
+    }
+    sections
+}
+
+struct Assertion {
+    /// Byte range of the whole macro call
+    span: std::ops::Range<usize>,
+    weak: bool,
+}
+
+/// Assertion macro calls in `body`, classified by strength
+fn assertions(masked: &str, body: std::ops::Range<usize>) -> Vec<Assertion> {
+    ASSERTION
+        .captures_iter(&masked[body.clone()])
+        .filter_map(|captures| {
+            let call = captures.get(0)?;
+            let open = body.start + call.end() - 1;
+            let close = matching_close(masked, open)?;
+            let first_arg = first_argument(&masked[open + 1..close - 1]);
+            let weak = matches!(&captures[1], "assert" | "debug_assert" | "prop_assert")
+                && WEAK_ASSERTION.is_match(&collapse_whitespace(first_arg));
+            Some(Assertion { span: body.start + call.start()..close, weak })
+        })
+        .collect()
+}
+
+/// Text up to the first top-level comma
+fn first_argument(args: &str) -> &str {
+    let mut depth = 0i32;
+    for (i, c) in args.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            ',' if depth <= 0 => return &args[..i],
+            _ => {}
+        }
+    }
+    args
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse LCOV line records (`SF:`/`DA:`) into totals and per-file reports
+fn parse_lcov(text: &str) -> (CoverageReport, BTreeMap<String, CoverageReport>) {
+    let mut total = CoverageReport::new();
+    let mut per_file: BTreeMap<String, CoverageReport> = BTreeMap::new();
+    let mut current = String::new();
+    for line in text.lines().map(str::trim) {
+        if let Some(file) = line.strip_prefix("SF:") {
+            current = file.to_string();
+        } else if let Some(record) = line.strip_prefix("DA:") {
+            let mut fields = record.split(',');
+            let (Some(line_number), Some(hits)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            let covered = hits.trim().parse::<u64>().is_ok_and(|hits| hits > 0);
+            let item = format!("{current}:{line_number}");
+            total.add_item(item.clone(), covered);
+            per_file.entry(current.clone()).or_default().add_item(item, covered);
+        }
+    }
+    (total, per_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+    use std::fs;
+
+    fn write(root: &Path, path: &str, text: &str) {
+        let path = root.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, text).unwrap();
+    }
+
+    const COMPLIANT_TESTS: &str = r#"
+pub fn total(items: &[u32]) -> u32 {
+    assert_guard_batch_size(items);
+    items.iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    test!(test_total, {
+        // Arrange
+        let items = [1, 2];
+
+        // Act
+        let sum = total(&items);
+
+        // Assert
+        assert_eq!(sum, 3, "braces in strings {{ are ignored");
+    });
+
+    #[test]
+    #[should_panic(expected = "batch")]
+    fn test_total_rejects_large_batches() {
+        // Act & Assert
+        total(&[0; 2000]);
+    }
+}
+"#;
+
+    test!(test_compliant_project_passes, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "src/lib.rs", COMPLIANT_TESTS);
+        write(dir.path(), "Cargo.toml", "[dev-dependencies]\nchicago-tdd-tools = \"26\"\n");
+
+        // Act
+        let scorecard = QualityAudit::new(dir.path()).run().unwrap();
+
+        // Assert
+        assert!(scorecard.passed, "{}", scorecard.to_markdown());
+        assert_eq!(scorecard.score, 100.0);
+        assert_eq!(scorecard.check(AuditCheck::Aaa).unwrap().examined, 2);
+        assert_eq!(scorecard.check(AuditCheck::GuardIngress).unwrap().examined, 1);
+        assert_eq!(scorecard.check(AuditCheck::Coverage).unwrap().status, CheckStatus::Skipped);
+        assert_eq!(scorecard.findings().count(), 0);
+    });
+
+    test!(test_flags_missing_aaa_and_weak_assertions, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "tests/orders.rs",
+            "#[test]\nfn test_weak() {\n    let r: Result<u8, ()> = Ok(1);\n    assert!(\n        r.is_ok()\n    );\n}\n\n#[tokio::test]\nasync fn test_silent() {\n    // Act\n    let _ = 1;\n}\n",
+        );
+        let audit = QualityAudit::new(dir.path())
+            .with_checks(vec![AuditCheck::AssertionStrength, AuditCheck::Aaa]);
+
+        // Act
+        let scorecard = audit.run().unwrap();
+
+        // Assert
+        let aaa = scorecard.check(AuditCheck::Aaa).unwrap();
+        assert_eq!(aaa.status, CheckStatus::Failed);
+        assert_eq!(
+            aaa.findings[0].to_string(),
+            "tests/orders.rs:1: test `test_weak` has no `// Act`/`// Assert` section"
+        );
+        assert_eq!(aaa.findings[1].message, "test `test_silent` has no `// Assert` section");
+        let strength = scorecard.check(AuditCheck::AssertionStrength).unwrap();
+        assert_eq!(strength.findings[0].line, Some(4));
+        assert_eq!(
+            strength.findings[0].message,
+            "test `test_weak` only makes weak assertions (`assert!( r.is_ok() )`)"
+        );
+        assert_eq!(strength.findings[1].message, "test `test_silent` makes no assertions");
+        assert_eq!(
+            scorecard.checks.iter().map(|c| c.check).collect::<Vec<_>>(),
+            [AuditCheck::Aaa, AuditCheck::AssertionStrength]
+        );
+        assert!(!scorecard.passed);
+    });
+
+    test!(test_flags_mocks_and_unguarded_ingress, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "Cargo.toml", "[dev-dependencies]\nmockall = \"0.13\"\n");
+        write(
+            dir.path(),
+            "src/lib.rs",
+            "#[cfg_attr(test, mockall::automock)]\npub trait Store {}\n\npub fn load(ids: Vec<u64>) -> usize {\n    ids.len()\n}\n\npub fn one(id: u64) -> u64 { id }\n",
+        );
+
+        // Act
+        let scorecard = QualityAudit::new(dir.path())
+            .with_checks(vec![AuditCheck::MockPolicy, AuditCheck::GuardIngress])
+            .run()
+            .unwrap();
+
+        // Assert
+        let mocks = scorecard.check(AuditCheck::MockPolicy).unwrap();
+        assert_eq!((mocks.examined, mocks.score), (2, 0.0));
+        assert_eq!(mocks.findings[0].message, "depends on mocking crate `mockall`");
+        assert_eq!(mocks.findings[1].message, "uses mocking framework (`mockall::`)");
+        let guards = scorecard.check(AuditCheck::GuardIngress).unwrap();
+        assert_eq!(guards.examined, 1);
+        assert_eq!(
+            guards.findings[0].to_string(),
+            "src/lib.rs:4: `load` takes a collection but never calls a guard"
+        );
+    });
+
+    test!(test_coverage_gate_reads_lcov, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            "target/llvm-cov/lcov.info",
+            "SF:src/a.rs\nDA:1,3\nDA:2,1\nend_of_record\nSF:src/b.rs\nDA:1,0\nDA:2,4\nend_of_record\n",
+        );
+        let config = AuditConfig {
+            checks: vec![AuditCheck::Coverage],
+            coverage_threshold: 80.0,
+            ..AuditConfig::default()
+        };
+
+        // Act
+        let scorecard = QualityAudit::new(dir.path()).with_config(config).run().unwrap();
+
+        // Assert
+        let coverage = scorecard.check(AuditCheck::Coverage).unwrap();
+        assert_eq!(coverage.status, CheckStatus::Failed);
+        assert_eq!(coverage.score, 75.0);
+        assert_eq!(coverage.findings.len(), 1);
+        assert_eq!(coverage.findings[0].to_string(), "src/b.rs: 50.0% line coverage (1/2)");
+        assert!(scorecard.to_markdown().contains("| coverage | failed | 75.0 | 4 |"));
+    });
+
+    test!(test_config_from_project_file, {
+        // Arrange
+        let dir = tempfile::tempdir().unwrap();
+        write(
+            dir.path(),
+            AUDIT_CONFIG_FILE,
+            "[test]\nunit_timeout_seconds = 1\n\n[audit]\nchecks = [\"aaa\", \"guard-ingress\"]\nmin_score = 95.0\n",
+        );
+
+        // Act
+        let audit = QualityAudit::for_project(dir.path()).unwrap();
+
+        // Assert
+        assert_eq!(audit.config().checks, [AuditCheck::Aaa, AuditCheck::GuardIngress]);
+        assert_eq!(audit.config().min_score, 95.0);
+        assert_eq!(audit.config().coverage_threshold, 80.0);
+        assert!(AuditConfig::from_config_str("[audit]\nmin_score = 101.0\n").is_err());
+        assert!(AuditConfig::from_config_str("[audit]\nchecks = [\"vibes\"]\n").is_err());
+        assert_eq!(
+            AuditCheck::parse_list("aaa, Mock-Policy").unwrap(),
+            [AuditCheck::Aaa, AuditCheck::MockPolicy]
+        );
+        assert!(matches!(AuditCheck::parse("vibes"), Err(AuditError::UnknownCheck(_))));
+    });
+}
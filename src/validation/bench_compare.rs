@@ -0,0 +1,556 @@
+//! Benchmark A/B Comparison
+//!
+//! Answers "is the candidate faster than the baseline?" with statistics instead of
+//! eyeballing two means. Samples from each side are compared with a two-sided
+//! Mann-Whitney U test (no normality assumption, robust to the long tails timing data
+//! has) and Cliff's delta as the effect size. A change is only reported when it is
+//! both significant and larger than negligible.
+//!
+//! [`AbBenchmark`] produces the samples by running two commands (e.g. the same
+//! benchmark binary built from two commits) interleaved, so drift in machine load
+//! affects both sides equally. Samples from any other source can be compared with
+//! [`BenchComparison::compare`]. Results convert to a [`TestReceipt`] for storage.
+//!
+//! Command runs are timed as whole processes through [`CheckedCommand`], so this suits
+//! end-to-end benchmarks in the millisecond range and up. Compare in-process samples
+//! for finer differences.
+//!
+//! # Example
+//!
+//! ```rust
+//! use chicago_tdd_tools::validation::bench_compare::{BenchComparison, BenchSamples, BenchVerdict};
+//!
+//! let baseline = BenchSamples::from_nanos("main", [120.0, 118.0, 125.0, 121.0, 119.0, 123.0, 122.0, 120.0]);
+//! let candidate = BenchSamples::from_nanos("pr", [101.0, 99.0, 103.0, 100.0, 98.0, 102.0, 100.0, 101.0]);
+//!
+//! let comparison = BenchComparison::compare(&baseline, &candidate, 0.05);
+//! assert_eq!(comparison.verdict, BenchVerdict::Faster);
+//! println!("{comparison}");
+//! ```
+
+use crate::core::command::{CheckedCommand, CommandResult};
+use crate::core::receipt::{EnvironmentFingerprint, TestOutcome, TestReceipt, TimingMeasurement};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::time::Duration;
+
+/// Default significance level
+pub const DEFAULT_ALPHA: f64 = 0.05;
+
+/// Timing samples for one side of a comparison
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchSamples {
+    /// Label (e.g. branch, commit, or binary name)
+    pub label: String,
+    /// Sample durations in nanoseconds
+    pub nanos: Vec<f64>,
+}
+
+impl BenchSamples {
+    /// Samples from measured durations
+    #[must_use]
+    pub fn new(label: impl Into<String>, durations: &[Duration]) -> Self {
+        Self::from_nanos(label, durations.iter().map(|d| d.as_secs_f64() * 1e9))
+    }
+
+    /// Samples from raw nanosecond values
+    #[must_use]
+    pub fn from_nanos(label: impl Into<String>, nanos: impl IntoIterator<Item = f64>) -> Self {
+        Self { label: label.into(), nanos: nanos.into_iter().collect() }
+    }
+
+    /// Summary statistics
+    #[must_use]
+    pub fn summary(&self) -> BenchSummary {
+        let mut sorted = self.nanos.clone();
+        sorted.sort_by(f64::total_cmp);
+        #[allow(clippy::cast_precision_loss)] // Sample counts are far below 2^52
+        let n = sorted.len() as f64;
+        let mean = if sorted.is_empty() { 0.0 } else { sorted.iter().sum::<f64>() / n };
+        let variance = if sorted.len() < 2 {
+            0.0
+        } else {
+            sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        };
+        let median = match sorted.len() {
+            0 => 0.0,
+            len if len % 2 == 0 => f64::midpoint(sorted[len / 2 - 1], sorted[len / 2]),
+            len => sorted[len / 2],
+        };
+        BenchSummary {
+            label: self.label.clone(),
+            samples: sorted.len(),
+            mean_ns: mean,
+            median_ns: median,
+            stddev_ns: variance.sqrt(),
+        }
+    }
+}
+
+/// Summary statistics of one side
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchSummary {
+    /// Label of the samples
+    pub label: String,
+    /// Number of samples
+    pub samples: usize,
+    /// Mean in nanoseconds
+    pub mean_ns: f64,
+    /// Median in nanoseconds
+    pub median_ns: f64,
+    /// Sample standard deviation in nanoseconds
+    pub stddev_ns: f64,
+}
+
+/// Size of a difference by Cliff's delta (Romano et al. thresholds)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EffectMagnitude {
+    /// |δ| < 0.147
+    Negligible,
+    /// |δ| < 0.33
+    Small,
+    /// |δ| < 0.474
+    Medium,
+    /// |δ| ≥ 0.474
+    Large,
+}
+
+impl EffectMagnitude {
+    /// Classify a Cliff's delta value
+    #[must_use]
+    pub fn from_delta(delta: f64) -> Self {
+        match delta.abs() {
+            d if d < 0.147 => Self::Negligible,
+            d if d < 0.33 => Self::Small,
+            d if d < 0.474 => Self::Medium,
+            _ => Self::Large,
+        }
+    }
+}
+
+impl fmt::Display for EffectMagnitude {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Negligible => "negligible",
+            Self::Small => "small",
+            Self::Medium => "medium",
+            Self::Large => "large",
+        })
+    }
+}
+
+/// Outcome of a comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchVerdict {
+    /// Candidate is significantly faster
+    Faster,
+    /// Candidate is significantly slower
+    Slower,
+    /// No significant, non-negligible difference
+    NoChange,
+}
+
+impl fmt::Display for BenchVerdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Faster => "faster",
+            Self::Slower => "slower",
+            Self::NoChange => "no significant change",
+        })
+    }
+}
+
+/// Statistical comparison of baseline and candidate samples
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchComparison {
+    /// Baseline summary
+    pub baseline: BenchSummary,
+    /// Candidate summary
+    pub candidate: BenchSummary,
+    /// Median change of candidate relative to baseline, in percent (negative = faster)
+    pub change_pct: f64,
+    /// Mann-Whitney U statistic of the candidate
+    pub u_statistic: f64,
+    /// Two-sided p-value (normal approximation with tie correction)
+    pub p_value: f64,
+    /// Cliff's delta: P(candidate > baseline) - P(candidate < baseline)
+    pub cliffs_delta: f64,
+    /// Magnitude of `cliffs_delta`
+    pub magnitude: EffectMagnitude,
+    /// Significance level used
+    pub alpha: f64,
+    /// Overall verdict
+    pub verdict: BenchVerdict,
+}
+
+impl BenchComparison {
+    /// Compare `candidate` against `baseline` at significance level `alpha`
+    #[must_use]
+    pub fn compare(baseline: &BenchSamples, candidate: &BenchSamples, alpha: f64) -> Self {
+        let (u_statistic, p_value) = mann_whitney(&baseline.nanos, &candidate.nanos);
+        let cliffs_delta = cliffs_delta(&baseline.nanos, &candidate.nanos);
+        let magnitude = EffectMagnitude::from_delta(cliffs_delta);
+        let verdict = if p_value >= alpha || magnitude == EffectMagnitude::Negligible {
+            BenchVerdict::NoChange
+        } else if cliffs_delta < 0.0 {
+            BenchVerdict::Faster
+        } else {
+            BenchVerdict::Slower
+        };
+        let baseline = baseline.summary();
+        let candidate = candidate.summary();
+        let change_pct = if baseline.median_ns == 0.0 {
+            0.0
+        } else {
+            (candidate.median_ns - baseline.median_ns) / baseline.median_ns * 100.0
+        };
+        Self {
+            baseline,
+            candidate,
+            change_pct,
+            u_statistic,
+            p_value,
+            cliffs_delta,
+            magnitude,
+            alpha,
+            verdict,
+        }
+    }
+
+    /// Whether the candidate is significantly slower
+    #[must_use]
+    pub fn is_regression(&self) -> bool {
+        self.verdict == BenchVerdict::Slower
+    }
+
+    /// Receipt recording this comparison (fails on regression)
+    ///
+    /// Statistics are stored as metadata (`bench.p_value`, `bench.cliffs_delta`, ...).
+    #[must_use]
+    pub fn to_receipt(&self, name: &str) -> TestReceipt {
+        let mut hasher = Sha256::new();
+        hasher.update(self.baseline.label.as_bytes());
+        hasher.update(self.candidate.label.as_bytes());
+        let code_hash = format!("{:x}", hasher.finalize())[..16].to_string();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        // Median milliseconds of a benchmark run fit comfortably in u64
+        let median_ms = (self.candidate.median_ns / 1e6).round().max(0.0) as u64;
+        let timing =
+            TimingMeasurement::new(0, median_ms, "cold".to_string(), !self.is_regression(), 0);
+        let mut receipt = TestReceipt::new(
+            format!("bench:{name}"),
+            code_hash,
+            EnvironmentFingerprint::capture(),
+            vec!["candidate is not significantly slower than baseline".to_string()],
+            timing,
+            Vec::new(),
+            if self.is_regression() { TestOutcome::Fail } else { TestOutcome::Pass },
+        );
+        let metadata = [
+            ("bench.baseline", self.baseline.label.clone()),
+            ("bench.candidate", self.candidate.label.clone()),
+            ("bench.baseline_median_ns", format!("{:.0}", self.baseline.median_ns)),
+            ("bench.candidate_median_ns", format!("{:.0}", self.candidate.median_ns)),
+            ("bench.change_pct", format!("{:.2}", self.change_pct)),
+            ("bench.p_value", format!("{:.6}", self.p_value)),
+            ("bench.cliffs_delta", format!("{:.4}", self.cliffs_delta)),
+            ("bench.effect", self.magnitude.to_string()),
+            ("bench.verdict", self.verdict.to_string()),
+        ];
+        for (key, value) in metadata {
+            receipt.add_metadata(key, value);
+        }
+        receipt.sign();
+        receipt
+    }
+}
+
+impl fmt::Display for BenchComparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let glyph = match self.verdict {
+            BenchVerdict::Faster => "✅",
+            BenchVerdict::Slower => "🚨",
+            BenchVerdict::NoChange => "➖",
+        };
+        writeln!(
+            f,
+            "{glyph} {} vs {}: {} ({:+.2}% median)",
+            self.candidate.label, self.baseline.label, self.verdict, self.change_pct
+        )?;
+        for side in [&self.baseline, &self.candidate] {
+            writeln!(
+                f,
+                "   {}: median {} | mean {} ± {} (n={})",
+                side.label,
+                format_nanos(side.median_ns),
+                format_nanos(side.mean_ns),
+                format_nanos(side.stddev_ns),
+                side.samples
+            )?;
+        }
+        write!(
+            f,
+            "   p = {:.4} (α = {}), Cliff's δ = {:+.3} ({})",
+            self.p_value, self.alpha, self.cliffs_delta, self.magnitude
+        )
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    match nanos {
+        n if n >= 1e9 => format!("{:.3}s", n / 1e9),
+        n if n >= 1e6 => format!("{:.3}ms", n / 1e6),
+        n if n >= 1e3 => format!("{:.3}µs", n / 1e3),
+        n => format!("{n:.0}ns"),
+    }
+}
+
+/// Mann-Whitney U of `candidate` and its two-sided p-value
+#[allow(clippy::cast_precision_loss)] // Sample counts are far below 2^52
+fn mann_whitney(baseline: &[f64], candidate: &[f64]) -> (f64, f64) {
+    let (n1, n2) = (baseline.len() as f64, candidate.len() as f64);
+    if baseline.is_empty() || candidate.is_empty() {
+        return (0.0, 1.0);
+    }
+    let mut pooled: Vec<(f64, bool)> = baseline
+        .iter()
+        .map(|&x| (x, false))
+        .chain(candidate.iter().map(|&x| (x, true)))
+        .collect();
+    pooled.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    // Average ranks over ties, accumulating the tie correction term
+    let mut candidate_rank_sum = 0.0;
+    let mut tie_term = 0.0;
+    let mut start = 0;
+    while start < pooled.len() {
+        let end = pooled[start..]
+            .iter()
+            .take_while(|p| p.0.total_cmp(&pooled[start].0).is_eq())
+            .count()
+            + start;
+        let rank = (start + end + 1) as f64 / 2.0;
+        let in_candidate = pooled[start..end].iter().filter(|p| p.1).count() as f64;
+        candidate_rank_sum += rank * in_candidate;
+        let ties = (end - start) as f64;
+        tie_term += ties.powi(3) - ties;
+        start = end;
+    }
+
+    let u = n2.mul_add(-(n2 + 1.0) / 2.0, candidate_rank_sum);
+    let n = n1 + n2;
+    let variance = n1 * n2 / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)));
+    if variance <= 0.0 {
+        return (u, 1.0);
+    }
+    let mean = n1 * n2 / 2.0;
+    let z = ((u - mean).abs() - 0.5).max(0.0) / variance.sqrt();
+    (u, (2.0 * (1.0 - standard_normal_cdf(z))).clamp(0.0, 1.0))
+}
+
+/// P(candidate > baseline) - P(candidate < baseline)
+#[allow(clippy::cast_precision_loss)] // Pair counts are far below 2^52
+fn cliffs_delta(baseline: &[f64], candidate: &[f64]) -> f64 {
+    if baseline.is_empty() || candidate.is_empty() {
+        return 0.0;
+    }
+    let dominance: i64 = candidate
+        .iter()
+        .flat_map(|c| baseline.iter().map(move |b| c.total_cmp(b) as i64))
+        .sum();
+    dominance as f64 / (baseline.len() * candidate.len()) as f64
+}
+
+/// Φ(z) via the Abramowitz-Stegun 7.1.26 erf approximation (|error| < 1.5e-7)
+fn standard_normal_cdf(z: f64) -> f64 {
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / 0.327_591_1f64.mul_add(x, 1.0);
+    let poly = t * 1.061_405_429f64
+        .mul_add(t, -1.453_152_027)
+        .mul_add(t, 1.421_413_741)
+        .mul_add(t, -0.284_496_736)
+        .mul_add(t, 0.254_829_592);
+    let erf = 1.0 - poly * (-x * x).exp();
+    if z >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
+/// A/B benchmark of two commands
+///
+/// Typically the same benchmark binary built from the baseline and candidate
+/// commits. Runs alternate baseline/candidate so both sides see the same noise.
+#[derive(Debug, Clone)]
+pub struct AbBenchmark {
+    name: String,
+    baseline: (String, CheckedCommand),
+    candidate: (String, CheckedCommand),
+    runs: usize,
+    warmup: usize,
+    alpha: f64,
+}
+
+impl AbBenchmark {
+    /// Compare `candidate` against `baseline` (labelled by their command lines)
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        baseline: CheckedCommand,
+        candidate: CheckedCommand,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            baseline: (baseline.command_line(), baseline),
+            candidate: (candidate.command_line(), candidate),
+            runs: 10,
+            warmup: 1,
+            alpha: DEFAULT_ALPHA,
+        }
+    }
+
+    /// Label the two sides (e.g. commit hashes)
+    #[must_use]
+    pub fn labels(mut self, baseline: impl Into<String>, candidate: impl Into<String>) -> Self {
+        self.baseline.0 = baseline.into();
+        self.candidate.0 = candidate.into();
+        self
+    }
+
+    /// Measured runs per side (default 10, minimum 2)
+    #[must_use]
+    pub fn runs(mut self, runs: usize) -> Self {
+        self.runs = runs.max(2);
+        self
+    }
+
+    /// Unmeasured warmup runs per side (default 1)
+    #[must_use]
+    pub const fn warmup(mut self, warmup: usize) -> Self {
+        self.warmup = warmup;
+        self
+    }
+
+    /// Significance level (default [`DEFAULT_ALPHA`])
+    #[must_use]
+    pub const fn alpha(mut self, alpha: f64) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Benchmark name (used for receipts)
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Run both commands interleaved and compare their wall-clock times
+    ///
+    /// # Errors
+    ///
+    /// Returns the first command failure (non-zero exit, timeout, or spawn error).
+    pub fn run(&self) -> CommandResult<BenchComparison> {
+        for _ in 0..self.warmup {
+            self.baseline.1.run()?;
+            self.candidate.1.run()?;
+        }
+        let mut baseline = Vec::with_capacity(self.runs);
+        let mut candidate = Vec::with_capacity(self.runs);
+        for _ in 0..self.runs {
+            baseline.push(self.baseline.1.run()?.elapsed);
+            candidate.push(self.candidate.1.run()?.elapsed);
+        }
+        Ok(BenchComparison::compare(
+            &BenchSamples::new(self.baseline.0.clone(), &baseline),
+            &BenchSamples::new(self.candidate.0.clone(), &candidate),
+            self.alpha,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    fn samples(label: &str, center: f64, count: usize) -> BenchSamples {
+        // Deterministic jitter of ±5% around `center`
+        let jitter = [0.0, 0.03, -0.02, 0.05, -0.04, 0.01, -0.05, 0.02, -0.01, 0.04];
+        BenchSamples::from_nanos(
+            label,
+            (0..count).map(|i| center * (1.0 + jitter[i % jitter.len()])),
+        )
+    }
+
+    test!(test_detects_significant_speedup_and_regression, {
+        // Arrange
+        let baseline = samples("main", 1_000_000.0, 20);
+        let candidate = samples("pr", 800_000.0, 20);
+
+        // Act
+        let faster = BenchComparison::compare(&baseline, &candidate, DEFAULT_ALPHA);
+        let slower = BenchComparison::compare(&candidate, &baseline, DEFAULT_ALPHA);
+
+        // Assert
+        assert_eq!(faster.verdict, BenchVerdict::Faster);
+        assert!(faster.p_value < 0.001, "p = {}", faster.p_value);
+        assert!((faster.cliffs_delta + 1.0).abs() < f64::EPSILON);
+        assert_eq!(faster.magnitude, EffectMagnitude::Large);
+        assert!((faster.change_pct + 20.0).abs() < 1.0, "{}", faster.change_pct);
+        assert!(slower.is_regression());
+        assert!(faster.to_string().contains("pr vs main: faster"));
+    });
+
+    test!(test_same_distribution_is_no_change, {
+        // Arrange
+        let baseline = samples("main", 1_000_000.0, 20);
+        let candidate = samples("pr", 1_000_000.0, 20);
+
+        // Act
+        let comparison = BenchComparison::compare(&baseline, &candidate, DEFAULT_ALPHA);
+
+        // Assert
+        assert_eq!(comparison.verdict, BenchVerdict::NoChange);
+        assert!(comparison.p_value > 0.9, "p = {}", comparison.p_value);
+        assert_eq!(comparison.magnitude, EffectMagnitude::Negligible);
+    });
+
+    test!(test_receipt_records_statistics, {
+        // Arrange
+        let comparison = BenchComparison::compare(
+            &samples("v1", 1_000_000.0, 10),
+            &samples("v2", 2_000_000.0, 10),
+            DEFAULT_ALPHA,
+        );
+
+        // Act
+        let receipt = comparison.to_receipt("startup");
+
+        // Assert
+        assert_eq!(receipt.contract_name, "bench:startup");
+        assert_eq!(receipt.result, TestOutcome::Fail);
+        assert_eq!(receipt.get_metadata("bench.verdict"), Some("slower"));
+        assert_eq!(receipt.get_metadata("bench.effect"), Some("large"));
+        assert!(receipt.verify_signature());
+    });
+
+    #[cfg(unix)]
+    test!(test_ab_benchmark_runs_commands_interleaved, {
+        // Arrange
+        let quick = CheckedCommand::new("true");
+        let slow = CheckedCommand::new("sleep").arg("0.05");
+
+        // Act
+        let comparison = AbBenchmark::new("sleep", quick, slow)
+            .labels("old", "new")
+            .runs(6)
+            .run()
+            .unwrap();
+
+        // Assert
+        assert_eq!(comparison.baseline.samples, 6);
+        assert_eq!(comparison.candidate.label, "new");
+        assert_eq!(comparison.verdict, BenchVerdict::Slower);
+    });
+}
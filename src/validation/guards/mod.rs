@@ -12,6 +12,25 @@
 //! For compile-time validation, see the `validated` submodule which provides
 //! `ValidatedRun<const LEN: usize>` and `ValidatedBatch<const SIZE: usize>`.
 //!
+//! # Profiles
+//!
+//! Workloads with different limits (hot path, batch ingest, streaming) use named
+//! profiles from the `profile` submodule: `GuardValidator::for_profile("streaming")` at
+//! runtime (configurable in `chicago-tdd-tools.toml`), or `ProfiledRun<P, LEN>` and
+//! `ProfiledBatch<P, SIZE>` for compile-time checks against a profile marker type.
+//!
+//! # Instrumentation
+//!
+//! "Guards exist" and "guards executed" are different facts. Attach [`GuardCounters`]
+//! with [`GuardValidator::with_counters`] to count validations performed and violations
+//! by constraint, then assert on the counts (or export them as OTEL metrics with the
+//! `otel` feature) after an integration scenario.
+//!
+//! The counters also keep each constraint's peak utilization (largest value seen over its
+//! limit, so `1.0` is exactly at the limit) and a log of violations. With the `otel`
+//! feature these export as `guard.utilization` gauges and `guard.violation` span events,
+//! showing how close production-shaped inputs run to the Chatman limits.
+//!
 //! ## Examples
 //!
 //! ### Runtime Validation
@@ -27,7 +46,26 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! ### Counting Validations
+//!
+//! ```rust
+//! use chicago_tdd_tools::guards::{GuardConstraint, GuardCounters, GuardValidator};
+//! use std::sync::Arc;
+//!
+//! let counters = Arc::new(GuardCounters::new());
+//! let validator = GuardValidator::new().with_counters(Arc::clone(&counters));
+//!
+//! // Code under test receives the validator and validates its ingress
+//! let _ = validator.validate_run_len(5);
+//! let _ = validator.validate_run_len(9);
+//!
+//! assert_eq!(counters.validations(), 2);
+//! assert_eq!(counters.violations(GuardConstraint::MaxRunLen), 1);
+//! ```
 
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
 use thiserror::Error;
 
 /// Guard constraint error
@@ -42,6 +80,9 @@ pub enum GuardConstraintError {
     /// Invalid constraint value
     #[error("Invalid constraint value: {0}")]
     InvalidConstraintValue(String),
+    /// No guard profile with this name
+    #[error("Unknown guard profile '{}' (known: {})", .0, .1)]
+    UnknownProfile(String, String),
 }
 
 /// Result type for guard constraint validation
@@ -53,10 +94,265 @@ pub const MAX_RUN_LEN: usize = 8;
 /// Maximum batch size
 pub const MAX_BATCH_SIZE: usize = 1000;
 
+/// Guard constraint checked by a [`GuardValidator`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GuardConstraint {
+    /// Run length ≤ `max_run_len`
+    MaxRunLen,
+    /// Batch size ≤ `max_batch_size`
+    MaxBatchSize,
+}
+
+impl GuardConstraint {
+    /// All constraints, in reporting order
+    pub const ALL: [Self; 2] = [Self::MaxRunLen, Self::MaxBatchSize];
+
+    /// Stable name used as the `guard.constraint` metric attribute
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::MaxRunLen => "max_run_len",
+            Self::MaxBatchSize => "max_batch_size",
+        }
+    }
+}
+
+/// Metric name for the number of guard validations performed
+pub const GUARD_VALIDATIONS_METRIC: &str = "guard.validations";
+
+/// Metric name for the number of guard violations (attribute `guard.constraint`)
+pub const GUARD_VIOLATIONS_METRIC: &str = "guard.violations";
+
+/// Metric name for peak value / limit per constraint (attribute `guard.constraint`)
+pub const GUARD_UTILIZATION_METRIC: &str = "guard.utilization";
+
+/// Span event name for one guard violation
+pub const GUARD_VIOLATION_EVENT: &str = "guard.violation";
+
+/// Violations kept by [`GuardCounters`]; later ones are counted but not logged
+pub const MAX_RECORDED_VIOLATIONS: usize = 1024;
+
+/// One guard violation recorded by [`GuardCounters`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GuardViolation {
+    /// Violated constraint
+    pub constraint: GuardConstraint,
+    /// Offending run length or batch size
+    pub value: usize,
+    /// Limit it exceeded
+    pub limit: usize,
+    /// When it happened, in milliseconds since the epoch
+    pub timestamp_ms: u64,
+}
+
+impl GuardViolation {
+    /// The violation as a `guard.violation` span event
+    ///
+    /// Attributes: `guard.constraint`, `guard.value`, and `guard.limit`.
+    #[cfg(feature = "otel")]
+    #[must_use]
+    pub fn to_span_event(self) -> crate::observability::otel::types::SpanEvent {
+        use crate::observability::otel::types::{Attributes, SpanEvent};
+
+        SpanEvent {
+            name: GUARD_VIOLATION_EVENT.to_string(),
+            timestamp_ms: self.timestamp_ms,
+            attributes: Attributes::from([
+                ("guard.constraint".to_string(), self.constraint.as_str().to_string()),
+                ("guard.value".to_string(), self.value.to_string()),
+                ("guard.limit".to_string(), self.limit.to_string()),
+            ]),
+        }
+    }
+}
+
+fn now_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX))
+}
+
+/// Validation and violation counts recorded by instrumented [`GuardValidator`]s
+///
+/// Share one instance (via `Arc`) between the test and the validators handed to the
+/// code under test. Counting is lock-free, so instrumented validators stay cheap; only
+/// violations take a lock, to append to the violation log.
+#[derive(Debug, Default)]
+pub struct GuardCounters {
+    validations: AtomicU64,
+    run_len_violations: AtomicU64,
+    batch_size_violations: AtomicU64,
+    /// Peak utilization per constraint, as `f64` bits
+    run_len_peak: AtomicU64,
+    batch_size_peak: AtomicU64,
+    violation_log: Mutex<Vec<GuardViolation>>,
+}
+
+impl GuardCounters {
+    /// Create zeroed counters
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            validations: AtomicU64::new(0),
+            run_len_violations: AtomicU64::new(0),
+            batch_size_violations: AtomicU64::new(0),
+            run_len_peak: AtomicU64::new(0),
+            batch_size_peak: AtomicU64::new(0),
+            violation_log: Mutex::new(Vec::new()),
+        }
+    }
+
+    const fn violation_counter(&self, constraint: GuardConstraint) -> &AtomicU64 {
+        match constraint {
+            GuardConstraint::MaxRunLen => &self.run_len_violations,
+            GuardConstraint::MaxBatchSize => &self.batch_size_violations,
+        }
+    }
+
+    const fn peak_cell(&self, constraint: GuardConstraint) -> &AtomicU64 {
+        match constraint {
+            GuardConstraint::MaxRunLen => &self.run_len_peak,
+            GuardConstraint::MaxBatchSize => &self.batch_size_peak,
+        }
+    }
+
+    fn record(&self, constraint: GuardConstraint, value: usize, limit: usize) {
+        self.validations.fetch_add(1, Ordering::Relaxed);
+
+        #[allow(clippy::cast_precision_loss)] // Ratio for monitoring; exactness is not needed
+        let utilization = value as f64 / limit.max(1) as f64;
+        let _ =
+            self.peak_cell(constraint)
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+                    (utilization > f64::from_bits(bits)).then(|| utilization.to_bits())
+                });
+
+        if value > limit {
+            self.violation_counter(constraint).fetch_add(1, Ordering::Relaxed);
+            let mut log = self.violation_log.lock().unwrap_or_else(PoisonError::into_inner);
+            if log.len() < MAX_RECORDED_VIOLATIONS {
+                log.push(GuardViolation { constraint, value, limit, timestamp_ms: now_ms() });
+            }
+        }
+    }
+
+    /// Validations performed (passed or failed)
+    #[must_use]
+    pub fn validations(&self) -> u64 {
+        self.validations.load(Ordering::Relaxed)
+    }
+
+    /// Violations of one constraint
+    #[must_use]
+    pub fn violations(&self, constraint: GuardConstraint) -> u64 {
+        self.violation_counter(constraint).load(Ordering::Relaxed)
+    }
+
+    /// Violations of all constraints
+    #[must_use]
+    pub fn total_violations(&self) -> u64 {
+        GuardConstraint::ALL.iter().map(|&constraint| self.violations(constraint)).sum()
+    }
+
+    /// Largest value seen for a constraint divided by its limit (0.0 before any validation)
+    ///
+    /// `1.0` means an input ran exactly at the limit; above `1.0` means it was violated.
+    #[must_use]
+    pub fn peak_utilization(&self, constraint: GuardConstraint) -> f64 {
+        f64::from_bits(self.peak_cell(constraint).load(Ordering::Relaxed))
+    }
+
+    /// Violations recorded so far, oldest first (at most [`MAX_RECORDED_VIOLATIONS`])
+    #[must_use]
+    pub fn recorded_violations(&self) -> Vec<GuardViolation> {
+        self.violation_log.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Zero all counts (for reuse between scenarios)
+    pub fn reset(&self) {
+        self.validations.store(0, Ordering::Relaxed);
+        for constraint in GuardConstraint::ALL {
+            self.violation_counter(constraint).store(0, Ordering::Relaxed);
+            self.peak_cell(constraint).store(0, Ordering::Relaxed);
+        }
+        self.violation_log.lock().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+
+    /// Current counts as OTEL counter metrics
+    ///
+    /// One `guard.validations` metric plus one `guard.violations` metric per constraint,
+    /// labelled with a `guard.constraint` attribute.
+    #[cfg(feature = "otel")]
+    #[must_use]
+    pub fn to_metrics(&self) -> Vec<crate::observability::otel::types::Metric> {
+        use crate::observability::otel::types::{Attributes, Metric, MetricValue};
+
+        let timestamp_ms = now_ms();
+        let mut metrics = vec![Metric {
+            name: GUARD_VALIDATIONS_METRIC.to_string(),
+            value: MetricValue::Counter(self.validations()),
+            timestamp_ms,
+            attributes: Attributes::new(),
+        }];
+        metrics.extend(GuardConstraint::ALL.iter().map(|&constraint| Metric {
+            name: GUARD_VIOLATIONS_METRIC.to_string(),
+            value: MetricValue::Counter(self.violations(constraint)),
+            timestamp_ms,
+            attributes: Attributes::from([(
+                "guard.constraint".to_string(),
+                constraint.as_str().to_string(),
+            )]),
+        }));
+        metrics
+    }
+
+    /// Peak utilization per constraint as `guard.utilization` gauge metrics
+    ///
+    /// One gauge per constraint, labelled with a `guard.constraint` attribute. Track the
+    /// gauge across runs to see how much headroom production-shaped inputs leave.
+    #[cfg(feature = "otel")]
+    #[must_use]
+    pub fn utilization_metrics(&self) -> Vec<crate::observability::otel::types::Metric> {
+        use crate::observability::otel::types::{Attributes, Metric, MetricValue};
+
+        let timestamp_ms = now_ms();
+        GuardConstraint::ALL
+            .iter()
+            .map(|&constraint| Metric {
+                name: GUARD_UTILIZATION_METRIC.to_string(),
+                value: MetricValue::Gauge(self.peak_utilization(constraint)),
+                timestamp_ms,
+                attributes: Attributes::from([(
+                    "guard.constraint".to_string(),
+                    constraint.as_str().to_string(),
+                )]),
+            })
+            .collect()
+    }
+
+    /// Recorded violations as `guard.violation` span events
+    #[cfg(feature = "otel")]
+    #[must_use]
+    pub fn violation_events(&self) -> Vec<crate::observability::otel::types::SpanEvent> {
+        self.recorded_violations()
+            .into_iter()
+            .map(GuardViolation::to_span_event)
+            .collect()
+    }
+
+    /// Append the recorded violations to `span` as `guard.violation` events
+    #[cfg(feature = "otel")]
+    pub fn record_on_span(&self, span: &mut crate::observability::otel::types::Span) {
+        span.events.extend(self.violation_events());
+    }
+}
+
 /// Guard constraint validator
+#[derive(Debug, Clone)]
 pub struct GuardValidator {
     max_run_len: usize,
     max_batch_size: usize,
+    counters: Option<Arc<GuardCounters>>,
 }
 
 impl Default for GuardValidator {
@@ -69,13 +365,76 @@ impl GuardValidator {
     /// Create a new guard validator with default constraints
     #[must_use]
     pub const fn new() -> Self {
-        Self { max_run_len: MAX_RUN_LEN, max_batch_size: MAX_BATCH_SIZE }
+        Self { max_run_len: MAX_RUN_LEN, max_batch_size: MAX_BATCH_SIZE, counters: None }
     }
 
     /// Create a guard validator with custom constraints
     #[must_use]
     pub const fn with_constraints(max_run_len: usize, max_batch_size: usize) -> Self {
-        Self { max_run_len, max_batch_size }
+        Self { max_run_len, max_batch_size, counters: None }
+    }
+
+    /// Create a guard validator with the limits of a named profile
+    ///
+    /// Profiles are looked up in [`GuardProfiles::configured`]: the built-in profiles
+    /// plus any overrides and additions in `chicago-tdd-tools.toml`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GuardConstraintError::UnknownProfile`] if no profile has that name.
+    pub fn for_profile(name: &str) -> GuardConstraintResult<Self> {
+        GuardProfiles::configured().require(name).map(Self::from_profile)
+    }
+
+    /// Create a guard validator with a profile's limits
+    #[must_use]
+    pub const fn from_profile(profile: &GuardProfile) -> Self {
+        Self::with_constraints(profile.max_run_len, profile.max_batch_size)
+    }
+
+    /// Create a guard validator with the compile-time limits of profile `P`
+    #[must_use]
+    pub const fn for_limits<P: GuardLimits>() -> Self {
+        Self::with_constraints(P::MAX_RUN_LEN, P::MAX_BATCH_SIZE)
+    }
+
+    /// Count every validation (and violation) in `counters`
+    #[must_use]
+    pub fn with_counters(mut self, counters: Arc<GuardCounters>) -> Self {
+        self.counters = Some(counters);
+        self
+    }
+
+    /// Counters attached with [`GuardValidator::with_counters`], if any
+    #[must_use]
+    pub const fn counters(&self) -> Option<&Arc<GuardCounters>> {
+        self.counters.as_ref()
+    }
+
+    /// Maximum run length this validator allows
+    #[must_use]
+    pub const fn max_run_len(&self) -> usize {
+        self.max_run_len
+    }
+
+    fn record(
+        &self,
+        constraint: GuardConstraint,
+        value: usize,
+        limit: usize,
+    ) -> GuardConstraintResult<()> {
+        if let Some(counters) = &self.counters {
+            counters.record(constraint, value, limit);
+        }
+        if value <= limit {
+            return Ok(());
+        }
+        Err(match constraint {
+            GuardConstraint::MaxRunLen => GuardConstraintError::MaxRunLengthExceeded(value, limit),
+            GuardConstraint::MaxBatchSize => {
+                GuardConstraintError::MaxBatchSizeExceeded(value, limit)
+            }
+        })
     }
 
     /// Validate run length at ingress
@@ -99,11 +458,8 @@ impl GuardValidator {
     /// # Errors
     ///
     /// Returns an error if run length exceeds maximum allowed length.
-    pub const fn validate_run_len(&self, len: usize) -> GuardConstraintResult<()> {
-        if len > self.max_run_len {
-            return Err(GuardConstraintError::MaxRunLengthExceeded(len, self.max_run_len));
-        }
-        Ok(())
+    pub fn validate_run_len(&self, len: usize) -> GuardConstraintResult<()> {
+        self.record(GuardConstraint::MaxRunLen, len, self.max_run_len)
     }
 
     /// Validate batch size at ingress
@@ -126,11 +482,8 @@ impl GuardValidator {
     /// # Errors
     ///
     /// Returns an error if batch size exceeds maximum allowed size.
-    pub const fn validate_batch_size(&self, size: usize) -> GuardConstraintResult<()> {
-        if size > self.max_batch_size {
-            return Err(GuardConstraintError::MaxBatchSizeExceeded(size, self.max_batch_size));
-        }
-        Ok(())
+    pub fn validate_batch_size(&self, size: usize) -> GuardConstraintResult<()> {
+        self.record(GuardConstraint::MaxBatchSize, size, self.max_batch_size)
     }
 
     /// Validate run length for a slice/array
@@ -140,7 +493,7 @@ impl GuardValidator {
     /// # Errors
     ///
     /// Returns an error if run length exceeds maximum allowed length.
-    pub const fn validate_run<T>(&self, items: &[T]) -> GuardConstraintResult<()> {
+    pub fn validate_run<T>(&self, items: &[T]) -> GuardConstraintResult<()> {
         self.validate_run_len(items.len())
     }
 
@@ -151,7 +504,7 @@ impl GuardValidator {
     /// # Errors
     ///
     /// Returns an error if batch size exceeds maximum allowed size.
-    pub const fn validate_batch<T>(&self, items: &[T]) -> GuardConstraintResult<()> {
+    pub fn validate_batch<T>(&self, items: &[T]) -> GuardConstraintResult<()> {
         self.validate_batch_size(items.len())
     }
 }
@@ -204,6 +557,10 @@ pub fn assert_guard_batch_size<T>(items: &[T]) {
 pub mod validated;
 pub use validated::{AssertBatchSize, AssertRunLen, ValidatedBatch, ValidatedRun};
 
+// Named guard profiles (runtime and compile-time)
+pub mod profile;
+pub use profile::{GuardLimits, GuardProfile, GuardProfiles, ProfiledBatch, ProfiledRun};
+
 #[cfg(test)]
 #[allow(clippy::panic)] // Test code - panic is appropriate for test failures
 mod tests {
@@ -285,6 +642,121 @@ mod tests {
         assert_guard_batch_size(&invalid_batch); // Should panic
     }
 
+    #[test]
+    fn test_counters_record_validations_and_violations_by_constraint() {
+        let counters = Arc::new(GuardCounters::new());
+        let validator = GuardValidator::new().with_counters(Arc::clone(&counters));
+        let cloned = validator.clone();
+
+        let _ = validator.validate_run(&[0; 3]);
+        let _ = validator.validate_run_len(9);
+        let _ = cloned.validate_batch_size(1001);
+        let _ = cloned.validate_batch(&[0; 10]);
+
+        assert_eq!(counters.validations(), 4);
+        assert_eq!(counters.violations(GuardConstraint::MaxRunLen), 1);
+        assert_eq!(counters.violations(GuardConstraint::MaxBatchSize), 1);
+        assert_eq!(counters.total_violations(), 2);
+
+        counters.reset();
+        assert_eq!(counters.validations(), 0);
+        assert_eq!(counters.total_violations(), 0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)] // Exact ratios of small integers
+    fn test_counters_track_peak_utilization_and_log_violations() {
+        let counters = Arc::new(GuardCounters::new());
+        let validator = GuardValidator::new().with_counters(Arc::clone(&counters));
+
+        let _ = validator.validate_run_len(6);
+        let _ = validator.validate_run_len(4);
+        let _ = validator.validate_batch_size(1500);
+
+        assert_eq!(counters.peak_utilization(GuardConstraint::MaxRunLen), 0.75);
+        assert_eq!(counters.peak_utilization(GuardConstraint::MaxBatchSize), 1.5);
+        let violations = counters.recorded_violations();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(
+            (violations[0].constraint, violations[0].value, violations[0].limit),
+            (GuardConstraint::MaxBatchSize, 1500, 1000)
+        );
+
+        counters.reset();
+        assert_eq!(counters.peak_utilization(GuardConstraint::MaxBatchSize), 0.0);
+        assert!(counters.recorded_violations().is_empty());
+    }
+
+    #[test]
+    fn test_uninstrumented_validator_has_no_counters() {
+        let validator = GuardValidator::new();
+        assert!(validator.validate_run_len(9).is_err());
+        assert!(validator.counters().is_none());
+    }
+
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_counters_export_otel_metrics() {
+        use crate::observability::otel::types::MetricValue;
+
+        let counters = GuardCounters::new();
+        counters.record(GuardConstraint::MaxBatchSize, 1001, 1000);
+        counters.record(GuardConstraint::MaxRunLen, 3, 8);
+
+        let metrics = counters.to_metrics();
+
+        let counts: Vec<_> = metrics
+            .iter()
+            .map(|metric| {
+                let MetricValue::Counter(count) = metric.value else {
+                    panic!("guard metrics should be counters: {metric:?}");
+                };
+                (metric.name.as_str(), metric.attributes.get("guard.constraint").cloned(), count)
+            })
+            .collect();
+        assert_eq!(
+            counts,
+            [
+                (GUARD_VALIDATIONS_METRIC, None, 2),
+                (GUARD_VIOLATIONS_METRIC, Some("max_run_len".to_string()), 0),
+                (GUARD_VIOLATIONS_METRIC, Some("max_batch_size".to_string()), 1),
+            ]
+        );
+    }
+
+    #[cfg(feature = "otel")]
+    #[test]
+    fn test_violations_export_as_otel_events_and_utilization_gauges() {
+        use crate::observability::otel::test_helpers::create_test_span;
+        use crate::observability::otel::types::MetricValue;
+
+        let counters = Arc::new(GuardCounters::new());
+        let validator = GuardValidator::new().with_counters(Arc::clone(&counters));
+        let _ = validator.validate_run_len(4);
+        let _ = validator.validate_run_len(12);
+        let mut span = create_test_span("ingest");
+
+        counters.record_on_span(&mut span);
+        let gauges: Vec<_> = counters
+            .utilization_metrics()
+            .into_iter()
+            .map(|metric| {
+                let MetricValue::Gauge(value) = metric.value else {
+                    panic!("utilization metrics should be gauges: {metric:?}");
+                };
+                (metric.attributes["guard.constraint"].clone(), value)
+            })
+            .collect();
+
+        assert_eq!(span.events.len(), 1);
+        let event = &span.events[0];
+        assert_eq!(event.name, GUARD_VIOLATION_EVENT);
+        assert_eq!(event.attributes["guard.constraint"], "max_run_len");
+        assert_eq!(event.attributes["guard.value"], "12");
+        assert_eq!(event.attributes["guard.limit"], "8");
+        assert_eq!(gauges, [("max_run_len".to_string(), 1.5), ("max_batch_size".to_string(), 0.0)]);
+    }
+
     // ========================================================================
     // Error Path Tests (80% of bugs are in error paths)
     // ========================================================================
@@ -296,6 +768,7 @@ mod tests {
             GuardConstraintError::MaxRunLengthExceeded(9, 8),
             GuardConstraintError::MaxBatchSizeExceeded(1500, 1000),
             GuardConstraintError::InvalidConstraintValue("test".to_string()),
+            GuardConstraintError::UnknownProfile("test".to_string(), "default".to_string()),
         ];
 
         for error in errors {
@@ -308,7 +781,8 @@ mod tests {
                 || display.contains("maximum")
                 || display.contains("Invalid")
                 || display.contains("constraint")
-                || display.contains("Chatman");
+                || display.contains("Chatman")
+                || display.contains("profile");
             assert!(is_descriptive, "Error message should be descriptive: {display}");
         }
     }
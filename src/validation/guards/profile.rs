@@ -0,0 +1,488 @@
+//! Guard Profiles
+//!
+//! Named constraint sets for workloads whose limits differ: a hot path allows only small
+//! batches, batch ingest allows large ones, and streaming allows long runs of small
+//! batches. Profiles come in two forms:
+//!
+//! - **Runtime**: [`GuardProfile`] values, looked up by name from [`GuardProfiles`]. The
+//!   built-in profiles can be overridden, and new ones added, in `chicago-tdd-tools.toml`;
+//!   [`GuardValidator::for_profile`](super::GuardValidator::for_profile) uses the configured set.
+//! - **Compile-time**: marker types implementing [`GuardLimits`], generated with
+//!   [`guard_profile!`](crate::guard_profile). [`ProfiledRun`] and [`ProfiledBatch`] check
+//!   their const-generic length against the profile when the code is compiled. Marker
+//!   types carry the limits written in the source; config overrides do not apply to them.
+//!
+//! # Configuration
+//!
+//! ```toml
+//! [guards]
+//! max_run_len = 8          # the `default` profile
+//! max_batch_size = 1000
+//!
+//! [guards.profiles.streaming]
+//! max_run_len = 128        # unset limits keep the built-in (or default) value
+//!
+//! [guards.profiles.telemetry]
+//! max_run_len = 32
+//! max_batch_size = 500
+//! ```
+//!
+//! # Examples
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use chicago_tdd_tools::guards::profile::{GuardProfiles, ProfiledBatch, StreamingGuards};
+//! use chicago_tdd_tools::guards::GuardValidator;
+//!
+//! // Runtime: limits come from chicago-tdd-tools.toml, falling back to the built-ins
+//! let validator = GuardValidator::for_profile("streaming")?;
+//! validator.validate_batch_size(16)?;
+//! assert!(GuardProfiles::builtin().get("batch-ingest").is_some());
+//!
+//! // Compile-time: SIZE is checked against StreamingGuards::MAX_BATCH_SIZE
+//! let batch = ProfiledBatch::<StreamingGuards, 4>::new(vec![0; 4])?;
+//! assert_eq!(batch.len(), 4);
+//! # Ok(())
+//! # }
+//! ```
+
+use super::{GuardConstraintError, GuardConstraintResult, MAX_BATCH_SIZE, MAX_RUN_LEN};
+use crate::core::const_assert::Validated;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+use std::sync::OnceLock;
+
+/// Limits of a guard profile, known at compile time
+///
+/// Implement with [`guard_profile!`](crate::guard_profile).
+pub trait GuardLimits {
+    /// Profile name (as used by [`GuardProfiles::get`])
+    const NAME: &'static str;
+    /// Maximum run length
+    const MAX_RUN_LEN: usize;
+    /// Maximum batch size
+    const MAX_BATCH_SIZE: usize;
+}
+
+/// Define a compile-time guard profile: a marker type implementing [`GuardLimits`]
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::guard_profile;
+/// use chicago_tdd_tools::guards::profile::{GuardLimits, GuardProfile, ProfiledRun};
+///
+/// guard_profile!(
+///     /// Telemetry export batches
+///     pub TelemetryGuards = "telemetry" { max_run_len: 32, max_batch_size: 500 }
+/// );
+///
+/// assert_eq!(TelemetryGuards::MAX_RUN_LEN, 32);
+/// assert_eq!(GuardProfile::of::<TelemetryGuards>().name, "telemetry");
+/// let run = ProfiledRun::<TelemetryGuards, 20>::new(vec![0; 20]).unwrap();
+/// assert_eq!(run.len(), 20);
+/// ```
+#[macro_export]
+macro_rules! guard_profile {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $ty:ident = $name:literal {
+            max_run_len: $max_run_len:expr,
+            max_batch_size: $max_batch_size:expr $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+        $vis struct $ty;
+
+        impl $crate::validation::guards::profile::GuardLimits for $ty {
+            const NAME: &'static str = $name;
+            const MAX_RUN_LEN: usize = $max_run_len;
+            const MAX_BATCH_SIZE: usize = $max_batch_size;
+        }
+    };
+}
+
+crate::guard_profile!(
+    /// `default`: the Chatman Constant run length and the standard batch size
+    pub DefaultGuards = "default" { max_run_len: MAX_RUN_LEN, max_batch_size: MAX_BATCH_SIZE }
+);
+
+crate::guard_profile!(
+    /// `hot-path`: Chatman Constant runs in small batches that fit the tick budget
+    pub HotPathGuards = "hot-path" { max_run_len: MAX_RUN_LEN, max_batch_size: 64 }
+);
+
+crate::guard_profile!(
+    /// `batch-ingest`: Chatman Constant runs in large bulk-load batches
+    pub BatchIngestGuards = "batch-ingest" { max_run_len: MAX_RUN_LEN, max_batch_size: 100_000 }
+);
+
+crate::guard_profile!(
+    /// `streaming`: long runs of events delivered in small micro-batches
+    pub StreamingGuards = "streaming" { max_run_len: 64, max_batch_size: 16 }
+);
+
+/// Named guard constraint set
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardProfile {
+    /// Profile name
+    pub name: String,
+    /// Maximum run length
+    pub max_run_len: usize,
+    /// Maximum batch size
+    pub max_batch_size: usize,
+}
+
+impl GuardProfile {
+    /// Create a profile
+    #[must_use]
+    pub fn new(name: impl Into<String>, max_run_len: usize, max_batch_size: usize) -> Self {
+        Self { name: name.into(), max_run_len, max_batch_size }
+    }
+
+    /// The runtime form of a compile-time profile
+    #[must_use]
+    pub fn of<P: GuardLimits>() -> Self {
+        Self::new(P::NAME, P::MAX_RUN_LEN, P::MAX_BATCH_SIZE)
+    }
+}
+
+/// Set of named guard profiles
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardProfiles {
+    /// Built-in profiles first, then configured ones in name order
+    profiles: Vec<GuardProfile>,
+}
+
+impl Default for GuardProfiles {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl GuardProfiles {
+    /// The built-in profiles: `default`, `hot-path`, `batch-ingest`, and `streaming`
+    #[must_use]
+    pub fn builtin() -> Self {
+        Self {
+            profiles: vec![
+                GuardProfile::of::<DefaultGuards>(),
+                GuardProfile::of::<HotPathGuards>(),
+                GuardProfile::of::<BatchIngestGuards>(),
+                GuardProfile::of::<StreamingGuards>(),
+            ],
+        }
+    }
+
+    /// The profiles configured in `chicago-tdd-tools.toml`, loaded once per process
+    ///
+    /// An invalid `[guards]` section is logged and the built-in profiles are used.
+    #[must_use]
+    pub fn configured() -> &'static Self {
+        static CONFIGURED: OnceLock<GuardProfiles> = OnceLock::new();
+        CONFIGURED.get_or_init(crate::core::config::loading::guard_profiles)
+    }
+
+    /// Parse the `[guards]` section of a `chicago-tdd-tools.toml` document
+    ///
+    /// `max_run_len` / `max_batch_size` directly under `[guards]` override the `default`
+    /// profile. Each `[guards.profiles.<name>]` table overrides a built-in profile or
+    /// adds a new one; limits it leaves unset keep the built-in value, or the `default`
+    /// profile's value for new profiles.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GuardConstraintError::InvalidConstraintValue`] if the document is not
+    /// valid TOML, the section has unknown keys, or a limit is 0.
+    pub fn from_config_str(text: &str) -> GuardConstraintResult<Self> {
+        let invalid = |e: &dyn std::fmt::Display| {
+            GuardConstraintError::InvalidConstraintValue(format!("[guards]: {e}"))
+        };
+        let document: toml::Table = text.parse().map_err(|e| invalid(&e))?;
+        let Some(section) = document.get("guards") else {
+            return Ok(Self::builtin());
+        };
+        let config: GuardsConfig = section.clone().try_into().map_err(|e| invalid(&e))?;
+
+        let default_limits =
+            LimitsConfig { max_run_len: config.max_run_len, max_batch_size: config.max_batch_size };
+        let mut profiles = Self::builtin();
+        let sections = config.profiles.iter().map(|(name, limits)| (name.as_str(), limits));
+        for (name, limits) in std::iter::once(("default", &default_limits)).chain(sections) {
+            let mut profile = profiles.get(name).cloned().unwrap_or_else(|| {
+                let default = profiles
+                    .get("default")
+                    .cloned()
+                    .unwrap_or_else(GuardProfile::of::<DefaultGuards>);
+                GuardProfile { name: name.to_string(), ..default }
+            });
+            limits.apply_to(&mut profile)?;
+            match profiles.profiles.iter_mut().find(|p| p.name == name) {
+                Some(slot) => *slot = profile,
+                None => profiles.profiles.push(profile),
+            }
+        }
+        Ok(profiles)
+    }
+
+    /// The profile with the given name
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&GuardProfile> {
+        self.profiles.iter().find(|p| p.name == name)
+    }
+
+    /// The profile with the given name, or an error listing the known names
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GuardConstraintError::UnknownProfile`] if no profile has that name.
+    pub fn require(&self, name: &str) -> GuardConstraintResult<&GuardProfile> {
+        self.get(name).ok_or_else(|| {
+            GuardConstraintError::UnknownProfile(name.to_string(), self.names().join(", "))
+        })
+    }
+
+    /// Profile names, built-ins first
+    #[must_use]
+    pub fn names(&self) -> Vec<&str> {
+        self.profiles.iter().map(|p| p.name.as_str()).collect()
+    }
+
+    /// Iterate over the profiles
+    pub fn iter(&self) -> impl Iterator<Item = &GuardProfile> {
+        self.profiles.iter()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GuardsConfig {
+    max_run_len: Option<usize>,
+    max_batch_size: Option<usize>,
+    #[serde(default)]
+    profiles: BTreeMap<String, LimitsConfig>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LimitsConfig {
+    max_run_len: Option<usize>,
+    max_batch_size: Option<usize>,
+}
+
+impl LimitsConfig {
+    fn apply_to(&self, profile: &mut GuardProfile) -> GuardConstraintResult<()> {
+        for (key, value, slot) in [
+            ("max_run_len", self.max_run_len, &mut profile.max_run_len),
+            ("max_batch_size", self.max_batch_size, &mut profile.max_batch_size),
+        ] {
+            match value {
+                Some(0) => {
+                    return Err(GuardConstraintError::InvalidConstraintValue(format!(
+                        "[guards] profile '{}': {key} must be > 0",
+                        profile.name
+                    )))
+                }
+                Some(value) => *slot = value,
+                None => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Poka-Yoke: Compile-Time Validated Types per Profile
+// ============================================================================
+
+/// Run whose length is checked against profile `P` at compile time
+///
+/// `ProfiledRun::<P, LEN>::new` fails to compile when `LEN > P::MAX_RUN_LEN`:
+///
+/// ```rust,compile_fail
+/// use chicago_tdd_tools::guards::profile::{HotPathGuards, ProfiledRun};
+///
+/// // 9 exceeds HotPathGuards::MAX_RUN_LEN (8) - compile error!
+/// let run = ProfiledRun::<HotPathGuards, 9>::new(vec![0; 9]);
+/// ```
+pub struct ProfiledRun<P: GuardLimits, const LEN: usize> {
+    inner: Validated<Vec<u8>>,
+    _profile: PhantomData<P>,
+}
+
+impl<P: GuardLimits, const LEN: usize> ProfiledRun<P, LEN> {
+    const WITHIN_PROFILE: () =
+        assert!(LEN <= P::MAX_RUN_LEN, "run length exceeds the guard profile's max_run_len");
+
+    /// Create a run, checking the data length matches `LEN`
+    ///
+    /// # Errors
+    ///
+    /// Returns `GuardConstraintError::InvalidConstraintValue` if the data length
+    /// doesn't match the const generic LEN.
+    pub fn new(data: Vec<u8>) -> GuardConstraintResult<Self> {
+        let () = Self::WITHIN_PROFILE;
+        if data.len() != LEN {
+            return Err(GuardConstraintError::InvalidConstraintValue(format!(
+                "Data length {} doesn't match const generic LEN {LEN} (profile '{}')",
+                data.len(),
+                P::NAME
+            )));
+        }
+        Ok(Self { inner: Validated::new(data), _profile: PhantomData })
+    }
+
+    /// Get the run length (always `LEN`)
+    #[must_use]
+    #[allow(clippy::unused_self)] // Required for trait consistency - const fn needs self
+    #[allow(clippy::len_without_is_empty)] // Compile-time validated - length is always LEN
+    pub const fn len(&self) -> usize {
+        LEN
+    }
+
+    /// Get a reference to the run data
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        self.inner.as_ref()
+    }
+
+    /// Consume the run and return the data
+    #[must_use]
+    pub fn into_data(self) -> Vec<u8> {
+        self.inner.into_inner()
+    }
+}
+
+/// Batch whose size is checked against profile `P` at compile time
+///
+/// `ProfiledBatch::<P, SIZE>::new` fails to compile when `SIZE > P::MAX_BATCH_SIZE`:
+///
+/// ```rust,compile_fail
+/// use chicago_tdd_tools::guards::profile::{ProfiledBatch, StreamingGuards};
+///
+/// // 17 exceeds StreamingGuards::MAX_BATCH_SIZE (16) - compile error!
+/// let batch = ProfiledBatch::<StreamingGuards, 17>::new(vec![0; 17]);
+/// ```
+pub struct ProfiledBatch<P: GuardLimits, const SIZE: usize> {
+    inner: Validated<Vec<u8>>,
+    _profile: PhantomData<P>,
+}
+
+impl<P: GuardLimits, const SIZE: usize> ProfiledBatch<P, SIZE> {
+    const WITHIN_PROFILE: () =
+        assert!(SIZE <= P::MAX_BATCH_SIZE, "batch size exceeds the guard profile's max_batch_size");
+
+    /// Create a batch, checking the data length matches `SIZE`
+    ///
+    /// # Errors
+    ///
+    /// Returns `GuardConstraintError::InvalidConstraintValue` if the data length
+    /// doesn't match the const generic SIZE.
+    pub fn new(data: Vec<u8>) -> GuardConstraintResult<Self> {
+        let () = Self::WITHIN_PROFILE;
+        if data.len() != SIZE {
+            return Err(GuardConstraintError::InvalidConstraintValue(format!(
+                "Data length {} doesn't match const generic SIZE {SIZE} (profile '{}')",
+                data.len(),
+                P::NAME
+            )));
+        }
+        Ok(Self { inner: Validated::new(data), _profile: PhantomData })
+    }
+
+    /// Get the batch size (always `SIZE`)
+    #[must_use]
+    #[allow(clippy::unused_self)] // Required for trait consistency - const fn needs self
+    #[allow(clippy::len_without_is_empty)] // Compile-time validated - size is always SIZE
+    pub const fn len(&self) -> usize {
+        SIZE
+    }
+
+    /// Get a reference to the batch data
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        self.inner.as_ref()
+    }
+
+    /// Consume the batch and return the data
+    #[must_use]
+    pub fn into_data(self) -> Vec<u8> {
+        self.inner.into_inner()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)] // Test code - unwrap is acceptable
+mod tests {
+    use super::*;
+    use crate::validation::guards::GuardValidator;
+
+    #[test]
+    fn test_builtin_profiles_match_marker_types() {
+        let profiles = GuardProfiles::builtin();
+
+        assert_eq!(profiles.names(), ["default", "hot-path", "batch-ingest", "streaming"]);
+        assert_eq!(profiles.get("streaming"), Some(&GuardProfile::of::<StreamingGuards>()));
+        assert_eq!(profiles.get("default").unwrap().max_run_len, MAX_RUN_LEN);
+        let shipped = include_str!("../../../chicago-tdd-tools.toml");
+        assert_eq!(GuardProfiles::from_config_str(shipped).unwrap(), profiles);
+    }
+
+    #[test]
+    fn test_config_overrides_and_adds_profiles() {
+        let config = r"
+            [guards]
+            max_run_len = 10
+            max_batch_size = 2000
+
+            [guards.profiles.streaming]
+            max_run_len = 128
+
+            [guards.profiles.telemetry]
+            max_batch_size = 500
+        ";
+
+        let profiles = GuardProfiles::from_config_str(config).unwrap();
+
+        assert_eq!(profiles.get("default"), Some(&GuardProfile::new("default", 10, 2000)));
+        assert_eq!(profiles.get("streaming"), Some(&GuardProfile::new("streaming", 128, 16)));
+        assert_eq!(profiles.get("telemetry"), Some(&GuardProfile::new("telemetry", 10, 500)));
+        assert_eq!(profiles.get("hot-path"), Some(&GuardProfile::of::<HotPathGuards>()));
+    }
+
+    #[test]
+    fn test_invalid_config_is_rejected() {
+        let zero = "[guards.profiles.streaming]\nmax_batch_size = 0";
+        let unknown_key = "[guards.profiles.streaming]\nmax_run = 4";
+
+        let zero = GuardProfiles::from_config_str(zero).unwrap_err();
+        let unknown_key = GuardProfiles::from_config_str(unknown_key).unwrap_err();
+
+        assert!(zero.to_string().contains("profile 'streaming': max_batch_size must be > 0"));
+        assert!(unknown_key.to_string().contains("max_run"), "{unknown_key}");
+    }
+
+    #[test]
+    fn test_validator_for_profile_uses_profile_limits() {
+        let streaming = GuardValidator::for_profile("streaming").unwrap();
+        let hot_path = GuardValidator::for_limits::<HotPathGuards>();
+
+        assert!(streaming.validate_run_len(64).is_ok());
+        assert!(streaming.validate_batch_size(17).is_err());
+        assert!(hot_path.validate_batch_size(65).is_err());
+        let unknown = GuardValidator::for_profile("nope").unwrap_err();
+        assert!(unknown.to_string().contains("known: default, hot-path"), "{unknown}");
+    }
+
+    #[test]
+    fn test_profiled_types_check_length() {
+        let run = ProfiledRun::<StreamingGuards, 64>::new(vec![0; 64]).unwrap();
+        let batch = ProfiledBatch::<BatchIngestGuards, 5000>::new(vec![0; 5000]).unwrap();
+
+        assert_eq!((run.len(), batch.len()), (64, 5000));
+        assert_eq!(run.into_data().len(), 64);
+        assert!(ProfiledRun::<HotPathGuards, 4>::new(vec![0; 3]).is_err());
+    }
+}
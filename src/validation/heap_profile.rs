@@ -0,0 +1,322 @@
+//! Heap Profiling for Performance Tests
+//!
+//! Allocation assertions backed by [DHAT](https://docs.rs/dhat). A bare "expected at
+//! most 10 allocations, got 4 812" is not actionable; when a [`HeapProfile`] assertion
+//! fails, the panic message lists the allocation hotspots (call sites ranked by
+//! allocation count) and the full DHAT profile is saved as a failure artifact that
+//! opens in the DHAT viewer (`dh_view.html`).
+//!
+//! **Required feature**: `heap-profiling`
+//!
+//! # Setup
+//!
+//! DHAT counts allocations through the global allocator, for every thread in the
+//! process. Put heap tests in their own integration test file so other tests do not
+//! allocate concurrently, and install the allocator there:
+//!
+//! ```rust,ignore
+//! // tests/heap.rs
+//! use chicago_tdd_tools::performance_test;
+//! use chicago_tdd_tools::validation::heap_profile::HeapProfilingAlloc;
+//!
+//! #[global_allocator]
+//! static ALLOC: HeapProfilingAlloc = HeapProfilingAlloc;
+//!
+//! performance_test!(test_parse_allocates_little, allocations <= 8, {
+//!     let order = parse_order(r#"{"id":7}"#);
+//!     assert_eq!(order.id, 7);
+//! });
+//! ```
+//!
+//! Profiles are serialized process-wide, so heap tests in the same binary never overlap
+//! each other. Artifacts go to `heap/<test>.json` under the artifacts directory resolved by
+//! `ProjectLayout` (`<target dir>/chicago-tdd`, or `$CHICAGO_TDD_ARTIFACTS_DIR` when set),
+//! and are only kept when an assertion fails.
+
+use crate::core::layout::ProjectLayout;
+use serde::Deserialize;
+use std::fmt::{self, Write as _};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// Global allocator that records allocations while a [`HeapProfile`] is running
+pub use dhat::Alloc as HeapProfilingAlloc;
+
+/// Environment variable overriding the failure artifact root
+pub use crate::core::layout::ARTIFACTS_DIR_ENV_VAR;
+
+/// Hotspots listed in failure messages
+const HOTSPOTS_IN_REPORT: usize = 5;
+
+/// Frame prefixes that are allocator plumbing rather than the caller
+const PLUMBING_FRAMES: &[&str] = &[
+    "alloc::", "<alloc::", "core::", "<core::", "std::", "<std::", "dhat::", "<dhat::", "__rust",
+    "[root]",
+];
+
+/// Source locations of standard library frames (generic impls defined there)
+const PLUMBING_SOURCES: &[&str] = &["(alloc/src/", "(core/src/", "(std/src/", "/library/"];
+
+/// Frames of this module (the failure message itself allocates)
+const OWN_FRAMES: &str = "chicago_tdd_tools::validation::heap_profile::";
+
+/// DHAT allows one profiler per process
+static PROFILE_LOCK: Mutex<()> = Mutex::new(());
+
+/// Allocation totals for a profiled region
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HeapUsage {
+    /// Allocations made
+    pub allocations: u64,
+    /// Bytes allocated in total
+    pub bytes: u64,
+    /// Peak bytes live at once
+    pub peak_bytes: usize,
+    /// Bytes still live
+    pub live_bytes: usize,
+}
+
+impl fmt::Display for HeapUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocation(s), {} byte(s) total, {} byte(s) peak, {} byte(s) live",
+            self.allocations, self.bytes, self.peak_bytes, self.live_bytes
+        )
+    }
+}
+
+/// One allocation call site from a DHAT profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllocationHotspot {
+    /// First non-allocator frame, e.g. `my_crate::index::build (src/index.rs:42:9)`
+    pub site: String,
+    /// Allocations made at this site
+    pub allocations: u64,
+    /// Bytes allocated at this site
+    pub bytes: u64,
+}
+
+/// A running heap profile for one test
+///
+/// Dropping the profile without asserting discards it.
+#[derive(Debug)]
+pub struct HeapProfile {
+    name: String,
+    artifact: PathBuf,
+    profiler: Option<dhat::Profiler>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+impl HeapProfile {
+    /// Start profiling (waits for any other running profile to finish)
+    #[must_use]
+    pub fn start(name: &str) -> Self {
+        let lock = PROFILE_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+        let artifact = artifacts_dir().join("heap").join(format!("{}.json", sanitize(name)));
+        if let Some(parent) = artifact.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let profiler = dhat::Profiler::builder().file_name(&artifact).build();
+        Self { name: name.to_string(), artifact, profiler: Some(profiler), _lock: lock }
+    }
+
+    /// Totals since [`HeapProfile::start`]
+    ///
+    /// All zeros unless [`HeapProfilingAlloc`] is the global allocator.
+    #[must_use]
+    #[allow(clippy::unused_self)] // DHAT stats are global; `&self` proves a profile is running
+    pub fn usage(&self) -> HeapUsage {
+        let stats = dhat::HeapStats::get();
+        HeapUsage {
+            allocations: stats.total_blocks,
+            bytes: stats.total_bytes,
+            peak_bytes: stats.max_bytes,
+            live_bytes: stats.curr_bytes,
+        }
+    }
+
+    /// Where the profile is saved if an assertion fails
+    #[must_use]
+    pub fn artifact_path(&self) -> &Path {
+        &self.artifact
+    }
+
+    /// Assert at most `max` allocations were made
+    ///
+    /// # Panics
+    ///
+    /// Panics with the hotspot report if the limit is exceeded.
+    pub fn assert_max_allocations(self, max: u64) {
+        let usage = self.usage();
+        if usage.allocations > max {
+            self.fail(&format!("expected at most {max} allocation(s)"), usage);
+        }
+    }
+
+    /// Assert at most `max` bytes were allocated in total
+    ///
+    /// # Panics
+    ///
+    /// Panics with the hotspot report if the limit is exceeded.
+    pub fn assert_max_bytes(self, max: u64) {
+        let usage = self.usage();
+        if usage.bytes > max {
+            self.fail(&format!("expected at most {max} byte(s) allocated"), usage);
+        }
+    }
+
+    /// Assert peak live heap stayed at or below `max` bytes
+    ///
+    /// # Panics
+    ///
+    /// Panics with the hotspot report if the limit is exceeded.
+    pub fn assert_max_peak_bytes(self, max: usize) {
+        let usage = self.usage();
+        if usage.peak_bytes > max {
+            self.fail(&format!("expected peak heap of at most {max} byte(s)"), usage);
+        }
+    }
+
+    /// Stop profiling, save the artifact, and panic with the hotspot report
+    #[allow(clippy::panic)] // Assertion helper: failing the test is the point
+    fn fail(mut self, expectation: &str, usage: HeapUsage) -> ! {
+        // Dropping the profiler writes the DHAT JSON file
+        drop(self.profiler.take());
+        let report = std::fs::read_to_string(&self.artifact)
+            .map_err(|e| e.to_string())
+            .and_then(|json| hotspots(&json))
+            .map_or_else(|e| format!("   (hotspots unavailable: {e})\n"), |spots| render(&spots));
+        panic!(
+            "🚨 Heap assertion failed in '{}': {expectation}, got {usage}\n\
+             {report}   📄 DHAT profile: {} (open in dh_view.html)",
+            self.name,
+            self.artifact.display()
+        );
+    }
+}
+
+impl Drop for HeapProfile {
+    fn drop(&mut self) {
+        // Passing (or abandoned) profiles leave no artifact behind
+        if self.profiler.take().is_some() {
+            let _ = std::fs::remove_file(&self.artifact);
+        }
+    }
+}
+
+fn artifacts_dir() -> PathBuf {
+    ProjectLayout::detect().artifacts_dir().to_path_buf()
+}
+
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '_' })
+        .collect()
+}
+
+fn render(spots: &[AllocationHotspot]) -> String {
+    let mut out = String::from("   Top allocation sites:\n");
+    for spot in spots.iter().take(HOTSPOTS_IN_REPORT) {
+        let _ =
+            writeln!(out, "   {:>6} alloc(s) {:>9} B  {}", spot.allocations, spot.bytes, spot.site);
+    }
+    out
+}
+
+#[derive(Deserialize)]
+struct DhatProfile {
+    pps: Vec<DhatProgramPoint>,
+    ftbl: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct DhatProgramPoint {
+    tb: u64,
+    tbk: u64,
+    fs: Vec<usize>,
+}
+
+/// Allocation hotspots of a DHAT JSON profile, most allocations first
+///
+/// Program points are grouped by their first frame outside the allocator and the
+/// standard library.
+///
+/// # Errors
+///
+/// Returns an error if `json` is not a DHAT heap profile.
+pub fn hotspots(json: &str) -> Result<Vec<AllocationHotspot>, String> {
+    let profile: DhatProfile = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let mut spots: Vec<AllocationHotspot> = Vec::new();
+    for pp in &profile.pps {
+        let frames = pp.fs.iter().filter_map(|&i| profile.ftbl.get(i)).map(|f| strip_address(f));
+        let mut frames = frames.peekable();
+        let first = frames.peek().map(ToString::to_string).unwrap_or_default();
+        let site = frames.find(|f| !is_plumbing(f)).map_or(first, ToString::to_string);
+        if site.contains(OWN_FRAMES) {
+            continue;
+        }
+        match spots.iter_mut().find(|s| s.site == site) {
+            Some(spot) => {
+                spot.allocations += pp.tbk;
+                spot.bytes += pp.tb;
+            }
+            None => spots.push(AllocationHotspot { site, allocations: pp.tbk, bytes: pp.tb }),
+        }
+    }
+    spots.sort_by(|a, b| b.allocations.cmp(&a.allocations).then(b.bytes.cmp(&a.bytes)));
+    Ok(spots)
+}
+
+fn is_plumbing(frame: &str) -> bool {
+    PLUMBING_FRAMES.iter().any(|p| frame.starts_with(p))
+        || PLUMBING_SOURCES.iter().any(|p| frame.contains(p))
+}
+
+/// `0x1234: path::to::fn (file.rs:1:2)` → `path::to::fn (file.rs:1:2)`
+fn strip_address(frame: &str) -> &str {
+    frame
+        .split_once(": ")
+        .filter(|(addr, _)| addr.starts_with("0x"))
+        .map_or(frame, |(_, rest)| rest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test;
+
+    test!(test_hotspots_group_by_first_caller_frame, {
+        // Arrange
+        let json = r#"{
+            "dhatFileVersion": 2,
+            "pps": [
+                { "tb": 64, "tbk": 4, "fs": [1, 2, 3] },
+                { "tb": 32, "tbk": 1, "fs": [1, 4] },
+                { "tb": 16, "tbk": 2, "fs": [1, 6, 2, 5] },
+                { "tb": 62, "tbk": 1, "fs": [1, 7] }
+            ],
+            "ftbl": [
+                "[root]",
+                "0x1: alloc::alloc::alloc (alloc/src/alloc.rs:95:14)",
+                "0x2: my_app::index::build (src/index.rs:42:9)",
+                "0x3: my_app::main (src/main.rs:3:5)",
+                "0x4: my_app::config::load (src/config.rs:7:13)",
+                "0x5: my_app::cli::run (src/cli.rs:9:1)",
+                "0x6: <u8 as ConvertVec>::to_vec (alloc/src/slice.rs:448:29)",
+                "0x7: <chicago_tdd_tools::validation::heap_profile::HeapProfile>::fail (src/x.rs:1:1)"
+            ]
+        }"#;
+
+        // Act
+        let spots = hotspots(json).unwrap();
+
+        // Assert
+        assert_eq!(spots.len(), 2);
+        assert_eq!(spots[0].site, "my_app::index::build (src/index.rs:42:9)");
+        assert_eq!((spots[0].allocations, spots[0].bytes), (6, 80));
+        assert_eq!(spots[1].site, "my_app::config::load (src/config.rs:7:13)");
+        assert!(render(&spots).contains("     6 alloc(s)        80 B  my_app::index::build"));
+        assert!(hotspots("{}").is_err());
+    });
+}
@@ -64,8 +64,49 @@
 //! let results = validator.validate_all();
 //! assert!(results.iter().all(|r| r.jtbd_success));
 //! ```
+//!
+//! ## Weighted Outcome Scoring
+//!
+//! A scenario can be attached to a job with weighted outcomes (partial credit) and
+//! acceptance criteria (must all hold, or the scenario scores 0%). [`JtbdValidator::score_all`]
+//! aggregates per-job scores into a [`JtbdReport`] that serializes to JSON or Markdown.
+//!
+//! ```rust
+//! use chicago_tdd_tools::jtbd::{ExecutionContext, ExecutionResult, JtbdScenario, JtbdValidator};
+//! use std::collections::HashMap;
+//!
+//! let mut validator = JtbdValidator::new();
+//! validator.register_scored_scenario(
+//!     JtbdScenario {
+//!         name: "Checkout".to_string(),
+//!         setup_context: Box::new(ExecutionContext::default),
+//!         execute: Box::new(|_ctx| {
+//!             let mut vars = HashMap::new();
+//!             vars.insert("order_id".to_string(), "ORD-001".to_string());
+//!             ExecutionResult::ok(vars)
+//!         }),
+//!         validate_result: Box::new(|_ctx, result| result.success),
+//!         expected_behavior: "Customer can buy the cart".to_string(),
+//!     }
+//!     .for_job("Buy products")
+//!     .acceptance("order id issued", |_ctx, r| r.variables.contains_key("order_id"))
+//!     .outcome("order persisted", 3.0, |_ctx, r| r.variables.contains_key("order_id"))
+//!     .outcome("receipt emailed", 1.0, |_ctx, r| r.variables.contains_key("receipt")),
+//! );
+//!
+//! let report = validator.score_all();
+//! assert_eq!(report.job("Buy products").unwrap().score, 75.0);
+//! assert!(report.to_markdown().contains("| Buy products | 75.0% | 1 |"));
+//! ```
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt::{self, Write as _};
+use thiserror::Error;
+
+#[cfg(feature = "jtbd-linkage")]
+#[doc(hidden)]
+pub use linkme as __linkme;
 
 // ============================================================================
 // Poka-Yoke: Type-Level Validation
@@ -266,10 +307,131 @@ pub struct JtbdScenario {
     pub expected_behavior: String,
 }
 
+impl JtbdScenario {
+    /// Attach this scenario to a job so it can declare weighted outcomes and acceptance criteria
+    #[must_use]
+    pub fn for_job(self, job: impl Into<String>) -> ScoredScenario {
+        ScoredScenario {
+            job: job.into(),
+            scenario: self,
+            outcomes: Vec::new(),
+            acceptance_criteria: Vec::new(),
+        }
+    }
+}
+
+/// Weighted outcome a scenario is expected to achieve (partial credit)
+pub struct JtbdOutcome {
+    /// Outcome name
+    pub name: String,
+    /// Relative weight (non-negative)
+    pub weight: f64,
+    /// Whether the outcome was achieved
+    pub check: ValidateResultFn,
+}
+
+/// Acceptance criterion a scenario must meet to score at all
+pub struct AcceptanceCriterion {
+    /// Criterion description
+    pub description: String,
+    /// Whether the criterion holds
+    pub check: ValidateResultFn,
+}
+
+/// JTBD scenario attached to a job, with weighted outcomes and acceptance criteria
+///
+/// Created with [`JtbdScenario::for_job`]. A scenario scores 0% if execution fails, its
+/// `validate_result` rejects the result, or any acceptance criterion fails; otherwise it
+/// scores the weighted share of achieved outcomes (100% when it declares none).
+pub struct ScoredScenario {
+    /// Job the scenario contributes to
+    pub job: String,
+    /// Underlying scenario
+    pub scenario: JtbdScenario,
+    /// Weighted outcomes
+    pub outcomes: Vec<JtbdOutcome>,
+    /// Acceptance criteria
+    pub acceptance_criteria: Vec<AcceptanceCriterion>,
+}
+
+impl ScoredScenario {
+    /// Add a weighted outcome (negative weights are treated as 0)
+    #[must_use]
+    pub fn outcome<F>(mut self, name: impl Into<String>, weight: f64, check: F) -> Self
+    where
+        F: Fn(&ExecutionContext, &ExecutionResult) -> bool + Send + Sync + 'static,
+    {
+        self.outcomes.push(JtbdOutcome {
+            name: name.into(),
+            weight: weight.max(0.0),
+            check: Box::new(check),
+        });
+        self
+    }
+
+    /// Add an acceptance criterion
+    #[must_use]
+    pub fn acceptance<F>(mut self, description: impl Into<String>, check: F) -> Self
+    where
+        F: Fn(&ExecutionContext, &ExecutionResult) -> bool + Send + Sync + 'static,
+    {
+        self.acceptance_criteria
+            .push(AcceptanceCriterion { description: description.into(), check: Box::new(check) });
+        self
+    }
+
+    /// Execute the scenario once and score it
+    #[must_use]
+    pub fn score(&self) -> ScenarioScore {
+        let context = (self.scenario.setup_context)();
+        let result = (self.scenario.execute)(&context);
+        let jtbd_success = (self.scenario.validate_result)(&context, &result);
+
+        let outcomes: Vec<OutcomeResult> = self
+            .outcomes
+            .iter()
+            .map(|o| OutcomeResult {
+                name: o.name.clone(),
+                weight: o.weight,
+                achieved: (o.check)(&context, &result),
+            })
+            .collect();
+        let criteria: Vec<CriterionResult> = self
+            .acceptance_criteria
+            .iter()
+            .map(|c| CriterionResult {
+                description: c.description.clone(),
+                met: (c.check)(&context, &result),
+            })
+            .collect();
+
+        let accepted = result.success && jtbd_success && criteria.iter().all(|c| c.met);
+        let total_weight: f64 = outcomes.iter().map(|o| o.weight).sum();
+        let score = if !accepted {
+            0.0
+        } else if total_weight > 0.0 {
+            let achieved: f64 = outcomes.iter().filter(|o| o.achieved).map(|o| o.weight).sum();
+            achieved / total_weight * 100.0
+        } else {
+            100.0
+        };
+
+        ScenarioScore {
+            scenario: self.scenario.name.clone(),
+            execution_success: result.success,
+            jtbd_success,
+            accepted,
+            score,
+            outcomes,
+            criteria,
+        }
+    }
+}
+
 /// JTBD validator
 pub struct JtbdValidator {
-    /// JTBD scenarios
-    scenarios: Vec<JtbdScenario>,
+    /// JTBD scenarios (plain scenarios form a job named after themselves)
+    scenarios: Vec<ScoredScenario>,
 }
 
 impl JtbdValidator {
@@ -281,7 +443,15 @@ impl JtbdValidator {
     }
 
     /// Register a JTBD scenario
+    ///
+    /// The scenario forms its own job (named after the scenario) with no weighted outcomes.
     pub fn register_scenario(&mut self, scenario: JtbdScenario) {
+        let job = scenario.name.clone();
+        self.scenarios.push(scenario.for_job(job));
+    }
+
+    /// Register a scenario with a job, weighted outcomes, and acceptance criteria
+    pub fn register_scored_scenario(&mut self, scenario: ScoredScenario) {
         self.scenarios.push(scenario);
     }
 
@@ -290,7 +460,7 @@ impl JtbdValidator {
     /// **Poka-Yoke**: Uses `ScenarioIndex` newtype to prevent index errors.
     #[must_use]
     pub fn validate_scenario(&self, index: ScenarioIndex) -> Option<JtbdValidationResult> {
-        let scenario = self.scenarios.get(index.get())?;
+        let scenario = &self.scenarios.get(index.get())?.scenario;
 
         // Setup execution context
         let context = (scenario.setup_context)();
@@ -374,6 +544,87 @@ impl JtbdValidator {
             },
         }
     }
+
+    /// Execute every scenario once and aggregate per-job scores
+    #[must_use]
+    pub fn score_all(&self) -> JtbdReport {
+        let mut jobs: Vec<JobScore> = Vec::new();
+        for scored in &self.scenarios {
+            let score = scored.score();
+            match jobs.iter_mut().find(|j| j.job == scored.job) {
+                Some(job) => job.scenarios.push(score),
+                None => jobs.push(JobScore {
+                    job: scored.job.clone(),
+                    score: 0.0,
+                    scenarios: vec![score],
+                }),
+            }
+        }
+        for job in &mut jobs {
+            job.score = mean(job.scenarios.iter().map(|s| s.score));
+        }
+        let score = mean(jobs.iter().map(|j| j.score));
+        JtbdReport { score, jobs }
+    }
+
+    /// Match registered scenarios against test links
+    ///
+    /// A link covers a scenario when both its job and scenario name match. Plain
+    /// scenarios (see [`JtbdValidator::register_scenario`]) form a job named after
+    /// themselves.
+    #[must_use]
+    pub fn test_coverage_with(&self, links: &[JtbdTestLink]) -> JtbdCoverage {
+        let mut coverage = JtbdCoverage::default();
+        for scored in &self.scenarios {
+            let (job, scenario) = (&scored.job, &scored.scenario.name);
+            if coverage.scenarios.iter().any(|c| &c.job == job && &c.scenario == scenario) {
+                continue;
+            }
+            let tests = links
+                .iter()
+                .filter(|link| link.job == job && link.scenario == scenario)
+                .map(|link| link.test.to_string())
+                .collect();
+            coverage.scenarios.push(ScenarioCoverage {
+                job: job.clone(),
+                scenario: scenario.clone(),
+                tests,
+            });
+        }
+        coverage.unmatched = links
+            .iter()
+            .filter(|link| {
+                !coverage
+                    .scenarios
+                    .iter()
+                    .any(|c| c.job == link.job && c.scenario == link.scenario)
+            })
+            .copied()
+            .collect();
+        coverage
+    }
+
+    /// Match registered scenarios against tests linked with `#[jtbd(...)]`
+    ///
+    /// **Required feature**: `jtbd-linkage`
+    #[cfg(feature = "jtbd-linkage")]
+    #[must_use]
+    pub fn test_coverage(&self) -> JtbdCoverage {
+        self.test_coverage_with(linked_tests())
+    }
+
+    /// Fail if any registered scenario has no test linked with `#[jtbd(...)]`
+    ///
+    /// **Required feature**: `jtbd-linkage`
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JtbdCoverageError::UncoveredScenarios`] listing every scenario without a
+    /// covering test.
+    #[cfg(feature = "jtbd-linkage")]
+    pub fn validate_test_coverage(&self) -> Result<JtbdCoverage, JtbdCoverageError> {
+        self.test_coverage().into_result()
+    }
 }
 
 impl Default for JtbdValidator {
@@ -417,6 +668,269 @@ impl JtbdValidationSummary {
     }
 }
 
+/// Result of one weighted outcome
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OutcomeResult {
+    /// Outcome name
+    pub name: String,
+    /// Relative weight
+    pub weight: f64,
+    /// Whether the outcome was achieved
+    pub achieved: bool,
+}
+
+/// Result of one acceptance criterion
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CriterionResult {
+    /// Criterion description
+    pub description: String,
+    /// Whether the criterion held
+    pub met: bool,
+}
+
+/// Score for one scenario
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScenarioScore {
+    /// Scenario name
+    pub scenario: String,
+    /// Whether execution succeeded
+    pub execution_success: bool,
+    /// Whether the scenario's `validate_result` accepted the result
+    pub jtbd_success: bool,
+    /// Whether execution, validation, and every acceptance criterion passed
+    pub accepted: bool,
+    /// Score (0–100%)
+    pub score: f64,
+    /// Weighted outcome results
+    pub outcomes: Vec<OutcomeResult>,
+    /// Acceptance criterion results
+    pub criteria: Vec<CriterionResult>,
+}
+
+/// Aggregated score for one job (mean of its scenarios)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobScore {
+    /// Job name
+    pub job: String,
+    /// Score (0–100%)
+    pub score: f64,
+    /// Scenario scores, in registration order
+    pub scenarios: Vec<ScenarioScore>,
+}
+
+/// Per-job JTBD scores across all scenarios
+///
+/// Serializes to JSON (via serde or [`JtbdReport::to_json`]) and renders to Markdown for
+/// product owners.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JtbdReport {
+    /// Overall score (mean of job scores, 0–100%)
+    pub score: f64,
+    /// Job scores, in order of first registration
+    pub jobs: Vec<JobScore>,
+}
+
+impl JtbdReport {
+    /// Score for the named job
+    #[must_use]
+    pub fn job(&self, name: &str) -> Option<&JobScore> {
+        self.jobs.iter().find(|j| j.job == name)
+    }
+
+    /// Whether every job scored at least `min_score` percent
+    #[must_use]
+    pub fn meets(&self, min_score: f64) -> bool {
+        self.jobs.iter().all(|j| j.score >= min_score)
+    }
+
+    /// Render as pretty-printed JSON
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+
+    /// Render as Markdown: a job summary table followed by per-scenario details
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!(
+            "# JTBD Report\n\n**Overall**: {:.1}%\n\n| Job | Score | Scenarios |\n|-----|-------|-----------|\n",
+            self.score
+        );
+        for job in &self.jobs {
+            let _ =
+                writeln!(markdown, "| {} | {:.1}% | {} |", job.job, job.score, job.scenarios.len());
+        }
+        for job in &self.jobs {
+            let _ = write!(markdown, "\n## {} ({:.1}%)\n", job.job, job.score);
+            for scenario in &job.scenarios {
+                let verdict = if scenario.accepted {
+                    "accepted"
+                } else if !scenario.execution_success {
+                    "execution failed"
+                } else {
+                    "not accepted"
+                };
+                let _ = write!(
+                    markdown,
+                    "\n### {}: {:.1}% ({verdict})\n\n",
+                    scenario.scenario, scenario.score
+                );
+                for criterion in &scenario.criteria {
+                    let _ = writeln!(
+                        markdown,
+                        "- [{}] Acceptance: {}",
+                        if criterion.met { "x" } else { " " },
+                        criterion.description
+                    );
+                }
+                for outcome in &scenario.outcomes {
+                    let _ = writeln!(
+                        markdown,
+                        "- [{}] {} (weight {})",
+                        if outcome.achieved { "x" } else { " " },
+                        outcome.name,
+                        outcome.weight
+                    );
+                }
+            }
+        }
+        markdown
+    }
+}
+
+// ============================================================================
+// Test Linkage
+// ============================================================================
+
+/// A test linked to a JTBD scenario with the `#[jtbd(job = "...", scenario = "...")]`
+/// attribute
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct JtbdTestLink {
+    /// Job the test covers
+    pub job: &'static str,
+    /// Scenario the test covers
+    pub scenario: &'static str,
+    /// Test path (`module::path::test_fn`)
+    pub test: &'static str,
+}
+
+/// Link-time registry of tests annotated with `#[jtbd(...)]`
+///
+/// **Required feature**: `jtbd-linkage`
+#[cfg(feature = "jtbd-linkage")]
+#[linkme::distributed_slice]
+pub static JTBD_TEST_LINKS: [JtbdTestLink];
+
+/// Every test linked with `#[jtbd(...)]` in this binary, in no particular order
+///
+/// **Required feature**: `jtbd-linkage`
+#[cfg(feature = "jtbd-linkage")]
+#[must_use]
+pub fn linked_tests() -> &'static [JtbdTestLink] {
+    JTBD_TEST_LINKS.static_slice()
+}
+
+/// Tests covering one registered scenario
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScenarioCoverage {
+    /// Job name
+    pub job: String,
+    /// Scenario name
+    pub scenario: String,
+    /// Linked tests
+    pub tests: Vec<String>,
+}
+
+impl ScenarioCoverage {
+    /// Whether at least one test covers the scenario
+    #[must_use]
+    pub const fn is_covered(&self) -> bool {
+        !self.tests.is_empty()
+    }
+}
+
+/// Which registered scenarios are covered by linked tests
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct JtbdCoverage {
+    /// Registered scenarios, in order of first registration
+    pub scenarios: Vec<ScenarioCoverage>,
+    /// Links naming a job/scenario pair that is not registered (typos or stale links)
+    pub unmatched: Vec<JtbdTestLink>,
+}
+
+impl JtbdCoverage {
+    /// Scenarios without a covering test
+    pub fn uncovered(&self) -> impl Iterator<Item = &ScenarioCoverage> {
+        self.scenarios.iter().filter(|s| !s.is_covered())
+    }
+
+    /// Whether every registered scenario has a covering test
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.uncovered().next().is_none()
+    }
+
+    /// `Ok(self)` if every scenario is covered
+    ///
+    /// # Errors
+    ///
+    /// Returns [`JtbdCoverageError::UncoveredScenarios`] otherwise.
+    pub fn into_result(self) -> Result<Self, JtbdCoverageError> {
+        let uncovered: Vec<String> =
+            self.uncovered().map(|s| format!("{} / {}", s.job, s.scenario)).collect();
+        if uncovered.is_empty() {
+            Ok(self)
+        } else {
+            Err(JtbdCoverageError::UncoveredScenarios { uncovered })
+        }
+    }
+}
+
+impl fmt::Display for JtbdCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for scenario in &self.scenarios {
+            let tests =
+                if scenario.is_covered() { scenario.tests.join(", ") } else { "-".to_string() };
+            writeln!(
+                f,
+                "{} {} / {}: {tests}",
+                if scenario.is_covered() { "✅" } else { "❌" },
+                scenario.job,
+                scenario.scenario
+            )?;
+        }
+        for link in &self.unmatched {
+            writeln!(
+                f,
+                "⚠️  {} links unknown scenario {} / {}",
+                link.test, link.job, link.scenario
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// JTBD test coverage error
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum JtbdCoverageError {
+    /// Registered scenarios have no linked test
+    #[error("🚨 {} JTBD scenario(s) have no covering test: {}\n   💡 FIX: Link a test with #[jtbd(job = \"...\", scenario = \"...\")]", uncovered.len(), uncovered.join(", "))]
+    UncoveredScenarios {
+        /// Uncovered scenarios as `job / scenario`
+        uncovered: Vec<String>,
+    },
+}
+
+#[allow(clippy::cast_precision_loss)] // Scenario counts stay far below 2^53
+fn mean(scores: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = scores.fold((0.0, 0_usize), |(sum, count), s| (sum + s, count + 1));
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f64
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::panic, clippy::unwrap_used, clippy::float_cmp)] // Test code - panic, unwrap, and float comparisons are acceptable
 mod tests {
@@ -507,4 +1021,109 @@ mod tests {
         let result = validator.validate_scenario(invalid_index);
         assert!(result.is_none());
     }
+
+    fn checkout(order_id: Option<&'static str>) -> JtbdScenario {
+        JtbdScenario {
+            name: "Checkout".to_string(),
+            setup_context: Box::new(ExecutionContext::default),
+            execute: Box::new(move |_ctx| {
+                let mut vars = HashMap::new();
+                if let Some(id) = order_id {
+                    vars.insert("order_id".to_string(), id.to_string());
+                }
+                ExecutionResult::ok(vars)
+            }),
+            validate_result: Box::new(|_ctx, result| result.success),
+            expected_behavior: "Customer can buy the cart".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_weighted_outcomes_score_per_job() {
+        let mut validator = JtbdValidator::new();
+        validator.register_scored_scenario(
+            checkout(Some("ORD-1"))
+                .for_job("Buy products")
+                .outcome("order persisted", 3.0, |_ctx, r| r.variables.contains_key("order_id"))
+                .outcome("receipt emailed", 1.0, |_ctx, r| r.variables.contains_key("receipt")),
+        );
+        validator.register_scored_scenario(checkout(Some("ORD-2")).for_job("Buy products"));
+        validator.register_scenario(checkout(None));
+
+        let report = validator.score_all();
+
+        let buy = report.job("Buy products").unwrap();
+        assert_eq!(buy.scenarios[0].score, 75.0);
+        assert_eq!(buy.scenarios[1].score, 100.0);
+        assert_eq!(buy.score, 87.5);
+        assert_eq!(report.job("Checkout").unwrap().score, 100.0);
+        assert_eq!(report.score, 93.75);
+        assert!(report.meets(80.0));
+        assert!(!report.meets(90.0));
+    }
+
+    #[test]
+    fn test_failed_acceptance_scores_zero() {
+        let mut validator = JtbdValidator::new();
+        validator.register_scored_scenario(
+            checkout(None)
+                .for_job("Buy products")
+                .acceptance("order id issued", |_ctx, r| r.variables.contains_key("order_id"))
+                .outcome("payment captured", 1.0, |_ctx, r| r.success),
+        );
+
+        let report = validator.score_all();
+
+        let scenario = &report.jobs[0].scenarios[0];
+        assert!(!scenario.accepted);
+        assert!(scenario.outcomes[0].achieved);
+        assert_eq!(scenario.score, 0.0);
+        let markdown = report.to_markdown();
+        assert!(markdown.contains("### Checkout: 0.0% (not accepted)"), "{markdown}");
+        assert!(markdown.contains("- [ ] Acceptance: order id issued"));
+        assert!(markdown.contains("- [x] payment captured (weight 1)"));
+    }
+
+    #[test]
+    fn test_jtbd_report_json_roundtrip() {
+        let mut validator = JtbdValidator::new();
+        validator.register_scored_scenario(
+            checkout(Some("ORD-1"))
+                .for_job("Buy products")
+                .outcome("paid", 1.0, |_, r| r.success),
+        );
+        let report = validator.score_all();
+
+        let parsed: JtbdReport = serde_json::from_str(&report.to_json()).unwrap();
+
+        assert_eq!(parsed, report);
+        assert!(validator.validate_all()[0].jtbd_success);
+    }
+
+    #[test]
+    fn test_coverage_reports_uncovered_and_unmatched() {
+        let mut validator = JtbdValidator::new();
+        validator.register_scored_scenario(checkout(Some("ORD-1")).for_job("Buy products"));
+        validator.register_scenario(checkout(None));
+        let links = [
+            JtbdTestLink { job: "Buy products", scenario: "Checkout", test: "tests::test_buy" },
+            JtbdTestLink { job: "Buy products", scenario: "Chekout", test: "tests::test_typo" },
+        ];
+
+        let coverage = validator.test_coverage_with(&links);
+
+        assert_eq!(coverage.scenarios.len(), 2);
+        assert_eq!(coverage.scenarios[0].tests, vec!["tests::test_buy".to_string()]);
+        assert_eq!(coverage.unmatched, vec![links[1]]);
+        assert!(!coverage.is_complete());
+        assert!(coverage.to_string().contains("❌ Checkout / Checkout: -"));
+        let err = coverage.into_result().unwrap_err();
+        assert_eq!(
+            err,
+            JtbdCoverageError::UncoveredScenarios {
+                uncovered: vec!["Checkout / Checkout".to_string()]
+            }
+        );
+        assert!(err.to_string().contains("1 JTBD scenario(s) have no covering test"));
+    }
 }
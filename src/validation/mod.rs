@@ -2,19 +2,27 @@
 //!
 //! Quality assurance and constraint validation: test coverage analysis,
 //! guard constraints (runtime and compile-time), Jobs To Be Done validation,
-//! and performance validation.
+//! performance validation, heap profiling, A/B benchmark comparison, and the Chicago TDD
+//! compliance audit.
 
 pub mod advanced_phases;
+pub mod audit;
+pub mod bench_compare;
 pub mod coverage;
 pub mod guards;
+#[cfg(feature = "heap-profiling")]
+pub mod heap_profile;
 pub mod jtbd;
 pub mod performance;
 pub mod thermal;
 
 // Re-export commonly used items
 pub use advanced_phases::*;
+pub use bench_compare::*;
 pub use coverage::*;
 pub use guards::*;
+#[cfg(feature = "heap-profiling")]
+pub use heap_profile::*;
 pub use jtbd::*;
 pub use performance::*;
 pub use thermal::*;
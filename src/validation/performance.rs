@@ -1,8 +1,12 @@
 //! Performance Validation
 //!
-//! Provides RDTSC benchmarking and tick measurement utilities for hot path validation.
+//! Provides cycle-counter benchmarking and tick measurement utilities for hot path validation.
 //! Ensures operations meet the Chatman Constant (≤8 ticks = 2ns budget).
 //!
+//! Ticks come from a [`CycleCounter`] backend selected at runtime: RDTSC on `x86_64`,
+//! CNTVCT on ARM64 (including Apple Silicon), and a monotonic clock everywhere else.
+//! Set `CHICAGO_TDD_CYCLE_COUNTER` to force a backend.
+//!
 //! # Poka-Yoke: Type-Level Validation
 //!
 //! This module provides both runtime validation (for dynamic cases) and compile-time
@@ -10,6 +14,8 @@
 //! compile-time validated tick budgets.
 
 use crate::core::const_assert::Validated;
+use std::sync::OnceLock;
+use std::time::Instant;
 use thiserror::Error;
 
 /// Performance validation error
@@ -32,11 +38,165 @@ pub type PerformanceValidationResult<T> = Result<T, PerformanceValidationError>;
 /// Tick budget for hot path operations (Chatman Constant: 8 ticks = 2ns)
 pub const HOT_PATH_TICK_BUDGET: u64 = 8;
 
-/// Tick counter using RDTSC (Read Time-Stamp Counter)
+/// Environment variable that forces a [`CycleCounter`] backend by name
+///
+/// Accepts `rdtsc`, `cntvct`, or `monotonic`. Unknown or unavailable backends are
+/// ignored (with a warning) and auto-selection is used instead.
+pub const CYCLE_COUNTER_ENV_VAR: &str = "CHICAGO_TDD_CYCLE_COUNTER";
+
+/// Source of tick counts for [`TickCounter`]
+///
+/// Backends are cheap, stateless readers of a monotonically increasing counter. Tick
+/// units are backend-specific (CPU cycles, timer ticks, or nanoseconds), so budgets
+/// should be compared only against measurements from the same backend.
+pub trait CycleCounter: Send + Sync {
+    /// Backend name, as accepted by [`CYCLE_COUNTER_ENV_VAR`]
+    fn name(&self) -> &'static str;
+
+    /// Whether this backend can be read on the current machine
+    fn is_available(&self) -> bool;
+
+    /// Read the current tick count
+    fn read(&self) -> u64;
+}
+
+/// x86 Time-Stamp Counter (`rdtsc`)
+#[cfg(target_arch = "x86_64")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RdtscCounter;
+
+#[cfg(target_arch = "x86_64")]
+impl CycleCounter for RdtscCounter {
+    fn name(&self) -> &'static str {
+        "rdtsc"
+    }
+
+    fn is_available(&self) -> bool {
+        std::arch::is_x86_feature_detected!("tsc")
+    }
+
+    fn read(&self) -> u64 {
+        // SAFETY: RDTSC is safe on x86_64 - it's a read-only instruction
+        #[allow(unsafe_code)]
+        unsafe {
+            std::arch::x86_64::_rdtsc()
+        }
+    }
+}
+
+/// ARM64 virtual counter (`CNTVCT_EL0`)
+///
+/// Readable from user space on Linux and macOS, including Apple Silicon.
+#[cfg(target_arch = "aarch64")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CntvctCounter;
+
+#[cfg(target_arch = "aarch64")]
+impl CycleCounter for CntvctCounter {
+    fn name(&self) -> &'static str {
+        "cntvct"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn read(&self) -> u64 {
+        // SAFETY: Reading CNTVCT_EL0 is safe - it's a read-only register
+        let val: u64;
+        #[allow(unsafe_code)]
+        unsafe {
+            std::arch::asm!(
+                "mrs {}, cntvct_el0",
+                out(reg) val,
+                options(nostack, nomem)
+            );
+        }
+        val
+    }
+}
+
+/// Monotonic-clock fallback, counting nanoseconds since first use
 ///
-/// On `x86_64`, uses `rdtsc` instruction for cycle counting.
-/// On other platforms, uses `std::time::Instant` as fallback.
+/// Available on every platform; used when no hardware counter is.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MonotonicCounter;
+
+impl CycleCounter for MonotonicCounter {
+    fn name(&self) -> &'static str {
+        "monotonic"
+    }
+
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    fn read(&self) -> u64 {
+        static ORIGIN: OnceLock<Instant> = OnceLock::new();
+        // Nanoseconds won't exceed u64::MAX for reasonable process lifetimes
+        #[allow(clippy::cast_possible_truncation)]
+        let nanos = ORIGIN.get_or_init(Instant::now).elapsed().as_nanos() as u64;
+        nanos
+    }
+}
+
+/// Backends in order of preference for the current target
+#[must_use]
+pub fn cycle_counter_backends() -> Vec<&'static dyn CycleCounter> {
+    vec![
+        #[cfg(target_arch = "x86_64")]
+        &RdtscCounter,
+        #[cfg(target_arch = "aarch64")]
+        &CntvctCounter,
+        &MonotonicCounter,
+    ]
+}
+
+/// The process-wide [`CycleCounter`], selected on first use
+///
+/// Honors [`CYCLE_COUNTER_ENV_VAR`] when it names an available backend; otherwise
+/// picks the first available backend from [`cycle_counter_backends`].
+#[must_use]
+pub fn cycle_counter() -> &'static dyn CycleCounter {
+    static SELECTED: OnceLock<&'static dyn CycleCounter> = OnceLock::new();
+    *SELECTED.get_or_init(|| select_cycle_counter(std::env::var(CYCLE_COUNTER_ENV_VAR).ok()))
+}
+
+fn select_cycle_counter(requested: Option<String>) -> &'static dyn CycleCounter {
+    let backends = cycle_counter_backends();
+    if let Some(requested) = requested.filter(|name| !name.is_empty()) {
+        match backends.iter().find(|b| b.name().eq_ignore_ascii_case(&requested)) {
+            Some(backend) if backend.is_available() => return *backend,
+            Some(_) => {
+                #[cfg(feature = "logging")]
+                log::warn!(
+                    "{CYCLE_COUNTER_ENV_VAR}={requested}: backend unavailable on this machine, auto-selecting"
+                );
+                #[cfg(not(feature = "logging"))]
+                eprintln!(
+                    "Warning: {CYCLE_COUNTER_ENV_VAR}={requested}: backend unavailable on this machine, auto-selecting"
+                );
+            }
+            None => {
+                #[cfg(feature = "logging")]
+                log::warn!("{CYCLE_COUNTER_ENV_VAR}={requested}: unknown backend, auto-selecting");
+                #[cfg(not(feature = "logging"))]
+                eprintln!(
+                    "Warning: {CYCLE_COUNTER_ENV_VAR}={requested}: unknown backend, auto-selecting"
+                );
+            }
+        }
+    }
+    backends.into_iter().find(|b| b.is_available()).unwrap_or(&MonotonicCounter)
+}
+
+/// Tick counter backed by a [`CycleCounter`]
+///
+/// [`Self::start`] uses the auto-selected backend ([`cycle_counter`]): RDTSC on
+/// `x86_64`, CNTVCT on ARM64, and a monotonic clock elsewhere.
 pub struct TickCounter {
+    /// Backend the ticks are read from
+    counter: &'static dyn CycleCounter,
     /// Start tick count
     start_ticks: u64,
 }
@@ -45,50 +205,25 @@ impl TickCounter {
     /// Create a new tick counter and start counting
     #[must_use]
     pub fn start() -> Self {
-        Self { start_ticks: Self::read_ticks() }
+        Self::start_with(cycle_counter())
     }
 
-    /// Read current tick count
-    fn read_ticks() -> u64 {
-        #[cfg(target_arch = "x86_64")]
-        {
-            // SAFETY: RDTSC is safe on x86_64 - it's a read-only instruction
-            #[allow(unsafe_code)]
-            unsafe {
-                std::arch::x86_64::_rdtsc()
-            }
-        }
-        #[cfg(target_arch = "aarch64")]
-        {
-            // ARM64: Use CNTVCT_EL0 (Virtual Count Register)
-            // SAFETY: Reading CNTVCT_EL0 is safe - it's a read-only register
-            let val: u64;
-            #[allow(unsafe_code)]
-            unsafe {
-                std::arch::asm!(
-                    "mrs {}, cntvct_el0",
-                    out(reg) val,
-                    options(nostack, nomem)
-                );
-            }
-            val
-        }
-        #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
-        {
-            // Fallback: use SystemTime for non-x86_64/ARM64 platforms
-            #[allow(clippy::cast_possible_truncation)]
-            // Nanoseconds won't exceed u64::MAX for reasonable durations
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .unwrap_or_default()
-                .as_nanos() as u64
-        }
+    /// Create a tick counter on a specific backend and start counting
+    #[must_use]
+    pub fn start_with(counter: &'static dyn CycleCounter) -> Self {
+        Self { counter, start_ticks: counter.read() }
+    }
+
+    /// Name of the backend this counter reads
+    #[must_use]
+    pub fn backend(&self) -> &'static str {
+        self.counter.name()
     }
 
     /// Get elapsed ticks since start
     #[must_use]
     pub fn elapsed_ticks(&self) -> u64 {
-        Self::read_ticks().saturating_sub(self.start_ticks)
+        self.counter.read().saturating_sub(self.start_ticks)
     }
 
     /// Check if elapsed ticks exceed budget
@@ -569,6 +704,29 @@ mod tests {
         assert!(result.min_ticks <= result.max_ticks);
     }
 
+    #[test]
+    fn test_cycle_counter_auto_selects_available_backend() {
+        let selected = select_cycle_counter(None);
+        assert!(selected.is_available());
+        assert_eq!(selected.name(), cycle_counter_backends()[0].name());
+        #[cfg(target_arch = "aarch64")]
+        assert_eq!(selected.name(), "cntvct");
+    }
+
+    #[test]
+    fn test_cycle_counter_env_override() {
+        assert_eq!(select_cycle_counter(Some("MONOTONIC".to_string())).name(), "monotonic");
+        assert!(select_cycle_counter(Some("sundial".to_string())).is_available());
+    }
+
+    #[test]
+    fn test_tick_counter_on_monotonic_backend() {
+        let counter = TickCounter::start_with(&MonotonicCounter);
+        std::thread::sleep(std::time::Duration::from_millis(1));
+        assert_eq!(counter.backend(), "monotonic");
+        assert!(counter.elapsed_ticks() >= 1_000_000);
+    }
+
     #[test]
     fn test_tick_measurer() {
         let measurer = TickMeasurer::new(|| 42);
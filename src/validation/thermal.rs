@@ -26,9 +26,17 @@
 //!     - Full IO (Docker, network, storage)
 //!     - Integration with external services
 //!     - OTEL/Weaver observability
+//!
+//! ThermalSimulator → ThermalLevel × N → Injects:
+//!     - Background CPU load (spinning threads)
+//!     - Frequency throttling (section stretched by a degradation factor)
+//!     - Reports measured-vs-budget margin per level
 //! ```
 
 use crate::validation::performance::{TickCounter, HOT_PATH_TICK_BUDGET};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Barrier;
 use thiserror::Error;
 
 /// Thermal test error
@@ -67,6 +75,17 @@ pub enum ThermalTestError {
         /// Budget limit
         budget: usize,
     },
+
+    /// Tick budget exceeded under simulated thermal throttling
+    #[error("Tick budget exceeded at thermal level '{level}': {actual} > {budget}")]
+    ThermalBudgetExceeded {
+        /// Thermal level name
+        level: String,
+        /// Median ticks measured at that level
+        actual: u64,
+        /// Budget limit
+        budget: u64,
+    },
 }
 
 /// Result type for thermal tests
@@ -377,6 +396,319 @@ impl Default for ColdPathTest {
     }
 }
 
+// ============================================================================
+// Thermal throttling simulation
+// ============================================================================
+
+/// One simulated thermal condition
+///
+/// `degradation` is the slowdown factor applied to the section under test (1.0 = full
+/// clock speed, 2.0 = half clock speed); `load_threads` background threads spin on the
+/// CPU while the section runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalLevel {
+    /// Level name used in reports
+    pub name: String,
+    /// Slowdown factor (clamped to at least 1.0)
+    pub degradation: f64,
+    /// Number of background threads generating CPU load
+    pub load_threads: usize,
+}
+
+impl ThermalLevel {
+    /// Create a level with the given degradation factor and no background load
+    #[must_use]
+    pub fn new(name: impl Into<String>, degradation: f64) -> Self {
+        Self { name: name.into(), degradation: degradation.max(1.0), load_threads: 0 }
+    }
+
+    /// Spin `threads` background threads while the section runs
+    #[must_use]
+    pub const fn with_load_threads(mut self, threads: usize) -> Self {
+        self.load_threads = threads;
+        self
+    }
+
+    /// Full clock speed, no background load
+    #[must_use]
+    pub fn nominal() -> Self {
+        Self::new("nominal", 1.0)
+    }
+
+    /// Moderate throttling: 1.5× slowdown with one busy core
+    #[must_use]
+    pub fn warm() -> Self {
+        Self::new("warm", 1.5).with_load_threads(1)
+    }
+
+    /// Heavy throttling: 2× slowdown with two busy cores
+    #[must_use]
+    pub fn hot() -> Self {
+        Self::new("hot", 2.0).with_load_threads(2)
+    }
+
+    /// Severe throttling: 4× slowdown with every other core busy
+    #[must_use]
+    pub fn critical() -> Self {
+        let cores = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+        Self::new("critical", 4.0).with_load_threads(cores.saturating_sub(1))
+    }
+
+    /// The default ladder: nominal, warm, hot, critical
+    #[must_use]
+    pub fn ladder() -> Vec<Self> {
+        vec![Self::nominal(), Self::warm(), Self::hot(), Self::critical()]
+    }
+}
+
+/// Measured-vs-budget result for one thermal level
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalMeasurement {
+    /// Level the section ran under
+    pub level: ThermalLevel,
+    /// Median ticks across iterations (including injected throttling)
+    pub median_ticks: u64,
+    /// Slowest iteration
+    pub max_ticks: u64,
+    /// Budget the median was checked against
+    pub budget: u64,
+}
+
+impl ThermalMeasurement {
+    /// Ticks left under the budget (negative when over budget)
+    #[must_use]
+    pub fn margin(&self) -> i128 {
+        i128::from(self.budget) - i128::from(self.median_ticks)
+    }
+
+    /// Margin as a fraction of the budget (0.25 = 25% headroom, negative when over)
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // Tick counts stay far below 2^53
+    pub fn margin_ratio(&self) -> f64 {
+        if self.budget == 0 {
+            return if self.median_ticks == 0 { 0.0 } else { f64::NEG_INFINITY };
+        }
+        self.margin() as f64 / self.budget as f64
+    }
+
+    /// Whether the median stayed within budget
+    #[must_use]
+    pub const fn within_budget(&self) -> bool {
+        self.median_ticks <= self.budget
+    }
+}
+
+/// Per-level results of a [`ThermalSimulator`] run
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThermalReport {
+    /// Tick budget every level was checked against
+    pub budget: u64,
+    /// One measurement per level, in the order configured
+    pub measurements: Vec<ThermalMeasurement>,
+}
+
+impl ThermalReport {
+    /// Whether every level stayed within budget
+    #[must_use]
+    pub fn passed(&self) -> bool {
+        self.measurements.iter().all(ThermalMeasurement::within_budget)
+    }
+
+    /// Measurement with the smallest margin
+    #[must_use]
+    pub fn worst(&self) -> Option<&ThermalMeasurement> {
+        self.measurements.iter().min_by_key(|m| m.margin())
+    }
+
+    /// Measurement for the named level
+    #[must_use]
+    pub fn level(&self, name: &str) -> Option<&ThermalMeasurement> {
+        self.measurements.iter().find(|m| m.level.name == name)
+    }
+
+    /// Convert into an error naming the first level over budget
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ThermalTestError::ThermalBudgetExceeded`] if any level exceeded the budget.
+    pub fn into_result(self) -> ThermalTestResult<Self> {
+        let exceeded = self.measurements.iter().find(|m| !m.within_budget()).map(|m| {
+            ThermalTestError::ThermalBudgetExceeded {
+                level: m.level.name.clone(),
+                actual: m.median_ticks,
+                budget: m.budget,
+            }
+        });
+        exceeded.map_or(Ok(self), Err)
+    }
+}
+
+impl fmt::Display for ThermalReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Thermal simulation (budget {} ticks)", self.budget)?;
+        for m in &self.measurements {
+            writeln!(
+                f,
+                "  {:<10} x{:<4.2} load={:<3} median={:<10} max={:<10} margin={:+} ({:+.1}%){}",
+                m.level.name,
+                m.level.degradation,
+                m.level.load_threads,
+                m.median_ticks,
+                m.max_ticks,
+                m.margin(),
+                m.margin_ratio() * 100.0,
+                if m.within_budget() { "" } else { "  OVER BUDGET" }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Thermal throttling simulator
+///
+/// Runs a section under each configured [`ThermalLevel`]: background threads spin to
+/// create CPU contention, and each iteration is stretched by the level's degradation
+/// factor (busy-waiting until `degradation × raw ticks` have elapsed) to emulate a
+/// lowered clock. The median of each level's iterations is checked against the tick
+/// budget, and the report records the margin at every level.
+///
+/// # Example
+///
+/// ```rust
+/// use chicago_tdd_tools::validation::thermal::{ThermalLevel, ThermalSimulator};
+///
+/// let report = ThermalSimulator::new(50_000_000)
+///     .with_levels(vec![ThermalLevel::nominal(), ThermalLevel::new("throttled", 2.0)])
+///     .run(|| std::hint::black_box((0..100).sum::<u64>()));
+///
+/// assert!(report.passed(), "{report}");
+/// assert!(report.level("throttled").unwrap().median_ticks > 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ThermalSimulator {
+    budget: u64,
+    levels: Vec<ThermalLevel>,
+    iterations: usize,
+}
+
+impl ThermalSimulator {
+    /// Create a simulator checking `budget` ticks across [`ThermalLevel::ladder`]
+    #[must_use]
+    pub fn new(budget: u64) -> Self {
+        Self { budget, levels: ThermalLevel::ladder(), iterations: 5 }
+    }
+
+    /// Create a simulator for the hot path budget (τ ≤ 8)
+    #[must_use]
+    pub fn hot_path() -> Self {
+        Self::new(HOT_PATH_TICK_BUDGET)
+    }
+
+    /// Create a simulator for a warm path configuration's budget
+    #[must_use]
+    pub fn warm_path(config: WarmPathConfig) -> Self {
+        Self::new(config.max_ticks)
+    }
+
+    /// Replace the thermal levels
+    #[must_use]
+    pub fn with_levels(mut self, levels: Vec<ThermalLevel>) -> Self {
+        self.levels = levels;
+        self
+    }
+
+    /// Add a thermal level
+    #[must_use]
+    pub fn with_level(mut self, level: ThermalLevel) -> Self {
+        self.levels.push(level);
+        self
+    }
+
+    /// Iterations per level (at least 1; default 5)
+    #[must_use]
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations.max(1);
+        self
+    }
+
+    /// Configured thermal levels
+    #[must_use]
+    pub fn levels(&self) -> &[ThermalLevel] {
+        &self.levels
+    }
+
+    /// Run `f` under every level and report the measured-vs-budget margins
+    pub fn run<F, T>(&self, mut f: F) -> ThermalReport
+    where
+        F: FnMut() -> T,
+    {
+        let measurements =
+            self.levels.iter().map(|level| self.measure(level, &mut f)).collect::<Vec<_>>();
+        ThermalReport { budget: self.budget, measurements }
+    }
+
+    /// Run `f` under every level and assert the budget held at each
+    ///
+    /// # Panics
+    ///
+    /// Panics with the full report if any level exceeded the budget.
+    pub fn run_assert<F, T>(&self, f: F) -> ThermalReport
+    where
+        F: FnMut() -> T,
+    {
+        let report = self.run(f);
+        assert!(report.passed(), "Thermal simulation failed:\n{report}");
+        report
+    }
+
+    fn measure<F, T>(&self, level: &ThermalLevel, f: &mut F) -> ThermalMeasurement
+    where
+        F: FnMut() -> T,
+    {
+        let stop = AtomicBool::new(false);
+        let ready = Barrier::new(level.load_threads + 1);
+        let mut ticks = std::thread::scope(|scope| {
+            for _ in 0..level.load_threads {
+                scope.spawn(|| {
+                    ready.wait();
+                    while !stop.load(Ordering::Relaxed) {
+                        std::hint::spin_loop();
+                    }
+                });
+            }
+            ready.wait();
+            let ticks = (0..self.iterations)
+                .map(|_| throttled_ticks(level.degradation, &mut *f))
+                .collect::<Vec<_>>();
+            stop.store(true, Ordering::Relaxed);
+            ticks
+        });
+        ticks.sort_unstable();
+        ThermalMeasurement {
+            level: level.clone(),
+            median_ticks: ticks[ticks.len() / 2],
+            max_ticks: ticks.last().copied().unwrap_or(0),
+            budget: self.budget,
+        }
+    }
+}
+
+/// Run `f` once and busy-wait until `degradation × raw ticks` have elapsed
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation, clippy::cast_sign_loss)] // Tick counts stay far below 2^53; degradation is clamped to ≥ 1.0
+fn throttled_ticks<F, T>(degradation: f64, f: &mut F) -> u64
+where
+    F: FnMut() -> T,
+{
+    let counter = TickCounter::start();
+    std::hint::black_box(f());
+    let raw = counter.elapsed_ticks();
+    let target = (raw as f64 * degradation) as u64;
+    while counter.elapsed_ticks() < target {
+        std::hint::spin_loop();
+    }
+    counter.elapsed_ticks()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -485,4 +817,57 @@ mod tests {
             assert!(!display.is_empty(), "Error should have display message");
         }
     }
+
+    #[test]
+    fn test_thermal_simulator_reports_margin_per_level() {
+        let simulator = ThermalSimulator::new(u64::MAX / 8)
+            .with_levels(vec![ThermalLevel::nominal(), ThermalLevel::hot()])
+            .with_iterations(3);
+
+        let report = simulator.run_assert(|| std::hint::black_box((0..1_000).sum::<u64>()));
+
+        assert_eq!(report.measurements.len(), 2);
+        let hot = report.level("hot").unwrap();
+        assert_eq!(hot.level.load_threads, 2);
+        assert!(hot.max_ticks >= hot.median_ticks);
+        assert!(hot.margin() > 0 && hot.margin_ratio() > 0.0);
+        assert!(report.to_string().contains("hot"));
+    }
+
+    #[test]
+    fn test_thermal_simulator_throttling_stretches_section() {
+        let work = || (0..std::hint::black_box(100_000u64)).fold(0, u64::wrapping_add);
+        let report = ThermalSimulator::new(u64::MAX / 8)
+            .with_levels(vec![ThermalLevel::nominal(), ThermalLevel::new("x10", 10.0)])
+            .run(work);
+
+        let nominal = report.level("nominal").unwrap().median_ticks;
+        let throttled = report.level("x10").unwrap().median_ticks;
+        assert!(throttled > nominal, "throttled {throttled} <= nominal {nominal}");
+    }
+
+    #[test]
+    fn test_thermal_report_names_level_over_budget() {
+        let report = ThermalSimulator::new(0)
+            .with_levels(vec![ThermalLevel::new("slow", 2.0)])
+            .with_iterations(1)
+            .run(|| std::thread::sleep(std::time::Duration::from_millis(1)));
+
+        assert!(!report.passed());
+        assert!(report.worst().unwrap().margin() < 0);
+        assert!(report.to_string().contains("OVER BUDGET"));
+        match report.into_result() {
+            Err(ThermalTestError::ThermalBudgetExceeded { level, budget, .. }) => {
+                assert_eq!(level, "slow");
+                assert_eq!(budget, 0);
+            }
+            other => panic!("expected budget violation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_thermal_level_clamps_degradation() {
+        assert!((ThermalLevel::new("boost", 0.5).degradation - 1.0).abs() < f64::EPSILON);
+        assert_eq!(ThermalLevel::ladder().len(), 4);
+    }
 }
@@ -0,0 +1,47 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+//! Compile-fail guarantees
+//!
+//! Snippets the crate's types and macros must reject, checked with the compile-fail
+//! harness against the library cargo built for these tests.
+
+use chicago_tdd_tools::testing::compile_fail::{CompileFailCase, CompileFailHarness};
+
+#[test]
+fn test_poka_yoke_guarantees_do_not_compile() {
+    // Arrange
+    let harness = CompileFailHarness::for_crate(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .case(
+            CompileFailCase::new(
+                "validated_run_over_max_len",
+                "use chicago_tdd_tools::guards::validated::ValidatedRun;\n\
+                 let _ = ValidatedRun::<9>::new(vec![0; 9]);",
+            )
+            .expect_error("AssertRunLen<9>"),
+        )
+        .case(
+            CompileFailCase::new(
+                "typestate_illegal_transition",
+                "use chicago_tdd_tools::Typestate;\n\
+                 #[derive(Typestate)]\n\
+                 #[typestate(machine = Door, transitions(open: Closed -> Open))]\n\
+                 enum DoorState { Closed, Open }\n\
+                 let _ = Door::new().open().open();",
+            )
+            .expect_error("no method named `open` found for struct `Door<Open>`"),
+        )
+        .case(
+            CompileFailCase::new(
+                "const_assert_size_mismatch",
+                "chicago_tdd_tools::const_assert_size_eq!(u64, 4);",
+            )
+            .expect_error("size_of::<u64>() != 4"),
+        );
+
+    // Act
+    let report = harness.run().unwrap();
+
+    // Assert
+    report.assert_passed();
+    assert_eq!(report.outcomes.len(), 3);
+}
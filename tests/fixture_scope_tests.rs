@@ -0,0 +1,67 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+//! Tests for `#[fixture(scope = ..., setup = ...)]`
+//!
+//! Module- and session-scoped fixtures must be created once and shared; test-scoped
+//! fixtures (the default) are created per test.
+
+use chicago_tdd_tools::fixture;
+use chicago_tdd_tools::fixture::{FixtureResult, FixtureScopeKind, SharedFixture};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static SESSION_SETUPS: AtomicUsize = AtomicUsize::new(0);
+
+/// Stands in for an expensive resource such as a container
+#[derive(Debug)]
+struct ExpensiveService {
+    port: u16,
+}
+
+#[allow(clippy::unnecessary_wraps)] // Fixture setup signature
+fn start_service() -> FixtureResult<ExpensiveService> {
+    SESSION_SETUPS.fetch_add(1, Ordering::SeqCst);
+    Ok(ExpensiveService { port: 5432 })
+}
+
+#[fixture(scope = "session", setup = start_service)]
+fn test_session_fixture_first_user() {
+    let shared: &SharedFixture<ExpensiveService> = &fixture;
+    assert_eq!(shared.scope(), FixtureScopeKind::Session);
+    assert_eq!(fixture.port, 5432);
+    assert_eq!(SESSION_SETUPS.load(Ordering::SeqCst), 1);
+}
+
+#[fixture(scope = "session", setup = start_service)]
+fn test_session_fixture_second_user() {
+    assert_eq!(fixture.port, 5432);
+    assert_eq!(SESSION_SETUPS.load(Ordering::SeqCst), 1, "setup must run once per process");
+}
+
+mod module_scope {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Counter of the first `TestFixture` seen by this module's tests
+    static SEEN: Mutex<Option<u64>> = Mutex::new(None);
+
+    fn assert_same_instance(counter: u64) {
+        let first = *SEEN.lock().unwrap().get_or_insert(counter);
+        assert_eq!(first, counter, "module fixture was recreated");
+    }
+
+    #[fixture(scope = "module")]
+    fn test_module_fixture_first_user() {
+        assert_eq!(fixture.scope(), FixtureScopeKind::Module);
+        assert_same_instance(fixture.test_counter());
+    }
+
+    #[fixture(scope = "module")]
+    fn test_module_fixture_second_user() {
+        assert_same_instance(fixture.test_counter());
+    }
+}
+
+#[fixture]
+fn test_default_scope_is_per_test() {
+    fixture.set_metadata("phase".to_string(), "act".to_string());
+    assert_eq!(fixture.get_metadata("phase").map(String::as_str), Some("act"));
+}
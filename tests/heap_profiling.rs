@@ -0,0 +1,47 @@
+//! Heap profiling assertions
+//!
+//! DHAT counts allocations process-wide, so these run in their own test binary with the
+//! profiling allocator installed.
+#![cfg(feature = "heap-profiling")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use chicago_tdd_tools::performance_test;
+use chicago_tdd_tools::validation::heap_profile::{HeapProfile, HeapProfilingAlloc};
+use std::hint::black_box;
+
+#[global_allocator]
+static ALLOC: HeapProfilingAlloc = HeapProfilingAlloc;
+
+#[inline(never)]
+fn build_labels(count: usize) -> Vec<String> {
+    (0..count).map(|i| format!("label-{i}")).collect()
+}
+
+// The budget leaves headroom for the test harness, which allocates on its own threads
+performance_test!(test_allocation_budget_passes, allocations <= 16, {
+    let labels = black_box(vec![1_u8, 2, 3]);
+    assert_eq!(labels.len(), 3);
+});
+
+#[test]
+#[allow(clippy::significant_drop_tightening)] // The profile is consumed by the assertion
+fn test_exceeded_budget_reports_hotspots_and_keeps_artifact() {
+    // Arrange
+    let profile = HeapProfile::start("over_budget");
+    let artifact = profile.artifact_path().to_path_buf();
+
+    // Act
+    black_box(build_labels(50));
+    let failure = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        profile.assert_max_allocations(10);
+    }))
+    .unwrap_err();
+
+    // Assert
+    let message = failure.downcast_ref::<String>().unwrap();
+    assert!(message.contains("expected at most 10 allocation(s)"), "{message}");
+    assert!(message.contains("Top allocation sites"), "{message}");
+    assert!(message.contains("build_labels"), "{message}");
+    assert!(artifact.exists(), "artifact missing: {}", artifact.display());
+    std::fs::remove_file(artifact).unwrap();
+}
@@ -548,6 +548,7 @@ fn test_orchestrator_schedules_tests_by_priority_and_qos() {
         qos: QoSClass::BestEffort,
         resource_budget: ResourceBudget::default_budget(),
         metadata: std::collections::HashMap::new(),
+        requirements: std::collections::HashMap::new(),
     };
 
     let high_priority = TestPlan {
@@ -558,6 +559,7 @@ fn test_orchestrator_schedules_tests_by_priority_and_qos() {
         qos: QoSClass::Premium,
         resource_budget: ResourceBudget::unlimited(),
         metadata: std::collections::HashMap::new(),
+        requirements: std::collections::HashMap::new(),
     };
 
     orchestrator.submit_plan(low_priority);
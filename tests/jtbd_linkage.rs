@@ -0,0 +1,67 @@
+//! Tests for `#[jtbd(job = ..., scenario = ...)]`
+//!
+//! Links are collected at link time, so they are checked from an integration test binary
+//! that declares its own linked tests.
+#![cfg(feature = "jtbd-linkage")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use chicago_tdd_tools::jtbd;
+use chicago_tdd_tools::jtbd::{
+    linked_tests, ExecutionContext, ExecutionResult, JtbdCoverageError, JtbdScenario, JtbdValidator,
+};
+use std::collections::HashMap;
+
+fn scenario(name: &str) -> JtbdScenario {
+    JtbdScenario {
+        name: name.to_string(),
+        setup_context: Box::new(ExecutionContext::default),
+        execute: Box::new(|_ctx| ExecutionResult::ok(HashMap::new())),
+        validate_result: Box::new(|_ctx, result| result.success),
+        expected_behavior: "Customer can buy the cart".to_string(),
+    }
+}
+
+#[jtbd(job = "Buy products", scenario = "Checkout")]
+#[test]
+fn test_checkout_is_linked() {
+    // Arrange & Act
+    let link = linked_tests().iter().find(|l| l.test.ends_with("::test_checkout_is_linked"));
+
+    // Assert
+    let link = link.expect("the attribute registers the test");
+    assert_eq!((link.job, link.scenario), ("Buy products", "Checkout"));
+    assert_eq!(link.test, "jtbd_linkage::test_checkout_is_linked");
+}
+
+#[test]
+fn test_validator_fails_for_scenario_without_linked_test() {
+    // Arrange
+    let mut validator = JtbdValidator::new();
+    validator.register_scored_scenario(scenario("Checkout").for_job("Buy products"));
+    validator.register_scored_scenario(scenario("Refund").for_job("Buy products"));
+
+    // Act
+    let result = validator.validate_test_coverage();
+
+    // Assert
+    assert_eq!(
+        result.unwrap_err(),
+        JtbdCoverageError::UncoveredScenarios {
+            uncovered: vec!["Buy products / Refund".to_string()]
+        }
+    );
+}
+
+#[test]
+fn test_validator_passes_when_every_scenario_is_linked() {
+    // Arrange
+    let mut validator = JtbdValidator::new();
+    validator.register_scored_scenario(scenario("Checkout").for_job("Buy products"));
+
+    // Act
+    let coverage = validator.validate_test_coverage().unwrap();
+
+    // Assert
+    assert!(coverage.is_complete());
+    assert_eq!(coverage.scenarios[0].tests, vec!["jtbd_linkage::test_checkout_is_linked"]);
+}
@@ -0,0 +1,276 @@
+//! Deterministic simulation of swarm consensus (`swarm::consensus`)
+//!
+//! Five coordinator replicas exchange messages through an in-memory network. Every
+//! round, the seeded `DeterministicPool` decides the order in which nodes tick and
+//! messages are delivered, and partitions cut off the nodes it schedules first, so a
+//! failing seed replays exactly. Raft's safety properties are checked after every round.
+#![cfg(feature = "deterministic-scheduling")]
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+use chicago_tdd_tools::deterministic_test;
+use chicago_tdd_tools::swarm::consensus::{
+    Decision, LogEntry, RaftConfig, RaftEnvelope, RaftNode, Role,
+};
+use chicago_tdd_tools::swarm::{OperationChain, SwarmCoordinator, SwarmMember};
+use chicago_tdd_tools::testing::scheduling::{DeterministicPool, SchedulingMode};
+use std::collections::HashMap;
+
+const NODES: [&str; 5] = ["c1", "c2", "c3", "c4", "c5"];
+
+/// Rounds allowed for an election or for replication to finish
+const ROUNDS: usize = 300;
+
+struct Simulation {
+    nodes: Vec<RaftNode>,
+    in_flight: Vec<RaftEnvelope>,
+    /// Partition group of each node; messages only flow within a group
+    groups: Vec<usize>,
+    /// Leader of each term seen so far
+    leaders: HashMap<u64, String>,
+    /// Longest committed log seen so far
+    committed: Vec<LogEntry>,
+}
+
+impl Simulation {
+    fn new(pool: &DeterministicPool) -> Self {
+        let seed = match pool.schedule() {
+            SchedulingMode::Seeded(seed) => seed,
+            SchedulingMode::Sequential => 0,
+        };
+        let config = RaftConfig::default().with_seed(seed);
+        Self {
+            nodes: NODES.iter().map(|id| RaftNode::new(*id, NODES, config)).collect(),
+            in_flight: Vec::new(),
+            groups: vec![0; NODES.len()],
+            leaders: HashMap::new(),
+            committed: Vec::new(),
+        }
+    }
+
+    fn index(id: &str) -> usize {
+        NODES.iter().position(|node| *node == id).unwrap()
+    }
+
+    /// Cut `nodes` off from the rest of the cluster
+    fn isolate(&mut self, nodes: &[usize]) {
+        self.heal();
+        for node in nodes {
+            self.groups[*node] = 1;
+        }
+    }
+
+    /// Cut off `count` nodes chosen by the scheduler
+    fn partition(&mut self, pool: &DeterministicPool, count: usize) {
+        let order = pool.execution_order(NODES.len());
+        self.isolate(&order[..count]);
+    }
+
+    fn heal(&mut self) {
+        self.groups = vec![0; NODES.len()];
+    }
+
+    /// Every node ticks, then every in-flight message is delivered (or dropped at a
+    /// partition), each in scheduler order
+    fn round(&mut self, pool: &DeterministicPool) {
+        for node in pool.execution_order(self.nodes.len()) {
+            self.nodes[node].tick();
+        }
+        let mut batch: Vec<Option<RaftEnvelope>> =
+            std::mem::take(&mut self.in_flight).into_iter().map(Some).collect();
+        for index in pool.execution_order(batch.len()) {
+            let envelope = batch[index].take().unwrap();
+            let (from, to) = (Self::index(&envelope.from), Self::index(&envelope.to));
+            if self.groups[from] == self.groups[to] {
+                self.nodes[to].step(envelope);
+            }
+        }
+        for node in &mut self.nodes {
+            self.in_flight.extend(node.take_messages());
+        }
+        self.check_safety();
+    }
+
+    fn run_until(&mut self, pool: &DeterministicPool, done: impl Fn(&Self) -> bool) -> bool {
+        for _ in 0..ROUNDS {
+            if done(self) {
+                return true;
+            }
+            self.round(pool);
+        }
+        done(self)
+    }
+
+    /// Election safety and state machine safety
+    fn check_safety(&mut self) {
+        for node in &self.nodes {
+            if node.role() == Role::Leader {
+                let leader =
+                    self.leaders.entry(node.term()).or_insert_with(|| node.id().to_string());
+                assert_eq!(leader, node.id(), "two leaders in term {}", node.term());
+            }
+        }
+        for node in &self.nodes {
+            let committed = node.committed();
+            let shared = committed.len().min(self.committed.len());
+            assert_eq!(
+                committed[..shared],
+                self.committed[..shared],
+                "{} committed a log that diverges from another replica's",
+                node.id()
+            );
+            if committed.len() > self.committed.len() {
+                self.committed = committed.to_vec();
+            }
+        }
+    }
+
+    /// The latest-term leader that can reach a majority, if any
+    ///
+    /// A leader cut off in an earlier term still believes it leads until it hears of
+    /// the newer term.
+    fn leader(&self) -> Option<usize> {
+        (0..NODES.len())
+            .filter(|node| self.nodes[*node].is_leader())
+            .filter(|node| {
+                let group = self.groups[*node];
+                self.groups.iter().filter(|g| **g == group).count() > NODES.len() / 2
+            })
+            .max_by_key(|node| self.nodes[*node].term())
+    }
+
+    /// Whether every node in the leader's group has committed the leader's whole log
+    fn replicated(&self) -> bool {
+        self.leader().is_some_and(|leader| {
+            let log = self.nodes[leader].log();
+            (0..NODES.len())
+                .filter(|node| self.groups[*node] == self.groups[leader])
+                .all(|node| self.nodes[node].committed() == log)
+        })
+    }
+
+    fn propose(&mut self, decision: Decision) -> Option<u64> {
+        let leader = self.leader()?;
+        self.nodes[leader].propose(decision).ok()
+    }
+
+    /// Propose `decision` until the whole cluster has committed it
+    ///
+    /// A proposal is lost when its leader is deposed before replicating it, so clients
+    /// retry, as they would against a real cluster.
+    fn commit(&mut self, pool: &DeterministicPool, decision: &Decision) -> bool {
+        let committed = |sim: &Self| {
+            sim.nodes.iter().all(|node| {
+                node.committed().iter().any(|entry| entry.decision.as_ref() == Some(decision))
+            })
+        };
+        for _ in 0..5 {
+            if !self.run_until(pool, |sim| sim.leader().is_some()) {
+                return false;
+            }
+            self.propose(decision.clone());
+            if self.run_until(pool, |sim| committed(sim) || sim.replicated()) && committed(self) {
+                return true;
+            }
+        }
+        false
+    }
+}
+
+fn coordinator() -> SwarmCoordinator {
+    let mut coordinator = SwarmCoordinator::new();
+    for agent in ["agent-1", "agent-2", "agent-3"] {
+        coordinator.register_member(
+            SwarmMember::new(agent.to_string(), agent.to_string()).with_capacity(100),
+        );
+    }
+    coordinator
+}
+
+deterministic_test!(test_cluster_elects_one_recognised_leader, pool, seeds = 0..32, {
+    // Arrange
+    let mut sim = Simulation::new(pool);
+
+    // Act
+    let elected = sim.run_until(pool, |sim| {
+        sim.leader()
+            .is_some_and(|leader| sim.nodes.iter().all(|node| node.leader() == Some(NODES[leader])))
+    });
+
+    // Assert
+    assert!(elected, "no leader recognised by every node within {ROUNDS} rounds");
+});
+
+deterministic_test!(test_committed_decisions_survive_partitions, pool, seeds = 0..16, {
+    // Arrange
+    let mut sim = Simulation::new(pool);
+    let mut replicas: Vec<SwarmCoordinator> = NODES.iter().map(|_| coordinator()).collect();
+    let mut proposed = 0;
+
+    // Act: alternate healthy and partitioned phases while proposing decisions
+    for phase in 0..6 {
+        if phase % 2 == 1 {
+            sim.partition(pool, 2);
+        } else {
+            sim.heal();
+        }
+        for step in 0..40 {
+            sim.round(pool);
+            if step % 10 == 5 {
+                let agent = format!("agent-{}", proposed % 3 + 1);
+                if sim.propose(Decision::assign_task(format!("task-{proposed}"), agent)).is_some() {
+                    proposed += 1;
+                }
+            }
+        }
+    }
+    sim.heal();
+    let chain = OperationChain::new("chain-1".to_string(), "Review".to_string());
+    assert!(sim.commit(pool, &Decision::compose_chain(&chain)), "final decision never committed");
+    for (node, replica) in sim.nodes.iter_mut().zip(&mut replicas) {
+        for decision in node.take_committed() {
+            replica.apply_decision(&decision).unwrap();
+        }
+    }
+
+    // Assert: every replica committed the same log and reached the same assignments
+    let log = sim.nodes[0].committed().to_vec();
+    assert!(sim.nodes.iter().all(|node| node.committed() == log));
+    assert!(proposed > 0, "no decision was proposed");
+    for task in 0..proposed {
+        let task = format!("task-{task}");
+        let assigned = replicas[0].assignment(&task);
+        assert!(replicas.iter().all(|replica| replica.assignment(&task) == assigned), "{task}");
+    }
+    assert!(replicas.iter().all(|replica| replica.composed_chain("chain-1").is_some()));
+});
+
+deterministic_test!(test_minority_leader_cannot_commit, pool, seeds = 0..8, {
+    // Arrange: a settled leader, then cut it off with one follower
+    let mut sim = Simulation::new(pool);
+    assert!(sim.run_until(pool, |sim| sim.leader().is_some()));
+    let old_leader = sim.leader().unwrap();
+    let follower = (old_leader + 1) % NODES.len();
+    sim.isolate(&[old_leader, follower]);
+
+    // Act
+    let stranded = Decision::assign_task("stranded", "agent-1");
+    let stranded_index = sim.nodes[old_leader].propose(stranded.clone()).unwrap();
+    assert!(sim.run_until(pool, |sim| sim.leader().is_some_and(|leader| leader != old_leader)));
+    let agreed = Decision::assign_task("agreed", "agent-2");
+    sim.propose(agreed.clone()).unwrap();
+    assert!(sim.run_until(pool, Simulation::replicated));
+    assert!(sim.nodes[old_leader].commit_index() < stranded_index, "minority committed");
+    sim.heal();
+    assert!(sim.run_until(pool, |sim| {
+        sim.nodes.iter().all(|node| {
+            node.committed().iter().any(|entry| entry.decision.as_ref() == Some(&agreed))
+        })
+    }));
+
+    // Assert: the old leader stepped down and dropped its uncommitted entry
+    assert_ne!(sim.nodes[old_leader].role(), Role::Leader);
+    assert!(sim
+        .nodes
+        .iter()
+        .all(|node| node.log().iter().all(|entry| entry.decision.as_ref() != Some(&stranded))));
+});
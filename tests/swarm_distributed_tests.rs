@@ -0,0 +1,236 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+//! Tests for distributed test execution (`swarm::distributed_runner`)
+//!
+//! Workers are real processes: this test binary re-runs itself with `--exact
+//! distributed_worker_entry`, which serves one shard over stdin/stdout. Environment
+//! variables make a worker crash or hang partway through its shard.
+
+use chicago_tdd_tools::core::receipt::TestOutcome;
+use chicago_tdd_tools::swarm::distributed_runner::{serve_stdio, DistributedRunner, WorkerSpec};
+use chicago_tdd_tools::swarm::{QoSClass, ResourceBudget, TestPlan};
+use std::collections::HashMap;
+use std::time::Duration;
+
+const WORKER_ENV: &str = "CTT_DISTRIBUTED_WORKER";
+const CRASH_AFTER_ENV: &str = "CTT_WORKER_CRASH_AFTER";
+const HANG_ENV: &str = "CTT_WORKER_HANG";
+/// Marker file: only the first worker to create it crashes
+const CRASH_ONCE_ENV: &str = "CTT_WORKER_CRASH_ONCE";
+
+/// Worker process body; a no-op when run as an ordinary test
+#[test]
+fn distributed_worker_entry() {
+    if std::env::var_os(WORKER_ENV).is_none() {
+        return;
+    }
+    let crash_after: Option<usize> =
+        std::env::var(CRASH_AFTER_ENV).ok().map(|count| count.parse().unwrap());
+    let crash_once = std::env::var_os(CRASH_ONCE_ENV);
+    let hang = std::env::var_os(HANG_ENV).is_some();
+    let mut ran = 0;
+    serve_stdio(|contract| {
+        let first_crash = || {
+            crash_once.as_ref().is_none_or(|marker| {
+                std::fs::OpenOptions::new().write(true).create_new(true).open(marker).is_ok()
+            })
+        };
+        if crash_after == Some(ran) && first_crash() {
+            std::process::exit(3);
+        }
+        if hang {
+            std::thread::sleep(Duration::from_secs(60));
+        }
+        ran += 1;
+        std::thread::sleep(Duration::from_millis(20));
+        if contract.contains("fail") {
+            TestOutcome::Fail
+        } else {
+            TestOutcome::Pass
+        }
+    })
+    .unwrap();
+}
+
+fn worker(name: &str) -> WorkerSpec {
+    WorkerSpec::local(name, std::env::current_exe().unwrap().display().to_string())
+        .with_arg("--exact")
+        .with_arg("distributed_worker_entry")
+        .with_arg("--nocapture")
+        .with_arg("--test-threads=1")
+        .with_env(WORKER_ENV, "1")
+}
+
+fn plan(contracts: &[&str]) -> TestPlan {
+    TestPlan {
+        plan_id: "distributed".to_string(),
+        contracts: contracts.iter().map(ToString::to_string).collect(),
+        requester: "ci".to_string(),
+        priority: 50,
+        qos: QoSClass::Standard,
+        resource_budget: ResourceBudget::unlimited(),
+        metadata: HashMap::new(),
+        requirements: HashMap::new(),
+    }
+}
+
+fn contracts(
+    result: &chicago_tdd_tools::swarm::test_orchestrator::TestExecutionResult,
+) -> Vec<&str> {
+    result.receipts.iter().map(|receipt| receipt.contract_name.as_str()).collect()
+}
+
+#[test]
+fn test_shards_run_on_every_worker_and_merge_in_plan_order() {
+    // Arrange
+    let names = ["t0", "t1", "t2_fail", "t3", "t4", "t5"];
+    let runner =
+        DistributedRunner::new(vec![worker("w1"), worker("w2"), worker("w3")]).with_shard_size(2);
+
+    // Act
+    let report = runner.run(&plan(&names)).unwrap();
+
+    // Assert
+    assert!(report.failures.is_empty(), "{:?}", report.failures);
+    assert_eq!(contracts(&report.result), names);
+    assert_eq!(report.result.summary.passed, 5);
+    assert_eq!(report.result.summary.failed, 1);
+    let mut workers: Vec<_> = report
+        .result
+        .receipts
+        .iter()
+        .filter_map(|receipt| receipt.get_metadata("worker"))
+        .collect();
+    workers.sort_unstable();
+    workers.dedup();
+    assert!(workers.len() > 1, "only {workers:?} ran shards");
+}
+
+#[test]
+fn test_crashed_worker_shard_is_reassigned() {
+    // Arrange: whichever worker gets the shard reports one test, then exits
+    let names = ["t0", "t1", "t2", "t3"];
+    let marker = std::env::temp_dir().join(format!("ctt-crash-once-{}", std::process::id()));
+    std::fs::remove_file(&marker).ok();
+    let crashy = |name: &str| {
+        worker(name)
+            .with_env(CRASH_AFTER_ENV, "1")
+            .with_env(CRASH_ONCE_ENV, marker.display().to_string())
+    };
+    let runner = DistributedRunner::new(vec![crashy("w1"), crashy("w2")]).with_shard_size(4);
+
+    // Act
+    let report = runner.run(&plan(&names)).unwrap();
+
+    // Assert: nothing lost, nothing run twice
+    assert!(report.result.summary.all_passed(), "{:?}", report.result.receipts);
+    assert_eq!(contracts(&report.result), names);
+    assert_eq!(report.failures.len(), 1);
+    let crashed = report.failures[0].worker.as_str();
+    let first = &report.result.receipts[0];
+    assert_eq!(first.get_metadata("worker"), Some(crashed));
+    assert_eq!(first.get_metadata("attempt"), Some("1"));
+    let rest = &report.result.receipts[1..];
+    assert!(rest.iter().all(|receipt| receipt.get_metadata("worker") != Some(crashed)));
+    assert!(rest.iter().all(|receipt| receipt.get_metadata("attempt") == Some("2")));
+    std::fs::remove_file(&marker).ok();
+}
+
+#[test]
+fn test_hung_worker_times_out_and_is_retired() {
+    // Arrange
+    let runner = DistributedRunner::new(vec![worker("hung").with_env(HANG_ENV, "1")])
+        .with_shard_timeout(Duration::from_millis(500));
+
+    // Act
+    let report = runner.run(&plan(&["t0", "t1"])).unwrap();
+
+    // Assert: no worker left, so the tests error instead of hanging the run
+    assert_eq!(report.failures.len(), 1);
+    assert!(report.failures[0].reason.contains("timed out"), "{}", report.failures[0].reason);
+    assert!(report
+        .result
+        .receipts
+        .iter()
+        .all(|receipt| receipt.result == TestOutcome::Error));
+    assert!(report.result.receipts[0]
+        .get_metadata("error")
+        .unwrap()
+        .contains("no workers left"));
+}
+
+#[cfg(unix)]
+#[test]
+fn test_timeout_kills_worker_grandchildren() {
+    // Arrange: the worker forks a grandchild that holds its stdout open
+    let forking = WorkerSpec::local("forking", "sh").with_arg("-c").with_arg("sleep 30 & wait");
+    let runner =
+        DistributedRunner::new(vec![forking]).with_shard_timeout(Duration::from_millis(300));
+    let started = std::time::Instant::now();
+
+    // Act
+    let report = runner.run(&plan(&["t0"])).unwrap();
+
+    // Assert: the run does not wait for the grandchild's sleep to end
+    assert!(started.elapsed() < Duration::from_secs(10), "{:?}", started.elapsed());
+    assert!(report.failures[0].reason.contains("timed out"), "{}", report.failures[0].reason);
+}
+
+#[cfg(unix)]
+#[test]
+fn test_finished_worker_with_child_holding_stdout_is_not_a_failure() {
+    // Arrange: the worker finishes its shard and exits, leaving a background child
+    // that keeps its stdout open
+    let script = r#"read shard
+echo 'ctt-result {"contract":"t0","outcome":"Pass","wall_clock_ms":1}'
+echo 'ctt-done 0'
+sleep 30 &"#;
+    let daemonizing = WorkerSpec::local("daemonizing", "sh").with_arg("-c").with_arg(script);
+    let runner =
+        DistributedRunner::new(vec![daemonizing]).with_shard_timeout(Duration::from_secs(20));
+    let started = std::time::Instant::now();
+
+    // Act
+    let report = runner.run(&plan(&["t0"])).unwrap();
+
+    // Assert: the run neither waits for the child nor reports the worker as failed
+    assert!(started.elapsed() < Duration::from_secs(10), "{:?}", started.elapsed());
+    assert!(report.failures.is_empty(), "{:?}", report.failures);
+    assert!(report.result.summary.all_passed(), "{:?}", report.result.receipts);
+}
+
+#[test]
+fn test_shard_errors_after_max_attempts() {
+    // Arrange: every worker crashes before reporting anything
+    let runner = DistributedRunner::new(vec![
+        worker("a").with_env(CRASH_AFTER_ENV, "0"),
+        worker("b").with_env(CRASH_AFTER_ENV, "0"),
+        worker("c").with_env(CRASH_AFTER_ENV, "0"),
+    ])
+    .with_max_attempts(2);
+
+    // Act
+    let report = runner.run(&plan(&["t0"])).unwrap();
+
+    // Assert: the third worker never gets a third attempt
+    assert_eq!(report.failures.len(), 2);
+    let receipt = &report.result.receipts[0];
+    assert_eq!(receipt.result, TestOutcome::Error);
+    assert!(receipt.get_metadata("error").unwrap().contains("failed 2 times"));
+}
+
+#[test]
+fn test_missing_worker_program_is_a_worker_failure() {
+    // Arrange
+    let runner = DistributedRunner::new(vec![
+        WorkerSpec::local("missing", "/nonexistent/ctt-worker"),
+        worker("healthy"),
+    ])
+    .with_shard_size(1);
+
+    // Act
+    let report = runner.run(&plan(&["t0", "t1"])).unwrap();
+
+    // Assert
+    assert!(report.result.summary.all_passed());
+    assert!(report.failures.iter().all(|failure| failure.worker == "missing"));
+}
@@ -0,0 +1,103 @@
+#![allow(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+//! Tests for SWIM gossip membership (`swarm::gossip`)
+//!
+//! Every node binds a real UDP socket on localhost; nothing is mocked.
+
+use chicago_tdd_tools::assert_eventually;
+use chicago_tdd_tools::swarm::member::MemberState;
+use chicago_tdd_tools::swarm::{GossipConfig, GossipNode, PeerStatus, SwarmMember};
+use std::time::Duration;
+
+const CONVERGENCE: Duration = Duration::from_secs(10);
+
+fn config(swarm_id: &str) -> GossipConfig {
+    GossipConfig::new(swarm_id)
+        .with_protocol_period(Duration::from_millis(50))
+        .with_ack_timeout(Duration::from_millis(20))
+        .with_suspicion_timeout(Duration::from_millis(300))
+}
+
+fn node(id: &str, swarm_id: &str) -> GossipNode {
+    let member =
+        SwarmMember::new(id.to_string(), format!("{id} agent")).with_sector("Academic".to_string());
+    GossipNode::start(member, "127.0.0.1:0", config(swarm_id)).unwrap()
+}
+
+fn ids(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_members_discover_each_other_through_a_seed() {
+    // Arrange
+    let a = node("a", "discovery");
+    let b = node("b", "discovery");
+    let c = node("c", "discovery");
+
+    // Act: c only knows b, b only knows a
+    b.join(a.local_addr()).unwrap();
+    c.join(b.local_addr()).unwrap();
+
+    // Assert
+    assert_eventually!(a.alive_peers() => |peers| *peers == ids(&["b", "c"]), timeout = CONVERGENCE);
+    assert_eventually!(b.alive_peers() => |peers| *peers == ids(&["a", "c"]), timeout = CONVERGENCE);
+    assert_eventually!(c.alive_peers() => |peers| *peers == ids(&["a", "b"]), timeout = CONVERGENCE);
+    let membership = c.membership();
+    assert_eq!(membership.swarm_id, "discovery");
+    assert_eq!(membership.member_count(), 3);
+    assert_eq!(membership.members_for_sector("Academic").len(), 3);
+}
+
+#[test]
+fn test_stopped_member_is_marked_failed() {
+    // Arrange
+    let a = node("a", "failure");
+    let b = node("b", "failure");
+    let c = node("c", "failure");
+    b.join(a.local_addr()).unwrap();
+    c.join(a.local_addr()).unwrap();
+    assert_eventually!(b.alive_peers() => |peers| peers.len() == 2, timeout = CONVERGENCE);
+
+    // Act
+    c.shutdown();
+
+    // Assert
+    assert_eventually!(a.peer_status("c") == Some(PeerStatus::Failed), timeout = CONVERGENCE);
+    assert_eventually!(b.peer_status("c") == Some(PeerStatus::Failed), timeout = CONVERGENCE);
+    assert_eq!(a.membership().get_member("c").map(|m| m.state), Some(MemberState::Failed));
+    assert_eq!(a.alive_peers(), ids(&["b"]));
+}
+
+#[test]
+fn test_restarted_member_rejoins_with_higher_incarnation() {
+    // Arrange
+    let a = node("a", "rejoin");
+    let b = node("b", "rejoin");
+    b.join(a.local_addr()).unwrap();
+    assert_eventually!(a.alive_peers() => |peers| *peers == ids(&["b"]), timeout = CONVERGENCE);
+    b.shutdown();
+    assert_eventually!(a.peer_status("b") == Some(PeerStatus::Failed), timeout = CONVERGENCE);
+
+    // Act: same member ID, new process and port
+    let restarted = node("b", "rejoin");
+    restarted.join(a.local_addr()).unwrap();
+
+    // Assert
+    assert_eventually!(a.peer_status("b") == Some(PeerStatus::Alive), timeout = CONVERGENCE);
+    assert!(restarted.incarnation() > 0, "rejoining must outbid the recorded failure");
+}
+
+#[test]
+fn test_members_of_other_swarms_are_ignored() {
+    // Arrange
+    let a = node("a", "claims");
+    let outsider = node("x", "academic");
+
+    // Act
+    outsider.join(a.local_addr()).unwrap();
+    std::thread::sleep(Duration::from_millis(200));
+
+    // Assert
+    assert_eq!(a.peer_status("x"), None);
+    assert!(outsider.alive_peers().is_empty());
+}
@@ -0,0 +1,55 @@
+//! Tests for `#[tdd_test]` phase timing
+//!
+//! The attribute reads phase markers from comments, so it is exercised from an
+//! integration test binary on real source.
+#![allow(clippy::unwrap_used, clippy::expect_used)]
+
+use chicago_tdd_tools::core::events::{subscribe, unsubscribe, Event, EventLog};
+use chicago_tdd_tools::core::receipt::{current_phase_timings, AaaPhase};
+use chicago_tdd_tools::tdd_test;
+
+fn phases(log: &EventLog) -> Vec<AaaPhase> {
+    log.events()
+        .into_iter()
+        .filter_map(|event| match event {
+            Event::PhaseEntered { phase } => Some(phase),
+            _ => None,
+        })
+        .collect()
+}
+
+#[tdd_test]
+fn test_marker_comments_enter_phases() {
+    // Arrange
+    let log = EventLog::current_thread();
+    let id = subscribe(log.clone());
+    let values = [3, 1, 2];
+
+    // Act: Sum the values
+    let total: i32 = values.iter().sum();
+
+    // Assert
+    unsubscribe(id);
+    assert_eq!(total, 6);
+    assert_eq!(phases(&log), [AaaPhase::Act, AaaPhase::Assert]);
+    let timings = current_phase_timings().expect("markers time the phases");
+    assert!(timings.total() > 0);
+}
+
+#[tdd_test]
+async fn test_marker_comments_enter_phases_in_async_tests() {
+    // Arrange
+    let log = EventLog::current_thread();
+    let id = subscribe(log.clone());
+
+    // Act & Assert
+    tokio::task::yield_now().await;
+    unsubscribe(id);
+    assert_eq!(phases(&log), [AaaPhase::Act]);
+}
+
+#[tdd_test]
+fn test_unmarked_body_has_no_phase_timings() {
+    let timings = current_phase_timings();
+    assert_eq!(timings, None);
+}
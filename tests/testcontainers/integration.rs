@@ -107,5 +107,29 @@ mod integration_tests {
             "Commands should produce different output"
         );
     });
+
+    test!(integration_chaos_restores_container_on_drop, {
+        // Arrange: Set up Docker and create real container
+        require_docker();
+        let client = ContainerClient::new();
+        let container = GenericContainer::with_command(client.client(), ALPINE_IMAGE, ALPINE_TAG, "sleep", &["infinity"], None)
+            .unwrap_or_else(|e| panic!("Failed to create container: {}", e));
+
+        // Act: Pause, partition, and kill the container, then drop the chaos handle
+        {
+            let mut chaos = container.chaos().unwrap_or_else(|e| panic!("Failed to get chaos handle: {}", e));
+            assert_ok!(&chaos.pause(), "Should pause container");
+            let partitioned = chaos.partition();
+            assert_ok!(&partitioned, "Should disconnect container from its networks");
+            assert_ok!(&chaos.kill(), "Should kill container");
+            assert!(!chaos.is_restored(), "Disruptions should be outstanding");
+        }
+
+        // Assert: Container is running again and usable
+        let result = container.exec("echo", &["recovered"]);
+        assert_ok!(&result, "Should execute command after restoration");
+        let exec_result = result.expect("Exec result should be available after assert_ok verification");
+        assert_eq!(exec_result.stdout.trim(), "recovered");
+    });
 }
 
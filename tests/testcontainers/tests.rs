@@ -427,6 +427,25 @@ mod tests {
         assert_that_with_msg(&(port80 > 0 && port443 > 0 && port8080 > 0), |v| *v, "All ports should be mapped");
     });
 
+    test!(free_host_ports_are_reserved_while_container_runs, {
+        // Arrange: Set up Docker and client
+        require_docker();
+        let client = ContainerClient::new();
+
+        // Act: Map container port to a pre-allocated host port
+        let container =
+            GenericContainer::with_free_host_ports(client.client(), NGINX_IMAGE, NGINX_TAG, &[80])
+                .unwrap_or_else(|e| panic!("Failed to create container: {}", e));
+        let port = container
+            .get_host_port(80)
+            .unwrap_or_else(|e| panic!("Failed to get host port: {}", e));
+
+        // Assert: Port stays reserved until the container is dropped
+        assert!(chicago_tdd_tools::core::ports::PortAllocator::is_reserved(port));
+        drop(container);
+        assert!(!chicago_tdd_tools::core::ports::PortAllocator::is_reserved(port));
+    });
+
     test!(env_vars_all_paths, {
         // Arrange: Set up Docker and client
         require_docker();